@@ -29,6 +29,9 @@ pub enum LinkedProvider {
     GitHub,
     Google,
     Slack,
+    /// An organisation's identity provider. Only linked from the user's
+    /// own session, never surfaced on the account page.
+    Sso,
 }
 
 impl LinkedProvider {
@@ -37,6 +40,7 @@ impl LinkedProvider {
             Self::GitHub => "github",
             Self::Google => "google",
             Self::Slack => "slack",
+            Self::Sso => "sso",
         }
     }
 
@@ -45,6 +49,7 @@ impl LinkedProvider {
             "github" => Some(Self::GitHub),
             "google" => Some(Self::Google),
             "slack" => Some(Self::Slack),
+            "sso" => Some(Self::Sso),
             _ => None,
         }
     }
@@ -54,6 +59,7 @@ impl LinkedProvider {
             Self::GitHub => "GitHub",
             Self::Google => "Google",
             Self::Slack => "Slack",
+            Self::Sso => "Single sign-on",
         }
     }
 }
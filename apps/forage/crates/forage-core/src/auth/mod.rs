@@ -1,5 +1,6 @@
 pub mod linked;
 pub mod magic_link;
+pub mod sso;
 mod validation;
 
 pub use linked::{
@@ -98,7 +99,11 @@ pub enum AuthError {
 #[derive(Debug, Clone)]
 pub enum LoginResult {
     Success(AuthTokens),
-    MfaRequired { mfa_session_token: String },
+    MfaRequired {
+        mfa_session_token: String,
+        /// The second factors this user has set up.
+        methods: Vec<MfaMethod>,
+    },
     /// The server requires the user to verify their email before logging
     /// in. Forage shows a "verify your email" page with a resend form.
     EmailNotVerified,
@@ -132,6 +137,38 @@ pub struct MfaSetup {
     pub secret: String,
 }
 
+/// A second factor that can complete a password login.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MfaMethod {
+    Totp,
+    Passkey,
+}
+
+/// A WebAuthn passkey registered to the user.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Passkey {
+    pub passkey_id: String,
+    pub name: String,
+    pub created_at: Option<String>,
+    pub last_used_at: Option<String>,
+}
+
+/// A WebAuthn ceremony started by forest-server. `options_json` is handed
+/// to the browser's `navigator.credentials` API unchanged.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PasskeyCeremony {
+    pub ceremony_id: String,
+    pub options_json: String,
+}
+
+/// The browser's answer to a `PasskeyCeremony`: the credential JSON from
+/// `navigator.credentials.create()` or `.get()`.
+#[derive(Debug, Clone)]
+pub struct PasskeyResponse {
+    pub ceremony_id: String,
+    pub credential_json: String,
+}
+
 /// Result of an OAuth login via forest-server.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OAuthLoginResult {
@@ -279,6 +316,54 @@ pub trait ForestAuth: Send + Sync {
         code: &str,
     ) -> Result<(), AuthError>;
 
+    /// Start registering a passkey for the signed-in user.
+    async fn begin_passkey_registration(
+        &self,
+        access_token: &str,
+    ) -> Result<PasskeyCeremony, AuthError>;
+
+    /// Verify and store the passkey the browser created.
+    async fn finish_passkey_registration(
+        &self,
+        access_token: &str,
+        name: &str,
+        response: &PasskeyResponse,
+    ) -> Result<Passkey, AuthError>;
+
+    async fn list_passkeys(&self, access_token: &str) -> Result<Vec<Passkey>, AuthError>;
+
+    async fn rename_passkey(
+        &self,
+        access_token: &str,
+        passkey_id: &str,
+        name: &str,
+    ) -> Result<(), AuthError>;
+
+    async fn delete_passkey(&self, access_token: &str, passkey_id: &str)
+        -> Result<(), AuthError>;
+
+    /// Start a passkey login. With the MFA session token from a password
+    /// login the passkey is the second factor; without one it is a
+    /// passwordless login with a discoverable credential.
+    async fn begin_passkey_login(
+        &self,
+        mfa_session_token: Option<&str>,
+    ) -> Result<PasskeyCeremony, AuthError>;
+
+    /// Complete a passkey login started with `begin_passkey_login`.
+    async fn finish_passkey_login(
+        &self,
+        response: &PasskeyResponse,
+    ) -> Result<AuthTokens, AuthError>;
+
+    /// Ask `user_id` to confirm an action with one of their passkeys;
+    /// the response goes along with the action (`approve_device_login`).
+    /// Service-account-only on the forest side.
+    async fn begin_passkey_verification(
+        &self,
+        user_id: &str,
+    ) -> Result<PasskeyCeremony, AuthError>;
+
     /// List the OAuth identities linked to a user (GitHub, Google, etc.).
     /// Sourced from Forest's `identities` table via `GetUser`.
     /// Slack identities are NOT included — they live in Forage's
@@ -317,13 +402,15 @@ pub trait ForestAuth: Send + Sync {
 
     /// Approve a forest CLI device-login grant on behalf of the
     /// browser-authenticated user. Service-account-only on the forest
-    /// side. See apps/forest/TASKS/022-device-login.md.
+    /// side. See apps/forest/TASKS/022-device-login.md. With `passkey`,
+    /// forest only approves if the assertion verifies for `user_id`.
     async fn approve_device_login(
         &self,
         user_code: &str,
         user_id: &str,
         approving_ip: &str,
         approving_user_agent: &str,
+        passkey: Option<&PasskeyResponse>,
     ) -> Result<(), AuthError>;
 
     /// Deny a forest CLI device-login grant. Service-account-only.
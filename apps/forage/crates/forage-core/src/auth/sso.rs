@@ -0,0 +1,625 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::OidcIdentity;
+
+/// Provider name passed to forest's `OAuthLogin` for organisation SSO.
+pub const SSO_PROVIDER: &str = "sso";
+
+/// Roles an SSO-provisioned member may be given. Mirrors forest's
+/// organisation roles.
+pub const SSO_ROLES: &[&str] = &["member", "admin"];
+
+/// Prefix of the TXT record value that proves domain ownership.
+const DOMAIN_VERIFICATION_PREFIX: &str = "forage-domain-verification=";
+
+/// Shared mailbox providers. Nobody can prove ownership of these, so no
+/// organisation may claim them for SSO.
+const PUBLIC_EMAIL_DOMAINS: &[&str] = &[
+    "gmail.com",
+    "googlemail.com",
+    "outlook.com",
+    "hotmail.com",
+    "live.com",
+    "msn.com",
+    "yahoo.com",
+    "icloud.com",
+    "me.com",
+    "aol.com",
+    "proton.me",
+    "protonmail.com",
+    "gmx.com",
+    "gmx.net",
+    "mail.com",
+    "yandex.com",
+    "zoho.com",
+];
+
+/// Errors from SSO configuration and identity mapping.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum SsoError {
+    #[error("not found: {0}")]
+    NotFound(String),
+
+    #[error("invalid input: {0}")]
+    InvalidInput(String),
+
+    #[error("store error: {0}")]
+    Store(String),
+}
+
+/// An organisation's single sign-on setup. One per organisation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrgSsoConfig {
+    /// Organisation slug (as used in URLs).
+    pub organisation: String,
+    /// Forest organisation id, needed to provision members.
+    pub organisation_id: String,
+    pub provider: SsoProvider,
+    /// When set, members of the organisation may not sign in with a
+    /// password or magic link.
+    pub enforce_sso: bool,
+    /// When set, users that authenticate with the IdP but are not yet
+    /// members are added to the organisation with `default_role`.
+    pub jit_provisioning: bool,
+    pub default_role: String,
+    /// Lower-case email domains the IdP may assert. Each one must carry
+    /// the organisation's verification TXT record when it is claimed, so
+    /// an IdP never vouches for addresses outside domains the
+    /// organisation owns. Also used to route "sign in with SSO" by email
+    /// address.
+    pub allowed_domains: Vec<String>,
+    pub updated_by: String,
+    pub updated_at: String,
+}
+
+/// Identity provider protocol and its settings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "protocol", rename_all = "snake_case")]
+pub enum SsoProvider {
+    Oidc(OidcProviderConfig),
+    Saml(SamlProviderConfig),
+}
+
+impl SsoProvider {
+    pub fn protocol(&self) -> &'static str {
+        match self {
+            Self::Oidc(_) => "oidc",
+            Self::Saml(_) => "saml",
+        }
+    }
+}
+
+/// A generic OpenID Connect provider, located through its discovery
+/// document.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OidcProviderConfig {
+    /// Issuer URL or the full `.well-known/openid-configuration` URL.
+    pub discovery_url: String,
+    pub client_id: String,
+    pub client_secret: String,
+    pub scopes: Vec<String>,
+    #[serde(default)]
+    pub claims: ClaimMapping,
+}
+
+impl OidcProviderConfig {
+    /// URL of the discovery document, accepting either an issuer or the
+    /// document itself.
+    pub fn discovery_document_url(&self) -> String {
+        let url = self.discovery_url.trim_end_matches('/');
+        if url.ends_with("/.well-known/openid-configuration") {
+            url.to_string()
+        } else {
+            format!("{url}/.well-known/openid-configuration")
+        }
+    }
+}
+
+/// Which claims carry the user's identity. Claim names may use `.` to
+/// reach into nested objects (e.g. `profile.email`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClaimMapping {
+    pub subject: String,
+    pub email: String,
+    pub name: String,
+    #[serde(default)]
+    pub picture: Option<String>,
+}
+
+impl Default for ClaimMapping {
+    fn default() -> Self {
+        Self {
+            subject: "sub".into(),
+            email: "email".into(),
+            name: "name".into(),
+            picture: Some("picture".into()),
+        }
+    }
+}
+
+impl ClaimMapping {
+    /// Map a set of ID token / userinfo claims to an identity. The
+    /// subject and email are required; the name falls back to the email.
+    pub fn identity_from_claims(
+        &self,
+        claims: &serde_json::Value,
+    ) -> Result<OidcIdentity, SsoError> {
+        let sub = claim_str(claims, &self.subject).ok_or_else(|| {
+            SsoError::InvalidInput(format!("missing subject claim '{}'", self.subject))
+        })?;
+        let email = claim_str(claims, &self.email).ok_or_else(|| {
+            SsoError::InvalidInput(format!("missing email claim '{}'", self.email))
+        })?;
+        let name = claim_str(claims, &self.name).unwrap_or_else(|| email.clone());
+        let picture_url = self
+            .picture
+            .as_deref()
+            .filter(|c| !c.is_empty())
+            .and_then(|c| claim_str(claims, c));
+
+        Ok(OidcIdentity {
+            sub,
+            email,
+            name,
+            picture_url,
+            login: None,
+        })
+    }
+}
+
+fn claim_str(claims: &serde_json::Value, path: &str) -> Option<String> {
+    let value = path
+        .split('.')
+        .try_fold(claims, |value, key| value.get(key))?;
+    let s = match value {
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Number(n) => n.to_string(),
+        _ => return None,
+    };
+    (!s.trim().is_empty()).then_some(s)
+}
+
+/// A SAML 2.0 identity provider using the HTTP-Redirect binding for
+/// requests and HTTP-POST for responses.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SamlProviderConfig {
+    pub idp_entity_id: String,
+    pub idp_sso_url: String,
+    /// PEM-encoded signing certificate of the IdP.
+    pub idp_certificate: String,
+    /// Attribute holding the email. Empty uses the subject NameID.
+    #[serde(default)]
+    pub email_attribute: String,
+    /// Attribute holding the display name. Empty falls back to the email.
+    #[serde(default)]
+    pub name_attribute: String,
+}
+
+impl OrgSsoConfig {
+    /// Check the configuration is complete enough to attempt a login.
+    pub fn validate(&self) -> Result<(), SsoError> {
+        if !SSO_ROLES.contains(&self.default_role.as_str()) {
+            return Err(SsoError::InvalidInput(format!(
+                "default role must be one of: {}",
+                SSO_ROLES.join(", ")
+            )));
+        }
+        if self.allowed_domains.is_empty() {
+            return Err(SsoError::InvalidInput(
+                "at least one email domain is required".into(),
+            ));
+        }
+        for domain in &self.allowed_domains {
+            if domain.is_empty()
+                || domain.contains('@')
+                || !domain.contains('.')
+                || *domain != domain.to_ascii_lowercase()
+            {
+                return Err(SsoError::InvalidInput(format!(
+                    "invalid email domain: {domain}"
+                )));
+            }
+            if PUBLIC_EMAIL_DOMAINS.contains(&domain.as_str()) {
+                return Err(SsoError::InvalidInput(format!(
+                    "{domain} is a public email provider and cannot be used for SSO"
+                )));
+            }
+        }
+        match &self.provider {
+            SsoProvider::Oidc(oidc) => {
+                validate_url("discovery URL", &oidc.discovery_url)?;
+                if oidc.client_id.trim().is_empty() {
+                    return Err(SsoError::InvalidInput("client ID is required".into()));
+                }
+                if oidc.client_secret.is_empty() {
+                    return Err(SsoError::InvalidInput("client secret is required".into()));
+                }
+                if !oidc.scopes.iter().any(|s| s == "openid") {
+                    return Err(SsoError::InvalidInput(
+                        "scopes must include 'openid'".into(),
+                    ));
+                }
+                if oidc.claims.subject.is_empty() || oidc.claims.email.is_empty() {
+                    return Err(SsoError::InvalidInput(
+                        "subject and email claims are required".into(),
+                    ));
+                }
+            }
+            SsoProvider::Saml(saml) => {
+                if saml.idp_entity_id.trim().is_empty() {
+                    return Err(SsoError::InvalidInput("IdP entity ID is required".into()));
+                }
+                validate_url("IdP SSO URL", &saml.idp_sso_url)?;
+                if !saml.idp_certificate.contains("BEGIN CERTIFICATE") {
+                    return Err(SsoError::InvalidInput(
+                        "IdP certificate must be PEM encoded".into(),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether the IdP may sign in a user with this email.
+    pub fn email_allowed(&self, email: &str) -> bool {
+        email_domain(email).is_some_and(|d| self.allowed_domains.contains(&d))
+    }
+
+    /// The first claimed domain that does not publish this organisation's
+    /// verification TXT record. A DNS record is the proof of ownership
+    /// required to claim a domain; a mailbox on it is not enough.
+    pub async fn unproven_domain(
+        &self,
+        dns: &dyn DomainTxtLookup,
+    ) -> Result<Option<&str>, SsoError> {
+        for domain in &self.allowed_domains {
+            let expected = domain_verification_value(&self.organisation_id, domain);
+            let records = dns.txt_records(&domain_verification_name(domain)).await?;
+            if !records.iter().any(|r| r.trim() == expected) {
+                return Ok(Some(domain));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Provider user id stored in forest. Subjects are namespaced by
+    /// organisation so two IdPs issuing the same `sub` never collide.
+    pub fn provider_user_id(&self, subject: &str) -> String {
+        format!("{}:{}", self.organisation, subject)
+    }
+}
+
+/// Lower-cased domain part of an email address.
+pub fn email_domain(email: &str) -> Option<String> {
+    let (_, domain) = email.rsplit_once('@')?;
+    (!domain.is_empty()).then(|| domain.to_ascii_lowercase())
+}
+
+/// DNS name the domain verification TXT record is published at.
+pub fn domain_verification_name(domain: &str) -> String {
+    format!("_forage-verification.{domain}")
+}
+
+/// TXT record value that proves `organisation_id` controls `domain`.
+pub fn domain_verification_value(organisation_id: &str, domain: &str) -> String {
+    let digest = Sha256::digest(format!("{organisation_id}:{domain}").as_bytes());
+    let hex: String = digest.iter().map(|b| format!("{b:02x}")).collect();
+    format!("{DOMAIN_VERIFICATION_PREFIX}{hex}")
+}
+
+fn validate_url(field: &str, url: &str) -> Result<(), SsoError> {
+    let parsed = url::Url::parse(url)
+        .map_err(|e| SsoError::InvalidInput(format!("{field} is not a valid URL: {e}")))?;
+    // Plain http is only accepted for local IdPs during development.
+    let local = matches!(parsed.host_str(), Some("localhost" | "127.0.0.1" | "[::1]"));
+    match parsed.scheme() {
+        "https" => Ok(()),
+        "http" if local => Ok(()),
+        _ => Err(SsoError::InvalidInput(format!(
+            "{field} must be an https URL"
+        ))),
+    }
+}
+
+/// Resolves DNS TXT records for domain verification.
+#[async_trait::async_trait]
+pub trait DomainTxtLookup: Send + Sync {
+    /// TXT records at `name`; empty when the name has none.
+    async fn txt_records(&self, name: &str) -> Result<Vec<String>, SsoError>;
+}
+
+/// Fixed TXT records for development and tests.
+#[derive(Default)]
+pub struct InMemoryTxtRecords {
+    records: Mutex<HashMap<String, Vec<String>>>,
+}
+
+impl InMemoryTxtRecords {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn publish(&self, name: &str, value: &str) {
+        self.records
+            .lock()
+            .unwrap()
+            .entry(name.to_string())
+            .or_default()
+            .push(value.to_string());
+    }
+}
+
+#[async_trait::async_trait]
+impl DomainTxtLookup for InMemoryTxtRecords {
+    async fn txt_records(&self, name: &str) -> Result<Vec<String>, SsoError> {
+        Ok(self
+            .records
+            .lock()
+            .unwrap()
+            .get(name)
+            .cloned()
+            .unwrap_or_default())
+    }
+}
+
+/// Persistence for organisation SSO configurations.
+#[async_trait::async_trait]
+pub trait SsoConfigStore: Send + Sync {
+    async fn get(&self, organisation: &str) -> Result<Option<OrgSsoConfig>, SsoError>;
+
+    /// Create or replace the organisation's configuration.
+    async fn upsert(&self, config: &OrgSsoConfig) -> Result<(), SsoError>;
+
+    async fn delete(&self, organisation: &str) -> Result<(), SsoError>;
+
+    /// The configuration claiming this email domain, if any.
+    async fn find_by_email_domain(&self, domain: &str)
+    -> Result<Option<OrgSsoConfig>, SsoError>;
+
+    /// Organisations that enforce SSO for their members.
+    async fn enforced_organisations(&self) -> Result<Vec<String>, SsoError>;
+}
+
+/// In-memory SSO config store for development and tests.
+pub struct InMemorySsoConfigStore {
+    configs: Mutex<HashMap<String, OrgSsoConfig>>,
+}
+
+impl InMemorySsoConfigStore {
+    pub fn new() -> Self {
+        Self {
+            configs: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl Default for InMemorySsoConfigStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait::async_trait]
+impl SsoConfigStore for InMemorySsoConfigStore {
+    async fn get(&self, organisation: &str) -> Result<Option<OrgSsoConfig>, SsoError> {
+        Ok(self.configs.lock().unwrap().get(organisation).cloned())
+    }
+
+    async fn upsert(&self, config: &OrgSsoConfig) -> Result<(), SsoError> {
+        let mut configs = self.configs.lock().unwrap();
+        let claimed = configs.values().find(|c| {
+            c.organisation != config.organisation
+                && c.allowed_domains
+                    .iter()
+                    .any(|d| config.allowed_domains.contains(d))
+        });
+        if let Some(other) = claimed {
+            return Err(SsoError::InvalidInput(format!(
+                "an email domain is already claimed by organisation {}",
+                other.organisation
+            )));
+        }
+        configs.insert(config.organisation.clone(), config.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, organisation: &str) -> Result<(), SsoError> {
+        self.configs
+            .lock()
+            .unwrap()
+            .remove(organisation)
+            .map(|_| ())
+            .ok_or_else(|| SsoError::NotFound(organisation.to_string()))
+    }
+
+    async fn find_by_email_domain(
+        &self,
+        domain: &str,
+    ) -> Result<Option<OrgSsoConfig>, SsoError> {
+        let domain = domain.to_ascii_lowercase();
+        Ok(self
+            .configs
+            .lock()
+            .unwrap()
+            .values()
+            .find(|c| c.allowed_domains.contains(&domain))
+            .cloned())
+    }
+
+    async fn enforced_organisations(&self) -> Result<Vec<String>, SsoError> {
+        let mut orgs: Vec<String> = self
+            .configs
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|c| c.enforce_sso)
+            .map(|c| c.organisation.clone())
+            .collect();
+        orgs.sort();
+        Ok(orgs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn oidc_config() -> OrgSsoConfig {
+        OrgSsoConfig {
+            organisation: "acme".into(),
+            organisation_id: "org-1".into(),
+            provider: SsoProvider::Oidc(OidcProviderConfig {
+                discovery_url: "https://idp.acme.com".into(),
+                client_id: "forage".into(),
+                client_secret: "secret".into(),
+                scopes: vec!["openid".into(), "email".into()],
+                claims: ClaimMapping::default(),
+            }),
+            enforce_sso: true,
+            jit_provisioning: true,
+            default_role: "member".into(),
+            allowed_domains: vec!["acme.com".into()],
+            updated_by: "admin".into(),
+            updated_at: String::new(),
+        }
+    }
+
+    #[test]
+    fn valid_config_passes() {
+        oidc_config().validate().unwrap();
+    }
+
+    #[test]
+    fn rejects_plain_http_and_unknown_role() {
+        let mut config = oidc_config();
+        if let SsoProvider::Oidc(oidc) = &mut config.provider {
+            oidc.discovery_url = "http://idp.acme.com".into();
+        }
+        assert!(config.validate().is_err());
+
+        // Only the exact loopback hosts get the http exemption.
+        for url in [
+            "http://localhost.attacker.com",
+            "http://127.0.0.1.nip.io",
+            "http://localhost@idp.acme.com",
+            "not a url",
+        ] {
+            let mut config = oidc_config();
+            if let SsoProvider::Oidc(oidc) = &mut config.provider {
+                oidc.discovery_url = url.into();
+            }
+            assert!(config.validate().is_err(), "{url}");
+        }
+        for url in [
+            "http://localhost:8080",
+            "http://127.0.0.1/realms/dev",
+            "http://[::1]:9000",
+        ] {
+            let mut config = oidc_config();
+            if let SsoProvider::Oidc(oidc) = &mut config.provider {
+                oidc.discovery_url = url.into();
+            }
+            config.validate().unwrap();
+        }
+
+        let mut config = oidc_config();
+        config.default_role = "owner".into();
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn requires_owned_non_public_domains() {
+        let mut config = oidc_config();
+        config.allowed_domains.clear();
+        assert!(config.validate().is_err());
+
+        config.allowed_domains = vec!["gmail.com".into()];
+        assert!(config.validate().unwrap_err().to_string().contains("public"));
+    }
+
+    #[tokio::test]
+    async fn domains_are_proven_by_the_organisations_txt_record() {
+        let config = oidc_config();
+        let dns = InMemoryTxtRecords::new();
+        assert_eq!(config.unproven_domain(&dns).await.unwrap(), Some("acme.com"));
+
+        // Another organisation's record does not count.
+        dns.publish(
+            "_forage-verification.acme.com",
+            &domain_verification_value("org-other", "acme.com"),
+        );
+        assert_eq!(config.unproven_domain(&dns).await.unwrap(), Some("acme.com"));
+
+        dns.publish(
+            &domain_verification_name("acme.com"),
+            &domain_verification_value(&config.organisation_id, "acme.com"),
+        );
+        assert_eq!(config.unproven_domain(&dns).await.unwrap(), None);
+    }
+
+    #[test]
+    fn discovery_url_accepts_issuer_or_document() {
+        let SsoProvider::Oidc(mut oidc) = oidc_config().provider else {
+            unreachable!()
+        };
+        assert_eq!(
+            oidc.discovery_document_url(),
+            "https://idp.acme.com/.well-known/openid-configuration"
+        );
+        oidc.discovery_url = "https://idp.acme.com/.well-known/openid-configuration".into();
+        assert_eq!(
+            oidc.discovery_document_url(),
+            "https://idp.acme.com/.well-known/openid-configuration"
+        );
+    }
+
+    #[test]
+    fn claim_mapping_supports_nested_claims() {
+        let mapping = ClaimMapping {
+            subject: "oid".into(),
+            email: "profile.mail".into(),
+            name: "profile.display".into(),
+            picture: None,
+        };
+        let identity = mapping
+            .identity_from_claims(&serde_json::json!({
+                "oid": 42,
+                "profile": {"mail": "jane@acme.com", "display": "Jane"}
+            }))
+            .unwrap();
+        assert_eq!(identity.sub, "42");
+        assert_eq!(identity.email, "jane@acme.com");
+        assert_eq!(identity.name, "Jane");
+
+        let err = mapping
+            .identity_from_claims(&serde_json::json!({"oid": "1"}))
+            .unwrap_err();
+        assert!(err.to_string().contains("profile.mail"));
+    }
+
+    #[test]
+    fn email_domain_restriction() {
+        let config = oidc_config();
+        assert!(config.email_allowed("jane@ACME.com"));
+        assert!(!config.email_allowed("jane@evil.com"));
+        assert_eq!(config.provider_user_id("abc"), "acme:abc");
+    }
+
+    #[tokio::test]
+    async fn store_rejects_domain_claimed_by_other_org() {
+        let store = InMemorySsoConfigStore::new();
+        store.upsert(&oidc_config()).await.unwrap();
+
+        let mut other = oidc_config();
+        other.organisation = "other".into();
+        assert!(store.upsert(&other).await.is_err());
+
+        let found = store.find_by_email_domain("ACME.com").await.unwrap().unwrap();
+        assert_eq!(found.organisation, "acme");
+        assert_eq!(store.enforced_organisations().await.unwrap(), vec!["acme"]);
+    }
+}
@@ -5,7 +5,7 @@ use tokio::sync::Mutex;
 use uuid::Uuid;
 
 use super::{
-    ComputeError, ComputeInstance, ComputeResourceSpec, ComputeScheduler, ResourceKind, Rollout,
+    ComputeError, ComputeInstance, ComputeResourceSpec, ComputeScheduler, ResourceSpec, Rollout,
     RolloutEvent, RolloutResource, RolloutStatus,
 };
 
@@ -56,7 +56,7 @@ impl ComputeScheduler for InMemoryComputeScheduler {
             .iter()
             .map(|r| RolloutResource {
                 name: r.name.clone(),
-                kind: r.kind,
+                kind: r.kind(),
                 status: RolloutStatus::Pending,
                 message: "queued".into(),
             })
@@ -72,28 +72,53 @@ impl ComputeScheduler for InMemoryComputeScheduler {
             created_at: now,
         };
 
-        // Create instances for container-service resources
+        // Create instances for workload resources (services and routes only
+        // configure networking, so they have nothing to run).
         let region = labels.get("region").cloned().unwrap_or("eu-west-1".into());
         let project = labels.get("project").cloned().unwrap_or_default();
         let destination = labels.get("destination").cloned().unwrap_or_default();
         let environment = labels.get("environment").cloned().unwrap_or_default();
         let new_instances: Vec<ComputeInstance> = resources
             .iter()
-            .filter(|r| r.kind == ResourceKind::ContainerService)
-            .map(|r| ComputeInstance {
-                id: Uuid::new_v4().to_string(),
-                namespace: namespace.to_string(),
-                resource_name: r.name.clone(),
-                project: project.clone(),
-                destination: destination.clone(),
-                environment: environment.clone(),
-                region: region.clone(),
-                image: r.image.clone().unwrap_or_else(|| "unknown".into()),
-                replicas: r.replicas,
-                cpu: r.cpu.clone().unwrap_or_else(|| "250m".into()),
-                memory: r.memory.clone().unwrap_or_else(|| "256Mi".into()),
-                status: "pending".into(),
-                created_at: now,
+            .filter_map(|r| {
+                let container = r.container()?;
+                let (replicas, status) = match &r.spec {
+                    ResourceSpec::ContainerService(cs) => {
+                        let min = cs.scaling.autoscaling.as_ref().map(|a| a.min_replicas);
+                        (cs.scaling.replicas.max(min.unwrap_or(0)), "pending")
+                    }
+                    ResourceSpec::Job(j) => (j.job_config.parallelism.unwrap_or(1), "pending"),
+                    ResourceSpec::CronJob(cj) if cj.suspend => (0, "suspended"),
+                    ResourceSpec::CronJob(_) => (0, "scheduled"),
+                    ResourceSpec::Service(_) | ResourceSpec::Route(_) => return None,
+                };
+                let requests = &container.resources.requests;
+                let limits = &container.resources.limits;
+                Some(ComputeInstance {
+                    id: Uuid::new_v4().to_string(),
+                    namespace: namespace.to_string(),
+                    resource_name: r.name.clone(),
+                    kind: r.kind(),
+                    project: project.clone(),
+                    destination: destination.clone(),
+                    environment: environment.clone(),
+                    region: region.clone(),
+                    image: container.image.clone(),
+                    replicas,
+                    cpu: requests
+                        .cpu
+                        .clone()
+                        .or_else(|| limits.cpu.clone())
+                        .unwrap_or_else(|| "250m".into()),
+                    memory: requests
+                        .memory
+                        .clone()
+                        .or_else(|| limits.memory.clone())
+                        .unwrap_or_else(|| "256Mi".into()),
+                    status: status.into(),
+                    spec: r.clone(),
+                    created_at: now,
+                })
             })
             .collect();
 
@@ -123,7 +148,7 @@ impl ComputeScheduler for InMemoryComputeScheduler {
         let ns = namespace.to_string();
         let resource_names: Vec<(String, String)> = resources
             .iter()
-            .map(|r| (r.name.clone(), r.kind.to_string()))
+            .map(|r| (r.name.clone(), r.kind().to_string()))
             .collect();
 
         tokio::spawn(async move {
@@ -307,19 +332,55 @@ impl ComputeScheduler for InMemoryComputeScheduler {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::compute::ResourceKind;
+    use crate::compute::spec::{
+        Container, ContainerServiceSpec, CronJobSpec, ResourceList, ResourceRequirements,
+        ScalingPolicy,
+    };
+
+    fn container_service(
+        name: &str,
+        image: &str,
+        replicas: u32,
+        cpu: Option<&str>,
+        memory: Option<&str>,
+    ) -> ComputeResourceSpec {
+        ComputeResourceSpec {
+            name: name.into(),
+            spec: ResourceSpec::ContainerService(ContainerServiceSpec {
+                scaling: ScalingPolicy {
+                    replicas,
+                    autoscaling: None,
+                },
+                container: Container {
+                    name: name.into(),
+                    image: image.into(),
+                    resources: ResourceRequirements {
+                        requests: ResourceList {
+                            cpu: cpu.map(Into::into),
+                            memory: memory.map(Into::into),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                ..Default::default()
+            }),
+        }
+    }
 
     #[tokio::test(flavor = "multi_thread")]
     async fn apply_creates_rollout_and_instances() {
         let scheduler = InMemoryComputeScheduler::new();
 
-        let resources = vec![ComputeResourceSpec {
-            name: "my-api".into(),
-            kind: ResourceKind::ContainerService,
-            image: Some("registry.forage.sh/org/app:v1".into()),
-            replicas: 2,
-            cpu: Some("500m".into()),
-            memory: Some("512Mi".into()),
-        }];
+        let resources = vec![container_service(
+            "my-api",
+            "registry.forage.sh/org/app:v1",
+            2,
+            Some("500m"),
+            Some("512Mi"),
+        )];
 
         let mut labels = HashMap::new();
         labels.insert("region".into(), "eu-west-1".into());
@@ -343,18 +404,57 @@ mod tests {
         assert_eq!(instances[0].replicas, 2);
     }
 
+    #[tokio::test]
+    async fn instances_reflect_requested_spec() {
+        let scheduler = InMemoryComputeScheduler::new();
+
+        let cron = ComputeResourceSpec {
+            name: "nightly".into(),
+            spec: ResourceSpec::CronJob(CronJobSpec {
+                schedule: "0 3 * * *".into(),
+                container: Container {
+                    image: "img:cron".into(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }),
+        };
+        let api = container_service("api", "img:v1", 3, Some("100m"), None);
+
+        scheduler
+            .apply_resources(
+                "spec-1",
+                "ns",
+                vec![api.clone(), cron.clone()],
+                HashMap::new(),
+            )
+            .await
+            .unwrap();
+
+        let instances = scheduler.list_instances("ns").await.unwrap();
+        assert_eq!(instances.len(), 2);
+
+        let api_inst = instances.iter().find(|i| i.resource_name == "api").unwrap();
+        assert_eq!(api_inst.kind, ResourceKind::ContainerService);
+        assert_eq!(api_inst.replicas, 3);
+        assert_eq!(api_inst.cpu, "100m");
+        assert_eq!(api_inst.memory, "256Mi");
+        assert_eq!(api_inst.spec, api);
+
+        let cron_inst = instances
+            .iter()
+            .find(|i| i.resource_name == "nightly")
+            .unwrap();
+        assert_eq!(cron_inst.kind, ResourceKind::CronJob);
+        assert_eq!(cron_inst.status, "scheduled");
+        assert_eq!(cron_inst.spec, cron);
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn rollout_completes_successfully() {
         let scheduler = InMemoryComputeScheduler::new();
 
-        let resources = vec![ComputeResourceSpec {
-            name: "svc".into(),
-            kind: ResourceKind::ContainerService,
-            image: Some("img:latest".into()),
-            replicas: 1,
-            cpu: None,
-            memory: None,
-        }];
+        let resources = vec![container_service("svc", "img:latest", 1, None, None)];
 
         let rollout_id = scheduler
             .apply_resources("test-2", "ns", resources, HashMap::new())
@@ -373,14 +473,7 @@ mod tests {
     async fn watch_rollout_streams_events() {
         let scheduler = InMemoryComputeScheduler::new();
 
-        let resources = vec![ComputeResourceSpec {
-            name: "app".into(),
-            kind: ResourceKind::ContainerService,
-            image: Some("img:v1".into()),
-            replicas: 1,
-            cpu: None,
-            memory: None,
-        }];
+        let resources = vec![container_service("app", "img:v1", 1, None, None)];
 
         let rollout_id = scheduler
             .apply_resources("test-3", "ns", resources, HashMap::new())
@@ -407,14 +500,7 @@ mod tests {
     async fn delete_removes_resources() {
         let scheduler = InMemoryComputeScheduler::new();
 
-        let resources = vec![ComputeResourceSpec {
-            name: "app".into(),
-            kind: ResourceKind::ContainerService,
-            image: Some("img:v1".into()),
-            replicas: 1,
-            cpu: None,
-            memory: None,
-        }];
+        let resources = vec![container_service("app", "img:v1", 1, None, None)];
 
         let mut labels = HashMap::new();
         labels.insert("project".into(), "test".into());
@@ -41,15 +41,8 @@ pub fn available_regions() -> Vec<&'static Region> {
 // Domain types
 // ---------------------------------------------------------------------------
 
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-pub struct ComputeResourceSpec {
-    pub name: String,
-    pub kind: ResourceKind,
-    pub image: Option<String>,
-    pub replicas: u32,
-    pub cpu: Option<String>,
-    pub memory: Option<String>,
-}
+pub mod spec;
+pub use spec::{ComputeResourceSpec, ResourceSpec, validate_resources};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -127,6 +120,7 @@ pub struct ComputeInstance {
     pub id: String,
     pub namespace: String,
     pub resource_name: String,
+    pub kind: ResourceKind,
     pub project: String,
     pub destination: String,
     pub environment: String,
@@ -136,6 +130,8 @@ pub struct ComputeInstance {
     pub cpu: String,
     pub memory: String,
     pub status: String,
+    /// The resource exactly as it was requested.
+    pub spec: ComputeResourceSpec,
     pub created_at: chrono::DateTime<chrono::Utc>,
 }
 
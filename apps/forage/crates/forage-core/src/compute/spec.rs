@@ -0,0 +1,1574 @@
+//! Typed model of every resource kind accepted by `ApplyResources`.
+//!
+//! Mirrors `forage.proto` one-to-one, but with optional fields as `Option`,
+//! enumerated strings as enums and oneofs as Rust enums.  Conversion from the
+//! wire types lives in the gRPC adapter; everything here is transport-free so
+//! schedulers can work against it directly.
+
+use std::collections::{BTreeMap, HashSet};
+use std::time::Duration;
+
+use super::{ComputeError, ResourceKind};
+
+/// Declares a string-backed enum with its canonical (Kubernetes) spelling,
+/// a default variant and a `FromStr` that rejects unknown values.
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        $name:ident, $field:literal {
+            $(#[default] $default:ident => $default_str:literal,)?
+            $($variant:ident => $str:literal,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(
+            Debug, Clone, Copy, PartialEq, Eq, Hash, Default, serde::Serialize, serde::Deserialize,
+        )]
+        pub enum $name {
+            $(#[default] $default,)?
+            $($variant,)*
+        }
+
+        impl $name {
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $($name::$default => $default_str,)?
+                    $($name::$variant => $str,)*
+                }
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = ComputeError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $($default_str => Ok($name::$default),)?
+                    $($str => Ok($name::$variant),)*
+                    other => Err(ComputeError::InvalidRequest(format!(
+                        "unknown {}: {other:?}",
+                        $field
+                    ))),
+                }
+            }
+        }
+    };
+}
+
+string_enum! {
+    Protocol, "protocol" {
+        #[default] Tcp => "TCP",
+        Udp => "UDP",
+        Sctp => "SCTP",
+    }
+}
+
+string_enum! {
+    ImagePullPolicy, "image pull policy" {
+        #[default] IfNotPresent => "IfNotPresent",
+        Always => "Always",
+        Never => "Never",
+    }
+}
+
+string_enum! {
+    RestartPolicy, "restart policy" {
+        #[default] Always => "Always",
+        OnFailure => "OnFailure",
+        Never => "Never",
+    }
+}
+
+string_enum! {
+    ServiceType, "service type" {
+        #[default] ClusterIp => "ClusterIP",
+        NodePort => "NodePort",
+        LoadBalancer => "LoadBalancer",
+        Headless => "Headless",
+    }
+}
+
+string_enum! {
+    SessionAffinity, "session affinity" {
+        #[default] None => "None",
+        ClientIp => "ClientIP",
+    }
+}
+
+string_enum! {
+    PathMatchType, "path match type" {
+        #[default] PathPrefix => "PathPrefix",
+        Exact => "Exact",
+        RegularExpression => "RegularExpression",
+    }
+}
+
+string_enum! {
+    ValueMatchType, "match type" {
+        #[default] Exact => "Exact",
+        RegularExpression => "RegularExpression",
+    }
+}
+
+string_enum! {
+    TlsMode, "tls mode" {
+        #[default] Terminate => "Terminate",
+        Passthrough => "Passthrough",
+    }
+}
+
+string_enum! {
+    ConcurrencyPolicy, "concurrency policy" {
+        #[default] Allow => "Allow",
+        Forbid => "Forbid",
+        Replace => "Replace",
+    }
+}
+
+string_enum! {
+    CompletionMode, "completion mode" {
+        #[default] NonIndexed => "NonIndexed",
+        Indexed => "Indexed",
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Envelope
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ComputeResourceSpec {
+    pub name: String,
+    pub spec: ResourceSpec,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ResourceSpec {
+    ContainerService(ContainerServiceSpec),
+    Service(ServiceSpec),
+    Route(RouteSpec),
+    CronJob(CronJobSpec),
+    Job(JobSpec),
+}
+
+impl ComputeResourceSpec {
+    pub fn kind(&self) -> ResourceKind {
+        match &self.spec {
+            ResourceSpec::ContainerService(_) => ResourceKind::ContainerService,
+            ResourceSpec::Service(_) => ResourceKind::Service,
+            ResourceSpec::Route(_) => ResourceKind::Route,
+            ResourceSpec::CronJob(_) => ResourceKind::CronJob,
+            ResourceSpec::Job(_) => ResourceKind::Job,
+        }
+    }
+
+    /// The main container of workload kinds; `None` for services and routes.
+    pub fn container(&self) -> Option<&Container> {
+        match &self.spec {
+            ResourceSpec::ContainerService(cs) => Some(&cs.container),
+            ResourceSpec::CronJob(cj) => Some(&cj.container),
+            ResourceSpec::Job(j) => Some(&j.container),
+            ResourceSpec::Service(_) | ResourceSpec::Route(_) => None,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), ComputeError> {
+        let invalid = |msg: String| ComputeError::InvalidRequest(format!("{}: {msg}", self.name));
+
+        if self.name.is_empty() {
+            return Err(ComputeError::InvalidRequest(
+                "resource name is required".into(),
+            ));
+        }
+        if self.name.len() > 253
+            || !self
+                .name
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.'))
+        {
+            return Err(invalid(
+                "name must be at most 253 alphanumerics, '-', '_' or '.'".into(),
+            ));
+        }
+
+        match &self.spec {
+            ResourceSpec::ContainerService(cs) => cs.validate().map_err(invalid),
+            ResourceSpec::Service(s) => s.validate().map_err(invalid),
+            ResourceSpec::Route(r) => r.validate().map_err(invalid),
+            ResourceSpec::CronJob(cj) => cj.validate().map_err(invalid),
+            ResourceSpec::Job(j) => j.validate().map_err(invalid),
+        }
+    }
+}
+
+/// Validate a whole apply batch: every resource on its own, plus unique names.
+pub fn validate_resources(resources: &[ComputeResourceSpec]) -> Result<(), ComputeError> {
+    let mut names = HashSet::new();
+    for resource in resources {
+        resource.validate()?;
+        if !names.insert(resource.name.as_str()) {
+            return Err(ComputeError::InvalidRequest(format!(
+                "{}: duplicate resource name",
+                resource.name
+            )));
+        }
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// ContainerService
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct ContainerServiceSpec {
+    pub scaling: ScalingPolicy,
+    pub container: Container,
+    pub sidecars: Vec<Container>,
+    pub init_containers: Vec<Container>,
+    pub volumes: Vec<Volume>,
+    pub update_strategy: UpdateStrategy,
+    pub pod_config: PodConfig,
+}
+
+impl ContainerServiceSpec {
+    fn validate(&self) -> Result<(), String> {
+        self.scaling.validate()?;
+        validate_pod(
+            std::iter::once(&self.container)
+                .chain(&self.sidecars)
+                .chain(&self.init_containers),
+            &self.volumes,
+        )?;
+        self.update_strategy.validate()?;
+        self.pod_config.validate()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct ScalingPolicy {
+    pub replicas: u32,
+    pub autoscaling: Option<AutoscalingPolicy>,
+}
+
+impl ScalingPolicy {
+    fn validate(&self) -> Result<(), String> {
+        let Some(hpa) = &self.autoscaling else {
+            return Ok(());
+        };
+        if hpa.max_replicas == 0 {
+            return Err("autoscaling max_replicas must be at least 1".into());
+        }
+        if hpa.min_replicas > hpa.max_replicas {
+            return Err(format!(
+                "autoscaling min_replicas ({}) exceeds max_replicas ({})",
+                hpa.min_replicas, hpa.max_replicas
+            ));
+        }
+        for (label, target) in [
+            ("cpu", hpa.target_cpu_utilization_percent),
+            ("memory", hpa.target_memory_utilization_percent),
+        ] {
+            if let Some(pct) = target
+                && !(1..=100).contains(&pct)
+            {
+                return Err(format!(
+                    "autoscaling {label} target must be between 1 and 100, got {pct}"
+                ));
+            }
+        }
+        for metric in &hpa.custom_metrics {
+            if metric.name.is_empty() {
+                return Err("autoscaling custom metric name is required".into());
+            }
+            if !matches!(
+                metric.target_type.as_str(),
+                "Value" | "AverageValue" | "Utilization"
+            ) {
+                return Err(format!(
+                    "custom metric {}: unknown target type {:?}",
+                    metric.name, metric.target_type
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct AutoscalingPolicy {
+    pub min_replicas: u32,
+    pub max_replicas: u32,
+    pub target_cpu_utilization_percent: Option<u32>,
+    pub target_memory_utilization_percent: Option<u32>,
+    pub custom_metrics: Vec<CustomMetric>,
+    pub scale_down_stabilization: Option<Duration>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct CustomMetric {
+    pub name: String,
+    pub target_type: String,
+    pub target_value: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum UpdateStrategy {
+    RollingUpdate {
+        max_unavailable: Option<String>,
+        max_surge: Option<String>,
+    },
+    Recreate,
+    /// Nothing requested; the scheduler's rolling-update defaults apply.
+    #[default]
+    Default,
+}
+
+impl UpdateStrategy {
+    fn validate(&self) -> Result<(), String> {
+        if let UpdateStrategy::RollingUpdate {
+            max_unavailable,
+            max_surge,
+        } = self
+        {
+            for value in [max_unavailable, max_surge].into_iter().flatten() {
+                if !is_int_or_percent(value) {
+                    return Err(format!(
+                        "rolling update value must be a number or percentage, got {value:?}"
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Container
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct Container {
+    pub name: String,
+    pub image: String,
+    pub command: Vec<String>,
+    pub args: Vec<String>,
+    pub working_dir: Option<String>,
+    pub env: Vec<EnvVar>,
+    pub ports: Vec<ContainerPort>,
+    pub resources: ResourceRequirements,
+    pub volume_mounts: Vec<VolumeMount>,
+    pub liveness_probe: Option<Probe>,
+    pub readiness_probe: Option<Probe>,
+    pub startup_probe: Option<Probe>,
+    pub lifecycle: Lifecycle,
+    pub security_context: Option<ContainerSecurityContext>,
+    pub image_pull_policy: ImagePullPolicy,
+    pub stdin: bool,
+    pub tty: bool,
+}
+
+impl Container {
+    fn validate(&self, volumes: &HashSet<&str>) -> Result<(), String> {
+        let at = |msg: String| format!("container {}: {msg}", self.name);
+
+        if self.image.is_empty() {
+            return Err(at("image is required".into()));
+        }
+        if let Some(dir) = &self.working_dir
+            && !dir.starts_with('/')
+        {
+            return Err(at(format!("working_dir must be absolute, got {dir:?}")));
+        }
+
+        let mut env_names = HashSet::new();
+        for var in &self.env {
+            if var.name.is_empty() {
+                return Err(at("env var name is required".into()));
+            }
+            if !env_names.insert(var.name.as_str()) {
+                return Err(at(format!("duplicate env var {}", var.name)));
+            }
+            var.source
+                .validate()
+                .map_err(|e| at(format!("env {}: {e}", var.name)))?;
+        }
+
+        let mut port_names = HashSet::new();
+        let mut port_numbers = HashSet::new();
+        for port in &self.ports {
+            validate_port(port.container_port).map_err(&at)?;
+            if !port.name.is_empty() && !port_names.insert(port.name.as_str()) {
+                return Err(at(format!("duplicate port name {}", port.name)));
+            }
+            if !port_numbers.insert((port.container_port, port.protocol)) {
+                return Err(at(format!(
+                    "duplicate port {}/{}",
+                    port.container_port, port.protocol
+                )));
+            }
+        }
+
+        self.resources.validate().map_err(&at)?;
+
+        for mount in &self.volume_mounts {
+            if !volumes.contains(mount.name.as_str()) {
+                return Err(at(format!(
+                    "volume mount references unknown volume {:?}",
+                    mount.name
+                )));
+            }
+            if !mount.mount_path.starts_with('/') {
+                return Err(at(format!(
+                    "mount path must be absolute, got {:?}",
+                    mount.mount_path
+                )));
+            }
+        }
+
+        for (label, probe) in [
+            ("liveness", &self.liveness_probe),
+            ("readiness", &self.readiness_probe),
+            ("startup", &self.startup_probe),
+        ] {
+            if let Some(probe) = probe {
+                probe
+                    .validate()
+                    .map_err(|e| at(format!("{label} probe: {e}")))?;
+            }
+        }
+        for (label, hook) in [
+            ("post_start", &self.lifecycle.post_start),
+            ("pre_stop", &self.lifecycle.pre_stop),
+        ] {
+            if let Some(hook) = hook {
+                hook.validate()
+                    .map_err(|e| at(format!("{label} hook: {e}")))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct EnvVar {
+    pub name: String,
+    pub source: EnvSource,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "from", rename_all = "snake_case")]
+pub enum EnvSource {
+    Value { value: String },
+    Secret { secret_name: String, key: String },
+    Config { config_name: String, key: String },
+    Field { path: String },
+    ResourceField { resource: String },
+}
+
+impl EnvSource {
+    fn validate(&self) -> Result<(), String> {
+        match self {
+            EnvSource::Value { .. } => Ok(()),
+            EnvSource::Secret { secret_name, key } if secret_name.is_empty() || key.is_empty() => {
+                Err("secret ref requires secret_name and key".into())
+            }
+            EnvSource::Config { config_name, key } if config_name.is_empty() || key.is_empty() => {
+                Err("config ref requires config_name and key".into())
+            }
+            EnvSource::Field { path } if path.is_empty() => Err("field ref is empty".into()),
+            EnvSource::ResourceField { resource } if resource.is_empty() => {
+                Err("resource field ref is empty".into())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ContainerPort {
+    pub name: String,
+    pub container_port: u32,
+    pub protocol: Protocol,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct ResourceRequirements {
+    pub requests: ResourceList,
+    pub limits: ResourceList,
+}
+
+impl ResourceRequirements {
+    fn validate(&self) -> Result<(), String> {
+        for (label, list) in [("requests", &self.requests), ("limits", &self.limits)] {
+            for (name, quantity) in [
+                ("cpu", &list.cpu),
+                ("memory", &list.memory),
+                ("ephemeral_storage", &list.ephemeral_storage),
+            ] {
+                if let Some(q) = quantity
+                    && !is_quantity(q)
+                {
+                    return Err(format!("{label}.{name} is not a valid quantity: {q:?}"));
+                }
+            }
+            for (name, q) in &list.extended {
+                if !is_quantity(q) {
+                    return Err(format!("{label}.{name} is not a valid quantity: {q:?}"));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct ResourceList {
+    pub cpu: Option<String>,
+    pub memory: Option<String>,
+    pub ephemeral_storage: Option<String>,
+    pub extended: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct VolumeMount {
+    pub name: String,
+    pub mount_path: String,
+    pub sub_path: Option<String>,
+    pub read_only: bool,
+}
+
+// ---------------------------------------------------------------------------
+// Probes & lifecycle
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Probe {
+    pub handler: ProbeHandler,
+    pub initial_delay_seconds: u32,
+    pub period_seconds: Option<u32>,
+    pub timeout_seconds: Option<u32>,
+    pub success_threshold: Option<u32>,
+    pub failure_threshold: Option<u32>,
+}
+
+impl Probe {
+    fn validate(&self) -> Result<(), String> {
+        match &self.handler {
+            ProbeHandler::HttpGet(http) => http.validate(),
+            ProbeHandler::TcpSocket { port } => validate_port(*port),
+            ProbeHandler::Exec { command } => validate_exec(command),
+            ProbeHandler::Grpc { port, .. } => validate_port(*port),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ProbeHandler {
+    HttpGet(HttpGetAction),
+    TcpSocket { port: u32 },
+    Exec { command: Vec<String> },
+    Grpc { port: u32, service: Option<String> },
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct HttpGetAction {
+    pub path: String,
+    pub port: u32,
+    pub scheme: HttpScheme,
+    pub headers: Vec<(String, String)>,
+}
+
+impl HttpGetAction {
+    fn validate(&self) -> Result<(), String> {
+        validate_port(self.port)?;
+        if !self.path.is_empty() && !self.path.starts_with('/') {
+            return Err(format!(
+                "http path must start with '/', got {:?}",
+                self.path
+            ));
+        }
+        Ok(())
+    }
+}
+
+string_enum! {
+    HttpScheme, "http scheme" {
+        #[default] Http => "HTTP",
+        Https => "HTTPS",
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct Lifecycle {
+    pub post_start: Option<LifecycleHandler>,
+    pub pre_stop: Option<LifecycleHandler>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LifecycleHandler {
+    Exec { command: Vec<String> },
+    HttpGet(HttpGetAction),
+    TcpSocket { port: u32 },
+}
+
+impl LifecycleHandler {
+    fn validate(&self) -> Result<(), String> {
+        match self {
+            LifecycleHandler::Exec { command } => validate_exec(command),
+            LifecycleHandler::HttpGet(http) => http.validate(),
+            LifecycleHandler::TcpSocket { port } => validate_port(*port),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Security
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct ContainerSecurityContext {
+    pub run_as_non_root: bool,
+    pub run_as_user: Option<i64>,
+    pub run_as_group: Option<i64>,
+    pub read_only_root_filesystem: bool,
+    pub privileged: bool,
+    pub allow_privilege_escalation: bool,
+    pub capabilities_add: Vec<String>,
+    pub capabilities_drop: Vec<String>,
+    pub se_linux_type: Option<String>,
+    pub seccomp_profile: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct PodSecurityContext {
+    pub run_as_user: Option<i64>,
+    pub run_as_group: Option<i64>,
+    pub run_as_non_root: bool,
+    pub fs_group: Option<i64>,
+    pub supplemental_groups: Vec<i64>,
+    pub fs_group_change_policy: Option<String>,
+    pub seccomp_profile: Option<String>,
+}
+
+// ---------------------------------------------------------------------------
+// Volumes
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Volume {
+    pub name: String,
+    pub source: VolumeSource,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum VolumeSource {
+    EmptyDir {
+        medium: Option<String>,
+        size_limit: Option<String>,
+    },
+    Secret {
+        secret_name: String,
+        items: Vec<KeyToPath>,
+        default_mode: Option<u32>,
+        optional: bool,
+    },
+    ConfigMap {
+        config_map_name: String,
+        items: Vec<KeyToPath>,
+        default_mode: Option<u32>,
+        optional: bool,
+    },
+    Pvc {
+        claim_name: String,
+        read_only: bool,
+    },
+    HostPath {
+        path: String,
+        path_type: Option<String>,
+    },
+    Nfs {
+        server: String,
+        path: String,
+        read_only: bool,
+    },
+}
+
+impl VolumeSource {
+    fn validate(&self) -> Result<(), String> {
+        match self {
+            VolumeSource::EmptyDir { size_limit, .. } => match size_limit {
+                Some(q) if !is_quantity(q) => Err(format!(
+                    "empty_dir size_limit is not a valid quantity: {q:?}"
+                )),
+                _ => Ok(()),
+            },
+            VolumeSource::Secret { secret_name, .. } if secret_name.is_empty() => {
+                Err("secret volume requires secret_name".into())
+            }
+            VolumeSource::ConfigMap {
+                config_map_name, ..
+            } if config_map_name.is_empty() => {
+                Err("config map volume requires config_map_name".into())
+            }
+            VolumeSource::Pvc { claim_name, .. } if claim_name.is_empty() => {
+                Err("pvc volume requires claim_name".into())
+            }
+            VolumeSource::HostPath { path, .. } if !path.starts_with('/') => {
+                Err(format!("host path must be absolute, got {path:?}"))
+            }
+            VolumeSource::Nfs { server, path, .. } if server.is_empty() || path.is_empty() => {
+                Err("nfs volume requires server and path".into())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct KeyToPath {
+    pub key: String,
+    pub path: String,
+    pub mode: Option<u32>,
+}
+
+// ---------------------------------------------------------------------------
+// Pod configuration
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct PodConfig {
+    pub service_account_name: Option<String>,
+    /// `None` means the kind's default (Always for services, OnFailure for jobs).
+    pub restart_policy: Option<RestartPolicy>,
+    pub termination_grace_period_seconds: Option<u32>,
+    pub dns_policy: Option<String>,
+    pub dns_config: Option<PodDnsConfig>,
+    pub host_network: bool,
+    pub node_selector: BTreeMap<String, String>,
+    pub tolerations: Vec<Toleration>,
+    pub affinity: Affinity,
+    pub topology_spread_constraints: Vec<TopologySpreadConstraint>,
+    pub image_pull_secrets: Vec<String>,
+    pub security_context: Option<PodSecurityContext>,
+    pub priority_class_name: Option<String>,
+    pub runtime_class_name: Option<String>,
+    pub annotations: BTreeMap<String, String>,
+    pub labels: BTreeMap<String, String>,
+}
+
+impl PodConfig {
+    fn validate(&self) -> Result<(), String> {
+        for toleration in &self.tolerations {
+            if !matches!(toleration.operator.as_str(), "" | "Equal" | "Exists") {
+                return Err(format!(
+                    "toleration {}: unknown operator {:?}",
+                    toleration.key, toleration.operator
+                ));
+            }
+            if !matches!(
+                toleration.effect.as_str(),
+                "" | "NoSchedule" | "PreferNoSchedule" | "NoExecute"
+            ) {
+                return Err(format!(
+                    "toleration {}: unknown effect {:?}",
+                    toleration.key, toleration.effect
+                ));
+            }
+        }
+        for term in self
+            .affinity
+            .node_preferred
+            .iter()
+            .map(|p| &p.weight)
+            .chain(self.affinity.pod_preferred.iter().map(|p| &p.weight))
+            .chain(self.affinity.pod_anti_preferred.iter().map(|p| &p.weight))
+        {
+            if !(1..=100).contains(term) {
+                return Err(format!(
+                    "affinity weight must be between 1 and 100, got {term}"
+                ));
+            }
+        }
+        for constraint in &self.topology_spread_constraints {
+            if constraint.max_skew < 1 {
+                return Err(format!(
+                    "topology spread on {}: max_skew must be at least 1",
+                    constraint.topology_key
+                ));
+            }
+            if constraint.topology_key.is_empty() {
+                return Err("topology spread constraint requires topology_key".into());
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct PodDnsConfig {
+    pub nameservers: Vec<String>,
+    pub searches: Vec<String>,
+    pub options: Vec<(String, Option<String>)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct Toleration {
+    pub key: String,
+    pub operator: String,
+    pub value: String,
+    pub effect: String,
+    pub toleration_seconds: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct Affinity {
+    pub node_required: Vec<NodeSelectorTerm>,
+    pub node_preferred: Vec<PreferredSchedulingTerm>,
+    pub pod_required: Vec<PodAffinityTerm>,
+    pub pod_preferred: Vec<WeightedPodAffinityTerm>,
+    pub pod_anti_required: Vec<PodAffinityTerm>,
+    pub pod_anti_preferred: Vec<WeightedPodAffinityTerm>,
+}
+
+impl Affinity {
+    pub fn is_empty(&self) -> bool {
+        self == &Affinity::default()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct PreferredSchedulingTerm {
+    pub weight: i32,
+    pub preference: NodeSelectorTerm,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct NodeSelectorTerm {
+    pub match_expressions: Vec<SelectorRequirement>,
+    pub match_fields: Vec<SelectorRequirement>,
+}
+
+/// Shared by node selector and label selector requirements; the allowed
+/// operators differ slightly ("Gt"/"Lt" only apply to nodes).
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct SelectorRequirement {
+    pub key: String,
+    pub operator: String,
+    pub values: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct WeightedPodAffinityTerm {
+    pub weight: i32,
+    pub term: PodAffinityTerm,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct PodAffinityTerm {
+    pub label_selector: LabelSelector,
+    pub topology_key: String,
+    pub namespaces: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct LabelSelector {
+    pub match_labels: BTreeMap<String, String>,
+    pub match_expressions: Vec<SelectorRequirement>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct TopologySpreadConstraint {
+    pub max_skew: i32,
+    pub topology_key: String,
+    pub when_unsatisfiable: String,
+    pub label_selector: LabelSelector,
+}
+
+// ---------------------------------------------------------------------------
+// Service
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct ServiceSpec {
+    pub target: String,
+    pub service_type: ServiceType,
+    pub ports: Vec<ServicePort>,
+    pub session_affinity: SessionAffinity,
+    pub inline_route: Option<InlineRoute>,
+    pub annotations: BTreeMap<String, String>,
+}
+
+impl ServiceSpec {
+    fn validate(&self) -> Result<(), String> {
+        if self.target.is_empty() {
+            return Err("service target is required".into());
+        }
+        if self.ports.is_empty() && self.service_type != ServiceType::Headless {
+            return Err("service requires at least one port".into());
+        }
+        let mut numbers = HashSet::new();
+        for port in &self.ports {
+            validate_port(port.port)?;
+            if let Some(target) = port.target_port {
+                validate_port(target)?;
+            }
+            if !numbers.insert((port.port, port.protocol)) {
+                return Err(format!(
+                    "duplicate service port {}/{}",
+                    port.port, port.protocol
+                ));
+            }
+            if let Some(node_port) = port.node_port {
+                if self.service_type != ServiceType::NodePort {
+                    return Err(format!(
+                        "port {}: node_port is only valid for NodePort services",
+                        port.port
+                    ));
+                }
+                validate_port(node_port)?;
+            }
+        }
+        if let Some(route) = &self.inline_route {
+            if route.hostnames.is_empty() {
+                return Err("inline route requires at least one hostname".into());
+            }
+            validate_hostnames(&route.hostnames)?;
+            validate_rules(&route.rules)?;
+            validate_tls(route.tls.as_ref())?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ServicePort {
+    pub name: String,
+    pub port: u32,
+    /// Defaults to `port` when unset.
+    pub target_port: Option<u32>,
+    pub protocol: Protocol,
+    pub node_port: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct InlineRoute {
+    pub hostnames: Vec<String>,
+    pub rules: Vec<RouteRule>,
+    pub tls: Option<RouteTls>,
+}
+
+// ---------------------------------------------------------------------------
+// Route
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct RouteSpec {
+    pub target_service: String,
+    pub hostnames: Vec<String>,
+    pub rules: Vec<RouteRule>,
+    pub tls: Option<RouteTls>,
+    pub gateway_ref: Option<String>,
+    pub priority: i32,
+}
+
+impl RouteSpec {
+    fn validate(&self) -> Result<(), String> {
+        if self.target_service.is_empty() {
+            return Err("route target_service is required".into());
+        }
+        validate_hostnames(&self.hostnames)?;
+        validate_rules(&self.rules)?;
+        validate_tls(self.tls.as_ref())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct RouteRule {
+    pub matches: Vec<RouteMatch>,
+    pub backends: Vec<RouteBackend>,
+    pub filters: Vec<RouteFilter>,
+    pub timeout: Option<Duration>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct RouteMatch {
+    pub path: Option<PathMatch>,
+    pub headers: Vec<ValueMatch>,
+    pub query_params: Vec<ValueMatch>,
+    pub method: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct PathMatch {
+    pub match_type: PathMatchType,
+    pub value: String,
+}
+
+/// Header or query-parameter condition.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ValueMatch {
+    pub match_type: ValueMatchType,
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct RouteBackend {
+    pub service: String,
+    pub port: u32,
+    /// `None` means an even split between the rule's backends.
+    pub weight: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RouteFilter {
+    RequestHeaderModifier(HeaderModifier),
+    ResponseHeaderModifier(HeaderModifier),
+    RequestRedirect {
+        scheme: Option<String>,
+        hostname: Option<String>,
+        port: Option<u32>,
+        path: Option<String>,
+        status_code: Option<u32>,
+    },
+    UrlRewrite {
+        hostname: Option<String>,
+        path: Option<PathMatch>,
+    },
+    RequestMirror {
+        service: String,
+        port: u32,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct HeaderModifier {
+    pub set: BTreeMap<String, String>,
+    pub add: BTreeMap<String, String>,
+    pub remove: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct RouteTls {
+    pub mode: TlsMode,
+    pub certificate_ref: Option<String>,
+}
+
+fn validate_hostnames(hostnames: &[String]) -> Result<(), String> {
+    for host in hostnames {
+        let bare = host.strip_prefix("*.").unwrap_or(host);
+        if !is_dns_subdomain(&bare.to_ascii_lowercase()) {
+            return Err(format!("invalid hostname {host:?}"));
+        }
+    }
+    Ok(())
+}
+
+fn validate_rules(rules: &[RouteRule]) -> Result<(), String> {
+    for rule in rules {
+        for m in &rule.matches {
+            if let Some(path) = &m.path
+                && path.match_type != PathMatchType::RegularExpression
+                && !path.value.starts_with('/')
+            {
+                return Err(format!(
+                    "route path must start with '/', got {:?}",
+                    path.value
+                ));
+            }
+        }
+        for backend in &rule.backends {
+            if backend.service.is_empty() {
+                return Err("route backend requires a service".into());
+            }
+            validate_port(backend.port)?;
+            if let Some(weight) = backend.weight
+                && !(1..=100).contains(&weight)
+            {
+                return Err(format!(
+                    "backend {}: weight must be between 1 and 100, got {weight}",
+                    backend.service
+                ));
+            }
+        }
+        for filter in &rule.filters {
+            match filter {
+                RouteFilter::RequestRedirect {
+                    status_code: Some(code),
+                    ..
+                } if !matches!(code, 301 | 302 | 303 | 307 | 308) => {
+                    return Err(format!("unsupported redirect status code {code}"));
+                }
+                RouteFilter::RequestMirror { service, port } => {
+                    if service.is_empty() {
+                        return Err("request mirror requires a service".into());
+                    }
+                    validate_port(*port)?;
+                }
+                _ => {}
+            }
+        }
+    }
+    Ok(())
+}
+
+fn validate_tls(tls: Option<&RouteTls>) -> Result<(), String> {
+    match tls {
+        Some(RouteTls {
+            mode: TlsMode::Terminate,
+            certificate_ref: None,
+        }) => Err("tls termination requires a certificate_ref".into()),
+        _ => Ok(()),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// CronJob / Job
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct CronJobSpec {
+    pub schedule: String,
+    pub timezone: Option<String>,
+    pub container: Container,
+    pub volumes: Vec<Volume>,
+    pub job_config: JobConfig,
+    pub pod_config: PodConfig,
+    pub concurrency_policy: ConcurrencyPolicy,
+    pub successful_jobs_history_limit: Option<u32>,
+    pub failed_jobs_history_limit: Option<u32>,
+    pub suspend: bool,
+    pub starting_deadline_seconds: Option<i64>,
+}
+
+impl CronJobSpec {
+    fn validate(&self) -> Result<(), String> {
+        if !is_cron_schedule(&self.schedule) {
+            return Err(format!("invalid cron schedule {:?}", self.schedule));
+        }
+        if let Some(deadline) = self.starting_deadline_seconds
+            && deadline < 0
+        {
+            return Err("starting_deadline_seconds must not be negative".into());
+        }
+        validate_pod(std::iter::once(&self.container), &self.volumes)?;
+        self.job_config.validate()?;
+        validate_job_pod(&self.pod_config)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct JobSpec {
+    pub container: Container,
+    pub volumes: Vec<Volume>,
+    pub job_config: JobConfig,
+    pub pod_config: PodConfig,
+}
+
+impl JobSpec {
+    fn validate(&self) -> Result<(), String> {
+        validate_pod(std::iter::once(&self.container), &self.volumes)?;
+        self.job_config.validate()?;
+        validate_job_pod(&self.pod_config)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct JobConfig {
+    pub completions: Option<u32>,
+    pub parallelism: Option<u32>,
+    pub completion_mode: CompletionMode,
+    pub backoff_limit: Option<u32>,
+    pub active_deadline_seconds: Option<i64>,
+    pub ttl_seconds_after_finished: Option<i64>,
+    /// `None` means OnFailure.
+    pub restart_policy: Option<RestartPolicy>,
+}
+
+impl JobConfig {
+    fn validate(&self) -> Result<(), String> {
+        if self.restart_policy == Some(RestartPolicy::Always) {
+            return Err("job restart policy must be OnFailure or Never".into());
+        }
+        if self.completion_mode == CompletionMode::Indexed && self.completions.is_none() {
+            return Err("indexed jobs require completions".into());
+        }
+        for (label, value) in [
+            ("active_deadline_seconds", self.active_deadline_seconds),
+            (
+                "ttl_seconds_after_finished",
+                self.ttl_seconds_after_finished,
+            ),
+        ] {
+            if let Some(v) = value
+                && v < 0
+            {
+                return Err(format!("{label} must not be negative"));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn validate_job_pod(pod: &PodConfig) -> Result<(), String> {
+    if pod.restart_policy == Some(RestartPolicy::Always) {
+        return Err("job pods cannot use restart policy Always".into());
+    }
+    pod.validate()
+}
+
+// ---------------------------------------------------------------------------
+// Shared helpers
+// ---------------------------------------------------------------------------
+
+fn validate_pod<'a>(
+    containers: impl Iterator<Item = &'a Container>,
+    volumes: &[Volume],
+) -> Result<(), String> {
+    let mut volume_names = HashSet::new();
+    for volume in volumes {
+        if volume.name.is_empty() {
+            return Err("volume name is required".into());
+        }
+        if !volume_names.insert(volume.name.as_str()) {
+            return Err(format!("duplicate volume {}", volume.name));
+        }
+        volume
+            .source
+            .validate()
+            .map_err(|e| format!("volume {}: {e}", volume.name))?;
+    }
+
+    let mut container_names = HashSet::new();
+    for container in containers {
+        if !container.name.is_empty() && !container_names.insert(container.name.as_str()) {
+            return Err(format!("duplicate container name {}", container.name));
+        }
+        container.validate(&volume_names)?;
+    }
+    Ok(())
+}
+
+fn validate_port(port: u32) -> Result<(), String> {
+    if (1..=65535).contains(&port) {
+        Ok(())
+    } else {
+        Err(format!("port must be between 1 and 65535, got {port}"))
+    }
+}
+
+fn validate_exec(command: &[String]) -> Result<(), String> {
+    if command.is_empty() {
+        Err("exec command is empty".into())
+    } else {
+        Ok(())
+    }
+}
+
+fn is_dns_subdomain(s: &str) -> bool {
+    !s.is_empty()
+        && s.len() <= 253
+        && s.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && label
+                    .bytes()
+                    .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+        })
+}
+
+/// Kubernetes quantity: a decimal number with an optional SI or binary suffix
+/// ("100m", "0.5", "128Mi", "1G").
+fn is_quantity(s: &str) -> bool {
+    const SUFFIXES: &[&str] = &[
+        "Ki", "Mi", "Gi", "Ti", "Pi", "Ei", "n", "u", "m", "k", "M", "G", "T", "P", "E",
+    ];
+    let number = SUFFIXES
+        .iter()
+        .find_map(|suffix| s.strip_suffix(suffix))
+        .unwrap_or(s);
+    let mut parts = number.splitn(2, '.');
+    let whole = parts.next().unwrap_or_default();
+    let frac = parts.next();
+    let digits = |p: &str| p.bytes().all(|b| b.is_ascii_digit());
+    match frac {
+        Some(frac) => digits(whole) && digits(frac) && !(whole.is_empty() && frac.is_empty()),
+        None => !whole.is_empty() && digits(whole),
+    }
+}
+
+fn is_int_or_percent(s: &str) -> bool {
+    let digits = s.strip_suffix('%').unwrap_or(s);
+    !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Five-field cron expressions plus the `@hourly`-style macros.
+fn is_cron_schedule(s: &str) -> bool {
+    if let Some(shortcut) = s.strip_prefix('@') {
+        return matches!(
+            shortcut,
+            "yearly" | "annually" | "monthly" | "weekly" | "daily" | "midnight" | "hourly"
+        );
+    }
+    let fields: Vec<&str> = s.split_whitespace().collect();
+    fields.len() == 5
+        && fields.iter().all(|f| {
+            f.bytes()
+                .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'*' | b'/' | b',' | b'-' | b'?'))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn container(image: &str) -> Container {
+        Container {
+            name: "app".into(),
+            image: image.into(),
+            ..Default::default()
+        }
+    }
+
+    fn service(spec: ContainerServiceSpec) -> ComputeResourceSpec {
+        ComputeResourceSpec {
+            name: "my-api".into(),
+            spec: ResourceSpec::ContainerService(spec),
+        }
+    }
+
+    fn invalid_message(result: Result<(), ComputeError>) -> String {
+        match result {
+            Err(ComputeError::InvalidRequest(msg)) => msg,
+            other => panic!("expected InvalidRequest, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn valid_container_service_passes() {
+        let spec = service(ContainerServiceSpec {
+            scaling: ScalingPolicy {
+                replicas: 2,
+                autoscaling: Some(AutoscalingPolicy {
+                    min_replicas: 2,
+                    max_replicas: 5,
+                    target_cpu_utilization_percent: Some(70),
+                    ..Default::default()
+                }),
+            },
+            container: Container {
+                env: vec![EnvVar {
+                    name: "DATABASE_URL".into(),
+                    source: EnvSource::Secret {
+                        secret_name: "db".into(),
+                        key: "url".into(),
+                    },
+                }],
+                ports: vec![ContainerPort {
+                    name: "http".into(),
+                    container_port: 8080,
+                    protocol: Protocol::Tcp,
+                }],
+                resources: ResourceRequirements {
+                    requests: ResourceList {
+                        cpu: Some("250m".into()),
+                        memory: Some("256Mi".into()),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                volume_mounts: vec![VolumeMount {
+                    name: "cache".into(),
+                    mount_path: "/cache".into(),
+                    sub_path: None,
+                    read_only: false,
+                }],
+                readiness_probe: Some(Probe {
+                    handler: ProbeHandler::HttpGet(HttpGetAction {
+                        path: "/healthz".into(),
+                        port: 8080,
+                        scheme: HttpScheme::Http,
+                        headers: vec![],
+                    }),
+                    initial_delay_seconds: 5,
+                    period_seconds: None,
+                    timeout_seconds: None,
+                    success_threshold: None,
+                    failure_threshold: None,
+                }),
+                ..container("img:v1")
+            },
+            volumes: vec![Volume {
+                name: "cache".into(),
+                source: VolumeSource::EmptyDir {
+                    medium: None,
+                    size_limit: Some("1Gi".into()),
+                },
+            }],
+            ..Default::default()
+        });
+
+        validate_resources(&[spec]).unwrap();
+    }
+
+    #[test]
+    fn missing_image_is_rejected() {
+        let spec = service(ContainerServiceSpec {
+            container: container(""),
+            ..Default::default()
+        });
+        let msg = invalid_message(spec.validate());
+        assert!(msg.contains("image is required"), "{msg}");
+        assert!(msg.starts_with("my-api:"), "{msg}");
+    }
+
+    #[test]
+    fn mount_of_undeclared_volume_is_rejected() {
+        let spec = service(ContainerServiceSpec {
+            container: Container {
+                volume_mounts: vec![VolumeMount {
+                    name: "data".into(),
+                    mount_path: "/data".into(),
+                    sub_path: None,
+                    read_only: true,
+                }],
+                ..container("img:v1")
+            },
+            ..Default::default()
+        });
+        let msg = invalid_message(spec.validate());
+        assert!(msg.contains("unknown volume"), "{msg}");
+    }
+
+    #[test]
+    fn autoscaling_bounds_are_checked() {
+        let spec = service(ContainerServiceSpec {
+            scaling: ScalingPolicy {
+                replicas: 1,
+                autoscaling: Some(AutoscalingPolicy {
+                    min_replicas: 4,
+                    max_replicas: 2,
+                    ..Default::default()
+                }),
+            },
+            container: container("img:v1"),
+            ..Default::default()
+        });
+        let msg = invalid_message(spec.validate());
+        assert!(msg.contains("exceeds max_replicas"), "{msg}");
+    }
+
+    #[test]
+    fn bad_quantity_is_rejected() {
+        let spec = service(ContainerServiceSpec {
+            container: Container {
+                resources: ResourceRequirements {
+                    limits: ResourceList {
+                        memory: Some("lots".into()),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                ..container("img:v1")
+            },
+            ..Default::default()
+        });
+        let msg = invalid_message(spec.validate());
+        assert!(msg.contains("limits.memory"), "{msg}");
+    }
+
+    #[test]
+    fn cron_schedule_and_restart_policy_are_checked() {
+        let mut cron = CronJobSpec {
+            schedule: "*/5 * * * *".into(),
+            container: container("img:v1"),
+            ..Default::default()
+        };
+        let resource = |cron: &CronJobSpec| ComputeResourceSpec {
+            name: "nightly".into(),
+            spec: ResourceSpec::CronJob(cron.clone()),
+        };
+        resource(&cron).validate().unwrap();
+
+        cron.schedule = "every five minutes".into();
+        assert!(invalid_message(resource(&cron).validate()).contains("cron schedule"));
+
+        cron.schedule = "@daily".into();
+        cron.job_config.restart_policy = Some(RestartPolicy::Always);
+        assert!(invalid_message(resource(&cron).validate()).contains("restart policy"));
+    }
+
+    #[test]
+    fn service_and_route_rules_are_checked() {
+        let svc = ComputeResourceSpec {
+            name: "my-api-svc".into(),
+            spec: ResourceSpec::Service(ServiceSpec {
+                target: "my-api".into(),
+                ports: vec![ServicePort {
+                    name: "http".into(),
+                    port: 80,
+                    target_port: Some(8080),
+                    protocol: Protocol::Tcp,
+                    node_port: Some(30080),
+                }],
+                ..Default::default()
+            }),
+        };
+        assert!(invalid_message(svc.validate()).contains("NodePort"));
+
+        let route = ComputeResourceSpec {
+            name: "my-api-route".into(),
+            spec: ResourceSpec::Route(RouteSpec {
+                target_service: "my-api-svc".into(),
+                hostnames: vec!["api.example.com".into()],
+                rules: vec![RouteRule {
+                    backends: vec![RouteBackend {
+                        service: "my-api-svc".into(),
+                        port: 80,
+                        weight: Some(150),
+                    }],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }),
+        };
+        assert!(invalid_message(route.validate()).contains("weight"));
+    }
+
+    #[test]
+    fn duplicate_resource_names_are_rejected() {
+        let spec = service(ContainerServiceSpec {
+            container: container("img:v1"),
+            ..Default::default()
+        });
+        let msg = invalid_message(validate_resources(&[spec.clone(), spec]));
+        assert!(msg.contains("duplicate resource name"), "{msg}");
+    }
+
+    #[test]
+    fn string_enums_parse_canonical_spellings() {
+        assert_eq!("UDP".parse::<Protocol>().unwrap(), Protocol::Udp);
+        assert_eq!(
+            "ClusterIP".parse::<ServiceType>().unwrap(),
+            ServiceType::ClusterIp
+        );
+        assert!("udp".parse::<Protocol>().is_err());
+        assert_eq!(Protocol::default().to_string(), "TCP");
+    }
+}
@@ -0,0 +1,65 @@
+use super::router::{build_release_url, NotificationEvent};
+
+/// Build a Discord webhook message with a single embed for the event.
+pub fn format_discord_message(event: &NotificationEvent, forage_url: &str) -> serde_json::Value {
+    // Discord embed colours are 24-bit integers.
+    let color: u32 = match event.notification_type.as_str() {
+        "release_succeeded" => 0x36a64f,
+        "release_failed" => 0xdc3545,
+        "release_started" => 0x0d6efd,
+        "drift_detected" => 0xfd7e14,
+        "drift_resolved" => 0x36a64f,
+        "schedule_failed" => 0xdc3545,
+        _ => 0x6c757d,
+    };
+
+    let mut embed = serde_json::json!({
+        "title": event.title,
+        "color": color,
+        "author": { "name": format!("{}/{}", event.organisation, event.project) },
+    });
+    if !event.timestamp.is_empty() {
+        embed["timestamp"] = event.timestamp.clone().into();
+    }
+
+    match &event.release {
+        Some(r) => {
+            if !r.context_title.is_empty() {
+                embed["description"] = r.context_title.clone().into();
+            }
+            let mut fields = Vec::new();
+            if !r.destination.is_empty() {
+                fields.push(serde_json::json!({ "name": "Destination", "value": r.destination, "inline": true }));
+            }
+            if !r.environment.is_empty() {
+                fields.push(serde_json::json!({ "name": "Environment", "value": r.environment, "inline": true }));
+            }
+            if !r.commit_sha.is_empty() {
+                let short = &r.commit_sha[..r.commit_sha.len().min(7)];
+                fields.push(serde_json::json!({
+                    "name": "Commit",
+                    "value": format!("`{short}` ({})", r.commit_branch),
+                    "inline": true,
+                }));
+            }
+            if let Some(err) = &r.error_message {
+                fields.push(serde_json::json!({ "name": "Error", "value": err }));
+            }
+            embed["fields"] = fields.into();
+            let url = build_release_url(event, r, forage_url);
+            if !url.is_empty() {
+                embed["url"] = url.into();
+            }
+        }
+        None if !event.body.is_empty() => embed["description"] = event.body.clone().into(),
+        None => {}
+    }
+
+    serde_json::json!({
+        "username": "Forage",
+        "content": event.title,
+        "embeds": [embed],
+        // Never let release text ping @everyone or roles.
+        "allowed_mentions": { "parse": [] },
+    })
+}
@@ -19,3 +19,186 @@ pub struct EmailEnvelope {
     pub body_text: String,
     pub email_type: String,
 }
+
+// ── Email digest integration ─────────────────────────────────────────
+
+/// Email type (and NATS subject suffix) for integration digests.
+pub const DIGEST_EMAIL_TYPE: &str = "integration-digest";
+
+pub fn default_digest_interval_minutes() -> u32 {
+    60
+}
+
+/// Whether a digest whose oldest entry was queued at `oldest_at` is due.
+/// Unparseable timestamps count as due so entries never get stuck.
+pub fn digest_due(oldest_at: &str, interval_minutes: u32, now: chrono::DateTime<chrono::Utc>) -> bool {
+    match chrono::DateTime::parse_from_rfc3339(oldest_at) {
+        Ok(t) => now - t.with_timezone(&chrono::Utc) >= chrono::Duration::minutes(interval_minutes.into()),
+        Err(_) => true,
+    }
+}
+
+/// Queue entry for a routed notification.
+pub fn digest_entry_from_event(
+    integration_id: &str,
+    event: &super::router::NotificationEvent,
+) -> super::DigestEntry {
+    let body = match &event.release {
+        Some(r) => {
+            let mut line = r.destination.clone();
+            if !r.source_username.is_empty() {
+                line.push_str(&format!(" by {}", r.source_username));
+            }
+            if let Some(err) = &r.error_message {
+                line.push_str(&format!(": {err}"));
+            }
+            line
+        }
+        None => event.body.clone(),
+    };
+    super::DigestEntry {
+        id: uuid::Uuid::new_v4().to_string(),
+        integration_id: integration_id.to_string(),
+        organisation: event.organisation.clone(),
+        notification_id: event.id.clone(),
+        notification_type: event.notification_type.clone(),
+        project: event.project.clone(),
+        title: event.title.clone(),
+        body,
+        created_at: chrono::Utc::now().to_rfc3339(),
+    }
+}
+
+/// Render one digest email per recipient.
+pub fn render_digest(
+    integration_name: &str,
+    organisation: &str,
+    recipients: &[String],
+    entries: &[super::DigestEntry],
+    forage_url: &str,
+) -> Vec<EmailEnvelope> {
+    let failed = entries
+        .iter()
+        .filter(|e| e.notification_type == "release_failed")
+        .count();
+    let subject = if failed > 0 {
+        format!(
+            "[{organisation}] {} release updates ({failed} failed)",
+            entries.len()
+        )
+    } else {
+        format!("[{organisation}] {} release updates", entries.len())
+    };
+
+    let mut html = format!(
+        "<p>Release activity in <strong>{}</strong> since the last digest:</p><ul>",
+        escape(organisation)
+    );
+    let mut text = format!("Release activity in {organisation} since the last digest:\n\n");
+    for e in entries {
+        let label = match e.notification_type.as_str() {
+            "release_succeeded" => "Succeeded",
+            "release_failed" => "Failed",
+            "release_started" => "Started",
+            "release_annotated" => "Annotated",
+            "drift_detected" => "Drift detected",
+            "drift_resolved" => "Drift resolved",
+            "schedule_failed" => "Schedule failed",
+            _ => "Update",
+        };
+        html.push_str(&format!(
+            "<li><strong>{label}</strong> {}/{}: {}{}</li>",
+            escape(&e.organisation),
+            escape(&e.project),
+            escape(&e.title),
+            if e.body.is_empty() { String::new() } else { format!(" &mdash; {}", escape(&e.body)) },
+        ));
+        text.push_str(&format!("- [{label}] {}/{}: {}", e.organisation, e.project, e.title));
+        if !e.body.is_empty() {
+            text.push_str(&format!(" - {}", e.body));
+        }
+        text.push('\n');
+    }
+    html.push_str("</ul>");
+
+    let settings_url = format!(
+        "{}/orgs/{organisation}/settings/integrations",
+        forage_url.trim_end_matches('/')
+    );
+    html.push_str(&format!(
+        "<p>You receive this because you are listed on the <a href=\"{}\">{}</a> integration.</p>",
+        escape(&settings_url),
+        escape(integration_name)
+    ));
+    text.push_str(&format!(
+        "\nYou receive this because you are listed on the {integration_name} integration: {settings_url}\n"
+    ));
+
+    recipients
+        .iter()
+        .map(|to| EmailEnvelope {
+            to: to.clone(),
+            subject: subject.clone(),
+            body_html: html.clone(),
+            body_text: text.clone(),
+            email_type: DIGEST_EMAIL_TYPE.into(),
+        })
+        .collect()
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(notification_type: &str, title: &str) -> crate::integrations::DigestEntry {
+        crate::integrations::DigestEntry {
+            id: "e".into(),
+            integration_id: "i".into(),
+            organisation: "acme".into(),
+            notification_id: "n".into(),
+            notification_type: notification_type.into(),
+            project: "web".into(),
+            title: title.into(),
+            body: "prod-eu".into(),
+            created_at: "2026-03-09T14:30:00Z".into(),
+        }
+    }
+
+    #[test]
+    fn digest_due_after_interval() {
+        let now = chrono::DateTime::parse_from_rfc3339("2026-03-09T15:00:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        assert!(digest_due("2026-03-09T14:00:00Z", 60, now));
+        assert!(!digest_due("2026-03-09T14:30:00Z", 60, now));
+        assert!(digest_due("garbage", 60, now));
+    }
+
+    #[test]
+    fn digest_renders_one_mail_per_recipient_and_escapes() {
+        let entries = vec![
+            entry("release_succeeded", "Deployed <v2>"),
+            entry("release_failed", "Failed"),
+        ];
+        let mails = render_digest(
+            "ops",
+            "acme",
+            &["a@example.com".into(), "b@example.com".into()],
+            &entries,
+            "https://forage.sh",
+        );
+        assert_eq!(mails.len(), 2);
+        assert_eq!(mails[1].to, "b@example.com");
+        assert_eq!(mails[0].subject, "[acme] 2 release updates (1 failed)");
+        assert_eq!(mails[0].email_type, DIGEST_EMAIL_TYPE);
+        assert!(mails[0].body_html.contains("Deployed &lt;v2&gt;"));
+        assert!(mails[0].body_text.contains("- [Failed] acme/web: Failed - prod-eu"));
+    }
+}
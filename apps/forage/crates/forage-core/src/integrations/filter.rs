@@ -0,0 +1,444 @@
+use serde::{Deserialize, Serialize};
+
+use super::router::NotificationEvent;
+use super::IntegrationError;
+
+/// Max entries per filter list.
+pub const MAX_FILTER_VALUES: usize = 20;
+
+/// Max length of a title override.
+pub const MAX_TITLE_TEMPLATE_LEN: usize = 200;
+
+/// Max length of a body override.
+pub const MAX_BODY_TEMPLATE_LEN: usize = 2000;
+
+/// Placeholders available to message overrides, with what they expand to.
+pub const TEMPLATE_PLACEHOLDERS: &[(&str, &str)] = &[
+    ("title", "Original notification title"),
+    ("event", "Event type, e.g. release_failed"),
+    ("organisation", "Organisation name"),
+    ("project", "Project name"),
+    ("environment", "Environment of the release"),
+    ("destination", "Destination of the release"),
+    ("release", "Release slug"),
+    ("author", "User who created the release"),
+    ("commit", "Short commit SHA"),
+    ("branch", "Commit branch"),
+    ("error", "Error message of a failed release"),
+];
+
+/// How serious an event is, ordered from least to most severe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    Info,
+    Error,
+}
+
+impl Severity {
+    pub const ALL: &[Severity] = &[Severity::Info, Severity::Error];
+
+    /// Severity of a notification type; failures and drift are errors.
+    pub fn of(notification_type: &str) -> Self {
+        match notification_type {
+            "release_failed" | "drift_detected" | "schedule_failed" => Self::Error,
+            _ => Self::Info,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Info => "info",
+            Self::Error => "error",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "info" => Some(Self::Info),
+            "error" => Some(Self::Error),
+            _ => None,
+        }
+    }
+}
+
+/// Narrows which events an integration receives beyond the per-type
+/// [`NotificationRule`](super::NotificationRule) toggles, and optionally
+/// rewrites the message it gets.
+///
+/// Empty lists match everything. Entries match exactly, or by prefix when
+/// they end in `*` (`prod-*`).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NotificationFilter {
+    #[serde(default)]
+    pub projects: Vec<String>,
+    #[serde(default)]
+    pub environments: Vec<String>,
+    #[serde(default)]
+    pub destinations: Vec<String>,
+    /// Drops events less severe than this.
+    #[serde(default)]
+    pub min_severity: Option<Severity>,
+    /// Replaces the notification title, e.g. `{project} failed in {environment}`.
+    #[serde(default)]
+    pub title_template: Option<String>,
+    /// Replaces the notification body.
+    #[serde(default)]
+    pub body_template: Option<String>,
+}
+
+impl NotificationFilter {
+    /// True when the filter neither narrows nor rewrites anything.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Whether `event` passes the severity, project, environment and
+    /// destination filters. Events without a release only pass when no
+    /// environment or destination filter is set.
+    pub fn matches(&self, event: &NotificationEvent) -> bool {
+        if self
+            .min_severity
+            .is_some_and(|min| Severity::of(&event.notification_type) < min)
+        {
+            return false;
+        }
+        if !matches_any(&self.projects, &event.project) {
+            return false;
+        }
+        if self.environments.is_empty() && self.destinations.is_empty() {
+            return true;
+        }
+        let Some(release) = &event.release else {
+            return false;
+        };
+        matches_any(&self.environments, &release.environment)
+            && matches_any(&self.destinations, &release.destination)
+    }
+
+    /// The event with the title/body overrides applied.
+    pub fn render(&self, event: &NotificationEvent) -> NotificationEvent {
+        let mut rendered = event.clone();
+        if let Some(t) = &self.title_template {
+            rendered.title = render_template(t, event);
+        }
+        if let Some(t) = &self.body_template {
+            rendered.body = render_template(t, event);
+        }
+        rendered
+    }
+
+    /// Check list sizes, entry syntax and template placeholders.
+    pub fn validate(&self) -> Result<(), IntegrationError> {
+        for (label, values) in [
+            ("projects", &self.projects),
+            ("environments", &self.environments),
+            ("destinations", &self.destinations),
+        ] {
+            if values.len() > MAX_FILTER_VALUES {
+                return Err(IntegrationError::InvalidInput(format!(
+                    "At most {MAX_FILTER_VALUES} {label} can be listed"
+                )));
+            }
+            if let Some(bad) = values.iter().find(|v| !is_valid_pattern(v)) {
+                return Err(IntegrationError::InvalidInput(format!(
+                    "Invalid {label} entry '{bad}': use letters, digits, '-', '_' or '.', optionally ending in '*'"
+                )));
+            }
+        }
+        for (label, template, max) in [
+            ("Title", &self.title_template, MAX_TITLE_TEMPLATE_LEN),
+            ("Body", &self.body_template, MAX_BODY_TEMPLATE_LEN),
+        ] {
+            let Some(template) = template else { continue };
+            if template.trim().is_empty() {
+                return Err(IntegrationError::InvalidInput(format!(
+                    "{label} override must not be blank"
+                )));
+            }
+            if template.chars().count() > max {
+                return Err(IntegrationError::InvalidInput(format!(
+                    "{label} override must be at most {max} characters"
+                )));
+            }
+            if let Some(unknown) = placeholders(template)
+                .find(|p| !TEMPLATE_PLACEHOLDERS.iter().any(|(name, _)| name == p))
+            {
+                return Err(IntegrationError::InvalidInput(format!(
+                    "{label} override uses unknown placeholder '{{{unknown}}}'"
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parse a comma- or newline-separated form field into filter entries.
+pub fn parse_filter_list(input: &str) -> Vec<String> {
+    let mut values: Vec<String> = Vec::new();
+    for v in input.split([',', '\n']).map(str::trim).filter(|v| !v.is_empty()) {
+        if !values.iter().any(|existing| existing == v) {
+            values.push(v.to_string());
+        }
+    }
+    values
+}
+
+fn matches_any(patterns: &[String], value: &str) -> bool {
+    patterns.is_empty()
+        || patterns.iter().any(|p| match p.strip_suffix('*') {
+            Some(prefix) => value.starts_with(prefix),
+            None => p == value,
+        })
+}
+
+fn is_valid_pattern(p: &str) -> bool {
+    let body = p.strip_suffix('*').unwrap_or(p);
+    p.len() <= 100
+        && (!body.is_empty() || p == "*")
+        && body
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
+
+/// Names inside `{...}` in a template.
+fn placeholders(template: &str) -> impl Iterator<Item = &str> {
+    template.split('{').skip(1).filter_map(|s| s.split_once('}').map(|(name, _)| name))
+}
+
+fn render_template(template: &str, event: &NotificationEvent) -> String {
+    let release = event.release.as_ref();
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let Some(end) = after.find('}') else {
+            rest = &rest[start..];
+            break;
+        };
+        let name = &after[..end];
+        let value = match name {
+            "title" => Some(event.title.clone()),
+            "event" => Some(event.notification_type.clone()),
+            "organisation" => Some(event.organisation.clone()),
+            "project" => Some(event.project.clone()),
+            "environment" => Some(release.map(|r| r.environment.clone()).unwrap_or_default()),
+            "destination" => Some(release.map(|r| r.destination.clone()).unwrap_or_default()),
+            "release" => Some(release.map(|r| r.slug.clone()).unwrap_or_default()),
+            "author" => Some(release.map(|r| r.source_username.clone()).unwrap_or_default()),
+            "commit" => Some(
+                release
+                    .map(|r| r.commit_sha.chars().take(7).collect())
+                    .unwrap_or_default(),
+            ),
+            "branch" => Some(release.map(|r| r.commit_branch.clone()).unwrap_or_default()),
+            "error" => Some(
+                release
+                    .and_then(|r| r.error_message.clone())
+                    .unwrap_or_default(),
+            ),
+            _ => None,
+        };
+        match value {
+            Some(v) => out.push_str(&v),
+            // Unknown names are left as written.
+            None => out.push_str(&rest[start..start + end + 2]),
+        }
+        rest = &after[end + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integrations::router::ReleaseContext;
+
+    fn event(project: &str, environment: &str, destination: &str) -> NotificationEvent {
+        NotificationEvent {
+            id: "notif-1".into(),
+            notification_type: "release_failed".into(),
+            title: "Release failed".into(),
+            body: String::new(),
+            organisation: "acme".into(),
+            project: project.into(),
+            timestamp: "2026-03-09T15:00:00Z".into(),
+            release: Some(ReleaseContext {
+                slug: "payments-v3".into(),
+                artifact_id: "art_1".into(),
+                release_intent_id: String::new(),
+                destination: destination.into(),
+                environment: environment.into(),
+                source_username: "alice".into(),
+                source_user_id: String::new(),
+                commit_sha: "deadbeef1234567".into(),
+                commit_branch: "main".into(),
+                context_title: String::new(),
+                context_web: String::new(),
+                destination_count: 1,
+                error_message: Some("health check timed out".into()),
+            }),
+        }
+    }
+
+    #[test]
+    fn empty_filter_matches_everything() {
+        let f = NotificationFilter::default();
+        assert!(f.is_empty());
+        assert!(f.matches(&event("payments", "prod", "prod-eu")));
+        let mut bare = event("payments", "prod", "prod-eu");
+        bare.release = None;
+        assert!(f.matches(&bare));
+    }
+
+    #[test]
+    fn filter_by_project_and_environment() {
+        let f = NotificationFilter {
+            projects: vec!["payments".into()],
+            environments: vec!["prod".into()],
+            ..Default::default()
+        };
+        assert!(f.matches(&event("payments", "prod", "prod-eu")));
+        assert!(!f.matches(&event("payments", "staging", "staging-eu")));
+        assert!(!f.matches(&event("checkout", "prod", "prod-eu")));
+    }
+
+    #[test]
+    fn destination_prefix_pattern() {
+        let f = NotificationFilter {
+            destinations: vec!["prod-*".into()],
+            ..Default::default()
+        };
+        assert!(f.matches(&event("payments", "prod", "prod-eu")));
+        assert!(f.matches(&event("payments", "prod", "prod-us")));
+        assert!(!f.matches(&event("payments", "staging", "staging-eu")));
+    }
+
+    #[test]
+    fn environment_filter_rejects_events_without_release() {
+        let f = NotificationFilter {
+            environments: vec!["prod".into()],
+            ..Default::default()
+        };
+        let mut bare = event("payments", "prod", "prod-eu");
+        bare.release = None;
+        assert!(!f.matches(&bare));
+    }
+
+    #[test]
+    fn min_severity_drops_less_severe_events() {
+        let f = NotificationFilter {
+            min_severity: Some(Severity::Error),
+            ..Default::default()
+        };
+        assert!(!f.is_empty());
+        assert!(f.matches(&event("payments", "prod", "prod-eu")));
+        let mut succeeded = event("payments", "prod", "prod-eu");
+        succeeded.notification_type = "release_succeeded".into();
+        assert!(!f.matches(&succeeded));
+
+        let info = NotificationFilter {
+            min_severity: Some(Severity::Info),
+            ..Default::default()
+        };
+        assert!(info.matches(&succeeded));
+    }
+
+    #[test]
+    fn severity_of_notification_types() {
+        assert_eq!(Severity::of("release_failed"), Severity::Error);
+        assert_eq!(Severity::of("drift_detected"), Severity::Error);
+        assert_eq!(Severity::of("release_succeeded"), Severity::Info);
+        assert_eq!(Severity::of("release_annotated"), Severity::Info);
+        for s in Severity::ALL {
+            assert_eq!(Severity::parse(s.as_str()), Some(*s));
+        }
+        assert_eq!(Severity::parse("critical"), None);
+    }
+
+    #[test]
+    fn filters_saved_before_severity_still_load() {
+        let f: NotificationFilter =
+            serde_json::from_str(r#"{"projects":["payments"]}"#).unwrap();
+        assert_eq!(f.min_severity, None);
+        assert_eq!(f.projects, vec!["payments"]);
+    }
+
+    #[test]
+    fn render_applies_overrides() {
+        let f = NotificationFilter {
+            title_template: Some(":rotating_light: {project} failed in {environment}".into()),
+            body_template: Some("{release} ({commit} on {branch}) by {author}: {error}".into()),
+            ..Default::default()
+        };
+        let rendered = f.render(&event("payments", "prod", "prod-eu"));
+        assert_eq!(rendered.title, ":rotating_light: payments failed in prod");
+        assert_eq!(
+            rendered.body,
+            "payments-v3 (deadbee on main) by alice: health check timed out"
+        );
+    }
+
+    #[test]
+    fn render_without_overrides_is_identity() {
+        let e = event("payments", "prod", "prod-eu");
+        let rendered = NotificationFilter::default().render(&e);
+        assert_eq!(rendered.title, e.title);
+        assert_eq!(rendered.body, e.body);
+    }
+
+    #[test]
+    fn render_keeps_unknown_and_unclosed_braces() {
+        let f = NotificationFilter {
+            title_template: Some("{nope} {title} {unclosed".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            f.render(&event("p", "e", "d")).title,
+            "{nope} Release failed {unclosed"
+        );
+    }
+
+    #[test]
+    fn validate_rejects_unknown_placeholder() {
+        let f = NotificationFilter {
+            title_template: Some("{project} {secret}".into()),
+            ..Default::default()
+        };
+        assert!(f.validate().is_err());
+    }
+
+    #[test]
+    fn validate_rejects_bad_patterns_and_long_lists() {
+        let bad = NotificationFilter {
+            projects: vec!["pay ments".into()],
+            ..Default::default()
+        };
+        assert!(bad.validate().is_err());
+
+        let long = NotificationFilter {
+            environments: (0..=MAX_FILTER_VALUES).map(|i| format!("env{i}")).collect(),
+            ..Default::default()
+        };
+        assert!(long.validate().is_err());
+
+        let ok = NotificationFilter {
+            projects: vec!["payments".into()],
+            destinations: vec!["prod-*".into(), "*".into()],
+            title_template: Some("{project}: {title}".into()),
+            ..Default::default()
+        };
+        assert!(ok.validate().is_ok());
+    }
+
+    #[test]
+    fn parse_filter_list_splits_and_dedups() {
+        assert_eq!(
+            parse_filter_list("payments, checkout\nledger,,payments "),
+            vec!["payments", "checkout", "ledger"]
+        );
+        assert!(parse_filter_list("  ").is_empty());
+    }
+}
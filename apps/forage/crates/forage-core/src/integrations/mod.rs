@@ -1,11 +1,18 @@
+pub mod discord;
 pub mod email;
+pub mod filter;
 pub mod nats;
+pub mod pagerduty;
 pub mod router;
+pub mod slack;
+pub mod teams;
 pub mod webhook;
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+pub use filter::{NotificationFilter, Severity};
+
 // ── Integration types ────────────────────────────────────────────────
 
 /// An org-level notification integration (Slack workspace, webhook URL, etc.).
@@ -32,6 +39,11 @@ pub struct Integration {
 pub enum IntegrationType {
     Slack,
     Webhook,
+    Teams,
+    Discord,
+    #[serde(rename = "pagerduty")]
+    PagerDuty,
+    Email,
 }
 
 impl IntegrationType {
@@ -39,6 +51,10 @@ impl IntegrationType {
         match self {
             Self::Slack => "slack",
             Self::Webhook => "webhook",
+            Self::Teams => "teams",
+            Self::Discord => "discord",
+            Self::PagerDuty => "pagerduty",
+            Self::Email => "email",
         }
     }
 
@@ -46,6 +62,10 @@ impl IntegrationType {
         match s {
             "slack" => Some(Self::Slack),
             "webhook" => Some(Self::Webhook),
+            "teams" => Some(Self::Teams),
+            "discord" => Some(Self::Discord),
+            "pagerduty" => Some(Self::PagerDuty),
+            "email" => Some(Self::Email),
             _ => None,
         }
     }
@@ -54,6 +74,20 @@ impl IntegrationType {
         match self {
             Self::Slack => "Slack",
             Self::Webhook => "Webhook",
+            Self::Teams => "Microsoft Teams",
+            Self::Discord => "Discord",
+            Self::PagerDuty => "PagerDuty",
+            Self::Email => "Email digest",
+        }
+    }
+
+    /// Whether a new integration of this type starts with the rule for
+    /// `notification_type` enabled. PagerDuty only pages on failures and
+    /// drift (and resolves them), so the other events start switched off.
+    pub fn default_rule_enabled(&self, notification_type: &str) -> bool {
+        match self {
+            Self::PagerDuty => pagerduty::HANDLED_EVENTS.contains(&notification_type),
+            _ => true,
         }
     }
 }
@@ -77,6 +111,19 @@ pub enum IntegrationConfig {
         #[serde(default)]
         headers: HashMap<String, String>,
     },
+    /// Microsoft Teams incoming webhook (Workflows or connector URL).
+    Teams { webhook_url: String },
+    /// Discord channel webhook.
+    Discord { webhook_url: String },
+    /// PagerDuty Events API v2 integration key for a service.
+    #[serde(rename = "pagerduty")]
+    PagerDuty { routing_key: String },
+    /// Batches events and mails a summary to the recipients.
+    Email {
+        recipients: Vec<String>,
+        #[serde(default = "email::default_digest_interval_minutes")]
+        digest_interval_minutes: u32,
+    },
 }
 
 // ── Notification rules ───────────────────────────────────────────────
@@ -96,6 +143,9 @@ pub const NOTIFICATION_TYPES: &[&str] = &[
     "release_started",
     "release_succeeded",
     "release_failed",
+    "drift_detected",
+    "drift_resolved",
+    "schedule_failed",
 ];
 
 // ── Slack user links ─────────────────────────────────────────────────
@@ -140,9 +190,35 @@ pub struct SlackMessageRef {
     pub updated_at: String,
 }
 
+// ── Email digests ────────────────────────────────────────────────────
+
+/// An event waiting to go out in an email digest integration's next mail.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DigestEntry {
+    pub id: String,
+    pub integration_id: String,
+    pub organisation: String,
+    pub notification_id: String,
+    pub notification_type: String,
+    pub project: String,
+    pub title: String,
+    pub body: String,
+    pub created_at: String,
+}
+
+/// An email digest integration with queued entries.
+#[derive(Debug, Clone)]
+pub struct DigestBacklog {
+    pub integration_id: String,
+    pub organisation: String,
+    /// When the oldest queued entry was added; the digest is due once this
+    /// is older than the integration's interval.
+    pub oldest_at: String,
+}
+
 // ── Delivery log ─────────────────────────────────────────────────────
 
-/// Record of a notification delivery attempt.
+/// A notification delivery to one integration, and the state of its retries.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NotificationDelivery {
     pub id: String,
@@ -150,15 +226,65 @@ pub struct NotificationDelivery {
     pub notification_id: String,
     pub status: DeliveryStatus,
     pub error_message: Option<String>,
+    /// When the latest attempt was made (or the delivery queued, if none yet).
     pub attempted_at: String,
+    pub attempts: u32,
+    /// HTTP status of the latest attempt, when the receiver answered.
+    pub response_code: Option<u16>,
+    /// When the queue will try again; `None` once delivered or dead-lettered.
+    pub next_attempt_at: Option<String>,
+    /// The serialized `QueuedTask`, kept so the delivery can be retried or
+    /// redelivered. It holds no credentials: each attempt resolves the
+    /// integration again. `None` for deliveries recorded without a payload.
+    pub task: Option<serde_json::Value>,
+    pub created_at: String,
+}
+
+impl NotificationDelivery {
+    /// Whether the delivery can be sent again from the integration page.
+    pub fn can_redeliver(&self) -> bool {
+        self.task.is_some()
+            && matches!(
+                self.status,
+                DeliveryStatus::Delivered | DeliveryStatus::Failed | DeliveryStatus::DeadLettered
+            )
+    }
+}
+
+/// Result of one delivery attempt, applied by `record_delivery_attempt`.
+#[derive(Debug, Clone)]
+pub struct DeliveryAttempt {
+    pub status: DeliveryStatus,
+    pub response_code: Option<u16>,
+    pub error_message: Option<String>,
+    pub next_attempt_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Wait after the Nth failed attempt (1-based) before trying again. The
+/// first two are short enough to retry in-process; the rest back off over
+/// roughly sixteen hours before the delivery is dead-lettered.
+pub const DELIVERY_RETRY_DELAYS_SECS: &[i64] =
+    &[1, 5, 60, 300, 900, 1800, 3600, 7200, 14400, 28800];
+
+/// Delay before the next attempt after `attempts` failed attempts, or `None`
+/// when the retry budget is spent.
+pub fn delivery_retry_delay(attempts: u32) -> Option<chrono::Duration> {
+    let idx = (attempts as usize).checked_sub(1)?;
+    DELIVERY_RETRY_DELAYS_SECS
+        .get(idx)
+        .map(|s| chrono::Duration::seconds(*s))
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum DeliveryStatus {
     Delivered,
+    /// The latest attempt failed; another is scheduled.
     Failed,
+    /// Queued, not attempted yet.
     Pending,
+    /// Gave up: retries exhausted or the receiver can never accept it.
+    DeadLettered,
 }
 
 impl DeliveryStatus {
@@ -167,6 +293,7 @@ impl DeliveryStatus {
             Self::Delivered => "delivered",
             Self::Failed => "failed",
             Self::Pending => "pending",
+            Self::DeadLettered => "dead_lettered",
         }
     }
 
@@ -175,6 +302,7 @@ impl DeliveryStatus {
             "delivered" => Some(Self::Delivered),
             "failed" => Some(Self::Failed),
             "pending" => Some(Self::Pending),
+            "dead_lettered" => Some(Self::DeadLettered),
             _ => None,
         }
     }
@@ -264,6 +392,20 @@ pub trait IntegrationStore: Send + Sync {
         enabled: bool,
     ) -> Result<(), IntegrationError>;
 
+    /// Get the project/environment/destination filter and message overrides
+    /// of an integration. Integrations without one get the empty filter.
+    async fn get_notification_filter(
+        &self,
+        integration_id: &str,
+    ) -> Result<NotificationFilter, IntegrationError>;
+
+    /// Replace the notification filter of an integration.
+    async fn set_notification_filter(
+        &self,
+        integration_id: &str,
+        filter: &NotificationFilter,
+    ) -> Result<(), IntegrationError>;
+
     /// Record a delivery attempt.
     async fn record_delivery(
         &self,
@@ -280,13 +422,57 @@ pub trait IntegrationStore: Send + Sync {
         notification_type: &str,
     ) -> Result<Vec<Integration>, IntegrationError>;
 
-    /// List recent delivery attempts for an integration, newest first.
+    /// List recent deliveries for an integration, newest first.
     async fn list_deliveries(
         &self,
         integration_id: &str,
         limit: usize,
     ) -> Result<Vec<NotificationDelivery>, IntegrationError>;
 
+    // ── Delivery queue ────────────────────────────────────────────────
+
+    /// Persist a delivery before it is attempted. The queue leaves it alone
+    /// until `lease_until`, giving the caller time to attempt it inline.
+    async fn enqueue_delivery(
+        &self,
+        integration_id: &str,
+        notification_id: &str,
+        task: serde_json::Value,
+        lease_until: chrono::DateTime<chrono::Utc>,
+    ) -> Result<NotificationDelivery, IntegrationError>;
+
+    /// Apply the outcome of an attempt and bump the attempt count.
+    async fn record_delivery_attempt(
+        &self,
+        delivery_id: &str,
+        attempt: &DeliveryAttempt,
+    ) -> Result<(), IntegrationError>;
+
+    /// Claim up to `limit` deliveries that are due by `now`, leasing them
+    /// until `lease_until`. Only the oldest undelivered entry of each
+    /// integration is eligible, so every integration drains in order and a
+    /// dead receiver only holds up its own queue.
+    async fn claim_due_deliveries(
+        &self,
+        now: chrono::DateTime<chrono::Utc>,
+        lease_until: chrono::DateTime<chrono::Utc>,
+        limit: usize,
+    ) -> Result<Vec<NotificationDelivery>, IntegrationError>;
+
+    /// Get a single delivery of an integration.
+    async fn get_delivery(
+        &self,
+        integration_id: &str,
+        delivery_id: &str,
+    ) -> Result<NotificationDelivery, IntegrationError>;
+
+    /// Queue a fresh copy of a stored delivery, due immediately.
+    async fn redeliver(
+        &self,
+        integration_id: &str,
+        delivery_id: &str,
+    ) -> Result<NotificationDelivery, IntegrationError>;
+
     /// Update the configuration (and optionally the name) of an existing integration.
     async fn update_integration_config(
         &self,
@@ -311,6 +497,13 @@ pub trait IntegrationStore: Send + Sync {
         team_id: &str,
     ) -> Result<Option<SlackUserLink>, IntegrationError>;
 
+    /// Find which Forage user a Slack user in a workspace is linked to, if any.
+    async fn get_slack_user_link_by_slack_user(
+        &self,
+        team_id: &str,
+        slack_user_id: &str,
+    ) -> Result<Option<SlackUserLink>, IntegrationError>;
+
     /// Create or update the Slack user link for a given (user_id, team_id) pair.
     async fn upsert_slack_user_link(&self, link: &SlackUserLink) -> Result<(), IntegrationError>;
 
@@ -341,6 +534,20 @@ pub trait IntegrationStore: Send + Sync {
         &self,
         msg_ref: &SlackMessageRef,
     ) -> Result<(), IntegrationError>;
+
+    // ── Email digests ─────────────────────────────────────────────────
+
+    /// Queue an event for an email digest integration's next mail.
+    async fn enqueue_digest_entry(&self, entry: &DigestEntry) -> Result<(), IntegrationError>;
+
+    /// List integrations with queued digest entries.
+    async fn list_digest_backlog(&self) -> Result<Vec<DigestBacklog>, IntegrationError>;
+
+    /// Remove and return all queued entries for an integration, oldest first.
+    async fn take_digest_entries(
+        &self,
+        integration_id: &str,
+    ) -> Result<Vec<DigestEntry>, IntegrationError>;
 }
 
 // ── Token generation ────────────────────────────────────────────────
@@ -381,6 +588,60 @@ pub fn validate_webhook_url(url: &str) -> Result<(), IntegrationError> {
     ))
 }
 
+/// Validate a Discord channel webhook URL.
+pub fn validate_discord_webhook_url(url: &str) -> Result<(), IntegrationError> {
+    if url.starts_with("https://discord.com/api/webhooks/")
+        || url.starts_with("https://discordapp.com/api/webhooks/")
+        || url.starts_with("http://localhost")
+        || url.starts_with("http://127.0.0.1")
+    {
+        return Ok(());
+    }
+    Err(IntegrationError::InvalidInput(
+        "Discord webhook URL must start with https://discord.com/api/webhooks/".to_string(),
+    ))
+}
+
+/// Validate a PagerDuty Events v2 integration (routing) key: 32 alphanumerics.
+pub fn validate_pagerduty_routing_key(key: &str) -> Result<(), IntegrationError> {
+    if key.len() == 32 && key.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Ok(());
+    }
+    Err(IntegrationError::InvalidInput(
+        "PagerDuty integration key must be 32 letters and digits".to_string(),
+    ))
+}
+
+/// Maximum recipients of one email digest integration.
+pub const MAX_DIGEST_RECIPIENTS: usize = 20;
+
+/// Validate email digest recipients.
+pub fn validate_email_recipients(recipients: &[String]) -> Result<(), IntegrationError> {
+    if recipients.is_empty() {
+        return Err(IntegrationError::InvalidInput(
+            "Add at least one recipient".to_string(),
+        ));
+    }
+    if recipients.len() > MAX_DIGEST_RECIPIENTS {
+        return Err(IntegrationError::InvalidInput(format!(
+            "At most {MAX_DIGEST_RECIPIENTS} recipients are allowed"
+        )));
+    }
+    for r in recipients {
+        let valid = r.len() <= 254
+            && !r.chars().any(|c| c.is_whitespace() || c.is_control())
+            && r.split_once('@').is_some_and(|(local, domain)| {
+                !local.is_empty() && domain.contains('.') && !domain.contains('@')
+            });
+        if !valid {
+            return Err(IntegrationError::InvalidInput(format!(
+                "Invalid email address: {r}"
+            )));
+        }
+    }
+    Ok(())
+}
+
 /// Validate an integration name (reuse slug rules: lowercase alphanumeric + hyphens, max 64).
 pub fn validate_integration_name(name: &str) -> Result<(), IntegrationError> {
     if name.is_empty() {
@@ -413,6 +674,8 @@ pub struct InMemoryIntegrationStore {
     token_hashes: std::sync::Mutex<HashMap<String, String>>,
     slack_user_links: std::sync::Mutex<Vec<SlackUserLink>>,
     slack_message_refs: std::sync::Mutex<Vec<SlackMessageRef>>,
+    digest_entries: std::sync::Mutex<Vec<DigestEntry>>,
+    filters: std::sync::Mutex<HashMap<String, NotificationFilter>>,
 }
 
 impl InMemoryIntegrationStore {
@@ -424,6 +687,8 @@ impl InMemoryIntegrationStore {
             token_hashes: std::sync::Mutex::new(HashMap::new()),
             slack_user_links: std::sync::Mutex::new(Vec::new()),
             slack_message_refs: std::sync::Mutex::new(Vec::new()),
+            digest_entries: std::sync::Mutex::new(Vec::new()),
+            filters: std::sync::Mutex::new(HashMap::new()),
         }
     }
 }
@@ -510,7 +775,7 @@ impl IntegrationStore for InMemoryIntegrationStore {
                 id: uuid::Uuid::new_v4().to_string(),
                 integration_id: id.clone(),
                 notification_type: nt.to_string(),
-                enabled: true,
+                enabled: input.integration_type.default_rule_enabled(nt),
             });
         }
 
@@ -546,6 +811,7 @@ impl IntegrationStore for InMemoryIntegrationStore {
         // Cascade delete rules
         let mut rules = self.rules.lock().unwrap();
         rules.retain(|r| r.integration_id != id);
+        self.filters.lock().unwrap().remove(id);
         Ok(())
     }
 
@@ -601,6 +867,28 @@ impl IntegrationStore for InMemoryIntegrationStore {
         Ok(())
     }
 
+    async fn get_notification_filter(
+        &self,
+        integration_id: &str,
+    ) -> Result<NotificationFilter, IntegrationError> {
+        let filters = self.filters.lock().unwrap();
+        Ok(filters.get(integration_id).cloned().unwrap_or_default())
+    }
+
+    async fn set_notification_filter(
+        &self,
+        integration_id: &str,
+        filter: &NotificationFilter,
+    ) -> Result<(), IntegrationError> {
+        let mut filters = self.filters.lock().unwrap();
+        if filter.is_empty() {
+            filters.remove(integration_id);
+        } else {
+            filters.insert(integration_id.to_string(), filter.clone());
+        }
+        Ok(())
+    }
+
     async fn record_delivery(
         &self,
         integration_id: &str,
@@ -609,13 +897,19 @@ impl IntegrationStore for InMemoryIntegrationStore {
         error_message: Option<&str>,
     ) -> Result<(), IntegrationError> {
         let mut deliveries = self.deliveries.lock().unwrap();
+        let now = chrono::Utc::now().to_rfc3339();
         deliveries.push(NotificationDelivery {
             id: uuid::Uuid::new_v4().to_string(),
             integration_id: integration_id.to_string(),
             notification_id: notification_id.to_string(),
             status,
             error_message: error_message.map(|s| s.to_string()),
-            attempted_at: chrono::Utc::now().to_rfc3339(),
+            attempted_at: now.clone(),
+            attempts: 1,
+            response_code: None,
+            next_attempt_at: None,
+            task: None,
+            created_at: now,
         });
         Ok(())
     }
@@ -631,12 +925,135 @@ impl IntegrationStore for InMemoryIntegrationStore {
             .filter(|d| d.integration_id == integration_id)
             .cloned()
             .collect();
-        // Sort newest first (by attempted_at descending)
-        matching.sort_by(|a, b| b.attempted_at.cmp(&a.attempted_at));
+        // Sort newest first (by created_at descending)
+        matching.sort_by(|a, b| b.created_at.cmp(&a.created_at));
         matching.truncate(limit);
         Ok(matching)
     }
 
+    async fn enqueue_delivery(
+        &self,
+        integration_id: &str,
+        notification_id: &str,
+        task: serde_json::Value,
+        lease_until: chrono::DateTime<chrono::Utc>,
+    ) -> Result<NotificationDelivery, IntegrationError> {
+        let now = chrono::Utc::now().to_rfc3339();
+        let delivery = NotificationDelivery {
+            id: uuid::Uuid::new_v4().to_string(),
+            integration_id: integration_id.to_string(),
+            notification_id: notification_id.to_string(),
+            status: DeliveryStatus::Pending,
+            error_message: None,
+            attempted_at: now.clone(),
+            attempts: 0,
+            response_code: None,
+            next_attempt_at: Some(lease_until.to_rfc3339()),
+            task: Some(task),
+            created_at: now,
+        };
+        self.deliveries.lock().unwrap().push(delivery.clone());
+        Ok(delivery)
+    }
+
+    async fn record_delivery_attempt(
+        &self,
+        delivery_id: &str,
+        attempt: &DeliveryAttempt,
+    ) -> Result<(), IntegrationError> {
+        let mut deliveries = self.deliveries.lock().unwrap();
+        let d = deliveries
+            .iter_mut()
+            .find(|d| d.id == delivery_id)
+            .ok_or_else(|| IntegrationError::NotFound(delivery_id.to_string()))?;
+        d.attempts += 1;
+        d.status = attempt.status;
+        d.response_code = attempt.response_code;
+        d.error_message = attempt.error_message.clone();
+        d.next_attempt_at = attempt.next_attempt_at.map(|t| t.to_rfc3339());
+        d.attempted_at = chrono::Utc::now().to_rfc3339();
+        Ok(())
+    }
+
+    async fn claim_due_deliveries(
+        &self,
+        now: chrono::DateTime<chrono::Utc>,
+        lease_until: chrono::DateTime<chrono::Utc>,
+        limit: usize,
+    ) -> Result<Vec<NotificationDelivery>, IntegrationError> {
+        let mut deliveries = self.deliveries.lock().unwrap();
+
+        // Head of each integration's queue: its oldest undelivered entry.
+        let mut heads: Vec<usize> = Vec::new();
+        for (i, d) in deliveries.iter().enumerate() {
+            if d.task.is_none()
+                || !matches!(d.status, DeliveryStatus::Pending | DeliveryStatus::Failed)
+            {
+                continue;
+            }
+            match heads
+                .iter_mut()
+                .find(|h| deliveries[**h].integration_id == d.integration_id)
+            {
+                Some(h) if d.created_at < deliveries[*h].created_at => *h = i,
+                Some(_) => {}
+                None => heads.push(i),
+            }
+        }
+
+        let is_due = |d: &NotificationDelivery| {
+            d.next_attempt_at
+                .as_deref()
+                .and_then(|t| chrono::DateTime::parse_from_rfc3339(t).ok())
+                .is_none_or(|t| t <= now)
+        };
+        heads.retain(|h| is_due(&deliveries[*h]));
+        heads.sort_by(|a, b| deliveries[*a].next_attempt_at.cmp(&deliveries[*b].next_attempt_at));
+        heads.truncate(limit);
+
+        Ok(heads
+            .into_iter()
+            .map(|h| {
+                deliveries[h].next_attempt_at = Some(lease_until.to_rfc3339());
+                deliveries[h].clone()
+            })
+            .collect())
+    }
+
+    async fn get_delivery(
+        &self,
+        integration_id: &str,
+        delivery_id: &str,
+    ) -> Result<NotificationDelivery, IntegrationError> {
+        self.deliveries
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|d| d.id == delivery_id && d.integration_id == integration_id)
+            .cloned()
+            .ok_or_else(|| IntegrationError::NotFound(delivery_id.to_string()))
+    }
+
+    async fn redeliver(
+        &self,
+        integration_id: &str,
+        delivery_id: &str,
+    ) -> Result<NotificationDelivery, IntegrationError> {
+        let original = self.get_delivery(integration_id, delivery_id).await?;
+        let Some(task) = original.task else {
+            return Err(IntegrationError::InvalidInput(
+                "delivery has no stored payload".to_string(),
+            ));
+        };
+        self.enqueue_delivery(
+            integration_id,
+            &original.notification_id,
+            task,
+            chrono::Utc::now(),
+        )
+        .await
+    }
+
     async fn list_matching_integrations(
         &self,
         organisation: &str,
@@ -690,6 +1107,18 @@ impl IntegrationStore for InMemoryIntegrationStore {
             .cloned())
     }
 
+    async fn get_slack_user_link_by_slack_user(
+        &self,
+        team_id: &str,
+        slack_user_id: &str,
+    ) -> Result<Option<SlackUserLink>, IntegrationError> {
+        let links = self.slack_user_links.lock().unwrap();
+        Ok(links
+            .iter()
+            .find(|l| l.team_id == team_id && l.slack_user_id == slack_user_id)
+            .cloned())
+    }
+
     async fn upsert_slack_user_link(&self, link: &SlackUserLink) -> Result<(), IntegrationError> {
         let mut links = self.slack_user_links.lock().unwrap();
         if let Some(existing) = links
@@ -751,6 +1180,40 @@ impl IntegrationStore for InMemoryIntegrationStore {
         }
         Ok(())
     }
+    async fn enqueue_digest_entry(&self, entry: &DigestEntry) -> Result<(), IntegrationError> {
+        self.digest_entries.lock().unwrap().push(entry.clone());
+        Ok(())
+    }
+
+    async fn list_digest_backlog(&self) -> Result<Vec<DigestBacklog>, IntegrationError> {
+        let entries = self.digest_entries.lock().unwrap();
+        let mut backlog: Vec<DigestBacklog> = Vec::new();
+        for e in entries.iter() {
+            match backlog.iter_mut().find(|b| b.integration_id == e.integration_id) {
+                Some(b) if e.created_at < b.oldest_at => b.oldest_at = e.created_at.clone(),
+                Some(_) => {}
+                None => backlog.push(DigestBacklog {
+                    integration_id: e.integration_id.clone(),
+                    organisation: e.organisation.clone(),
+                    oldest_at: e.created_at.clone(),
+                }),
+            }
+        }
+        Ok(backlog)
+    }
+
+    async fn take_digest_entries(
+        &self,
+        integration_id: &str,
+    ) -> Result<Vec<DigestEntry>, IntegrationError> {
+        let mut entries = self.digest_entries.lock().unwrap();
+        let (mut taken, kept): (Vec<_>, Vec<_>) = entries
+            .drain(..)
+            .partition(|e| e.integration_id == integration_id);
+        *entries = kept;
+        taken.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+        Ok(taken)
+    }
 }
 
 #[cfg(test)]
@@ -759,7 +1222,14 @@ mod tests {
 
     #[test]
     fn integration_type_roundtrip() {
-        for t in &[IntegrationType::Slack, IntegrationType::Webhook] {
+        for t in &[
+            IntegrationType::Slack,
+            IntegrationType::Webhook,
+            IntegrationType::Teams,
+            IntegrationType::Discord,
+            IntegrationType::PagerDuty,
+            IntegrationType::Email,
+        ] {
             let s = t.as_str();
             assert_eq!(IntegrationType::parse(s), Some(*t));
         }
@@ -767,7 +1237,7 @@ mod tests {
 
     #[test]
     fn integration_type_unknown_returns_none() {
-        assert_eq!(IntegrationType::parse("discord"), None);
+        assert_eq!(IntegrationType::parse("opsgenie"), None);
         assert_eq!(IntegrationType::parse(""), None);
     }
 
@@ -777,12 +1247,23 @@ mod tests {
             DeliveryStatus::Delivered,
             DeliveryStatus::Failed,
             DeliveryStatus::Pending,
+            DeliveryStatus::DeadLettered,
         ] {
             let str = s.as_str();
             assert_eq!(DeliveryStatus::parse(str), Some(*s));
         }
     }
 
+    #[test]
+    fn delivery_retry_delay_backs_off_then_gives_up() {
+        assert_eq!(delivery_retry_delay(0), None);
+        assert_eq!(delivery_retry_delay(1), Some(chrono::Duration::seconds(1)));
+        assert_eq!(delivery_retry_delay(3), Some(chrono::Duration::minutes(1)));
+        let last = DELIVERY_RETRY_DELAYS_SECS.len() as u32;
+        assert_eq!(delivery_retry_delay(last), Some(chrono::Duration::hours(8)));
+        assert_eq!(delivery_retry_delay(last + 1), None);
+    }
+
     #[test]
     fn validate_webhook_url_https() {
         assert!(validate_webhook_url("https://example.com/hook").is_ok());
@@ -858,10 +1339,48 @@ mod tests {
         }
     }
 
+    #[test]
+    fn integration_config_new_channels_serde_roundtrip() {
+        let json = serde_json::to_string(&IntegrationConfig::PagerDuty {
+            routing_key: "r".repeat(32),
+        })
+        .unwrap();
+        assert!(json.contains("\"type\":\"pagerduty\""));
+        assert!(matches!(
+            serde_json::from_str::<IntegrationConfig>(&json).unwrap(),
+            IntegrationConfig::PagerDuty { .. }
+        ));
+
+        // Digest interval falls back to the default when absent.
+        let parsed: IntegrationConfig =
+            serde_json::from_str(r#"{"type":"email","recipients":["ops@example.com"]}"#).unwrap();
+        match parsed {
+            IntegrationConfig::Email { recipients, digest_interval_minutes } => {
+                assert_eq!(recipients, vec!["ops@example.com".to_string()]);
+                assert_eq!(digest_interval_minutes, 60);
+            }
+            _ => panic!("expected Email config"),
+        }
+    }
+
+    #[test]
+    fn validate_new_channel_inputs() {
+        assert!(validate_discord_webhook_url("https://discord.com/api/webhooks/1/abc").is_ok());
+        assert!(validate_discord_webhook_url("https://evil.example.com/api/webhooks/1").is_err());
+        assert!(validate_pagerduty_routing_key(&"a1".repeat(16)).is_ok());
+        assert!(validate_pagerduty_routing_key("short").is_err());
+        assert!(validate_pagerduty_routing_key(&"-".repeat(32)).is_err());
+        assert!(validate_email_recipients(&["ops@example.com".into()]).is_ok());
+        assert!(validate_email_recipients(&[]).is_err());
+        assert!(validate_email_recipients(&["not-an-email".into()]).is_err());
+        assert!(validate_email_recipients(&["a b@example.com".into()]).is_err());
+    }
+
     #[test]
     fn notification_types_are_known() {
-        assert_eq!(NOTIFICATION_TYPES.len(), 4);
+        assert_eq!(NOTIFICATION_TYPES.len(), 7);
         assert!(NOTIFICATION_TYPES.contains(&"release_failed"));
+        assert!(NOTIFICATION_TYPES.contains(&"drift_detected"));
     }
 
     #[test]
@@ -928,4 +1447,125 @@ mod tests {
         let listed = store.list_integrations("myorg").await.unwrap();
         assert!(listed[0].api_token.is_none());
     }
+
+    #[tokio::test]
+    async fn in_memory_store_pagerduty_defaults_to_failure_rules() {
+        let store = InMemoryIntegrationStore::new();
+        let created = store
+            .create_integration(&CreateIntegrationInput {
+                organisation: "myorg".into(),
+                integration_type: IntegrationType::PagerDuty,
+                name: "on-call".into(),
+                config: IntegrationConfig::PagerDuty {
+                    routing_key: "r".repeat(32),
+                },
+                created_by: "user-1".into(),
+            })
+            .await
+            .unwrap();
+
+        let rules = store.list_rules(&created.id).await.unwrap();
+        let enabled: Vec<_> = rules
+            .iter()
+            .filter(|r| r.enabled)
+            .map(|r| r.notification_type.as_str())
+            .collect();
+        assert_eq!(enabled.len(), 4);
+        assert!(enabled.contains(&"release_failed"));
+        assert!(enabled.contains(&"release_succeeded"));
+        assert!(enabled.contains(&"drift_detected"));
+        assert!(enabled.contains(&"drift_resolved"));
+    }
+
+    #[tokio::test]
+    async fn in_memory_store_digest_entries_are_taken_once() {
+        let store = InMemoryIntegrationStore::new();
+        for (id, integration_id, at) in [
+            ("b", "i1", "2026-03-09T14:31:00Z"),
+            ("a", "i1", "2026-03-09T14:30:00Z"),
+            ("c", "i2", "2026-03-09T14:32:00Z"),
+        ] {
+            store
+                .enqueue_digest_entry(&DigestEntry {
+                    id: id.into(),
+                    integration_id: integration_id.into(),
+                    organisation: "myorg".into(),
+                    notification_id: "n".into(),
+                    notification_type: "release_failed".into(),
+                    project: "web".into(),
+                    title: "t".into(),
+                    body: String::new(),
+                    created_at: at.into(),
+                })
+                .await
+                .unwrap();
+        }
+
+        let backlog = store.list_digest_backlog().await.unwrap();
+        assert_eq!(backlog.len(), 2);
+        let i1 = backlog.iter().find(|b| b.integration_id == "i1").unwrap();
+        assert_eq!(i1.oldest_at, "2026-03-09T14:30:00Z");
+
+        let taken = store.take_digest_entries("i1").await.unwrap();
+        assert_eq!(taken.iter().map(|e| e.id.as_str()).collect::<Vec<_>>(), ["a", "b"]);
+        assert!(store.take_digest_entries("i1").await.unwrap().is_empty());
+        assert_eq!(store.list_digest_backlog().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn in_memory_queue_claims_one_head_per_integration() {
+        let store = InMemoryIntegrationStore::new();
+        let now = chrono::Utc::now();
+        let first = store
+            .enqueue_delivery("i1", "n1", serde_json::json!({}), now)
+            .await
+            .unwrap();
+        let second = store
+            .enqueue_delivery("i1", "n2", serde_json::json!({}), now)
+            .await
+            .unwrap();
+        let other = store
+            .enqueue_delivery("i2", "n3", serde_json::json!({}), now)
+            .await
+            .unwrap();
+
+        let lease = now + chrono::Duration::minutes(5);
+        let claimed = store.claim_due_deliveries(now, lease, 10).await.unwrap();
+        let mut ids: Vec<_> = claimed.iter().map(|d| d.id.clone()).collect();
+        ids.sort();
+        let mut expected = vec![first.id.clone(), other.id.clone()];
+        expected.sort();
+        assert_eq!(ids, expected, "only the oldest entry per integration is claimed");
+
+        // Leased entries are not handed out twice.
+        assert!(store.claim_due_deliveries(now, lease, 10).await.unwrap().is_empty());
+
+        // Once the head is delivered, the next entry becomes the head.
+        store
+            .record_delivery_attempt(
+                &first.id,
+                &DeliveryAttempt {
+                    status: DeliveryStatus::Delivered,
+                    response_code: Some(200),
+                    error_message: None,
+                    next_attempt_at: None,
+                },
+            )
+            .await
+            .unwrap();
+        let claimed = store.claim_due_deliveries(now, lease, 10).await.unwrap();
+        assert_eq!(claimed.len(), 1);
+        assert_eq!(claimed[0].id, second.id);
+
+        let delivered = store.get_delivery("i1", &first.id).await.unwrap();
+        assert_eq!(delivered.attempts, 1);
+        assert_eq!(delivered.response_code, Some(200));
+        assert!(delivered.can_redeliver());
+
+        let copy = store.redeliver("i1", &first.id).await.unwrap();
+        assert_ne!(copy.id, first.id);
+        assert_eq!(copy.status, DeliveryStatus::Pending);
+        assert_eq!(copy.notification_id, "n1");
+        assert!(store.redeliver("i2", &first.id).await.is_err());
+    }
 }
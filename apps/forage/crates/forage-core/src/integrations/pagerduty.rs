@@ -0,0 +1,202 @@
+use super::router::{build_release_url, is_drift, NotificationEvent};
+
+/// PagerDuty Events API v2 endpoint.
+pub const PAGERDUTY_EVENTS_URL: &str = "https://events.pagerduty.com/v2/enqueue";
+
+/// Events a PagerDuty integration acts on: failures and drift trigger an
+/// incident, the next success (or drift resolution) for the same
+/// destination resolves it.
+pub const HANDLED_EVENTS: &[&str] = &[
+    "release_failed",
+    "release_succeeded",
+    "drift_detected",
+    "drift_resolved",
+];
+
+/// Deduplication key shared by the trigger and resolve events of one
+/// destination, so a later success closes the incident a failure opened.
+/// Drift gets its own key: a successful release doesn't mean the
+/// destination stopped drifting, nor the other way round.
+pub fn dedup_key(event: &NotificationEvent) -> Option<String> {
+    let release = event.release.as_ref()?;
+    let target = if release.destination.is_empty() {
+        &release.environment
+    } else {
+        &release.destination
+    };
+    let mut key = format!("forage/{}/{}/{}", event.organisation, event.project, target);
+    if is_drift(event) {
+        key.push_str("/drift");
+    }
+    Some(key)
+}
+
+/// Build an Events v2 payload for `event`, or `None` if PagerDuty has
+/// nothing to do with it (non-release events, starts, annotations).
+pub fn build_pagerduty_event(
+    event: &NotificationEvent,
+    routing_key: &str,
+    forage_url: &str,
+) -> Option<serde_json::Value> {
+    let dedup_key = dedup_key(event)?;
+    let release = event.release.as_ref()?;
+
+    match event.notification_type.as_str() {
+        "release_failed" => {
+            let mut summary = format!(
+                "{}/{}: release to {} failed",
+                event.organisation,
+                event.project,
+                if release.destination.is_empty() { &release.environment } else { &release.destination },
+            );
+            if let Some(err) = &release.error_message {
+                summary.push_str(&format!(" — {err}"));
+            }
+            // PagerDuty truncates at 1024 chars; do it ourselves on a char boundary.
+            let summary: String = summary.chars().take(1024).collect();
+
+            let mut body = serde_json::json!({
+                "routing_key": routing_key,
+                "event_action": "trigger",
+                "dedup_key": dedup_key,
+                "payload": {
+                    "summary": summary,
+                    "source": format!("forage/{}/{}", event.organisation, event.project),
+                    "severity": "error",
+                    "component": release.destination,
+                    "group": release.environment,
+                    "class": "release_failed",
+                    "custom_details": {
+                        "release": release.slug,
+                        "artifact_id": release.artifact_id,
+                        "commit_sha": release.commit_sha,
+                        "commit_branch": release.commit_branch,
+                        "author": release.source_username,
+                        "error": release.error_message,
+                    },
+                },
+                "client": "Forage",
+            });
+            let url = build_release_url(event, release, forage_url);
+            if !url.is_empty() {
+                body["client_url"] = url.clone().into();
+                body["links"] = serde_json::json!([{ "href": url, "text": "View release in Forage" }]);
+            }
+            Some(body)
+        }
+        "drift_detected" => {
+            let target = if release.destination.is_empty() { &release.environment } else { &release.destination };
+            let mut summary = format!(
+                "{}/{}: {} drifted from its release",
+                event.organisation, event.project, target,
+            );
+            if let Some(detail) = &release.error_message {
+                summary.push_str(&format!(" — {detail}"));
+            }
+            let summary: String = summary.chars().take(1024).collect();
+
+            let mut body = serde_json::json!({
+                "routing_key": routing_key,
+                "event_action": "trigger",
+                "dedup_key": dedup_key,
+                "payload": {
+                    "summary": summary,
+                    "source": format!("forage/{}/{}", event.organisation, event.project),
+                    "severity": "warning",
+                    "component": release.destination,
+                    "group": release.environment,
+                    "class": "drift_detected",
+                    "custom_details": {
+                        "release": release.slug,
+                        "artifact_id": release.artifact_id,
+                        "drift": release.error_message,
+                    },
+                },
+                "client": "Forage",
+            });
+            let url = build_release_url(event, release, forage_url);
+            if !url.is_empty() {
+                body["client_url"] = url.clone().into();
+                body["links"] = serde_json::json!([{ "href": url, "text": "View release in Forage" }]);
+            }
+            Some(body)
+        }
+        "release_succeeded" | "drift_resolved" => Some(serde_json::json!({
+            "routing_key": routing_key,
+            "event_action": "resolve",
+            "dedup_key": dedup_key,
+        })),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integrations::router::ReleaseContext;
+
+    fn event(notification_type: &str) -> NotificationEvent {
+        NotificationEvent {
+            id: "n-1".into(),
+            notification_type: notification_type.into(),
+            title: "Release".into(),
+            body: String::new(),
+            organisation: "acme".into(),
+            project: "web".into(),
+            timestamp: "2026-03-09T14:30:00Z".into(),
+            release: Some(ReleaseContext {
+                slug: "brave-otter".into(),
+                artifact_id: "art_1".into(),
+                release_intent_id: "ri_1".into(),
+                destination: "prod-eu".into(),
+                environment: "production".into(),
+                source_username: "alice".into(),
+                source_user_id: "u1".into(),
+                commit_sha: "abc1234def".into(),
+                commit_branch: "main".into(),
+                context_title: String::new(),
+                context_web: String::new(),
+                destination_count: 1,
+                error_message: Some("health check timeout".into()),
+            }),
+        }
+    }
+
+    #[test]
+    fn failure_triggers_and_success_resolves_same_key() {
+        let trigger = build_pagerduty_event(&event("release_failed"), "key", "https://forage.sh").unwrap();
+        assert_eq!(trigger["event_action"], "trigger");
+        assert_eq!(trigger["dedup_key"], "forage/acme/web/prod-eu");
+        assert_eq!(trigger["payload"]["severity"], "error");
+        assert!(trigger["payload"]["summary"].as_str().unwrap().contains("health check timeout"));
+        assert_eq!(trigger["client_url"], "https://forage.sh/orgs/acme/projects/web/releases/brave-otter");
+
+        let resolve = build_pagerduty_event(&event("release_succeeded"), "key", "").unwrap();
+        assert_eq!(resolve["event_action"], "resolve");
+        assert_eq!(resolve["dedup_key"], trigger["dedup_key"]);
+    }
+
+    #[test]
+    fn drift_pages_separately_from_release_failures() {
+        let trigger = build_pagerduty_event(&event("drift_detected"), "key", "").unwrap();
+        assert_eq!(trigger["event_action"], "trigger");
+        assert_eq!(trigger["dedup_key"], "forage/acme/web/prod-eu/drift");
+        assert_eq!(trigger["payload"]["severity"], "warning");
+
+        let resolve = build_pagerduty_event(&event("drift_resolved"), "key", "").unwrap();
+        assert_eq!(resolve["event_action"], "resolve");
+        assert_eq!(resolve["dedup_key"], trigger["dedup_key"]);
+
+        let success = build_pagerduty_event(&event("release_succeeded"), "key", "").unwrap();
+        assert_ne!(success["dedup_key"], trigger["dedup_key"]);
+    }
+
+    #[test]
+    fn other_events_are_skipped() {
+        assert!(build_pagerduty_event(&event("release_started"), "key", "").is_none());
+        assert!(build_pagerduty_event(&event("schedule_failed"), "key", "").is_none());
+        let mut bare = event("release_failed");
+        bare.release = None;
+        assert!(build_pagerduty_event(&bare, "key", "").is_none());
+    }
+}
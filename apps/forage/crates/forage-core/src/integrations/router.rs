@@ -2,7 +2,7 @@ use super::{Integration, IntegrationConfig, IntegrationStore};
 use super::webhook::{ReleasePayload, WebhookPayload};
 
 /// A notification event from Forest, normalized for routing.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct NotificationEvent {
     pub id: String,
     pub notification_type: String,
@@ -15,7 +15,7 @@ pub struct NotificationEvent {
 }
 
 /// Release context from the notification event.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ReleaseContext {
     pub slug: String,
     pub artifact_id: String,
@@ -33,7 +33,11 @@ pub struct ReleaseContext {
 }
 
 /// A dispatch task produced by the router: what to send where.
-#[derive(Debug, Clone)]
+///
+/// Carries the integration's credentials, so it is never persisted: the
+/// delivery queue stores its [`QueuedTask`] instead.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
 #[allow(clippy::large_enum_variant)]
 pub enum DispatchTask {
     Webhook {
@@ -68,16 +72,184 @@ pub enum DispatchTask {
         event: NotificationEvent,
         message: SlackMessage,
     },
+    /// Adaptive card posted to a Teams incoming webhook.
+    Teams {
+        integration_id: String,
+        webhook_url: String,
+        notification_id: String,
+        event: NotificationEvent,
+    },
+    /// Embed posted to a Discord channel webhook.
+    Discord {
+        integration_id: String,
+        webhook_url: String,
+        notification_id: String,
+        event: NotificationEvent,
+    },
+    /// PagerDuty Events v2: trigger on failure, resolve on success.
+    PagerDuty {
+        integration_id: String,
+        routing_key: String,
+        notification_id: String,
+        event: NotificationEvent,
+    },
+    /// Queue the event for the integration's next email digest.
+    EmailDigest {
+        integration_id: String,
+        notification_id: String,
+        event: NotificationEvent,
+    },
+}
+
+impl DispatchTask {
+    pub fn integration_id(&self) -> &str {
+        match self {
+            Self::Webhook { integration_id, .. }
+            | Self::Slack { integration_id, .. }
+            | Self::SlackDm { integration_id, .. }
+            | Self::Teams { integration_id, .. }
+            | Self::Discord { integration_id, .. }
+            | Self::PagerDuty { integration_id, .. }
+            | Self::EmailDigest { integration_id, .. } => integration_id,
+        }
+    }
+
+    pub fn notification_id(&self) -> &str {
+        match self {
+            Self::Webhook { payload, .. } => &payload.notification_id,
+            Self::Slack { notification_id, .. }
+            | Self::SlackDm { notification_id, .. }
+            | Self::Teams { notification_id, .. }
+            | Self::Discord { notification_id, .. }
+            | Self::PagerDuty { notification_id, .. }
+            | Self::EmailDigest { notification_id, .. } => notification_id,
+        }
+    }
+}
+
+/// What the delivery queue stores for a [`DispatchTask`]: the message and
+/// who it is for, but none of the integration's URLs, secrets or tokens.
+/// Those stay encrypted in the integration config and are read again at
+/// each attempt, so disabling an integration or rotating its secret also
+/// applies to deliveries already queued.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum QueuedTask {
+    Webhook {
+        payload: WebhookPayload,
+    },
+    SlackDm {
+        slack_user_id: String,
+        event: NotificationEvent,
+    },
+    /// Every other channel is routed again from the (already filtered) event.
+    Channel {
+        event: NotificationEvent,
+    },
+}
+
+impl DispatchTask {
+    pub fn to_queued(&self) -> QueuedTask {
+        match self {
+            Self::Webhook { payload, .. } => QueuedTask::Webhook {
+                payload: payload.clone(),
+            },
+            Self::SlackDm {
+                slack_user_id,
+                event,
+                ..
+            } => QueuedTask::SlackDm {
+                slack_user_id: slack_user_id.clone(),
+                event: event.clone(),
+            },
+            Self::Slack { event, .. }
+            | Self::Teams { event, .. }
+            | Self::Discord { event, .. }
+            | Self::PagerDuty { event, .. }
+            | Self::EmailDigest { event, .. } => QueuedTask::Channel {
+                event: event.clone(),
+            },
+        }
+    }
+}
+
+impl QueuedTask {
+    pub fn organisation(&self) -> &str {
+        match self {
+            Self::Webhook { payload } => &payload.organisation,
+            Self::SlackDm { event, .. } | Self::Channel { event } => &event.organisation,
+        }
+    }
+
+    /// Rebuild the dispatch task from `integration`'s current config.
+    /// `None` when the integration no longer takes this delivery.
+    pub fn resolve(&self, integration: &Integration) -> Option<DispatchTask> {
+        match (self, &integration.config) {
+            (
+                Self::Webhook { payload },
+                IntegrationConfig::Webhook {
+                    url,
+                    secret,
+                    headers,
+                },
+            ) => Some(DispatchTask::Webhook {
+                integration_id: integration.id.clone(),
+                url: url.clone(),
+                secret: secret.clone(),
+                headers: headers.clone(),
+                payload: payload.clone(),
+            }),
+            (
+                Self::SlackDm {
+                    slack_user_id,
+                    event,
+                },
+                IntegrationConfig::Slack { access_token, .. },
+            ) if !access_token.is_empty() => Some(DispatchTask::SlackDm {
+                integration_id: integration.id.clone(),
+                access_token: access_token.clone(),
+                slack_user_id: slack_user_id.clone(),
+                release_id: event.release.as_ref().map(|r| r.slug.clone()).unwrap_or_default(),
+                notification_id: event.id.clone(),
+                event_type: event.notification_type.clone(),
+                event: event.clone(),
+                message: format_slack_message(event, &std::collections::HashMap::new(), ""),
+            }),
+            // Webhooks queue their payload, never a channel event.
+            (Self::Channel { event }, config)
+                if !matches!(config, IntegrationConfig::Webhook { .. }) =>
+            {
+                route_notification(event, std::slice::from_ref(integration))
+                    .into_iter()
+                    .next()
+            }
+            _ => None,
+        }
+    }
 }
 
 /// A formatted Slack message (Block Kit compatible).
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct SlackMessage {
     pub text: String,
     pub color: String,
     pub blocks: Vec<serde_json::Value>,
 }
 
+/// Whether `event` reports drift on a destination rather than release progress.
+pub fn is_drift(event: &NotificationEvent) -> bool {
+    matches!(
+        event.notification_type.as_str(),
+        "drift_detected" | "drift_resolved"
+    )
+}
+
+/// Whether `event` belongs on its release's Slack message. Drift and
+/// schedule failures are posted on their own.
+pub fn merges_into_release(event: &NotificationEvent) -> bool {
+    !is_drift(event) && event.notification_type != "schedule_failed"
+}
+
 /// Route a notification event to dispatch tasks based on matching integrations.
 pub fn route_notification(
     event: &NotificationEvent,
@@ -87,7 +259,7 @@ pub fn route_notification(
 
     integrations
         .iter()
-        .map(|integration| match &integration.config {
+        .filter_map(|integration| Some(match &integration.config {
             IntegrationConfig::Webhook {
                 url,
                 secret,
@@ -106,12 +278,15 @@ pub fn route_notification(
                 ..
             } => {
                 let message = format_slack_message(event, &std::collections::HashMap::new(), "");
-                // Group by release slug (shared across all destinations in a release)
-                let release_id = event
-                    .release
-                    .as_ref()
-                    .map(|r| r.slug.clone())
-                    .unwrap_or_default();
+                // Group by release slug (shared across all destinations in a release).
+                // Drift gets one message per destination, updated when it resolves.
+                // Schedule failures have no release to group by.
+                let release_id = match event.release.as_ref() {
+                    Some(r) if is_drift(event) => format!("drift:{}:{}", r.slug, r.destination),
+                    _ if !merges_into_release(event) => format!("schedule:{}", event.id),
+                    Some(r) => r.slug.clone(),
+                    None => String::new(),
+                };
                 DispatchTask::Slack {
                     integration_id: integration.id.clone(),
                     webhook_url: webhook_url.clone(),
@@ -124,7 +299,38 @@ pub fn route_notification(
                     message,
                 }
             }
-        })
+            IntegrationConfig::Teams { webhook_url } => DispatchTask::Teams {
+                integration_id: integration.id.clone(),
+                webhook_url: webhook_url.clone(),
+                notification_id: event.id.clone(),
+                event: event.clone(),
+            },
+            IntegrationConfig::Discord { webhook_url } => DispatchTask::Discord {
+                integration_id: integration.id.clone(),
+                webhook_url: webhook_url.clone(),
+                notification_id: event.id.clone(),
+                event: event.clone(),
+            },
+            IntegrationConfig::PagerDuty { routing_key } => {
+                // Only failures, drift and the events that resolve them reach PagerDuty.
+                if event.release.is_none()
+                    || !super::pagerduty::HANDLED_EVENTS.contains(&event.notification_type.as_str())
+                {
+                    return None;
+                }
+                DispatchTask::PagerDuty {
+                    integration_id: integration.id.clone(),
+                    routing_key: routing_key.clone(),
+                    notification_id: event.id.clone(),
+                    event: event.clone(),
+                }
+            }
+            IntegrationConfig::Email { .. } => DispatchTask::EmailDigest {
+                integration_id: integration.id.clone(),
+                notification_id: event.id.clone(),
+                event: event.clone(),
+            },
+        }))
         .collect()
 }
 
@@ -144,7 +350,26 @@ pub async fn route_notification_for_org(
         }
     };
 
-    let mut tasks = route_notification(event, &integrations);
+    // Narrow by each integration's filter and apply its message overrides.
+    let mut tasks = Vec::new();
+    for integration in &integrations {
+        let filter = match store.get_notification_filter(&integration.id).await {
+            Ok(f) => f,
+            Err(e) => {
+                // Sending unfiltered could leak events to a channel that
+                // was narrowed to keep them out, so skip this one.
+                tracing::warn!(integration_id = %integration.id, error = %e, "failed to load notification filter, skipping integration");
+                continue;
+            }
+        };
+        if !filter.matches(event) {
+            continue;
+        }
+        tasks.extend(route_notification(
+            &filter.render(event),
+            std::slice::from_ref(integration),
+        ));
+    }
 
     // Produce personal DM tasks for the release owner (if they linked Slack)
     if let Some(release) = &event.release {
@@ -270,6 +495,9 @@ pub fn format_slack_message(
             "release_failed" => "#dc3545",
             "release_started" => "#0d6efd",
             "release_annotated" => "#6c757d",
+            "drift_detected" => "#fd7e14",
+            "drift_resolved" => "#36a64f",
+            "schedule_failed" => "#dc3545",
             _ => "#6c757d",
         }
     } else {
@@ -325,6 +553,9 @@ pub fn format_slack_message(
                 "release_succeeded" => ":white_check_mark:",
                 "release_failed" => ":x:",
                 "release_started" => ":arrows_counterclockwise:",
+                "drift_detected" => ":warning:",
+                "drift_resolved" => ":white_check_mark:",
+                "schedule_failed" => ":x:",
                 _ => ":bell:",
             };
             let status_label = match event.notification_type.as_str() {
@@ -332,6 +563,9 @@ pub fn format_slack_message(
                 "release_failed" => "Failed",
                 "release_started" => "Deploying",
                 "release_annotated" => "Annotated",
+                "drift_detected" => "Drifted",
+                "drift_resolved" => "In sync",
+                "schedule_failed" => "Schedule failed",
                 _ => "Unknown",
             };
             let mut dest_line = format!("{dest_emoji}  `{}`  {status_label}", r.destination);
@@ -473,7 +707,7 @@ fn build_metadata_context(
 }
 
 /// Build the release URL for deep linking.
-fn build_release_url(
+pub(crate) fn build_release_url(
     event: &NotificationEvent,
     r: &ReleaseContext,
     forage_url: &str,
@@ -679,6 +913,9 @@ fn aggregate_emoji(event_type: &str) -> &'static str {
         "release_failed" => ":x:",
         "release_started" => ":rocket:",
         "release_annotated" => ":memo:",
+        "drift_detected" => ":warning:",
+        "drift_resolved" => ":white_check_mark:",
+        "schedule_failed" => ":x:",
         _ => ":bell:",
     }
 }
@@ -816,6 +1053,43 @@ mod tests {
         }
     }
 
+    #[test]
+    fn drift_gets_its_own_slack_message() {
+        let event = NotificationEvent {
+            notification_type: "drift_detected".into(),
+            title: "Drift detected: test-org/my-project".into(),
+            ..test_event()
+        };
+        let tasks = route_notification(&event, &[slack_integration("s1")]);
+
+        match &tasks[0] {
+            DispatchTask::Slack { release_id, message, .. } => {
+                assert_eq!(release_id, "drift:test-release:prod-eu");
+                assert_eq!(message.color, "#fd7e14");
+            }
+            _ => panic!("expected Slack task"),
+        }
+    }
+
+    #[test]
+    fn schedule_failure_gets_its_own_slack_message() {
+        let event = NotificationEvent {
+            id: "notif-schedule".into(),
+            notification_type: "schedule_failed".into(),
+            title: "Scheduled release not started: test-org/my-project".into(),
+            ..test_event()
+        };
+        let tasks = route_notification(&event, &[slack_integration("s1")]);
+
+        match &tasks[0] {
+            DispatchTask::Slack { release_id, message, .. } => {
+                assert_eq!(release_id, "schedule:notif-schedule");
+                assert_eq!(message.color, "#dc3545");
+            }
+            _ => panic!("expected Slack task"),
+        }
+    }
+
     #[test]
     fn route_to_multiple_integrations() {
         let event = test_event();
@@ -824,6 +1098,60 @@ mod tests {
         assert_eq!(tasks.len(), 2);
     }
 
+    fn integration_with(id: &str, config: IntegrationConfig) -> Integration {
+        Integration {
+            config,
+            ..webhook_integration(id)
+        }
+    }
+
+    #[test]
+    fn route_to_teams_discord_and_email() {
+        let event = test_event();
+        let integrations = vec![
+            integration_with("t1", IntegrationConfig::Teams { webhook_url: "https://example.webhook.office.com/x".into() }),
+            integration_with("d1", IntegrationConfig::Discord { webhook_url: "https://discord.com/api/webhooks/1/x".into() }),
+            integration_with("e1", IntegrationConfig::Email { recipients: vec!["ops@example.com".into()], digest_interval_minutes: 60 }),
+        ];
+        let tasks = route_notification(&event, &integrations);
+        assert_eq!(tasks.len(), 3);
+        assert!(matches!(&tasks[0], DispatchTask::Teams { webhook_url, .. } if webhook_url.contains("office.com")));
+        assert!(matches!(&tasks[1], DispatchTask::Discord { notification_id, .. } if notification_id == "notif-1"));
+        assert!(matches!(&tasks[2], DispatchTask::EmailDigest { .. }));
+        assert_eq!(tasks[2].integration_id(), "e1");
+    }
+
+    #[test]
+    fn route_to_pagerduty_only_failures_and_successes() {
+        let pd = vec![integration_with("p1", IntegrationConfig::PagerDuty { routing_key: "k".repeat(32) })];
+
+        let failed = test_event();
+        assert!(matches!(&route_notification(&failed, &pd)[..], [DispatchTask::PagerDuty { .. }]));
+
+        let mut succeeded = test_event();
+        succeeded.notification_type = "release_succeeded".into();
+        assert_eq!(route_notification(&succeeded, &pd).len(), 1);
+
+        let mut started = test_event();
+        started.notification_type = "release_started".into();
+        assert!(route_notification(&started, &pd).is_empty());
+
+        let mut bare = test_event();
+        bare.release = None;
+        assert!(route_notification(&bare, &pd).is_empty());
+    }
+
+    #[test]
+    fn dispatch_task_survives_serde_roundtrip() {
+        let event = test_event();
+        for task in route_notification(&event, &[webhook_integration("w1"), slack_integration("s1")]) {
+            let json = serde_json::to_value(&task).unwrap();
+            let parsed: DispatchTask = serde_json::from_value(json).unwrap();
+            assert_eq!(parsed.integration_id(), task.integration_id());
+            assert_eq!(parsed.notification_id(), "notif-1");
+        }
+    }
+
     #[test]
     fn route_to_empty_integrations() {
         let event = test_event();
@@ -941,6 +1269,7 @@ mod tests {
                 release_ids: vec![],
                 approval_status: None,
                 auto_approve: None,
+                batches: vec![],
             },
             PipelineRunStageState {
                 stage_id: "s2".into(),
@@ -957,6 +1286,7 @@ mod tests {
                 release_ids: vec![],
                 approval_status: None,
                 auto_approve: None,
+                batches: vec![],
             },
             PipelineRunStageState {
                 stage_id: "s3".into(),
@@ -973,6 +1303,7 @@ mod tests {
                 release_ids: vec![],
                 approval_status: None,
                 auto_approve: None,
+                batches: vec![],
             },
             PipelineRunStageState {
                 stage_id: "s4".into(),
@@ -989,6 +1320,7 @@ mod tests {
                 release_ids: vec![],
                 approval_status: None,
                 auto_approve: None,
+                batches: vec![],
             },
             PipelineRunStageState {
                 stage_id: "s5".into(),
@@ -1005,6 +1337,7 @@ mod tests {
                 release_ids: vec![],
                 approval_status: None,
                 auto_approve: None,
+                batches: vec![],
             },
         ];
 
@@ -1047,6 +1380,7 @@ mod tests {
             release_ids: vec![],
             approval_status: None,
             auto_approve: None,
+            batches: vec![],
         }];
 
         let blocks = format_pipeline_blocks(&stages);
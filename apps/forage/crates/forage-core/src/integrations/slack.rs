@@ -0,0 +1,227 @@
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+/// Slack rejects replays older than five minutes; we do the same.
+pub const SLACK_SIGNATURE_MAX_AGE_SECS: i64 = 300;
+
+pub const APPROVE_ACTION_ID: &str = "forage_approve";
+pub const REJECT_ACTION_ID: &str = "forage_reject";
+
+/// Verify a Slack request signature (`X-Slack-Signature`, v0 scheme).
+///
+/// The signature is HMAC-SHA256 over `v0:{timestamp}:{body}` keyed with the
+/// app's signing secret. `now` is unix seconds, passed in so tests can pin it.
+pub fn verify_slack_signature(
+    signing_secret: &str,
+    timestamp: &str,
+    body: &[u8],
+    signature: &str,
+    now: i64,
+) -> bool {
+    let Ok(ts) = timestamp.parse::<i64>() else {
+        return false;
+    };
+    if (now - ts).abs() > SLACK_SIGNATURE_MAX_AGE_SECS {
+        return false;
+    }
+    let Some(expected) = signature.strip_prefix("v0=").and_then(hex_decode) else {
+        return false;
+    };
+
+    let mut mac = Hmac::<Sha256>::new_from_slice(signing_secret.as_bytes())
+        .expect("HMAC accepts any key length");
+    mac.update(b"v0:");
+    mac.update(timestamp.as_bytes());
+    mac.update(b":");
+    mac.update(body);
+    mac.verify_slice(&expected).is_ok()
+}
+
+/// Compute the `X-Slack-Signature` header value for a request body.
+pub fn sign_slack_request(signing_secret: &str, timestamp: &str, body: &[u8]) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(signing_secret.as_bytes())
+        .expect("HMAC accepts any key length");
+    mac.update(b"v0:");
+    mac.update(timestamp.as_bytes());
+    mac.update(b":");
+    mac.update(body);
+    let digest = mac.finalize().into_bytes();
+    let mut out = String::from("v0=");
+    for b in digest {
+        out.push_str(&format!("{b:02x}"));
+    }
+    out
+}
+
+fn hex_decode(s: &str) -> Option<Vec<u8>> {
+    if !s.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(s.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+// ── Approval buttons ─────────────────────────────────────────────────
+
+/// What an approve/reject button acts on. Serialized into the button `value`
+/// so the interactivity endpoint needs no server-side lookup.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SlackApprovalTarget {
+    /// A plan stage waiting for its output to be approved.
+    PlanStage {
+        organisation: String,
+        project: String,
+        release_intent_id: String,
+        stage_id: String,
+        environment: String,
+    },
+    /// A deploy held back by an external approval policy.
+    Release {
+        organisation: String,
+        project: String,
+        release_intent_id: String,
+        environment: String,
+    },
+}
+
+impl SlackApprovalTarget {
+    pub fn organisation(&self) -> &str {
+        match self {
+            Self::PlanStage { organisation, .. } | Self::Release { organisation, .. } => organisation,
+        }
+    }
+
+    pub fn environment(&self) -> &str {
+        match self {
+            Self::PlanStage { environment, .. } | Self::Release { environment, .. } => environment,
+        }
+    }
+
+    /// Stable block id so a click can find and replace its own buttons.
+    pub fn block_id(&self) -> String {
+        match self {
+            Self::PlanStage { stage_id, .. } => format!("forage_approval:plan:{stage_id}"),
+            Self::Release { environment, .. } => format!("forage_approval:release:{environment}"),
+        }
+    }
+
+    fn subject(&self) -> String {
+        match self {
+            Self::PlanStage { environment, .. } => format!("Plan for `{environment}`"),
+            Self::Release { environment, .. } => format!("Release to `{environment}`"),
+        }
+    }
+
+    /// Line shown in place of the buttons once someone has acted.
+    pub fn decision_text(&self, approved: bool, slack_user_id: &str) -> String {
+        if approved {
+            format!(":white_check_mark: {} approved by <@{slack_user_id}>", self.subject())
+        } else {
+            format!(":x: {} rejected by <@{slack_user_id}>", self.subject())
+        }
+    }
+}
+
+/// Build the Block Kit blocks offering approve/reject buttons for each target.
+pub fn approval_action_blocks(targets: &[SlackApprovalTarget]) -> Vec<serde_json::Value> {
+    targets
+        .iter()
+        .map(|target| {
+            let value = serde_json::to_string(target).unwrap_or_default();
+            serde_json::json!({
+                "type": "actions",
+                "block_id": target.block_id(),
+                "elements": [
+                    {
+                        "type": "button",
+                        "action_id": APPROVE_ACTION_ID,
+                        "style": "primary",
+                        "text": { "type": "plain_text", "text": format!("Approve {}", target.environment()) },
+                        "value": value,
+                    },
+                    {
+                        "type": "button",
+                        "action_id": REJECT_ACTION_ID,
+                        "style": "danger",
+                        "text": { "type": "plain_text", "text": "Reject" },
+                        "value": value,
+                        "confirm": {
+                            "title": { "type": "plain_text", "text": "Reject?" },
+                            "text": { "type": "mrkdwn", "text": format!("{} will not be deployed.", target.subject()) },
+                            "confirm": { "type": "plain_text", "text": "Reject" },
+                            "deny": { "type": "plain_text", "text": "Cancel" },
+                        },
+                    },
+                ],
+            })
+        })
+        .collect()
+}
+
+/// Replace the actions block with `block_id` by a context line recording the
+/// decision. Returns false if the block was not found.
+pub fn replace_action_block(blocks: &mut [serde_json::Value], block_id: &str, text: &str) -> bool {
+    let Some(block) = blocks
+        .iter_mut()
+        .find(|b| b["type"] == "actions" && b["block_id"] == block_id)
+    else {
+        return false;
+    };
+    *block = serde_json::json!({
+        "type": "context",
+        "elements": [{ "type": "mrkdwn", "text": text }],
+    });
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "8f742231b10e8888abcd99yyyzzz85a5";
+
+    #[test]
+    fn signature_roundtrip_verifies() {
+        let body = b"payload=%7B%7D";
+        let sig = sign_slack_request(SECRET, "1531420618", body);
+        assert!(sig.starts_with("v0="));
+        assert!(verify_slack_signature(SECRET, "1531420618", body, &sig, 1531420618));
+    }
+
+    #[test]
+    fn signature_rejects_tampering_and_stale_timestamps() {
+        let body = b"payload=%7B%7D";
+        let sig = sign_slack_request(SECRET, "1531420618", body);
+        assert!(!verify_slack_signature(SECRET, "1531420618", b"payload=x", &sig, 1531420618));
+        assert!(!verify_slack_signature("other", "1531420618", body, &sig, 1531420618));
+        assert!(!verify_slack_signature(SECRET, "1531420618", body, &sig, 1531420618 + 301));
+        assert!(!verify_slack_signature(SECRET, "1531420618", body, "v0=zz", 1531420618));
+        assert!(!verify_slack_signature(SECRET, "nope", body, &sig, 1531420618));
+    }
+
+    #[test]
+    fn approval_blocks_carry_target_and_can_be_resolved() {
+        let target = SlackApprovalTarget::PlanStage {
+            organisation: "acme".into(),
+            project: "web".into(),
+            release_intent_id: "ri-1".into(),
+            stage_id: "plan-prod".into(),
+            environment: "prod".into(),
+        };
+        let mut blocks = approval_action_blocks(std::slice::from_ref(&target));
+        assert_eq!(blocks.len(), 1);
+        let value = blocks[0]["elements"][0]["value"].as_str().unwrap();
+        let parsed: SlackApprovalTarget = serde_json::from_str(value).unwrap();
+        assert_eq!(parsed, target);
+
+        let text = target.decision_text(true, "U123");
+        assert!(replace_action_block(&mut blocks, &target.block_id(), &text));
+        assert_eq!(blocks[0]["type"], "context");
+        assert!(blocks[0]["elements"][0]["text"].as_str().unwrap().contains("<@U123>"));
+        assert!(!replace_action_block(&mut blocks, &target.block_id(), &text));
+    }
+}
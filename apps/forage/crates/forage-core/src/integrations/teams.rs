@@ -0,0 +1,95 @@
+use super::router::{build_release_url, NotificationEvent};
+
+/// Build a Teams incoming-webhook message carrying an Adaptive Card.
+///
+/// Works with both Workflows ("Post to a channel when a webhook request is
+/// received") and legacy Office 365 connector URLs.
+pub fn format_teams_message(event: &NotificationEvent, forage_url: &str) -> serde_json::Value {
+    let (style, status) = match event.notification_type.as_str() {
+        "release_succeeded" => ("good", "Deployed"),
+        "release_failed" => ("attention", "Failed"),
+        "release_started" => ("accent", "Deploying"),
+        "release_annotated" => ("default", "Annotated"),
+        "drift_detected" => ("warning", "Drifted"),
+        "drift_resolved" => ("good", "In sync"),
+        "schedule_failed" => ("attention", "Schedule failed"),
+        _ => ("default", "Update"),
+    };
+
+    let mut body = vec![
+        serde_json::json!({
+            "type": "TextBlock",
+            "text": event.title,
+            "weight": "Bolder",
+            "size": "Medium",
+            "color": style,
+            "wrap": true,
+        }),
+        serde_json::json!({
+            "type": "TextBlock",
+            "text": format!("{}/{}", event.organisation, event.project),
+            "isSubtle": true,
+            "spacing": "None",
+        }),
+    ];
+
+    let mut actions = Vec::new();
+    if let Some(r) = &event.release {
+        if !r.context_title.is_empty() {
+            body.push(serde_json::json!({
+                "type": "TextBlock",
+                "text": r.context_title,
+                "wrap": true,
+            }));
+        }
+        let mut facts = vec![serde_json::json!({ "title": "Status", "value": status })];
+        if !r.destination.is_empty() {
+            facts.push(serde_json::json!({ "title": "Destination", "value": r.destination }));
+        }
+        if !r.environment.is_empty() {
+            facts.push(serde_json::json!({ "title": "Environment", "value": r.environment }));
+        }
+        if !r.commit_sha.is_empty() {
+            let short = &r.commit_sha[..r.commit_sha.len().min(7)];
+            facts.push(serde_json::json!({
+                "title": "Commit",
+                "value": format!("{short} ({})", r.commit_branch),
+            }));
+        }
+        if !r.source_username.is_empty() {
+            facts.push(serde_json::json!({ "title": "By", "value": r.source_username }));
+        }
+        if let Some(err) = &r.error_message {
+            facts.push(serde_json::json!({ "title": "Error", "value": err }));
+        }
+        body.push(serde_json::json!({ "type": "FactSet", "facts": facts }));
+
+        let url = build_release_url(event, r, forage_url);
+        if !url.is_empty() {
+            actions.push(serde_json::json!({
+                "type": "Action.OpenUrl",
+                "title": "View Release",
+                "url": url,
+            }));
+        }
+    } else if !event.body.is_empty() {
+        body.push(serde_json::json!({ "type": "TextBlock", "text": event.body, "wrap": true }));
+    }
+
+    serde_json::json!({
+        "type": "message",
+        "summary": event.title,
+        "attachments": [{
+            "contentType": "application/vnd.microsoft.card.adaptive",
+            "contentUrl": null,
+            "content": {
+                "$schema": "http://adaptivecards.io/schemas/adaptive-card.json",
+                "type": "AdaptiveCard",
+                "version": "1.4",
+                "msteams": { "width": "Full" },
+                "body": body,
+                "actions": actions,
+            },
+        }],
+    })
+}
@@ -251,6 +251,17 @@ pub struct Environment {
     pub description: Option<String>,
     pub sort_order: i32,
     pub created_at: String,
+    #[serde(default)]
+    pub protection: EnvironmentProtection,
+}
+
+/// Rules forest enforces for every project releasing into an environment.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EnvironmentProtection {
+    pub protected: bool,
+    pub required_prior_environment: Option<String>,
+    pub approver_groups: Vec<String>,
+    pub allowed_branches: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -326,6 +337,24 @@ pub struct PipelineRunStageState {
     pub approval_status: Option<String>,
     #[serde(default)]
     pub auto_approve: Option<bool>,
+    /// Batched deploy stages: progress of each batch, in rollout order.
+    #[serde(default)]
+    pub batches: Vec<PipelineRunBatchState>,
+}
+
+/// Progress of one batch of a batched deploy stage.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PipelineRunBatchState {
+    pub destinations: Vec<String>,
+    pub status: String, // same values as PipelineRunStageState::status
+    pub started_at: Option<String>,
+    pub completed_at: Option<String>,
+    pub wait_until: Option<String>,
+    #[serde(default)]
+    pub release_ids: Vec<String>,
+    pub error_message: Option<String>,
+    #[serde(default)]
+    pub health_check: bool,
 }
 
 /// Combined response from get_destination_states: destinations only.
@@ -408,6 +437,35 @@ pub struct UpdateTriggerInput {
     pub use_pipeline: Option<bool>,
 }
 
+// ── Release schedules ───────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseSchedule {
+    pub id: String,
+    /// Exactly one of `run_at` (a single release) or `cron` (UTC) is set.
+    pub run_at: Option<String>,
+    pub cron: Option<String>,
+    /// When unset, the latest artifact (from `branch`, if set) is released.
+    pub artifact_id: Option<String>,
+    pub branch: Option<String>,
+    pub environments: Vec<String>,
+    pub destinations: Vec<String>,
+    pub use_pipeline: bool,
+    /// "ACTIVE", "COMPLETED" or "CANCELLED".
+    pub status: String,
+    pub next_run_at: Option<String>,
+    pub last_run: Option<ReleaseScheduleRun>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseScheduleRun {
+    pub ran_at: String,
+    /// "RELEASED", "SKIPPED", "BLOCKED" or "FAILED".
+    pub outcome: String,
+    pub message: String,
+    pub release_intent_id: Option<String>,
+}
+
 // ── Policies (deployment gating) ────────────────────────────────────
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -485,11 +543,31 @@ pub struct PipelineStage {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum PipelineStageConfig {
-    Deploy { environment: String },
+    Deploy {
+        environment: String,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        batches: Vec<DeployBatch>,
+    },
     Wait { duration_seconds: i64 },
     Plan { environment: String, auto_approve: bool },
 }
 
+/// One batch of a deploy stage's rollout; set one of `destinations`,
+/// `labels` or `percentage`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DeployBatch {
+    #[serde(default)]
+    pub destinations: Vec<String>,
+    #[serde(default)]
+    pub labels: std::collections::BTreeMap<String, String>,
+    #[serde(default)]
+    pub percentage: Option<u32>,
+    #[serde(default)]
+    pub wait_seconds: i64,
+    #[serde(default)]
+    pub health_check: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReleasePipeline {
     pub id: String,
@@ -602,6 +680,15 @@ pub trait ForestPlatform: Send + Sync {
         role: &str,
     ) -> Result<OrgMember, PlatformError>;
 
+    /// Mirror the organisation's SSO enforcement flag to forest, which
+    /// refuses non-SSO logins for members while it is set.
+    async fn set_sso_enforcement(
+        &self,
+        access_token: &str,
+        organisation_id: &str,
+        enforced: bool,
+    ) -> Result<(), PlatformError>;
+
     async fn get_artifact_by_slug(
         &self,
         access_token: &str,
@@ -635,6 +722,7 @@ pub trait ForestPlatform: Send + Sync {
         id: &str,
         description: Option<&str>,
         sort_order: Option<i32>,
+        protection: Option<&EnvironmentProtection>,
     ) -> Result<Environment, PlatformError>;
 
     async fn create_destination(
@@ -716,6 +804,14 @@ pub trait ForestPlatform: Send + Sync {
         name: &str,
     ) -> Result<(), PlatformError>;
 
+    /// Active schedules, soonest first.
+    async fn list_release_schedules(
+        &self,
+        access_token: &str,
+        organisation: &str,
+        project: &str,
+    ) -> Result<Vec<ReleaseSchedule>, PlatformError>;
+
     async fn list_policies(
         &self,
         access_token: &str,
@@ -809,6 +905,7 @@ pub trait ForestPlatform: Send + Sync {
         release_intent_id: Option<&str>,
     ) -> Result<Vec<PolicyEvaluation>, PlatformError>;
 
+    #[allow(clippy::too_many_arguments)]
     async fn approve_release(
         &self,
         access_token: &str,
@@ -818,8 +915,10 @@ pub trait ForestPlatform: Send + Sync {
         target_environment: &str,
         comment: Option<&str>,
         force_bypass: bool,
+        on_behalf_of_user_id: Option<&str>,
     ) -> Result<ApprovalState, PlatformError>;
 
+    #[allow(clippy::too_many_arguments)]
     async fn reject_release(
         &self,
         access_token: &str,
@@ -828,6 +927,7 @@ pub trait ForestPlatform: Send + Sync {
         release_intent_id: &str,
         target_environment: &str,
         comment: Option<&str>,
+        on_behalf_of_user_id: Option<&str>,
     ) -> Result<ApprovalState, PlatformError>;
 
     async fn get_approval_state(
@@ -844,6 +944,7 @@ pub trait ForestPlatform: Send + Sync {
         access_token: &str,
         release_intent_id: &str,
         stage_id: &str,
+        on_behalf_of_user_id: Option<&str>,
     ) -> Result<(), PlatformError>;
 
     async fn reject_plan_stage(
@@ -852,6 +953,7 @@ pub trait ForestPlatform: Send + Sync {
         release_intent_id: &str,
         stage_id: &str,
         reason: Option<&str>,
+        on_behalf_of_user_id: Option<&str>,
     ) -> Result<(), PlatformError>;
 
     async fn get_plan_output(
@@ -860,6 +962,14 @@ pub trait ForestPlatform: Send + Sync {
         release_intent_id: &str,
         stage_id: &str,
     ) -> Result<PlanOutput, PlatformError>;
+
+    /// Cancel a queued or running release.
+    async fn cancel_release(
+        &self,
+        access_token: &str,
+        release_id: &str,
+        reason: Option<&str>,
+    ) -> Result<(), PlatformError>;
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -2,7 +2,7 @@ use std::path::{Path, PathBuf};
 
 use chrono::{Duration, Utc};
 
-use super::{SessionData, SessionError, SessionId, SessionStore};
+use super::{summarize_for_user, SessionData, SessionError, SessionId, SessionStore, SessionSummary};
 
 /// File-based session store. Each session is a JSON file in a directory.
 /// Suitable for local development — sessions survive server restarts.
@@ -54,6 +54,25 @@ impl FileSessionStore {
         }
     }
 
+    /// All readable sessions in the directory, keyed by id.
+    fn read_all(&self) -> Vec<(SessionId, SessionData)> {
+        let Ok(entries) = std::fs::read_dir(&self.dir) else {
+            return vec![];
+        };
+        entries
+            .flatten()
+            .filter_map(|entry| {
+                let path = entry.path();
+                if path.extension().and_then(|e| e.to_str()) != Some("json") {
+                    return None;
+                }
+                let id = path.file_stem()?.to_str()?.to_string();
+                let data = serde_json::from_str(&std::fs::read_to_string(&path).ok()?).ok()?;
+                Some((SessionId::from_raw(id), data))
+            })
+            .collect()
+    }
+
     pub fn session_count(&self) -> usize {
         std::fs::read_dir(&self.dir)
             .map(|e| {
@@ -112,4 +131,26 @@ impl SessionStore for FileSessionStore {
             Err(e) => Err(SessionError::Store(format!("delete error: {e}"))),
         }
     }
+
+    async fn list_for_user(&self, user_id: &str) -> Result<Vec<SessionSummary>, SessionError> {
+        let sessions = self.read_all();
+        Ok(summarize_for_user(sessions.iter().map(|(id, d)| (id, d)), user_id))
+    }
+
+    async fn delete_for_user(
+        &self,
+        user_id: &str,
+        keep: Option<&SessionId>,
+    ) -> Result<u64, SessionError> {
+        let mut deleted = 0;
+        for (id, _) in self
+            .read_all()
+            .into_iter()
+            .filter(|(id, d)| Some(id) != keep && d.user.as_ref().is_some_and(|u| u.user_id == user_id))
+        {
+            self.delete(&id).await?;
+            deleted += 1;
+        }
+        Ok(deleted)
+    }
 }
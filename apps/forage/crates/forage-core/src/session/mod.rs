@@ -115,6 +115,15 @@ impl SessionData {
     }
 }
 
+/// A session as listed in session management. `id` is the session's bearer
+/// credential: compare against it, never render it.
+#[derive(Debug, Clone)]
+pub struct SessionSummary {
+    pub id: SessionId,
+    pub created_at: DateTime<Utc>,
+    pub last_seen_at: DateTime<Utc>,
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum SessionError {
     #[error("session store error: {0}")]
@@ -128,6 +137,32 @@ pub trait SessionStore: Send + Sync {
     async fn get(&self, id: &SessionId) -> Result<Option<SessionData>, SessionError>;
     async fn update(&self, id: &SessionId, data: SessionData) -> Result<(), SessionError>;
     async fn delete(&self, id: &SessionId) -> Result<(), SessionError>;
+    /// List a user's sessions, most recently active first.
+    async fn list_for_user(&self, user_id: &str) -> Result<Vec<SessionSummary>, SessionError>;
+    /// Delete all of a user's sessions except `keep`. Returns how many were deleted.
+    async fn delete_for_user(
+        &self,
+        user_id: &str,
+        keep: Option<&SessionId>,
+    ) -> Result<u64, SessionError>;
+}
+
+/// Summaries of the sessions in `sessions` that belong to `user_id`, most
+/// recently active first. Shared by the stores that hold full session data.
+fn summarize_for_user<'a>(
+    sessions: impl Iterator<Item = (&'a SessionId, &'a SessionData)>,
+    user_id: &str,
+) -> Vec<SessionSummary> {
+    let mut out: Vec<SessionSummary> = sessions
+        .filter(|(_, d)| d.user.as_ref().is_some_and(|u| u.user_id == user_id))
+        .map(|(id, d)| SessionSummary {
+            id: id.clone(),
+            created_at: d.created_at,
+            last_seen_at: d.last_seen_at,
+        })
+        .collect();
+    out.sort_by_key(|s| std::cmp::Reverse(s.last_seen_at));
+    out
 }
 
 #[cfg(test)]
@@ -258,6 +293,44 @@ mod tests {
         store.delete(&id).await.unwrap();
     }
 
+    #[tokio::test]
+    async fn in_memory_store_lists_and_deletes_sessions_for_user() {
+        let store = InMemorySessionStore::new();
+        let alice = |last_seen_mins_ago: i64| {
+            let mut d = make_session_data();
+            d.user = Some(CachedUser {
+                user_id: "user-alice".into(),
+                username: "alice".into(),
+                profile_picture_url: None,
+                emails: vec![],
+                orgs: vec![],
+            });
+            d.last_seen_at = Utc::now() - chrono::Duration::minutes(last_seen_mins_ago);
+            d
+        };
+        let current = store.create(alice(0)).await.unwrap();
+        let laptop = store.create(alice(60)).await.unwrap();
+        let _phone = store.create(alice(5)).await.unwrap();
+        let anonymous = store.create(make_session_data()).await.unwrap();
+
+        let listed = store.list_for_user("user-alice").await.unwrap();
+        assert_eq!(listed.len(), 3);
+        assert_eq!(listed[0].id, current);
+        assert_eq!(listed[2].id, laptop);
+
+        let deleted = store
+            .delete_for_user("user-alice", Some(&current))
+            .await
+            .unwrap();
+        assert_eq!(deleted, 2);
+        assert!(store.get(&current).await.unwrap().is_some());
+        assert!(store.get(&laptop).await.unwrap().is_none());
+        assert!(store.get(&anonymous).await.unwrap().is_some());
+
+        assert_eq!(store.delete_for_user("user-alice", None).await.unwrap(), 1);
+        assert!(store.list_for_user("user-alice").await.unwrap().is_empty());
+    }
+
     fn make_session_data() -> SessionData {
         SessionData {
             access_token: "test-access".into(),
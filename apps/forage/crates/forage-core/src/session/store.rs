@@ -3,7 +3,7 @@ use std::sync::RwLock;
 
 use chrono::{Duration, Utc};
 
-use super::{SessionData, SessionError, SessionId, SessionStore};
+use super::{summarize_for_user, SessionData, SessionError, SessionId, SessionStore, SessionSummary};
 
 /// In-memory session store. Suitable for single-instance deployments.
 /// Sessions are lost on server restart.
@@ -63,4 +63,22 @@ impl SessionStore for InMemorySessionStore {
         sessions.remove(id);
         Ok(())
     }
+
+    async fn list_for_user(&self, user_id: &str) -> Result<Vec<SessionSummary>, SessionError> {
+        let sessions = self.sessions.read().unwrap();
+        Ok(summarize_for_user(sessions.iter(), user_id))
+    }
+
+    async fn delete_for_user(
+        &self,
+        user_id: &str,
+        keep: Option<&SessionId>,
+    ) -> Result<u64, SessionError> {
+        let mut sessions = self.sessions.write().unwrap();
+        let before = sessions.len();
+        sessions.retain(|id, data| {
+            Some(id) == keep || data.user.as_ref().is_none_or(|u| u.user_id != user_id)
+        });
+        Ok((before - sessions.len()) as u64)
+    }
 }
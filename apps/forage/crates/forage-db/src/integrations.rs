@@ -1,6 +1,6 @@
 use forage_core::integrations::{
-    CreateIntegrationInput, DeliveryStatus, Integration, IntegrationConfig, IntegrationError,
-    IntegrationStore, IntegrationType, NotificationDelivery, NotificationRule, SlackMessageRef,
+    CreateIntegrationInput, DeliveryAttempt, DeliveryStatus, DigestBacklog, DigestEntry, Integration, IntegrationConfig, IntegrationError,
+    IntegrationStore, IntegrationType, NotificationDelivery, NotificationFilter, NotificationRule, SlackMessageRef,
     SlackUserLink, NOTIFICATION_TYPES,
 };
 use sqlx::PgPool;
@@ -57,7 +57,7 @@ impl PgIntegrationStore {
 
 /// Simple XOR obfuscation. This is NOT production-grade encryption.
 /// TODO: Replace with AES-256-GCM when aes-gcm dependency is added.
-fn xor_bytes(data: &[u8], key: &[u8]) -> Vec<u8> {
+pub(crate) fn xor_bytes(data: &[u8], key: &[u8]) -> Vec<u8> {
     if key.is_empty() {
         return data.to_vec();
     }
@@ -146,15 +146,16 @@ impl IntegrationStore for PgIntegrationStore {
             }
         })?;
 
-        // Create default notification rules (all enabled)
+        // Create default notification rules
         for nt in NOTIFICATION_TYPES {
             sqlx::query(
                 "INSERT INTO notification_rules (id, integration_id, notification_type, enabled)
-                 VALUES ($1, $2, $3, true)",
+                 VALUES ($1, $2, $3, $4)",
             )
             .bind(Uuid::new_v4())
             .bind(id)
             .bind(*nt)
+            .bind(input.integration_type.default_rule_enabled(nt))
             .execute(&self.pool)
             .await
             .map_err(|e| IntegrationError::Store(e.to_string()))?;
@@ -318,6 +319,61 @@ impl IntegrationStore for PgIntegrationStore {
         Ok(())
     }
 
+    async fn get_notification_filter(
+        &self,
+        integration_id: &str,
+    ) -> Result<NotificationFilter, IntegrationError> {
+        let uuid: Uuid = integration_id
+            .parse()
+            .map_err(|_| IntegrationError::NotFound(integration_id.to_string()))?;
+
+        let row: Option<(serde_json::Value,)> =
+            sqlx::query_as("SELECT filter FROM notification_filters WHERE integration_id = $1")
+                .bind(uuid)
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(|e| IntegrationError::Store(e.to_string()))?;
+
+        match row {
+            Some((filter,)) => serde_json::from_value(filter)
+                .map_err(|e| IntegrationError::Store(format!("invalid notification filter: {e}"))),
+            None => Ok(NotificationFilter::default()),
+        }
+    }
+
+    async fn set_notification_filter(
+        &self,
+        integration_id: &str,
+        filter: &NotificationFilter,
+    ) -> Result<(), IntegrationError> {
+        let uuid: Uuid = integration_id
+            .parse()
+            .map_err(|_| IntegrationError::NotFound(integration_id.to_string()))?;
+
+        if filter.is_empty() {
+            sqlx::query("DELETE FROM notification_filters WHERE integration_id = $1")
+                .bind(uuid)
+                .execute(&self.pool)
+                .await
+                .map_err(|e| IntegrationError::Store(e.to_string()))?;
+            return Ok(());
+        }
+
+        let value = serde_json::to_value(filter)
+            .map_err(|e| IntegrationError::Store(e.to_string()))?;
+        sqlx::query(
+            "INSERT INTO notification_filters (integration_id, filter, updated_at)
+             VALUES ($1, $2, now())
+             ON CONFLICT (integration_id) DO UPDATE SET filter = $2, updated_at = now()",
+        )
+        .bind(uuid)
+        .bind(value)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| IntegrationError::Store(e.to_string()))?;
+        Ok(())
+    }
+
     async fn record_delivery(
         &self,
         integration_id: &str,
@@ -354,33 +410,160 @@ impl IntegrationStore for PgIntegrationStore {
             .parse()
             .map_err(|_| IntegrationError::NotFound(integration_id.to_string()))?;
 
-        let rows: Vec<DeliveryRow> = sqlx::query_as(
-            "SELECT id, integration_id, notification_id, status, error_message, attempted_at
+        let rows: Vec<DeliveryRow> = sqlx::query_as(&format!(
+            "SELECT {DELIVERY_COLUMNS}
              FROM notification_deliveries
              WHERE integration_id = $1
-             ORDER BY attempted_at DESC
-             LIMIT $2",
-        )
+             ORDER BY created_at DESC
+             LIMIT $2"
+        ))
         .bind(uuid)
         .bind(limit as i64)
         .fetch_all(&self.pool)
         .await
         .map_err(|e| IntegrationError::Store(e.to_string()))?;
 
-        Ok(rows
-            .into_iter()
-            .map(|r| {
-                let status = DeliveryStatus::parse(&r.status).unwrap_or(DeliveryStatus::Pending);
-                NotificationDelivery {
-                    id: r.id.to_string(),
-                    integration_id: r.integration_id.to_string(),
-                    notification_id: r.notification_id,
-                    status,
-                    error_message: r.error_message,
-                    attempted_at: r.attempted_at.to_rfc3339(),
-                }
-            })
-            .collect())
+        Ok(rows.into_iter().map(NotificationDelivery::from).collect())
+    }
+
+    // ── Delivery queue ───────────────────────────────────────────
+
+    async fn enqueue_delivery(
+        &self,
+        integration_id: &str,
+        notification_id: &str,
+        task: serde_json::Value,
+        lease_until: chrono::DateTime<chrono::Utc>,
+    ) -> Result<NotificationDelivery, IntegrationError> {
+        let uuid: Uuid = integration_id
+            .parse()
+            .map_err(|_| IntegrationError::NotFound(integration_id.to_string()))?;
+
+        let row: DeliveryRow = sqlx::query_as(&format!(
+            "INSERT INTO notification_deliveries (id, integration_id, notification_id, status, attempts, next_attempt_at, task, attempted_at, created_at)
+             VALUES ($1, $2, $3, 'pending', 0, $4, $5, now(), now())
+             RETURNING {DELIVERY_COLUMNS}"
+        ))
+        .bind(Uuid::new_v4())
+        .bind(uuid)
+        .bind(notification_id)
+        .bind(lease_until)
+        .bind(task)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| IntegrationError::Store(e.to_string()))?;
+
+        Ok(row.into())
+    }
+
+    async fn record_delivery_attempt(
+        &self,
+        delivery_id: &str,
+        attempt: &DeliveryAttempt,
+    ) -> Result<(), IntegrationError> {
+        let id: Uuid = delivery_id
+            .parse()
+            .map_err(|_| IntegrationError::NotFound(delivery_id.to_string()))?;
+
+        let result = sqlx::query(
+            "UPDATE notification_deliveries
+             SET attempts = attempts + 1, status = $2, response_code = $3, error_message = $4,
+                 next_attempt_at = $5, attempted_at = now()
+             WHERE id = $1",
+        )
+        .bind(id)
+        .bind(attempt.status.as_str())
+        .bind(attempt.response_code.map(i32::from))
+        .bind(attempt.error_message.as_deref())
+        .bind(attempt.next_attempt_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| IntegrationError::Store(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(IntegrationError::NotFound(delivery_id.to_string()));
+        }
+        Ok(())
+    }
+
+    async fn claim_due_deliveries(
+        &self,
+        now: chrono::DateTime<chrono::Utc>,
+        lease_until: chrono::DateTime<chrono::Utc>,
+        limit: usize,
+    ) -> Result<Vec<NotificationDelivery>, IntegrationError> {
+        // Heads are the oldest undelivered entry per integration; SKIP LOCKED
+        // lets several forage replicas drain the queue without double sends.
+        let rows: Vec<DeliveryRow> = sqlx::query_as(&format!(
+            "WITH heads AS (
+                 SELECT DISTINCT ON (integration_id) id, next_attempt_at
+                 FROM notification_deliveries
+                 WHERE status IN ('pending', 'failed') AND task IS NOT NULL
+                 ORDER BY integration_id, created_at
+             ),
+             due AS (
+                 SELECT d.id
+                 FROM notification_deliveries d
+                 JOIN heads h ON h.id = d.id
+                 WHERE h.next_attempt_at IS NULL OR h.next_attempt_at <= $1
+                 ORDER BY h.next_attempt_at
+                 LIMIT $3
+                 FOR UPDATE OF d SKIP LOCKED
+             )
+             UPDATE notification_deliveries
+             SET next_attempt_at = $2
+             WHERE id IN (SELECT id FROM due)
+             RETURNING {DELIVERY_COLUMNS}"
+        ))
+        .bind(now)
+        .bind(lease_until)
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| IntegrationError::Store(e.to_string()))?;
+
+        Ok(rows.into_iter().map(NotificationDelivery::from).collect())
+    }
+
+    async fn get_delivery(
+        &self,
+        integration_id: &str,
+        delivery_id: &str,
+    ) -> Result<NotificationDelivery, IntegrationError> {
+        let not_found = || IntegrationError::NotFound(delivery_id.to_string());
+        let iid: Uuid = integration_id.parse().map_err(|_| not_found())?;
+        let id: Uuid = delivery_id.parse().map_err(|_| not_found())?;
+
+        let row: Option<DeliveryRow> = sqlx::query_as(&format!(
+            "SELECT {DELIVERY_COLUMNS} FROM notification_deliveries WHERE id = $1 AND integration_id = $2"
+        ))
+        .bind(id)
+        .bind(iid)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| IntegrationError::Store(e.to_string()))?;
+
+        row.map(NotificationDelivery::from).ok_or_else(not_found)
+    }
+
+    async fn redeliver(
+        &self,
+        integration_id: &str,
+        delivery_id: &str,
+    ) -> Result<NotificationDelivery, IntegrationError> {
+        let original = self.get_delivery(integration_id, delivery_id).await?;
+        let Some(task) = original.task else {
+            return Err(IntegrationError::InvalidInput(
+                "delivery has no stored payload".to_string(),
+            ));
+        };
+        self.enqueue_delivery(
+            integration_id,
+            &original.notification_id,
+            task,
+            chrono::Utc::now(),
+        )
+        .await
     }
 
     async fn list_matching_integrations(
@@ -452,6 +635,33 @@ impl IntegrationStore for PgIntegrationStore {
         }))
     }
 
+    async fn get_slack_user_link_by_slack_user(
+        &self,
+        team_id: &str,
+        slack_user_id: &str,
+    ) -> Result<Option<SlackUserLink>, IntegrationError> {
+        let row: Option<SlackUserLinkRow> = sqlx::query_as(
+            "SELECT id, user_id, team_id, team_name, slack_user_id, slack_username, created_at
+             FROM slack_user_links WHERE team_id = $1 AND slack_user_id = $2
+             ORDER BY created_at DESC LIMIT 1",
+        )
+        .bind(team_id)
+        .bind(slack_user_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| IntegrationError::Store(e.to_string()))?;
+
+        Ok(row.map(|r| SlackUserLink {
+            id: r.id.to_string(),
+            user_id: r.user_id,
+            team_id: r.team_id,
+            team_name: r.team_name,
+            slack_user_id: r.slack_user_id,
+            slack_username: r.slack_username,
+            created_at: r.created_at.to_rfc3339(),
+        }))
+    }
+
     async fn upsert_slack_user_link(
         &self,
         link: &SlackUserLink,
@@ -581,6 +791,94 @@ impl IntegrationStore for PgIntegrationStore {
         .map_err(|e| IntegrationError::Store(e.to_string()))?;
         Ok(())
     }
+
+    // ── Email digests ────────────────────────────────────────────
+
+    async fn enqueue_digest_entry(&self, entry: &DigestEntry) -> Result<(), IntegrationError> {
+        let iid = Uuid::parse_str(&entry.integration_id)
+            .map_err(|e| IntegrationError::Store(e.to_string()))?;
+        sqlx::query(
+            "INSERT INTO email_digest_entries (id, integration_id, organisation, notification_id, notification_type, project, title, body)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
+        )
+        .bind(Uuid::parse_str(&entry.id).map_err(|e| IntegrationError::Store(e.to_string()))?)
+        .bind(iid)
+        .bind(&entry.organisation)
+        .bind(&entry.notification_id)
+        .bind(&entry.notification_type)
+        .bind(&entry.project)
+        .bind(&entry.title)
+        .bind(&entry.body)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| IntegrationError::Store(e.to_string()))?;
+        Ok(())
+    }
+
+    async fn list_digest_backlog(&self) -> Result<Vec<DigestBacklog>, IntegrationError> {
+        let rows: Vec<(Uuid, String, chrono::DateTime<chrono::Utc>)> = sqlx::query_as(
+            "SELECT integration_id, MIN(organisation), MIN(created_at)
+             FROM email_digest_entries
+             GROUP BY integration_id",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| IntegrationError::Store(e.to_string()))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(integration_id, organisation, oldest_at)| DigestBacklog {
+                integration_id: integration_id.to_string(),
+                organisation,
+                oldest_at: oldest_at.to_rfc3339(),
+            })
+            .collect())
+    }
+
+    async fn take_digest_entries(
+        &self,
+        integration_id: &str,
+    ) -> Result<Vec<DigestEntry>, IntegrationError> {
+        let iid =
+            Uuid::parse_str(integration_id).map_err(|e| IntegrationError::Store(e.to_string()))?;
+        let mut rows: Vec<DigestEntryRow> = sqlx::query_as(
+            "DELETE FROM email_digest_entries WHERE integration_id = $1
+             RETURNING id, integration_id, organisation, notification_id, notification_type, project, title, body, created_at",
+        )
+        .bind(iid)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| IntegrationError::Store(e.to_string()))?;
+        rows.sort_by_key(|r| r.created_at);
+
+        Ok(rows
+            .into_iter()
+            .map(|r| DigestEntry {
+                id: r.id.to_string(),
+                integration_id: r.integration_id.to_string(),
+                organisation: r.organisation,
+                notification_id: r.notification_id,
+                notification_type: r.notification_type,
+                project: r.project,
+                title: r.title,
+                body: r.body,
+                created_at: r.created_at.to_rfc3339(),
+            })
+            .collect())
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct DigestEntryRow {
+    id: Uuid,
+    integration_id: Uuid,
+    organisation: String,
+    notification_id: String,
+    notification_type: String,
+    project: String,
+    title: String,
+    body: String,
+    created_at: chrono::DateTime<chrono::Utc>,
 }
 
 #[derive(sqlx::FromRow)]
@@ -629,6 +927,8 @@ struct RuleRow {
     enabled: bool,
 }
 
+const DELIVERY_COLUMNS: &str = "id, integration_id, notification_id, status, error_message, attempted_at, attempts, response_code, next_attempt_at, task, created_at";
+
 #[derive(sqlx::FromRow)]
 struct DeliveryRow {
     id: Uuid,
@@ -637,4 +937,27 @@ struct DeliveryRow {
     status: String,
     error_message: Option<String>,
     attempted_at: chrono::DateTime<chrono::Utc>,
+    attempts: i32,
+    response_code: Option<i32>,
+    next_attempt_at: Option<chrono::DateTime<chrono::Utc>>,
+    task: Option<serde_json::Value>,
+    created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<DeliveryRow> for NotificationDelivery {
+    fn from(r: DeliveryRow) -> Self {
+        NotificationDelivery {
+            id: r.id.to_string(),
+            integration_id: r.integration_id.to_string(),
+            notification_id: r.notification_id,
+            status: DeliveryStatus::parse(&r.status).unwrap_or(DeliveryStatus::Pending),
+            error_message: r.error_message,
+            attempted_at: r.attempted_at.to_rfc3339(),
+            attempts: r.attempts.max(0) as u32,
+            response_code: r.response_code.and_then(|c| u16::try_from(c).ok()),
+            next_attempt_at: r.next_attempt_at.map(|t| t.to_rfc3339()),
+            task: r.task,
+            created_at: r.created_at.to_rfc3339(),
+        }
+    }
 }
@@ -2,11 +2,13 @@ mod integrations;
 mod magic_link;
 mod profile_pictures;
 mod sessions;
+mod sso;
 
 pub use integrations::PgIntegrationStore;
 pub use magic_link::PgMagicLinkStore;
 pub use profile_pictures::{PgProfilePictureStore, ProfilePicture};
 pub use sessions::PgSessionStore;
+pub use sso::PgSsoConfigStore;
 pub use sqlx::PgPool;
 
 /// Run all pending migrations.
@@ -0,0 +1,21 @@
+CREATE TABLE IF NOT EXISTS org_sso_configs (
+    organisation TEXT PRIMARY KEY,
+    organisation_id TEXT NOT NULL,
+    protocol TEXT NOT NULL,
+    -- Provider settings (including the OIDC client secret), encrypted
+    -- with the integration encryption key.
+    provider_encrypted BYTEA NOT NULL,
+    enforce_sso BOOLEAN NOT NULL DEFAULT false,
+    jit_provisioning BOOLEAN NOT NULL DEFAULT false,
+    default_role TEXT NOT NULL DEFAULT 'member',
+    updated_by TEXT NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+-- An email domain routes to at most one organisation's IdP.
+CREATE TABLE IF NOT EXISTS org_sso_domains (
+    domain TEXT PRIMARY KEY,
+    organisation TEXT NOT NULL REFERENCES org_sso_configs(organisation) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_org_sso_domains_org ON org_sso_domains(organisation);
@@ -0,0 +1,14 @@
+-- Events queued for email digest integrations, flushed by the digest sender
+CREATE TABLE IF NOT EXISTS email_digest_entries (
+    id UUID PRIMARY KEY,
+    integration_id UUID NOT NULL REFERENCES integrations(id) ON DELETE CASCADE,
+    organisation TEXT NOT NULL,
+    notification_id TEXT NOT NULL,
+    notification_type TEXT NOT NULL,
+    project TEXT NOT NULL DEFAULT '',
+    title TEXT NOT NULL DEFAULT '',
+    body TEXT NOT NULL DEFAULT '',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_email_digest_entries_integration ON email_digest_entries(integration_id, created_at);
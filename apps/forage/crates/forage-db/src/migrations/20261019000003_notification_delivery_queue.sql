@@ -0,0 +1,12 @@
+-- Turn the delivery log into a persistent per-integration delivery queue
+ALTER TABLE notification_deliveries ADD COLUMN IF NOT EXISTS attempts INT NOT NULL DEFAULT 1;
+ALTER TABLE notification_deliveries ADD COLUMN IF NOT EXISTS response_code INT;
+ALTER TABLE notification_deliveries ADD COLUMN IF NOT EXISTS next_attempt_at TIMESTAMPTZ;
+ALTER TABLE notification_deliveries ADD COLUMN IF NOT EXISTS task JSONB;
+ALTER TABLE notification_deliveries ADD COLUMN IF NOT EXISTS created_at TIMESTAMPTZ NOT NULL DEFAULT now();
+
+UPDATE notification_deliveries SET created_at = attempted_at;
+
+CREATE INDEX idx_deliveries_integration_created ON notification_deliveries(integration_id, created_at DESC);
+CREATE INDEX idx_deliveries_queue ON notification_deliveries(integration_id, created_at)
+    WHERE status IN ('pending', 'failed') AND task IS NOT NULL;
@@ -0,0 +1,6 @@
+-- Per-integration project/environment/destination filters and message overrides
+CREATE TABLE IF NOT EXISTS notification_filters (
+    integration_id UUID PRIMARY KEY REFERENCES integrations(id) ON DELETE CASCADE,
+    filter JSONB NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
@@ -0,0 +1,2 @@
+-- Session management lists and revokes sessions per user
+CREATE INDEX IF NOT EXISTS idx_sessions_user_id ON sessions (user_id);
@@ -0,0 +1,7 @@
+-- Drift notifications: give existing integrations rules for the new types.
+-- New integrations get them from NOTIFICATION_TYPES on create.
+INSERT INTO notification_rules (integration_id, notification_type, enabled)
+SELECT i.id, t.notification_type, true
+FROM integrations i
+CROSS JOIN (VALUES ('drift_detected'), ('drift_resolved')) AS t(notification_type)
+ON CONFLICT (integration_id, notification_type) DO NOTHING;
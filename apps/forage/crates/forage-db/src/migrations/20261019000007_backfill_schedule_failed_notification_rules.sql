@@ -0,0 +1,6 @@
+-- Schedule failure notifications: give existing integrations a rule for the
+-- new type. PagerDuty doesn't act on it, so its rule starts disabled.
+INSERT INTO notification_rules (integration_id, notification_type, enabled)
+SELECT i.id, 'schedule_failed', i.integration_type <> 'pagerduty'
+FROM integrations i
+ON CONFLICT (integration_id, notification_type) DO NOTHING;
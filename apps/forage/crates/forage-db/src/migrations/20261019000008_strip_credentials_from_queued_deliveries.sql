@@ -0,0 +1,15 @@
+-- Queued deliveries used to store the whole dispatch task, including
+-- webhook secrets and headers, Slack tokens, PagerDuty routing keys and
+-- webhook URLs, in plaintext. They now store only the message; the
+-- integration config is read (and decrypted) at each attempt.
+UPDATE notification_deliveries
+SET task = CASE task->>'kind'
+    WHEN 'webhook' THEN jsonb_build_object('kind', 'webhook', 'payload', task->'payload')
+    WHEN 'slack_dm' THEN jsonb_build_object(
+        'kind', 'slack_dm',
+        'slack_user_id', task->'slack_user_id',
+        'event', task->'event'
+    )
+    ELSE jsonb_build_object('kind', 'channel', 'event', task->'event')
+END
+WHERE task IS NOT NULL;
@@ -2,13 +2,21 @@ use std::time::Duration;
 
 use chrono::{DateTime, Utc};
 use forage_core::auth::UserEmail;
-use forage_core::session::{CachedOrg, CachedUser, SessionData, SessionError, SessionId, SessionStore};
+use forage_core::session::{
+    CachedOrg, CachedUser, SessionData, SessionError, SessionId, SessionStore, SessionSummary,
+};
 use moka::future::Cache;
 use sqlx::PgPool;
 
+/// Upper bound on how stale a cached session may be across replicas.
+const CACHE_TTL: Duration = Duration::from_secs(30);
+
 /// PostgreSQL-backed session store with a Moka write-through cache.
 /// Reads check the cache first, falling back to Postgres on miss.
 /// Writes update both cache and Postgres atomically.
+///
+/// Cache entries live at most `CACHE_TTL`, which bounds how long a session
+/// deleted through another replica keeps working on this one.
 pub struct PgSessionStore {
     pool: PgPool,
     cache: Cache<String, SessionData>,
@@ -18,7 +26,7 @@ impl PgSessionStore {
     pub fn new(pool: PgPool) -> Self {
         let cache = Cache::builder()
             .max_capacity(10_000)
-            .time_to_idle(Duration::from_secs(30 * 60)) // evict after 30min idle
+            .time_to_live(CACHE_TTL)
             .build();
         Self { pool, cache }
     }
@@ -135,6 +143,50 @@ impl SessionStore for PgSessionStore {
 
         Ok(())
     }
+
+    async fn list_for_user(&self, user_id: &str) -> Result<Vec<SessionSummary>, SessionError> {
+        let rows: Vec<(String, DateTime<Utc>, DateTime<Utc>)> = sqlx::query_as(
+            "SELECT session_id, created_at, last_seen_at
+             FROM sessions WHERE user_id = $1
+             ORDER BY last_seen_at DESC",
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| SessionError::Store(e.to_string()))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(id, created_at, last_seen_at)| SessionSummary {
+                id: SessionId::from_raw(id),
+                created_at,
+                last_seen_at,
+            })
+            .collect())
+    }
+
+    async fn delete_for_user(
+        &self,
+        user_id: &str,
+        keep: Option<&SessionId>,
+    ) -> Result<u64, SessionError> {
+        let deleted: Vec<(String,)> = sqlx::query_as(
+            "DELETE FROM sessions
+             WHERE user_id = $1 AND ($2::TEXT IS NULL OR session_id <> $2)
+             RETURNING session_id",
+        )
+        .bind(user_id)
+        .bind(keep.map(|k| k.as_str()))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| SessionError::Store(e.to_string()))?;
+
+        for (id,) in &deleted {
+            self.cache.invalidate(id.as_str()).await;
+        }
+
+        Ok(deleted.len() as u64)
+    }
 }
 
 /// Extract user fields for SQL binding, shared by create and update.
@@ -0,0 +1,188 @@
+use forage_core::auth::sso::{OrgSsoConfig, SsoConfigStore, SsoError, SsoProvider};
+use sqlx::PgPool;
+
+use crate::integrations::xor_bytes;
+
+/// PostgreSQL-backed SSO configuration store. Provider settings are
+/// encrypted with the same key as integration configs.
+pub struct PgSsoConfigStore {
+    pool: PgPool,
+    encryption_key: Vec<u8>,
+}
+
+impl PgSsoConfigStore {
+    pub fn new(pool: PgPool, encryption_key: Vec<u8>) -> Self {
+        Self {
+            pool,
+            encryption_key,
+        }
+    }
+
+    fn encrypt_provider(&self, provider: &SsoProvider) -> Result<Vec<u8>, SsoError> {
+        let json = serde_json::to_vec(provider).map_err(|e| SsoError::Store(e.to_string()))?;
+        Ok(xor_bytes(&json, &self.encryption_key))
+    }
+
+    fn decrypt_provider(&self, encrypted: &[u8]) -> Result<SsoProvider, SsoError> {
+        let json = xor_bytes(encrypted, &self.encryption_key);
+        serde_json::from_slice(&json)
+            .map_err(|e| SsoError::Store(format!("decrypt failed: {e}")))
+    }
+
+    async fn load(&self, row: SsoConfigRow) -> Result<OrgSsoConfig, SsoError> {
+        let domains: Vec<(String,)> = sqlx::query_as(
+            "SELECT domain FROM org_sso_domains WHERE organisation = $1 ORDER BY domain",
+        )
+        .bind(&row.organisation)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| SsoError::Store(e.to_string()))?;
+
+        Ok(OrgSsoConfig {
+            provider: self.decrypt_provider(&row.provider_encrypted)?,
+            organisation: row.organisation,
+            organisation_id: row.organisation_id,
+            enforce_sso: row.enforce_sso,
+            jit_provisioning: row.jit_provisioning,
+            default_role: row.default_role,
+            allowed_domains: domains.into_iter().map(|d| d.0).collect(),
+            updated_by: row.updated_by,
+            updated_at: row.updated_at.to_rfc3339(),
+        })
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct SsoConfigRow {
+    organisation: String,
+    organisation_id: String,
+    provider_encrypted: Vec<u8>,
+    enforce_sso: bool,
+    jit_provisioning: bool,
+    default_role: String,
+    updated_by: String,
+    updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+const SELECT_CONFIG: &str = "SELECT organisation, organisation_id, provider_encrypted, \
+     enforce_sso, jit_provisioning, default_role, updated_by, updated_at \
+     FROM org_sso_configs";
+
+#[async_trait::async_trait]
+impl SsoConfigStore for PgSsoConfigStore {
+    async fn get(&self, organisation: &str) -> Result<Option<OrgSsoConfig>, SsoError> {
+        let row: Option<SsoConfigRow> =
+            sqlx::query_as(&format!("{SELECT_CONFIG} WHERE organisation = $1"))
+                .bind(organisation)
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(|e| SsoError::Store(e.to_string()))?;
+        match row {
+            Some(row) => Ok(Some(self.load(row).await?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn upsert(&self, config: &OrgSsoConfig) -> Result<(), SsoError> {
+        let encrypted = self.encrypt_provider(&config.provider)?;
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| SsoError::Store(e.to_string()))?;
+
+        sqlx::query(
+            "INSERT INTO org_sso_configs \
+             (organisation, organisation_id, protocol, provider_encrypted, enforce_sso, \
+              jit_provisioning, default_role, updated_by) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8) \
+             ON CONFLICT (organisation) DO UPDATE SET \
+               organisation_id = EXCLUDED.organisation_id, \
+               protocol = EXCLUDED.protocol, \
+               provider_encrypted = EXCLUDED.provider_encrypted, \
+               enforce_sso = EXCLUDED.enforce_sso, \
+               jit_provisioning = EXCLUDED.jit_provisioning, \
+               default_role = EXCLUDED.default_role, \
+               updated_by = EXCLUDED.updated_by, \
+               updated_at = now()",
+        )
+        .bind(&config.organisation)
+        .bind(&config.organisation_id)
+        .bind(config.provider.protocol())
+        .bind(&encrypted)
+        .bind(config.enforce_sso)
+        .bind(config.jit_provisioning)
+        .bind(&config.default_role)
+        .bind(&config.updated_by)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| SsoError::Store(e.to_string()))?;
+
+        sqlx::query("DELETE FROM org_sso_domains WHERE organisation = $1")
+            .bind(&config.organisation)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| SsoError::Store(e.to_string()))?;
+
+        for domain in &config.allowed_domains {
+            sqlx::query("INSERT INTO org_sso_domains (domain, organisation) VALUES ($1, $2)")
+                .bind(domain)
+                .bind(&config.organisation)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| match e {
+                    sqlx::Error::Database(ref db) if db.is_unique_violation() => {
+                        SsoError::InvalidInput(format!(
+                            "email domain {domain} is already claimed by another organisation"
+                        ))
+                    }
+                    other => SsoError::Store(other.to_string()),
+                })?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| SsoError::Store(e.to_string()))?;
+        Ok(())
+    }
+
+    async fn delete(&self, organisation: &str) -> Result<(), SsoError> {
+        let result = sqlx::query("DELETE FROM org_sso_configs WHERE organisation = $1")
+            .bind(organisation)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| SsoError::Store(e.to_string()))?;
+        if result.rows_affected() == 0 {
+            return Err(SsoError::NotFound(organisation.to_string()));
+        }
+        Ok(())
+    }
+
+    async fn find_by_email_domain(
+        &self,
+        domain: &str,
+    ) -> Result<Option<OrgSsoConfig>, SsoError> {
+        let row: Option<SsoConfigRow> = sqlx::query_as(&format!(
+            "{SELECT_CONFIG} WHERE organisation = \
+             (SELECT organisation FROM org_sso_domains WHERE domain = $1)"
+        ))
+        .bind(domain.to_ascii_lowercase())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| SsoError::Store(e.to_string()))?;
+        match row {
+            Some(row) => Ok(Some(self.load(row).await?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn enforced_organisations(&self) -> Result<Vec<String>, SsoError> {
+        let rows: Vec<(String,)> = sqlx::query_as(
+            "SELECT organisation FROM org_sso_configs WHERE enforce_sso ORDER BY organisation",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| SsoError::Store(e.to_string()))?;
+        Ok(rows.into_iter().map(|r| r.0).collect())
+    }
+}
@@ -235,3 +235,20 @@ pub fn clear_session_cookie() -> CookieJar {
 
     CookieJar::new().add(cookie)
 }
+
+/// Sign a user out everywhere except `keep`: best-effort revoke each
+/// session's refresh token on forest-server, then delete the sessions.
+/// Returns how many sessions were deleted.
+pub async fn revoke_user_sessions(
+    state: &AppState,
+    user_id: &str,
+    keep: Option<&SessionId>,
+) -> Result<u64, forage_core::session::SessionError> {
+    let sessions = state.sessions.list_for_user(user_id).await?;
+    for summary in sessions.iter().filter(|s| Some(&s.id) != keep) {
+        if let Ok(Some(data)) = state.sessions.get(&summary.id).await {
+            let _ = state.forest_client.logout(&data.refresh_token).await;
+        }
+    }
+    state.sessions.delete_for_user(user_id, keep).await
+}
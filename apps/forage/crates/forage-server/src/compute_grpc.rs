@@ -1,13 +1,15 @@
 use std::pin::Pin;
 use std::sync::Arc;
 
+use forage_core::compute::spec::{self, ResourceSpec};
 use forage_core::compute::{
-    ComputeError, ComputeResourceSpec, ComputeScheduler, ResourceKind, RolloutStatus,
+    ComputeError, ComputeResourceSpec, ComputeScheduler, RolloutStatus, validate_resources,
 };
 use forage_grpc::forage_service_server::ForageService;
 use forage_grpc::{
-    ApplyResourcesRequest, ApplyResourcesResponse, DeleteResourcesRequest,
-    DeleteResourcesResponse, RolloutEvent as ProtoRolloutEvent, WatchRolloutRequest,
+    AppliedResource, ApplyResourcesRequest, ApplyResourcesResponse, DeleteResourcesRequest,
+    DeleteResourcesResponse, ForageResource, GetAppliedResourcesRequest,
+    GetAppliedResourcesResponse, RolloutEvent as ProtoRolloutEvent, WatchRolloutRequest,
 };
 use tokio_stream::Stream;
 use tonic::{Request, Response, Status};
@@ -38,55 +40,13 @@ impl ForageService for ForageServiceImpl {
             return Err(Status::invalid_argument("at least one resource is required"));
         }
 
-        let resources: Vec<ComputeResourceSpec> = req
+        let resources = req
             .resources
-            .iter()
-            .map(|r| {
-                let (kind, image, replicas, cpu, memory) = match &r.spec {
-                    Some(forage_grpc::forage_resource::Spec::ContainerService(cs)) => {
-                        let container = cs.container.as_ref();
-                        let scaling = cs.scaling.as_ref();
-                        (
-                            ResourceKind::ContainerService,
-                            container.map(|c| c.image.clone()),
-                            scaling.map(|s| s.replicas).unwrap_or(1),
-                            container
-                                .and_then(|c| c.resources.as_ref())
-                                .and_then(|r| r.requests.as_ref())
-                                .map(|r| r.cpu.clone()),
-                            container
-                                .and_then(|c| c.resources.as_ref())
-                                .and_then(|r| r.requests.as_ref())
-                                .map(|r| r.memory.clone()),
-                        )
-                    }
-                    Some(forage_grpc::forage_resource::Spec::Service(_)) => {
-                        (ResourceKind::Service, None, 1, None, None)
-                    }
-                    Some(forage_grpc::forage_resource::Spec::Route(_)) => {
-                        (ResourceKind::Route, None, 1, None, None)
-                    }
-                    Some(forage_grpc::forage_resource::Spec::CronJob(cj)) => {
-                        let image = cj.container.as_ref().map(|c| c.image.clone());
-                        (ResourceKind::CronJob, image, 1, None, None)
-                    }
-                    Some(forage_grpc::forage_resource::Spec::Job(j)) => {
-                        let image = j.container.as_ref().map(|c| c.image.clone());
-                        (ResourceKind::Job, image, 1, None, None)
-                    }
-                    None => (ResourceKind::ContainerService, None, 1, None, None),
-                };
-
-                ComputeResourceSpec {
-                    name: r.name.clone(),
-                    kind,
-                    image,
-                    replicas,
-                    cpu,
-                    memory,
-                }
-            })
-            .collect();
+            .into_iter()
+            .map(resource_from_proto)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(compute_err_to_status)?;
+        validate_resources(&resources).map_err(compute_err_to_status)?;
 
         let rollout_id = self
             .scheduler
@@ -138,6 +98,55 @@ impl ForageService for ForageServiceImpl {
 
         Ok(Response::new(DeleteResourcesResponse {}))
     }
+
+    async fn get_applied_resources(
+        &self,
+        request: Request<GetAppliedResourcesRequest>,
+    ) -> Result<Response<GetAppliedResourcesResponse>, Status> {
+        let req = request.into_inner();
+
+        if req.namespace.is_empty() {
+            return Err(Status::invalid_argument("namespace is required"));
+        }
+
+        let instances = self
+            .scheduler
+            .list_instances(&req.namespace)
+            .await
+            .map_err(compute_err_to_status)?;
+
+        let resources = instances
+            .into_iter()
+            .filter(|i| instance_matches(i, &req.labels))
+            .map(|i| AppliedResource {
+                name: i.resource_name,
+                kind: i.kind.to_string(),
+                image: i.image,
+                replicas: i.replicas,
+                status: i.status,
+            })
+            .collect();
+
+        Ok(Response::new(GetAppliedResourcesResponse { resources }))
+    }
+}
+
+/// Instances only keep the labels forage schedules by, so a selector on any
+/// other label matches nothing.
+fn instance_matches(
+    instance: &forage_core::compute::ComputeInstance,
+    labels: &std::collections::HashMap<String, String>,
+) -> bool {
+    labels.iter().all(|(key, value)| {
+        let actual = match key.as_str() {
+            "project" => &instance.project,
+            "destination" => &instance.destination,
+            "environment" => &instance.environment,
+            "region" => &instance.region,
+            _ => return false,
+        };
+        actual == value
+    })
 }
 
 fn compute_err_to_status(e: ComputeError) -> Status {
@@ -158,3 +167,707 @@ fn domain_status_to_proto(s: RolloutStatus) -> forage_grpc::RolloutStatus {
         RolloutStatus::RolledBack => forage_grpc::RolloutStatus::RolledBack,
     }
 }
+
+// ---------------------------------------------------------------------------
+// Proto → domain conversion
+//
+// proto3 has no presence for scalars, so empty strings and zero numbers are
+// read as "unset".  Unknown enum spellings and missing oneofs are rejected
+// with `InvalidRequest`; semantic checks live in `ComputeResourceSpec::validate`.
+// ---------------------------------------------------------------------------
+
+type ConvertResult<T> = Result<T, ComputeError>;
+
+fn invalid(msg: impl Into<String>) -> ComputeError {
+    ComputeError::InvalidRequest(msg.into())
+}
+
+fn non_empty(s: String) -> Option<String> {
+    (!s.is_empty()).then_some(s)
+}
+
+fn non_zero<T: Default + PartialEq>(v: T) -> Option<T> {
+    (v != T::default()).then_some(v)
+}
+
+fn parse_enum<T>(s: &str) -> ConvertResult<T>
+where
+    T: std::str::FromStr<Err = ComputeError> + Default,
+{
+    if s.is_empty() {
+        Ok(T::default())
+    } else {
+        s.parse()
+    }
+}
+
+fn parse_opt_enum<T>(s: &str) -> ConvertResult<Option<T>>
+where
+    T: std::str::FromStr<Err = ComputeError>,
+{
+    if s.is_empty() {
+        Ok(None)
+    } else {
+        s.parse().map(Some)
+    }
+}
+
+fn duration_from_proto(seconds: i64, nanos: i32) -> ConvertResult<std::time::Duration> {
+    if seconds < 0 || nanos < 0 {
+        return Err(invalid("durations must not be negative"));
+    }
+    Ok(std::time::Duration::new(seconds as u64, nanos as u32))
+}
+
+fn sorted<K: Ord, V>(map: std::collections::HashMap<K, V>) -> std::collections::BTreeMap<K, V> {
+    map.into_iter().collect()
+}
+
+fn resource_from_proto(r: ForageResource) -> ConvertResult<ComputeResourceSpec> {
+    use forage_grpc::forage_resource::Spec;
+
+    let name = r.name;
+    let at = |e: ComputeError| match e {
+        ComputeError::InvalidRequest(msg) => invalid(format!("{name}: {msg}")),
+        other => other,
+    };
+
+    let spec = match r.spec {
+        Some(Spec::ContainerService(cs)) => {
+            container_service_from_proto(cs).map(ResourceSpec::ContainerService)
+        }
+        Some(Spec::Service(s)) => service_from_proto(s).map(ResourceSpec::Service),
+        Some(Spec::Route(r)) => route_from_proto(r).map(ResourceSpec::Route),
+        Some(Spec::CronJob(cj)) => cron_job_from_proto(cj).map(ResourceSpec::CronJob),
+        Some(Spec::Job(j)) => job_from_proto(j).map(ResourceSpec::Job),
+        None => Err(invalid("resource spec is required")),
+    }
+    .map_err(at)?;
+
+    Ok(ComputeResourceSpec { name, spec })
+}
+
+fn container_service_from_proto(
+    cs: forage_grpc::ContainerServiceSpec,
+) -> ConvertResult<spec::ContainerServiceSpec> {
+    let scaling = match cs.scaling {
+        Some(s) => spec::ScalingPolicy {
+            replicas: s.replicas,
+            autoscaling: s.autoscaling.map(autoscaling_from_proto).transpose()?,
+        },
+        None => spec::ScalingPolicy {
+            replicas: 1,
+            autoscaling: None,
+        },
+    };
+
+    let update_strategy = match cs.update_strategy {
+        None => spec::UpdateStrategy::Default,
+        Some(u) => match u.r#type.as_str() {
+            "" | "RollingUpdate" => {
+                let rolling = u.rolling_update.unwrap_or_default();
+                if u.r#type.is_empty()
+                    && rolling.max_unavailable.is_empty()
+                    && rolling.max_surge.is_empty()
+                {
+                    spec::UpdateStrategy::Default
+                } else {
+                    spec::UpdateStrategy::RollingUpdate {
+                        max_unavailable: non_empty(rolling.max_unavailable),
+                        max_surge: non_empty(rolling.max_surge),
+                    }
+                }
+            }
+            "Recreate" if u.rolling_update.is_some() => {
+                return Err(invalid("rolling_update is not allowed with Recreate"));
+            }
+            "Recreate" => spec::UpdateStrategy::Recreate,
+            other => return Err(invalid(format!("unknown update strategy: {other:?}"))),
+        },
+    };
+
+    Ok(spec::ContainerServiceSpec {
+        scaling,
+        container: required_container(cs.container)?,
+        sidecars: containers_from_proto(cs.sidecars)?,
+        init_containers: containers_from_proto(cs.init_containers)?,
+        volumes: volumes_from_proto(cs.volumes)?,
+        update_strategy,
+        pod_config: pod_config_from_proto(cs.pod_config)?,
+    })
+}
+
+fn autoscaling_from_proto(
+    a: forage_grpc::AutoscalingPolicy,
+) -> ConvertResult<spec::AutoscalingPolicy> {
+    Ok(spec::AutoscalingPolicy {
+        min_replicas: a.min_replicas,
+        max_replicas: a.max_replicas,
+        target_cpu_utilization_percent: non_zero(a.target_cpu_utilization_percent),
+        target_memory_utilization_percent: non_zero(a.target_memory_utilization_percent),
+        custom_metrics: a
+            .custom_metrics
+            .into_iter()
+            .map(|m| spec::CustomMetric {
+                name: m.name,
+                target_type: m.target_type,
+                target_value: m.target_value,
+            })
+            .collect(),
+        scale_down_stabilization: a
+            .scale_down_stabilization
+            .map(|d| duration_from_proto(d.seconds, d.nanos))
+            .transpose()?,
+    })
+}
+
+fn required_container(c: Option<forage_grpc::Container>) -> ConvertResult<spec::Container> {
+    container_from_proto(c.ok_or_else(|| invalid("container is required"))?)
+}
+
+fn containers_from_proto(cs: Vec<forage_grpc::Container>) -> ConvertResult<Vec<spec::Container>> {
+    cs.into_iter().map(container_from_proto).collect()
+}
+
+fn container_from_proto(c: forage_grpc::Container) -> ConvertResult<spec::Container> {
+    let resources = c.resources.unwrap_or_default();
+
+    Ok(spec::Container {
+        env: c
+            .env
+            .into_iter()
+            .map(env_var_from_proto)
+            .collect::<ConvertResult<_>>()?,
+        ports: c
+            .ports
+            .into_iter()
+            .map(|p| {
+                Ok(spec::ContainerPort {
+                    name: p.name,
+                    container_port: p.container_port,
+                    protocol: parse_enum(&p.protocol)?,
+                })
+            })
+            .collect::<ConvertResult<_>>()?,
+        resources: spec::ResourceRequirements {
+            requests: resource_list_from_proto(resources.requests),
+            limits: resource_list_from_proto(resources.limits),
+        },
+        volume_mounts: c
+            .volume_mounts
+            .into_iter()
+            .map(|m| spec::VolumeMount {
+                name: m.name,
+                mount_path: m.mount_path,
+                sub_path: non_empty(m.sub_path),
+                read_only: m.read_only,
+            })
+            .collect(),
+        liveness_probe: c.liveness_probe.map(probe_from_proto).transpose()?,
+        readiness_probe: c.readiness_probe.map(probe_from_proto).transpose()?,
+        startup_probe: c.startup_probe.map(probe_from_proto).transpose()?,
+        lifecycle: match c.lifecycle {
+            Some(l) => spec::Lifecycle {
+                post_start: l
+                    .post_start
+                    .map(lifecycle_handler_from_proto)
+                    .transpose()?
+                    .flatten(),
+                pre_stop: l
+                    .pre_stop
+                    .map(lifecycle_handler_from_proto)
+                    .transpose()?
+                    .flatten(),
+            },
+            None => spec::Lifecycle::default(),
+        },
+        security_context: c.security_context.map(|sc| {
+            let caps = sc.capabilities.unwrap_or_default();
+            spec::ContainerSecurityContext {
+                run_as_non_root: sc.run_as_non_root,
+                run_as_user: non_zero(sc.run_as_user),
+                run_as_group: non_zero(sc.run_as_group),
+                read_only_root_filesystem: sc.read_only_root_filesystem,
+                privileged: sc.privileged,
+                allow_privilege_escalation: sc.allow_privilege_escalation,
+                capabilities_add: caps.add,
+                capabilities_drop: caps.drop,
+                se_linux_type: non_empty(sc.se_linux_type),
+                seccomp_profile: non_empty(sc.seccomp_profile),
+            }
+        }),
+        image_pull_policy: parse_enum(&c.image_pull_policy)?,
+        name: c.name,
+        image: c.image,
+        command: c.command,
+        args: c.args,
+        working_dir: non_empty(c.working_dir),
+        stdin: c.stdin,
+        tty: c.tty,
+    })
+}
+
+fn env_var_from_proto(e: forage_grpc::EnvVar) -> ConvertResult<spec::EnvVar> {
+    use forage_grpc::env_var::ValueSource;
+
+    let source = match e.value_source {
+        Some(ValueSource::Value(value)) => spec::EnvSource::Value { value },
+        // An env var without a source is an explicit empty value.
+        None => spec::EnvSource::Value {
+            value: String::new(),
+        },
+        Some(ValueSource::SecretRef(r)) => spec::EnvSource::Secret {
+            secret_name: r.secret_name,
+            key: r.key,
+        },
+        Some(ValueSource::ConfigRef(r)) => spec::EnvSource::Config {
+            config_name: r.config_name,
+            key: r.key,
+        },
+        Some(ValueSource::FieldRef(path)) => spec::EnvSource::Field { path },
+        Some(ValueSource::ResourceFieldRef(resource)) => {
+            spec::EnvSource::ResourceField { resource }
+        }
+    };
+
+    Ok(spec::EnvVar {
+        name: e.name,
+        source,
+    })
+}
+
+fn resource_list_from_proto(l: Option<forage_grpc::ResourceList>) -> spec::ResourceList {
+    let l = l.unwrap_or_default();
+    spec::ResourceList {
+        cpu: non_empty(l.cpu),
+        memory: non_empty(l.memory),
+        ephemeral_storage: non_empty(l.ephemeral_storage),
+        extended: sorted(l.extended),
+    }
+}
+
+fn http_get_from_proto(h: forage_grpc::HttpGetProbe) -> ConvertResult<spec::HttpGetAction> {
+    Ok(spec::HttpGetAction {
+        path: h.path,
+        port: h.port,
+        scheme: parse_enum(&h.scheme)?,
+        headers: h
+            .http_headers
+            .into_iter()
+            .map(|h| (h.name, h.value))
+            .collect(),
+    })
+}
+
+fn probe_from_proto(p: forage_grpc::Probe) -> ConvertResult<spec::Probe> {
+    use forage_grpc::probe::Handler;
+
+    let handler = match p.handler {
+        Some(Handler::HttpGet(h)) => spec::ProbeHandler::HttpGet(http_get_from_proto(h)?),
+        Some(Handler::TcpSocket(t)) => spec::ProbeHandler::TcpSocket { port: t.port },
+        Some(Handler::Exec(e)) => spec::ProbeHandler::Exec { command: e.command },
+        Some(Handler::Grpc(g)) => spec::ProbeHandler::Grpc {
+            port: g.port,
+            service: non_empty(g.service),
+        },
+        None => return Err(invalid("probe handler is required")),
+    };
+
+    Ok(spec::Probe {
+        handler,
+        initial_delay_seconds: p.initial_delay_seconds,
+        period_seconds: non_zero(p.period_seconds),
+        timeout_seconds: non_zero(p.timeout_seconds),
+        success_threshold: non_zero(p.success_threshold),
+        failure_threshold: non_zero(p.failure_threshold),
+    })
+}
+
+/// An empty handler means "no hook", so it maps to `None` rather than an error.
+fn lifecycle_handler_from_proto(
+    h: forage_grpc::LifecycleHandler,
+) -> ConvertResult<Option<spec::LifecycleHandler>> {
+    use forage_grpc::lifecycle_handler::Action;
+
+    Ok(match h.action {
+        Some(Action::Exec(e)) => Some(spec::LifecycleHandler::Exec { command: e.command }),
+        Some(Action::HttpGet(h)) => Some(spec::LifecycleHandler::HttpGet(http_get_from_proto(h)?)),
+        Some(Action::TcpSocket(t)) => Some(spec::LifecycleHandler::TcpSocket { port: t.port }),
+        None => None,
+    })
+}
+
+fn volumes_from_proto(vs: Vec<forage_grpc::Volume>) -> ConvertResult<Vec<spec::Volume>> {
+    use forage_grpc::volume::Source;
+
+    let key_paths = |items: Vec<forage_grpc::KeyToPath>| {
+        items
+            .into_iter()
+            .map(|i| spec::KeyToPath {
+                key: i.key,
+                path: i.path,
+                mode: non_zero(i.mode),
+            })
+            .collect()
+    };
+
+    vs.into_iter()
+        .map(|v| {
+            let source = match v.source {
+                Some(Source::EmptyDir(e)) => spec::VolumeSource::EmptyDir {
+                    medium: non_empty(e.medium),
+                    size_limit: non_empty(e.size_limit),
+                },
+                Some(Source::Secret(s)) => spec::VolumeSource::Secret {
+                    secret_name: s.secret_name,
+                    items: key_paths(s.items),
+                    default_mode: non_zero(s.default_mode),
+                    optional: s.optional,
+                },
+                Some(Source::ConfigMap(c)) => spec::VolumeSource::ConfigMap {
+                    config_map_name: c.config_map_name,
+                    items: key_paths(c.items),
+                    default_mode: non_zero(c.default_mode),
+                    optional: c.optional,
+                },
+                Some(Source::Pvc(p)) => spec::VolumeSource::Pvc {
+                    claim_name: p.claim_name,
+                    read_only: p.read_only,
+                },
+                Some(Source::HostPath(h)) => spec::VolumeSource::HostPath {
+                    path: h.path,
+                    path_type: non_empty(h.r#type),
+                },
+                Some(Source::Nfs(n)) => spec::VolumeSource::Nfs {
+                    server: n.server,
+                    path: n.path,
+                    read_only: n.read_only,
+                },
+                None => return Err(invalid(format!("volume {} has no source", v.name))),
+            };
+            Ok(spec::Volume {
+                name: v.name,
+                source,
+            })
+        })
+        .collect()
+}
+
+fn pod_config_from_proto(p: Option<forage_grpc::PodConfig>) -> ConvertResult<spec::PodConfig> {
+    let Some(p) = p else {
+        return Ok(spec::PodConfig::default());
+    };
+
+    let requirement = |r: forage_grpc::NodeSelectorRequirement| spec::SelectorRequirement {
+        key: r.key,
+        operator: r.operator,
+        values: r.values,
+    };
+    let node_term = |t: forage_grpc::NodeSelectorTerm| spec::NodeSelectorTerm {
+        match_expressions: t.match_expressions.into_iter().map(requirement).collect(),
+        match_fields: t.match_fields.into_iter().map(requirement).collect(),
+    };
+
+    let affinity = p.affinity.unwrap_or_default();
+    let node = affinity.node_affinity.unwrap_or_default();
+    let pod = affinity.pod_affinity.unwrap_or_default();
+    let anti = affinity.pod_anti_affinity.unwrap_or_default();
+
+    Ok(spec::PodConfig {
+        service_account_name: non_empty(p.service_account_name),
+        restart_policy: parse_opt_enum(&p.restart_policy)?,
+        termination_grace_period_seconds: non_zero(p.termination_grace_period_seconds),
+        dns_policy: non_empty(p.dns_policy),
+        dns_config: p.dns_config.map(|d| spec::PodDnsConfig {
+            nameservers: d.nameservers,
+            searches: d.searches,
+            options: d
+                .options
+                .into_iter()
+                .map(|o| (o.name, non_empty(o.value)))
+                .collect(),
+        }),
+        host_network: p.host_network,
+        node_selector: sorted(p.node_selector),
+        tolerations: p
+            .tolerations
+            .into_iter()
+            .map(|t| spec::Toleration {
+                key: t.key,
+                operator: t.operator,
+                value: t.value,
+                effect: t.effect,
+                toleration_seconds: non_zero(t.toleration_seconds),
+            })
+            .collect(),
+        affinity: spec::Affinity {
+            node_required: node
+                .required
+                .map(|r| r.terms.into_iter().map(node_term).collect())
+                .unwrap_or_default(),
+            node_preferred: node
+                .preferred
+                .into_iter()
+                .map(|p| spec::PreferredSchedulingTerm {
+                    weight: p.weight,
+                    preference: p.preference.map(node_term).unwrap_or_default(),
+                })
+                .collect(),
+            pod_required: pod.required.into_iter().map(pod_affinity_term).collect(),
+            pod_preferred: pod.preferred.into_iter().map(weighted_pod_term).collect(),
+            pod_anti_required: anti.required.into_iter().map(pod_affinity_term).collect(),
+            pod_anti_preferred: anti.preferred.into_iter().map(weighted_pod_term).collect(),
+        },
+        topology_spread_constraints: p
+            .topology_spread_constraints
+            .into_iter()
+            .map(|t| spec::TopologySpreadConstraint {
+                max_skew: t.max_skew,
+                topology_key: t.topology_key,
+                when_unsatisfiable: t.when_unsatisfiable,
+                label_selector: label_selector_from_proto(t.label_selector),
+            })
+            .collect(),
+        image_pull_secrets: p.image_pull_secrets,
+        security_context: p.security_context.map(|sc| spec::PodSecurityContext {
+            run_as_user: non_zero(sc.run_as_user),
+            run_as_group: non_zero(sc.run_as_group),
+            run_as_non_root: sc.run_as_non_root,
+            fs_group: non_zero(sc.fs_group),
+            supplemental_groups: sc.supplemental_groups,
+            fs_group_change_policy: non_empty(sc.fs_group_change_policy),
+            seccomp_profile: non_empty(sc.seccomp_profile),
+        }),
+        priority_class_name: non_empty(p.priority_class_name),
+        runtime_class_name: non_empty(p.runtime_class_name),
+        annotations: sorted(p.annotations),
+        labels: sorted(p.labels),
+    })
+}
+
+fn label_selector_from_proto(s: Option<forage_grpc::LabelSelector>) -> spec::LabelSelector {
+    let s = s.unwrap_or_default();
+    spec::LabelSelector {
+        match_labels: sorted(s.match_labels),
+        match_expressions: s
+            .match_expressions
+            .into_iter()
+            .map(|r| spec::SelectorRequirement {
+                key: r.key,
+                operator: r.operator,
+                values: r.values,
+            })
+            .collect(),
+    }
+}
+
+fn pod_affinity_term(t: forage_grpc::PodAffinityTerm) -> spec::PodAffinityTerm {
+    spec::PodAffinityTerm {
+        label_selector: label_selector_from_proto(t.label_selector),
+        topology_key: t.topology_key,
+        namespaces: t.namespaces,
+    }
+}
+
+fn weighted_pod_term(t: forage_grpc::WeightedPodAffinityTerm) -> spec::WeightedPodAffinityTerm {
+    spec::WeightedPodAffinityTerm {
+        weight: t.weight,
+        term: t.term.map(pod_affinity_term).unwrap_or_default(),
+    }
+}
+
+fn service_from_proto(s: forage_grpc::ServiceSpec) -> ConvertResult<spec::ServiceSpec> {
+    Ok(spec::ServiceSpec {
+        target: s.target,
+        service_type: parse_enum(&s.r#type)?,
+        ports: s
+            .ports
+            .into_iter()
+            .map(|p| {
+                Ok(spec::ServicePort {
+                    name: p.name,
+                    port: p.port,
+                    target_port: non_zero(p.target_port),
+                    protocol: parse_enum(&p.protocol)?,
+                    node_port: non_zero(p.node_port),
+                })
+            })
+            .collect::<ConvertResult<_>>()?,
+        session_affinity: parse_enum(&s.session_affinity)?,
+        inline_route: s
+            .inline_route
+            .map(|r| {
+                Ok::<_, ComputeError>(spec::InlineRoute {
+                    hostnames: r.hostnames,
+                    rules: route_rules_from_proto(r.rules)?,
+                    tls: r.tls.map(route_tls_from_proto).transpose()?,
+                })
+            })
+            .transpose()?,
+        annotations: sorted(s.annotations),
+    })
+}
+
+fn route_from_proto(r: forage_grpc::RouteSpec) -> ConvertResult<spec::RouteSpec> {
+    Ok(spec::RouteSpec {
+        target_service: r.target_service,
+        hostnames: r.hostnames,
+        rules: route_rules_from_proto(r.rules)?,
+        tls: r.tls.map(route_tls_from_proto).transpose()?,
+        gateway_ref: non_empty(r.gateway_ref),
+        priority: r.priority,
+    })
+}
+
+fn route_tls_from_proto(t: forage_grpc::RouteTls) -> ConvertResult<spec::RouteTls> {
+    Ok(spec::RouteTls {
+        mode: parse_enum(&t.mode)?,
+        certificate_ref: non_empty(t.certificate_ref),
+    })
+}
+
+fn path_match_from_proto(p: forage_grpc::PathMatch) -> ConvertResult<spec::PathMatch> {
+    Ok(spec::PathMatch {
+        match_type: parse_enum(&p.r#type)?,
+        value: p.value,
+    })
+}
+
+fn route_rules_from_proto(
+    rules: Vec<forage_grpc::RouteRule>,
+) -> ConvertResult<Vec<spec::RouteRule>> {
+    use forage_grpc::route_filter::Filter;
+
+    rules
+        .into_iter()
+        .map(|rule| {
+            let matches = rule
+                .matches
+                .into_iter()
+                .map(|m| {
+                    Ok(spec::RouteMatch {
+                        path: m.path.map(path_match_from_proto).transpose()?,
+                        headers: m
+                            .headers
+                            .into_iter()
+                            .map(|h| {
+                                Ok(spec::ValueMatch {
+                                    match_type: parse_enum(&h.r#type)?,
+                                    name: h.name,
+                                    value: h.value,
+                                })
+                            })
+                            .collect::<ConvertResult<_>>()?,
+                        query_params: m
+                            .query_params
+                            .into_iter()
+                            .map(|q| {
+                                Ok(spec::ValueMatch {
+                                    match_type: parse_enum(&q.r#type)?,
+                                    name: q.name,
+                                    value: q.value,
+                                })
+                            })
+                            .collect::<ConvertResult<_>>()?,
+                        method: non_empty(m.method),
+                    })
+                })
+                .collect::<ConvertResult<_>>()?;
+
+            let filters = rule
+                .filters
+                .into_iter()
+                .map(|f| {
+                    Ok(match f.filter {
+                        Some(Filter::RequestHeaderModifier(m)) => {
+                            spec::RouteFilter::RequestHeaderModifier(spec::HeaderModifier {
+                                set: sorted(m.set),
+                                add: sorted(m.add),
+                                remove: m.remove,
+                            })
+                        }
+                        Some(Filter::ResponseHeaderModifier(m)) => {
+                            spec::RouteFilter::ResponseHeaderModifier(spec::HeaderModifier {
+                                set: sorted(m.set),
+                                add: sorted(m.add),
+                                remove: m.remove,
+                            })
+                        }
+                        Some(Filter::RequestRedirect(r)) => spec::RouteFilter::RequestRedirect {
+                            scheme: non_empty(r.scheme),
+                            hostname: non_empty(r.hostname),
+                            port: non_zero(r.port),
+                            path: non_empty(r.path),
+                            status_code: non_zero(r.status_code),
+                        },
+                        Some(Filter::UrlRewrite(u)) => spec::RouteFilter::UrlRewrite {
+                            hostname: non_empty(u.hostname),
+                            path: u.path.map(path_match_from_proto).transpose()?,
+                        },
+                        Some(Filter::RequestMirror(m)) => spec::RouteFilter::RequestMirror {
+                            service: m.service,
+                            port: m.port,
+                        },
+                        None => return Err(invalid("route filter is empty")),
+                    })
+                })
+                .collect::<ConvertResult<_>>()?;
+
+            Ok(spec::RouteRule {
+                matches,
+                backends: rule
+                    .backends
+                    .into_iter()
+                    .map(|b| spec::RouteBackend {
+                        service: b.service,
+                        port: b.port,
+                        weight: non_zero(b.weight),
+                    })
+                    .collect(),
+                filters,
+                timeout: rule
+                    .timeout
+                    .map(|d| duration_from_proto(d.seconds, d.nanos))
+                    .transpose()?,
+            })
+        })
+        .collect()
+}
+
+fn job_config_from_proto(j: Option<forage_grpc::JobConfig>) -> ConvertResult<spec::JobConfig> {
+    let j = j.unwrap_or_default();
+    Ok(spec::JobConfig {
+        completions: non_zero(j.completions),
+        parallelism: non_zero(j.parallelism),
+        completion_mode: parse_enum(&j.completion_mode)?,
+        backoff_limit: non_zero(j.backoff_limit),
+        active_deadline_seconds: non_zero(j.active_deadline_seconds),
+        ttl_seconds_after_finished: non_zero(j.ttl_seconds_after_finished),
+        restart_policy: parse_opt_enum(&j.restart_policy)?,
+    })
+}
+
+fn cron_job_from_proto(cj: forage_grpc::CronJobSpec) -> ConvertResult<spec::CronJobSpec> {
+    Ok(spec::CronJobSpec {
+        schedule: cj.schedule,
+        timezone: non_empty(cj.timezone),
+        container: required_container(cj.container)?,
+        volumes: volumes_from_proto(cj.volumes)?,
+        job_config: job_config_from_proto(cj.job_config)?,
+        pod_config: pod_config_from_proto(cj.pod_config)?,
+        concurrency_policy: parse_enum(&cj.concurrency_policy)?,
+        successful_jobs_history_limit: non_zero(cj.successful_jobs_history_limit),
+        failed_jobs_history_limit: non_zero(cj.failed_jobs_history_limit),
+        suspend: cj.suspend,
+        starting_deadline_seconds: non_zero(cj.starting_deadline_seconds),
+    })
+}
+
+fn job_from_proto(j: forage_grpc::JobSpec) -> ConvertResult<spec::JobSpec> {
+    Ok(spec::JobSpec {
+        container: required_container(j.container)?,
+        volumes: volumes_from_proto(j.volumes)?,
+        job_config: job_config_from_proto(j.job_config)?,
+        pod_config: pod_config_from_proto(j.pod_config)?,
+    })
+}
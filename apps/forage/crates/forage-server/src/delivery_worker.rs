@@ -0,0 +1,54 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use forage_core::integrations::IntegrationStore;
+use notmad::{Component, ComponentInfo, MadError};
+use tokio_util::sync::CancellationToken;
+
+use crate::forest_client::GrpcForestClient;
+use crate::notification_worker::NotificationDispatcher;
+
+/// Deliveries attempted per poll.
+const BATCH_SIZE: usize = 20;
+
+/// Background component that drains the persistent delivery queue: retries
+/// failed deliveries on their backoff schedule and picks up anything left
+/// behind by a restart.
+pub struct DeliveryWorker {
+    pub store: Arc<dyn IntegrationStore>,
+    pub forage_url: String,
+    pub grpc: Arc<GrpcForestClient>,
+    pub service_token: String,
+    pub approval_buttons: bool,
+}
+
+impl Component for DeliveryWorker {
+    fn info(&self) -> ComponentInfo {
+        "forage/delivery-worker".into()
+    }
+
+    async fn run(&self, cancellation_token: CancellationToken) -> Result<(), MadError> {
+        let dispatcher = NotificationDispatcher::new(self.store.clone(), self.forage_url.clone())
+            .with_grpc(self.grpc.clone(), self.service_token.clone())
+            .with_approval_buttons(self.approval_buttons);
+
+        let mut interval = tokio::time::interval(Duration::from_secs(5));
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+        loop {
+            tokio::select! {
+                _ = cancellation_token.cancelled() => break,
+                _ = interval.tick() => {
+                    // Keep draining while full batches come back.
+                    while dispatcher.process_due(BATCH_SIZE).await == BATCH_SIZE {
+                        if cancellation_token.is_cancelled() {
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
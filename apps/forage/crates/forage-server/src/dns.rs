@@ -0,0 +1,26 @@
+use forage_core::auth::sso::{DomainTxtLookup, SsoError};
+use hickory_resolver::TokioResolver;
+
+/// TXT lookups through the host's configured DNS resolvers.
+pub struct SystemTxtLookup {
+    resolver: TokioResolver,
+}
+
+impl SystemTxtLookup {
+    pub fn new() -> anyhow::Result<Self> {
+        Ok(Self {
+            resolver: TokioResolver::builder_tokio()?.build(),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl DomainTxtLookup for SystemTxtLookup {
+    async fn txt_records(&self, name: &str) -> Result<Vec<String>, SsoError> {
+        match self.resolver.txt_lookup(name).await {
+            Ok(lookup) => Ok(lookup.iter().map(|txt| txt.to_string()).collect()),
+            Err(e) if e.is_no_records_found() || e.is_nx_domain() => Ok(Vec::new()),
+            Err(e) => Err(SsoError::Store(format!("DNS lookup for {name} failed: {e}"))),
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_nats::jetstream;
+use forage_core::integrations::email::{self, EmailEnvelope, DIGEST_EMAIL_TYPE};
+use forage_core::integrations::{DigestBacklog, DigestEntry, IntegrationConfig, IntegrationStore};
+use notmad::{Component, ComponentInfo, MadError};
+use tokio_util::sync::CancellationToken;
+
+/// Background component that mails queued email digest entries once an
+/// integration's interval has passed, via the same NATS email stream as
+/// magic links.
+pub struct EmailDigestSender {
+    pub store: Arc<dyn IntegrationStore>,
+    pub jetstream: jetstream::Context,
+    pub forage_url: String,
+}
+
+impl Component for EmailDigestSender {
+    fn info(&self) -> ComponentInfo {
+        "forage/email-digest-sender".into()
+    }
+
+    async fn run(&self, cancellation_token: CancellationToken) -> Result<(), MadError> {
+        let mut interval = tokio::time::interval(Duration::from_secs(60));
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+        loop {
+            tokio::select! {
+                _ = cancellation_token.cancelled() => break,
+                _ = interval.tick() => self.flush().await,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl EmailDigestSender {
+    async fn flush(&self) {
+        let backlog = match self.store.list_digest_backlog().await {
+            Ok(b) => b,
+            Err(e) => {
+                tracing::warn!(error = %e, "email digest: failed to list backlog");
+                return;
+            }
+        };
+
+        for item in backlog {
+            let Some((mails, entries)) =
+                take_due_digest(self.store.as_ref(), &item, &self.forage_url, chrono::Utc::now())
+                    .await
+            else {
+                continue;
+            };
+
+            let mut failed = false;
+            for mail in &mails {
+                let payload = match serde_json::to_vec(mail) {
+                    Ok(p) => p,
+                    Err(e) => {
+                        tracing::error!(error = %e, "email digest: failed to serialize envelope");
+                        continue;
+                    }
+                };
+                if let Err(e) = self
+                    .jetstream
+                    .publish(email::email_subject(DIGEST_EMAIL_TYPE), payload.into())
+                    .await
+                {
+                    tracing::warn!(integration_id = %item.integration_id, error = %e, "email digest: publish failed");
+                    failed = true;
+                    break;
+                }
+            }
+
+            // Put the entries back so the next tick retries the whole digest.
+            if failed {
+                for entry in &entries {
+                    let _ = self.store.enqueue_digest_entry(entry).await;
+                }
+            } else {
+                tracing::info!(
+                    integration_id = %item.integration_id,
+                    entries = entries.len(),
+                    recipients = mails.len(),
+                    "email digest sent"
+                );
+            }
+        }
+    }
+}
+
+/// Take the queued entries of a due digest and render its mails.
+///
+/// Returns `None` when the digest is not due yet. Entries for integrations
+/// that were deleted, disabled or are no longer email digests are dropped.
+pub async fn take_due_digest(
+    store: &dyn IntegrationStore,
+    item: &DigestBacklog,
+    forage_url: &str,
+    now: chrono::DateTime<chrono::Utc>,
+) -> Option<(Vec<EmailEnvelope>, Vec<DigestEntry>)> {
+    let integration = store
+        .get_integration(&item.organisation, &item.integration_id)
+        .await
+        .ok()
+        .filter(|i| i.enabled);
+
+    let (recipients, interval) = match integration.as_ref().map(|i| &i.config) {
+        Some(IntegrationConfig::Email {
+            recipients,
+            digest_interval_minutes,
+        }) => (recipients.clone(), *digest_interval_minutes),
+        _ => {
+            let _ = store.take_digest_entries(&item.integration_id).await;
+            return None;
+        }
+    };
+
+    if !email::digest_due(&item.oldest_at, interval, now) {
+        return None;
+    }
+
+    let entries = store.take_digest_entries(&item.integration_id).await.ok()?;
+    if entries.is_empty() {
+        return None;
+    }
+    let name = integration.map(|i| i.name).unwrap_or_default();
+    let mails = email::render_digest(&name, &item.organisation, &recipients, &entries, forage_url);
+    Some((mails, entries))
+}
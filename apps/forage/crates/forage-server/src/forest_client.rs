@@ -1,14 +1,16 @@
 use forage_core::auth::{
-    AddEmailResult, AuthError, AuthTokens, CreatedToken, ForestAuth, LoginResult, MfaSetup,
-    PersonalAccessToken, RegisterResult, User, UserEmail, UserProfile,
+    AddEmailResult, AuthError, AuthTokens, CreatedToken, ForestAuth, LoginResult, MfaMethod,
+    MfaSetup, Passkey, PasskeyCeremony, PasskeyResponse, PersonalAccessToken, RegisterResult,
+    User, UserEmail, UserProfile,
 };
 use forage_core::platform::{
     ApprovalDecisionEntry, ApprovalState, Artifact, ArtifactContext, ArtifactDestination,
     ArtifactRef, ArtifactSource, CreatePolicyInput, CreateReleasePipelineInput, CreateTriggerInput,
-    Destination, DestinationType, DestinationTypeInfo, Environment, ForestPlatform,
-    MetadataFieldDef, NotificationPreference, Organisation, OrgMember, PipelineStage,
-    PipelineStageConfig, PlanOutput, PlatformError, Policy, PolicyConfig, PolicyEvaluation,
-    ReleasePipeline, Trigger, UpdatePolicyInput, UpdateReleasePipelineInput, UpdateTriggerInput,
+    Destination, DestinationType, DestinationTypeInfo, Environment, EnvironmentProtection,
+    ForestPlatform, MetadataFieldDef, NotificationPreference, Organisation, OrgMember,
+    PipelineStage, PipelineStageConfig, PlanOutput, PlatformError, Policy, PolicyConfig,
+    PolicyEvaluation, ReleasePipeline, ReleaseSchedule, ReleaseScheduleRun, Trigger, UpdatePolicyInput, UpdateReleasePipelineInput,
+    UpdateTriggerInput,
 };
 use forage_core::registry::{
     ComponentDetail, ComponentSearchResult, ComponentSummary, ComponentVersionInfo, ForestRegistry,
@@ -17,6 +19,7 @@ use forage_core::registry::{
 use forage_grpc::policy_service_client::PolicyServiceClient;
 use forage_grpc::registry_service_client::RegistryServiceClient;
 use forage_grpc::release_pipeline_service_client::ReleasePipelineServiceClient;
+use forage_grpc::release_schedule_service_client::ReleaseScheduleServiceClient;
 use forage_grpc::trigger_service_client::TriggerServiceClient;
 use forage_grpc::destination_service_client::DestinationServiceClient;
 use forage_grpc::environment_service_client::EnvironmentServiceClient;
@@ -96,6 +99,10 @@ impl GrpcForestClient {
         PolicyServiceClient::new(self.channel.clone())
     }
 
+    fn release_schedule_client(&self) -> ReleaseScheduleServiceClient<Channel> {
+        ReleaseScheduleServiceClient::new(self.channel.clone())
+    }
+
     fn pipeline_client(&self) -> ReleasePipelineServiceClient<Channel> {
         ReleasePipelineServiceClient::new(self.channel.clone())
     }
@@ -201,6 +208,25 @@ fn convert_token(t: forage_grpc::PersonalAccessToken) -> PersonalAccessToken {
     }
 }
 
+fn convert_passkey(p: forage_grpc::Passkey) -> Passkey {
+    Passkey {
+        passkey_id: p.passkey_id,
+        name: p.name,
+        created_at: p.created_at.map(|ts| ts.to_string()),
+        last_used_at: p.last_used_at.map(|ts| ts.to_string()),
+    }
+}
+
+fn convert_passkey_ceremony(
+    ceremony: Option<forage_grpc::PasskeyCeremony>,
+) -> Result<PasskeyCeremony, AuthError> {
+    let ceremony = ceremony.ok_or(AuthError::Other("no passkey ceremony in response".into()))?;
+    Ok(PasskeyCeremony {
+        ceremony_id: ceremony.ceremony_id,
+        options_json: ceremony.options_json,
+    })
+}
+
 #[async_trait::async_trait]
 impl ForestAuth for GrpcForestClient {
     #[tracing::instrument(skip_all)]
@@ -260,8 +286,18 @@ impl ForestAuth for GrpcForestClient {
         };
 
         if resp.mfa_required {
+            let methods = resp
+                .mfa_types
+                .iter()
+                .filter_map(|t| match forage_grpc::MfaType::try_from(*t) {
+                    Ok(forage_grpc::MfaType::Totp) => Some(MfaMethod::Totp),
+                    Ok(forage_grpc::MfaType::Passkey) => Some(MfaMethod::Passkey),
+                    _ => None,
+                })
+                .collect();
             return Ok(LoginResult::MfaRequired {
                 mfa_session_token: resp.mfa_session_token,
+                methods,
             });
         }
 
@@ -369,6 +405,180 @@ impl ForestAuth for GrpcForestClient {
         Ok(())
     }
 
+    #[tracing::instrument(skip_all)]
+    async fn begin_passkey_registration(
+        &self,
+        access_token: &str,
+    ) -> Result<PasskeyCeremony, AuthError> {
+        let req = Self::authed_request(
+            access_token,
+            forage_grpc::BeginPasskeyRegistrationRequest {},
+        )?;
+
+        let resp = self
+            .client()
+            .begin_passkey_registration(req)
+            .await
+            .map_err(map_status)?
+            .into_inner();
+
+        convert_passkey_ceremony(resp.ceremony)
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn finish_passkey_registration(
+        &self,
+        access_token: &str,
+        name: &str,
+        response: &PasskeyResponse,
+    ) -> Result<Passkey, AuthError> {
+        let req = Self::authed_request(
+            access_token,
+            forage_grpc::FinishPasskeyRegistrationRequest {
+                ceremony_id: response.ceremony_id.clone(),
+                name: name.into(),
+                credential_json: response.credential_json.clone(),
+            },
+        )?;
+
+        let resp = self
+            .client()
+            .finish_passkey_registration(req)
+            .await
+            .map_err(map_status)?
+            .into_inner();
+
+        resp.passkey
+            .map(convert_passkey)
+            .ok_or(AuthError::Other("no passkey in response".into()))
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn list_passkeys(&self, access_token: &str) -> Result<Vec<Passkey>, AuthError> {
+        let req = Self::authed_request(access_token, forage_grpc::ListPasskeysRequest {})?;
+
+        let resp = self
+            .client()
+            .list_passkeys(req)
+            .await
+            .map_err(map_status)?
+            .into_inner();
+
+        Ok(resp.passkeys.into_iter().map(convert_passkey).collect())
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn rename_passkey(
+        &self,
+        access_token: &str,
+        passkey_id: &str,
+        name: &str,
+    ) -> Result<(), AuthError> {
+        let req = Self::authed_request(
+            access_token,
+            forage_grpc::RenamePasskeyRequest {
+                passkey_id: passkey_id.into(),
+                name: name.into(),
+            },
+        )?;
+
+        self.client()
+            .rename_passkey(req)
+            .await
+            .map_err(map_status)?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn delete_passkey(
+        &self,
+        access_token: &str,
+        passkey_id: &str,
+    ) -> Result<(), AuthError> {
+        let req = Self::authed_request(
+            access_token,
+            forage_grpc::DeletePasskeyRequest {
+                passkey_id: passkey_id.into(),
+            },
+        )?;
+
+        self.client()
+            .delete_passkey(req)
+            .await
+            .map_err(map_status)?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn begin_passkey_login(
+        &self,
+        mfa_session_token: Option<&str>,
+    ) -> Result<PasskeyCeremony, AuthError> {
+        let resp = self
+            .client()
+            .begin_passkey_login(forage_grpc::BeginPasskeyLoginRequest {
+                mfa_session_token: mfa_session_token.unwrap_or_default().into(),
+            })
+            .await
+            .map_err(map_status)?
+            .into_inner();
+
+        convert_passkey_ceremony(resp.ceremony)
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn finish_passkey_login(
+        &self,
+        response: &PasskeyResponse,
+    ) -> Result<AuthTokens, AuthError> {
+        let resp = self
+            .client()
+            .finish_passkey_login(forage_grpc::FinishPasskeyLoginRequest {
+                ceremony_id: response.ceremony_id.clone(),
+                credential_json: response.credential_json.clone(),
+            })
+            .await
+            .map_err(map_status)?
+            .into_inner();
+
+        let tokens = resp
+            .tokens
+            .ok_or(AuthError::Other("no tokens in passkey login response".into()))?;
+        Ok(AuthTokens {
+            access_token: tokens.access_token,
+            refresh_token: tokens.refresh_token,
+            expires_in_seconds: tokens.expires_in_seconds,
+        })
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn begin_passkey_verification(
+        &self,
+        user_id: &str,
+    ) -> Result<PasskeyCeremony, AuthError> {
+        let service_key = self
+            .service_account_key
+            .as_deref()
+            .ok_or(AuthError::Other("service account key not configured".into()))?;
+
+        let req = bearer_request(
+            service_key,
+            forage_grpc::BeginPasskeyVerificationRequest {
+                user_id: user_id.into(),
+            },
+        )
+        .map_err(AuthError::Other)?;
+
+        let resp = self
+            .client()
+            .begin_passkey_verification(req)
+            .await
+            .map_err(map_status)?
+            .into_inner();
+
+        convert_passkey_ceremony(resp.ceremony)
+    }
+
     #[tracing::instrument(skip_all)]
     async fn refresh_token(&self, refresh_token: &str) -> Result<AuthTokens, AuthError> {
         let resp = self
@@ -753,6 +963,7 @@ impl ForestAuth for GrpcForestClient {
             "gitlab" => forage_grpc::OAuthProvider::OauthProviderGitlab as i32,
             "microsoft" => forage_grpc::OAuthProvider::OauthProviderMicrosoft as i32,
             "magic-link" => forage_grpc::OAuthProvider::OauthProviderMagicLink as i32,
+            "sso" => forage_grpc::OAuthProvider::OauthProviderSso as i32,
             _ => return Err(AuthError::Other(format!("unsupported OAuth provider: {provider}"))),
         };
 
@@ -901,6 +1112,7 @@ impl ForestAuth for GrpcForestClient {
         user_id: &str,
         approving_ip: &str,
         approving_user_agent: &str,
+        passkey: Option<&PasskeyResponse>,
     ) -> Result<(), AuthError> {
         let service_key = self
             .service_account_key
@@ -914,6 +1126,10 @@ impl ForestAuth for GrpcForestClient {
                 user_id: user_id.into(),
                 approving_ip: approving_ip.into(),
                 approving_user_agent: approving_user_agent.into(),
+                passkey_ceremony_id: passkey.map(|p| p.ceremony_id.clone()).unwrap_or_default(),
+                passkey_credential_json: passkey
+                    .map(|p| p.credential_json.clone())
+                    .unwrap_or_default(),
             },
         )
         .map_err(AuthError::Other)?;
@@ -963,6 +1179,9 @@ fn linked_provider_to_proto(p: forage_core::auth::LinkedProvider) -> Option<i32>
         forage_core::auth::LinkedProvider::Google => {
             Some(forage_grpc::OAuthProvider::OauthProviderGoogle as i32)
         }
+        forage_core::auth::LinkedProvider::Sso => {
+            Some(forage_grpc::OAuthProvider::OauthProviderSso as i32)
+        }
         forage_core::auth::LinkedProvider::Slack => None,
     }
 }
@@ -1122,16 +1341,30 @@ fn convert_artifact(a: forage_grpc::Artifact) -> Artifact {
 
 fn convert_pipeline_stage(s: forage_grpc::PipelineStage) -> PipelineStage {
     let config = match s.config {
-        Some(forage_grpc::pipeline_stage::Config::Deploy(d)) => {
-            PipelineStageConfig::Deploy { environment: d.environment }
-        }
+        Some(forage_grpc::pipeline_stage::Config::Deploy(d)) => PipelineStageConfig::Deploy {
+            environment: d.environment,
+            batches: d
+                .batches
+                .into_iter()
+                .map(|b| forage_core::platform::DeployBatch {
+                    destinations: b.destinations,
+                    labels: b.labels.into_iter().collect(),
+                    percentage: b.percentage,
+                    wait_seconds: b.wait_seconds,
+                    health_check: b.health_check,
+                })
+                .collect(),
+        },
         Some(forage_grpc::pipeline_stage::Config::Wait(w)) => {
             PipelineStageConfig::Wait { duration_seconds: w.duration_seconds }
         }
         Some(forage_grpc::pipeline_stage::Config::Plan(p)) => {
             PipelineStageConfig::Plan { environment: p.environment, auto_approve: p.auto_approve }
         }
-        None => PipelineStageConfig::Deploy { environment: String::new() },
+        None => PipelineStageConfig::Deploy {
+            environment: String::new(),
+            batches: Vec::new(),
+        },
     };
     PipelineStage {
         id: s.id,
@@ -1151,15 +1384,7 @@ fn convert_pipeline_stage_state(
         Ok(forage_grpc::PipelineRunStageType::Plan) => "plan",
         _ => "unknown",
     };
-    let status = match forage_grpc::PipelineRunStageStatus::try_from(s.status) {
-        Ok(forage_grpc::PipelineRunStageStatus::Pending) => "PENDING",
-        Ok(forage_grpc::PipelineRunStageStatus::Active) => "RUNNING",
-        Ok(forage_grpc::PipelineRunStageStatus::Succeeded) => "SUCCEEDED",
-        Ok(forage_grpc::PipelineRunStageStatus::Failed) => "FAILED",
-        Ok(forage_grpc::PipelineRunStageStatus::Cancelled) => "CANCELLED",
-        Ok(forage_grpc::PipelineRunStageStatus::AwaitingApproval) => "AWAITING_APPROVAL",
-        _ => "PENDING",
-    };
+    let status = run_stage_status_str(s.status);
     forage_core::platform::PipelineRunStageState {
         stage_id: s.stage_id,
         depends_on: s.depends_on,
@@ -1175,6 +1400,32 @@ fn convert_pipeline_stage_state(
         release_ids: s.release_ids,
         approval_status: s.approval_status,
         auto_approve: s.auto_approve,
+        batches: s
+            .batches
+            .into_iter()
+            .map(|b| forage_core::platform::PipelineRunBatchState {
+                destinations: b.destinations,
+                status: run_stage_status_str(b.status).into(),
+                started_at: b.started_at,
+                completed_at: b.completed_at,
+                wait_until: b.wait_until,
+                release_ids: b.release_ids,
+                error_message: b.error_message,
+                health_check: b.health_check,
+            })
+            .collect(),
+    }
+}
+
+fn run_stage_status_str(status: i32) -> &'static str {
+    match forage_grpc::PipelineRunStageStatus::try_from(status) {
+        Ok(forage_grpc::PipelineRunStageStatus::Pending) => "PENDING",
+        Ok(forage_grpc::PipelineRunStageStatus::Active) => "RUNNING",
+        Ok(forage_grpc::PipelineRunStageStatus::Succeeded) => "SUCCEEDED",
+        Ok(forage_grpc::PipelineRunStageStatus::Failed) => "FAILED",
+        Ok(forage_grpc::PipelineRunStageStatus::Cancelled) => "CANCELLED",
+        Ok(forage_grpc::PipelineRunStageStatus::AwaitingApproval) => "AWAITING_APPROVAL",
+        _ => "PENDING",
     }
 }
 
@@ -1202,9 +1453,19 @@ fn convert_stages_to_grpc(stages: &[PipelineStage]) -> Vec<forage_grpc::Pipeline
             id: s.id.clone(),
             depends_on: s.depends_on.clone(),
             config: Some(match &s.config {
-                PipelineStageConfig::Deploy { environment } => {
+                PipelineStageConfig::Deploy { environment, batches } => {
                     forage_grpc::pipeline_stage::Config::Deploy(forage_grpc::DeployStageConfig {
                         environment: environment.clone(),
+                        batches: batches
+                            .iter()
+                            .map(|b| forage_grpc::DeployBatch {
+                                destinations: b.destinations.clone(),
+                                labels: b.labels.clone().into_iter().collect(),
+                                percentage: b.percentage,
+                                wait_seconds: b.wait_seconds,
+                                health_check: b.health_check,
+                            })
+                            .collect(),
                     })
                 }
                 PipelineStageConfig::Wait { duration_seconds } => {
@@ -1253,6 +1514,40 @@ fn convert_trigger(t: forage_grpc::Trigger) -> Trigger {
     }
 }
 
+fn convert_release_schedule(s: forage_grpc::ReleaseSchedule) -> ReleaseSchedule {
+    let status = match forage_grpc::ReleaseScheduleStatus::try_from(s.status) {
+        Ok(forage_grpc::ReleaseScheduleStatus::Completed) => "COMPLETED",
+        Ok(forage_grpc::ReleaseScheduleStatus::Cancelled) => "CANCELLED",
+        _ => "ACTIVE",
+    };
+    ReleaseSchedule {
+        id: s.id,
+        run_at: s.run_at,
+        cron: s.cron,
+        artifact_id: s.artifact_id,
+        branch: s.branch,
+        environments: s.environments,
+        destinations: s.destinations,
+        use_pipeline: s.use_pipeline,
+        status: status.into(),
+        next_run_at: s.next_run_at,
+        last_run: s.last_run.map(|r| {
+            let outcome = match forage_grpc::ReleaseScheduleOutcome::try_from(r.outcome) {
+                Ok(forage_grpc::ReleaseScheduleOutcome::Released) => "RELEASED",
+                Ok(forage_grpc::ReleaseScheduleOutcome::Skipped) => "SKIPPED",
+                Ok(forage_grpc::ReleaseScheduleOutcome::Blocked) => "BLOCKED",
+                _ => "FAILED",
+            };
+            ReleaseScheduleRun {
+                ran_at: r.ran_at,
+                outcome: outcome.into(),
+                message: r.message,
+                release_intent_id: r.release_intent_id,
+            }
+        }),
+    }
+}
+
 fn convert_policy(p: forage_grpc::Policy) -> Policy {
     let policy_type_str = match forage_grpc::PolicyType::try_from(p.policy_type) {
         Ok(forage_grpc::PolicyType::SoakTime) => "soak_time",
@@ -1342,6 +1637,24 @@ fn policy_config_to_grpc(
     }
 }
 
+fn convert_environment(e: forage_grpc::Environment) -> Environment {
+    let protection = e.protection.unwrap_or_default();
+    Environment {
+        id: e.id,
+        organisation: e.organisation,
+        name: e.name,
+        description: e.description.filter(|v| !v.is_empty()),
+        sort_order: e.sort_order,
+        created_at: e.created_at,
+        protection: EnvironmentProtection {
+            protected: protection.protected,
+            required_prior_environment: protection.required_prior_environment,
+            approver_groups: protection.approver_groups,
+            allowed_branches: protection.allowed_branches,
+        },
+    }
+}
+
 fn convert_member(m: forage_grpc::OrganisationMember) -> OrgMember {
     OrgMember {
         user_id: m.user_id,
@@ -1601,6 +1914,27 @@ impl ForestPlatform for GrpcForestClient {
         Ok(convert_member(member))
     }
 
+    async fn set_sso_enforcement(
+        &self,
+        access_token: &str,
+        organisation_id: &str,
+        enforced: bool,
+    ) -> Result<(), PlatformError> {
+        let req = platform_authed_request(
+            access_token,
+            forage_grpc::SetSsoEnforcementRequest {
+                organisation_id: organisation_id.into(),
+                enforced,
+            },
+        )?;
+
+        self.org_client()
+            .set_sso_enforcement(req)
+            .await
+            .map_err(map_platform_status)?;
+        Ok(())
+    }
+
     #[tracing::instrument(skip_all)]
     async fn get_artifact_by_slug(
         &self,
@@ -1648,14 +1982,7 @@ impl ForestPlatform for GrpcForestClient {
         Ok(resp
             .environments
             .into_iter()
-            .map(|e| Environment {
-                id: e.id,
-                organisation: e.organisation,
-                name: e.name,
-                description: e.description.filter(|v| !v.is_empty()),
-                sort_order: e.sort_order,
-                created_at: e.created_at,
-            })
+            .map(convert_environment)
             .collect())
     }
 
@@ -1720,14 +2047,7 @@ impl ForestPlatform for GrpcForestClient {
         let e = resp
             .environment
             .ok_or(PlatformError::Other("no environment in response".into()))?;
-        Ok(Environment {
-            id: e.id,
-            organisation: e.organisation,
-            name: e.name,
-            description: e.description.filter(|v| !v.is_empty()),
-            sort_order: e.sort_order,
-            created_at: e.created_at,
-        })
+        Ok(convert_environment(e))
     }
 
     #[tracing::instrument(skip_all)]
@@ -1737,6 +2057,7 @@ impl ForestPlatform for GrpcForestClient {
         id: &str,
         description: Option<&str>,
         sort_order: Option<i32>,
+        protection: Option<&EnvironmentProtection>,
     ) -> Result<Environment, PlatformError> {
         let req = platform_authed_request(
             access_token,
@@ -1744,6 +2065,12 @@ impl ForestPlatform for GrpcForestClient {
                 id: id.into(),
                 description: description.map(|s| s.to_string()),
                 sort_order,
+                protection: protection.map(|p| forage_grpc::EnvironmentProtection {
+                    protected: p.protected,
+                    required_prior_environment: p.required_prior_environment.clone(),
+                    approver_groups: p.approver_groups.clone(),
+                    allowed_branches: p.allowed_branches.clone(),
+                }),
             },
         )?;
         let resp = self
@@ -1755,14 +2082,7 @@ impl ForestPlatform for GrpcForestClient {
         let e = resp
             .environment
             .ok_or(PlatformError::Other("no environment in response".into()))?;
-        Ok(Environment {
-            id: e.id,
-            organisation: e.organisation,
-            name: e.name,
-            description: e.description.filter(|v| !v.is_empty()),
-            sort_order: e.sort_order,
-            created_at: e.created_at,
-        })
+        Ok(convert_environment(e))
     }
 
     #[tracing::instrument(skip_all)]
@@ -1789,6 +2109,8 @@ impl ForestPlatform for GrpcForestClient {
                     description: String::new(),
                     fields: vec![],
                 }),
+                runner_labels: Default::default(),
+                queue_policy: Default::default(),
             },
         )?;
         self.dest_client()
@@ -1850,6 +2172,8 @@ impl ForestPlatform for GrpcForestClient {
                 name: name.into(),
                 metadata: metadata.clone(),
                 organisation: organisation.into(),
+                runner_labels: None,
+                queue_policy: None,
             },
         )?;
         self.dest_client()
@@ -2122,6 +2446,39 @@ impl ForestPlatform for GrpcForestClient {
         Ok(())
     }
 
+    #[tracing::instrument(skip_all)]
+    async fn list_release_schedules(
+        &self,
+        access_token: &str,
+        organisation: &str,
+        project: &str,
+    ) -> Result<Vec<ReleaseSchedule>, PlatformError> {
+        let req = platform_authed_request(
+            access_token,
+            forage_grpc::ListReleaseSchedulesRequest {
+                project: Some(forage_grpc::Project {
+                    organisation: organisation.into(),
+                    project: project.into(),
+                    readme: String::new(),
+                    description: String::new(),
+                    metadata: Some(Default::default()),
+                }),
+                include_inactive: false,
+            },
+        )?;
+        let resp = self
+            .release_schedule_client()
+            .list_release_schedules(req)
+            .await
+            .map_err(map_platform_status)?
+            .into_inner();
+        Ok(resp
+            .schedules
+            .into_iter()
+            .map(convert_release_schedule)
+            .collect())
+    }
+
     async fn list_policies(
         &self,
         access_token: &str,
@@ -2515,6 +2872,7 @@ impl ForestPlatform for GrpcForestClient {
         target_environment: &str,
         comment: Option<&str>,
         force_bypass: bool,
+        on_behalf_of_user_id: Option<&str>,
     ) -> Result<ApprovalState, PlatformError> {
         let req = platform_authed_request(
             access_token,
@@ -2530,6 +2888,7 @@ impl ForestPlatform for GrpcForestClient {
                 target_environment: target_environment.into(),
                 comment: comment.map(|s| s.to_string()),
                 force_bypass,
+                on_behalf_of_user_id: on_behalf_of_user_id.map(|s| s.to_string()),
             },
         )?;
         let resp = self
@@ -2549,6 +2908,7 @@ impl ForestPlatform for GrpcForestClient {
         release_intent_id: &str,
         target_environment: &str,
         comment: Option<&str>,
+        on_behalf_of_user_id: Option<&str>,
     ) -> Result<ApprovalState, PlatformError> {
         let req = platform_authed_request(
             access_token,
@@ -2563,6 +2923,7 @@ impl ForestPlatform for GrpcForestClient {
                 release_intent_id: release_intent_id.into(),
                 target_environment: target_environment.into(),
                 comment: comment.map(|s| s.to_string()),
+                on_behalf_of_user_id: on_behalf_of_user_id.map(|s| s.to_string()),
             },
         )?;
         let resp = self
@@ -2609,12 +2970,14 @@ impl ForestPlatform for GrpcForestClient {
         access_token: &str,
         release_intent_id: &str,
         stage_id: &str,
+        on_behalf_of_user_id: Option<&str>,
     ) -> Result<(), PlatformError> {
         let req = platform_authed_request(
             access_token,
             forage_grpc::ApprovePlanStageRequest {
                 release_intent_id: release_intent_id.into(),
                 stage_id: stage_id.into(),
+                on_behalf_of_user_id: on_behalf_of_user_id.map(|s| s.to_string()),
             },
         )?;
         self.release_client()
@@ -2624,12 +2987,33 @@ impl ForestPlatform for GrpcForestClient {
         Ok(())
     }
 
+    async fn cancel_release(
+        &self,
+        access_token: &str,
+        release_id: &str,
+        reason: Option<&str>,
+    ) -> Result<(), PlatformError> {
+        let req = platform_authed_request(
+            access_token,
+            forage_grpc::CancelReleaseRequest {
+                release_id: release_id.into(),
+                reason: reason.map(|r| r.into()),
+            },
+        )?;
+        self.release_client()
+            .cancel_release(req)
+            .await
+            .map_err(map_platform_status)?;
+        Ok(())
+    }
+
     async fn reject_plan_stage(
         &self,
         access_token: &str,
         release_intent_id: &str,
         stage_id: &str,
         reason: Option<&str>,
+        on_behalf_of_user_id: Option<&str>,
     ) -> Result<(), PlatformError> {
         let req = platform_authed_request(
             access_token,
@@ -2637,6 +3021,7 @@ impl ForestPlatform for GrpcForestClient {
                 release_intent_id: release_intent_id.into(),
                 stage_id: stage_id.into(),
                 reason: reason.map(|s| s.into()),
+                on_behalf_of_user_id: on_behalf_of_user_id.map(|s| s.to_string()),
             },
         )?;
         self.release_client()
@@ -1,6 +1,9 @@
 mod auth;
 mod compute_grpc;
+mod delivery_worker;
+mod dns;
 mod email_consumer;
+mod email_digest;
 mod forest_client;
 mod oidc;
 mod notification_consumer;
@@ -9,6 +12,7 @@ mod manifest_view;
 mod notification_worker;
 mod pretty_json;
 mod routes;
+mod saml;
 mod serve_grpc;
 mod serve_http;
 mod session_reaper;
@@ -120,6 +124,7 @@ async fn main() -> anyhow::Result<()> {
         Option<Arc<dyn forage_core::integrations::IntegrationStore>>,
         Option<Arc<dyn forage_core::auth::magic_link::MagicLinkStore>>,
     );
+    let sso_store: Option<Arc<dyn forage_core::auth::sso::SsoConfigStore>>;
     let state_profile_pictures: Option<Arc<forage_db::PgProfilePictureStore>>;
 
     if let Ok(database_url) = std::env::var("DATABASE_URL") {
@@ -137,6 +142,10 @@ async fn main() -> anyhow::Result<()> {
             "forage-dev-key-not-for-production!!".to_string()
         });
         let pg_integrations = Arc::new(forage_db::PgIntegrationStore::new(
+            pool.clone(),
+            encryption_key.clone().into_bytes(),
+        ));
+        let pg_sso = Arc::new(forage_db::PgSsoConfigStore::new(
             pool.clone(),
             encryption_key.into_bytes(),
         ));
@@ -156,6 +165,7 @@ async fn main() -> anyhow::Result<()> {
             Some(pg_integrations as Arc<dyn forage_core::integrations::IntegrationStore>);
         magic_link_store =
             Some(pg_magic_link as Arc<dyn forage_core::auth::magic_link::MagicLinkStore>);
+        sso_store = Some(pg_sso as Arc<dyn forage_core::auth::sso::SsoConfigStore>);
         state_profile_pictures = Some(pg_profile_pictures);
     } else {
         let session_dir = std::env::var("SESSION_DIR").unwrap_or_else(|_| "target/sessions".into());
@@ -173,6 +183,7 @@ async fn main() -> anyhow::Result<()> {
         sessions = file_store as Arc<dyn SessionStore>;
         integration_store = None;
         magic_link_store = None;
+        sso_store = None;
         state_profile_pictures = None;
     };
 
@@ -196,6 +207,12 @@ async fn main() -> anyhow::Result<()> {
         state = state.with_service_account_key(key.to_string());
     }
 
+    if let Some(store) = sso_store {
+        state = state
+            .with_sso_store(store)
+            .with_domain_txt_lookup(Arc::new(dns::SystemTxtLookup::new()?));
+    }
+
     if let Some(store) = state_profile_pictures {
         state = state.with_profile_picture_store(store);
     }
@@ -210,6 +227,7 @@ async fn main() -> anyhow::Result<()> {
             client_id,
             client_secret,
             redirect_host: forage_host.clone(),
+            signing_secret: env_var_nonempty("SLACK_SIGNING_SECRET"),
         });
     }
 
@@ -282,6 +300,18 @@ async fn main() -> anyhow::Result<()> {
         // manage.
         if let Some(service_token) = forest_client.service_account_key().map(String::from) {
             let forage_url = forage_host.clone();
+            let approval_buttons = state
+                .slack_config
+                .as_ref()
+                .is_some_and(|c| c.signing_secret.is_some());
+
+            mad.add(delivery_worker::DeliveryWorker {
+                store: store.clone(),
+                forage_url: forage_url.clone(),
+                grpc: forest_client.clone(),
+                service_token: service_token.clone(),
+                approval_buttons,
+            });
 
             if let Some(ref js) = nats_jetstream {
                 // JetStream mode: ingester publishes, consumer dispatches
@@ -299,6 +329,7 @@ async fn main() -> anyhow::Result<()> {
                     forage_url,
                     grpc: grpc_for_consumer,
                     service_token: token_for_consumer,
+                    approval_buttons,
                 });
             } else {
                 // Fallback: direct dispatch (no durability)
@@ -310,6 +341,7 @@ async fn main() -> anyhow::Result<()> {
                     store: store.clone(),
                     service_token,
                     forage_url,
+                    approval_buttons,
                 });
             }
         } else {
@@ -335,6 +367,15 @@ async fn main() -> anyhow::Result<()> {
         }
     }
 
+    // Email digest integrations publish through the same email stream.
+    if let (Some(store), Some(js)) = (&integration_store, &state.email_jetstream) {
+        mad.add(email_digest::EmailDigestSender {
+            store: store.clone(),
+            jetstream: js.clone(),
+            forage_url: forage_host.clone(),
+        });
+    }
+
     // Compute scheduler (mock for now — simulates container lifecycle)
     let compute_scheduler = Arc::new(forage_core::compute::InMemoryComputeScheduler::new());
     state = state.with_compute_scheduler(compute_scheduler.clone());
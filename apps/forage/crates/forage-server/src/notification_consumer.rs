@@ -21,6 +21,8 @@ pub struct NotificationConsumer {
     pub forage_url: String,
     pub grpc: Arc<GrpcForestClient>,
     pub service_token: String,
+    /// Attach approve/reject buttons to Slack messages (see `NotificationDispatcher`).
+    pub approval_buttons: bool,
 }
 
 impl Component for NotificationConsumer {
@@ -31,7 +33,8 @@ impl Component for NotificationConsumer {
     async fn run(&self, cancellation_token: CancellationToken) -> Result<(), MadError> {
         let dispatcher = Arc::new(
             NotificationDispatcher::new(self.store.clone(), self.forage_url.clone())
-                .with_grpc(self.grpc.clone(), self.service_token.clone()),
+                .with_grpc(self.grpc.clone(), self.service_token.clone())
+                .with_approval_buttons(self.approval_buttons),
         );
 
         let mut backoff = 1u64;
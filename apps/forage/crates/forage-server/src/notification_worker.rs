@@ -1,9 +1,16 @@
 use std::sync::Arc;
 use std::time::Duration;
 
-use forage_core::integrations::router::{DispatchTask, NotificationEvent, ReleaseContext};
+use forage_core::integrations::router::{
+    DispatchTask, NotificationEvent, QueuedTask, ReleaseContext,
+};
+use forage_core::integrations::slack::SlackApprovalTarget;
+use forage_core::integrations::{discord, email, pagerduty, teams};
 use forage_core::integrations::webhook::sign_payload;
-use forage_core::integrations::{DeliveryStatus, IntegrationStore};
+use forage_core::integrations::{
+    DeliveryAttempt, DeliveryStatus, IntegrationError, IntegrationStore, NotificationDelivery,
+};
+use forage_core::platform::ForestPlatform;
 use notmad::{Component, ComponentInfo, MadError};
 use tokio_util::sync::CancellationToken;
 
@@ -20,6 +27,11 @@ pub struct NotificationDispatcher {
     grpc: Option<Arc<GrpcForestClient>>,
     /// Service token for authenticating gRPC calls to fetch pipeline state.
     service_token: String,
+    /// Post approve/reject buttons for pending approvals. Only enabled when
+    /// the Slack interactivity endpoint can verify the clicks.
+    approval_buttons: bool,
+    /// PagerDuty Events v2 endpoint; overridden in tests.
+    pagerduty_events_url: String,
 }
 
 impl NotificationDispatcher {
@@ -28,7 +40,15 @@ impl NotificationDispatcher {
             .timeout(Duration::from_secs(10))
             .build()
             .expect("failed to build reqwest client");
-        Self { http, store, forage_url, grpc: None, service_token: String::new() }
+        Self {
+            http,
+            store,
+            forage_url,
+            grpc: None,
+            service_token: String::new(),
+            approval_buttons: false,
+            pagerduty_events_url: pagerduty::PAGERDUTY_EVENTS_URL.into(),
+        }
     }
 
     pub fn with_grpc(mut self, grpc: Arc<GrpcForestClient>, service_token: String) -> Self {
@@ -37,91 +57,207 @@ impl NotificationDispatcher {
         self
     }
 
-    /// Execute a dispatch task with retry (3 attempts, exponential backoff).
+    pub fn with_approval_buttons(mut self, enabled: bool) -> Self {
+        self.approval_buttons = enabled;
+        self
+    }
+
+    #[cfg(test)]
+    pub fn with_pagerduty_events_url(mut self, url: String) -> Self {
+        self.pagerduty_events_url = url;
+        self
+    }
+
+    /// Deliver a dispatch task through the persistent delivery queue.
+    ///
+    /// The delivery is stored first, then attempted inline while the retry
+    /// schedule's delays are short. If it still fails it stays queued and the
+    /// `DeliveryWorker` keeps retrying it across restarts until it succeeds or
+    /// is dead-lettered. Only the task's [`QueuedTask`] is stored; queued
+    /// attempts read the integration's config again.
     pub async fn dispatch(&self, task: &DispatchTask) {
-        let (integration_id, notification_id) = match task {
-            DispatchTask::Webhook {
-                integration_id,
-                payload,
-                ..
-            } => (integration_id.clone(), payload.notification_id.clone()),
-            DispatchTask::Slack {
-                integration_id,
-                notification_id,
-                ..
-            } => (integration_id.clone(), notification_id.clone()),
-            DispatchTask::SlackDm {
-                integration_id,
-                notification_id,
-                ..
-            } => (integration_id.clone(), notification_id.clone()),
+        let integration_id = task.integration_id();
+        let task_json = match serde_json::to_value(task.to_queued()) {
+            Ok(v) => v,
+            Err(e) => {
+                tracing::error!(integration_id = %integration_id, error = %e, "failed to serialize dispatch task");
+                return;
+            }
         };
 
-        let delays = [1, 5, 25]; // seconds
-        for (attempt, delay) in delays.iter().enumerate() {
-            match self.try_dispatch(task).await {
-                Ok(()) => {
-                    tracing::info!(
-                        integration_id = %integration_id,
-                        attempt = attempt + 1,
-                        "notification delivered"
-                    );
-                    let _ = self
-                        .store
-                        .record_delivery(&integration_id, &notification_id, DeliveryStatus::Delivered, None)
-                        .await;
-                    return;
+        let lease_until = chrono::Utc::now() + chrono::Duration::seconds(INLINE_LEASE_SECS);
+        let mut delivery = match self
+            .store
+            .enqueue_delivery(integration_id, task.notification_id(), task_json, lease_until)
+            .await
+        {
+            Ok(d) => d,
+            Err(e) => {
+                // Without a queue entry we can still try once; nothing will retry it.
+                tracing::error!(integration_id = %integration_id, error = %e, "failed to enqueue delivery, attempting once");
+                if let Err(e) = self.try_dispatch(task).await {
+                    tracing::error!(integration_id = %integration_id, error = %e.message, "unqueued delivery failed");
                 }
-                Err(e) => {
-                    // Don't retry errors that will never succeed
-                    let non_retryable = is_non_retryable_error(&e);
-                    if non_retryable {
-                        tracing::error!(
-                            integration_id = %integration_id,
-                            error = %e,
-                            "non-retryable delivery error"
-                        );
-                        let _ = self
-                            .store
-                            .record_delivery(
-                                &integration_id,
-                                &notification_id,
-                                DeliveryStatus::Failed,
-                                Some(&e),
-                            )
-                            .await;
-                        return;
-                    }
+                return;
+            }
+        };
 
-                    tracing::warn!(
+        loop {
+            let mut outcome = self.attempt(delivery.attempts, task).await;
+            delivery.attempts += 1;
+            let inline_wait = match (outcome.status, outcome.next_attempt_at) {
+                (DeliveryStatus::Failed, Some(next))
+                    if next - chrono::Utc::now()
+                        <= chrono::Duration::seconds(INLINE_RETRY_MAX_DELAY_SECS) =>
+                {
+                    // Keep the entry leased so the worker doesn't race us.
+                    outcome.next_attempt_at = Some(lease_until);
+                    Some((next - chrono::Utc::now()).to_std().unwrap_or_default())
+                }
+                _ => None,
+            };
+            self.record_attempt(&delivery.id, &outcome).await;
+            match inline_wait {
+                Some(wait) => tokio::time::sleep(wait).await,
+                None => return,
+            }
+        }
+    }
+
+    /// Claim due deliveries from the queue and attempt each once.
+    /// Returns how many were attempted.
+    pub async fn process_due(&self, limit: usize) -> usize {
+        let now = chrono::Utc::now();
+        let lease_until = now + chrono::Duration::seconds(INLINE_LEASE_SECS);
+        let claimed = match self.store.claim_due_deliveries(now, lease_until, limit).await {
+            Ok(c) => c,
+            Err(e) => {
+                tracing::warn!(error = %e, "failed to claim due deliveries");
+                return 0;
+            }
+        };
+
+        for delivery in &claimed {
+            self.deliver_queued(delivery).await;
+        }
+        claimed.len()
+    }
+
+    /// Attempt a stored delivery once, e.g. after a redeliver, against the
+    /// integration as it is now.
+    pub async fn deliver_queued(&self, delivery: &NotificationDelivery) {
+        let queued = delivery
+            .task
+            .clone()
+            .map(serde_json::from_value::<QueuedTask>);
+        let Some(Ok(queued)) = queued else {
+            // Unreadable payload (e.g. written by an incompatible version).
+            self.dead_letter(&delivery.id, "stored payload could not be decoded")
+                .await;
+            return;
+        };
+
+        let integration = match self
+            .store
+            .get_integration(queued.organisation(), &delivery.integration_id)
+            .await
+        {
+            Ok(integration) => integration,
+            Err(IntegrationError::NotFound(_)) => {
+                self.dead_letter(&delivery.id, "integration was deleted").await;
+                return;
+            }
+            Err(e) => {
+                // Left leased; the queue picks it up again once the lease runs out.
+                tracing::warn!(delivery_id = %delivery.id, error = %e, "failed to load integration for delivery");
+                return;
+            }
+        };
+        if !integration.enabled {
+            self.dead_letter(&delivery.id, "integration is disabled").await;
+            return;
+        }
+        let Some(task) = queued.resolve(&integration) else {
+            self.dead_letter(&delivery.id, "integration no longer takes this delivery")
+                .await;
+            return;
+        };
+
+        let outcome = self.attempt(delivery.attempts, &task).await;
+        self.record_attempt(&delivery.id, &outcome).await;
+    }
+
+    async fn dead_letter(&self, delivery_id: &str, reason: &str) {
+        tracing::warn!(delivery_id = %delivery_id, reason = %reason, "delivery dead-lettered");
+        self.record_attempt(
+            delivery_id,
+            &DeliveryAttempt {
+                status: DeliveryStatus::DeadLettered,
+                response_code: None,
+                error_message: Some(reason.into()),
+                next_attempt_at: None,
+            },
+        )
+        .await;
+    }
+
+    /// Make one attempt and work out what the queue should do next.
+    async fn attempt(&self, previous_attempts: u32, task: &DispatchTask) -> DeliveryAttempt {
+        let integration_id = task.integration_id();
+        let attempts = previous_attempts + 1;
+        match self.try_dispatch(task).await {
+            Ok(response_code) => {
+                tracing::info!(integration_id = %integration_id, attempt = attempts, "notification delivered");
+                DeliveryAttempt {
+                    status: DeliveryStatus::Delivered,
+                    response_code,
+                    error_message: None,
+                    next_attempt_at: None,
+                }
+            }
+            Err(e) => {
+                // Don't retry errors that will never succeed
+                let next = if is_non_retryable_error(&e.message) {
+                    None
+                } else {
+                    forage_core::integrations::delivery_retry_delay(attempts)
+                        .map(|d| chrono::Utc::now() + d)
+                };
+                match next {
+                    Some(_) => tracing::warn!(
                         integration_id = %integration_id,
-                        attempt = attempt + 1,
-                        error = %e,
+                        attempt = attempts,
+                        error = %e.message,
                         "delivery attempt failed"
-                    );
-                    if attempt < delays.len() - 1 {
-                        tokio::time::sleep(Duration::from_secs(*delay)).await;
+                    ),
+                    None => tracing::error!(
+                        integration_id = %integration_id,
+                        attempt = attempts,
+                        error = %e.message,
+                        "delivery dead-lettered"
+                    ),
+                }
+                DeliveryAttempt {
+                    status: if next.is_some() {
+                        DeliveryStatus::Failed
                     } else {
-                        tracing::error!(
-                            integration_id = %integration_id,
-                            "all delivery attempts exhausted"
-                        );
-                        let _ = self
-                            .store
-                            .record_delivery(
-                                &integration_id,
-                                &notification_id,
-                                DeliveryStatus::Failed,
-                                Some(&e),
-                            )
-                            .await;
-                    }
+                        DeliveryStatus::DeadLettered
+                    },
+                    response_code: e.response_code,
+                    error_message: Some(e.message),
+                    next_attempt_at: next,
                 }
             }
         }
     }
 
-    async fn try_dispatch(&self, task: &DispatchTask) -> Result<(), String> {
+    async fn record_attempt(&self, delivery_id: &str, outcome: &DeliveryAttempt) {
+        if let Err(e) = self.store.record_delivery_attempt(delivery_id, outcome).await {
+            tracing::error!(delivery_id = %delivery_id, error = %e, "failed to record delivery attempt");
+        }
+    }
+
+    async fn try_dispatch(&self, task: &DispatchTask) -> Result<Option<u16>, DispatchFailure> {
         match task {
             DispatchTask::Webhook {
                 url,
@@ -130,8 +266,8 @@ impl NotificationDispatcher {
                 payload,
                 ..
             } => {
-                let body =
-                    serde_json::to_vec(payload).map_err(|e| format!("serialize: {e}"))?;
+                let body = serde_json::to_vec(payload)
+                    .map_err(|e| DispatchFailure::from(format!("serialize: {e}")))?;
 
                 let mut req = self
                     .http
@@ -152,14 +288,17 @@ impl NotificationDispatcher {
                     .body(body)
                     .send()
                     .await
-                    .map_err(|e| format!("http: {e}"))?;
+                    .map_err(|e| DispatchFailure::from(format!("http: {e}")))?;
 
                 let status = resp.status();
                 if status.is_success() {
-                    Ok(())
+                    Ok(Some(status.as_u16()))
                 } else {
                     let body = resp.text().await.unwrap_or_default();
-                    Err(format!("HTTP {status}: {body}"))
+                    Err(DispatchFailure {
+                        message: format!("HTTP {status}: {body}"),
+                        response_code: Some(status.as_u16()),
+                    })
                 }
             }
             DispatchTask::Slack {
@@ -184,6 +323,8 @@ impl NotificationDispatcher {
                         event,
                     )
                     .await
+                    .map(|()| None)
+                    .map_err(DispatchFailure::from)
                 } else {
                     // Fallback: webhook URL (no update-in-place possible)
                     self.dispatch_slack_webhook(webhook_url, message).await
@@ -211,7 +352,72 @@ impl NotificationDispatcher {
                     event,
                 )
                 .await
+                .map(|()| None)
+                .map_err(DispatchFailure::from)
+            }
+            DispatchTask::Teams {
+                webhook_url, event, ..
+            } => {
+                let payload = teams::format_teams_message(event, &self.forage_url);
+                self.post_json(webhook_url, &payload, "Teams").await
+            }
+            DispatchTask::Discord {
+                webhook_url, event, ..
+            } => {
+                let payload = discord::format_discord_message(event, &self.forage_url);
+                self.post_json(webhook_url, &payload, "Discord").await
+            }
+            DispatchTask::PagerDuty {
+                routing_key, event, ..
+            } => {
+                let Some(payload) =
+                    pagerduty::build_pagerduty_event(event, routing_key, &self.forage_url)
+                else {
+                    return Ok(None);
+                };
+                self.post_json(&self.pagerduty_events_url, &payload, "PagerDuty")
+                    .await
             }
+            DispatchTask::EmailDigest {
+                integration_id,
+                event,
+                ..
+            } => {
+                // Queued here; the digest sender mails it on the integration's schedule.
+                self.store
+                    .enqueue_digest_entry(&email::digest_entry_from_event(integration_id, event))
+                    .await
+                    .map(|()| None)
+                    .map_err(|e| DispatchFailure::from(format!("digest enqueue: {e}")))
+            }
+        }
+    }
+
+    /// POST a JSON body to a chat/incident webhook and map non-2xx to an error.
+    async fn post_json(
+        &self,
+        url: &str,
+        payload: &serde_json::Value,
+        label: &str,
+    ) -> Result<Option<u16>, DispatchFailure> {
+        let resp = self
+            .http
+            .post(url)
+            .header("User-Agent", "Forage/1.0")
+            .json(payload)
+            .send()
+            .await
+            .map_err(|e| DispatchFailure::from(format!("{label} http: {e}")))?;
+
+        let status = resp.status();
+        if status.is_success() {
+            Ok(Some(status.as_u16()))
+        } else {
+            let body = resp.text().await.unwrap_or_default();
+            Err(DispatchFailure {
+                message: format!("{label} HTTP {status}: {body}"),
+                response_code: Some(status.as_u16()),
+            })
         }
     }
 
@@ -227,7 +433,12 @@ impl NotificationDispatcher {
         event: &forage_core::integrations::router::NotificationEvent,
     ) -> Result<(), String> {
         use forage_core::integrations::{DestinationStatus, SlackMessageRef};
-        use forage_core::integrations::router::format_slack_message;
+        use forage_core::integrations::router::{format_slack_message, merges_into_release};
+
+        // Drift and schedule failures are reported on their own message:
+        // they have no rollout to merge into and no pipeline or approvals
+        // to show.
+        let release = event.release.as_ref().filter(|_| merges_into_release(event));
 
         // Get existing ref (with accumulated destinations) if we already posted
         let existing_ref = self
@@ -242,7 +453,7 @@ impl NotificationDispatcher {
             .map(|r| r.destinations.clone())
             .unwrap_or_default();
 
-        if let Some(ref r) = event.release {
+        if let Some(r) = release {
             if !r.destination.is_empty() {
                 let status = match event_type {
                     "release_started" => "started",
@@ -265,7 +476,7 @@ impl NotificationDispatcher {
         let mut message = format_slack_message(event, &destinations, &self.forage_url);
 
         // Query pipeline stages and insert before destinations
-        if let Some(ref r) = event.release {
+        if let Some(r) = release {
             if !r.release_intent_id.is_empty() {
                 if let Some(stages) = self
                     .fetch_pipeline_stages(&event.organisation, &event.project, &r.release_intent_id)
@@ -287,6 +498,16 @@ impl NotificationDispatcher {
                             message.blocks.insert(insert_at + i, block);
                         }
                     }
+
+                    // Approve/reject buttons go last, below the destinations.
+                    if self.approval_buttons {
+                        let targets = self
+                            .pending_approvals(event, &r.release_intent_id, &stages)
+                            .await;
+                        message.blocks.extend(
+                            forage_core::integrations::slack::approval_action_blocks(&targets),
+                        );
+                    }
                 }
             }
         }
@@ -480,12 +701,85 @@ impl NotificationDispatcher {
         }
     }
 
+    /// Approval gates in the pipeline that are waiting on a person: plan
+    /// stages awaiting approval, and ready deploy stages still short of the
+    /// approvals an external approval policy requires.
+    async fn pending_approvals(
+        &self,
+        event: &NotificationEvent,
+        release_intent_id: &str,
+        stages: &[forage_core::platform::PipelineRunStageState],
+    ) -> Vec<SlackApprovalTarget> {
+        let mut targets: Vec<SlackApprovalTarget> = Vec::new();
+
+        for stage in stages {
+            let Some(environment) = stage.environment.clone() else {
+                continue;
+            };
+            let target = match (stage.stage_type.as_str(), stage.status.as_str()) {
+                ("plan", "AWAITING_APPROVAL") => SlackApprovalTarget::PlanStage {
+                    organisation: event.organisation.clone(),
+                    project: event.project.clone(),
+                    release_intent_id: release_intent_id.to_string(),
+                    stage_id: stage.stage_id.clone(),
+                    environment,
+                },
+                ("deploy", "PENDING") => {
+                    let ready = stage.depends_on.iter().all(|dep| {
+                        stages
+                            .iter()
+                            .any(|s| &s.stage_id == dep && s.status == "SUCCEEDED")
+                    });
+                    let Some(grpc) = self.grpc.as_ref().filter(|_| ready) else {
+                        continue;
+                    };
+                    match grpc
+                        .get_approval_state(
+                            &self.service_token,
+                            &event.organisation,
+                            &event.project,
+                            release_intent_id,
+                            &environment,
+                        )
+                        .await
+                    {
+                        Ok(state) if state.current_approvals < state.required_approvals => {
+                            SlackApprovalTarget::Release {
+                                organisation: event.organisation.clone(),
+                                project: event.project.clone(),
+                                release_intent_id: release_intent_id.to_string(),
+                                environment,
+                            }
+                        }
+                        Ok(_) => continue,
+                        Err(e) => {
+                            tracing::debug!(
+                                release_intent_id = %release_intent_id,
+                                environment = %environment,
+                                error = %e,
+                                "failed to fetch approval state"
+                            );
+                            continue;
+                        }
+                    }
+                }
+                _ => continue,
+            };
+            // Slack requires block ids to be unique within a message.
+            if !targets.iter().any(|t| t.block_id() == target.block_id()) {
+                targets.push(target);
+            }
+        }
+
+        targets
+    }
+
     /// Fallback: post via incoming webhook URL (no update-in-place).
     async fn dispatch_slack_webhook(
         &self,
         webhook_url: &str,
         message: &forage_core::integrations::router::SlackMessage,
-    ) -> Result<(), String> {
+    ) -> Result<Option<u16>, DispatchFailure> {
         let payload = serde_json::json!({
             "text": message.text,
             "attachments": [{
@@ -501,14 +795,41 @@ impl NotificationDispatcher {
             .json(&payload)
             .send()
             .await
-            .map_err(|e| format!("slack http: {e}"))?;
+            .map_err(|e| DispatchFailure::from(format!("slack http: {e}")))?;
 
         let status = resp.status();
         if status.is_success() {
-            Ok(())
+            Ok(Some(status.as_u16()))
         } else {
             let body = resp.text().await.unwrap_or_default();
-            Err(format!("Slack HTTP {status}: {body}"))
+            Err(DispatchFailure {
+                message: format!("Slack HTTP {status}: {body}"),
+                response_code: Some(status.as_u16()),
+            })
+        }
+    }
+}
+
+/// How long an inline attempt holds its queue entry before the worker may
+/// pick it up.
+const INLINE_LEASE_SECS: i64 = 300;
+
+/// Retries scheduled at most this far out are waited for inline; later ones
+/// are left to the `DeliveryWorker`.
+const INLINE_RETRY_MAX_DELAY_SECS: i64 = 5;
+
+/// A failed delivery attempt.
+pub struct DispatchFailure {
+    pub message: String,
+    /// HTTP status, when the receiver answered.
+    pub response_code: Option<u16>,
+}
+
+impl From<String> for DispatchFailure {
+    fn from(message: String) -> Self {
+        Self {
+            message,
+            response_code: None,
         }
     }
 }
@@ -539,6 +860,9 @@ pub fn proto_to_event(n: &forage_grpc::Notification) -> NotificationEvent {
         forage_grpc::NotificationType::ReleaseStarted => "release_started",
         forage_grpc::NotificationType::ReleaseSucceeded => "release_succeeded",
         forage_grpc::NotificationType::ReleaseFailed => "release_failed",
+        forage_grpc::NotificationType::DriftDetected => "drift_detected",
+        forage_grpc::NotificationType::DriftResolved => "drift_resolved",
+        forage_grpc::NotificationType::ScheduleFailed => "schedule_failed",
         _ => "unknown",
     };
 
@@ -585,6 +909,8 @@ pub struct NotificationListener {
     pub service_token: String,
     /// Base URL of the Forage web UI for deep links (e.g. "https://forage.example.com").
     pub forage_url: String,
+    /// Attach approve/reject buttons to Slack messages (see `NotificationDispatcher`).
+    pub approval_buttons: bool,
 }
 
 impl Component for NotificationListener {
@@ -595,7 +921,8 @@ impl Component for NotificationListener {
     async fn run(&self, cancellation_token: CancellationToken) -> Result<(), MadError> {
         let dispatcher = Arc::new(
             NotificationDispatcher::new(self.store.clone(), self.forage_url.clone())
-                .with_grpc(self.grpc.clone(), self.service_token.clone()),
+                .with_grpc(self.grpc.clone(), self.service_token.clone())
+                .with_approval_buttons(self.approval_buttons),
         );
 
         // For now, listen on the global stream (no org filter).
@@ -1,3 +1,4 @@
+use forage_core::auth::sso::OidcProviderConfig;
 use forage_core::auth::{AuthError, OidcExchange, OidcIdentity};
 use reqwest::header;
 use serde::Deserialize;
@@ -223,3 +224,228 @@ impl OidcExchange for GitHubOidcExchange {
         })
     }
 }
+
+// ─── Organisation SSO (generic OIDC) ───────────────────────────────
+
+/// OIDC exchange for an organisation-configured provider, located through
+/// its discovery document.
+pub struct GenericOidcExchange {
+    config: OidcProviderConfig,
+    http: reqwest::Client,
+}
+
+/// The parts of an OpenID provider's discovery document forage uses.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OidcDiscovery {
+    pub issuer: String,
+    pub authorization_endpoint: String,
+    pub token_endpoint: String,
+    #[serde(default)]
+    pub userinfo_endpoint: Option<String>,
+    #[serde(default)]
+    pub token_endpoint_auth_methods_supported: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct OidcTokenResponse {
+    access_token: String,
+    #[serde(default)]
+    id_token: Option<String>,
+}
+
+impl GenericOidcExchange {
+    pub fn new(config: OidcProviderConfig) -> Self {
+        Self {
+            config,
+            http: reqwest::Client::new(),
+        }
+    }
+
+    pub async fn discover(&self) -> Result<OidcDiscovery, AuthError> {
+        let url = self.config.discovery_document_url();
+        let resp = self
+            .http
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| AuthError::Other(format!("OIDC discovery failed: {e}")))?;
+        if !resp.status().is_success() {
+            return Err(AuthError::Other(format!(
+                "OIDC discovery at {url} returned {}",
+                resp.status()
+            )));
+        }
+        resp.json()
+            .await
+            .map_err(|e| AuthError::Other(format!("failed to parse OIDC discovery document: {e}")))
+    }
+
+    /// URL of the provider's consent screen.
+    pub async fn authorization_url(
+        &self,
+        redirect_uri: &str,
+        state: &str,
+    ) -> Result<String, AuthError> {
+        let discovery = self.discover().await?;
+        let separator = if discovery.authorization_endpoint.contains('?') {
+            '&'
+        } else {
+            '?'
+        };
+        Ok(format!(
+            "{}{separator}client_id={}&redirect_uri={}&response_type=code&scope={}&state={}",
+            discovery.authorization_endpoint,
+            urlencoding::encode(&self.config.client_id),
+            urlencoding::encode(redirect_uri),
+            urlencoding::encode(&self.config.scopes.join(" ")),
+            urlencoding::encode(state),
+        ))
+    }
+
+    /// Claims of an ID token received directly from the token endpoint.
+    /// The TLS channel to the issuer authenticates the token (OIDC Core
+    /// §3.1.3.7), so only issuer, audience and expiry are checked.
+    fn id_token_claims(
+        &self,
+        discovery: &OidcDiscovery,
+        id_token: &str,
+    ) -> Result<serde_json::Value, AuthError> {
+        use base64::Engine;
+
+        let payload = id_token
+            .split('.')
+            .nth(1)
+            .ok_or_else(|| AuthError::Other("malformed ID token".into()))?;
+        let bytes = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(payload.trim_end_matches('='))
+            .map_err(|e| AuthError::Other(format!("malformed ID token: {e}")))?;
+        let claims: serde_json::Value = serde_json::from_slice(&bytes)
+            .map_err(|e| AuthError::Other(format!("malformed ID token: {e}")))?;
+
+        if claims.get("iss").and_then(|v| v.as_str()) != Some(discovery.issuer.as_str()) {
+            return Err(AuthError::Other("ID token issuer mismatch".into()));
+        }
+        let audience_ok = match claims.get("aud") {
+            Some(serde_json::Value::String(aud)) => *aud == self.config.client_id,
+            Some(serde_json::Value::Array(auds)) => auds
+                .iter()
+                .any(|a| a.as_str() == Some(self.config.client_id.as_str())),
+            _ => false,
+        };
+        if !audience_ok {
+            return Err(AuthError::Other("ID token audience mismatch".into()));
+        }
+        let expired = claims
+            .get("exp")
+            .and_then(|v| v.as_i64())
+            .is_none_or(|exp| exp <= chrono::Utc::now().timestamp());
+        if expired {
+            return Err(AuthError::TokenExpired);
+        }
+        Ok(claims)
+    }
+}
+
+#[async_trait::async_trait]
+impl OidcExchange for GenericOidcExchange {
+    async fn exchange_code(
+        &self,
+        code: &str,
+        redirect_uri: &str,
+    ) -> Result<OidcIdentity, AuthError> {
+        let discovery = self.discover().await?;
+
+        // client_secret_basic is the default unless the provider only
+        // advertises client_secret_post.
+        let methods = &discovery.token_endpoint_auth_methods_supported;
+        let post_secret = methods.iter().any(|m| m == "client_secret_post")
+            && !methods.iter().any(|m| m == "client_secret_basic");
+        let mut form = vec![
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", redirect_uri),
+        ];
+        let mut request = self.http.post(&discovery.token_endpoint);
+        if post_secret {
+            form.push(("client_id", &self.config.client_id));
+            form.push(("client_secret", &self.config.client_secret));
+        } else {
+            request = request.basic_auth(
+                urlencoding::encode(&self.config.client_id),
+                Some(urlencoding::encode(&self.config.client_secret)),
+            );
+        }
+
+        let token_resp = request
+            .header(header::ACCEPT, "application/json")
+            .form(&form)
+            .send()
+            .await
+            .map_err(|e| AuthError::Other(format!("OIDC token exchange failed: {e}")))?;
+        if !token_resp.status().is_success() {
+            let status = token_resp.status();
+            let body = token_resp.text().await.unwrap_or_default();
+            return Err(AuthError::Other(format!(
+                "OIDC token exchange returned {status}: {body}"
+            )));
+        }
+        let tokens: OidcTokenResponse = token_resp
+            .json()
+            .await
+            .map_err(|e| AuthError::Other(format!("failed to parse OIDC token response: {e}")))?;
+
+        let mut claims = match &tokens.id_token {
+            Some(id_token) => self.id_token_claims(&discovery, id_token)?,
+            None => serde_json::json!({}),
+        };
+
+        // Userinfo claims take precedence; providers often only put the
+        // profile there.
+        if let Some(userinfo_endpoint) = &discovery.userinfo_endpoint {
+            let resp = self
+                .http
+                .get(userinfo_endpoint)
+                .bearer_auth(&tokens.access_token)
+                .send()
+                .await
+                .map_err(|e| AuthError::Other(format!("OIDC userinfo request failed: {e}")))?;
+            if !resp.status().is_success() {
+                return Err(AuthError::Other(format!(
+                    "OIDC userinfo returned {}",
+                    resp.status()
+                )));
+            }
+            let userinfo: serde_json::Value = resp
+                .json()
+                .await
+                .map_err(|e| AuthError::Other(format!("failed to parse OIDC userinfo: {e}")))?;
+            // The userinfo subject must match the ID token's (§5.3.2).
+            if let (Some(token_sub), Some(info_sub)) = (claims.get("sub"), userinfo.get("sub"))
+                && token_sub != info_sub
+            {
+                return Err(AuthError::Other("userinfo subject mismatch".into()));
+            }
+            if let (Some(claims), serde_json::Value::Object(userinfo)) =
+                (claims.as_object_mut(), userinfo)
+            {
+                claims.extend(userinfo);
+            }
+        } else if tokens.id_token.is_none() {
+            return Err(AuthError::Other(
+                "provider returned neither an ID token nor a userinfo endpoint".into(),
+            ));
+        }
+
+        // JIT provisioning creates accounts under the asserted email and
+        // the domain allow-list admits logins by it, so an address the
+        // provider itself has not verified is never accepted.
+        if claims.get("email_verified").and_then(|v| v.as_bool()) == Some(false) {
+            return Err(AuthError::Other("email address is not verified by the provider".into()));
+        }
+
+        self.config
+            .claims
+            .identity_from_claims(&claims)
+            .map_err(|e| AuthError::Other(e.to_string()))
+    }
+}
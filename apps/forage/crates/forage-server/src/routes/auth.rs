@@ -14,9 +14,10 @@ use crate::auth::{self, MaybeSession, Session};
 use crate::state::AppState;
 use axum_extra::extract::CookieJar;
 use forage_core::auth::{
-    validate_email, validate_password, validate_username, LoginResult, RegisterResult, UserEmail,
+    validate_email, validate_password, validate_username, LoginResult, MfaMethod, Passkey,
+    RegisterResult, UserEmail,
 };
-use forage_core::session::{CachedOrg, CachedUser, SessionData, generate_csrf_token};
+use forage_core::session::{CachedOrg, CachedUser, SessionData, SessionSummary, generate_csrf_token};
 
 pub fn router() -> Router<AppState> {
     Router::new()
@@ -72,6 +73,10 @@ pub fn router() -> Router<AppState> {
             "/settings/account/google/disconnect",
             post(google_link_disconnect),
         )
+        .route(
+            "/settings/account/sessions/sign-out-others",
+            post(sign_out_other_sessions),
+        )
         .route("/settings/account/picture", post(upload_picture_submit))
         .route("/settings/account/picture/remove", post(remove_picture_submit))
         .route("/avatars/{user_id}", get(serve_avatar))
@@ -339,6 +344,10 @@ async fn login_submit(
         .await
     {
         Ok(LoginResult::Success(tokens)) => {
+            if let Some(msg) = super::sso::sso_enforcement_error(&state, &tokens.access_token).await {
+                return render_login(&state, &form.identifier, Some(msg), rt);
+            }
+
             let user_cache = match state
                 .forest_client
                 .get_user(&tokens.access_token)
@@ -407,7 +416,10 @@ async fn login_submit(
                 ),
             }
         }
-        Ok(LoginResult::MfaRequired { mfa_session_token }) => {
+        Ok(LoginResult::MfaRequired {
+            mfa_session_token,
+            methods,
+        }) => {
             // Store the MFA session token in a short-lived cookie and show the challenge page.
             use axum::http::header::SET_COOKIE;
             use axum::http::HeaderValue;
@@ -416,22 +428,7 @@ async fn login_submit(
                 "forage_mfa_session={}; HttpOnly; SameSite=Lax; Path=/login; Max-Age=300",
                 mfa_session_token
             );
-            let html = state
-                .templates
-                .render(
-                    "pages/mfa_challenge.html.jinja",
-                    context! {
-                        title => "Two-factor authentication - Forage",
-                        description => "Enter your authenticator code to continue",
-                        error => None::<String>,
-                    },
-                )
-                .map_err(|e| {
-                    tracing::error!("template error: {e:#}");
-                    axum::http::StatusCode::INTERNAL_SERVER_ERROR
-                })?;
-
-            let mut response = Html(html).into_response();
+            let mut response = render_mfa_challenge(&state, &methods, None)?;
             if let Ok(val) = HeaderValue::from_str(&cookie_value) {
                 response.headers_mut().insert(SET_COOKIE, val);
             }
@@ -456,6 +453,11 @@ async fn login_submit(
             Some("Invalid email/username or password".into()),
             rt,
         ),
+        // Forest refuses password logins for members of organisations
+        // that enforce single sign-on.
+        Err(forage_core::auth::AuthError::PermissionDenied(msg)) => {
+            render_login(&state, &form.identifier, Some(msg), rt)
+        }
         Err(forage_core::auth::AuthError::Unavailable(msg)) => {
             tracing::error!("forest-server unavailable: {msg}");
             render_login(
@@ -481,9 +483,6 @@ async fn login_mfa_submit(
     jar: CookieJar,
     Form(form): Form<MfaForm>,
 ) -> Result<Response, axum::http::StatusCode> {
-    use axum::http::header::SET_COOKIE;
-    use axum::http::HeaderValue;
-
     let mfa_token = jar
         .get("forage_mfa_session")
         .map(|c| c.value().to_string())
@@ -499,39 +498,50 @@ async fn login_mfa_submit(
     {
         Ok(t) => t,
         Err(forage_core::auth::AuthError::InvalidCredentials) => {
-            let html = state
-                .templates
-                .render(
-                    "pages/mfa_challenge.html.jinja",
-                    context! {
-                        title => "Two-factor authentication - Forage",
-                        description => "Enter your authenticator code to continue",
-                        error => Some("Invalid or expired code. Please try again."),
-                    },
-                )
-                .map_err(|e| {
-                    tracing::error!("template error: {e:#}");
-                    axum::http::StatusCode::INTERNAL_SERVER_ERROR
-                })?;
-            return Ok(Html(html).into_response());
+            return render_mfa_challenge(
+                &state,
+                &[MfaMethod::Totp],
+                Some("Invalid or expired code. Please try again.".into()),
+            );
+        }
+        Err(forage_core::auth::AuthError::PermissionDenied(msg)) => {
+            let mut response = render_login(&state, "", Some(msg), None)?;
+            let clear_mfa = "forage_mfa_session=; HttpOnly; SameSite=Lax; Path=/login; Max-Age=0";
+            if let Ok(val) = axum::http::HeaderValue::from_str(clear_mfa) {
+                response
+                    .headers_mut()
+                    .append(axum::http::header::SET_COOKIE, val);
+            }
+            return Ok(response);
         }
         Err(e) => {
             tracing::error!("MFA verify error: {e}");
-            let html = state
-                .templates
-                .render(
-                    "pages/mfa_challenge.html.jinja",
-                    context! {
-                        title => "Two-factor authentication - Forage",
-                        description => "Enter your authenticator code to continue",
-                        error => Some(e.to_string()),
-                    },
-                )
-                .map_err(|_| axum::http::StatusCode::INTERNAL_SERVER_ERROR)?;
-            return Ok(Html(html).into_response());
+            return render_mfa_challenge(&state, &[MfaMethod::Totp], Some(e.to_string()));
         }
     };
 
+    complete_mfa_login(&state, tokens).await
+}
+
+/// Finish a login whose second factor (TOTP or passkey) has been
+/// verified: start a session, clear the MFA cookie and go to the
+/// dashboard. Passwordless passkey logins end here too.
+pub(super) async fn complete_mfa_login(
+    state: &AppState,
+    tokens: forage_core::auth::AuthTokens,
+) -> Result<Response, axum::http::StatusCode> {
+    use axum::http::header::SET_COOKIE;
+    use axum::http::HeaderValue;
+
+    if let Some(msg) = super::sso::sso_enforcement_error(state, &tokens.access_token).await {
+        let mut response = render_login(state, "", Some(msg), None)?;
+        let clear_mfa = "forage_mfa_session=; HttpOnly; SameSite=Lax; Path=/login; Max-Age=0";
+        if let Ok(val) = HeaderValue::from_str(clear_mfa) {
+            response.headers_mut().append(SET_COOKIE, val);
+        }
+        return Ok(response);
+    }
+
     let user_cache = match state.forest_client.get_user(&tokens.access_token).await {
         Ok(u) => {
             let orgs = state
@@ -588,7 +598,32 @@ async fn login_mfa_submit(
     }
 }
 
-fn render_login(
+pub(super) fn render_mfa_challenge(
+    state: &AppState,
+    methods: &[MfaMethod],
+    error: Option<String>,
+) -> Result<Response, axum::http::StatusCode> {
+    let html = state
+        .templates
+        .render(
+            "pages/mfa_challenge.html.jinja",
+            context! {
+                title => "Two-factor authentication - Forage",
+                description => "Enter your authenticator code to continue",
+                error => error,
+                has_totp => methods.contains(&MfaMethod::Totp),
+                has_passkey => methods.contains(&MfaMethod::Passkey),
+            },
+        )
+        .map_err(|e| {
+            tracing::error!("template error: {e:#}");
+            axum::http::StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    Ok(Html(html).into_response())
+}
+
+pub(super) fn render_login(
     state: &AppState,
     identifier: &str,
     error: Option<String>,
@@ -606,6 +641,7 @@ fn render_login(
                 has_google_oauth => state.google_oauth_config.is_some(),
                 has_github_oauth => state.github_oauth_config.is_some(),
                 has_magic_link => state.magic_link_store.is_some(),
+                has_sso => state.sso_store.is_some(),
                 return_to => return_to,
             },
         )
@@ -635,6 +671,20 @@ async fn logout_submit(
     Ok((auth::clear_session_cookie(), Redirect::to("/")))
 }
 
+async fn sign_out_other_sessions(
+    State(state): State<AppState>,
+    session: Session,
+    Form(form): Form<CsrfForm>,
+) -> Result<Response, Response> {
+    if !auth::validate_csrf(&session, &form._csrf) {
+        return Err(error_page(&state, StatusCode::FORBIDDEN, "Invalid request", "CSRF validation failed. Please try again."));
+    }
+    auth::revoke_user_sessions(&state, &session.user.user_id, Some(&session.session_id))
+        .await
+        .map_err(|e| internal_error(&state, "sign out other sessions", &e))?;
+    Ok(Redirect::to("/settings/account?flash=signed_out_others").into_response())
+}
+
 // ─── Tokens ─────────────────────────────────────────────────────────
 
 async fn tokens_page(
@@ -800,6 +850,24 @@ async fn account_page(
     let linked_accounts =
         forage_core::auth::merge_linked_identities(forest_identities, &slack_links);
 
+    let sessions = state
+        .sessions
+        .list_for_user(&session.user.user_id)
+        .await
+        .unwrap_or_else(|e| {
+            tracing::warn!(error = %e, "failed to list sessions");
+            vec![]
+        });
+
+    let passkeys = state
+        .forest_client
+        .list_passkeys(&session.access_token)
+        .await
+        .unwrap_or_else(|e| {
+            tracing::warn!(error = %e, "failed to list passkeys");
+            vec![]
+        });
+
     // Fetch fresh user info to get current mfa_enabled state.
     let mfa_enabled = state
         .forest_client
@@ -815,6 +883,8 @@ async fn account_page(
         &prefs,
         &slack_links,
         &linked_accounts,
+        &sessions,
+        &passkeys,
         mfa_enabled,
         flash_message(query.flash.as_deref()),
         error_message(query.error.as_deref()),
@@ -827,7 +897,12 @@ fn flash_message(flash: Option<&str>) -> Option<&'static str> {
     match flash? {
         "linked_github" => Some("GitHub account linked."),
         "linked_google" => Some("Google account linked."),
+        "linked_sso" => Some("Single sign-on linked. You can now sign in through your identity provider."),
         "verification_resent" => Some("Verification email sent. Check your inbox."),
+        "signed_out_others" => Some("Signed out of all other sessions."),
+        "passkey_added" => Some("Passkey added."),
+        "passkey_renamed" => Some("Passkey renamed."),
+        "passkey_deleted" => Some("Passkey removed."),
         _ => None,
     }
 }
@@ -849,14 +924,22 @@ fn error_message(error: Option<&str>) -> Option<&'static str> {
         "already_linked_google" => {
             Some("You already have a Google account linked. Disconnect it first to switch.")
         }
+        "already_linked_other_sso" => {
+            Some("This single sign-on identity is already linked to another Forage user.")
+        }
+        "already_linked_sso" => Some("You already have a single sign-on identity linked."),
         "link_failed_github" => Some("Linking your GitHub account failed. Please try again."),
         "link_failed_google" => Some("Linking your Google account failed. Please try again."),
+        "link_failed_sso" => Some("Linking single sign-on failed. Please try again."),
         "verification_resend_ineligible" => {
             Some("That email isn't eligible for verification right now.")
         }
         "verification_resend_failed" => {
             Some("Couldn't send the verification email. Please try again.")
         }
+        "passkey_failed" => Some("Couldn't register that passkey. Please try again."),
+        "passkey_duplicate" => Some("That passkey is already registered."),
+        "passkey_name" => Some("Give the passkey a name of at most 64 characters."),
         _ => None,
     }
 }
@@ -870,6 +953,8 @@ fn render_account(
     notification_prefs: &[forage_core::platform::NotificationPreference],
     slack_links: &[SlackUserLink],
     linked_accounts: &[forage_core::auth::LinkedIdentity],
+    sessions: &[SessionSummary],
+    passkeys: &[Passkey],
     mfa_enabled: bool,
     flash: Option<&str>,
     oauth_error: Option<&str>,
@@ -921,6 +1006,18 @@ fn render_account(
                     linked_at => &l.linked_at,
                     disconnect_key => &l.disconnect_key,
                 }).collect::<Vec<_>>(),
+                sessions => sessions.iter().map(|s| context! {
+                    created_at => s.created_at.to_rfc3339(),
+                    last_seen_at => s.last_seen_at.to_rfc3339(),
+                    current => s.id == session.session_id,
+                }).collect::<Vec<_>>(),
+                has_other_sessions => sessions.iter().any(|s| s.id != session.session_id),
+                passkeys => passkeys.iter().map(|p| context! {
+                    passkey_id => &p.passkey_id,
+                    name => &p.name,
+                    created_at => &p.created_at,
+                    last_used_at => &p.last_used_at,
+                }).collect::<Vec<_>>(),
                 has_github_link => linked_accounts.iter().any(|l| l.provider == forage_core::auth::LinkedProvider::GitHub),
                 has_google_link => linked_accounts.iter().any(|l| l.provider == forage_core::auth::LinkedProvider::Google),
                 flash => flash,
@@ -955,7 +1052,7 @@ async fn update_username_submit(
     }
 
     if let Err(e) = validate_username(&form.username) {
-        return render_account(&state, &session, Some(&e.0), &[], &[], &[], false, None, None);
+        return render_account(&state, &session, Some(&e.0), &[], &[], &[], &[], &[], false, None, None);
     }
 
     match state
@@ -977,11 +1074,11 @@ async fn update_username_submit(
             Ok(Redirect::to("/settings/account").into_response())
         }
         Err(forage_core::auth::AuthError::AlreadyExists(_)) => {
-            render_account(&state, &session, Some("Username is already taken."), &[], &[], &[], false, None, None)
+            render_account(&state, &session, Some("Username is already taken."), &[], &[], &[], &[], &[], false, None, None)
         }
         Err(e) => {
             tracing::error!("failed to update username: {e}");
-            render_account(&state, &session, Some("Could not update username. Please try again."), &[], &[], &[], false, None, None)
+            render_account(&state, &session, Some("Could not update username. Please try again."), &[], &[], &[], &[], &[], false, None, None)
         }
     }
 }
@@ -1009,11 +1106,11 @@ async fn change_password_submit(
     }
 
     if form.new_password != form.new_password_confirm {
-        return render_account(&state, &session, Some("New passwords do not match."), &[], &[], &[], false, None, None);
+        return render_account(&state, &session, Some("New passwords do not match."), &[], &[], &[], &[], &[], false, None, None);
     }
 
     if let Err(e) = validate_password(&form.new_password) {
-        return render_account(&state, &session, Some(&e.0), &[], &[], &[], false, None, None);
+        return render_account(&state, &session, Some(&e.0), &[], &[], &[], &[], &[], false, None, None);
     }
 
     match state
@@ -1028,11 +1125,11 @@ async fn change_password_submit(
     {
         Ok(()) => Ok(Redirect::to("/settings/account").into_response()),
         Err(forage_core::auth::AuthError::InvalidCredentials) => {
-            render_account(&state, &session, Some("Current password is incorrect."), &[], &[], &[], false, None, None)
+            render_account(&state, &session, Some("Current password is incorrect."), &[], &[], &[], &[], &[], false, None, None)
         }
         Err(e) => {
             tracing::error!("failed to change password: {e}");
-            render_account(&state, &session, Some("Could not change password. Please try again."), &[], &[], &[], false, None, None)
+            render_account(&state, &session, Some("Could not change password. Please try again."), &[], &[], &[], &[], &[], false, None, None)
         }
     }
 }
@@ -1058,7 +1155,7 @@ async fn add_email_submit(
     }
 
     if let Err(e) = validate_email(&form.email) {
-        return render_account(&state, &session, Some(&e.0), &[], &[], &[], false, None, None);
+        return render_account(&state, &session, Some(&e.0), &[], &[], &[], &[], &[], false, None, None);
     }
 
     match state
@@ -1092,11 +1189,11 @@ async fn add_email_submit(
             Ok(Redirect::to("/settings/account").into_response())
         }
         Err(forage_core::auth::AuthError::AlreadyExists(_)) => {
-            render_account(&state, &session, Some("Email is already registered."), &[], &[], &[], false, None, None)
+            render_account(&state, &session, Some("Email is already registered."), &[], &[], &[], &[], &[], false, None, None)
         }
         Err(e) => {
             tracing::error!("failed to add email: {e}");
-            render_account(&state, &session, Some("Could not add email. Please try again."), &[], &[], &[], false, None, None)
+            render_account(&state, &session, Some("Could not add email. Please try again."), &[], &[], &[], &[], &[], false, None, None)
         }
     }
 }
@@ -1141,7 +1238,7 @@ async fn remove_email_submit(
         }
         Err(e) => {
             tracing::error!("failed to remove email: {e}");
-            render_account(&state, &session, Some("Could not remove email. Please try again."), &[], &[], &[], false, None, None)
+            render_account(&state, &session, Some("Could not remove email. Please try again."), &[], &[], &[], &[], &[], false, None, None)
         }
     }
 }
@@ -1832,6 +1929,9 @@ async fn google_oauth_callback(
                 "Temporarily unavailable",
                 "Authentication service is temporarily unavailable. Please try again later.",
             ),
+            forage_core::auth::AuthError::PermissionDenied(msg) => {
+                error_page(&state, StatusCode::FORBIDDEN, "Single sign-on required", msg)
+            }
             _ => {
                 tracing::error!(error = %e, "Google OAuth login failed");
                 error_page(
@@ -2108,6 +2208,9 @@ async fn github_oauth_callback(
                 "Temporarily unavailable",
                 "Authentication service is temporarily unavailable. Please try again later.",
             ),
+            forage_core::auth::AuthError::PermissionDenied(msg) => {
+                error_page(&state, StatusCode::FORBIDDEN, "Single sign-on required", msg)
+            }
             _ => {
                 tracing::error!(error = %e, "GitHub OAuth login failed");
                 error_page(
@@ -2521,6 +2624,9 @@ async fn magic_link_verify(
                 "Temporarily unavailable",
                 "Authentication service is temporarily unavailable. Please try again later.",
             ),
+            forage_core::auth::AuthError::PermissionDenied(msg) => {
+                error_page(&state, StatusCode::FORBIDDEN, "Single sign-on required", msg)
+            }
             _ => {
                 tracing::error!(error = %e, "magic link login failed");
                 error_page(
@@ -2532,6 +2638,12 @@ async fn magic_link_verify(
             }
         })?;
 
+    if let Some(msg) =
+        super::sso::sso_enforcement_error(&state, &result.tokens.access_token).await
+    {
+        return Err(error_page(&state, StatusCode::FORBIDDEN, "Single sign-on required", &msg));
+    }
+
     // Build session (same pattern as Google OAuth callback).
     let user_cache = {
         let orgs = match state
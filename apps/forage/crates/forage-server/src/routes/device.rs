@@ -10,6 +10,11 @@
 //! Authentication: the GET handler requires a logged-in session. If the
 //! user isn't logged in the `Session` extractor redirects to
 //! `/login?return_to=/device?user_code=…` and brings them back.
+//!
+//! Users with passkeys can additionally confirm the approval with one:
+//! `/device/passkey/options` starts a verification ceremony for the
+//! session's user and the form carries the assertion through to
+//! `ApproveDeviceLogin`, which checks it before issuing tokens.
 
 use axum::extract::{Query, State};
 use axum::http::{HeaderMap, StatusCode};
@@ -19,14 +24,17 @@ use axum::{Form, Router};
 use minijinja::context;
 use serde::Deserialize;
 
+use super::passkeys::{ceremony_response, json_error};
 use super::{error_page, internal_error};
 use crate::auth::{self, Session};
+use forage_core::auth::PasskeyResponse;
 use crate::state::AppState;
 
 pub fn router() -> Router<AppState> {
     Router::new()
         .route("/device", get(device_page))
         .route("/device", post(device_submit))
+        .route("/device/passkey/options", post(device_passkey_options))
 }
 
 #[derive(Deserialize)]
@@ -41,10 +49,10 @@ async fn device_page(
     Query(params): Query<DeviceQuery>,
 ) -> Result<Response, Response> {
     if state.service_account_key.is_none() {
-        return render_device(&state, &session, "", None, Some("unconfigured"));
+        return render_device(&state, &session, "", None, Some("unconfigured")).await;
     }
     let user_code_prefill = params.user_code.unwrap_or_default();
-    render_device(&state, &session, &user_code_prefill, None, None)
+    render_device(&state, &session, &user_code_prefill, None, None).await
 }
 
 #[derive(Deserialize)]
@@ -52,6 +60,37 @@ struct DeviceForm {
     user_code: String,
     action: String, // "approve" or "deny"
     _csrf: String,
+    /// Set when the user approved with a passkey (passkeys.js).
+    #[serde(default)]
+    passkey_ceremony_id: String,
+    #[serde(default)]
+    passkey_credential_json: String,
+}
+
+#[derive(Deserialize)]
+struct CsrfForm {
+    _csrf: String,
+}
+
+async fn device_passkey_options(
+    State(state): State<AppState>,
+    session: Session,
+    Form(form): Form<CsrfForm>,
+) -> Response {
+    if !auth::validate_csrf(&session, &form._csrf) {
+        return json_error(StatusCode::FORBIDDEN, "CSRF validation failed. Please reload the page.");
+    }
+    match state
+        .forest_client
+        .begin_passkey_verification(&session.user.user_id)
+        .await
+    {
+        Ok(ceremony) => ceremony_response(ceremony),
+        Err(e) => {
+            tracing::error!(error = %e, "begin passkey verification failed");
+            json_error(StatusCode::BAD_GATEWAY, "Couldn't start the passkey prompt.")
+        }
+    }
 }
 
 async fn device_submit(
@@ -65,7 +104,7 @@ async fn device_submit(
         // the "unconfigured" page, but a stale form post must not slip
         // through to forest_client and mint a misleading
         // "code wasn't recognised" error.
-        return render_device(&state, &session, "", None, Some("unconfigured"));
+        return render_device(&state, &session, "", None, Some("unconfigured")).await;
     }
     if !auth::validate_csrf(&session, &form._csrf) {
         return Err(error_page(
@@ -84,7 +123,7 @@ async fn device_submit(
             "",
             Some("Enter the code shown in your terminal."),
             None,
-        );
+        ).await;
     }
 
     // Forward the approver's apparent IP + UA so forest-server's audit
@@ -105,6 +144,10 @@ async fn device_submit(
         .to_string();
 
     let user_id = &session.user.user_id;
+    let passkey = (!form.passkey_ceremony_id.is_empty()).then(|| PasskeyResponse {
+        ceremony_id: form.passkey_ceremony_id.clone(),
+        credential_json: form.passkey_credential_json.clone(),
+    });
 
     match form.action.as_str() {
         "approve" => match state
@@ -114,6 +157,7 @@ async fn device_submit(
                 user_id,
                 &approving_ip,
                 &approving_user_agent,
+                passkey.as_ref(),
             )
             .await
         {
@@ -123,16 +167,15 @@ async fn device_submit(
                 user_code,
                 None,
                 Some("approved"),
-            ),
+            ).await,
             Err(e) => {
                 tracing::warn!(error = %e, "device login approval failed");
-                render_device(
-                    &state,
-                    &session,
-                    user_code,
-                    Some("That code wasn't recognised, or has already expired. Start a new login from your terminal."),
-                    None,
-                )
+                let message = if passkey.is_some() {
+                    "That passkey wasn't accepted, or the code has expired. Try again, or start a new login from your terminal."
+                } else {
+                    "That code wasn't recognised, or has already expired. Start a new login from your terminal."
+                };
+                render_device(&state, &session, user_code, Some(message), None).await
             }
         },
         "deny" => match state
@@ -146,7 +189,7 @@ async fn device_submit(
                 user_code,
                 None,
                 Some("denied"),
-            ),
+            ).await,
             Err(e) => {
                 tracing::warn!(error = %e, "device login denial failed");
                 render_device(
@@ -155,7 +198,7 @@ async fn device_submit(
                     user_code,
                     Some("That code wasn't recognised, or has already expired."),
                     None,
-                )
+                ).await
             }
         },
         // Anything other than approve/deny is a probable bot or tampered
@@ -169,13 +212,22 @@ async fn device_submit(
     }
 }
 
-fn render_device(
+async fn render_device(
     state: &AppState,
     session: &Session,
     user_code: &str,
     error: Option<&str>,
     result: Option<&str>,
 ) -> Result<Response, Response> {
+    // Only offer "Approve with passkey" to users who have one.
+    let has_passkeys = result.is_none()
+        && state
+            .forest_client
+            .list_passkeys(&session.access_token)
+            .await
+            .map(|p| !p.is_empty())
+            .unwrap_or(false);
+
     let html = state
         .templates
         .render(
@@ -195,6 +247,7 @@ fn render_device(
                 // "approved" | "denied" | None — the template uses this
                 // to swap the form for a success/cancelled banner.
                 result => result,
+                has_passkeys => has_passkeys,
             },
         )
         .map_err(|e| internal_error(state, "template error", &e))?;
@@ -5,9 +5,11 @@ use axum::response::{Html, IntoResponse, Redirect, Response};
 use axum::routing::{get, post};
 use axum::{Form, Router};
 use forage_core::integrations::router::{NotificationEvent, ReleaseContext};
+use forage_core::integrations::filter::{parse_filter_list, Severity, TEMPLATE_PLACEHOLDERS};
 use forage_core::integrations::{
-    validate_integration_name, validate_webhook_url, CreateIntegrationInput, IntegrationConfig,
-    IntegrationType,
+    validate_discord_webhook_url, validate_email_recipients, validate_integration_name,
+    validate_pagerduty_routing_key, validate_webhook_url, CreateIntegrationInput,
+    IntegrationConfig, IntegrationType, NotificationFilter,
 };
 use forage_core::platform::validate_slug;
 use forage_core::session::CachedOrg;
@@ -33,6 +35,10 @@ pub fn router() -> Router<AppState> {
             "/orgs/{org}/settings/integrations/webhook",
             post(create_webhook),
         )
+        .route(
+            "/orgs/{org}/settings/integrations/install/{kind}",
+            get(install_channel_page).post(create_channel),
+        )
         .route(
             "/orgs/{org}/settings/integrations/{id}",
             get(integration_detail),
@@ -41,6 +47,10 @@ pub fn router() -> Router<AppState> {
             "/orgs/{org}/settings/integrations/{id}/rules",
             post(update_rules),
         )
+        .route(
+            "/orgs/{org}/settings/integrations/{id}/filters",
+            post(update_filters),
+        )
         .route(
             "/orgs/{org}/settings/integrations/{id}/toggle",
             post(toggle_integration),
@@ -53,6 +63,10 @@ pub fn router() -> Router<AppState> {
             "/orgs/{org}/settings/integrations/{id}/test",
             post(test_integration),
         )
+        .route(
+            "/orgs/{org}/settings/integrations/{id}/deliveries/{delivery_id}/redeliver",
+            post(redeliver),
+        )
         .route(
             "/orgs/{org}/settings/integrations/install/slack",
             get(install_slack_page),
@@ -69,6 +83,10 @@ pub fn router() -> Router<AppState> {
             "/integrations/slack/callback",
             get(slack_oauth_callback),
         )
+        .route(
+            "/integrations/slack/interactivity",
+            post(slack_interactivity),
+        )
 }
 
 fn require_org_membership<'a>(
@@ -144,6 +162,10 @@ struct ListQuery {
 struct DetailQuery {
     #[serde(default)]
     test: Option<String>,
+    #[serde(default)]
+    redelivered: Option<String>,
+    #[serde(default)]
+    error: Option<String>,
 }
 
 // ─── List integrations ──────────────────────────────────────────────
@@ -336,6 +358,205 @@ async fn create_webhook(
     Ok(Html(html).into_response())
 }
 
+// ─── Teams / Discord / PagerDuty / Email ────────────────────────────
+
+/// Digest frequencies offered on the email install page.
+const DIGEST_INTERVALS: &[(u32, &str)] = &[
+    (15, "Every 15 minutes"),
+    (60, "Hourly"),
+    (240, "Every 4 hours"),
+    (1440, "Daily"),
+];
+
+/// Integration types installed through the generic channel form.
+fn channel_kind(state: &AppState, kind: &str) -> Result<IntegrationType, Response> {
+    match IntegrationType::parse(kind) {
+        Some(
+            t @ (IntegrationType::Teams
+            | IntegrationType::Discord
+            | IntegrationType::PagerDuty
+            | IntegrationType::Email),
+        ) => Ok(t),
+        _ => Err(error_page(
+            state,
+            axum::http::StatusCode::NOT_FOUND,
+            "Not found",
+            "Unknown integration type.",
+        )),
+    }
+}
+
+async fn install_channel_page(
+    State(state): State<AppState>,
+    session: Session,
+    Path((org, kind)): Path<(String, String)>,
+    Query(query): Query<ListQuery>,
+) -> Result<Response, Response> {
+    let cached_org = require_org_membership(&state, &session.user.orgs, &org)?;
+    require_admin(&state, cached_org)?;
+    require_integration_store(&state)?;
+    let integration_type = channel_kind(&state, &kind)?;
+
+    let html = state
+        .templates
+        .render(
+            "pages/install_channel.html.jinja",
+            context! {
+                title => format!("Install {} - {} - Forage", integration_type.display_name(), org),
+                description => format!("Set up a {} integration", integration_type.display_name()),
+                user => context! {
+                    username => &session.user.username,
+                    user_id => &session.user.user_id,
+                },
+                current_org => &org,
+                orgs => session.user.orgs.iter().map(|o| context! { name => &o.name, role => &o.role }).collect::<Vec<_>>(),
+                csrf_token => &session.csrf_token,
+                active_tab => "settings",
+                kind => integration_type.as_str(),
+                type_display => integration_type.display_name(),
+                digest_intervals => DIGEST_INTERVALS,
+                error => query.error,
+            },
+        )
+        .map_err(|e| internal_error(&state, "template error", &e))?;
+
+    Ok(Html(html).into_response())
+}
+
+#[derive(Deserialize)]
+struct CreateChannelForm {
+    _csrf: String,
+    name: String,
+    #[serde(default)]
+    webhook_url: String,
+    #[serde(default)]
+    routing_key: String,
+    #[serde(default)]
+    recipients: String,
+    #[serde(default)]
+    digest_interval_minutes: Option<u32>,
+}
+
+/// Build the config for a channel integration from the install form.
+fn channel_config(
+    integration_type: IntegrationType,
+    form: &CreateChannelForm,
+) -> Result<IntegrationConfig, String> {
+    match integration_type {
+        IntegrationType::Teams => {
+            let url = form.webhook_url.trim();
+            validate_webhook_url(url).map_err(|e| e.to_string())?;
+            Ok(IntegrationConfig::Teams {
+                webhook_url: url.to_string(),
+            })
+        }
+        IntegrationType::Discord => {
+            let url = form.webhook_url.trim();
+            validate_discord_webhook_url(url).map_err(|e| e.to_string())?;
+            Ok(IntegrationConfig::Discord {
+                webhook_url: url.to_string(),
+            })
+        }
+        IntegrationType::PagerDuty => {
+            let key = form.routing_key.trim();
+            validate_pagerduty_routing_key(key).map_err(|e| e.to_string())?;
+            Ok(IntegrationConfig::PagerDuty {
+                routing_key: key.to_string(),
+            })
+        }
+        IntegrationType::Email => {
+            let recipients: Vec<String> = form
+                .recipients
+                .split([',', '\n'])
+                .map(|r| r.trim().to_string())
+                .filter(|r| !r.is_empty())
+                .collect();
+            validate_email_recipients(&recipients).map_err(|e| e.to_string())?;
+            let digest_interval_minutes = form
+                .digest_interval_minutes
+                .unwrap_or_else(forage_core::integrations::email::default_digest_interval_minutes);
+            if !DIGEST_INTERVALS.iter().any(|(m, _)| *m == digest_interval_minutes) {
+                return Err("Unsupported digest frequency".to_string());
+            }
+            Ok(IntegrationConfig::Email {
+                recipients,
+                digest_interval_minutes,
+            })
+        }
+        IntegrationType::Slack | IntegrationType::Webhook => {
+            Err("Unsupported integration type".to_string())
+        }
+    }
+}
+
+async fn create_channel(
+    State(state): State<AppState>,
+    session: Session,
+    Path((org, kind)): Path<(String, String)>,
+    Form(form): Form<CreateChannelForm>,
+) -> Result<Response, Response> {
+    let cached_org = require_org_membership(&state, &session.user.orgs, &org)?;
+    require_admin(&state, cached_org)?;
+    require_integration_store(&state)?;
+    validate_csrf(&session, &form._csrf)?;
+    let integration_type = channel_kind(&state, &kind)?;
+
+    let config = validate_integration_name(&form.name)
+        .map_err(|e| e.to_string())
+        .and_then(|_| channel_config(integration_type, &form));
+    let config = match config {
+        Ok(c) => c,
+        Err(e) => {
+            return Ok(Redirect::to(&format!(
+                "/orgs/{}/settings/integrations/install/{}?error={}",
+                org,
+                integration_type.as_str(),
+                urlencoding::encode(&e)
+            ))
+            .into_response());
+        }
+    };
+
+    let store = state.integration_store.as_ref().unwrap();
+    let created = store
+        .create_integration(&CreateIntegrationInput {
+            organisation: org.clone(),
+            integration_type,
+            name: form.name,
+            config,
+            created_by: session.user.user_id.clone(),
+        })
+        .await
+        .map_err(|e| internal_error(&state, "create integration", &e))?;
+
+    let html = state
+        .templates
+        .render(
+            "pages/integration_installed.html.jinja",
+            context! {
+                title => format!("{} installed - Forage", created.name),
+                description => "Integration installed successfully",
+                user => context! {
+                    username => &session.user.username,
+                    user_id => &session.user.user_id,
+                },
+                current_org => &org,
+                orgs => session.user.orgs.iter().map(|o| context! { name => &o.name, role => &o.role }).collect::<Vec<_>>(),
+                csrf_token => &session.csrf_token,
+                active_tab => "settings",
+                integration => context! {
+                    id => &created.id,
+                    name => &created.name,
+                    type_display => created.integration_type.display_name(),
+                },
+                api_token => created.api_token,
+            },
+        )
+        .map_err(|e| internal_error(&state, "template error", &e))?;
+
+    Ok(Html(html).into_response())
+}
+
 // ─── Integration detail ─────────────────────────────────────────────
 
 async fn integration_detail(
@@ -362,6 +583,7 @@ async fn integration_detail(
         })?;
 
     let rules = store.list_rules(&id).await.unwrap_or_default();
+    let filter = store.get_notification_filter(&id).await.unwrap_or_default();
     let deliveries = store.list_deliveries(&id, 20).await.unwrap_or_default();
 
     let deliveries_ctx: Vec<_> = deliveries
@@ -373,6 +595,10 @@ async fn integration_detail(
                 status => d.status.as_str(),
                 error_message => &d.error_message,
                 attempted_at => &d.attempted_at,
+                attempts => d.attempts,
+                response_code => d.response_code,
+                next_attempt_at => &d.next_attempt_at,
+                can_redeliver => d.can_redeliver(),
             }
         })
         .collect();
@@ -411,6 +637,36 @@ async fn integration_detail(
             detail => url,
             has_secret => secret.is_some(),
         },
+        IntegrationConfig::Teams { webhook_url } | IntegrationConfig::Discord { webhook_url } => {
+            // Both URLs embed their credential in the path; only show the host.
+            let host = reqwest::Url::parse(webhook_url)
+                .ok()
+                .and_then(|u| u.host_str().map(String::from))
+                .unwrap_or_default();
+            context! {
+                type_name => integration.integration_type.display_name(),
+                detail => format!("Webhook on {host}"),
+            }
+        }
+        IntegrationConfig::PagerDuty { routing_key } => context! {
+            type_name => "PagerDuty",
+            detail => format!("Integration key …{}", &routing_key[routing_key.len().saturating_sub(4)..]),
+        },
+        IntegrationConfig::Email {
+            recipients,
+            digest_interval_minutes,
+        } => context! {
+            type_name => "Email digest",
+            detail => format!(
+                "{} · {}",
+                recipients.join(", "),
+                DIGEST_INTERVALS
+                    .iter()
+                    .find(|(m, _)| m == digest_interval_minutes)
+                    .map(|(_, label)| label.to_string())
+                    .unwrap_or_else(|| format!("every {digest_interval_minutes} minutes")),
+            ),
+        },
     };
 
     let html = state
@@ -439,8 +695,23 @@ async fn integration_detail(
                 config => config_display,
                 has_slack_oauth => state.slack_config.is_some(),
                 rules => rules_ctx,
+                filter => context! {
+                    projects => filter.projects.join(", "),
+                    environments => filter.environments.join(", "),
+                    destinations => filter.destinations.join(", "),
+                    min_severity => filter.min_severity.map(|s| s.as_str()).unwrap_or(""),
+                    title_template => filter.title_template.as_deref().unwrap_or(""),
+                    body_template => filter.body_template.as_deref().unwrap_or(""),
+                    active => !filter.is_empty(),
+                },
+                placeholders => TEMPLATE_PLACEHOLDERS
+                    .iter()
+                    .map(|(name, label)| context! { name => format!("{{{name}}}"), label => label })
+                    .collect::<Vec<_>>(),
                 deliveries => deliveries_ctx,
                 test_sent => query.test.is_some(),
+                redelivered => query.redelivered.is_some(),
+                error => query.error,
             },
         )
         .map_err(|e| internal_error(&state, "template error", &e))?;
@@ -489,6 +760,87 @@ async fn update_rules(
     .into_response())
 }
 
+// ─── Update notification filters ────────────────────────────────────
+
+#[derive(Deserialize)]
+struct UpdateFiltersForm {
+    _csrf: String,
+    #[serde(default)]
+    projects: String,
+    #[serde(default)]
+    environments: String,
+    #[serde(default)]
+    destinations: String,
+    #[serde(default)]
+    min_severity: String,
+    #[serde(default)]
+    title_template: String,
+    #[serde(default)]
+    body_template: String,
+}
+
+async fn update_filters(
+    State(state): State<AppState>,
+    session: Session,
+    Path((org, id)): Path<(String, String)>,
+    Form(form): Form<UpdateFiltersForm>,
+) -> Result<Response, Response> {
+    let cached_org = require_org_membership(&state, &session.user.orgs, &org)?;
+    require_admin(&state, cached_org)?;
+    require_integration_store(&state)?;
+    validate_csrf(&session, &form._csrf)?;
+
+    let store = state.integration_store.as_ref().unwrap();
+
+    // Verify integration belongs to org
+    store
+        .get_integration(&org, &id)
+        .await
+        .map_err(|e| internal_error(&state, "get integration", &e))?;
+
+    let reject = |message: &str| {
+        Redirect::to(&format!(
+            "/orgs/{}/settings/integrations/{}?error={}",
+            org,
+            id,
+            urlencoding::encode(message)
+        ))
+        .into_response()
+    };
+
+    let min_severity = match form.min_severity.as_str() {
+        "" => None,
+        s => match Severity::parse(s) {
+            Some(severity) => Some(severity),
+            None => return Ok(reject(&format!("Unknown severity '{s}'"))),
+        },
+    };
+    let template = |t: &str| Some(t.trim().to_string()).filter(|t| !t.is_empty());
+    let filter = NotificationFilter {
+        projects: parse_filter_list(&form.projects),
+        environments: parse_filter_list(&form.environments),
+        destinations: parse_filter_list(&form.destinations),
+        min_severity,
+        title_template: template(&form.title_template),
+        body_template: template(&form.body_template),
+    };
+
+    if let Err(e) = filter.validate() {
+        return Ok(reject(&e.to_string()));
+    }
+
+    store
+        .set_notification_filter(&id, &filter)
+        .await
+        .map_err(|e| internal_error(&state, "update notification filter", &e))?;
+
+    Ok(Redirect::to(&format!(
+        "/orgs/{}/settings/integrations/{}",
+        org, id
+    ))
+    .into_response())
+}
+
 // ─── Toggle integration ─────────────────────────────────────────────
 
 #[derive(Deserialize)]
@@ -594,7 +946,12 @@ async fn test_integration(
         }),
     };
 
-    let tasks = forage_core::integrations::router::route_notification(&test_event, &[integration]);
+    // Apply the message overrides (not the filters) so the test previews them.
+    let filter = store.get_notification_filter(&id).await.unwrap_or_default();
+    let tasks = forage_core::integrations::router::route_notification(
+        &filter.render(&test_event),
+        &[integration],
+    );
     let dispatcher = NotificationDispatcher::new(Arc::clone(store), String::new());
     for task in &tasks {
         dispatcher.dispatch(task).await;
@@ -607,6 +964,64 @@ async fn test_integration(
     .into_response())
 }
 
+// ─── Redeliver ──────────────────────────────────────────────────────
+
+async fn redeliver(
+    State(state): State<AppState>,
+    session: Session,
+    Path((org, id, delivery_id)): Path<(String, String, String)>,
+    Form(form): Form<CsrfForm>,
+) -> Result<Response, Response> {
+    let cached_org = require_org_membership(&state, &session.user.orgs, &org)?;
+    require_admin(&state, cached_org)?;
+    require_integration_store(&state)?;
+    validate_csrf(&session, &form._csrf)?;
+
+    let store = state.integration_store.as_ref().unwrap();
+    // Scope the delivery to an integration of this org.
+    store.get_integration(&org, &id).await.map_err(|e| {
+        error_page(
+            &state,
+            axum::http::StatusCode::NOT_FOUND,
+            "Not found",
+            &format!("Integration not found: {e}"),
+        )
+    })?;
+
+    let original = store.get_delivery(&id, &delivery_id).await.map_err(|e| {
+        error_page(
+            &state,
+            axum::http::StatusCode::NOT_FOUND,
+            "Not found",
+            &format!("Delivery not found: {e}"),
+        )
+    })?;
+    if !original.can_redeliver() {
+        return Err(error_page(
+            &state,
+            axum::http::StatusCode::BAD_REQUEST,
+            "Cannot redeliver",
+            "This delivery has no stored payload or is still queued.",
+        ));
+    }
+
+    let delivery = store
+        .redeliver(&id, &delivery_id)
+        .await
+        .map_err(|e| internal_error(&state, "redeliver", &e))?;
+
+    // First attempt inline so the result shows on the page; failures stay
+    // queued for the delivery worker.
+    let dispatcher = NotificationDispatcher::new(Arc::clone(store), state.forage_host.clone());
+    dispatcher.deliver_queued(&delivery).await;
+
+    Ok(Redirect::to(&format!(
+        "/orgs/{}/settings/integrations/{}?redelivered=1",
+        org, id
+    ))
+    .into_response())
+}
+
 // ─── Install Slack page ─────────────────────────────────────────────
 
 async fn install_slack_page(
@@ -1010,6 +1425,262 @@ async fn slack_oauth_callback(
     }
 }
 
+// ─── Slack interactivity (approval buttons) ─────────────────────────
+
+#[derive(Deserialize)]
+struct SlackInteraction {
+    #[serde(rename = "type")]
+    kind: String,
+    user: SlackInteractionId,
+    team: SlackInteractionId,
+    #[serde(default)]
+    actions: Vec<SlackInteractionAction>,
+    #[serde(default)]
+    response_url: String,
+    #[serde(default)]
+    message: serde_json::Value,
+}
+
+#[derive(Deserialize)]
+struct SlackInteractionId {
+    id: String,
+}
+
+#[derive(Deserialize)]
+struct SlackInteractionAction {
+    action_id: String,
+    #[serde(default)]
+    block_id: String,
+    #[serde(default)]
+    value: String,
+}
+
+/// Receives button clicks from Slack. Requests are authenticated by Slack's
+/// signing secret, not a session: the clicking Slack user is mapped to a
+/// Forage user through their Slack link, and Forest is called with the
+/// service account acting on that user's behalf.
+async fn slack_interactivity(
+    State(state): State<AppState>,
+    headers: axum::http::HeaderMap,
+    body: axum::body::Bytes,
+) -> Response {
+    use forage_core::integrations::slack::{
+        replace_action_block, verify_slack_signature, SlackApprovalTarget, APPROVE_ACTION_ID,
+        REJECT_ACTION_ID,
+    };
+
+    let Some(signing_secret) = state
+        .slack_config
+        .as_ref()
+        .and_then(|c| c.signing_secret.as_deref())
+    else {
+        return (
+            axum::http::StatusCode::SERVICE_UNAVAILABLE,
+            "Slack interactivity is not configured",
+        )
+            .into_response();
+    };
+
+    let header = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default()
+    };
+    if !verify_slack_signature(
+        signing_secret,
+        header("x-slack-request-timestamp"),
+        &body,
+        header("x-slack-signature"),
+        chrono::Utc::now().timestamp(),
+    ) {
+        return (axum::http::StatusCode::UNAUTHORIZED, "invalid signature").into_response();
+    }
+
+    let Some(interaction) = form_field(&body, "payload")
+        .and_then(|p| serde_json::from_str::<SlackInteraction>(&p).ok())
+    else {
+        return (axum::http::StatusCode::BAD_REQUEST, "invalid payload").into_response();
+    };
+
+    // Only our own approval buttons are handled; acknowledge anything else.
+    let Some(action) = interaction.actions.first().filter(|a| {
+        interaction.kind == "block_actions"
+            && (a.action_id == APPROVE_ACTION_ID || a.action_id == REJECT_ACTION_ID)
+    }) else {
+        return axum::http::StatusCode::OK.into_response();
+    };
+    let Ok(target) = serde_json::from_str::<SlackApprovalTarget>(&action.value) else {
+        return (axum::http::StatusCode::BAD_REQUEST, "invalid action value").into_response();
+    };
+    if validate_slack_webhook_url(&interaction.response_url).is_err() {
+        return (axum::http::StatusCode::BAD_REQUEST, "invalid response_url").into_response();
+    }
+
+    let approve = action.action_id == APPROVE_ACTION_ID;
+    let slack_user_id = &interaction.user.id;
+    let reply = match (&state.integration_store, &state.service_account_key) {
+        (Some(store), Some(service_key)) => {
+            match store
+                .get_slack_user_link_by_slack_user(&interaction.team.id, slack_user_id)
+                .await
+            {
+                Ok(Some(link)) => {
+                    match submit_slack_decision(&state, service_key, &link.user_id, &target, approve)
+                        .await
+                    {
+                        Ok(()) => {
+                            tracing::info!(
+                                user_id = %link.user_id,
+                                slack_user_id = %slack_user_id,
+                                organisation = %target.organisation(),
+                                environment = %target.environment(),
+                                approve,
+                                "slack approval decision submitted"
+                            );
+                            let text = target.decision_text(approve, slack_user_id);
+                            let mut message = interaction.message.clone();
+                            if let Some(attachments) = message["attachments"].as_array_mut() {
+                                for attachment in attachments {
+                                    if let Some(blocks) = attachment["blocks"].as_array_mut() {
+                                        replace_action_block(blocks, &action.block_id, &text);
+                                    }
+                                }
+                            }
+                            serde_json::json!({
+                                "replace_original": true,
+                                "text": message["text"],
+                                "attachments": message["attachments"],
+                            })
+                        }
+                        Err(e) => slack_ephemeral(&slack_decision_error(&target, approve, &e)),
+                    }
+                }
+                Ok(None) => slack_ephemeral(&format!(
+                    "Your Slack account is not linked to Forage yet. <{}/settings/account/slack/connect|Link your account>, then try again.",
+                    state.forage_host.trim_end_matches('/')
+                )),
+                Err(e) => {
+                    tracing::error!(error = %e, "failed to look up slack user link");
+                    slack_ephemeral("Something went wrong on our side. Please try again.")
+                }
+            }
+        }
+        _ => slack_ephemeral("Approving from Slack is not available on this Forage instance."),
+    };
+
+    let http = reqwest::Client::new();
+    if let Err(e) = http
+        .post(&interaction.response_url)
+        .json(&reply)
+        .send()
+        .await
+        .and_then(|r| r.error_for_status())
+    {
+        tracing::warn!(error = %e, "failed to post slack interaction response");
+    }
+
+    axum::http::StatusCode::OK.into_response()
+}
+
+async fn submit_slack_decision(
+    state: &AppState,
+    service_key: &str,
+    user_id: &str,
+    target: &forage_core::integrations::slack::SlackApprovalTarget,
+    approve: bool,
+) -> Result<(), forage_core::platform::PlatformError> {
+    use forage_core::integrations::slack::SlackApprovalTarget;
+
+    let platform = &state.platform_client;
+    match (target, approve) {
+        (SlackApprovalTarget::PlanStage { release_intent_id, stage_id, .. }, true) => {
+            platform
+                .approve_plan_stage(service_key, release_intent_id, stage_id, Some(user_id))
+                .await
+        }
+        (SlackApprovalTarget::PlanStage { release_intent_id, stage_id, .. }, false) => {
+            platform
+                .reject_plan_stage(
+                    service_key,
+                    release_intent_id,
+                    stage_id,
+                    Some("Rejected from Slack"),
+                    Some(user_id),
+                )
+                .await
+        }
+        (
+            SlackApprovalTarget::Release { organisation, project, release_intent_id, environment },
+            true,
+        ) => platform
+            .approve_release(
+                service_key,
+                organisation,
+                project,
+                release_intent_id,
+                environment,
+                Some("Approved from Slack"),
+                false,
+                Some(user_id),
+            )
+            .await
+            .map(|_| ()),
+        (
+            SlackApprovalTarget::Release { organisation, project, release_intent_id, environment },
+            false,
+        ) => platform
+            .reject_release(
+                service_key,
+                organisation,
+                project,
+                release_intent_id,
+                environment,
+                Some("Rejected from Slack"),
+                Some(user_id),
+            )
+            .await
+            .map(|_| ()),
+    }
+}
+
+fn slack_decision_error(
+    target: &forage_core::integrations::slack::SlackApprovalTarget,
+    approve: bool,
+    err: &forage_core::platform::PlatformError,
+) -> String {
+    let verb = if approve { "approve" } else { "reject" };
+    match err {
+        forage_core::platform::PlatformError::NotFound(_) => format!(
+            "Could not {verb} `{}`: it is no longer waiting for approval.",
+            target.environment()
+        ),
+        other => format!("Could not {verb} `{}`: {other}", target.environment()),
+    }
+}
+
+fn slack_ephemeral(text: &str) -> serde_json::Value {
+    serde_json::json!({
+        "response_type": "ephemeral",
+        "replace_original": false,
+        "text": text,
+    })
+}
+
+/// Read one field from an `application/x-www-form-urlencoded` body.
+fn form_field(body: &[u8], name: &str) -> Option<String> {
+    std::str::from_utf8(body)
+        .ok()?
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(k, _)| *k == name)
+        .and_then(|(_, v)| {
+            urlencoding::decode(&v.replace('+', " "))
+                .ok()
+                .map(|v| v.into_owned())
+        })
+}
+
 // ─── Helpers ────────────────────────────────────────────────────────
 
 fn notification_type_label(nt: &str) -> &str {
@@ -1018,6 +1689,9 @@ fn notification_type_label(nt: &str) -> &str {
         "release_started" => "Release started",
         "release_succeeded" => "Release succeeded",
         "release_failed" => "Release failed",
+        "drift_detected" => "Drift detected",
+        "drift_resolved" => "Drift resolved",
+        "schedule_failed" => "Schedule failed",
         other => other,
     }
 }
@@ -3,8 +3,10 @@ mod device;
 mod events;
 mod integrations;
 mod pages;
+mod passkeys;
 mod platform;
 mod registry;
+mod sso;
 
 use axum::Router;
 use axum::http::StatusCode;
@@ -20,10 +22,12 @@ pub fn router() -> Router<AppState> {
         .merge(pages::router())
         .merge(auth::router())
         .merge(device::router())
+        .merge(passkeys::router())
         .merge(platform::router())
         .merge(events::router())
         .merge(integrations::router())
         .merge(registry::router())
+        .merge(sso::router())
 }
 
 /// Render an error page with the given status code, heading, and message.
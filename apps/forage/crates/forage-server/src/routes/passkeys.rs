@@ -0,0 +1,272 @@
+//! WebAuthn passkeys: registration from account settings, and sign-in
+//! either as the second factor after a password or on their own.
+//!
+//! forest-server owns the ceremonies (challenges, verification, the
+//! credential store). Forage only relays the options JSON to the
+//! browser and the browser's credential JSON back, so every handler here
+//! is a thin wrapper. The `*/options` endpoints answer JSON for
+//! `static/js/passkeys.js`; everything else is a plain form POST.
+
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Redirect, Response};
+use axum::routing::post;
+use axum::{Form, Json, Router};
+use axum_extra::extract::CookieJar;
+use forage_core::auth::{AuthError, MfaMethod, PasskeyCeremony, PasskeyResponse};
+use serde::Deserialize;
+
+use super::auth::{complete_mfa_login, render_login, render_mfa_challenge};
+use super::{error_page, internal_error};
+use crate::auth::{self, Session};
+use crate::state::AppState;
+
+/// Mirrors forest-server's limit so a too-long name fails with a useful
+/// message instead of a generic registration error.
+const MAX_PASSKEY_NAME_LEN: usize = 64;
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/login/passkey/options", post(login_options))
+        .route("/login/passkey", post(login_submit))
+        .route("/settings/account/passkeys/options", post(register_options))
+        .route("/settings/account/passkeys", post(register_submit))
+        .route("/settings/account/passkeys/{id}/rename", post(rename_submit))
+        .route("/settings/account/passkeys/{id}/delete", post(delete_submit))
+}
+
+/// `{ceremony_id, options}` for passkeys.js. forest-server produces the
+/// options as JSON text; parse it so the browser gets an object, not a
+/// string it has to decode twice.
+pub(super) fn ceremony_response(ceremony: PasskeyCeremony) -> Response {
+    match serde_json::from_str::<serde_json::Value>(&ceremony.options_json) {
+        Ok(options) => Json(serde_json::json!({
+            "ceremony_id": ceremony.ceremony_id,
+            "options": options,
+        }))
+        .into_response(),
+        Err(e) => {
+            tracing::error!(error = %e, "forest-server returned invalid passkey options");
+            json_error(StatusCode::BAD_GATEWAY, "Couldn't start the passkey prompt.")
+        }
+    }
+}
+
+pub(super) fn json_error(status: StatusCode, message: &str) -> Response {
+    (status, Json(serde_json::json!({ "error": message }))).into_response()
+}
+
+// ─── Sign-in ────────────────────────────────────────────────────────
+
+#[derive(Deserialize)]
+struct LoginOptionsForm {
+    /// `mfa` when the password step already passed and the passkey is
+    /// the second factor; absent for passwordless sign-in.
+    #[serde(default)]
+    mode: Option<String>,
+}
+
+async fn login_options(
+    State(state): State<AppState>,
+    jar: CookieJar,
+    Form(form): Form<LoginOptionsForm>,
+) -> Response {
+    let mfa_token = match form.mode.as_deref() {
+        Some("mfa") => match jar.get("forage_mfa_session") {
+            Some(c) => Some(c.value().to_string()),
+            None => {
+                return json_error(
+                    StatusCode::BAD_REQUEST,
+                    "Your sign-in expired. Please start again.",
+                );
+            }
+        },
+        _ => None,
+    };
+
+    match state
+        .forest_client
+        .begin_passkey_login(mfa_token.as_deref())
+        .await
+    {
+        Ok(ceremony) => ceremony_response(ceremony),
+        Err(AuthError::InvalidCredentials) => json_error(
+            StatusCode::UNAUTHORIZED,
+            "Your sign-in expired. Please start again.",
+        ),
+        Err(e) => {
+            tracing::error!(error = %e, "begin passkey login failed");
+            json_error(
+                StatusCode::BAD_GATEWAY,
+                "Couldn't start the passkey prompt.",
+            )
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct PasskeyForm {
+    ceremony_id: String,
+    credential_json: String,
+    /// `mfa` when the passkey is the second factor of a password login.
+    #[serde(default)]
+    mode: Option<String>,
+}
+
+impl PasskeyForm {
+    fn response(self) -> PasskeyResponse {
+        PasskeyResponse {
+            ceremony_id: self.ceremony_id,
+            credential_json: self.credential_json,
+        }
+    }
+}
+
+async fn login_submit(
+    State(state): State<AppState>,
+    Form(form): Form<PasskeyForm>,
+) -> Result<Response, StatusCode> {
+    let mfa = form.mode.as_deref() == Some("mfa");
+    match state.forest_client.finish_passkey_login(&form.response()).await {
+        Ok(tokens) => complete_mfa_login(&state, tokens).await,
+        // A rejected passkey leaves the MFA session intact, so stay on the
+        // challenge and let the user try again.
+        Err(AuthError::InvalidCredentials) if mfa => render_mfa_challenge(
+            &state,
+            &[MfaMethod::Passkey],
+            Some("That passkey wasn't accepted. Please try again.".into()),
+        ),
+        Err(AuthError::InvalidCredentials) => render_login(
+            &state,
+            "",
+            Some("That passkey wasn't accepted. Please try again.".into()),
+            None,
+        ),
+        Err(AuthError::PermissionDenied(msg)) => render_login(&state, "", Some(msg), None),
+        Err(e) => {
+            tracing::error!(error = %e, "passkey login failed");
+            render_login(&state, "", Some(e.to_string()), None)
+        }
+    }
+}
+
+// ─── Account settings ───────────────────────────────────────────────
+
+#[derive(Deserialize)]
+struct CsrfForm {
+    _csrf: String,
+}
+
+async fn register_options(
+    State(state): State<AppState>,
+    session: Session,
+    Form(form): Form<CsrfForm>,
+) -> Response {
+    if !auth::validate_csrf(&session, &form._csrf) {
+        return json_error(StatusCode::FORBIDDEN, "CSRF validation failed. Please reload the page.");
+    }
+
+    match state
+        .forest_client
+        .begin_passkey_registration(&session.access_token)
+        .await
+    {
+        Ok(ceremony) => ceremony_response(ceremony),
+        Err(e) => {
+            tracing::error!(error = %e, "begin passkey registration failed");
+            json_error(
+                StatusCode::BAD_GATEWAY,
+                "Couldn't start the passkey prompt.",
+            )
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct RegisterForm {
+    _csrf: String,
+    name: String,
+    ceremony_id: String,
+    credential_json: String,
+}
+
+async fn register_submit(
+    State(state): State<AppState>,
+    session: Session,
+    Form(form): Form<RegisterForm>,
+) -> Result<Response, Response> {
+    if !auth::validate_csrf(&session, &form._csrf) {
+        return Err(error_page(&state, StatusCode::FORBIDDEN, "Invalid request", "CSRF validation failed. Please try again."));
+    }
+    let name = form.name.trim();
+    if name.is_empty() || name.chars().count() > MAX_PASSKEY_NAME_LEN {
+        return Ok(Redirect::to("/settings/account?error=passkey_name").into_response());
+    }
+
+    let response = PasskeyResponse {
+        ceremony_id: form.ceremony_id,
+        credential_json: form.credential_json,
+    };
+    match state
+        .forest_client
+        .finish_passkey_registration(&session.access_token, name, &response)
+        .await
+    {
+        Ok(_) => Ok(Redirect::to("/settings/account?flash=passkey_added").into_response()),
+        Err(AuthError::AlreadyExists(_)) => {
+            Ok(Redirect::to("/settings/account?error=passkey_duplicate").into_response())
+        }
+        Err(e) => {
+            tracing::warn!(error = %e, "passkey registration failed");
+            Ok(Redirect::to("/settings/account?error=passkey_failed").into_response())
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct RenameForm {
+    _csrf: String,
+    name: String,
+}
+
+async fn rename_submit(
+    State(state): State<AppState>,
+    session: Session,
+    Path(passkey_id): Path<String>,
+    Form(form): Form<RenameForm>,
+) -> Result<Response, Response> {
+    if !auth::validate_csrf(&session, &form._csrf) {
+        return Err(error_page(&state, StatusCode::FORBIDDEN, "Invalid request", "CSRF validation failed. Please try again."));
+    }
+    let name = form.name.trim();
+    if name.is_empty() || name.chars().count() > MAX_PASSKEY_NAME_LEN {
+        return Ok(Redirect::to("/settings/account?error=passkey_name").into_response());
+    }
+
+    state
+        .forest_client
+        .rename_passkey(&session.access_token, &passkey_id, name)
+        .await
+        .map_err(|e| internal_error(&state, "failed to rename passkey", &e))?;
+
+    Ok(Redirect::to("/settings/account?flash=passkey_renamed").into_response())
+}
+
+async fn delete_submit(
+    State(state): State<AppState>,
+    session: Session,
+    Path(passkey_id): Path<String>,
+    Form(form): Form<CsrfForm>,
+) -> Result<Response, Response> {
+    if !auth::validate_csrf(&session, &form._csrf) {
+        return Err(error_page(&state, StatusCode::FORBIDDEN, "Invalid request", "CSRF validation failed. Please try again."));
+    }
+
+    state
+        .forest_client
+        .delete_passkey(&session.access_token, &passkey_id)
+        .await
+        .map_err(|e| internal_error(&state, "failed to delete passkey", &e))?;
+
+    Ok(Redirect::to("/settings/account?flash=passkey_deleted").into_response())
+}
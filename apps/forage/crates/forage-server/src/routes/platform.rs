@@ -7,8 +7,8 @@ use axum_extra::extract::Form;
 use chrono::Datelike;
 use forage_core::platform::{
     validate_slug, CreatePolicyInput, CreateReleasePipelineInput, CreateTriggerInput,
-    PipelineStage, PolicyConfig, UpdatePolicyInput, UpdateReleasePipelineInput,
-    UpdateTriggerInput,
+    EnvironmentProtection, PipelineStage, PolicyConfig, UpdatePolicyInput,
+    UpdateReleasePipelineInput, UpdateTriggerInput,
 };
 use forage_core::session::CachedOrg;
 use minijinja::context;
@@ -52,6 +52,10 @@ pub fn router() -> Router<AppState> {
             "/orgs/{org}/destinations/environments/{id}/order",
             post(reorder_environment_submit),
         )
+        .route(
+            "/orgs/{org}/destinations/environments/{id}/protection",
+            post(update_environment_protection_submit),
+        )
         .route(
             "/orgs/{org}/destinations/create",
             post(create_destination_submit),
@@ -82,6 +86,10 @@ pub fn router() -> Router<AppState> {
             "/orgs/{org}/settings/members/{user_id}/remove",
             post(remove_member_submit),
         )
+        .route(
+            "/orgs/{org}/settings/members/{user_id}/sign-out",
+            post(sign_out_member_submit),
+        )
         .route(
             "/orgs/{org}/projects/{project}/deploy",
             post(deploy_release),
@@ -155,6 +163,10 @@ pub fn router() -> Router<AppState> {
             "/api/orgs/{org}/projects/{project}/plan-stages/{stage_id}/output",
             get(get_plan_output_api),
         )
+        .route(
+            "/api/orgs/{org}/projects/{project}/releases/{release_id}/cancel",
+            post(cancel_release_submit),
+        )
         .route(
             "/api/orgs/{org}/projects/{project}/timeline",
             get(timeline_api),
@@ -832,6 +844,7 @@ async fn project_detail(
         component_versions,
         comp_detail,
         project_info,
+        schedules,
     ) = tokio::join!(
         state
             .platform_client
@@ -856,6 +869,9 @@ async fn project_detail(
         state
             .platform_client
             .get_project(&session.access_token, &org, &project),
+        state
+            .platform_client
+            .list_release_schedules(&session.access_token, &org, &project),
     );
     let artifacts = artifacts.map_err(|e| internal_error(&state, "list_artifacts", &e))?;
     let projects = warn_default("list_projects", projects);
@@ -864,6 +880,7 @@ async fn project_detail(
     let release_intents = warn_default("get_release_intent_states", release_intents);
     let project_pipelines = warn_default("list_release_pipelines", project_pipelines);
     let component_versions = warn_default("list_component_versions", component_versions);
+    let schedules = warn_default("list_release_schedules", schedules);
     // Project-level description + blessed metadata. A missing project
     // (Ok(None)) or a transient gRPC failure both degrade to empty —
     // the Overview still renders with the component-level fallback.
@@ -991,6 +1008,7 @@ async fn project_detail(
                 // component would otherwise render its own empty state
                 // and crowd the Overview's Get-started panel.
                 project_has_timeline => !data.timeline.is_empty(),
+                schedules => schedules,
             },
         )
         .map_err(|e| {
@@ -1342,6 +1360,7 @@ async fn artifact_detail(
             context! {
                 name => ds.destination_name,
                 environment => ds.environment,
+                release_id => ds.release_id,
                 status => ds.status,
                 error_message => ds.error_message,
                 queued_at => ds.queued_at,
@@ -3115,6 +3134,10 @@ async fn destinations_page(
                 name => e.name,
                 description => e.description,
                 sort_order => e.sort_order,
+                protected => e.protection.protected,
+                required_prior_environment => e.protection.required_prior_environment,
+                approver_groups => e.protection.approver_groups,
+                allowed_branches => e.protection.allowed_branches,
                 destinations => env_dests,
             }
         })
@@ -3249,7 +3272,7 @@ async fn reorder_environment_submit(
 
     state
         .platform_client
-        .update_environment(&session.access_token, &id, None, Some(form.sort_order))
+        .update_environment(&session.access_token, &id, None, Some(form.sort_order), None)
         .await
         .map_err(|e| {
             internal_error(&state, "update environment error", &e)
@@ -3258,6 +3281,77 @@ async fn reorder_environment_submit(
     Ok(Redirect::to(&format!("/orgs/{org}/destinations")).into_response())
 }
 
+#[derive(Deserialize)]
+struct EnvironmentProtectionForm {
+    _csrf: String,
+    #[serde(default)]
+    protected: Option<String>,
+    #[serde(default)]
+    required_prior_environment: String,
+    /// Comma-separated SCIM group names.
+    #[serde(default)]
+    approver_groups: String,
+    /// One branch regex per line; regexes may themselves contain commas.
+    #[serde(default)]
+    allowed_branches: String,
+}
+
+impl EnvironmentProtectionForm {
+    fn to_protection(&self) -> EnvironmentProtection {
+        let prior = self.required_prior_environment.trim();
+        EnvironmentProtection {
+            protected: self.protected.is_some(),
+            required_prior_environment: (!prior.is_empty()).then(|| prior.to_string()),
+            approver_groups: self
+                .approver_groups
+                .split(',')
+                .map(str::trim)
+                .filter(|g| !g.is_empty())
+                .map(String::from)
+                .collect(),
+            allowed_branches: self
+                .allowed_branches
+                .lines()
+                .map(str::trim)
+                .filter(|b| !b.is_empty())
+                .map(String::from)
+                .collect(),
+        }
+    }
+}
+
+async fn update_environment_protection_submit(
+    State(state): State<AppState>,
+    session: Session,
+    Path((org, id)): Path<(String, String)>,
+    Form(form): Form<EnvironmentProtectionForm>,
+) -> Result<Response, Response> {
+    let orgs = &session.user.orgs;
+    let current_org = require_org_membership(&state, orgs, &org)?;
+    require_admin(&state, current_org)?;
+    if !auth::validate_csrf(&session, &form._csrf) {
+        return Err(error_page(&state, StatusCode::FORBIDDEN, "Invalid request", "CSRF validation failed. Please try again."));
+    }
+
+    let protection = form.to_protection();
+    match state
+        .platform_client
+        .update_environment(&session.access_token, &id, None, None, Some(&protection))
+        .await
+    {
+        Ok(_) => Ok(Redirect::to(&format!("/orgs/{org}/destinations")).into_response()),
+        // Forest rejects invalid rules (unknown prior environment, bad
+        // branch regex, cycles) with a message worth showing as-is.
+        Err(forage_core::platform::PlatformError::Other(msg)) => Err(error_page(
+            &state,
+            StatusCode::BAD_REQUEST,
+            "Could not update protection rules",
+            &msg,
+        )),
+        Err(e) => Err(internal_error(&state, "update environment protection error", &e)),
+    }
+}
+
 #[derive(Deserialize)]
 struct CreateDestinationForm {
     _csrf: String,
@@ -3504,10 +3598,17 @@ async fn update_destination_submit(
 
 // ─── Members ────────────────────────────────────────────────────────
 
+#[derive(Deserialize, Default)]
+struct MembersQuery {
+    #[serde(default)]
+    signed_out: Option<String>,
+}
+
 async fn members_page(
     State(state): State<AppState>,
     session: Session,
     Path(org): Path<String>,
+    Query(query): Query<MembersQuery>,
 ) -> Result<Response, Response> {
     let orgs = &session.user.orgs;
     let current_org = require_org_membership(&state, orgs, &org)?;
@@ -3533,7 +3634,9 @@ async fn members_page(
                 orgs => orgs_context(orgs),
                 org_name => &org,
                 is_admin => is_admin,
+                is_owner => current_org.role == "owner",
                 active_tab => "settings",
+                signed_out => query.signed_out,
                 members => members.iter().map(|m| context! {
                     user_id => m.user_id,
                     username => m.username,
@@ -3706,6 +3809,69 @@ async fn remove_member_submit(
     Ok(Redirect::to(&format!("/orgs/{org}/settings/members")).into_response())
 }
 
+/// Sign a member out of every session. Admins can do this for members and
+/// other admins; only owners can sign out owners.
+async fn sign_out_member_submit(
+    State(state): State<AppState>,
+    session: Session,
+    Path((org, user_id)): Path<(String, String)>,
+    Form(form): Form<CsrfForm>,
+) -> Result<Response, Response> {
+    let orgs = &session.user.orgs;
+    let current_org = require_org_membership(&state, orgs, &org)?;
+    require_admin(&state, current_org)?;
+
+    if !auth::validate_csrf(&session, &form._csrf) {
+        return Err(error_page(
+            &state,
+            StatusCode::FORBIDDEN,
+            "Invalid request",
+            "CSRF validation failed. Please try again.",
+        ));
+    }
+
+    let members = state
+        .platform_client
+        .list_members(&session.access_token, &current_org.organisation_id)
+        .await
+        .map_err(|e| internal_error(&state, "list_members", &e))?;
+    let Some(member) = members.iter().find(|m| m.user_id == user_id) else {
+        return Err(error_page(
+            &state,
+            StatusCode::NOT_FOUND,
+            "Not found",
+            "That user is not a member of this organisation.",
+        ));
+    };
+    if member.role == "owner" && current_org.role != "owner" {
+        return Err(error_page(
+            &state,
+            StatusCode::FORBIDDEN,
+            "Access denied",
+            "Only owners can sign out an owner.",
+        ));
+    }
+
+    // Signing yourself out here keeps the session you are using.
+    let keep = (user_id == session.user.user_id).then_some(&session.session_id);
+    let count = auth::revoke_user_sessions(&state, &user_id, keep)
+        .await
+        .map_err(|e| internal_error(&state, "revoke sessions", &e))?;
+    tracing::info!(
+        org = %org,
+        user_id = %user_id,
+        revoked_by = %session.user.user_id,
+        count,
+        "signed member out of all sessions"
+    );
+
+    Ok(Redirect::to(&format!(
+        "/orgs/{org}/settings/members?signed_out={}",
+        urlencoding::encode(&member.username)
+    ))
+    .into_response())
+}
+
 // ─── Auto-Release Policies ──────────────────────────────────────────
 
 // ─── Triggers (auto-release triggers) ───────────────────────────────
@@ -5088,6 +5254,17 @@ fn approval_error(
     headers: &axum::http::HeaderMap,
     status: StatusCode,
     message: &str,
+) -> Response {
+    action_error(state, headers, status, "Approval failed", message)
+}
+
+/// JSON error for API callers, an error page for form posts.
+fn action_error(
+    state: &AppState,
+    headers: &axum::http::HeaderMap,
+    status: StatusCode,
+    title: &str,
+    message: &str,
 ) -> Response {
     let wants_json = headers
         .get(axum::http::header::ACCEPT)
@@ -5097,7 +5274,7 @@ fn approval_error(
     if wants_json {
         (status, Json(serde_json::json!({ "error": message }))).into_response()
     } else {
-        error_page(state, status, "Approval failed", message)
+        error_page(state, status, title, message)
     }
 }
 
@@ -5133,6 +5310,7 @@ async fn approve_release_submit(
             &form.target_environment,
             comment.as_deref(),
             force_bypass,
+            None,
         )
         .await
         .map_err(|e| match e {
@@ -5183,6 +5361,7 @@ async fn reject_release_submit(
             &form.release_intent_id,
             &form.target_environment,
             comment.as_deref(),
+            None,
         )
         .await
         .map_err(|e| match e {
@@ -5240,6 +5419,7 @@ async fn approve_plan_stage_submit(
             &session.access_token,
             &form.release_intent_id,
             &stage_id,
+            None,
         )
         .await
         .map_err(|e| match e {
@@ -5292,6 +5472,7 @@ async fn reject_plan_stage_submit(
             &form.release_intent_id,
             &stage_id,
             reason.as_deref(),
+            None,
         )
         .await
         .map_err(|e| match e {
@@ -5313,6 +5494,70 @@ async fn reject_plan_stage_submit(
     }
 }
 
+// ── Release cancel ──────────────────────────────────────────────────
+
+#[derive(Deserialize)]
+struct CancelReleaseForm {
+    csrf_token: String,
+    #[serde(default)]
+    reason: Option<String>,
+    #[serde(default)]
+    redirect_to: Option<String>,
+}
+
+async fn cancel_release_submit(
+    State(state): State<AppState>,
+    session: Session,
+    headers: axum::http::HeaderMap,
+    Path((org, _project, release_id)): Path<(String, String, String)>,
+    Form(form): Form<CancelReleaseForm>,
+) -> Result<Response, Response> {
+    let orgs = &session.user.orgs;
+    require_org_membership(&state, orgs, &org)?;
+
+    if form.csrf_token != session.csrf_token {
+        return Err(action_error(
+            &state,
+            &headers,
+            StatusCode::FORBIDDEN,
+            "Cancel failed",
+            "CSRF validation failed. Please try again.",
+        ));
+    }
+
+    let reason = form.reason.as_deref().and_then(|s| {
+        let t = s.trim();
+        if t.is_empty() {
+            None
+        } else {
+            Some(t.to_string())
+        }
+    });
+
+    state
+        .platform_client
+        .cancel_release(&session.access_token, &release_id, reason.as_deref())
+        .await
+        .map_err(|e| match e {
+            forage_core::platform::PlatformError::NotAuthenticated => {
+                axum::response::Redirect::to("/login").into_response()
+            }
+            other => action_error(
+                &state,
+                &headers,
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Cancel failed",
+                &format!("{other}"),
+            ),
+        })?;
+
+    if let Some(redirect) = &form.redirect_to {
+        Ok(Redirect::to(redirect).into_response())
+    } else {
+        Ok(Json(serde_json::json!({ "ok": true })).into_response())
+    }
+}
+
 #[derive(Deserialize)]
 struct PlanOutputQuery {
     release_intent_id: String,
@@ -5389,6 +5634,7 @@ async fn compute_page(
             context! {
                 id => i.id,
                 resource_name => i.resource_name,
+                kind => i.kind.to_string(),
                 project => i.project,
                 destination => i.destination,
                 environment => i.environment,
@@ -5398,6 +5644,7 @@ async fn compute_page(
                 cpu => i.cpu,
                 memory => i.memory,
                 status => i.status,
+                spec => compute_spec_context(&i.spec),
             }
         })
         .collect();
@@ -5459,6 +5706,144 @@ async fn compute_page(
     Ok(Html(html).into_response())
 }
 
+/// Summarise the requested spec of a compute instance for the compute page.
+/// Env values are never rendered; only where they come from.
+fn compute_spec_context(resource: &forage_core::compute::ComputeResourceSpec) -> minijinja::Value {
+    use forage_core::compute::ResourceSpec;
+    use forage_core::compute::spec::{EnvSource, ProbeHandler, UpdateStrategy, VolumeSource};
+
+    let Some(container) = resource.container() else {
+        return minijinja::Value::from(());
+    };
+
+    let env: Vec<minijinja::Value> = container
+        .env
+        .iter()
+        .map(|e| {
+            let source = match &e.source {
+                EnvSource::Value { .. } => "value".to_string(),
+                EnvSource::Secret { secret_name, key } => format!("secret {secret_name}/{key}"),
+                EnvSource::Config { config_name, key } => format!("config {config_name}/{key}"),
+                EnvSource::Field { path } => format!("field {path}"),
+                EnvSource::ResourceField { resource } => format!("resource {resource}"),
+            };
+            context! { name => e.name, source => source }
+        })
+        .collect();
+
+    let ports: Vec<String> = container
+        .ports
+        .iter()
+        .map(|p| {
+            if p.name.is_empty() {
+                format!("{}/{}", p.container_port, p.protocol)
+            } else {
+                format!("{}:{}/{}", p.name, p.container_port, p.protocol)
+            }
+        })
+        .collect();
+
+    let probes: Vec<String> = [
+        ("liveness", &container.liveness_probe),
+        ("readiness", &container.readiness_probe),
+        ("startup", &container.startup_probe),
+    ]
+    .into_iter()
+    .filter_map(|(label, probe)| {
+        let handler = match &probe.as_ref()?.handler {
+            ProbeHandler::HttpGet(h) => format!("GET {}:{}", h.path, h.port),
+            ProbeHandler::TcpSocket { port } => format!("tcp {port}"),
+            ProbeHandler::Exec { command } => format!("exec {}", command.join(" ")),
+            ProbeHandler::Grpc { port, .. } => format!("grpc {port}"),
+        };
+        Some(format!("{label}: {handler}"))
+    })
+    .collect();
+
+    let (volumes, autoscaling, schedule, update_strategy, sidecars) = match &resource.spec {
+        ResourceSpec::ContainerService(cs) => (
+            &cs.volumes,
+            cs.scaling.autoscaling.as_ref().map(|a| {
+                let mut s = format!("{}–{} replicas", a.min_replicas, a.max_replicas);
+                if let Some(cpu) = a.target_cpu_utilization_percent {
+                    s.push_str(&format!(", cpu {cpu}%"));
+                }
+                if let Some(mem) = a.target_memory_utilization_percent {
+                    s.push_str(&format!(", memory {mem}%"));
+                }
+                s
+            }),
+            None,
+            match &cs.update_strategy {
+                UpdateStrategy::Recreate => Some("Recreate".to_string()),
+                UpdateStrategy::RollingUpdate {
+                    max_unavailable,
+                    max_surge,
+                } => Some(format!(
+                    "RollingUpdate (max unavailable {}, max surge {})",
+                    max_unavailable.as_deref().unwrap_or("default"),
+                    max_surge.as_deref().unwrap_or("default"),
+                )),
+                UpdateStrategy::Default => None,
+            },
+            cs.sidecars.iter().map(|c| c.name.clone()).collect(),
+        ),
+        ResourceSpec::CronJob(cj) => (
+            &cj.volumes,
+            None,
+            Some(match &cj.timezone {
+                Some(tz) => format!("{} ({tz})", cj.schedule),
+                None => cj.schedule.clone(),
+            }),
+            None,
+            vec![],
+        ),
+        ResourceSpec::Job(j) => (&j.volumes, None, None, None, vec![]),
+        ResourceSpec::Service(_) | ResourceSpec::Route(_) => unreachable!("no container"),
+    };
+
+    let volumes: Vec<String> = volumes
+        .iter()
+        .map(|v| {
+            let source = match &v.source {
+                VolumeSource::EmptyDir { .. } => "emptyDir".to_string(),
+                VolumeSource::Secret { secret_name, .. } => format!("secret {secret_name}"),
+                VolumeSource::ConfigMap {
+                    config_map_name, ..
+                } => format!("config map {config_map_name}"),
+                VolumeSource::Pvc { claim_name, .. } => format!("pvc {claim_name}"),
+                VolumeSource::HostPath { path, .. } => format!("host {path}"),
+                VolumeSource::Nfs { server, path, .. } => format!("nfs {server}:{path}"),
+            };
+            format!("{} ({source})", v.name)
+        })
+        .collect();
+
+    let limits = &container.resources.limits;
+    let limits = match (&limits.cpu, &limits.memory) {
+        (None, None) => None,
+        (cpu, memory) => Some(format!(
+            "{} / {}",
+            cpu.as_deref().unwrap_or("-"),
+            memory.as_deref().unwrap_or("-")
+        )),
+    };
+
+    context! {
+        command => container.command.join(" "),
+        args => container.args.join(" "),
+        env => env,
+        ports => ports,
+        probes => probes,
+        volumes => volumes,
+        limits => limits,
+        autoscaling => autoscaling,
+        schedule => schedule,
+        update_strategy => update_strategy,
+        sidecars => sidecars,
+    }
+}
+
 async fn rollout_detail_page(
     State(state): State<AppState>,
     session: Session,
@@ -0,0 +1,1081 @@
+use axum::extract::{Path, Query, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{Html, IntoResponse, Redirect, Response};
+use axum::routing::{get, post};
+use axum::{Form, Router};
+use axum_extra::extract::CookieJar;
+use chrono::Utc;
+use forage_core::auth::sso::{
+    ClaimMapping, OidcProviderConfig, OrgSsoConfig, SamlProviderConfig, SsoError, SsoProvider,
+    SSO_PROVIDER, SSO_ROLES, domain_verification_name, domain_verification_value, email_domain,
+};
+use forage_core::auth::{AuthError, LinkedProvider, OidcExchange, OidcIdentity, link_input_from_oidc};
+use forage_core::platform::validate_slug;
+use forage_core::session::{
+    CachedOrg, CachedUser, SessionData, SessionId, generate_csrf_token,
+};
+use minijinja::context;
+use serde::Deserialize;
+
+use super::{error_page, internal_error};
+use crate::auth::{self, MaybeSession, Session};
+use crate::oidc::GenericOidcExchange;
+use crate::saml;
+use crate::state::AppState;
+
+const SSO_STATE_COOKIE: &str = "forage_sso_state";
+/// Set when a signed-in user links the IdP to their account. Holds the
+/// session id and the user it belongs to, because the SAML response
+/// arrives as a cross-site POST, which does not carry the (SameSite=Lax)
+/// session cookie.
+const SSO_LINK_COOKIE: &str = "forage_sso_link";
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/auth/sso", get(sso_login_page).post(sso_login_submit))
+        .route("/auth/sso/{org}", get(sso_start))
+        .route("/auth/sso/{org}/link", get(sso_link_page).post(sso_link_start))
+        .route("/auth/sso/{org}/callback", get(oidc_callback))
+        .route("/auth/sso/{org}/acs", post(saml_acs))
+        .route("/auth/sso/{org}/metadata", get(saml_metadata))
+        .route(
+            "/orgs/{org}/settings/sso",
+            get(sso_settings_page).post(sso_settings_submit),
+        )
+        .route("/orgs/{org}/settings/sso/delete", post(sso_settings_delete))
+}
+
+// ─── Enforcement ────────────────────────────────────────────────────
+
+/// Error to show when the user behind `access_token` belongs to an
+/// organisation that enforces SSO, so password, MFA and magic-link
+/// logins must be refused. Lookup failures fail closed.
+pub(super) async fn sso_enforcement_error(state: &AppState, access_token: &str) -> Option<String> {
+    let store = state.sso_store.as_ref()?;
+
+    let enforced = match store.enforced_organisations().await {
+        Ok(enforced) if enforced.is_empty() => return None,
+        Ok(enforced) => enforced,
+        Err(e) => {
+            tracing::error!(error = %e, "failed to load SSO enforcement");
+            return Some("Could not check your organisation's sign-in policy. Please try again.".into());
+        }
+    };
+
+    match state.platform_client.list_my_organisations(access_token).await {
+        Ok(orgs) => orgs.into_iter().find(|o| enforced.contains(&o.name)).map(|o| {
+            format!(
+                "{} requires single sign-on. Sign in through your identity provider instead.",
+                o.name
+            )
+        }),
+        Err(e) => {
+            tracing::error!(error = %e, "failed to fetch orgs for SSO enforcement");
+            Some("Could not check your organisation's sign-in policy. Please try again.".into())
+        }
+    }
+}
+
+// ─── Helpers ────────────────────────────────────────────────────────
+
+async fn load_config(state: &AppState, org: &str) -> Result<OrgSsoConfig, Response> {
+    let not_configured = || {
+        error_page(
+            state,
+            StatusCode::NOT_FOUND,
+            "Not available",
+            "Single sign-on is not configured for this organisation.",
+        )
+    };
+
+    if !validate_slug(org) {
+        return Err(not_configured());
+    }
+    let store = state.sso_store.as_ref().ok_or_else(not_configured)?;
+    store
+        .get(org)
+        .await
+        .map_err(|e| internal_error(state, "load SSO config", &e))?
+        .ok_or_else(not_configured)
+}
+
+fn sso_url(state: &AppState, org: &str, suffix: &str) -> String {
+    format!("{}/auth/sso/{org}{suffix}", state.forage_host)
+}
+
+fn state_cookie_path(org: &str) -> String {
+    format!("/auth/sso/{org}")
+}
+
+fn clear_state_cookie(jar: CookieJar, org: &str) -> CookieJar {
+    [SSO_STATE_COOKIE, SSO_LINK_COOKIE]
+        .into_iter()
+        .fold(jar, |jar, name| {
+            let mut cookie = axum_extra::extract::cookie::Cookie::from(name);
+            cookie.set_path(state_cookie_path(org));
+            cookie.make_removal();
+            jar.add(cookie)
+        })
+}
+
+/// The session that started a link flow, if this callback belongs to one.
+/// The session must still belong to the user who started the link.
+async fn link_session(state: &AppState, jar: &CookieJar) -> Result<Option<SessionData>, Response> {
+    let Some(cookie) = jar.get(SSO_LINK_COOKIE) else {
+        return Ok(None);
+    };
+    let (session_id, user_id) = cookie.value().split_once('.').unwrap_or((cookie.value(), ""));
+    state
+        .sessions
+        .get(&SessionId::from_raw(session_id.to_string()))
+        .await
+        .ok()
+        .flatten()
+        .filter(|s| {
+            s.user
+                .as_ref()
+                .is_some_and(|u| !user_id.is_empty() && u.user_id == user_id)
+        })
+        .map(Some)
+        .ok_or_else(|| {
+            error_page(
+                state,
+                StatusCode::FORBIDDEN,
+                "Link failed",
+                "Your session ended before the link completed. Sign in and try again.",
+            )
+        })
+}
+
+fn login_failed(state: &AppState, message: &str) -> Response {
+    error_page(state, StatusCode::FORBIDDEN, "Login failed", message)
+}
+
+// ─── SSO entry page ─────────────────────────────────────────────────
+
+async fn sso_login_page(
+    State(state): State<AppState>,
+    maybe: MaybeSession,
+) -> Result<Response, Response> {
+    if maybe.session.is_some() {
+        return Ok(Redirect::to("/dashboard").into_response());
+    }
+    render_sso_login(&state, "", None)
+}
+
+#[derive(Deserialize)]
+struct SsoLoginForm {
+    identifier: String,
+}
+
+/// POST /auth/sso — find the organisation by slug or email domain.
+async fn sso_login_submit(
+    State(state): State<AppState>,
+    Form(form): Form<SsoLoginForm>,
+) -> Result<Response, Response> {
+    let identifier = form.identifier.trim();
+    let not_found = "No single sign-on configuration matches. Check the organisation name or email.";
+
+    let Some(store) = state.sso_store.as_ref() else {
+        return render_sso_login(&state, identifier, Some(not_found));
+    };
+
+    let config = if identifier.contains('@') {
+        match email_domain(identifier) {
+            Some(domain) => store.find_by_email_domain(&domain).await,
+            None => Ok(None),
+        }
+    } else if validate_slug(identifier) {
+        store.get(identifier).await
+    } else {
+        Ok(None)
+    };
+
+    match config.map_err(|e| internal_error(&state, "find SSO config", &e))? {
+        Some(config) => Ok(Redirect::to(&format!("/auth/sso/{}", config.organisation)).into_response()),
+        None => render_sso_login(&state, identifier, Some(not_found)),
+    }
+}
+
+fn render_sso_login(
+    state: &AppState,
+    identifier: &str,
+    error: Option<&str>,
+) -> Result<Response, Response> {
+    let html = state
+        .templates
+        .render(
+            "pages/sso_login.html.jinja",
+            context! {
+                title => "Single Sign-On - Forage",
+                description => "Sign in through your organisation's identity provider",
+                identifier => identifier,
+                error => error,
+            },
+        )
+        .map_err(|e| internal_error(state, "template error", &e))?;
+    Ok(Html(html).into_response())
+}
+
+// ─── Login start ────────────────────────────────────────────────────
+
+/// GET /auth/sso/{org} — redirect to the organisation's identity provider.
+async fn sso_start(
+    State(state): State<AppState>,
+    maybe: MaybeSession,
+    Path(org): Path<String>,
+) -> Result<Response, Response> {
+    if maybe.session.is_some() {
+        return Ok(Redirect::to("/dashboard").into_response());
+    }
+    let config = load_config(&state, &org).await?;
+    redirect_to_idp(&state, &config, None).await
+}
+
+/// GET /auth/sso/{org}/link — ask the signed-in user to confirm linking the
+/// organisation's IdP to their account. SSO logins never take over an
+/// existing account by email, so this is how a user with an account joins
+/// its organisation's SSO.
+async fn sso_link_page(
+    State(state): State<AppState>,
+    session: Session,
+    Path(org): Path<String>,
+) -> Result<Response, Response> {
+    let config = load_config(&state, &org).await?;
+    let html = state
+        .templates
+        .render(
+            "pages/sso_link.html.jinja",
+            context! {
+                title => "Connect Single Sign-On - Forage",
+                description => "Link your organisation's identity provider to your account",
+                organisation => &config.organisation,
+                username => &session.user.username,
+                csrf_token => &session.csrf_token,
+            },
+        )
+        .map_err(|e| internal_error(&state, "template error", &e))?;
+    Ok(Html(html).into_response())
+}
+
+/// POST /auth/sso/{org}/link — start the confirmed link at the IdP.
+async fn sso_link_start(
+    State(state): State<AppState>,
+    session: Session,
+    Path(org): Path<String>,
+    Form(form): Form<CsrfForm>,
+) -> Result<Response, Response> {
+    validate_csrf(&session, &form._csrf)?;
+    let config = load_config(&state, &org).await?;
+    redirect_to_idp(&state, &config, Some((&session.session_id, &session.user.user_id))).await
+}
+
+async fn redirect_to_idp(
+    state: &AppState,
+    config: &OrgSsoConfig,
+    link: Option<(&SessionId, &str)>,
+) -> Result<Response, Response> {
+    let org = &config.organisation;
+    let oauth_state = generate_csrf_token();
+
+    let (location, cookie_attributes, state_value) = match &config.provider {
+        SsoProvider::Oidc(oidc) => {
+            let url = GenericOidcExchange::new(oidc.clone())
+                .authorization_url(&sso_url(state, org, "/callback"), &oauth_state)
+                .await
+                .map_err(|e| {
+                    tracing::error!(error = %e, org = %org, "OIDC discovery failed");
+                    error_page(
+                        state,
+                        StatusCode::BAD_GATEWAY,
+                        "Login failed",
+                        "Could not reach your organisation's identity provider. Please try again.",
+                    )
+                })?;
+            (url, "HttpOnly; SameSite=Lax", oauth_state)
+        }
+        SsoProvider::Saml(idp) => {
+            let request_id = saml::new_request_id();
+            let entity_id = sso_url(state, org, "/metadata");
+            let acs_url = sso_url(state, org, "/acs");
+            let sp = saml::ServiceProvider {
+                entity_id: &entity_id,
+                acs_url: &acs_url,
+            };
+            let url = saml::authn_request_url(idp, &sp, &request_id, &oauth_state, Utc::now())
+                .map_err(|e| internal_error(state, "build SAML request", &e))?;
+            // The IdP answers with a cross-site POST, which only carries
+            // SameSite=None cookies.
+            (
+                url,
+                "HttpOnly; Secure; SameSite=None",
+                format!("{oauth_state}.{request_id}"),
+            )
+        }
+    };
+
+    let path = state_cookie_path(org);
+    let mut cookies = vec![format!(
+        "{SSO_STATE_COOKIE}={state_value}; {cookie_attributes}; Path={path}; Max-Age=600"
+    )];
+    if let Some((session_id, user_id)) = link {
+        cookies.push(format!(
+            "{SSO_LINK_COOKIE}={}.{user_id}; {cookie_attributes}; Path={path}; Max-Age=600",
+            session_id.as_str()
+        ));
+    }
+
+    let mut headers = HeaderMap::new();
+    for cookie in cookies {
+        headers.append(axum::http::header::SET_COOKIE, cookie.parse().unwrap());
+    }
+    headers.insert(
+        axum::http::header::LOCATION,
+        location
+            .parse()
+            .map_err(|e| internal_error(state, "SSO redirect", &e))?,
+    );
+    Ok((StatusCode::FOUND, headers).into_response())
+}
+
+// ─── OIDC callback ──────────────────────────────────────────────────
+
+#[derive(Deserialize)]
+struct OidcCallbackQuery {
+    code: Option<String>,
+    state: Option<String>,
+    error: Option<String>,
+}
+
+/// GET /auth/sso/{org}/callback — authorization-code callback.
+async fn oidc_callback(
+    State(state): State<AppState>,
+    jar: CookieJar,
+    Path(org): Path<String>,
+    Query(query): Query<OidcCallbackQuery>,
+) -> Result<Response, Response> {
+    let expected = jar.get(SSO_STATE_COOKIE).map(|c| c.value().to_string());
+    match expected {
+        Some(ref expected)
+            if !expected.is_empty() && Some(expected.as_str()) == query.state.as_deref() => {}
+        _ => {
+            return Err(error_page(
+                &state,
+                StatusCode::FORBIDDEN,
+                "Invalid request",
+                "SSO state mismatch. Please try again.",
+            ));
+        }
+    }
+
+    if query.error.is_some() {
+        return Ok(Redirect::to("/login").into_response());
+    }
+
+    let config = load_config(&state, &org).await?;
+    let SsoProvider::Oidc(oidc) = &config.provider else {
+        return Err(error_page(
+            &state,
+            StatusCode::BAD_REQUEST,
+            "Invalid request",
+            "This organisation does not use OIDC.",
+        ));
+    };
+
+    let code = query.code.as_deref().unwrap_or("");
+    if code.is_empty() {
+        return Err(error_page(
+            &state,
+            StatusCode::BAD_REQUEST,
+            "Invalid request",
+            "Missing authorization code.",
+        ));
+    }
+
+    let identity = GenericOidcExchange::new(oidc.clone())
+        .exchange_code(code, &sso_url(&state, &org, "/callback"))
+        .await
+        .map_err(|e| {
+            tracing::warn!(error = %e, org = %org, "SSO OIDC exchange failed");
+            error_page(
+                &state,
+                StatusCode::BAD_GATEWAY,
+                "Login failed",
+                "Your identity provider did not confirm the login. Please try again.",
+            )
+        })?;
+
+    let response = match link_session(&state, &jar).await? {
+        Some(session) => complete_sso_link(&state, &config, identity, session).await?,
+        None => complete_sso_login(&state, &config, identity).await?,
+    };
+    Ok((clear_state_cookie(CookieJar::new(), &org), response).into_response())
+}
+
+// ─── SAML ───────────────────────────────────────────────────────────
+
+#[derive(Deserialize)]
+struct SamlAcsForm {
+    #[serde(rename = "SAMLResponse")]
+    saml_response: String,
+    #[serde(rename = "RelayState", default)]
+    relay_state: String,
+}
+
+/// POST /auth/sso/{org}/acs — SAML assertion consumer service.
+async fn saml_acs(
+    State(state): State<AppState>,
+    jar: CookieJar,
+    Path(org): Path<String>,
+    Form(form): Form<SamlAcsForm>,
+) -> Result<Response, Response> {
+    // IdP-initiated logins carry no request to answer and are refused.
+    let request_id = jar
+        .get(SSO_STATE_COOKIE)
+        .and_then(|c| {
+            let (expected, request_id) = c.value().split_once('.')?;
+            (!expected.is_empty() && expected == form.relay_state).then(|| request_id.to_string())
+        })
+        .ok_or_else(|| {
+            error_page(
+                &state,
+                StatusCode::FORBIDDEN,
+                "Invalid request",
+                "SSO state mismatch. Start the login from Forage and try again.",
+            )
+        })?;
+
+    let config = load_config(&state, &org).await?;
+    let SsoProvider::Saml(idp) = &config.provider else {
+        return Err(error_page(
+            &state,
+            StatusCode::BAD_REQUEST,
+            "Invalid request",
+            "This organisation does not use SAML.",
+        ));
+    };
+
+    let entity_id = sso_url(&state, &org, "/metadata");
+    let acs_url = sso_url(&state, &org, "/acs");
+    let sp = saml::ServiceProvider {
+        entity_id: &entity_id,
+        acs_url: &acs_url,
+    };
+    let identity =
+        saml::identity_from_response(idp, &sp, &request_id, &form.saml_response, Utc::now())
+            .map_err(|e| {
+                tracing::warn!(error = %e, org = %org, "rejected SAML response");
+                login_failed(
+                    &state,
+                    "Your identity provider's response could not be verified. Please try again.",
+                )
+            })?;
+
+    let response = match link_session(&state, &jar).await? {
+        Some(session) => complete_sso_link(&state, &config, identity, session).await?,
+        None => complete_sso_login(&state, &config, identity).await?,
+    };
+    Ok((clear_state_cookie(CookieJar::new(), &org), response).into_response())
+}
+
+/// GET /auth/sso/{org}/metadata — SP metadata to load into the IdP.
+async fn saml_metadata(
+    State(state): State<AppState>,
+    Path(org): Path<String>,
+) -> Result<Response, Response> {
+    let config = load_config(&state, &org).await?;
+    if !matches!(config.provider, SsoProvider::Saml(_)) {
+        return Err(error_page(
+            &state,
+            StatusCode::NOT_FOUND,
+            "Not available",
+            "This organisation does not use SAML.",
+        ));
+    }
+    let entity_id = sso_url(&state, &org, "/metadata");
+    let acs_url = sso_url(&state, &org, "/acs");
+    let xml = saml::metadata(&saml::ServiceProvider {
+        entity_id: &entity_id,
+        acs_url: &acs_url,
+    });
+    Ok(([(axum::http::header::CONTENT_TYPE, "application/samlmetadata+xml")], xml).into_response())
+}
+
+// ─── Login completion ───────────────────────────────────────────────
+
+/// Link a verified IdP identity to the account whose session started the
+/// link. Forest only accepts SSO links from forage's service account.
+async fn complete_sso_link(
+    state: &AppState,
+    config: &OrgSsoConfig,
+    identity: OidcIdentity,
+    session: SessionData,
+) -> Result<Response, Response> {
+    if !config.email_allowed(&identity.email) {
+        tracing::warn!(org = %config.organisation, "SSO link with email outside allowed domains");
+        return Err(login_failed(
+            state,
+            "Your email address is not on a domain managed by this organisation's identity provider.",
+        ));
+    }
+    // The IdP must vouch for the account owner, not just for someone on
+    // the organisation's domains.
+    let owns_email = session.user.as_ref().is_some_and(|u| {
+        u.emails
+            .iter()
+            .any(|e| e.verified && e.email.eq_ignore_ascii_case(&identity.email))
+    });
+    if !owns_email {
+        tracing::warn!(org = %config.organisation, "SSO link with email not verified on the account");
+        return Err(login_failed(
+            state,
+            "Your identity provider signed you in with an email address that is not a verified address on this account.",
+        ));
+    }
+    let service_key = state.service_account_key.as_deref().ok_or_else(|| {
+        error_page(
+            state,
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Not available",
+            "Linking single sign-on is not available on this Forage instance.",
+        )
+    })?;
+    let user_id = session.user.map(|u| u.user_id).unwrap_or_default();
+
+    let identity = OidcIdentity {
+        sub: config.provider_user_id(&identity.sub),
+        ..identity
+    };
+    let input = link_input_from_oidc(LinkedProvider::Sso, &identity);
+    let location = match state
+        .forest_client
+        .link_oauth_provider(service_key, &user_id, &input)
+        .await
+    {
+        Ok(()) => {
+            tracing::info!(org = %config.organisation, user_id = %user_id, "linked SSO identity");
+            "/settings/account?flash=linked_sso"
+        }
+        Err(AuthError::AlreadyExists(msg)) if msg.contains("already linked to another user") => {
+            "/settings/account?error=already_linked_other_sso"
+        }
+        Err(AuthError::AlreadyExists(_)) => "/settings/account?error=already_linked_sso",
+        Err(e) => {
+            tracing::error!(error = %e, org = %config.organisation, "SSO link failed");
+            "/settings/account?error=link_failed_sso"
+        }
+    };
+    Ok(Redirect::to(location).into_response())
+}
+
+/// Sign in a verified IdP identity, provisioning the user into the
+/// organisation when just-in-time provisioning is enabled.
+async fn complete_sso_login(
+    state: &AppState,
+    config: &OrgSsoConfig,
+    identity: OidcIdentity,
+) -> Result<Response, Response> {
+    // An IdP may only vouch for addresses on domains the organisation
+    // has proven it owns.
+    if !config.email_allowed(&identity.email) {
+        tracing::warn!(org = %config.organisation, "SSO login with email outside allowed domains");
+        return Err(login_failed(
+            state,
+            "Your email address is not on a domain managed by this organisation's identity provider.",
+        ));
+    }
+
+    let result = state
+        .forest_client
+        .oauth_login(
+            SSO_PROVIDER,
+            &config.provider_user_id(&identity.sub),
+            &identity.email,
+            &identity.name,
+            identity.picture_url.as_deref(),
+        )
+        .await
+        .map_err(|e| match &e {
+            // Forest never links an SSO login to an existing account by
+            // email; the user has to link it from their own session.
+            AuthError::AlreadyExists(_) => login_failed(
+                state,
+                &format!(
+                    "An account with this email address already exists. Sign in to it the way you usually do, then visit /auth/sso/{}/link to connect your identity provider.",
+                    config.organisation
+                ),
+            ),
+            AuthError::Unavailable(_) => error_page(
+                state,
+                StatusCode::SERVICE_UNAVAILABLE,
+                "Temporarily unavailable",
+                "Authentication service is temporarily unavailable. Please try again later.",
+            ),
+            _ => {
+                tracing::error!(error = %e, "SSO login failed");
+                error_page(
+                    state,
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Login failed",
+                    "Single sign-on failed. Please try again.",
+                )
+            }
+        })?;
+
+    let mut orgs: Vec<CachedOrg> = state
+        .platform_client
+        .list_my_organisations(&result.tokens.access_token)
+        .await
+        .map_err(|e| internal_error(state, "list orgs during SSO login", &e))?
+        .into_iter()
+        .map(|o| CachedOrg {
+            organisation_id: o.organisation_id,
+            name: o.name,
+            role: o.role,
+        })
+        .collect();
+
+    if !orgs.iter().any(|o| o.name == config.organisation) {
+        if !config.jit_provisioning {
+            return Err(login_failed(
+                state,
+                "You are not a member of this organisation. Ask an organisation admin to invite you.",
+            ));
+        }
+        let service_key = state.service_account_key.as_deref().ok_or_else(|| {
+            error_page(
+                state,
+                StatusCode::SERVICE_UNAVAILABLE,
+                "Not available",
+                "Automatic provisioning is not available on this Forage instance.",
+            )
+        })?;
+        let member = state
+            .platform_client
+            .add_member(
+                service_key,
+                &config.organisation_id,
+                &result.user.user_id,
+                &config.default_role,
+            )
+            .await
+            .map_err(|e| internal_error(state, "SSO just-in-time provisioning", &e))?;
+        tracing::info!(
+            org = %config.organisation,
+            user_id = %result.user.user_id,
+            role = %member.role,
+            "provisioned SSO user"
+        );
+        orgs.push(CachedOrg {
+            organisation_id: config.organisation_id.clone(),
+            name: config.organisation.clone(),
+            role: member.role,
+        });
+    }
+
+    let now = Utc::now();
+    let session_data = SessionData {
+        access_token: result.tokens.access_token,
+        refresh_token: result.tokens.refresh_token,
+        access_expires_at: now
+            + chrono::Duration::seconds(auth::cap_token_expiry(
+                result.tokens.expires_in_seconds,
+            )),
+        user: Some(CachedUser {
+            user_id: result.user.user_id,
+            username: result.user.username,
+            profile_picture_url: result.user.profile_picture_url,
+            emails: result.user.emails,
+            orgs,
+        }),
+        csrf_token: generate_csrf_token(),
+        created_at: now,
+        last_seen_at: now,
+        needs_username: result.is_new_user,
+    };
+
+    let session_id = state
+        .sessions
+        .create(session_data)
+        .await
+        .map_err(|e| internal_error(state, "create session", &e))?;
+
+    let redirect_to = if result.is_new_user {
+        "/auth/complete-profile".to_string()
+    } else {
+        format!("/orgs/{}", config.organisation)
+    };
+    Ok((auth::session_cookie(&session_id, true), Redirect::to(&redirect_to)).into_response())
+}
+
+// ─── Settings ───────────────────────────────────────────────────────
+
+fn require_admin<'a>(
+    state: &AppState,
+    session: &'a Session,
+    org: &str,
+) -> Result<&'a CachedOrg, Response> {
+    if !validate_slug(org) {
+        return Err(error_page(
+            state,
+            StatusCode::BAD_REQUEST,
+            "Invalid request",
+            "Invalid organisation name.",
+        ));
+    }
+    let cached_org = session
+        .user
+        .orgs
+        .iter()
+        .find(|o| o.name == org)
+        .ok_or_else(|| {
+            error_page(
+                state,
+                StatusCode::FORBIDDEN,
+                "Access denied",
+                "You are not a member of this organisation.",
+            )
+        })?;
+    if cached_org.role != "owner" && cached_org.role != "admin" {
+        return Err(error_page(
+            state,
+            StatusCode::FORBIDDEN,
+            "Access denied",
+            "You must be an admin to manage single sign-on.",
+        ));
+    }
+    if state.sso_store.is_none() {
+        return Err(error_page(
+            state,
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Not available",
+            "Single sign-on requires a database. Set DATABASE_URL to enable.",
+        ));
+    }
+    Ok(cached_org)
+}
+
+fn validate_csrf(session: &Session, form_csrf: &str) -> Result<(), Response> {
+    if session.csrf_token == form_csrf {
+        Ok(())
+    } else {
+        Err((StatusCode::FORBIDDEN, "CSRF token mismatch").into_response())
+    }
+}
+
+async fn sso_settings_page(
+    State(state): State<AppState>,
+    session: Session,
+    Path(org): Path<String>,
+) -> Result<Response, Response> {
+    require_admin(&state, &session, &org)?;
+    let config = state
+        .sso_store
+        .as_ref()
+        .unwrap()
+        .get(&org)
+        .await
+        .map_err(|e| internal_error(&state, "load SSO config", &e))?;
+    render_sso_settings(&state, &session, &org, config.as_ref(), None, None)
+}
+
+#[derive(Deserialize)]
+struct SsoSettingsForm {
+    _csrf: String,
+    protocol: String,
+    #[serde(default)]
+    discovery_url: String,
+    #[serde(default)]
+    client_id: String,
+    #[serde(default)]
+    client_secret: String,
+    #[serde(default)]
+    scopes: String,
+    #[serde(default)]
+    claim_subject: String,
+    #[serde(default)]
+    claim_email: String,
+    #[serde(default)]
+    claim_name: String,
+    #[serde(default)]
+    claim_picture: String,
+    #[serde(default)]
+    idp_entity_id: String,
+    #[serde(default)]
+    idp_sso_url: String,
+    #[serde(default)]
+    idp_certificate: String,
+    #[serde(default)]
+    email_attribute: String,
+    #[serde(default)]
+    name_attribute: String,
+    #[serde(default)]
+    allowed_domains: String,
+    default_role: String,
+    #[serde(default)]
+    jit_provisioning: Option<String>,
+    #[serde(default)]
+    enforce_sso: Option<String>,
+}
+
+async fn sso_settings_submit(
+    State(state): State<AppState>,
+    session: Session,
+    Path(org): Path<String>,
+    Form(form): Form<SsoSettingsForm>,
+) -> Result<Response, Response> {
+    let cached_org = require_admin(&state, &session, &org)?;
+    validate_csrf(&session, &form._csrf)?;
+    let store = state.sso_store.as_ref().unwrap();
+
+    let existing = store
+        .get(&org)
+        .await
+        .map_err(|e| internal_error(&state, "load SSO config", &e))?;
+
+    let provider = match form.protocol.as_str() {
+        "oidc" => {
+            // A blank secret keeps the stored one, so admins can edit
+            // the config without re-entering it.
+            let client_secret = match (&existing, form.client_secret.is_empty()) {
+                (Some(OrgSsoConfig { provider: SsoProvider::Oidc(old), .. }), true) => {
+                    old.client_secret.clone()
+                }
+                _ => form.client_secret.clone(),
+            };
+            let non_empty = |s: &str| Some(s.trim().to_string()).filter(|s| !s.is_empty());
+            let defaults = ClaimMapping::default();
+            SsoProvider::Oidc(OidcProviderConfig {
+                discovery_url: form.discovery_url.trim().to_string(),
+                client_id: form.client_id.trim().to_string(),
+                client_secret,
+                scopes: form.scopes.split_whitespace().map(String::from).collect(),
+                claims: ClaimMapping {
+                    subject: non_empty(&form.claim_subject).unwrap_or(defaults.subject),
+                    email: non_empty(&form.claim_email).unwrap_or(defaults.email),
+                    name: non_empty(&form.claim_name).unwrap_or(defaults.name),
+                    picture: non_empty(&form.claim_picture),
+                },
+            })
+        }
+        "saml" => SsoProvider::Saml(SamlProviderConfig {
+            idp_entity_id: form.idp_entity_id.trim().to_string(),
+            idp_sso_url: form.idp_sso_url.trim().to_string(),
+            idp_certificate: form.idp_certificate.trim().to_string(),
+            email_attribute: form.email_attribute.trim().to_string(),
+            name_attribute: form.name_attribute.trim().to_string(),
+        }),
+        _ => {
+            return Err(error_page(
+                &state,
+                StatusCode::BAD_REQUEST,
+                "Invalid request",
+                "Unknown SSO protocol.",
+            ));
+        }
+    };
+
+    let mut allowed_domains: Vec<String> = form
+        .allowed_domains
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|d| !d.is_empty())
+        .map(|d| d.to_ascii_lowercase())
+        .collect();
+    allowed_domains.sort();
+    allowed_domains.dedup();
+
+    let config = OrgSsoConfig {
+        organisation: org.clone(),
+        organisation_id: cached_org.organisation_id.clone(),
+        provider,
+        enforce_sso: form.enforce_sso.is_some(),
+        jit_provisioning: form.jit_provisioning.is_some(),
+        default_role: form.default_role.clone(),
+        allowed_domains,
+        updated_by: session.user.username.clone(),
+        updated_at: Utc::now().to_rfc3339(),
+    };
+
+    let result = match config.validate() {
+        Ok(()) => check_domain_ownership(&state, &config).await,
+        Err(e) => Err(e),
+    };
+
+    // Forest is what refuses password logins, so it has to agree before
+    // the config says enforcement is on: mirror first, and put forest back
+    // if the config can't be saved.
+    let result = match result {
+        Ok(()) => {
+            if let Err(e) = state
+                .platform_client
+                .set_sso_enforcement(
+                    &session.access_token,
+                    &cached_org.organisation_id,
+                    config.enforce_sso,
+                )
+                .await
+            {
+                tracing::error!(org = %org, "mirror SSO enforcement: {e:#}");
+                return render_sso_settings(
+                    &state,
+                    &session,
+                    &org,
+                    Some(&config),
+                    Some("Could not update single sign-on enforcement. Nothing was saved; please try again."),
+                    Some(StatusCode::BAD_GATEWAY),
+                );
+            }
+
+            let saved = store.upsert(&config).await;
+            let was_enforced = existing.as_ref().is_some_and(|c| c.enforce_sso);
+            if saved.is_err() && was_enforced != config.enforce_sso {
+                let restored = state
+                    .platform_client
+                    .set_sso_enforcement(
+                        &session.access_token,
+                        &cached_org.organisation_id,
+                        was_enforced,
+                    )
+                    .await;
+                if let Err(e) = restored {
+                    tracing::error!(org = %org, "restore SSO enforcement after failed save: {e:#}");
+                }
+            }
+            saved
+        }
+        Err(e) => Err(e),
+    };
+
+    match result {
+        Ok(()) => {
+            tracing::info!(org = %org, protocol = config.provider.protocol(), "saved SSO config");
+            Ok(Redirect::to(&format!("/orgs/{org}/settings/sso")).into_response())
+        }
+        Err(SsoError::InvalidInput(msg)) => render_sso_settings(
+            &state,
+            &session,
+            &org,
+            Some(&config),
+            Some(&msg),
+            Some(StatusCode::BAD_REQUEST),
+        ),
+        Err(e) => Err(internal_error(&state, "save SSO config", &e)),
+    }
+}
+
+/// Every claimed domain must publish the organisation's verification
+/// TXT record.
+async fn check_domain_ownership(state: &AppState, config: &OrgSsoConfig) -> Result<(), SsoError> {
+    let dns = state.domain_txt_lookup.as_ref().ok_or_else(|| {
+        SsoError::InvalidInput("domain verification is not available on this Forage instance".into())
+    })?;
+    match config.unproven_domain(dns.as_ref()).await {
+        Ok(None) => Ok(()),
+        Ok(Some(domain)) => Err(SsoError::InvalidInput(format!(
+            "publish a TXT record at {} with the value {} to prove you own {domain}",
+            domain_verification_name(domain),
+            domain_verification_value(&config.organisation_id, domain),
+        ))),
+        Err(e) => Err(SsoError::InvalidInput(format!(
+            "could not verify domain ownership: {e}"
+        ))),
+    }
+}
+
+#[derive(Deserialize)]
+struct CsrfForm {
+    _csrf: String,
+}
+
+async fn sso_settings_delete(
+    State(state): State<AppState>,
+    session: Session,
+    Path(org): Path<String>,
+    Form(form): Form<CsrfForm>,
+) -> Result<Response, Response> {
+    let cached_org = require_admin(&state, &session, &org)?;
+    validate_csrf(&session, &form._csrf)?;
+
+    // Lift enforcement in forest first, so members are never locked out
+    // of password login with no identity provider left to sign in with.
+    state
+        .platform_client
+        .set_sso_enforcement(&session.access_token, &cached_org.organisation_id, false)
+        .await
+        .map_err(|e| internal_error(&state, "mirror SSO enforcement", &e))?;
+
+    match state.sso_store.as_ref().unwrap().delete(&org).await {
+        Ok(()) | Err(SsoError::NotFound(_)) => {
+            tracing::info!(org = %org, "removed SSO config");
+            Ok(Redirect::to(&format!("/orgs/{org}/settings/sso")).into_response())
+        }
+        Err(e) => Err(internal_error(&state, "delete SSO config", &e)),
+    }
+}
+
+fn render_sso_settings(
+    state: &AppState,
+    session: &Session,
+    org: &str,
+    config: Option<&OrgSsoConfig>,
+    error: Option<&str>,
+    status: Option<StatusCode>,
+) -> Result<Response, Response> {
+    let (oidc, saml) = match config.map(|c| &c.provider) {
+        Some(SsoProvider::Oidc(oidc)) => (Some(oidc), None),
+        Some(SsoProvider::Saml(saml)) => (None, Some(saml)),
+        None => (None, None),
+    };
+    let default_claims = ClaimMapping::default();
+
+    let html = state
+        .templates
+        .render(
+            "pages/sso_settings.html.jinja",
+            context! {
+                title => format!("Single Sign-On - {} - Forage", org),
+                description => "Configure single sign-on for your organisation",
+                user => context! {
+                    username => &session.user.username,
+                    user_id => &session.user.user_id,
+                },
+                current_org => org,
+                orgs => session.user.orgs.iter().map(|o| context! { name => &o.name, role => &o.role }).collect::<Vec<_>>(),
+                csrf_token => &session.csrf_token,
+                active_tab => "settings",
+                error => error,
+                configured => config.is_some(),
+                protocol => config.map(|c| c.provider.protocol()).unwrap_or("oidc"),
+                discovery_url => oidc.map(|o| o.discovery_url.as_str()).unwrap_or(""),
+                client_id => oidc.map(|o| o.client_id.as_str()).unwrap_or(""),
+                has_client_secret => oidc.is_some_and(|o| !o.client_secret.is_empty()),
+                scopes => oidc.map(|o| o.scopes.join(" ")).unwrap_or_else(|| "openid email profile".into()),
+                claim_subject => oidc.map(|o| o.claims.subject.as_str()).unwrap_or(&default_claims.subject),
+                claim_email => oidc.map(|o| o.claims.email.as_str()).unwrap_or(&default_claims.email),
+                claim_name => oidc.map(|o| o.claims.name.as_str()).unwrap_or(&default_claims.name),
+                claim_picture => oidc.and_then(|o| o.claims.picture.as_deref()).unwrap_or(""),
+                idp_entity_id => saml.map(|s| s.idp_entity_id.as_str()).unwrap_or(""),
+                idp_sso_url => saml.map(|s| s.idp_sso_url.as_str()).unwrap_or(""),
+                idp_certificate => saml.map(|s| s.idp_certificate.as_str()).unwrap_or(""),
+                email_attribute => saml.map(|s| s.email_attribute.as_str()).unwrap_or(""),
+                name_attribute => saml.map(|s| s.name_attribute.as_str()).unwrap_or(""),
+                allowed_domains => config.map(|c| c.allowed_domains.join(", ")).unwrap_or_default(),
+                domain_records => config.map(|c| c.allowed_domains.iter().map(|d| context! {
+                    domain => d,
+                    name => domain_verification_name(d),
+                    value => domain_verification_value(&c.organisation_id, d),
+                }).collect::<Vec<_>>()).unwrap_or_default(),
+                default_role => config.map(|c| c.default_role.as_str()).unwrap_or("member"),
+                roles => SSO_ROLES,
+                jit_provisioning => config.is_none_or(|c| c.jit_provisioning),
+                enforce_sso => config.is_some_and(|c| c.enforce_sso),
+                updated_by => config.map(|c| c.updated_by.as_str()),
+                login_url => sso_url(state, org, ""),
+                callback_url => sso_url(state, org, "/callback"),
+                metadata_url => sso_url(state, org, "/metadata"),
+                acs_url => sso_url(state, org, "/acs"),
+            },
+        )
+        .map_err(|e| internal_error(state, "template error", &e))?;
+    Ok((status.unwrap_or(StatusCode::OK), Html(html)).into_response())
+}
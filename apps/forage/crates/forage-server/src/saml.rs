@@ -0,0 +1,374 @@
+//! SAML 2.0 service-provider side of organisation SSO.
+//!
+//! Forage sends unsigned `AuthnRequest`s over the HTTP-Redirect binding
+//! and accepts `Response`s over HTTP-POST. A response is only trusted if
+//! the `Response` or its single `Assertion` is covered by an XML
+//! signature made with the IdP certificate the org admin configured;
+//! signature verification is done by bergshamra. Encrypted assertions and
+//! IdP-initiated logins are not supported.
+
+use std::collections::HashMap;
+use std::io::Write;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use chrono::{DateTime, Utc};
+use forage_core::auth::sso::SamlProviderConfig;
+use forage_core::auth::{AuthError, OidcIdentity};
+use roxmltree::{Document, Node};
+
+const NS_PROTOCOL: &str = "urn:oasis:names:tc:SAML:2.0:protocol";
+const NS_ASSERTION: &str = "urn:oasis:names:tc:SAML:2.0:assertion";
+const BINDING_POST: &str = "urn:oasis:names:tc:SAML:2.0:bindings:HTTP-POST";
+const STATUS_SUCCESS: &str = "urn:oasis:names:tc:SAML:2.0:status:Success";
+const CONFIRMATION_BEARER: &str = "urn:oasis:names:tc:SAML:2.0:cm:bearer";
+
+/// Tolerated clock difference between forage and the IdP.
+const CLOCK_SKEW_SECONDS: i64 = 120;
+
+/// Forage's identity towards an organisation's IdP.
+pub struct ServiceProvider<'a> {
+    pub entity_id: &'a str,
+    pub acs_url: &'a str,
+}
+
+/// A fresh `AuthnRequest` ID. XML IDs may not start with a digit.
+pub fn new_request_id() -> String {
+    format!("_{}", uuid::Uuid::new_v4().simple())
+}
+
+/// Build the IdP redirect URL carrying a deflated `AuthnRequest`.
+pub fn authn_request_url(
+    idp: &SamlProviderConfig,
+    sp: &ServiceProvider<'_>,
+    request_id: &str,
+    relay_state: &str,
+    now: DateTime<Utc>,
+) -> Result<String, AuthError> {
+    let xml = format!(
+        r#"<samlp:AuthnRequest xmlns:samlp="{NS_PROTOCOL}" xmlns:saml="{NS_ASSERTION}" ID="{id}" Version="2.0" IssueInstant="{instant}" Destination="{destination}" ProtocolBinding="{BINDING_POST}" AssertionConsumerServiceURL="{acs}"><saml:Issuer>{issuer}</saml:Issuer><samlp:NameIDPolicy AllowCreate="true"/></samlp:AuthnRequest>"#,
+        id = escape_attr(request_id),
+        instant = now.format("%Y-%m-%dT%H:%M:%SZ"),
+        destination = escape_attr(&idp.idp_sso_url),
+        acs = escape_attr(sp.acs_url),
+        issuer = escape_text(sp.entity_id),
+    );
+
+    let mut encoder =
+        flate2::write::DeflateEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder
+        .write_all(xml.as_bytes())
+        .and_then(|_| encoder.finish())
+        .map(|deflated| {
+            let separator = if idp.idp_sso_url.contains('?') { '&' } else { '?' };
+            format!(
+                "{}{separator}SAMLRequest={}&RelayState={}",
+                idp.idp_sso_url,
+                urlencoding::encode(&STANDARD.encode(deflated)),
+                urlencoding::encode(relay_state),
+            )
+        })
+        .map_err(|e| AuthError::Other(format!("failed to encode SAML request: {e}")))
+}
+
+/// Service-provider metadata for the org admin to load into their IdP.
+pub fn metadata(sp: &ServiceProvider<'_>) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<md:EntityDescriptor xmlns:md="urn:oasis:names:tc:SAML:2.0:metadata" entityID="{entity_id}">
+  <md:SPSSODescriptor AuthnRequestsSigned="false" WantAssertionsSigned="true" protocolSupportEnumeration="{NS_PROTOCOL}">
+    <md:NameIDFormat>urn:oasis:names:tc:SAML:1.1:nameid-format:emailAddress</md:NameIDFormat>
+    <md:AssertionConsumerService Binding="{BINDING_POST}" Location="{acs}" index="0" isDefault="true"/>
+  </md:SPSSODescriptor>
+</md:EntityDescriptor>
+"#,
+        entity_id = escape_attr(sp.entity_id),
+        acs = escape_attr(sp.acs_url),
+    )
+}
+
+/// Verify a base64 `SAMLResponse` answering `request_id` and map its
+/// assertion to an identity.
+pub fn identity_from_response(
+    idp: &SamlProviderConfig,
+    sp: &ServiceProvider<'_>,
+    request_id: &str,
+    saml_response: &str,
+    now: DateTime<Utc>,
+) -> Result<OidcIdentity, AuthError> {
+    verify_response(idp, sp, request_id, saml_response, now)
+        .map_err(|e| AuthError::Other(format!("invalid SAML response: {e}")))
+}
+
+fn verify_response(
+    idp: &SamlProviderConfig,
+    sp: &ServiceProvider<'_>,
+    request_id: &str,
+    saml_response: &str,
+    now: DateTime<Utc>,
+) -> Result<OidcIdentity, String> {
+    let compact: String = saml_response.split_whitespace().collect();
+    let bytes = STANDARD
+        .decode(compact)
+        .map_err(|e| format!("not base64: {e}"))?;
+    let xml = String::from_utf8(bytes).map_err(|_| "not UTF-8".to_string())?;
+    // roxmltree rejects DTDs by default, which rules out entity expansion
+    // attacks.
+    let doc = Document::parse(&xml).map_err(|e| format!("malformed XML: {e}"))?;
+
+    let response = doc.root_element();
+    if !response.has_tag_name((NS_PROTOCOL, "Response")) {
+        return Err("root element is not a samlp:Response".into());
+    }
+    if response.attribute("InResponseTo") != Some(request_id) {
+        return Err("InResponseTo does not match the login request".into());
+    }
+    if let Some(destination) = response.attribute("Destination")
+        && destination != sp.acs_url
+    {
+        return Err(format!("unexpected Destination {destination}"));
+    }
+    if let Some(issuer) = child(response, NS_ASSERTION, "Issuer")
+        && text(issuer) != idp.idp_entity_id
+    {
+        return Err("response Issuer does not match the IdP".into());
+    }
+    let status = child(response, NS_PROTOCOL, "Status")
+        .and_then(|s| child(s, NS_PROTOCOL, "StatusCode"))
+        .and_then(|c| c.attribute("Value"));
+    if status != Some(STATUS_SUCCESS) {
+        return Err(format!(
+            "IdP returned status {}",
+            status.unwrap_or("(missing)")
+        ));
+    }
+
+    if child(response, NS_ASSERTION, "EncryptedAssertion").is_some() {
+        return Err("encrypted assertions are not supported".into());
+    }
+    let assertions: Vec<Node> = children(response, NS_ASSERTION, "Assertion").collect();
+    let [assertion] = assertions[..] else {
+        return Err(format!(
+            "expected exactly one assertion, found {}",
+            assertions.len()
+        ));
+    };
+
+    let signed = signed_ids(&xml, &idp.idp_certificate)?;
+    let covers = |element: Node| {
+        element
+            .attribute("ID")
+            .is_some_and(|id| signed.iter().any(|s| s == id))
+    };
+    if !covers(response) && !covers(assertion) {
+        return Err("neither the response nor the assertion is signed".into());
+    }
+
+    check_assertion(idp, sp, request_id, assertion, now)
+}
+
+fn check_assertion(
+    idp: &SamlProviderConfig,
+    sp: &ServiceProvider<'_>,
+    request_id: &str,
+    assertion: Node,
+    now: DateTime<Utc>,
+) -> Result<OidcIdentity, String> {
+    let skew = chrono::Duration::seconds(CLOCK_SKEW_SECONDS);
+
+    let issuer = child(assertion, NS_ASSERTION, "Issuer").map(text);
+    if issuer.as_deref() != Some(idp.idp_entity_id.as_str()) {
+        return Err("assertion Issuer does not match the IdP".into());
+    }
+
+    if let Some(conditions) = child(assertion, NS_ASSERTION, "Conditions") {
+        if let Some(not_before) = conditions.attribute("NotBefore")
+            && now + skew < parse_instant(not_before)?
+        {
+            return Err("assertion is not yet valid".into());
+        }
+        if let Some(not_on_or_after) = conditions.attribute("NotOnOrAfter")
+            && now - skew >= parse_instant(not_on_or_after)?
+        {
+            return Err("assertion has expired".into());
+        }
+        for restriction in children(conditions, NS_ASSERTION, "AudienceRestriction") {
+            if !children(restriction, NS_ASSERTION, "Audience").any(|a| text(a) == sp.entity_id)
+            {
+                return Err("assertion is not addressed to this service provider".into());
+            }
+        }
+    }
+
+    let subject =
+        child(assertion, NS_ASSERTION, "Subject").ok_or("assertion has no Subject")?;
+    let name_id = child(subject, NS_ASSERTION, "NameID")
+        .map(text)
+        .filter(|n| !n.is_empty())
+        .ok_or("assertion has no NameID")?;
+
+    let mut confirmed = false;
+    for confirmation in children(subject, NS_ASSERTION, "SubjectConfirmation") {
+        if confirmation.attribute("Method") != Some(CONFIRMATION_BEARER) {
+            continue;
+        }
+        let Some(data) = child(confirmation, NS_ASSERTION, "SubjectConfirmationData") else {
+            continue;
+        };
+        let recipient_ok = data.attribute("Recipient").is_none_or(|r| r == sp.acs_url);
+        let in_response_ok = data
+            .attribute("InResponseTo")
+            .is_none_or(|r| r == request_id);
+        let fresh = match data.attribute("NotOnOrAfter") {
+            Some(t) => now - skew < parse_instant(t)?,
+            None => false,
+        };
+        if recipient_ok && in_response_ok && fresh {
+            confirmed = true;
+            break;
+        }
+    }
+    if !confirmed {
+        return Err("no valid bearer subject confirmation".into());
+    }
+
+    let mut attributes: HashMap<&str, String> = HashMap::new();
+    for statement in children(assertion, NS_ASSERTION, "AttributeStatement") {
+        for attribute in children(statement, NS_ASSERTION, "Attribute") {
+            let (Some(name), Some(value)) = (
+                attribute.attribute("Name"),
+                child(attribute, NS_ASSERTION, "AttributeValue").map(text),
+            ) else {
+                continue;
+            };
+            if !value.is_empty() {
+                attributes.entry(name).or_insert(value);
+            }
+        }
+    }
+    let attribute = |name: &str| {
+        (!name.is_empty())
+            .then(|| attributes.get(name).cloned())
+            .flatten()
+    };
+
+    let email = attribute(&idp.email_attribute).unwrap_or_else(|| name_id.clone());
+    if !email.contains('@') {
+        return Err("assertion carries no email address".into());
+    }
+    let name = attribute(&idp.name_attribute).unwrap_or_else(|| email.clone());
+
+    Ok(OidcIdentity {
+        sub: name_id,
+        email,
+        name,
+        picture_url: None,
+        login: None,
+    })
+}
+
+// ─── XML signature ──────────────────────────────────────────────────
+
+/// Verify every XML signature in the message against the IdP certificate
+/// and return the IDs of the elements they cover.
+///
+/// Canonicalization, digests and signature checks are left to bergshamra,
+/// which also rejects duplicate IDs and references that do not point at
+/// an ancestor of their signature (signature wrapping).
+fn signed_ids(xml: &str, certificate: &str) -> Result<Vec<String>, String> {
+    let key = bergshamra::keys::loader::load_x509_cert_pem(certificate.as_bytes())
+        .map_err(|e| format!("invalid IdP certificate: {e}"))?;
+    let mut keys = bergshamra::KeysManager::new();
+    keys.add_key(key);
+    let ctx = bergshamra::DsigContext::new(keys);
+
+    let results = bergshamra::verify_all(&ctx, xml)
+        .map_err(|e| format!("signature verification failed: {e}"))?;
+    if results.is_empty() {
+        return Err("message is not signed".into());
+    }
+
+    let mut ids = Vec::new();
+    for result in results {
+        if !result.all_reference_digests_verified() {
+            return Err(match result {
+                bergshamra::VerifyResult::Invalid { reason } => {
+                    format!("signature verification failed: {reason}")
+                }
+                bergshamra::VerifyResult::Valid { .. } => {
+                    "signature does not cover the message".into()
+                }
+            });
+        }
+        if let bergshamra::VerifyResult::Valid { references, .. } = result {
+            for reference in references {
+                let id = reference
+                    .uri
+                    .strip_prefix('#')
+                    .ok_or("signature must reference an element by ID")?;
+                ids.push(id.to_string());
+            }
+        }
+    }
+    Ok(ids)
+}
+
+// ─── helpers ────────────────────────────────────────────────────────
+
+fn child<'a, 'input>(node: Node<'a, 'input>, ns: &str, name: &str) -> Option<Node<'a, 'input>> {
+    children(node, ns, name).next()
+}
+
+fn children<'a, 'input>(
+    node: Node<'a, 'input>,
+    ns: &str,
+    name: &str,
+) -> impl Iterator<Item = Node<'a, 'input>> {
+    node.children()
+        .filter(move |n| n.is_element() && n.has_tag_name((ns, name)))
+}
+
+fn text(node: Node) -> String {
+    node.descendants()
+        .filter(|n| n.is_text())
+        .filter_map(|n| n.text())
+        .collect::<String>()
+        .trim()
+        .to_string()
+}
+
+fn parse_instant(value: &str) -> Result<DateTime<Utc>, String> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|t| t.with_timezone(&Utc))
+        .map_err(|_| format!("invalid timestamp {value}"))
+}
+
+fn escape_text(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '\r' => out.push_str("&#xD;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn escape_attr(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '"' => out.push_str("&quot;"),
+            '\t' => out.push_str("&#x9;"),
+            '\n' => out.push_str("&#xA;"),
+            '\r' => out.push_str("&#xD;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
@@ -3,6 +3,7 @@ use std::sync::Arc;
 use crate::forest_client::GrpcForestClient;
 use crate::templates::TemplateEngine;
 use forage_core::auth::magic_link::MagicLinkStore;
+use forage_core::auth::sso::{DomainTxtLookup, SsoConfigStore};
 use forage_core::auth::{ForestAuth, OidcExchange};
 use forage_core::compute::ComputeScheduler;
 use forage_core::integrations::IntegrationStore;
@@ -17,6 +18,9 @@ pub struct SlackConfig {
     pub client_id: String,
     pub client_secret: String,
     pub redirect_host: String,
+    /// Verifies interactivity requests (approval buttons). Buttons are only
+    /// posted when this is set.
+    pub signing_secret: Option<String>,
 }
 
 /// Google OAuth configuration.
@@ -55,6 +59,8 @@ pub struct AppState {
     pub profile_picture_store: Option<Arc<PgProfilePictureStore>>,
     pub registry_client: Option<Arc<dyn ForestRegistry>>,
     pub service_account_key: Option<String>,
+    pub sso_store: Option<Arc<dyn SsoConfigStore>>,
+    pub domain_txt_lookup: Option<Arc<dyn DomainTxtLookup>>,
 }
 
 impl AppState {
@@ -83,6 +89,8 @@ impl AppState {
             profile_picture_store: None,
             registry_client: None,
             service_account_key: None,
+            sso_store: None,
+            domain_txt_lookup: None,
         }
     }
 
@@ -155,4 +163,14 @@ impl AppState {
         self.service_account_key = Some(key);
         self
     }
+
+    pub fn with_sso_store(mut self, store: Arc<dyn SsoConfigStore>) -> Self {
+        self.sso_store = Some(store);
+        self
+    }
+
+    pub fn with_domain_txt_lookup(mut self, dns: Arc<dyn DomainTxtLookup>) -> Self {
+        self.domain_txt_lookup = Some(dns);
+        self
+    }
 }
@@ -5,9 +5,9 @@ use chrono::Utc;
 use forage_core::auth::{self, LoginResult, MfaSetup, *};
 use forage_core::platform::{
     Artifact, ArtifactContext, CreatePolicyInput, CreateReleasePipelineInput, CreateTriggerInput,
-    Destination, DestinationTypeInfo, Environment, ForestPlatform, NotificationPreference,
-    Organisation, OrgMember, PlatformError, Policy, ReleasePipeline, Trigger, UpdatePolicyInput,
-    UpdateReleasePipelineInput, UpdateTriggerInput,
+    Destination, DestinationTypeInfo, Environment, EnvironmentProtection, ForestPlatform,
+    NotificationPreference, Organisation, OrgMember, PlatformError, Policy, ReleasePipeline,
+    ReleaseSchedule, Trigger, UpdatePolicyInput, UpdateReleasePipelineInput, UpdateTriggerInput,
 };
 use forage_core::registry::{
     ComponentDetail, ComponentSearchResult, ComponentVersionInfo, ForestRegistry, ToolSummary,
@@ -40,6 +40,14 @@ pub(crate) struct MockBehavior {
     pub setup_mfa_result: Option<Result<MfaSetup, AuthError>>,
     pub verify_mfa_setup_result: Option<Result<(), AuthError>>,
     pub disable_mfa_result: Option<Result<(), AuthError>>,
+    pub begin_passkey_registration_result: Option<Result<PasskeyCeremony, AuthError>>,
+    pub finish_passkey_registration_result: Option<Result<Passkey, AuthError>>,
+    pub list_passkeys_result: Option<Result<Vec<Passkey>, AuthError>>,
+    pub rename_passkey_result: Option<Result<(), AuthError>>,
+    pub delete_passkey_result: Option<Result<(), AuthError>>,
+    pub begin_passkey_login_result: Option<Result<PasskeyCeremony, AuthError>>,
+    pub finish_passkey_login_result: Option<Result<AuthTokens, AuthError>>,
+    pub begin_passkey_verification_result: Option<Result<PasskeyCeremony, AuthError>>,
     pub list_linked_identities_result:
         Option<Result<Vec<forage_core::auth::LinkedIdentity>, AuthError>>,
     pub link_oauth_provider_result: Option<Result<(), AuthError>>,
@@ -60,6 +68,7 @@ pub(crate) struct MockPlatformBehavior {
     pub add_member_result: Option<Result<OrgMember, PlatformError>>,
     pub remove_member_result: Option<Result<(), PlatformError>>,
     pub update_member_role_result: Option<Result<OrgMember, PlatformError>>,
+    pub set_sso_enforcement_result: Option<Result<(), PlatformError>>,
     pub get_artifact_by_slug_result: Option<Result<Artifact, PlatformError>>,
     pub list_environments_result: Option<Result<Vec<Environment>, PlatformError>>,
     pub update_environment_result: Option<Result<Environment, PlatformError>>,
@@ -68,6 +77,7 @@ pub(crate) struct MockPlatformBehavior {
     pub create_trigger_result: Option<Result<Trigger, PlatformError>>,
     pub update_trigger_result: Option<Result<Trigger, PlatformError>>,
     pub delete_trigger_result: Option<Result<(), PlatformError>>,
+    pub list_release_schedules_result: Option<Result<Vec<ReleaseSchedule>, PlatformError>>,
     pub list_release_pipelines_result: Option<Result<Vec<ReleasePipeline>, PlatformError>>,
     pub create_release_pipeline_result: Option<Result<ReleasePipeline, PlatformError>>,
     pub update_release_pipeline_result: Option<Result<ReleasePipeline, PlatformError>>,
@@ -76,6 +86,8 @@ pub(crate) struct MockPlatformBehavior {
     pub get_notification_preferences_result: Option<Result<Vec<NotificationPreference>, PlatformError>>,
     pub set_notification_preference_result: Option<Result<(), PlatformError>>,
     pub list_destination_types_result: Option<Result<Vec<DestinationTypeInfo>, PlatformError>>,
+    pub approve_release_result: Option<Result<forage_core::platform::ApprovalState, PlatformError>>,
+    pub approve_plan_stage_result: Option<Result<(), PlatformError>>,
 }
 
 pub(crate) fn ok_tokens() -> AuthTokens {
@@ -86,6 +98,13 @@ pub(crate) fn ok_tokens() -> AuthTokens {
     }
 }
 
+pub(crate) fn ok_passkey_ceremony() -> PasskeyCeremony {
+    PasskeyCeremony {
+        ceremony_id: "passkey-ceremony-1".into(),
+        options_json: r#"{"challenge":"Y2hhbGxlbmdl","rpId":"localhost"}"#.into(),
+    }
+}
+
 pub(crate) fn ok_user() -> User {
     User {
         user_id: "user-123".into(),
@@ -374,6 +393,83 @@ impl ForestAuth for MockForestClient {
         b.list_linked_identities_result.clone().unwrap_or(Ok(vec![]))
     }
 
+    async fn begin_passkey_registration(
+        &self,
+        _access_token: &str,
+    ) -> Result<PasskeyCeremony, AuthError> {
+        let b = self.behavior.lock().unwrap();
+        b.begin_passkey_registration_result
+            .clone()
+            .unwrap_or(Ok(ok_passkey_ceremony()))
+    }
+
+    async fn finish_passkey_registration(
+        &self,
+        _access_token: &str,
+        name: &str,
+        _response: &PasskeyResponse,
+    ) -> Result<Passkey, AuthError> {
+        let b = self.behavior.lock().unwrap();
+        b.finish_passkey_registration_result.clone().unwrap_or(Ok(Passkey {
+            passkey_id: "pk-1".into(),
+            name: name.into(),
+            created_at: None,
+            last_used_at: None,
+        }))
+    }
+
+    async fn list_passkeys(&self, _access_token: &str) -> Result<Vec<Passkey>, AuthError> {
+        let b = self.behavior.lock().unwrap();
+        b.list_passkeys_result.clone().unwrap_or(Ok(vec![]))
+    }
+
+    async fn rename_passkey(
+        &self,
+        _access_token: &str,
+        _passkey_id: &str,
+        _name: &str,
+    ) -> Result<(), AuthError> {
+        let b = self.behavior.lock().unwrap();
+        b.rename_passkey_result.clone().unwrap_or(Ok(()))
+    }
+
+    async fn delete_passkey(
+        &self,
+        _access_token: &str,
+        _passkey_id: &str,
+    ) -> Result<(), AuthError> {
+        let b = self.behavior.lock().unwrap();
+        b.delete_passkey_result.clone().unwrap_or(Ok(()))
+    }
+
+    async fn begin_passkey_login(
+        &self,
+        _mfa_session_token: Option<&str>,
+    ) -> Result<PasskeyCeremony, AuthError> {
+        let b = self.behavior.lock().unwrap();
+        b.begin_passkey_login_result
+            .clone()
+            .unwrap_or(Ok(ok_passkey_ceremony()))
+    }
+
+    async fn finish_passkey_login(
+        &self,
+        _response: &PasskeyResponse,
+    ) -> Result<AuthTokens, AuthError> {
+        let b = self.behavior.lock().unwrap();
+        b.finish_passkey_login_result.clone().unwrap_or(Ok(ok_tokens()))
+    }
+
+    async fn begin_passkey_verification(
+        &self,
+        _user_id: &str,
+    ) -> Result<PasskeyCeremony, AuthError> {
+        let b = self.behavior.lock().unwrap();
+        b.begin_passkey_verification_result
+            .clone()
+            .unwrap_or(Ok(ok_passkey_ceremony()))
+    }
+
     async fn link_oauth_provider(
         &self,
         _access_token: &str,
@@ -400,6 +496,7 @@ impl ForestAuth for MockForestClient {
         _user_id: &str,
         _approving_ip: &str,
         _approving_user_agent: &str,
+        _passkey: Option<&PasskeyResponse>,
     ) -> Result<(), AuthError> {
         let b = self.behavior.lock().unwrap();
         b.approve_device_login_result.clone().unwrap_or(Ok(()))
@@ -568,6 +665,16 @@ impl ForestPlatform for MockPlatformClient {
         }))
     }
 
+    async fn set_sso_enforcement(
+        &self,
+        _access_token: &str,
+        _organisation_id: &str,
+        _enforced: bool,
+    ) -> Result<(), PlatformError> {
+        let b = self.behavior.lock().unwrap();
+        b.set_sso_enforcement_result.clone().unwrap_or(Ok(()))
+    }
+
     async fn get_artifact_by_slug(
         &self,
         _access_token: &str,
@@ -625,6 +732,7 @@ impl ForestPlatform for MockPlatformClient {
             description: description.map(|s| s.to_string()),
             sort_order,
             created_at: "2026-03-08T00:00:00Z".into(),
+            protection: EnvironmentProtection::default(),
         })
     }
 
@@ -634,6 +742,7 @@ impl ForestPlatform for MockPlatformClient {
         id: &str,
         description: Option<&str>,
         sort_order: Option<i32>,
+        protection: Option<&EnvironmentProtection>,
     ) -> Result<Environment, PlatformError> {
         let b = self.behavior.lock().unwrap();
         if let Some(result) = b.update_environment_result.clone() {
@@ -646,6 +755,7 @@ impl ForestPlatform for MockPlatformClient {
             description: description.map(|s| s.to_string()),
             sort_order: sort_order.unwrap_or(0),
             created_at: "2026-03-08T00:00:00Z".into(),
+            protection: protection.cloned().unwrap_or_default(),
         })
     }
 
@@ -785,6 +895,16 @@ impl ForestPlatform for MockPlatformClient {
         b.delete_trigger_result.clone().unwrap_or(Ok(()))
     }
 
+    async fn list_release_schedules(
+        &self,
+        _access_token: &str,
+        _organisation: &str,
+        _project: &str,
+    ) -> Result<Vec<ReleaseSchedule>, PlatformError> {
+        let b = self.behavior.lock().unwrap();
+        b.list_release_schedules_result.clone().unwrap_or(Ok(vec![]))
+    }
+
     async fn list_policies(
         &self,
         _access_token: &str,
@@ -943,12 +1063,14 @@ impl ForestPlatform for MockPlatformClient {
         _target_environment: &str,
         _comment: Option<&str>,
         _force_bypass: bool,
+        _on_behalf_of_user_id: Option<&str>,
     ) -> Result<forage_core::platform::ApprovalState, PlatformError> {
-        Ok(forage_core::platform::ApprovalState {
+        let b = self.behavior.lock().unwrap();
+        b.approve_release_result.clone().unwrap_or(Ok(forage_core::platform::ApprovalState {
             required_approvals: 1,
             current_approvals: 1,
             decisions: vec![],
-        })
+        }))
     }
 
     async fn reject_release(
@@ -959,6 +1081,7 @@ impl ForestPlatform for MockPlatformClient {
         _release_intent_id: &str,
         _target_environment: &str,
         _comment: Option<&str>,
+        _on_behalf_of_user_id: Option<&str>,
     ) -> Result<forage_core::platform::ApprovalState, PlatformError> {
         Ok(forage_core::platform::ApprovalState {
             required_approvals: 1,
@@ -987,8 +1110,10 @@ impl ForestPlatform for MockPlatformClient {
         _access_token: &str,
         _release_intent_id: &str,
         _stage_id: &str,
+        _on_behalf_of_user_id: Option<&str>,
     ) -> Result<(), PlatformError> {
-        Ok(())
+        let b = self.behavior.lock().unwrap();
+        b.approve_plan_stage_result.clone().unwrap_or(Ok(()))
     }
 
     async fn reject_plan_stage(
@@ -997,6 +1122,16 @@ impl ForestPlatform for MockPlatformClient {
         _release_intent_id: &str,
         _stage_id: &str,
         _reason: Option<&str>,
+        _on_behalf_of_user_id: Option<&str>,
+    ) -> Result<(), PlatformError> {
+        Ok(())
+    }
+
+    async fn cancel_release(
+        &self,
+        _access_token: &str,
+        _release_id: &str,
+        _reason: Option<&str>,
     ) -> Result<(), PlatformError> {
         Ok(())
     }
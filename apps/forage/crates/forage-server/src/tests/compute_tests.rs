@@ -0,0 +1,287 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use forage_core::compute::spec::{EnvSource, ProbeHandler, Protocol, VolumeSource};
+use forage_core::compute::{
+    ComputeScheduler, InMemoryComputeScheduler, ResourceKind, ResourceSpec,
+};
+use forage_grpc::forage_service_server::ForageService;
+use forage_grpc::{
+    ApplyResourcesRequest, GetAppliedResourcesRequest, AutoscalingPolicy, Container, ContainerPort, ContainerServiceSpec,
+    CronJobSpec, EnvVar, ForageResource, HttpGetProbe, Probe, ResourceList, ResourceRequirements,
+    ScalingPolicy, SecretKeyRef, Volume, VolumeMount, env_var, forage_resource, probe, volume,
+};
+use tower::ServiceExt;
+
+use crate::build_router;
+use crate::compute_grpc::ForageServiceImpl;
+use crate::test_support::*;
+
+fn api_resource() -> ForageResource {
+    ForageResource {
+        name: "my-api".into(),
+        spec: Some(forage_resource::Spec::ContainerService(
+            ContainerServiceSpec {
+                scaling: Some(ScalingPolicy {
+                    replicas: 2,
+                    autoscaling: Some(AutoscalingPolicy {
+                        min_replicas: 2,
+                        max_replicas: 6,
+                        target_cpu_utilization_percent: 75,
+                        ..Default::default()
+                    }),
+                }),
+                container: Some(Container {
+                    name: "my-api".into(),
+                    image: "registry.forage.sh/testorg/my-api:v2".into(),
+                    env: vec![
+                        EnvVar {
+                            name: "LOG_LEVEL".into(),
+                            value_source: Some(env_var::ValueSource::Value("debug".into())),
+                        },
+                        EnvVar {
+                            name: "DATABASE_URL".into(),
+                            value_source: Some(env_var::ValueSource::SecretRef(SecretKeyRef {
+                                secret_name: "db".into(),
+                                key: "url".into(),
+                            })),
+                        },
+                    ],
+                    ports: vec![ContainerPort {
+                        name: "http".into(),
+                        container_port: 8080,
+                        protocol: String::new(),
+                    }],
+                    resources: Some(ResourceRequirements {
+                        requests: Some(ResourceList {
+                            cpu: "500m".into(),
+                            memory: "512Mi".into(),
+                            ..Default::default()
+                        }),
+                        limits: Some(ResourceList {
+                            cpu: "1".into(),
+                            memory: "1Gi".into(),
+                            ..Default::default()
+                        }),
+                    }),
+                    volume_mounts: vec![VolumeMount {
+                        name: "cache".into(),
+                        mount_path: "/var/cache/app".into(),
+                        ..Default::default()
+                    }],
+                    readiness_probe: Some(Probe {
+                        handler: Some(probe::Handler::HttpGet(HttpGetProbe {
+                            path: "/healthz".into(),
+                            port: 8080,
+                            ..Default::default()
+                        })),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }),
+                volumes: vec![Volume {
+                    name: "cache".into(),
+                    source: Some(volume::Source::EmptyDir(Default::default())),
+                }],
+                ..Default::default()
+            },
+        )),
+    }
+}
+
+fn apply_request(resources: Vec<ForageResource>) -> ApplyResourcesRequest {
+    ApplyResourcesRequest {
+        apply_id: "release-1".into(),
+        namespace: "testorg".into(),
+        resources,
+        labels: HashMap::from([("project".to_string(), "my-project".to_string())]),
+    }
+}
+
+#[tokio::test]
+async fn apply_resources_keeps_full_spec() {
+    let scheduler = Arc::new(InMemoryComputeScheduler::new());
+    let svc = ForageServiceImpl {
+        scheduler: scheduler.clone(),
+    };
+
+    let cron = ForageResource {
+        name: "nightly-report".into(),
+        spec: Some(forage_resource::Spec::CronJob(CronJobSpec {
+            schedule: "0 3 * * *".into(),
+            timezone: "Europe/Copenhagen".into(),
+            container: Some(Container {
+                image: "registry.forage.sh/testorg/report:v1".into(),
+                ..Default::default()
+            }),
+            concurrency_policy: "Forbid".into(),
+            ..Default::default()
+        })),
+    };
+
+    svc.apply_resources(tonic::Request::new(apply_request(vec![
+        api_resource(),
+        cron,
+    ])))
+    .await
+    .unwrap();
+
+    let instances = scheduler.list_instances("testorg").await.unwrap();
+    assert_eq!(instances.len(), 2);
+
+    let api = instances
+        .iter()
+        .find(|i| i.resource_name == "my-api")
+        .unwrap();
+    assert_eq!(api.cpu, "500m");
+    assert_eq!(api.memory, "512Mi");
+    let ResourceSpec::ContainerService(cs) = &api.spec.spec else {
+        panic!("expected container service, got {:?}", api.spec.spec);
+    };
+    assert_eq!(cs.scaling.autoscaling.as_ref().unwrap().max_replicas, 6);
+    assert_eq!(cs.container.resources.limits.memory.as_deref(), Some("1Gi"));
+    assert_eq!(cs.container.ports[0].protocol, Protocol::Tcp);
+    assert!(matches!(
+        &cs.container.env[1].source,
+        EnvSource::Secret { secret_name, key } if secret_name == "db" && key == "url"
+    ));
+    assert!(matches!(
+        cs.container.readiness_probe.as_ref().unwrap().handler,
+        ProbeHandler::HttpGet(_)
+    ));
+    assert!(matches!(
+        cs.volumes[0].source,
+        VolumeSource::EmptyDir { .. }
+    ));
+
+    let cron = instances
+        .iter()
+        .find(|i| i.resource_name == "nightly-report")
+        .unwrap();
+    assert_eq!(cron.kind, ResourceKind::CronJob);
+    let ResourceSpec::CronJob(cj) = &cron.spec.spec else {
+        panic!("expected cron job, got {:?}", cron.spec.spec);
+    };
+    assert_eq!(cj.timezone.as_deref(), Some("Europe/Copenhagen"));
+}
+
+#[tokio::test]
+async fn apply_resources_rejects_invalid_spec() {
+    let svc = ForageServiceImpl {
+        scheduler: Arc::new(InMemoryComputeScheduler::new()),
+    };
+
+    let mut bad_port = api_resource();
+    if let Some(forage_resource::Spec::ContainerService(cs)) = &mut bad_port.spec {
+        cs.container.as_mut().unwrap().ports[0].protocol = "HTTP".into();
+    }
+    let err = svc
+        .apply_resources(tonic::Request::new(apply_request(vec![bad_port])))
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), tonic::Code::InvalidArgument);
+    assert!(err.message().contains("my-api"), "{}", err.message());
+    assert!(err.message().contains("protocol"), "{}", err.message());
+
+    let mut unknown_volume = api_resource();
+    if let Some(forage_resource::Spec::ContainerService(cs)) = &mut unknown_volume.spec {
+        cs.volumes.clear();
+    }
+    let err = svc
+        .apply_resources(tonic::Request::new(apply_request(vec![unknown_volume])))
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), tonic::Code::InvalidArgument);
+    assert!(
+        err.message().contains("unknown volume"),
+        "{}",
+        err.message()
+    );
+
+    let missing_spec = ForageResource {
+        name: "empty".into(),
+        spec: None,
+    };
+    let err = svc
+        .apply_resources(tonic::Request::new(apply_request(vec![missing_spec])))
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), tonic::Code::InvalidArgument);
+}
+
+#[tokio::test]
+async fn applied_resources_are_filtered_by_labels() {
+    let svc = ForageServiceImpl {
+        scheduler: Arc::new(InMemoryComputeScheduler::new()),
+    };
+    svc.apply_resources(tonic::Request::new(apply_request(vec![api_resource()])))
+        .await
+        .unwrap();
+
+    let applied = |labels: &[(&str, &str)]| {
+        svc.get_applied_resources(tonic::Request::new(GetAppliedResourcesRequest {
+            namespace: "testorg".into(),
+            labels: labels
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        }))
+    };
+
+    let resources = applied(&[("project", "my-project")])
+        .await
+        .unwrap()
+        .into_inner()
+        .resources;
+    assert_eq!(resources.len(), 1);
+    assert_eq!(resources[0].name, "my-api");
+    assert_eq!(resources[0].kind, "container_service");
+    assert_eq!(resources[0].image, "registry.forage.sh/testorg/my-api:v2");
+    assert_eq!(resources[0].replicas, 2);
+
+    for labels in [&[("project", "other")][..], &[("team", "platform")][..]] {
+        let resources = applied(labels).await.unwrap().into_inner().resources;
+        assert!(resources.is_empty(), "{labels:?} matched {resources:?}");
+    }
+}
+
+#[tokio::test]
+async fn compute_page_shows_requested_spec() {
+    let scheduler = Arc::new(InMemoryComputeScheduler::new());
+    ForageServiceImpl {
+        scheduler: scheduler.clone(),
+    }
+    .apply_resources(tonic::Request::new(apply_request(vec![api_resource()])))
+    .await
+    .unwrap();
+
+    let (state, sessions) = test_state();
+    let state = state.with_compute_scheduler(scheduler);
+    let cookie = create_test_session(&sessions).await;
+    let app = build_router(state);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/orgs/testorg/settings/compute")
+                .header("cookie", &cookie)
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let html = String::from_utf8(body.to_vec()).unwrap();
+    assert!(html.contains("http:8080&#x2f;TCP"));
+    assert!(html.contains("secret db&#x2f;url"));
+    assert!(html.contains("2–6 replicas, cpu 75%"));
+    assert!(html.contains("limit 1 &#x2f; 1Gi"));
+    // Literal env values are never rendered.
+    assert!(html.contains("LOG_LEVEL"));
+    assert!(!html.contains("debug"));
+}
@@ -0,0 +1,421 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU16, AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use forage_core::integrations::router::{route_notification, NotificationEvent};
+use forage_core::integrations::{
+    CreateIntegrationInput, DeliveryStatus, InMemoryIntegrationStore, Integration,
+    IntegrationConfig, IntegrationStore, IntegrationType, DELIVERY_RETRY_DELAYS_SECS,
+};
+use tower::ServiceExt;
+
+use crate::notification_worker::NotificationDispatcher;
+use crate::test_support::*;
+
+/// Local receiver answering with a switchable status code and counting hits.
+struct Receiver {
+    url: String,
+    status: Arc<AtomicU16>,
+    hits: Arc<AtomicUsize>,
+}
+
+async fn start_receiver(status: StatusCode) -> Receiver {
+    let status = Arc::new(AtomicU16::new(status.as_u16()));
+    let hits = Arc::new(AtomicUsize::new(0));
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/hook", listener.local_addr().unwrap());
+
+    let (s, h) = (status.clone(), hits.clone());
+    let app = axum::Router::new().route(
+        "/hook",
+        axum::routing::post(move || {
+            let (s, h) = (s.clone(), h.clone());
+            async move {
+                h.fetch_add(1, Ordering::SeqCst);
+                StatusCode::from_u16(s.load(Ordering::SeqCst)).unwrap()
+            }
+        }),
+    );
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+    Receiver { url, status, hits }
+}
+
+async fn webhook_integration(store: &InMemoryIntegrationStore, url: &str) -> Integration {
+    store
+        .create_integration(&CreateIntegrationInput {
+            organisation: "testorg".into(),
+            integration_type: IntegrationType::Webhook,
+            name: "queued-hook".into(),
+            config: IntegrationConfig::Webhook {
+                url: url.into(),
+                secret: None,
+                headers: HashMap::new(),
+            },
+            created_by: "user-123".into(),
+        })
+        .await
+        .unwrap()
+}
+
+fn event() -> NotificationEvent {
+    NotificationEvent {
+        id: "notif-queue-1".into(),
+        notification_type: "release_succeeded".into(),
+        title: "Deploy v2.0 succeeded".into(),
+        body: String::new(),
+        organisation: "testorg".into(),
+        project: "my-api".into(),
+        timestamp: "2026-03-09T15:00:00Z".into(),
+        release: None,
+    }
+}
+
+/// Queue the event for `integration` without attempting it, due now.
+async fn enqueue(store: &InMemoryIntegrationStore, integration: &Integration) -> String {
+    let tasks = route_notification(&event(), std::slice::from_ref(integration));
+    store
+        .enqueue_delivery(
+            &integration.id,
+            tasks[0].notification_id(),
+            serde_json::to_value(tasks[0].to_queued()).unwrap(),
+            chrono::Utc::now(),
+        )
+        .await
+        .unwrap()
+        .id
+}
+
+// ─── Queue ──────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn failed_delivery_is_retried_from_the_queue() {
+    let receiver = start_receiver(StatusCode::INTERNAL_SERVER_ERROR).await;
+    let store = Arc::new(InMemoryIntegrationStore::new());
+    let integration = webhook_integration(&store, &receiver.url).await;
+    let delivery_id = enqueue(&store, &integration).await;
+    let dispatcher = NotificationDispatcher::new(store.clone(), String::new());
+
+    assert_eq!(dispatcher.process_due(10).await, 1);
+    let d = store.get_delivery(&integration.id, &delivery_id).await.unwrap();
+    assert_eq!(d.status, DeliveryStatus::Failed);
+    assert_eq!(d.attempts, 1);
+    assert_eq!(d.response_code, Some(500));
+    assert!(d.next_attempt_at.is_some());
+
+    // Not due again until the backoff passes.
+    assert_eq!(dispatcher.process_due(10).await, 0);
+
+    receiver.status.store(200, Ordering::SeqCst);
+    tokio::time::sleep(std::time::Duration::from_millis(1100)).await;
+    assert_eq!(dispatcher.process_due(10).await, 1);
+
+    let d = store.get_delivery(&integration.id, &delivery_id).await.unwrap();
+    assert_eq!(d.status, DeliveryStatus::Delivered);
+    assert_eq!(d.attempts, 2);
+    assert_eq!(d.response_code, Some(200));
+    assert_eq!(receiver.hits.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn delivery_is_dead_lettered_after_retry_schedule() {
+    let receiver = start_receiver(StatusCode::BAD_GATEWAY).await;
+    let store = Arc::new(InMemoryIntegrationStore::new());
+    let integration = webhook_integration(&store, &receiver.url).await;
+    let delivery_id = enqueue(&store, &integration).await;
+    let dispatcher = NotificationDispatcher::new(store.clone(), String::new());
+
+    let mut delivery = store.get_delivery(&integration.id, &delivery_id).await.unwrap();
+    delivery.attempts = DELIVERY_RETRY_DELAYS_SECS.len() as u32;
+    dispatcher.deliver_queued(&delivery).await;
+
+    let d = store.get_delivery(&integration.id, &delivery_id).await.unwrap();
+    assert_eq!(d.status, DeliveryStatus::DeadLettered);
+    assert_eq!(d.response_code, Some(502));
+    assert!(d.next_attempt_at.is_none());
+    assert!(d.can_redeliver());
+    assert_eq!(dispatcher.process_due(10).await, 0);
+}
+
+#[tokio::test]
+async fn undecodable_payload_is_dead_lettered() {
+    let store = Arc::new(InMemoryIntegrationStore::new());
+    let integration = webhook_integration(&store, "http://127.0.0.1:1/hook").await;
+    let delivery = store
+        .enqueue_delivery(
+            &integration.id,
+            "notif-1",
+            serde_json::json!({ "kind": "carrier_pigeon" }),
+            chrono::Utc::now(),
+        )
+        .await
+        .unwrap();
+    let dispatcher = NotificationDispatcher::new(store.clone(), String::new());
+
+    assert_eq!(dispatcher.process_due(10).await, 1);
+    let d = store.get_delivery(&integration.id, &delivery.id).await.unwrap();
+    assert_eq!(d.status, DeliveryStatus::DeadLettered);
+}
+
+#[tokio::test]
+async fn dispatch_leaves_unreachable_delivery_queued() {
+    let receiver = start_receiver(StatusCode::SERVICE_UNAVAILABLE).await;
+    let store = Arc::new(InMemoryIntegrationStore::new());
+    let integration = webhook_integration(&store, &receiver.url).await;
+    let dispatcher = NotificationDispatcher::new(store.clone(), String::new());
+
+    let tasks = route_notification(&event(), std::slice::from_ref(&integration));
+    dispatcher.dispatch(&tasks[0]).await;
+
+    let deliveries = store.list_deliveries(&integration.id, 10).await.unwrap();
+    assert_eq!(deliveries.len(), 1, "one queue entry, updated in place");
+    let d = &deliveries[0];
+    assert_eq!(d.status, DeliveryStatus::Failed);
+    assert_eq!(d.attempts, 3);
+    assert_eq!(d.response_code, Some(503));
+    assert!(d.next_attempt_at.is_some());
+    assert_eq!(receiver.hits.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn queued_delivery_stores_no_credentials() {
+    let receiver = start_receiver(StatusCode::OK).await;
+    let store = Arc::new(InMemoryIntegrationStore::new());
+    let integration = store
+        .create_integration(&CreateIntegrationInput {
+            organisation: "testorg".into(),
+            integration_type: IntegrationType::Webhook,
+            name: "signed-hook".into(),
+            config: IntegrationConfig::Webhook {
+                url: receiver.url.clone(),
+                secret: Some("whsec-do-not-store".into()),
+                headers: HashMap::from([(
+                    "Authorization".into(),
+                    "Bearer do-not-store".into(),
+                )]),
+            },
+            created_by: "user-123".into(),
+        })
+        .await
+        .unwrap();
+    let dispatcher = NotificationDispatcher::new(store.clone(), String::new());
+
+    let tasks = route_notification(&event(), std::slice::from_ref(&integration));
+    dispatcher.dispatch(&tasks[0]).await;
+
+    let d = &store.list_deliveries(&integration.id, 10).await.unwrap()[0];
+    let stored = d.task.as_ref().unwrap().to_string();
+    assert!(stored.contains("Deploy v2.0 succeeded"));
+    assert!(!stored.contains("do-not-store"));
+    assert!(!stored.contains(&receiver.url));
+}
+
+#[tokio::test]
+async fn queued_delivery_uses_the_current_config() {
+    let old = start_receiver(StatusCode::OK).await;
+    let new = start_receiver(StatusCode::OK).await;
+    let store = Arc::new(InMemoryIntegrationStore::new());
+    let integration = webhook_integration(&store, &old.url).await;
+    let delivery_id = enqueue(&store, &integration).await;
+    store
+        .update_integration_config(
+            "testorg",
+            &integration.id,
+            &integration.name,
+            &IntegrationConfig::Webhook {
+                url: new.url.clone(),
+                secret: None,
+                headers: HashMap::new(),
+            },
+        )
+        .await
+        .unwrap();
+    let dispatcher = NotificationDispatcher::new(store.clone(), String::new());
+
+    assert_eq!(dispatcher.process_due(10).await, 1);
+
+    let d = store.get_delivery(&integration.id, &delivery_id).await.unwrap();
+    assert_eq!(d.status, DeliveryStatus::Delivered);
+    assert_eq!(old.hits.load(Ordering::SeqCst), 0);
+    assert_eq!(new.hits.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn queued_delivery_to_disabled_integration_is_dead_lettered() {
+    let receiver = start_receiver(StatusCode::OK).await;
+    let store = Arc::new(InMemoryIntegrationStore::new());
+    let integration = webhook_integration(&store, &receiver.url).await;
+    let delivery_id = enqueue(&store, &integration).await;
+    store
+        .set_integration_enabled("testorg", &integration.id, false)
+        .await
+        .unwrap();
+    let dispatcher = NotificationDispatcher::new(store.clone(), String::new());
+
+    assert_eq!(dispatcher.process_due(10).await, 1);
+
+    let d = store.get_delivery(&integration.id, &delivery_id).await.unwrap();
+    assert_eq!(d.status, DeliveryStatus::DeadLettered);
+    assert_eq!(d.error_message.as_deref(), Some("integration is disabled"));
+    assert_eq!(receiver.hits.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn queued_delivery_to_deleted_integration_is_dead_lettered() {
+    let receiver = start_receiver(StatusCode::OK).await;
+    let store = Arc::new(InMemoryIntegrationStore::new());
+    let integration = webhook_integration(&store, &receiver.url).await;
+    let delivery_id = enqueue(&store, &integration).await;
+    store
+        .delete_integration("testorg", &integration.id)
+        .await
+        .unwrap();
+    let dispatcher = NotificationDispatcher::new(store.clone(), String::new());
+
+    assert_eq!(dispatcher.process_due(10).await, 1);
+
+    let d = store.get_delivery(&integration.id, &delivery_id).await.unwrap();
+    assert_eq!(d.status, DeliveryStatus::DeadLettered);
+    assert_eq!(d.error_message.as_deref(), Some("integration was deleted"));
+    assert_eq!(receiver.hits.load(Ordering::SeqCst), 0);
+}
+
+// ─── Integration page ───────────────────────────────────────────────
+
+#[tokio::test]
+async fn detail_page_shows_response_code_and_redeliver() {
+    let (state, sessions, integrations) =
+        test_state_with_integrations(MockForestClient::new(), MockPlatformClient::new());
+    let app = crate::build_router(state);
+    let cookie = create_test_session(&sessions).await;
+
+    let receiver = start_receiver(StatusCode::GONE).await;
+    let integration = webhook_integration(&integrations, &receiver.url).await;
+    let delivery_id = enqueue(&integrations, &integration).await;
+    let mut delivery = integrations
+        .get_delivery(&integration.id, &delivery_id)
+        .await
+        .unwrap();
+    delivery.attempts = DELIVERY_RETRY_DELAYS_SECS.len() as u32;
+    NotificationDispatcher::new(integrations.clone(), String::new())
+        .deliver_queued(&delivery)
+        .await;
+
+    let resp = app
+        .oneshot(
+            Request::builder()
+                .uri(format!("/orgs/testorg/settings/integrations/{}", integration.id))
+                .header("cookie", cookie)
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let text = String::from_utf8_lossy(&body);
+    assert!(text.contains("Dead-lettered"));
+    assert!(text.contains("410"));
+    assert!(text.contains(&format!("/deliveries/{delivery_id}/redeliver")));
+}
+
+#[tokio::test]
+async fn redeliver_sends_a_fresh_copy() {
+    let (state, sessions, integrations) =
+        test_state_with_integrations(MockForestClient::new(), MockPlatformClient::new());
+    let app = crate::build_router(state);
+    let cookie = create_test_session(&sessions).await;
+
+    let receiver = start_receiver(StatusCode::OK).await;
+    let integration = webhook_integration(&integrations, &receiver.url).await;
+    let delivery_id = enqueue(&integrations, &integration).await;
+    let dispatcher = NotificationDispatcher::new(integrations.clone(), String::new());
+    dispatcher.process_due(10).await;
+    assert_eq!(receiver.hits.load(Ordering::SeqCst), 1);
+
+    let resp = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!(
+                    "/orgs/testorg/settings/integrations/{}/deliveries/{}/redeliver",
+                    integration.id, delivery_id
+                ))
+                .header("cookie", cookie)
+                .header("content-type", "application/x-www-form-urlencoded")
+                .body(Body::from("_csrf=test-csrf"))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(resp.status(), StatusCode::SEE_OTHER);
+    assert_eq!(
+        resp.headers().get("location").unwrap(),
+        &format!(
+            "/orgs/testorg/settings/integrations/{}?redelivered=1",
+            integration.id
+        )
+    );
+    assert_eq!(receiver.hits.load(Ordering::SeqCst), 2);
+
+    let deliveries = integrations.list_deliveries(&integration.id, 10).await.unwrap();
+    assert_eq!(deliveries.len(), 2);
+    assert!(deliveries
+        .iter()
+        .all(|d| d.status == DeliveryStatus::Delivered));
+}
+
+#[tokio::test]
+async fn redeliver_rejects_other_orgs_integration() {
+    let (state, sessions, integrations) =
+        test_state_with_integrations(MockForestClient::new(), MockPlatformClient::new());
+    let app = crate::build_router(state);
+    let cookie = create_test_session(&sessions).await;
+
+    let integration = integrations
+        .create_integration(&CreateIntegrationInput {
+            organisation: "otherorg".into(),
+            integration_type: IntegrationType::Webhook,
+            name: "theirs".into(),
+            config: IntegrationConfig::Webhook {
+                url: "http://127.0.0.1:1/hook".into(),
+                secret: None,
+                headers: HashMap::new(),
+            },
+            created_by: "someone".into(),
+        })
+        .await
+        .unwrap();
+    let delivery_id = enqueue(&integrations, &integration).await;
+
+    let resp = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!(
+                    "/orgs/testorg/settings/integrations/{}/deliveries/{}/redeliver",
+                    integration.id, delivery_id
+                ))
+                .header("cookie", cookie)
+                .header("content-type", "application/x-www-form-urlencoded")
+                .body(Body::from("_csrf=test-csrf"))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    assert_eq!(
+        integrations
+            .list_deliveries(&integration.id, 10)
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+}
@@ -1,6 +1,7 @@
 mod account_link_tests;
 mod account_tests;
 mod auth_tests;
+mod compute_tests;
 mod device_tests;
 mod integration_tests;
 mod nats_tests;
@@ -13,3 +14,10 @@ mod magic_link_tests;
 mod oauth_tests;
 mod registry_tests;
 mod webhook_delivery_tests;
+mod sso_tests;
+mod slack_interactivity_tests;
+mod notification_channel_tests;
+mod delivery_queue_tests;
+mod notification_filter_tests;
+mod session_management_tests;
+mod passkey_tests;
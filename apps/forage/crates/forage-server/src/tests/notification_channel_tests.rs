@@ -0,0 +1,448 @@
+use std::sync::{Arc, Mutex};
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use forage_core::integrations::router::{
+    route_notification, route_notification_for_org, NotificationEvent, ReleaseContext,
+};
+use forage_core::integrations::{
+    CreateIntegrationInput, InMemoryIntegrationStore, Integration, IntegrationConfig,
+    IntegrationStore, IntegrationType,
+};
+use tower::ServiceExt;
+
+use crate::email_digest::take_due_digest;
+use crate::notification_worker::NotificationDispatcher;
+use crate::test_support::*;
+
+/// Local stand-in for a Teams/Discord/PagerDuty endpoint: records JSON bodies.
+async fn start_stand_in() -> (String, Arc<Mutex<Vec<serde_json::Value>>>) {
+    let received: Arc<Mutex<Vec<serde_json::Value>>> = Arc::default();
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/hook", listener.local_addr().unwrap());
+
+    let sink = received.clone();
+    let app = axum::Router::new().route(
+        "/hook",
+        axum::routing::post(move |axum::Json(body): axum::Json<serde_json::Value>| {
+            let sink = sink.clone();
+            async move {
+                sink.lock().unwrap().push(body);
+                // PagerDuty answers 202; chat webhooks 200/204. Either is success.
+                StatusCode::ACCEPTED
+            }
+        }),
+    );
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+    (url, received)
+}
+
+fn event(notification_type: &str) -> NotificationEvent {
+    NotificationEvent {
+        id: format!("notif-{notification_type}"),
+        notification_type: notification_type.into(),
+        title: format!("Deploy v2.0 {notification_type}"),
+        body: String::new(),
+        organisation: "testorg".into(),
+        project: "my-api".into(),
+        timestamp: "2026-03-09T15:00:00Z".into(),
+        release: Some(ReleaseContext {
+            slug: "my-api-v2".into(),
+            artifact_id: "art_abc".into(),
+            release_intent_id: "ri_1".into(),
+            destination: "prod-eu".into(),
+            environment: "production".into(),
+            source_username: "alice".into(),
+            source_user_id: String::new(),
+            commit_sha: "deadbeef1234567".into(),
+            commit_branch: "main".into(),
+            context_title: "feat: faster checkout".into(),
+            context_web: String::new(),
+            destination_count: 1,
+            error_message: (notification_type == "release_failed")
+                .then(|| "container exited with code 137".into()),
+        }),
+    }
+}
+
+async fn install(
+    store: &InMemoryIntegrationStore,
+    integration_type: IntegrationType,
+    config: IntegrationConfig,
+) -> Integration {
+    store
+        .create_integration(&CreateIntegrationInput {
+            organisation: "testorg".into(),
+            integration_type,
+            name: format!("{}-test", integration_type.as_str()),
+            config,
+            created_by: "user-1".into(),
+        })
+        .await
+        .unwrap()
+}
+
+// ─── Dispatch against local stand-ins ───────────────────────────────
+
+#[tokio::test]
+async fn teams_receives_adaptive_card() {
+    let (url, received) = start_stand_in().await;
+    let store = Arc::new(InMemoryIntegrationStore::new());
+    let integration = install(&store, IntegrationType::Teams, IntegrationConfig::Teams { webhook_url: url }).await;
+    let dispatcher = NotificationDispatcher::new(store.clone(), "https://forage.example.com".into());
+
+    for task in route_notification(&event("release_failed"), std::slice::from_ref(&integration)) {
+        dispatcher.dispatch(&task).await;
+    }
+
+    let deliveries = store.list_deliveries(&integration.id, 10).await.unwrap();
+    assert_eq!(deliveries[0].status.as_str(), "delivered");
+
+    let received = received.lock().unwrap();
+    assert_eq!(received.len(), 1);
+    let attachment = &received[0]["attachments"][0];
+    assert_eq!(attachment["contentType"], "application/vnd.microsoft.card.adaptive");
+    let card = &attachment["content"];
+    assert_eq!(card["type"], "AdaptiveCard");
+    assert_eq!(card["body"][0]["color"], "attention");
+    let rendered = card.to_string();
+    assert!(rendered.contains("container exited with code 137"));
+    assert_eq!(
+        card["actions"][0]["url"],
+        "https://forage.example.com/orgs/testorg/projects/my-api/releases/my-api-v2"
+    );
+}
+
+#[tokio::test]
+async fn discord_receives_embed_without_mentions() {
+    let (url, received) = start_stand_in().await;
+    let store = Arc::new(InMemoryIntegrationStore::new());
+    let integration = install(&store, IntegrationType::Discord, IntegrationConfig::Discord { webhook_url: url }).await;
+    let dispatcher = NotificationDispatcher::new(store.clone(), String::new());
+
+    for task in route_notification(&event("release_succeeded"), &[integration]) {
+        dispatcher.dispatch(&task).await;
+    }
+
+    let received = received.lock().unwrap();
+    assert_eq!(received.len(), 1);
+    let embed = &received[0]["embeds"][0];
+    assert_eq!(embed["color"], 0x36a64f);
+    assert_eq!(embed["description"], "feat: faster checkout");
+    assert_eq!(embed["fields"][0]["value"], "prod-eu");
+    assert_eq!(received[0]["allowed_mentions"]["parse"], serde_json::json!([]));
+}
+
+#[tokio::test]
+async fn pagerduty_triggers_on_failure_and_resolves_on_success() {
+    let (url, received) = start_stand_in().await;
+    let store = Arc::new(InMemoryIntegrationStore::new());
+    let routing_key = "a".repeat(32);
+    install(
+        &store,
+        IntegrationType::PagerDuty,
+        IntegrationConfig::PagerDuty { routing_key: routing_key.clone() },
+    )
+    .await;
+    let dispatcher = NotificationDispatcher::new(store.clone(), String::new())
+        .with_pagerduty_events_url(url);
+
+    for nt in ["release_started", "release_failed", "release_succeeded"] {
+        for task in route_notification_for_org(store.as_ref(), &event(nt)).await {
+            dispatcher.dispatch(&task).await;
+        }
+    }
+
+    let received = received.lock().unwrap();
+    assert_eq!(received.len(), 2, "release_started is not routed to PagerDuty");
+    assert_eq!(received[0]["event_action"], "trigger");
+    assert_eq!(received[0]["routing_key"], routing_key.as_str());
+    assert_eq!(received[0]["payload"]["severity"], "error");
+    assert_eq!(received[1]["event_action"], "resolve");
+    assert_eq!(received[1]["dedup_key"], received[0]["dedup_key"]);
+}
+
+#[tokio::test]
+async fn email_digest_queues_and_flushes_when_due() {
+    let store = Arc::new(InMemoryIntegrationStore::new());
+    let integration = install(
+        &store,
+        IntegrationType::Email,
+        IntegrationConfig::Email {
+            recipients: vec!["ops@example.com".into(), "lead@example.com".into()],
+            digest_interval_minutes: 60,
+        },
+    )
+    .await;
+    let dispatcher = NotificationDispatcher::new(store.clone(), String::new());
+
+    for nt in ["release_started", "release_failed"] {
+        for task in route_notification_for_org(store.as_ref(), &event(nt)).await {
+            dispatcher.dispatch(&task).await;
+        }
+    }
+
+    let backlog = store.list_digest_backlog().await.unwrap();
+    assert_eq!(backlog.len(), 1);
+    assert_eq!(backlog[0].integration_id, integration.id);
+
+    // Not due yet: nothing is taken.
+    let now = chrono::Utc::now();
+    assert!(take_due_digest(store.as_ref(), &backlog[0], "https://forage.sh", now).await.is_none());
+    assert_eq!(store.list_digest_backlog().await.unwrap().len(), 1);
+
+    let later = now + chrono::Duration::minutes(61);
+    let (mails, entries) = take_due_digest(store.as_ref(), &backlog[0], "https://forage.sh", later)
+        .await
+        .unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(mails.len(), 2);
+    assert_eq!(mails[0].to, "ops@example.com");
+    assert_eq!(mails[0].subject, "[testorg] 2 release updates (1 failed)");
+    assert!(mails[0].body_text.contains("container exited with code 137"));
+    assert!(store.list_digest_backlog().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn email_digest_drops_entries_for_disabled_integration() {
+    let store = Arc::new(InMemoryIntegrationStore::new());
+    let integration = install(
+        &store,
+        IntegrationType::Email,
+        IntegrationConfig::Email { recipients: vec!["ops@example.com".into()], digest_interval_minutes: 15 },
+    )
+    .await;
+    let dispatcher = NotificationDispatcher::new(store.clone(), String::new());
+    for task in route_notification(&event("release_failed"), std::slice::from_ref(&integration)) {
+        dispatcher.dispatch(&task).await;
+    }
+    store.set_integration_enabled("testorg", &integration.id, false).await.unwrap();
+
+    let backlog = store.list_digest_backlog().await.unwrap();
+    let later = chrono::Utc::now() + chrono::Duration::hours(1);
+    assert!(take_due_digest(store.as_ref(), &backlog[0], "", later).await.is_none());
+    assert!(store.list_digest_backlog().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn drift_events_reach_every_channel() {
+    let (url, received) = start_stand_in().await;
+    let store = Arc::new(InMemoryIntegrationStore::new());
+    let teams = install(&store, IntegrationType::Teams, IntegrationConfig::Teams { webhook_url: url.clone() }).await;
+    let discord = install(&store, IntegrationType::Discord, IntegrationConfig::Discord { webhook_url: url.clone() }).await;
+    let pagerduty = install(
+        &store,
+        IntegrationType::PagerDuty,
+        IntegrationConfig::PagerDuty { routing_key: "a".repeat(32) },
+    )
+    .await;
+    let dispatcher = NotificationDispatcher::new(store.clone(), String::new())
+        .with_pagerduty_events_url(url);
+
+    for nt in ["drift_detected", "drift_resolved"] {
+        let tasks = route_notification_for_org(store.as_ref(), &event(nt)).await;
+        let mut routed: Vec<&str> = tasks.iter().map(|t| t.integration_id()).collect();
+        routed.sort();
+        let mut expected = vec![teams.id.as_str(), discord.id.as_str(), pagerduty.id.as_str()];
+        expected.sort();
+        assert_eq!(routed, expected, "{nt} reaches every channel");
+
+        for task in &tasks {
+            dispatcher.dispatch(task).await;
+        }
+    }
+
+    let received = received.lock().unwrap();
+    assert_eq!(received.len(), 6);
+    let incidents: Vec<_> = received.iter().filter(|b| b["routing_key"].is_string()).collect();
+    assert_eq!(incidents.len(), 2);
+    assert_eq!(incidents[0]["event_action"], "trigger");
+    assert_eq!(incidents[0]["dedup_key"], "forage/testorg/my-api/prod-eu/drift");
+    assert_eq!(incidents[1]["event_action"], "resolve");
+    assert_eq!(incidents[1]["dedup_key"], incidents[0]["dedup_key"]);
+}
+
+// ─── Install routes ─────────────────────────────────────────────────
+
+fn build_app() -> (
+    axum::Router,
+    Arc<forage_core::session::InMemorySessionStore>,
+    Arc<InMemoryIntegrationStore>,
+) {
+    let (state, sessions, integrations) =
+        test_state_with_integrations(MockForestClient::new(), MockPlatformClient::new());
+    (crate::build_router(state), sessions, integrations)
+}
+
+fn post_form(uri: &str, cookie: String, body: &str) -> Request<Body> {
+    Request::builder()
+        .method("POST")
+        .uri(uri)
+        .header("cookie", cookie)
+        .header("content-type", "application/x-www-form-urlencoded")
+        .body(Body::from(body.to_string()))
+        .unwrap()
+}
+
+#[tokio::test]
+async fn install_channel_pages_render() {
+    let (app, sessions, _) = build_app();
+    let cookie = create_test_session(&sessions).await;
+
+    for (kind, expected) in [
+        ("teams", "Install Microsoft Teams"),
+        ("discord", "Install Discord"),
+        ("pagerduty", "Integration key"),
+        ("email", "Hourly"),
+    ] {
+        let resp = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri(format!("/orgs/testorg/settings/integrations/install/{kind}"))
+                    .header("cookie", cookie.clone())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK, "{kind}");
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX).await.unwrap();
+        assert!(String::from_utf8_lossy(&body).contains(expected), "{kind}");
+    }
+}
+
+#[tokio::test]
+async fn install_unknown_channel_returns_404() {
+    let (app, sessions, _) = build_app();
+    let cookie = create_test_session(&sessions).await;
+
+    let resp = app
+        .oneshot(
+            Request::builder()
+                .uri("/orgs/testorg/settings/integrations/install/carrier-pigeon")
+                .header("cookie", cookie)
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn create_pagerduty_enables_only_failure_rules() {
+    let (app, sessions, integrations) = build_app();
+    let cookie = create_test_session(&sessions).await;
+
+    let body = format!("_csrf=test-csrf&name=on-call&routing_key={}", "b".repeat(32));
+    let resp = app
+        .oneshot(post_form("/orgs/testorg/settings/integrations/install/pagerduty", cookie, &body))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let all = integrations.list_integrations("testorg").await.unwrap();
+    assert_eq!(all.len(), 1);
+    assert_eq!(all[0].integration_type, IntegrationType::PagerDuty);
+    let rules = integrations.list_rules(&all[0].id).await.unwrap();
+    let mut enabled: Vec<_> = rules.iter().filter(|r| r.enabled).map(|r| r.notification_type.clone()).collect();
+    enabled.sort();
+    assert_eq!(enabled, ["release_failed", "release_succeeded"]);
+}
+
+#[tokio::test]
+async fn create_email_digest_parses_recipients() {
+    let (app, sessions, integrations) = build_app();
+    let cookie = create_test_session(&sessions).await;
+
+    let body = "_csrf=test-csrf&name=digest&recipients=ops%40example.com%2C+lead%40example.com%0Ateam%40example.com&digest_interval_minutes=1440";
+    let resp = app
+        .oneshot(post_form("/orgs/testorg/settings/integrations/install/email", cookie, body))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let all = integrations.list_integrations("testorg").await.unwrap();
+    match &all[0].config {
+        IntegrationConfig::Email { recipients, digest_interval_minutes } => {
+            assert_eq!(recipients, &["ops@example.com", "lead@example.com", "team@example.com"]);
+            assert_eq!(*digest_interval_minutes, 1440);
+        }
+        other => panic!("expected email config, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn create_channel_rejects_invalid_input() {
+    let (app, sessions, integrations) = build_app();
+    let cookie = create_test_session(&sessions).await;
+
+    for (kind, body) in [
+        ("discord", "_csrf=test-csrf&name=d&webhook_url=https%3A%2F%2Fevil.example.com%2Fhook"),
+        ("teams", "_csrf=test-csrf&name=t&webhook_url=http%3A%2F%2Fexample.com%2Fhook"),
+        ("pagerduty", "_csrf=test-csrf&name=p&routing_key=short"),
+        ("email", "_csrf=test-csrf&name=e&recipients=not-an-email"),
+        ("email", "_csrf=test-csrf&name=e&recipients=ops%40example.com&digest_interval_minutes=1"),
+    ] {
+        let resp = app
+            .clone()
+            .oneshot(post_form(
+                &format!("/orgs/testorg/settings/integrations/install/{kind}"),
+                cookie.clone(),
+                body,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::SEE_OTHER, "{kind}: {body}");
+        let location = resp.headers().get("location").unwrap().to_str().unwrap();
+        assert!(location.contains(&format!("install/{kind}?error=")), "{location}");
+    }
+    assert!(integrations.list_integrations("testorg").await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn create_channel_invalid_csrf_returns_403() {
+    let (app, sessions, _) = build_app();
+    let cookie = create_test_session(&sessions).await;
+
+    let resp = app
+        .oneshot(post_form(
+            "/orgs/testorg/settings/integrations/install/teams",
+            cookie,
+            "_csrf=wrong&name=t&webhook_url=https%3A%2F%2Fexample.com%2Fhook",
+        ))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn detail_page_redacts_channel_secrets() {
+    let (app, sessions, integrations) = build_app();
+    let cookie = create_test_session(&sessions).await;
+    let integration = install(
+        &integrations,
+        IntegrationType::Discord,
+        IntegrationConfig::Discord {
+            webhook_url: "https://discord.com/api/webhooks/123/super-secret-token".into(),
+        },
+    )
+    .await;
+
+    let resp = app
+        .oneshot(
+            Request::builder()
+                .uri(format!("/orgs/testorg/settings/integrations/{}", integration.id))
+                .header("cookie", cookie)
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(resp.into_body(), usize::MAX).await.unwrap();
+    let text = String::from_utf8_lossy(&body);
+    assert!(text.contains("Webhook on discord.com"));
+    assert!(!text.contains("super-secret-token"));
+}
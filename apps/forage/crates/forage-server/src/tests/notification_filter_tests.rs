@@ -0,0 +1,294 @@
+use std::collections::HashMap;
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use forage_core::integrations::router::{
+    route_notification_for_org, DispatchTask, NotificationEvent, ReleaseContext,
+};
+use forage_core::integrations::{
+    CreateIntegrationInput, InMemoryIntegrationStore, Integration, IntegrationConfig,
+    IntegrationStore, IntegrationType, NotificationFilter, Severity,
+};
+use tower::ServiceExt;
+
+use crate::test_support::*;
+
+async fn webhook(store: &InMemoryIntegrationStore, name: &str) -> Integration {
+    store
+        .create_integration(&CreateIntegrationInput {
+            organisation: "testorg".into(),
+            integration_type: IntegrationType::Webhook,
+            name: name.into(),
+            config: IntegrationConfig::Webhook {
+                url: format!("https://example.com/{name}"),
+                secret: None,
+                headers: HashMap::new(),
+            },
+            created_by: "user-123".into(),
+        })
+        .await
+        .unwrap()
+}
+
+fn event(project: &str, environment: &str) -> NotificationEvent {
+    NotificationEvent {
+        id: format!("notif-{project}-{environment}"),
+        notification_type: "release_failed".into(),
+        title: "Release failed".into(),
+        body: String::new(),
+        organisation: "testorg".into(),
+        project: project.into(),
+        timestamp: "2026-03-09T15:00:00Z".into(),
+        release: Some(ReleaseContext {
+            slug: format!("{project}-v3"),
+            artifact_id: "art_1".into(),
+            release_intent_id: "ri_1".into(),
+            destination: format!("{environment}-eu"),
+            environment: environment.into(),
+            source_username: "alice".into(),
+            source_user_id: String::new(),
+            commit_sha: "deadbeef1234567".into(),
+            commit_branch: "main".into(),
+            context_title: String::new(),
+            context_web: String::new(),
+            destination_count: 1,
+            error_message: Some("health check timed out".into()),
+        }),
+    }
+}
+
+fn targets(tasks: &[DispatchTask]) -> Vec<&str> {
+    tasks.iter().map(|t| t.integration_id()).collect()
+}
+
+// ─── Routing ────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn filtered_integration_only_gets_matching_events() {
+    let store = InMemoryIntegrationStore::new();
+    let everything = webhook(&store, "everything").await;
+    let payments = webhook(&store, "payments").await;
+    store
+        .set_notification_filter(
+            &payments.id,
+            &NotificationFilter {
+                projects: vec!["payments".into()],
+                environments: vec!["prod".into()],
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    let tasks = route_notification_for_org(&store, &event("payments", "prod")).await;
+    assert_eq!(targets(&tasks), vec![everything.id.as_str(), payments.id.as_str()]);
+
+    let tasks = route_notification_for_org(&store, &event("payments", "staging")).await;
+    assert_eq!(targets(&tasks), vec![everything.id.as_str()]);
+
+    let tasks = route_notification_for_org(&store, &event("checkout", "prod")).await;
+    assert_eq!(targets(&tasks), vec![everything.id.as_str()]);
+}
+
+#[tokio::test]
+async fn event_type_rules_still_apply_with_filter() {
+    let store = InMemoryIntegrationStore::new();
+    let payments = webhook(&store, "payments").await;
+    store
+        .set_notification_filter(
+            &payments.id,
+            &NotificationFilter {
+                projects: vec!["payments".into()],
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    store
+        .set_rule_enabled(&payments.id, "release_failed", false)
+        .await
+        .unwrap();
+
+    let tasks = route_notification_for_org(&store, &event("payments", "prod")).await;
+    assert!(tasks.is_empty());
+}
+
+#[tokio::test]
+async fn message_overrides_are_applied_per_integration() {
+    let store = InMemoryIntegrationStore::new();
+    let plain = webhook(&store, "plain").await;
+    let custom = webhook(&store, "custom").await;
+    store
+        .set_notification_filter(
+            &custom.id,
+            &NotificationFilter {
+                title_template: Some("{project} broke {environment}".into()),
+                body_template: Some("{release} by {author}: {error}".into()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    let tasks = route_notification_for_org(&store, &event("payments", "prod")).await;
+    let payload = |id: &str| match tasks.iter().find(|t| t.integration_id() == id) {
+        Some(DispatchTask::Webhook { payload, .. }) => payload.clone(),
+        other => panic!("expected webhook task, got {other:?}"),
+    };
+
+    assert_eq!(payload(&plain.id).title, "Release failed");
+    let custom_payload = payload(&custom.id);
+    assert_eq!(custom_payload.title, "payments broke prod");
+    assert_eq!(
+        custom_payload.body,
+        "payments-v3 by alice: health check timed out"
+    );
+}
+
+#[tokio::test]
+async fn min_severity_drops_less_severe_events() {
+    let store = InMemoryIntegrationStore::new();
+    let everything = webhook(&store, "everything").await;
+    let failures = webhook(&store, "failures").await;
+    store
+        .set_notification_filter(
+            &failures.id,
+            &NotificationFilter {
+                min_severity: Some(Severity::Error),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    let tasks = route_notification_for_org(&store, &event("payments", "prod")).await;
+    assert_eq!(targets(&tasks).len(), 2);
+
+    let mut succeeded = event("payments", "prod");
+    succeeded.notification_type = "release_succeeded".into();
+    let tasks = route_notification_for_org(&store, &succeeded).await;
+    assert_eq!(targets(&tasks), vec![everything.id.as_str()]);
+}
+
+// ─── Settings page ──────────────────────────────────────────────────
+
+#[tokio::test]
+async fn save_filters_and_show_them_on_detail_page() {
+    let (state, sessions, integrations) =
+        test_state_with_integrations(MockForestClient::new(), MockPlatformClient::new());
+    let app = crate::build_router(state);
+    let cookie = create_test_session(&sessions).await;
+    let integration = webhook(&integrations, "payments").await;
+
+    let body = "_csrf=test-csrf&projects=payments%2C+ledger&environments=prod&destinations=prod-*\
+                &min_severity=error&title_template=%7Bproject%7D+failed&body_template=";
+    let resp = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!(
+                    "/orgs/testorg/settings/integrations/{}/filters",
+                    integration.id
+                ))
+                .header("cookie", &cookie)
+                .header("content-type", "application/x-www-form-urlencoded")
+                .body(Body::from(body))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::SEE_OTHER);
+
+    let filter = integrations
+        .get_notification_filter(&integration.id)
+        .await
+        .unwrap();
+    assert_eq!(filter.projects, vec!["payments", "ledger"]);
+    assert_eq!(filter.environments, vec!["prod"]);
+    assert_eq!(filter.destinations, vec!["prod-*"]);
+    assert_eq!(filter.min_severity, Some(Severity::Error));
+    assert_eq!(filter.title_template.as_deref(), Some("{project} failed"));
+    assert_eq!(filter.body_template, None);
+
+    let resp = app
+        .oneshot(
+            Request::builder()
+                .uri(format!("/orgs/testorg/settings/integrations/{}", integration.id))
+                .header("cookie", &cookie)
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let text = String::from_utf8_lossy(&body);
+    assert!(text.contains("Filters"));
+    assert!(text.contains("payments, ledger"));
+    assert!(text.contains("{project} failed"));
+    assert!(text.contains(r#"value="error" selected"#));
+}
+
+#[tokio::test]
+async fn save_filters_rejects_unknown_placeholder() {
+    let (state, sessions, integrations) =
+        test_state_with_integrations(MockForestClient::new(), MockPlatformClient::new());
+    let app = crate::build_router(state);
+    let cookie = create_test_session(&sessions).await;
+    let integration = webhook(&integrations, "payments").await;
+
+    let resp = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!(
+                    "/orgs/testorg/settings/integrations/{}/filters",
+                    integration.id
+                ))
+                .header("cookie", &cookie)
+                .header("content-type", "application/x-www-form-urlencoded")
+                .body(Body::from("_csrf=test-csrf&title_template=%7Bsecret%7D"))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(resp.status(), StatusCode::SEE_OTHER);
+    let location = resp.headers().get("location").unwrap().to_str().unwrap();
+    assert!(location.contains("?error="), "{location}");
+    assert!(integrations
+        .get_notification_filter(&integration.id)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn save_filters_requires_csrf() {
+    let (state, sessions, integrations) =
+        test_state_with_integrations(MockForestClient::new(), MockPlatformClient::new());
+    let app = crate::build_router(state);
+    let cookie = create_test_session(&sessions).await;
+    let integration = webhook(&integrations, "payments").await;
+
+    let resp = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!(
+                    "/orgs/testorg/settings/integrations/{}/filters",
+                    integration.id
+                ))
+                .header("cookie", &cookie)
+                .header("content-type", "application/x-www-form-urlencoded")
+                .body(Body::from("_csrf=wrong&projects=payments"))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+}
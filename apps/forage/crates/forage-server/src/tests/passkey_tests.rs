@@ -0,0 +1,414 @@
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use forage_core::auth::{AuthError, LoginResult, MfaMethod, Passkey};
+use tower::ServiceExt;
+
+use crate::build_router;
+use crate::test_support::*;
+
+fn post(uri: &str, cookie: Option<&str>, body: &str) -> Request<Body> {
+    let mut builder = Request::builder()
+        .method("POST")
+        .uri(uri)
+        .header("content-type", "application/x-www-form-urlencoded");
+    if let Some(cookie) = cookie {
+        builder = builder.header("cookie", cookie);
+    }
+    builder.body(Body::from(body.to_string())).unwrap()
+}
+
+async fn body_string(response: axum::response::Response) -> String {
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    String::from_utf8(body.to_vec()).unwrap()
+}
+
+fn laptop_passkey() -> Passkey {
+    Passkey {
+        passkey_id: "pk-laptop".into(),
+        name: "Work laptop".into(),
+        created_at: Some("2026-05-01T10:00:00Z".into()),
+        last_used_at: None,
+    }
+}
+
+// ─── Account settings ───────────────────────────────────────────────
+
+#[tokio::test]
+async fn account_page_lists_passkeys() {
+    let mock = MockForestClient::with_behavior(MockBehavior {
+        list_passkeys_result: Some(Ok(vec![laptop_passkey()])),
+        ..Default::default()
+    });
+    let (state, sessions) = test_state_with(mock, MockPlatformClient::new());
+    let cookie = create_test_session(&sessions).await;
+    let app = build_router(state);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/settings/account")
+                .header("cookie", &cookie)
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let html = body_string(response).await;
+    assert!(html.contains("Passkeys"));
+    assert!(html.contains("Work laptop"));
+    assert!(html.contains("/settings/account/passkeys/pk-laptop/delete"));
+    assert!(html.contains("/static/js/passkeys.js"));
+}
+
+#[tokio::test]
+async fn registration_options_return_ceremony_json() {
+    let (state, sessions) = test_state();
+    let cookie = create_test_session(&sessions).await;
+    let app = build_router(state);
+
+    let response = app
+        .oneshot(post(
+            "/settings/account/passkeys/options",
+            Some(&cookie),
+            "_csrf=test-csrf",
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let json: serde_json::Value = serde_json::from_str(&body_string(response).await).unwrap();
+    assert_eq!(json["ceremony_id"], "passkey-ceremony-1");
+    // Options are relayed as an object, not a JSON string.
+    assert_eq!(json["options"]["rpId"], "localhost");
+}
+
+#[tokio::test]
+async fn registration_options_require_csrf() {
+    let (state, sessions) = test_state();
+    let cookie = create_test_session(&sessions).await;
+    let app = build_router(state);
+
+    let response = app
+        .oneshot(post(
+            "/settings/account/passkeys/options",
+            Some(&cookie),
+            "_csrf=wrong",
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn add_passkey_redirects_with_flash() {
+    let (state, sessions) = test_state();
+    let cookie = create_test_session(&sessions).await;
+    let app = build_router(state);
+
+    let response = app
+        .oneshot(post(
+            "/settings/account/passkeys",
+            Some(&cookie),
+            "_csrf=test-csrf&name=Laptop&ceremony_id=passkey-ceremony-1&credential_json=%7B%7D",
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::SEE_OTHER);
+    assert_eq!(
+        response.headers().get("location").unwrap(),
+        "/settings/account?flash=passkey_added"
+    );
+}
+
+#[tokio::test]
+async fn add_passkey_failure_redirects_with_error() {
+    let mock = MockForestClient::with_behavior(MockBehavior {
+        finish_passkey_registration_result: Some(Err(AuthError::InvalidCredentials)),
+        ..Default::default()
+    });
+    let (state, sessions) = test_state_with(mock, MockPlatformClient::new());
+    let cookie = create_test_session(&sessions).await;
+    let app = build_router(state);
+
+    let response = app
+        .oneshot(post(
+            "/settings/account/passkeys",
+            Some(&cookie),
+            "_csrf=test-csrf&name=Laptop&ceremony_id=passkey-ceremony-1&credential_json=%7B%7D",
+        ))
+        .await
+        .unwrap();
+    assert_eq!(
+        response.headers().get("location").unwrap(),
+        "/settings/account?error=passkey_failed"
+    );
+}
+
+#[tokio::test]
+async fn add_passkey_rejects_blank_name() {
+    let (state, sessions) = test_state();
+    let cookie = create_test_session(&sessions).await;
+    let app = build_router(state);
+
+    let response = app
+        .oneshot(post(
+            "/settings/account/passkeys",
+            Some(&cookie),
+            "_csrf=test-csrf&name=+&ceremony_id=passkey-ceremony-1&credential_json=%7B%7D",
+        ))
+        .await
+        .unwrap();
+    assert_eq!(
+        response.headers().get("location").unwrap(),
+        "/settings/account?error=passkey_name"
+    );
+}
+
+#[tokio::test]
+async fn delete_passkey_requires_csrf() {
+    let (state, sessions) = test_state();
+    let cookie = create_test_session(&sessions).await;
+    let app = build_router(state);
+
+    let response = app
+        .oneshot(post(
+            "/settings/account/passkeys/pk-laptop/delete",
+            Some(&cookie),
+            "_csrf=wrong",
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+// ─── Sign-in ────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn login_page_offers_passkey_sign_in() {
+    let response = test_app()
+        .oneshot(Request::builder().uri("/login").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let html = body_string(response).await;
+    assert!(html.contains("Sign in with a passkey"));
+    assert!(html.contains("/login/passkey/options"));
+}
+
+#[tokio::test]
+async fn passwordless_login_options_need_no_session() {
+    let response = test_app()
+        .oneshot(post("/login/passkey/options", None, ""))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let json: serde_json::Value = serde_json::from_str(&body_string(response).await).unwrap();
+    assert_eq!(json["ceremony_id"], "passkey-ceremony-1");
+}
+
+#[tokio::test]
+async fn mfa_login_options_need_mfa_cookie() {
+    let response = test_app()
+        .oneshot(post("/login/passkey/options", None, "mode=mfa"))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = test_app()
+        .oneshot(post(
+            "/login/passkey/options",
+            Some("forage_mfa_session=mfa-token"),
+            "mode=mfa",
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn passkey_login_creates_session() {
+    let response = test_app()
+        .oneshot(post(
+            "/login/passkey",
+            Some("forage_mfa_session=mfa-token"),
+            "ceremony_id=passkey-ceremony-1&credential_json=%7B%7D",
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::SEE_OTHER);
+    assert_eq!(response.headers().get("location").unwrap(), "/dashboard");
+    let cookies: Vec<_> = response
+        .headers()
+        .get_all("set-cookie")
+        .iter()
+        .map(|v| v.to_str().unwrap().to_string())
+        .collect();
+    assert!(cookies.iter().any(|c| c.starts_with("forage_session=")));
+    assert!(cookies
+        .iter()
+        .any(|c| c.starts_with("forage_mfa_session=;") && c.contains("Max-Age=0")));
+}
+
+#[tokio::test]
+async fn rejected_passkey_login_shows_error() {
+    let mock = MockForestClient::with_behavior(MockBehavior {
+        finish_passkey_login_result: Some(Err(AuthError::InvalidCredentials)),
+        ..Default::default()
+    });
+    let (state, _) = test_state_with(mock, MockPlatformClient::new());
+
+    let response = build_router(state)
+        .oneshot(post(
+            "/login/passkey",
+            None,
+            "ceremony_id=passkey-ceremony-1&credential_json=%7B%7D",
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.headers().get("set-cookie").is_none());
+    let html = body_string(response).await;
+    assert!(html.contains("That passkey wasn"));
+}
+
+#[tokio::test]
+async fn rejected_mfa_passkey_stays_on_the_challenge() {
+    let mock = MockForestClient::with_behavior(MockBehavior {
+        finish_passkey_login_result: Some(Err(AuthError::InvalidCredentials)),
+        ..Default::default()
+    });
+    let (state, _) = test_state_with(mock, MockPlatformClient::new());
+
+    let response = build_router(state)
+        .oneshot(post(
+            "/login/passkey",
+            Some("forage_mfa_session=mfa-token"),
+            "ceremony_id=passkey-ceremony-1&credential_json=%7B%7D&mode=mfa",
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    // The MFA cookie is kept so the user can try again.
+    assert!(response.headers().get("set-cookie").is_none());
+    let html = body_string(response).await;
+    assert!(html.contains("That passkey wasn"));
+    assert!(html.contains(r#"data-passkey-mode="mfa""#));
+}
+
+#[tokio::test]
+async fn mfa_challenge_offers_passkey_when_available() {
+    let mock = MockForestClient::with_behavior(MockBehavior {
+        login_result: Some(Ok(LoginResult::MfaRequired {
+            mfa_session_token: "mfa-token".into(),
+            methods: vec![MfaMethod::Passkey],
+        })),
+        ..Default::default()
+    });
+    let (state, _) = test_state_with(mock, MockPlatformClient::new());
+
+    let response = build_router(state)
+        .oneshot(post(
+            "/login",
+            None,
+            "identifier=testuser&password=CorrectPass123",
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let html = body_string(response).await;
+    assert!(html.contains("Use passkey"));
+    assert!(html.contains(r#"data-passkey-mode="mfa""#));
+    // Passkey-only users get no TOTP form.
+    assert!(!html.contains(r#"action="/login/mfa""#));
+}
+
+// ─── Device approval ────────────────────────────────────────────────
+
+#[tokio::test]
+async fn device_page_offers_passkey_approval_to_passkey_users() {
+    let mock = MockForestClient::with_behavior(MockBehavior {
+        list_passkeys_result: Some(Ok(vec![laptop_passkey()])),
+        ..Default::default()
+    });
+    let (state, sessions) = test_state_with(mock, MockPlatformClient::new());
+    let state = state.with_service_account_key("test-service-key".into());
+    let cookie = create_test_session(&sessions).await;
+
+    let response = build_router(state)
+        .oneshot(
+            Request::builder()
+                .uri("/device?user_code=ABCD-EFGH")
+                .header("cookie", &cookie)
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let html = body_string(response).await;
+    assert!(html.contains("Approve with passkey"));
+    assert!(html.contains("/device/passkey/options"));
+}
+
+#[tokio::test]
+async fn device_page_hides_passkey_approval_without_passkeys() {
+    let (state, sessions) = test_state();
+    let state = state.with_service_account_key("test-service-key".into());
+    let cookie = create_test_session(&sessions).await;
+
+    let response = build_router(state)
+        .oneshot(
+            Request::builder()
+                .uri("/device?user_code=ABCD-EFGH")
+                .header("cookie", &cookie)
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let html = body_string(response).await;
+    assert!(!html.contains("Approve with passkey"));
+}
+
+#[tokio::test]
+async fn device_passkey_options_require_csrf() {
+    let (state, sessions) = test_state();
+    let cookie = create_test_session(&sessions).await;
+    let app = build_router(state);
+
+    let response = app
+        .clone()
+        .oneshot(post("/device/passkey/options", Some(&cookie), "_csrf=wrong"))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = app
+        .oneshot(post("/device/passkey/options", Some(&cookie), "_csrf=test-csrf"))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn rejected_passkey_device_approval_explains_why() {
+    let mock = MockForestClient::with_behavior(MockBehavior {
+        approve_device_login_result: Some(Err(AuthError::InvalidCredentials)),
+        ..Default::default()
+    });
+    let (state, sessions) = test_state_with(mock, MockPlatformClient::new());
+    let state = state.with_service_account_key("test-service-key".into());
+    let cookie = create_test_session(&sessions).await;
+
+    let response = build_router(state)
+        .oneshot(post(
+            "/device",
+            Some(&cookie),
+            "_csrf=test-csrf&user_code=ABCD-EFGH&action=approve\
+             &passkey_ceremony_id=passkey-ceremony-1&passkey_credential_json=%7B%7D",
+        ))
+        .await
+        .unwrap();
+    let html = body_string(response).await;
+    assert!(html.contains("passkey wasn"));
+}
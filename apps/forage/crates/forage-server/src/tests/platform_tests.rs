@@ -2,6 +2,7 @@ use axum::body::Body;
 use axum::http::{Request, StatusCode};
 use forage_core::platform::{
     Artifact, ArtifactContext, ArtifactDestination, ArtifactRef, ArtifactSource, PlatformError,
+    ReleaseSchedule, ReleaseScheduleRun,
 };
 use tower::ServiceExt;
 
@@ -651,6 +652,62 @@ async fn project_releases_returns_200_with_artifacts() {
     assert!(html.contains("project=\"my-api\""));
 }
 
+#[tokio::test]
+async fn project_detail_shows_release_schedules() {
+    // The sidebar is hidden behind the Get-started panel on an empty
+    // project, so give the project a README.
+    let platform = MockPlatformClient::with_behavior(MockPlatformBehavior {
+        get_project_result: Some(Ok(Some(forage_core::platform::Project {
+            organisation: "testorg".into(),
+            project: "my-api".into(),
+            readme: "# my-api".into(),
+            ..Default::default()
+        }))),
+        list_release_schedules_result: Some(Ok(vec![ReleaseSchedule {
+            id: "sched-1".into(),
+            run_at: None,
+            cron: Some("0 2 * * *".into()),
+            artifact_id: None,
+            branch: Some("main".into()),
+            environments: vec!["perf".into()],
+            destinations: vec![],
+            use_pipeline: false,
+            status: "ACTIVE".into(),
+            next_run_at: Some("2099-01-01T02:00:00Z".into()),
+            last_run: Some(ReleaseScheduleRun {
+                ran_at: "2026-01-01T02:00:00Z".into(),
+                outcome: "BLOCKED".into(),
+                message: "branch restriction".into(),
+                release_intent_id: None,
+            }),
+        }])),
+        ..Default::default()
+    });
+    let (state, sessions) = test_state_with(MockForestClient::new(), platform);
+    let cookie = create_test_session(&sessions).await;
+    let app = build_router(state);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/orgs/testorg/projects/my-api")
+                .header("cookie", &cookie)
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let html = String::from_utf8(body.to_vec()).unwrap();
+    assert!(html.contains("Scheduled releases"));
+    assert!(html.contains("0 2 * * *"));
+    assert!(html.contains("perf"));
+    assert!(html.contains("blocked"));
+}
+
 #[tokio::test]
 async fn project_releases_empty_artifacts_shows_empty_state() {
     let platform = MockPlatformClient::with_behavior(MockPlatformBehavior {
@@ -1290,6 +1347,7 @@ async fn destinations_page_renders_drag_handles_for_admin() {
                     description: None,
                     sort_order: 0,
                     created_at: "2026-03-08T00:00:00Z".into(),
+                    protection: Default::default(),
                 },
             ])),
             ..Default::default()
@@ -1330,6 +1388,7 @@ async fn destinations_page_no_drag_handles_for_non_admin() {
                     description: None,
                     sort_order: 0,
                     created_at: "2026-03-08T00:00:00Z".into(),
+                    protection: Default::default(),
                 },
             ])),
             ..Default::default()
@@ -1356,6 +1415,160 @@ async fn destinations_page_no_drag_handles_for_non_admin() {
     assert!(!html.contains("draggable=\"true\""), "non-admin should not see drag handles");
 }
 
+// ─── Environment protection ─────────────────────────────────────────
+
+#[tokio::test]
+async fn destinations_page_shows_environment_protection() {
+    let (state, sessions) = test_state_with(
+        MockForestClient::new(),
+        MockPlatformClient::with_behavior(MockPlatformBehavior {
+            list_environments_result: Some(Ok(vec![
+                forage_core::platform::Environment {
+                    id: "env-prod".into(),
+                    organisation: "testorg".into(),
+                    name: "prod".into(),
+                    description: None,
+                    sort_order: 0,
+                    created_at: "2026-03-08T00:00:00Z".into(),
+                    protection: forage_core::platform::EnvironmentProtection {
+                        protected: true,
+                        required_prior_environment: Some("staging".into()),
+                        approver_groups: vec!["release-managers".into()],
+                        allowed_branches: vec!["^main$".into()],
+                    },
+                },
+            ])),
+            ..Default::default()
+        }),
+    );
+    let cookie = create_test_session(&sessions).await;
+    let app = build_router(state);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/orgs/testorg/destinations")
+                .header("cookie", &cookie)
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let html = String::from_utf8(body.to_vec()).unwrap();
+    assert!(html.contains(">protected<"), "expected protected badge");
+    assert!(html.contains("release-managers"));
+    assert!(html.contains("^main$"));
+    assert!(html.contains("/destinations/environments/env-prod/protection"));
+}
+
+#[tokio::test]
+async fn update_environment_protection_success_redirects() {
+    let (state, sessions) = test_state();
+    let cookie = create_test_session(&sessions).await;
+    let app = build_router(state);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/orgs/testorg/destinations/environments/env-prod/protection")
+                .header("cookie", &cookie)
+                .header("content-type", "application/x-www-form-urlencoded")
+                .body(Body::from(
+                    "_csrf=test-csrf&protected=true&required_prior_environment=staging\
+                     &approver_groups=sre%2C+release-managers&allowed_branches=%5Emain%24",
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::SEE_OTHER);
+    assert_eq!(
+        response.headers().get("location").unwrap(),
+        "/orgs/testorg/destinations"
+    );
+}
+
+#[tokio::test]
+async fn update_environment_protection_rejected_rules_return_400() {
+    let (state, sessions) = test_state_with(
+        MockForestClient::new(),
+        MockPlatformClient::with_behavior(MockPlatformBehavior {
+            update_environment_result: Some(Err(forage_core::platform::PlatformError::Other(
+                "prior environment 'qa' does not exist".into(),
+            ))),
+            ..Default::default()
+        }),
+    );
+    let cookie = create_test_session(&sessions).await;
+    let app = build_router(state);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/orgs/testorg/destinations/environments/env-prod/protection")
+                .header("cookie", &cookie)
+                .header("content-type", "application/x-www-form-urlencoded")
+                .body(Body::from("_csrf=test-csrf&required_prior_environment=qa"))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let html = String::from_utf8(body.to_vec()).unwrap();
+    assert!(html.contains("does not exist"), "expected forest's validation message");
+}
+
+#[tokio::test]
+async fn update_environment_protection_invalid_csrf_returns_403() {
+    let (state, sessions) = test_state();
+    let cookie = create_test_session(&sessions).await;
+    let app = build_router(state);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/orgs/testorg/destinations/environments/env-prod/protection")
+                .header("cookie", &cookie)
+                .header("content-type", "application/x-www-form-urlencoded")
+                .body(Body::from("_csrf=wrong-token&protected=true"))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn update_environment_protection_non_admin_returns_403() {
+    let (state, sessions) = test_state();
+    let cookie = create_test_session_member(&sessions).await;
+    let app = build_router(state);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/orgs/testorg/destinations/environments/env-prod/protection")
+                .header("cookie", &cookie)
+                .header("content-type", "application/x-www-form-urlencoded")
+                .body(Body::from("_csrf=test-csrf&protected=true"))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
 // ─── Releases ────────────────────────────────────────────────────────
 
 #[tokio::test]
@@ -1889,3 +2102,51 @@ async fn fresh_project_overview_renders_get_started_panel() {
     // And the typical Components/Releases section headers are absent.
     assert!(!html.contains("<h2 class=\"text-lg font-bold\">Components</h2>"));
 }
+
+#[tokio::test]
+async fn cancel_release_redirects_back() {
+    let (state, sessions) = test_state();
+    let cookie = create_test_session(&sessions).await;
+    let app = build_router(state);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/orgs/testorg/projects/my-api/releases/rel-1/cancel")
+                .header("cookie", &cookie)
+                .header("content-type", "application/x-www-form-urlencoded")
+                .body(Body::from(
+                    "csrf_token=test-csrf&redirect_to=/orgs/testorg/projects/my-api/releases/my-api-abc",
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::SEE_OTHER);
+    assert_eq!(
+        response.headers().get("location").unwrap(),
+        "/orgs/testorg/projects/my-api/releases/my-api-abc"
+    );
+}
+
+#[tokio::test]
+async fn cancel_release_requires_csrf() {
+    let (state, sessions) = test_state();
+    let cookie = create_test_session(&sessions).await;
+    let app = build_router(state);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/orgs/testorg/projects/my-api/releases/rel-1/cancel")
+                .header("cookie", &cookie)
+                .header("content-type", "application/x-www-form-urlencoded")
+                .body(Body::from("csrf_token=wrong-token"))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
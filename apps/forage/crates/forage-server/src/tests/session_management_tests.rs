@@ -0,0 +1,239 @@
+use std::sync::Arc;
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use chrono::Utc;
+use forage_core::platform::OrgMember;
+use forage_core::session::{
+    CachedOrg, CachedUser, InMemorySessionStore, SessionData, SessionId, SessionStore,
+};
+use tower::ServiceExt;
+
+use crate::build_router;
+use crate::test_support::*;
+
+/// Create a session for an arbitrary user, returning its id.
+async fn session_for(sessions: &Arc<InMemorySessionStore>, user_id: &str, role: &str) -> SessionId {
+    let now = Utc::now();
+    sessions
+        .create(SessionData {
+            access_token: "mock-access".into(),
+            refresh_token: format!("refresh-{user_id}"),
+            csrf_token: "test-csrf".into(),
+            needs_username: false,
+            access_expires_at: now + chrono::Duration::hours(1),
+            user: Some(CachedUser {
+                user_id: user_id.into(),
+                username: user_id.into(),
+                profile_picture_url: None,
+                emails: vec![],
+                orgs: vec![CachedOrg {
+                    organisation_id: "org-1".into(),
+                    name: "testorg".into(),
+                    role: role.into(),
+                }],
+            }),
+            created_at: now - chrono::Duration::days(2),
+            last_seen_at: now - chrono::Duration::hours(3),
+        })
+        .await
+        .unwrap()
+}
+
+fn platform_with_members(members: Vec<OrgMember>) -> MockPlatformClient {
+    MockPlatformClient::with_behavior(MockPlatformBehavior {
+        list_members_result: Some(Ok(members)),
+        ..Default::default()
+    })
+}
+
+fn member(user_id: &str, role: &str) -> OrgMember {
+    OrgMember {
+        user_id: user_id.into(),
+        username: user_id.into(),
+        role: role.into(),
+        joined_at: None,
+    }
+}
+
+fn post(uri: &str, cookie: &str, body: &str) -> Request<Body> {
+    Request::builder()
+        .method("POST")
+        .uri(uri)
+        .header("cookie", cookie)
+        .header("content-type", "application/x-www-form-urlencoded")
+        .body(Body::from(body.to_string()))
+        .unwrap()
+}
+
+// ─── Account page ───────────────────────────────────────────────────
+
+#[tokio::test]
+async fn account_page_lists_sessions() {
+    let (state, sessions) = test_state();
+    let cookie = create_test_session(&sessions).await;
+    session_for(&sessions, "user-123", "owner").await;
+    let app = build_router(state);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/settings/account")
+                .header("cookie", &cookie)
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let html = String::from_utf8(body.to_vec()).unwrap();
+    assert!(html.contains("Sessions"));
+    assert_eq!(html.matches("This session").count(), 1);
+    assert!(html.contains("Sign out other sessions"));
+    // Session ids are credentials and never rendered.
+    let own_id = cookie.trim_start_matches("forage_session=");
+    assert!(!html.contains(own_id));
+}
+
+#[tokio::test]
+async fn sign_out_other_sessions_keeps_current() {
+    let (state, sessions) = test_state();
+    let cookie = create_test_session(&sessions).await;
+    let other = session_for(&sessions, "user-123", "owner").await;
+    let someone_else = session_for(&sessions, "user-456", "member").await;
+    let app = build_router(state);
+
+    let response = app
+        .oneshot(post(
+            "/settings/account/sessions/sign-out-others",
+            &cookie,
+            "_csrf=test-csrf",
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::SEE_OTHER);
+    assert_eq!(
+        response.headers().get("location").unwrap(),
+        "/settings/account?flash=signed_out_others"
+    );
+
+    let current = SessionId::from_raw(cookie.trim_start_matches("forage_session=").into());
+    assert!(sessions.get(&current).await.unwrap().is_some());
+    assert!(sessions.get(&other).await.unwrap().is_none());
+    assert!(sessions.get(&someone_else).await.unwrap().is_some());
+}
+
+#[tokio::test]
+async fn sign_out_other_sessions_requires_csrf() {
+    let (state, sessions) = test_state();
+    let cookie = create_test_session(&sessions).await;
+    let other = session_for(&sessions, "user-123", "owner").await;
+    let app = build_router(state);
+
+    let response = app
+        .oneshot(post(
+            "/settings/account/sessions/sign-out-others",
+            &cookie,
+            "_csrf=wrong",
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    assert!(sessions.get(&other).await.unwrap().is_some());
+}
+
+// ─── Admin revoke ───────────────────────────────────────────────────
+
+#[tokio::test]
+async fn admin_signs_member_out_everywhere() {
+    let (state, sessions) = test_state_with(
+        MockForestClient::new(),
+        platform_with_members(vec![member("user-123", "owner"), member("user-456", "member")]),
+    );
+    let cookie = create_test_session(&sessions).await;
+    let laptop = session_for(&sessions, "user-456", "member").await;
+    let phone = session_for(&sessions, "user-456", "member").await;
+    let app = build_router(state);
+
+    let response = app
+        .oneshot(post(
+            "/orgs/testorg/settings/members/user-456/sign-out",
+            &cookie,
+            "_csrf=test-csrf",
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::SEE_OTHER);
+    assert_eq!(
+        response.headers().get("location").unwrap(),
+        "/orgs/testorg/settings/members?signed_out=user-456"
+    );
+    assert!(sessions.get(&laptop).await.unwrap().is_none());
+    assert!(sessions.get(&phone).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn sign_out_rejects_non_member() {
+    let (state, sessions) = test_state();
+    let cookie = create_test_session(&sessions).await;
+    let outsider = session_for(&sessions, "user-999", "member").await;
+    let app = build_router(state);
+
+    let response = app
+        .oneshot(post(
+            "/orgs/testorg/settings/members/user-999/sign-out",
+            &cookie,
+            "_csrf=test-csrf",
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert!(sessions.get(&outsider).await.unwrap().is_some());
+}
+
+#[tokio::test]
+async fn sign_out_requires_admin() {
+    let (state, sessions) = test_state_with(
+        MockForestClient::new(),
+        platform_with_members(vec![member("user-123", "member"), member("user-456", "member")]),
+    );
+    let cookie = create_test_session_member(&sessions).await;
+    let target = session_for(&sessions, "user-456", "member").await;
+    let app = build_router(state);
+
+    let response = app
+        .oneshot(post(
+            "/orgs/testorg/settings/members/user-456/sign-out",
+            &cookie,
+            "_csrf=test-csrf",
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    assert!(sessions.get(&target).await.unwrap().is_some());
+}
+
+#[tokio::test]
+async fn admin_cannot_sign_out_owner() {
+    let (state, sessions) = test_state_with(
+        MockForestClient::new(),
+        platform_with_members(vec![member("user-123", "admin"), member("user-1", "owner")]),
+    );
+    let admin = session_for(&sessions, "user-123", "admin").await;
+    let owner = session_for(&sessions, "user-1", "owner").await;
+    let app = build_router(state);
+
+    let response = app
+        .oneshot(post(
+            "/orgs/testorg/settings/members/user-1/sign-out",
+            &format!("forage_session={admin}"),
+            "_csrf=test-csrf",
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    assert!(sessions.get(&owner).await.unwrap().is_some());
+}
@@ -0,0 +1,283 @@
+use std::sync::{Arc, Mutex};
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use forage_core::integrations::slack::{
+    approval_action_blocks, sign_slack_request, SlackApprovalTarget, APPROVE_ACTION_ID,
+    REJECT_ACTION_ID,
+};
+use forage_core::integrations::{IntegrationStore, SlackUserLink};
+use forage_core::platform::PlatformError;
+use tower::ServiceExt;
+
+use crate::state::SlackConfig;
+use crate::test_support::*;
+
+const SIGNING_SECRET: &str = "test-signing-secret";
+
+fn build_app(platform: MockPlatformClient) -> (axum::Router, Arc<forage_core::integrations::InMemoryIntegrationStore>) {
+    let (state, _, integrations) = test_state_with_integrations(MockForestClient::new(), platform);
+    let state = state
+        .with_service_account_key("service-key".into())
+        .with_forage_host("https://forage.example.com".into())
+        .with_slack_config(SlackConfig {
+            client_id: "cid".into(),
+            client_secret: "csecret".into(),
+            redirect_host: "https://forage.example.com".into(),
+            signing_secret: Some(SIGNING_SECRET.into()),
+        });
+    (crate::build_router(state), integrations)
+}
+
+/// Capture what the handler posts back to Slack's `response_url`.
+async fn start_response_url() -> (String, Arc<Mutex<Vec<serde_json::Value>>>) {
+    let received: Arc<Mutex<Vec<serde_json::Value>>> = Arc::default();
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/respond", listener.local_addr().unwrap());
+
+    let sink = received.clone();
+    let app = axum::Router::new().route(
+        "/respond",
+        axum::routing::post(move |axum::Json(body): axum::Json<serde_json::Value>| {
+            let sink = sink.clone();
+            async move {
+                sink.lock().unwrap().push(body);
+                StatusCode::OK
+            }
+        }),
+    );
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+    (url, received)
+}
+
+fn plan_target() -> SlackApprovalTarget {
+    SlackApprovalTarget::PlanStage {
+        organisation: "testorg".into(),
+        project: "my-api".into(),
+        release_intent_id: "ri-1".into(),
+        stage_id: "plan-prod".into(),
+        environment: "prod".into(),
+    }
+}
+
+fn interaction_payload(action_id: &str, response_url: &str) -> serde_json::Value {
+    let target = plan_target();
+    let mut blocks = vec![serde_json::json!({
+        "type": "section",
+        "text": { "type": "mrkdwn", "text": ":shield:  Awaiting plan approval for `prod`" }
+    })];
+    blocks.extend(approval_action_blocks(std::slice::from_ref(&target)));
+    serde_json::json!({
+        "type": "block_actions",
+        "user": { "id": "U123" },
+        "team": { "id": "T1" },
+        "response_url": response_url,
+        "actions": [{
+            "action_id": action_id,
+            "block_id": target.block_id(),
+            "value": serde_json::to_string(&target).unwrap(),
+        }],
+        "message": {
+            "text": "Release update: testorg/my-api",
+            "attachments": [{ "color": "#0d6efd", "blocks": blocks }],
+        },
+    })
+}
+
+fn signed_request(payload: &serde_json::Value, secret: &str) -> Request<Body> {
+    let body = format!(
+        "payload={}",
+        urlencoding::encode(&payload.to_string())
+    );
+    let timestamp = chrono::Utc::now().timestamp().to_string();
+    let signature = sign_slack_request(secret, &timestamp, body.as_bytes());
+    Request::builder()
+        .method("POST")
+        .uri("/integrations/slack/interactivity")
+        .header("content-type", "application/x-www-form-urlencoded")
+        .header("x-slack-request-timestamp", timestamp)
+        .header("x-slack-signature", signature)
+        .body(Body::from(body))
+        .unwrap()
+}
+
+async fn link_slack_user(store: &forage_core::integrations::InMemoryIntegrationStore) {
+    store
+        .upsert_slack_user_link(&SlackUserLink {
+            id: uuid::Uuid::new_v4().to_string(),
+            user_id: "user-123".into(),
+            team_id: "T1".into(),
+            team_name: "Acme".into(),
+            slack_user_id: "U123".into(),
+            slack_username: "alice".into(),
+            created_at: chrono::Utc::now().to_rfc3339(),
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn interactivity_returns_503_without_signing_secret() {
+    let (state, _, _) =
+        test_state_with_integrations(MockForestClient::new(), MockPlatformClient::new());
+    let app = crate::build_router(state);
+
+    let resp = app
+        .oneshot(signed_request(
+            &interaction_payload(APPROVE_ACTION_ID, "http://127.0.0.1:1/respond"),
+            SIGNING_SECRET,
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+}
+
+#[tokio::test]
+async fn interactivity_rejects_bad_signature() {
+    let (app, integrations) = build_app(MockPlatformClient::new());
+    link_slack_user(&integrations).await;
+    let (url, received) = start_response_url().await;
+
+    let resp = app
+        .oneshot(signed_request(
+            &interaction_payload(APPROVE_ACTION_ID, &url),
+            "wrong-secret",
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+    assert!(received.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn interactivity_prompts_unlinked_user_to_link_account() {
+    let (app, _) = build_app(MockPlatformClient::new());
+    let (url, received) = start_response_url().await;
+
+    let resp = app
+        .oneshot(signed_request(
+            &interaction_payload(APPROVE_ACTION_ID, &url),
+            SIGNING_SECRET,
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(resp.status(), StatusCode::OK);
+    let received = received.lock().unwrap();
+    assert_eq!(received.len(), 1);
+    assert_eq!(received[0]["response_type"], "ephemeral");
+    assert_eq!(received[0]["replace_original"], false);
+    let text = received[0]["text"].as_str().unwrap();
+    assert!(text.contains("https://forage.example.com/settings/account/slack/connect"));
+}
+
+#[tokio::test]
+async fn interactivity_approves_and_records_who_approved() {
+    let (app, integrations) = build_app(MockPlatformClient::new());
+    link_slack_user(&integrations).await;
+    let (url, received) = start_response_url().await;
+
+    let resp = app
+        .oneshot(signed_request(
+            &interaction_payload(APPROVE_ACTION_ID, &url),
+            SIGNING_SECRET,
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(resp.status(), StatusCode::OK);
+    let received = received.lock().unwrap();
+    assert_eq!(received.len(), 1);
+    assert_eq!(received[0]["replace_original"], true);
+    let blocks = received[0]["attachments"][0]["blocks"].as_array().unwrap();
+    assert!(
+        blocks.iter().all(|b| b["type"] != "actions"),
+        "buttons should be replaced once acted on"
+    );
+    let rendered = serde_json::to_string(blocks).unwrap();
+    assert!(rendered.contains("Plan for `prod` approved by <@U123>"));
+}
+
+#[tokio::test]
+async fn interactivity_reject_updates_message() {
+    let (app, integrations) = build_app(MockPlatformClient::new());
+    link_slack_user(&integrations).await;
+    let (url, received) = start_response_url().await;
+
+    app.oneshot(signed_request(
+        &interaction_payload(REJECT_ACTION_ID, &url),
+        SIGNING_SECRET,
+    ))
+    .await
+    .unwrap();
+
+    let received = received.lock().unwrap();
+    let rendered = serde_json::to_string(&received[0]["attachments"]).unwrap();
+    assert!(rendered.contains("Plan for `prod` rejected by <@U123>"));
+}
+
+#[tokio::test]
+async fn interactivity_reports_forest_denial_ephemerally() {
+    let platform = MockPlatformClient::with_behavior(MockPlatformBehavior {
+        approve_plan_stage_result: Some(Err(PlatformError::Other(
+            "permission denied".into(),
+        ))),
+        ..Default::default()
+    });
+    let (app, integrations) = build_app(platform);
+    link_slack_user(&integrations).await;
+    let (url, received) = start_response_url().await;
+
+    let resp = app
+        .oneshot(signed_request(
+            &interaction_payload(APPROVE_ACTION_ID, &url),
+            SIGNING_SECRET,
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(resp.status(), StatusCode::OK);
+    let received = received.lock().unwrap();
+    assert_eq!(received.len(), 1);
+    assert_eq!(received[0]["response_type"], "ephemeral");
+    assert!(received[0]["text"]
+        .as_str()
+        .unwrap()
+        .contains("Could not approve `prod`"));
+}
+
+#[tokio::test]
+async fn interactivity_ignores_unrelated_actions() {
+    let (app, integrations) = build_app(MockPlatformClient::new());
+    link_slack_user(&integrations).await;
+    let (url, received) = start_response_url().await;
+
+    let resp = app
+        .oneshot(signed_request(
+            &interaction_payload("view_release", &url),
+            SIGNING_SECRET,
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert!(received.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn interactivity_rejects_foreign_response_url() {
+    let (app, integrations) = build_app(MockPlatformClient::new());
+    link_slack_user(&integrations).await;
+
+    let resp = app
+        .oneshot(signed_request(
+            &interaction_payload(APPROVE_ACTION_ID, "https://attacker.example.com/x"),
+            SIGNING_SECRET,
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
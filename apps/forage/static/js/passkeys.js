@@ -0,0 +1,125 @@
+/**
+ * WebAuthn passkey ceremonies for plain HTML forms.
+ *
+ * A form opts in with data-passkey="register" | "login" | "verify" and
+ * data-passkey-options (the URL returning {ceremony_id, options}). Clicking
+ * its [data-passkey-start] button fetches options, runs the browser
+ * ceremony, fills the [data-passkey-ceremony] / [data-passkey-credential]
+ * hidden inputs and submits the form, so the server side stays an ordinary
+ * form POST.
+ *
+ * Optional attributes:
+ *   data-passkey-mode="mfa"   sent as `mode` to the options endpoint
+ *
+ * Start buttons are rendered hidden and only revealed when the browser
+ * supports WebAuthn.
+ */
+(function () {
+  if (!window.PublicKeyCredential || !navigator.credentials) return;
+
+  function toBuffer(b64url) {
+    const b64 = b64url.replace(/-/g, "+").replace(/_/g, "/");
+    const padded = b64 + "===".slice((b64.length + 3) % 4);
+    return Uint8Array.from(atob(padded), (c) => c.charCodeAt(0)).buffer;
+  }
+
+  function toBase64url(buffer) {
+    const bytes = new Uint8Array(buffer);
+    let binary = "";
+    for (const b of bytes) binary += String.fromCharCode(b);
+    return btoa(binary).replace(/\+/g, "-").replace(/\//g, "_").replace(/=+$/, "");
+  }
+
+  function decodeCreation(options) {
+    options.challenge = toBuffer(options.challenge);
+    options.user.id = toBuffer(options.user.id);
+    (options.excludeCredentials || []).forEach((c) => (c.id = toBuffer(c.id)));
+    return options;
+  }
+
+  function decodeRequest(options) {
+    options.challenge = toBuffer(options.challenge);
+    (options.allowCredentials || []).forEach((c) => (c.id = toBuffer(c.id)));
+    return options;
+  }
+
+  function encodeCredential(cred) {
+    const r = cred.response;
+    const response = { clientDataJSON: toBase64url(r.clientDataJSON) };
+    if (r.attestationObject) {
+      response.attestationObject = toBase64url(r.attestationObject);
+      response.transports = r.getTransports ? r.getTransports() : [];
+    } else {
+      response.authenticatorData = toBase64url(r.authenticatorData);
+      response.signature = toBase64url(r.signature);
+      if (r.userHandle) response.userHandle = toBase64url(r.userHandle);
+    }
+    return JSON.stringify({
+      id: cred.id,
+      rawId: toBase64url(cred.rawId),
+      type: cred.type,
+      response,
+    });
+  }
+
+  async function fetchOptions(form) {
+    const body = new URLSearchParams();
+    const csrf = form.querySelector('input[name="_csrf"]');
+    if (csrf) body.set("_csrf", csrf.value);
+    if (form.dataset.passkeyMode) body.set("mode", form.dataset.passkeyMode);
+
+    const res = await fetch(form.dataset.passkeyOptions, {
+      method: "POST",
+      credentials: "same-origin",
+      body,
+    });
+    const json = await res.json().catch(() => ({}));
+    if (!res.ok) throw new Error(json.error || "Couldn't start the passkey prompt.");
+    return json;
+  }
+
+  async function run(form, button, errorEl) {
+    errorEl.hidden = true;
+    if (!form.reportValidity()) return;
+    button.disabled = true;
+    try {
+      const { ceremony_id, options } = await fetchOptions(form);
+      const cred =
+        form.dataset.passkey === "register"
+          ? await navigator.credentials.create({ publicKey: decodeCreation(options) })
+          : await navigator.credentials.get({ publicKey: decodeRequest(options) });
+
+      form.querySelector("[data-passkey-ceremony]").value = ceremony_id;
+      form.querySelector("[data-passkey-credential]").value = encodeCredential(cred);
+      // form.submit() skips the clicked button's name/value, so carry it
+      // over (the device page needs action=approve).
+      if (button.name) {
+        const hidden = document.createElement("input");
+        hidden.type = "hidden";
+        hidden.name = button.name;
+        hidden.value = button.value;
+        form.appendChild(hidden);
+      }
+      form.submit();
+    } catch (err) {
+      // NotAllowedError is the user dismissing the prompt; say so plainly.
+      errorEl.textContent =
+        err.name === "NotAllowedError"
+          ? "The passkey prompt was cancelled or timed out."
+          : err.message;
+      errorEl.hidden = false;
+      button.disabled = false;
+    }
+  }
+
+  document.querySelectorAll("form[data-passkey]").forEach((form) => {
+    const button = form.querySelector("[data-passkey-start]");
+    if (!button) return;
+    const errorEl =
+      form.querySelector("[data-passkey-error]") ||
+      form.parentElement.querySelector("[data-passkey-error]");
+
+    button.hidden = false;
+    button.addEventListener("click", () => run(form, button, errorEl));
+  });
+})();
@@ -215,6 +215,11 @@ pub struct CreateDestinationRequest {
     pub r#type: ::core::option::Option<DestinationType>,
     #[prost(string, tag="5")]
     pub organisation: ::prost::alloc::string::String,
+    /// Labels a runner must carry to be assigned this destination's releases.
+    #[prost(map="string, string", tag="6")]
+    pub runner_labels: ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+    #[prost(enumeration="ReleaseQueuePolicy", tag="7")]
+    pub queue_policy: i32,
 }
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct CreateDestinationResponse {
@@ -227,6 +232,18 @@ pub struct UpdateDestinationRequest {
     pub metadata: ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
     #[prost(string, tag="3")]
     pub organisation: ::prost::alloc::string::String,
+    /// When set, replaces the destination's required runner labels.
+    /// Unset leaves them unchanged.
+    #[prost(message, optional, tag="4")]
+    pub runner_labels: ::core::option::Option<RunnerLabels>,
+    /// When set, replaces the destination's release queue policy.
+    #[prost(enumeration="ReleaseQueuePolicy", optional, tag="5")]
+    pub queue_policy: ::core::option::Option<i32>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RunnerLabels {
+    #[prost(map="string, string", tag="1")]
+    pub labels: ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
 }
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct UpdateDestinationResponse {
@@ -259,6 +276,106 @@ pub struct ListDestinationTypesResponse {
     #[prost(message, repeated, tag="1")]
     pub types: ::prost::alloc::vec::Vec<DestinationType>,
 }
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TerraformState {
+    #[prost(string, tag="1")]
+    pub environment: ::prost::alloc::string::String,
+    /// 0 until terraform has written a state.
+    #[prost(int64, tag="2")]
+    pub current_version: i64,
+    #[prost(message, optional, tag="3")]
+    pub lock: ::core::option::Option<TerraformStateLock>,
+    #[prost(string, tag="4")]
+    pub updated_at: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TerraformStateLock {
+    #[prost(string, tag="1")]
+    pub lock_id: ::prost::alloc::string::String,
+    /// As reported by terraform, e.g. `forest@host`.
+    #[prost(string, tag="2")]
+    pub who: ::prost::alloc::string::String,
+    /// e.g. `OperationTypeApply`.
+    #[prost(string, tag="3")]
+    pub operation: ::prost::alloc::string::String,
+    /// Release that took the lock, when known.
+    #[prost(string, optional, tag="4")]
+    pub release_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="5")]
+    pub locked_at: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TerraformStateVersion {
+    #[prost(int64, tag="1")]
+    pub version: i64,
+    #[prost(string, optional, tag="2")]
+    pub release_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Set when this version was created by rolling back to an older one.
+    #[prost(int64, optional, tag="3")]
+    pub rolled_back_from: ::core::option::Option<i64>,
+    #[prost(int64, tag="4")]
+    pub size_bytes: i64,
+    #[prost(string, tag="5")]
+    pub created_at: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ListTerraformStatesRequest {
+    #[prost(string, tag="1")]
+    pub organisation: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub project: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListTerraformStatesResponse {
+    #[prost(message, repeated, tag="1")]
+    pub states: ::prost::alloc::vec::Vec<TerraformState>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ListTerraformStateVersionsRequest {
+    #[prost(string, tag="1")]
+    pub organisation: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub project: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub environment: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListTerraformStateVersionsResponse {
+    /// Newest first.
+    #[prost(message, repeated, tag="1")]
+    pub versions: ::prost::alloc::vec::Vec<TerraformStateVersion>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ForceUnlockTerraformStateRequest {
+    #[prost(string, tag="1")]
+    pub organisation: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub project: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub environment: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ForceUnlockTerraformStateResponse {
+    /// The lock that was dropped; unset when the state wasn't locked.
+    #[prost(message, optional, tag="1")]
+    pub released_lock: ::core::option::Option<TerraformStateLock>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct RollbackTerraformStateRequest {
+    #[prost(string, tag="1")]
+    pub organisation: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub project: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub environment: ::prost::alloc::string::String,
+    #[prost(int64, tag="4")]
+    pub version: i64,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct RollbackTerraformStateResponse {
+    #[prost(message, optional, tag="1")]
+    pub version: ::core::option::Option<TerraformStateVersion>,
+}
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Destination {
     #[prost(string, tag="1")]
@@ -271,6 +388,10 @@ pub struct Destination {
     pub r#type: ::core::option::Option<DestinationType>,
     #[prost(string, tag="5")]
     pub organisation: ::prost::alloc::string::String,
+    #[prost(map="string, string", tag="6")]
+    pub runner_labels: ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+    #[prost(enumeration="ReleaseQueuePolicy", tag="7")]
+    pub queue_policy: i32,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DestinationType {
@@ -300,6 +421,41 @@ pub struct MetadataFieldSchema {
     #[prost(string, tag="6")]
     pub default_value: ::prost::alloc::string::String,
 }
+/// How a destination handles releases that arrive while another release to
+/// it is in flight. Releases to a destination always run one at a time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ReleaseQueuePolicy {
+    /// Treated as WAIT.
+    Unspecified = 0,
+    /// Queued releases run in the order they were requested.
+    Wait = 1,
+    /// A new release cancels releases still waiting in the queue; only the
+    /// latest one runs once the destination is free.
+    Supersede = 2,
+}
+impl ReleaseQueuePolicy {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "RELEASE_QUEUE_POLICY_UNSPECIFIED",
+            Self::Wait => "RELEASE_QUEUE_POLICY_WAIT",
+            Self::Supersede => "RELEASE_QUEUE_POLICY_SUPERSEDE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "RELEASE_QUEUE_POLICY_UNSPECIFIED" => Some(Self::Unspecified),
+            "RELEASE_QUEUE_POLICY_WAIT" => Some(Self::Wait),
+            "RELEASE_QUEUE_POLICY_SUPERSEDE" => Some(Self::Supersede),
+            _ => None,
+        }
+    }
+}
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct Environment {
     #[prost(string, tag="1")]
@@ -314,6 +470,27 @@ pub struct Environment {
     pub sort_order: i32,
     #[prost(string, tag="6")]
     pub created_at: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="7")]
+    pub protection: ::core::option::Option<EnvironmentProtection>,
+}
+/// Rules the server enforces for every project releasing into an
+/// environment, on top of the project's own policies.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct EnvironmentProtection {
+    /// Releases need an approval before they run.
+    #[prost(bool, tag="1")]
+    pub protected: bool,
+    /// An environment the artifact must already have been released to
+    /// successfully, e.g. `staging` before `prod`.
+    #[prost(string, optional, tag="2")]
+    pub required_prior_environment: ::core::option::Option<::prost::alloc::string::String>,
+    /// SCIM group display names. A protected environment with groups set needs
+    /// an approval from a member of each group.
+    #[prost(string, repeated, tag="3")]
+    pub approver_groups: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Branch regexes an artifact's branch must match. Empty allows any branch.
+    #[prost(string, repeated, tag="4")]
+    pub allowed_branches: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct CreateEnvironmentRequest {
@@ -376,6 +553,10 @@ pub struct UpdateEnvironmentRequest {
     pub description: ::core::option::Option<::prost::alloc::string::String>,
     #[prost(int32, optional, tag="3")]
     pub sort_order: ::core::option::Option<i32>,
+    /// When set, replaces the environment's protection rules. Unset leaves them
+    /// unchanged. Changing protection requires the organisation admin role.
+    #[prost(message, optional, tag="4")]
+    pub protection: ::core::option::Option<EnvironmentProtection>,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct UpdateEnvironmentResponse {
@@ -603,6 +784,34 @@ pub struct DeleteResourcesRequest {
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct DeleteResourcesResponse {
 }
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetAppliedResourcesRequest {
+    #[prost(string, tag="1")]
+    pub namespace: ::prost::alloc::string::String,
+    /// Selector labels. Matched against the labels the resources were applied
+    /// with; forage keeps project, destination, environment and region.
+    #[prost(map="string, string", tag="2")]
+    pub labels: ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetAppliedResourcesResponse {
+    #[prost(message, repeated, tag="1")]
+    pub resources: ::prost::alloc::vec::Vec<AppliedResource>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AppliedResource {
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+    /// container_service, job or cron_job.
+    #[prost(string, tag="2")]
+    pub kind: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub image: ::prost::alloc::string::String,
+    #[prost(uint32, tag="4")]
+    pub replicas: u32,
+    #[prost(string, tag="5")]
+    pub status: ::prost::alloc::string::String,
+}
 /// ===========================================================================
 /// Resource envelope — every item in the apply list is one of these.
 /// ===========================================================================
@@ -1930,6 +2139,12 @@ pub enum NotificationType {
     ReleaseStarted = 2,
     ReleaseSucceeded = 3,
     ReleaseFailed = 4,
+    /// A deployed release no longer matches its destination.
+    DriftDetected = 5,
+    /// A drifted destination matches its release again.
+    DriftResolved = 6,
+    /// A release schedule could not start its release.
+    ScheduleFailed = 7,
 }
 impl NotificationType {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -1943,6 +2158,9 @@ impl NotificationType {
             Self::ReleaseStarted => "NOTIFICATION_TYPE_RELEASE_STARTED",
             Self::ReleaseSucceeded => "NOTIFICATION_TYPE_RELEASE_SUCCEEDED",
             Self::ReleaseFailed => "NOTIFICATION_TYPE_RELEASE_FAILED",
+            Self::DriftDetected => "NOTIFICATION_TYPE_DRIFT_DETECTED",
+            Self::DriftResolved => "NOTIFICATION_TYPE_DRIFT_RESOLVED",
+            Self::ScheduleFailed => "NOTIFICATION_TYPE_SCHEDULE_FAILED",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -1953,6 +2171,9 @@ impl NotificationType {
             "NOTIFICATION_TYPE_RELEASE_STARTED" => Some(Self::ReleaseStarted),
             "NOTIFICATION_TYPE_RELEASE_SUCCEEDED" => Some(Self::ReleaseSucceeded),
             "NOTIFICATION_TYPE_RELEASE_FAILED" => Some(Self::ReleaseFailed),
+            "NOTIFICATION_TYPE_DRIFT_DETECTED" => Some(Self::DriftDetected),
+            "NOTIFICATION_TYPE_DRIFT_RESOLVED" => Some(Self::DriftResolved),
+            "NOTIFICATION_TYPE_SCHEDULE_FAILED" => Some(Self::ScheduleFailed),
             _ => None,
         }
     }
@@ -2070,6 +2291,9 @@ pub struct OrganisationMember {
     #[prost(message, optional, tag="4")]
     pub joined_at: ::core::option::Option<::prost_types::Timestamp>,
 }
+/// Requires an org admin. Service-account callers (forage SSO just-in-time
+/// provisioning) skip the admin check; for them the call is idempotent and
+/// leaves an existing membership's role untouched.
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct AddMemberRequest {
     #[prost(string, tag="1")]
@@ -2126,6 +2350,98 @@ pub struct ListMembersResponse {
     #[prost(int32, tag="3")]
     pub total_count: i32,
 }
+// ─── SCIM group → role mappings ─────────────────────────────────────
+// Members provisioned over SCIM get their organisation role from the IdP
+// groups they belong to: admin if any of their groups maps to admin,
+// member otherwise. With no mappings, roles are managed by hand.
+
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ScimGroupRole {
+    #[prost(string, tag="1")]
+    pub group_name: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub role: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="3")]
+    pub created_at: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// Forage owns single sign-on configuration and mirrors its enforcement
+/// flag here. While set, members can only sign in through the
+/// organisation's identity provider. Requires organisation admin.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SetSsoEnforcementRequest {
+    #[prost(string, tag="1")]
+    pub organisation_id: ::prost::alloc::string::String,
+    #[prost(bool, tag="2")]
+    pub enforced: bool,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SetSsoEnforcementResponse {
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SetScimGroupRoleRequest {
+    #[prost(string, tag="1")]
+    pub organisation_id: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub group_name: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub role: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SetScimGroupRoleResponse {
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ListScimGroupRolesRequest {
+    #[prost(string, tag="1")]
+    pub organisation_id: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListScimGroupRolesResponse {
+    #[prost(message, repeated, tag="1")]
+    pub group_roles: ::prost::alloc::vec::Vec<ScimGroupRole>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DeleteScimGroupRoleRequest {
+    #[prost(string, tag="1")]
+    pub organisation_id: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub group_name: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DeleteScimGroupRoleResponse {
+}
+// ─── SCIM invites ───────────────────────────────────────────────────
+// When an organisation's IdP provisions an account that already exists,
+// and the account's email is not verified on a domain the organisation
+// has proven, the account owner must accept before becoming a member.
+
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct OrganisationInvite {
+    #[prost(string, tag="1")]
+    pub organisation_id: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub organisation: ::prost::alloc::string::String,
+    /// The userName the IdP provisioned the account under.
+    #[prost(string, tag="3")]
+    pub user_name: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="4")]
+    pub created_at: ::core::option::Option<::prost_types::Timestamp>,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ListMyInvitesRequest {
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListMyInvitesResponse {
+    #[prost(message, repeated, tag="1")]
+    pub invites: ::prost::alloc::vec::Vec<OrganisationInvite>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AcceptInviteRequest {
+    #[prost(string, tag="1")]
+    pub organisation_id: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AcceptInviteResponse {
+}
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AnnotateReleaseRequest {
     #[prost(string, tag="1")]
@@ -2440,7 +2756,7 @@ pub struct ReleaseIntentState {
     pub steps: ::prost::alloc::vec::Vec<ReleaseStepState>,
 }
 /// Status of a single pipeline stage (saga coordinator view).
-#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PipelineStageState {
     #[prost(string, tag="1")]
     pub stage_id: ::prost::alloc::string::String,
@@ -2479,6 +2795,30 @@ pub struct PipelineStageState {
     /// plan stages
     #[prost(bool, optional, tag="14")]
     pub auto_approve: ::core::option::Option<bool>,
+    /// batched deploy stages, in rollout order
+    #[prost(message, repeated, tag="15")]
+    pub batches: ::prost::alloc::vec::Vec<PipelineStageBatch>,
+}
+/// Progress of one batch of a batched deploy stage.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PipelineStageBatch {
+    #[prost(string, repeated, tag="1")]
+    pub destinations: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(enumeration="PipelineRunStageStatus", tag="2")]
+    pub status: i32,
+    #[prost(string, optional, tag="3")]
+    pub started_at: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="4")]
+    pub completed_at: ::core::option::Option<::prost::alloc::string::String>,
+    /// When the bake after this batch ends and the next batch may start.
+    #[prost(string, optional, tag="5")]
+    pub wait_until: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, repeated, tag="6")]
+    pub release_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="7")]
+    pub error_message: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(bool, tag="8")]
+    pub health_check: bool,
 }
 /// Status of a single release step (release_states row).
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
@@ -2503,6 +2843,16 @@ pub struct ReleaseStepState {
     pub completed_at: ::core::option::Option<::prost::alloc::string::String>,
     #[prost(string, optional, tag="10")]
     pub error_message: ::core::option::Option<::prost::alloc::string::String>,
+    /// Whether the destination still matches this release: IN_SYNC, DRIFTED
+    /// or ERROR. Only set on the release currently deployed to the
+    /// destination, once the drift detector has checked it.
+    #[prost(string, optional, tag="11")]
+    pub drift_status: ::core::option::Option<::prost::alloc::string::String>,
+    /// What differs (DRIFTED) or why the check failed (ERROR).
+    #[prost(string, optional, tag="12")]
+    pub drift_detail: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="13")]
+    pub drift_checked_at: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct DestinationState {
@@ -2537,6 +2887,10 @@ pub struct DestinationState {
     /// When the runner actually started executing.
     #[prost(string, optional, tag="14")]
     pub started_at: ::core::option::Option<::prost::alloc::string::String>,
+    /// The destination's queue policy, so callers can tell whether queued
+    /// releases will run in turn or be superseded.
+    #[prost(enumeration="ReleaseQueuePolicy", tag="15")]
+    pub queue_policy: i32,
 }
 // ── Pipeline run progress ────────────────────────────────────────────
 
@@ -2553,7 +2907,7 @@ pub struct PipelineRunState {
     pub stages: ::prost::alloc::vec::Vec<PipelineRunStage>,
 }
 /// Status of a single stage within a pipeline run.
-#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PipelineRunStage {
     #[prost(string, tag="1")]
     pub stage_id: ::prost::alloc::string::String,
@@ -2591,6 +2945,9 @@ pub struct PipelineRunStage {
     /// plan stages
     #[prost(bool, optional, tag="14")]
     pub auto_approve: ::core::option::Option<bool>,
+    /// batched deploy stages, in rollout order
+    #[prost(message, repeated, tag="15")]
+    pub batches: ::prost::alloc::vec::Vec<PipelineStageBatch>,
 }
 // ── Plan stage approval ──────────────────────────────────────────────
 
@@ -2600,6 +2957,10 @@ pub struct ApprovePlanStageRequest {
     pub release_intent_id: ::prost::alloc::string::String,
     #[prost(string, tag="2")]
     pub stage_id: ::prost::alloc::string::String,
+    /// Service accounts only: approve as this user (e.g. a Slack button click
+    /// relayed by forage). The user's own permissions are checked.
+    #[prost(string, optional, tag="3")]
+    pub on_behalf_of_user_id: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct ApprovePlanStageResponse {
@@ -2612,6 +2973,9 @@ pub struct RejectPlanStageRequest {
     pub stage_id: ::prost::alloc::string::String,
     #[prost(string, optional, tag="3")]
     pub reason: ::core::option::Option<::prost::alloc::string::String>,
+    /// Service accounts only: reject as this user.
+    #[prost(string, optional, tag="4")]
+    pub on_behalf_of_user_id: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct RejectPlanStageResponse {
@@ -2646,6 +3010,22 @@ pub struct PlanDestinationOutput {
     #[prost(string, tag="4")]
     pub status: ::prost::alloc::string::String,
 }
+/// Cancel a release. A queued release leaves its destination's queue; an
+/// assigned or running one is stopped on the runner executing it. Pipeline
+/// stages that depend on the release are cancelled too.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct CancelReleaseRequest {
+    #[prost(string, tag="1")]
+    pub release_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="2")]
+    pub reason: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct CancelReleaseResponse {
+    /// Status of the release after the request (CANCELLED on success).
+    #[prost(string, tag="1")]
+    pub status: ::prost::alloc::string::String,
+}
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct Source {
     #[prost(string, optional, tag="1")]
@@ -3088,6 +3468,10 @@ pub struct ExternalApproveReleaseRequest {
     pub comment: ::core::option::Option<::prost::alloc::string::String>,
     #[prost(bool, tag="5")]
     pub force_bypass: bool,
+    /// Service accounts only: approve as this user. Policy checks, including
+    /// the self-approval rule, apply to that user.
+    #[prost(string, optional, tag="6")]
+    pub on_behalf_of_user_id: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ExternalApproveReleaseResponse {
@@ -3104,6 +3488,9 @@ pub struct ExternalRejectReleaseRequest {
     pub target_environment: ::prost::alloc::string::String,
     #[prost(string, optional, tag="4")]
     pub comment: ::core::option::Option<::prost::alloc::string::String>,
+    /// Service accounts only: reject as this user.
+    #[prost(string, optional, tag="5")]
+    pub on_behalf_of_user_id: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ExternalRejectReleaseResponse {
@@ -3545,31 +3932,211 @@ impl ComponentShape {
         }
     }
 }
-// ── Per-type config messages ─────────────────────────────────────────
+// ── List ─────────────────────────────────────────────────────────────
 
+/// Releases newest first. Every filter is optional and they combine with AND.
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
-pub struct DeployStageConfig {
+pub struct ListReleasesRequest {
     #[prost(string, tag="1")]
-    pub environment: ::prost::alloc::string::String,
-}
-#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
-pub struct WaitStageConfig {
-    #[prost(int64, tag="1")]
-    pub duration_seconds: i64,
+    pub organisation: ::prost::alloc::string::String,
+    /// All projects in the organisation when unset.
+    #[prost(string, optional, tag="2")]
+    pub project: ::core::option::Option<::prost::alloc::string::String>,
+    /// Releases with at least one deploy step to this environment.
+    #[prost(string, optional, tag="3")]
+    pub environment: ::core::option::Option<::prost::alloc::string::String>,
+    /// Git branch recorded on the annotation.
+    #[prost(string, optional, tag="4")]
+    pub branch: ::core::option::Option<::prost::alloc::string::String>,
+    /// Source username or email recorded on the annotation (case-insensitive).
+    #[prost(string, optional, tag="5")]
+    pub author: ::core::option::Option<::prost::alloc::string::String>,
+    /// Releases with at least one deploy step in this status, e.g. FAILED.
+    #[prost(string, optional, tag="6")]
+    pub status: ::core::option::Option<::prost::alloc::string::String>,
+    /// RFC 3339 bounds on when the release was created. `since` is
+    /// inclusive, `until` exclusive.
+    #[prost(string, optional, tag="7")]
+    pub since: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="8")]
+    pub until: ::core::option::Option<::prost::alloc::string::String>,
+    /// Defaults to 20, capped at 100.
+    #[prost(int32, tag="9")]
+    pub page_size: i32,
+    /// Opaque cursor from a previous response's next_page_token.
+    #[prost(string, tag="10")]
+    pub page_token: ::prost::alloc::string::String,
 }
-#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
-pub struct PlanStageConfig {
-    #[prost(string, tag="1")]
-    pub environment: ::prost::alloc::string::String,
-    #[prost(bool, tag="2")]
-    pub auto_approve: bool,
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListReleasesResponse {
+    #[prost(message, repeated, tag="1")]
+    pub releases: ::prost::alloc::vec::Vec<ReleaseHistoryEntry>,
+    /// Empty on the last page.
+    #[prost(string, tag="2")]
+    pub next_page_token: ::prost::alloc::string::String,
 }
-// ── A single pipeline stage ──────────────────────────────────────────
-
-#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
-pub struct PipelineStage {
+/// One release: a request to deploy an annotated artifact.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReleaseHistoryEntry {
     #[prost(string, tag="1")]
-    pub id: ::prost::alloc::string::String,
+    pub release_intent_id: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub slug: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub artifact_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="4")]
+    pub project: ::core::option::Option<Project>,
+    #[prost(message, optional, tag="5")]
+    pub context: ::core::option::Option<ArtifactContext>,
+    #[prost(message, optional, tag="6")]
+    pub source: ::core::option::Option<Source>,
+    #[prost(message, optional, tag="7")]
+    pub r#ref: ::core::option::Option<Ref>,
+    /// Current status of each deploy step.
+    #[prost(message, repeated, tag="8")]
+    pub destinations: ::prost::alloc::vec::Vec<ReleaseDestinationStatus>,
+    #[prost(string, tag="9")]
+    pub created_at: ::prost::alloc::string::String,
+}
+// ── Show ─────────────────────────────────────────────────────────────
+
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GetReleaseRequest {
+    #[prost(string, tag="1")]
+    pub slug: ::prost::alloc::string::String,
+    /// Step logs can be large, so they are only returned on request.
+    #[prost(bool, tag="2")]
+    pub include_logs: bool,
+    /// The project the release belongs to. Access is checked against it
+    /// before the slug is looked up.
+    #[prost(string, tag="3")]
+    pub organisation: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub project: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetReleaseResponse {
+    #[prost(message, optional, tag="1")]
+    pub artifact: ::core::option::Option<Artifact>,
+    /// Every step of every release of the artifact, oldest first.
+    #[prost(message, repeated, tag="2")]
+    pub attempts: ::prost::alloc::vec::Vec<ReleaseAttempt>,
+    /// Policies as they evaluate now, per environment the artifact was
+    /// released to.
+    #[prost(message, repeated, tag="3")]
+    pub policies: ::prost::alloc::vec::Vec<EnvironmentPolicyEvaluations>,
+}
+/// A single deploy or plan step on one destination (a release_states row).
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReleaseAttempt {
+    #[prost(string, tag="1")]
+    pub release_id: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub release_intent_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="3")]
+    pub stage_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="4")]
+    pub destination: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub environment: ::prost::alloc::string::String,
+    /// "deploy" or "plan"
+    #[prost(string, tag="6")]
+    pub mode: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub status: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="8")]
+    pub error_message: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="9")]
+    pub queued_at: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="10")]
+    pub assigned_at: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="11")]
+    pub started_at: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="12")]
+    pub completed_at: ::core::option::Option<::prost::alloc::string::String>,
+    /// Status transitions, oldest first.
+    #[prost(message, repeated, tag="13")]
+    pub events: ::prost::alloc::vec::Vec<ReleaseAttemptEvent>,
+    /// Only set when include_logs is true.
+    #[prost(message, repeated, tag="14")]
+    pub logs: ::prost::alloc::vec::Vec<ReleaseLogLine>,
+    /// Latest health reported for the destination, deploy steps only.
+    #[prost(string, optional, tag="15")]
+    pub health_status: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="16")]
+    pub health_message: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ReleaseAttemptEvent {
+    /// e.g. release.requested, release.started, release.failed
+    #[prost(string, tag="1")]
+    pub event_type: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub created_at: ::prost::alloc::string::String,
+    /// Error message or cancellation reason, when the event carries one.
+    #[prost(string, optional, tag="3")]
+    pub message: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EnvironmentPolicyEvaluations {
+    #[prost(string, tag="1")]
+    pub environment: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag="2")]
+    pub evaluations: ::prost::alloc::vec::Vec<PolicyEvaluation>,
+    #[prost(bool, tag="3")]
+    pub all_passed: bool,
+}
+// ── Per-type config messages ─────────────────────────────────────────
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeployStageConfig {
+    #[prost(string, tag="1")]
+    pub environment: ::prost::alloc::string::String,
+    /// Release to the environment's destinations in these batches, in order.
+    /// Destinations no batch selects form a final batch. Empty releases to
+    /// all destinations at once.
+    #[prost(message, repeated, tag="2")]
+    pub batches: ::prost::alloc::vec::Vec<DeployBatch>,
+}
+/// One batch of a deploy stage's rollout. Set exactly one of destinations,
+/// labels or percentage.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeployBatch {
+    /// Destinations by name.
+    #[prost(string, repeated, tag="1")]
+    pub destinations: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Destinations whose metadata has all of these values.
+    #[prost(map="string, string", tag="2")]
+    pub labels: ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+    /// This share of the environment's destinations, rounded up.
+    #[prost(uint32, optional, tag="3")]
+    pub percentage: ::core::option::Option<u32>,
+    /// Seconds to wait after this batch succeeds before the next starts.
+    #[prost(int64, tag="4")]
+    pub wait_seconds: i64,
+    /// Wait for this batch's destinations to report healthy before the next
+    /// starts; an unhealthy destination halts the rollout.
+    #[prost(bool, tag="5")]
+    pub health_check: bool,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct WaitStageConfig {
+    #[prost(int64, tag="1")]
+    pub duration_seconds: i64,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PlanStageConfig {
+    #[prost(string, tag="1")]
+    pub environment: ::prost::alloc::string::String,
+    #[prost(bool, tag="2")]
+    pub auto_approve: bool,
+}
+// ── A single pipeline stage ──────────────────────────────────────────
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PipelineStage {
+    #[prost(string, tag="1")]
+    pub id: ::prost::alloc::string::String,
     #[prost(string, repeated, tag="2")]
     pub depends_on: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
     #[prost(oneof="pipeline_stage::Config", tags="10, 11, 12")]
@@ -3577,7 +4144,7 @@ pub struct PipelineStage {
 }
 /// Nested message and enum types in `PipelineStage`.
 pub mod pipeline_stage {
-    #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Config {
         #[prost(message, tag="10")]
         Deploy(super::DeployStageConfig),
@@ -3736,6 +4303,263 @@ impl PipelineStageStatus {
         }
     }
 }
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ReleaseSchedule {
+    #[prost(string, tag="1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub project: ::core::option::Option<Project>,
+    /// Exactly one of run_at (RFC 3339) or cron (five fields, UTC) is set.
+    #[prost(string, optional, tag="3")]
+    pub run_at: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="4")]
+    pub cron: ::core::option::Option<::prost::alloc::string::String>,
+    /// The artifact released on every run. When unset, the project's latest
+    /// artifact, from `branch` if set, is picked when the schedule runs.
+    #[prost(string, optional, tag="5")]
+    pub artifact_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="6")]
+    pub branch: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, repeated, tag="7")]
+    pub environments: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, repeated, tag="8")]
+    pub destinations: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(bool, tag="9")]
+    pub force: bool,
+    #[prost(bool, tag="10")]
+    pub use_pipeline: bool,
+    #[prost(enumeration="ReleaseScheduleStatus", tag="11")]
+    pub status: i32,
+    /// RFC 3339; unset once the schedule is completed or cancelled.
+    #[prost(string, optional, tag="12")]
+    pub next_run_at: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(message, optional, tag="13")]
+    pub last_run: ::core::option::Option<ReleaseScheduleRun>,
+    /// "type:id" of the actor the schedule releases as.
+    #[prost(string, tag="14")]
+    pub created_by: ::prost::alloc::string::String,
+    #[prost(string, tag="15")]
+    pub created_at: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ReleaseScheduleRun {
+    #[prost(string, tag="1")]
+    pub ran_at: ::prost::alloc::string::String,
+    #[prost(enumeration="ReleaseScheduleOutcome", tag="2")]
+    pub outcome: i32,
+    #[prost(string, tag="3")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="4")]
+    pub release_intent_id: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct CreateReleaseScheduleRequest {
+    #[prost(message, optional, tag="1")]
+    pub project: ::core::option::Option<Project>,
+    #[prost(string, optional, tag="2")]
+    pub run_at: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="3")]
+    pub cron: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="4")]
+    pub artifact_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="5")]
+    pub branch: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, repeated, tag="6")]
+    pub environments: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, repeated, tag="7")]
+    pub destinations: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(bool, tag="8")]
+    pub force: bool,
+    #[prost(bool, tag="9")]
+    pub use_pipeline: bool,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct CreateReleaseScheduleResponse {
+    #[prost(message, optional, tag="1")]
+    pub schedule: ::core::option::Option<ReleaseSchedule>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ListReleaseSchedulesRequest {
+    #[prost(message, optional, tag="1")]
+    pub project: ::core::option::Option<Project>,
+    /// Also list completed and cancelled schedules.
+    #[prost(bool, tag="2")]
+    pub include_inactive: bool,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListReleaseSchedulesResponse {
+    #[prost(message, repeated, tag="1")]
+    pub schedules: ::prost::alloc::vec::Vec<ReleaseSchedule>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct CancelReleaseScheduleRequest {
+    #[prost(message, optional, tag="1")]
+    pub project: ::core::option::Option<Project>,
+    #[prost(string, tag="2")]
+    pub id: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct CancelReleaseScheduleResponse {
+    #[prost(message, optional, tag="1")]
+    pub schedule: ::core::option::Option<ReleaseSchedule>,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ReleaseScheduleStatus {
+    Unspecified = 0,
+    Active = 1,
+    /// A one-off schedule that has run.
+    Completed = 2,
+    Cancelled = 3,
+}
+impl ReleaseScheduleStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "RELEASE_SCHEDULE_STATUS_UNSPECIFIED",
+            Self::Active => "RELEASE_SCHEDULE_STATUS_ACTIVE",
+            Self::Completed => "RELEASE_SCHEDULE_STATUS_COMPLETED",
+            Self::Cancelled => "RELEASE_SCHEDULE_STATUS_CANCELLED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "RELEASE_SCHEDULE_STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+            "RELEASE_SCHEDULE_STATUS_ACTIVE" => Some(Self::Active),
+            "RELEASE_SCHEDULE_STATUS_COMPLETED" => Some(Self::Completed),
+            "RELEASE_SCHEDULE_STATUS_CANCELLED" => Some(Self::Cancelled),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ReleaseScheduleOutcome {
+    Unspecified = 0,
+    Released = 1,
+    /// There was no artifact to release.
+    Skipped = 2,
+    /// A policy, or the creator's access, stopped the release.
+    Blocked = 3,
+    Failed = 4,
+}
+impl ReleaseScheduleOutcome {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "RELEASE_SCHEDULE_OUTCOME_UNSPECIFIED",
+            Self::Released => "RELEASE_SCHEDULE_OUTCOME_RELEASED",
+            Self::Skipped => "RELEASE_SCHEDULE_OUTCOME_SKIPPED",
+            Self::Blocked => "RELEASE_SCHEDULE_OUTCOME_BLOCKED",
+            Self::Failed => "RELEASE_SCHEDULE_OUTCOME_FAILED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "RELEASE_SCHEDULE_OUTCOME_UNSPECIFIED" => Some(Self::Unspecified),
+            "RELEASE_SCHEDULE_OUTCOME_RELEASED" => Some(Self::Released),
+            "RELEASE_SCHEDULE_OUTCOME_SKIPPED" => Some(Self::Skipped),
+            "RELEASE_SCHEDULE_OUTCOME_BLOCKED" => Some(Self::Blocked),
+            "RELEASE_SCHEDULE_OUTCOME_FAILED" => Some(Self::Failed),
+            _ => None,
+        }
+    }
+}
+/// Unset fields keep everything of that kind.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct RetentionPolicy {
+    #[prost(string, tag="1")]
+    pub organisation: ::prost::alloc::string::String,
+    /// Artifacts to keep per project, and per environment within it.
+    /// Artifacts currently deployed or mid-release are always kept.
+    #[prost(int32, optional, tag="2")]
+    pub keep_artifacts: ::core::option::Option<i32>,
+    /// Days to keep release logs and health observations.
+    #[prost(int32, optional, tag="3")]
+    pub log_retention_days: ::core::option::Option<i32>,
+    #[prost(int32, optional, tag="4")]
+    pub notification_retention_days: ::core::option::Option<i32>,
+    #[prost(string, tag="5")]
+    pub updated_by: ::prost::alloc::string::String,
+    /// Empty when no policy has been set.
+    #[prost(string, tag="6")]
+    pub updated_at: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GetRetentionPolicyRequest {
+    #[prost(string, tag="1")]
+    pub organisation: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GetRetentionPolicyResponse {
+    #[prost(message, optional, tag="1")]
+    pub policy: ::core::option::Option<RetentionPolicy>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SetRetentionPolicyRequest {
+    #[prost(string, tag="1")]
+    pub organisation: ::prost::alloc::string::String,
+    #[prost(int32, optional, tag="2")]
+    pub keep_artifacts: ::core::option::Option<i32>,
+    #[prost(int32, optional, tag="3")]
+    pub log_retention_days: ::core::option::Option<i32>,
+    #[prost(int32, optional, tag="4")]
+    pub notification_retention_days: ::core::option::Option<i32>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SetRetentionPolicyResponse {
+    #[prost(message, optional, tag="1")]
+    pub policy: ::core::option::Option<RetentionPolicy>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PreviewRetentionRequest {
+    #[prost(string, tag="1")]
+    pub organisation: ::prost::alloc::string::String,
+    /// Preview this policy instead of the stored one, e.g. before setting it.
+    #[prost(message, optional, tag="2")]
+    pub policy: ::core::option::Option<RetentionPolicy>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PreviewRetentionResponse {
+    #[prost(message, optional, tag="1")]
+    pub policy: ::core::option::Option<RetentionPolicy>,
+    /// Oldest first.
+    #[prost(message, repeated, tag="2")]
+    pub artifacts: ::prost::alloc::vec::Vec<ExpiredArtifact>,
+    #[prost(int64, tag="3")]
+    pub artifact_files: i64,
+    #[prost(int64, tag="4")]
+    pub artifact_bytes: i64,
+    #[prost(int64, tag="5")]
+    pub log_blocks: i64,
+    #[prost(int64, tag="6")]
+    pub health_observations: i64,
+    #[prost(int64, tag="7")]
+    pub notifications: i64,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ExpiredArtifact {
+    #[prost(string, tag="1")]
+    pub artifact_id: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub project: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub slug: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub created_at: ::prost::alloc::string::String,
+    #[prost(int64, tag="5")]
+    pub file_count: i64,
+    #[prost(int64, tag="6")]
+    pub size_bytes: i64,
+}
 // ============================================================================
 // Connect stream: Runner → Server
 // ============================================================================
@@ -3769,6 +4593,22 @@ pub struct RunnerRegister {
     /// Maximum number of simultaneous releases this runner can process.
     #[prost(int32, tag="3")]
     pub max_concurrent: i32,
+    /// Free-form labels (e.g. "network" = "prod-vpc"). Destinations can
+    /// declare required runner labels; only runners carrying all of them
+    /// (with matching values) are assigned that destination's work.
+    #[prost(map="string, string", tag="4")]
+    pub labels: ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+    /// Optional pool name. Matched like a label with the key "pool".
+    #[prost(string, tag="5")]
+    pub pool: ::prost::alloc::string::String,
+    /// Restrict this runner to work for one organisation (and optionally one
+    /// project within it). Scoped registrations must authenticate with a
+    /// bearer token that has admin access to the organisation. Unscoped
+    /// runners are shared and may take work for any organisation.
+    #[prost(string, optional, tag="6")]
+    pub organisation: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="7")]
+    pub project: ::core::option::Option<::prost::alloc::string::String>,
 }
 /// Describes a destination type the runner supports.
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
@@ -3803,7 +4643,7 @@ pub struct WorkAck {
 
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ServerMessage {
-    #[prost(oneof="server_message::Message", tags="1, 2")]
+    #[prost(oneof="server_message::Message", tags="1, 2, 3")]
     pub message: ::core::option::Option<server_message::Message>,
 }
 /// Nested message and enum types in `ServerMessage`.
@@ -3814,6 +4654,8 @@ pub mod server_message {
         RegisterAck(super::RegisterAck),
         #[prost(message, tag="2")]
         WorkAssignment(super::WorkAssignment),
+        #[prost(message, tag="3")]
+        CancelWork(super::CancelWork),
     }
 }
 /// Server response to RunnerRegister.
@@ -3827,6 +4669,16 @@ pub struct RegisterAck {
     #[prost(string, tag="3")]
     pub reason: ::prost::alloc::string::String,
 }
+/// Sent when a user cancels a release this runner is working on. The runner
+/// should stop the destination handler and report
+/// RELEASE_OUTCOME_CANCELLED via CompleteRelease.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct CancelWork {
+    #[prost(string, tag="1")]
+    pub release_id: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub reason: ::prost::alloc::string::String,
+}
 /// Work assignment pushed to a runner when a matching release is available.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct WorkAssignment {
@@ -3855,6 +4707,12 @@ pub struct WorkAssignment {
     /// these fields. Server-issued per release_token; do NOT log.
     #[prost(message, optional, tag="8")]
     pub artifact_store: ::core::option::Option<ReleaseArtifactStore>,
+    /// Decrypted secrets in scope for the release's project and environment,
+    /// by name. `secret://<name>` references in the destination metadata are
+    /// already resolved. Do NOT log; forest-server masks these values in
+    /// pushed logs.
+    #[prost(map="string, string", tag="9")]
+    pub secrets: ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
 }
 /// Generic per-release artifact store exposed by forest-server. The runner
 /// (or destination handler) translates the URL + basic-auth pair into
@@ -4005,6 +4863,47 @@ pub struct CompleteReleaseRequest {
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct CompleteReleaseResponse {
 }
+// ============================================================================
+// ListRunners
+// ============================================================================
+
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ListRunnersRequest {
+    /// Runners able to take work for this organisation: those scoped to it
+    /// plus shared runners. Empty lists every runner (service accounts only).
+    #[prost(string, tag="1")]
+    pub organisation: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListRunnersResponse {
+    #[prost(message, repeated, tag="1")]
+    pub runners: ::prost::alloc::vec::Vec<RunnerInfo>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RunnerInfo {
+    #[prost(string, tag="1")]
+    pub runner_id: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag="2")]
+    pub capabilities: ::prost::alloc::vec::Vec<DestinationCapability>,
+    #[prost(map="string, string", tag="3")]
+    pub labels: ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+    #[prost(string, tag="4")]
+    pub pool: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="5")]
+    pub organisation: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="6")]
+    pub project: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(int32, tag="7")]
+    pub max_concurrent: i32,
+    #[prost(int32, tag="8")]
+    pub active_releases: i32,
+    /// Seconds since the last heartbeat from this runner.
+    #[prost(uint64, tag="9")]
+    pub heartbeat_age_seconds: u64,
+    /// RFC 3339 timestamp of when the runner registered.
+    #[prost(string, tag="10")]
+    pub connected_at: ::prost::alloc::string::String,
+}
 /// Execution mode for a work assignment.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -4044,6 +4943,8 @@ pub enum ReleaseOutcome {
     Unspecified = 0,
     Success = 1,
     Failure = 2,
+    /// The runner stopped the release after receiving CancelWork.
+    Cancelled = 3,
 }
 impl ReleaseOutcome {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -4055,6 +4956,7 @@ impl ReleaseOutcome {
             Self::Unspecified => "RELEASE_OUTCOME_UNSPECIFIED",
             Self::Success => "RELEASE_OUTCOME_SUCCESS",
             Self::Failure => "RELEASE_OUTCOME_FAILURE",
+            Self::Cancelled => "RELEASE_OUTCOME_CANCELLED",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -4063,11 +4965,81 @@ impl ReleaseOutcome {
             "RELEASE_OUTCOME_UNSPECIFIED" => Some(Self::Unspecified),
             "RELEASE_OUTCOME_SUCCESS" => Some(Self::Success),
             "RELEASE_OUTCOME_FAILURE" => Some(Self::Failure),
+            "RELEASE_OUTCOME_CANCELLED" => Some(Self::Cancelled),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SecretScope {
+    #[prost(string, tag="1")]
+    pub organisation: ::prost::alloc::string::String,
+    /// Empty applies to every project in the organisation.
+    #[prost(string, tag="2")]
+    pub project: ::prost::alloc::string::String,
+    /// Empty applies to every environment.
+    #[prost(string, tag="3")]
+    pub environment: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Secret {
+    #[prost(message, optional, tag="1")]
+    pub scope: ::core::option::Option<SecretScope>,
+    #[prost(string, tag="2")]
+    pub name: ::prost::alloc::string::String,
+    /// Starts at 1 and increases with every rotation.
+    #[prost(int32, tag="3")]
+    pub version: i32,
+    #[prost(string, tag="4")]
+    pub updated_by: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub created_at: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub updated_at: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SetSecretRequest {
+    #[prost(message, optional, tag="1")]
+    pub scope: ::core::option::Option<SecretScope>,
+    /// Letters, digits, '_' and '-', starting with a letter or '_'.
+    #[prost(string, tag="2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub value: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SetSecretResponse {
+    #[prost(message, optional, tag="1")]
+    pub secret: ::core::option::Option<Secret>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ListSecretsRequest {
+    #[prost(string, tag="1")]
+    pub organisation: ::prost::alloc::string::String,
+    /// When set, only secrets scoped to exactly this project ("" for
+    /// organisation-wide secrets).
+    #[prost(string, optional, tag="2")]
+    pub project: ::core::option::Option<::prost::alloc::string::String>,
+    /// When set, only secrets scoped to exactly this environment.
+    #[prost(string, optional, tag="3")]
+    pub environment: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListSecretsResponse {
+    #[prost(message, repeated, tag="1")]
+    pub secrets: ::prost::alloc::vec::Vec<Secret>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DeleteSecretRequest {
+    #[prost(message, optional, tag="1")]
+    pub scope: ::core::option::Option<SecretScope>,
+    #[prost(string, tag="2")]
+    pub name: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DeleteSecretResponse {
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct Trigger {
     #[prost(string, tag="1")]
     pub id: ::prost::alloc::string::String,
@@ -4283,6 +5255,10 @@ pub struct LoginResponse {
     pub mfa_required: bool,
     #[prost(string, tag="4")]
     pub mfa_session_token: ::prost::alloc::string::String,
+    /// Second factors the user can complete the login with. Set when
+    /// mfa_required is true.
+    #[prost(enumeration="MfaType", repeated, tag="5")]
+    pub mfa_types: ::prost::alloc::vec::Vec<i32>,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct RefreshTokenRequest {
@@ -4670,6 +5646,122 @@ pub struct VerifyLoginMfaResponse {
     #[prost(message, optional, tag="2")]
     pub tokens: ::core::option::Option<AuthTokens>,
 }
+// ─── Passkeys ────────────────────────────────────────────────────────
+
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Passkey {
+    /// UUID
+    #[prost(string, tag="1")]
+    pub passkey_id: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="3")]
+    pub created_at: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag="4")]
+    pub last_used_at: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// A WebAuthn ceremony in progress. The ceremony_id is single-use and
+/// expires with its challenge after five minutes.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PasskeyCeremony {
+    #[prost(string, tag="1")]
+    pub ceremony_id: ::prost::alloc::string::String,
+    /// PublicKeyCredentialCreationOptionsJSON for registration,
+    /// PublicKeyCredentialRequestOptionsJSON for login and verification.
+    #[prost(string, tag="2")]
+    pub options_json: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BeginPasskeyRegistrationRequest {
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BeginPasskeyRegistrationResponse {
+    #[prost(message, optional, tag="1")]
+    pub ceremony: ::core::option::Option<PasskeyCeremony>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct FinishPasskeyRegistrationRequest {
+    #[prost(string, tag="1")]
+    pub ceremony_id: ::prost::alloc::string::String,
+    /// Shown in the passkey list, e.g. "Work laptop".
+    #[prost(string, tag="2")]
+    pub name: ::prost::alloc::string::String,
+    /// RegistrationResponseJSON from navigator.credentials.create().
+    #[prost(string, tag="3")]
+    pub credential_json: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct FinishPasskeyRegistrationResponse {
+    #[prost(message, optional, tag="1")]
+    pub passkey: ::core::option::Option<Passkey>,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ListPasskeysRequest {
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListPasskeysResponse {
+    #[prost(message, repeated, tag="1")]
+    pub passkeys: ::prost::alloc::vec::Vec<Passkey>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct RenamePasskeyRequest {
+    #[prost(string, tag="1")]
+    pub passkey_id: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub name: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct RenamePasskeyResponse {
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DeletePasskeyRequest {
+    #[prost(string, tag="1")]
+    pub passkey_id: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DeletePasskeyResponse {
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BeginPasskeyLoginRequest {
+    /// Set to complete a password login that returned mfa_required; the
+    /// assertion must then come from one of that user's passkeys. Empty for
+    /// passwordless login with a discoverable credential.
+    #[prost(string, tag="1")]
+    pub mfa_session_token: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BeginPasskeyLoginResponse {
+    #[prost(message, optional, tag="1")]
+    pub ceremony: ::core::option::Option<PasskeyCeremony>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct FinishPasskeyLoginRequest {
+    #[prost(string, tag="1")]
+    pub ceremony_id: ::prost::alloc::string::String,
+    /// AuthenticationResponseJSON from navigator.credentials.get().
+    #[prost(string, tag="2")]
+    pub credential_json: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FinishPasskeyLoginResponse {
+    #[prost(message, optional, tag="1")]
+    pub user: ::core::option::Option<User>,
+    #[prost(message, optional, tag="2")]
+    pub tokens: ::core::option::Option<AuthTokens>,
+}
+/// Service-account-only. Challenges user_id to confirm an action in the
+/// browser with one of their passkeys; the assertion is passed along with
+/// the action (today: ApproveDeviceLogin).
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BeginPasskeyVerificationRequest {
+    #[prost(string, tag="1")]
+    pub user_id: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BeginPasskeyVerificationResponse {
+    #[prost(message, optional, tag="1")]
+    pub ceremony: ::core::option::Option<PasskeyCeremony>,
+}
 // ─── Device authorization grant (RFC 8628) ───────────────────────────
 //
 // See `apps/forest/TASKS/022-device-login.md` for the full flow.
@@ -4746,6 +5838,12 @@ pub struct ApproveDeviceLoginRequest {
     pub approving_ip: ::prost::alloc::string::String,
     #[prost(string, tag="4")]
     pub approving_user_agent: ::prost::alloc::string::String,
+    /// Optional passkey confirmation, from BeginPasskeyVerification. When
+    /// set, the grant is approved only if the assertion verifies for user_id.
+    #[prost(string, tag="5")]
+    pub passkey_ceremony_id: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub passkey_credential_json: ::prost::alloc::string::String,
 }
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct ApproveDeviceLoginResponse {
@@ -4770,6 +5868,9 @@ pub enum OAuthProvider {
     OauthProviderGitlab = 3,
     OauthProviderMicrosoft = 4,
     OauthProviderMagicLink = 5,
+    /// Organisation-configured OIDC or SAML identity provider. The
+    /// provider_user_id is namespaced by the organisation (`<org>:<subject>`).
+    OauthProviderSso = 6,
 }
 impl OAuthProvider {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -4784,6 +5885,7 @@ impl OAuthProvider {
             Self::OauthProviderGitlab => "OAUTH_PROVIDER_GITLAB",
             Self::OauthProviderMicrosoft => "OAUTH_PROVIDER_MICROSOFT",
             Self::OauthProviderMagicLink => "OAUTH_PROVIDER_MAGIC_LINK",
+            Self::OauthProviderSso => "OAUTH_PROVIDER_SSO",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -4795,6 +5897,7 @@ impl OAuthProvider {
             "OAUTH_PROVIDER_GITLAB" => Some(Self::OauthProviderGitlab),
             "OAUTH_PROVIDER_MICROSOFT" => Some(Self::OauthProviderMicrosoft),
             "OAUTH_PROVIDER_MAGIC_LINK" => Some(Self::OauthProviderMagicLink),
+            "OAUTH_PROVIDER_SSO" => Some(Self::OauthProviderSso),
             _ => None,
         }
     }
@@ -4806,6 +5909,7 @@ impl OAuthProvider {
 pub enum MfaType {
     Unspecified = 0,
     Totp = 1,
+    Passkey = 2,
 }
 impl MfaType {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -4816,6 +5920,7 @@ impl MfaType {
         match self {
             Self::Unspecified => "MFA_TYPE_UNSPECIFIED",
             Self::Totp => "MFA_TYPE_TOTP",
+            Self::Passkey => "MFA_TYPE_PASSKEY",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -4823,6 +5928,7 @@ impl MfaType {
         match value {
             "MFA_TYPE_UNSPECIFIED" => Some(Self::Unspecified),
             "MFA_TYPE_TOTP" => Some(Self::Totp),
+            "MFA_TYPE_PASSKEY" => Some(Self::Passkey),
             _ => None,
         }
     }
@@ -1464,7 +1464,6 @@ pub mod destination_service_client {
     )]
     use tonic::codegen::*;
     use tonic::codegen::http::Uri;
-    ///
     #[derive(Debug, Clone)]
     pub struct DestinationServiceClient<T> {
         inner: tonic::client::Grpc<T>,
@@ -1545,7 +1544,6 @@ pub mod destination_service_client {
             self.inner = self.inner.max_encoding_message_size(limit);
             self
         }
-        ///
         pub async fn create_destination(
             &mut self,
             request: impl tonic::IntoRequest<super::CreateDestinationRequest>,
@@ -1572,7 +1570,6 @@ pub mod destination_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
-        ///
         pub async fn update_destination(
             &mut self,
             request: impl tonic::IntoRequest<super::UpdateDestinationRequest>,
@@ -1680,6 +1677,122 @@ pub mod destination_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        pub async fn list_terraform_states(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListTerraformStatesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListTerraformStatesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/forest.v1.DestinationService/ListTerraformStates",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "forest.v1.DestinationService",
+                        "ListTerraformStates",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_terraform_state_versions(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListTerraformStateVersionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListTerraformStateVersionsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/forest.v1.DestinationService/ListTerraformStateVersions",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "forest.v1.DestinationService",
+                        "ListTerraformStateVersions",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn force_unlock_terraform_state(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ForceUnlockTerraformStateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ForceUnlockTerraformStateResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/forest.v1.DestinationService/ForceUnlockTerraformState",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "forest.v1.DestinationService",
+                        "ForceUnlockTerraformState",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn rollback_terraform_state(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RollbackTerraformStateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RollbackTerraformStateResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/forest.v1.DestinationService/RollbackTerraformState",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "forest.v1.DestinationService",
+                        "RollbackTerraformState",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -1695,7 +1808,6 @@ pub mod destination_service_server {
     /// Generated trait containing gRPC methods that should be implemented for use with DestinationServiceServer.
     #[async_trait]
     pub trait DestinationService: std::marker::Send + std::marker::Sync + 'static {
-        ///
         async fn create_destination(
             &self,
             request: tonic::Request<super::CreateDestinationRequest>,
@@ -1703,7 +1815,6 @@ pub mod destination_service_server {
             tonic::Response<super::CreateDestinationResponse>,
             tonic::Status,
         >;
-        ///
         async fn update_destination(
             &self,
             request: tonic::Request<super::UpdateDestinationRequest>,
@@ -1732,8 +1843,35 @@ pub mod destination_service_server {
             tonic::Response<super::ListDestinationTypesResponse>,
             tonic::Status,
         >;
+        async fn list_terraform_states(
+            &self,
+            request: tonic::Request<super::ListTerraformStatesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListTerraformStatesResponse>,
+            tonic::Status,
+        >;
+        async fn list_terraform_state_versions(
+            &self,
+            request: tonic::Request<super::ListTerraformStateVersionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListTerraformStateVersionsResponse>,
+            tonic::Status,
+        >;
+        async fn force_unlock_terraform_state(
+            &self,
+            request: tonic::Request<super::ForceUnlockTerraformStateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ForceUnlockTerraformStateResponse>,
+            tonic::Status,
+        >;
+        async fn rollback_terraform_state(
+            &self,
+            request: tonic::Request<super::RollbackTerraformStateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RollbackTerraformStateResponse>,
+            tonic::Status,
+        >;
     }
-    ///
     #[derive(Debug)]
     pub struct DestinationServiceServer<T> {
         inner: Arc<T>,
@@ -2052,63 +2190,269 @@ pub mod destination_service_server {
                     };
                     Box::pin(fut)
                 }
-                _ => {
-                    Box::pin(async move {
-                        let mut response = http::Response::new(
-                            tonic::body::Body::default(),
-                        );
-                        let headers = response.headers_mut();
-                        headers
-                            .insert(
-                                tonic::Status::GRPC_STATUS,
-                                (tonic::Code::Unimplemented as i32).into(),
+                "/forest.v1.DestinationService/ListTerraformStates" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListTerraformStatesSvc<T: DestinationService>(pub Arc<T>);
+                    impl<
+                        T: DestinationService,
+                    > tonic::server::UnaryService<super::ListTerraformStatesRequest>
+                    for ListTerraformStatesSvc<T> {
+                        type Response = super::ListTerraformStatesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListTerraformStatesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DestinationService>::list_terraform_states(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListTerraformStatesSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
-                        headers
-                            .insert(
-                                http::header::CONTENT_TYPE,
-                                tonic::metadata::GRPC_CONTENT_TYPE,
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/forest.v1.DestinationService/ListTerraformStateVersions" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListTerraformStateVersionsSvc<T: DestinationService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: DestinationService,
+                    > tonic::server::UnaryService<
+                        super::ListTerraformStateVersionsRequest,
+                    > for ListTerraformStateVersionsSvc<T> {
+                        type Response = super::ListTerraformStateVersionsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::ListTerraformStateVersionsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DestinationService>::list_terraform_state_versions(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListTerraformStateVersionsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
-                        Ok(response)
-                    })
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
                 }
-            }
-        }
-    }
-    impl<T> Clone for DestinationServiceServer<T> {
-        fn clone(&self) -> Self {
-            let inner = self.inner.clone();
-            Self {
-                inner,
-                accept_compression_encodings: self.accept_compression_encodings,
-                send_compression_encodings: self.send_compression_encodings,
-                max_decoding_message_size: self.max_decoding_message_size,
-                max_encoding_message_size: self.max_encoding_message_size,
-            }
-        }
-    }
-    /// Generated gRPC service name
-    pub const SERVICE_NAME: &str = "forest.v1.DestinationService";
-    impl<T> tonic::server::NamedService for DestinationServiceServer<T> {
-        const NAME: &'static str = SERVICE_NAME;
-    }
-}
-/// Generated client implementations.
-pub mod environment_service_client {
-    #![allow(
-        unused_variables,
-        dead_code,
-        missing_docs,
-        clippy::wildcard_imports,
-        clippy::let_unit_value,
-    )]
-    use tonic::codegen::*;
-    use tonic::codegen::http::Uri;
-    ///
-    #[derive(Debug, Clone)]
-    pub struct EnvironmentServiceClient<T> {
-        inner: tonic::client::Grpc<T>,
-    }
-    impl EnvironmentServiceClient<tonic::transport::Channel> {
+                "/forest.v1.DestinationService/ForceUnlockTerraformState" => {
+                    #[allow(non_camel_case_types)]
+                    struct ForceUnlockTerraformStateSvc<T: DestinationService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: DestinationService,
+                    > tonic::server::UnaryService<
+                        super::ForceUnlockTerraformStateRequest,
+                    > for ForceUnlockTerraformStateSvc<T> {
+                        type Response = super::ForceUnlockTerraformStateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::ForceUnlockTerraformStateRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DestinationService>::force_unlock_terraform_state(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ForceUnlockTerraformStateSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/forest.v1.DestinationService/RollbackTerraformState" => {
+                    #[allow(non_camel_case_types)]
+                    struct RollbackTerraformStateSvc<T: DestinationService>(pub Arc<T>);
+                    impl<
+                        T: DestinationService,
+                    > tonic::server::UnaryService<super::RollbackTerraformStateRequest>
+                    for RollbackTerraformStateSvc<T> {
+                        type Response = super::RollbackTerraformStateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RollbackTerraformStateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DestinationService>::rollback_terraform_state(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = RollbackTerraformStateSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(
+                            tonic::body::Body::default(),
+                        );
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for DestinationServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "forest.v1.DestinationService";
+    impl<T> tonic::server::NamedService for DestinationServiceServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+/// Generated client implementations.
+pub mod environment_service_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    ///
+    #[derive(Debug, Clone)]
+    pub struct EnvironmentServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl EnvironmentServiceClient<tonic::transport::Channel> {
         /// Attempt to create a new client by connecting to a given endpoint.
         pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
         where
@@ -3943,6 +4287,32 @@ pub mod forage_service_client {
                 .insert(GrpcMethod::new("forest.v1.ForageService", "DeleteResources"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn get_applied_resources(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetAppliedResourcesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetAppliedResourcesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/forest.v1.ForageService/GetAppliedResources",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("forest.v1.ForageService", "GetAppliedResources"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -3985,6 +4355,13 @@ pub mod forage_service_server {
             tonic::Response<super::DeleteResourcesResponse>,
             tonic::Status,
         >;
+        async fn get_applied_resources(
+            &self,
+            request: tonic::Request<super::GetAppliedResourcesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetAppliedResourcesResponse>,
+            tonic::Status,
+        >;
     }
     #[derive(Debug)]
     pub struct ForageServiceServer<T> {
@@ -4199,6 +4576,52 @@ pub mod forage_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/forest.v1.ForageService/GetAppliedResources" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetAppliedResourcesSvc<T: ForageService>(pub Arc<T>);
+                    impl<
+                        T: ForageService,
+                    > tonic::server::UnaryService<super::GetAppliedResourcesRequest>
+                    for GetAppliedResourcesSvc<T> {
+                        type Response = super::GetAppliedResourcesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetAppliedResourcesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ForageService>::get_applied_resources(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetAppliedResourcesSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         let mut response = http::Response::new(
@@ -5917,41 +6340,209 @@ pub mod organisation_service_client {
                 .insert(GrpcMethod::new("forest.v1.OrganisationService", "ListMembers"));
             self.inner.unary(req, path, codec).await
         }
-    }
-}
-/// Generated server implementations.
-pub mod organisation_service_server {
-    #![allow(
-        unused_variables,
-        dead_code,
-        missing_docs,
-        clippy::wildcard_imports,
-        clippy::let_unit_value,
-    )]
-    use tonic::codegen::*;
-    /// Generated trait containing gRPC methods that should be implemented for use with OrganisationServiceServer.
-    #[async_trait]
-    pub trait OrganisationService: std::marker::Send + std::marker::Sync + 'static {
         ///
-        async fn create_organisation(
-            &self,
-            request: tonic::Request<super::CreateOrganisationRequest>,
+        pub async fn set_sso_enforcement(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetSsoEnforcementRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::CreateOrganisationResponse>,
+            tonic::Response<super::SetSsoEnforcementResponse>,
             tonic::Status,
-        >;
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/forest.v1.OrganisationService/SetSsoEnforcement",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("forest.v1.OrganisationService", "SetSsoEnforcement"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         ///
-        async fn get_organisation(
-            &self,
-            request: tonic::Request<super::GetOrganisationRequest>,
+        pub async fn set_scim_group_role(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetScimGroupRoleRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::GetOrganisationResponse>,
+            tonic::Response<super::SetScimGroupRoleResponse>,
             tonic::Status,
-        >;
-        ///
-        async fn search_organisations(
-            &self,
-            request: tonic::Request<super::SearchOrganisationsRequest>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/forest.v1.OrganisationService/SetScimGroupRole",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("forest.v1.OrganisationService", "SetScimGroupRole"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        ///
+        pub async fn list_scim_group_roles(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListScimGroupRolesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListScimGroupRolesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/forest.v1.OrganisationService/ListScimGroupRoles",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "forest.v1.OrganisationService",
+                        "ListScimGroupRoles",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        ///
+        pub async fn delete_scim_group_role(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeleteScimGroupRoleRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteScimGroupRoleResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/forest.v1.OrganisationService/DeleteScimGroupRole",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "forest.v1.OrganisationService",
+                        "DeleteScimGroupRole",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        ///
+        pub async fn list_my_invites(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListMyInvitesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListMyInvitesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/forest.v1.OrganisationService/ListMyInvites",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("forest.v1.OrganisationService", "ListMyInvites"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        ///
+        pub async fn accept_invite(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AcceptInviteRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AcceptInviteResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/forest.v1.OrganisationService/AcceptInvite",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("forest.v1.OrganisationService", "AcceptInvite"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod organisation_service_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with OrganisationServiceServer.
+    #[async_trait]
+    pub trait OrganisationService: std::marker::Send + std::marker::Sync + 'static {
+        ///
+        async fn create_organisation(
+            &self,
+            request: tonic::Request<super::CreateOrganisationRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CreateOrganisationResponse>,
+            tonic::Status,
+        >;
+        ///
+        async fn get_organisation(
+            &self,
+            request: tonic::Request<super::GetOrganisationRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetOrganisationResponse>,
+            tonic::Status,
+        >;
+        ///
+        async fn search_organisations(
+            &self,
+            request: tonic::Request<super::SearchOrganisationsRequest>,
         ) -> std::result::Result<
             tonic::Response<super::SearchOrganisationsResponse>,
             tonic::Status,
@@ -5996,6 +6587,54 @@ pub mod organisation_service_server {
             tonic::Response<super::ListMembersResponse>,
             tonic::Status,
         >;
+        ///
+        async fn set_sso_enforcement(
+            &self,
+            request: tonic::Request<super::SetSsoEnforcementRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetSsoEnforcementResponse>,
+            tonic::Status,
+        >;
+        ///
+        async fn set_scim_group_role(
+            &self,
+            request: tonic::Request<super::SetScimGroupRoleRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetScimGroupRoleResponse>,
+            tonic::Status,
+        >;
+        ///
+        async fn list_scim_group_roles(
+            &self,
+            request: tonic::Request<super::ListScimGroupRolesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListScimGroupRolesResponse>,
+            tonic::Status,
+        >;
+        ///
+        async fn delete_scim_group_role(
+            &self,
+            request: tonic::Request<super::DeleteScimGroupRoleRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteScimGroupRoleResponse>,
+            tonic::Status,
+        >;
+        ///
+        async fn list_my_invites(
+            &self,
+            request: tonic::Request<super::ListMyInvitesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListMyInvitesResponse>,
+            tonic::Status,
+        >;
+        ///
+        async fn accept_invite(
+            &self,
+            request: tonic::Request<super::AcceptInviteRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AcceptInviteResponse>,
+            tonic::Status,
+        >;
     }
     ///
     #[derive(Debug)]
@@ -6457,48 +7096,336 @@ pub mod organisation_service_server {
                     };
                     Box::pin(fut)
                 }
-                _ => {
-                    Box::pin(async move {
-                        let mut response = http::Response::new(
-                            tonic::body::Body::default(),
-                        );
-                        let headers = response.headers_mut();
-                        headers
-                            .insert(
-                                tonic::Status::GRPC_STATUS,
-                                (tonic::Code::Unimplemented as i32).into(),
-                            );
-                        headers
-                            .insert(
-                                http::header::CONTENT_TYPE,
-                                tonic::metadata::GRPC_CONTENT_TYPE,
+                "/forest.v1.OrganisationService/SetSsoEnforcement" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetSsoEnforcementSvc<T: OrganisationService>(pub Arc<T>);
+                    impl<
+                        T: OrganisationService,
+                    > tonic::server::UnaryService<super::SetSsoEnforcementRequest>
+                    for SetSsoEnforcementSvc<T> {
+                        type Response = super::SetSsoEnforcementResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetSsoEnforcementRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as OrganisationService>::set_sso_enforcement(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SetSsoEnforcementSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
-                        Ok(response)
-                    })
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
                 }
-            }
-        }
-    }
-    impl<T> Clone for OrganisationServiceServer<T> {
-        fn clone(&self) -> Self {
-            let inner = self.inner.clone();
-            Self {
-                inner,
-                accept_compression_encodings: self.accept_compression_encodings,
-                send_compression_encodings: self.send_compression_encodings,
-                max_decoding_message_size: self.max_decoding_message_size,
-                max_encoding_message_size: self.max_encoding_message_size,
-            }
-        }
-    }
-    /// Generated gRPC service name
-    pub const SERVICE_NAME: &str = "forest.v1.OrganisationService";
-    impl<T> tonic::server::NamedService for OrganisationServiceServer<T> {
-        const NAME: &'static str = SERVICE_NAME;
-    }
-}
-/// Generated client implementations.
-pub mod release_service_client {
+                "/forest.v1.OrganisationService/SetScimGroupRole" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetScimGroupRoleSvc<T: OrganisationService>(pub Arc<T>);
+                    impl<
+                        T: OrganisationService,
+                    > tonic::server::UnaryService<super::SetScimGroupRoleRequest>
+                    for SetScimGroupRoleSvc<T> {
+                        type Response = super::SetScimGroupRoleResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetScimGroupRoleRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as OrganisationService>::set_scim_group_role(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SetScimGroupRoleSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/forest.v1.OrganisationService/ListScimGroupRoles" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListScimGroupRolesSvc<T: OrganisationService>(pub Arc<T>);
+                    impl<
+                        T: OrganisationService,
+                    > tonic::server::UnaryService<super::ListScimGroupRolesRequest>
+                    for ListScimGroupRolesSvc<T> {
+                        type Response = super::ListScimGroupRolesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListScimGroupRolesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as OrganisationService>::list_scim_group_roles(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListScimGroupRolesSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/forest.v1.OrganisationService/DeleteScimGroupRole" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeleteScimGroupRoleSvc<T: OrganisationService>(pub Arc<T>);
+                    impl<
+                        T: OrganisationService,
+                    > tonic::server::UnaryService<super::DeleteScimGroupRoleRequest>
+                    for DeleteScimGroupRoleSvc<T> {
+                        type Response = super::DeleteScimGroupRoleResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DeleteScimGroupRoleRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as OrganisationService>::delete_scim_group_role(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DeleteScimGroupRoleSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/forest.v1.OrganisationService/ListMyInvites" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListMyInvitesSvc<T: OrganisationService>(pub Arc<T>);
+                    impl<
+                        T: OrganisationService,
+                    > tonic::server::UnaryService<super::ListMyInvitesRequest>
+                    for ListMyInvitesSvc<T> {
+                        type Response = super::ListMyInvitesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListMyInvitesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as OrganisationService>::list_my_invites(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListMyInvitesSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/forest.v1.OrganisationService/AcceptInvite" => {
+                    #[allow(non_camel_case_types)]
+                    struct AcceptInviteSvc<T: OrganisationService>(pub Arc<T>);
+                    impl<
+                        T: OrganisationService,
+                    > tonic::server::UnaryService<super::AcceptInviteRequest>
+                    for AcceptInviteSvc<T> {
+                        type Response = super::AcceptInviteResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AcceptInviteRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as OrganisationService>::accept_invite(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AcceptInviteSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(
+                            tonic::body::Body::default(),
+                        );
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for OrganisationServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "forest.v1.OrganisationService";
+    impl<T> tonic::server::NamedService for OrganisationServiceServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+/// Generated client implementations.
+pub mod release_service_client {
     #![allow(
         unused_variables,
         dead_code,
@@ -6982,21 +7909,45 @@ pub mod release_service_client {
                 .insert(GrpcMethod::new("forest.v1.ReleaseService", "GetPlanOutput"));
             self.inner.unary(req, path, codec).await
         }
-    }
-}
-/// Generated server implementations.
-pub mod release_service_server {
-    #![allow(
-        unused_variables,
-        dead_code,
-        missing_docs,
-        clippy::wildcard_imports,
-        clippy::let_unit_value,
-    )]
-    use tonic::codegen::*;
-    /// Generated trait containing gRPC methods that should be implemented for use with ReleaseServiceServer.
-    #[async_trait]
-    pub trait ReleaseService: std::marker::Send + std::marker::Sync + 'static {
+        pub async fn cancel_release(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CancelReleaseRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CancelReleaseResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/forest.v1.ReleaseService/CancelRelease",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("forest.v1.ReleaseService", "CancelRelease"));
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod release_service_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with ReleaseServiceServer.
+    #[async_trait]
+    pub trait ReleaseService: std::marker::Send + std::marker::Sync + 'static {
         async fn annotate_release(
             &self,
             request: tonic::Request<super::AnnotateReleaseRequest>,
@@ -7112,6 +8063,13 @@ pub mod release_service_server {
             tonic::Response<super::GetPlanOutputResponse>,
             tonic::Status,
         >;
+        async fn cancel_release(
+            &self,
+            request: tonic::Request<super::CancelReleaseRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CancelReleaseResponse>,
+            tonic::Status,
+        >;
     }
     #[derive(Debug)]
     pub struct ReleaseServiceServer<T> {
@@ -7932,6 +8890,51 @@ pub mod release_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/forest.v1.ReleaseService/CancelRelease" => {
+                    #[allow(non_camel_case_types)]
+                    struct CancelReleaseSvc<T: ReleaseService>(pub Arc<T>);
+                    impl<
+                        T: ReleaseService,
+                    > tonic::server::UnaryService<super::CancelReleaseRequest>
+                    for CancelReleaseSvc<T> {
+                        type Response = super::CancelReleaseResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CancelReleaseRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReleaseService>::cancel_release(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CancelReleaseSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         let mut response = http::Response::new(
@@ -10272,7 +11275,7 @@ pub mod registry_service_server {
     }
 }
 /// Generated client implementations.
-pub mod release_pipeline_service_client {
+pub mod release_history_service_client {
     #![allow(
         unused_variables,
         dead_code,
@@ -10282,12 +11285,11 @@ pub mod release_pipeline_service_client {
     )]
     use tonic::codegen::*;
     use tonic::codegen::http::Uri;
-    ///
     #[derive(Debug, Clone)]
-    pub struct ReleasePipelineServiceClient<T> {
+    pub struct ReleaseHistoryServiceClient<T> {
         inner: tonic::client::Grpc<T>,
     }
-    impl ReleasePipelineServiceClient<tonic::transport::Channel> {
+    impl ReleaseHistoryServiceClient<tonic::transport::Channel> {
         /// Attempt to create a new client by connecting to a given endpoint.
         pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
         where
@@ -10298,7 +11300,7 @@ pub mod release_pipeline_service_client {
             Ok(Self::new(conn))
         }
     }
-    impl<T> ReleasePipelineServiceClient<T>
+    impl<T> ReleaseHistoryServiceClient<T>
     where
         T: tonic::client::GrpcService<tonic::body::Body>,
         T::Error: Into<StdError>,
@@ -10316,7 +11318,7 @@ pub mod release_pipeline_service_client {
         pub fn with_interceptor<F>(
             inner: T,
             interceptor: F,
-        ) -> ReleasePipelineServiceClient<InterceptedService<T, F>>
+        ) -> ReleaseHistoryServiceClient<InterceptedService<T, F>>
         where
             F: tonic::service::Interceptor,
             T::ResponseBody: Default,
@@ -10330,9 +11332,7 @@ pub mod release_pipeline_service_client {
                 http::Request<tonic::body::Body>,
             >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
         {
-            ReleasePipelineServiceClient::new(
-                InterceptedService::new(inner, interceptor),
-            )
+            ReleaseHistoryServiceClient::new(InterceptedService::new(inner, interceptor))
         }
         /// Compress requests with the given encoding.
         ///
@@ -10365,72 +11365,11 @@ pub mod release_pipeline_service_client {
             self.inner = self.inner.max_encoding_message_size(limit);
             self
         }
-        ///
-        pub async fn create_release_pipeline(
-            &mut self,
-            request: impl tonic::IntoRequest<super::CreateReleasePipelineRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::CreateReleasePipelineResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::unknown(
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
-            let codec = tonic_prost::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/forest.v1.ReleasePipelineService/CreateReleasePipeline",
-            );
-            let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(
-                    GrpcMethod::new(
-                        "forest.v1.ReleasePipelineService",
-                        "CreateReleasePipeline",
-                    ),
-                );
-            self.inner.unary(req, path, codec).await
-        }
-        ///
-        pub async fn update_release_pipeline(
-            &mut self,
-            request: impl tonic::IntoRequest<super::UpdateReleasePipelineRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::UpdateReleasePipelineResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::unknown(
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
-            let codec = tonic_prost::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/forest.v1.ReleasePipelineService/UpdateReleasePipeline",
-            );
-            let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(
-                    GrpcMethod::new(
-                        "forest.v1.ReleasePipelineService",
-                        "UpdateReleasePipeline",
-                    ),
-                );
-            self.inner.unary(req, path, codec).await
-        }
-        ///
-        pub async fn delete_release_pipeline(
+        pub async fn list_releases(
             &mut self,
-            request: impl tonic::IntoRequest<super::DeleteReleasePipelineRequest>,
+            request: impl tonic::IntoRequest<super::ListReleasesRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::DeleteReleasePipelineResponse>,
+            tonic::Response<super::ListReleasesResponse>,
             tonic::Status,
         > {
             self.inner
@@ -10443,24 +11382,20 @@ pub mod release_pipeline_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/forest.v1.ReleasePipelineService/DeleteReleasePipeline",
+                "/forest.v1.ReleaseHistoryService/ListReleases",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
-                    GrpcMethod::new(
-                        "forest.v1.ReleasePipelineService",
-                        "DeleteReleasePipeline",
-                    ),
+                    GrpcMethod::new("forest.v1.ReleaseHistoryService", "ListReleases"),
                 );
             self.inner.unary(req, path, codec).await
         }
-        ///
-        pub async fn list_release_pipelines(
+        pub async fn get_release(
             &mut self,
-            request: impl tonic::IntoRequest<super::ListReleasePipelinesRequest>,
+            request: impl tonic::IntoRequest<super::GetReleaseRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::ListReleasePipelinesResponse>,
+            tonic::Response<super::GetReleaseResponse>,
             tonic::Status,
         > {
             self.inner
@@ -10473,22 +11408,19 @@ pub mod release_pipeline_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/forest.v1.ReleasePipelineService/ListReleasePipelines",
+                "/forest.v1.ReleaseHistoryService/GetRelease",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
-                    GrpcMethod::new(
-                        "forest.v1.ReleasePipelineService",
-                        "ListReleasePipelines",
-                    ),
+                    GrpcMethod::new("forest.v1.ReleaseHistoryService", "GetRelease"),
                 );
             self.inner.unary(req, path, codec).await
         }
     }
 }
 /// Generated server implementations.
-pub mod release_pipeline_service_server {
+pub mod release_history_service_server {
     #![allow(
         unused_variables,
         dead_code,
@@ -10497,52 +11429,33 @@ pub mod release_pipeline_service_server {
         clippy::let_unit_value,
     )]
     use tonic::codegen::*;
-    /// Generated trait containing gRPC methods that should be implemented for use with ReleasePipelineServiceServer.
+    /// Generated trait containing gRPC methods that should be implemented for use with ReleaseHistoryServiceServer.
     #[async_trait]
-    pub trait ReleasePipelineService: std::marker::Send + std::marker::Sync + 'static {
-        ///
-        async fn create_release_pipeline(
-            &self,
-            request: tonic::Request<super::CreateReleasePipelineRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::CreateReleasePipelineResponse>,
-            tonic::Status,
-        >;
-        ///
-        async fn update_release_pipeline(
-            &self,
-            request: tonic::Request<super::UpdateReleasePipelineRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::UpdateReleasePipelineResponse>,
-            tonic::Status,
-        >;
-        ///
-        async fn delete_release_pipeline(
+    pub trait ReleaseHistoryService: std::marker::Send + std::marker::Sync + 'static {
+        async fn list_releases(
             &self,
-            request: tonic::Request<super::DeleteReleasePipelineRequest>,
+            request: tonic::Request<super::ListReleasesRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::DeleteReleasePipelineResponse>,
+            tonic::Response<super::ListReleasesResponse>,
             tonic::Status,
         >;
-        ///
-        async fn list_release_pipelines(
+        async fn get_release(
             &self,
-            request: tonic::Request<super::ListReleasePipelinesRequest>,
+            request: tonic::Request<super::GetReleaseRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::ListReleasePipelinesResponse>,
+            tonic::Response<super::GetReleaseResponse>,
             tonic::Status,
         >;
     }
-    ///
     #[derive(Debug)]
-    pub struct ReleasePipelineServiceServer<T> {
+    pub struct ReleaseHistoryServiceServer<T> {
         inner: Arc<T>,
         accept_compression_encodings: EnabledCompressionEncodings,
         send_compression_encodings: EnabledCompressionEncodings,
         max_decoding_message_size: Option<usize>,
         max_encoding_message_size: Option<usize>,
     }
-    impl<T> ReleasePipelineServiceServer<T> {
+    impl<T> ReleaseHistoryServiceServer<T> {
         pub fn new(inner: T) -> Self {
             Self::from_arc(Arc::new(inner))
         }
@@ -10594,9 +11507,9 @@ pub mod release_pipeline_service_server {
         }
     }
     impl<T, B> tonic::codegen::Service<http::Request<B>>
-    for ReleasePipelineServiceServer<T>
+    for ReleaseHistoryServiceServer<T>
     where
-        T: ReleasePipelineService,
+        T: ReleaseHistoryService,
         B: Body + std::marker::Send + 'static,
         B::Error: Into<StdError> + std::marker::Send + 'static,
     {
@@ -10611,30 +11524,25 @@ pub mod release_pipeline_service_server {
         }
         fn call(&mut self, req: http::Request<B>) -> Self::Future {
             match req.uri().path() {
-                "/forest.v1.ReleasePipelineService/CreateReleasePipeline" => {
+                "/forest.v1.ReleaseHistoryService/ListReleases" => {
                     #[allow(non_camel_case_types)]
-                    struct CreateReleasePipelineSvc<T: ReleasePipelineService>(
-                        pub Arc<T>,
-                    );
+                    struct ListReleasesSvc<T: ReleaseHistoryService>(pub Arc<T>);
                     impl<
-                        T: ReleasePipelineService,
-                    > tonic::server::UnaryService<super::CreateReleasePipelineRequest>
-                    for CreateReleasePipelineSvc<T> {
-                        type Response = super::CreateReleasePipelineResponse;
+                        T: ReleaseHistoryService,
+                    > tonic::server::UnaryService<super::ListReleasesRequest>
+                    for ListReleasesSvc<T> {
+                        type Response = super::ListReleasesResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::CreateReleasePipelineRequest>,
+                            request: tonic::Request<super::ListReleasesRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ReleasePipelineService>::create_release_pipeline(
-                                        &inner,
-                                        request,
-                                    )
+                                <T as ReleaseHistoryService>::list_releases(&inner, request)
                                     .await
                             };
                             Box::pin(fut)
@@ -10646,7 +11554,7 @@ pub mod release_pipeline_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = CreateReleasePipelineSvc(inner);
+                        let method = ListReleasesSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -10662,30 +11570,25 @@ pub mod release_pipeline_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/forest.v1.ReleasePipelineService/UpdateReleasePipeline" => {
+                "/forest.v1.ReleaseHistoryService/GetRelease" => {
                     #[allow(non_camel_case_types)]
-                    struct UpdateReleasePipelineSvc<T: ReleasePipelineService>(
-                        pub Arc<T>,
-                    );
+                    struct GetReleaseSvc<T: ReleaseHistoryService>(pub Arc<T>);
                     impl<
-                        T: ReleasePipelineService,
-                    > tonic::server::UnaryService<super::UpdateReleasePipelineRequest>
-                    for UpdateReleasePipelineSvc<T> {
-                        type Response = super::UpdateReleasePipelineResponse;
+                        T: ReleaseHistoryService,
+                    > tonic::server::UnaryService<super::GetReleaseRequest>
+                    for GetReleaseSvc<T> {
+                        type Response = super::GetReleaseResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::UpdateReleasePipelineRequest>,
+                            request: tonic::Request<super::GetReleaseRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ReleasePipelineService>::update_release_pipeline(
-                                        &inner,
-                                        request,
-                                    )
+                                <T as ReleaseHistoryService>::get_release(&inner, request)
                                     .await
                             };
                             Box::pin(fut)
@@ -10697,109 +11600,7 @@ pub mod release_pipeline_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = UpdateReleasePipelineSvc(inner);
-                        let codec = tonic_prost::ProstCodec::default();
-                        let mut grpc = tonic::server::Grpc::new(codec)
-                            .apply_compression_config(
-                                accept_compression_encodings,
-                                send_compression_encodings,
-                            )
-                            .apply_max_message_size_config(
-                                max_decoding_message_size,
-                                max_encoding_message_size,
-                            );
-                        let res = grpc.unary(method, req).await;
-                        Ok(res)
-                    };
-                    Box::pin(fut)
-                }
-                "/forest.v1.ReleasePipelineService/DeleteReleasePipeline" => {
-                    #[allow(non_camel_case_types)]
-                    struct DeleteReleasePipelineSvc<T: ReleasePipelineService>(
-                        pub Arc<T>,
-                    );
-                    impl<
-                        T: ReleasePipelineService,
-                    > tonic::server::UnaryService<super::DeleteReleasePipelineRequest>
-                    for DeleteReleasePipelineSvc<T> {
-                        type Response = super::DeleteReleasePipelineResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
-                        fn call(
-                            &mut self,
-                            request: tonic::Request<super::DeleteReleasePipelineRequest>,
-                        ) -> Self::Future {
-                            let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as ReleasePipelineService>::delete_release_pipeline(
-                                        &inner,
-                                        request,
-                                    )
-                                    .await
-                            };
-                            Box::pin(fut)
-                        }
-                    }
-                    let accept_compression_encodings = self.accept_compression_encodings;
-                    let send_compression_encodings = self.send_compression_encodings;
-                    let max_decoding_message_size = self.max_decoding_message_size;
-                    let max_encoding_message_size = self.max_encoding_message_size;
-                    let inner = self.inner.clone();
-                    let fut = async move {
-                        let method = DeleteReleasePipelineSvc(inner);
-                        let codec = tonic_prost::ProstCodec::default();
-                        let mut grpc = tonic::server::Grpc::new(codec)
-                            .apply_compression_config(
-                                accept_compression_encodings,
-                                send_compression_encodings,
-                            )
-                            .apply_max_message_size_config(
-                                max_decoding_message_size,
-                                max_encoding_message_size,
-                            );
-                        let res = grpc.unary(method, req).await;
-                        Ok(res)
-                    };
-                    Box::pin(fut)
-                }
-                "/forest.v1.ReleasePipelineService/ListReleasePipelines" => {
-                    #[allow(non_camel_case_types)]
-                    struct ListReleasePipelinesSvc<T: ReleasePipelineService>(
-                        pub Arc<T>,
-                    );
-                    impl<
-                        T: ReleasePipelineService,
-                    > tonic::server::UnaryService<super::ListReleasePipelinesRequest>
-                    for ListReleasePipelinesSvc<T> {
-                        type Response = super::ListReleasePipelinesResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
-                        fn call(
-                            &mut self,
-                            request: tonic::Request<super::ListReleasePipelinesRequest>,
-                        ) -> Self::Future {
-                            let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as ReleasePipelineService>::list_release_pipelines(
-                                        &inner,
-                                        request,
-                                    )
-                                    .await
-                            };
-                            Box::pin(fut)
-                        }
-                    }
-                    let accept_compression_encodings = self.accept_compression_encodings;
-                    let send_compression_encodings = self.send_compression_encodings;
-                    let max_decoding_message_size = self.max_decoding_message_size;
-                    let max_encoding_message_size = self.max_encoding_message_size;
-                    let inner = self.inner.clone();
-                    let fut = async move {
-                        let method = ListReleasePipelinesSvc(inner);
+                        let method = GetReleaseSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -10837,7 +11638,7 @@ pub mod release_pipeline_service_server {
             }
         }
     }
-    impl<T> Clone for ReleasePipelineServiceServer<T> {
+    impl<T> Clone for ReleaseHistoryServiceServer<T> {
         fn clone(&self) -> Self {
             let inner = self.inner.clone();
             Self {
@@ -10850,13 +11651,13 @@ pub mod release_pipeline_service_server {
         }
     }
     /// Generated gRPC service name
-    pub const SERVICE_NAME: &str = "forest.v1.ReleasePipelineService";
-    impl<T> tonic::server::NamedService for ReleasePipelineServiceServer<T> {
+    pub const SERVICE_NAME: &str = "forest.v1.ReleaseHistoryService";
+    impl<T> tonic::server::NamedService for ReleaseHistoryServiceServer<T> {
         const NAME: &'static str = SERVICE_NAME;
     }
 }
 /// Generated client implementations.
-pub mod runner_service_client {
+pub mod release_pipeline_service_client {
     #![allow(
         unused_variables,
         dead_code,
@@ -10866,11 +11667,12 @@ pub mod runner_service_client {
     )]
     use tonic::codegen::*;
     use tonic::codegen::http::Uri;
+    ///
     #[derive(Debug, Clone)]
-    pub struct RunnerServiceClient<T> {
+    pub struct ReleasePipelineServiceClient<T> {
         inner: tonic::client::Grpc<T>,
     }
-    impl RunnerServiceClient<tonic::transport::Channel> {
+    impl ReleasePipelineServiceClient<tonic::transport::Channel> {
         /// Attempt to create a new client by connecting to a given endpoint.
         pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
         where
@@ -10881,7 +11683,7 @@ pub mod runner_service_client {
             Ok(Self::new(conn))
         }
     }
-    impl<T> RunnerServiceClient<T>
+    impl<T> ReleasePipelineServiceClient<T>
     where
         T: tonic::client::GrpcService<tonic::body::Body>,
         T::Error: Into<StdError>,
@@ -10899,7 +11701,7 @@ pub mod runner_service_client {
         pub fn with_interceptor<F>(
             inner: T,
             interceptor: F,
-        ) -> RunnerServiceClient<InterceptedService<T, F>>
+        ) -> ReleasePipelineServiceClient<InterceptedService<T, F>>
         where
             F: tonic::service::Interceptor,
             T::ResponseBody: Default,
@@ -10913,7 +11715,9 @@ pub mod runner_service_client {
                 http::Request<tonic::body::Body>,
             >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
         {
-            RunnerServiceClient::new(InterceptedService::new(inner, interceptor))
+            ReleasePipelineServiceClient::new(
+                InterceptedService::new(inner, interceptor),
+            )
         }
         /// Compress requests with the given encoding.
         ///
@@ -10946,83 +11750,12 @@ pub mod runner_service_client {
             self.inner = self.inner.max_encoding_message_size(limit);
             self
         }
-        pub async fn register_runner(
-            &mut self,
-            request: impl tonic::IntoStreamingRequest<Message = super::RunnerMessage>,
-        ) -> std::result::Result<
-            tonic::Response<tonic::codec::Streaming<super::ServerMessage>>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::unknown(
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
-            let codec = tonic_prost::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/forest.v1.RunnerService/RegisterRunner",
-            );
-            let mut req = request.into_streaming_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("forest.v1.RunnerService", "RegisterRunner"));
-            self.inner.streaming(req, path, codec).await
-        }
-        pub async fn get_release_files(
-            &mut self,
-            request: impl tonic::IntoRequest<super::GetReleaseFilesRequest>,
-        ) -> std::result::Result<
-            tonic::Response<tonic::codec::Streaming<super::ReleaseFile>>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::unknown(
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
-            let codec = tonic_prost::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/forest.v1.RunnerService/GetReleaseFiles",
-            );
-            let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("forest.v1.RunnerService", "GetReleaseFiles"));
-            self.inner.server_streaming(req, path, codec).await
-        }
-        pub async fn push_logs(
-            &mut self,
-            request: impl tonic::IntoStreamingRequest<Message = super::PushLogRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::PushLogResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::unknown(
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
-            let codec = tonic_prost::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/forest.v1.RunnerService/PushLogs",
-            );
-            let mut req = request.into_streaming_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("forest.v1.RunnerService", "PushLogs"));
-            self.inner.client_streaming(req, path, codec).await
-        }
-        pub async fn get_spec_files(
+        ///
+        pub async fn create_release_pipeline(
             &mut self,
-            request: impl tonic::IntoRequest<super::GetSpecFilesRequest>,
+            request: impl tonic::IntoRequest<super::CreateReleasePipelineRequest>,
         ) -> std::result::Result<
-            tonic::Response<tonic::codec::Streaming<super::ReleaseFile>>,
+            tonic::Response<super::CreateReleasePipelineResponse>,
             tonic::Status,
         > {
             self.inner
@@ -11035,18 +11768,24 @@ pub mod runner_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/forest.v1.RunnerService/GetSpecFiles",
+                "/forest.v1.ReleasePipelineService/CreateReleasePipeline",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("forest.v1.RunnerService", "GetSpecFiles"));
-            self.inner.server_streaming(req, path, codec).await
+                .insert(
+                    GrpcMethod::new(
+                        "forest.v1.ReleasePipelineService",
+                        "CreateReleasePipeline",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
-        pub async fn get_release_annotation(
+        ///
+        pub async fn update_release_pipeline(
             &mut self,
-            request: impl tonic::IntoRequest<super::GetReleaseAnnotationRequest>,
+            request: impl tonic::IntoRequest<super::UpdateReleasePipelineRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::ReleaseAnnotationResponse>,
+            tonic::Response<super::UpdateReleasePipelineResponse>,
             tonic::Status,
         > {
             self.inner
@@ -11059,20 +11798,24 @@ pub mod runner_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/forest.v1.RunnerService/GetReleaseAnnotation",
+                "/forest.v1.ReleasePipelineService/UpdateReleasePipeline",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
-                    GrpcMethod::new("forest.v1.RunnerService", "GetReleaseAnnotation"),
+                    GrpcMethod::new(
+                        "forest.v1.ReleasePipelineService",
+                        "UpdateReleasePipeline",
+                    ),
                 );
             self.inner.unary(req, path, codec).await
         }
-        pub async fn get_project_info(
+        ///
+        pub async fn delete_release_pipeline(
             &mut self,
-            request: impl tonic::IntoRequest<super::GetProjectInfoRequest>,
+            request: impl tonic::IntoRequest<super::DeleteReleasePipelineRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::ProjectInfoResponse>,
+            tonic::Response<super::DeleteReleasePipelineResponse>,
             tonic::Status,
         > {
             self.inner
@@ -11085,18 +11828,24 @@ pub mod runner_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/forest.v1.RunnerService/GetProjectInfo",
+                "/forest.v1.ReleasePipelineService/DeleteReleasePipeline",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("forest.v1.RunnerService", "GetProjectInfo"));
+                .insert(
+                    GrpcMethod::new(
+                        "forest.v1.ReleasePipelineService",
+                        "DeleteReleasePipeline",
+                    ),
+                );
             self.inner.unary(req, path, codec).await
         }
-        pub async fn complete_release(
+        ///
+        pub async fn list_release_pipelines(
             &mut self,
-            request: impl tonic::IntoRequest<super::CompleteReleaseRequest>,
+            request: impl tonic::IntoRequest<super::ListReleasePipelinesRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::CompleteReleaseResponse>,
+            tonic::Response<super::ListReleasePipelinesResponse>,
             tonic::Status,
         > {
             self.inner
@@ -11109,17 +11858,22 @@ pub mod runner_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/forest.v1.RunnerService/CompleteRelease",
+                "/forest.v1.ReleasePipelineService/ListReleasePipelines",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("forest.v1.RunnerService", "CompleteRelease"));
+                .insert(
+                    GrpcMethod::new(
+                        "forest.v1.ReleasePipelineService",
+                        "ListReleasePipelines",
+                    ),
+                );
             self.inner.unary(req, path, codec).await
         }
     }
 }
 /// Generated server implementations.
-pub mod runner_service_server {
+pub mod release_pipeline_service_server {
     #![allow(
         unused_variables,
         dead_code,
@@ -11128,83 +11882,52 @@ pub mod runner_service_server {
         clippy::let_unit_value,
     )]
     use tonic::codegen::*;
-    /// Generated trait containing gRPC methods that should be implemented for use with RunnerServiceServer.
+    /// Generated trait containing gRPC methods that should be implemented for use with ReleasePipelineServiceServer.
     #[async_trait]
-    pub trait RunnerService: std::marker::Send + std::marker::Sync + 'static {
-        /// Server streaming response type for the RegisterRunner method.
-        type RegisterRunnerStream: tonic::codegen::tokio_stream::Stream<
-                Item = std::result::Result<super::ServerMessage, tonic::Status>,
-            >
-            + std::marker::Send
-            + 'static;
-        async fn register_runner(
+    pub trait ReleasePipelineService: std::marker::Send + std::marker::Sync + 'static {
+        ///
+        async fn create_release_pipeline(
             &self,
-            request: tonic::Request<tonic::Streaming<super::RunnerMessage>>,
+            request: tonic::Request<super::CreateReleasePipelineRequest>,
         ) -> std::result::Result<
-            tonic::Response<Self::RegisterRunnerStream>,
+            tonic::Response<super::CreateReleasePipelineResponse>,
             tonic::Status,
         >;
-        /// Server streaming response type for the GetReleaseFiles method.
-        type GetReleaseFilesStream: tonic::codegen::tokio_stream::Stream<
-                Item = std::result::Result<super::ReleaseFile, tonic::Status>,
-            >
-            + std::marker::Send
-            + 'static;
-        async fn get_release_files(
+        ///
+        async fn update_release_pipeline(
             &self,
-            request: tonic::Request<super::GetReleaseFilesRequest>,
+            request: tonic::Request<super::UpdateReleasePipelineRequest>,
         ) -> std::result::Result<
-            tonic::Response<Self::GetReleaseFilesStream>,
+            tonic::Response<super::UpdateReleasePipelineResponse>,
             tonic::Status,
         >;
-        async fn push_logs(
-            &self,
-            request: tonic::Request<tonic::Streaming<super::PushLogRequest>>,
-        ) -> std::result::Result<tonic::Response<super::PushLogResponse>, tonic::Status>;
-        /// Server streaming response type for the GetSpecFiles method.
-        type GetSpecFilesStream: tonic::codegen::tokio_stream::Stream<
-                Item = std::result::Result<super::ReleaseFile, tonic::Status>,
-            >
-            + std::marker::Send
-            + 'static;
-        async fn get_spec_files(
-            &self,
-            request: tonic::Request<super::GetSpecFilesRequest>,
-        ) -> std::result::Result<
-            tonic::Response<Self::GetSpecFilesStream>,
-            tonic::Status,
-        >;
-        async fn get_release_annotation(
-            &self,
-            request: tonic::Request<super::GetReleaseAnnotationRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::ReleaseAnnotationResponse>,
-            tonic::Status,
-        >;
-        async fn get_project_info(
+        ///
+        async fn delete_release_pipeline(
             &self,
-            request: tonic::Request<super::GetProjectInfoRequest>,
+            request: tonic::Request<super::DeleteReleasePipelineRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::ProjectInfoResponse>,
+            tonic::Response<super::DeleteReleasePipelineResponse>,
             tonic::Status,
         >;
-        async fn complete_release(
+        ///
+        async fn list_release_pipelines(
             &self,
-            request: tonic::Request<super::CompleteReleaseRequest>,
+            request: tonic::Request<super::ListReleasePipelinesRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::CompleteReleaseResponse>,
+            tonic::Response<super::ListReleasePipelinesResponse>,
             tonic::Status,
         >;
     }
+    ///
     #[derive(Debug)]
-    pub struct RunnerServiceServer<T> {
+    pub struct ReleasePipelineServiceServer<T> {
         inner: Arc<T>,
         accept_compression_encodings: EnabledCompressionEncodings,
         send_compression_encodings: EnabledCompressionEncodings,
         max_decoding_message_size: Option<usize>,
         max_encoding_message_size: Option<usize>,
     }
-    impl<T> RunnerServiceServer<T> {
+    impl<T> ReleasePipelineServiceServer<T> {
         pub fn new(inner: T) -> Self {
             Self::from_arc(Arc::new(inner))
         }
@@ -11255,9 +11978,10 @@ pub mod runner_service_server {
             self
         }
     }
-    impl<T, B> tonic::codegen::Service<http::Request<B>> for RunnerServiceServer<T>
+    impl<T, B> tonic::codegen::Service<http::Request<B>>
+    for ReleasePipelineServiceServer<T>
     where
-        T: RunnerService,
+        T: ReleasePipelineService,
         B: Body + std::marker::Send + 'static,
         B::Error: Into<StdError> + std::marker::Send + 'static,
     {
@@ -11272,75 +11996,30 @@ pub mod runner_service_server {
         }
         fn call(&mut self, req: http::Request<B>) -> Self::Future {
             match req.uri().path() {
-                "/forest.v1.RunnerService/RegisterRunner" => {
-                    #[allow(non_camel_case_types)]
-                    struct RegisterRunnerSvc<T: RunnerService>(pub Arc<T>);
-                    impl<
-                        T: RunnerService,
-                    > tonic::server::StreamingService<super::RunnerMessage>
-                    for RegisterRunnerSvc<T> {
-                        type Response = super::ServerMessage;
-                        type ResponseStream = T::RegisterRunnerStream;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::ResponseStream>,
-                            tonic::Status,
-                        >;
-                        fn call(
-                            &mut self,
-                            request: tonic::Request<
-                                tonic::Streaming<super::RunnerMessage>,
-                            >,
-                        ) -> Self::Future {
-                            let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as RunnerService>::register_runner(&inner, request).await
-                            };
-                            Box::pin(fut)
-                        }
-                    }
-                    let accept_compression_encodings = self.accept_compression_encodings;
-                    let send_compression_encodings = self.send_compression_encodings;
-                    let max_decoding_message_size = self.max_decoding_message_size;
-                    let max_encoding_message_size = self.max_encoding_message_size;
-                    let inner = self.inner.clone();
-                    let fut = async move {
-                        let method = RegisterRunnerSvc(inner);
-                        let codec = tonic_prost::ProstCodec::default();
-                        let mut grpc = tonic::server::Grpc::new(codec)
-                            .apply_compression_config(
-                                accept_compression_encodings,
-                                send_compression_encodings,
-                            )
-                            .apply_max_message_size_config(
-                                max_decoding_message_size,
-                                max_encoding_message_size,
-                            );
-                        let res = grpc.streaming(method, req).await;
-                        Ok(res)
-                    };
-                    Box::pin(fut)
-                }
-                "/forest.v1.RunnerService/GetReleaseFiles" => {
+                "/forest.v1.ReleasePipelineService/CreateReleasePipeline" => {
                     #[allow(non_camel_case_types)]
-                    struct GetReleaseFilesSvc<T: RunnerService>(pub Arc<T>);
+                    struct CreateReleasePipelineSvc<T: ReleasePipelineService>(
+                        pub Arc<T>,
+                    );
                     impl<
-                        T: RunnerService,
-                    > tonic::server::ServerStreamingService<
-                        super::GetReleaseFilesRequest,
-                    > for GetReleaseFilesSvc<T> {
-                        type Response = super::ReleaseFile;
-                        type ResponseStream = T::GetReleaseFilesStream;
+                        T: ReleasePipelineService,
+                    > tonic::server::UnaryService<super::CreateReleasePipelineRequest>
+                    for CreateReleasePipelineSvc<T> {
+                        type Response = super::CreateReleasePipelineResponse;
                         type Future = BoxFuture<
-                            tonic::Response<Self::ResponseStream>,
+                            tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::GetReleaseFilesRequest>,
+                            request: tonic::Request<super::CreateReleasePipelineRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as RunnerService>::get_release_files(&inner, request)
+                                <T as ReleasePipelineService>::create_release_pipeline(
+                                        &inner,
+                                        request,
+                                    )
                                     .await
                             };
                             Box::pin(fut)
@@ -11352,7 +12031,7 @@ pub mod runner_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = GetReleaseFilesSvc(inner);
+                        let method = CreateReleasePipelineSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -11363,78 +12042,36 @@ pub mod runner_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.server_streaming(method, req).await;
+                        let res = grpc.unary(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/forest.v1.RunnerService/PushLogs" => {
+                "/forest.v1.ReleasePipelineService/UpdateReleasePipeline" => {
                     #[allow(non_camel_case_types)]
-                    struct PushLogsSvc<T: RunnerService>(pub Arc<T>);
+                    struct UpdateReleasePipelineSvc<T: ReleasePipelineService>(
+                        pub Arc<T>,
+                    );
                     impl<
-                        T: RunnerService,
-                    > tonic::server::ClientStreamingService<super::PushLogRequest>
-                    for PushLogsSvc<T> {
-                        type Response = super::PushLogResponse;
+                        T: ReleasePipelineService,
+                    > tonic::server::UnaryService<super::UpdateReleasePipelineRequest>
+                    for UpdateReleasePipelineSvc<T> {
+                        type Response = super::UpdateReleasePipelineResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<
-                                tonic::Streaming<super::PushLogRequest>,
-                            >,
-                        ) -> Self::Future {
-                            let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as RunnerService>::push_logs(&inner, request).await
-                            };
-                            Box::pin(fut)
-                        }
-                    }
-                    let accept_compression_encodings = self.accept_compression_encodings;
-                    let send_compression_encodings = self.send_compression_encodings;
-                    let max_decoding_message_size = self.max_decoding_message_size;
-                    let max_encoding_message_size = self.max_encoding_message_size;
-                    let inner = self.inner.clone();
-                    let fut = async move {
-                        let method = PushLogsSvc(inner);
-                        let codec = tonic_prost::ProstCodec::default();
-                        let mut grpc = tonic::server::Grpc::new(codec)
-                            .apply_compression_config(
-                                accept_compression_encodings,
-                                send_compression_encodings,
-                            )
-                            .apply_max_message_size_config(
-                                max_decoding_message_size,
-                                max_encoding_message_size,
-                            );
-                        let res = grpc.client_streaming(method, req).await;
-                        Ok(res)
-                    };
-                    Box::pin(fut)
-                }
-                "/forest.v1.RunnerService/GetSpecFiles" => {
-                    #[allow(non_camel_case_types)]
-                    struct GetSpecFilesSvc<T: RunnerService>(pub Arc<T>);
-                    impl<
-                        T: RunnerService,
-                    > tonic::server::ServerStreamingService<super::GetSpecFilesRequest>
-                    for GetSpecFilesSvc<T> {
-                        type Response = super::ReleaseFile;
-                        type ResponseStream = T::GetSpecFilesStream;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::ResponseStream>,
-                            tonic::Status,
-                        >;
-                        fn call(
-                            &mut self,
-                            request: tonic::Request<super::GetSpecFilesRequest>,
+                            request: tonic::Request<super::UpdateReleasePipelineRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as RunnerService>::get_spec_files(&inner, request).await
+                                <T as ReleasePipelineService>::update_release_pipeline(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -11445,7 +12082,7 @@ pub mod runner_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = GetSpecFilesSvc(inner);
+                        let method = UpdateReleasePipelineSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -11456,30 +12093,32 @@ pub mod runner_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.server_streaming(method, req).await;
+                        let res = grpc.unary(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/forest.v1.RunnerService/GetReleaseAnnotation" => {
+                "/forest.v1.ReleasePipelineService/DeleteReleasePipeline" => {
                     #[allow(non_camel_case_types)]
-                    struct GetReleaseAnnotationSvc<T: RunnerService>(pub Arc<T>);
+                    struct DeleteReleasePipelineSvc<T: ReleasePipelineService>(
+                        pub Arc<T>,
+                    );
                     impl<
-                        T: RunnerService,
-                    > tonic::server::UnaryService<super::GetReleaseAnnotationRequest>
-                    for GetReleaseAnnotationSvc<T> {
-                        type Response = super::ReleaseAnnotationResponse;
+                        T: ReleasePipelineService,
+                    > tonic::server::UnaryService<super::DeleteReleasePipelineRequest>
+                    for DeleteReleasePipelineSvc<T> {
+                        type Response = super::DeleteReleasePipelineResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::GetReleaseAnnotationRequest>,
+                            request: tonic::Request<super::DeleteReleasePipelineRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as RunnerService>::get_release_annotation(
+                                <T as ReleasePipelineService>::delete_release_pipeline(
                                         &inner,
                                         request,
                                     )
@@ -11494,7 +12133,7 @@ pub mod runner_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = GetReleaseAnnotationSvc(inner);
+                        let method = DeleteReleasePipelineSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -11510,25 +12149,30 @@ pub mod runner_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/forest.v1.RunnerService/GetProjectInfo" => {
+                "/forest.v1.ReleasePipelineService/ListReleasePipelines" => {
                     #[allow(non_camel_case_types)]
-                    struct GetProjectInfoSvc<T: RunnerService>(pub Arc<T>);
+                    struct ListReleasePipelinesSvc<T: ReleasePipelineService>(
+                        pub Arc<T>,
+                    );
                     impl<
-                        T: RunnerService,
-                    > tonic::server::UnaryService<super::GetProjectInfoRequest>
-                    for GetProjectInfoSvc<T> {
-                        type Response = super::ProjectInfoResponse;
+                        T: ReleasePipelineService,
+                    > tonic::server::UnaryService<super::ListReleasePipelinesRequest>
+                    for ListReleasePipelinesSvc<T> {
+                        type Response = super::ListReleasePipelinesResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::GetProjectInfoRequest>,
+                            request: tonic::Request<super::ListReleasePipelinesRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as RunnerService>::get_project_info(&inner, request)
+                                <T as ReleasePipelineService>::list_release_pipelines(
+                                        &inner,
+                                        request,
+                                    )
                                     .await
                             };
                             Box::pin(fut)
@@ -11540,53 +12184,7 @@ pub mod runner_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = GetProjectInfoSvc(inner);
-                        let codec = tonic_prost::ProstCodec::default();
-                        let mut grpc = tonic::server::Grpc::new(codec)
-                            .apply_compression_config(
-                                accept_compression_encodings,
-                                send_compression_encodings,
-                            )
-                            .apply_max_message_size_config(
-                                max_decoding_message_size,
-                                max_encoding_message_size,
-                            );
-                        let res = grpc.unary(method, req).await;
-                        Ok(res)
-                    };
-                    Box::pin(fut)
-                }
-                "/forest.v1.RunnerService/CompleteRelease" => {
-                    #[allow(non_camel_case_types)]
-                    struct CompleteReleaseSvc<T: RunnerService>(pub Arc<T>);
-                    impl<
-                        T: RunnerService,
-                    > tonic::server::UnaryService<super::CompleteReleaseRequest>
-                    for CompleteReleaseSvc<T> {
-                        type Response = super::CompleteReleaseResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
-                        fn call(
-                            &mut self,
-                            request: tonic::Request<super::CompleteReleaseRequest>,
-                        ) -> Self::Future {
-                            let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as RunnerService>::complete_release(&inner, request)
-                                    .await
-                            };
-                            Box::pin(fut)
-                        }
-                    }
-                    let accept_compression_encodings = self.accept_compression_encodings;
-                    let send_compression_encodings = self.send_compression_encodings;
-                    let max_decoding_message_size = self.max_decoding_message_size;
-                    let max_encoding_message_size = self.max_encoding_message_size;
-                    let inner = self.inner.clone();
-                    let fut = async move {
-                        let method = CompleteReleaseSvc(inner);
+                        let method = ListReleasePipelinesSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -11624,7 +12222,7 @@ pub mod runner_service_server {
             }
         }
     }
-    impl<T> Clone for RunnerServiceServer<T> {
+    impl<T> Clone for ReleasePipelineServiceServer<T> {
         fn clone(&self) -> Self {
             let inner = self.inner.clone();
             Self {
@@ -11637,13 +12235,13 @@ pub mod runner_service_server {
         }
     }
     /// Generated gRPC service name
-    pub const SERVICE_NAME: &str = "forest.v1.RunnerService";
-    impl<T> tonic::server::NamedService for RunnerServiceServer<T> {
+    pub const SERVICE_NAME: &str = "forest.v1.ReleasePipelineService";
+    impl<T> tonic::server::NamedService for ReleasePipelineServiceServer<T> {
         const NAME: &'static str = SERVICE_NAME;
     }
 }
 /// Generated client implementations.
-pub mod trigger_service_client {
+pub mod release_schedule_service_client {
     #![allow(
         unused_variables,
         dead_code,
@@ -11653,12 +12251,11 @@ pub mod trigger_service_client {
     )]
     use tonic::codegen::*;
     use tonic::codegen::http::Uri;
-    ///
     #[derive(Debug, Clone)]
-    pub struct TriggerServiceClient<T> {
+    pub struct ReleaseScheduleServiceClient<T> {
         inner: tonic::client::Grpc<T>,
     }
-    impl TriggerServiceClient<tonic::transport::Channel> {
+    impl ReleaseScheduleServiceClient<tonic::transport::Channel> {
         /// Attempt to create a new client by connecting to a given endpoint.
         pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
         where
@@ -11669,7 +12266,7 @@ pub mod trigger_service_client {
             Ok(Self::new(conn))
         }
     }
-    impl<T> TriggerServiceClient<T>
+    impl<T> ReleaseScheduleServiceClient<T>
     where
         T: tonic::client::GrpcService<tonic::body::Body>,
         T::Error: Into<StdError>,
@@ -11687,7 +12284,7 @@ pub mod trigger_service_client {
         pub fn with_interceptor<F>(
             inner: T,
             interceptor: F,
-        ) -> TriggerServiceClient<InterceptedService<T, F>>
+        ) -> ReleaseScheduleServiceClient<InterceptedService<T, F>>
         where
             F: tonic::service::Interceptor,
             T::ResponseBody: Default,
@@ -11701,7 +12298,9 @@ pub mod trigger_service_client {
                 http::Request<tonic::body::Body>,
             >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
         {
-            TriggerServiceClient::new(InterceptedService::new(inner, interceptor))
+            ReleaseScheduleServiceClient::new(
+                InterceptedService::new(inner, interceptor),
+            )
         }
         /// Compress requests with the given encoding.
         ///
@@ -11734,37 +12333,11 @@ pub mod trigger_service_client {
             self.inner = self.inner.max_encoding_message_size(limit);
             self
         }
-        ///
-        pub async fn create_trigger(
-            &mut self,
-            request: impl tonic::IntoRequest<super::CreateTriggerRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::CreateTriggerResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::unknown(
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
-            let codec = tonic_prost::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/forest.v1.TriggerService/CreateTrigger",
-            );
-            let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("forest.v1.TriggerService", "CreateTrigger"));
-            self.inner.unary(req, path, codec).await
-        }
-        ///
-        pub async fn update_trigger(
+        pub async fn create_release_schedule(
             &mut self,
-            request: impl tonic::IntoRequest<super::UpdateTriggerRequest>,
+            request: impl tonic::IntoRequest<super::CreateReleaseScheduleRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::UpdateTriggerResponse>,
+            tonic::Response<super::CreateReleaseScheduleResponse>,
             tonic::Status,
         > {
             self.inner
@@ -11777,19 +12350,23 @@ pub mod trigger_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/forest.v1.TriggerService/UpdateTrigger",
+                "/forest.v1.ReleaseScheduleService/CreateReleaseSchedule",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("forest.v1.TriggerService", "UpdateTrigger"));
+                .insert(
+                    GrpcMethod::new(
+                        "forest.v1.ReleaseScheduleService",
+                        "CreateReleaseSchedule",
+                    ),
+                );
             self.inner.unary(req, path, codec).await
         }
-        ///
-        pub async fn delete_trigger(
+        pub async fn list_release_schedules(
             &mut self,
-            request: impl tonic::IntoRequest<super::DeleteTriggerRequest>,
+            request: impl tonic::IntoRequest<super::ListReleaseSchedulesRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::DeleteTriggerResponse>,
+            tonic::Response<super::ListReleaseSchedulesResponse>,
             tonic::Status,
         > {
             self.inner
@@ -11802,19 +12379,23 @@ pub mod trigger_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/forest.v1.TriggerService/DeleteTrigger",
+                "/forest.v1.ReleaseScheduleService/ListReleaseSchedules",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("forest.v1.TriggerService", "DeleteTrigger"));
+                .insert(
+                    GrpcMethod::new(
+                        "forest.v1.ReleaseScheduleService",
+                        "ListReleaseSchedules",
+                    ),
+                );
             self.inner.unary(req, path, codec).await
         }
-        ///
-        pub async fn list_triggers(
+        pub async fn cancel_release_schedule(
             &mut self,
-            request: impl tonic::IntoRequest<super::ListTriggersRequest>,
+            request: impl tonic::IntoRequest<super::CancelReleaseScheduleRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::ListTriggersResponse>,
+            tonic::Response<super::CancelReleaseScheduleResponse>,
             tonic::Status,
         > {
             self.inner
@@ -11827,17 +12408,22 @@ pub mod trigger_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/forest.v1.TriggerService/ListTriggers",
+                "/forest.v1.ReleaseScheduleService/CancelReleaseSchedule",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("forest.v1.TriggerService", "ListTriggers"));
+                .insert(
+                    GrpcMethod::new(
+                        "forest.v1.ReleaseScheduleService",
+                        "CancelReleaseSchedule",
+                    ),
+                );
             self.inner.unary(req, path, codec).await
         }
     }
 }
 /// Generated server implementations.
-pub mod trigger_service_server {
+pub mod release_schedule_service_server {
     #![allow(
         unused_variables,
         dead_code,
@@ -11846,52 +12432,40 @@ pub mod trigger_service_server {
         clippy::let_unit_value,
     )]
     use tonic::codegen::*;
-    /// Generated trait containing gRPC methods that should be implemented for use with TriggerServiceServer.
+    /// Generated trait containing gRPC methods that should be implemented for use with ReleaseScheduleServiceServer.
     #[async_trait]
-    pub trait TriggerService: std::marker::Send + std::marker::Sync + 'static {
-        ///
-        async fn create_trigger(
-            &self,
-            request: tonic::Request<super::CreateTriggerRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::CreateTriggerResponse>,
-            tonic::Status,
-        >;
-        ///
-        async fn update_trigger(
+    pub trait ReleaseScheduleService: std::marker::Send + std::marker::Sync + 'static {
+        async fn create_release_schedule(
             &self,
-            request: tonic::Request<super::UpdateTriggerRequest>,
+            request: tonic::Request<super::CreateReleaseScheduleRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::UpdateTriggerResponse>,
+            tonic::Response<super::CreateReleaseScheduleResponse>,
             tonic::Status,
         >;
-        ///
-        async fn delete_trigger(
+        async fn list_release_schedules(
             &self,
-            request: tonic::Request<super::DeleteTriggerRequest>,
+            request: tonic::Request<super::ListReleaseSchedulesRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::DeleteTriggerResponse>,
+            tonic::Response<super::ListReleaseSchedulesResponse>,
             tonic::Status,
         >;
-        ///
-        async fn list_triggers(
+        async fn cancel_release_schedule(
             &self,
-            request: tonic::Request<super::ListTriggersRequest>,
+            request: tonic::Request<super::CancelReleaseScheduleRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::ListTriggersResponse>,
+            tonic::Response<super::CancelReleaseScheduleResponse>,
             tonic::Status,
         >;
     }
-    ///
     #[derive(Debug)]
-    pub struct TriggerServiceServer<T> {
+    pub struct ReleaseScheduleServiceServer<T> {
         inner: Arc<T>,
         accept_compression_encodings: EnabledCompressionEncodings,
         send_compression_encodings: EnabledCompressionEncodings,
         max_decoding_message_size: Option<usize>,
         max_encoding_message_size: Option<usize>,
     }
-    impl<T> TriggerServiceServer<T> {
+    impl<T> ReleaseScheduleServiceServer<T> {
         pub fn new(inner: T) -> Self {
             Self::from_arc(Arc::new(inner))
         }
@@ -11942,9 +12516,10 @@ pub mod trigger_service_server {
             self
         }
     }
-    impl<T, B> tonic::codegen::Service<http::Request<B>> for TriggerServiceServer<T>
+    impl<T, B> tonic::codegen::Service<http::Request<B>>
+    for ReleaseScheduleServiceServer<T>
     where
-        T: TriggerService,
+        T: ReleaseScheduleService,
         B: Body + std::marker::Send + 'static,
         B::Error: Into<StdError> + std::marker::Send + 'static,
     {
@@ -11959,70 +12534,31 @@ pub mod trigger_service_server {
         }
         fn call(&mut self, req: http::Request<B>) -> Self::Future {
             match req.uri().path() {
-                "/forest.v1.TriggerService/CreateTrigger" => {
-                    #[allow(non_camel_case_types)]
-                    struct CreateTriggerSvc<T: TriggerService>(pub Arc<T>);
-                    impl<
-                        T: TriggerService,
-                    > tonic::server::UnaryService<super::CreateTriggerRequest>
-                    for CreateTriggerSvc<T> {
-                        type Response = super::CreateTriggerResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
-                        fn call(
-                            &mut self,
-                            request: tonic::Request<super::CreateTriggerRequest>,
-                        ) -> Self::Future {
-                            let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as TriggerService>::create_trigger(&inner, request).await
-                            };
-                            Box::pin(fut)
-                        }
-                    }
-                    let accept_compression_encodings = self.accept_compression_encodings;
-                    let send_compression_encodings = self.send_compression_encodings;
-                    let max_decoding_message_size = self.max_decoding_message_size;
-                    let max_encoding_message_size = self.max_encoding_message_size;
-                    let inner = self.inner.clone();
-                    let fut = async move {
-                        let method = CreateTriggerSvc(inner);
-                        let codec = tonic_prost::ProstCodec::default();
-                        let mut grpc = tonic::server::Grpc::new(codec)
-                            .apply_compression_config(
-                                accept_compression_encodings,
-                                send_compression_encodings,
-                            )
-                            .apply_max_message_size_config(
-                                max_decoding_message_size,
-                                max_encoding_message_size,
-                            );
-                        let res = grpc.unary(method, req).await;
-                        Ok(res)
-                    };
-                    Box::pin(fut)
-                }
-                "/forest.v1.TriggerService/UpdateTrigger" => {
+                "/forest.v1.ReleaseScheduleService/CreateReleaseSchedule" => {
                     #[allow(non_camel_case_types)]
-                    struct UpdateTriggerSvc<T: TriggerService>(pub Arc<T>);
+                    struct CreateReleaseScheduleSvc<T: ReleaseScheduleService>(
+                        pub Arc<T>,
+                    );
                     impl<
-                        T: TriggerService,
-                    > tonic::server::UnaryService<super::UpdateTriggerRequest>
-                    for UpdateTriggerSvc<T> {
-                        type Response = super::UpdateTriggerResponse;
+                        T: ReleaseScheduleService,
+                    > tonic::server::UnaryService<super::CreateReleaseScheduleRequest>
+                    for CreateReleaseScheduleSvc<T> {
+                        type Response = super::CreateReleaseScheduleResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::UpdateTriggerRequest>,
+                            request: tonic::Request<super::CreateReleaseScheduleRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as TriggerService>::update_trigger(&inner, request).await
+                                <T as ReleaseScheduleService>::create_release_schedule(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -12033,7 +12569,7 @@ pub mod trigger_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = UpdateTriggerSvc(inner);
+                        let method = CreateReleaseScheduleSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -12049,25 +12585,31 @@ pub mod trigger_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/forest.v1.TriggerService/DeleteTrigger" => {
+                "/forest.v1.ReleaseScheduleService/ListReleaseSchedules" => {
                     #[allow(non_camel_case_types)]
-                    struct DeleteTriggerSvc<T: TriggerService>(pub Arc<T>);
+                    struct ListReleaseSchedulesSvc<T: ReleaseScheduleService>(
+                        pub Arc<T>,
+                    );
                     impl<
-                        T: TriggerService,
-                    > tonic::server::UnaryService<super::DeleteTriggerRequest>
-                    for DeleteTriggerSvc<T> {
-                        type Response = super::DeleteTriggerResponse;
+                        T: ReleaseScheduleService,
+                    > tonic::server::UnaryService<super::ListReleaseSchedulesRequest>
+                    for ListReleaseSchedulesSvc<T> {
+                        type Response = super::ListReleaseSchedulesResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::DeleteTriggerRequest>,
+                            request: tonic::Request<super::ListReleaseSchedulesRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as TriggerService>::delete_trigger(&inner, request).await
+                                <T as ReleaseScheduleService>::list_release_schedules(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -12078,7 +12620,7 @@ pub mod trigger_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = DeleteTriggerSvc(inner);
+                        let method = ListReleaseSchedulesSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -12094,25 +12636,31 @@ pub mod trigger_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/forest.v1.TriggerService/ListTriggers" => {
+                "/forest.v1.ReleaseScheduleService/CancelReleaseSchedule" => {
                     #[allow(non_camel_case_types)]
-                    struct ListTriggersSvc<T: TriggerService>(pub Arc<T>);
+                    struct CancelReleaseScheduleSvc<T: ReleaseScheduleService>(
+                        pub Arc<T>,
+                    );
                     impl<
-                        T: TriggerService,
-                    > tonic::server::UnaryService<super::ListTriggersRequest>
-                    for ListTriggersSvc<T> {
-                        type Response = super::ListTriggersResponse;
+                        T: ReleaseScheduleService,
+                    > tonic::server::UnaryService<super::CancelReleaseScheduleRequest>
+                    for CancelReleaseScheduleSvc<T> {
+                        type Response = super::CancelReleaseScheduleResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::ListTriggersRequest>,
+                            request: tonic::Request<super::CancelReleaseScheduleRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as TriggerService>::list_triggers(&inner, request).await
+                                <T as ReleaseScheduleService>::cancel_release_schedule(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -12123,7 +12671,7 @@ pub mod trigger_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListTriggersSvc(inner);
+                        let method = CancelReleaseScheduleSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -12161,7 +12709,7 @@ pub mod trigger_service_server {
             }
         }
     }
-    impl<T> Clone for TriggerServiceServer<T> {
+    impl<T> Clone for ReleaseScheduleServiceServer<T> {
         fn clone(&self) -> Self {
             let inner = self.inner.clone();
             Self {
@@ -12174,13 +12722,13 @@ pub mod trigger_service_server {
         }
     }
     /// Generated gRPC service name
-    pub const SERVICE_NAME: &str = "forest.v1.TriggerService";
-    impl<T> tonic::server::NamedService for TriggerServiceServer<T> {
+    pub const SERVICE_NAME: &str = "forest.v1.ReleaseScheduleService";
+    impl<T> tonic::server::NamedService for ReleaseScheduleServiceServer<T> {
         const NAME: &'static str = SERVICE_NAME;
     }
 }
 /// Generated client implementations.
-pub mod users_service_client {
+pub mod retention_service_client {
     #![allow(
         unused_variables,
         dead_code,
@@ -12191,10 +12739,10 @@ pub mod users_service_client {
     use tonic::codegen::*;
     use tonic::codegen::http::Uri;
     #[derive(Debug, Clone)]
-    pub struct UsersServiceClient<T> {
+    pub struct RetentionServiceClient<T> {
         inner: tonic::client::Grpc<T>,
     }
-    impl UsersServiceClient<tonic::transport::Channel> {
+    impl RetentionServiceClient<tonic::transport::Channel> {
         /// Attempt to create a new client by connecting to a given endpoint.
         pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
         where
@@ -12205,7 +12753,7 @@ pub mod users_service_client {
             Ok(Self::new(conn))
         }
     }
-    impl<T> UsersServiceClient<T>
+    impl<T> RetentionServiceClient<T>
     where
         T: tonic::client::GrpcService<tonic::body::Body>,
         T::Error: Into<StdError>,
@@ -12223,7 +12771,7 @@ pub mod users_service_client {
         pub fn with_interceptor<F>(
             inner: T,
             interceptor: F,
-        ) -> UsersServiceClient<InterceptedService<T, F>>
+        ) -> RetentionServiceClient<InterceptedService<T, F>>
         where
             F: tonic::service::Interceptor,
             T::ResponseBody: Default,
@@ -12237,7 +12785,7 @@ pub mod users_service_client {
                 http::Request<tonic::body::Body>,
             >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
         {
-            UsersServiceClient::new(InterceptedService::new(inner, interceptor))
+            RetentionServiceClient::new(InterceptedService::new(inner, interceptor))
         }
         /// Compress requests with the given encoding.
         ///
@@ -12270,11 +12818,11 @@ pub mod users_service_client {
             self.inner = self.inner.max_encoding_message_size(limit);
             self
         }
-        pub async fn register(
+        pub async fn get_retention_policy(
             &mut self,
-            request: impl tonic::IntoRequest<super::RegisterRequest>,
+            request: impl tonic::IntoRequest<super::GetRetentionPolicyRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::RegisterResponse>,
+            tonic::Response<super::GetRetentionPolicyResponse>,
             tonic::Status,
         > {
             self.inner
@@ -12287,17 +12835,22 @@ pub mod users_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/forest.v1.UsersService/Register",
+                "/forest.v1.RetentionService/GetRetentionPolicy",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("forest.v1.UsersService", "Register"));
+                .insert(
+                    GrpcMethod::new("forest.v1.RetentionService", "GetRetentionPolicy"),
+                );
             self.inner.unary(req, path, codec).await
         }
-        pub async fn login(
+        pub async fn set_retention_policy(
             &mut self,
-            request: impl tonic::IntoRequest<super::LoginRequest>,
-        ) -> std::result::Result<tonic::Response<super::LoginResponse>, tonic::Status> {
+            request: impl tonic::IntoRequest<super::SetRetentionPolicyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetRetentionPolicyResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -12308,18 +12861,20 @@ pub mod users_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/forest.v1.UsersService/Login",
+                "/forest.v1.RetentionService/SetRetentionPolicy",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("forest.v1.UsersService", "Login"));
+                .insert(
+                    GrpcMethod::new("forest.v1.RetentionService", "SetRetentionPolicy"),
+                );
             self.inner.unary(req, path, codec).await
         }
-        pub async fn refresh_token(
+        pub async fn preview_retention(
             &mut self,
-            request: impl tonic::IntoRequest<super::RefreshTokenRequest>,
+            request: impl tonic::IntoRequest<super::PreviewRetentionRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::RefreshTokenResponse>,
+            tonic::Response<super::PreviewRetentionResponse>,
             tonic::Status,
         > {
             self.inner
@@ -12332,17 +12887,2327 @@ pub mod users_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/forest.v1.UsersService/RefreshToken",
+                "/forest.v1.RetentionService/PreviewRetention",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("forest.v1.UsersService", "RefreshToken"));
+                .insert(
+                    GrpcMethod::new("forest.v1.RetentionService", "PreviewRetention"),
+                );
             self.inner.unary(req, path, codec).await
         }
-        pub async fn logout(
-            &mut self,
-            request: impl tonic::IntoRequest<super::LogoutRequest>,
-        ) -> std::result::Result<tonic::Response<super::LogoutResponse>, tonic::Status> {
+    }
+}
+/// Generated server implementations.
+pub mod retention_service_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with RetentionServiceServer.
+    #[async_trait]
+    pub trait RetentionService: std::marker::Send + std::marker::Sync + 'static {
+        async fn get_retention_policy(
+            &self,
+            request: tonic::Request<super::GetRetentionPolicyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetRetentionPolicyResponse>,
+            tonic::Status,
+        >;
+        async fn set_retention_policy(
+            &self,
+            request: tonic::Request<super::SetRetentionPolicyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetRetentionPolicyResponse>,
+            tonic::Status,
+        >;
+        async fn preview_retention(
+            &self,
+            request: tonic::Request<super::PreviewRetentionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PreviewRetentionResponse>,
+            tonic::Status,
+        >;
+    }
+    #[derive(Debug)]
+    pub struct RetentionServiceServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> RetentionServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for RetentionServiceServer<T>
+    where
+        T: RetentionService,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/forest.v1.RetentionService/GetRetentionPolicy" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetRetentionPolicySvc<T: RetentionService>(pub Arc<T>);
+                    impl<
+                        T: RetentionService,
+                    > tonic::server::UnaryService<super::GetRetentionPolicyRequest>
+                    for GetRetentionPolicySvc<T> {
+                        type Response = super::GetRetentionPolicyResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetRetentionPolicyRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as RetentionService>::get_retention_policy(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetRetentionPolicySvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/forest.v1.RetentionService/SetRetentionPolicy" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetRetentionPolicySvc<T: RetentionService>(pub Arc<T>);
+                    impl<
+                        T: RetentionService,
+                    > tonic::server::UnaryService<super::SetRetentionPolicyRequest>
+                    for SetRetentionPolicySvc<T> {
+                        type Response = super::SetRetentionPolicyResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetRetentionPolicyRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as RetentionService>::set_retention_policy(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SetRetentionPolicySvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/forest.v1.RetentionService/PreviewRetention" => {
+                    #[allow(non_camel_case_types)]
+                    struct PreviewRetentionSvc<T: RetentionService>(pub Arc<T>);
+                    impl<
+                        T: RetentionService,
+                    > tonic::server::UnaryService<super::PreviewRetentionRequest>
+                    for PreviewRetentionSvc<T> {
+                        type Response = super::PreviewRetentionResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PreviewRetentionRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as RetentionService>::preview_retention(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PreviewRetentionSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(
+                            tonic::body::Body::default(),
+                        );
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for RetentionServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "forest.v1.RetentionService";
+    impl<T> tonic::server::NamedService for RetentionServiceServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+/// Generated client implementations.
+pub mod runner_service_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    #[derive(Debug, Clone)]
+    pub struct RunnerServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl RunnerServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> RunnerServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> RunnerServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::Body>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            RunnerServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        pub async fn register_runner(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<Message = super::RunnerMessage>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ServerMessage>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/forest.v1.RunnerService/RegisterRunner",
+            );
+            let mut req = request.into_streaming_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("forest.v1.RunnerService", "RegisterRunner"));
+            self.inner.streaming(req, path, codec).await
+        }
+        pub async fn get_release_files(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetReleaseFilesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ReleaseFile>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/forest.v1.RunnerService/GetReleaseFiles",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("forest.v1.RunnerService", "GetReleaseFiles"));
+            self.inner.server_streaming(req, path, codec).await
+        }
+        pub async fn push_logs(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<Message = super::PushLogRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PushLogResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/forest.v1.RunnerService/PushLogs",
+            );
+            let mut req = request.into_streaming_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("forest.v1.RunnerService", "PushLogs"));
+            self.inner.client_streaming(req, path, codec).await
+        }
+        pub async fn get_spec_files(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetSpecFilesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ReleaseFile>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/forest.v1.RunnerService/GetSpecFiles",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("forest.v1.RunnerService", "GetSpecFiles"));
+            self.inner.server_streaming(req, path, codec).await
+        }
+        pub async fn get_release_annotation(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetReleaseAnnotationRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ReleaseAnnotationResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/forest.v1.RunnerService/GetReleaseAnnotation",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("forest.v1.RunnerService", "GetReleaseAnnotation"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_project_info(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetProjectInfoRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ProjectInfoResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/forest.v1.RunnerService/GetProjectInfo",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("forest.v1.RunnerService", "GetProjectInfo"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn complete_release(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CompleteReleaseRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CompleteReleaseResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/forest.v1.RunnerService/CompleteRelease",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("forest.v1.RunnerService", "CompleteRelease"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_runners(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListRunnersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListRunnersResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/forest.v1.RunnerService/ListRunners",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("forest.v1.RunnerService", "ListRunners"));
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod runner_service_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with RunnerServiceServer.
+    #[async_trait]
+    pub trait RunnerService: std::marker::Send + std::marker::Sync + 'static {
+        /// Server streaming response type for the RegisterRunner method.
+        type RegisterRunnerStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::ServerMessage, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        async fn register_runner(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::RunnerMessage>>,
+        ) -> std::result::Result<
+            tonic::Response<Self::RegisterRunnerStream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the GetReleaseFiles method.
+        type GetReleaseFilesStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::ReleaseFile, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        async fn get_release_files(
+            &self,
+            request: tonic::Request<super::GetReleaseFilesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::GetReleaseFilesStream>,
+            tonic::Status,
+        >;
+        async fn push_logs(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::PushLogRequest>>,
+        ) -> std::result::Result<tonic::Response<super::PushLogResponse>, tonic::Status>;
+        /// Server streaming response type for the GetSpecFiles method.
+        type GetSpecFilesStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::ReleaseFile, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        async fn get_spec_files(
+            &self,
+            request: tonic::Request<super::GetSpecFilesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::GetSpecFilesStream>,
+            tonic::Status,
+        >;
+        async fn get_release_annotation(
+            &self,
+            request: tonic::Request<super::GetReleaseAnnotationRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ReleaseAnnotationResponse>,
+            tonic::Status,
+        >;
+        async fn get_project_info(
+            &self,
+            request: tonic::Request<super::GetProjectInfoRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ProjectInfoResponse>,
+            tonic::Status,
+        >;
+        async fn complete_release(
+            &self,
+            request: tonic::Request<super::CompleteReleaseRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CompleteReleaseResponse>,
+            tonic::Status,
+        >;
+        async fn list_runners(
+            &self,
+            request: tonic::Request<super::ListRunnersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListRunnersResponse>,
+            tonic::Status,
+        >;
+    }
+    #[derive(Debug)]
+    pub struct RunnerServiceServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> RunnerServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for RunnerServiceServer<T>
+    where
+        T: RunnerService,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/forest.v1.RunnerService/RegisterRunner" => {
+                    #[allow(non_camel_case_types)]
+                    struct RegisterRunnerSvc<T: RunnerService>(pub Arc<T>);
+                    impl<
+                        T: RunnerService,
+                    > tonic::server::StreamingService<super::RunnerMessage>
+                    for RegisterRunnerSvc<T> {
+                        type Response = super::ServerMessage;
+                        type ResponseStream = T::RegisterRunnerStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                tonic::Streaming<super::RunnerMessage>,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as RunnerService>::register_runner(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = RegisterRunnerSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/forest.v1.RunnerService/GetReleaseFiles" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetReleaseFilesSvc<T: RunnerService>(pub Arc<T>);
+                    impl<
+                        T: RunnerService,
+                    > tonic::server::ServerStreamingService<
+                        super::GetReleaseFilesRequest,
+                    > for GetReleaseFilesSvc<T> {
+                        type Response = super::ReleaseFile;
+                        type ResponseStream = T::GetReleaseFilesStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetReleaseFilesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as RunnerService>::get_release_files(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetReleaseFilesSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/forest.v1.RunnerService/PushLogs" => {
+                    #[allow(non_camel_case_types)]
+                    struct PushLogsSvc<T: RunnerService>(pub Arc<T>);
+                    impl<
+                        T: RunnerService,
+                    > tonic::server::ClientStreamingService<super::PushLogRequest>
+                    for PushLogsSvc<T> {
+                        type Response = super::PushLogResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                tonic::Streaming<super::PushLogRequest>,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as RunnerService>::push_logs(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PushLogsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.client_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/forest.v1.RunnerService/GetSpecFiles" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetSpecFilesSvc<T: RunnerService>(pub Arc<T>);
+                    impl<
+                        T: RunnerService,
+                    > tonic::server::ServerStreamingService<super::GetSpecFilesRequest>
+                    for GetSpecFilesSvc<T> {
+                        type Response = super::ReleaseFile;
+                        type ResponseStream = T::GetSpecFilesStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetSpecFilesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as RunnerService>::get_spec_files(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetSpecFilesSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/forest.v1.RunnerService/GetReleaseAnnotation" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetReleaseAnnotationSvc<T: RunnerService>(pub Arc<T>);
+                    impl<
+                        T: RunnerService,
+                    > tonic::server::UnaryService<super::GetReleaseAnnotationRequest>
+                    for GetReleaseAnnotationSvc<T> {
+                        type Response = super::ReleaseAnnotationResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetReleaseAnnotationRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as RunnerService>::get_release_annotation(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetReleaseAnnotationSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/forest.v1.RunnerService/GetProjectInfo" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetProjectInfoSvc<T: RunnerService>(pub Arc<T>);
+                    impl<
+                        T: RunnerService,
+                    > tonic::server::UnaryService<super::GetProjectInfoRequest>
+                    for GetProjectInfoSvc<T> {
+                        type Response = super::ProjectInfoResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetProjectInfoRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as RunnerService>::get_project_info(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetProjectInfoSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/forest.v1.RunnerService/CompleteRelease" => {
+                    #[allow(non_camel_case_types)]
+                    struct CompleteReleaseSvc<T: RunnerService>(pub Arc<T>);
+                    impl<
+                        T: RunnerService,
+                    > tonic::server::UnaryService<super::CompleteReleaseRequest>
+                    for CompleteReleaseSvc<T> {
+                        type Response = super::CompleteReleaseResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CompleteReleaseRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as RunnerService>::complete_release(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CompleteReleaseSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/forest.v1.RunnerService/ListRunners" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListRunnersSvc<T: RunnerService>(pub Arc<T>);
+                    impl<
+                        T: RunnerService,
+                    > tonic::server::UnaryService<super::ListRunnersRequest>
+                    for ListRunnersSvc<T> {
+                        type Response = super::ListRunnersResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListRunnersRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as RunnerService>::list_runners(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListRunnersSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(
+                            tonic::body::Body::default(),
+                        );
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for RunnerServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "forest.v1.RunnerService";
+    impl<T> tonic::server::NamedService for RunnerServiceServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+/// Generated client implementations.
+pub mod secret_service_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    #[derive(Debug, Clone)]
+    pub struct SecretServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl SecretServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> SecretServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> SecretServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::Body>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            SecretServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        pub async fn set_secret(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetSecretRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetSecretResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/forest.v1.SecretService/SetSecret",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("forest.v1.SecretService", "SetSecret"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_secrets(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListSecretsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListSecretsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/forest.v1.SecretService/ListSecrets",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("forest.v1.SecretService", "ListSecrets"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn delete_secret(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeleteSecretRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteSecretResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/forest.v1.SecretService/DeleteSecret",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("forest.v1.SecretService", "DeleteSecret"));
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod secret_service_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with SecretServiceServer.
+    #[async_trait]
+    pub trait SecretService: std::marker::Send + std::marker::Sync + 'static {
+        async fn set_secret(
+            &self,
+            request: tonic::Request<super::SetSecretRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetSecretResponse>,
+            tonic::Status,
+        >;
+        async fn list_secrets(
+            &self,
+            request: tonic::Request<super::ListSecretsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListSecretsResponse>,
+            tonic::Status,
+        >;
+        async fn delete_secret(
+            &self,
+            request: tonic::Request<super::DeleteSecretRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteSecretResponse>,
+            tonic::Status,
+        >;
+    }
+    #[derive(Debug)]
+    pub struct SecretServiceServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> SecretServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for SecretServiceServer<T>
+    where
+        T: SecretService,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/forest.v1.SecretService/SetSecret" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetSecretSvc<T: SecretService>(pub Arc<T>);
+                    impl<
+                        T: SecretService,
+                    > tonic::server::UnaryService<super::SetSecretRequest>
+                    for SetSecretSvc<T> {
+                        type Response = super::SetSecretResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetSecretRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SecretService>::set_secret(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SetSecretSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/forest.v1.SecretService/ListSecrets" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListSecretsSvc<T: SecretService>(pub Arc<T>);
+                    impl<
+                        T: SecretService,
+                    > tonic::server::UnaryService<super::ListSecretsRequest>
+                    for ListSecretsSvc<T> {
+                        type Response = super::ListSecretsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListSecretsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SecretService>::list_secrets(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListSecretsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/forest.v1.SecretService/DeleteSecret" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeleteSecretSvc<T: SecretService>(pub Arc<T>);
+                    impl<
+                        T: SecretService,
+                    > tonic::server::UnaryService<super::DeleteSecretRequest>
+                    for DeleteSecretSvc<T> {
+                        type Response = super::DeleteSecretResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DeleteSecretRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SecretService>::delete_secret(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DeleteSecretSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(
+                            tonic::body::Body::default(),
+                        );
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for SecretServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "forest.v1.SecretService";
+    impl<T> tonic::server::NamedService for SecretServiceServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+/// Generated client implementations.
+pub mod trigger_service_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    ///
+    #[derive(Debug, Clone)]
+    pub struct TriggerServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl TriggerServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> TriggerServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> TriggerServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::Body>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            TriggerServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        ///
+        pub async fn create_trigger(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CreateTriggerRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CreateTriggerResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/forest.v1.TriggerService/CreateTrigger",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("forest.v1.TriggerService", "CreateTrigger"));
+            self.inner.unary(req, path, codec).await
+        }
+        ///
+        pub async fn update_trigger(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UpdateTriggerRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdateTriggerResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/forest.v1.TriggerService/UpdateTrigger",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("forest.v1.TriggerService", "UpdateTrigger"));
+            self.inner.unary(req, path, codec).await
+        }
+        ///
+        pub async fn delete_trigger(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeleteTriggerRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteTriggerResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/forest.v1.TriggerService/DeleteTrigger",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("forest.v1.TriggerService", "DeleteTrigger"));
+            self.inner.unary(req, path, codec).await
+        }
+        ///
+        pub async fn list_triggers(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListTriggersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListTriggersResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/forest.v1.TriggerService/ListTriggers",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("forest.v1.TriggerService", "ListTriggers"));
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod trigger_service_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with TriggerServiceServer.
+    #[async_trait]
+    pub trait TriggerService: std::marker::Send + std::marker::Sync + 'static {
+        ///
+        async fn create_trigger(
+            &self,
+            request: tonic::Request<super::CreateTriggerRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CreateTriggerResponse>,
+            tonic::Status,
+        >;
+        ///
+        async fn update_trigger(
+            &self,
+            request: tonic::Request<super::UpdateTriggerRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdateTriggerResponse>,
+            tonic::Status,
+        >;
+        ///
+        async fn delete_trigger(
+            &self,
+            request: tonic::Request<super::DeleteTriggerRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteTriggerResponse>,
+            tonic::Status,
+        >;
+        ///
+        async fn list_triggers(
+            &self,
+            request: tonic::Request<super::ListTriggersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListTriggersResponse>,
+            tonic::Status,
+        >;
+    }
+    ///
+    #[derive(Debug)]
+    pub struct TriggerServiceServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> TriggerServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for TriggerServiceServer<T>
+    where
+        T: TriggerService,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/forest.v1.TriggerService/CreateTrigger" => {
+                    #[allow(non_camel_case_types)]
+                    struct CreateTriggerSvc<T: TriggerService>(pub Arc<T>);
+                    impl<
+                        T: TriggerService,
+                    > tonic::server::UnaryService<super::CreateTriggerRequest>
+                    for CreateTriggerSvc<T> {
+                        type Response = super::CreateTriggerResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CreateTriggerRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TriggerService>::create_trigger(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CreateTriggerSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/forest.v1.TriggerService/UpdateTrigger" => {
+                    #[allow(non_camel_case_types)]
+                    struct UpdateTriggerSvc<T: TriggerService>(pub Arc<T>);
+                    impl<
+                        T: TriggerService,
+                    > tonic::server::UnaryService<super::UpdateTriggerRequest>
+                    for UpdateTriggerSvc<T> {
+                        type Response = super::UpdateTriggerResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UpdateTriggerRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TriggerService>::update_trigger(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = UpdateTriggerSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/forest.v1.TriggerService/DeleteTrigger" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeleteTriggerSvc<T: TriggerService>(pub Arc<T>);
+                    impl<
+                        T: TriggerService,
+                    > tonic::server::UnaryService<super::DeleteTriggerRequest>
+                    for DeleteTriggerSvc<T> {
+                        type Response = super::DeleteTriggerResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DeleteTriggerRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TriggerService>::delete_trigger(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DeleteTriggerSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/forest.v1.TriggerService/ListTriggers" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListTriggersSvc<T: TriggerService>(pub Arc<T>);
+                    impl<
+                        T: TriggerService,
+                    > tonic::server::UnaryService<super::ListTriggersRequest>
+                    for ListTriggersSvc<T> {
+                        type Response = super::ListTriggersResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListTriggersRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TriggerService>::list_triggers(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListTriggersSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(
+                            tonic::body::Body::default(),
+                        );
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for TriggerServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "forest.v1.TriggerService";
+    impl<T> tonic::server::NamedService for TriggerServiceServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+/// Generated client implementations.
+pub mod users_service_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    #[derive(Debug, Clone)]
+    pub struct UsersServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl UsersServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> UsersServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> UsersServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::Body>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            UsersServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        pub async fn register(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RegisterRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RegisterResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/forest.v1.UsersService/Register",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("forest.v1.UsersService", "Register"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn login(
+            &mut self,
+            request: impl tonic::IntoRequest<super::LoginRequest>,
+        ) -> std::result::Result<tonic::Response<super::LoginResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/forest.v1.UsersService/Login",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("forest.v1.UsersService", "Login"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn refresh_token(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RefreshTokenRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RefreshTokenResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/forest.v1.UsersService/RefreshToken",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("forest.v1.UsersService", "RefreshToken"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn logout(
+            &mut self,
+            request: impl tonic::IntoRequest<super::LogoutRequest>,
+        ) -> std::result::Result<tonic::Response<super::LogoutResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -12880,6 +15745,207 @@ pub mod users_service_client {
                 .insert(GrpcMethod::new("forest.v1.UsersService", "VerifyLoginMfa"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn begin_passkey_registration(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BeginPasskeyRegistrationRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BeginPasskeyRegistrationResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/forest.v1.UsersService/BeginPasskeyRegistration",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("forest.v1.UsersService", "BeginPasskeyRegistration"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn finish_passkey_registration(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FinishPasskeyRegistrationRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::FinishPasskeyRegistrationResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/forest.v1.UsersService/FinishPasskeyRegistration",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "forest.v1.UsersService",
+                        "FinishPasskeyRegistration",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_passkeys(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListPasskeysRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListPasskeysResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/forest.v1.UsersService/ListPasskeys",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("forest.v1.UsersService", "ListPasskeys"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn rename_passkey(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RenamePasskeyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RenamePasskeyResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/forest.v1.UsersService/RenamePasskey",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("forest.v1.UsersService", "RenamePasskey"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn delete_passkey(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeletePasskeyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeletePasskeyResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/forest.v1.UsersService/DeletePasskey",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("forest.v1.UsersService", "DeletePasskey"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn begin_passkey_login(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BeginPasskeyLoginRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BeginPasskeyLoginResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/forest.v1.UsersService/BeginPasskeyLogin",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("forest.v1.UsersService", "BeginPasskeyLogin"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn finish_passkey_login(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FinishPasskeyLoginRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::FinishPasskeyLoginResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/forest.v1.UsersService/FinishPasskeyLogin",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("forest.v1.UsersService", "FinishPasskeyLogin"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn begin_passkey_verification(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BeginPasskeyVerificationRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BeginPasskeyVerificationResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/forest.v1.UsersService/BeginPasskeyVerification",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("forest.v1.UsersService", "BeginPasskeyVerification"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn initiate_device_login(
             &mut self,
             request: impl tonic::IntoRequest<super::InitiateDeviceLoginRequest>,
@@ -13105,58 +16171,114 @@ pub mod users_service_server {
         >;
         async fn create_personal_access_token(
             &self,
-            request: tonic::Request<super::CreatePersonalAccessTokenRequest>,
+            request: tonic::Request<super::CreatePersonalAccessTokenRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CreatePersonalAccessTokenResponse>,
+            tonic::Status,
+        >;
+        async fn list_personal_access_tokens(
+            &self,
+            request: tonic::Request<super::ListPersonalAccessTokensRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListPersonalAccessTokensResponse>,
+            tonic::Status,
+        >;
+        async fn delete_personal_access_token(
+            &self,
+            request: tonic::Request<super::DeletePersonalAccessTokenRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeletePersonalAccessTokenResponse>,
+            tonic::Status,
+        >;
+        async fn token_info(
+            &self,
+            request: tonic::Request<super::TokenInfoRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TokenInfoResponse>,
+            tonic::Status,
+        >;
+        async fn setup_mfa(
+            &self,
+            request: tonic::Request<super::SetupMfaRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetupMfaResponse>,
+            tonic::Status,
+        >;
+        async fn verify_mfa(
+            &self,
+            request: tonic::Request<super::VerifyMfaRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::VerifyMfaResponse>,
+            tonic::Status,
+        >;
+        async fn disable_mfa(
+            &self,
+            request: tonic::Request<super::DisableMfaRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DisableMfaResponse>,
+            tonic::Status,
+        >;
+        async fn verify_login_mfa(
+            &self,
+            request: tonic::Request<super::VerifyLoginMfaRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::VerifyLoginMfaResponse>,
+            tonic::Status,
+        >;
+        async fn begin_passkey_registration(
+            &self,
+            request: tonic::Request<super::BeginPasskeyRegistrationRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::CreatePersonalAccessTokenResponse>,
+            tonic::Response<super::BeginPasskeyRegistrationResponse>,
             tonic::Status,
         >;
-        async fn list_personal_access_tokens(
+        async fn finish_passkey_registration(
             &self,
-            request: tonic::Request<super::ListPersonalAccessTokensRequest>,
+            request: tonic::Request<super::FinishPasskeyRegistrationRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::ListPersonalAccessTokensResponse>,
+            tonic::Response<super::FinishPasskeyRegistrationResponse>,
             tonic::Status,
         >;
-        async fn delete_personal_access_token(
+        async fn list_passkeys(
             &self,
-            request: tonic::Request<super::DeletePersonalAccessTokenRequest>,
+            request: tonic::Request<super::ListPasskeysRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::DeletePersonalAccessTokenResponse>,
+            tonic::Response<super::ListPasskeysResponse>,
             tonic::Status,
         >;
-        async fn token_info(
+        async fn rename_passkey(
             &self,
-            request: tonic::Request<super::TokenInfoRequest>,
+            request: tonic::Request<super::RenamePasskeyRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::TokenInfoResponse>,
+            tonic::Response<super::RenamePasskeyResponse>,
             tonic::Status,
         >;
-        async fn setup_mfa(
+        async fn delete_passkey(
             &self,
-            request: tonic::Request<super::SetupMfaRequest>,
+            request: tonic::Request<super::DeletePasskeyRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::SetupMfaResponse>,
+            tonic::Response<super::DeletePasskeyResponse>,
             tonic::Status,
         >;
-        async fn verify_mfa(
+        async fn begin_passkey_login(
             &self,
-            request: tonic::Request<super::VerifyMfaRequest>,
+            request: tonic::Request<super::BeginPasskeyLoginRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::VerifyMfaResponse>,
+            tonic::Response<super::BeginPasskeyLoginResponse>,
             tonic::Status,
         >;
-        async fn disable_mfa(
+        async fn finish_passkey_login(
             &self,
-            request: tonic::Request<super::DisableMfaRequest>,
+            request: tonic::Request<super::FinishPasskeyLoginRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::DisableMfaResponse>,
+            tonic::Response<super::FinishPasskeyLoginResponse>,
             tonic::Status,
         >;
-        async fn verify_login_mfa(
+        async fn begin_passkey_verification(
             &self,
-            request: tonic::Request<super::VerifyLoginMfaRequest>,
+            request: tonic::Request<super::BeginPasskeyVerificationRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::VerifyLoginMfaResponse>,
+            tonic::Response<super::BeginPasskeyVerificationResponse>,
             tonic::Status,
         >;
         async fn initiate_device_login(
@@ -13266,23 +16388,292 @@ pub mod users_service_server {
             match req.uri().path() {
                 "/forest.v1.UsersService/Register" => {
                     #[allow(non_camel_case_types)]
-                    struct RegisterSvc<T: UsersService>(pub Arc<T>);
+                    struct RegisterSvc<T: UsersService>(pub Arc<T>);
+                    impl<
+                        T: UsersService,
+                    > tonic::server::UnaryService<super::RegisterRequest>
+                    for RegisterSvc<T> {
+                        type Response = super::RegisterResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RegisterRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as UsersService>::register(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = RegisterSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/forest.v1.UsersService/Login" => {
+                    #[allow(non_camel_case_types)]
+                    struct LoginSvc<T: UsersService>(pub Arc<T>);
+                    impl<
+                        T: UsersService,
+                    > tonic::server::UnaryService<super::LoginRequest> for LoginSvc<T> {
+                        type Response = super::LoginResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::LoginRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as UsersService>::login(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = LoginSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/forest.v1.UsersService/RefreshToken" => {
+                    #[allow(non_camel_case_types)]
+                    struct RefreshTokenSvc<T: UsersService>(pub Arc<T>);
+                    impl<
+                        T: UsersService,
+                    > tonic::server::UnaryService<super::RefreshTokenRequest>
+                    for RefreshTokenSvc<T> {
+                        type Response = super::RefreshTokenResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RefreshTokenRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as UsersService>::refresh_token(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = RefreshTokenSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/forest.v1.UsersService/Logout" => {
+                    #[allow(non_camel_case_types)]
+                    struct LogoutSvc<T: UsersService>(pub Arc<T>);
+                    impl<
+                        T: UsersService,
+                    > tonic::server::UnaryService<super::LogoutRequest>
+                    for LogoutSvc<T> {
+                        type Response = super::LogoutResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::LogoutRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as UsersService>::logout(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = LogoutSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/forest.v1.UsersService/GetUser" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetUserSvc<T: UsersService>(pub Arc<T>);
+                    impl<
+                        T: UsersService,
+                    > tonic::server::UnaryService<super::GetUserRequest>
+                    for GetUserSvc<T> {
+                        type Response = super::GetUserResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetUserRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as UsersService>::get_user(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetUserSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/forest.v1.UsersService/UpdateUser" => {
+                    #[allow(non_camel_case_types)]
+                    struct UpdateUserSvc<T: UsersService>(pub Arc<T>);
+                    impl<
+                        T: UsersService,
+                    > tonic::server::UnaryService<super::UpdateUserRequest>
+                    for UpdateUserSvc<T> {
+                        type Response = super::UpdateUserResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UpdateUserRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as UsersService>::update_user(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = UpdateUserSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/forest.v1.UsersService/DeleteUser" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeleteUserSvc<T: UsersService>(pub Arc<T>);
                     impl<
                         T: UsersService,
-                    > tonic::server::UnaryService<super::RegisterRequest>
-                    for RegisterSvc<T> {
-                        type Response = super::RegisterResponse;
+                    > tonic::server::UnaryService<super::DeleteUserRequest>
+                    for DeleteUserSvc<T> {
+                        type Response = super::DeleteUserResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RegisterRequest>,
+                            request: tonic::Request<super::DeleteUserRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as UsersService>::register(&inner, request).await
+                                <T as UsersService>::delete_user(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -13293,7 +16684,7 @@ pub mod users_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = RegisterSvc(inner);
+                        let method = DeleteUserSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -13309,24 +16700,25 @@ pub mod users_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/forest.v1.UsersService/Login" => {
+                "/forest.v1.UsersService/ListUsers" => {
                     #[allow(non_camel_case_types)]
-                    struct LoginSvc<T: UsersService>(pub Arc<T>);
+                    struct ListUsersSvc<T: UsersService>(pub Arc<T>);
                     impl<
                         T: UsersService,
-                    > tonic::server::UnaryService<super::LoginRequest> for LoginSvc<T> {
-                        type Response = super::LoginResponse;
+                    > tonic::server::UnaryService<super::ListUsersRequest>
+                    for ListUsersSvc<T> {
+                        type Response = super::ListUsersResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::LoginRequest>,
+                            request: tonic::Request<super::ListUsersRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as UsersService>::login(&inner, request).await
+                                <T as UsersService>::list_users(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -13337,7 +16729,7 @@ pub mod users_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = LoginSvc(inner);
+                        let method = ListUsersSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -13353,25 +16745,25 @@ pub mod users_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/forest.v1.UsersService/RefreshToken" => {
+                "/forest.v1.UsersService/GetUserStats" => {
                     #[allow(non_camel_case_types)]
-                    struct RefreshTokenSvc<T: UsersService>(pub Arc<T>);
+                    struct GetUserStatsSvc<T: UsersService>(pub Arc<T>);
                     impl<
                         T: UsersService,
-                    > tonic::server::UnaryService<super::RefreshTokenRequest>
-                    for RefreshTokenSvc<T> {
-                        type Response = super::RefreshTokenResponse;
+                    > tonic::server::UnaryService<super::GetUserStatsRequest>
+                    for GetUserStatsSvc<T> {
+                        type Response = super::GetUserStatsResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RefreshTokenRequest>,
+                            request: tonic::Request<super::GetUserStatsRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as UsersService>::refresh_token(&inner, request).await
+                                <T as UsersService>::get_user_stats(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -13382,7 +16774,7 @@ pub mod users_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = RefreshTokenSvc(inner);
+                        let method = GetUserStatsSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -13398,25 +16790,25 @@ pub mod users_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/forest.v1.UsersService/Logout" => {
+                "/forest.v1.UsersService/ChangePassword" => {
                     #[allow(non_camel_case_types)]
-                    struct LogoutSvc<T: UsersService>(pub Arc<T>);
+                    struct ChangePasswordSvc<T: UsersService>(pub Arc<T>);
                     impl<
                         T: UsersService,
-                    > tonic::server::UnaryService<super::LogoutRequest>
-                    for LogoutSvc<T> {
-                        type Response = super::LogoutResponse;
+                    > tonic::server::UnaryService<super::ChangePasswordRequest>
+                    for ChangePasswordSvc<T> {
+                        type Response = super::ChangePasswordResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::LogoutRequest>,
+                            request: tonic::Request<super::ChangePasswordRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as UsersService>::logout(&inner, request).await
+                                <T as UsersService>::change_password(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -13427,7 +16819,7 @@ pub mod users_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = LogoutSvc(inner);
+                        let method = ChangePasswordSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -13443,25 +16835,25 @@ pub mod users_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/forest.v1.UsersService/GetUser" => {
+                "/forest.v1.UsersService/AddEmail" => {
                     #[allow(non_camel_case_types)]
-                    struct GetUserSvc<T: UsersService>(pub Arc<T>);
+                    struct AddEmailSvc<T: UsersService>(pub Arc<T>);
                     impl<
                         T: UsersService,
-                    > tonic::server::UnaryService<super::GetUserRequest>
-                    for GetUserSvc<T> {
-                        type Response = super::GetUserResponse;
+                    > tonic::server::UnaryService<super::AddEmailRequest>
+                    for AddEmailSvc<T> {
+                        type Response = super::AddEmailResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::GetUserRequest>,
+                            request: tonic::Request<super::AddEmailRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as UsersService>::get_user(&inner, request).await
+                                <T as UsersService>::add_email(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -13472,7 +16864,7 @@ pub mod users_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = GetUserSvc(inner);
+                        let method = AddEmailSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -13488,25 +16880,25 @@ pub mod users_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/forest.v1.UsersService/UpdateUser" => {
+                "/forest.v1.UsersService/VerifyEmail" => {
                     #[allow(non_camel_case_types)]
-                    struct UpdateUserSvc<T: UsersService>(pub Arc<T>);
+                    struct VerifyEmailSvc<T: UsersService>(pub Arc<T>);
                     impl<
                         T: UsersService,
-                    > tonic::server::UnaryService<super::UpdateUserRequest>
-                    for UpdateUserSvc<T> {
-                        type Response = super::UpdateUserResponse;
+                    > tonic::server::UnaryService<super::VerifyEmailRequest>
+                    for VerifyEmailSvc<T> {
+                        type Response = super::VerifyEmailResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::UpdateUserRequest>,
+                            request: tonic::Request<super::VerifyEmailRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as UsersService>::update_user(&inner, request).await
+                                <T as UsersService>::verify_email(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -13517,7 +16909,7 @@ pub mod users_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = UpdateUserSvc(inner);
+                        let method = VerifyEmailSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -13533,25 +16925,31 @@ pub mod users_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/forest.v1.UsersService/DeleteUser" => {
+                "/forest.v1.UsersService/ConfirmEmailVerification" => {
                     #[allow(non_camel_case_types)]
-                    struct DeleteUserSvc<T: UsersService>(pub Arc<T>);
+                    struct ConfirmEmailVerificationSvc<T: UsersService>(pub Arc<T>);
                     impl<
                         T: UsersService,
-                    > tonic::server::UnaryService<super::DeleteUserRequest>
-                    for DeleteUserSvc<T> {
-                        type Response = super::DeleteUserResponse;
+                    > tonic::server::UnaryService<super::ConfirmEmailVerificationRequest>
+                    for ConfirmEmailVerificationSvc<T> {
+                        type Response = super::ConfirmEmailVerificationResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::DeleteUserRequest>,
+                            request: tonic::Request<
+                                super::ConfirmEmailVerificationRequest,
+                            >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as UsersService>::delete_user(&inner, request).await
+                                <T as UsersService>::confirm_email_verification(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -13562,7 +16960,7 @@ pub mod users_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = DeleteUserSvc(inner);
+                        let method = ConfirmEmailVerificationSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -13578,25 +16976,116 @@ pub mod users_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/forest.v1.UsersService/ListUsers" => {
+                "/forest.v1.UsersService/RemoveEmail" => {
                     #[allow(non_camel_case_types)]
-                    struct ListUsersSvc<T: UsersService>(pub Arc<T>);
+                    struct RemoveEmailSvc<T: UsersService>(pub Arc<T>);
                     impl<
                         T: UsersService,
-                    > tonic::server::UnaryService<super::ListUsersRequest>
-                    for ListUsersSvc<T> {
-                        type Response = super::ListUsersResponse;
+                    > tonic::server::UnaryService<super::RemoveEmailRequest>
+                    for RemoveEmailSvc<T> {
+                        type Response = super::RemoveEmailResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::ListUsersRequest>,
+                            request: tonic::Request<super::RemoveEmailRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as UsersService>::list_users(&inner, request).await
+                                <T as UsersService>::remove_email(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = RemoveEmailSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/forest.v1.UsersService/OAuthLogin" => {
+                    #[allow(non_camel_case_types)]
+                    struct OAuthLoginSvc<T: UsersService>(pub Arc<T>);
+                    impl<
+                        T: UsersService,
+                    > tonic::server::UnaryService<super::OAuthLoginRequest>
+                    for OAuthLoginSvc<T> {
+                        type Response = super::OAuthLoginResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::OAuthLoginRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as UsersService>::o_auth_login(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = OAuthLoginSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/forest.v1.UsersService/LinkOAuthProvider" => {
+                    #[allow(non_camel_case_types)]
+                    struct LinkOAuthProviderSvc<T: UsersService>(pub Arc<T>);
+                    impl<
+                        T: UsersService,
+                    > tonic::server::UnaryService<super::LinkOAuthProviderRequest>
+                    for LinkOAuthProviderSvc<T> {
+                        type Response = super::LinkOAuthProviderResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::LinkOAuthProviderRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as UsersService>::link_o_auth_provider(&inner, request)
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -13607,7 +17096,7 @@ pub mod users_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListUsersSvc(inner);
+                        let method = LinkOAuthProviderSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -13623,25 +17112,26 @@ pub mod users_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/forest.v1.UsersService/GetUserStats" => {
+                "/forest.v1.UsersService/UnlinkOAuthProvider" => {
                     #[allow(non_camel_case_types)]
-                    struct GetUserStatsSvc<T: UsersService>(pub Arc<T>);
+                    struct UnlinkOAuthProviderSvc<T: UsersService>(pub Arc<T>);
                     impl<
                         T: UsersService,
-                    > tonic::server::UnaryService<super::GetUserStatsRequest>
-                    for GetUserStatsSvc<T> {
-                        type Response = super::GetUserStatsResponse;
+                    > tonic::server::UnaryService<super::UnlinkOAuthProviderRequest>
+                    for UnlinkOAuthProviderSvc<T> {
+                        type Response = super::UnlinkOAuthProviderResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::GetUserStatsRequest>,
+                            request: tonic::Request<super::UnlinkOAuthProviderRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as UsersService>::get_user_stats(&inner, request).await
+                                <T as UsersService>::unlink_o_auth_provider(&inner, request)
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -13652,7 +17142,7 @@ pub mod users_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = GetUserStatsSvc(inner);
+                        let method = UnlinkOAuthProviderSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -13668,25 +17158,32 @@ pub mod users_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/forest.v1.UsersService/ChangePassword" => {
+                "/forest.v1.UsersService/CreatePersonalAccessToken" => {
                     #[allow(non_camel_case_types)]
-                    struct ChangePasswordSvc<T: UsersService>(pub Arc<T>);
+                    struct CreatePersonalAccessTokenSvc<T: UsersService>(pub Arc<T>);
                     impl<
                         T: UsersService,
-                    > tonic::server::UnaryService<super::ChangePasswordRequest>
-                    for ChangePasswordSvc<T> {
-                        type Response = super::ChangePasswordResponse;
+                    > tonic::server::UnaryService<
+                        super::CreatePersonalAccessTokenRequest,
+                    > for CreatePersonalAccessTokenSvc<T> {
+                        type Response = super::CreatePersonalAccessTokenResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::ChangePasswordRequest>,
+                            request: tonic::Request<
+                                super::CreatePersonalAccessTokenRequest,
+                            >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as UsersService>::change_password(&inner, request).await
+                                <T as UsersService>::create_personal_access_token(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -13697,7 +17194,7 @@ pub mod users_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ChangePasswordSvc(inner);
+                        let method = CreatePersonalAccessTokenSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -13713,25 +17210,31 @@ pub mod users_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/forest.v1.UsersService/AddEmail" => {
+                "/forest.v1.UsersService/ListPersonalAccessTokens" => {
                     #[allow(non_camel_case_types)]
-                    struct AddEmailSvc<T: UsersService>(pub Arc<T>);
+                    struct ListPersonalAccessTokensSvc<T: UsersService>(pub Arc<T>);
                     impl<
                         T: UsersService,
-                    > tonic::server::UnaryService<super::AddEmailRequest>
-                    for AddEmailSvc<T> {
-                        type Response = super::AddEmailResponse;
+                    > tonic::server::UnaryService<super::ListPersonalAccessTokensRequest>
+                    for ListPersonalAccessTokensSvc<T> {
+                        type Response = super::ListPersonalAccessTokensResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::AddEmailRequest>,
+                            request: tonic::Request<
+                                super::ListPersonalAccessTokensRequest,
+                            >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as UsersService>::add_email(&inner, request).await
+                                <T as UsersService>::list_personal_access_tokens(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -13742,7 +17245,7 @@ pub mod users_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = AddEmailSvc(inner);
+                        let method = ListPersonalAccessTokensSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -13758,25 +17261,32 @@ pub mod users_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/forest.v1.UsersService/VerifyEmail" => {
+                "/forest.v1.UsersService/DeletePersonalAccessToken" => {
                     #[allow(non_camel_case_types)]
-                    struct VerifyEmailSvc<T: UsersService>(pub Arc<T>);
+                    struct DeletePersonalAccessTokenSvc<T: UsersService>(pub Arc<T>);
                     impl<
                         T: UsersService,
-                    > tonic::server::UnaryService<super::VerifyEmailRequest>
-                    for VerifyEmailSvc<T> {
-                        type Response = super::VerifyEmailResponse;
+                    > tonic::server::UnaryService<
+                        super::DeletePersonalAccessTokenRequest,
+                    > for DeletePersonalAccessTokenSvc<T> {
+                        type Response = super::DeletePersonalAccessTokenResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::VerifyEmailRequest>,
+                            request: tonic::Request<
+                                super::DeletePersonalAccessTokenRequest,
+                            >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as UsersService>::verify_email(&inner, request).await
+                                <T as UsersService>::delete_personal_access_token(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -13787,7 +17297,7 @@ pub mod users_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = VerifyEmailSvc(inner);
+                        let method = DeletePersonalAccessTokenSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -13803,31 +17313,25 @@ pub mod users_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/forest.v1.UsersService/ConfirmEmailVerification" => {
+                "/forest.v1.UsersService/TokenInfo" => {
                     #[allow(non_camel_case_types)]
-                    struct ConfirmEmailVerificationSvc<T: UsersService>(pub Arc<T>);
+                    struct TokenInfoSvc<T: UsersService>(pub Arc<T>);
                     impl<
                         T: UsersService,
-                    > tonic::server::UnaryService<super::ConfirmEmailVerificationRequest>
-                    for ConfirmEmailVerificationSvc<T> {
-                        type Response = super::ConfirmEmailVerificationResponse;
+                    > tonic::server::UnaryService<super::TokenInfoRequest>
+                    for TokenInfoSvc<T> {
+                        type Response = super::TokenInfoResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<
-                                super::ConfirmEmailVerificationRequest,
-                            >,
+                            request: tonic::Request<super::TokenInfoRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as UsersService>::confirm_email_verification(
-                                        &inner,
-                                        request,
-                                    )
-                                    .await
+                                <T as UsersService>::token_info(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -13838,7 +17342,7 @@ pub mod users_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ConfirmEmailVerificationSvc(inner);
+                        let method = TokenInfoSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -13854,25 +17358,25 @@ pub mod users_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/forest.v1.UsersService/RemoveEmail" => {
+                "/forest.v1.UsersService/SetupMfa" => {
                     #[allow(non_camel_case_types)]
-                    struct RemoveEmailSvc<T: UsersService>(pub Arc<T>);
+                    struct SetupMfaSvc<T: UsersService>(pub Arc<T>);
                     impl<
                         T: UsersService,
-                    > tonic::server::UnaryService<super::RemoveEmailRequest>
-                    for RemoveEmailSvc<T> {
-                        type Response = super::RemoveEmailResponse;
+                    > tonic::server::UnaryService<super::SetupMfaRequest>
+                    for SetupMfaSvc<T> {
+                        type Response = super::SetupMfaResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RemoveEmailRequest>,
+                            request: tonic::Request<super::SetupMfaRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as UsersService>::remove_email(&inner, request).await
+                                <T as UsersService>::setup_mfa(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -13883,7 +17387,7 @@ pub mod users_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = RemoveEmailSvc(inner);
+                        let method = SetupMfaSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -13899,25 +17403,25 @@ pub mod users_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/forest.v1.UsersService/OAuthLogin" => {
+                "/forest.v1.UsersService/VerifyMfa" => {
                     #[allow(non_camel_case_types)]
-                    struct OAuthLoginSvc<T: UsersService>(pub Arc<T>);
+                    struct VerifyMfaSvc<T: UsersService>(pub Arc<T>);
                     impl<
                         T: UsersService,
-                    > tonic::server::UnaryService<super::OAuthLoginRequest>
-                    for OAuthLoginSvc<T> {
-                        type Response = super::OAuthLoginResponse;
+                    > tonic::server::UnaryService<super::VerifyMfaRequest>
+                    for VerifyMfaSvc<T> {
+                        type Response = super::VerifyMfaResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::OAuthLoginRequest>,
+                            request: tonic::Request<super::VerifyMfaRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as UsersService>::o_auth_login(&inner, request).await
+                                <T as UsersService>::verify_mfa(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -13928,7 +17432,7 @@ pub mod users_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = OAuthLoginSvc(inner);
+                        let method = VerifyMfaSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -13944,26 +17448,25 @@ pub mod users_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/forest.v1.UsersService/LinkOAuthProvider" => {
+                "/forest.v1.UsersService/DisableMfa" => {
                     #[allow(non_camel_case_types)]
-                    struct LinkOAuthProviderSvc<T: UsersService>(pub Arc<T>);
+                    struct DisableMfaSvc<T: UsersService>(pub Arc<T>);
                     impl<
                         T: UsersService,
-                    > tonic::server::UnaryService<super::LinkOAuthProviderRequest>
-                    for LinkOAuthProviderSvc<T> {
-                        type Response = super::LinkOAuthProviderResponse;
+                    > tonic::server::UnaryService<super::DisableMfaRequest>
+                    for DisableMfaSvc<T> {
+                        type Response = super::DisableMfaResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::LinkOAuthProviderRequest>,
+                            request: tonic::Request<super::DisableMfaRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as UsersService>::link_o_auth_provider(&inner, request)
-                                    .await
+                                <T as UsersService>::disable_mfa(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -13974,7 +17477,7 @@ pub mod users_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = LinkOAuthProviderSvc(inner);
+                        let method = DisableMfaSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -13990,26 +17493,25 @@ pub mod users_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/forest.v1.UsersService/UnlinkOAuthProvider" => {
+                "/forest.v1.UsersService/VerifyLoginMfa" => {
                     #[allow(non_camel_case_types)]
-                    struct UnlinkOAuthProviderSvc<T: UsersService>(pub Arc<T>);
+                    struct VerifyLoginMfaSvc<T: UsersService>(pub Arc<T>);
                     impl<
                         T: UsersService,
-                    > tonic::server::UnaryService<super::UnlinkOAuthProviderRequest>
-                    for UnlinkOAuthProviderSvc<T> {
-                        type Response = super::UnlinkOAuthProviderResponse;
+                    > tonic::server::UnaryService<super::VerifyLoginMfaRequest>
+                    for VerifyLoginMfaSvc<T> {
+                        type Response = super::VerifyLoginMfaResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::UnlinkOAuthProviderRequest>,
+                            request: tonic::Request<super::VerifyLoginMfaRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as UsersService>::unlink_o_auth_provider(&inner, request)
-                                    .await
+                                <T as UsersService>::verify_login_mfa(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -14020,7 +17522,7 @@ pub mod users_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = UnlinkOAuthProviderSvc(inner);
+                        let method = VerifyLoginMfaSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -14036,15 +17538,14 @@ pub mod users_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/forest.v1.UsersService/CreatePersonalAccessToken" => {
+                "/forest.v1.UsersService/BeginPasskeyRegistration" => {
                     #[allow(non_camel_case_types)]
-                    struct CreatePersonalAccessTokenSvc<T: UsersService>(pub Arc<T>);
+                    struct BeginPasskeyRegistrationSvc<T: UsersService>(pub Arc<T>);
                     impl<
                         T: UsersService,
-                    > tonic::server::UnaryService<
-                        super::CreatePersonalAccessTokenRequest,
-                    > for CreatePersonalAccessTokenSvc<T> {
-                        type Response = super::CreatePersonalAccessTokenResponse;
+                    > tonic::server::UnaryService<super::BeginPasskeyRegistrationRequest>
+                    for BeginPasskeyRegistrationSvc<T> {
+                        type Response = super::BeginPasskeyRegistrationResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -14052,12 +17553,12 @@ pub mod users_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::CreatePersonalAccessTokenRequest,
+                                super::BeginPasskeyRegistrationRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as UsersService>::create_personal_access_token(
+                                <T as UsersService>::begin_passkey_registration(
                                         &inner,
                                         request,
                                     )
@@ -14072,7 +17573,7 @@ pub mod users_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = CreatePersonalAccessTokenSvc(inner);
+                        let method = BeginPasskeyRegistrationSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -14088,14 +17589,15 @@ pub mod users_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/forest.v1.UsersService/ListPersonalAccessTokens" => {
+                "/forest.v1.UsersService/FinishPasskeyRegistration" => {
                     #[allow(non_camel_case_types)]
-                    struct ListPersonalAccessTokensSvc<T: UsersService>(pub Arc<T>);
+                    struct FinishPasskeyRegistrationSvc<T: UsersService>(pub Arc<T>);
                     impl<
                         T: UsersService,
-                    > tonic::server::UnaryService<super::ListPersonalAccessTokensRequest>
-                    for ListPersonalAccessTokensSvc<T> {
-                        type Response = super::ListPersonalAccessTokensResponse;
+                    > tonic::server::UnaryService<
+                        super::FinishPasskeyRegistrationRequest,
+                    > for FinishPasskeyRegistrationSvc<T> {
+                        type Response = super::FinishPasskeyRegistrationResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -14103,12 +17605,12 @@ pub mod users_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::ListPersonalAccessTokensRequest,
+                                super::FinishPasskeyRegistrationRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as UsersService>::list_personal_access_tokens(
+                                <T as UsersService>::finish_passkey_registration(
                                         &inner,
                                         request,
                                     )
@@ -14123,7 +17625,7 @@ pub mod users_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListPersonalAccessTokensSvc(inner);
+                        let method = FinishPasskeyRegistrationSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -14139,32 +17641,25 @@ pub mod users_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/forest.v1.UsersService/DeletePersonalAccessToken" => {
+                "/forest.v1.UsersService/ListPasskeys" => {
                     #[allow(non_camel_case_types)]
-                    struct DeletePersonalAccessTokenSvc<T: UsersService>(pub Arc<T>);
+                    struct ListPasskeysSvc<T: UsersService>(pub Arc<T>);
                     impl<
                         T: UsersService,
-                    > tonic::server::UnaryService<
-                        super::DeletePersonalAccessTokenRequest,
-                    > for DeletePersonalAccessTokenSvc<T> {
-                        type Response = super::DeletePersonalAccessTokenResponse;
+                    > tonic::server::UnaryService<super::ListPasskeysRequest>
+                    for ListPasskeysSvc<T> {
+                        type Response = super::ListPasskeysResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<
-                                super::DeletePersonalAccessTokenRequest,
-                            >,
+                            request: tonic::Request<super::ListPasskeysRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as UsersService>::delete_personal_access_token(
-                                        &inner,
-                                        request,
-                                    )
-                                    .await
+                                <T as UsersService>::list_passkeys(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -14175,7 +17670,7 @@ pub mod users_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = DeletePersonalAccessTokenSvc(inner);
+                        let method = ListPasskeysSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -14191,25 +17686,25 @@ pub mod users_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/forest.v1.UsersService/TokenInfo" => {
+                "/forest.v1.UsersService/RenamePasskey" => {
                     #[allow(non_camel_case_types)]
-                    struct TokenInfoSvc<T: UsersService>(pub Arc<T>);
+                    struct RenamePasskeySvc<T: UsersService>(pub Arc<T>);
                     impl<
                         T: UsersService,
-                    > tonic::server::UnaryService<super::TokenInfoRequest>
-                    for TokenInfoSvc<T> {
-                        type Response = super::TokenInfoResponse;
+                    > tonic::server::UnaryService<super::RenamePasskeyRequest>
+                    for RenamePasskeySvc<T> {
+                        type Response = super::RenamePasskeyResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::TokenInfoRequest>,
+                            request: tonic::Request<super::RenamePasskeyRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as UsersService>::token_info(&inner, request).await
+                                <T as UsersService>::rename_passkey(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -14220,7 +17715,7 @@ pub mod users_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = TokenInfoSvc(inner);
+                        let method = RenamePasskeySvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -14236,25 +17731,25 @@ pub mod users_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/forest.v1.UsersService/SetupMfa" => {
+                "/forest.v1.UsersService/DeletePasskey" => {
                     #[allow(non_camel_case_types)]
-                    struct SetupMfaSvc<T: UsersService>(pub Arc<T>);
+                    struct DeletePasskeySvc<T: UsersService>(pub Arc<T>);
                     impl<
                         T: UsersService,
-                    > tonic::server::UnaryService<super::SetupMfaRequest>
-                    for SetupMfaSvc<T> {
-                        type Response = super::SetupMfaResponse;
+                    > tonic::server::UnaryService<super::DeletePasskeyRequest>
+                    for DeletePasskeySvc<T> {
+                        type Response = super::DeletePasskeyResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::SetupMfaRequest>,
+                            request: tonic::Request<super::DeletePasskeyRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as UsersService>::setup_mfa(&inner, request).await
+                                <T as UsersService>::delete_passkey(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -14265,7 +17760,7 @@ pub mod users_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = SetupMfaSvc(inner);
+                        let method = DeletePasskeySvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -14281,25 +17776,26 @@ pub mod users_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/forest.v1.UsersService/VerifyMfa" => {
+                "/forest.v1.UsersService/BeginPasskeyLogin" => {
                     #[allow(non_camel_case_types)]
-                    struct VerifyMfaSvc<T: UsersService>(pub Arc<T>);
+                    struct BeginPasskeyLoginSvc<T: UsersService>(pub Arc<T>);
                     impl<
                         T: UsersService,
-                    > tonic::server::UnaryService<super::VerifyMfaRequest>
-                    for VerifyMfaSvc<T> {
-                        type Response = super::VerifyMfaResponse;
+                    > tonic::server::UnaryService<super::BeginPasskeyLoginRequest>
+                    for BeginPasskeyLoginSvc<T> {
+                        type Response = super::BeginPasskeyLoginResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::VerifyMfaRequest>,
+                            request: tonic::Request<super::BeginPasskeyLoginRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as UsersService>::verify_mfa(&inner, request).await
+                                <T as UsersService>::begin_passkey_login(&inner, request)
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -14310,7 +17806,7 @@ pub mod users_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = VerifyMfaSvc(inner);
+                        let method = BeginPasskeyLoginSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -14326,25 +17822,26 @@ pub mod users_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/forest.v1.UsersService/DisableMfa" => {
+                "/forest.v1.UsersService/FinishPasskeyLogin" => {
                     #[allow(non_camel_case_types)]
-                    struct DisableMfaSvc<T: UsersService>(pub Arc<T>);
+                    struct FinishPasskeyLoginSvc<T: UsersService>(pub Arc<T>);
                     impl<
                         T: UsersService,
-                    > tonic::server::UnaryService<super::DisableMfaRequest>
-                    for DisableMfaSvc<T> {
-                        type Response = super::DisableMfaResponse;
+                    > tonic::server::UnaryService<super::FinishPasskeyLoginRequest>
+                    for FinishPasskeyLoginSvc<T> {
+                        type Response = super::FinishPasskeyLoginResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::DisableMfaRequest>,
+                            request: tonic::Request<super::FinishPasskeyLoginRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as UsersService>::disable_mfa(&inner, request).await
+                                <T as UsersService>::finish_passkey_login(&inner, request)
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -14355,7 +17852,7 @@ pub mod users_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = DisableMfaSvc(inner);
+                        let method = FinishPasskeyLoginSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -14371,25 +17868,31 @@ pub mod users_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/forest.v1.UsersService/VerifyLoginMfa" => {
+                "/forest.v1.UsersService/BeginPasskeyVerification" => {
                     #[allow(non_camel_case_types)]
-                    struct VerifyLoginMfaSvc<T: UsersService>(pub Arc<T>);
+                    struct BeginPasskeyVerificationSvc<T: UsersService>(pub Arc<T>);
                     impl<
                         T: UsersService,
-                    > tonic::server::UnaryService<super::VerifyLoginMfaRequest>
-                    for VerifyLoginMfaSvc<T> {
-                        type Response = super::VerifyLoginMfaResponse;
+                    > tonic::server::UnaryService<super::BeginPasskeyVerificationRequest>
+                    for BeginPasskeyVerificationSvc<T> {
+                        type Response = super::BeginPasskeyVerificationResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::VerifyLoginMfaRequest>,
+                            request: tonic::Request<
+                                super::BeginPasskeyVerificationRequest,
+                            >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as UsersService>::verify_login_mfa(&inner, request).await
+                                <T as UsersService>::begin_passkey_verification(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -14400,7 +17903,7 @@ pub mod users_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = VerifyLoginMfaSvc(inner);
+                        let method = BeginPasskeyVerificationSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
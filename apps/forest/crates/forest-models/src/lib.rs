@@ -72,6 +72,7 @@ impl From<Project> for forest_grpc_interface::Project {
     }
 }
 
+#[derive(Clone)]
 pub struct Destination {
     pub organisation: String,
     pub name: String,
@@ -79,6 +80,12 @@ pub struct Destination {
     pub metadata: HashMap<String, String>,
 
     pub destination_type: DestinationType,
+
+    /// Labels a runner must carry to be assigned releases for this
+    /// destination. Empty means any capable runner (or in-process).
+    pub runner_labels: HashMap<String, String>,
+
+    pub queue_policy: QueuePolicy,
 }
 
 impl Destination {
@@ -95,8 +102,20 @@ impl Destination {
             environment: environment.into(),
             metadata,
             destination_type,
+            runner_labels: HashMap::new(),
+            queue_policy: QueuePolicy::default(),
         }
     }
+
+    pub fn with_runner_labels(mut self, runner_labels: HashMap<String, String>) -> Self {
+        self.runner_labels = runner_labels;
+        self
+    }
+
+    pub fn with_queue_policy(mut self, queue_policy: QueuePolicy) -> Self {
+        self.queue_policy = queue_policy;
+        self
+    }
 }
 
 impl Display for Destination {
@@ -113,10 +132,13 @@ impl From<Destination> for forest_grpc_interface::Destination {
             environment: value.environment,
             r#type: Some(value.destination_type.into()),
             metadata: value.metadata,
+            runner_labels: value.runner_labels,
+            queue_policy: forest_grpc_interface::ReleaseQueuePolicy::from(value.queue_policy).into(),
         }
     }
 }
 
+#[derive(Clone)]
 pub struct MetadataFieldSchema {
     pub name: String,
     pub label: String,
@@ -126,6 +148,7 @@ pub struct MetadataFieldSchema {
     pub default_value: String,
 }
 
+#[derive(Clone)]
 pub struct DestinationType {
     pub organisation: String,
     pub name: String,
@@ -177,6 +200,64 @@ impl From<forest_grpc_interface::DestinationType> for DestinationType {
     }
 }
 
+/// What happens to releases that arrive while a destination is busy.
+/// Releases to a destination always run one at a time.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QueuePolicy {
+    /// Queued releases run in the order they were requested.
+    #[default]
+    Wait,
+    /// A new release cancels releases still waiting; only the latest runs.
+    Supersede,
+}
+
+impl QueuePolicy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            QueuePolicy::Wait => "wait",
+            QueuePolicy::Supersede => "supersede",
+        }
+    }
+}
+
+impl Display for QueuePolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for QueuePolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "wait" => Ok(QueuePolicy::Wait),
+            "supersede" => Ok(QueuePolicy::Supersede),
+            _ => Err(format!("unknown queue policy: {s} (expected wait or supersede)")),
+        }
+    }
+}
+
+impl From<QueuePolicy> for forest_grpc_interface::ReleaseQueuePolicy {
+    fn from(value: QueuePolicy) -> Self {
+        match value {
+            QueuePolicy::Wait => Self::Wait,
+            QueuePolicy::Supersede => Self::Supersede,
+        }
+    }
+}
+
+impl From<forest_grpc_interface::ReleaseQueuePolicy> for QueuePolicy {
+    fn from(value: forest_grpc_interface::ReleaseQueuePolicy) -> Self {
+        match value {
+            forest_grpc_interface::ReleaseQueuePolicy::Supersede => QueuePolicy::Supersede,
+            forest_grpc_interface::ReleaseQueuePolicy::Wait
+            | forest_grpc_interface::ReleaseQueuePolicy::Unspecified => QueuePolicy::Wait,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ReleaseStatus {
     Queued,
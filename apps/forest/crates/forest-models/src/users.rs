@@ -63,6 +63,7 @@ pub enum OAuthProvider {
     Gitlab,
     Microsoft,
     MagicLink,
+    Sso,
 }
 
 impl OAuthProvider {
@@ -73,6 +74,7 @@ impl OAuthProvider {
             Self::Gitlab => "gitlab",
             Self::Microsoft => "microsoft",
             Self::MagicLink => "magic-link",
+            Self::Sso => "sso",
         }
     }
 }
@@ -87,6 +89,7 @@ impl std::str::FromStr for OAuthProvider {
             "gitlab" => Ok(Self::Gitlab),
             "microsoft" => Ok(Self::Microsoft),
             "magic-link" => Ok(Self::MagicLink),
+            "sso" => Ok(Self::Sso),
             _ => Err(format!("unknown oauth provider: {s}")),
         }
     }
@@ -100,6 +103,7 @@ impl From<OAuthProvider> for forest_grpc_interface::OAuthProvider {
             OAuthProvider::Gitlab => Self::OauthProviderGitlab,
             OAuthProvider::Microsoft => Self::OauthProviderMicrosoft,
             OAuthProvider::MagicLink => Self::OauthProviderMagicLink,
+            OAuthProvider::Sso => Self::OauthProviderSso,
         }
     }
 }
@@ -114,6 +118,7 @@ impl TryFrom<forest_grpc_interface::OAuthProvider> for OAuthProvider {
             forest_grpc_interface::OAuthProvider::OauthProviderGitlab => Ok(Self::Gitlab),
             forest_grpc_interface::OAuthProvider::OauthProviderMicrosoft => Ok(Self::Microsoft),
             forest_grpc_interface::OAuthProvider::OauthProviderMagicLink => Ok(Self::MagicLink),
+            forest_grpc_interface::OAuthProvider::OauthProviderSso => Ok(Self::Sso),
             forest_grpc_interface::OAuthProvider::OauthProviderUnspecified => {
                 Err("unspecified oauth provider".into())
             }
@@ -227,6 +232,10 @@ impl TryFrom<forest_grpc_interface::MfaType> for MfaType {
             forest_grpc_interface::MfaType::Unspecified => {
                 Err("unspecified mfa type".into())
             }
+            // Passkeys live in their own credential store, not user_mfa.
+            forest_grpc_interface::MfaType::Passkey => {
+                Err("passkeys are not an mfa record type".into())
+            }
         }
     }
 }
@@ -1,24 +1,47 @@
-use std::path::PathBuf;
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
 
 use anyhow::Context;
 use forest_grpc_interface::{
     DestinationCapability, GetProjectInfoRequest, GetReleaseAnnotationRequest,
     GetReleaseFilesRequest, GetSpecFilesRequest, PushLogRequest, ReleaseAnnotationResponse,
     RunnerHeartbeat, RunnerMessage, RunnerRegister, WorkAssignment, runner_message,
-    runner_service_client::RunnerServiceClient,
+    runner_service_client::RunnerServiceClient, server_message,
 };
 use futures::StreamExt;
 use tokio::sync::mpsc;
 use tokio_stream::wrappers::UnboundedReceiverStream;
+use tokio_util::sync::CancellationToken;
+
+/// Where a runner may be scheduled: labels and pool it advertises, and the
+/// organisation/project it is dedicated to (shared when unset).
+#[derive(Clone, Debug, Default)]
+pub struct RunnerPlacement {
+    pub labels: HashMap<String, String>,
+    pub pool: Option<String>,
+    pub organisation: Option<String>,
+    pub project: Option<String>,
+}
 
 /// Client that connects to a forest-server RunnerService.
 pub struct ForestRunnerClient {
     addr: String,
+    token: Option<String>,
 }
 
 impl ForestRunnerClient {
     pub fn new(addr: String) -> Self {
-        Self { addr }
+        Self { addr, token: None }
+    }
+
+    /// Token presented on registration. Required for runners scoped to an
+    /// organisation or project; the token's owner must be an org admin.
+    pub fn with_token(mut self, token: Option<String>) -> Self {
+        self.token = token;
+        self
     }
 
     /// Connect to the server, register as a runner, and return a session
@@ -28,6 +51,7 @@ impl ForestRunnerClient {
         runner_id: String,
         capabilities: Vec<DestinationCapability>,
         max_concurrent: i32,
+        placement: RunnerPlacement,
     ) -> anyhow::Result<RunnerSession> {
         let channel = tonic::transport::Channel::from_shared(self.addr.clone())
             .context("invalid server address")?
@@ -46,31 +70,122 @@ impl ForestRunnerClient {
                 runner_id: runner_id.clone(),
                 capabilities,
                 max_concurrent,
+                labels: placement.labels,
+                pool: placement.pool.unwrap_or_default(),
+                organisation: placement.organisation,
+                project: placement.project,
             })),
         })?;
 
-        let outbound_stream = UnboundedReceiverStream::new(outbound_rx);
+        let mut request = tonic::Request::new(UnboundedReceiverStream::new(outbound_rx));
+        if let Some(token) = &self.token {
+            request.metadata_mut().insert(
+                "authorization",
+                format!("Bearer {token}")
+                    .parse()
+                    .context("runner token is not valid header data")?,
+            );
+        }
 
         let response = client
-            .register_runner(outbound_stream)
+            .register_runner(request)
             .await
             .context("failed to register runner")?;
 
-        let inbound = response.into_inner();
+        let mut inbound = response.into_inner();
+
+        // Read the server stream in the background so cancellations arrive
+        // while a release is executing, not only between assignments.
+        let (work_tx, work_rx) = mpsc::unbounded_channel();
+        let cancellations = Cancellations::default();
+        let reader_cancellations = cancellations.clone();
+        tokio::spawn(async move {
+            loop {
+                match inbound.next().await {
+                    Some(Ok(msg)) => match msg.message {
+                        Some(server_message::Message::WorkAssignment(assignment)) => {
+                            // The session was dropped; nobody is left to run work.
+                            let Ok(()) = work_tx.send(assignment) else {
+                                break;
+                            };
+                        }
+                        Some(server_message::Message::CancelWork(cancel)) => {
+                            tracing::info!(
+                                release_id = %cancel.release_id,
+                                reason = %cancel.reason,
+                                "server cancelled release"
+                            );
+                            reader_cancellations.cancel(&cancel.release_id);
+                        }
+                        // RegisterAck or other messages — skip
+                        _ => {}
+                    },
+                    Some(Err(e)) => {
+                        tracing::error!("stream error: {e}");
+                        break;
+                    }
+                    None => break,
+                }
+            }
+        });
 
         Ok(RunnerSession {
             client: RunnerServiceClient::new(channel),
             outbound_tx,
-            inbound,
+            work_rx,
+            cancellations,
         })
     }
 }
 
+/// Releases the server has asked this runner to stop, keyed by release id.
+#[derive(Clone, Default)]
+struct Cancellations(Arc<Mutex<HashMap<String, CancellationToken>>>);
+
+impl Cancellations {
+    fn token(&self, release_id: &str) -> CancellationToken {
+        let mut tokens = self.0.lock().unwrap();
+        tokens.entry(release_id.to_string()).or_default().clone()
+    }
+
+    /// A cancellation can overtake its assignment, so unknown releases are
+    /// remembered as already cancelled.
+    fn cancel(&self, release_id: &str) {
+        self.token(release_id).cancel();
+    }
+
+    fn forget(&self, release_id: &str) {
+        self.0.lock().unwrap().remove(release_id);
+    }
+}
+
+/// Cancellation for one executing release. Stops tracking the release
+/// when dropped.
+pub struct CancellationWatch {
+    release_id: String,
+    token: CancellationToken,
+    cancellations: Cancellations,
+}
+
+impl CancellationWatch {
+    /// Completes once the server cancels the release.
+    pub async fn cancelled(&self) {
+        self.token.cancelled().await
+    }
+}
+
+impl Drop for CancellationWatch {
+    fn drop(&mut self) {
+        self.cancellations.forget(&self.release_id);
+    }
+}
+
 /// An active runner session with the forest-server.
 pub struct RunnerSession {
     client: RunnerServiceClient<tonic::transport::Channel>,
     outbound_tx: mpsc::UnboundedSender<RunnerMessage>,
-    inbound: tonic::Streaming<forest_grpc_interface::ServerMessage>,
+    work_rx: mpsc::UnboundedReceiver<WorkAssignment>,
+    cancellations: Cancellations,
 }
 
 /// A lightweight handle that can send heartbeats without borrowing the full session.
@@ -100,23 +215,15 @@ impl RunnerSession {
     /// Wait for the next work assignment from the server.
     /// Returns None if the stream is closed.
     pub async fn next_work(&mut self) -> Option<WorkAssignment> {
-        loop {
-            match self.inbound.next().await {
-                Some(Ok(msg)) => {
-                    if let Some(forest_grpc_interface::server_message::Message::WorkAssignment(
-                        assignment,
-                    )) = msg.message
-                    {
-                        return Some(assignment);
-                    }
-                    // RegisterAck or other messages — skip
-                }
-                Some(Err(e)) => {
-                    tracing::error!("stream error: {e}");
-                    return None;
-                }
-                None => return None,
-            }
+        self.work_rx.recv().await
+    }
+
+    /// Watch for the server cancelling a release this runner is executing.
+    pub fn watch_cancellation(&self, release_id: &str) -> CancellationWatch {
+        CancellationWatch {
+            release_id: release_id.to_string(),
+            token: self.cancellations.token(release_id),
+            cancellations: self.cancellations.clone(),
         }
     }
 
@@ -249,3 +356,33 @@ impl RunnerSession {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cancellation_before_assignment_runs_is_kept() {
+        let cancellations = Cancellations::default();
+        cancellations.cancel("r1");
+
+        assert!(cancellations.token("r1").is_cancelled());
+        assert!(!cancellations.token("r2").is_cancelled());
+    }
+
+    #[test]
+    fn watch_forgets_release_on_drop() {
+        let cancellations = Cancellations::default();
+        let watch = CancellationWatch {
+            release_id: "r1".into(),
+            token: cancellations.token("r1"),
+            cancellations: cancellations.clone(),
+        };
+
+        cancellations.cancel("r1");
+        assert!(watch.token.is_cancelled());
+
+        drop(watch);
+        assert!(cancellations.0.lock().unwrap().is_empty());
+    }
+}
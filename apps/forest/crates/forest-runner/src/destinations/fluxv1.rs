@@ -1,5 +1,5 @@
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, BTreeSet, HashMap},
     path::{Path, PathBuf},
     process::Stdio,
 };
@@ -233,6 +233,28 @@ impl FluxV1Handler {
         config: &DestinationConfig,
         mode: Mode,
     ) -> anyhow::Result<()> {
+        Self::render(backend, config, mode).await.map(|_| ())
+    }
+
+    /// Compare what the release would write with the repository as it is
+    /// now, without writing anything.
+    ///
+    /// Returns a summary of the differing files, or `None` when the
+    /// repository still matches the release.
+    pub async fn detect_drift(
+        backend: &dyn DestinationBackend,
+        config: &DestinationConfig,
+    ) -> anyhow::Result<Option<String>> {
+        Self::render(backend, config, Mode::Prepare).await
+    }
+
+    /// Shared by `run` and `detect_drift`. In prepare mode, returns the
+    /// files that would change, if any.
+    async fn render(
+        backend: &dyn DestinationBackend,
+        config: &DestinationConfig,
+        mode: Mode,
+    ) -> anyhow::Result<Option<String>> {
         let flux_meta = FluxMetadata::from_metadata(&config.metadata)
             .context("invalid flux destination metadata")?;
 
@@ -264,6 +286,7 @@ impl FluxV1Handler {
             .context("read dir found no destinations for env")?;
 
         let mut matched = false;
+        let mut changes = Vec::new();
         while let Some(entry) = env_dir_entries.next_entry().await? {
             if !entry.file_type().await?.is_dir() {
                 continue;
@@ -333,7 +356,7 @@ impl FluxV1Handler {
                 format!("{}-{}", project_info.organisation, project_info.project);
 
             // 7. Execute git or local mode
-            if flux_meta.is_local() {
+            let changed = if flux_meta.is_local() {
                 run_local(
                     backend,
                     &flux_meta,
@@ -345,7 +368,7 @@ impl FluxV1Handler {
                     &mode,
                     identity.as_ref(),
                 )
-                .await?;
+                .await?
             } else {
                 run_git(
                     backend,
@@ -357,15 +380,16 @@ impl FluxV1Handler {
                     &project_name,
                     identity.as_ref(), &mode,
                 )
-                .await?;
-            }
+                .await?
+            };
+            changes.extend(changed);
         }
 
         if !matched {
             anyhow::bail!("failed to find a destination match for submitted release");
         }
 
-        Ok(())
+        Ok((!changes.is_empty()).then(|| changes.join("\n")))
     }
 
     /// Generate a Flux Kustomization CR YAML.
@@ -569,12 +593,7 @@ spec:
         releases_dir: &Path,
         manifest_files: &[(String, String)],
     ) -> anyhow::Result<()> {
-        let mut out = String::from(
-            "apiVersion: kustomize.config.k8s.io/v1beta1\nkind: Kustomization\nresources:\n",
-        );
-        for (name, _) in manifest_files {
-            out.push_str(&format!("  - {name}\n"));
-        }
+        let out = releases_kustomize_yaml(manifest_files);
         tokio::fs::write(releases_dir.join("kustomization.yaml"), out.as_bytes()).await?;
         Ok(())
     }
@@ -606,6 +625,17 @@ spec:
 
 // ====== PURE HELPER FUNCTIONS ======
 
+/// The plain kustomize `kustomization.yaml` for a releases directory.
+fn releases_kustomize_yaml(manifest_files: &[(String, String)]) -> String {
+    let mut out = String::from(
+        "apiVersion: kustomize.config.k8s.io/v1beta1\nkind: Kustomization\nresources:\n",
+    );
+    for (name, _) in manifest_files {
+        out.push_str(&format!("  - {name}\n"));
+    }
+    out
+}
+
 /// Build release.yaml content from a `ReleaseAnnotation` and `DestinationConfig`.
 fn build_release_yaml(
     annotation: &ReleaseAnnotation,
@@ -679,7 +709,7 @@ async fn run_local(
     project: &str,
     mode: &Mode,
     identity: Option<&crate::backend::ReleaseIdentity>,
-) -> anyhow::Result<()> {
+) -> anyhow::Result<Option<String>> {
     let local_root = meta.local_path.as_ref().context("local_path required")?;
 
     let releases_rel = meta.releases_path(env, destination_name, project);
@@ -698,6 +728,17 @@ async fn run_local(
             for (name, _) in manifest_files {
                 backend.log_stdout(&format!("  {}", name));
             }
+
+            let changes = diff_local(&releases_abs, manifest_files).await?;
+            if changes.is_empty() {
+                backend.log_stdout("[flux@1] no changes detected");
+                return Ok(None);
+            }
+            backend.log_stdout("[flux@1] changes detected:");
+            for change in &changes {
+                backend.log_stdout(&format!("  {change}"));
+            }
+            return Ok(Some(changes.join("\n")));
         }
         Mode::Apply => {
             backend.log_stdout(&format!(
@@ -732,7 +773,7 @@ async fn run_local(
         }
     }
 
-    Ok(())
+    Ok(None)
 }
 
 // ====== GIT MODE ======
@@ -747,7 +788,7 @@ async fn run_git(
     project: &str,
     identity: Option<&crate::backend::ReleaseIdentity>,
     mode: &Mode,
-) -> anyhow::Result<()> {
+) -> anyhow::Result<Option<String>> {
     let clone_dir = backend.create_temp_dir().await?;
     let git_env = meta.git_env();
     let effective_url = meta.effective_git_url()?;
@@ -825,14 +866,15 @@ async fn run_git(
         Mode::Prepare => {
             if has_changes {
                 backend.log_stdout("[flux@1] changes detected:");
-                // Show summary - ignore exit code (diff returns 1 when there are diffs)
-                let _ = run_command(
+                let stat = run_command(
                     backend,
                     &repo_dir,
                     &["diff", "--cached", "--stat"],
                     &git_env,
                 )
-                .await;
+                .await
+                .context("git diff --stat")?;
+                return Ok(Some(stat.trim_end().to_string()));
             } else {
                 backend.log_stdout("[flux@1] no changes detected");
             }
@@ -883,7 +925,7 @@ async fn run_git(
         }
     }
 
-    Ok(())
+    Ok(None)
 }
 
 // ====== RECONCILIATION ======
@@ -918,12 +960,13 @@ async fn trigger_reconciliation(backend: &dyn DestinationBackend, url: &str) {
 
 // ====== COMMAND EXECUTION ======
 
+/// Run git, logging its output. Returns stdout.
 async fn run_command(
     backend: &dyn DestinationBackend,
     cwd: &Path,
     args: &[&str],
     env: &HashMap<String, String>,
-) -> anyhow::Result<()> {
+) -> anyhow::Result<String> {
     let exe = std::env::var("GIT_EXE").unwrap_or_else(|_| "git".to_string());
 
     tracing::debug!(cwd =% cwd.display(), "running {} {}", exe, args.join(" "));
@@ -958,7 +1001,7 @@ async fn run_command(
     }
 
     tracing::debug!("git command success");
-    Ok(())
+    Ok(stdout.into_owned())
 }
 
 // ====== FILE I/O HELPERS ======
@@ -1015,6 +1058,50 @@ async fn read_config_as_yaml(dir: &Path) -> anyhow::Result<Option<String>> {
     }
 }
 
+/// Files in `releases_dir` that differ from what a release would write there.
+/// Only the manifests and the kustomization listing them are compared; the
+/// `.forest/` metadata is never applied, so edits to it aren't drift.
+async fn diff_local(
+    releases_dir: &Path,
+    manifest_files: &[(String, String)],
+) -> anyhow::Result<Vec<String>> {
+    let mut expected: BTreeMap<String, String> = manifest_files.iter().cloned().collect();
+    expected.insert(
+        "kustomization.yaml".to_string(),
+        releases_kustomize_yaml(manifest_files),
+    );
+
+    let mut changes = Vec::new();
+    let mut present = BTreeSet::new();
+    if releases_dir.exists() {
+        let mut entries = tokio::fs::read_dir(releases_dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            if !entry.file_type().await?.is_file() {
+                continue;
+            }
+            let name = entry.file_name().to_string_lossy().to_string();
+            match expected.get(&name) {
+                Some(content) => {
+                    if tokio::fs::read(entry.path()).await? != content.as_bytes() {
+                        changes.push(format!("modified: {name}"));
+                    }
+                }
+                None => changes.push(format!("unexpected: {name}")),
+            }
+            present.insert(name);
+        }
+    }
+    changes.extend(
+        expected
+            .keys()
+            .filter(|name| !present.contains(*name))
+            .map(|name| format!("missing: {name}")),
+    );
+    changes.sort();
+
+    Ok(changes)
+}
+
 /// Write manifest files to a directory.
 async fn write_manifest_files(dir: &Path, files: &[(String, String)]) -> anyhow::Result<()> {
     for (name, content) in files {
@@ -1588,6 +1675,61 @@ mod tests {
         assert!(clusters_dir.join("old-project.yaml").exists());
     }
 
+    #[tokio::test]
+    async fn test_diff_local_reports_drifted_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let manifests = vec![
+            ("10-namespace.yaml".to_string(), "kind: Namespace\n".to_string()),
+            ("20-deployment.yaml".to_string(), "kind: Deployment\n".to_string()),
+        ];
+
+        write_manifest_files(dir.path(), &manifests).await.unwrap();
+        FluxV1Handler::write_releases_kustomize_yaml(dir.path(), &manifests)
+            .await
+            .unwrap();
+        write_forest_metadata(
+            dir.path(),
+            &ForestMetadataFiles {
+                config_yaml: None,
+                release_yaml: "slug: a\n".into(),
+                spec_yaml: String::new(),
+            },
+        )
+        .await
+        .unwrap();
+        assert!(diff_local(dir.path(), &manifests).await.unwrap().is_empty());
+
+        tokio::fs::write(dir.path().join("20-deployment.yaml"), "kind: Deployment\nreplicas: 5\n")
+            .await
+            .unwrap();
+        tokio::fs::remove_file(dir.path().join("10-namespace.yaml"))
+            .await
+            .unwrap();
+        tokio::fs::write(dir.path().join("99-extra.yaml"), "kind: ConfigMap\n")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            diff_local(dir.path(), &manifests).await.unwrap(),
+            vec![
+                "missing: 10-namespace.yaml",
+                "modified: 20-deployment.yaml",
+                "unexpected: 99-extra.yaml",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_diff_local_missing_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let manifests = vec![("10-namespace.yaml".to_string(), "kind: Namespace\n".to_string())];
+
+        assert_eq!(
+            diff_local(&dir.path().join("gone"), &manifests).await.unwrap(),
+            vec!["missing: 10-namespace.yaml", "missing: kustomization.yaml"]
+        );
+    }
+
     // ====== INTEGRATION: GIT CLONE/COMMIT/PUSH CYCLE ======
 
     #[tokio::test]
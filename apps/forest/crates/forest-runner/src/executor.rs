@@ -68,6 +68,7 @@ impl Executor {
 
         self.active_count.fetch_add(1, Ordering::Relaxed);
         let _guard = ActiveGuard(self.active_count.clone());
+        let cancellation = session.watch_cancellation(&assignment.release_id);
 
         // Open log stream first so we can log during data fetching
         let log_sender = session
@@ -159,16 +160,33 @@ impl Executor {
         let is_plan_mode = ReleaseMode::try_from(assignment.mode)
             .unwrap_or(ReleaseMode::Deploy) == ReleaseMode::Plan;
 
-        // Run the destination handler
-        let result = if is_plan_mode {
-            run_destination_plan(handler.as_ref(), &ctx).await
-        } else {
-            run_destination(handler.as_ref(), &ctx).await.map(|()| None)
+        // Run the destination handler; a cancellation from the server
+        // drops it mid-flight.
+        let run = async {
+            if is_plan_mode {
+                run_destination_plan(handler.as_ref(), &ctx).await
+            } else {
+                run_destination(handler.as_ref(), &ctx).await.map(|()| None)
+            }
+        };
+        let result = tokio::select! {
+            biased;
+            _ = cancellation.cancelled() => None,
+            result = run => Some(result),
         };
 
         // Cleanup temp dir (best effort)
         let _ = tokio::fs::remove_dir_all(&temp_dir).await;
 
+        let Some(result) = result else {
+            tracing::info!(release_token, "release cancelled");
+            logger.log_stderr("release cancelled");
+            session
+                .complete_release(release_token, ReleaseOutcome::Cancelled, None, None)
+                .await?;
+            return Ok(());
+        };
+
         // Report completion
         match &result {
             Ok(plan_output) => {
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use clap::Parser;
-use forest_runner::client::ForestRunnerClient;
+use forest_runner::client::{ForestRunnerClient, RunnerPlacement};
 use forest_runner::destinations::RunnerDestination;
 use forest_runner::destinations::fluxv1::FluxV1RunnerDestination;
 use forest_runner::executor::Executor;
@@ -29,6 +29,35 @@ struct Cli {
     /// Destinations to enable (can be repeated or comma-separated): flux
     #[arg(long = "destination", env = "FOREST_DESTINATIONS", value_delimiter = ',')]
     destinations: Vec<String>,
+
+    /// Labels to advertise, as key=value (can be repeated or comma-separated).
+    /// Destinations requiring runner labels are only assigned to runners
+    /// carrying all of them. Only org-scoped runners may carry labels.
+    #[arg(long = "label", env = "FOREST_RUNNER_LABELS", value_delimiter = ',', value_parser = parse_label, requires = "organisation")]
+    labels: Vec<(String, String)>,
+
+    /// Pool this runner belongs to; matched as the `pool` runner label
+    #[arg(long, env = "FOREST_RUNNER_POOL", requires = "organisation")]
+    pool: Option<String>,
+
+    /// Only take releases for this organisation (requires --token)
+    #[arg(long, env = "FOREST_RUNNER_ORGANISATION")]
+    organisation: Option<String>,
+
+    /// Only take releases for this project (requires --organisation)
+    #[arg(long, env = "FOREST_RUNNER_PROJECT", requires = "organisation")]
+    project: Option<String>,
+
+    /// Token used to register an organisation- or project-scoped runner
+    #[arg(long, env = "FOREST_RUNNER_TOKEN", hide_env_values = true)]
+    token: Option<String>,
+}
+
+fn parse_label(s: &str) -> Result<(String, String), String> {
+    match s.split_once('=') {
+        Some((k, v)) if !k.trim().is_empty() => Ok((k.trim().into(), v.trim().into())),
+        _ => Err(format!("invalid label '{s}', expected key=value")),
+    }
 }
 
 fn default_runner_id() -> String {
@@ -75,17 +104,33 @@ async fn main() -> anyhow::Result<()> {
         server = %cli.server_addr,
         capabilities = ?capabilities.iter().map(|c| format!("{}/{}/{}", c.organisation, c.name, c.version)).collect::<Vec<_>>(),
         max_concurrent = cli.max_concurrent,
+        labels = ?cli.labels,
+        pool = ?cli.pool,
+        organisation = ?cli.organisation,
+        project = ?cli.project,
         "starting forest-runner"
     );
 
+    if cli.organisation.is_some() && cli.token.is_none() {
+        anyhow::bail!("--organisation requires --token to register a scoped runner");
+    }
+
+    let placement = RunnerPlacement {
+        labels: cli.labels.iter().cloned().collect(),
+        pool: cli.pool.clone(),
+        organisation: cli.organisation.clone(),
+        project: cli.project.clone(),
+    };
+
     let executor = Arc::new(Executor::new(destinations));
-    let client = ForestRunnerClient::new(cli.server_addr.clone());
+    let client = ForestRunnerClient::new(cli.server_addr.clone()).with_token(cli.token.clone());
 
     let runner_service = RunnerService::new(
         client,
         cli.runner_id.clone(),
         capabilities,
         cli.max_concurrent,
+        placement,
         executor,
     );
 
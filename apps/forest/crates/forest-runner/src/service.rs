@@ -5,7 +5,7 @@ use forest_grpc_interface::DestinationCapability;
 use notmad::{Component, ComponentInfo, MadError};
 use tokio_util::sync::CancellationToken;
 
-use crate::client::ForestRunnerClient;
+use crate::client::{ForestRunnerClient, RunnerPlacement};
 use crate::executor::Executor;
 
 /// notmad Component that manages the runner lifecycle:
@@ -15,6 +15,7 @@ pub struct RunnerService {
     runner_id: String,
     capabilities: Vec<DestinationCapability>,
     max_concurrent: i32,
+    placement: RunnerPlacement,
     executor: Arc<Executor>,
 }
 
@@ -24,6 +25,7 @@ impl RunnerService {
         runner_id: String,
         capabilities: Vec<DestinationCapability>,
         max_concurrent: i32,
+        placement: RunnerPlacement,
         executor: Arc<Executor>,
     ) -> Self {
         Self {
@@ -31,6 +33,7 @@ impl RunnerService {
             runner_id,
             capabilities,
             max_concurrent,
+            placement,
             executor,
         }
     }
@@ -44,6 +47,7 @@ impl RunnerService {
                 self.runner_id.clone(),
                 self.capabilities.clone(),
                 self.max_concurrent,
+                self.placement.clone(),
             )
             .await?;
 
@@ -0,0 +1,2 @@
+-- Labels a runner must carry to be assigned releases for a destination.
+ALTER TABLE destinations ADD COLUMN runner_labels JSONB NOT NULL DEFAULT '{}'::jsonb;
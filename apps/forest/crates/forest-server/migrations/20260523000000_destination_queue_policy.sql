@@ -0,0 +1,12 @@
+-- Releases to a destination run one at a time. The queue policy decides
+-- what happens to releases that arrive while one is in flight:
+--   wait      — queued releases run in the order they were requested
+--   supersede — a new release cancels releases still waiting in the queue
+ALTER TABLE destinations
+    ADD COLUMN queue_policy TEXT NOT NULL DEFAULT 'wait'
+    CHECK (queue_policy IN ('wait', 'supersede'));
+
+-- Queue lookups: in-flight and queued releases per project + destination.
+CREATE INDEX IF NOT EXISTS idx_release_states_destination_queue
+    ON release_states (project_id, destination_id, queued_at)
+    WHERE status IN ('QUEUED', 'ASSIGNED', 'RUNNING');
@@ -0,0 +1,24 @@
+-- WebAuthn passkeys. A user may register several, each named so they
+-- can tell their laptop from their security key. A passkey serves as a
+-- second factor after a password login, or as a passwordless login on
+-- its own.
+--
+-- `passkey` is the credential as webauthn-rs serialises it, public key
+-- and signature counter included. `credential_id` is copied out of it
+-- so assertions can be looked up.
+
+CREATE TABLE user_passkeys (
+    id             UUID PRIMARY KEY,
+    user_id        UUID        NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    name           TEXT        NOT NULL,
+    credential_id  BYTEA       NOT NULL,
+    passkey        JSONB       NOT NULL,
+    last_used_at   TIMESTAMPTZ,
+    created_at     TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at     TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+-- Assertions identify the credential, not the user.
+CREATE UNIQUE INDEX user_passkeys_credential_id_idx ON user_passkeys (credential_id);
+
+CREATE INDEX user_passkeys_user_id_idx ON user_passkeys (user_id);
@@ -0,0 +1,50 @@
+-- SCIM 2.0 provisioning (RFC 7643/7644). An organisation's identity
+-- provider pushes Users and Groups through an app token carrying the
+-- `scim` permission; forest maps them onto organisation_members.
+--
+-- `scim_users` records which members the IdP manages and the userName /
+-- externalId it knows them by. Deactivated users keep their row (so the
+-- IdP can still read and reactivate them) but lose their membership.
+
+CREATE TABLE scim_users (
+    organisation_id UUID        NOT NULL REFERENCES organisations(id) ON DELETE CASCADE,
+    user_id         UUID        NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    user_name       TEXT        NOT NULL,
+    external_id     TEXT,
+    display_name    TEXT,
+    active          BOOLEAN     NOT NULL DEFAULT true,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at      TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (organisation_id, user_id)
+);
+CREATE UNIQUE INDEX scim_users_org_user_name_idx ON scim_users (organisation_id, lower(user_name));
+
+CREATE TABLE scim_groups (
+    id              UUID PRIMARY KEY,
+    organisation_id UUID        NOT NULL REFERENCES organisations(id) ON DELETE CASCADE,
+    display_name    TEXT        NOT NULL,
+    external_id     TEXT,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at      TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+CREATE UNIQUE INDEX scim_groups_org_display_name_idx ON scim_groups (organisation_id, lower(display_name));
+
+CREATE TABLE scim_group_members (
+    group_id UUID NOT NULL REFERENCES scim_groups(id) ON DELETE CASCADE,
+    user_id  UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    PRIMARY KEY (group_id, user_id)
+);
+CREATE INDEX scim_group_members_user_idx ON scim_group_members (user_id);
+
+-- Organisation role granted by membership of an IdP group, keyed by the
+-- group's display name so a mapping can be set up before the IdP first
+-- pushes the group. When an organisation has any mappings, the roles of
+-- its SCIM-managed members follow their groups.
+CREATE TABLE scim_group_roles (
+    organisation_id UUID        NOT NULL REFERENCES organisations(id) ON DELETE CASCADE,
+    group_name      TEXT        NOT NULL,
+    role            TEXT        NOT NULL,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at      TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+CREATE UNIQUE INDEX scim_group_roles_org_group_idx ON scim_group_roles (organisation_id, lower(group_name));
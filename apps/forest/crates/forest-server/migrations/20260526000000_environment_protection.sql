@@ -0,0 +1,17 @@
+-- Organisation-wide protection rules on environments. They apply to every
+-- project releasing into the environment, on top of project policies.
+--
+-- `allowed_branches` holds branch regexes (empty = any branch),
+-- `required_prior_environment` names an environment the artifact must have
+-- been released to successfully first, and `protected` environments need an
+-- approval per release — one from a member of each of `approver_groups`
+-- (SCIM group display names) when any are set.
+
+ALTER TABLE environments
+    ADD COLUMN protected BOOLEAN NOT NULL DEFAULT false,
+    ADD COLUMN required_prior_environment TEXT,
+    ADD COLUMN approver_groups TEXT[] NOT NULL DEFAULT '{}',
+    ADD COLUMN allowed_branches TEXT[] NOT NULL DEFAULT '{}';
+
+-- Approvals for a protected environment need not belong to a project policy.
+ALTER TABLE approval_decisions ALTER COLUMN policy_id DROP NOT NULL;
@@ -0,0 +1,37 @@
+-- Terraform http backend state for `forest/terraform` destinations, one
+-- record per `<environment>.<project_id>`. Used to live in memory, so a
+-- restart lost every state and any lock held during an apply.
+--
+-- `secret` is the basic-auth password handed to terraform (in-process or
+-- on a remote runner). The lock columns describe the current holder as
+-- terraform reported it, plus the release that took it when known.
+
+CREATE TABLE terraform_states (
+    state_id           TEXT PRIMARY KEY,
+    project_id         UUID        NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    environment        TEXT        NOT NULL,
+    secret             TEXT        NOT NULL,
+    current_version    BIGINT      NOT NULL DEFAULT 0,
+    lock_id            TEXT,
+    lock_who           TEXT,
+    lock_operation     TEXT,
+    lock_release_id    UUID,
+    locked_at          TIMESTAMPTZ,
+    created_at         TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at         TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX terraform_states_project_id_idx ON terraform_states (project_id);
+
+-- Every state terraform has written, newest last. Rolling back copies an
+-- old version forward as a new one, so history is never rewritten.
+CREATE TABLE terraform_state_versions (
+    state_id           TEXT        NOT NULL REFERENCES terraform_states(state_id) ON DELETE CASCADE,
+    version            BIGINT      NOT NULL,
+    state              TEXT        NOT NULL,
+    lock_id            TEXT,
+    release_id         UUID,
+    rolled_back_from   BIGINT,
+    created_at         TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (state_id, version)
+);
@@ -0,0 +1,26 @@
+-- Encrypted secrets, scoped to an organisation and optionally narrowed to a
+-- project and/or environment. An empty `project` or `environment` means the
+-- secret applies to all of them; the most specific secret wins at release
+-- time.
+--
+-- Values are envelope encrypted: `ciphertext` is sealed with a per-secret
+-- data key, and `encrypted_key` is that data key sealed with the server's
+-- key identified by `key_id`. Both are `nonce || AES-256-GCM ciphertext`.
+-- `version` counts rotations.
+
+CREATE TABLE secrets (
+    id             UUID PRIMARY KEY,
+    organisation   TEXT        NOT NULL,
+    project        TEXT        NOT NULL DEFAULT '',
+    environment    TEXT        NOT NULL DEFAULT '',
+    name           TEXT        NOT NULL,
+    version        INTEGER     NOT NULL DEFAULT 1,
+    key_id         TEXT        NOT NULL,
+    encrypted_key  BYTEA       NOT NULL,
+    ciphertext     BYTEA       NOT NULL,
+    created_by     TEXT,
+    updated_by     TEXT,
+    created_at     TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at     TIMESTAMPTZ NOT NULL DEFAULT now(),
+    UNIQUE (organisation, project, environment, name)
+);
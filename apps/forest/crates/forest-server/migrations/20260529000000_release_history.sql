@@ -0,0 +1,8 @@
+-- Release history is browsed newest first, per project, and paged with a
+-- (created, id) keyset cursor.
+CREATE INDEX idx_release_intents_project_created
+    ON release_intents (project_id, created DESC, id DESC);
+
+-- `show` collects every step of an artifact's releases.
+CREATE INDEX idx_release_states_artifact
+    ON release_states (artifact_id, queued_at);
@@ -0,0 +1,26 @@
+-- Organisation retention policies, applied by the retention reaper.
+--
+-- `keep_artifacts` keeps the newest N artifacts per project, and the N most
+-- recently released per environment within it; anything currently deployed
+-- or mid-release is always kept. `log_retention_days` expires release logs
+-- and health observations, `notification_retention_days` notifications.
+-- A NULL keeps everything of that kind.
+
+CREATE TABLE retention_policies (
+    organisation                TEXT PRIMARY KEY REFERENCES organisations(name),
+    keep_artifacts              INTEGER CHECK (keep_artifacts > 0),
+    log_retention_days          INTEGER CHECK (log_retention_days > 0),
+    notification_retention_days INTEGER CHECK (notification_retention_days > 0),
+    updated_by                  TEXT,
+    created_at                  TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at                  TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+-- Set when an artifact's files are purged. The annotation and release
+-- history stay, but the artifact can no longer be released.
+ALTER TABLE artifacts ADD COLUMN files_purged_at TIMESTAMPTZ;
+
+CREATE INDEX idx_artifacts_artifact_id ON artifacts (artifact_id);
+CREATE INDEX idx_artifact_staging_created ON artifact_staging (created);
+CREATE INDEX idx_release_logs_created ON release_logs (created);
+CREATE INDEX idx_notifications_org_created ON notifications (organisation, created_at);
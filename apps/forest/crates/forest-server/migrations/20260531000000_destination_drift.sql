@@ -0,0 +1,20 @@
+-- Drift detection: the latest check of the release deployed to each
+-- destination against what is live on the target.
+--
+-- One row per project and destination. `release_id` is the release that
+-- was checked; when a newer release lands the row is re-checked on the
+-- next pass. `status` is NULL while the first check is in flight.
+
+CREATE TABLE destination_drift (
+    destination_id UUID NOT NULL REFERENCES destinations(id) ON DELETE CASCADE,
+    project_id     UUID NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    release_id     UUID NOT NULL,
+    status         TEXT CHECK (status IN ('IN_SYNC', 'DRIFTED', 'ERROR')),
+    detail         TEXT,
+    checked_at     TIMESTAMPTZ NOT NULL DEFAULT now(),
+    -- When `status` last changed, i.e. how long the destination has drifted.
+    changed_at     TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (destination_id, project_id)
+);
+
+CREATE INDEX idx_destination_drift_release ON destination_drift (release_id);
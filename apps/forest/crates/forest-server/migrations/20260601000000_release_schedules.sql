@@ -0,0 +1,39 @@
+-- Scheduled releases, fired by the release scheduler.
+--
+-- A schedule runs once at `run_at`, or repeatedly on a UTC `cron`
+-- expression. It releases `artifact_id`, or when that is NULL the project's
+-- latest artifact (from `branch`, if set) at the time it runs. Runs go
+-- through the same policy checks as a manual release, as the actor who
+-- created the schedule.
+--
+-- `next_run_at` is NULL once a schedule is completed or cancelled. The
+-- `last_*` columns describe the most recent run.
+
+CREATE TABLE release_schedules (
+    id                     UUID PRIMARY KEY,
+    project_id             UUID NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    run_at                 TIMESTAMPTZ,
+    cron                   TEXT,
+    artifact_id            UUID,
+    branch                 TEXT,
+    environments           TEXT[] NOT NULL DEFAULT '{}',
+    destinations           TEXT[] NOT NULL DEFAULT '{}',
+    force_release          BOOLEAN NOT NULL DEFAULT false,
+    use_pipeline           BOOLEAN NOT NULL DEFAULT false,
+    status                 TEXT NOT NULL DEFAULT 'ACTIVE'
+                           CHECK (status IN ('ACTIVE', 'COMPLETED', 'CANCELLED')),
+    next_run_at            TIMESTAMPTZ,
+    last_run_at            TIMESTAMPTZ,
+    last_outcome           TEXT CHECK (last_outcome IN ('RELEASED', 'SKIPPED', 'BLOCKED', 'FAILED')),
+    last_message           TEXT,
+    last_release_intent_id UUID,
+    created_by_type        TEXT NOT NULL,
+    created_by_id          UUID NOT NULL,
+    created_at             TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at             TIMESTAMPTZ NOT NULL DEFAULT now(),
+    CHECK ((run_at IS NULL) <> (cron IS NULL)),
+    CHECK (artifact_id IS NULL OR branch IS NULL)
+);
+
+CREATE INDEX idx_release_schedules_project ON release_schedules (project_id, created_at);
+CREATE INDEX idx_release_schedules_due ON release_schedules (next_run_at) WHERE status = 'ACTIVE';
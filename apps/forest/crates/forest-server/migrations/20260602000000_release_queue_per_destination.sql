@@ -0,0 +1,8 @@
+-- A destination's queue spans projects: several projects can release to
+-- one shared destination (e.g. one Flux repository), and those releases
+-- must not run at the same time either.
+DROP INDEX IF EXISTS idx_release_states_destination_queue;
+
+CREATE INDEX idx_release_states_destination_queue
+    ON release_states (destination_id, queued_at)
+    WHERE status IN ('QUEUED', 'ASSIGNED', 'RUNNING');
@@ -0,0 +1,7 @@
+-- An IdP may only take over an account forest already knows when the
+-- account's email is verified and on a domain the organisation has proven
+-- over DNS. Anyone else it provisions gets a pending invite: the SCIM
+-- record exists, but membership waits until the account owner accepts.
+ALTER TABLE scim_users ADD COLUMN pending BOOLEAN NOT NULL DEFAULT false;
+
+CREATE INDEX scim_users_pending_idx ON scim_users (user_id) WHERE pending;
@@ -0,0 +1,4 @@
+-- Organisations that require single sign-on. Forage owns the SSO
+-- configuration and mirrors the flag here, so forest refuses password,
+-- passkey, magic-link and other OAuth logins before issuing any tokens.
+ALTER TABLE organisations ADD COLUMN sso_enforced BOOLEAN NOT NULL DEFAULT false;
@@ -0,0 +1,4 @@
+-- Releases held by a protected environment until someone approves them.
+-- They keep their place in the destination queue but don't block it:
+-- another project's approved release to a shared destination runs first.
+ALTER TABLE release_states ADD COLUMN awaiting_approval BOOLEAN NOT NULL DEFAULT false;
@@ -2,7 +2,7 @@ use uuid::Uuid;
 
 /// Represents who performed an action — either a human user, an app, or a
 /// service account (long-lived infrastructure key).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Actor {
     User { user_id: Uuid },
     App { app_id: Uuid, organisation_id: Uuid },
@@ -80,6 +80,20 @@ pub async fn execute() -> anyhow::Result<()> {
         .map(|v| matches!(v.as_str(), "1" | "true" | "TRUE" | "True"))
         .unwrap_or(false);
 
+    let secrets_key = std::env::var("FOREST_SECRETS_KEY")
+        .ok()
+        .filter(|v| !v.is_empty())
+        .map(|key| {
+            let key = BASE64_STANDARD
+                .decode(key)
+                .context("FOREST_SECRETS_KEY was not base64")?;
+            if key.len() != 32 {
+                anyhow::bail!("FOREST_SECRETS_KEY must decode to exactly 32 bytes (is {})", key.len());
+            }
+            Ok(key)
+        })
+        .transpose()?;
+
     let config = Config {
         external_host: cli.external_host.clone(),
         terraform_external_host: cli.terraform_external_host.clone(),
@@ -107,6 +121,8 @@ pub async fn execute() -> anyhow::Result<()> {
             .ok()
             .filter(|v| !v.is_empty())
             .map(|v| v.trim_end_matches('/').to_string()),
+
+        secrets_key,
     };
 
     validate_config(&config)?;
@@ -1,9 +1,11 @@
 use std::net::SocketAddr;
 
 use crate::{
-    checks::Checks, destinations::terraformv1::TerraformV1ServerState, grpc,
-    intent_coordinator::IntentCoordinator, release_reaper::ReleaseReaper,
-    runner_manager::RunnerManager, scheduler::SchedulerState, servehttp::ServeHttp, state::State,
+    checks::Checks, destinations::terraformv1::TerraformV1ServerState,
+    drift_detector::DriftDetector, grpc, intent_coordinator::IntentCoordinator,
+    release_reaper::ReleaseReaper, release_scheduler::ReleaseScheduler,
+    retention_reaper::RetentionReaper, runner_manager::RunnerManager, scheduler::SchedulerState,
+    servehttp::ServeHttp, state::State,
 };
 
 #[derive(clap::Parser)]
@@ -23,8 +25,19 @@ pub struct ServeCommand {
 
     /// Disable in-process destination execution. Releases will only be
     /// dispatched to remote runners and will fail if no runner is available.
+    /// Drift detection, which only runs in-process, is turned off too.
     #[arg(long, env = "FOREST_DISABLE_IN_PROCESS", default_value = "false")]
     disable_in_process: bool,
+
+    /// Hours before an artifact or component upload that was never
+    /// committed is deleted.
+    #[arg(long, env = "FOREST_STAGING_RETENTION_HOURS", default_value = "24")]
+    staging_retention_hours: u64,
+
+    /// Minutes between drift checks of each deployed destination. 0 turns
+    /// drift detection off.
+    #[arg(long, env = "FOREST_DRIFT_CHECK_INTERVAL_MINUTES", default_value = "60")]
+    drift_check_interval_minutes: u64,
 }
 
 impl ServeCommand {
@@ -41,6 +54,7 @@ impl ServeCommand {
                 host: self.http_host,
                 object_store: state.object_store.clone(),
                 db: state.db.clone(),
+                state: state.clone(),
             })
             .add(Checks {
                 state: state.clone(),
@@ -48,7 +62,19 @@ impl ServeCommand {
             .add(state.terraform_v1_server(self.terraform_host))
             .add(state.scheduler(runner_manager.clone(), self.disable_in_process))
             .add(ReleaseReaper::new(state, runner_manager.clone()))
+            .add(RetentionReaper::new(
+                state,
+                std::time::Duration::from_secs(self.staging_retention_hours * 60 * 60),
+            ))
             .add(IntentCoordinator::new(state))
+            .add(ReleaseScheduler::new(state))
+            .add_conditional(
+                self.drift_check_interval_minutes > 0 && !self.disable_in_process,
+                DriftDetector::new(
+                    state,
+                    std::time::Duration::from_secs(self.drift_check_interval_minutes * 60),
+                ),
+            )
             .add(state.drop_queue.clone())
             .run()
             .await?;
@@ -14,6 +14,7 @@ use crate::{
     services::{
         artifact_staging_registry::ArtifactStagingRegistryState,
         release_logs_registry::ReleaseLogsRegistry, release_registry::ReleaseItem,
+        secrets::SecretRegistryState,
     },
     temp_dir::TempDirectoriesState,
 };
@@ -67,6 +68,7 @@ impl DestinationService {
                 temp: state.temp_directories(),
                 artifact_files: state.artifact_staging_registry(),
                 tf_state: state.terraform_state_store(),
+                secrets: state.secret_registry(),
             },
             release_logs_registry,
         )
@@ -132,6 +134,23 @@ impl DestinationService {
         self.inner.supports_plan()
     }
 
+    pub(crate) async fn detect_drift(
+        &self,
+        logger: &DestinationLogger,
+        deployed_release: &ReleaseItem,
+        destination: &Destination,
+    ) -> anyhow::Result<Option<String>> {
+        tracing::debug!(id =% deployed_release.id, destination =% self.name(), "checking drift");
+
+        self.inner
+            .detect_drift(logger, deployed_release, destination)
+            .await
+    }
+
+    pub fn supports_drift(&self) -> bool {
+        self.inner.supports_drift()
+    }
+
     fn create_logger(&self, staged_release: &ReleaseItem) -> DestinationLogger {
         DestinationLogger::new(staged_release.clone(), self.release_logs_registry.clone())
     }
@@ -192,6 +211,23 @@ pub trait DestinationEdge {
     fn supports_plan(&self) -> bool {
         false
     }
+
+    /// Compare what `release` deployed with what is live on the target now.
+    /// Returns a summary of what differs, or None when it still matches.
+    #[allow(unused_variables)]
+    async fn detect_drift(
+        &self,
+        logger: &DestinationLogger,
+        release: &ReleaseItem,
+        destination: &Destination,
+    ) -> anyhow::Result<Option<String>> {
+        Ok(None)
+    }
+
+    /// Whether this destination type can detect drift.
+    fn supports_drift(&self) -> bool {
+        false
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -216,4 +216,21 @@ impl DestinationEdge for FluxV1Destination {
             .await
             .context("flux release failed")
     }
+
+    async fn detect_drift(
+        &self,
+        logger: &DestinationLogger,
+        release: &ReleaseItem,
+        destination: &Destination,
+    ) -> anyhow::Result<Option<String>> {
+        let backend = self.create_backend(logger, release, destination);
+        let config = InProcessBackend::config_from_destination(destination);
+        FluxV1Handler::detect_drift(&backend, &config)
+            .await
+            .context("flux drift check failed")
+    }
+
+    fn supports_drift(&self) -> bool {
+        true
+    }
 }
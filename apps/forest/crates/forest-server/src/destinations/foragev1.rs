@@ -2,9 +2,9 @@ use std::collections::HashMap;
 
 use anyhow::Context;
 use forest_grpc_interface::{
-    forage_service_client::ForageServiceClient, ApplyResourcesRequest, ContainerServiceSpec,
-    ForageResource, RolloutStatus, ScalingPolicy, WatchRolloutRequest,
-    forage_resource, Container,
+    forage_service_client::ForageServiceClient, AppliedResource, ApplyResourcesRequest,
+    ContainerServiceSpec, ForageResource, GetAppliedResourcesRequest, RolloutStatus,
+    ScalingPolicy, WatchRolloutRequest, forage_resource, Container,
 };
 use forest_models::Destination;
 
@@ -135,9 +135,7 @@ impl DestinationEdge for ForageV1Destination {
         // resource from the release metadata.  In the future this will parse the
         // actual deployment files from the artifact.
         let resource_name = destination.name.clone();
-        let image = meta
-            .image
-            .unwrap_or_else(|| format!("registry.forage.sh/{}/{}", destination.organisation, resource_name));
+        let image = meta.image(destination);
 
         let resource = ForageResource {
             name: resource_name.clone(),
@@ -228,6 +226,85 @@ impl DestinationEdge for ForageV1Destination {
             }
         }
     }
+
+    async fn detect_drift(
+        &self,
+        _logger: &DestinationLogger,
+        release: &ReleaseItem,
+        destination: &Destination,
+    ) -> anyhow::Result<Option<String>> {
+        let meta = ForageV1Metadata::from_metadata(&destination.metadata)?;
+
+        let mut client = ForageServiceClient::connect(meta.forage_url.clone())
+            .await
+            .context(format!(
+                "failed to connect to forage at {}",
+                meta.forage_url
+            ))?;
+
+        let mut labels = HashMap::new();
+        labels.insert("project".into(), release.project.clone());
+        labels.insert("destination".into(), destination.name.clone());
+        labels.insert("environment".into(), destination.environment.clone());
+
+        let applied = client
+            .get_applied_resources(GetAppliedResourcesRequest {
+                namespace: meta.namespace.clone(),
+                labels,
+            })
+            .await
+            .context("GetAppliedResources RPC failed")?
+            .into_inner()
+            .resources;
+
+        let changes = compare_applied(
+            &destination.name,
+            &meta.image(destination),
+            meta.replicas,
+            &applied,
+        );
+
+        Ok((!changes.is_empty()).then(|| changes.join("\n")))
+    }
+
+    fn supports_drift(&self) -> bool {
+        true
+    }
+}
+
+/// Differences between the container service a release applied and what
+/// forage is running for the destination.
+fn compare_applied(
+    name: &str,
+    image: &str,
+    replicas: u32,
+    applied: &[AppliedResource],
+) -> Vec<String> {
+    let mut changes = Vec::new();
+
+    match applied.iter().find(|r| r.name == name) {
+        None => changes.push(format!("missing: {name}")),
+        Some(resource) => {
+            if resource.image != image {
+                changes.push(format!(
+                    "image: {name} runs {}, released {image}",
+                    resource.image
+                ));
+            }
+            if resource.replicas != replicas {
+                changes.push(format!(
+                    "replicas: {name} runs {}, released {replicas}",
+                    resource.replicas
+                ));
+            }
+        }
+    }
+
+    for resource in applied.iter().filter(|r| r.name != name) {
+        changes.push(format!("unexpected: {} ({})", resource.name, resource.kind));
+    }
+
+    changes
 }
 
 /// Validated metadata for the forage/containers@1 destination type.
@@ -263,6 +340,16 @@ impl ForageV1Metadata {
         Ok(())
     }
 
+    /// The image a release deploys, falling back to the forage registry.
+    fn image(&self, destination: &Destination) -> String {
+        self.image.clone().unwrap_or_else(|| {
+            format!(
+                "registry.forage.sh/{}/{}",
+                destination.organisation, destination.name
+            )
+        })
+    }
+
     fn from_metadata(metadata: &HashMap<String, String>) -> anyhow::Result<Self> {
         Self::validate(metadata)?;
         Ok(Self {
@@ -280,3 +367,41 @@ impl ForageV1Metadata {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn applied(name: &str, image: &str, replicas: u32) -> AppliedResource {
+        AppliedResource {
+            name: name.into(),
+            kind: "container_service".into(),
+            image: image.into(),
+            replicas,
+            status: "running".into(),
+        }
+    }
+
+    #[test]
+    fn compare_applied_reports_changed_and_stray_resources() {
+        let image = "registry.forage.sh/acme/web:1";
+
+        assert!(compare_applied("web", image, 2, &[applied("web", image, 2)]).is_empty());
+
+        assert_eq!(
+            compare_applied(
+                "web",
+                image,
+                2,
+                &[applied("web", "nginx:latest", 3), applied("debug", "busybox", 1)],
+            ),
+            vec![
+                "image: web runs nginx:latest, released registry.forage.sh/acme/web:1",
+                "replicas: web runs 3, released 2",
+                "unexpected: debug (container_service)",
+            ]
+        );
+
+        assert_eq!(compare_applied("web", image, 2, &[]), vec!["missing: web"]);
+    }
+}
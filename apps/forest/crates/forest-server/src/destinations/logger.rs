@@ -1,15 +1,21 @@
-use std::time::UNIX_EPOCH;
+use std::{
+    sync::{Arc, RwLock},
+    time::UNIX_EPOCH,
+};
 
 use tokio::sync::mpsc::UnboundedSender;
 
 use crate::services::{
     release_logs_registry::{LogChannel, LogLine, ReleaseLogsRegistry},
     release_registry::ReleaseItem,
+    secrets,
 };
 
 #[derive(Clone)]
 pub struct DestinationLogger {
     input: UnboundedSender<(LogChannel, String)>,
+    /// Secret values masked out of every line before it is stored.
+    redactions: Arc<RwLock<Vec<String>>>,
 }
 
 impl DestinationLogger {
@@ -119,7 +125,35 @@ impl DestinationLogger {
             }
         });
 
-        Self { input }
+        Self {
+            input,
+            redactions: Arc::default(),
+        }
+    }
+
+    /// A logger for work outside a release, such as drift checks. Lines go
+    /// to tracing at debug level instead of the release's logs.
+    pub fn detached() -> Self {
+        let (input, mut output) = tokio::sync::mpsc::unbounded_channel::<(LogChannel, String)>();
+        tokio::spawn(async move {
+            while let Some((_, line)) = output.recv().await {
+                tracing::debug!("{line}");
+            }
+        });
+
+        Self {
+            input,
+            redactions: Arc::default(),
+        }
+    }
+
+    /// Mask these values in every line logged from now on.
+    pub fn redact(&self, values: Vec<String>) {
+        let mut redactions = self.redactions.write().expect("redactions lock poisoned");
+        redactions.extend(values);
+        // Longest first, so a value containing another is masked whole.
+        redactions.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+        redactions.dedup();
     }
 
     pub fn log_stdout(&self, line: &str) {
@@ -131,7 +165,11 @@ impl DestinationLogger {
     }
 
     fn log(&self, channel: LogChannel, line: &str) {
-        if let Err(e) = self.input.send((channel, line.to_string())) {
+        let line = {
+            let redactions = self.redactions.read().expect("redactions lock poisoned");
+            secrets::redact(line, &redactions)
+        };
+        if let Err(e) = self.input.send((channel, line)) {
             tracing::warn!("failed to send log line: {:?}", e);
         }
     }
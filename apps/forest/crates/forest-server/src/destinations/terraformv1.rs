@@ -1,9 +1,4 @@
-use std::{
-    collections::{BTreeMap, HashMap},
-    net::SocketAddr,
-    process::Stdio,
-    sync::{Arc, OnceLock},
-};
+use std::{collections::HashMap, net::SocketAddr, process::Stdio, sync::Arc};
 
 use anyhow::Context;
 use axum::{
@@ -12,24 +7,33 @@ use axum::{
     routing::post,
 };
 use axum::{response::IntoResponse, routing::get};
+use base64::Engine;
+use chrono::{DateTime, Utc};
 use forest_models::Destination;
 
-use http::StatusCode;
+use hmac::{Hmac, Mac};
+use http::{HeaderMap, StatusCode};
 use notmad::{Component, ComponentInfo, MadError};
 use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use sqlx::PgPool;
+use subtle::ConstantTimeEq;
 use tokio::{
     io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
     net::TcpListener,
-    sync::Mutex,
 };
 use tokio_util::sync::CancellationToken;
 use tower_http::trace::TraceLayer;
 use tracing::Span;
+use uuid::Uuid;
 
 use crate::{
     State,
     destinations::{DestinationEdge, DestinationIndex, logger::DestinationLogger},
-    services::{artifact_staging_registry::ArtifactStagingRegistry, release_registry::ReleaseItem},
+    services::{
+        artifact_staging_registry::ArtifactStagingRegistry, release_registry::ReleaseItem,
+        secrets::SecretRegistry,
+    },
     temp_dir::TempDirectories,
 };
 
@@ -61,37 +65,156 @@ fn resolve_terraform_exe() -> String {
     "terraform".to_string()
 }
 
+/// Terraform http backend state for `forest/terraform` destinations, keyed by
+/// `<environment>.<project_id>`. Persisted in Postgres so state, locks and
+/// credentials survive restarts and are shared between replicas.
 #[derive(Clone)]
 pub struct TerraformStateStore {
-    // TODO: move to some kind of database
-    states: Arc<Mutex<BTreeMap<String, Option<String>>>>,
-    users: Arc<Mutex<BTreeMap<String, String>>>,
-    locks: Arc<Mutex<BTreeMap<String, Mutex<Option<String>>>>>,
+    db: PgPool,
 
     pub external_url: String,
 }
 
-impl TerraformStateStore {
-    pub async fn get(&self, project_id: &str) -> anyhow::Result<Option<String>> {
-        let states = self.states.lock().await;
+/// Who holds a state lock, as terraform reported it when taking it.
+#[derive(Debug, Clone)]
+pub struct TerraformLock {
+    pub lock_id: String,
+    pub who: Option<String>,
+    pub operation: Option<String>,
+    pub release_id: Option<Uuid>,
+    /// None for a lock whose time was not recorded.
+    pub locked_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TerraformStateRecord {
+    pub state_id: String,
+    pub environment: String,
+    /// 0 until terraform has written a state.
+    pub current_version: i64,
+    pub lock: Option<TerraformLock>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TerraformStateVersion {
+    pub version: i64,
+    pub lock_id: Option<String>,
+    pub release_id: Option<Uuid>,
+    pub rolled_back_from: Option<i64>,
+    pub size_bytes: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+pub enum RollbackOutcome {
+    RolledBack(TerraformStateVersion),
+    Locked(TerraformLock),
+    UnknownVersion,
+}
 
-        tracing::debug!(project_id, "get state");
+fn lock_from_columns(
+    lock_id: Option<String>,
+    who: Option<String>,
+    operation: Option<String>,
+    release_id: Option<Uuid>,
+    locked_at: Option<DateTime<Utc>>,
+) -> Option<TerraformLock> {
+    Some(TerraformLock {
+        lock_id: lock_id?,
+        who,
+        operation,
+        release_id,
+        locked_at,
+    })
+}
+
+/// The password for one release's backend URL: an HMAC of the release id,
+/// keyed by the state's secret.
+fn release_password(secret: &str, release_id: &Uuid) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("hmac accepts any key length");
+    mac.update(release_id.as_bytes());
+    hex::encode(mac.finalize().into_bytes())
+}
+
+impl TerraformStateStore {
+    pub async fn get(&self, state_id: &str) -> anyhow::Result<Option<String>> {
+        tracing::debug!(state_id, "get state");
+
+        let state = sqlx::query_scalar!(
+            r#"SELECT v.state
+               FROM terraform_states s
+               JOIN terraform_state_versions v
+                 ON v.state_id = s.state_id AND v.version = s.current_version
+               WHERE s.state_id = $1"#,
+            state_id
+        )
+        .fetch_optional(&self.db)
+        .await
+        .context("get terraform state")?;
 
-        Ok(states.get(project_id).cloned().flatten())
+        Ok(state)
     }
 
-    pub async fn add(&self, project_id: &str, lock_id: &str, state: &str) -> anyhow::Result<()> {
-        let mut states = self.states.lock().await;
-        if let Some(lock) = self.get_lock(project_id).await?
-            && lock == lock_id
-        {
-        } else {
+    /// Store a new version of the state. The caller must hold the lock.
+    /// Writes that don't change the state are not recorded again.
+    pub async fn add(
+        &self,
+        state_id: &str,
+        lock_id: &str,
+        state: &str,
+        release_id: Option<Uuid>,
+    ) -> anyhow::Result<()> {
+        let mut tx = self.db.begin().await?;
+
+        let current = sqlx::query!(
+            r#"SELECT s.lock_id, s.current_version, v.state AS "state?"
+               FROM terraform_states s
+               LEFT JOIN terraform_state_versions v
+                 ON v.state_id = s.state_id AND v.version = s.current_version
+               WHERE s.state_id = $1
+               FOR UPDATE OF s"#,
+            state_id
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .context("load terraform state")?
+        .context("unknown terraform state")?;
+
+        if current.lock_id.as_deref() != Some(lock_id) {
             anyhow::bail!("lock id doesn't match the currently held lock for project");
-        };
+        }
 
-        tracing::debug!(project_id, "saving state");
+        if current.state.as_deref() == Some(state) {
+            return Ok(());
+        }
 
-        states.insert(project_id.into(), Some(state.to_string()));
+        tracing::debug!(state_id, version = current.current_version + 1, "saving state");
+
+        let version = current.current_version + 1;
+        sqlx::query!(
+            r#"INSERT INTO terraform_state_versions (state_id, version, state, lock_id, release_id)
+               VALUES ($1, $2, $3, $4, $5)"#,
+            state_id,
+            version,
+            state,
+            lock_id,
+            release_id,
+        )
+        .execute(&mut *tx)
+        .await
+        .context("insert terraform state version")?;
+
+        sqlx::query!(
+            "UPDATE terraform_states SET current_version = $2, updated_at = now() WHERE state_id = $1",
+            state_id,
+            version,
+        )
+        .execute(&mut *tx)
+        .await
+        .context("update terraform state")?;
+
+        tx.commit().await?;
 
         Ok(())
     }
@@ -108,76 +231,318 @@ impl TerraformStateStore {
         format!("{environment}.{project_id}")
     }
 
+    /// Base URL and password terraform uses for a release. Carrying the
+    /// release in the path lets locks and state versions record which
+    /// release made them, for in-process and remote runs alike. The
+    /// password is derived from the release id, so it only works on that
+    /// release's URL; the state's secret itself is never handed out.
+    pub async fn release_credentials(
+        &self,
+        state_id: String,
+        release_id: &Uuid,
+    ) -> anyhow::Result<(String, String)> {
+        let (state_id, secret) = self.urls(state_id).await?;
+        let url = format!(
+            "{}/{state_id}/releases/{release_id}",
+            self.external_url.trim_end_matches('/')
+        );
+
+        Ok((url, release_password(&secret, release_id)))
+    }
+
     /// Look up (or generate) the secret for a state id and return both back.
-    pub async fn urls(&self, state_id: String) -> (String, String) {
-        let mut user = self.users.lock().await;
+    pub async fn urls(&self, state_id: String) -> anyhow::Result<(String, String)> {
+        let (environment, project_id) = state_id
+            .rsplit_once('.')
+            .context("terraform state id must be <environment>.<project_id>")?;
+        let project_id: Uuid = project_id.parse().context("terraform state project id")?;
+
+        let secret = sqlx::query_scalar!(
+            r#"INSERT INTO terraform_states (state_id, project_id, environment, secret)
+               VALUES ($1, $2, $3, $4)
+               ON CONFLICT (state_id) DO UPDATE SET state_id = EXCLUDED.state_id
+               RETURNING secret"#,
+            state_id,
+            project_id,
+            environment,
+            uuid::Uuid::new_v4().to_string(),
+        )
+        .fetch_one(&self.db)
+        .await
+        .context("get terraform state secret")?;
+
+        Ok((state_id, secret))
+    }
 
-        let secret = user.entry(state_id.clone()).or_insert_with(|| {
-            let id = uuid::Uuid::new_v4();
-            id.to_string()
+    /// Check the basic-auth password. On a release's URL it must be that
+    /// release's password, which is what makes the release id in the path
+    /// trustworthy.
+    async fn verify_secret(
+        &self,
+        state_id: &str,
+        release_id: Option<Uuid>,
+        password: &str,
+    ) -> anyhow::Result<bool> {
+        let secret = sqlx::query_scalar!(
+            "SELECT secret FROM terraform_states WHERE state_id = $1",
+            state_id
+        )
+        .fetch_optional(&self.db)
+        .await
+        .context("get terraform state secret")?;
+
+        let expected = secret.map(|s| match release_id {
+            Some(release_id) => release_password(&s, &release_id),
+            None => s,
         });
 
-        (state_id, secret.clone())
+        // Constant time, so response timing doesn't reveal how much of the
+        // password matched.
+        Ok(expected.is_some_and(|s| bool::from(s.as_bytes().ct_eq(password.as_bytes()))))
     }
 
-    async fn get_lock(&self, project_id: &str) -> anyhow::Result<Option<String>> {
-        let locks = self.locks.lock().await;
-        if let Some(project_handle) = locks.get(project_id)
-            && let Some(project) = project_handle.lock().await.as_ref()
-        {
-            return Ok(Some(project.clone()));
+    async fn get_lock(&self, state_id: &str) -> anyhow::Result<Option<TerraformLock>> {
+        let row = sqlx::query!(
+            r#"SELECT lock_id, lock_who, lock_operation, lock_release_id, locked_at
+               FROM terraform_states WHERE state_id = $1"#,
+            state_id
+        )
+        .fetch_optional(&self.db)
+        .await
+        .context("get terraform lock")?;
+
+        Ok(row.and_then(|r| {
+            lock_from_columns(
+                r.lock_id,
+                r.lock_who,
+                r.lock_operation,
+                r.lock_release_id,
+                r.locked_at,
+            )
+        }))
+    }
+
+    async fn attempt_lock(
+        &self,
+        state_id: &str,
+        req: &LockRequest,
+        release_id: Option<Uuid>,
+    ) -> anyhow::Result<LockState> {
+        let taken = sqlx::query_scalar!(
+            r#"UPDATE terraform_states
+               SET lock_id = $2, lock_who = $3, lock_operation = $4,
+                   lock_release_id = $5, locked_at = now()
+               WHERE state_id = $1 AND lock_id IS NULL
+               RETURNING state_id"#,
+            state_id,
+            req.lock_id,
+            req.who,
+            req.operation,
+            release_id,
+        )
+        .fetch_optional(&self.db)
+        .await
+        .context("lock terraform state")?;
+
+        if taken.is_some() {
+            return Ok(LockState::Held);
         }
 
-        Ok(None)
+        match self.get_lock(state_id).await? {
+            // Same lock is held
+            Some(lock) if lock.lock_id == req.lock_id => Ok(LockState::Held),
+            Some(lock) => Ok(LockState::Wait(lock)),
+            None => anyhow::bail!("unknown terraform state: {state_id}"),
+        }
     }
 
-    async fn attempt_lock(&self, project_id: String, lock_id: &str) -> anyhow::Result<LockState> {
-        let mut locks = self.locks.lock().await;
+    async fn attempt_unlock(&self, state_id: &str, lock_id: &str) -> anyhow::Result<UnlockState> {
+        let released = sqlx::query_scalar!(
+            r#"UPDATE terraform_states
+               SET lock_id = NULL, lock_who = NULL, lock_operation = NULL,
+                   lock_release_id = NULL, locked_at = NULL
+               WHERE state_id = $1 AND lock_id = $2
+               RETURNING state_id"#,
+            state_id,
+            lock_id,
+        )
+        .fetch_optional(&self.db)
+        .await
+        .context("unlock terraform state")?;
+
+        if released.is_some() {
+            return Ok(UnlockState::Available);
+        }
 
-        let project_lock = locks
-            .entry(project_id.to_string())
-            .or_insert_with(Mutex::default);
+        match self.get_lock(state_id).await? {
+            Some(_) => Ok(UnlockState::NotOwnedLock),
+            None => Ok(UnlockState::Available),
+        }
+    }
 
-        let mut project_handle = project_lock.lock().await;
+    /// Drop the lock whoever holds it, returning the lock that was dropped.
+    pub async fn force_unlock(&self, state_id: &str) -> anyhow::Result<Option<TerraformLock>> {
+        let mut tx = self.db.begin().await?;
+
+        let row = sqlx::query!(
+            r#"SELECT lock_id, lock_who, lock_operation, lock_release_id, locked_at
+               FROM terraform_states WHERE state_id = $1 FOR UPDATE"#,
+            state_id
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .context("get terraform lock")?;
+
+        let Some(lock) = row.and_then(|r| {
+            lock_from_columns(
+                r.lock_id,
+                r.lock_who,
+                r.lock_operation,
+                r.lock_release_id,
+                r.locked_at,
+            )
+        }) else {
+            return Ok(None);
+        };
 
-        if let Some(project_handle) = project_handle.as_ref() {
-            // Same lock is held
-            if project_handle == lock_id {
-                return Ok(LockState::Held);
-            }
+        sqlx::query!(
+            r#"UPDATE terraform_states
+               SET lock_id = NULL, lock_who = NULL, lock_operation = NULL,
+                   lock_release_id = NULL, locked_at = NULL
+               WHERE state_id = $1"#,
+            state_id,
+        )
+        .execute(&mut *tx)
+        .await
+        .context("force unlock terraform state")?;
 
-            return Ok(LockState::Wait);
-        }
+        tx.commit().await?;
 
-        *project_handle = Some(lock_id.to_string());
+        tracing::info!(state_id, lock_id = lock.lock_id, "force unlocked terraform state");
 
-        Ok(LockState::Held)
+        Ok(Some(lock))
     }
 
-    async fn attempt_unlock(
+    pub async fn list_for_project(
         &self,
-        project_id: String,
-        lock_id: &str,
-    ) -> anyhow::Result<UnlockState> {
-        let locks = self.locks.lock().await;
+        project_id: &Uuid,
+    ) -> anyhow::Result<Vec<TerraformStateRecord>> {
+        let rows = sqlx::query!(
+            r#"SELECT state_id, environment, current_version, lock_id, lock_who,
+                      lock_operation, lock_release_id, locked_at, updated_at
+               FROM terraform_states
+               WHERE project_id = $1
+               ORDER BY environment"#,
+            project_id
+        )
+        .fetch_all(&self.db)
+        .await
+        .context("list terraform states")?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| TerraformStateRecord {
+                state_id: r.state_id,
+                environment: r.environment,
+                current_version: r.current_version,
+                lock: lock_from_columns(
+                    r.lock_id,
+                    r.lock_who,
+                    r.lock_operation,
+                    r.lock_release_id,
+                    r.locked_at,
+                ),
+                updated_at: r.updated_at,
+            })
+            .collect())
+    }
 
-        let Some(project_lock) = locks.get(&project_id) else {
-            return Ok(UnlockState::Available);
-        };
+    /// Version history of a state, newest first.
+    pub async fn versions(&self, state_id: &str) -> anyhow::Result<Vec<TerraformStateVersion>> {
+        let rows = sqlx::query_as!(
+            TerraformStateVersion,
+            r#"SELECT version, lock_id, release_id, rolled_back_from,
+                      octet_length(state)::BIGINT AS "size_bytes!", created_at
+               FROM terraform_state_versions
+               WHERE state_id = $1
+               ORDER BY version DESC"#,
+            state_id
+        )
+        .fetch_all(&self.db)
+        .await
+        .context("list terraform state versions")?;
+
+        Ok(rows)
+    }
 
-        let mut project_handle = project_lock.lock().await;
+    /// Make an earlier version current again by copying it forward as a new
+    /// version. Refused while the state is locked.
+    pub async fn rollback(&self, state_id: &str, version: i64) -> anyhow::Result<RollbackOutcome> {
+        let mut tx = self.db.begin().await?;
+
+        let current = sqlx::query!(
+            r#"SELECT current_version, lock_id, lock_who, lock_operation, lock_release_id, locked_at
+               FROM terraform_states WHERE state_id = $1 FOR UPDATE"#,
+            state_id
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .context("load terraform state")?;
+
+        let Some(current) = current else {
+            return Ok(RollbackOutcome::UnknownVersion);
+        };
 
-        if let Some(_handle) = project_handle.take_if(|handle| handle == lock_id) {
-            return Ok(UnlockState::Available);
+        if let Some(lock) = lock_from_columns(
+            current.lock_id,
+            current.lock_who,
+            current.lock_operation,
+            current.lock_release_id,
+            current.locked_at,
+        ) {
+            return Ok(RollbackOutcome::Locked(lock));
         }
 
-        Ok(UnlockState::NotOwnedLock)
+        let next = current.current_version + 1;
+        let Some(created) = sqlx::query_as!(
+            TerraformStateVersion,
+            r#"INSERT INTO terraform_state_versions (state_id, version, state, rolled_back_from)
+               SELECT state_id, $3, state, version
+               FROM terraform_state_versions
+               WHERE state_id = $1 AND version = $2
+               RETURNING version, lock_id, release_id, rolled_back_from,
+                         octet_length(state)::BIGINT AS "size_bytes!", created_at"#,
+            state_id,
+            version,
+            next,
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .context("copy terraform state version")?
+        else {
+            return Ok(RollbackOutcome::UnknownVersion);
+        };
+
+        sqlx::query!(
+            "UPDATE terraform_states SET current_version = $2, updated_at = now() WHERE state_id = $1",
+            state_id,
+            next,
+        )
+        .execute(&mut *tx)
+        .await
+        .context("update terraform state")?;
+
+        tx.commit().await?;
+
+        tracing::info!(state_id, from = version, to = next, "rolled back terraform state");
+
+        Ok(RollbackOutcome::RolledBack(created))
     }
 }
 
 enum LockState {
     Held,
-    Wait,
+    Wait(TerraformLock),
 }
 enum UnlockState {
     Available,
@@ -190,16 +555,11 @@ pub trait TerraformStateStoreState {
 
 impl TerraformStateStoreState for State {
     fn terraform_state_store(&self) -> TerraformStateStore {
-        static ONCE: OnceLock<TerraformStateStore> = OnceLock::new();
-
-        ONCE.get_or_init(|| TerraformStateStore {
-            states: Arc::default(),
-            users: Arc::default(),
-            locks: Arc::default(),
+        TerraformStateStore {
+            db: self.db.clone(),
 
             external_url: self.config.terraform_external_host.clone(),
-        })
-        .clone()
+        }
     }
 }
 
@@ -211,9 +571,15 @@ pub struct TerraformV1Server {
 impl TerraformV1Server {
     pub async fn serve(&self, cancel: CancellationToken) -> anyhow::Result<()> {
         let router = axum::Router::new()
-            .route("/{project_id}", get(Self::get_state).post(Self::post_state))
-            .route("/{project_id}/lock", post(Self::lock_state))
-            .route("/{project_id}/unlock", post(Self::unlock_state))
+            .route("/{state_id}", get(Self::get_state).post(Self::post_state))
+            .route("/{state_id}/lock", post(Self::lock_state))
+            .route("/{state_id}/unlock", post(Self::unlock_state))
+            .route(
+                "/{state_id}/releases/{release_id}",
+                get(Self::get_state).post(Self::post_state),
+            )
+            .route("/{state_id}/releases/{release_id}/lock", post(Self::lock_state))
+            .route("/{state_id}/releases/{release_id}/unlock", post(Self::unlock_state))
             .layer(TraceLayer::new_for_http().on_request(
                 |req: &axum::http::Request<_>, _span: &Span| {
                     let uri = req.uri();
@@ -239,17 +605,56 @@ impl TerraformV1Server {
         Ok(())
     }
 
+    /// Terraform sends the state's secret, or on a release's URL that
+    /// release's password, as the basic-auth password.
+    async fn authenticate(
+        store: &TerraformStateStore,
+        path: &StatePath,
+        headers: &HeaderMap,
+    ) -> Result<(), ApiError> {
+        let password = headers
+            .get(http::header::AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.strip_prefix("Basic "))
+            .and_then(|v| base64::engine::general_purpose::STANDARD.decode(v).ok())
+            .and_then(|v| String::from_utf8(v).ok())
+            .and_then(|v| v.split_once(':').map(|(_, p)| p.to_string()));
+
+        let Some(password) = password else {
+            return Err(ApiError::Unauthorized);
+        };
+
+        match store
+            .verify_secret(&path.state_id, path.release_id, &password)
+            .await
+        {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(ApiError::Unauthorized),
+            Err(e) => {
+                tracing::warn!(
+                    state_id = path.state_id,
+                    "failed to verify terraform credentials: {e:#}"
+                );
+                Err(ApiError::InternalServerError)
+            }
+        }
+    }
+
     async fn get_state(
         AState(state): AState<State>,
-        Path(project_id): Path<String>,
+        Path(path): Path<StatePath>,
+        headers: HeaderMap,
     ) -> Result<impl IntoResponse, ApiError> {
-        let Ok(state) = state.terraform_state_store().get(&project_id).await else {
-            tracing::info!(project_id, "failed to request state");
+        let store = state.terraform_state_store();
+        Self::authenticate(&store, &path, &headers).await?;
+
+        let Ok(state) = store.get(&path.state_id).await else {
+            tracing::info!(state_id = path.state_id, "failed to request state");
             return Err(ApiError::BadRequest);
         };
 
         let Some(state) = state else {
-            tracing::info!(project_id, "no terraform state found");
+            tracing::info!(state_id = path.state_id, "no terraform state found");
             return Err(ApiError::NotFound);
         };
 
@@ -257,13 +662,16 @@ impl TerraformV1Server {
     }
     async fn post_state(
         AState(state): AState<State>,
-        Path(project_id): Path<String>,
+        Path(path): Path<StatePath>,
         Query(req): Query<LockRequest>,
+        headers: HeaderMap,
         body: String,
     ) -> Result<impl IntoResponse, ApiError> {
-        if let Err(e) = state
-            .terraform_state_store()
-            .add(&project_id, &req.lock_id, &body)
+        let store = state.terraform_state_store();
+        Self::authenticate(&store, &path, &headers).await?;
+
+        if let Err(e) = store
+            .add(&path.state_id, &req.lock_id, &body, path.release_id)
             .await
         {
             tracing::error!("failed to save state: {e:#}");
@@ -274,37 +682,40 @@ impl TerraformV1Server {
     }
     async fn lock_state(
         AState(state): AState<State>,
-        Path(project_id): Path<String>,
+        Path(path): Path<StatePath>,
+        headers: HeaderMap,
         Json(req): Json<LockRequest>,
     ) -> Result<impl IntoResponse, ApiError> {
-        tracing::info!(lock_id = req.lock_id, project_id, "locking state");
+        let store = state.terraform_state_store();
+        Self::authenticate(&store, &path, &headers).await?;
 
-        let Ok(lock) = state
-            .terraform_state_store()
-            .attempt_lock(project_id, &req.lock_id)
-            .await
-        else {
-            tracing::warn!("failed to lock state");
-            return Err(ApiError::InternalServerError);
+        tracing::info!(lock_id = req.lock_id, state_id = path.state_id, "locking state");
+
+        let lock = match store.attempt_lock(&path.state_id, &req, path.release_id).await {
+            Ok(lock) => lock,
+            Err(e) => {
+                tracing::warn!("failed to lock state: {e:#}");
+                return Err(ApiError::InternalServerError);
+            }
         };
 
         match lock {
             LockState::Held => Ok(()),
-            LockState::Wait => Err(ApiError::Custom(StatusCode::CONFLICT)),
+            LockState::Wait(holder) => Err(ApiError::Locked(holder)),
         }
     }
     async fn unlock_state(
         AState(state): AState<State>,
-        Path(project_id): Path<String>,
+        Path(path): Path<StatePath>,
+        headers: HeaderMap,
         Json(req): Json<LockRequest>,
     ) -> Result<impl IntoResponse, ApiError> {
-        tracing::info!(lock_id = req.lock_id, project_id, "unlocking state");
+        let store = state.terraform_state_store();
+        Self::authenticate(&store, &path, &headers).await?;
 
-        let Ok(lock) = state
-            .terraform_state_store()
-            .attempt_unlock(project_id, &req.lock_id)
-            .await
-        else {
+        tracing::info!(lock_id = req.lock_id, state_id = path.state_id, "unlocking state");
+
+        let Ok(lock) = store.attempt_unlock(&path.state_id, &req.lock_id).await else {
             tracing::warn!("failed to unlock state");
             return Err(ApiError::InternalServerError);
         };
@@ -316,32 +727,57 @@ impl TerraformV1Server {
     }
 }
 
+/// Terraform's `LockInfo`. Only the fields worth keeping are read.
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct LockRequest {
     #[serde(alias = "ID")]
     lock_id: String,
+    #[serde(default, alias = "Who")]
+    who: Option<String>,
+    #[serde(default, alias = "Operation")]
+    operation: Option<String>,
 }
 
-struct StatePath {}
+#[derive(Deserialize)]
+struct StatePath {
+    state_id: String,
+    #[serde(default)]
+    release_id: Option<Uuid>,
+}
 
 pub enum ApiError {
     BadRequest,
+    Unauthorized,
     NotFound,
     InternalServerError,
-    Custom(StatusCode),
+    /// Someone else holds the lock; terraform prints the body as the holder.
+    Locked(TerraformLock),
 }
 
 impl IntoResponse for ApiError {
     fn into_response(self) -> axum::response::Response {
         match self {
-            ApiError::BadRequest => (StatusCode::BAD_REQUEST, "invalid request"),
-            ApiError::NotFound => (StatusCode::NOT_FOUND, "found no plan"),
+            ApiError::BadRequest => (StatusCode::BAD_REQUEST, "invalid request").into_response(),
+            ApiError::Unauthorized => {
+                (StatusCode::UNAUTHORIZED, "invalid credentials").into_response()
+            }
+            ApiError::NotFound => (StatusCode::NOT_FOUND, "found no plan").into_response(),
             ApiError::InternalServerError => {
-                (StatusCode::INTERNAL_SERVER_ERROR, "internal server error")
+                (StatusCode::INTERNAL_SERVER_ERROR, "internal server error").into_response()
             }
-            ApiError::Custom(status) => (status, "custom error"),
+            ApiError::Locked(lock) => (
+                StatusCode::CONFLICT,
+                Json(serde_json::json!({
+                    "ID": lock.lock_id,
+                    "Who": lock.who.unwrap_or_default(),
+                    "Operation": lock.operation.unwrap_or_default(),
+                    "Info": lock.release_id.map(|id| format!("forest release {id}")).unwrap_or_default(),
+                    // Terraform parses this as a time; null when unknown.
+                    "Created": lock.locked_at.map(|t| t.to_rfc3339()),
+                })),
+            )
+                .into_response(),
         }
-        .into_response()
     }
 }
 
@@ -374,30 +810,54 @@ pub struct TerraformV1Destination {
     pub temp: TempDirectories,
     pub artifact_files: ArtifactStagingRegistry,
     pub tf_state: TerraformStateStore,
+    pub secrets: SecretRegistry,
 }
 
 impl TerraformV1Destination {
-    async fn run(
+    /// State backend settings, plus the release's secrets as `TF_VAR_<name>`
+    /// so modules can declare them as variables.
+    async fn tf_envs(
         &self,
-        logger: &DestinationLogger,
         release: &ReleaseItem,
         destination: &Destination,
-        mode: Mode,
-    ) -> anyhow::Result<()> {
+    ) -> anyhow::Result<HashMap<String, String>> {
         let project_id = &release.project_id;
         let state_id = self.tf_state.state_id(destination, &project_id.to_string());
-        let (id, password) = self.tf_state.urls(state_id).await;
+        let (base, password) = self
+            .tf_state
+            .release_credentials(state_id, &release.id)
+            .await?;
+
+        let mut tf_envs = HashMap::from([
+            ("TF_HTTP_UNLOCK_ADDRESS".to_string(), format!("{base}/unlock")),
+            ("TF_HTTP_LOCK_ADDRESS".to_string(), format!("{base}/lock")),
+            ("TF_HTTP_ADDRESS".to_string(), base),
+            ("TF_HTTP_PASSWORD".to_string(), password),
+        ]);
 
-        let base = format!("{}/{id}", self.tf_state.external_url.trim_end_matches("/"));
-        let lock = format!("{base}/lock");
-        let unlock = format!("{base}/unlock");
+        let secrets = self
+            .secrets
+            .resolve(&destination.organisation, &release.project, &destination.environment)
+            .await
+            .context("resolve secrets")?;
+        tf_envs.extend(
+            secrets
+                .values()
+                .iter()
+                .map(|(name, value)| (format!("TF_VAR_{name}"), value.clone())),
+        );
+
+        Ok(tf_envs)
+    }
 
-        let tf_envs = HashMap::from([
-            ("TF_HTTP_ADDRESS", base.as_str()),
-            ("TF_HTTP_UNLOCK_ADDRESS", unlock.as_str()),
-            ("TF_HTTP_LOCK_ADDRESS", lock.as_str()),
-            ("TF_HTTP_PASSWORD", password.as_str()),
-        ]);
+    async fn run(
+        &self,
+        logger: &DestinationLogger,
+        release: &ReleaseItem,
+        destination: &Destination,
+        mode: Mode,
+    ) -> anyhow::Result<()> {
+        let tf_envs = self.tf_envs(release, destination).await?;
 
         let temp_dir = self.temp.create_emphemeral_temp().await?;
         let files = self
@@ -504,27 +964,16 @@ impl TerraformV1Destination {
         Ok(())
     }
 
-    /// Run terraform init + plan, capturing stdout as the plan output string.
+    /// Run terraform init + plan with `plan_args`, capturing stdout as the
+    /// plan output string.
     async fn run_capture(
         &self,
         logger: &DestinationLogger,
         release: &ReleaseItem,
         destination: &Destination,
+        plan_args: &[&str],
     ) -> anyhow::Result<String> {
-        let project_id = &release.project_id;
-        let state_id = self.tf_state.state_id(destination, &project_id.to_string());
-        let (id, password) = self.tf_state.urls(state_id).await;
-
-        let base = format!("{}/{id}", self.tf_state.external_url.trim_end_matches("/"));
-        let lock = format!("{base}/lock");
-        let unlock = format!("{base}/unlock");
-
-        let tf_envs = HashMap::from([
-            ("TF_HTTP_ADDRESS", base.as_str()),
-            ("TF_HTTP_UNLOCK_ADDRESS", unlock.as_str()),
-            ("TF_HTTP_LOCK_ADDRESS", lock.as_str()),
-            ("TF_HTTP_PASSWORD", password.as_str()),
-        ]);
+        let tf_envs = self.tf_envs(release, destination).await?;
 
         let temp_dir = self.temp.create_emphemeral_temp().await?;
         let files = self
@@ -575,7 +1024,7 @@ impl TerraformV1Destination {
 
             // plan — capture stdout
             plan_output = self
-                .run_command_capture(logger, destination, &dir, &tf_envs, &["plan"])
+                .run_command_capture(logger, destination, &dir, &tf_envs, plan_args)
                 .await
                 .context("terraform plan")?;
         }
@@ -593,7 +1042,7 @@ impl TerraformV1Destination {
         logger: &DestinationLogger,
         destination: &Destination,
         path: &std::path::Path,
-        tf_envs: &HashMap<&str, &str>,
+        tf_envs: &HashMap<String, String>,
         args: &[&str],
     ) -> anyhow::Result<String> {
         tracing::debug!(path =% path.display(), "running terraform {} (capture)", args.join(" "));
@@ -675,7 +1124,7 @@ impl TerraformV1Destination {
         logger: &DestinationLogger,
         destination: &Destination,
         path: &std::path::Path,
-        tf_envs: &HashMap<&str, &str>,
+        tf_envs: &HashMap<String, String>,
         args: &[&str],
     ) -> anyhow::Result<()> {
         tracing::debug!(path =% path.display(), "running terraform {}", args.join(" "));
@@ -824,7 +1273,7 @@ impl DestinationEdge for TerraformV1Destination {
         release: &ReleaseItem,
         destination: &Destination,
     ) -> anyhow::Result<Option<String>> {
-        let output = self.run_capture(logger, release, destination)
+        let output = self.run_capture(logger, release, destination, &["plan"])
             .await
             .context("terraform plan failed")?;
 
@@ -834,6 +1283,54 @@ impl DestinationEdge for TerraformV1Destination {
     fn supports_plan(&self) -> bool {
         true
     }
+
+    async fn detect_drift(
+        &self,
+        logger: &DestinationLogger,
+        release: &ReleaseItem,
+        destination: &Destination,
+    ) -> anyhow::Result<Option<String>> {
+        let output = self
+            .run_capture(logger, release, destination, DRIFT_PLAN_ARGS)
+            .await
+            .context("terraform drift plan failed")?;
+
+        Ok(plan_changes(&output))
+    }
+
+    fn supports_drift(&self) -> bool {
+        true
+    }
+}
+
+/// Drift checks run beside releases, so they neither take the state lock
+/// nor propose changes: a refresh-only plan reports just what changed
+/// outside of terraform.
+const DRIFT_PLAN_ARGS: &[&str] = &["plan", "-refresh-only", "-lock=false"];
+
+/// Summarise a plan of an already applied release. A clean plan means the
+/// infrastructure still matches; anything else is drift. Keeps the resource
+/// headers and the `Plan:` totals, not the full attribute diff.
+fn plan_changes(output: &str) -> Option<String> {
+    let changed_outside = output.contains("Objects have changed outside of Terraform");
+    let no_changes = output.lines().any(|l| l.trim_start().starts_with("No changes."));
+    if no_changes && !changed_outside {
+        return None;
+    }
+
+    let summary: Vec<&str> = output
+        .lines()
+        .map(str::trim)
+        .filter(|l| {
+            l.starts_with("# ") || l.starts_with("Plan:") || l.starts_with("Changes to Outputs")
+        })
+        .collect();
+
+    if summary.is_empty() {
+        return Some("terraform plan reported changes".into());
+    }
+
+    Some(summary.join("\n"))
 }
 
 enum Mode {
@@ -863,4 +1360,52 @@ mod tests {
             None => unsafe { std::env::remove_var("TERRAFORM_EXE") },
         }
     }
+
+    #[test]
+    fn drift_plan_is_read_only() {
+        assert!(DRIFT_PLAN_ARGS.contains(&"-lock=false"));
+        assert!(DRIFT_PLAN_ARGS.contains(&"-refresh-only"));
+    }
+
+    #[test]
+    fn clean_plan_is_not_drift() {
+        let output = "aws_s3_bucket.site: Refreshing state... [id=site]\n\n\
+No changes. Your infrastructure matches the configuration.\n";
+        assert_eq!(plan_changes(output), None);
+    }
+
+    #[test]
+    fn plan_with_changes_is_summarised() {
+        let output = "\
+Terraform will perform the following actions:
+
+  # aws_s3_bucket.site will be updated in-place
+  ~ resource \"aws_s3_bucket\" \"site\" {
+      ~ tags = {
+          - \"owner\" = \"someone\" -> null
+        }
+    }
+
+Plan: 0 to add, 1 to change, 0 to destroy.
+";
+        assert_eq!(
+            plan_changes(output).as_deref(),
+            Some("# aws_s3_bucket.site will be updated in-place\nPlan: 0 to add, 1 to change, 0 to destroy.")
+        );
+    }
+
+    #[test]
+    fn changes_outside_terraform_are_drift() {
+        let output = "\
+Note: Objects have changed outside of Terraform
+
+  # aws_instance.web has been changed
+
+No changes. Your infrastructure still matches the configuration.
+";
+        assert_eq!(
+            plan_changes(output).as_deref(),
+            Some("# aws_instance.web has been changed")
+        );
+    }
 }
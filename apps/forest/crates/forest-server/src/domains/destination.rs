@@ -2,6 +2,7 @@ use std::collections::HashMap;
 
 use anyhow::bail;
 use forest_event_store::{Aggregate, AggregateRoot, EventData, IntoStreamCategory, StreamCategory};
+use forest_models::QueuePolicy;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -11,6 +12,7 @@ use uuid::Uuid;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type")]
+#[allow(clippy::large_enum_variant)]
 pub enum DestinationEvent {
     Created {
         destination_id: Uuid,
@@ -22,10 +24,20 @@ pub enum DestinationEvent {
         type_organisation: String,
         type_name: String,
         type_version: u32,
+        #[serde(default)]
+        runner_labels: HashMap<String, String>,
+        #[serde(default)]
+        queue_policy: QueuePolicy,
     },
     MetadataUpdated {
         metadata: HashMap<String, String>,
     },
+    RunnerLabelsUpdated {
+        runner_labels: HashMap<String, String>,
+    },
+    QueuePolicyUpdated {
+        queue_policy: QueuePolicy,
+    },
     Deleted,
 }
 
@@ -34,6 +46,8 @@ impl EventData for DestinationEvent {
         match self {
             DestinationEvent::Created { .. } => "destination.created",
             DestinationEvent::MetadataUpdated { .. } => "destination.metadata_updated",
+            DestinationEvent::RunnerLabelsUpdated { .. } => "destination.runner_labels_updated",
+            DestinationEvent::QueuePolicyUpdated { .. } => "destination.queue_policy_updated",
             DestinationEvent::Deleted => "destination.deleted",
         }
     }
@@ -62,6 +76,8 @@ pub struct DestinationAggregate {
     pub type_organisation: String,
     pub type_name: String,
     pub type_version: u32,
+    pub runner_labels: HashMap<String, String>,
+    pub queue_policy: QueuePolicy,
 }
 
 impl Default for DestinationAggregate {
@@ -77,6 +93,8 @@ impl Default for DestinationAggregate {
             type_organisation: String::new(),
             type_name: String::new(),
             type_version: 0,
+            runner_labels: HashMap::new(),
+            queue_policy: QueuePolicy::Wait,
         }
     }
 }
@@ -100,6 +118,8 @@ impl Aggregate for DestinationAggregate {
                 type_organisation,
                 type_name,
                 type_version,
+                runner_labels,
+                queue_policy,
             } => {
                 self.status = DestinationStatus::Active;
                 self.destination_id = Some(*destination_id);
@@ -111,10 +131,18 @@ impl Aggregate for DestinationAggregate {
                 self.type_organisation.clone_from(type_organisation);
                 self.type_name.clone_from(type_name);
                 self.type_version = *type_version;
+                self.runner_labels.clone_from(runner_labels);
+                self.queue_policy = *queue_policy;
             }
             DestinationEvent::MetadataUpdated { metadata } => {
                 self.metadata.clone_from(metadata);
             }
+            DestinationEvent::RunnerLabelsUpdated { runner_labels } => {
+                self.runner_labels.clone_from(runner_labels);
+            }
+            DestinationEvent::QueuePolicyUpdated { queue_policy } => {
+                self.queue_policy = *queue_policy;
+            }
             DestinationEvent::Deleted => {
                 self.status = DestinationStatus::Deleted;
             }
@@ -135,6 +163,8 @@ pub struct CreateDestinationParams {
     pub type_organisation: String,
     pub type_name: String,
     pub type_version: u32,
+    pub runner_labels: HashMap<String, String>,
+    pub queue_policy: QueuePolicy,
 }
 
 impl DestinationAggregate {
@@ -172,6 +202,8 @@ impl DestinationAggregate {
             type_organisation: params.type_organisation,
             type_name: params.type_name,
             type_version: params.type_version,
+            runner_labels: params.runner_labels,
+            queue_policy: params.queue_policy,
         });
 
         Ok(destination_id)
@@ -196,6 +228,44 @@ impl DestinationAggregate {
         Ok(())
     }
 
+    pub fn update_runner_labels(
+        root: &mut AggregateRoot<Self>,
+        runner_labels: HashMap<String, String>,
+    ) -> anyhow::Result<()> {
+        match root.state.status {
+            DestinationStatus::NonExistent => {
+                bail!("destination does not exist");
+            }
+            DestinationStatus::Deleted => {
+                bail!("destination has been deleted");
+            }
+            DestinationStatus::Active => {}
+        }
+
+        root.record(DestinationEvent::RunnerLabelsUpdated { runner_labels });
+
+        Ok(())
+    }
+
+    pub fn update_queue_policy(
+        root: &mut AggregateRoot<Self>,
+        queue_policy: QueuePolicy,
+    ) -> anyhow::Result<()> {
+        match root.state.status {
+            DestinationStatus::NonExistent => {
+                bail!("destination does not exist");
+            }
+            DestinationStatus::Deleted => {
+                bail!("destination has been deleted");
+            }
+            DestinationStatus::Active => {}
+        }
+
+        root.record(DestinationEvent::QueuePolicyUpdated { queue_policy });
+
+        Ok(())
+    }
+
     pub fn delete(root: &mut AggregateRoot<Self>) -> anyhow::Result<()> {
         match root.state.status {
             DestinationStatus::NonExistent => {
@@ -241,6 +311,8 @@ mod tests {
             type_organisation: "forest".into(),
             type_name: "kubernetes".into(),
             type_version: 1,
+            runner_labels: HashMap::new(),
+            queue_policy: QueuePolicy::Wait,
         }
     }
 
@@ -343,6 +415,91 @@ mod tests {
         assert_eq!(root.pending_count(), 3); // create + 2 updates
     }
 
+    // ----------------------------------------------------------
+    // Update runner labels
+    // ----------------------------------------------------------
+
+    #[test]
+    fn update_runner_labels_replaces_labels() {
+        let mut root = new_root();
+        DestinationAggregate::create(&mut root, default_params()).unwrap();
+
+        let labels: HashMap<String, String> = [("gpu".into(), "true".into())].into();
+        DestinationAggregate::update_runner_labels(&mut root, labels.clone()).unwrap();
+
+        assert_eq!(root.state.runner_labels, labels);
+        assert_eq!(root.state.metadata.get("cluster").unwrap(), "us-east-1");
+        assert_eq!(root.pending_count(), 2);
+    }
+
+    #[test]
+    fn update_runner_labels_rejects_deleted() {
+        let mut root = new_root();
+        DestinationAggregate::create(&mut root, default_params()).unwrap();
+        DestinationAggregate::delete(&mut root).unwrap();
+
+        let err = DestinationAggregate::update_runner_labels(&mut root, HashMap::new());
+        assert!(err.is_err());
+        assert!(err.unwrap_err().to_string().contains("deleted"));
+    }
+
+    #[test]
+    fn created_event_without_runner_labels_deserializes() {
+        let json = serde_json::json!({
+            "type": "Created",
+            "destination_id": Uuid::now_v7(),
+            "organisation": "acme",
+            "name": "prod-k8s",
+            "environment": "production",
+            "environment_id": Uuid::now_v7(),
+            "metadata": {},
+            "type_organisation": "forest",
+            "type_name": "kubernetes",
+            "type_version": 1,
+        });
+
+        let event: DestinationEvent = serde_json::from_value(json).unwrap();
+        let DestinationEvent::Created { runner_labels, queue_policy, .. } = event else {
+            panic!("expected Created");
+        };
+        assert!(runner_labels.is_empty());
+        assert_eq!(queue_policy, QueuePolicy::Wait);
+    }
+
+    // ----------------------------------------------------------
+    // Update queue policy
+    // ----------------------------------------------------------
+
+    #[test]
+    fn update_queue_policy_sets_policy() {
+        let mut root = new_root();
+        DestinationAggregate::create(&mut root, default_params()).unwrap();
+        assert_eq!(root.state.queue_policy, QueuePolicy::Wait);
+
+        DestinationAggregate::update_queue_policy(&mut root, QueuePolicy::Supersede).unwrap();
+
+        assert_eq!(root.state.queue_policy, QueuePolicy::Supersede);
+        assert_eq!(root.pending_count(), 2);
+    }
+
+    #[test]
+    fn update_queue_policy_rejects_non_existent() {
+        let mut root = new_root();
+
+        let err = DestinationAggregate::update_queue_policy(&mut root, QueuePolicy::Supersede);
+        assert!(err.is_err());
+        assert!(err.unwrap_err().to_string().contains("does not exist"));
+    }
+
+    #[test]
+    fn queue_policy_serializes_lowercase() {
+        let json = serde_json::to_value(DestinationEvent::QueuePolicyUpdated {
+            queue_policy: QueuePolicy::Supersede,
+        })
+        .unwrap();
+        assert_eq!(json["queue_policy"], "supersede");
+    }
+
     // ----------------------------------------------------------
     // Delete
     // ----------------------------------------------------------
@@ -472,10 +629,18 @@ mod tests {
                 type_organisation: "forest".into(),
                 type_name: "kubernetes".into(),
                 type_version: 1,
+                runner_labels: [("gpu".into(), "true".into())].into(),
+                queue_policy: QueuePolicy::Supersede,
             },
             DestinationEvent::MetadataUpdated {
                 metadata: [("new".into(), "meta".into())].into(),
             },
+            DestinationEvent::RunnerLabelsUpdated {
+                runner_labels: [("zone".into(), "eu".into())].into(),
+            },
+            DestinationEvent::QueuePolicyUpdated {
+                queue_policy: QueuePolicy::Supersede,
+            },
             DestinationEvent::Deleted,
         ];
 
@@ -694,6 +859,8 @@ mod tests {
                 type_organisation: String::new(),
                 type_name: String::new(),
                 type_version: 0,
+                runner_labels: HashMap::new(),
+                queue_policy: QueuePolicy::Wait,
             }.event_type(),
             "destination.created"
         );
@@ -701,6 +868,14 @@ mod tests {
             DestinationEvent::MetadataUpdated { metadata: HashMap::new() }.event_type(),
             "destination.metadata_updated"
         );
+        assert_eq!(
+            DestinationEvent::RunnerLabelsUpdated { runner_labels: HashMap::new() }.event_type(),
+            "destination.runner_labels_updated"
+        );
+        assert_eq!(
+            DestinationEvent::QueuePolicyUpdated { queue_policy: QueuePolicy::Wait }.event_type(),
+            "destination.queue_policy_updated"
+        );
         assert_eq!(DestinationEvent::Deleted.event_type(), "destination.deleted");
     }
 
@@ -722,6 +897,8 @@ mod tests {
             type_organisation: "forest".into(),
             type_name: "flux".into(),
             type_version: 2,
+            runner_labels: [("gpu".into(), "true".into())].into(),
+            queue_policy: QueuePolicy::Supersede,
         };
 
         let json = serde_json::to_value(&event).unwrap();
@@ -738,6 +915,8 @@ mod tests {
                 type_organisation,
                 type_name,
                 type_version,
+                runner_labels,
+                queue_policy,
             } => {
                 assert_eq!(destination_id, dest_id);
                 assert_eq!(organisation, "myorg");
@@ -750,6 +929,8 @@ mod tests {
                 assert_eq!(type_organisation, "forest");
                 assert_eq!(type_name, "flux");
                 assert_eq!(type_version, 2);
+                assert_eq!(runner_labels.get("gpu").unwrap(), "true");
+                assert_eq!(queue_policy, QueuePolicy::Supersede);
             }
             _ => panic!("wrong variant after roundtrip"),
         }
@@ -803,6 +984,8 @@ mod tests {
             type_organisation: "f".into(),
             type_name: "k".into(),
             type_version: 1,
+            runner_labels: HashMap::new(),
+            queue_policy: QueuePolicy::Wait,
         };
 
         agg.apply(&event);
@@ -0,0 +1,209 @@
+use std::time::Duration;
+
+use anyhow::Context;
+use notmad::{Component, ComponentInfo, MadError};
+use tokio_util::sync::CancellationToken;
+
+use crate::{
+    State,
+    destination_services::{DestinationServices, DestinationServicesState},
+    destinations::logger::DestinationLogger,
+    services::{
+        destination_registry::{DestinationRegistry, DestinationRegistryState},
+        drift::{DriftRegistry, DriftRegistryState, DriftStatus, DriftTransition},
+        notification_registry::{NotificationRegistry, NotificationRegistryState, ReleaseContext},
+        release_registry::{ReleaseItem, ReleaseRegistry, ReleaseRegistryState},
+        secrets::{self, SecretRegistry, SecretRegistryState},
+    },
+};
+
+/// Periodically checks that each destination still matches the release
+/// deployed to it, and notifies when it stops (or starts again) matching.
+/// Checks run in this process, never on remote runners.
+pub struct DriftDetector {
+    drift_registry: DriftRegistry,
+    destination_registry: DestinationRegistry,
+    destinations: DestinationServices,
+    notification_registry: NotificationRegistry,
+    release_registry: ReleaseRegistry,
+    secrets: SecretRegistry,
+    interval: Duration,
+}
+
+impl DriftDetector {
+    pub fn new(state: &State, interval: Duration) -> Self {
+        Self {
+            drift_registry: state.drift_registry(),
+            destination_registry: state.destination_registry(),
+            destinations: state.destination_services(),
+            notification_registry: state.notification_registry(),
+            release_registry: state.release_registry(),
+            secrets: state.secret_registry(),
+            interval,
+        }
+    }
+
+    async fn detect(&self) -> anyhow::Result<()> {
+        for release in self.drift_registry.due(self.interval).await? {
+            if !self.drift_registry.claim(&release, self.interval).await? {
+                continue;
+            }
+
+            if let Err(e) = self.check(&release).await {
+                tracing::warn!(
+                    release_id = %release.id,
+                    destination_id = %release.destination_id,
+                    "drift check failed: {e:#}"
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn check(&self, release: &ReleaseItem) -> anyhow::Result<()> {
+        let Some(destination) = self
+            .destination_registry
+            .get(&release.destination_id)
+            .await?
+        else {
+            return Ok(());
+        };
+
+        // Checks run in-process, so destinations pinned to labelled runners
+        // are left alone, as are types without a check or only implemented
+        // on remote runners. They stay claimed but unrecorded so they show
+        // no drift status.
+        if !destination.runner_labels.is_empty() {
+            return Ok(());
+        }
+        let Some(dest_svc) = self.destinations.get_destination(
+            &destination.destination_type.organisation,
+            &destination.destination_type.name,
+            destination.destination_type.version,
+        ) else {
+            return Ok(());
+        };
+        if !dest_svc.supports_drift() {
+            return Ok(());
+        }
+
+        let secrets = self
+            .secrets
+            .resolve(&destination.organisation, &release.project, &destination.environment)
+            .await
+            .context("resolve secrets")?;
+        let logger = DestinationLogger::detached();
+        logger.redact(secrets.redactions());
+
+        let outcome = match secrets.resolve_metadata(&destination.metadata) {
+            Ok(metadata) => {
+                let mut destination = destination.clone();
+                destination.metadata = metadata;
+                dest_svc.detect_drift(&logger, release, &destination).await
+            }
+            Err(e) => Err(e),
+        };
+
+        let (status, detail) = match outcome {
+            Ok(None) => (DriftStatus::InSync, None),
+            Ok(Some(changes)) => (DriftStatus::Drifted, Some(changes)),
+            Err(e) => (DriftStatus::Error, Some(format!("{e:#}"))),
+        };
+        let detail = detail.map(|d| secrets::redact(&d, &secrets.redactions()));
+
+        let previous = self
+            .drift_registry
+            .record(release, status, detail.as_deref())
+            .await?;
+
+        let Some(transition) = DriftTransition::between(previous, status) else {
+            return Ok(());
+        };
+
+        let organisation = &destination.organisation;
+        let project = &release.project;
+        let (notification_type, title, body) = match transition {
+            DriftTransition::Detected => (
+                "DRIFT_DETECTED",
+                format!("Drift detected: {organisation}/{project}"),
+                format!(
+                    "{} no longer matches release {}:\n{}",
+                    destination.name,
+                    release.id,
+                    detail.as_deref().unwrap_or_default()
+                ),
+            ),
+            DriftTransition::Resolved => (
+                "DRIFT_RESOLVED",
+                format!("Drift resolved: {organisation}/{project}"),
+                format!(
+                    "{} matches release {} again",
+                    destination.name, release.id
+                ),
+            ),
+        };
+
+        let ann_ctx = self
+            .release_registry
+            .get_annotation_context(&release.artifact)
+            .await
+            .ok();
+
+        let release_context = ReleaseContext {
+            slug: ann_ctx.as_ref().map(|a| a.slug.clone()),
+            artifact_id: Some(release.artifact.to_string()),
+            release_intent_id: Some(release.release_intent_id.to_string()),
+            destination: Some(destination.name.clone()),
+            environment: Some(destination.environment.clone()),
+            version: ann_ctx
+                .as_ref()
+                .and_then(|a| a.reference.version.clone()),
+            error_message: detail,
+            ..Default::default()
+        };
+
+        if let Err(e) = self
+            .notification_registry
+            .create_notification(
+                notification_type,
+                &title,
+                &body,
+                organisation,
+                project,
+                &release_context,
+            )
+            .await
+        {
+            tracing::warn!("failed to create {} notification: {e:#}", notification_type);
+        }
+
+        Ok(())
+    }
+}
+
+impl Component for DriftDetector {
+    fn info(&self) -> ComponentInfo {
+        "forest-server/drift-detector".into()
+    }
+
+    async fn run(&self, cancellation_token: CancellationToken) -> Result<(), MadError> {
+        // Wake often enough to pick up fresh deploys soon after they land;
+        // `due` holds back everything checked within the interval.
+        let mut tick = tokio::time::interval(self.interval.min(Duration::from_secs(60)));
+        tick.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+        loop {
+            tokio::select! {
+                _ = cancellation_token.cancelled() => break,
+                _ = tick.tick() => {
+                    if let Err(e) = self.detect().await {
+                        tracing::error!("drift detector error: {e:#}");
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -12,10 +12,14 @@ use forest_grpc_interface::{
     notification_service_server::NotificationServiceServer,
     organisation_service_server::OrganisationServiceServer,
     registry_service_server::RegistryServiceServer,
+    release_history_service_server::ReleaseHistoryServiceServer,
     release_pipeline_service_server::ReleasePipelineServiceServer,
+    release_schedule_service_server::ReleaseScheduleServiceServer,
     release_service_server::ReleaseServiceServer,
     runner_service_server::RunnerServiceServer,
     release_health_service_server::ReleaseHealthServiceServer,
+    retention_service_server::RetentionServiceServer,
+    secret_service_server::SecretServiceServer,
     status_service_server::StatusServiceServer,
     users_service_server::UsersServiceServer,
 };
@@ -51,6 +55,10 @@ mod registry;
 mod release;
 pub mod runner;
 mod release_health;
+mod release_history;
+mod release_schedules;
+mod retention;
+mod secrets;
 mod status;
 mod users;
 
@@ -138,6 +146,22 @@ impl GrpcServer {
                     state: self.state.clone(),
                 },
             ))
+            .add_service(SecretServiceServer::new(secrets::SecretsServer {
+                state: self.state.clone(),
+            }))
+            .add_service(ReleaseHistoryServiceServer::new(
+                release_history::ReleaseHistoryServer {
+                    state: self.state.clone(),
+                },
+            ))
+            .add_service(RetentionServiceServer::new(retention::RetentionServer {
+                state: self.state.clone(),
+            }))
+            .add_service(ReleaseScheduleServiceServer::new(
+                release_schedules::ReleaseSchedulesServer {
+                    state: self.state.clone(),
+                },
+            ))
             .serve_with_shutdown(
                 self.host,
                 async move { cancellation_token.cancelled().await },
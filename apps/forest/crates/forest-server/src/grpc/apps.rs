@@ -4,7 +4,7 @@ use uuid::Uuid;
 use crate::{
     actor::Actor,
     grpc::{artifacts::GrpcErrorExt, authorize},
-    services::app_aggregate::AppAggregateServiceState,
+    services::{app_aggregate::AppAggregateServiceState, scim::SCIM_PERMISSION},
     state::State,
 };
 
@@ -12,6 +12,16 @@ pub struct AppsServer {
     pub state: State,
 }
 
+/// Apps with the `scim` permission can add and remove organisation
+/// members, so only admins may create them or mint their tokens.
+fn required_role(permissions: &[String]) -> authorize::OrgRole {
+    if permissions.iter().any(|p| p == SCIM_PERMISSION) {
+        authorize::OrgRole::Admin
+    } else {
+        authorize::OrgRole::Member
+    }
+}
+
 fn require_user(actor: &Actor) -> Result<Uuid, tonic::Status> {
     match actor {
         Actor::User { user_id } => Ok(*user_id),
@@ -40,7 +50,7 @@ impl AppService for AppsServer {
             &self.state.db,
             &actor,
             org_id,
-            authorize::OrgRole::Member,
+            required_role(&req.permissions),
         )
         .await?;
 
@@ -236,20 +246,24 @@ impl AppService for AppsServer {
             .parse()
             .map_err(|_| tonic::Status::invalid_argument("invalid app_id"))?;
 
-        let org_id = sqlx::query_scalar!("SELECT organisation_id FROM apps WHERE id = $1", app_id)
-            .fetch_optional(&self.state.db)
-            .await
-            .map_err(|e| {
-                tracing::error!("authz: {e}");
-                tonic::Status::internal("lookup failed")
-            })?
-            .ok_or_else(|| tonic::Status::not_found("app not found"))?;
+        let app = sqlx::query!(
+            "SELECT organisation_id, permissions FROM apps WHERE id = $1",
+            app_id
+        )
+        .fetch_optional(&self.state.db)
+        .await
+        .map_err(|e| {
+            tracing::error!("authz: {e}");
+            tonic::Status::internal("lookup failed")
+        })?
+        .ok_or_else(|| tonic::Status::not_found("app not found"))?;
+        let permissions: Vec<String> = serde_json::from_value(app.permissions).unwrap_or_default();
 
         let _authz = authorize::require_org_access_by_id(
             &self.state.db,
             &actor,
-            org_id,
-            authorize::OrgRole::Member,
+            app.organisation_id,
+            required_role(&permissions),
         )
         .await?;
 
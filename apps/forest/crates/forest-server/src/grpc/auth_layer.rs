@@ -161,6 +161,10 @@ fn auth_mode(path: &str) -> AuthMode {
         "/forest.v1.UsersService/Login",
         "/forest.v1.UsersService/RefreshToken",
         "/forest.v1.UsersService/VerifyLoginMfa",
+        // Passkey login replaces (or completes) Login; the ceremony and
+        // the assertion authenticate the caller.
+        "/forest.v1.UsersService/BeginPasskeyLogin",
+        "/forest.v1.UsersService/FinishPasskeyLogin",
         // Device authorization grant: the CLI has no token yet, that's
         // the whole point. Approve/Deny are NOT on this list — they
         // require service-account auth and are checked at the handler
@@ -177,16 +181,23 @@ fn auth_mode(path: &str) -> AuthMode {
         "/grpc.health.v1.Health/",
     ];
     let optional = [
+        // Shared runners register anonymously; org/project-scoped runners
+        // present a token, which the handler checks for org admin.
+        "/forest.v1.RunnerService/RegisterRunner",
+        // User-facing listing; the handler requires an authenticated actor.
+        "/forest.v1.RunnerService/ListRunners",
         // Registry discovery endpoints allow anonymous browsing of public components,
         // but must still recognise authenticated callers so they see private components
         // from their own orgs.
         "/forest.v1.RegistryService/SearchComponents",
         "/forest.v1.RegistryService/GetComponentDetail",
     ];
-    if none.iter().any(|p| path.starts_with(p)) {
-        AuthMode::None
-    } else if optional.iter().any(|p| path.starts_with(p)) {
+    // Optional is checked first so individual RPCs can opt back in to
+    // token handling under a prefix that is otherwise `None`.
+    if optional.iter().any(|p| path.starts_with(p)) {
         AuthMode::Optional
+    } else if none.iter().any(|p| path.starts_with(p)) {
+        AuthMode::None
     } else {
         AuthMode::Required
     }
@@ -131,6 +131,28 @@ impl AuthenticatedActor {
         }
     }
 
+    /// Delegation path for approval RPCs. Without a target the caller
+    /// acts as itself. Naming a target user is reserved for service
+    /// accounts (Forage relaying a Slack button click); the returned
+    /// actor is that user, so the handler's org and self-approval
+    /// checks run against them rather than the service account.
+    pub fn acting_for(self, on_behalf_of_user_id: Option<&str>) -> Result<Actor, tonic::Status> {
+        let Some(target) = on_behalf_of_user_id else {
+            return Ok(self.0);
+        };
+        match self.0 {
+            Actor::ServiceAccount { .. } => {
+                let user_id = target.parse::<Uuid>().map_err(|_| {
+                    tonic::Status::invalid_argument("invalid on_behalf_of_user_id")
+                })?;
+                Ok(Actor::User { user_id })
+            }
+            _ => Err(tonic::Status::permission_denied(
+                "only service accounts may act on behalf of another user",
+            )),
+        }
+    }
+
     /// User-self only, no service-account bypass. Use for endpoints
     /// where the service account has no legitimate need to act on
     /// behalf of a specific user (e.g. UnlinkOAuthProvider — Forage's
@@ -355,6 +377,46 @@ mod typed_gate_tests {
         assert_eq!(err.code(), tonic::Code::PermissionDenied);
     }
 
+    #[test]
+    fn acting_for_without_target_keeps_caller() {
+        let id = Uuid::now_v7();
+        match user(id).acting_for(None).unwrap() {
+            Actor::User { user_id } => assert_eq!(user_id, id),
+            _ => panic!("expected User variant"),
+        }
+        assert!(matches!(
+            service_account().acting_for(None).unwrap(),
+            Actor::ServiceAccount { .. }
+        ));
+    }
+
+    #[test]
+    fn acting_for_lets_service_account_delegate_to_user() {
+        let target = Uuid::now_v7();
+        match service_account()
+            .acting_for(Some(&target.to_string()))
+            .unwrap()
+        {
+            Actor::User { user_id } => assert_eq!(user_id, target),
+            _ => panic!("expected User variant"),
+        }
+    }
+
+    #[test]
+    fn acting_for_denies_user_and_app_delegation() {
+        let target = Uuid::now_v7().to_string();
+        let err = user(Uuid::now_v7()).acting_for(Some(&target)).unwrap_err();
+        assert_eq!(err.code(), tonic::Code::PermissionDenied);
+        let err = app().acting_for(Some(&target)).unwrap_err();
+        assert_eq!(err.code(), tonic::Code::PermissionDenied);
+    }
+
+    #[test]
+    fn acting_for_rejects_malformed_user_id() {
+        let err = service_account().acting_for(Some("not-a-uuid")).unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+    }
+
     #[test]
     fn into_actor_escape_hatch_returns_inner() {
         // For read-any-auth endpoints. Confirms the variant survives.
@@ -4,6 +4,9 @@ use tonic::Response;
 
 use crate::{
     destination_services::DestinationServicesState,
+    destinations::terraformv1::{
+        RollbackOutcome, TerraformLock, TerraformStateStore, TerraformStateStoreState,
+    },
     grpc::{artifacts::GrpcErrorExt, authorize},
     services::{
         destination_aggregate::DestinationAggregateServiceState,
@@ -17,6 +20,62 @@ pub struct DestinationServer {
     pub state: State,
 }
 
+fn terraform_lock_to_grpc(lock: TerraformLock) -> TerraformStateLock {
+    TerraformStateLock {
+        lock_id: lock.lock_id,
+        who: lock.who.unwrap_or_default(),
+        operation: lock.operation.unwrap_or_default(),
+        release_id: lock.release_id.map(|id| id.to_string()),
+        locked_at: lock
+            .locked_at
+            .map(|t| t.to_rfc3339())
+            .unwrap_or_else(|| "unknown".into()),
+    }
+}
+
+fn terraform_version_to_grpc(
+    v: crate::destinations::terraformv1::TerraformStateVersion,
+) -> TerraformStateVersion {
+    TerraformStateVersion {
+        version: v.version,
+        release_id: v.release_id.map(|id| id.to_string()),
+        rolled_back_from: v.rolled_back_from,
+        size_bytes: v.size_bytes,
+        created_at: v.created_at.to_rfc3339(),
+    }
+}
+
+impl DestinationServer {
+    /// Authorize the actor for the project's organisation and resolve the
+    /// terraform state id for the project in `environment`.
+    async fn terraform_state_id(
+        &self,
+        actor: &crate::actor::Actor,
+        organisation: &str,
+        project: &str,
+        environment: &str,
+        role: authorize::OrgRole,
+    ) -> Result<String, tonic::Status> {
+        authorize::require_org_access(&self.state.db, actor, organisation, role).await?;
+
+        if environment.is_empty() {
+            return Err(tonic::Status::invalid_argument("environment is required"));
+        }
+
+        let project_id = self
+            .state
+            .release_registry()
+            .get_project_id(organisation, project)
+            .await
+            .map_err(|_| tonic::Status::not_found("project not found"))?;
+
+        Ok(TerraformStateStore::state_id_for(
+            environment,
+            &project_id.to_string(),
+        ))
+    }
+}
+
 #[async_trait::async_trait]
 impl DestinationService for DestinationServer {
     async fn create_destination(
@@ -35,6 +94,8 @@ impl DestinationService for DestinationServer {
 
         tracing::debug!("create destination: {:?}", req);
 
+        let queue_policy = req.queue_policy().into();
+
         let dest_type: forest_models::DestinationType = req
             .r#type
             .context("destination type is required")
@@ -62,6 +123,8 @@ impl DestinationService for DestinationServer {
                 &dest_type.organisation,
                 &dest_type.name,
                 dest_type.version as u32,
+                req.runner_labels,
+                queue_policy,
             )
             .await
             .context("create destination")
@@ -96,6 +159,7 @@ impl DestinationService for DestinationServer {
         )
         .await?;
 
+        let queue_policy = req.queue_policy.map(|_| req.queue_policy().into());
         self.state
             .destination_aggregate_service()
             .update_metadata(&req.organisation, &req.name, req.metadata)
@@ -103,6 +167,24 @@ impl DestinationService for DestinationServer {
             .context("update destination")
             .to_internal_error()?;
 
+        if let Some(runner_labels) = req.runner_labels {
+            self.state
+                .destination_aggregate_service()
+                .update_runner_labels(&req.organisation, &req.name, runner_labels.labels)
+                .await
+                .context("update destination runner labels")
+                .to_internal_error()?;
+        }
+
+        if let Some(queue_policy) = queue_policy {
+            self.state
+                .destination_aggregate_service()
+                .update_queue_policy(&req.organisation, &req.name, queue_policy)
+                .await
+                .context("update destination queue policy")
+                .to_internal_error()?;
+        }
+
         self.state.event_bus().emit(EventPayload {
             organisation: req.organisation.clone(),
             project: String::new(),
@@ -192,4 +274,175 @@ impl DestinationService for DestinationServer {
             destinations: destinations.into_iter().map(|n| n.into()).collect(),
         }))
     }
+
+    async fn list_terraform_states(
+        &self,
+        request: tonic::Request<ListTerraformStatesRequest>,
+    ) -> std::result::Result<tonic::Response<ListTerraformStatesResponse>, tonic::Status> {
+        let actor = authorize::extract_actor(&request)?;
+        let req = request.into_inner();
+        authorize::require_org_access(
+            &self.state.db,
+            &actor,
+            &req.organisation,
+            authorize::OrgRole::Member,
+        )
+        .await?;
+
+        let project_id = self
+            .state
+            .release_registry()
+            .get_project_id(&req.organisation, &req.project)
+            .await
+            .map_err(|_| tonic::Status::not_found("project not found"))?;
+
+        let states = self
+            .state
+            .terraform_state_store()
+            .list_for_project(&project_id)
+            .await
+            .context("list terraform states")
+            .to_internal_error()?;
+
+        Ok(Response::new(ListTerraformStatesResponse {
+            states: states
+                .into_iter()
+                .map(|s| TerraformState {
+                    environment: s.environment,
+                    current_version: s.current_version,
+                    lock: s.lock.map(terraform_lock_to_grpc),
+                    updated_at: s.updated_at.to_rfc3339(),
+                })
+                .collect(),
+        }))
+    }
+
+    async fn list_terraform_state_versions(
+        &self,
+        request: tonic::Request<ListTerraformStateVersionsRequest>,
+    ) -> std::result::Result<tonic::Response<ListTerraformStateVersionsResponse>, tonic::Status>
+    {
+        let actor = authorize::extract_actor(&request)?;
+        let req = request.into_inner();
+        let state_id = self
+            .terraform_state_id(
+                &actor,
+                &req.organisation,
+                &req.project,
+                &req.environment,
+                authorize::OrgRole::Member,
+            )
+            .await?;
+
+        let versions = self
+            .state
+            .terraform_state_store()
+            .versions(&state_id)
+            .await
+            .context("list terraform state versions")
+            .to_internal_error()?;
+
+        Ok(Response::new(ListTerraformStateVersionsResponse {
+            versions: versions.into_iter().map(terraform_version_to_grpc).collect(),
+        }))
+    }
+
+    async fn force_unlock_terraform_state(
+        &self,
+        request: tonic::Request<ForceUnlockTerraformStateRequest>,
+    ) -> std::result::Result<tonic::Response<ForceUnlockTerraformStateResponse>, tonic::Status>
+    {
+        let actor = authorize::extract_actor(&request)?;
+        let req = request.into_inner();
+        let state_id = self
+            .terraform_state_id(
+                &actor,
+                &req.organisation,
+                &req.project,
+                &req.environment,
+                authorize::OrgRole::Admin,
+            )
+            .await?;
+
+        let released = self
+            .state
+            .terraform_state_store()
+            .force_unlock(&state_id)
+            .await
+            .context("force unlock terraform state")
+            .to_internal_error()?;
+
+        if let Some(lock) = &released {
+            self.state.event_bus().emit(EventPayload {
+                organisation: req.organisation.clone(),
+                project: req.project.clone(),
+                resource_type: "terraform_state",
+                action: "force_unlocked",
+                resource_id: req.environment.clone(),
+                metadata: [("lock_id".into(), lock.lock_id.clone())].into(),
+            }).await;
+        }
+
+        Ok(Response::new(ForceUnlockTerraformStateResponse {
+            released_lock: released.map(terraform_lock_to_grpc),
+        }))
+    }
+
+    async fn rollback_terraform_state(
+        &self,
+        request: tonic::Request<RollbackTerraformStateRequest>,
+    ) -> std::result::Result<tonic::Response<RollbackTerraformStateResponse>, tonic::Status> {
+        let actor = authorize::extract_actor(&request)?;
+        let req = request.into_inner();
+        let state_id = self
+            .terraform_state_id(
+                &actor,
+                &req.organisation,
+                &req.project,
+                &req.environment,
+                authorize::OrgRole::Admin,
+            )
+            .await?;
+
+        let outcome = self
+            .state
+            .terraform_state_store()
+            .rollback(&state_id, req.version)
+            .await
+            .context("rollback terraform state")
+            .to_internal_error()?;
+
+        let version = match outcome {
+            RollbackOutcome::RolledBack(version) => version,
+            RollbackOutcome::Locked(lock) => {
+                return Err(tonic::Status::failed_precondition(format!(
+                    "terraform state is locked by '{}', force unlock it first",
+                    lock.lock_id
+                )));
+            }
+            RollbackOutcome::UnknownVersion => {
+                return Err(tonic::Status::not_found(format!(
+                    "terraform state version {} not found",
+                    req.version
+                )));
+            }
+        };
+
+        self.state.event_bus().emit(EventPayload {
+            organisation: req.organisation.clone(),
+            project: req.project.clone(),
+            resource_type: "terraform_state",
+            action: "rolled_back",
+            resource_id: req.environment.clone(),
+            metadata: [
+                ("from_version".into(), req.version.to_string()),
+                ("version".into(), version.version.to_string()),
+            ]
+            .into(),
+        }).await;
+
+        Ok(Response::new(RollbackTerraformStateResponse {
+            version: Some(terraform_version_to_grpc(version)),
+        }))
+    }
 }
@@ -5,6 +5,7 @@ use tonic::Response;
 use crate::{
     grpc::{artifacts::GrpcErrorExt, authorize},
     services::{
+        environment_protection::EnvironmentProtection as Protection,
         environment_registry::{EnvironmentRecord, EnvironmentRegistryState},
         event_bus::{EventBusState, EventPayload},
     },
@@ -23,6 +24,70 @@ fn record_to_grpc(r: EnvironmentRecord) -> Environment {
         description: r.description,
         sort_order: r.sort_order,
         created_at: r.created_at.to_rfc3339(),
+        protection: Some(EnvironmentProtection {
+            protected: r.protection.protected,
+            required_prior_environment: r.protection.required_prior_environment,
+            approver_groups: r.protection.approver_groups,
+            allowed_branches: r.protection.allowed_branches,
+        }),
+    }
+}
+
+fn protection_from_grpc(p: EnvironmentProtection) -> Protection {
+    Protection {
+        protected: p.protected,
+        required_prior_environment: p.required_prior_environment,
+        approver_groups: p.approver_groups,
+        allowed_branches: p.allowed_branches,
+    }
+    .normalized()
+}
+
+impl EnvironmentsServer {
+    async fn validate_protection(
+        &self,
+        id: &uuid::Uuid,
+        organisation: &str,
+        protection: &Protection,
+    ) -> Result<(), tonic::Status> {
+        let registry = self.state.environment_registry();
+        let env = registry
+            .get_by_id(id)
+            .await
+            .context("get environment")
+            .to_internal_error()?
+            .ok_or_else(|| tonic::Status::not_found("environment not found"))?;
+
+        protection
+            .validate(&env.name)
+            .map_err(|e| tonic::Status::invalid_argument(format!("{e:#}")))?;
+
+        if let Some(prior) = &protection.required_prior_environment {
+            registry
+                .get_by_org_name(organisation, prior)
+                .await
+                .context("get prior environment")
+                .to_internal_error()?
+                .ok_or_else(|| {
+                    tonic::Status::invalid_argument(format!(
+                        "prior environment '{prior}' does not exist"
+                    ))
+                })?;
+
+            if registry
+                .creates_prior_cycle(organisation, &env.name, prior)
+                .await
+                .context("check prior environments")
+                .to_internal_error()?
+            {
+                return Err(tonic::Status::invalid_argument(format!(
+                    "requiring '{prior}' before '{}' would create a cycle",
+                    env.name
+                )));
+            }
+        }
+
+        Ok(())
     }
 }
 
@@ -166,18 +231,30 @@ impl EnvironmentService for EnvironmentsServer {
             tonic::Status::internal("lookup failed")
         })?
         .ok_or_else(|| tonic::Status::not_found("environment not found"))?;
-        let _authz = authorize::require_org_access(
-            &self.state.db,
-            &actor,
-            &org_name,
-            authorize::OrgRole::Member,
-        )
-        .await?;
+        // Protection rules bind every project in the organisation, so only
+        // admins may change them.
+        let role = if req.protection.is_some() {
+            authorize::OrgRole::Admin
+        } else {
+            authorize::OrgRole::Member
+        };
+        let _authz =
+            authorize::require_org_access(&self.state.db, &actor, &org_name, role).await?;
+
+        let protection = req.protection.map(protection_from_grpc);
+        if let Some(protection) = &protection {
+            self.validate_protection(&id, &org_name, protection).await?;
+        }
 
         let rec = self
             .state
             .environment_registry()
-            .update(&id, req.description.as_deref(), req.sort_order)
+            .update(
+                &id,
+                req.description.as_deref(),
+                req.sort_order,
+                protection.as_ref(),
+            )
             .await
             .context("update environment")
             .to_internal_error()?;
@@ -188,7 +265,11 @@ impl EnvironmentService for EnvironmentsServer {
             resource_type: "environment",
             action: "updated",
             resource_id: id.to_string(),
-            metadata: [("name".into(), rec.name.clone())].into(),
+            metadata: [
+                ("name".into(), rec.name.clone()),
+                ("protected".into(), rec.protection.protected.to_string()),
+            ]
+            .into(),
         }).await;
 
         Ok(Response::new(UpdateEnvironmentResponse {
@@ -201,6 +201,9 @@ fn notification_type_to_str(t: NotificationType) -> &'static str {
         NotificationType::ReleaseStarted => "RELEASE_STARTED",
         NotificationType::ReleaseSucceeded => "RELEASE_SUCCEEDED",
         NotificationType::ReleaseFailed => "RELEASE_FAILED",
+        NotificationType::DriftDetected => "DRIFT_DETECTED",
+        NotificationType::DriftResolved => "DRIFT_RESOLVED",
+        NotificationType::ScheduleFailed => "SCHEDULE_FAILED",
         NotificationType::Unspecified => "UNSPECIFIED",
     }
 }
@@ -211,6 +214,9 @@ fn notification_type_from_str(s: &str) -> NotificationType {
         "RELEASE_STARTED" => NotificationType::ReleaseStarted,
         "RELEASE_SUCCEEDED" => NotificationType::ReleaseSucceeded,
         "RELEASE_FAILED" => NotificationType::ReleaseFailed,
+        "DRIFT_DETECTED" => NotificationType::DriftDetected,
+        "DRIFT_RESOLVED" => NotificationType::DriftResolved,
+        "SCHEDULE_FAILED" => NotificationType::ScheduleFailed,
         _ => NotificationType::Unspecified,
     }
 }
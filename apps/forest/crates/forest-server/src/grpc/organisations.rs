@@ -2,7 +2,13 @@ use forest_grpc_interface::{organisation_service_server::OrganisationService, *}
 use uuid::Uuid;
 
 use super::error;
-use crate::{services::organisations::OrganisationServiceState, state::State, tokens::AppClaims};
+use crate::{
+    actor::Actor,
+    grpc::authorize,
+    services::{organisations::OrganisationServiceState, scim::ScimServiceState},
+    state::State,
+    tokens::AppClaims,
+};
 
 pub struct OrganisationsServer {
     pub state: State,
@@ -149,15 +155,26 @@ impl OrganisationService for OrganisationsServer {
         &self,
         request: tonic::Request<AddMemberRequest>,
     ) -> std::result::Result<tonic::Response<AddMemberResponse>, tonic::Status> {
-        let claims = request
-            .extensions()
-            .get::<AppClaims>()
-            .ok_or_else(|| tonic::Status::unauthenticated("missing auth context"))?;
-
-        let requester_id = claims
-            .user_id
-            .parse::<Uuid>()
-            .map_err(|_| tonic::Status::internal("invalid user_id in token"))?;
+        // Forage provisions SSO users just-in-time with its service
+        // account; that path bypasses the org-admin check below.
+        let service_account = crate::grpc::authorize::unauthenticated_actor(&request)
+            .require_authenticated()
+            .and_then(|actor| actor.require_service_account())
+            .is_ok();
+        let requester_id = if service_account {
+            None
+        } else {
+            let claims = request
+                .extensions()
+                .get::<AppClaims>()
+                .ok_or_else(|| tonic::Status::unauthenticated("missing auth context"))?;
+            Some(
+                claims
+                    .user_id
+                    .parse::<Uuid>()
+                    .map_err(|_| tonic::Status::internal("invalid user_id in token"))?,
+            )
+        };
 
         let req = request.into_inner();
 
@@ -171,12 +188,20 @@ impl OrganisationService for OrganisationsServer {
             .parse::<Uuid>()
             .map_err(|_| tonic::Status::invalid_argument("invalid user_id"))?;
 
-        let member = self
-            .state
-            .organisation_service()
-            .add_member(organisation_id, user_id, &req.role, requester_id)
-            .await
-            .map_err(error::to_status)?;
+        let service = self.state.organisation_service();
+        let member = match requester_id {
+            Some(requester_id) => {
+                service
+                    .add_member(organisation_id, user_id, &req.role, requester_id)
+                    .await
+            }
+            None => {
+                service
+                    .provision_member(organisation_id, user_id, &req.role)
+                    .await
+            }
+        }
+        .map_err(error::to_status)?;
 
         Ok(tonic::Response::new(AddMemberResponse {
             member: Some(member_to_grpc(member)),
@@ -260,7 +285,7 @@ impl OrganisationService for OrganisationsServer {
         &self,
         request: tonic::Request<ListMembersRequest>,
     ) -> std::result::Result<tonic::Response<ListMembersResponse>, tonic::Status> {
-        let actor = crate::grpc::authorize::extract_actor(&request)?;
+        let actor = authorize::extract_actor(&request)?;
         let req = request.into_inner();
 
         let organisation_id = req
@@ -268,11 +293,11 @@ impl OrganisationService for OrganisationsServer {
             .parse::<Uuid>()
             .map_err(|_| tonic::Status::invalid_argument("invalid organisation_id"))?;
 
-        crate::grpc::authorize::require_org_access_by_id(
+        authorize::require_org_access_by_id(
             &self.state.db,
             &actor,
             organisation_id,
-            crate::grpc::authorize::OrgRole::Member,
+            authorize::OrgRole::Member,
         )
         .await?;
 
@@ -303,6 +328,219 @@ impl OrganisationService for OrganisationsServer {
             total_count: result.total_count as i32,
         }))
     }
+
+    async fn set_sso_enforcement(
+        &self,
+        request: tonic::Request<SetSsoEnforcementRequest>,
+    ) -> std::result::Result<tonic::Response<SetSsoEnforcementResponse>, tonic::Status> {
+        let actor = authorize::extract_actor(&request)?;
+        let req = request.into_inner();
+
+        let organisation_id = req
+            .organisation_id
+            .parse::<Uuid>()
+            .map_err(|_| tonic::Status::invalid_argument("invalid organisation_id"))?;
+
+        authorize::require_org_access_by_id(
+            &self.state.db,
+            &actor,
+            organisation_id,
+            authorize::OrgRole::Admin,
+        )
+        .await?;
+
+        self.state
+            .organisation_service()
+            .set_sso_enforced(organisation_id, req.enforced)
+            .await
+            .map_err(error::to_status)?;
+
+        Ok(tonic::Response::new(SetSsoEnforcementResponse {}))
+    }
+
+    async fn set_scim_group_role(
+        &self,
+        request: tonic::Request<SetScimGroupRoleRequest>,
+    ) -> std::result::Result<tonic::Response<SetScimGroupRoleResponse>, tonic::Status> {
+        let actor = authorize::extract_actor(&request)?;
+        let requester_id = require_user(&actor)?;
+        let req = request.into_inner();
+
+        let organisation_id = req
+            .organisation_id
+            .parse::<Uuid>()
+            .map_err(|_| tonic::Status::invalid_argument("invalid organisation_id"))?;
+
+        authorize::require_org_access_by_id(
+            &self.state.db,
+            &actor,
+            organisation_id,
+            authorize::OrgRole::Admin,
+        )
+        .await?;
+
+        self.state
+            .scim_service()
+            .set_group_role(organisation_id, &req.group_name, &req.role, requester_id)
+            .await
+            .map_err(error::to_status)?;
+
+        Ok(tonic::Response::new(SetScimGroupRoleResponse {}))
+    }
+
+    async fn list_scim_group_roles(
+        &self,
+        request: tonic::Request<ListScimGroupRolesRequest>,
+    ) -> std::result::Result<tonic::Response<ListScimGroupRolesResponse>, tonic::Status> {
+        let actor = authorize::extract_actor(&request)?;
+        let req = request.into_inner();
+
+        let organisation_id = req
+            .organisation_id
+            .parse::<Uuid>()
+            .map_err(|_| tonic::Status::invalid_argument("invalid organisation_id"))?;
+
+        authorize::require_org_access_by_id(
+            &self.state.db,
+            &actor,
+            organisation_id,
+            authorize::OrgRole::Member,
+        )
+        .await?;
+
+        let rows = self
+            .state
+            .scim_service()
+            .list_group_roles(organisation_id)
+            .await
+            .map_err(error::to_status)?;
+
+        Ok(tonic::Response::new(ListScimGroupRolesResponse {
+            group_roles: rows
+                .into_iter()
+                .map(|row| ScimGroupRole {
+                    group_name: row.group_name,
+                    role: row.role,
+                    created_at: Some(prost_types::Timestamp {
+                        seconds: row.created_at.timestamp(),
+                        nanos: row.created_at.timestamp_subsec_nanos() as i32,
+                    }),
+                })
+                .collect(),
+        }))
+    }
+
+    async fn delete_scim_group_role(
+        &self,
+        request: tonic::Request<DeleteScimGroupRoleRequest>,
+    ) -> std::result::Result<tonic::Response<DeleteScimGroupRoleResponse>, tonic::Status> {
+        let actor = authorize::extract_actor(&request)?;
+        let requester_id = require_user(&actor)?;
+        let req = request.into_inner();
+
+        let organisation_id = req
+            .organisation_id
+            .parse::<Uuid>()
+            .map_err(|_| tonic::Status::invalid_argument("invalid organisation_id"))?;
+
+        authorize::require_org_access_by_id(
+            &self.state.db,
+            &actor,
+            organisation_id,
+            authorize::OrgRole::Admin,
+        )
+        .await?;
+
+        let deleted = self
+            .state
+            .scim_service()
+            .delete_group_role(organisation_id, &req.group_name, requester_id)
+            .await
+            .map_err(error::to_status)?;
+        if !deleted {
+            return Err(tonic::Status::not_found("no role mapping for this group"));
+        }
+
+        Ok(tonic::Response::new(DeleteScimGroupRoleResponse {}))
+    }
+
+    async fn list_my_invites(
+        &self,
+        request: tonic::Request<ListMyInvitesRequest>,
+    ) -> std::result::Result<tonic::Response<ListMyInvitesResponse>, tonic::Status> {
+        let actor = authorize::extract_actor(&request)?;
+        let user_id = require_invitee(&actor)?;
+
+        let rows = self
+            .state
+            .scim_service()
+            .list_invites(user_id)
+            .await
+            .map_err(error::to_status)?;
+
+        Ok(tonic::Response::new(ListMyInvitesResponse {
+            invites: rows
+                .into_iter()
+                .map(|row| OrganisationInvite {
+                    organisation_id: row.organisation_id.to_string(),
+                    organisation: row.organisation,
+                    user_name: row.user_name,
+                    created_at: Some(prost_types::Timestamp {
+                        seconds: row.created_at.timestamp(),
+                        nanos: row.created_at.timestamp_subsec_nanos() as i32,
+                    }),
+                })
+                .collect(),
+        }))
+    }
+
+    async fn accept_invite(
+        &self,
+        request: tonic::Request<AcceptInviteRequest>,
+    ) -> std::result::Result<tonic::Response<AcceptInviteResponse>, tonic::Status> {
+        let actor = authorize::extract_actor(&request)?;
+        let user_id = require_invitee(&actor)?;
+        let req = request.into_inner();
+
+        let organisation_id = req
+            .organisation_id
+            .parse::<Uuid>()
+            .map_err(|_| tonic::Status::invalid_argument("invalid organisation_id"))?;
+
+        let accepted = self
+            .state
+            .scim_service()
+            .accept_invite(organisation_id, user_id)
+            .await
+            .map_err(error::to_status)?;
+        if !accepted {
+            return Err(tonic::Status::not_found("no pending invite from this organisation"));
+        }
+
+        Ok(tonic::Response::new(AcceptInviteResponse {}))
+    }
+}
+
+/// Role mappings decide who administers the organisation, so only a
+/// signed-in admin may change them — not an app of the organisation.
+fn require_user(actor: &Actor) -> Result<Uuid, tonic::Status> {
+    match actor {
+        Actor::User { user_id } => Ok(*user_id),
+        _ => Err(tonic::Status::permission_denied(
+            "only users can manage SCIM role mappings",
+        )),
+    }
+}
+
+/// Invites are accepted by the account owner themselves; apps and service
+/// accounts can't join an organisation on a user's behalf.
+fn require_invitee(actor: &Actor) -> Result<Uuid, tonic::Status> {
+    match actor {
+        Actor::User { user_id } => Ok(*user_id),
+        _ => Err(tonic::Status::permission_denied(
+            "only users can accept organisation invites",
+        )),
+    }
 }
 
 fn member_to_grpc(member: crate::services::organisations::MemberInfo) -> OrganisationMember {
@@ -10,6 +10,7 @@ use crate::{
     actor::Actor,
     grpc::{artifacts::GrpcErrorExt, authorize},
     services::{
+        environment_protection::{self, EnvironmentProtection},
         event_bus::{EventBusState, EventPayload},
         policy::{
             self as policy_svc, PolicyConfig, PolicyRegistryState, PolicyType,
@@ -25,6 +26,104 @@ pub struct PoliciesServer {
     pub state: State,
 }
 
+/// What it takes to approve a release into an environment: the project's
+/// approval policy, the environment's own protection, or both.
+struct ApprovalRequirement {
+    policy_id: Option<uuid::Uuid>,
+    required_approvals: i32,
+    protection: EnvironmentProtection,
+}
+
+impl PoliciesServer {
+    async fn approval_requirement(
+        &self,
+        project_id: &uuid::Uuid,
+        target_environment: &str,
+    ) -> Result<ApprovalRequirement, tonic::Status> {
+        let policy = self
+            .state
+            .policy_registry()
+            .find_approval_policy_for_environment(project_id, target_environment)
+            .await
+            .context("find approval policy")
+            .to_internal_error()?;
+
+        let mut conn = self
+            .state
+            .db
+            .acquire()
+            .await
+            .context("acquire connection")
+            .to_internal_error()?;
+        let protection =
+            environment_protection::load_for_project(&mut conn, project_id, target_environment)
+                .await
+                .context("load environment protection")
+                .to_internal_error()?;
+
+        if policy.is_none() && !protection.protected {
+            tracing::warn!(
+                %target_environment,
+                "no approval policy found for environment"
+            );
+            return Err(tonic::Status::not_found(
+                "no approval policy found for environment",
+            ));
+        }
+
+        let policy_approvals = policy
+            .as_ref()
+            .and_then(|p| p.config.get("required_approvals"))
+            .and_then(|v| v.as_i64())
+            .unwrap_or(if policy.is_some() { 1 } else { 0 }) as i32;
+
+        Ok(ApprovalRequirement {
+            policy_id: policy.map(|p| p.id),
+            required_approvals: policy_approvals.max(protection.required_approvals()),
+            protection,
+        })
+    }
+
+    /// Refuse decisions from users outside the environment's approver groups
+    /// when there is no project policy their decision could count towards.
+    async fn require_approver(
+        &self,
+        organisation: &str,
+        user_id: &uuid::Uuid,
+        target_environment: &str,
+        requirement: &ApprovalRequirement,
+    ) -> Result<(), tonic::Status> {
+        if requirement.policy_id.is_some() {
+            return Ok(());
+        }
+
+        let mut conn = self
+            .state
+            .db
+            .acquire()
+            .await
+            .context("acquire connection")
+            .to_internal_error()?;
+        let is_approver = environment_protection::is_approver(
+            &mut conn,
+            organisation,
+            user_id,
+            &requirement.protection,
+        )
+        .await
+        .context("check approver")
+        .to_internal_error()?;
+
+        if !is_approver {
+            return Err(tonic::Status::permission_denied(format!(
+                "approving releases to '{target_environment}' requires membership of one of: {}",
+                requirement.protection.approver_groups.join(", ")
+            )));
+        }
+        Ok(())
+    }
+}
+
 fn record_to_grpc(r: policy_svc::PolicyRecord) -> Policy {
     let config = PolicyConfig::from_record(&r.policy_type, &r.config).ok();
 
@@ -76,7 +175,7 @@ fn record_to_grpc(r: policy_svc::PolicyRecord) -> Policy {
     }
 }
 
-fn eval_to_grpc(e: policy_svc::PolicyEvaluation) -> PolicyEvaluation {
+pub(crate) fn eval_to_grpc(e: policy_svc::PolicyEvaluation) -> PolicyEvaluation {
     let policy_type = match e.policy_type {
         PolicyType::SoakTime => 1,
         PolicyType::BranchRestriction => 2,
@@ -436,7 +535,9 @@ impl PolicyService for PoliciesServer {
         &self,
         request: tonic::Request<ExternalApproveReleaseRequest>,
     ) -> Result<Response<ExternalApproveReleaseResponse>, tonic::Status> {
-        let actor = authorize::extract_actor(&request)?;
+        let actor = authorize::unauthenticated_actor(&request)
+            .require_authenticated()?
+            .acting_for(request.get_ref().on_behalf_of_user_id.as_deref())?;
 
         let user_id = match &actor {
             Actor::User { user_id } => *user_id,
@@ -472,8 +573,13 @@ impl PolicyService for PoliciesServer {
             .context("invalid release_intent_id")
             .to_internal_error()?;
 
-        // Prevent self-approval unless force_bypass
-        if !req.force_bypass {
+        let requirement = self
+            .approval_requirement(&project_id, &req.target_environment)
+            .await?;
+
+        // Prevent self-approval unless force_bypass, which protected
+        // environments ignore.
+        if !requirement.protection.allows_self_approval(req.force_bypass) {
             let intent_actor = self
                 .state
                 .policy_registry()
@@ -494,26 +600,14 @@ impl PolicyService for PoliciesServer {
             }
         }
 
-        let policy = self
-            .state
-            .policy_registry()
-            .find_approval_policy_for_environment(&project_id, &req.target_environment)
-            .await
-            .context("find approval policy")
-            .to_internal_error()?
-            .ok_or_else(|| {
-                tracing::warn!(
-                    target_environment = %req.target_environment,
-                    "no approval policy found for environment"
-                );
-                tonic::Status::not_found("no approval policy found for environment")
-            })?;
-
-        let required_approvals = policy
-            .config
-            .get("required_approvals")
-            .and_then(|v| v.as_i64())
-            .unwrap_or(1) as i32;
+        let required_approvals = requirement.required_approvals;
+        self.require_approver(
+            &project.organisation,
+            &user_id,
+            &req.target_environment,
+            &requirement,
+        )
+        .await?;
 
         let user_profile = self
             .state
@@ -531,7 +625,7 @@ impl PolicyService for PoliciesServer {
             .policy_registry()
             .record_approval_decision(
                 &release_intent_id,
-                &policy.id,
+                requirement.policy_id.as_ref(),
                 &req.target_environment,
                 &user_id,
                 &username,
@@ -579,7 +673,9 @@ impl PolicyService for PoliciesServer {
         &self,
         request: tonic::Request<ExternalRejectReleaseRequest>,
     ) -> Result<Response<ExternalRejectReleaseResponse>, tonic::Status> {
-        let actor = authorize::extract_actor(&request)?;
+        let actor = authorize::unauthenticated_actor(&request)
+            .require_authenticated()?
+            .acting_for(request.get_ref().on_behalf_of_user_id.as_deref())?;
 
         let user_id = match &actor {
             Actor::User { user_id } => *user_id,
@@ -615,26 +711,17 @@ impl PolicyService for PoliciesServer {
             .context("invalid release_intent_id")
             .to_internal_error()?;
 
-        let policy = self
-            .state
-            .policy_registry()
-            .find_approval_policy_for_environment(&project_id, &req.target_environment)
-            .await
-            .context("find approval policy")
-            .to_internal_error()?
-            .ok_or_else(|| {
-                tracing::warn!(
-                    target_environment = %req.target_environment,
-                    "no approval policy found for environment"
-                );
-                tonic::Status::not_found("no approval policy found for environment")
-            })?;
-
-        let required_approvals = policy
-            .config
-            .get("required_approvals")
-            .and_then(|v| v.as_i64())
-            .unwrap_or(1) as i32;
+        let requirement = self
+            .approval_requirement(&project_id, &req.target_environment)
+            .await?;
+        let required_approvals = requirement.required_approvals;
+        self.require_approver(
+            &project.organisation,
+            &user_id,
+            &req.target_environment,
+            &requirement,
+        )
+        .await?;
 
         let user_profile = self
             .state
@@ -652,7 +739,7 @@ impl PolicyService for PoliciesServer {
             .policy_registry()
             .record_approval_decision(
                 &release_intent_id,
-                &policy.id,
+                requirement.policy_id.as_ref(),
                 &req.target_environment,
                 &user_id,
                 &username,
@@ -730,26 +817,10 @@ impl PolicyService for PoliciesServer {
             .context("invalid release_intent_id")
             .to_internal_error()?;
 
-        let policy = self
-            .state
-            .policy_registry()
-            .find_approval_policy_for_environment(&project_id, &req.target_environment)
-            .await
-            .context("find approval policy")
-            .to_internal_error()?
-            .ok_or_else(|| {
-                tracing::warn!(
-                    target_environment = %req.target_environment,
-                    "no approval policy found for environment"
-                );
-                tonic::Status::not_found("no approval policy found for environment")
-            })?;
-
-        let required_approvals = policy
-            .config
-            .get("required_approvals")
-            .and_then(|v| v.as_i64())
-            .unwrap_or(1) as i32;
+        let requirement = self
+            .approval_requirement(&project_id, &req.target_environment)
+            .await?;
+        let required_approvals = requirement.required_approvals;
 
         let state_info = self
             .state
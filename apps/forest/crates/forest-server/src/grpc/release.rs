@@ -29,6 +29,7 @@ use crate::{
         release_logs_registry::{LogChannel, ReleaseLogsRegistryState},
         release_pipeline::ReleasePipelineRegistryState,
         release_registry::{self, ReleaseAnnotation, ReleaseDestination, ReleaseRegistryState},
+        release_request::{self, ReleaseRequestError},
         users::UserServiceState,
     },
     state::State,
@@ -249,6 +250,32 @@ impl ReleaseService for ReleaseServer {
                     if blocked {
                         continue;
                     }
+                    if !trigger_match.use_pipeline {
+                        match release_request::check_prior_environments(
+                            &self.state.db,
+                            &project_id,
+                            &artifact_id,
+                            &trigger_match.target_environments,
+                        )
+                        .await
+                        {
+                            Ok(None) => {}
+                            Ok(Some(reason)) => {
+                                tracing::info!(
+                                    trigger = %trigger_match.trigger_name,
+                                    "trigger blocked: {reason}",
+                                );
+                                continue;
+                            }
+                            Err(e) => {
+                                tracing::warn!(
+                                    trigger = %trigger_match.trigger_name,
+                                    "failed to check environment protection: {e:#}"
+                                );
+                                continue;
+                            }
+                        }
+                    }
 
                     tracing::info!(
                         trigger = %trigger_match.trigger_name,
@@ -404,167 +431,26 @@ impl ReleaseService for ReleaseServer {
             ).await?;
         }
 
-        // Evaluate branch restriction policies before releasing
-        let ann_ctx_for_policy = self
-            .state
-            .release_registry()
-            .get_annotation_context(&artifact_id)
-            .await
-            .ok();
-        let branch_for_policy = ann_ctx_for_policy
-            .as_ref()
-            .and_then(|a| a.reference.commit_branch.clone());
-
-        // Collect all target environments to check policies against
-        let target_envs: Vec<String> = if !req.environments.is_empty() {
-            req.environments.clone()
-        } else {
-            // When releasing to specific destinations, resolve their environments
-            Vec::new()
-        };
-
-        if let Ok(project_id) = self
-            .state
-            .release_registry()
-            .get_project_id_from_artifact(&artifact_id)
-            .await
+        let created = match release_request::submit(
+            &self.state,
+            &actor,
+            release_org.as_deref(),
+            release_request::ReleaseRequest {
+                artifact_id,
+                destinations: req.destinations,
+                environments: req.environments,
+                force: req.force,
+                use_pipeline: req.use_pipeline,
+            },
+        )
+        .await
         {
-            for env in &target_envs {
-                let evaluations = self
-                    .state
-                    .policy_registry()
-                    .evaluate_for_environment(&project_id, env, branch_for_policy.as_deref(), None)
-                    .await
-                    .unwrap_or_default();
-
-                for eval in &evaluations {
-                    // Only enforce branch_restriction at request time.
-                    // soak_time is handled by the scheduler (deferred retry).
-                    if !eval.passed
-                        && eval.policy_type == PolicyType::BranchRestriction
-                    {
-                        return Err(tonic::Status::failed_precondition(format!(
-                            "blocked by policy '{}': {}",
-                            eval.policy_name, eval.reason
-                        )));
-                    }
-                }
+            Ok(created) => created,
+            Err(ReleaseRequestError::Blocked(reason)) => {
+                return Err(tonic::Status::failed_precondition(reason));
             }
-        }
-
-        let created = self
-            .state
-            .release_registry()
-            .release(
-                &artifact_id,
-                req.destinations,
-                req.environments,
-                &actor,
-                &self.state.release_event_store(),
-                req.force,
-                req.use_pipeline,
-                &self.state.release_pipeline_registry(),
-            )
-            .await
-            .context("release")
-            .to_internal_error()?;
-
-        let dest_count = created.releases.len();
-        let dest_names: Vec<String> = created
-            .releases
-            .iter()
-            .map(|r| r.destination.clone())
-            .collect();
-
-        // Fetch annotation context to enrich the started notification
-        let ann_ctx = self
-            .state
-            .release_registry()
-            .get_annotation_context(&artifact_id)
-            .await
-            .ok();
-
-        if let Err(e) = self
-            .state
-            .notification_registry()
-            .create_notification(
-                "RELEASE_STARTED",
-                &format!(
-                    "Release started: {}/{}",
-                    &created.organisation, &created.project
-                ),
-                &format!("Release staged to {} destination(s)", dest_count),
-                &created.organisation,
-                &created.project,
-                &NotifReleaseContext {
-                    slug: ann_ctx.as_ref().map(|a| a.slug.clone()),
-                    artifact_id: Some(artifact_id.to_string()),
-                    release_intent_id: Some(created.release_intent_id.to_string()),
-                    destination: if dest_names.len() == 1 {
-                        Some(dest_names[0].clone())
-                    } else {
-                        None
-                    },
-                    destination_count: dest_count as i32,
-                    source_username: ann_ctx.as_ref().and_then(|a| a.source.username.clone()),
-                    source_email: ann_ctx.as_ref().and_then(|a| a.source.email.clone()),
-                    source_user_id: match &actor {
-                        Actor::User { user_id } => Some(user_id.to_string()),
-                        _ => None,
-                    },
-                    source_type: ann_ctx
-                        .as_ref()
-                        .and_then(|a| a.source.source_type.clone()),
-                    run_url: ann_ctx.as_ref().and_then(|a| a.source.run_url.clone()),
-                    commit_sha: ann_ctx.as_ref().map(|a| a.reference.commit_sha.clone()),
-                    commit_branch: ann_ctx
-                        .as_ref()
-                        .and_then(|a| a.reference.commit_branch.clone()),
-                    commit_message: ann_ctx
-                        .as_ref()
-                        .and_then(|a| a.reference.commit_message.clone()),
-                    version: ann_ctx
-                        .as_ref()
-                        .and_then(|a| a.reference.version.clone()),
-                    repo_url: ann_ctx
-                        .as_ref()
-                        .and_then(|a| a.reference.repo_url.clone()),
-                    context_title: ann_ctx.as_ref().map(|a| a.context.title.clone()),
-                    context_description: ann_ctx
-                        .as_ref()
-                        .and_then(|a| a.context.description.clone()),
-                    context_web: ann_ctx.as_ref().and_then(|a| a.context.web.clone()),
-                    context_pr: ann_ctx.as_ref().and_then(|a| a.context.pr.clone()),
-                    ..Default::default()
-                },
-            )
-            .await
-        {
-            tracing::warn!("failed to create release started notification: {e:#}");
-        }
-
-        self.state.event_bus().emit(EventPayload {
-            organisation: created.organisation.clone(),
-            project: created.project.clone(),
-            resource_type: "release",
-            action: "created",
-            resource_id: created.release_intent_id.to_string(),
-            metadata: [
-                ("destinations".into(), dest_names.join(",")),
-            ].into(),
-        }).await;
-
-        // Signal the IntentCoordinator to evaluate this pipeline
-        if !created.activated_stages.is_empty() || req.use_pipeline {
-            let _ = self
-                .state
-                .nats
-                .publish(
-                    "forest.intent.evaluate",
-                    created.release_intent_id.to_string().into(),
-                )
-                .await;
-        }
+            Err(ReleaseRequestError::Internal(e)) => return Err(e).to_internal_error(),
+        };
 
         Ok(Response::new(ReleaseResponse {
             intents: created
@@ -647,6 +533,10 @@ impl ReleaseService for ReleaseServer {
             // Track last-seen stage statuses for pipeline stage updates
             let mut last_stage_statuses: std::collections::HashMap<String, String> =
                 std::collections::HashMap::new();
+            // Plan stages stay ACTIVE while awaiting approval, so approval
+            // transitions are tracked separately to still emit an update.
+            let mut last_approval_statuses: std::collections::HashMap<String, String> =
+                std::collections::HashMap::new();
 
             let mut fallback_interval =
                 tokio::time::interval(std::time::Duration::from_secs(2));
@@ -679,13 +569,27 @@ impl ReleaseService for ReleaseServer {
                         ) {
                             for (stage_id, state) in &stage_states {
                                 let status_str = format!("{:?}", state.status).to_uppercase();
+                                let approval_str = state
+                                    .approval_status
+                                    .map(|a| format!("{:?}", a).to_uppercase());
                                 let changed = last_stage_statuses
                                     .get(stage_id)
-                                    .is_none_or(|prev| *prev != status_str);
+                                    .is_none_or(|prev| *prev != status_str)
+                                    || last_approval_statuses.get(stage_id)
+                                        != approval_str.as_ref();
 
                                 if changed {
                                     last_stage_statuses
                                         .insert(stage_id.clone(), status_str.clone());
+                                    match &approval_str {
+                                        Some(a) => {
+                                            last_approval_statuses
+                                                .insert(stage_id.clone(), a.clone());
+                                        }
+                                        None => {
+                                            last_approval_statuses.remove(stage_id);
+                                        }
+                                    }
 
                                     let stage_type = stages.get(stage_id).map(|def| {
                                         match &def.config {
@@ -707,7 +611,7 @@ impl ReleaseService for ReleaseServer {
                                                     completed_at: state.completed_at.clone(),
                                                     wait_until: state.wait_until.clone(),
                                                     error_message: state.error_message.clone(),
-                                                    approval_status: state.approval_status.map(|a| format!("{:?}", a).to_uppercase()),
+                                                    approval_status: approval_str,
                                                 },
                                             ),
                                         ),
@@ -1147,6 +1051,12 @@ impl ReleaseService for ReleaseServer {
                     destination_id: r.destination_id.to_string(),
                     destination_name: r.destination_name,
                     environment: r.environment,
+                    queue_policy: r
+                        .queue_policy
+                        .parse::<forest_models::QueuePolicy>()
+                        .map(forest_grpc_interface::ReleaseQueuePolicy::from)
+                        .unwrap_or_default()
+                        .into(),
                     release_id: Some(r.release_id.to_string()),
                     artifact_id: Some(r.artifact_id.to_string()),
                     status: Some(r.status),
@@ -1219,6 +1129,9 @@ impl ReleaseService for ReleaseServer {
                         started_at: s.started_at.map(|t| t.to_rfc3339()),
                         completed_at: s.completed_at.map(|t| t.to_rfc3339()),
                         error_message: s.error_message,
+                        drift_status: s.drift_status,
+                        drift_detail: s.drift_detail,
+                        drift_checked_at: s.drift_checked_at.map(|t| t.to_rfc3339()),
                     })
                     .collect();
 
@@ -1244,7 +1157,9 @@ impl ReleaseService for ReleaseServer {
     ) -> Result<Response<ApprovePlanStageResponse>, tonic::Status> {
         use crate::services::release_pipeline::{ApprovalStatus, PipelineStages, StageConfig, StageStates};
 
-        let actor = authorize::extract_actor(&request)?;
+        let actor = authorize::unauthenticated_actor(&request)
+            .require_authenticated()?
+            .acting_for(request.get_ref().on_behalf_of_user_id.as_deref())?;
         let req = request.into_inner();
         let intent_id: Uuid = req.release_intent_id.parse()
             .context("invalid release_intent_id")
@@ -1351,7 +1266,9 @@ impl ReleaseService for ReleaseServer {
     ) -> Result<Response<RejectPlanStageResponse>, tonic::Status> {
         use crate::services::release_pipeline::{ApprovalStatus, PipelineStages, StageConfig, StageStates};
 
-        let actor = authorize::extract_actor(&request)?;
+        let actor = authorize::unauthenticated_actor(&request)
+            .require_authenticated()?
+            .acting_for(request.get_ref().on_behalf_of_user_id.as_deref())?;
         let req = request.into_inner();
         let intent_id: Uuid = req.release_intent_id.parse()
             .context("invalid release_intent_id")
@@ -1450,6 +1367,71 @@ impl ReleaseService for ReleaseServer {
         Ok(Response::new(RejectPlanStageResponse {}))
     }
 
+    async fn cancel_release(
+        &self,
+        request: tonic::Request<CancelReleaseRequest>,
+    ) -> Result<Response<CancelReleaseResponse>, tonic::Status> {
+        let actor = authorize::extract_actor(&request)?;
+        let req = request.into_inner();
+        let release_id: Uuid = req
+            .release_id
+            .parse()
+            .map_err(|_| tonic::Status::invalid_argument("invalid release_id"))?;
+
+        let release = sqlx::query!(
+            "SELECT rs.status, p.organisation, p.project
+             FROM release_states rs
+             JOIN projects p ON p.id = rs.project_id
+             WHERE rs.release_id = $1",
+            release_id,
+        )
+        .fetch_optional(&self.state.db)
+        .await
+        .context("resolve release organisation")
+        .to_internal_error()?
+        .ok_or_else(|| tonic::Status::not_found("release not found"))?;
+
+        authorize::require_org_access(
+            &self.state.db,
+            &actor,
+            &release.organisation,
+            authorize::OrgRole::Member,
+        )
+        .await?;
+
+        if !matches!(release.status.as_str(), "QUEUED" | "ASSIGNED" | "RUNNING") {
+            return Err(tonic::Status::failed_precondition(format!(
+                "release already finished ({})",
+                release.status
+            )));
+        }
+
+        let reason = req
+            .reason
+            .filter(|r| !r.is_empty())
+            .unwrap_or_else(|| "cancelled by user".into());
+
+        self.state
+            .release_event_store()
+            .cancel_release(release_id, &reason, &actor)
+            .await
+            .context("cancel release")
+            .to_internal_error()?;
+
+        self.state.event_bus().emit(EventPayload {
+            organisation: release.organisation,
+            project: release.project,
+            resource_type: "release",
+            action: "cancelled",
+            resource_id: release_id.to_string(),
+            metadata: [("reason".into(), reason)].into(),
+        }).await;
+
+        Ok(Response::new(CancelReleaseResponse {
+            status: "CANCELLED".into(),
+        }))
+    }
+
     async fn get_plan_output(
         &self,
         request: tonic::Request<GetPlanOutputRequest>,
@@ -1621,7 +1603,7 @@ fn stage_def_to_type_fields(
 ) -> (i32, Option<String>, Option<i64>, Option<bool>) {
     use crate::services::release_pipeline::StageConfig;
     match config {
-        StageConfig::Deploy { environment } => (
+        StageConfig::Deploy { environment, .. } => (
             forest_grpc_interface::PipelineRunStageType::Deploy as i32,
             Some(environment.clone()),
             None,
@@ -1642,6 +1624,29 @@ fn stage_def_to_type_fields(
     }
 }
 
+fn batches_to_proto(
+    state: Option<&crate::services::release_pipeline::StageState>,
+) -> Vec<forest_grpc_interface::PipelineStageBatch> {
+    state
+        .and_then(|s| s.batches.as_ref())
+        .map(|batches| {
+            batches
+                .iter()
+                .map(|b| forest_grpc_interface::PipelineStageBatch {
+                    destinations: b.destinations.clone(),
+                    status: stage_status_to_proto(&b.status) as i32,
+                    started_at: b.started_at.clone(),
+                    completed_at: b.completed_at.clone(),
+                    wait_until: b.wait_until.clone(),
+                    release_ids: b.release_ids.clone(),
+                    error_message: b.error_message.clone(),
+                    health_check: b.health_check,
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 fn intent_to_stage_states(
     intent: &crate::services::release_event_store::ReleaseIntentRow,
 ) -> Vec<forest_grpc_interface::PipelineStageState> {
@@ -1702,6 +1707,7 @@ fn intent_to_stage_states(
                 release_ids,
                 approval_status,
                 auto_approve,
+                batches: batches_to_proto(state),
             }
         })
         .collect()
@@ -1767,6 +1773,7 @@ fn pipeline_run_to_proto(
                 release_ids,
                 approval_status,
                 auto_approve,
+                batches: batches_to_proto(state),
             }
         })
         .collect();
@@ -0,0 +1,300 @@
+use std::collections::{BTreeMap, HashMap};
+
+use anyhow::Context;
+use forest_grpc_interface::{release_history_service_server::ReleaseHistoryService, *};
+use tonic::Response;
+use uuid::Uuid;
+
+use crate::{
+    grpc::{artifacts::GrpcErrorExt, authorize, policies::eval_to_grpc},
+    services::{
+        policy::PolicyRegistryState,
+        release_health,
+        release_history::{Attempt, Cursor, HistoryEntry, ReleaseFilter, ReleaseHistoryState},
+        release_logs_registry::{self, ReleaseLogsRegistryState},
+        release_registry::ReleaseRegistryState,
+    },
+    state::State,
+};
+
+const DEFAULT_PAGE_SIZE: i64 = 20;
+const MAX_PAGE_SIZE: i64 = 100;
+
+pub struct ReleaseHistoryServer {
+    pub state: State,
+}
+
+#[allow(clippy::result_large_err)]
+fn parse_time(field: &str, value: Option<String>) -> Result<Option<chrono::DateTime<chrono::Utc>>, tonic::Status> {
+    value
+        .filter(|v| !v.is_empty())
+        .map(|v| {
+            chrono::DateTime::parse_from_rfc3339(&v)
+                .map(|t| t.with_timezone(&chrono::Utc))
+                .map_err(|e| tonic::Status::invalid_argument(format!("{field}: {e}")))
+        })
+        .transpose()
+}
+
+fn non_empty(value: Option<String>) -> Option<String> {
+    value.filter(|v| !v.is_empty())
+}
+
+fn entry_to_grpc(e: HistoryEntry) -> ReleaseHistoryEntry {
+    ReleaseHistoryEntry {
+        release_intent_id: e.release_intent_id.to_string(),
+        slug: e.slug,
+        artifact_id: e.artifact_id.to_string(),
+        project: Some(e.project.into()),
+        context: Some(e.context.into()),
+        source: Some(e.source.into()),
+        r#ref: Some(e.reference.into()),
+        destinations: e
+            .destinations
+            .into_iter()
+            .map(|d| ReleaseDestinationStatus {
+                destination: d.destination,
+                environment: d.environment,
+                status: d.status,
+            })
+            .collect(),
+        created_at: e.created_at.to_rfc3339(),
+    }
+}
+
+fn attempt_to_grpc(a: &Attempt) -> ReleaseAttempt {
+    ReleaseAttempt {
+        release_id: a.release_id.to_string(),
+        release_intent_id: a.release_intent_id.to_string(),
+        stage_id: a.stage_id.clone(),
+        destination: a.destination.clone(),
+        environment: a.environment.clone(),
+        mode: a.mode.clone(),
+        status: a.status.clone(),
+        error_message: a.error_message.clone(),
+        queued_at: a.queued_at.to_rfc3339(),
+        assigned_at: a.assigned_at.map(|t| t.to_rfc3339()),
+        started_at: a.started_at.map(|t| t.to_rfc3339()),
+        completed_at: a.completed_at.map(|t| t.to_rfc3339()),
+        events: Vec::new(),
+        logs: Vec::new(),
+        health_status: None,
+        health_message: None,
+    }
+}
+
+#[tonic::async_trait]
+impl ReleaseHistoryService for ReleaseHistoryServer {
+    async fn list_releases(
+        &self,
+        request: tonic::Request<ListReleasesRequest>,
+    ) -> Result<Response<ListReleasesResponse>, tonic::Status> {
+        let actor = authorize::extract_actor(&request)?;
+        let req = request.into_inner();
+
+        authorize::require_org_access(
+            &self.state.db,
+            &actor,
+            &req.organisation,
+            authorize::OrgRole::Member,
+        )
+        .await?;
+
+        let cursor = if req.page_token.is_empty() {
+            None
+        } else {
+            Some(
+                Cursor::decode(&req.page_token)
+                    .map_err(|e| tonic::Status::invalid_argument(format!("page_token: {e:#}")))?,
+            )
+        };
+        let page_size = match req.page_size {
+            n if n > 0 => (n as i64).min(MAX_PAGE_SIZE),
+            _ => DEFAULT_PAGE_SIZE,
+        };
+
+        let filter = ReleaseFilter {
+            organisation: req.organisation,
+            project: non_empty(req.project),
+            environment: non_empty(req.environment),
+            branch: non_empty(req.branch),
+            author: non_empty(req.author),
+            status: non_empty(req.status).map(|s| s.to_uppercase()),
+            since: parse_time("since", req.since)?,
+            until: parse_time("until", req.until)?,
+        };
+
+        let (entries, next) = self
+            .state
+            .release_history()
+            .list(&filter, cursor.as_ref(), page_size)
+            .await
+            .to_internal_error()?;
+
+        Ok(Response::new(ListReleasesResponse {
+            releases: entries.into_iter().map(entry_to_grpc).collect(),
+            next_page_token: next.map(|c| c.encode()).unwrap_or_default(),
+        }))
+    }
+
+    async fn get_release(
+        &self,
+        request: tonic::Request<GetReleaseRequest>,
+    ) -> Result<Response<GetReleaseResponse>, tonic::Status> {
+        let actor = authorize::extract_actor(&request)?;
+        let req = request.into_inner();
+
+        // Authorize before touching the slug, and answer the same for a
+        // slug in another project as for one that does not exist, so
+        // callers cannot probe for releases outside their projects.
+        authorize::require_org_access(
+            &self.state.db,
+            &actor,
+            &req.organisation,
+            authorize::OrgRole::Member,
+        )
+        .await?;
+
+        let annotation = self
+            .state
+            .release_registry()
+            .find_release_annotation_by_slug(&req.slug)
+            .await
+            .to_internal_error()?
+            .filter(|a| {
+                a.project.organisation == req.organisation && a.project.project == req.project
+            })
+            .ok_or_else(|| tonic::Status::not_found(format!("release {} not found", req.slug)))?;
+
+        let project_id = self
+            .state
+            .release_registry()
+            .get_project_id(&annotation.project.organisation, &annotation.project.project)
+            .await
+            .context("resolve project")
+            .to_internal_error()?;
+
+        let history = self.state.release_history();
+        let attempts = history
+            .attempts(&project_id, &annotation.artifact_id)
+            .await
+            .to_internal_error()?;
+
+        let release_ids: Vec<Uuid> = attempts.iter().map(|a| a.release_id).collect();
+        let mut events: HashMap<Uuid, Vec<ReleaseAttemptEvent>> = HashMap::new();
+        for event in history.events(&release_ids).await.to_internal_error()? {
+            events
+                .entry(event.release_id)
+                .or_default()
+                .push(ReleaseAttemptEvent {
+                    event_type: event.event_type,
+                    created_at: event.created_at.to_rfc3339(),
+                    message: event.message,
+                });
+        }
+
+        let mut intents: Vec<Uuid> = attempts.iter().map(|a| a.release_intent_id).collect();
+        intents.sort();
+        intents.dedup();
+
+        // Logs and health are recorded per intent and destination, not per
+        // step. Attach them to the latest step on that destination.
+        let mut latest: HashMap<(Uuid, Uuid), Uuid> = HashMap::new();
+        for a in &attempts {
+            latest.insert((a.release_intent_id, a.destination_id), a.release_id);
+        }
+
+        let mut logs: HashMap<Uuid, Vec<ReleaseLogLine>> = HashMap::new();
+        let mut health: HashMap<(Uuid, String), (String, String)> = HashMap::new();
+        for intent in &intents {
+            if req.include_logs {
+                let blocks = self
+                    .state
+                    .release_logs_registry()
+                    .get_logs_by_intent(*intent)
+                    .await
+                    .to_internal_error()?;
+                for block in blocks {
+                    let Some(release_id) = latest.get(&(*intent, block.destination_id)) else {
+                        continue;
+                    };
+                    let destination = attempts
+                        .iter()
+                        .find(|a| a.release_id == *release_id)
+                        .map(|a| a.destination.clone())
+                        .unwrap_or_default();
+                    logs.entry(*release_id)
+                        .or_default()
+                        .extend(block.log_lines.into_iter().map(|l| ReleaseLogLine {
+                            destination: destination.clone(),
+                            line: l.line,
+                            timestamp: l.timestamp.to_string(),
+                            channel: match l.channel {
+                                release_logs_registry::LogChannel::Stdout => LogChannel::Stdout,
+                                release_logs_registry::LogChannel::Stderr => LogChannel::Stderr,
+                            }
+                            .into(),
+                        }));
+                }
+            }
+
+            for obs in release_health::get_observations_for_intent(&self.state.db, *intent)
+                .await
+                .to_internal_error()?
+            {
+                health.insert((*intent, obs.destination_name), (obs.status, obs.message));
+            }
+        }
+
+        // Policies as they evaluate now, against the latest release to
+        // each environment.
+        let mut environments: BTreeMap<&str, Uuid> = BTreeMap::new();
+        for a in attempts.iter().filter(|a| a.mode == "deploy") {
+            environments.insert(&a.environment, a.release_intent_id);
+        }
+        let mut policies = Vec::new();
+        for (environment, intent) in environments {
+            let evaluations = self
+                .state
+                .policy_registry()
+                .evaluate_for_environment(
+                    &project_id,
+                    environment,
+                    annotation.reference.commit_branch.as_deref(),
+                    Some(&intent),
+                )
+                .await
+                .context("evaluate policies")
+                .to_internal_error()?;
+            policies.push(EnvironmentPolicyEvaluations {
+                environment: environment.to_string(),
+                all_passed: evaluations.iter().all(|e| e.passed),
+                evaluations: evaluations.into_iter().map(eval_to_grpc).collect(),
+            });
+        }
+
+        let attempts = attempts
+            .iter()
+            .map(|a| {
+                let mut attempt = attempt_to_grpc(a);
+                attempt.events = events.remove(&a.release_id).unwrap_or_default();
+                attempt.logs = logs.remove(&a.release_id).unwrap_or_default();
+                if a.mode == "deploy"
+                    && latest.get(&(a.release_intent_id, a.destination_id)) == Some(&a.release_id)
+                    && let Some((status, message)) =
+                        health.remove(&(a.release_intent_id, a.destination.clone()))
+                {
+                    attempt.health_status = Some(status);
+                    attempt.health_message = Some(message).filter(|m| !m.is_empty());
+                }
+                attempt
+            })
+            .collect();
+
+        Ok(Response::new(GetReleaseResponse {
+            artifact: Some(annotation.into()),
+            attempts,
+            policies,
+        }))
+    }
+}
@@ -9,7 +9,7 @@ use crate::{
     grpc::{artifacts::GrpcErrorExt, authorize},
     services::{
         release_pipeline::{
-            CreatePipelineParams, PipelineStages, ReleasePipelineRegistryState,
+            self, CreatePipelineParams, PipelineStages, ReleasePipelineRegistryState,
             StageConfig, StageDefinition, UpdatePipelineParams,
         },
         release_registry::ReleaseRegistryState,
@@ -28,9 +28,19 @@ fn stages_to_proto(stages: &PipelineStages) -> Vec<PipelineStage> {
         .iter()
         .map(|(id, def)| {
             let config = match &def.config {
-                StageConfig::Deploy { environment } => {
+                StageConfig::Deploy { environment, batches } => {
                     Some(pipeline_stage::Config::Deploy(DeployStageConfig {
                         environment: environment.clone(),
+                        batches: batches
+                            .iter()
+                            .map(|b| DeployBatch {
+                                destinations: b.destinations.clone(),
+                                labels: b.labels.clone().into_iter().collect(),
+                                percentage: b.percentage,
+                                wait_seconds: b.wait_seconds,
+                                health_check: b.health_check,
+                            })
+                            .collect(),
                     }))
                 }
                 StageConfig::Wait { duration_seconds } => {
@@ -65,6 +75,17 @@ fn stages_from_proto(proto_stages: Vec<PipelineStage>) -> anyhow::Result<Pipelin
         let config = match ps.config {
             Some(pipeline_stage::Config::Deploy(c)) => StageConfig::Deploy {
                 environment: c.environment,
+                batches: c
+                    .batches
+                    .into_iter()
+                    .map(|b| release_pipeline::DeployBatch {
+                        destinations: b.destinations,
+                        labels: b.labels.into_iter().collect(),
+                        percentage: b.percentage,
+                        wait_seconds: b.wait_seconds,
+                        health_check: b.health_check,
+                    })
+                    .collect(),
             },
             Some(pipeline_stage::Config::Wait(c)) => StageConfig::Wait {
                 duration_seconds: c.duration_seconds,
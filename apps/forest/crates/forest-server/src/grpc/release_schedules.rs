@@ -0,0 +1,291 @@
+use anyhow::Context;
+use forest_grpc_interface::{release_schedule_service_server::ReleaseScheduleService, *};
+use tonic::Response;
+use uuid::Uuid;
+
+use crate::{
+    grpc::{artifacts::GrpcErrorExt, authorize},
+    services::{
+        event_bus::{EventBusState, EventPayload},
+        release_registry::ReleaseRegistryState,
+        release_schedule::{
+            self, CronSchedule, NewReleaseSchedule, ReleaseScheduleRegistryState, RunOutcome,
+            ScheduleStatus, ScheduleTiming,
+        },
+    },
+    state::State,
+};
+
+pub struct ReleaseSchedulesServer {
+    pub state: State,
+}
+
+fn status_to_grpc(s: ScheduleStatus) -> ReleaseScheduleStatus {
+    match s {
+        ScheduleStatus::Active => ReleaseScheduleStatus::Active,
+        ScheduleStatus::Completed => ReleaseScheduleStatus::Completed,
+        ScheduleStatus::Cancelled => ReleaseScheduleStatus::Cancelled,
+    }
+}
+
+fn outcome_to_grpc(o: RunOutcome) -> ReleaseScheduleOutcome {
+    match o {
+        RunOutcome::Released => ReleaseScheduleOutcome::Released,
+        RunOutcome::Skipped => ReleaseScheduleOutcome::Skipped,
+        RunOutcome::Blocked => ReleaseScheduleOutcome::Blocked,
+        RunOutcome::Failed => ReleaseScheduleOutcome::Failed,
+    }
+}
+
+fn schedule_to_grpc(s: release_schedule::ReleaseSchedule) -> ReleaseSchedule {
+    let last_run = s.last_run_at.map(|ran_at| ReleaseScheduleRun {
+        ran_at: ran_at.to_rfc3339(),
+        outcome: s
+            .last_outcome
+            .map(outcome_to_grpc)
+            .unwrap_or(ReleaseScheduleOutcome::Unspecified)
+            .into(),
+        message: s.last_message.clone().unwrap_or_default(),
+        release_intent_id: s.last_release_intent_id.map(|id| id.to_string()),
+    });
+
+    ReleaseSchedule {
+        id: s.id.to_string(),
+        project: Some(Project {
+            organisation: s.organisation,
+            project: s.project,
+            ..Default::default()
+        }),
+        run_at: s.run_at.map(|t| t.to_rfc3339()),
+        cron: s.cron,
+        artifact_id: s.artifact_id.map(|id| id.to_string()),
+        branch: s.branch,
+        environments: s.environments,
+        destinations: s.destinations,
+        force: s.force,
+        use_pipeline: s.use_pipeline,
+        status: status_to_grpc(s.status).into(),
+        next_run_at: s.next_run_at.map(|t| t.to_rfc3339()),
+        last_run,
+        created_by: format!("{}:{}", s.created_by_type, s.created_by_id),
+        created_at: s.created_at.to_rfc3339(),
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn parse_timing(
+    run_at: Option<String>,
+    cron: Option<String>,
+) -> Result<ScheduleTiming, tonic::Status> {
+    match (run_at, cron) {
+        (Some(run_at), None) => chrono::DateTime::parse_from_rfc3339(&run_at)
+            .map(|t| ScheduleTiming::At(t.with_timezone(&chrono::Utc)))
+            .map_err(|e| {
+                tonic::Status::invalid_argument(format!(
+                    "run_at must be an RFC 3339 time, e.g. 2026-10-20T06:00:00Z: {e}"
+                ))
+            }),
+        (None, Some(cron)) => CronSchedule::parse(&cron)
+            .map(ScheduleTiming::Cron)
+            .map_err(|e| tonic::Status::invalid_argument(format!("{e:#}"))),
+        _ => Err(tonic::Status::invalid_argument(
+            "exactly one of run_at or cron is required",
+        )),
+    }
+}
+
+#[tonic::async_trait]
+impl ReleaseScheduleService for ReleaseSchedulesServer {
+    async fn create_release_schedule(
+        &self,
+        request: tonic::Request<CreateReleaseScheduleRequest>,
+    ) -> Result<Response<CreateReleaseScheduleResponse>, tonic::Status> {
+        let actor = authorize::extract_actor(&request)?;
+        let req = request.into_inner();
+
+        let project = req
+            .project
+            .ok_or_else(|| tonic::Status::invalid_argument("project is required"))?;
+
+        authorize::require_project_access(
+            &self.state.db,
+            &actor,
+            &project,
+            authorize::OrgRole::Member,
+        )
+        .await?;
+
+        let project_id = self
+            .state
+            .release_registry()
+            .get_project_id(&project.organisation, &project.project)
+            .await
+            .context("resolve project")
+            .to_internal_error()?;
+
+        let timing = parse_timing(req.run_at, req.cron)?;
+
+        let artifact_id = match req.artifact_id {
+            Some(id) => {
+                let id: Uuid = id
+                    .parse()
+                    .map_err(|_| tonic::Status::invalid_argument("invalid artifact_id"))?;
+                let artifact_project = self
+                    .state
+                    .release_registry()
+                    .get_project_id_from_artifact(&id)
+                    .await
+                    .ok();
+                if artifact_project != Some(project_id) {
+                    return Err(tonic::Status::not_found(
+                        "artifact not found in this project",
+                    ));
+                }
+                Some(id)
+            }
+            None => None,
+        };
+
+        let schedule = NewReleaseSchedule {
+            project_id,
+            timing,
+            artifact_id,
+            branch: req.branch,
+            environments: req.environments,
+            destinations: req.destinations,
+            force: req.force,
+            use_pipeline: req.use_pipeline,
+        };
+        schedule
+            .validate(chrono::Utc::now())
+            .map_err(|e| tonic::Status::invalid_argument(e.to_string()))?;
+
+        let schedule = self
+            .state
+            .release_schedule_registry()
+            .create(&schedule, &actor)
+            .await
+            .context("create release schedule")
+            .to_internal_error()?;
+
+        self.state
+            .event_bus()
+            .emit(EventPayload {
+                organisation: project.organisation.clone(),
+                project: project.project.clone(),
+                resource_type: "release_schedule",
+                action: "created",
+                resource_id: schedule.id.to_string(),
+                metadata: [(
+                    "when".into(),
+                    schedule
+                        .cron
+                        .clone()
+                        .or_else(|| schedule.run_at.map(|t| t.to_rfc3339()))
+                        .unwrap_or_default(),
+                )]
+                .into(),
+            })
+            .await;
+
+        Ok(Response::new(CreateReleaseScheduleResponse {
+            schedule: Some(schedule_to_grpc(schedule)),
+        }))
+    }
+
+    async fn list_release_schedules(
+        &self,
+        request: tonic::Request<ListReleaseSchedulesRequest>,
+    ) -> Result<Response<ListReleaseSchedulesResponse>, tonic::Status> {
+        let actor = authorize::extract_actor(&request)?;
+        let req = request.into_inner();
+
+        let project = req
+            .project
+            .ok_or_else(|| tonic::Status::invalid_argument("project is required"))?;
+
+        authorize::require_project_access(
+            &self.state.db,
+            &actor,
+            &project,
+            authorize::OrgRole::Member,
+        )
+        .await?;
+
+        let project_id = self
+            .state
+            .release_registry()
+            .get_project_id(&project.organisation, &project.project)
+            .await
+            .context("resolve project")
+            .to_internal_error()?;
+
+        let schedules = self
+            .state
+            .release_schedule_registry()
+            .list(&project_id, req.include_inactive)
+            .await
+            .to_internal_error()?;
+
+        Ok(Response::new(ListReleaseSchedulesResponse {
+            schedules: schedules.into_iter().map(schedule_to_grpc).collect(),
+        }))
+    }
+
+    async fn cancel_release_schedule(
+        &self,
+        request: tonic::Request<CancelReleaseScheduleRequest>,
+    ) -> Result<Response<CancelReleaseScheduleResponse>, tonic::Status> {
+        let actor = authorize::extract_actor(&request)?;
+        let req = request.into_inner();
+
+        let project = req
+            .project
+            .ok_or_else(|| tonic::Status::invalid_argument("project is required"))?;
+
+        authorize::require_project_access(
+            &self.state.db,
+            &actor,
+            &project,
+            authorize::OrgRole::Member,
+        )
+        .await?;
+
+        let id: Uuid = req
+            .id
+            .parse()
+            .map_err(|_| tonic::Status::invalid_argument("invalid schedule id"))?;
+
+        let project_id = self
+            .state
+            .release_registry()
+            .get_project_id(&project.organisation, &project.project)
+            .await
+            .context("resolve project")
+            .to_internal_error()?;
+
+        let schedule = self
+            .state
+            .release_schedule_registry()
+            .cancel(&project_id, &id)
+            .await
+            .to_internal_error()?
+            .ok_or_else(|| tonic::Status::not_found("no active schedule with this id"))?;
+
+        self.state
+            .event_bus()
+            .emit(EventPayload {
+                organisation: project.organisation.clone(),
+                project: project.project.clone(),
+                resource_type: "release_schedule",
+                action: "cancelled",
+                resource_id: schedule.id.to_string(),
+                metadata: Default::default(),
+            })
+            .await;
+
+        Ok(Response::new(CancelReleaseScheduleResponse {
+            schedule: Some(schedule_to_grpc(schedule)),
+        }))
+    }
+}
@@ -0,0 +1,184 @@
+use anyhow::Context;
+use forest_grpc_interface::{retention_service_server::RetentionService, *};
+use tonic::Response;
+
+use crate::{
+    actor::Actor,
+    grpc::{artifacts::GrpcErrorExt, authorize},
+    services::{
+        event_bus::{EventBusState, EventPayload},
+        retention::{self, RetentionRegistryState},
+    },
+    state::State,
+};
+
+pub struct RetentionServer {
+    pub state: State,
+}
+
+fn policy_to_grpc(p: retention::RetentionPolicy) -> RetentionPolicy {
+    RetentionPolicy {
+        organisation: p.organisation,
+        keep_artifacts: p.keep_artifacts,
+        log_retention_days: p.log_retention_days,
+        notification_retention_days: p.notification_retention_days,
+        updated_by: p.updated_by.unwrap_or_default(),
+        updated_at: p.updated_at.map(|t| t.to_rfc3339()).unwrap_or_default(),
+    }
+}
+
+fn actor_label(actor: &Actor) -> String {
+    format!("{}:{}", actor.actor_type(), actor.actor_id())
+}
+
+fn describe(value: Option<i32>) -> String {
+    value.map_or_else(|| "keep all".to_string(), |v| v.to_string())
+}
+
+#[tonic::async_trait]
+impl RetentionService for RetentionServer {
+    async fn get_retention_policy(
+        &self,
+        request: tonic::Request<GetRetentionPolicyRequest>,
+    ) -> Result<Response<GetRetentionPolicyResponse>, tonic::Status> {
+        let actor = authorize::extract_actor(&request)?;
+        let req = request.into_inner();
+        authorize::require_org_access(
+            &self.state.db,
+            &actor,
+            &req.organisation,
+            authorize::OrgRole::Member,
+        )
+        .await?;
+
+        let policy = self
+            .state
+            .retention_registry()
+            .get(&req.organisation)
+            .await
+            .to_internal_error()?;
+
+        Ok(Response::new(GetRetentionPolicyResponse {
+            policy: Some(policy_to_grpc(policy)),
+        }))
+    }
+
+    async fn set_retention_policy(
+        &self,
+        request: tonic::Request<SetRetentionPolicyRequest>,
+    ) -> Result<Response<SetRetentionPolicyResponse>, tonic::Status> {
+        let actor = authorize::extract_actor(&request)?;
+        let req = request.into_inner();
+        authorize::require_org_access(
+            &self.state.db,
+            &actor,
+            &req.organisation,
+            authorize::OrgRole::Admin,
+        )
+        .await?;
+
+        let policy = retention::RetentionPolicy {
+            organisation: req.organisation,
+            keep_artifacts: req.keep_artifacts,
+            log_retention_days: req.log_retention_days,
+            notification_retention_days: req.notification_retention_days,
+            ..Default::default()
+        };
+        policy
+            .validate()
+            .map_err(|e| tonic::Status::invalid_argument(e.to_string()))?;
+
+        let policy = self
+            .state
+            .retention_registry()
+            .set(&policy, &actor_label(&actor))
+            .await
+            .context("set retention policy")
+            .to_internal_error()?;
+
+        self.state
+            .event_bus()
+            .emit(EventPayload {
+                organisation: policy.organisation.clone(),
+                project: String::new(),
+                resource_type: "retention",
+                action: "updated",
+                resource_id: String::new(),
+                metadata: [
+                    ("keep_artifacts".into(), describe(policy.keep_artifacts)),
+                    ("log_retention_days".into(), describe(policy.log_retention_days)),
+                    (
+                        "notification_retention_days".into(),
+                        describe(policy.notification_retention_days),
+                    ),
+                    ("actor".into(), actor_label(&actor)),
+                ]
+                .into(),
+            })
+            .await;
+
+        Ok(Response::new(SetRetentionPolicyResponse {
+            policy: Some(policy_to_grpc(policy)),
+        }))
+    }
+
+    async fn preview_retention(
+        &self,
+        request: tonic::Request<PreviewRetentionRequest>,
+    ) -> Result<Response<PreviewRetentionResponse>, tonic::Status> {
+        let actor = authorize::extract_actor(&request)?;
+        let req = request.into_inner();
+        authorize::require_org_access(
+            &self.state.db,
+            &actor,
+            &req.organisation,
+            authorize::OrgRole::Member,
+        )
+        .await?;
+
+        let registry = self.state.retention_registry();
+        let policy = match req.policy {
+            Some(p) => {
+                let policy = retention::RetentionPolicy {
+                    organisation: req.organisation,
+                    keep_artifacts: p.keep_artifacts,
+                    log_retention_days: p.log_retention_days,
+                    notification_retention_days: p.notification_retention_days,
+                    ..Default::default()
+                };
+                policy
+                    .validate()
+                    .map_err(|e| tonic::Status::invalid_argument(e.to_string()))?;
+                policy
+            }
+            None => registry.get(&req.organisation).await.to_internal_error()?,
+        };
+
+        let plan = registry
+            .plan(&policy, chrono::Utc::now())
+            .await
+            .context("plan retention")
+            .to_internal_error()?;
+
+        Ok(Response::new(PreviewRetentionResponse {
+            policy: Some(policy_to_grpc(policy)),
+            artifact_files: plan.artifacts.iter().map(|a| a.file_count).sum(),
+            artifact_bytes: plan.artifacts.iter().map(|a| a.size_bytes).sum(),
+            artifacts: plan
+                .artifacts
+                .into_iter()
+                .map(|a| ExpiredArtifact {
+                    artifact_id: a.id.to_string(),
+                    project: a.project,
+                    slug: a.slug,
+                    created_at: a.created.to_rfc3339(),
+                    file_count: a.file_count,
+                    size_bytes: a.size_bytes,
+                })
+                .collect(),
+            log_blocks: plan.log_blocks,
+            health_observations: plan.health_observations,
+            notifications: plan.notifications,
+        }))
+    }
+}
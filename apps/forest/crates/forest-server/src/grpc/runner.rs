@@ -2,20 +2,22 @@ use std::pin::Pin;
 
 use forest_grpc_interface::{runner_service_server::RunnerService, *};
 use forest_models::ReleaseStatus;
-use futures::Stream;
+use futures::{Stream, StreamExt};
 use tokio::sync::mpsc;
 use tokio_stream::wrappers::ReceiverStream;
 use tonic::Response;
 use uuid::Uuid;
 
 use crate::{
-    runner_manager::{DestinationCapability, RunnerManager},
+    grpc::authorize,
+    runner_manager::{DestinationCapability, RunnerManager, RunnerRegistration, RunnerScope},
     services::{
         artifact_staging_registry::ArtifactStagingRegistryState,
         destination_registry::DestinationRegistryState,
         notification_registry::NotificationRegistryState,
         release_event_store::{
-            EventPayload, ReleaseEventStoreState, ReleaseEventType,
+            EventPayload, RELEASE_CANCEL_SUBJECT_PREFIX, ReleaseCancelSignal,
+            ReleaseEventStoreState, ReleaseEventType,
         },
         release_finalizer,
         release_logs_registry::{
@@ -23,6 +25,7 @@ use crate::{
         },
         release_registry::ReleaseRegistryState,
         release_token_registry::ReleaseTokenRegistryState,
+        secrets::{self, SecretRegistryState},
     },
     state::State,
 };
@@ -41,6 +44,7 @@ impl RunnerService for RunnerServer {
         &self,
         request: tonic::Request<tonic::Streaming<RunnerMessage>>,
     ) -> Result<Response<Self::RegisterRunnerStream>, tonic::Status> {
+        let actor = authorize::try_extract_actor(&request);
         let mut inbound = request.into_inner();
 
         // Wait for the first message to be RunnerRegister
@@ -65,6 +69,37 @@ impl RunnerService for RunnerServer {
             register.runner_id.clone()
         };
 
+        let scope = RunnerScope {
+            organisation: register.organisation.filter(|o| !o.is_empty()),
+            project: register.project.filter(|p| !p.is_empty()),
+        };
+
+        // Shared runners may register anonymously. A runner that claims an
+        // organisation's work must prove it speaks for that organisation.
+        let identity = match (&scope.organisation, &scope.project) {
+            (None, Some(_)) => {
+                return Err(tonic::Status::invalid_argument(
+                    "a project-scoped runner must also set organisation",
+                ));
+            }
+            (Some(organisation), _) => {
+                let actor = actor.ok_or_else(|| {
+                    tonic::Status::unauthenticated(
+                        "scoped runner registration requires an authorization token",
+                    )
+                })?;
+                authorize::require_org_access(
+                    &self.state.db,
+                    &actor,
+                    organisation,
+                    authorize::OrgRole::Admin,
+                )
+                .await?;
+                Some(actor)
+            }
+            (None, None) => actor,
+        };
+
         let capabilities: Vec<DestinationCapability> = register
             .capabilities
             .into_iter()
@@ -75,17 +110,33 @@ impl RunnerService for RunnerServer {
             })
             .collect();
 
+        // Cancellations are published to every replica; the stream handler
+        // forwards the ones addressed to this runner.
+        let mut cancellations = self
+            .state
+            .nats
+            .subscribe(format!("{RELEASE_CANCEL_SUBJECT_PREFIX}*"))
+            .await
+            .map_err(|e| tonic::Status::internal(format!("subscribe to cancellations: {e}")))?;
+
         // Channel for the scheduler to send work assignments to this runner
         let (work_tx, mut work_rx) = mpsc::channel::<WorkAssignment>(16);
 
         self.runner_manager
             .register_runner(
                 runner_id.clone(),
-                capabilities,
-                register.max_concurrent,
+                RunnerRegistration {
+                    identity,
+                    capabilities,
+                    labels: register.labels,
+                    pool: Some(register.pool).filter(|p| !p.is_empty()),
+                    scope,
+                    max_concurrent: register.max_concurrent,
+                },
                 work_tx,
             )
-            .await;
+            .await
+            .map_err(|e| tonic::Status::already_exists(e.to_string()))?;
 
         // Outbound channel to the runner
         let (out_tx, out_rx) = mpsc::channel(16);
@@ -129,6 +180,34 @@ impl RunnerService for RunnerServer {
                         }
                     }
 
+                    // Cancellation for a release this runner may hold → forward
+                    Some(msg) = cancellations.next() => {
+                        let Some(release_id) = msg.subject.strip_prefix(RELEASE_CANCEL_SUBJECT_PREFIX) else {
+                            continue;
+                        };
+                        let signal: ReleaseCancelSignal = match serde_json::from_slice(&msg.payload) {
+                            Ok(signal) => signal,
+                            Err(e) => {
+                                tracing::warn!(release_id, "invalid release cancel signal: {e}");
+                                continue;
+                            }
+                        };
+                        if signal.runner_id != runner_id_clone {
+                            continue;
+                        }
+
+                        tracing::info!(runner_id = %runner_id_clone, release_id, "forwarding release cancellation to runner");
+                        let msg = ServerMessage {
+                            message: Some(server_message::Message::CancelWork(CancelWork {
+                                release_id: release_id.to_string(),
+                                reason: signal.reason,
+                            })),
+                        };
+                        if out_tx.send(Ok(msg)).await.is_err() {
+                            break;
+                        }
+                    }
+
                     // Inbound messages from runner (heartbeat, work ack)
                     msg = inbound.message() => {
                         match msg {
@@ -192,9 +271,13 @@ impl RunnerService for RunnerServer {
                 }
             }
 
-            // Unregister runner on disconnect
-            runner_manager.unregister_runner(&runner_id_clone).await;
+            // Unregister runner on disconnect. If it already reconnected on
+            // a new stream, that stream owns the registration and its work.
             tracing::info!(runner_id = %runner_id_clone, "runner stream closed");
+            drop(work_rx);
+            if !runner_manager.unregister_runner(&runner_id_clone).await {
+                return;
+            }
 
             // Recovery: fail any in-flight releases for this runner
             let token_registry = state_clone.release_token_registry();
@@ -414,6 +497,7 @@ impl RunnerService for RunnerServer {
         let logs_registry = self.state.release_logs_registry();
 
         let mut validated_scope = None;
+        let mut redactions = Vec::new();
         let attempt = Uuid::now_v7();
         let mut buffer: Vec<LogLine> = Vec::new();
         let mut sequence: i64 = 0;
@@ -436,6 +520,22 @@ impl RunnerService for RunnerServer {
                             "invalid, expired, or revoked release token",
                         )
                     })?;
+
+                // Mask secret values even if the runner forgot to.
+                let (organisation, project) = self
+                    .state
+                    .release_registry()
+                    .get_project_context(&scope.project_id)
+                    .await
+                    .map_err(|e| tonic::Status::internal(format!("resolve project: {e}")))?;
+                redactions = self
+                    .state
+                    .secret_registry()
+                    .resolve(&organisation, &project, &scope.environment)
+                    .await
+                    .map_err(|e| tonic::Status::internal(format!("resolve secrets: {e}")))?
+                    .redactions();
+
                 validated_scope = Some(scope);
             }
 
@@ -448,7 +548,7 @@ impl RunnerService for RunnerServer {
 
             buffer.push(LogLine {
                 channel,
-                line: msg.line,
+                line: secrets::redact(&msg.line, &redactions),
                 timestamp: msg.timestamp as u128,
             });
 
@@ -506,6 +606,7 @@ impl RunnerService for RunnerServer {
         let status = match req.outcome() {
             ReleaseOutcome::Success => ReleaseStatus::Succeeded,
             ReleaseOutcome::Failure => ReleaseStatus::Failed,
+            ReleaseOutcome::Cancelled => ReleaseStatus::Cancelled,
             ReleaseOutcome::Unspecified => {
                 return Err(tonic::Status::invalid_argument(
                     "outcome must be SUCCESS or FAILURE",
@@ -536,22 +637,37 @@ impl RunnerService for RunnerServer {
             }
         }
 
+        // A release cancelled while the runner worked on it is already
+        // final; only the runner's bookkeeping below is left to do.
+        let current = self
+            .state
+            .release_event_store()
+            .get_release_state(&scope.release_id)
+            .await
+            .map_err(|e| tonic::Status::internal(format!("failed to load release: {e:#}")))?;
+        let already_final = matches!(
+            current.status.as_str(),
+            "SUCCEEDED" | "FAILED" | "CANCELLED" | "TIMED_OUT"
+        );
+
         // Finalize: emit event + create notification
-        release_finalizer::finalize_release(
-            &self.state.release_event_store(),
-            &self.state.release_registry(),
-            &self.state.notification_registry(),
-            &self.state.destination_registry(),
-            &scope.release_id,
-            &scope.release_intent_id,
-            &scope.artifact_id,
-            &scope.project_id,
-            &scope.destination_id,
-            status,
-            error_message,
-        )
-        .await
-        .map_err(|e| tonic::Status::internal(format!("failed to finalize release: {e}")))?;
+        if !already_final {
+            release_finalizer::finalize_release(
+                &self.state.release_event_store(),
+                &self.state.release_registry(),
+                &self.state.notification_registry(),
+                &self.state.destination_registry(),
+                &scope.release_id,
+                &scope.release_intent_id,
+                &scope.artifact_id,
+                &scope.project_id,
+                &scope.destination_id,
+                status,
+                error_message,
+            )
+            .await
+            .map_err(|e| tonic::Status::internal(format!("failed to finalize release: {e}")))?;
+        }
 
         // Revoke the token
         if let Err(e) = token_registry.revoke_token(&req.release_token).await {
@@ -572,4 +688,58 @@ impl RunnerService for RunnerServer {
 
         Ok(Response::new(CompleteReleaseResponse {}))
     }
+
+    async fn list_runners(
+        &self,
+        request: tonic::Request<ListRunnersRequest>,
+    ) -> Result<Response<ListRunnersResponse>, tonic::Status> {
+        let req_org = request.get_ref().organisation.clone();
+        let organisation = if req_org.is_empty() {
+            authorize::unauthenticated_actor(&request)
+                .require_authenticated()?
+                .require_service_account()?;
+            None
+        } else {
+            let actor = authorize::unauthenticated_actor(&request)
+                .require_authenticated()?
+                .into_actor();
+            authorize::require_org_access(
+                &self.state.db,
+                &actor,
+                &req_org,
+                authorize::OrgRole::Member,
+            )
+            .await?;
+            Some(req_org.as_str())
+        };
+
+        let runners = self
+            .runner_manager
+            .list_runners(organisation)
+            .await
+            .into_iter()
+            .map(|r| RunnerInfo {
+                runner_id: r.runner_id,
+                capabilities: r
+                    .capabilities
+                    .into_iter()
+                    .map(|c| forest_grpc_interface::DestinationCapability {
+                        organisation: c.organisation,
+                        name: c.name,
+                        version: c.version as u64,
+                    })
+                    .collect(),
+                labels: r.labels,
+                pool: r.pool.unwrap_or_default(),
+                organisation: r.scope.organisation,
+                project: r.scope.project,
+                max_concurrent: r.max_concurrent,
+                active_releases: r.active_releases,
+                heartbeat_age_seconds: r.heartbeat_age.as_secs(),
+                connected_at: r.connected_at.to_rfc3339(),
+            })
+            .collect();
+
+        Ok(Response::new(ListRunnersResponse { runners }))
+    }
 }
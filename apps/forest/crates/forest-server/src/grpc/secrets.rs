@@ -0,0 +1,199 @@
+use anyhow::Context;
+use forest_grpc_interface::{secret_service_server::SecretService, *};
+use tonic::Response;
+
+use crate::{
+    actor::Actor,
+    grpc::{artifacts::GrpcErrorExt, authorize},
+    services::{
+        event_bus::{EventBusState, EventPayload},
+        release_registry::ReleaseRegistryState,
+        secrets::{self, SecretRecord, SecretRegistryState},
+    },
+    state::State,
+};
+
+pub struct SecretsServer {
+    pub state: State,
+}
+
+fn record_to_grpc(r: SecretRecord) -> Secret {
+    Secret {
+        scope: Some(forest_grpc_interface::SecretScope {
+            organisation: r.scope.organisation,
+            project: r.scope.project,
+            environment: r.scope.environment,
+        }),
+        name: r.name,
+        version: r.version,
+        updated_by: r.updated_by.unwrap_or_default(),
+        created_at: r.created_at.to_rfc3339(),
+        updated_at: r.updated_at.to_rfc3339(),
+    }
+}
+
+fn actor_label(actor: &Actor) -> String {
+    format!("{}:{}", actor.actor_type(), actor.actor_id())
+}
+
+impl SecretsServer {
+    /// Authorize the actor as an organisation admin and check that the
+    /// project and environment the secret is scoped to exist.
+    async fn authorize_scope(
+        &self,
+        actor: &Actor,
+        scope: Option<forest_grpc_interface::SecretScope>,
+    ) -> Result<secrets::SecretScope, tonic::Status> {
+        let scope = scope.ok_or_else(|| tonic::Status::invalid_argument("scope is required"))?;
+        authorize::require_org_access(
+            &self.state.db,
+            actor,
+            &scope.organisation,
+            authorize::OrgRole::Admin,
+        )
+        .await?;
+
+        if !scope.project.is_empty() {
+            self.state
+                .release_registry()
+                .get_project_id(&scope.organisation, &scope.project)
+                .await
+                .map_err(|_| tonic::Status::not_found("project not found"))?;
+        }
+
+        if !scope.environment.is_empty() {
+            let exists = sqlx::query_scalar!(
+                r#"SELECT EXISTS(
+                       SELECT 1 FROM environments WHERE organisation = $1 AND name = $2
+                   ) AS "exists!""#,
+                scope.organisation,
+                scope.environment,
+            )
+            .fetch_one(&self.state.db)
+            .await
+            .context("check environment")
+            .to_internal_error()?;
+            if !exists {
+                return Err(tonic::Status::not_found("environment not found"));
+            }
+        }
+
+        Ok(secrets::SecretScope {
+            organisation: scope.organisation,
+            project: scope.project,
+            environment: scope.environment,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl SecretService for SecretsServer {
+    async fn set_secret(
+        &self,
+        request: tonic::Request<SetSecretRequest>,
+    ) -> Result<tonic::Response<SetSecretResponse>, tonic::Status> {
+        let actor = authorize::extract_actor(&request)?;
+        let req = request.into_inner();
+        let scope = self.authorize_scope(&actor, req.scope).await?;
+
+        secrets::validate_name(&req.name)
+            .map_err(|e| tonic::Status::invalid_argument(e.to_string()))?;
+
+        let record = self
+            .state
+            .secret_registry()
+            .set(&scope, &req.name, &req.value, &actor_label(&actor))
+            .await
+            .context("set secret")
+            .to_internal_error()?;
+
+        self.state.event_bus().emit(EventPayload {
+            organisation: scope.organisation.clone(),
+            project: scope.project.clone(),
+            resource_type: "secret",
+            action: if record.version == 1 { "created" } else { "rotated" },
+            resource_id: req.name.clone(),
+            metadata: [
+                ("scope".into(), scope.describe()),
+                ("version".into(), record.version.to_string()),
+                ("key_id".into(), record.key_id.clone()),
+                ("actor".into(), actor_label(&actor)),
+            ]
+            .into(),
+        }).await;
+
+        Ok(Response::new(SetSecretResponse {
+            secret: Some(record_to_grpc(record)),
+        }))
+    }
+
+    async fn list_secrets(
+        &self,
+        request: tonic::Request<ListSecretsRequest>,
+    ) -> Result<tonic::Response<ListSecretsResponse>, tonic::Status> {
+        let actor = authorize::extract_actor(&request)?;
+        let req = request.into_inner();
+        authorize::require_org_access(
+            &self.state.db,
+            &actor,
+            &req.organisation,
+            authorize::OrgRole::Member,
+        )
+        .await?;
+
+        let records = self
+            .state
+            .secret_registry()
+            .list(
+                &req.organisation,
+                req.project.as_deref(),
+                req.environment.as_deref(),
+            )
+            .await
+            .context("list secrets")
+            .to_internal_error()?;
+
+        Ok(Response::new(ListSecretsResponse {
+            secrets: records.into_iter().map(record_to_grpc).collect(),
+        }))
+    }
+
+    async fn delete_secret(
+        &self,
+        request: tonic::Request<DeleteSecretRequest>,
+    ) -> Result<tonic::Response<DeleteSecretResponse>, tonic::Status> {
+        let actor = authorize::extract_actor(&request)?;
+        let req = request.into_inner();
+        let scope = self.authorize_scope(&actor, req.scope).await?;
+
+        let deleted = self
+            .state
+            .secret_registry()
+            .delete(&scope, &req.name)
+            .await
+            .context("delete secret")
+            .to_internal_error()?;
+        if !deleted {
+            return Err(tonic::Status::not_found(format!(
+                "secret '{}' not found in {}",
+                req.name,
+                scope.describe()
+            )));
+        }
+
+        self.state.event_bus().emit(EventPayload {
+            organisation: scope.organisation.clone(),
+            project: scope.project.clone(),
+            resource_type: "secret",
+            action: "deleted",
+            resource_id: req.name.clone(),
+            metadata: [
+                ("scope".into(), scope.describe()),
+                ("actor".into(), actor_label(&actor)),
+            ]
+            .into(),
+        }).await;
+
+        Ok(Response::new(DeleteSecretResponse {}))
+    }
+}
@@ -7,13 +7,19 @@ use uuid::Uuid;
 use super::error;
 use crate::{
     actor::Actor,
+    repositories::passkeys::PasskeyRow,
     services::{
         device_login_aggregate::{DeviceLoginAggregateServiceState, DeviceLoginPollOutcome},
+        organisations::OrganisationServiceState,
         registration_policy::RegistrationPolicyState,
         users::UserServiceState,
     },
     state::State,
     tokens::TokenServiceState,
+    webauthn::{
+        self, DiscoverableKey, PublicKeyCredential, RegisterPublicKeyCredential, RelyingParty,
+        Webauthn,
+    },
 };
 
 pub struct UsersServer {
@@ -41,6 +47,229 @@ impl UsersServer {
         }
         Ok(())
     }
+
+    /// Refuse a login when one of the user's organisations requires
+    /// single sign-on, unless it came through that organisation's identity
+    /// provider (`via_sso`). Runs before any session or token is issued.
+    async fn enforce_sso(&self, user_id: Uuid, via_sso: Option<&str>) -> Result<(), tonic::Status> {
+        let required_by = self
+            .state
+            .organisation_service()
+            .sso_required_by(user_id, via_sso)
+            .await
+            .map_err(error::to_status)?;
+        match required_by {
+            Some(org) => Err(tonic::Status::permission_denied(format!(
+                "{org} requires single sign-on. Sign in through your identity provider instead."
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// The verifier for passkey ceremonies. Ceremonies run in the browser
+    /// on forage, so passkeys are bound to its URL.
+    fn webauthn(&self) -> Result<Webauthn, tonic::Status> {
+        let rp = self
+            .state
+            .config
+            .web_app_url
+            .as_deref()
+            .and_then(RelyingParty::from_web_app_url)
+            .ok_or_else(|| {
+                tonic::Status::failed_precondition(
+                    "FOREST_WEB_APP_URL is not configured; passkeys are disabled",
+                )
+            })?;
+        rp.webauthn().map_err(|e| {
+            tracing::error!(error = %e, "FOREST_WEB_APP_URL is not a valid passkey origin");
+            tonic::Status::failed_precondition(
+                "FOREST_WEB_APP_URL is not a valid passkey origin; passkeys are disabled",
+            )
+        })
+    }
+
+    /// The user's passkeys, for an assertion restricted to them.
+    async fn stored_passkeys(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<webauthn::Passkey>, tonic::Status> {
+        self.service()
+            .list_passkeys(user_id)
+            .await
+            .map_err(error::to_status)?
+            .iter()
+            .map(stored_passkey)
+            .collect()
+    }
+
+    /// Persist a ceremony's webauthn-rs state as a single-use oauth state
+    /// and hand back its id together with the options for the browser.
+    async fn start_passkey_ceremony(
+        &self,
+        kind: &str,
+        user_id: Option<Uuid>,
+        mfa_session_token: Option<&str>,
+        state: &impl serde::Serialize,
+        options: &impl serde::Serialize,
+    ) -> Result<PasskeyCeremony, tonic::Status> {
+        let data = serde_json::json!({
+            "type": kind,
+            "user_id": user_id.map(|id| id.to_string()),
+            "mfa_session_token": mfa_session_token,
+            "state": state,
+        });
+        let options_json = serde_json::to_string(options)
+            .map_err(|e| tonic::Status::internal(format!("passkey options: {e}")))?;
+        let ceremony_id = format!("passkey-{}", Uuid::now_v7());
+        let expires = Utc::now() + chrono::Duration::seconds(webauthn::CEREMONY_TIMEOUT_SECS);
+        self.service()
+            .create_oauth_state("passkey", &ceremony_id, None, &data, Some(expires))
+            .await
+            .map_err(error::to_status)?;
+
+        Ok(PasskeyCeremony {
+            ceremony_id,
+            options_json,
+        })
+    }
+
+    /// Consume a ceremony started by `start_passkey_ceremony`. Fails if
+    /// it is unknown, expired, already used, or of another kind.
+    async fn finish_passkey_ceremony(
+        &self,
+        ceremony_id: &str,
+        kind: &str,
+    ) -> Result<PendingCeremony, tonic::Status> {
+        let invalid = || tonic::Status::unauthenticated("invalid or expired passkey ceremony");
+        if !ceremony_id.starts_with("passkey-") {
+            return Err(invalid());
+        }
+        let state = self
+            .service()
+            .consume_oauth_state(ceremony_id)
+            .await
+            .map_err(error::to_status)?
+            .ok_or_else(invalid)?;
+        let field = |name: &str| state.data.get(name).and_then(|v| v.as_str());
+        if state.provider != "passkey" || field("type") != Some(kind) {
+            return Err(invalid());
+        }
+
+        Ok(PendingCeremony {
+            state: state
+                .data
+                .get("state")
+                .cloned()
+                .ok_or_else(|| tonic::Status::internal("invalid passkey ceremony data"))?,
+            user_id: field("user_id").and_then(|id| id.parse().ok()),
+            mfa_session_token: field("mfa_session_token").map(str::to_string),
+        })
+    }
+
+    /// Verify an assertion against the stored passkey it names and record
+    /// the use. If the ceremony was started for a particular user, the
+    /// passkey must be theirs.
+    async fn verify_passkey_assertion(
+        &self,
+        webauthn: &Webauthn,
+        ceremony: &PendingCeremony,
+        credential_json: &str,
+    ) -> Result<PasskeyRow, tonic::Status> {
+        let credential: PublicKeyCredential = serde_json::from_str(credential_json)
+            .map_err(|e| tonic::Status::invalid_argument(format!("malformed credential: {e}")))?;
+        let row = self
+            .service()
+            .find_passkey(credential.get_credential_id())
+            .await
+            .map_err(error::to_status)?
+            .ok_or_else(|| tonic::Status::unauthenticated("unknown passkey"))?;
+
+        let handle = credential
+            .get_user_unique_id()
+            .and_then(|h| Uuid::from_slice(h).ok());
+        let belongs = |user_id: Option<Uuid>| user_id.is_none_or(|id| id == row.user_id);
+        if !belongs(handle) || !belongs(ceremony.user_id) {
+            return Err(tonic::Status::unauthenticated(
+                "passkey does not belong to this user",
+            ));
+        }
+
+        // Passwordless ceremonies start before the user is known, so they
+        // are discoverable; the others were restricted to the user's
+        // passkeys when they began.
+        let mut passkey = stored_passkey(&row)?;
+        let result = match ceremony.user_id {
+            None => webauthn.finish_discoverable_authentication(
+                &credential,
+                ceremony.state()?,
+                &[DiscoverableKey::from(&passkey)],
+            ),
+            Some(_) => webauthn.finish_passkey_authentication(&credential, &ceremony.state()?),
+        }
+        .map_err(|e| {
+            tracing::warn!(passkey_id = %row.id, error = %e, "passkey assertion rejected");
+            tonic::Status::unauthenticated("passkey verification failed")
+        })?;
+
+        passkey.update_credential(&result);
+        self.service()
+            .touch_passkey(row.id, &passkey)
+            .await
+            .map_err(error::to_status)?;
+
+        Ok(row)
+    }
+
+    /// Start a 30-day session for `user_id` and issue its tokens.
+    async fn issue_login_tokens(&self, user_id: Uuid) -> Result<AuthTokens, tonic::Status> {
+        let (refresh_token, hash) = self
+            .state
+            .tokens()
+            .generate_refresh_token()
+            .map_err(error::to_status)?;
+
+        let expires = Utc::now()
+            .checked_add_days(Days::new(30))
+            .expect("to be able to add 30 days");
+
+        let session = self
+            .state
+            .user_service()
+            .create_session(user_id, &hash, Some(expires))
+            .await
+            .map_err(error::to_status)?;
+
+        let access_token = self
+            .state
+            .tokens()
+            .issue_access_token(
+                &user_id.to_string(),
+                &session.session_id.to_string(),
+                vec![],
+            )
+            .map_err(error::to_status)?;
+
+        Ok(AuthTokens {
+            access_token: access_token.as_string(),
+            refresh_token,
+            expires_in_seconds: expires.timestamp(),
+        })
+    }
+}
+
+/// A passkey ceremony read back from its oauth state.
+struct PendingCeremony {
+    state: serde_json::Value,
+    user_id: Option<Uuid>,
+    mfa_session_token: Option<String>,
+}
+
+impl PendingCeremony {
+    /// The webauthn-rs state saved when the ceremony began.
+    fn state<T: serde::de::DeserializeOwned>(&self) -> Result<T, tonic::Status> {
+        serde_json::from_value(self.state.clone())
+            .map_err(|_| tonic::Status::internal("invalid passkey ceremony data"))
+    }
 }
 
 #[async_trait::async_trait]
@@ -160,16 +389,28 @@ impl UsersService for UsersServer {
             }
         }
 
-        // Check MFA status — if the user has verified MFA, return a challenge
-        // instead of issuing tokens immediately.
+        self.enforce_sso(authenticated.user_id, None).await?;
+
+        // Check MFA status — if the user has verified TOTP or a passkey,
+        // return a challenge instead of issuing tokens immediately.
+        let mut mfa_types = Vec::new();
         let mfa = self
             .service()
             .get_mfa_for_user(authenticated.user_id)
             .await
             .map_err(error::to_status)?;
-        if let Some(mfa) = mfa
-            && mfa.verified
-        {
+        if mfa.is_some_and(|mfa| mfa.verified) {
+            mfa_types.push(MfaType::Totp as i32);
+        }
+        let passkeys = self
+            .service()
+            .list_passkeys(authenticated.user_id)
+            .await
+            .map_err(error::to_status)?;
+        if !passkeys.is_empty() {
+            mfa_types.push(MfaType::Passkey as i32);
+        }
+        if !mfa_types.is_empty() {
             let state_data = serde_json::json!({
                 "user_id": authenticated.user_id.to_string(),
                 "type": "mfa_login"
@@ -192,6 +433,7 @@ impl UsersService for UsersServer {
                 tokens: None,
                 mfa_required: true,
                 mfa_session_token: state_token,
+                mfa_types,
             }));
         }
 
@@ -231,6 +473,7 @@ impl UsersService for UsersServer {
             }),
             mfa_required: false,
             mfa_session_token: String::new(),
+            mfa_types: vec![],
         }))
     }
 
@@ -696,6 +939,14 @@ impl UsersService for UsersServer {
             .await
             .map_err(error::to_status)?
         {
+            // An organisation's IdP can assert any address, so org SSO
+            // never takes over an existing account. The user links it
+            // from their own session instead.
+            if provider == forest_grpc_interface::OAuthProvider::OauthProviderSso {
+                return Err(tonic::Status::already_exists(
+                    "an account with this email already exists; sign in and link single sign-on to it",
+                ));
+            }
             // Email already belongs to an existing user (e.g. registered with
             // password or another OAuth provider). Link this new provider and
             // log them in.
@@ -738,6 +989,13 @@ impl UsersService for UsersServer {
             (registered.user_id, true)
         };
 
+        // An SSO identity is namespaced by the organisation whose IdP
+        // vouched for it; every other provider counts as a non-SSO login.
+        let via_sso = (provider == forest_grpc_interface::OAuthProvider::OauthProviderSso)
+            .then(|| req.provider_user_id.split_once(':').map(|(org, _)| org))
+            .flatten();
+        self.enforce_sso(user_id, via_sso).await?;
+
         // Set profile picture from provider data if user doesn't already have one.
         // Uses a conditional update to avoid race conditions with concurrent logins.
         if let Some(ref data) = provider_data
@@ -811,12 +1069,21 @@ impl UsersService for UsersServer {
         // Self-or-SA: Forage's OAuth signup flow auto-links via its
         // service account; users can also link providers to their own
         // account.
-        let _actor = unauth
+        let actor = unauth
             .require_authenticated()?
             .require_user_self_or_service_account(user_id)?;
 
         let provider = forest_grpc_interface::OAuthProvider::try_from(req.provider)
             .map_err(|_| tonic::Status::invalid_argument("invalid provider"))?;
+        // SSO identities are only vouched for by forage after it has
+        // verified the IdP's response; a user cannot claim one directly.
+        if provider == forest_grpc_interface::OAuthProvider::OauthProviderSso
+            && !matches!(actor, Actor::ServiceAccount { .. })
+        {
+            return Err(tonic::Status::permission_denied(
+                "single sign-on identities can only be linked by a service account",
+            ));
+        }
 
         let provider_str = provider.as_str_name().to_lowercase();
         let provider_email = if req.provider_email.is_empty() {
@@ -1021,6 +1288,11 @@ impl UsersService for UsersServer {
         if mfa_type == MfaType::Unspecified {
             return Err(tonic::Status::invalid_argument("mfa_type is required"));
         }
+        if mfa_type == MfaType::Passkey {
+            return Err(tonic::Status::invalid_argument(
+                "passkeys are registered with BeginPasskeyRegistration",
+            ));
+        }
 
         let user_id: Uuid = claims
             .user_id
@@ -1181,13 +1453,15 @@ impl UsersService for UsersServer {
             .and_then(|s| s.parse().ok())
             .ok_or_else(|| tonic::Status::internal("invalid MFA session data"))?;
 
-        // Load MFA record and validate the TOTP code.
+        // Load MFA record and validate the TOTP code. Users whose only
+        // second factor is a passkey have no TOTP to check.
         let mfa = self
             .service()
             .get_mfa_for_user(user_id)
             .await
             .map_err(error::to_status)?
-            .ok_or_else(|| tonic::Status::internal("MFA not found for user"))?;
+            .filter(|mfa| mfa.verified)
+            .ok_or_else(|| tonic::Status::unauthenticated("TOTP is not enabled for this user"))?;
 
         let totp = TOTP::new(Algorithm::SHA1, 6, 1, 30, mfa.secret.clone(), None, String::new())
             .map_err(|e| tonic::Status::internal(format!("TOTP init error: {e}")))?;
@@ -1201,6 +1475,9 @@ impl UsersService for UsersServer {
             .await
             .map_err(error::to_status)?;
 
+        // Enforcement may have been switched on since the password step.
+        self.enforce_sso(user_id, None).await?;
+
         // Load the full user profile for the response.
         let profile = self
             .service()
@@ -1247,6 +1524,323 @@ impl UsersService for UsersServer {
         }))
     }
 
+    // ── Passkeys ─────────────────────────────────────────────────────
+
+    async fn begin_passkey_registration(
+        &self,
+        request: tonic::Request<BeginPasskeyRegistrationRequest>,
+    ) -> std::result::Result<tonic::Response<BeginPasskeyRegistrationResponse>, tonic::Status>
+    {
+        let user_id = claims_user_id(&request)?;
+        let webauthn = self.webauthn()?;
+
+        let profile = self
+            .service()
+            .get_user(user_id)
+            .await
+            .map_err(error::to_status)?
+            .ok_or_else(|| tonic::Status::not_found("user not found"))?;
+        let existing = self
+            .service()
+            .list_passkeys(user_id)
+            .await
+            .map_err(error::to_status)?
+            .into_iter()
+            .map(|row| row.credential_id.into())
+            .collect();
+
+        // The user handle is the forest user id, so passwordless
+        // assertions identify their account.
+        let (options, registration) = webauthn
+            .start_passkey_registration(
+                user_id,
+                &profile.username,
+                &profile.username,
+                Some(existing),
+            )
+            .map_err(|e| tonic::Status::internal(format!("passkey registration: {e}")))?;
+        let ceremony = self
+            .start_passkey_ceremony(
+                "passkey_registration",
+                Some(user_id),
+                None,
+                &registration,
+                &options.public_key,
+            )
+            .await?;
+
+        Ok(tonic::Response::new(BeginPasskeyRegistrationResponse {
+            ceremony: Some(ceremony),
+        }))
+    }
+
+    async fn finish_passkey_registration(
+        &self,
+        request: tonic::Request<FinishPasskeyRegistrationRequest>,
+    ) -> std::result::Result<tonic::Response<FinishPasskeyRegistrationResponse>, tonic::Status>
+    {
+        let user_id = claims_user_id(&request)?;
+        let req = request.into_inner();
+        let name = validate_passkey_name(&req.name)?;
+        let webauthn = self.webauthn()?;
+
+        let ceremony = self
+            .finish_passkey_ceremony(&req.ceremony_id, "passkey_registration")
+            .await?;
+        if ceremony.user_id != Some(user_id) {
+            return Err(tonic::Status::permission_denied(
+                "passkey ceremony belongs to another user",
+            ));
+        }
+
+        let credential: RegisterPublicKeyCredential = serde_json::from_str(&req.credential_json)
+            .map_err(|e| tonic::Status::invalid_argument(format!("malformed credential: {e}")))?;
+        let registered = webauthn
+            .finish_passkey_registration(&credential, &ceremony.state()?)
+            .map_err(|e| tonic::Status::invalid_argument(e.to_string()))?;
+
+        if self
+            .service()
+            .find_passkey(registered.cred_id().as_ref())
+            .await
+            .map_err(error::to_status)?
+            .is_some()
+        {
+            return Err(tonic::Status::already_exists("passkey is already registered"));
+        }
+
+        let passkey = self
+            .service()
+            .add_passkey(user_id, name, &registered)
+            .await
+            .map_err(error::to_status)?;
+
+        Ok(tonic::Response::new(FinishPasskeyRegistrationResponse {
+            passkey: Some(passkey_to_grpc(passkey)),
+        }))
+    }
+
+    async fn list_passkeys(
+        &self,
+        request: tonic::Request<ListPasskeysRequest>,
+    ) -> std::result::Result<tonic::Response<ListPasskeysResponse>, tonic::Status> {
+        let user_id = claims_user_id(&request)?;
+
+        let passkeys = self
+            .service()
+            .list_passkeys(user_id)
+            .await
+            .map_err(error::to_status)?;
+
+        Ok(tonic::Response::new(ListPasskeysResponse {
+            passkeys: passkeys.into_iter().map(passkey_to_grpc).collect(),
+        }))
+    }
+
+    async fn rename_passkey(
+        &self,
+        request: tonic::Request<RenamePasskeyRequest>,
+    ) -> std::result::Result<tonic::Response<RenamePasskeyResponse>, tonic::Status> {
+        let user_id = claims_user_id(&request)?;
+        let req = request.into_inner();
+        let passkey_id: Uuid = req
+            .passkey_id
+            .parse()
+            .map_err(|_| tonic::Status::invalid_argument("invalid passkey_id"))?;
+        let name = validate_passkey_name(&req.name)?;
+
+        let renamed = self
+            .service()
+            .rename_passkey(passkey_id, user_id, name)
+            .await
+            .map_err(error::to_status)?;
+        if renamed == 0 {
+            return Err(tonic::Status::not_found("passkey not found"));
+        }
+
+        Ok(tonic::Response::new(RenamePasskeyResponse {}))
+    }
+
+    async fn delete_passkey(
+        &self,
+        request: tonic::Request<DeletePasskeyRequest>,
+    ) -> std::result::Result<tonic::Response<DeletePasskeyResponse>, tonic::Status> {
+        let user_id = claims_user_id(&request)?;
+        let passkey_id: Uuid = request
+            .into_inner()
+            .passkey_id
+            .parse()
+            .map_err(|_| tonic::Status::invalid_argument("invalid passkey_id"))?;
+
+        let deleted = self
+            .service()
+            .delete_passkey(passkey_id, user_id)
+            .await
+            .map_err(error::to_status)?;
+        if deleted == 0 {
+            return Err(tonic::Status::not_found("passkey not found"));
+        }
+
+        Ok(tonic::Response::new(DeletePasskeyResponse {}))
+    }
+
+    async fn begin_passkey_login(
+        &self,
+        request: tonic::Request<BeginPasskeyLoginRequest>,
+    ) -> std::result::Result<tonic::Response<BeginPasskeyLoginResponse>, tonic::Status> {
+        let req = request.into_inner();
+        let webauthn = self.webauthn()?;
+
+        // Passwordless: any discoverable credential for this site. The
+        // user is identified by the assertion's user handle.
+        if req.mfa_session_token.is_empty() {
+            let (options, authentication) = webauthn
+                .start_discoverable_authentication()
+                .map_err(|e| tonic::Status::internal(format!("passkey login: {e}")))?;
+            let ceremony = self
+                .start_passkey_ceremony(
+                    "passkey_login",
+                    None,
+                    None,
+                    &authentication,
+                    &options.public_key,
+                )
+                .await?;
+            return Ok(tonic::Response::new(BeginPasskeyLoginResponse {
+                ceremony: Some(ceremony),
+            }));
+        }
+
+        // Second factor: peek rather than consume, so the user can still
+        // fall back to TOTP if they cancel the browser prompt.
+        let mfa_user_id = self
+            .service()
+            .peek_oauth_state(&req.mfa_session_token)
+            .await
+            .map_err(error::to_status)?
+            .and_then(|s| mfa_login_user_id(&s.data))
+            .ok_or_else(|| tonic::Status::unauthenticated("invalid or expired MFA session"))?;
+
+        let passkeys = self.stored_passkeys(mfa_user_id).await?;
+        if passkeys.is_empty() {
+            return Err(tonic::Status::failed_precondition("no passkeys registered"));
+        }
+
+        let (options, authentication) = webauthn
+            .start_passkey_authentication(&passkeys)
+            .map_err(|e| tonic::Status::internal(format!("passkey login: {e}")))?;
+        let ceremony = self
+            .start_passkey_ceremony(
+                "passkey_login",
+                Some(mfa_user_id),
+                Some(&req.mfa_session_token),
+                &authentication,
+                &options.public_key,
+            )
+            .await?;
+
+        Ok(tonic::Response::new(BeginPasskeyLoginResponse {
+            ceremony: Some(ceremony),
+        }))
+    }
+
+    async fn finish_passkey_login(
+        &self,
+        request: tonic::Request<FinishPasskeyLoginRequest>,
+    ) -> std::result::Result<tonic::Response<FinishPasskeyLoginResponse>, tonic::Status> {
+        let req = request.into_inner();
+        let webauthn = self.webauthn()?;
+
+        let ceremony = self
+            .finish_passkey_ceremony(&req.ceremony_id, "passkey_login")
+            .await?;
+
+        let passkey = self
+            .verify_passkey_assertion(&webauthn, &ceremony, &req.credential_json)
+            .await?;
+
+        // As a second factor the MFA session is single-use, exactly like
+        // VerifyLoginMfa. It is only spent once the assertion holds, so a
+        // failed prompt leaves the user free to try again.
+        if let Some(token) = &ceremony.mfa_session_token {
+            let mfa_user_id = self
+                .service()
+                .consume_oauth_state(token)
+                .await
+                .map_err(error::to_status)?
+                .and_then(|s| mfa_login_user_id(&s.data));
+            if mfa_user_id != Some(passkey.user_id) {
+                return Err(tonic::Status::unauthenticated(
+                    "invalid or expired MFA session",
+                ));
+            }
+        }
+
+        // Passwordless skips Login, so apply its email-verification gate.
+        if ceremony.mfa_session_token.is_none() && self.state.config.require_email_verification {
+            let has_verified = self
+                .service()
+                .user_has_verified_email(passkey.user_id)
+                .await
+                .map_err(error::to_status)?;
+            if !has_verified {
+                return Err(tonic::Status::failed_precondition("email_not_verified"));
+            }
+        }
+
+        self.enforce_sso(passkey.user_id, None).await?;
+
+        let profile = self
+            .service()
+            .get_user(passkey.user_id)
+            .await
+            .map_err(error::to_status)?
+            .ok_or_else(|| tonic::Status::internal("user not found"))?;
+        let tokens = self.issue_login_tokens(profile.user_id).await?;
+
+        Ok(tonic::Response::new(FinishPasskeyLoginResponse {
+            user: Some(profile_to_grpc_user(profile)),
+            tokens: Some(tokens),
+        }))
+    }
+
+    async fn begin_passkey_verification(
+        &self,
+        request: tonic::Request<BeginPasskeyVerificationRequest>,
+    ) -> std::result::Result<tonic::Response<BeginPasskeyVerificationResponse>, tonic::Status>
+    {
+        let _actor = crate::grpc::authorize::unauthenticated_actor(&request)
+            .require_authenticated()?
+            .require_service_account()?;
+
+        let req = request.into_inner();
+        let user_id = Uuid::parse_str(&req.user_id)
+            .map_err(|_| tonic::Status::invalid_argument("invalid user_id"))?;
+        let webauthn = self.webauthn()?;
+
+        let passkeys = self.stored_passkeys(user_id).await?;
+        if passkeys.is_empty() {
+            return Err(tonic::Status::failed_precondition("no passkeys registered"));
+        }
+
+        let (options, authentication) = webauthn
+            .start_passkey_authentication(&passkeys)
+            .map_err(|e| tonic::Status::internal(format!("passkey verification: {e}")))?;
+        let ceremony = self
+            .start_passkey_ceremony(
+                "passkey_verification",
+                Some(user_id),
+                None,
+                &authentication,
+                &options.public_key,
+            )
+            .await?;
+
+        Ok(tonic::Response::new(BeginPasskeyVerificationResponse {
+            ceremony: Some(ceremony),
+        }))
+    }
+
     // ── Device authorization grant (RFC 8628) ────────────────────────
 
     async fn initiate_device_login(
@@ -1359,6 +1953,22 @@ impl UsersService for UsersServer {
         let user_id = Uuid::parse_str(&req.user_id)
             .map_err(|_| tonic::Status::invalid_argument("invalid user_id"))?;
 
+        // Optional passkey confirmation: the assertion must come from one
+        // of the approving user's passkeys.
+        if !req.passkey_ceremony_id.is_empty() {
+            let webauthn = self.webauthn()?;
+            let ceremony = self
+                .finish_passkey_ceremony(&req.passkey_ceremony_id, "passkey_verification")
+                .await?;
+            if ceremony.user_id != Some(user_id) {
+                return Err(tonic::Status::permission_denied(
+                    "passkey ceremony belongs to another user",
+                ));
+            }
+            self.verify_passkey_assertion(&webauthn, &ceremony, &req.passkey_credential_json)
+                .await?;
+        }
+
         self.state
             .device_login_aggregate_service()
             .approve(
@@ -1444,6 +2054,58 @@ fn pat_info_to_grpc(info: crate::services::users::PersonalAccessTokenInfo) -> Pe
     }
 }
 
+/// The caller's user id, from the claims the auth layer attached.
+fn claims_user_id<T>(request: &tonic::Request<T>) -> Result<Uuid, tonic::Status> {
+    request
+        .extensions()
+        .get::<crate::tokens::AppClaims>()
+        .ok_or_else(|| tonic::Status::unauthenticated("missing auth"))?
+        .user_id
+        .parse()
+        .map_err(|_| tonic::Status::internal("invalid user_id in claims"))
+}
+
+/// The user a pending `mfa_login` state belongs to.
+fn mfa_login_user_id(data: &serde_json::Value) -> Option<Uuid> {
+    if data.get("type").and_then(|v| v.as_str()) != Some("mfa_login") {
+        return None;
+    }
+    data.get("user_id")
+        .and_then(|v| v.as_str())
+        .and_then(|s| s.parse().ok())
+}
+
+const MAX_PASSKEY_NAME_LEN: usize = 64;
+
+fn validate_passkey_name(name: &str) -> Result<&str, tonic::Status> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(tonic::Status::invalid_argument("name is required"));
+    }
+    if name.chars().count() > MAX_PASSKEY_NAME_LEN {
+        return Err(tonic::Status::invalid_argument(format!(
+            "name must be at most {MAX_PASSKEY_NAME_LEN} characters"
+        )));
+    }
+    Ok(name)
+}
+
+fn stored_passkey(row: &PasskeyRow) -> Result<webauthn::Passkey, tonic::Status> {
+    serde_json::from_value(row.passkey.clone()).map_err(|e| {
+        tracing::error!(passkey_id = %row.id, error = %e, "stored passkey is unreadable");
+        tonic::Status::internal("stored passkey is unreadable")
+    })
+}
+
+fn passkey_to_grpc(row: PasskeyRow) -> Passkey {
+    Passkey {
+        passkey_id: row.id.to_string(),
+        name: row.name,
+        created_at: Some(datetime_to_timestamp(row.created_at)),
+        last_used_at: row.last_used_at.map(datetime_to_timestamp),
+    }
+}
+
 fn provider_str_to_enum(provider: &str) -> forest_grpc_interface::OAuthProvider {
     match provider {
         "github" | "oauth_provider_github" => {
@@ -1458,6 +2120,7 @@ fn provider_str_to_enum(provider: &str) -> forest_grpc_interface::OAuthProvider
         "microsoft" | "oauth_provider_microsoft" => {
             forest_grpc_interface::OAuthProvider::OauthProviderMicrosoft
         }
+        "sso" | "oauth_provider_sso" => forest_grpc_interface::OAuthProvider::OauthProviderSso,
         _ => forest_grpc_interface::OAuthProvider::OauthProviderUnspecified,
     }
 }
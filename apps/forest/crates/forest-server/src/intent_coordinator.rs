@@ -7,13 +7,22 @@ use notmad::{Component, ComponentInfo, MadError};
 use tokio_util::sync::CancellationToken;
 use uuid::Uuid;
 
-use crate::services::release_event_store::{check_approval_policies, check_soak_time_policies};
+use crate::services::environment_protection;
+use crate::services::release_event_store::{
+    ReleaseEventStoreState, SupersededRelease, check_approval_policies, check_soak_time_policies,
+    supersede_queued,
+};
 use crate::services::release_pipeline::{
-    ApprovalStatus, PipelineStages, StageConfig, StageState, StageStates, StageStatus,
-    find_ready_stages, has_failed_dependency, init_stage_states, is_pipeline_complete,
+    ApprovalStatus, BatchTarget, PipelineStages, StageConfig, StageState,
+    StageStates, StageStatus, expand_batches, find_ready_stages, has_failed_dependency,
+    init_stage_states, is_pipeline_complete, settled_stage_status,
 };
 use crate::State;
 
+/// How long a batch's destinations get to report healthy before a batched
+/// rollout halts.
+const BATCH_HEALTH_TIMEOUT: chrono::Duration = chrono::Duration::minutes(30);
+
 /// The IntentCoordinator is the single saga orchestrator for pipeline release intents.
 ///
 /// It owns the full lifecycle of a pipeline: activating stages, completing wait stages,
@@ -176,6 +185,7 @@ async fn evaluate(state: &State, intent_id: Uuid) -> anyhow::Result<()> {
                 .entry(stage_id.clone())
                 .or_default()
                 .push(ReleaseRow {
+                    release_id: row.release_id.to_string(),
                     status: row.status.clone(),
                     error_message: row.error_message.clone(),
                 });
@@ -186,6 +196,7 @@ async fn evaluate(state: &State, intent_id: Uuid) -> anyhow::Result<()> {
     let now_str = now.to_rfc3339();
     let mut changed = false;
     let mut new_release_ids: Vec<Uuid> = Vec::new();
+    let mut superseded: Vec<SupersededRelease> = Vec::new();
     let mut earliest_timer: Option<chrono::DateTime<chrono::Utc>> = None;
 
     // Step 3a: Derive status of ACTIVE stages from their children
@@ -203,6 +214,37 @@ async fn evaluate(state: &State, intent_id: Uuid) -> anyhow::Result<()> {
         };
 
         match &stage_def.config {
+            StageConfig::Deploy { .. } if current.batches.is_some() => {
+                let releases = releases_by_stage
+                    .get(stage_id)
+                    .map(|v| v.as_slice())
+                    .unwrap_or(&[]);
+                let mut updated = current.clone();
+                let progress = advance_batches(
+                    &mut tx,
+                    &intent_id,
+                    &intent.project_id,
+                    &intent.artifact,
+                    stage_id,
+                    &mut updated,
+                    releases,
+                    now,
+                )
+                .await?;
+
+                if let Some(timer) = progress.retry_at {
+                    earliest_timer = Some(match earliest_timer {
+                        Some(existing) => existing.min(timer),
+                        None => timer,
+                    });
+                }
+                new_release_ids.extend(progress.new_release_ids);
+                superseded.extend(progress.superseded);
+                if updated != *current {
+                    stage_states.insert(stage_id.clone(), updated);
+                    changed = true;
+                }
+            }
             StageConfig::Deploy { .. } => {
                 let stage_releases = releases_by_stage.get(stage_id);
                 let releases: &[ReleaseRow] = stage_releases
@@ -223,16 +265,11 @@ async fn evaluate(state: &State, intent_id: Uuid) -> anyhow::Result<()> {
                     continue; // Still in progress
                 }
 
-                let all_succeeded = releases.iter().all(|r| r.status == "SUCCEEDED");
-
                 let mut updated = current.clone();
-                if all_succeeded {
-                    updated.status = StageStatus::Succeeded;
-                    updated.completed_at = Some(now_str.clone());
-                } else {
-                    updated.status = StageStatus::Failed;
-                    updated.completed_at = Some(now_str.clone());
-                    // Aggregate error messages from failed releases
+                updated.status = settled_stage_status(releases.iter().map(|r| r.status.as_str()));
+                updated.completed_at = Some(now_str.clone());
+                if updated.status != StageStatus::Succeeded {
+                    // Aggregate error messages from failed or cancelled releases
                     let errors: Vec<String> = releases
                         .iter()
                         .filter(|r| r.status != "SUCCEEDED")
@@ -294,12 +331,12 @@ async fn evaluate(state: &State, intent_id: Uuid) -> anyhow::Result<()> {
                     continue;
                 }
 
-                let all_succeeded = releases.iter().all(|r| r.status == "SUCCEEDED");
+                let settled = settled_stage_status(releases.iter().map(|r| r.status.as_str()));
                 let mut updated = current.clone();
 
-                if !all_succeeded {
-                    // Plan execution itself failed
-                    updated.status = StageStatus::Failed;
+                if settled != StageStatus::Succeeded {
+                    // Plan execution itself failed or was cancelled
+                    updated.status = settled;
                     updated.completed_at = Some(now_str.clone());
                     let errors: Vec<String> = releases
                         .iter()
@@ -359,11 +396,21 @@ async fn evaluate(state: &State, intent_id: Uuid) -> anyhow::Result<()> {
                 .get(stage_id)
                 .is_none_or(|s| s.status == StageStatus::Pending);
             if is_pending && has_failed_dependency(stage_id, &stages, &stage_states) {
+                let upstream_failed = stages[stage_id].depends_on.iter().any(|dep| {
+                    stage_states
+                        .get(dep)
+                        .is_some_and(|s| s.status == StageStatus::Failed)
+                });
+                let reason = if upstream_failed {
+                    "upstream stage failed"
+                } else {
+                    "upstream stage cancelled"
+                };
                 stage_states.insert(
                     stage_id.clone(),
                     StageState {
                         status: StageStatus::Cancelled,
-                        error_message: Some("upstream stage failed".into()),
+                        error_message: Some(reason.into()),
                         completed_at: Some(now_str.clone()),
                         ..StageState::pending()
                     },
@@ -386,7 +433,57 @@ async fn evaluate(state: &State, intent_id: Uuid) -> anyhow::Result<()> {
         };
 
         match &stage_def.config {
-            StageConfig::Deploy { environment } => {
+            StageConfig::Deploy {
+                environment,
+                batches,
+            } => {
+                // Environment protection: a disallowed branch or a missing
+                // release to the prior environment won't resolve by waiting,
+                // so the stage fails outright.
+                let protection = environment_protection::load_for_project(
+                    &mut tx,
+                    &intent.project_id,
+                    environment,
+                )
+                .await?;
+                let branch = if protection.allowed_branches.is_empty() {
+                    None
+                } else {
+                    environment_protection::artifact_branch(&mut tx, &intent.artifact).await?
+                };
+                let violation = match protection.check_branch(environment, branch.as_deref()) {
+                    Some(reason) => Some(reason),
+                    None => {
+                        environment_protection::check_prior_environment(
+                            &mut tx,
+                            &intent.project_id,
+                            &intent.artifact,
+                            environment,
+                            &protection,
+                        )
+                        .await?
+                    }
+                };
+                if let Some(reason) = violation {
+                    tracing::warn!(
+                        %intent_id,
+                        stage_id,
+                        environment,
+                        "coordinator: deploy stage failed — {reason}"
+                    );
+                    stage_states.insert(
+                        stage_id.clone(),
+                        StageState {
+                            status: StageStatus::Failed,
+                            error_message: Some(reason),
+                            completed_at: Some(now_str.clone()),
+                            ..StageState::pending()
+                        },
+                    );
+                    changed = true;
+                    continue;
+                }
+
                 // Check soak_time policies inside the transaction
                 let soak_blocked =
                     check_soak_time_policies(&mut tx, &intent.project_id, &intent.artifact, environment).await?;
@@ -414,12 +511,25 @@ async fn evaluate(state: &State, intent_id: Uuid) -> anyhow::Result<()> {
                     continue;
                 }
 
+                let protection_blocked = environment_protection::check_approvals(
+                    &mut tx,
+                    &intent.project_id,
+                    &intent_id,
+                    environment,
+                    &protection,
+                )
+                .await?;
+                if let Some(reason) = protection_blocked {
+                    tracing::debug!(%intent_id, stage_id, environment, "coordinator: deploy stage blocked by environment protection — {reason}");
+                    continue;
+                }
+
                 // Resolve environment -> destinations, scoped to the intent's
                 // owning organisation. Env names are globally non-unique so
                 // without this filter a `dev` deploy stage would fan out into
                 // every org's dev destinations.
                 let dest_recs = sqlx::query!(
-                    r#"SELECT d.id
+                    r#"SELECT d.id, d.name, d.metadata, d.queue_policy
                      FROM destinations d
                      JOIN environments e ON d.environment_id = e.id
                      JOIN projects p ON p.id = $2
@@ -456,37 +566,66 @@ async fn evaluate(state: &State, intent_id: Uuid) -> anyhow::Result<()> {
                     continue;
                 }
 
-                let mut release_ids = Vec::new();
-                for dest in &dest_recs {
-                    let rid = Uuid::now_v7();
-                    sqlx::query!(
-                        "INSERT INTO release_states (
-                            release_id, release_intent_id, project_id,
-                            destination_id, artifact_id, status, stage_id
-                        ) VALUES ($1, $2, $3, $4, $5, 'QUEUED', $6)",
-                        rid,
-                        intent_id,
-                        intent.project_id,
-                        dest.id,
-                        intent.artifact,
-                        stage_id.as_str(),
-                    )
-                    .execute(&mut *tx)
-                    .await?;
+                // A batched rollout starts with its first batch; the rest
+                // follow as each one succeeds (see `advance_batches`).
+                let mut batch_states = None;
+                let mut targets: Vec<_> = dest_recs.iter().collect();
+                if !batches.is_empty() {
+                    let batch_targets: Vec<BatchTarget> = dest_recs
+                        .iter()
+                        .map(|d| BatchTarget {
+                            name: d.name.clone(),
+                            metadata: serde_json::from_value(d.metadata.clone())
+                                .unwrap_or_default(),
+                        })
+                        .collect();
+                    match expand_batches(batches, &batch_targets) {
+                        Ok(expanded) => {
+                            targets.retain(|d| expanded[0].destinations.contains(&d.name));
+                            batch_states = Some(expanded);
+                        }
+                        Err(e) => {
+                            tracing::warn!(%intent_id, stage_id, environment, "coordinator: deploy stage failed — {e:#}");
+                            stage_states.insert(
+                                stage_id.clone(),
+                                StageState {
+                                    status: StageStatus::Failed,
+                                    error_message: Some(format!("{e:#}")),
+                                    completed_at: Some(now_str.clone()),
+                                    ..StageState::pending()
+                                },
+                            );
+                            changed = true;
+                            continue;
+                        }
+                    }
+                }
 
-                    sqlx::query!(
-                        "INSERT INTO release_events (
-                            release_id, event_type, payload
-                        ) VALUES ($1, 'release.requested', '{}')",
-                        rid,
+                let mut release_ids = Vec::new();
+                for dest in &targets {
+                    let rid = queue_release(
+                        &mut tx,
+                        &intent_id,
+                        &intent.project_id,
+                        &intent.artifact,
+                        stage_id,
+                        &dest.id,
+                        &dest.queue_policy,
+                        "deploy",
+                        &mut superseded,
                     )
-                    .execute(&mut *tx)
                     .await?;
 
                     release_ids.push(rid.to_string());
                     new_release_ids.push(rid);
                 }
 
+                if let Some(first) = batch_states.as_mut().and_then(|b| b.first_mut()) {
+                    first.status = StageStatus::Active;
+                    first.started_at = Some(now_str.clone());
+                    first.release_ids = release_ids.clone();
+                }
+
                 stage_states.insert(
                     stage_id.clone(),
                     StageState {
@@ -494,6 +633,7 @@ async fn evaluate(state: &State, intent_id: Uuid) -> anyhow::Result<()> {
                         queued_at: Some(now_str.clone()),
                         started_at: Some(now_str.clone()),
                         release_ids: Some(release_ids),
+                        batches: batch_states,
                         ..StageState::pending()
                     },
                 );
@@ -503,7 +643,7 @@ async fn evaluate(state: &State, intent_id: Uuid) -> anyhow::Result<()> {
                     %intent_id,
                     stage_id,
                     environment,
-                    dest_count = dest_recs.len(),
+                    dest_count = targets.len(),
                     "coordinator: activated deploy stage"
                 );
             }
@@ -561,7 +701,7 @@ async fn evaluate(state: &State, intent_id: Uuid) -> anyhow::Result<()> {
                 // Resolve environment -> destinations, scoped to the intent's
                 // owning organisation (see deploy-stage comment above).
                 let dest_recs = sqlx::query!(
-                    r#"SELECT d.id
+                    r#"SELECT d.id, d.queue_policy
                      FROM destinations d
                      JOIN environments e ON d.environment_id = e.id
                      JOIN projects p ON p.id = $2
@@ -599,29 +739,17 @@ async fn evaluate(state: &State, intent_id: Uuid) -> anyhow::Result<()> {
 
                 let mut release_ids = Vec::new();
                 for dest in &dest_recs {
-                    let rid = Uuid::now_v7();
-                    sqlx::query!(
-                        "INSERT INTO release_states (
-                            release_id, release_intent_id, project_id,
-                            destination_id, artifact_id, status, stage_id, mode
-                        ) VALUES ($1, $2, $3, $4, $5, 'QUEUED', $6, 'plan')",
-                        rid,
-                        intent_id,
-                        intent.project_id,
-                        dest.id,
-                        intent.artifact,
-                        stage_id.as_str(),
-                    )
-                    .execute(&mut *tx)
-                    .await?;
-
-                    sqlx::query!(
-                        "INSERT INTO release_events (
-                            release_id, event_type, payload
-                        ) VALUES ($1, 'release.requested', '{}')",
-                        rid,
+                    let rid = queue_release(
+                        &mut tx,
+                        &intent_id,
+                        &intent.project_id,
+                        &intent.artifact,
+                        stage_id,
+                        &dest.id,
+                        &dest.queue_policy,
+                        "plan",
+                        &mut superseded,
                     )
-                    .execute(&mut *tx)
                     .await?;
 
                     release_ids.push(rid.to_string());
@@ -757,6 +885,12 @@ async fn evaluate(state: &State, intent_id: Uuid) -> anyhow::Result<()> {
     tx.commit().await?;
 
     // Step 7: After-commit NATS signals
+    // Let intents whose queued releases were superseded react
+    state
+        .release_event_store()
+        .notify_superseded(&superseded)
+        .await;
+
     // Signal newly queued releases to the scheduler
     for rid in &new_release_ids {
         let _ = state
@@ -820,7 +954,312 @@ async fn evaluate(state: &State, intent_id: Uuid) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Queue a release of the intent's artifact to one destination, first
+/// superseding what is queued there if the destination asks for it.
+#[allow(clippy::too_many_arguments)]
+async fn queue_release(
+    tx: &mut sqlx::PgConnection,
+    intent_id: &Uuid,
+    project_id: &Uuid,
+    artifact: &Uuid,
+    stage_id: &str,
+    destination_id: &Uuid,
+    queue_policy: &str,
+    mode: &str,
+    superseded: &mut Vec<SupersededRelease>,
+) -> anyhow::Result<Uuid> {
+    if queue_policy == "supersede" {
+        superseded.extend(
+            supersede_queued(
+                &mut *tx,
+                project_id,
+                destination_id,
+                Some(mode),
+                "superseded by newer release",
+                None,
+            )
+            .await?,
+        );
+    }
+
+    let rid = Uuid::now_v7();
+    sqlx::query!(
+        "INSERT INTO release_states (
+            release_id, release_intent_id, project_id,
+            destination_id, artifact_id, status, stage_id, mode
+        ) VALUES ($1, $2, $3, $4, $5, 'QUEUED', $6, $7)",
+        rid,
+        intent_id,
+        project_id,
+        destination_id,
+        artifact,
+        stage_id,
+        mode,
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "INSERT INTO release_events (
+            release_id, event_type, payload
+        ) VALUES ($1, 'release.requested', '{}')",
+        rid,
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    Ok(rid)
+}
+
+#[derive(Default)]
+struct BatchProgress {
+    new_release_ids: Vec<Uuid>,
+    superseded: Vec<SupersededRelease>,
+    /// When to look again: a bake ending, or a health check to retry.
+    retry_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Move a batched deploy stage along: settle the active batch once its
+/// releases finish, then start the next one when the bake is over and the
+/// batch reports healthy. A failed, cancelled or unhealthy batch halts the
+/// rollout and the later batches never start.
+#[allow(clippy::too_many_arguments)]
+async fn advance_batches(
+    tx: &mut sqlx::PgConnection,
+    intent_id: &Uuid,
+    project_id: &Uuid,
+    artifact: &Uuid,
+    stage_id: &str,
+    stage: &mut StageState,
+    releases: &[ReleaseRow],
+    now: chrono::DateTime<chrono::Utc>,
+) -> anyhow::Result<BatchProgress> {
+    let now_str = now.to_rfc3339();
+    let mut progress = BatchProgress::default();
+    let Some(batches) = stage.batches.as_mut() else {
+        return Ok(progress);
+    };
+
+    let Some(idx) = batches
+        .iter()
+        .position(|b| b.status != StageStatus::Succeeded)
+    else {
+        stage.status = StageStatus::Succeeded;
+        stage.completed_at = Some(now_str);
+        return Ok(progress);
+    };
+
+    match batches[idx].status {
+        StageStatus::Active => {
+            let batch_releases: Vec<&ReleaseRow> = releases
+                .iter()
+                .filter(|r| batches[idx].release_ids.contains(&r.release_id))
+                .collect();
+            let all_terminal = !batch_releases.is_empty()
+                && batch_releases.iter().all(|r| {
+                    matches!(
+                        r.status.as_str(),
+                        "SUCCEEDED" | "FAILED" | "CANCELLED" | "TIMED_OUT"
+                    )
+                });
+            if !all_terminal {
+                return Ok(progress);
+            }
+
+            let settled = settled_stage_status(batch_releases.iter().map(|r| r.status.as_str()));
+            let count = batches.len();
+            let batch = &mut batches[idx];
+            batch.status = settled;
+            batch.completed_at = Some(now_str.clone());
+
+            if settled != StageStatus::Succeeded {
+                let errors: Vec<String> = batch_releases
+                    .iter()
+                    .filter(|r| r.status != "SUCCEEDED")
+                    .filter_map(|r| r.error_message.clone())
+                    .collect();
+                if !errors.is_empty() {
+                    batch.error_message = Some(errors.join("; "));
+                }
+                let detail = batch.error_message.clone();
+                let reason = format!(
+                    "batch {} of {count} {}",
+                    idx + 1,
+                    if settled == StageStatus::Failed { "failed" } else { "was cancelled" }
+                );
+                halt(stage, settled, reason, detail, &now_str);
+                return Ok(progress);
+            }
+
+            if batch.wait_seconds > 0 {
+                let wait_until = now + chrono::Duration::seconds(batch.wait_seconds);
+                batch.wait_until = Some(wait_until.to_rfc3339());
+                progress.retry_at = Some(wait_until);
+            }
+
+            if idx + 1 == count {
+                stage.status = StageStatus::Succeeded;
+                stage.completed_at = Some(now_str);
+            }
+        }
+        StageStatus::Pending => {
+            // Batch 0 starts with the stage, so a pending batch always
+            // follows one that succeeded.
+            let Some(prev) = idx.checked_sub(1).map(|i| batches[i].clone()) else {
+                return Ok(progress);
+            };
+
+            if let Some(wait_until) = prev
+                .wait_until
+                .as_deref()
+                .and_then(|w| chrono::DateTime::parse_from_rfc3339(w).ok())
+                .map(|w| w.with_timezone(&chrono::Utc))
+                && wait_until > now
+            {
+                progress.retry_at = Some(wait_until);
+                return Ok(progress);
+            }
+
+            if prev.health_check {
+                let statuses = sqlx::query!(
+                    "SELECT destination_name, status, message
+                     FROM release_health_observations
+                     WHERE release_intent_id = $1 AND destination_name = ANY($2)",
+                    intent_id,
+                    &prev.destinations,
+                )
+                .fetch_all(&mut *tx)
+                .await
+                .context("load batch health")?;
+
+                if let Some(unhealthy) = statuses.iter().find(|s| s.status == "UNHEALTHY") {
+                    let reason = format!(
+                        "batch {idx} of {} is unhealthy: {}: {}",
+                        batches.len(),
+                        unhealthy.destination_name,
+                        unhealthy.message
+                    );
+                    halt(stage, StageStatus::Failed, reason, None, &now_str);
+                    return Ok(progress);
+                }
+
+                let healthy = prev.destinations.iter().all(|name| {
+                    statuses
+                        .iter()
+                        .any(|s| &s.destination_name == name && s.status == "HEALTHY")
+                });
+                if !healthy {
+                    let since = prev
+                        .wait_until
+                        .as_deref()
+                        .or(prev.completed_at.as_deref())
+                        .and_then(|t| chrono::DateTime::parse_from_rfc3339(t).ok())
+                        .map(|t| t.with_timezone(&chrono::Utc))
+                        .unwrap_or(now);
+                    if now - since > BATCH_HEALTH_TIMEOUT {
+                        let reason = format!(
+                            "batch {idx} of {} did not report healthy within {} minutes",
+                            batches.len(),
+                            BATCH_HEALTH_TIMEOUT.num_minutes()
+                        );
+                        halt(stage, StageStatus::Failed, reason, None, &now_str);
+                    } else {
+                        progress.retry_at = Some(now + chrono::Duration::seconds(15));
+                    }
+                    return Ok(progress);
+                }
+            }
+
+            let dests = sqlx::query!(
+                r#"SELECT d.id, d.queue_policy
+                 FROM destinations d
+                 JOIN projects p ON p.id = $2
+                 WHERE d.organisation = p.organisation AND d.name = ANY($1)"#,
+                &batches[idx].destinations,
+                project_id,
+            )
+            .fetch_all(&mut *tx)
+            .await
+            .context("resolve destinations for batch")?;
+
+            if dests.is_empty() {
+                let reason = format!(
+                    "batch {} of {}: destinations no longer exist",
+                    idx + 1,
+                    batches.len()
+                );
+                halt(stage, StageStatus::Failed, reason, None, &now_str);
+                return Ok(progress);
+            }
+
+            let mut release_ids = Vec::new();
+            for dest in &dests {
+                let rid = queue_release(
+                    &mut *tx,
+                    intent_id,
+                    project_id,
+                    artifact,
+                    stage_id,
+                    &dest.id,
+                    &dest.queue_policy,
+                    "deploy",
+                    &mut progress.superseded,
+                )
+                .await?;
+                release_ids.push(rid.to_string());
+                progress.new_release_ids.push(rid);
+            }
+
+            tracing::info!(
+                %intent_id,
+                stage_id,
+                batch = idx + 1,
+                of = batches.len(),
+                dest_count = dests.len(),
+                "coordinator: started deploy batch"
+            );
+
+            let batch = &mut batches[idx];
+            batch.status = StageStatus::Active;
+            batch.started_at = Some(now_str);
+            batch.release_ids = release_ids.clone();
+            stage
+                .release_ids
+                .get_or_insert_with(Vec::new)
+                .extend(release_ids);
+        }
+        StageStatus::Failed | StageStatus::Cancelled | StageStatus::Succeeded => {}
+    }
+
+    Ok(progress)
+}
+
+/// End a batched rollout early: the stage takes `status`, and batches that
+/// never started are cancelled.
+fn halt(
+    stage: &mut StageState,
+    status: StageStatus,
+    reason: String,
+    detail: Option<String>,
+    now_str: &str,
+) {
+    if let Some(batches) = stage.batches.as_mut() {
+        for batch in batches.iter_mut().filter(|b| b.status == StageStatus::Pending) {
+            batch.status = StageStatus::Cancelled;
+            batch.error_message = Some("rollout halted".into());
+            batch.completed_at = Some(now_str.to_string());
+        }
+    }
+    stage.status = status;
+    stage.completed_at = Some(now_str.to_string());
+    stage.error_message = Some(match detail {
+        Some(detail) => format!("{reason}: {detail}"),
+        None => reason,
+    });
+}
+
 struct ReleaseRow {
+    release_id: String,
     status: String,
     error_message: Option<String>,
 }
@@ -18,8 +18,12 @@ pub mod destination_services;
 pub mod destinations;
 
 pub mod grpc;
+pub mod drift_detector;
 pub mod release_reaper;
+pub mod release_scheduler;
+pub mod retention_reaper;
 pub mod runner_manager;
+pub mod scim;
 pub mod scheduler;
 pub mod intent_coordinator;
 mod temp_dir;
@@ -27,4 +31,5 @@ mod temp_dir;
 pub mod object_store;
 pub mod oci_registry;
 pub mod tokens;
+pub mod webauthn;
 pub mod webhooks;
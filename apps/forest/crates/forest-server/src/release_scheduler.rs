@@ -0,0 +1,191 @@
+use std::time::Duration;
+
+use notmad::{Component, ComponentInfo, MadError};
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+use crate::{
+    State,
+    grpc::authorize,
+    services::{
+        notification_registry::{NotificationRegistry, NotificationRegistryState, ReleaseContext},
+        release_request::{self, ReleaseRequest, ReleaseRequestError},
+        release_schedule::{
+            ReleaseSchedule, ReleaseScheduleRegistry, ReleaseScheduleRegistryState, RunOutcome,
+            ScheduleActor,
+        },
+    },
+};
+
+/// Cron schedules have minute precision; check often enough that runs
+/// start within a few seconds of their time.
+const TICK: Duration = Duration::from_secs(15);
+
+/// Fires scheduled releases when they fall due. Releases go through the
+/// same checks as the `Release` RPC, as the schedule's creator.
+pub struct ReleaseScheduler {
+    state: State,
+    schedules: ReleaseScheduleRegistry,
+    notification_registry: NotificationRegistry,
+}
+
+impl ReleaseScheduler {
+    pub fn new(state: &State) -> Self {
+        Self {
+            state: state.clone(),
+            schedules: state.release_schedule_registry(),
+            notification_registry: state.notification_registry(),
+        }
+    }
+
+    /// Run every schedule that is due now.
+    pub async fn fire_due(&self) -> anyhow::Result<()> {
+        let now = chrono::Utc::now();
+        for schedule in self.schedules.due(now).await? {
+            // A schedule that missed runs, e.g. while the server was down,
+            // runs once and moves on to its next time after now.
+            if !self
+                .schedules
+                .claim(&schedule, schedule.following_run(now))
+                .await?
+            {
+                continue;
+            }
+
+            let (outcome, message, release_intent_id) = self.release(&schedule).await;
+            tracing::info!(
+                schedule_id = %schedule.id,
+                organisation = %schedule.organisation,
+                project = %schedule.project,
+                outcome = outcome.as_str(),
+                "scheduled release: {message}"
+            );
+
+            self.schedules
+                .record_run(&schedule.id, outcome, &message, release_intent_id)
+                .await?;
+
+            if matches!(outcome, RunOutcome::Blocked | RunOutcome::Failed) {
+                self.notify_not_released(&schedule, &message).await;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn release(&self, schedule: &ReleaseSchedule) -> (RunOutcome, String, Option<Uuid>) {
+        let actor = match self.schedules.actor(schedule).await {
+            Ok(ScheduleActor::Active(actor)) => actor,
+            Ok(ScheduleActor::Revoked(reason)) => return (RunOutcome::Blocked, reason, None),
+            Err(e) => return (RunOutcome::Failed, format!("{e:#}"), None),
+        };
+
+        // The creator may have left the organisation since.
+        if let Err(status) = authorize::require_org_access(
+            &self.state.db,
+            &actor,
+            &schedule.organisation,
+            authorize::OrgRole::Member,
+        )
+        .await
+        {
+            return (
+                RunOutcome::Blocked,
+                format!("schedule creator no longer has access: {}", status.message()),
+                None,
+            );
+        }
+
+        let artifact_id = match schedule.artifact_id {
+            Some(id) => id,
+            None => match self
+                .schedules
+                .latest_artifact(&schedule.project_id, schedule.branch.as_deref())
+                .await
+            {
+                Ok(Some(id)) => id,
+                Ok(None) => {
+                    let message = match &schedule.branch {
+                        Some(branch) => format!("no artifact on branch '{branch}' to release"),
+                        None => "no artifact to release".to_string(),
+                    };
+                    return (RunOutcome::Skipped, message, None);
+                }
+                Err(e) => return (RunOutcome::Failed, format!("{e:#}"), None),
+            },
+        };
+
+        match release_request::submit(
+            &self.state,
+            &actor,
+            Some(&schedule.organisation),
+            ReleaseRequest {
+                artifact_id,
+                destinations: schedule.destinations.clone(),
+                environments: schedule.environments.clone(),
+                force: schedule.force,
+                use_pipeline: schedule.use_pipeline,
+            },
+        )
+        .await
+        {
+            Ok(created) => (
+                RunOutcome::Released,
+                format!(
+                    "released artifact {artifact_id} to {} destination(s)",
+                    created.releases.len()
+                ),
+                Some(created.release_intent_id),
+            ),
+            Err(e @ ReleaseRequestError::Blocked(_)) => (RunOutcome::Blocked, e.to_string(), None),
+            Err(e @ ReleaseRequestError::Internal(_)) => (RunOutcome::Failed, e.to_string(), None),
+        }
+    }
+
+    async fn notify_not_released(&self, schedule: &ReleaseSchedule, message: &str) {
+        let organisation = &schedule.organisation;
+        let project = &schedule.project;
+        if let Err(e) = self
+            .notification_registry
+            .create_notification(
+                "SCHEDULE_FAILED",
+                &format!("Scheduled release not started: {organisation}/{project}"),
+                &format!("Schedule {} did not release: {message}", schedule.id),
+                organisation,
+                project,
+                &ReleaseContext {
+                    artifact_id: schedule.artifact_id.map(|id| id.to_string()),
+                    error_message: Some(message.to_string()),
+                    ..Default::default()
+                },
+            )
+            .await
+        {
+            tracing::warn!("failed to create scheduled release notification: {e:#}");
+        }
+    }
+}
+
+impl Component for ReleaseScheduler {
+    fn info(&self) -> ComponentInfo {
+        "forest-server/release-scheduler".into()
+    }
+
+    async fn run(&self, cancellation_token: CancellationToken) -> Result<(), MadError> {
+        let mut tick = tokio::time::interval(TICK);
+        tick.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+        loop {
+            tokio::select! {
+                _ = cancellation_token.cancelled() => break,
+                _ = tick.tick() => {
+                    if let Err(e) = self.fire_due().await {
+                        tracing::error!("release scheduler error: {e:#}");
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
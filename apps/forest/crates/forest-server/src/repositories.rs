@@ -4,4 +4,6 @@ pub mod files;
 pub mod staging;
 
 pub mod organisations;
+pub mod passkeys;
+pub mod scim;
 pub mod users;
@@ -65,6 +65,8 @@ fn constraint_message_unique(constraint: Option<&str>) -> String {
         Some("identities_user_provider_key") => {
             "user already has an account linked for this provider".to_string()
         }
+        Some("scim_users_org_user_name_idx") => "userName already provisioned".to_string(),
+        Some("scim_groups_org_display_name_idx") => "group displayName already exists".to_string(),
         Some(name) => format!("resource already exists ({name})"),
         None => "resource already exists".to_string(),
     }
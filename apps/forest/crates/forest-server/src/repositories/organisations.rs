@@ -240,6 +240,32 @@ impl OrganisationRepository {
         Ok(())
     }
 
+    /// Whether `user_id` is the organisation's only owner, so removing them
+    /// would leave it without one.
+    pub async fn is_last_owner(
+        &self,
+        db: impl PgExecutor<'_>,
+        organisation_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<bool, DbError> {
+        let last = sqlx::query_scalar!(
+            r#"
+            SELECT NOT EXISTS (
+                SELECT 1 FROM organisation_members
+                WHERE organisation_id = $1 AND user_id <> $2 AND role = 'owner'
+            ) AS "last!"
+            FROM organisation_members
+            WHERE organisation_id = $1 AND user_id = $2 AND role = 'owner'
+            "#,
+            organisation_id,
+            user_id,
+        )
+        .fetch_optional(db)
+        .await?;
+
+        Ok(last.unwrap_or(false))
+    }
+
     pub async fn update_member_role(
         &self,
         db: impl PgExecutor<'_>,
@@ -331,6 +357,74 @@ impl OrganisationRepository {
 
         Ok(rows)
     }
+
+    /// Returns whether the setting changed.
+    pub async fn set_sso_enforced(
+        &self,
+        db: impl PgExecutor<'_>,
+        organisation_id: Uuid,
+        enforced: bool,
+    ) -> Result<bool, DbError> {
+        let result = sqlx::query!(
+            "UPDATE organisations SET sso_enforced = $2, updated_at = now()
+             WHERE id = $1 AND sso_enforced <> $2",
+            organisation_id,
+            enforced,
+        )
+        .execute(db)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Revoke every open session of the organisation's members.
+    pub async fn revoke_member_sessions(
+        &self,
+        db: impl PgExecutor<'_>,
+        organisation_id: Uuid,
+    ) -> Result<(), DbError> {
+        sqlx::query!(
+            r#"
+            UPDATE sessions
+            SET revoked_at = now(), updated_at = now()
+            WHERE revoked_at IS NULL
+              AND user_id IN (SELECT user_id FROM organisation_members WHERE organisation_id = $1)
+            "#,
+            organisation_id,
+        )
+        .execute(db)
+        .await?;
+
+        Ok(())
+    }
+
+    /// First organisation (by name) the user belongs to that enforces
+    /// single sign-on, ignoring `except`.
+    pub async fn sso_enforcing_organisation(
+        &self,
+        db: impl PgExecutor<'_>,
+        user_id: Uuid,
+        except: Option<&str>,
+    ) -> anyhow::Result<Option<String>> {
+        let name = sqlx::query_scalar!(
+            r#"
+            SELECT o.name
+            FROM organisations o
+            JOIN organisation_members om ON om.organisation_id = o.id
+            WHERE om.user_id = $1
+              AND o.sso_enforced
+              AND ($2::text IS NULL OR o.name <> $2)
+            ORDER BY o.name ASC
+            LIMIT 1
+            "#,
+            user_id,
+            except,
+        )
+        .fetch_optional(db)
+        .await?;
+
+        Ok(name)
+    }
 }
 
 // -- State trait --------------------------------------------------------------
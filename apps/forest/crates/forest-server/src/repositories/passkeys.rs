@@ -0,0 +1,182 @@
+use sqlx::{PgExecutor, PgPool};
+use uuid::Uuid;
+
+use super::error::DbError;
+use crate::state::State;
+
+pub struct PasskeyRepository {
+    db: PgPool,
+}
+
+// ─── Row types ───────────────────────────────────────────────────────
+
+pub struct PasskeyRow {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub credential_id: Vec<u8>,
+    pub passkey: serde_json::Value,
+    pub last_used_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+// ─── Repository implementation ───────────────────────────────────────
+
+impl PasskeyRepository {
+    pub fn pool(&self) -> &PgPool {
+        &self.db
+    }
+
+    pub async fn create_passkey(
+        &self,
+        db: impl PgExecutor<'_>,
+        id: Uuid,
+        user_id: Uuid,
+        name: &str,
+        credential_id: &[u8],
+        passkey: &serde_json::Value,
+    ) -> Result<PasskeyRow, DbError> {
+        let row = sqlx::query_as!(
+            PasskeyRow,
+            r#"
+            INSERT INTO user_passkeys (id, user_id, name, credential_id, passkey)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING id, user_id, name, credential_id, passkey,
+                      last_used_at, created_at, updated_at
+            "#,
+            id,
+            user_id,
+            name,
+            credential_id,
+            passkey,
+        )
+        .fetch_one(db)
+        .await?;
+
+        Ok(row)
+    }
+
+    pub async fn list_passkeys(
+        &self,
+        db: impl PgExecutor<'_>,
+        user_id: Uuid,
+    ) -> anyhow::Result<Vec<PasskeyRow>> {
+        let rows = sqlx::query_as!(
+            PasskeyRow,
+            r#"
+            SELECT id, user_id, name, credential_id, passkey,
+                   last_used_at, created_at, updated_at
+            FROM user_passkeys
+            WHERE user_id = $1
+            ORDER BY created_at
+            "#,
+            user_id,
+        )
+        .fetch_all(db)
+        .await?;
+
+        Ok(rows)
+    }
+
+    pub async fn get_passkey_by_credential_id(
+        &self,
+        db: impl PgExecutor<'_>,
+        credential_id: &[u8],
+    ) -> anyhow::Result<Option<PasskeyRow>> {
+        let row = sqlx::query_as!(
+            PasskeyRow,
+            r#"
+            SELECT id, user_id, name, credential_id, passkey,
+                   last_used_at, created_at, updated_at
+            FROM user_passkeys
+            WHERE credential_id = $1
+            "#,
+            credential_id,
+        )
+        .fetch_optional(db)
+        .await?;
+
+        Ok(row)
+    }
+
+    /// User-scoped rename. Returns the number of rows updated (0 if the
+    /// passkey doesn't exist or belongs to someone else).
+    pub async fn rename_passkey(
+        &self,
+        db: impl PgExecutor<'_>,
+        id: Uuid,
+        user_id: Uuid,
+        name: &str,
+    ) -> Result<u64, DbError> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE user_passkeys
+            SET name = $3, updated_at = now()
+            WHERE id = $1 AND user_id = $2
+            "#,
+            id,
+            user_id,
+            name,
+        )
+        .execute(db)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Record a successful assertion: store the credential with its new
+    /// signature counter.
+    pub async fn touch_passkey(
+        &self,
+        db: impl PgExecutor<'_>,
+        id: Uuid,
+        passkey: &serde_json::Value,
+    ) -> Result<(), DbError> {
+        sqlx::query!(
+            r#"
+            UPDATE user_passkeys
+            SET passkey = $2, last_used_at = now(), updated_at = now()
+            WHERE id = $1
+            "#,
+            id,
+            passkey,
+        )
+        .execute(db)
+        .await?;
+
+        Ok(())
+    }
+
+    /// User-scoped delete. Returns the number of rows deleted.
+    pub async fn delete_passkey(
+        &self,
+        db: impl PgExecutor<'_>,
+        id: Uuid,
+        user_id: Uuid,
+    ) -> Result<u64, DbError> {
+        let result = sqlx::query!(
+            "DELETE FROM user_passkeys WHERE id = $1 AND user_id = $2",
+            id,
+            user_id,
+        )
+        .execute(db)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+// ─── State trait ─────────────────────────────────────────────────────
+
+pub trait PasskeyRepositoryState {
+    fn passkey_repository(&self) -> PasskeyRepository;
+}
+
+impl PasskeyRepositoryState for State {
+    fn passkey_repository(&self) -> PasskeyRepository {
+        PasskeyRepository {
+            db: self.db.clone(),
+        }
+    }
+}
@@ -0,0 +1,667 @@
+use sqlx::{PgExecutor, PgPool};
+use uuid::Uuid;
+
+use super::error::DbError;
+use crate::state::State;
+
+pub struct ScimRepository {
+    db: PgPool,
+}
+
+// ─── Row types ───────────────────────────────────────────────────────
+
+/// An app token allowed to provision an organisation.
+pub struct ScimTokenRow {
+    pub app_id: Uuid,
+    pub organisation_id: Uuid,
+    pub organisation: String,
+}
+
+pub struct ScimUserRow {
+    pub organisation_id: Uuid,
+    pub user_id: Uuid,
+    pub user_name: String,
+    pub external_id: Option<String>,
+    pub display_name: Option<String>,
+    pub active: bool,
+    /// Waiting for the account owner to accept; not a member until then.
+    pub pending: bool,
+    /// The user's first registered email.
+    pub email: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// An organisation whose IdP provisioned an existing account, waiting for
+/// the account owner to accept.
+pub struct ScimInviteRow {
+    pub organisation_id: Uuid,
+    pub organisation: String,
+    pub user_name: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+pub struct ScimGroupRow {
+    pub id: Uuid,
+    pub organisation_id: Uuid,
+    pub display_name: String,
+    pub external_id: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+pub struct ScimGroupMemberRow {
+    pub user_id: Uuid,
+    pub user_name: String,
+}
+
+pub struct ScimGroupRoleRow {
+    pub group_name: String,
+    pub role: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+// ─── Repository implementation ───────────────────────────────────────
+
+impl ScimRepository {
+    pub fn pool(&self) -> &PgPool {
+        &self.db
+    }
+
+    /// Resolve a bearer token to the organisation it may provision. Only
+    /// live tokens of unsuspended apps holding the `scim` permission
+    /// qualify.
+    pub async fn resolve_token(
+        &self,
+        db: impl PgExecutor<'_>,
+        token_hash: &[u8],
+    ) -> anyhow::Result<Option<ScimTokenRow>> {
+        let row = sqlx::query_as!(
+            ScimTokenRow,
+            r#"
+            SELECT a.id AS app_id, a.organisation_id, o.name AS organisation
+            FROM app_tokens t
+            JOIN apps a ON a.id = t.app_id
+            JOIN organisations o ON o.id = a.organisation_id
+            WHERE t.token_hash = $1
+              AND t.revoked = false
+              AND a.suspended = false
+              AND (t.expires_at IS NULL OR t.expires_at > now())
+              AND a.permissions @> '["scim"]'::jsonb
+            "#,
+            token_hash,
+        )
+        .fetch_optional(db)
+        .await?;
+
+        Ok(row)
+    }
+
+    pub async fn touch_token(
+        &self,
+        db: impl PgExecutor<'_>,
+        token_hash: &[u8],
+    ) -> anyhow::Result<()> {
+        sqlx::query!(
+            "UPDATE app_tokens SET last_used = now() WHERE token_hash = $1",
+            token_hash,
+        )
+        .execute(db)
+        .await?;
+
+        Ok(())
+    }
+
+    // -- Users ----------------------------------------------------------------
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn insert_user(
+        &self,
+        db: impl PgExecutor<'_>,
+        organisation_id: Uuid,
+        user_id: Uuid,
+        user_name: &str,
+        external_id: Option<&str>,
+        display_name: Option<&str>,
+        active: bool,
+        pending: bool,
+    ) -> Result<(), DbError> {
+        sqlx::query!(
+            r#"
+            INSERT INTO scim_users (organisation_id, user_id, user_name, external_id, display_name, active, pending)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            "#,
+            organisation_id,
+            user_id,
+            user_name,
+            external_id,
+            display_name,
+            active,
+            pending,
+        )
+        .execute(db)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_user(
+        &self,
+        db: impl PgExecutor<'_>,
+        organisation_id: Uuid,
+        user_id: Uuid,
+    ) -> anyhow::Result<Option<ScimUserRow>> {
+        let row = sqlx::query_as!(
+            ScimUserRow,
+            r#"
+            SELECT s.organisation_id, s.user_id, s.user_name, s.external_id, s.display_name,
+                   s.active, s.pending,
+                   (SELECT e.email FROM user_emails e WHERE e.user_id = s.user_id
+                    ORDER BY e.created_at LIMIT 1) AS "email?",
+                   s.created_at, s.updated_at
+            FROM scim_users s
+            WHERE s.organisation_id = $1 AND s.user_id = $2
+            "#,
+            organisation_id,
+            user_id,
+        )
+        .fetch_optional(db)
+        .await?;
+
+        Ok(row)
+    }
+
+    /// Page through an organisation's SCIM users, optionally narrowed to
+    /// one userName (case-insensitive, as SCIM requires for userName).
+    pub async fn list_users(
+        &self,
+        db: impl PgExecutor<'_>,
+        organisation_id: Uuid,
+        user_name: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> anyhow::Result<Vec<ScimUserRow>> {
+        let rows = sqlx::query_as!(
+            ScimUserRow,
+            r#"
+            SELECT s.organisation_id, s.user_id, s.user_name, s.external_id, s.display_name,
+                   s.active, s.pending,
+                   (SELECT e.email FROM user_emails e WHERE e.user_id = s.user_id
+                    ORDER BY e.created_at LIMIT 1) AS "email?",
+                   s.created_at, s.updated_at
+            FROM scim_users s
+            WHERE s.organisation_id = $1
+              AND ($2::text IS NULL OR lower(s.user_name) = lower($2))
+            ORDER BY s.created_at, s.user_id
+            LIMIT $3 OFFSET $4
+            "#,
+            organisation_id,
+            user_name,
+            limit,
+            offset,
+        )
+        .fetch_all(db)
+        .await?;
+
+        Ok(rows)
+    }
+
+    pub async fn count_users(
+        &self,
+        db: impl PgExecutor<'_>,
+        organisation_id: Uuid,
+        user_name: Option<&str>,
+    ) -> anyhow::Result<i64> {
+        let count = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) AS "count!"
+            FROM scim_users
+            WHERE organisation_id = $1
+              AND ($2::text IS NULL OR lower(user_name) = lower($2))
+            "#,
+            organisation_id,
+            user_name,
+        )
+        .fetch_one(db)
+        .await?;
+
+        Ok(count)
+    }
+
+    pub async fn update_user(
+        &self,
+        db: impl PgExecutor<'_>,
+        organisation_id: Uuid,
+        user_id: Uuid,
+        user_name: &str,
+        external_id: Option<&str>,
+        display_name: Option<&str>,
+        active: bool,
+    ) -> Result<u64, DbError> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE scim_users
+            SET user_name = $3, external_id = $4, display_name = $5, active = $6,
+                updated_at = now()
+            WHERE organisation_id = $1 AND user_id = $2
+            "#,
+            organisation_id,
+            user_id,
+            user_name,
+            external_id,
+            display_name,
+            active,
+        )
+        .execute(db)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    pub async fn delete_user(
+        &self,
+        db: impl PgExecutor<'_>,
+        organisation_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<u64, DbError> {
+        let result = sqlx::query!(
+            "DELETE FROM scim_users WHERE organisation_id = $1 AND user_id = $2",
+            organisation_id,
+            user_id,
+        )
+        .execute(db)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Accept a pending invite. Returns whether the SCIM user is active, or
+    /// `None` if there was no pending invite.
+    pub async fn accept_invite(
+        &self,
+        db: impl PgExecutor<'_>,
+        organisation_id: Uuid,
+        user_id: Uuid,
+    ) -> anyhow::Result<Option<bool>> {
+        let active = sqlx::query_scalar!(
+            r#"
+            UPDATE scim_users SET pending = false, updated_at = now()
+            WHERE organisation_id = $1 AND user_id = $2 AND pending
+            RETURNING active
+            "#,
+            organisation_id,
+            user_id,
+        )
+        .fetch_optional(db)
+        .await?;
+
+        Ok(active)
+    }
+
+    pub async fn list_invites(
+        &self,
+        db: impl PgExecutor<'_>,
+        user_id: Uuid,
+    ) -> anyhow::Result<Vec<ScimInviteRow>> {
+        let rows = sqlx::query_as!(
+            ScimInviteRow,
+            r#"
+            SELECT s.organisation_id, o.name AS organisation, s.user_name, s.created_at
+            FROM scim_users s
+            JOIN organisations o ON o.id = s.organisation_id
+            WHERE s.user_id = $1 AND s.pending
+            ORDER BY s.created_at
+            "#,
+            user_id,
+        )
+        .fetch_all(db)
+        .await?;
+
+        Ok(rows)
+    }
+
+    pub async fn list_active_user_ids(
+        &self,
+        db: impl PgExecutor<'_>,
+        organisation_id: Uuid,
+    ) -> anyhow::Result<Vec<Uuid>> {
+        let ids = sqlx::query_scalar!(
+            "SELECT user_id FROM scim_users WHERE organisation_id = $1 AND active",
+            organisation_id,
+        )
+        .fetch_all(db)
+        .await?;
+
+        Ok(ids)
+    }
+
+    // -- Groups ---------------------------------------------------------------
+
+    pub async fn insert_group(
+        &self,
+        db: impl PgExecutor<'_>,
+        id: Uuid,
+        organisation_id: Uuid,
+        display_name: &str,
+        external_id: Option<&str>,
+    ) -> Result<ScimGroupRow, DbError> {
+        let row = sqlx::query_as!(
+            ScimGroupRow,
+            r#"
+            INSERT INTO scim_groups (id, organisation_id, display_name, external_id)
+            VALUES ($1, $2, $3, $4)
+            RETURNING id, organisation_id, display_name, external_id, created_at, updated_at
+            "#,
+            id,
+            organisation_id,
+            display_name,
+            external_id,
+        )
+        .fetch_one(db)
+        .await?;
+
+        Ok(row)
+    }
+
+    pub async fn get_group(
+        &self,
+        db: impl PgExecutor<'_>,
+        organisation_id: Uuid,
+        id: Uuid,
+    ) -> anyhow::Result<Option<ScimGroupRow>> {
+        let row = sqlx::query_as!(
+            ScimGroupRow,
+            r#"
+            SELECT id, organisation_id, display_name, external_id, created_at, updated_at
+            FROM scim_groups
+            WHERE organisation_id = $1 AND id = $2
+            "#,
+            organisation_id,
+            id,
+        )
+        .fetch_optional(db)
+        .await?;
+
+        Ok(row)
+    }
+
+    pub async fn list_groups(
+        &self,
+        db: impl PgExecutor<'_>,
+        organisation_id: Uuid,
+        display_name: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> anyhow::Result<Vec<ScimGroupRow>> {
+        let rows = sqlx::query_as!(
+            ScimGroupRow,
+            r#"
+            SELECT id, organisation_id, display_name, external_id, created_at, updated_at
+            FROM scim_groups
+            WHERE organisation_id = $1
+              AND ($2::text IS NULL OR lower(display_name) = lower($2))
+            ORDER BY created_at, id
+            LIMIT $3 OFFSET $4
+            "#,
+            organisation_id,
+            display_name,
+            limit,
+            offset,
+        )
+        .fetch_all(db)
+        .await?;
+
+        Ok(rows)
+    }
+
+    pub async fn count_groups(
+        &self,
+        db: impl PgExecutor<'_>,
+        organisation_id: Uuid,
+        display_name: Option<&str>,
+    ) -> anyhow::Result<i64> {
+        let count = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) AS "count!"
+            FROM scim_groups
+            WHERE organisation_id = $1
+              AND ($2::text IS NULL OR lower(display_name) = lower($2))
+            "#,
+            organisation_id,
+            display_name,
+        )
+        .fetch_one(db)
+        .await?;
+
+        Ok(count)
+    }
+
+    pub async fn update_group(
+        &self,
+        db: impl PgExecutor<'_>,
+        organisation_id: Uuid,
+        id: Uuid,
+        display_name: &str,
+        external_id: Option<&str>,
+    ) -> Result<u64, DbError> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE scim_groups
+            SET display_name = $3, external_id = $4, updated_at = now()
+            WHERE organisation_id = $1 AND id = $2
+            "#,
+            organisation_id,
+            id,
+            display_name,
+            external_id,
+        )
+        .execute(db)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    pub async fn delete_group(
+        &self,
+        db: impl PgExecutor<'_>,
+        organisation_id: Uuid,
+        id: Uuid,
+    ) -> Result<u64, DbError> {
+        let result = sqlx::query!(
+            "DELETE FROM scim_groups WHERE organisation_id = $1 AND id = $2",
+            organisation_id,
+            id,
+        )
+        .execute(db)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    pub async fn list_group_members(
+        &self,
+        db: impl PgExecutor<'_>,
+        group_id: Uuid,
+    ) -> anyhow::Result<Vec<ScimGroupMemberRow>> {
+        let rows = sqlx::query_as!(
+            ScimGroupMemberRow,
+            r#"
+            SELECT m.user_id, s.user_name
+            FROM scim_group_members m
+            JOIN scim_groups g ON g.id = m.group_id
+            JOIN scim_users s ON s.organisation_id = g.organisation_id AND s.user_id = m.user_id
+            WHERE m.group_id = $1
+            ORDER BY s.user_name
+            "#,
+            group_id,
+        )
+        .fetch_all(db)
+        .await?;
+
+        Ok(rows)
+    }
+
+    pub async fn add_group_member(
+        &self,
+        db: impl PgExecutor<'_>,
+        group_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), DbError> {
+        sqlx::query!(
+            r#"
+            INSERT INTO scim_group_members (group_id, user_id)
+            VALUES ($1, $2)
+            ON CONFLICT DO NOTHING
+            "#,
+            group_id,
+            user_id,
+        )
+        .execute(db)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn remove_group_member(
+        &self,
+        db: impl PgExecutor<'_>,
+        group_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), DbError> {
+        sqlx::query!(
+            "DELETE FROM scim_group_members WHERE group_id = $1 AND user_id = $2",
+            group_id,
+            user_id,
+        )
+        .execute(db)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Drop a user from every group of the organisation (SCIM delete).
+    pub async fn remove_user_from_groups(
+        &self,
+        db: impl PgExecutor<'_>,
+        organisation_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), DbError> {
+        sqlx::query!(
+            r#"
+            DELETE FROM scim_group_members m
+            USING scim_groups g
+            WHERE g.id = m.group_id AND g.organisation_id = $1 AND m.user_id = $2
+            "#,
+            organisation_id,
+            user_id,
+        )
+        .execute(db)
+        .await?;
+
+        Ok(())
+    }
+
+    // -- Group → role mappings ------------------------------------------------
+
+    pub async fn set_group_role(
+        &self,
+        db: impl PgExecutor<'_>,
+        organisation_id: Uuid,
+        group_name: &str,
+        role: &str,
+    ) -> Result<(), DbError> {
+        sqlx::query!(
+            r#"
+            INSERT INTO scim_group_roles (organisation_id, group_name, role)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (organisation_id, lower(group_name))
+            DO UPDATE SET group_name = EXCLUDED.group_name, role = EXCLUDED.role, updated_at = now()
+            "#,
+            organisation_id,
+            group_name,
+            role,
+        )
+        .execute(db)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete_group_role(
+        &self,
+        db: impl PgExecutor<'_>,
+        organisation_id: Uuid,
+        group_name: &str,
+    ) -> Result<u64, DbError> {
+        let result = sqlx::query!(
+            "DELETE FROM scim_group_roles WHERE organisation_id = $1 AND lower(group_name) = lower($2)",
+            organisation_id,
+            group_name,
+        )
+        .execute(db)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    pub async fn list_group_roles(
+        &self,
+        db: impl PgExecutor<'_>,
+        organisation_id: Uuid,
+    ) -> anyhow::Result<Vec<ScimGroupRoleRow>> {
+        let rows = sqlx::query_as!(
+            ScimGroupRoleRow,
+            r#"
+            SELECT group_name, role, created_at
+            FROM scim_group_roles
+            WHERE organisation_id = $1
+            ORDER BY lower(group_name)
+            "#,
+            organisation_id,
+        )
+        .fetch_all(db)
+        .await?;
+
+        Ok(rows)
+    }
+
+    /// Roles granted to a user through the mapped groups they belong to.
+    pub async fn mapped_roles_for_user(
+        &self,
+        db: impl PgExecutor<'_>,
+        organisation_id: Uuid,
+        user_id: Uuid,
+    ) -> anyhow::Result<Vec<String>> {
+        let roles = sqlx::query_scalar!(
+            r#"
+            SELECT r.role
+            FROM scim_group_members m
+            JOIN scim_groups g ON g.id = m.group_id
+            JOIN scim_group_roles r
+              ON r.organisation_id = g.organisation_id
+             AND lower(r.group_name) = lower(g.display_name)
+            WHERE g.organisation_id = $1 AND m.user_id = $2
+            "#,
+            organisation_id,
+            user_id,
+        )
+        .fetch_all(db)
+        .await?;
+
+        Ok(roles)
+    }
+}
+
+// ─── State trait ─────────────────────────────────────────────────────
+
+pub trait ScimRepositoryState {
+    fn scim_repository(&self) -> ScimRepository;
+}
+
+impl ScimRepositoryState for State {
+    fn scim_repository(&self) -> ScimRepository {
+        ScimRepository {
+            db: self.db.clone(),
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use std::time::Duration;
+
+use notmad::{Component, ComponentInfo, MadError};
+use tokio_util::sync::CancellationToken;
+
+use crate::{
+    State,
+    services::{
+        event_bus::{EventBus, EventBusState, EventPayload},
+        retention::{RetentionRegistry, RetentionRegistryState},
+    },
+};
+
+/// Applies every organisation's retention policy, and purges uploads that
+/// were started but never committed.
+pub struct RetentionReaper {
+    retention_registry: RetentionRegistry,
+    event_bus: EventBus,
+    interval: Duration,
+    staging_retention: Duration,
+}
+
+impl RetentionReaper {
+    pub fn new(state: &State, staging_retention: Duration) -> Self {
+        Self {
+            retention_registry: state.retention_registry(),
+            event_bus: state.event_bus(),
+            interval: Duration::from_secs(60 * 60),
+            staging_retention,
+        }
+    }
+
+    async fn reap(&self) -> anyhow::Result<()> {
+        let staging = self
+            .retention_registry
+            .purge_abandoned_staging(chrono::Duration::from_std(self.staging_retention)?)
+            .await?;
+        if staging.artifact_uploads > 0 || staging.component_uploads > 0 {
+            tracing::info!(
+                artifact_uploads = staging.artifact_uploads,
+                component_uploads = staging.component_uploads,
+                "purged abandoned uploads"
+            );
+        }
+
+        for policy in self.retention_registry.policies().await? {
+            let organisation = policy.organisation.clone();
+            let plan = match self.retention_registry.plan(&policy, chrono::Utc::now()).await {
+                Ok(plan) => plan,
+                Err(e) => {
+                    tracing::error!(%organisation, "failed to plan retention: {e:#}");
+                    continue;
+                }
+            };
+            if plan.is_empty() {
+                continue;
+            }
+
+            let purged = match self.retention_registry.apply(&plan).await {
+                Ok(purged) => purged,
+                Err(e) => {
+                    tracing::error!(%organisation, "failed to apply retention: {e:#}");
+                    continue;
+                }
+            };
+            tracing::info!(
+                %organisation,
+                artifacts = purged.artifacts,
+                files = purged.files,
+                log_blocks = purged.log_blocks,
+                health_observations = purged.health_observations,
+                notifications = purged.notifications,
+                "applied retention policy"
+            );
+
+            self.event_bus
+                .emit(EventPayload {
+                    organisation,
+                    project: String::new(),
+                    resource_type: "retention",
+                    action: "purged",
+                    resource_id: String::new(),
+                    metadata: [
+                        ("artifacts".into(), purged.artifacts.to_string()),
+                        ("files".into(), purged.files.to_string()),
+                        ("log_blocks".into(), purged.log_blocks.to_string()),
+                        (
+                            "health_observations".into(),
+                            purged.health_observations.to_string(),
+                        ),
+                        ("notifications".into(), purged.notifications.to_string()),
+                    ]
+                    .into(),
+                })
+                .await;
+        }
+
+        Ok(())
+    }
+}
+
+impl Component for RetentionReaper {
+    fn info(&self) -> ComponentInfo {
+        "forest-server/retention-reaper".into()
+    }
+
+    async fn run(&self, cancellation_token: CancellationToken) -> Result<(), MadError> {
+        let mut interval = tokio::time::interval(self.interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+        loop {
+            tokio::select! {
+                _ = cancellation_token.cancelled() => break,
+                _ = interval.tick() => {
+                    if let Err(e) = self.reap().await {
+                        tracing::error!("retention reaper error: {e:#}");
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
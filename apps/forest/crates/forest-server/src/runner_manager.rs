@@ -4,12 +4,21 @@ use std::{
     time::{Duration, Instant},
 };
 
+use chrono::{DateTime, Utc};
+
 use forest_grpc_interface::WorkAssignment;
 use tokio::sync::{RwLock, mpsc};
 
-use crate::destinations::DestinationIndex;
+use crate::{actor::Actor, destinations::DestinationIndex};
+
+/// Runners heartbeat every 30s; one that missed three is presumed gone.
+const STALE_HEARTBEAT: Duration = Duration::from_secs(90);
 
-/// Tracks connected runners and assigns work to them based on capabilities.
+/// Tracks connected runners and assigns work to them based on capabilities,
+/// labels and organisation/project scope.
+///
+/// State is in-memory and per server instance: a runner is only visible to
+/// the replica its stream is connected to.
 #[derive(Clone)]
 pub struct RunnerManager {
     inner: Arc<RwLock<RunnerManagerInner>>,
@@ -20,11 +29,78 @@ struct RunnerManagerInner {
 }
 
 struct ConnectedRunner {
+    identity: Option<Actor>,
     capabilities: Vec<DestinationCapability>,
+    labels: HashMap<String, String>,
+    pool: Option<String>,
+    scope: RunnerScope,
     max_concurrent: i32,
     active_releases: i32,
     work_sender: mpsc::Sender<WorkAssignment>,
     last_heartbeat: Instant,
+    connected_at: DateTime<Utc>,
+}
+
+/// Which organisation/project a runner is allowed to take work for.
+/// `None` means unrestricted at that level.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RunnerScope {
+    pub organisation: Option<String>,
+    pub project: Option<String>,
+}
+
+impl RunnerScope {
+    fn allows(&self, organisation: &str, project: &str) -> bool {
+        self.organisation.as_deref().is_none_or(|o| o == organisation)
+            && self.project.as_deref().is_none_or(|p| p == project)
+    }
+
+    /// Higher is more specific; used to prefer dedicated runners over shared ones.
+    fn specificity(&self) -> u8 {
+        match (&self.organisation, &self.project) {
+            (Some(_), Some(_)) => 2,
+            (Some(_), None) => 1,
+            _ => 0,
+        }
+    }
+}
+
+/// What a runner declares about itself when it registers.
+#[derive(Debug, Clone, Default)]
+pub struct RunnerRegistration {
+    /// Who registered the runner; `None` for an anonymous shared runner.
+    pub identity: Option<Actor>,
+    pub capabilities: Vec<DestinationCapability>,
+    pub labels: HashMap<String, String>,
+    pub pool: Option<String>,
+    pub scope: RunnerScope,
+    pub max_concurrent: i32,
+}
+
+/// What a release requires of the runner that executes it.
+#[derive(Debug, Clone)]
+pub struct WorkRequirements<'a> {
+    pub dest_type: &'a DestinationIndex,
+    pub organisation: &'a str,
+    pub project: &'a str,
+    pub labels: &'a HashMap<String, String>,
+    /// The assignment will hold decrypted secrets, so only an authenticated
+    /// runner scoped to the release's organisation may take it.
+    pub carries_secrets: bool,
+}
+
+/// Point-in-time view of a connected runner, for listing.
+#[derive(Debug, Clone)]
+pub struct RunnerSnapshot {
+    pub runner_id: String,
+    pub capabilities: Vec<DestinationCapability>,
+    pub labels: HashMap<String, String>,
+    pub pool: Option<String>,
+    pub scope: RunnerScope,
+    pub max_concurrent: i32,
+    pub active_releases: i32,
+    pub heartbeat_age: Duration,
+    pub connected_at: DateTime<Utc>,
 }
 
 #[derive(Debug, Clone)]
@@ -42,6 +118,37 @@ impl DestinationCapability {
     }
 }
 
+impl ConnectedRunner {
+    /// Every required label must be present with the same value. The
+    /// runner's pool counts as the label `pool`.
+    fn has_labels(&self, required: &HashMap<String, String>) -> bool {
+        required.iter().all(|(key, value)| {
+            self.labels.get(key) == Some(value)
+                || (key == "pool" && self.pool.as_deref() == Some(value.as_str()))
+        })
+    }
+
+    /// Registered with a token by someone who administers `organisation`,
+    /// and scoped to it. Anyone can register an anonymous shared runner.
+    fn trusted_by(&self, organisation: &str) -> bool {
+        self.identity.is_some() && self.scope.organisation.as_deref() == Some(organisation)
+    }
+
+    /// The runner's stream has closed, or it stopped heartbeating without
+    /// the stream noticing (e.g. a dropped connection).
+    fn is_gone(&self) -> bool {
+        self.work_sender.is_closed() || self.last_heartbeat.elapsed() > STALE_HEARTBEAT
+    }
+
+    fn can_take(&self, req: &WorkRequirements<'_>) -> bool {
+        self.active_releases < self.max_concurrent
+            && self.capabilities.iter().any(|c| c.matches(req.dest_type))
+            && self.scope.allows(req.organisation, req.project)
+            && self.has_labels(req.labels)
+            && (!req.carries_secrets || self.trusted_by(req.organisation))
+    }
+}
+
 impl Default for RunnerManager {
     fn default() -> Self {
         Self::new()
@@ -58,37 +165,83 @@ impl RunnerManager {
     }
 
     /// Register a new runner. Called when a runner sends RunnerRegister on the stream.
+    ///
+    /// A runner id belongs to whoever registered it: only the same identity,
+    /// with the same scope, may register it again (e.g. on reconnect). An
+    /// anonymous runner may only replace an anonymous one that is gone, so a
+    /// shared runner can reconnect before its old entry is reaped.
+    ///
+    /// Labels and pool are declared by the runner itself, so they only count
+    /// for org-scoped runners, whose registration an org admin authorized.
+    /// Anyone can register a shared runner and claim any label.
     pub async fn register_runner(
         &self,
         runner_id: String,
-        capabilities: Vec<DestinationCapability>,
-        max_concurrent: i32,
+        mut registration: RunnerRegistration,
         work_sender: mpsc::Sender<WorkAssignment>,
-    ) {
+    ) -> anyhow::Result<()> {
         let mut inner = self.inner.write().await;
+
+        if let Some(existing) = inner.runners.get(&runner_id) {
+            let same_owner =
+                existing.identity == registration.identity && existing.scope == registration.scope;
+            if !same_owner || (registration.identity.is_none() && !existing.is_gone()) {
+                anyhow::bail!("runner id {runner_id} is already registered by another runner");
+            }
+        }
+
+        if registration.scope.organisation.is_none()
+            && (!registration.labels.is_empty() || registration.pool.is_some())
+        {
+            tracing::warn!(
+                runner_id = %runner_id,
+                labels = ?registration.labels,
+                pool = ?registration.pool,
+                "ignoring labels and pool of a shared runner; only org-scoped runners may carry them"
+            );
+            registration.labels.clear();
+            registration.pool = None;
+        }
+
         tracing::info!(
             runner_id = %runner_id,
-            capabilities = ?capabilities.iter().map(|c| format!("{}/{}@{}", c.organisation, c.name, c.version)).collect::<Vec<_>>(),
-            max_concurrent,
+            capabilities = ?registration.capabilities.iter().map(|c| format!("{}/{}@{}", c.organisation, c.name, c.version)).collect::<Vec<_>>(),
+            labels = ?registration.labels,
+            pool = ?registration.pool,
+            organisation = ?registration.scope.organisation,
+            project = ?registration.scope.project,
+            max_concurrent = registration.max_concurrent,
             "runner registered"
         );
         inner.runners.insert(
             runner_id,
             ConnectedRunner {
-                capabilities,
-                max_concurrent,
+                identity: registration.identity,
+                capabilities: registration.capabilities,
+                labels: registration.labels,
+                pool: registration.pool,
+                scope: registration.scope,
+                max_concurrent: registration.max_concurrent,
                 active_releases: 0,
                 work_sender,
                 last_heartbeat: Instant::now(),
+                connected_at: Utc::now(),
             },
         );
+        Ok(())
     }
 
-    /// Unregister a runner. Called when the stream drops or the runner disconnects.
-    /// Returns the runner_id if it was found.
+    /// Unregister a runner. Called when the stream drops or the runner
+    /// disconnects, after the stream has dropped its work receiver. A
+    /// registration whose stream is still open belongs to a reconnect and
+    /// is kept. Returns whether the runner was removed.
     pub async fn unregister_runner(&self, runner_id: &str) -> bool {
         let mut inner = self.inner.write().await;
-        let removed = inner.runners.remove(runner_id).is_some();
+        let removed = inner
+            .runners
+            .get(runner_id)
+            .is_some_and(|r| r.work_sender.is_closed())
+            && inner.runners.remove(runner_id).is_some();
         if removed {
             tracing::info!(runner_id = %runner_id, "runner unregistered");
         }
@@ -104,26 +257,24 @@ impl RunnerManager {
         }
     }
 
-    /// Try to find a capable runner with spare capacity for the given destination type.
+    /// Try to find a runner for a release: it must support the destination
+    /// type, carry the required labels, be scoped to allow the release's
+    /// organisation/project, and have spare capacity. Releases carrying
+    /// secrets only go to runners the organisation registered itself. Project-scoped runners
+    /// win over org-scoped ones, which win over shared ones; ties go to the
+    /// runner with the most spare capacity.
     /// If found, increments active_releases and returns (runner_id, work_sender).
     pub async fn try_assign(
         &self,
-        dest_type: &DestinationIndex,
+        req: &WorkRequirements<'_>,
     ) -> Option<(String, mpsc::Sender<WorkAssignment>)> {
         let mut inner = self.inner.write().await;
 
-        // Find a runner that:
-        // 1. Has a matching capability
-        // 2. Has spare capacity (active_releases < max_concurrent)
-        // Pick the one with the most spare capacity (simple load balancing)
         let best = inner
             .runners
             .iter_mut()
-            .filter(|(_, r)| {
-                r.active_releases < r.max_concurrent
-                    && r.capabilities.iter().any(|c| c.matches(dest_type))
-            })
-            .max_by_key(|(_, r)| r.max_concurrent - r.active_releases);
+            .filter(|(_, r)| r.can_take(req))
+            .max_by_key(|(_, r)| (r.scope.specificity(), r.max_concurrent - r.active_releases));
 
         if let Some((runner_id, runner)) = best {
             runner.active_releases += 1;
@@ -131,7 +282,7 @@ impl RunnerManager {
             let sender = runner.work_sender.clone();
             tracing::debug!(
                 runner_id = %runner_id,
-                dest_type = %dest_type,
+                dest_type = %req.dest_type,
                 active_releases = runner.active_releases,
                 "assigned work to runner"
             );
@@ -169,9 +320,318 @@ impl RunnerManager {
         stale
     }
 
+    /// Snapshot of connected runners. With `organisation` set, only runners
+    /// that may take work for it (scoped to it, or shared) are returned.
+    pub async fn list_runners(&self, organisation: Option<&str>) -> Vec<RunnerSnapshot> {
+        let inner = self.inner.read().await;
+        let now = Instant::now();
+        let mut runners: Vec<RunnerSnapshot> = inner
+            .runners
+            .iter()
+            .filter(|(_, r)| {
+                organisation.is_none_or(|org| {
+                    r.scope.organisation.as_deref().is_none_or(|o| o == org)
+                })
+            })
+            .map(|(id, r)| RunnerSnapshot {
+                runner_id: id.clone(),
+                capabilities: r.capabilities.clone(),
+                labels: r.labels.clone(),
+                pool: r.pool.clone(),
+                scope: r.scope.clone(),
+                max_concurrent: r.max_concurrent,
+                active_releases: r.active_releases,
+                heartbeat_age: now.duration_since(r.last_heartbeat),
+                connected_at: r.connected_at,
+            })
+            .collect();
+        runners.sort_by(|a, b| a.runner_id.cmp(&b.runner_id));
+        runners
+    }
+
     /// Returns whether any runners are currently connected.
     pub async fn has_runners(&self) -> bool {
         let inner = self.inner.read().await;
         !inner.runners.is_empty()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flux() -> DestinationIndex {
+        DestinationIndex {
+            organisation: "forest".into(),
+            name: "flux".into(),
+            version: 1,
+        }
+    }
+
+    fn admin() -> Actor {
+        Actor::User {
+            user_id: uuid::Uuid::from_u128(1),
+        }
+    }
+
+    fn registration(scope: RunnerScope, labels: &[(&str, &str)]) -> RunnerRegistration {
+        RunnerRegistration {
+            identity: scope.organisation.is_some().then(admin),
+            capabilities: vec![DestinationCapability {
+                organisation: "forest".into(),
+                name: "flux".into(),
+                version: 1,
+            }],
+            labels: labels
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            pool: None,
+            scope,
+            max_concurrent: 2,
+        }
+    }
+
+    async fn register(manager: &RunnerManager, id: &str, registration: RunnerRegistration) {
+        let (tx, _rx) = mpsc::channel(1);
+        manager.register_runner(id.into(), registration, tx).await.unwrap();
+    }
+
+    async fn assign(
+        manager: &RunnerManager,
+        organisation: &str,
+        project: &str,
+        labels: &[(&str, &str)],
+    ) -> Option<String> {
+        assign_with(manager, organisation, project, labels, false).await
+    }
+
+    async fn assign_with(
+        manager: &RunnerManager,
+        organisation: &str,
+        project: &str,
+        labels: &[(&str, &str)],
+        carries_secrets: bool,
+    ) -> Option<String> {
+        let dest_type = flux();
+        let labels: HashMap<String, String> = labels
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        manager
+            .try_assign(&WorkRequirements {
+                dest_type: &dest_type,
+                organisation,
+                project,
+                labels: &labels,
+                carries_secrets,
+            })
+            .await
+            .map(|(id, _)| id)
+    }
+
+    fn org_scope(org: &str) -> RunnerScope {
+        RunnerScope {
+            organisation: Some(org.into()),
+            project: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn assign_requires_all_labels() {
+        let manager = RunnerManager::new();
+        register(&manager, "plain", registration(org_scope("acme"), &[])).await;
+        register(&manager, "gpu", registration(org_scope("acme"), &[("gpu", "true")])).await;
+
+        assert_eq!(
+            assign(&manager, "acme", "web", &[("gpu", "true")]).await.as_deref(),
+            Some("gpu")
+        );
+        assert_eq!(assign(&manager, "acme", "web", &[("gpu", "false")]).await, None);
+    }
+
+    #[tokio::test]
+    async fn pool_matches_pool_label() {
+        let manager = RunnerManager::new();
+        register(
+            &manager,
+            "eu",
+            RunnerRegistration {
+                pool: Some("eu".into()),
+                ..registration(org_scope("acme"), &[])
+            },
+        )
+        .await;
+
+        assert_eq!(
+            assign(&manager, "acme", "web", &[("pool", "eu")]).await.as_deref(),
+            Some("eu")
+        );
+        assert_eq!(assign(&manager, "acme", "web", &[("pool", "us")]).await, None);
+    }
+
+    #[tokio::test]
+    async fn shared_runner_labels_are_not_trusted() {
+        let manager = RunnerManager::new();
+        register(&manager, "fake-prod", registration(RunnerScope::default(), &[("env", "prod")])).await;
+        register(
+            &manager,
+            "fake-pool",
+            RunnerRegistration {
+                pool: Some("prod".into()),
+                ..registration(RunnerScope::default(), &[])
+            },
+        )
+        .await;
+
+        assert_eq!(assign(&manager, "acme", "web", &[("env", "prod")]).await, None);
+        assert_eq!(assign(&manager, "acme", "web", &[("pool", "prod")]).await, None);
+        assert!(manager.list_runners(None).await.iter().all(|r| r.labels.is_empty() && r.pool.is_none()));
+    }
+
+    #[tokio::test]
+    async fn runner_id_is_bound_to_its_registrant() {
+        let manager = RunnerManager::new();
+        register(&manager, "prod", registration(org_scope("acme"), &[("env", "prod")])).await;
+
+        let (tx, _rx) = mpsc::channel(1);
+        // Anonymous, shared re-registration of the same id.
+        assert!(
+            manager
+                .register_runner("prod".into(), registration(RunnerScope::default(), &[]), tx.clone())
+                .await
+                .is_err()
+        );
+        // Another identity with the same scope.
+        let other = RunnerRegistration {
+            identity: Some(Actor::User {
+                user_id: uuid::Uuid::from_u128(2),
+            }),
+            ..registration(org_scope("acme"), &[("env", "prod")])
+        };
+        assert!(manager.register_runner("prod".into(), other, tx.clone()).await.is_err());
+        // The same identity with a different scope.
+        assert!(
+            manager
+                .register_runner("prod".into(), registration(org_scope("other"), &[]), tx.clone())
+                .await
+                .is_err()
+        );
+        // The original registrant reconnecting.
+        manager
+            .register_runner("prod".into(), registration(org_scope("acme"), &[("env", "prod")]), tx)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            assign(&manager, "acme", "web", &[("env", "prod")]).await.as_deref(),
+            Some("prod")
+        );
+    }
+
+    #[tokio::test]
+    async fn shared_runner_reconnects_once_its_stream_closed() {
+        let manager = RunnerManager::new();
+        let (tx, rx) = mpsc::channel(1);
+        manager
+            .register_runner("shared".into(), registration(RunnerScope::default(), &[]), tx)
+            .await
+            .unwrap();
+
+        // The id is taken while the first stream is open.
+        let (tx, new_rx) = mpsc::channel(1);
+        assert!(
+            manager
+                .register_runner("shared".into(), registration(RunnerScope::default(), &[]), tx.clone())
+                .await
+                .is_err()
+        );
+
+        // Once it closed, the runner doesn't have to wait for the reaper.
+        drop(rx);
+        manager
+            .register_runner("shared".into(), registration(RunnerScope::default(), &[]), tx)
+            .await
+            .unwrap();
+
+        // The old stream cleaning up late leaves the new registration alone.
+        assert!(!manager.unregister_runner("shared").await);
+        assert_eq!(manager.list_runners(None).await.len(), 1);
+        drop(new_rx);
+        assert!(manager.unregister_runner("shared").await);
+    }
+
+    #[tokio::test]
+    async fn secrets_only_go_to_runners_the_org_registered() {
+        let manager = RunnerManager::new();
+        register(&manager, "shared", registration(RunnerScope::default(), &[])).await;
+        // Authenticated, but not scoped to any organisation.
+        register(
+            &manager,
+            "shared-authed",
+            RunnerRegistration {
+                identity: Some(admin()),
+                ..registration(RunnerScope::default(), &[])
+            },
+        )
+        .await;
+
+        assert_eq!(assign_with(&manager, "acme", "web", &[], true).await, None);
+
+        register(&manager, "acme", registration(org_scope("acme"), &[])).await;
+        assert_eq!(
+            assign_with(&manager, "acme", "web", &[], true).await.as_deref(),
+            Some("acme")
+        );
+        assert_eq!(assign_with(&manager, "other", "web", &[], true).await, None);
+    }
+
+    #[tokio::test]
+    async fn scoped_runner_only_takes_its_own_work() {
+        let manager = RunnerManager::new();
+        register(&manager, "acme-only", registration(org_scope("acme"), &[])).await;
+
+        assert_eq!(assign(&manager, "other", "web", &[]).await, None);
+        assert_eq!(
+            assign(&manager, "acme", "web", &[]).await.as_deref(),
+            Some("acme-only")
+        );
+    }
+
+    #[tokio::test]
+    async fn most_specific_runner_wins() {
+        let manager = RunnerManager::new();
+        register(&manager, "shared", registration(RunnerScope::default(), &[])).await;
+        register(&manager, "org", registration(org_scope("acme"), &[])).await;
+        register(
+            &manager,
+            "project",
+            registration(
+                RunnerScope {
+                    organisation: Some("acme".into()),
+                    project: Some("web".into()),
+                },
+                &[],
+            ),
+        )
+        .await;
+
+        assert_eq!(assign(&manager, "acme", "web", &[]).await.as_deref(), Some("project"));
+        assert_eq!(assign(&manager, "acme", "api", &[]).await.as_deref(), Some("org"));
+        assert_eq!(assign(&manager, "other", "web", &[]).await.as_deref(), Some("shared"));
+    }
+
+    #[tokio::test]
+    async fn list_runners_filters_by_organisation() {
+        let manager = RunnerManager::new();
+        register(&manager, "shared", registration(RunnerScope::default(), &[])).await;
+        register(&manager, "acme", registration(org_scope("acme"), &[])).await;
+        register(&manager, "other", registration(org_scope("other"), &[])).await;
+
+        let ids = |runners: Vec<RunnerSnapshot>| {
+            runners.into_iter().map(|r| r.runner_id).collect::<Vec<_>>()
+        };
+        assert_eq!(ids(manager.list_runners(Some("acme")).await), ["acme", "shared"]);
+        assert_eq!(ids(manager.list_runners(None).await).len(), 3);
+    }
+}
@@ -18,12 +18,14 @@ use crate::{
         logger::DestinationLogger,
         terraformv1::{TerraformStateStore, TerraformStateStoreState},
     },
-    runner_manager::RunnerManager,
+    runner_manager::{RunnerManager, WorkRequirements},
     services::{
         destination_registry::{DestinationRegistry, DestinationRegistryState},
+        environment_protection,
         notification_registry::{NotificationRegistry, NotificationRegistryState},
         release_event_store::{
-            EventPayload, ReleaseEventStore, ReleaseEventStoreState, ReleaseEventType,
+            EventPayload, RELEASE_CANCEL_SUBJECT_PREFIX, ReleaseEventStore,
+            ReleaseEventStoreState, ReleaseEventType,
         },
         release_finalizer,
         release_logs_registry::{ReleaseLogsRegistry, ReleaseLogsRegistryState},
@@ -32,6 +34,7 @@ use crate::{
         release_token_registry::{
             ReleaseTokenRegistry, ReleaseTokenRegistryState, ReleaseTokenScope,
         },
+        secrets::{SecretRegistry, SecretRegistryState},
     },
 };
 
@@ -46,10 +49,12 @@ struct SchedulerInner {
     release_token_registry: ReleaseTokenRegistry,
     release_event_store: ReleaseEventStore,
     policy_registry: PolicyRegistry,
+    secrets: SecretRegistry,
     /// Owned by terraformv1's in-process backend; the scheduler reads it to
     /// hand state-backend credentials to remote runners (e.g. hollow) via
-    /// WorkAssignment.terraform_state. Cheap clone (Arcs inside).
+    /// WorkAssignment.terraform_state. Cheap clone (pool inside).
     tf_state: TerraformStateStore,
+    db: sqlx::PgPool,
     nats: async_nats::Client,
     disable_in_process: bool,
 }
@@ -72,7 +77,9 @@ impl Scheduler {
                 release_token_registry: state.release_token_registry(),
                 release_event_store: state.release_event_store(),
                 policy_registry: state.policy_registry(),
+                secrets: state.secret_registry(),
                 tf_state: state.terraform_state_store(),
+                db: state.db.clone(),
                 nats: state.nats.clone(),
                 disable_in_process,
             }),
@@ -108,6 +115,13 @@ impl SchedulerInner {
             return Ok(());
         }
 
+        // Releases to a destination run one at a time, in queue order. The
+        // blocker picks this one up again when it reaches a terminal state.
+        if let Some(blocker) = self.release_event_store.blocking_release(&release_state).await? {
+            tracing::debug!(%release_id, %blocker, "release waiting in destination queue");
+            return Ok(());
+        }
+
         tracing::info!(%release_id, project_id = %release_state.project_id, destination_id = %release_state.destination_id, "processing queued release");
 
         let dest = self
@@ -143,6 +157,41 @@ impl SchedulerInner {
             }
         }
 
+        // Protected environments hold releases until approved. Pipeline
+        // stages are approved before their releases exist, so in practice
+        // this holds direct releases; the sweep picks them up again. A held
+        // release steps out of the destination queue so it doesn't block
+        // other projects sharing the destination.
+        let mut conn = self.db.acquire().await.context("acquire connection")?;
+        let protection = environment_protection::load_for_project(
+            &mut conn,
+            &release_state.project_id,
+            &dest.environment,
+        )
+        .await?;
+        let held = environment_protection::check_approvals(
+            &mut conn,
+            &release_state.project_id,
+            &release_state.release_intent_id,
+            &dest.environment,
+            &protection,
+        )
+        .await?;
+        drop(conn);
+        if protection.protected {
+            self.release_event_store
+                .set_awaiting_approval(&release_id, held.is_some())
+                .await?;
+        }
+        if let Some(reason) = held {
+            tracing::debug!(
+                %release_id,
+                env = %dest.environment,
+                "scheduler: release held by environment protection — {reason}",
+            );
+            return Ok(());
+        }
+
         let dest_index = DestinationIndex {
             organisation: dest.destination_type.organisation.clone(),
             name: dest.destination_type.name.clone(),
@@ -165,8 +214,37 @@ impl SchedulerInner {
             project: project_name,
         };
 
+        // Decrypted before picking a runner: a release that carries secrets
+        // may only go to a runner its organisation vouched for.
+        let resolved = async {
+            let secrets = self
+                .secrets
+                .resolve(&dest.organisation, &release_item.project, &dest.environment)
+                .await?;
+            let metadata = secrets.resolve_metadata(&dest.metadata)?;
+            Ok::<_, anyhow::Error>((secrets, metadata))
+        }
+        .await;
+        let (secrets, metadata) = match resolved {
+            Ok(resolved) => resolved,
+            Err(e) => {
+                return self
+                    .fail_release(&release_item, &format!("resolve secrets: {e:#}"))
+                    .await;
+            }
+        };
+
+        let requirements = WorkRequirements {
+            dest_type: &dest_index,
+            organisation: &dest.organisation,
+            project: &release_item.project,
+            labels: &dest.runner_labels,
+            carries_secrets: !secrets.values().is_empty(),
+        };
+        let assigned = self.runner_manager.try_assign(&requirements).await;
+
         // Try remote runner first
-        if let Some((runner_id, work_sender)) = self.runner_manager.try_assign(&dest_index).await {
+        if let Some((runner_id, work_sender)) = assigned {
             // Transition QUEUED -> ASSIGNED
             if let Err(e) = self
                 .release_event_store
@@ -181,8 +259,10 @@ impl SchedulerInner {
                 )
                 .await
             {
-                // Another instance already transitioned this release
+                // Another instance already transitioned this release, or the
+                // destination queue moved on; give the runner slot back.
                 tracing::debug!(%release_id, "skipping release (already transitioned): {e}");
+                self.runner_manager.release_completed(&runner_id).await;
                 return Ok(());
             }
 
@@ -225,13 +305,12 @@ impl SchedulerInner {
             {
                 let project_id = release_state.project_id.to_string();
                 let state_id = TerraformStateStore::state_id_for(&dest.environment, &project_id);
-                let (id, password) = self.tf_state.urls(state_id).await;
-                let url = format!(
-                    "{}/{id}",
-                    self.tf_state.external_url.trim_end_matches('/')
-                );
+                let (url, password) = self
+                    .tf_state
+                    .release_credentials(state_id.clone(), &release_id)
+                    .await?;
                 Some(ReleaseArtifactStore {
-                    id,
+                    id: state_id,
                     url,
                     username: "forest-terraform-v1".to_string(),
                     password,
@@ -249,12 +328,13 @@ impl SchedulerInner {
                 destination: Some(DestinationInfo {
                     name: dest.name.clone(),
                     environment: dest.environment.clone(),
-                    metadata: dest.metadata.clone(),
+                    metadata,
                     r#type: Some(dest_cap),
                     organisation: dest.organisation.clone(),
                 }),
                 mode: mode.into(),
                 artifact_store,
+                secrets: secrets.values().clone().into_iter().collect(),
             };
 
             match work_sender.send(assignment).await {
@@ -273,31 +353,9 @@ impl SchedulerInner {
                         runner_id = %runner_id,
                         "failed to send work to runner (channel closed)"
                     );
-                    self.release_event_store
-                        .emit_event(
-                            release_id,
-                            ReleaseEventType::Failed,
-                            EventPayload {
-                                error_message: Some("runner unavailable (channel closed)".into()),
-                                ..Default::default()
-                            },
-                            None,
-                        )
-                        .await?;
-
-                    if let Err(e) = release_finalizer::send_notification(
-                        &self.release_registry,
-                        &self.notification_registry,
-                        &self.destination_registry,
-                        &release_item,
-                        ReleaseStatus::Failed,
-                        Some("runner unavailable (channel closed)"),
-                    )
-                    .await
-                    {
-                        tracing::warn!("failed to create failure notification: {e:#}");
-                    }
-                    return Ok(());
+                    return self
+                        .fail_release(&release_item, "runner unavailable (channel closed)")
+                        .await;
                 }
             }
         }
@@ -312,8 +370,28 @@ impl SchedulerInner {
             return Ok(());
         }
 
+        // Destinations that ask for specific runner labels must run on a
+        // matching runner; the in-process executor never qualifies.
+        if !dest.runner_labels.is_empty() {
+            tracing::info!(
+                %release_id,
+                destination = %dest.name,
+                labels = ?dest.runner_labels,
+                "no runner with the required labels available — leaving queued"
+            );
+            return Ok(());
+        }
+
         tracing::info!(%release_id, destination = %dest.name, "assigning release to in-process executor");
 
+        // Listen before claiming the release so a cancellation can't slip in
+        // between the two.
+        let mut cancellations = self
+            .nats
+            .subscribe(format!("{RELEASE_CANCEL_SUBJECT_PREFIX}{release_id}"))
+            .await
+            .context("subscribe to release cancellation")?;
+
         // Transition QUEUED -> ASSIGNED (in-process)
         if let Err(e) = self
             .release_event_store
@@ -374,7 +452,17 @@ impl SchedulerInner {
 
         let is_plan_mode = release_state.mode == "plan";
 
-        let result = async {
+        let work = async {
+            // Decrypted only now that the release is about to run.
+            let secrets = self
+                .secrets
+                .resolve(&dest.organisation, &release_item.project, &dest.environment)
+                .await
+                .context("resolve secrets")?;
+            logger.redact(secrets.redactions());
+            let mut dest = dest.clone();
+            dest.metadata = secrets.resolve_metadata(&dest.metadata)?;
+
             if is_plan_mode {
                 // Plan mode: run the plan phase only, capture output
                 dest_svc.prepare(&logger, &release_item, &dest).await?;
@@ -410,11 +498,22 @@ impl SchedulerInner {
                 }
             }
             Ok::<(), anyhow::Error>(())
-        }
-        .await;
+        };
+
+        // Cancelling drops the destination work mid-flight. The release is
+        // already CANCELLED by whoever cancelled it.
+        let result = tokio::select! {
+            result = work => Some(result),
+            _ = cancellations.next() => None,
+        };
 
         heartbeat_token.cancel();
 
+        let Some(result) = result else {
+            tracing::info!(%release_id, destination = %dest.name, "release cancelled (in-process)");
+            return Ok(());
+        };
+
         match result {
             Ok(()) => {
                 self.release_event_store
@@ -473,6 +572,35 @@ impl SchedulerInner {
 
         Ok(())
     }
+
+    /// Fail a release before it started running and notify its subscribers.
+    async fn fail_release(&self, release_item: &ReleaseItem, message: &str) -> anyhow::Result<()> {
+        self.release_event_store
+            .emit_event(
+                release_item.id,
+                ReleaseEventType::Failed,
+                EventPayload {
+                    error_message: Some(message.into()),
+                    ..Default::default()
+                },
+                None,
+            )
+            .await?;
+
+        if let Err(e) = release_finalizer::send_notification(
+            &self.release_registry,
+            &self.notification_registry,
+            &self.destination_registry,
+            release_item,
+            ReleaseStatus::Failed,
+            Some(message),
+        )
+        .await
+        {
+            tracing::warn!("failed to create failure notification: {e:#}");
+        }
+        Ok(())
+    }
 }
 
 impl Component for Scheduler {
@@ -0,0 +1,976 @@
+//! SCIM 2.0 (RFC 7643/7644) Users and Groups endpoints under `/scim/v2`.
+//!
+//! Callers authenticate with an app token whose app holds the `scim`
+//! permission; the app's organisation is the one being provisioned. The
+//! protocol mapping lives here, the membership rules in
+//! [`crate::services::scim`].
+//!
+//! Only what identity providers actually send is supported: `eq` filters
+//! on `userName`/`displayName`, index-based pagination and PATCH
+//! add/replace/remove. Attributes forest has no use for (names, phone
+//! numbers, enterprise extensions) are accepted and ignored so a full IdP
+//! payload never fails provisioning.
+
+use axum::{
+    Router,
+    body::Bytes,
+    extract::{FromRequestParts, Path, Query, State as AxumState},
+    http::{StatusCode, header, request::Parts},
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use serde_json::{Value, json};
+use uuid::Uuid;
+
+use crate::{
+    State,
+    repositories::scim::ScimUserRow,
+    services::scim::{
+        ScimCaller, ScimError, ScimGroup, ScimGroupInput, ScimServiceState, ScimUserInput,
+    },
+};
+
+const USER_SCHEMA: &str = "urn:ietf:params:scim:schemas:core:2.0:User";
+const GROUP_SCHEMA: &str = "urn:ietf:params:scim:schemas:core:2.0:Group";
+const LIST_SCHEMA: &str = "urn:ietf:params:scim:api:messages:2.0:ListResponse";
+const ERROR_SCHEMA: &str = "urn:ietf:params:scim:api:messages:2.0:Error";
+const SCIM_CONTENT_TYPE: &str = "application/scim+json";
+
+const DEFAULT_PAGE_SIZE: i64 = 100;
+const MAX_PAGE_SIZE: i64 = 200;
+
+pub fn scim_routes(state: State) -> Router {
+    Router::new()
+        .route("/scim/v2/ServiceProviderConfig", get(service_provider_config))
+        .route("/scim/v2/ResourceTypes", get(resource_types))
+        .route("/scim/v2/Users", get(list_users).post(create_user))
+        .route(
+            "/scim/v2/Users/{id}",
+            get(get_user)
+                .put(replace_user)
+                .patch(patch_user)
+                .delete(delete_user),
+        )
+        .route("/scim/v2/Groups", get(list_groups).post(create_group))
+        .route(
+            "/scim/v2/Groups/{id}",
+            get(get_group)
+                .put(replace_group)
+                .patch(patch_group)
+                .delete(delete_group),
+        )
+        .with_state(state)
+}
+
+// ─── Responses and errors ────────────────────────────────────────────
+
+fn scim_json(status: StatusCode, body: Value) -> Response {
+    (
+        status,
+        [(header::CONTENT_TYPE, SCIM_CONTENT_TYPE)],
+        body.to_string(),
+    )
+        .into_response()
+}
+
+#[derive(Debug)]
+pub struct ScimApiError {
+    status: StatusCode,
+    scim_type: Option<&'static str>,
+    detail: String,
+}
+
+impl ScimApiError {
+    fn new(status: StatusCode, scim_type: Option<&'static str>, detail: impl Into<String>) -> Self {
+        Self {
+            status,
+            scim_type,
+            detail: detail.into(),
+        }
+    }
+
+    fn invalid_value(detail: impl Into<String>) -> Self {
+        Self::new(StatusCode::BAD_REQUEST, Some("invalidValue"), detail)
+    }
+
+    fn invalid_filter(detail: impl Into<String>) -> Self {
+        Self::new(StatusCode::BAD_REQUEST, Some("invalidFilter"), detail)
+    }
+
+    fn invalid_syntax(detail: impl Into<String>) -> Self {
+        Self::new(StatusCode::BAD_REQUEST, Some("invalidSyntax"), detail)
+    }
+
+    fn not_found() -> Self {
+        Self::new(StatusCode::NOT_FOUND, None, "resource not found")
+    }
+}
+
+impl From<ScimError> for ScimApiError {
+    fn from(err: ScimError) -> Self {
+        match err {
+            ScimError::NotFound => Self::not_found(),
+            ScimError::Conflict(msg) => Self::new(StatusCode::CONFLICT, Some("uniqueness"), msg),
+            ScimError::InvalidValue(msg) => Self::invalid_value(msg),
+            ScimError::Mutability(msg) => Self::new(StatusCode::BAD_REQUEST, Some("mutability"), msg),
+            ScimError::Other(err) => {
+                tracing::error!("scim request failed: {err:#}");
+                Self::new(StatusCode::INTERNAL_SERVER_ERROR, None, "internal error")
+            }
+        }
+    }
+}
+
+impl IntoResponse for ScimApiError {
+    fn into_response(self) -> Response {
+        let mut body = json!({
+            "schemas": [ERROR_SCHEMA],
+            "status": self.status.as_u16().to_string(),
+            "detail": self.detail,
+        });
+        if let Some(scim_type) = self.scim_type {
+            body["scimType"] = json!(scim_type);
+        }
+        scim_json(self.status, body)
+    }
+}
+
+type ScimResult = Result<Response, ScimApiError>;
+
+// ─── Authentication ──────────────────────────────────────────────────
+
+/// The organisation a request provisions, from its bearer token.
+pub struct Caller(ScimCaller);
+
+impl FromRequestParts<State> for Caller {
+    type Rejection = ScimApiError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &State) -> Result<Self, Self::Rejection> {
+        let unauthorized = || {
+            ScimApiError::new(
+                StatusCode::UNAUTHORIZED,
+                None,
+                "a bearer token for an app with the scim permission is required",
+            )
+        };
+
+        let token = parts
+            .headers
+            .get(header::AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.strip_prefix("Bearer "))
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .ok_or_else(unauthorized)?;
+
+        match state.scim_service().authenticate(token).await {
+            Ok(Some(caller)) => Ok(Caller(caller)),
+            Ok(None) => Err(unauthorized()),
+            Err(err) => Err(ScimError::Other(err).into()),
+        }
+    }
+}
+
+// ─── Discovery ───────────────────────────────────────────────────────
+
+async fn service_provider_config(_caller: Caller) -> Response {
+    scim_json(
+        StatusCode::OK,
+        json!({
+            "schemas": ["urn:ietf:params:scim:schemas:core:2.0:ServiceProviderConfig"],
+            "patch": { "supported": true },
+            "bulk": { "supported": false, "maxOperations": 0, "maxPayloadSize": 0 },
+            "filter": { "supported": true, "maxResults": MAX_PAGE_SIZE },
+            "changePassword": { "supported": false },
+            "sort": { "supported": false },
+            "etag": { "supported": false },
+            "authenticationSchemes": [{
+                "type": "oauthbearertoken",
+                "name": "App token",
+                "description": "Token of a forest app holding the scim permission",
+                "primary": true,
+            }],
+        }),
+    )
+}
+
+async fn resource_types(_caller: Caller) -> Response {
+    let resources = json!([
+        {
+            "schemas": ["urn:ietf:params:scim:schemas:core:2.0:ResourceType"],
+            "id": "User",
+            "name": "User",
+            "endpoint": "/Users",
+            "schema": USER_SCHEMA,
+        },
+        {
+            "schemas": ["urn:ietf:params:scim:schemas:core:2.0:ResourceType"],
+            "id": "Group",
+            "name": "Group",
+            "endpoint": "/Groups",
+            "schema": GROUP_SCHEMA,
+        },
+    ]);
+    scim_json(StatusCode::OK, list_response(resources, 2, 1))
+}
+
+// ─── Listing ─────────────────────────────────────────────────────────
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ListQuery {
+    filter: Option<String>,
+    start_index: Option<i64>,
+    count: Option<i64>,
+}
+
+impl ListQuery {
+    /// SCIM pages are 1-based; returns (start_index, limit, offset).
+    fn page(&self) -> (i64, i64, i64) {
+        let start_index = self.start_index.unwrap_or(1).max(1);
+        let count = self
+            .count
+            .unwrap_or(DEFAULT_PAGE_SIZE)
+            .clamp(0, MAX_PAGE_SIZE);
+        (start_index, count, start_index - 1)
+    }
+}
+
+fn list_response(resources: Value, total_results: i64, start_index: i64) -> Value {
+    let items_per_page = resources.as_array().map_or(0, Vec::len);
+    json!({
+        "schemas": [LIST_SCHEMA],
+        "totalResults": total_results,
+        "startIndex": start_index,
+        "itemsPerPage": items_per_page,
+        "Resources": resources,
+    })
+}
+
+/// Parse the only filter shape identity providers use for lookups:
+/// `<attribute> eq "<value>"`. Attribute names and the operator are
+/// case-insensitive.
+fn parse_eq_filter(filter: &str, attribute: &str) -> Result<String, ScimApiError> {
+    let unsupported = || {
+        ScimApiError::invalid_filter(format!(
+            "only '{attribute} eq \"value\"' filters are supported"
+        ))
+    };
+
+    let filter = filter.trim();
+    let (attr, rest) = filter.split_once(char::is_whitespace).ok_or_else(unsupported)?;
+    let (op, value) = rest
+        .trim_start()
+        .split_once(char::is_whitespace)
+        .ok_or_else(unsupported)?;
+    if !attr.eq_ignore_ascii_case(attribute) || !op.eq_ignore_ascii_case("eq") {
+        return Err(unsupported());
+    }
+
+    let value: String = serde_json::from_str(value.trim()).map_err(|_| unsupported())?;
+    Ok(value)
+}
+
+// ─── Users ───────────────────────────────────────────────────────────
+
+fn user_resource(user: &ScimUserRow) -> Value {
+    let mut resource = json!({
+        "schemas": [USER_SCHEMA],
+        "id": user.user_id,
+        "userName": user.user_name,
+        "active": user.active,
+        "meta": {
+            "resourceType": "User",
+            "created": user.created_at.to_rfc3339(),
+            "lastModified": user.updated_at.to_rfc3339(),
+            "location": format!("/scim/v2/Users/{}", user.user_id),
+        },
+    });
+    if let Some(external_id) = &user.external_id {
+        resource["externalId"] = json!(external_id);
+    }
+    if let Some(display_name) = &user.display_name {
+        resource["displayName"] = json!(display_name);
+    }
+    if let Some(email) = &user.email {
+        resource["emails"] = json!([{ "value": email, "primary": true }]);
+    }
+    resource
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UserBody {
+    #[serde(default)]
+    user_name: String,
+    external_id: Option<String>,
+    display_name: Option<String>,
+    name: Option<NameBody>,
+    #[serde(default)]
+    emails: Vec<EmailBody>,
+    active: Option<Value>,
+}
+
+#[derive(serde::Deserialize)]
+struct NameBody {
+    formatted: Option<String>,
+}
+
+#[derive(serde::Deserialize)]
+struct EmailBody {
+    value: String,
+    #[serde(default)]
+    primary: bool,
+}
+
+impl UserBody {
+    fn into_input(self) -> Result<ScimUserInput, ScimApiError> {
+        let email = self
+            .emails
+            .iter()
+            .find(|e| e.primary)
+            .or(self.emails.first())
+            .map(|e| e.value.clone());
+        let active = match &self.active {
+            Some(value) => parse_bool(value)?,
+            None => true,
+        };
+
+        Ok(ScimUserInput {
+            user_name: self.user_name,
+            external_id: self.external_id,
+            display_name: self
+                .display_name
+                .or_else(|| self.name.and_then(|n| n.formatted)),
+            email,
+            active,
+        })
+    }
+}
+
+/// Some IdPs send booleans as the strings "True"/"False".
+fn parse_bool(value: &Value) -> Result<bool, ScimApiError> {
+    match value {
+        Value::Bool(b) => Ok(*b),
+        Value::String(s) if s.eq_ignore_ascii_case("true") => Ok(true),
+        Value::String(s) if s.eq_ignore_ascii_case("false") => Ok(false),
+        _ => Err(ScimApiError::invalid_value("active must be a boolean")),
+    }
+}
+
+fn optional_string(value: &Value) -> Result<Option<String>, ScimApiError> {
+    match value {
+        Value::Null => Ok(None),
+        Value::String(s) => Ok(Some(s.clone())),
+        _ => Err(ScimApiError::invalid_value("expected a string")),
+    }
+}
+
+fn parse_body<T: serde::de::DeserializeOwned>(body: &Bytes) -> Result<T, ScimApiError> {
+    serde_json::from_slice(body).map_err(|e| ScimApiError::invalid_syntax(e.to_string()))
+}
+
+fn parse_id(id: &str) -> Result<Uuid, ScimApiError> {
+    Uuid::parse_str(id).map_err(|_| ScimApiError::not_found())
+}
+
+async fn list_users(
+    AxumState(state): AxumState<State>,
+    Caller(caller): Caller,
+    Query(query): Query<ListQuery>,
+) -> ScimResult {
+    let user_name = query
+        .filter
+        .as_deref()
+        .map(|f| parse_eq_filter(f, "userName"))
+        .transpose()?;
+    let (start_index, limit, offset) = query.page();
+
+    let page = state
+        .scim_service()
+        .list_users(&caller, user_name.as_deref(), limit, offset)
+        .await?;
+    let resources: Vec<Value> = page.resources.iter().map(user_resource).collect();
+
+    Ok(scim_json(
+        StatusCode::OK,
+        list_response(Value::Array(resources), page.total_results, start_index),
+    ))
+}
+
+async fn create_user(
+    AxumState(state): AxumState<State>,
+    Caller(caller): Caller,
+    body: Bytes,
+) -> ScimResult {
+    let input = parse_body::<UserBody>(&body)?.into_input()?;
+    let user = state.scim_service().create_user(&caller, input).await?;
+
+    Ok(scim_json(StatusCode::CREATED, user_resource(&user)))
+}
+
+async fn get_user(
+    AxumState(state): AxumState<State>,
+    Caller(caller): Caller,
+    Path(id): Path<String>,
+) -> ScimResult {
+    let user = state
+        .scim_service()
+        .get_user(&caller, parse_id(&id)?)
+        .await?;
+
+    Ok(scim_json(StatusCode::OK, user_resource(&user)))
+}
+
+async fn replace_user(
+    AxumState(state): AxumState<State>,
+    Caller(caller): Caller,
+    Path(id): Path<String>,
+    body: Bytes,
+) -> ScimResult {
+    let input = parse_body::<UserBody>(&body)?.into_input()?;
+    let user = state
+        .scim_service()
+        .replace_user(&caller, parse_id(&id)?, input)
+        .await?;
+
+    Ok(scim_json(StatusCode::OK, user_resource(&user)))
+}
+
+async fn patch_user(
+    AxumState(state): AxumState<State>,
+    Caller(caller): Caller,
+    Path(id): Path<String>,
+    body: Bytes,
+) -> ScimResult {
+    let id = parse_id(&id)?;
+    let patch: PatchBody = parse_body(&body)?;
+    let service = state.scim_service();
+
+    let current = service.get_user(&caller, id).await?;
+    let mut input = ScimUserInput {
+        user_name: current.user_name,
+        external_id: current.external_id,
+        display_name: current.display_name,
+        email: None,
+        active: current.active,
+    };
+    apply_user_patch(&mut input, patch.operations)?;
+    let user = service.replace_user(&caller, id, input).await?;
+
+    Ok(scim_json(StatusCode::OK, user_resource(&user)))
+}
+
+async fn delete_user(
+    AxumState(state): AxumState<State>,
+    Caller(caller): Caller,
+    Path(id): Path<String>,
+) -> ScimResult {
+    state
+        .scim_service()
+        .delete_user(&caller, parse_id(&id)?)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+// ─── Groups ──────────────────────────────────────────────────────────
+
+fn group_resource(group: &ScimGroup) -> Value {
+    let members: Vec<Value> = group
+        .members
+        .iter()
+        .map(|m| {
+            json!({
+                "value": m.user_id,
+                "display": m.user_name,
+                "$ref": format!("/scim/v2/Users/{}", m.user_id),
+            })
+        })
+        .collect();
+    let mut resource = json!({
+        "schemas": [GROUP_SCHEMA],
+        "id": group.group.id,
+        "displayName": group.group.display_name,
+        "members": members,
+        "meta": {
+            "resourceType": "Group",
+            "created": group.group.created_at.to_rfc3339(),
+            "lastModified": group.group.updated_at.to_rfc3339(),
+            "location": format!("/scim/v2/Groups/{}", group.group.id),
+        },
+    });
+    if let Some(external_id) = &group.group.external_id {
+        resource["externalId"] = json!(external_id);
+    }
+    resource
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GroupBody {
+    #[serde(default)]
+    display_name: String,
+    external_id: Option<String>,
+    #[serde(default)]
+    members: Vec<MemberBody>,
+}
+
+#[derive(serde::Deserialize)]
+struct MemberBody {
+    value: String,
+}
+
+impl GroupBody {
+    fn into_input(self) -> Result<ScimGroupInput, ScimApiError> {
+        Ok(ScimGroupInput {
+            display_name: self.display_name,
+            external_id: self.external_id,
+            members: self
+                .members
+                .iter()
+                .map(|m| member_id(&m.value))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+fn member_id(value: &str) -> Result<Uuid, ScimApiError> {
+    Uuid::parse_str(value)
+        .map_err(|_| ScimApiError::invalid_value(format!("member {value} is not a user id")))
+}
+
+async fn list_groups(
+    AxumState(state): AxumState<State>,
+    Caller(caller): Caller,
+    Query(query): Query<ListQuery>,
+) -> ScimResult {
+    let display_name = query
+        .filter
+        .as_deref()
+        .map(|f| parse_eq_filter(f, "displayName"))
+        .transpose()?;
+    let (start_index, limit, offset) = query.page();
+
+    let page = state
+        .scim_service()
+        .list_groups(&caller, display_name.as_deref(), limit, offset)
+        .await?;
+    let resources: Vec<Value> = page.resources.iter().map(group_resource).collect();
+
+    Ok(scim_json(
+        StatusCode::OK,
+        list_response(Value::Array(resources), page.total_results, start_index),
+    ))
+}
+
+async fn create_group(
+    AxumState(state): AxumState<State>,
+    Caller(caller): Caller,
+    body: Bytes,
+) -> ScimResult {
+    let input = parse_body::<GroupBody>(&body)?.into_input()?;
+    let group = state.scim_service().create_group(&caller, input).await?;
+
+    Ok(scim_json(StatusCode::CREATED, group_resource(&group)))
+}
+
+async fn get_group(
+    AxumState(state): AxumState<State>,
+    Caller(caller): Caller,
+    Path(id): Path<String>,
+) -> ScimResult {
+    let group = state
+        .scim_service()
+        .get_group(&caller, parse_id(&id)?)
+        .await?;
+
+    Ok(scim_json(StatusCode::OK, group_resource(&group)))
+}
+
+async fn replace_group(
+    AxumState(state): AxumState<State>,
+    Caller(caller): Caller,
+    Path(id): Path<String>,
+    body: Bytes,
+) -> ScimResult {
+    let input = parse_body::<GroupBody>(&body)?.into_input()?;
+    let group = state
+        .scim_service()
+        .replace_group(&caller, parse_id(&id)?, input)
+        .await?;
+
+    Ok(scim_json(StatusCode::OK, group_resource(&group)))
+}
+
+async fn patch_group(
+    AxumState(state): AxumState<State>,
+    Caller(caller): Caller,
+    Path(id): Path<String>,
+    body: Bytes,
+) -> ScimResult {
+    let id = parse_id(&id)?;
+    let patch: PatchBody = parse_body(&body)?;
+    let service = state.scim_service();
+
+    let current = service.get_group(&caller, id).await?;
+    let mut input = ScimGroupInput {
+        display_name: current.group.display_name,
+        external_id: current.group.external_id,
+        members: current.members.iter().map(|m| m.user_id).collect(),
+    };
+    apply_group_patch(&mut input, patch.operations)?;
+    let group = service.replace_group(&caller, id, input).await?;
+
+    Ok(scim_json(StatusCode::OK, group_resource(&group)))
+}
+
+async fn delete_group(
+    AxumState(state): AxumState<State>,
+    Caller(caller): Caller,
+    Path(id): Path<String>,
+) -> ScimResult {
+    state
+        .scim_service()
+        .delete_group(&caller, parse_id(&id)?)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+// ─── PATCH ───────────────────────────────────────────────────────────
+
+#[derive(serde::Deserialize)]
+struct PatchBody {
+    #[serde(rename = "Operations")]
+    operations: Vec<PatchOperation>,
+}
+
+#[derive(serde::Deserialize)]
+struct PatchOperation {
+    op: String,
+    path: Option<String>,
+    #[serde(default)]
+    value: Value,
+}
+
+#[derive(Debug, PartialEq)]
+enum PatchOp {
+    Add,
+    Replace,
+    Remove,
+}
+
+impl PatchOperation {
+    fn op(&self) -> Result<PatchOp, ScimApiError> {
+        match self.op.to_ascii_lowercase().as_str() {
+            "add" => Ok(PatchOp::Add),
+            "replace" => Ok(PatchOp::Replace),
+            "remove" => Ok(PatchOp::Remove),
+            other => Err(ScimApiError::invalid_value(format!(
+                "unsupported patch op: {other}"
+            ))),
+        }
+    }
+
+    /// Expand an operation into (attribute path, value) pairs. Without a
+    /// path the value is an object of attributes, which is how several
+    /// IdPs send replace operations.
+    fn targets(&self) -> Result<Vec<(String, Value)>, ScimApiError> {
+        match &self.path {
+            Some(path) => Ok(vec![(path.trim().to_string(), self.value.clone())]),
+            None => match &self.value {
+                Value::Object(map) => Ok(map.iter().map(|(k, v)| (k.clone(), v.clone())).collect()),
+                _ => Err(ScimApiError::new(
+                    StatusCode::BAD_REQUEST,
+                    Some("noTarget"),
+                    "patch operation needs a path or an object value",
+                )),
+            },
+        }
+    }
+}
+
+/// Strip the core schema URN some IdPs prefix attribute paths with.
+fn attribute_name<'a>(path: &'a str, schema: &str) -> &'a str {
+    path.strip_prefix(schema)
+        .and_then(|p| p.strip_prefix(':'))
+        .unwrap_or(path)
+}
+
+fn apply_user_patch(
+    input: &mut ScimUserInput,
+    operations: Vec<PatchOperation>,
+) -> Result<(), ScimApiError> {
+    for operation in operations {
+        let op = operation.op()?;
+        for (path, value) in operation.targets()? {
+            let attribute = attribute_name(&path, USER_SCHEMA);
+            let value = if op == PatchOp::Remove {
+                Value::Null
+            } else {
+                value
+            };
+            match attribute.to_ascii_lowercase().as_str() {
+                "active" => input.active = op != PatchOp::Remove && parse_bool(&value)?,
+                "username" => match optional_string(&value)? {
+                    Some(user_name) => input.user_name = user_name,
+                    None => return Err(ScimApiError::invalid_value("userName is required")),
+                },
+                "externalid" => input.external_id = optional_string(&value)?,
+                "displayname" | "name.formatted" => input.display_name = optional_string(&value)?,
+                _ => {}
+            }
+        }
+    }
+    Ok(())
+}
+
+fn apply_group_patch(
+    input: &mut ScimGroupInput,
+    operations: Vec<PatchOperation>,
+) -> Result<(), ScimApiError> {
+    for operation in operations {
+        let op = operation.op()?;
+        for (path, value) in operation.targets()? {
+            let attribute = attribute_name(&path, GROUP_SCHEMA);
+            let lower = attribute.to_ascii_lowercase();
+
+            if lower == "displayname" {
+                match (&op, optional_string(&value)?) {
+                    (PatchOp::Remove, _) | (_, None) => {
+                        return Err(ScimApiError::invalid_value("displayName is required"));
+                    }
+                    (_, Some(name)) => input.display_name = name,
+                }
+            } else if lower == "externalid" {
+                input.external_id = match op {
+                    PatchOp::Remove => None,
+                    _ => optional_string(&value)?,
+                };
+            } else if lower == "members" {
+                let ids = member_values(&value)?;
+                match op {
+                    PatchOp::Add => {
+                        for id in ids {
+                            if !input.members.contains(&id) {
+                                input.members.push(id);
+                            }
+                        }
+                    }
+                    PatchOp::Replace => input.members = ids,
+                    // Remove with no value clears the group.
+                    PatchOp::Remove if value.is_null() => input.members.clear(),
+                    PatchOp::Remove => input.members.retain(|m| !ids.contains(m)),
+                }
+            } else if let Some(filter) = lower
+                .strip_prefix("members[")
+                .and_then(|f| f.strip_suffix(']'))
+            {
+                // members[value eq "<id>"], only meaningful for remove.
+                if op != PatchOp::Remove {
+                    return Err(ScimApiError::new(
+                        StatusCode::BAD_REQUEST,
+                        Some("invalidPath"),
+                        "member filters are only supported for remove",
+                    ));
+                }
+                let id = member_id(&parse_eq_filter(filter, "value").map_err(|_| {
+                    ScimApiError::new(
+                        StatusCode::BAD_REQUEST,
+                        Some("invalidPath"),
+                        format!("unsupported member path: {path}"),
+                    )
+                })?)?;
+                input.members.retain(|m| *m != id);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Member values arrive as `[{"value": "<id>"}, ...]` (or a single object).
+fn member_values(value: &Value) -> Result<Vec<Uuid>, ScimApiError> {
+    let items = match value {
+        Value::Null => return Ok(Vec::new()),
+        Value::Array(items) => items.as_slice(),
+        Value::Object(_) => std::slice::from_ref(value),
+        _ => return Err(ScimApiError::invalid_value("members must be a list")),
+    };
+
+    items
+        .iter()
+        .map(|item| {
+            item.get("value")
+                .and_then(Value::as_str)
+                .ok_or_else(|| ScimApiError::invalid_value("member is missing a value"))
+                .and_then(member_id)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ops(value: Value) -> Vec<PatchOperation> {
+        serde_json::from_value::<PatchBody>(json!({ "Operations": value }))
+            .unwrap()
+            .operations
+    }
+
+    fn user() -> ScimUserInput {
+        ScimUserInput {
+            user_name: "alice@example.com".into(),
+            external_id: Some("ext-1".into()),
+            display_name: Some("Alice".into()),
+            email: None,
+            active: true,
+        }
+    }
+
+    #[test]
+    fn parses_eq_filters() {
+        assert_eq!(
+            parse_eq_filter(r#"userName eq "alice@example.com""#, "userName").unwrap(),
+            "alice@example.com"
+        );
+        assert_eq!(
+            parse_eq_filter(r#"username EQ "Bob \"B\"""#, "userName").unwrap(),
+            "Bob \"B\""
+        );
+        assert!(parse_eq_filter(r#"userName co "alice""#, "userName").is_err());
+        assert!(parse_eq_filter(r#"displayName eq "x""#, "userName").is_err());
+        assert!(parse_eq_filter("userName eq alice", "userName").is_err());
+    }
+
+    #[test]
+    fn user_patch_deactivates_with_path_or_value_object() {
+        let mut input = user();
+        apply_user_patch(&mut input, ops(json!([{ "op": "Replace", "path": "active", "value": "False" }])))
+            .unwrap();
+        assert!(!input.active);
+
+        let mut input = user();
+        apply_user_patch(&mut input, ops(json!([{ "op": "replace", "value": { "active": false } }])))
+            .unwrap();
+        assert!(!input.active);
+    }
+
+    #[test]
+    fn user_patch_updates_attributes_and_ignores_unknown() {
+        let mut input = user();
+        apply_user_patch(
+            &mut input,
+            ops(json!([
+                { "op": "replace", "path": "userName", "value": "alice@corp.example" },
+                { "op": "remove", "path": "externalId" },
+                { "op": "add", "path": "name.givenName", "value": "Alice" },
+                { "op": "replace", "value": { "displayName": "Alice A." } },
+            ])),
+        )
+        .unwrap();
+        assert_eq!(input.user_name, "alice@corp.example");
+        assert_eq!(input.external_id, None);
+        assert_eq!(input.display_name.as_deref(), Some("Alice A."));
+        assert!(input.active);
+    }
+
+    #[test]
+    fn user_patch_rejects_bad_values() {
+        let mut input = user();
+        assert!(
+            apply_user_patch(&mut input, ops(json!([{ "op": "replace", "path": "active", "value": 3 }])))
+                .is_err()
+        );
+        assert!(
+            apply_user_patch(&mut input, ops(json!([{ "op": "remove", "path": "userName" }])))
+                .is_err()
+        );
+        assert!(
+            apply_user_patch(&mut input, ops(json!([{ "op": "move", "path": "active" }]))).is_err()
+        );
+    }
+
+    #[test]
+    fn group_patch_adds_and_removes_members() {
+        let a = Uuid::now_v7();
+        let b = Uuid::now_v7();
+        let c = Uuid::now_v7();
+        let mut input = ScimGroupInput {
+            display_name: "Engineering".into(),
+            external_id: None,
+            members: vec![a],
+        };
+
+        apply_group_patch(
+            &mut input,
+            ops(json!([
+                { "op": "add", "path": "members", "value": [{ "value": a.to_string() }, { "value": b.to_string() }, { "value": c.to_string() }] },
+            ])),
+        )
+        .unwrap();
+        assert_eq!(input.members, vec![a, b, c]);
+
+        apply_group_patch(
+            &mut input,
+            ops(json!([
+                { "op": "remove", "path": format!("members[value eq \"{b}\"]") },
+                { "op": "remove", "path": "members", "value": [{ "value": c.to_string() }] },
+            ])),
+        )
+        .unwrap();
+        assert_eq!(input.members, vec![a]);
+
+        apply_group_patch(
+            &mut input,
+            ops(json!([
+                { "op": "replace", "value": { "displayName": "Platform", "members": [{ "value": c.to_string() }] } },
+            ])),
+        )
+        .unwrap();
+        assert_eq!(input.display_name, "Platform");
+        assert_eq!(input.members, vec![c]);
+
+        apply_group_patch(&mut input, ops(json!([{ "op": "remove", "path": "members" }]))).unwrap();
+        assert!(input.members.is_empty());
+    }
+
+    #[test]
+    fn group_patch_rejects_bad_members() {
+        let mut input = ScimGroupInput {
+            display_name: "Engineering".into(),
+            external_id: None,
+            members: Vec::new(),
+        };
+        assert!(
+            apply_group_patch(
+                &mut input,
+                ops(json!([{ "op": "add", "path": "members", "value": [{ "value": "not-a-uuid" }] }])),
+            )
+            .is_err()
+        );
+        assert!(
+            apply_group_patch(&mut input, ops(json!([{ "op": "remove", "path": "displayName" }])))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn user_body_prefers_primary_email_and_defaults_active() {
+        let body: UserBody = serde_json::from_value(json!({
+            "schemas": [USER_SCHEMA],
+            "userName": "alice",
+            "name": { "formatted": "Alice Example", "givenName": "Alice" },
+            "emails": [
+                { "value": "alice@home.example", "type": "home" },
+                { "value": "alice@example.com", "type": "work", "primary": true },
+            ],
+        }))
+        .unwrap();
+        let input = body.into_input().unwrap();
+        assert_eq!(input.email.as_deref(), Some("alice@example.com"));
+        assert_eq!(input.display_name.as_deref(), Some("Alice Example"));
+        assert!(input.active);
+    }
+}
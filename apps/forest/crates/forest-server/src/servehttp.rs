@@ -5,12 +5,15 @@ use notmad::{Component, ComponentInfo, MadError};
 use tokio::net::TcpListener;
 use tokio_util::sync::CancellationToken;
 
-use crate::{object_store::ObjectStore, webhooks};
+use crate::{
+    State, object_store::ObjectStore, scim, services::secrets::SecretRegistryState, webhooks,
+};
 
 pub struct ServeHttp {
     pub host: SocketAddr,
     pub object_store: ObjectStore,
     pub db: sqlx::PgPool,
+    pub state: State,
 }
 
 impl Component for ServeHttp {
@@ -24,7 +27,9 @@ impl Component for ServeHttp {
             .merge(crate::oci_registry::oci_routes(self.object_store.clone()))
             .merge(webhooks::webhook_routes(webhooks::WebhookState {
                 db: self.db.clone(),
-            }));
+                secrets: self.state.secret_registry(),
+            }))
+            .merge(scim::scim_routes(self.state.clone()));
 
         let listener = TcpListener::bind(&self.host)
             .await
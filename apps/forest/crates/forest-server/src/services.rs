@@ -11,18 +11,27 @@ pub mod artifact_registry;
 pub mod artifact_staging_registry;
 pub mod destination_aggregate;
 pub mod destination_registry;
+pub mod domain_proofs;
+pub mod drift;
+pub mod environment_protection;
 pub mod environment_registry;
 pub mod event_bus;
 pub mod event_subscription;
 pub mod release_logs_registry;
 pub mod release_registry;
+pub mod release_request;
 
 pub mod notification_registry;
 pub mod organisations;
 pub mod release_event_store;
 pub mod release_finalizer;
 pub mod release_pipeline;
+pub mod release_schedule;
 pub mod release_token_registry;
 pub mod users;
 pub mod registration_policy;
 pub mod release_health;
+pub mod release_history;
+pub mod retention;
+pub mod scim;
+pub mod secrets;
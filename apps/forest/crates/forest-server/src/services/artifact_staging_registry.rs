@@ -133,10 +133,17 @@ impl ArtifactStagingRegistry {
         id: &uuid::Uuid,
         env: &str,
     ) -> anyhow::Result<Vec<(PathBuf, String)>> {
-        let rec = sqlx::query!("SELECT artifact_id FROM artifacts WHERE id = $1", id)
-            .fetch_one(&self.db)
-            .await
-            .context("get artifact id")?;
+        let rec = sqlx::query!(
+            "SELECT artifact_id, files_purged_at FROM artifacts WHERE id = $1",
+            id
+        )
+        .fetch_one(&self.db)
+        .await
+        .context("get artifact id")?;
+        // Releasing with no files would deploy nothing.
+        if let Some(purged_at) = rec.files_purged_at {
+            anyhow::bail!("artifact files were purged by the retention policy at {purged_at}");
+        }
         let artifact_id = rec.artifact_id;
 
         // Get file metadata from DB
@@ -2,6 +2,7 @@ use std::collections::HashMap;
 
 use anyhow::Context;
 use forest_event_store::EventStore;
+use forest_models::QueuePolicy;
 use sqlx::{PgPool, Row};
 use uuid::Uuid;
 
@@ -21,6 +22,8 @@ pub struct DestinationRecord {
     pub type_organisation: String,
     pub type_name: String,
     pub type_version: i32,
+    pub runner_labels: HashMap<String, String>,
+    pub queue_policy: QueuePolicy,
 }
 
 // ============================================================
@@ -51,6 +54,8 @@ impl DestinationAggregateService {
         type_organisation: &str,
         type_name: &str,
         type_version: u32,
+        runner_labels: HashMap<String, String>,
+        queue_policy: QueuePolicy,
     ) -> anyhow::Result<Uuid> {
         // Resolve environment name to environment_id from existing projection
         let env_row = sqlx::query(
@@ -81,6 +86,8 @@ impl DestinationAggregateService {
                 type_organisation: type_organisation.to_string(),
                 type_name: type_name.to_string(),
                 type_version,
+                runner_labels: runner_labels.clone(),
+                queue_policy,
             },
         )?;
 
@@ -98,8 +105,9 @@ impl DestinationAggregateService {
                     sqlx::query(
                         "INSERT INTO destinations (
                             id, organisation, name, environment, environment_id,
-                            metadata, type_organisation, type_name, type_version
-                        ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)",
+                            metadata, type_organisation, type_name, type_version,
+                            runner_labels, queue_policy
+                        ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)",
                     )
                     .bind(destination_id)
                     .bind(&org)
@@ -110,6 +118,8 @@ impl DestinationAggregateService {
                     .bind(&t_org)
                     .bind(&t_name)
                     .bind(type_version as i32)
+                    .bind(serde_json::to_value(&runner_labels).unwrap())
+                    .bind(queue_policy.as_str())
                     .execute(&mut **tx)
                     .await
                     .context("insert destination projection")?;
@@ -163,6 +173,90 @@ impl DestinationAggregateService {
         Ok(())
     }
 
+    pub async fn update_runner_labels(
+        &self,
+        organisation: &str,
+        name: &str,
+        runner_labels: HashMap<String, String>,
+    ) -> anyhow::Result<()> {
+        let key = destination::stream_key(organisation, name);
+        let mut root = self
+            .event_store
+            .load_or_default::<DestinationAggregate>(&key)
+            .await?;
+
+        DestinationAggregate::update_runner_labels(&mut root, runner_labels.clone())?;
+
+        let org_owned = organisation.to_string();
+        let name_owned = name.to_string();
+
+        self.event_store
+            .save_with(&mut root, move |_events, tx| {
+                Box::pin(async move {
+                    let res = sqlx::query(
+                        "UPDATE destinations SET runner_labels = $1
+                         WHERE organisation = $2 AND name = $3",
+                    )
+                    .bind(serde_json::to_value(&runner_labels).unwrap())
+                    .bind(&org_owned)
+                    .bind(&name_owned)
+                    .execute(&mut **tx)
+                    .await
+                    .context("update destination runner labels projection")?;
+
+                    if res.rows_affected() != 1 {
+                        anyhow::bail!("destination projection not found for update");
+                    }
+                    Ok(())
+                })
+            })
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn update_queue_policy(
+        &self,
+        organisation: &str,
+        name: &str,
+        queue_policy: QueuePolicy,
+    ) -> anyhow::Result<()> {
+        let key = destination::stream_key(organisation, name);
+        let mut root = self
+            .event_store
+            .load_or_default::<DestinationAggregate>(&key)
+            .await?;
+
+        DestinationAggregate::update_queue_policy(&mut root, queue_policy)?;
+
+        let org_owned = organisation.to_string();
+        let name_owned = name.to_string();
+
+        self.event_store
+            .save_with(&mut root, move |_events, tx| {
+                Box::pin(async move {
+                    let res = sqlx::query(
+                        "UPDATE destinations SET queue_policy = $1
+                         WHERE organisation = $2 AND name = $3",
+                    )
+                    .bind(queue_policy.as_str())
+                    .bind(&org_owned)
+                    .bind(&name_owned)
+                    .execute(&mut **tx)
+                    .await
+                    .context("update destination queue policy projection")?;
+
+                    if res.rows_affected() != 1 {
+                        anyhow::bail!("destination projection not found for update");
+                    }
+                    Ok(())
+                })
+            })
+            .await?;
+
+        Ok(())
+    }
+
     pub async fn delete_destination(
         &self,
         organisation: &str,
@@ -213,7 +307,8 @@ impl DestinationAggregateService {
     ) -> anyhow::Result<Option<DestinationRecord>> {
         let row = sqlx::query(
             "SELECT id, organisation, name, metadata, environment, environment_id,
-                    type_organisation, type_name, type_version
+                    type_organisation, type_name, type_version, runner_labels,
+                    queue_policy
              FROM destinations
              WHERE id = $1
              LIMIT 1",
@@ -235,7 +330,8 @@ impl DestinationAggregateService {
     ) -> anyhow::Result<Option<DestinationRecord>> {
         let row = sqlx::query(
             "SELECT id, organisation, name, metadata, environment, environment_id,
-                    type_organisation, type_name, type_version
+                    type_organisation, type_name, type_version, runner_labels,
+                    queue_policy
              FROM destinations
              WHERE organisation = $1 AND name = $2
              LIMIT 1",
@@ -254,6 +350,8 @@ impl DestinationAggregateService {
 
 fn row_to_record(row: sqlx::postgres::PgRow) -> anyhow::Result<DestinationRecord> {
     let metadata: serde_json::Value = row.get("metadata");
+    let runner_labels: serde_json::Value = row.get("runner_labels");
+    let queue_policy: String = row.get("queue_policy");
     Ok(DestinationRecord {
         id: row.get("id"),
         organisation: row.get("organisation"),
@@ -264,6 +362,9 @@ fn row_to_record(row: sqlx::postgres::PgRow) -> anyhow::Result<DestinationRecord
         type_organisation: row.get("type_organisation"),
         type_name: row.get("type_name"),
         type_version: row.get("type_version"),
+        runner_labels: serde_json::from_value(runner_labels)
+            .context("runner labels are invalid")?,
+        queue_policy: queue_policy.parse().map_err(anyhow::Error::msg)?,
     })
 }
 
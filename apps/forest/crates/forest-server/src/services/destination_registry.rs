@@ -129,7 +129,9 @@ impl DestinationRegistry {
                     environment,
                     type_organisation,
                     type_name,
-                    type_version
+                    type_version,
+                    runner_labels,
+                    queue_policy
                 FROM destinations
                 WHERE id = $1
                 LIMIT 1;
@@ -154,7 +156,11 @@ impl DestinationRegistry {
                 description: String::new(),
                 fields: vec![],
             },
-        )))
+        )
+        .with_runner_labels(
+            serde_json::from_value(rec.runner_labels).context("runner labels are invalid")?,
+        )
+        .with_queue_policy(rec.queue_policy.parse().map_err(anyhow::Error::msg)?)))
     }
 }
 
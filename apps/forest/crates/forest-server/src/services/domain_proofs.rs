@@ -0,0 +1,67 @@
+//! Proof that an organisation controls an email domain: a DNS TXT record
+//! only whoever runs the domain can publish. Forage asks for the same
+//! record before an organisation's IdP may vouch for a domain, so one
+//! record serves both.
+
+use anyhow::Context;
+use hickory_resolver::TokioResolver;
+use sha2::Digest;
+use uuid::Uuid;
+
+static RESOLVER: tokio::sync::OnceCell<TokioResolver> = tokio::sync::OnceCell::const_new();
+
+/// Where the proof for `domain` is published.
+pub fn verification_name(domain: &str) -> String {
+    format!("_forage-verification.{domain}")
+}
+
+/// TXT record value that proves `organisation_id` controls `domain`.
+pub fn verification_value(organisation_id: Uuid, domain: &str) -> String {
+    let digest = sha2::Sha256::digest(format!("{organisation_id}:{domain}").as_bytes());
+    format!("forage-domain-verification={}", hex::encode(digest))
+}
+
+/// Whether the TXT records at [`verification_name`] prove the domain.
+fn proves(records: &[String], organisation_id: Uuid, domain: &str) -> bool {
+    let expected = verification_value(organisation_id, domain);
+    records.iter().any(|r| r.trim() == expected)
+}
+
+/// Whether `organisation_id` has published the proof for `domain`. A
+/// missing record is `false`; a failed lookup is an error, so callers
+/// don't mistake a DNS outage for a missing proof or the reverse.
+pub async fn is_proven(organisation_id: Uuid, domain: &str) -> anyhow::Result<bool> {
+    let resolver = RESOLVER
+        .get_or_try_init(|| async { Ok::<_, anyhow::Error>(TokioResolver::builder_tokio()?.build()) })
+        .await
+        .context("load DNS resolver configuration")?;
+
+    let name = verification_name(domain);
+    let records: Vec<String> = match resolver.txt_lookup(name.as_str()).await {
+        Ok(lookup) => lookup.iter().map(|txt| txt.to_string()).collect(),
+        Err(e) if e.is_no_records_found() || e.is_nx_domain() => Vec::new(),
+        Err(e) => return Err(anyhow::anyhow!("DNS lookup for {name} failed: {e}")),
+    };
+
+    Ok(proves(&records, organisation_id, domain))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_the_organisations_own_record_proves_a_domain() {
+        let org = Uuid::now_v7();
+        let other = Uuid::now_v7();
+        let records = vec![
+            "v=spf1 -all".to_string(),
+            verification_value(other, "example.com"),
+        ];
+        assert!(!proves(&records, org, "example.com"));
+
+        let records = vec![verification_value(org, "example.com")];
+        assert!(proves(&records, org, "example.com"));
+        assert!(!proves(&records, org, "example.org"));
+    }
+}
@@ -0,0 +1,238 @@
+//! Drift detection: whether each destination still matches the release
+//! deployed to it.
+//!
+//! The [`DriftDetector`](crate::drift_detector::DriftDetector) picks the
+//! deployed releases that are due a check, asks their destination type to
+//! compare them with the target, and records the outcome here. Only the
+//! latest successful deploy of a project to a destination is checked.
+
+use anyhow::Context;
+use sqlx::PgPool;
+
+use crate::{State, services::release_registry::ReleaseItem};
+
+/// Checks claimed per pass, so one slow pass can't hold every destination.
+const CHECK_BATCH: i64 = 100;
+
+#[derive(Clone)]
+pub struct DriftRegistry {
+    db: PgPool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DriftStatus {
+    InSync,
+    Drifted,
+    /// The check itself failed, e.g. the target was unreachable.
+    Error,
+}
+
+impl DriftStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::InSync => "IN_SYNC",
+            Self::Drifted => "DRIFTED",
+            Self::Error => "ERROR",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "IN_SYNC" => Some(Self::InSync),
+            "DRIFTED" => Some(Self::Drifted),
+            "ERROR" => Some(Self::Error),
+            _ => None,
+        }
+    }
+}
+
+/// A change worth notifying about. Errors aren't: they say nothing about
+/// the target, and a flaky endpoint would otherwise page on every pass.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DriftTransition {
+    Detected,
+    Resolved,
+}
+
+impl DriftTransition {
+    /// Compare the release's previous check with this one. `previous` is
+    /// `None` when the release hadn't been checked before.
+    pub fn between(previous: Option<DriftStatus>, current: DriftStatus) -> Option<Self> {
+        match (previous, current) {
+            (Some(DriftStatus::Drifted), DriftStatus::Drifted) => None,
+            (_, DriftStatus::Drifted) => Some(Self::Detected),
+            (Some(DriftStatus::Drifted), DriftStatus::InSync) => Some(Self::Resolved),
+            _ => None,
+        }
+    }
+}
+
+impl DriftRegistry {
+    /// Deployed releases due a check: those never checked, deployed since
+    /// their last check, or last checked more than `interval` ago.
+    /// Destinations with a release of any project in flight are skipped; they
+    /// are about to change anyway, and a check could contend with the
+    /// release for the target.
+    pub async fn due(&self, interval: std::time::Duration) -> anyhow::Result<Vec<ReleaseItem>> {
+        let rows = sqlx::query_as!(
+            ReleaseItem,
+            r#"SELECT
+                   rs.release_id AS "id!",
+                   rs.release_intent_id AS "release_intent_id!",
+                   rs.artifact_id AS "artifact!",
+                   rs.project_id AS "project_id!",
+                   rs.destination_id AS "destination_id!",
+                   rs.status AS "status!",
+                   p.project
+               FROM (
+                   SELECT DISTINCT ON (project_id, destination_id) *
+                   FROM release_states
+                   WHERE mode = 'deploy' AND status = 'SUCCEEDED'
+                   ORDER BY project_id, destination_id, completed_at DESC NULLS LAST
+               ) rs
+               JOIN projects p ON p.id = rs.project_id
+               LEFT JOIN destination_drift dd
+                 ON dd.destination_id = rs.destination_id AND dd.project_id = rs.project_id
+               WHERE (
+                   dd.release_id IS NULL
+                   OR dd.release_id <> rs.release_id
+                   OR dd.checked_at < now() - make_interval(secs => $1::double precision)
+               )
+               AND NOT EXISTS (
+                   SELECT 1 FROM release_states active
+                   WHERE active.destination_id = rs.destination_id
+                     AND active.status IN ('QUEUED', 'ASSIGNED', 'RUNNING')
+               )
+               ORDER BY dd.checked_at ASC NULLS FIRST
+               LIMIT $2"#,
+            interval.as_secs_f64(),
+            CHECK_BATCH,
+        )
+        .fetch_all(&self.db)
+        .await
+        .context("list releases due a drift check")?;
+
+        Ok(rows)
+    }
+
+    /// Claim a check so two servers don't run it at once. Returns false
+    /// when someone else checked it since [`due`](Self::due) listed it, or a
+    /// release to the destination has been queued since.
+    pub async fn claim(
+        &self,
+        release: &ReleaseItem,
+        interval: std::time::Duration,
+    ) -> anyhow::Result<bool> {
+        let claimed = sqlx::query_scalar!(
+            r#"INSERT INTO destination_drift (destination_id, project_id, release_id)
+               SELECT $1, $2, $3
+               WHERE NOT EXISTS (
+                   SELECT 1 FROM release_states active
+                   WHERE active.destination_id = $1
+                     AND active.status IN ('QUEUED', 'ASSIGNED', 'RUNNING')
+               )
+               ON CONFLICT (destination_id, project_id) DO UPDATE SET checked_at = now()
+               WHERE destination_drift.release_id <> EXCLUDED.release_id
+                  OR destination_drift.checked_at < now() - make_interval(secs => $4::double precision)
+               RETURNING destination_id"#,
+            release.destination_id,
+            release.project_id,
+            release.id,
+            interval.as_secs_f64(),
+        )
+        .fetch_optional(&self.db)
+        .await
+        .context("claim drift check")?;
+
+        Ok(claimed.is_some())
+    }
+
+    /// Record the outcome of a check. Returns what the same release was
+    /// last checked as, if anything.
+    pub async fn record(
+        &self,
+        release: &ReleaseItem,
+        status: DriftStatus,
+        detail: Option<&str>,
+    ) -> anyhow::Result<Option<DriftStatus>> {
+        let previous = sqlx::query_scalar!(
+            r#"UPDATE destination_drift d
+               SET release_id = $3,
+                   status = $4,
+                   detail = $5,
+                   checked_at = now(),
+                   changed_at = CASE
+                       WHEN prev.release_id <> $3 OR prev.status IS DISTINCT FROM $4 THEN now()
+                       ELSE d.changed_at
+                   END
+               FROM (
+                   SELECT release_id, status FROM destination_drift
+                   WHERE destination_id = $1 AND project_id = $2
+               ) prev
+               WHERE d.destination_id = $1 AND d.project_id = $2
+               RETURNING CASE WHEN prev.release_id = $3 THEN prev.status END AS previous"#,
+            release.destination_id,
+            release.project_id,
+            release.id,
+            status.as_str(),
+            detail,
+        )
+        .fetch_optional(&self.db)
+        .await
+        .context("record drift check")?
+        .flatten();
+
+        Ok(previous.as_deref().and_then(DriftStatus::parse))
+    }
+}
+
+pub trait DriftRegistryState {
+    fn drift_registry(&self) -> DriftRegistry;
+}
+
+impl DriftRegistryState for State {
+    fn drift_registry(&self) -> DriftRegistry {
+        DriftRegistry {
+            db: self.db.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drift_is_notified_once_until_resolved() {
+        use DriftStatus::*;
+
+        assert_eq!(
+            DriftTransition::between(None, Drifted),
+            Some(DriftTransition::Detected)
+        );
+        assert_eq!(
+            DriftTransition::between(Some(InSync), Drifted),
+            Some(DriftTransition::Detected)
+        );
+        assert_eq!(DriftTransition::between(Some(Drifted), Drifted), None);
+        assert_eq!(
+            DriftTransition::between(Some(Drifted), InSync),
+            Some(DriftTransition::Resolved)
+        );
+        assert_eq!(DriftTransition::between(None, InSync), None);
+    }
+
+    #[test]
+    fn errors_neither_detect_nor_resolve() {
+        use DriftStatus::*;
+
+        assert_eq!(DriftTransition::between(Some(Drifted), Error), None);
+        assert_eq!(DriftTransition::between(Some(InSync), Error), None);
+        // Nothing is known about the target while checks fail, so drift
+        // seen afterwards is reported again.
+        assert_eq!(
+            DriftTransition::between(Some(Error), Drifted),
+            Some(DriftTransition::Detected)
+        );
+    }
+}
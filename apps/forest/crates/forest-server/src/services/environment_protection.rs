@@ -0,0 +1,370 @@
+use anyhow::Context;
+use regex::Regex;
+use uuid::Uuid;
+
+/// Organisation-wide rules for releasing into an environment.
+///
+/// Unlike project policies these live on the environment itself, so every
+/// project releasing into it is held to them. They are checked on top of,
+/// not instead of, the project's policies.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EnvironmentProtection {
+    pub protected: bool,
+    pub required_prior_environment: Option<String>,
+    pub approver_groups: Vec<String>,
+    pub allowed_branches: Vec<String>,
+}
+
+impl EnvironmentProtection {
+    /// Trim and de-duplicate the rule lists, dropping blank entries.
+    pub fn normalized(self) -> Self {
+        fn clean(values: Vec<String>) -> Vec<String> {
+            let mut out: Vec<String> = Vec::new();
+            for v in values {
+                let v = v.trim();
+                if !v.is_empty() && !out.iter().any(|o| o.eq_ignore_ascii_case(v)) {
+                    out.push(v.to_string());
+                }
+            }
+            out
+        }
+
+        Self {
+            protected: self.protected,
+            required_prior_environment: self
+                .required_prior_environment
+                .map(|e| e.trim().to_string())
+                .filter(|e| !e.is_empty()),
+            approver_groups: clean(self.approver_groups),
+            allowed_branches: clean(self.allowed_branches),
+        }
+    }
+
+    /// Validate rules that don't need the database.
+    pub fn validate(&self, environment: &str) -> anyhow::Result<()> {
+        if self.required_prior_environment.as_deref() == Some(environment) {
+            anyhow::bail!("an environment cannot require itself as the prior environment");
+        }
+        if !self.approver_groups.is_empty() && !self.protected {
+            anyhow::bail!("approver groups only apply to protected environments");
+        }
+        for pattern in &self.allowed_branches {
+            Regex::new(pattern)
+                .with_context(|| format!("invalid regex for allowed branch '{pattern}'"))?;
+        }
+        Ok(())
+    }
+
+    /// Returns `Some(reason)` when `branch` may not be released into the
+    /// environment. Artifacts without branch information are refused as soon
+    /// as the environment restricts branches.
+    pub fn check_branch(&self, environment: &str, branch: Option<&str>) -> Option<String> {
+        if self.allowed_branches.is_empty() {
+            return None;
+        }
+        let allowed = self.allowed_branches.join("', '");
+        let Some(branch) = branch else {
+            return Some(format!(
+                "environment '{environment}' only allows branches matching '{allowed}', and the artifact has no branch"
+            ));
+        };
+        let matched = self
+            .allowed_branches
+            .iter()
+            .any(|p| Regex::new(p).is_ok_and(|re| re.is_match(branch)));
+        if matched {
+            None
+        } else {
+            Some(format!(
+                "environment '{environment}' only allows branches matching '{allowed}', not '{branch}'"
+            ))
+        }
+    }
+
+    /// Approvals a release needs before it may run: none for unprotected
+    /// environments, otherwise one per approver group (at least one).
+    pub fn required_approvals(&self) -> i32 {
+        if !self.protected {
+            0
+        } else {
+            self.approver_groups.len().max(1) as i32
+        }
+    }
+
+    /// Whether the author of a release may approve it themselves. Only
+    /// `force_bypass` allows it, and never into a protected environment:
+    /// the approval there is the four-eyes check.
+    pub fn allows_self_approval(&self, force_bypass: bool) -> bool {
+        force_bypass && !self.protected
+    }
+}
+
+/// Load the protection rules of `environment` in the organisation owning
+/// `project_id`. Unknown environments have no rules.
+pub(crate) async fn load_for_project(
+    conn: &mut sqlx::PgConnection,
+    project_id: &Uuid,
+    environment: &str,
+) -> anyhow::Result<EnvironmentProtection> {
+    let rec = sqlx::query!(
+        r#"SELECT e.protected, e.required_prior_environment, e.approver_groups, e.allowed_branches
+         FROM environments e
+         JOIN projects p ON p.organisation = e.organisation
+         WHERE p.id = $1 AND e.name = $2"#,
+        project_id,
+        environment,
+    )
+    .fetch_optional(&mut *conn)
+    .await
+    .context("load environment protection")?;
+
+    Ok(rec
+        .map(|r| EnvironmentProtection {
+            protected: r.protected,
+            required_prior_environment: r.required_prior_environment,
+            approver_groups: r.approver_groups,
+            allowed_branches: r.allowed_branches,
+        })
+        .unwrap_or_default())
+}
+
+/// The branch an artifact was annotated with, if any.
+pub(crate) async fn artifact_branch(
+    conn: &mut sqlx::PgConnection,
+    artifact_id: &Uuid,
+) -> anyhow::Result<Option<String>> {
+    let branch = sqlx::query_scalar!(
+        r#"SELECT ref->>'commit_branch' FROM annotations WHERE artifact_id = $1 LIMIT 1"#,
+        artifact_id,
+    )
+    .fetch_optional(&mut *conn)
+    .await
+    .context("load artifact branch")?;
+
+    Ok(branch.flatten())
+}
+
+/// Returns `Some(reason)` when the artifact has not yet been released
+/// successfully to the environment's required prior environment.
+pub(crate) async fn check_prior_environment(
+    conn: &mut sqlx::PgConnection,
+    project_id: &Uuid,
+    artifact_id: &Uuid,
+    environment: &str,
+    protection: &EnvironmentProtection,
+) -> anyhow::Result<Option<String>> {
+    let Some(prior) = protection.required_prior_environment.as_deref() else {
+        return Ok(None);
+    };
+
+    let released = sqlx::query_scalar!(
+        r#"SELECT EXISTS (
+             SELECT 1 FROM release_states rs
+             JOIN destinations d ON rs.destination_id = d.id
+             WHERE rs.project_id = $1
+               AND rs.artifact_id = $2
+               AND d.environment = $3
+               AND rs.status = 'SUCCEEDED'
+           ) as "exists!""#,
+        project_id,
+        artifact_id,
+        prior,
+    )
+    .fetch_one(&mut *conn)
+    .await
+    .context("check prior environment release")?;
+
+    Ok((!released).then(|| {
+        format!("environment '{environment}' requires a successful release to '{prior}' first")
+    }))
+}
+
+/// Returns `Some(reason)` while a release intent is still missing the
+/// approvals a protected environment asks for.
+pub(crate) async fn check_approvals(
+    conn: &mut sqlx::PgConnection,
+    project_id: &Uuid,
+    release_intent_id: &Uuid,
+    environment: &str,
+    protection: &EnvironmentProtection,
+) -> anyhow::Result<Option<String>> {
+    if !protection.protected {
+        return Ok(None);
+    }
+
+    if protection.approver_groups.is_empty() {
+        let approvals = sqlx::query_scalar!(
+            r#"SELECT COUNT(*) as "count!" FROM approval_decisions
+             WHERE release_intent_id = $1 AND target_environment = $2 AND decision = 'approved'"#,
+            release_intent_id,
+            environment,
+        )
+        .fetch_one(&mut *conn)
+        .await
+        .context("count environment approvals")?;
+
+        return Ok((approvals == 0)
+            .then(|| format!("environment '{environment}' is protected: awaiting approval")));
+    }
+
+    let groups: Vec<String> = protection
+        .approver_groups
+        .iter()
+        .map(|g| g.to_lowercase())
+        .collect();
+    let approved_groups = sqlx::query_scalar!(
+        r#"SELECT DISTINCT lower(g.display_name) as "group!"
+         FROM approval_decisions a
+         JOIN scim_group_members m ON m.user_id = a.user_id
+         JOIN scim_groups g ON g.id = m.group_id
+         JOIN organisations o ON o.id = g.organisation_id
+         JOIN projects p ON p.organisation = o.name
+         JOIN organisation_members om ON om.organisation_id = o.id AND om.user_id = a.user_id
+         WHERE p.id = $1
+           AND a.release_intent_id = $2
+           AND a.target_environment = $3
+           AND a.decision = 'approved'
+           AND lower(g.display_name) = ANY($4)"#,
+        project_id,
+        release_intent_id,
+        environment,
+        &groups,
+    )
+    .fetch_all(&mut *conn)
+    .await
+    .context("load approving groups")?;
+
+    let missing: Vec<&str> = protection
+        .approver_groups
+        .iter()
+        .filter(|g| !approved_groups.contains(&g.to_lowercase()))
+        .map(String::as_str)
+        .collect();
+
+    Ok((!missing.is_empty()).then(|| {
+        format!(
+            "environment '{environment}' is protected: awaiting approval from {}",
+            missing.join(", ")
+        )
+    }))
+}
+
+/// Whether `user_id` may approve releases into a protected environment:
+/// anyone in the organisation when no approver groups are set, otherwise
+/// members of at least one of the groups.
+pub(crate) async fn is_approver(
+    conn: &mut sqlx::PgConnection,
+    organisation: &str,
+    user_id: &Uuid,
+    protection: &EnvironmentProtection,
+) -> anyhow::Result<bool> {
+    if protection.approver_groups.is_empty() {
+        return Ok(true);
+    }
+
+    let groups: Vec<String> = protection
+        .approver_groups
+        .iter()
+        .map(|g| g.to_lowercase())
+        .collect();
+    let is_member = sqlx::query_scalar!(
+        r#"SELECT EXISTS (
+             SELECT 1 FROM scim_group_members m
+             JOIN scim_groups g ON g.id = m.group_id
+             JOIN organisations o ON o.id = g.organisation_id
+             WHERE o.name = $1 AND m.user_id = $2 AND lower(g.display_name) = ANY($3)
+           ) as "exists!""#,
+        organisation,
+        user_id,
+        &groups,
+    )
+    .fetch_one(&mut *conn)
+    .await
+    .context("check approver group membership")?;
+
+    Ok(is_member)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn protection() -> EnvironmentProtection {
+        EnvironmentProtection {
+            protected: true,
+            required_prior_environment: Some("staging".into()),
+            approver_groups: vec!["release-managers".into()],
+            allowed_branches: vec!["^main$".into(), "^release/.*".into()],
+        }
+    }
+
+    #[test]
+    fn normalized_trims_and_dedupes() {
+        let p = EnvironmentProtection {
+            protected: false,
+            required_prior_environment: Some("  ".into()),
+            approver_groups: vec![" SRE ".into(), "sre".into(), "".into()],
+            allowed_branches: vec!["main".into(), " main".into()],
+        }
+        .normalized();
+
+        assert_eq!(p.required_prior_environment, None);
+        assert_eq!(p.approver_groups, vec!["SRE".to_string()]);
+        assert_eq!(p.allowed_branches, vec!["main".to_string()]);
+    }
+
+    #[test]
+    fn validate_rejects_bad_rules() {
+        assert!(protection().validate("prod").is_ok());
+        assert!(protection().validate("staging").is_err());
+
+        let unprotected_groups = EnvironmentProtection {
+            protected: false,
+            ..protection()
+        };
+        assert!(unprotected_groups.validate("prod").is_err());
+
+        let bad_regex = EnvironmentProtection {
+            allowed_branches: vec!["(".into()],
+            ..protection()
+        };
+        assert!(bad_regex.validate("prod").is_err());
+    }
+
+    #[test]
+    fn check_branch_matches_any_pattern() {
+        let p = protection();
+        assert_eq!(p.check_branch("prod", Some("main")), None);
+        assert_eq!(p.check_branch("prod", Some("release/1.2")), None);
+        assert!(p.check_branch("prod", Some("feature/x")).is_some());
+        assert!(p.check_branch("prod", None).is_some());
+
+        let open = EnvironmentProtection::default();
+        assert_eq!(open.check_branch("dev", None), None);
+    }
+
+    #[test]
+    fn force_bypass_never_allows_self_approval_when_protected() {
+        assert!(!protection().allows_self_approval(true));
+        assert!(!protection().allows_self_approval(false));
+
+        let open = EnvironmentProtection::default();
+        assert!(open.allows_self_approval(true));
+        assert!(!open.allows_self_approval(false));
+    }
+
+    #[test]
+    fn required_approvals_follow_groups() {
+        assert_eq!(EnvironmentProtection::default().required_approvals(), 0);
+        let p = EnvironmentProtection {
+            protected: true,
+            ..Default::default()
+        };
+        assert_eq!(p.required_approvals(), 1);
+        let p = EnvironmentProtection {
+            approver_groups: vec!["a".into(), "b".into()],
+            ..protection()
+        };
+        assert_eq!(p.required_approvals(), 2);
+    }
+}
@@ -2,6 +2,7 @@ use anyhow::Context;
 use uuid::Uuid;
 
 use crate::State;
+use crate::services::environment_protection::EnvironmentProtection;
 
 pub struct EnvironmentRecord {
     pub id: Uuid,
@@ -10,6 +11,7 @@ pub struct EnvironmentRecord {
     pub description: Option<String>,
     pub sort_order: i32,
     pub created_at: chrono::DateTime<chrono::Utc>,
+    pub protection: EnvironmentProtection,
 }
 
 pub struct EnvironmentRegistry {
@@ -28,7 +30,8 @@ impl EnvironmentRegistry {
             r#"
             INSERT INTO environments (organisation, name, description, sort_order)
             VALUES ($1, $2, $3, $4)
-            RETURNING id, organisation, name, description, sort_order, created_at
+            RETURNING id, organisation, name, description, sort_order, created_at,
+                protected, required_prior_environment, approver_groups, allowed_branches
             "#,
             organisation,
             name,
@@ -46,13 +49,20 @@ impl EnvironmentRegistry {
             description: rec.description,
             sort_order: rec.sort_order,
             created_at: rec.created_at,
+            protection: EnvironmentProtection {
+                protected: rec.protected,
+                required_prior_environment: rec.required_prior_environment,
+                approver_groups: rec.approver_groups,
+                allowed_branches: rec.allowed_branches,
+            },
         })
     }
 
     pub async fn get_by_id(&self, id: &Uuid) -> anyhow::Result<Option<EnvironmentRecord>> {
         let rec = sqlx::query!(
             r#"
-            SELECT id, organisation, name, description, sort_order, created_at
+            SELECT id, organisation, name, description, sort_order, created_at,
+                protected, required_prior_environment, approver_groups, allowed_branches
             FROM environments
             WHERE id = $1
             "#,
@@ -69,6 +79,12 @@ impl EnvironmentRegistry {
             description: r.description,
             sort_order: r.sort_order,
             created_at: r.created_at,
+            protection: EnvironmentProtection {
+                protected: r.protected,
+                required_prior_environment: r.required_prior_environment,
+                approver_groups: r.approver_groups,
+                allowed_branches: r.allowed_branches,
+            },
         }))
     }
 
@@ -79,7 +95,8 @@ impl EnvironmentRegistry {
     ) -> anyhow::Result<Option<EnvironmentRecord>> {
         let rec = sqlx::query!(
             r#"
-            SELECT id, organisation, name, description, sort_order, created_at
+            SELECT id, organisation, name, description, sort_order, created_at,
+                protected, required_prior_environment, approver_groups, allowed_branches
             FROM environments
             WHERE organisation = $1 AND name = $2
             "#,
@@ -97,13 +114,20 @@ impl EnvironmentRegistry {
             description: r.description,
             sort_order: r.sort_order,
             created_at: r.created_at,
+            protection: EnvironmentProtection {
+                protected: r.protected,
+                required_prior_environment: r.required_prior_environment,
+                approver_groups: r.approver_groups,
+                allowed_branches: r.allowed_branches,
+            },
         }))
     }
 
     pub async fn list(&self, organisation: &str) -> anyhow::Result<Vec<EnvironmentRecord>> {
         let recs = sqlx::query!(
             r#"
-            SELECT id, organisation, name, description, sort_order, created_at
+            SELECT id, organisation, name, description, sort_order, created_at,
+                protected, required_prior_environment, approver_groups, allowed_branches
             FROM environments
             WHERE organisation = $1
             ORDER BY sort_order, name
@@ -123,6 +147,12 @@ impl EnvironmentRegistry {
                 description: r.description,
                 sort_order: r.sort_order,
                 created_at: r.created_at,
+                protection: EnvironmentProtection {
+                    protected: r.protected,
+                    required_prior_environment: r.required_prior_environment,
+                    approver_groups: r.approver_groups,
+                    allowed_branches: r.allowed_branches,
+                },
             })
             .collect())
     }
@@ -132,20 +162,34 @@ impl EnvironmentRegistry {
         id: &Uuid,
         description: Option<&str>,
         sort_order: Option<i32>,
+        protection: Option<&EnvironmentProtection>,
     ) -> anyhow::Result<EnvironmentRecord> {
+        // A set protection replaces all four rule columns at once.
         let rec = sqlx::query!(
             r#"
             UPDATE environments
             SET
                 description = COALESCE($2, description),
                 sort_order = COALESCE($3, sort_order),
+                protected = COALESCE($4, protected),
+                required_prior_environment = CASE
+                    WHEN $4::bool IS NULL THEN required_prior_environment
+                    ELSE $5
+                END,
+                approver_groups = COALESCE($6, approver_groups),
+                allowed_branches = COALESCE($7, allowed_branches),
                 updated_at = now()
             WHERE id = $1
-            RETURNING id, organisation, name, description, sort_order, created_at
+            RETURNING id, organisation, name, description, sort_order, created_at,
+                protected, required_prior_environment, approver_groups, allowed_branches
             "#,
             id,
             description,
             sort_order,
+            protection.map(|p| p.protected),
+            protection.and_then(|p| p.required_prior_environment.as_deref()),
+            protection.map(|p| p.approver_groups.as_slice()),
+            protection.map(|p| p.allowed_branches.as_slice()),
         )
         .fetch_one(&self.db)
         .await
@@ -158,9 +202,44 @@ impl EnvironmentRegistry {
             description: rec.description,
             sort_order: rec.sort_order,
             created_at: rec.created_at,
+            protection: EnvironmentProtection {
+                protected: rec.protected,
+                required_prior_environment: rec.required_prior_environment,
+                approver_groups: rec.approver_groups,
+                allowed_branches: rec.allowed_branches,
+            },
         })
     }
 
+    /// Whether making `prior` the required prior environment of `name`
+    /// would close a loop, e.g. prod → staging → prod.
+    pub async fn creates_prior_cycle(
+        &self,
+        organisation: &str,
+        name: &str,
+        prior: &str,
+    ) -> anyhow::Result<bool> {
+        let mut seen = vec![name.to_string()];
+        let mut next = Some(prior.to_string());
+        while let Some(current) = next {
+            if seen.contains(&current) {
+                return Ok(true);
+            }
+            next = sqlx::query_scalar!(
+                "SELECT required_prior_environment FROM environments
+                 WHERE organisation = $1 AND name = $2",
+                organisation,
+                current,
+            )
+            .fetch_optional(&self.db)
+            .await
+            .context("walk prior environments")?
+            .flatten();
+            seen.push(current);
+        }
+        Ok(false)
+    }
+
     pub async fn delete(&self, id: &Uuid) -> anyhow::Result<()> {
         let res = sqlx::query!("DELETE FROM environments WHERE id = $1", id)
             .execute(&self.db)
@@ -2,7 +2,10 @@ use uuid::Uuid;
 
 use crate::{
     State,
-    repositories::organisations::{OrganisationRepository, OrganisationRepositoryState},
+    repositories::{
+        error::DbError,
+        organisations::{OrganisationRepository, OrganisationRepositoryState},
+    },
 };
 
 pub struct OrganisationService {
@@ -123,6 +126,36 @@ impl OrganisationService {
             .add_member(self.db(), organisation_id, user_id, role)
             .await?;
 
+        self.member_info(organisation_id, user_id).await
+    }
+
+    /// Add a user to an organisation on behalf of a trusted service (SSO
+    /// just-in-time provisioning). Skips the admin check and is a no-op
+    /// returning the existing membership if the user already belongs to
+    /// the organisation, so repeated logins never change an assigned role.
+    pub async fn provision_member(
+        &self,
+        organisation_id: Uuid,
+        user_id: Uuid,
+        role: &str,
+    ) -> anyhow::Result<MemberInfo> {
+        validate_role(role)?;
+
+        if self
+            .repo
+            .get_member(self.db(), organisation_id, user_id)
+            .await?
+            .is_none()
+        {
+            self.repo
+                .add_member(self.db(), organisation_id, user_id, role)
+                .await?;
+        }
+
+        self.member_info(organisation_id, user_id).await
+    }
+
+    async fn member_info(&self, organisation_id: Uuid, user_id: Uuid) -> anyhow::Result<MemberInfo> {
         let row = self
             .repo
             .get_member_with_username(self.db(), organisation_id, user_id)
@@ -145,6 +178,17 @@ impl OrganisationService {
     ) -> anyhow::Result<()> {
         self.require_admin(organisation_id, requester_id).await?;
 
+        if self
+            .repo
+            .is_last_owner(self.db(), organisation_id, user_id)
+            .await?
+        {
+            return Err(DbError::ConstraintViolation(
+                "cannot remove the organisation's last owner".into(),
+            )
+            .into());
+        }
+
         self.repo
             .remove_member(self.db(), organisation_id, user_id)
             .await?;
@@ -180,6 +224,37 @@ impl OrganisationService {
         })
     }
 
+    /// Mirror forage's SSO enforcement setting for the organisation.
+    /// Turning enforcement on signs every member out, so sessions from
+    /// earlier password, passkey or OAuth logins can't keep refreshing.
+    pub async fn set_sso_enforced(&self, organisation_id: Uuid, enforced: bool) -> anyhow::Result<()> {
+        let mut tx = self.db().begin().await?;
+        let changed = self
+            .repo
+            .set_sso_enforced(&mut *tx, organisation_id, enforced)
+            .await?;
+        if changed && enforced {
+            self.repo
+                .revoke_member_sessions(&mut *tx, organisation_id)
+                .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// The organisation that forbids this login, if any: one the user
+    /// belongs to that enforces SSO, other than the one whose identity
+    /// provider they signed in through (`via_sso`).
+    pub async fn sso_required_by(
+        &self,
+        user_id: Uuid,
+        via_sso: Option<&str>,
+    ) -> anyhow::Result<Option<String>> {
+        self.repo
+            .sso_enforcing_organisation(self.db(), user_id, via_sso)
+            .await
+    }
+
     pub async fn list_my_organisations(
         &self,
         user_id: Uuid,
@@ -265,7 +340,7 @@ pub struct MemberListResult {
     pub total_count: i64,
 }
 
-fn validate_role(role: &str) -> anyhow::Result<()> {
+pub(crate) fn validate_role(role: &str) -> anyhow::Result<()> {
     match role {
         "admin" | "member" => Ok(()),
         _ => anyhow::bail!("invalid role: {role}, must be 'admin' or 'member'"),
@@ -5,6 +5,7 @@ use sqlx::PgPool;
 use uuid::Uuid;
 
 use crate::State;
+use crate::services::environment_protection;
 
 #[derive(Clone)]
 pub struct PolicyRegistry {
@@ -318,6 +319,83 @@ impl PolicyRegistry {
             }
         }
 
+        evaluations.extend(
+            self.evaluate_environment_protection(
+                project_id,
+                target_environment,
+                branch,
+                release_intent_id,
+            )
+            .await?,
+        );
+
+        Ok(evaluations)
+    }
+
+    /// Evaluate the target environment's own protection rules, reported
+    /// under the name `environment:<name>` alongside the project policies.
+    async fn evaluate_environment_protection(
+        &self,
+        project_id: &Uuid,
+        target_environment: &str,
+        branch: Option<&str>,
+        release_intent_id: Option<&Uuid>,
+    ) -> anyhow::Result<Vec<PolicyEvaluation>> {
+        let mut conn = self.db.acquire().await.context("acquire connection")?;
+        let protection =
+            environment_protection::load_for_project(&mut conn, project_id, target_environment)
+                .await?;
+        let policy_name = format!("environment:{target_environment}");
+        let mut evaluations = Vec::new();
+
+        if !protection.allowed_branches.is_empty() {
+            let blocked = protection.check_branch(target_environment, branch);
+            evaluations.push(PolicyEvaluation {
+                policy_name: policy_name.clone(),
+                policy_type: PolicyType::BranchRestriction,
+                passed: blocked.is_none(),
+                reason: blocked.unwrap_or_else(|| {
+                    format!("branch allowed in environment '{target_environment}'")
+                }),
+                approval_state: None,
+            });
+        }
+
+        if protection.protected {
+            let required_approvals = protection.required_approvals();
+            let (blocked, approval_state) = match release_intent_id {
+                Some(intent_id) => (
+                    environment_protection::check_approvals(
+                        &mut conn,
+                        project_id,
+                        intent_id,
+                        target_environment,
+                        &protection,
+                    )
+                    .await?,
+                    self.get_approval_state_info(intent_id, target_environment, required_approvals)
+                        .await?,
+                ),
+                None => (
+                    Some("approval required — no release intent context".to_string()),
+                    ApprovalStateInfo {
+                        required_approvals,
+                        current_approvals: 0,
+                        decisions: vec![],
+                    },
+                ),
+            };
+            evaluations.push(PolicyEvaluation {
+                policy_name,
+                policy_type: PolicyType::Approval,
+                passed: blocked.is_none(),
+                reason: blocked.unwrap_or_else(|| {
+                    format!("environment '{target_environment}' approval satisfied")
+                }),
+                approval_state: Some(approval_state),
+            });
+        }
+
         Ok(evaluations)
     }
 
@@ -559,7 +637,7 @@ impl PolicyRegistry {
     pub async fn record_approval_decision(
         &self,
         release_intent_id: &Uuid,
-        policy_id: &Uuid,
+        policy_id: Option<&Uuid>,
         target_environment: &str,
         user_id: &Uuid,
         username: &str,
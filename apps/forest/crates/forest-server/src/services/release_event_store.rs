@@ -1,6 +1,7 @@
 use anyhow::Context;
+use forest_models::QueuePolicy;
 use serde::{Deserialize, Serialize};
-use sqlx::PgPool;
+use sqlx::{PgConnection, PgPool};
 use uuid::Uuid;
 
 use crate::{actor::Actor, State};
@@ -54,6 +55,18 @@ impl ReleaseEventType {
     }
 }
 
+/// NATS subject prefix for stopping an in-flight release; the release id
+/// is appended.
+pub const RELEASE_CANCEL_SUBJECT_PREFIX: &str = "forest.release.cancel.";
+
+/// Published when an assigned or running release is cancelled. Only the
+/// executor named by `runner_id` acts on it.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReleaseCancelSignal {
+    pub runner_id: String,
+    pub reason: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, Default)]
 pub struct EventPayload {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -83,8 +96,10 @@ pub struct ReleaseEventStore {
 
 impl ReleaseEventStore {
     /// Create a new release: insert release_states + first event in one tx.
-    /// Allows queuing behind in-flight releases. When `force` is set, cancels
-    /// all other QUEUED releases for this project+destination first.
+    /// Releases queue behind in-flight releases to the same destination.
+    /// When `force` is set, or the destination's queue policy is supersede,
+    /// this project's other QUEUED releases to the destination are cancelled
+    /// first.
     pub async fn create_release(&self, params: CreateReleaseParams) -> anyhow::Result<Uuid> {
         let release_id = Uuid::now_v7();
         let mut tx = self.db.begin().await?;
@@ -92,47 +107,37 @@ impl ReleaseEventStore {
         let actor_id = params.actor.actor_id();
         let actor_type = params.actor.actor_type();
 
-        // Force release: cancel all QUEUED releases for this destination
-        let cancelled_intent_ids = if params.force {
-            let cancelled = sqlx::query!(
-                "UPDATE release_states
-                 SET status = 'CANCELLED', error_message = 'superseded by force release',
-                     completed_at = now(), updated_at = now()
-                 WHERE project_id = $1 AND destination_id = $2 AND status = 'QUEUED'
-                 RETURNING release_id, release_intent_id",
-                params.project_id,
-                params.destination_id,
+        let queue_policy = destination_queue_policy(&mut tx, &params.destination_id).await?;
+        let superseded = if params.force || queue_policy == QueuePolicy::Supersede {
+            // A force release displaces anything queued; the supersede
+            // policy only replaces releases of the same kind.
+            let (reason, mode) = if params.force {
+                ("superseded by force release", None)
+            } else {
+                ("superseded by newer release", Some("deploy"))
+            };
+
+            let cancelled = supersede_queued(
+                &mut tx,
+                &params.project_id,
+                &params.destination_id,
+                mode,
+                reason,
+                Some(&params.actor),
             )
-            .fetch_all(&mut *tx)
-            .await
-            .context("cancel queued releases for force")?;
-
-            let mut intent_ids = Vec::new();
-            for row in &cancelled {
-                sqlx::query!(
-                    "INSERT INTO release_events (
-                        release_id, event_type, payload, actor_id, actor_type
-                    ) VALUES ($1, 'release.cancelled', $2, $3, $4)",
-                    row.release_id,
-                    serde_json::json!({"reason": "superseded by force release"}),
-                    actor_id,
-                    actor_type,
-                )
-                .execute(&mut *tx)
-                .await?;
-                intent_ids.push((row.release_id, row.release_intent_id));
-            }
+            .await?;
 
             if !cancelled.is_empty() {
                 tracing::info!(
                     count = cancelled.len(),
                     project_id = %params.project_id,
                     destination_id = %params.destination_id,
-                    "force release: cancelled queued releases"
+                    force = params.force,
+                    "cancelled queued releases superseded by new release"
                 );
             }
 
-            intent_ids
+            cancelled
         } else {
             Vec::new()
         };
@@ -167,15 +172,7 @@ impl ReleaseEventStore {
 
         tx.commit().await?;
 
-        // Publish NATS status updates for cancelled releases
-        for (cancelled_id, cancelled_intent_id) in &cancelled_intent_ids {
-            let subject = format!("forest.release.status.{}", cancelled_intent_id);
-            let payload = serde_json::json!({
-                "release_id": cancelled_id.to_string(),
-                "status": "CANCELLED",
-            });
-            let _ = self.nats.publish(subject, payload.to_string().into()).await;
-        }
+        self.notify_superseded(&superseded).await;
 
         // Publish to NATS after commit (best-effort, fallback sweep catches misses)
         if let Err(e) = self
@@ -190,7 +187,9 @@ impl ReleaseEventStore {
     }
 
     /// Emit an event and update materialized state atomically.
-    /// Returns Err if the current status doesn't allow this transition.
+    /// Returns Err if the current status doesn't allow this transition, or,
+    /// for `Assigned`, if the release isn't at the head of its destination's
+    /// queue.
     pub async fn emit_event(
         &self,
         release_id: Uuid,
@@ -206,7 +205,7 @@ impl ReleaseEventStore {
 
         // Lock the row and verify current status
         let row = sqlx::query!(
-            "SELECT status, release_intent_id, project_id, destination_id, stage_id
+            "SELECT status, release_intent_id, project_id, destination_id, stage_id, queued_at
              FROM release_states
              WHERE release_id = $1
              FOR UPDATE",
@@ -230,6 +229,23 @@ impl ReleaseEventStore {
         let destination_id = row.destination_id;
         let stage_id = row.stage_id.clone();
 
+        // Releases to a destination run one at a time, oldest first. The
+        // advisory lock serializes claims on the same queue across server
+        // replicas until this transaction ends.
+        if event_type == ReleaseEventType::Assigned {
+            sqlx::query("SELECT pg_advisory_xact_lock(hashtextextended($1, 0))")
+                .bind(format!("release-queue/{destination_id}"))
+                .execute(&mut *tx)
+                .await
+                .context("lock destination queue")?;
+
+            if let Some(blocker) =
+                queue_blocker(&mut tx, &release_id, &destination_id, row.queued_at).await?
+            {
+                anyhow::bail!("destination busy: waiting behind release {blocker}");
+            }
+        }
+
         // Insert event
         let actor_id = actor.map(|a| a.actor_id());
         let actor_type = actor.map(|a| a.actor_type());
@@ -352,7 +368,7 @@ impl ReleaseEventStore {
                 | ReleaseEventType::TimedOut
         ) {
             if let Ok(Some(next_id)) = self
-                .next_queued_for_destination(&project_id, &destination_id)
+                .next_queued_for_destination(&destination_id)
                 .await
             {
                 tracing::info!(
@@ -384,6 +400,53 @@ impl ReleaseEventStore {
         Ok(())
     }
 
+    /// Cancel a release. Queued releases simply leave their destination's
+    /// queue; assigned or running ones are also signalled on
+    /// [`RELEASE_CANCEL_SUBJECT_PREFIX`] so whichever executor holds
+    /// them (a remote runner or the in-process scheduler) stops.
+    pub async fn cancel_release(
+        &self,
+        release_id: Uuid,
+        reason: &str,
+        actor: &Actor,
+    ) -> anyhow::Result<()> {
+        self.emit_event(
+            release_id,
+            ReleaseEventType::Cancelled,
+            EventPayload {
+                error_message: Some(reason.to_string()),
+                reason: Some(reason.to_string()),
+                ..Default::default()
+            },
+            Some(actor),
+        )
+        .await?;
+
+        let runner_id = sqlx::query_scalar!(
+            "SELECT runner_id FROM release_states WHERE release_id = $1",
+            release_id,
+        )
+        .fetch_one(&self.db)
+        .await
+        .context("get release runner")?;
+
+        if let Some(runner_id) = runner_id {
+            let signal = ReleaseCancelSignal {
+                runner_id,
+                reason: reason.to_string(),
+            };
+            let _ = self
+                .nats
+                .publish(
+                    format!("{RELEASE_CANCEL_SUBJECT_PREFIX}{release_id}"),
+                    serde_json::to_vec(&signal)?.into(),
+                )
+                .await;
+        }
+
+        Ok(())
+    }
+
     /// Finalize a direct (non-pipeline) intent when all child releases are terminal.
     async fn try_finalize_direct_intent(&self, intent_id: &Uuid) {
         let result: Result<_, anyhow::Error> = async {
@@ -450,7 +513,8 @@ impl ReleaseEventStore {
     }
 
     /// Pick up queued releases for the fallback sweep.
-    /// Only returns releases whose destination has no ASSIGNED/RUNNING release.
+    /// Only returns the head of each destination queue, and only when the
+    /// destination has no ASSIGNED/RUNNING release.
     pub async fn pick_queued_releases(&self, limit: i64) -> anyhow::Result<Vec<QueuedRelease>> {
         let rows = sqlx::query_as!(
             QueuedRelease,
@@ -461,9 +525,15 @@ impl ReleaseEventStore {
              WHERE rs.status = 'QUEUED'
                AND NOT EXISTS (
                    SELECT 1 FROM release_states active
-                   WHERE active.project_id = rs.project_id
-                     AND active.destination_id = rs.destination_id
-                     AND active.status IN ('ASSIGNED', 'RUNNING')
+                   WHERE active.destination_id = rs.destination_id
+                     AND (
+                         active.status IN ('ASSIGNED', 'RUNNING')
+                         OR (
+                             active.status = 'QUEUED'
+                             AND NOT active.awaiting_approval
+                             AND (active.queued_at, active.release_id) < (rs.queued_at, rs.release_id)
+                         )
+                     )
                )
              ORDER BY rs.queued_at
              LIMIT $1
@@ -476,18 +546,18 @@ impl ReleaseEventStore {
         Ok(rows)
     }
 
-    /// Find the next QUEUED release for a destination (oldest first).
+    /// Find the next QUEUED release for a destination (oldest first), from
+    /// any project. Releases held for approval come last; the sweep picks
+    /// them up once approved.
     pub async fn next_queued_for_destination(
         &self,
-        project_id: &Uuid,
         destination_id: &Uuid,
     ) -> anyhow::Result<Option<Uuid>> {
         let row = sqlx::query_scalar!(
             "SELECT release_id FROM release_states
-             WHERE project_id = $1 AND destination_id = $2 AND status = 'QUEUED'
-             ORDER BY queued_at ASC
+             WHERE destination_id = $1 AND status = 'QUEUED'
+             ORDER BY awaiting_approval, queued_at ASC
              LIMIT 1",
-            project_id,
             destination_id,
         )
         .fetch_optional(&self.db)
@@ -496,6 +566,65 @@ impl ReleaseEventStore {
         Ok(row)
     }
 
+    /// Mark a queued release as held (or no longer held) by environment
+    /// protection, so it stops (or resumes) blocking its destination queue.
+    pub async fn set_awaiting_approval(
+        &self,
+        release_id: &Uuid,
+        awaiting: bool,
+    ) -> anyhow::Result<()> {
+        sqlx::query!(
+            "UPDATE release_states SET awaiting_approval = $2
+             WHERE release_id = $1 AND status = 'QUEUED' AND awaiting_approval <> $2",
+            release_id,
+            awaiting,
+        )
+        .execute(&self.db)
+        .await
+        .context("update approval hold")?;
+
+        Ok(())
+    }
+
+    /// The release a queued release is waiting behind, if any. Advisory:
+    /// the authoritative check happens when the release is assigned.
+    pub async fn blocking_release(&self, release: &ReleaseState) -> anyhow::Result<Option<Uuid>> {
+        let mut conn = self.db.acquire().await?;
+        queue_blocker(
+            &mut conn,
+            &release.release_id,
+            &release.destination_id,
+            release.queued_at,
+        )
+        .await
+    }
+
+    /// Publish status updates for superseded releases and let their
+    /// intents react: pipelines re-evaluate, direct intents finalize.
+    pub(crate) async fn notify_superseded(&self, cancelled: &[SupersededRelease]) {
+        for release in cancelled {
+            let subject = format!("forest.release.status.{}", release.release_intent_id);
+            let payload = serde_json::json!({
+                "release_id": release.release_id.to_string(),
+                "status": "CANCELLED",
+            });
+            let _ = self.nats.publish(subject, payload.to_string().into()).await;
+
+            if release.stage_id.is_some() {
+                let _ = self
+                    .nats
+                    .publish(
+                        "forest.intent.evaluate",
+                        release.release_intent_id.to_string().into(),
+                    )
+                    .await;
+            } else {
+                self.try_finalize_direct_intent(&release.release_intent_id)
+                    .await;
+            }
+        }
+    }
+
     /// Get current state for a release by ID.
     pub async fn get_release_state(&self, release_id: &Uuid) -> anyhow::Result<ReleaseState> {
         let row = sqlx::query_as!(
@@ -595,8 +724,10 @@ impl ReleaseEventStore {
             r#"WITH in_flight AS (
                 SELECT
                     rs.*,
-                    ROW_NUMBER() OVER (PARTITION BY rs.destination_id ORDER BY rs.queued_at ASC)
-                        as queue_pos
+                    ROW_NUMBER() OVER (
+                        PARTITION BY rs.project_id, rs.destination_id
+                        ORDER BY rs.queued_at ASC, rs.release_id ASC
+                    ) as queue_pos
                 FROM release_states rs
                 JOIN destinations d ON d.id = rs.destination_id
                 WHERE d.organisation = $1
@@ -617,6 +748,7 @@ impl ReleaseEventStore {
                 d.id as destination_id,
                 d.name as destination_name,
                 d.environment,
+                d.queue_policy,
                 r.release_id as "release_id!",
                 r.release_intent_id as "release_intent_id!",
                 r.artifact_id as "artifact_id!",
@@ -772,9 +904,14 @@ impl ReleaseEventStore {
                 rs.assigned_at,
                 rs.started_at,
                 rs.completed_at,
-                rs.error_message
+                rs.error_message,
+                dd.status AS "drift_status?",
+                dd.detail AS "drift_detail?",
+                CASE WHEN dd.status IS NOT NULL THEN dd.checked_at END AS "drift_checked_at?"
             FROM release_states rs
             JOIN destinations d ON d.id = rs.destination_id
+            LEFT JOIN destination_drift dd
+              ON dd.release_id = rs.release_id AND dd.destination_id = rs.destination_id
             WHERE rs.release_intent_id = ANY($1)
             ORDER BY rs.queued_at ASC"#,
             &intent_ids,
@@ -927,6 +1064,7 @@ pub struct DestinationReleaseRow {
     pub destination_id: Uuid,
     pub destination_name: String,
     pub environment: String,
+    pub queue_policy: String,
     pub release_id: Uuid,
     pub artifact_id: Uuid,
     pub status: String,
@@ -949,6 +1087,13 @@ pub struct PipelineRunRow {
     pub stage_states: Option<serde_json::Value>,
 }
 
+/// A queued release cancelled because a newer release replaced it.
+pub struct SupersededRelease {
+    pub release_id: Uuid,
+    pub release_intent_id: Uuid,
+    pub stage_id: Option<String>,
+}
+
 pub struct StuckRelease {
     pub release_id: Uuid,
     pub release_intent_id: Uuid,
@@ -981,6 +1126,115 @@ pub struct ReleaseStepRow {
     pub started_at: Option<chrono::DateTime<chrono::Utc>>,
     pub completed_at: Option<chrono::DateTime<chrono::Utc>>,
     pub error_message: Option<String>,
+    /// Set once the drift detector has checked this release, while it is
+    /// still the one deployed to the destination.
+    pub drift_status: Option<String>,
+    pub drift_detail: Option<String>,
+    pub drift_checked_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// The destination's release queue policy (wait when unknown).
+pub(crate) async fn destination_queue_policy(
+    conn: &mut PgConnection,
+    destination_id: &Uuid,
+) -> anyhow::Result<QueuePolicy> {
+    let policy = sqlx::query_scalar!(
+        "SELECT queue_policy FROM destinations WHERE id = $1",
+        destination_id,
+    )
+    .fetch_optional(&mut *conn)
+    .await
+    .context("get destination queue policy")?;
+
+    policy
+        .map(|p| p.parse().map_err(anyhow::Error::msg))
+        .transpose()
+        .map(Option::unwrap_or_default)
+}
+
+/// Cancel releases waiting in a destination's queue so a newer release
+/// takes their place. The queue is shared by every project releasing to the
+/// destination, but only `project_id`'s own releases are superseded: another
+/// project's queued release deploys something else and still has to run.
+/// With `mode` set, only releases of that mode (deploy/plan) are cancelled. Callers publish the results with
+/// [`ReleaseEventStore::notify_superseded`] after committing.
+pub(crate) async fn supersede_queued(
+    conn: &mut PgConnection,
+    project_id: &Uuid,
+    destination_id: &Uuid,
+    mode: Option<&str>,
+    reason: &str,
+    actor: Option<&Actor>,
+) -> anyhow::Result<Vec<SupersededRelease>> {
+    let cancelled = sqlx::query_as!(
+        SupersededRelease,
+        "UPDATE release_states
+         SET status = 'CANCELLED', error_message = $4,
+             completed_at = now(), updated_at = now()
+         WHERE project_id = $1 AND destination_id = $2 AND status = 'QUEUED'
+           AND ($3::text IS NULL OR mode = $3)
+         RETURNING release_id, release_intent_id, stage_id",
+        project_id,
+        destination_id,
+        mode,
+        reason,
+    )
+    .fetch_all(&mut *conn)
+    .await
+    .context("cancel superseded queued releases")?;
+
+    let actor_id = actor.map(|a| a.actor_id());
+    let actor_type = actor.map(|a| a.actor_type());
+    for row in &cancelled {
+        sqlx::query!(
+            "INSERT INTO release_events (
+                release_id, event_type, payload, actor_id, actor_type
+            ) VALUES ($1, 'release.cancelled', $2, $3, $4)",
+            row.release_id,
+            serde_json::json!({ "reason": reason }),
+            actor_id,
+            actor_type,
+        )
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    Ok(cancelled)
+}
+
+/// The release `release_id` is waiting behind: an ASSIGNED/RUNNING release
+/// to the same destination, or an older release still QUEUED there. The
+/// queue spans projects, since projects can share a destination, so a
+/// release held for approval doesn't count: it would block every other
+/// project until someone approves it.
+async fn queue_blocker(
+    conn: &mut PgConnection,
+    release_id: &Uuid,
+    destination_id: &Uuid,
+    queued_at: chrono::DateTime<chrono::Utc>,
+) -> anyhow::Result<Option<Uuid>> {
+    let blocker = sqlx::query_scalar!(
+        "SELECT release_id FROM release_states
+         WHERE destination_id = $1 AND release_id <> $2
+           AND (
+               status IN ('ASSIGNED', 'RUNNING')
+               OR (
+                   status = 'QUEUED'
+                   AND NOT awaiting_approval
+                   AND (queued_at, release_id) < ($3, $2)
+               )
+           )
+         ORDER BY status = 'QUEUED', queued_at
+         LIMIT 1",
+        destination_id,
+        release_id,
+        queued_at,
+    )
+    .fetch_optional(&mut *conn)
+    .await
+    .context("check destination queue")?;
+
+    Ok(blocker)
 }
 
 pub trait ReleaseEventStoreState {
@@ -32,6 +32,18 @@ pub async fn finalize_release(
                 None,
             )
             .await?;
+    } else if status == ReleaseStatus::Cancelled {
+        release_event_store
+            .emit_event(
+                *release_id,
+                ReleaseEventType::Cancelled,
+                EventPayload {
+                    error_message: error_message.map(|s| s.to_string()),
+                    ..Default::default()
+                },
+                None,
+            )
+            .await?;
     } else {
         release_event_store
             .emit_event(
@@ -0,0 +1,333 @@
+//! Read-only queries over past releases, for `forest release list` and
+//! `forest release show`.
+//!
+//! A release is a release intent: one request to deploy an annotated
+//! artifact. Its attempts are the `release_states` rows it created, one
+//! per destination and mode.
+
+use std::collections::HashMap;
+
+use anyhow::Context;
+use base64::Engine;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    services::{
+        release_event_store::EventPayload,
+        release_registry::{
+            ArtifactContext, Project, Reference, ReleaseDestinationStatus, Source,
+        },
+    },
+    State,
+};
+
+#[derive(Clone)]
+pub struct ReleaseHistory {
+    db: PgPool,
+}
+
+#[derive(Default)]
+pub struct ReleaseFilter {
+    pub organisation: String,
+    pub project: Option<String>,
+    pub environment: Option<String>,
+    pub branch: Option<String>,
+    pub author: Option<String>,
+    pub status: Option<String>,
+    pub since: Option<chrono::DateTime<chrono::Utc>>,
+    pub until: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Keyset position in the newest-first listing: the last release on the
+/// previous page. Unlike an offset, it stays correct while new releases
+/// are created between pages.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cursor {
+    pub created: chrono::DateTime<chrono::Utc>,
+    pub id: Uuid,
+}
+
+impl Cursor {
+    pub fn encode(&self) -> String {
+        base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .encode(format!("{}:{}", self.created.timestamp_micros(), self.id))
+    }
+
+    pub fn decode(token: &str) -> anyhow::Result<Self> {
+        let raw = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(token)
+            .context("page token is not valid base64")?;
+        let raw = String::from_utf8(raw).context("page token is not utf-8")?;
+        let (micros, id) = raw.split_once(':').context("malformed page token")?;
+        let created = chrono::DateTime::from_timestamp_micros(
+            micros.parse().context("malformed page token timestamp")?,
+        )
+        .context("page token timestamp out of range")?;
+        Ok(Self {
+            created,
+            id: id.parse().context("malformed page token id")?,
+        })
+    }
+}
+
+pub struct HistoryEntry {
+    pub release_intent_id: Uuid,
+    pub artifact_id: Uuid,
+    pub slug: String,
+    pub project: Project,
+    pub source: Source,
+    pub context: ArtifactContext,
+    pub reference: Reference,
+    pub destinations: Vec<ReleaseDestinationStatus>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+pub struct Attempt {
+    pub release_id: Uuid,
+    pub release_intent_id: Uuid,
+    pub stage_id: Option<String>,
+    pub destination_id: Uuid,
+    pub destination: String,
+    pub environment: String,
+    pub mode: String,
+    pub status: String,
+    pub error_message: Option<String>,
+    pub queued_at: chrono::DateTime<chrono::Utc>,
+    pub assigned_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub started_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub completed_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+pub struct AttemptEvent {
+    pub release_id: Uuid,
+    pub event_type: String,
+    pub message: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl ReleaseHistory {
+    /// One page of releases matching `filter`, newest first, after
+    /// `cursor`. Returns the cursor for the next page when there is one.
+    pub async fn list(
+        &self,
+        filter: &ReleaseFilter,
+        cursor: Option<&Cursor>,
+        limit: i64,
+    ) -> anyhow::Result<(Vec<HistoryEntry>, Option<Cursor>)> {
+        let recs = sqlx::query!(
+            r#"
+            SELECT
+                ri.id,
+                ri.artifact,
+                ri.created,
+                a.slug,
+                a.source,
+                a.context,
+                a.ref,
+                p.organisation,
+                p.project
+            FROM release_intents ri
+            JOIN annotations a ON a.id = ri.annotation_id
+            JOIN projects p ON p.id = ri.project_id
+            WHERE p.organisation = $1
+              AND ($2::text IS NULL OR p.project = $2)
+              AND ($3::text IS NULL OR a.ref->>'commit_branch' = $3)
+              AND ($4::text IS NULL
+                   OR lower(a.source->>'username') = lower($4)
+                   OR lower(a.source->>'email') = lower($4))
+              AND ($5::timestamptz IS NULL OR ri.created >= $5)
+              AND ($6::timestamptz IS NULL OR ri.created < $6)
+              AND (($7::text IS NULL AND $8::text IS NULL) OR EXISTS (
+                    SELECT 1
+                    FROM release_states rs
+                    JOIN destinations d ON d.id = rs.destination_id
+                    WHERE rs.release_intent_id = ri.id
+                      AND rs.mode = 'deploy'
+                      AND ($7::text IS NULL OR d.environment = $7)
+                      AND ($8::text IS NULL OR rs.status = $8)
+                  ))
+              AND ($9::timestamptz IS NULL OR (ri.created, ri.id) < ($9, $10))
+            ORDER BY ri.created DESC, ri.id DESC
+            LIMIT $11
+            "#,
+            filter.organisation,
+            filter.project,
+            filter.branch,
+            filter.author,
+            filter.since,
+            filter.until,
+            filter.environment,
+            filter.status,
+            cursor.map(|c| c.created),
+            cursor.map(|c| c.id).unwrap_or_default(),
+            limit + 1,
+        )
+        .fetch_all(&self.db)
+        .await
+        .context("list releases")?;
+
+        let has_more = recs.len() as i64 > limit;
+        let recs: Vec<_> = recs.into_iter().take(limit as usize).collect();
+
+        let ids: Vec<Uuid> = recs.iter().map(|r| r.id).collect();
+        let dest_rows = sqlx::query!(
+            r#"
+            SELECT rs.release_intent_id, d.name, d.environment, rs.status
+            FROM release_states rs
+            JOIN destinations d ON d.id = rs.destination_id
+            WHERE rs.release_intent_id = ANY($1) AND rs.mode = 'deploy'
+            ORDER BY d.name
+            "#,
+            &ids,
+        )
+        .fetch_all(&self.db)
+        .await
+        .context("list release destinations")?;
+
+        let mut destinations: HashMap<Uuid, Vec<ReleaseDestinationStatus>> = HashMap::new();
+        for d in dest_rows {
+            destinations
+                .entry(d.release_intent_id)
+                .or_default()
+                .push(ReleaseDestinationStatus {
+                    destination: d.name,
+                    environment: d.environment,
+                    status: d.status,
+                });
+        }
+
+        let next = if has_more {
+            recs.last().map(|r| Cursor {
+                created: r.created,
+                id: r.id,
+            })
+        } else {
+            None
+        };
+
+        let entries = recs
+            .into_iter()
+            .map(|r| {
+                Ok(HistoryEntry {
+                    release_intent_id: r.id,
+                    artifact_id: r.artifact,
+                    slug: r.slug,
+                    project: Project {
+                        organisation: r.organisation,
+                        project: r.project,
+                    },
+                    source: serde_json::from_value(r.source).context("source")?,
+                    context: serde_json::from_value(r.context).context("context")?,
+                    reference: serde_json::from_value(r.r#ref).context("ref")?,
+                    destinations: destinations.remove(&r.id).unwrap_or_default(),
+                    created_at: r.created,
+                })
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        Ok((entries, next))
+    }
+
+    /// Every step of every release of an artifact, oldest first.
+    pub async fn attempts(
+        &self,
+        project_id: &Uuid,
+        artifact_id: &Uuid,
+    ) -> anyhow::Result<Vec<Attempt>> {
+        let attempts = sqlx::query_as!(
+            Attempt,
+            r#"
+            SELECT
+                rs.release_id,
+                rs.release_intent_id,
+                rs.stage_id,
+                rs.destination_id,
+                d.name as destination,
+                d.environment,
+                rs.mode,
+                rs.status,
+                rs.error_message,
+                rs.queued_at,
+                rs.assigned_at,
+                rs.started_at,
+                rs.completed_at
+            FROM release_states rs
+            JOIN destinations d ON d.id = rs.destination_id
+            WHERE rs.project_id = $1 AND rs.artifact_id = $2
+            ORDER BY rs.queued_at, rs.release_id
+            "#,
+            project_id,
+            artifact_id,
+        )
+        .fetch_all(&self.db)
+        .await
+        .context("get release attempts")?;
+
+        Ok(attempts)
+    }
+
+    /// Status transitions of the given releases, oldest first.
+    pub async fn events(&self, release_ids: &[Uuid]) -> anyhow::Result<Vec<AttemptEvent>> {
+        let recs = sqlx::query!(
+            r#"
+            SELECT release_id, event_type, payload, created_at
+            FROM release_events
+            WHERE release_id = ANY($1)
+            ORDER BY sequence
+            "#,
+            release_ids,
+        )
+        .fetch_all(&self.db)
+        .await
+        .context("get release events")?;
+
+        Ok(recs
+            .into_iter()
+            .map(|r| {
+                let payload: EventPayload = serde_json::from_value(r.payload).unwrap_or_default();
+                AttemptEvent {
+                    release_id: r.release_id,
+                    event_type: r.event_type,
+                    message: payload.error_message.or(payload.reason),
+                    created_at: r.created_at,
+                }
+            })
+            .collect())
+    }
+}
+
+pub trait ReleaseHistoryState {
+    fn release_history(&self) -> ReleaseHistory;
+}
+
+impl ReleaseHistoryState for State {
+    fn release_history(&self) -> ReleaseHistory {
+        ReleaseHistory {
+            db: self.db.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_round_trips() {
+        let cursor = Cursor {
+            created: chrono::DateTime::from_timestamp_micros(1_780_000_000_123_456).unwrap(),
+            id: Uuid::now_v7(),
+        };
+        assert_eq!(Cursor::decode(&cursor.encode()).unwrap(), cursor);
+    }
+
+    #[test]
+    fn malformed_cursors_are_rejected() {
+        assert!(Cursor::decode("not base64!").is_err());
+        let no_id = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode("123");
+        assert!(Cursor::decode(&no_id).is_err());
+        let bad_id = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode("123:nope");
+        assert!(Cursor::decode(&bad_id).is_err());
+    }
+}
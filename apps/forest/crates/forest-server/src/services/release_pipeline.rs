@@ -1,4 +1,4 @@
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 
 use anyhow::Context;
 use serde::{Deserialize, Serialize};
@@ -31,6 +31,10 @@ pub struct StageDefinition {
 pub enum StageConfig {
     Deploy {
         environment: String,
+        /// Roll out to the environment's destinations in these batches, in
+        /// order. Empty releases to every destination at once.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        batches: Vec<DeployBatch>,
     },
     Wait {
         duration_seconds: i64,
@@ -43,12 +47,138 @@ pub enum StageConfig {
     },
 }
 
+/// One batch of a deploy stage's rollout. Exactly one of `destinations`,
+/// `labels` or `percentage` selects its destinations.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DeployBatch {
+    /// Destinations by name.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub destinations: Vec<String>,
+
+    /// Destinations whose metadata has all of these values.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub labels: BTreeMap<String, String>,
+
+    /// This share of the environment's destinations, rounded up.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub percentage: Option<u32>,
+
+    /// Seconds to wait after this batch succeeds before the next starts.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub wait_seconds: i64,
+
+    /// Wait for the batch's destinations to report healthy before the next
+    /// batch starts.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub health_check: bool,
+}
+
+fn is_zero(v: &i64) -> bool {
+    *v == 0
+}
+
+impl DeployBatch {
+    fn validate(&self) -> anyhow::Result<()> {
+        let selectors = [
+            !self.destinations.is_empty(),
+            !self.labels.is_empty(),
+            self.percentage.is_some(),
+        ];
+        if selectors.iter().filter(|s| **s).count() != 1 {
+            anyhow::bail!("set exactly one of destinations, labels or percentage");
+        }
+        if let Some(p) = self.percentage
+            && !(1..=100).contains(&p)
+        {
+            anyhow::bail!("percentage must be between 1 and 100");
+        }
+        if self.wait_seconds < 0 {
+            anyhow::bail!("wait_seconds must not be negative");
+        }
+        Ok(())
+    }
+}
+
+/// A destination a batched deploy stage can select.
+pub struct BatchTarget {
+    pub name: String,
+    pub metadata: HashMap<String, String>,
+}
+
+/// Split an environment's destinations into the stage's batches. Each batch
+/// picks from the destinations earlier batches left, in name order; whatever
+/// is left at the end forms a final batch. A batch that selects nothing is
+/// an error, so a typo can't quietly fold a canary into the last batch.
+pub fn expand_batches(
+    batches: &[DeployBatch],
+    targets: &[BatchTarget],
+) -> anyhow::Result<Vec<BatchState>> {
+    let mut remaining: Vec<&BatchTarget> = targets.iter().collect();
+    remaining.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let mut expanded = Vec::new();
+    for (i, batch) in batches.iter().enumerate() {
+        let n = i + 1;
+        for name in &batch.destinations {
+            if !targets.iter().any(|t| &t.name == name) {
+                anyhow::bail!("batch {n}: destination '{name}' is not in the environment");
+            }
+        }
+
+        let selected: Vec<String> = if let Some(percentage) = batch.percentage {
+            let count = (targets.len() * percentage as usize).div_ceil(100);
+            remaining.iter().take(count).map(|t| t.name.clone()).collect()
+        } else {
+            remaining
+                .iter()
+                .filter(|t| {
+                    batch.destinations.contains(&t.name)
+                        || (!batch.labels.is_empty()
+                            && batch
+                                .labels
+                                .iter()
+                                .all(|(k, v)| t.metadata.get(k) == Some(v)))
+                })
+                .map(|t| t.name.clone())
+                .collect()
+        };
+
+        if selected.is_empty() {
+            anyhow::bail!("batch {n} selects no destinations");
+        }
+        remaining.retain(|t| !selected.contains(&t.name));
+
+        expanded.push(BatchState {
+            destinations: selected,
+            wait_seconds: batch.wait_seconds,
+            health_check: batch.health_check,
+            ..BatchState::pending()
+        });
+    }
+
+    if !remaining.is_empty() {
+        expanded.push(BatchState {
+            destinations: remaining.iter().map(|t| t.name.clone()).collect(),
+            ..BatchState::pending()
+        });
+    }
+
+    // Waiting or checking health after the last batch holds up nothing.
+    if let Some(last) = expanded.last_mut() {
+        last.wait_seconds = 0;
+        last.health_check = false;
+    }
+
+    Ok(expanded)
+}
+
 impl StageDefinition {
     pub fn deploy(environment: impl Into<String>, depends_on: Vec<String>) -> Self {
         Self {
             depends_on,
             config: StageConfig::Deploy {
                 environment: environment.into(),
+                batches: Vec::new(),
             },
         }
     }
@@ -75,7 +205,7 @@ impl StageDefinition {
 
 pub type StageStates = HashMap<String, StageState>;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StageState {
     pub status: StageStatus,
 
@@ -113,6 +243,59 @@ pub struct StageState {
     /// Who approved/rejected (actor_id).
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub approved_by: Option<String>,
+
+    /// For batched deploy stages: the rollout, in order. Fixed when the
+    /// stage starts, so editing the pipeline doesn't reshuffle a rollout
+    /// in progress.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub batches: Option<Vec<BatchState>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BatchState {
+    /// Destination names.
+    pub destinations: Vec<String>,
+
+    pub status: StageStatus,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub release_ids: Vec<String>,
+
+    /// Bake time after this batch, copied from its definition.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub wait_seconds: i64,
+
+    /// When the bake ends and the next batch may start.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wait_until: Option<String>,
+
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub health_check: bool,
+}
+
+impl BatchState {
+    pub fn pending() -> Self {
+        Self {
+            destinations: Vec::new(),
+            status: StageStatus::Pending,
+            started_at: None,
+            completed_at: None,
+            error_message: None,
+            release_ids: Vec::new(),
+            wait_seconds: 0,
+            wait_until: None,
+            health_check: false,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -146,6 +329,7 @@ impl StageState {
             approval_status: None,
             approval_at: None,
             approved_by: None,
+            batches: None,
         }
     }
 }
@@ -243,6 +427,16 @@ pub fn validate_pipeline(stages: &PipelineStages) -> anyhow::Result<()> {
         anyhow::bail!("pipeline contains a cycle");
     }
 
+    for (id, def) in stages {
+        if let StageConfig::Deploy { batches, .. } = &def.config {
+            for (i, batch) in batches.iter().enumerate() {
+                batch
+                    .validate()
+                    .with_context(|| format!("stage '{id}' batch {}", i + 1))?;
+            }
+        }
+    }
+
     Ok(())
 }
 
@@ -285,6 +479,23 @@ pub fn has_failed_dependency(
     })
 }
 
+/// Status of a deploy/plan stage once all of its releases are terminal:
+/// succeeded if every release succeeded, cancelled if the others were
+/// cancelled (none failed or timed out), failed otherwise.
+pub fn settled_stage_status<'a>(
+    release_statuses: impl IntoIterator<Item = &'a str>,
+) -> StageStatus {
+    let mut status = StageStatus::Succeeded;
+    for release_status in release_statuses {
+        match release_status {
+            "SUCCEEDED" => {}
+            "CANCELLED" => status = StageStatus::Cancelled,
+            _ => return StageStatus::Failed,
+        }
+    }
+    status
+}
+
 /// Check if the entire pipeline is finished (no PENDING or ACTIVE stages).
 pub fn is_pipeline_complete(states: &StageStates) -> bool {
     states.values().all(|s| {
@@ -558,6 +769,45 @@ mod tests {
         assert_eq!(ready, vec!["deploy-prod"]);
     }
 
+    #[test]
+    fn test_settled_stage_status() {
+        assert_eq!(
+            settled_stage_status(["SUCCEEDED", "SUCCEEDED"]),
+            StageStatus::Succeeded
+        );
+        assert_eq!(
+            settled_stage_status(["SUCCEEDED", "CANCELLED"]),
+            StageStatus::Cancelled
+        );
+        assert_eq!(
+            settled_stage_status(["CANCELLED", "FAILED"]),
+            StageStatus::Failed
+        );
+        assert_eq!(
+            settled_stage_status(["TIMED_OUT", "CANCELLED"]),
+            StageStatus::Failed
+        );
+    }
+
+    #[test]
+    fn test_cancelled_stage_blocks_dependents() {
+        let mut stages = PipelineStages::new();
+        stages.insert(
+            "deploy-dev".into(),
+            StageDefinition::deploy("dev", vec![]),
+        );
+        stages.insert(
+            "deploy-prod".into(),
+            StageDefinition::deploy("prod", vec!["deploy-dev".into()]),
+        );
+
+        let mut states = init_stage_states(&stages);
+        states.get_mut("deploy-dev").unwrap().status = StageStatus::Cancelled;
+
+        assert!(has_failed_dependency("deploy-prod", &stages, &states));
+        assert!(find_ready_stages(&stages, &states).is_empty());
+    }
+
     #[test]
     fn test_serde_roundtrip() {
         let mut stages = PipelineStages::new();
@@ -575,7 +825,7 @@ mod tests {
 
         assert_eq!(stages.len(), parsed.len());
         match &parsed["deploy-dev"].config {
-            StageConfig::Deploy { environment } => assert_eq!(environment, "dev"),
+            StageConfig::Deploy { environment, .. } => assert_eq!(environment, "dev"),
             _ => panic!("expected deploy stage"),
         }
         match &parsed["soak"].config {
@@ -696,6 +946,119 @@ mod tests {
         assert_eq!(ready, vec!["deploy-prod"]);
     }
 
+    fn target(name: &str, region: &str) -> BatchTarget {
+        BatchTarget {
+            name: name.into(),
+            metadata: HashMap::from([("region".into(), region.into())]),
+        }
+    }
+
+    fn batch_names(batches: &[BatchState]) -> Vec<Vec<&str>> {
+        batches
+            .iter()
+            .map(|b| b.destinations.iter().map(String::as_str).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_expand_batches_canary_then_rest() {
+        let targets = vec![
+            target("prod-us", "us"),
+            target("prod-eu", "eu"),
+            target("prod-ap", "ap"),
+        ];
+        let batches = vec![DeployBatch {
+            destinations: vec!["prod-eu".into()],
+            wait_seconds: 600,
+            health_check: true,
+            ..Default::default()
+        }];
+
+        let expanded = expand_batches(&batches, &targets).unwrap();
+        assert_eq!(
+            batch_names(&expanded),
+            vec![vec!["prod-eu"], vec!["prod-ap", "prod-us"]]
+        );
+        assert_eq!(expanded[0].wait_seconds, 600);
+        assert!(expanded[0].health_check);
+        assert!(expanded.iter().all(|b| b.status == StageStatus::Pending));
+    }
+
+    #[test]
+    fn test_expand_batches_by_label_and_percentage() {
+        let targets: Vec<BatchTarget> = ["a", "b", "c", "d", "e", "f"]
+            .into_iter()
+            .map(|n| target(n, if n < "c" { "eu" } else { "us" }))
+            .collect();
+        let batches = vec![
+            DeployBatch {
+                labels: BTreeMap::from([("region".into(), "eu".into())]),
+                ..Default::default()
+            },
+            // 50% of six, from the four left
+            DeployBatch {
+                percentage: Some(50),
+                wait_seconds: 60,
+                ..Default::default()
+            },
+            DeployBatch {
+                percentage: Some(100),
+                wait_seconds: 60,
+                health_check: true,
+                ..Default::default()
+            },
+        ];
+
+        let expanded = expand_batches(&batches, &targets).unwrap();
+        assert_eq!(
+            batch_names(&expanded),
+            vec![vec!["a", "b"], vec!["c", "d", "e"], vec!["f"]]
+        );
+        // Nothing follows the last batch, so it doesn't bake.
+        assert_eq!(expanded[2].wait_seconds, 0);
+        assert!(!expanded[2].health_check);
+    }
+
+    #[test]
+    fn test_expand_batches_rejects_unknown_or_empty() {
+        let targets = vec![target("prod-eu", "eu")];
+
+        let unknown = vec![DeployBatch {
+            destinations: vec!["prod-eu1".into()],
+            ..Default::default()
+        }];
+        let err = expand_batches(&unknown, &targets).unwrap_err();
+        assert!(err.to_string().contains("'prod-eu1' is not in the environment"));
+
+        let empty = vec![DeployBatch {
+            labels: BTreeMap::from([("region".into(), "us".into())]),
+            ..Default::default()
+        }];
+        let err = expand_batches(&empty, &targets).unwrap_err();
+        assert!(err.to_string().contains("batch 1 selects no destinations"));
+    }
+
+    #[test]
+    fn test_validate_pipeline_batches() {
+        let mut stages = PipelineStages::new();
+        stages.insert(
+            "deploy-prod".into(),
+            StageDefinition {
+                depends_on: vec![],
+                config: StageConfig::Deploy {
+                    environment: "prod".into(),
+                    batches: vec![DeployBatch {
+                        destinations: vec!["prod-eu".into()],
+                        percentage: Some(10),
+                        ..Default::default()
+                    }],
+                },
+            },
+        );
+        let err = validate_pipeline(&stages).unwrap_err();
+        assert!(format!("{err:#}").contains("exactly one of destinations, labels or percentage"));
+    }
+
     #[test]
     fn test_approval_status_serde() {
         let state = StageState {
@@ -480,6 +480,15 @@ impl ReleaseRegistry {
         &self,
         slug: &str,
     ) -> anyhow::Result<ReleaseAnnotation> {
+        self.find_release_annotation_by_slug(slug)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("failed to find annotation with slug: {}", slug))
+    }
+
+    pub async fn find_release_annotation_by_slug(
+        &self,
+        slug: &str,
+    ) -> anyhow::Result<Option<ReleaseAnnotation>> {
         let rec = sqlx::query!(
             "
                 SELECT
@@ -506,10 +515,10 @@ impl ReleaseRegistry {
         .context("get annotation (db)")?;
 
         let Some(rec) = rec else {
-            anyhow::bail!("failed to find annotation with slug: {}", slug);
+            return Ok(None);
         };
 
-        Ok(ReleaseAnnotation {
+        Ok(Some(ReleaseAnnotation {
             id: rec.id,
             artifact_id: rec.artifact_id,
             slug: rec.slug,
@@ -523,7 +532,7 @@ impl ReleaseRegistry {
             },
             destinations: Vec::new(),
             created_at: rec.created,
-        })
+        }))
     }
 
     pub async fn get_release_annotation_by_project(
@@ -1079,7 +1088,9 @@ impl ReleaseRegistry {
                     environment,
                     type_organisation,
                     type_name,
-                    type_version
+                    type_version,
+                    runner_labels,
+                    queue_policy
                 FROM destinations
                 WHERE organisation = $1
             ",
@@ -1103,7 +1114,11 @@ impl ReleaseRegistry {
                         description: String::new(),
                         fields: vec![],
                     },
-                ))
+                )
+                .with_runner_labels(
+                    serde_json::from_value(r.runner_labels).context("parse runner labels")?,
+                )
+                .with_queue_policy(r.queue_policy.parse().map_err(anyhow::Error::msg)?))
             })
             .collect::<anyhow::Result<Vec<_>>>()
     }
@@ -0,0 +1,271 @@
+//! Releasing an artifact on request: the checks every release must pass,
+//! and what happens once it is created.
+//!
+//! Shared by the `Release` RPC and scheduled releases, so both are held to
+//! the same policies.
+
+use anyhow::Context;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    State,
+    actor::Actor,
+    services::{
+        environment_protection,
+        event_bus::{EventBusState, EventPayload},
+        notification_registry::{NotificationRegistryState, ReleaseContext},
+        policy::{PolicyRegistryState, PolicyType},
+        release_event_store::ReleaseEventStoreState,
+        release_pipeline::ReleasePipelineRegistryState,
+        release_registry::{CreatedReleaseIntent, ReleaseRegistryState},
+        retention::RetentionRegistryState,
+    },
+};
+
+pub struct ReleaseRequest {
+    pub artifact_id: Uuid,
+    pub destinations: Vec<String>,
+    pub environments: Vec<String>,
+    pub force: bool,
+    pub use_pipeline: bool,
+}
+
+#[derive(Debug)]
+pub enum ReleaseRequestError {
+    /// A policy, or the state of the artifact, doesn't allow this release.
+    Blocked(String),
+    Internal(anyhow::Error),
+}
+
+impl std::fmt::Display for ReleaseRequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Blocked(reason) => f.write_str(reason),
+            Self::Internal(e) => write!(f, "{e:#}"),
+        }
+    }
+}
+
+impl From<anyhow::Error> for ReleaseRequestError {
+    fn from(e: anyhow::Error) -> Self {
+        Self::Internal(e)
+    }
+}
+
+/// Returns `Some(reason)` when any of `environments` requires a prior
+/// environment this artifact hasn't been released to successfully.
+pub async fn check_prior_environments(
+    db: &PgPool,
+    project_id: &Uuid,
+    artifact_id: &Uuid,
+    environments: &[String],
+) -> anyhow::Result<Option<String>> {
+    let mut conn = db.acquire().await.context("acquire connection")?;
+    for env in environments {
+        let protection = environment_protection::load_for_project(&mut conn, project_id, env).await?;
+        if let Some(reason) = environment_protection::check_prior_environment(
+            &mut conn,
+            project_id,
+            artifact_id,
+            env,
+            &protection,
+        )
+        .await?
+        {
+            return Ok(Some(reason));
+        }
+    }
+    Ok(None)
+}
+
+/// Check `request` against the artifact's retention and the target
+/// environments' policies, then create the release. `organisation` is the
+/// artifact's organisation, used to find the environments of explicitly
+/// named destinations. The caller is responsible for authorizing `actor`.
+pub async fn submit(
+    state: &State,
+    actor: &Actor,
+    organisation: Option<&str>,
+    request: ReleaseRequest,
+) -> Result<CreatedReleaseIntent, ReleaseRequestError> {
+    let artifact_id = request.artifact_id;
+
+    if let Some(purged_at) = state.retention_registry().purged_at(&artifact_id).await? {
+        return Err(ReleaseRequestError::Blocked(format!(
+            "artifact files were purged by the retention policy at {}; annotate a new release instead",
+            purged_at.to_rfc3339()
+        )));
+    }
+
+    // Evaluate branch restriction policies before releasing
+    let ann_ctx = state
+        .release_registry()
+        .get_annotation_context(&artifact_id)
+        .await
+        .ok();
+    let branch_for_policy = ann_ctx
+        .as_ref()
+        .and_then(|a| a.reference.commit_branch.clone());
+
+    // Collect all target environments to check policies against,
+    // including those of explicitly named destinations.
+    let mut target_envs: Vec<String> = request.environments.clone();
+    if !request.destinations.is_empty()
+        && let Some(org_name) = organisation
+    {
+        let dest_envs = sqlx::query_scalar!(
+            "SELECT DISTINCT environment FROM destinations
+             WHERE organisation = $1 AND name = ANY($2)",
+            org_name,
+            &request.destinations,
+        )
+        .fetch_all(&state.db)
+        .await
+        .context("resolve destination environments")?;
+        for env in dest_envs {
+            if !target_envs.contains(&env) {
+                target_envs.push(env);
+            }
+        }
+    }
+
+    if let Ok(project_id) = state
+        .release_registry()
+        .get_project_id_from_artifact(&artifact_id)
+        .await
+    {
+        // Pipelines check the prior environment when each stage starts.
+        if !request.use_pipeline
+            && let Some(reason) =
+                check_prior_environments(&state.db, &project_id, &artifact_id, &target_envs)
+                    .await?
+        {
+            return Err(ReleaseRequestError::Blocked(format!("blocked: {reason}")));
+        }
+
+        for env in &target_envs {
+            let evaluations = state
+                .policy_registry()
+                .evaluate_for_environment(&project_id, env, branch_for_policy.as_deref(), None)
+                .await
+                .unwrap_or_default();
+
+            for eval in &evaluations {
+                // Only enforce branch_restriction at request time.
+                // soak_time is handled by the scheduler (deferred retry).
+                if !eval.passed && eval.policy_type == PolicyType::BranchRestriction {
+                    return Err(ReleaseRequestError::Blocked(format!(
+                        "blocked by policy '{}': {}",
+                        eval.policy_name, eval.reason
+                    )));
+                }
+            }
+        }
+    }
+
+    let created = state
+        .release_registry()
+        .release(
+            &artifact_id,
+            request.destinations,
+            request.environments,
+            actor,
+            &state.release_event_store(),
+            request.force,
+            request.use_pipeline,
+            &state.release_pipeline_registry(),
+        )
+        .await
+        .context("release")?;
+
+    let dest_count = created.releases.len();
+    let dest_names: Vec<String> = created
+        .releases
+        .iter()
+        .map(|r| r.destination.clone())
+        .collect();
+
+    if let Err(e) = state
+        .notification_registry()
+        .create_notification(
+            "RELEASE_STARTED",
+            &format!(
+                "Release started: {}/{}",
+                &created.organisation, &created.project
+            ),
+            &format!("Release staged to {} destination(s)", dest_count),
+            &created.organisation,
+            &created.project,
+            &ReleaseContext {
+                slug: ann_ctx.as_ref().map(|a| a.slug.clone()),
+                artifact_id: Some(artifact_id.to_string()),
+                release_intent_id: Some(created.release_intent_id.to_string()),
+                destination: if dest_names.len() == 1 {
+                    Some(dest_names[0].clone())
+                } else {
+                    None
+                },
+                destination_count: dest_count as i32,
+                source_username: ann_ctx.as_ref().and_then(|a| a.source.username.clone()),
+                source_email: ann_ctx.as_ref().and_then(|a| a.source.email.clone()),
+                source_user_id: match actor {
+                    Actor::User { user_id } => Some(user_id.to_string()),
+                    _ => None,
+                },
+                source_type: ann_ctx
+                    .as_ref()
+                    .and_then(|a| a.source.source_type.clone()),
+                run_url: ann_ctx.as_ref().and_then(|a| a.source.run_url.clone()),
+                commit_sha: ann_ctx.as_ref().map(|a| a.reference.commit_sha.clone()),
+                commit_branch: ann_ctx
+                    .as_ref()
+                    .and_then(|a| a.reference.commit_branch.clone()),
+                commit_message: ann_ctx
+                    .as_ref()
+                    .and_then(|a| a.reference.commit_message.clone()),
+                version: ann_ctx
+                    .as_ref()
+                    .and_then(|a| a.reference.version.clone()),
+                repo_url: ann_ctx
+                    .as_ref()
+                    .and_then(|a| a.reference.repo_url.clone()),
+                context_title: ann_ctx.as_ref().map(|a| a.context.title.clone()),
+                context_description: ann_ctx
+                    .as_ref()
+                    .and_then(|a| a.context.description.clone()),
+                context_web: ann_ctx.as_ref().and_then(|a| a.context.web.clone()),
+                context_pr: ann_ctx.as_ref().and_then(|a| a.context.pr.clone()),
+                ..Default::default()
+            },
+        )
+        .await
+    {
+        tracing::warn!("failed to create release started notification: {e:#}");
+    }
+
+    state
+        .event_bus()
+        .emit(EventPayload {
+            organisation: created.organisation.clone(),
+            project: created.project.clone(),
+            resource_type: "release",
+            action: "created",
+            resource_id: created.release_intent_id.to_string(),
+            metadata: [("destinations".into(), dest_names.join(","))].into(),
+        })
+        .await;
+
+    // Signal the IntentCoordinator to evaluate this pipeline
+    if !created.activated_stages.is_empty() || request.use_pipeline {
+        let _ = state
+            .nats
+            .publish(
+                "forest.intent.evaluate",
+                created.release_intent_id.to_string().into(),
+            )
+            .await;
+    }
+
+    Ok(created)
+}
@@ -0,0 +1,775 @@
+//! Scheduled releases: a release at a set time, or on a repeating cron
+//! expression.
+//!
+//! The [`ReleaseScheduler`](crate::release_scheduler::ReleaseScheduler)
+//! claims schedules as they fall due and releases through
+//! [`release_request::submit`](crate::services::release_request::submit),
+//! so a scheduled release is held to the same policies as a manual one.
+//! Cron expressions are evaluated in UTC.
+
+use anyhow::Context;
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, Timelike, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{State, actor::Actor};
+
+/// Schedules claimed per pass.
+const RUN_BATCH: i64 = 50;
+
+#[derive(Clone)]
+pub struct ReleaseScheduleRegistry {
+    db: PgPool,
+    service_account_enabled: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScheduleStatus {
+    Active,
+    /// A one-off schedule that has run.
+    Completed,
+    Cancelled,
+}
+
+impl ScheduleStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Active => "ACTIVE",
+            Self::Completed => "COMPLETED",
+            Self::Cancelled => "CANCELLED",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "ACTIVE" => Some(Self::Active),
+            "COMPLETED" => Some(Self::Completed),
+            "CANCELLED" => Some(Self::Cancelled),
+            _ => None,
+        }
+    }
+}
+
+/// What happened the last time a schedule ran.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunOutcome {
+    Released,
+    /// There was no artifact to release.
+    Skipped,
+    /// A policy, or the creator's access, stopped the release.
+    Blocked,
+    Failed,
+}
+
+impl RunOutcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Released => "RELEASED",
+            Self::Skipped => "SKIPPED",
+            Self::Blocked => "BLOCKED",
+            Self::Failed => "FAILED",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "RELEASED" => Some(Self::Released),
+            "SKIPPED" => Some(Self::Skipped),
+            "BLOCKED" => Some(Self::Blocked),
+            "FAILED" => Some(Self::Failed),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScheduleTiming {
+    At(DateTime<Utc>),
+    Cron(CronSchedule),
+}
+
+impl ScheduleTiming {
+    /// The first run after `now`. `None` for a one-off time already past,
+    /// or a cron expression that never matches.
+    pub fn first_run(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self {
+            Self::At(at) => (*at > now).then_some(*at),
+            Self::Cron(cron) => cron.next_after(now),
+        }
+    }
+}
+
+/// A schedule to create.
+#[derive(Debug, Clone)]
+pub struct NewReleaseSchedule {
+    pub project_id: Uuid,
+    pub timing: ScheduleTiming,
+    /// Released on every run. When `None`, the project's latest artifact
+    /// (from `branch`, if set) is picked when the schedule runs.
+    pub artifact_id: Option<Uuid>,
+    pub branch: Option<String>,
+    pub environments: Vec<String>,
+    pub destinations: Vec<String>,
+    pub force: bool,
+    pub use_pipeline: bool,
+}
+
+impl NewReleaseSchedule {
+    pub fn validate(&self, now: DateTime<Utc>) -> anyhow::Result<()> {
+        if self.artifact_id.is_some() && self.branch.is_some() {
+            anyhow::bail!("set either an artifact or a branch, not both");
+        }
+        if self.branch.as_deref().is_some_and(|b| b.trim().is_empty()) {
+            anyhow::bail!("branch must not be empty");
+        }
+        if !self.use_pipeline && self.environments.is_empty() && self.destinations.is_empty() {
+            anyhow::bail!("at least one environment or destination is required, or use the pipeline");
+        }
+        if self.timing.first_run(now).is_none() {
+            match self.timing {
+                ScheduleTiming::At(_) => anyhow::bail!("run_at must be in the future"),
+                ScheduleTiming::Cron(_) => anyhow::bail!("cron expression never matches"),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ReleaseSchedule {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub organisation: String,
+    pub project: String,
+    pub run_at: Option<DateTime<Utc>>,
+    pub cron: Option<String>,
+    pub artifact_id: Option<Uuid>,
+    pub branch: Option<String>,
+    pub environments: Vec<String>,
+    pub destinations: Vec<String>,
+    pub force: bool,
+    pub use_pipeline: bool,
+    pub status: ScheduleStatus,
+    pub next_run_at: Option<DateTime<Utc>>,
+    pub last_run_at: Option<DateTime<Utc>>,
+    pub last_outcome: Option<RunOutcome>,
+    pub last_message: Option<String>,
+    pub last_release_intent_id: Option<Uuid>,
+    pub created_by_type: String,
+    pub created_by_id: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ReleaseSchedule {
+    /// The run after this one, or `None` when this is the last.
+    pub fn following_run(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.cron
+            .as_deref()
+            .and_then(|c| CronSchedule::parse(c).ok())
+            .and_then(|c| c.next_after(now))
+    }
+}
+
+struct ScheduleRow {
+    id: Uuid,
+    project_id: Uuid,
+    organisation: String,
+    project: String,
+    run_at: Option<DateTime<Utc>>,
+    cron: Option<String>,
+    artifact_id: Option<Uuid>,
+    branch: Option<String>,
+    environments: Vec<String>,
+    destinations: Vec<String>,
+    force_release: bool,
+    use_pipeline: bool,
+    status: String,
+    next_run_at: Option<DateTime<Utc>>,
+    last_run_at: Option<DateTime<Utc>>,
+    last_outcome: Option<String>,
+    last_message: Option<String>,
+    last_release_intent_id: Option<Uuid>,
+    created_by_type: String,
+    created_by_id: Uuid,
+    created_at: DateTime<Utc>,
+}
+
+impl From<ScheduleRow> for ReleaseSchedule {
+    fn from(r: ScheduleRow) -> Self {
+        Self {
+            id: r.id,
+            project_id: r.project_id,
+            organisation: r.organisation,
+            project: r.project,
+            run_at: r.run_at,
+            cron: r.cron,
+            artifact_id: r.artifact_id,
+            branch: r.branch,
+            environments: r.environments,
+            destinations: r.destinations,
+            force: r.force_release,
+            use_pipeline: r.use_pipeline,
+            status: ScheduleStatus::parse(&r.status).unwrap_or(ScheduleStatus::Cancelled),
+            next_run_at: r.next_run_at,
+            last_run_at: r.last_run_at,
+            last_outcome: r.last_outcome.as_deref().and_then(RunOutcome::parse),
+            last_message: r.last_message,
+            last_release_intent_id: r.last_release_intent_id,
+            created_by_type: r.created_by_type,
+            created_by_id: r.created_by_id,
+            created_at: r.created_at,
+        }
+    }
+}
+
+impl ReleaseScheduleRegistry {
+    pub async fn create(
+        &self,
+        schedule: &NewReleaseSchedule,
+        actor: &Actor,
+    ) -> anyhow::Result<ReleaseSchedule> {
+        let now = Utc::now();
+        schedule.validate(now)?;
+
+        let (run_at, cron) = match &schedule.timing {
+            ScheduleTiming::At(at) => (Some(*at), None),
+            ScheduleTiming::Cron(cron) => (None, Some(cron.expression().to_string())),
+        };
+        let id = Uuid::now_v7();
+
+        sqlx::query!(
+            r#"INSERT INTO release_schedules (
+                   id, project_id, run_at, cron, artifact_id, branch,
+                   environments, destinations, force_release, use_pipeline,
+                   next_run_at, created_by_type, created_by_id
+               ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)"#,
+            id,
+            schedule.project_id,
+            run_at,
+            cron,
+            schedule.artifact_id,
+            schedule.branch,
+            &schedule.environments,
+            &schedule.destinations,
+            schedule.force,
+            schedule.use_pipeline,
+            schedule.timing.first_run(now),
+            actor.actor_type(),
+            actor.actor_id(),
+        )
+        .execute(&self.db)
+        .await
+        .context("insert release schedule")?;
+
+        self.get(&id)
+            .await?
+            .context("release schedule not found after create")
+    }
+
+    pub async fn get(&self, id: &Uuid) -> anyhow::Result<Option<ReleaseSchedule>> {
+        let row = sqlx::query_as!(
+            ScheduleRow,
+            r#"SELECT s.id, s.project_id, p.organisation, p.project, s.run_at, s.cron,
+                      s.artifact_id, s.branch, s.environments, s.destinations,
+                      s.force_release, s.use_pipeline, s.status, s.next_run_at,
+                      s.last_run_at, s.last_outcome, s.last_message, s.last_release_intent_id,
+                      s.created_by_type, s.created_by_id, s.created_at
+               FROM release_schedules s
+               JOIN projects p ON p.id = s.project_id
+               WHERE s.id = $1"#,
+            id,
+        )
+        .fetch_optional(&self.db)
+        .await
+        .context("get release schedule")?;
+
+        Ok(row.map(Into::into))
+    }
+
+    /// A project's schedules, soonest first. Completed and cancelled ones
+    /// are only included with `include_inactive`.
+    pub async fn list(
+        &self,
+        project_id: &Uuid,
+        include_inactive: bool,
+    ) -> anyhow::Result<Vec<ReleaseSchedule>> {
+        let rows = sqlx::query_as!(
+            ScheduleRow,
+            r#"SELECT s.id, s.project_id, p.organisation, p.project, s.run_at, s.cron,
+                      s.artifact_id, s.branch, s.environments, s.destinations,
+                      s.force_release, s.use_pipeline, s.status, s.next_run_at,
+                      s.last_run_at, s.last_outcome, s.last_message, s.last_release_intent_id,
+                      s.created_by_type, s.created_by_id, s.created_at
+               FROM release_schedules s
+               JOIN projects p ON p.id = s.project_id
+               WHERE s.project_id = $1 AND ($2 OR s.status = 'ACTIVE')
+               ORDER BY s.next_run_at ASC NULLS LAST, s.created_at DESC"#,
+            project_id,
+            include_inactive,
+        )
+        .fetch_all(&self.db)
+        .await
+        .context("list release schedules")?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    /// Stop an active schedule. Returns `None` when there is no active
+    /// schedule with this id in the project.
+    pub async fn cancel(
+        &self,
+        project_id: &Uuid,
+        id: &Uuid,
+    ) -> anyhow::Result<Option<ReleaseSchedule>> {
+        let cancelled = sqlx::query_scalar!(
+            r#"UPDATE release_schedules
+               SET status = 'CANCELLED', next_run_at = NULL, updated_at = now()
+               WHERE id = $1 AND project_id = $2 AND status = 'ACTIVE'
+               RETURNING id"#,
+            id,
+            project_id,
+        )
+        .fetch_optional(&self.db)
+        .await
+        .context("cancel release schedule")?;
+
+        match cancelled {
+            Some(id) => self.get(&id).await,
+            None => Ok(None),
+        }
+    }
+
+    /// Active schedules whose next run is at or before `now`.
+    pub async fn due(&self, now: DateTime<Utc>) -> anyhow::Result<Vec<ReleaseSchedule>> {
+        let rows = sqlx::query_as!(
+            ScheduleRow,
+            r#"SELECT s.id, s.project_id, p.organisation, p.project, s.run_at, s.cron,
+                      s.artifact_id, s.branch, s.environments, s.destinations,
+                      s.force_release, s.use_pipeline, s.status, s.next_run_at,
+                      s.last_run_at, s.last_outcome, s.last_message, s.last_release_intent_id,
+                      s.created_by_type, s.created_by_id, s.created_at
+               FROM release_schedules s
+               JOIN projects p ON p.id = s.project_id
+               WHERE s.status = 'ACTIVE' AND s.next_run_at <= $1
+               ORDER BY s.next_run_at ASC
+               LIMIT $2"#,
+            now,
+            RUN_BATCH,
+        )
+        .fetch_all(&self.db)
+        .await
+        .context("list due release schedules")?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    /// Claim a due run by moving the schedule on to `next_run_at`, or
+    /// completing it when there is none. Returns false when another server
+    /// claimed the run, or the schedule was cancelled, since it was listed.
+    pub async fn claim(
+        &self,
+        schedule: &ReleaseSchedule,
+        next_run_at: Option<DateTime<Utc>>,
+    ) -> anyhow::Result<bool> {
+        let claimed = sqlx::query_scalar!(
+            r#"UPDATE release_schedules
+               SET next_run_at = $3,
+                   status = CASE WHEN $3::timestamptz IS NULL THEN 'COMPLETED' ELSE status END,
+                   updated_at = now()
+               WHERE id = $1 AND status = 'ACTIVE' AND next_run_at = $2
+               RETURNING id"#,
+            schedule.id,
+            schedule.next_run_at,
+            next_run_at,
+        )
+        .fetch_optional(&self.db)
+        .await
+        .context("claim release schedule")?;
+
+        Ok(claimed.is_some())
+    }
+
+    pub async fn record_run(
+        &self,
+        id: &Uuid,
+        outcome: RunOutcome,
+        message: &str,
+        release_intent_id: Option<Uuid>,
+    ) -> anyhow::Result<()> {
+        sqlx::query!(
+            r#"UPDATE release_schedules
+               SET last_run_at = now(),
+                   last_outcome = $2,
+                   last_message = $3,
+                   last_release_intent_id = $4,
+                   updated_at = now()
+               WHERE id = $1"#,
+            id,
+            outcome.as_str(),
+            message,
+            release_intent_id,
+        )
+        .execute(&self.db)
+        .await
+        .context("record release schedule run")?;
+
+        Ok(())
+    }
+
+    /// The project's newest artifact, from `branch` when set.
+    pub async fn latest_artifact(
+        &self,
+        project_id: &Uuid,
+        branch: Option<&str>,
+    ) -> anyhow::Result<Option<Uuid>> {
+        sqlx::query_scalar!(
+            r#"SELECT artifact_id FROM annotations
+               WHERE project_id = $1
+                 AND ($2::text IS NULL OR ref->>'commit_branch' = $2)
+               ORDER BY created DESC
+               LIMIT 1"#,
+            project_id,
+            branch,
+        )
+        .fetch_optional(&self.db)
+        .await
+        .context("find latest artifact")
+    }
+
+    /// The actor a schedule releases as: whoever created it. Apps are
+    /// loaded afresh, so a schedule stops releasing once its app is
+    /// suspended or deleted, and a service account's schedules stop once
+    /// the server no longer has a service account key.
+    pub async fn actor(&self, schedule: &ReleaseSchedule) -> anyhow::Result<ScheduleActor> {
+        let actor = match schedule.created_by_type.as_str() {
+            "user" => Actor::User {
+                user_id: schedule.created_by_id,
+            },
+            "app" => {
+                let app = sqlx::query!(
+                    "SELECT organisation_id, suspended FROM apps WHERE id = $1",
+                    schedule.created_by_id,
+                )
+                .fetch_optional(&self.db)
+                .await
+                .context("load schedule app")?;
+                let Some(app) = app else {
+                    return Ok(ScheduleActor::Revoked(
+                        "the app that created the schedule was deleted".into(),
+                    ));
+                };
+                if app.suspended {
+                    return Ok(ScheduleActor::Revoked(
+                        "the app that created the schedule is suspended".into(),
+                    ));
+                }
+                Actor::App {
+                    app_id: schedule.created_by_id,
+                    organisation_id: app.organisation_id,
+                }
+            }
+            "service_account" => {
+                if !self.service_account_enabled {
+                    return Ok(ScheduleActor::Revoked(
+                        "service accounts are no longer enabled".into(),
+                    ));
+                }
+                Actor::ServiceAccount {
+                    service_account_id: schedule.created_by_id,
+                }
+            }
+            other => anyhow::bail!("unknown actor type '{other}'"),
+        };
+        Ok(ScheduleActor::Active(actor))
+    }
+}
+
+/// Who a schedule releases as when it fires.
+pub enum ScheduleActor {
+    Active(Actor),
+    /// The creator can no longer act, and why.
+    Revoked(String),
+}
+
+pub trait ReleaseScheduleRegistryState {
+    fn release_schedule_registry(&self) -> ReleaseScheduleRegistry;
+}
+
+impl ReleaseScheduleRegistryState for State {
+    fn release_schedule_registry(&self) -> ReleaseScheduleRegistry {
+        ReleaseScheduleRegistry {
+            db: self.db.clone(),
+            service_account_enabled: self.config.service_account_token_hash.is_some(),
+        }
+    }
+}
+
+/// A five-field cron expression: minute, hour, day of month, month and day
+/// of week. Fields take `*`, values, ranges (`1-5`), steps (`*/15`,
+/// `0-30/10`) and comma-separated lists; day of week runs 0-6 from Sunday,
+/// with 7 also Sunday. `@hourly`, `@daily`, `@weekly` and `@monthly` are
+/// accepted as shorthands.
+///
+/// As in classic cron, when both day of month and day of week are
+/// restricted a day matching either one matches.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronSchedule {
+    expression: String,
+    minutes: u64,
+    hours: u64,
+    days: u64,
+    months: u64,
+    weekdays: u64,
+    days_restricted: bool,
+    weekdays_restricted: bool,
+}
+
+impl CronSchedule {
+    pub fn parse(expression: &str) -> anyhow::Result<Self> {
+        let expression = expression.trim();
+        let expanded = match expression {
+            "@hourly" => "0 * * * *",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@weekly" => "0 0 * * 0",
+            "@monthly" => "0 0 1 * *",
+            other => other,
+        };
+
+        let fields: Vec<&str> = expanded.split_whitespace().collect();
+        let [minute, hour, day, month, weekday] = fields[..] else {
+            anyhow::bail!(
+                "cron expression '{expression}' must have 5 fields: minute hour day-of-month month day-of-week"
+            );
+        };
+
+        let mut weekdays = parse_field(weekday, "day of week", 0, 7)?;
+        // 7 is Sunday too.
+        if weekdays & (1 << 7) != 0 {
+            weekdays = (weekdays & !(1 << 7)) | 1;
+        }
+
+        Ok(Self {
+            expression: expression.to_string(),
+            minutes: parse_field(minute, "minute", 0, 59)?,
+            hours: parse_field(hour, "hour", 0, 23)?,
+            days: parse_field(day, "day of month", 1, 31)?,
+            months: parse_field(month, "month", 1, 12)?,
+            weekdays,
+            days_restricted: day != "*",
+            weekdays_restricted: weekday != "*",
+        })
+    }
+
+    pub fn expression(&self) -> &str {
+        &self.expression
+    }
+
+    /// The first matching minute strictly after `after`.
+    pub fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let start = after
+            .naive_utc()
+            .with_second(0)?
+            .with_nanosecond(0)?
+            .checked_add_signed(chrono::Duration::minutes(1))?;
+        // Day-level skips bound the search; 29 February on a given weekday
+        // can be 28 years away.
+        let limit = start.checked_add_signed(chrono::Duration::days(366 * 29))?;
+
+        let mut t = start;
+        while t < limit {
+            if !bit(self.months, t.month()) {
+                let (year, month) = if t.month() == 12 {
+                    (t.year() + 1, 1)
+                } else {
+                    (t.year(), t.month() + 1)
+                };
+                t = NaiveDate::from_ymd_opt(year, month, 1)?.and_hms_opt(0, 0, 0)?;
+                continue;
+            }
+            if !self.day_matches(&t) {
+                t = start_of_next_day(&t)?;
+                continue;
+            }
+            if !bit(self.hours, t.hour()) {
+                t = t.with_minute(0)? + chrono::Duration::hours(1);
+                continue;
+            }
+            if !bit(self.minutes, t.minute()) {
+                t += chrono::Duration::minutes(1);
+                continue;
+            }
+            return Some(t.and_utc());
+        }
+        None
+    }
+
+    fn day_matches(&self, t: &NaiveDateTime) -> bool {
+        let day = bit(self.days, t.day());
+        let weekday = bit(self.weekdays, t.weekday().num_days_from_sunday());
+        match (self.days_restricted, self.weekdays_restricted) {
+            (true, true) => day || weekday,
+            _ => day && weekday,
+        }
+    }
+}
+
+fn bit(set: u64, value: u32) -> bool {
+    set & (1 << value) != 0
+}
+
+fn start_of_next_day(t: &NaiveDateTime) -> Option<NaiveDateTime> {
+    t.date().succ_opt()?.and_hms_opt(0, 0, 0)
+}
+
+fn parse_field(field: &str, name: &str, min: u32, max: u32) -> anyhow::Result<u64> {
+    let mut set = 0u64;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step: u32 = step
+                    .parse()
+                    .ok()
+                    .filter(|s| *s > 0)
+                    .with_context(|| format!("invalid step '{step}' in {name}"))?;
+                (range, step)
+            }
+            None => (part, 1),
+        };
+
+        let (start, end) = match range {
+            "*" => (min, max),
+            _ => match range.split_once('-') {
+                Some((a, b)) => (parse_value(a, name, min, max)?, parse_value(b, name, min, max)?),
+                // `5/15` means from 5 to the end in steps of 15.
+                None if step > 1 => (parse_value(range, name, min, max)?, max),
+                None => {
+                    let v = parse_value(range, name, min, max)?;
+                    (v, v)
+                }
+            },
+        };
+        if start > end {
+            anyhow::bail!("invalid range '{range}' in {name}");
+        }
+
+        for v in (start..=end).step_by(step as usize) {
+            set |= 1 << v;
+        }
+    }
+    Ok(set)
+}
+
+fn parse_value(value: &str, name: &str, min: u32, max: u32) -> anyhow::Result<u32> {
+    value
+        .parse()
+        .ok()
+        .filter(|v| (min..=max).contains(v))
+        .with_context(|| format!("{name} '{value}' must be between {min} and {max}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    fn next(expression: &str, after: &str) -> Option<DateTime<Utc>> {
+        CronSchedule::parse(expression).unwrap().next_after(at(after))
+    }
+
+    #[test]
+    fn cron_finds_the_next_matching_minute() {
+        assert_eq!(
+            next("0 6 * * *", "2026-10-19T05:59:30Z"),
+            Some(at("2026-10-19T06:00:00Z"))
+        );
+        // Strictly after: a run at 06:00 is followed by the next day's.
+        assert_eq!(
+            next("0 6 * * *", "2026-10-19T06:00:00Z"),
+            Some(at("2026-10-20T06:00:00Z"))
+        );
+        assert_eq!(
+            next("*/15 * * * *", "2026-10-19T10:16:00Z"),
+            Some(at("2026-10-19T10:30:00Z"))
+        );
+        assert_eq!(
+            next("@monthly", "2026-12-15T00:00:00Z"),
+            Some(at("2027-01-01T00:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn cron_weekdays_and_days() {
+        // 2026-10-19 is a Monday.
+        assert_eq!(
+            next("30 2 * * 1-5", "2026-10-16T12:00:00Z"),
+            Some(at("2026-10-19T02:30:00Z"))
+        );
+        assert_eq!(
+            next("0 0 * * 7", "2026-10-19T00:00:00Z"),
+            Some(at("2026-10-25T00:00:00Z"))
+        );
+        // Both restricted: the 1st of the month or any Monday.
+        assert_eq!(
+            next("0 0 1 * 1", "2026-10-20T00:00:00Z"),
+            Some(at("2026-10-26T00:00:00Z"))
+        );
+        assert_eq!(
+            next("0 0 29 2 *", "2026-03-01T00:00:00Z"),
+            Some(at("2028-02-29T00:00:00Z"))
+        );
+        assert_eq!(next("0 0 31 2 *", "2026-03-01T00:00:00Z"), None);
+    }
+
+    #[test]
+    fn cron_rejects_malformed_expressions() {
+        for expression in ["", "* * * *", "60 * * * *", "* 24 * * *", "*/0 * * * *", "5-1 * * * *", "a * * * *"] {
+            assert!(
+                CronSchedule::parse(expression).is_err(),
+                "{expression:?} should not parse"
+            );
+        }
+    }
+
+    #[test]
+    fn validate_schedule() {
+        let now = at("2026-10-19T12:00:00Z");
+        let schedule = NewReleaseSchedule {
+            project_id: Uuid::nil(),
+            timing: ScheduleTiming::At(at("2026-10-20T06:00:00Z")),
+            artifact_id: None,
+            branch: Some("main".into()),
+            environments: vec!["prod".into()],
+            destinations: vec![],
+            force: false,
+            use_pipeline: false,
+        };
+        schedule.validate(now).unwrap();
+
+        let past = NewReleaseSchedule {
+            timing: ScheduleTiming::At(at("2026-10-19T06:00:00Z")),
+            ..schedule.clone()
+        };
+        assert!(past.validate(now).is_err());
+
+        let both = NewReleaseSchedule {
+            artifact_id: Some(Uuid::nil()),
+            ..schedule.clone()
+        };
+        assert!(both.validate(now).is_err());
+
+        let no_targets = NewReleaseSchedule {
+            environments: vec![],
+            ..schedule.clone()
+        };
+        assert!(no_targets.validate(now).is_err());
+
+        let never = NewReleaseSchedule {
+            timing: ScheduleTiming::Cron(CronSchedule::parse("0 0 30 2 *").unwrap()),
+            ..schedule
+        };
+        assert!(never.validate(now).is_err());
+    }
+}
@@ -0,0 +1,809 @@
+//! Retention policies and the garbage collection they drive.
+//!
+//! An organisation's policy decides how many artifacts to keep and how long
+//! release logs, health observations and notifications live. The
+//! [`RetentionReaper`](crate::retention_reaper::RetentionReaper) applies
+//! every policy periodically; [`RetentionRegistry::plan`] computes the same
+//! result without deleting anything, for previews.
+//!
+//! Purging an artifact removes its files from object storage and the
+//! database but keeps its annotation and release history. It is marked
+//! purged first, so it can no longer be released while its files go.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use anyhow::Context;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{State, object_store::ObjectStore};
+
+/// Rows deleted per statement when expiring logs and notifications, so a
+/// first run against years of history doesn't hold one huge transaction.
+const DELETE_BATCH: i64 = 5000;
+
+#[derive(Clone)]
+pub struct RetentionRegistry {
+    db: PgPool,
+    object_store: ObjectStore,
+}
+
+/// `None` keeps everything of that kind.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RetentionPolicy {
+    pub organisation: String,
+    pub keep_artifacts: Option<i32>,
+    pub log_retention_days: Option<i32>,
+    pub notification_retention_days: Option<i32>,
+    pub updated_by: Option<String>,
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl RetentionPolicy {
+    pub fn validate(&self) -> anyhow::Result<()> {
+        for (field, value) in [
+            ("keep_artifacts", self.keep_artifacts),
+            ("log_retention_days", self.log_retention_days),
+            ("notification_retention_days", self.notification_retention_days),
+        ] {
+            if value.is_some_and(|v| v < 1) {
+                anyhow::bail!("{field} must be at least 1, or unset to keep everything");
+            }
+        }
+        Ok(())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keep_artifacts.is_none()
+            && self.log_retention_days.is_none()
+            && self.notification_retention_days.is_none()
+    }
+}
+
+/// What retention needs to know about one artifact.
+#[derive(Debug, Clone)]
+pub struct ArtifactUsage {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub created: chrono::DateTime<chrono::Utc>,
+    /// Last deploy to each environment.
+    pub releases: BTreeMap<String, chrono::DateTime<chrono::Utc>>,
+    /// Currently deployed somewhere, or part of a release still in progress.
+    pub pinned: bool,
+}
+
+/// Artifacts outside every keep set: not among the newest `keep` in their
+/// project, not among the `keep` most recently released to any
+/// environment, and not pinned.
+pub fn select_expired(artifacts: &[ArtifactUsage], keep: usize) -> Vec<Uuid> {
+    let mut kept: HashSet<Uuid> = artifacts.iter().filter(|a| a.pinned).map(|a| a.id).collect();
+
+    let mut by_project: HashMap<Uuid, Vec<&ArtifactUsage>> = HashMap::new();
+    for a in artifacts {
+        by_project.entry(a.project_id).or_default().push(a);
+    }
+
+    for project in by_project.values_mut() {
+        project.sort_by(|a, b| b.created.cmp(&a.created).then(b.id.cmp(&a.id)));
+        kept.extend(project.iter().take(keep).map(|a| a.id));
+
+        let mut by_env: BTreeMap<&str, Vec<(chrono::DateTime<chrono::Utc>, Uuid)>> =
+            BTreeMap::new();
+        for a in project.iter() {
+            for (env, at) in &a.releases {
+                by_env.entry(env).or_default().push((*at, a.id));
+            }
+        }
+        for releases in by_env.values_mut() {
+            releases.sort_by(|a, b| b.cmp(a));
+            kept.extend(releases.iter().take(keep).map(|(_, id)| *id));
+        }
+    }
+
+    let mut expired: Vec<&ArtifactUsage> =
+        artifacts.iter().filter(|a| !kept.contains(&a.id)).collect();
+    expired.sort_by_key(|a| a.created);
+    expired.into_iter().map(|a| a.id).collect()
+}
+
+#[derive(Debug, Clone)]
+pub struct ExpiredArtifact {
+    pub id: Uuid,
+    pub project: String,
+    pub slug: String,
+    pub created: chrono::DateTime<chrono::Utc>,
+    pub file_count: i64,
+    pub size_bytes: i64,
+}
+
+/// What applying a policy would delete right now.
+#[derive(Debug, Clone, Default)]
+pub struct RetentionPlan {
+    pub organisation: String,
+    pub artifacts: Vec<ExpiredArtifact>,
+    pub log_cutoff: Option<chrono::DateTime<chrono::Utc>>,
+    pub log_blocks: i64,
+    pub health_observations: i64,
+    pub notification_cutoff: Option<chrono::DateTime<chrono::Utc>>,
+    pub notifications: i64,
+}
+
+impl RetentionPlan {
+    pub fn is_empty(&self) -> bool {
+        self.artifacts.is_empty()
+            && self.log_blocks == 0
+            && self.health_observations == 0
+            && self.notifications == 0
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Purged {
+    pub artifacts: i64,
+    pub files: i64,
+    pub log_blocks: i64,
+    pub health_observations: i64,
+    pub notifications: i64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StagingPurge {
+    pub artifact_uploads: i64,
+    pub component_uploads: i64,
+}
+
+impl RetentionRegistry {
+    /// The organisation's policy; an empty one when none is set.
+    pub async fn get(&self, organisation: &str) -> anyhow::Result<RetentionPolicy> {
+        let rec = sqlx::query_as!(
+            RetentionPolicy,
+            r#"SELECT organisation, keep_artifacts, log_retention_days,
+                      notification_retention_days, updated_by,
+                      updated_at AS "updated_at?"
+               FROM retention_policies
+               WHERE organisation = $1"#,
+            organisation,
+        )
+        .fetch_optional(&self.db)
+        .await
+        .context("get retention policy")?;
+
+        Ok(rec.unwrap_or_else(|| RetentionPolicy {
+            organisation: organisation.to_string(),
+            ..Default::default()
+        }))
+    }
+
+    /// Replace the organisation's policy.
+    pub async fn set(&self, policy: &RetentionPolicy, actor: &str) -> anyhow::Result<RetentionPolicy> {
+        policy.validate()?;
+
+        let rec = sqlx::query_as!(
+            RetentionPolicy,
+            r#"INSERT INTO retention_policies
+                (organisation, keep_artifacts, log_retention_days,
+                 notification_retention_days, updated_by)
+               VALUES ($1, $2, $3, $4, $5)
+               ON CONFLICT (organisation) DO UPDATE
+               SET keep_artifacts = EXCLUDED.keep_artifacts,
+                   log_retention_days = EXCLUDED.log_retention_days,
+                   notification_retention_days = EXCLUDED.notification_retention_days,
+                   updated_by = EXCLUDED.updated_by,
+                   updated_at = now()
+               RETURNING organisation, keep_artifacts, log_retention_days,
+                         notification_retention_days, updated_by,
+                         updated_at AS "updated_at?""#,
+            policy.organisation,
+            policy.keep_artifacts,
+            policy.log_retention_days,
+            policy.notification_retention_days,
+            actor,
+        )
+        .fetch_one(&self.db)
+        .await
+        .context("store retention policy")?;
+
+        Ok(rec)
+    }
+
+    /// Every policy that retains less than everything.
+    pub async fn policies(&self) -> anyhow::Result<Vec<RetentionPolicy>> {
+        let recs = sqlx::query_as!(
+            RetentionPolicy,
+            r#"SELECT organisation, keep_artifacts, log_retention_days,
+                      notification_retention_days, updated_by,
+                      updated_at AS "updated_at?"
+               FROM retention_policies
+               WHERE keep_artifacts IS NOT NULL
+                  OR log_retention_days IS NOT NULL
+                  OR notification_retention_days IS NOT NULL
+               ORDER BY organisation"#,
+        )
+        .fetch_all(&self.db)
+        .await
+        .context("list retention policies")?;
+
+        Ok(recs)
+    }
+
+    /// When the artifact's files were purged, if they were.
+    pub async fn purged_at(
+        &self,
+        artifact_id: &Uuid,
+    ) -> anyhow::Result<Option<chrono::DateTime<chrono::Utc>>> {
+        let purged = sqlx::query_scalar!(
+            "SELECT files_purged_at FROM artifacts WHERE id = $1",
+            artifact_id,
+        )
+        .fetch_optional(&self.db)
+        .await
+        .context("get artifact purge time")?;
+
+        Ok(purged.flatten())
+    }
+
+    /// What `policy` would delete as of `now`.
+    pub async fn plan(
+        &self,
+        policy: &RetentionPolicy,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<RetentionPlan> {
+        let organisation = &policy.organisation;
+        let mut plan = RetentionPlan {
+            organisation: organisation.clone(),
+            ..Default::default()
+        };
+
+        if let Some(keep) = policy.keep_artifacts {
+            plan.artifacts = self.expired_artifacts(organisation, keep as usize).await?;
+        }
+
+        if let Some(days) = policy.log_retention_days {
+            let cutoff = now - chrono::Duration::days(days.into());
+            plan.log_cutoff = Some(cutoff);
+            plan.log_blocks = sqlx::query_scalar!(
+                r#"SELECT count(*) AS "count!"
+                   FROM release_logs rl
+                   JOIN release_intents ri ON ri.id = rl.release_intent_id
+                   JOIN projects p ON p.id = ri.project_id
+                   WHERE p.organisation = $1 AND rl.created < $2"#,
+                organisation,
+                cutoff,
+            )
+            .fetch_one(&self.db)
+            .await
+            .context("count expired logs")?;
+            plan.health_observations = sqlx::query_scalar!(
+                r#"SELECT count(*) AS "count!"
+                   FROM release_health_observations
+                   WHERE organisation = $1 AND updated_at < $2"#,
+                organisation,
+                cutoff,
+            )
+            .fetch_one(&self.db)
+            .await
+            .context("count expired health observations")?;
+        }
+
+        if let Some(days) = policy.notification_retention_days {
+            let cutoff = now - chrono::Duration::days(days.into());
+            plan.notification_cutoff = Some(cutoff);
+            plan.notifications = sqlx::query_scalar!(
+                r#"SELECT count(*) AS "count!"
+                   FROM notifications
+                   WHERE organisation = $1 AND created_at < $2"#,
+                organisation,
+                cutoff,
+            )
+            .fetch_one(&self.db)
+            .await
+            .context("count expired notifications")?;
+        }
+
+        Ok(plan)
+    }
+
+    async fn expired_artifacts(
+        &self,
+        organisation: &str,
+        keep: usize,
+    ) -> anyhow::Result<Vec<ExpiredArtifact>> {
+        let recs = sqlx::query!(
+            r#"SELECT ar.id, a.project_id, a.slug, a.created, p.project
+               FROM artifacts ar
+               JOIN annotations a ON a.artifact_id = ar.id
+               JOIN projects p ON p.id = a.project_id
+               WHERE p.organisation = $1 AND ar.files_purged_at IS NULL"#,
+            organisation,
+        )
+        .fetch_all(&self.db)
+        .await
+        .context("list artifacts")?;
+
+        let releases = sqlx::query!(
+            r#"SELECT rs.artifact_id, d.environment, max(rs.queued_at) AS "last_released!"
+               FROM release_states rs
+               JOIN destinations d ON d.id = rs.destination_id
+               JOIN projects p ON p.id = rs.project_id
+               WHERE p.organisation = $1 AND rs.mode = 'deploy'
+               GROUP BY rs.artifact_id, d.environment"#,
+            organisation,
+        )
+        .fetch_all(&self.db)
+        .await
+        .context("list artifact releases")?;
+
+        let mut conn = self.db.acquire().await?;
+        let pinned: HashSet<Uuid> = pinned_artifacts(&mut conn, organisation)
+            .await?
+            .into_iter()
+            .collect();
+
+        let mut usage: HashMap<Uuid, ArtifactUsage> = recs
+            .iter()
+            .map(|r| {
+                (
+                    r.id,
+                    ArtifactUsage {
+                        id: r.id,
+                        project_id: r.project_id,
+                        created: r.created,
+                        releases: BTreeMap::new(),
+                        pinned: pinned.contains(&r.id),
+                    },
+                )
+            })
+            .collect();
+        for r in releases {
+            if let Some(a) = usage.get_mut(&r.artifact_id) {
+                a.releases.insert(r.environment, r.last_released);
+            }
+        }
+
+        let usage: Vec<ArtifactUsage> = usage.into_values().collect();
+        let expired = select_expired(&usage, keep);
+        if expired.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let sizes = sqlx::query!(
+            r#"SELECT ar.id,
+                      count(af.id) AS "file_count!",
+                      coalesce(sum(octet_length(b.content)), 0)::bigint AS "size_bytes!"
+               FROM artifacts ar
+               LEFT JOIN artifact_files af ON af.artifact_staging_id = ar.artifact_id
+               LEFT JOIN blob_storage b ON b.id = af.file_content
+               WHERE ar.id = ANY($1)
+               GROUP BY ar.id"#,
+            &expired,
+        )
+        .fetch_all(&self.db)
+        .await
+        .context("size expired artifacts")?;
+        let sizes: HashMap<Uuid, (i64, i64)> = sizes
+            .into_iter()
+            .map(|s| (s.id, (s.file_count, s.size_bytes)))
+            .collect();
+
+        let recs: HashMap<Uuid, _> = recs.into_iter().map(|r| (r.id, r)).collect();
+        Ok(expired
+            .into_iter()
+            .filter_map(|id| {
+                let r = recs.get(&id)?;
+                let (file_count, size_bytes) = sizes.get(&id).copied().unwrap_or_default();
+                Some(ExpiredArtifact {
+                    id,
+                    project: r.project.clone(),
+                    slug: r.slug.clone(),
+                    created: r.created,
+                    file_count,
+                    size_bytes,
+                })
+            })
+            .collect())
+    }
+
+    /// Delete what `plan` lists. Artifacts that became pinned since the plan
+    /// was made are skipped.
+    pub async fn apply(&self, plan: &RetentionPlan) -> anyhow::Result<Purged> {
+        let mut purged = Purged::default();
+
+        // Re-check pinning in the transaction that marks the artifacts, so a
+        // release created while the plan ran keeps its files.
+        let planned: Vec<Uuid> = plan.artifacts.iter().map(|a| a.id).collect();
+        if !planned.is_empty() {
+            let mut tx = self.db.begin().await?;
+            let pinned = pinned_artifacts(&mut tx, &plan.organisation).await?;
+            let marked = sqlx::query!(
+                "UPDATE artifacts SET files_purged_at = now(), updated = now()
+                 WHERE id = ANY($1) AND NOT (id = ANY($2)) AND files_purged_at IS NULL",
+                &planned,
+                &pinned,
+            )
+            .execute(&mut *tx)
+            .await
+            .context("mark artifacts purged")?;
+            tx.commit().await?;
+            purged.artifacts = marked.rows_affected() as i64;
+        }
+
+        // Includes artifacts whose files failed to delete on an earlier run.
+        let staging_ids = sqlx::query_scalar!(
+            r#"SELECT DISTINCT ar.artifact_id
+               FROM artifacts ar
+               JOIN annotations a ON a.artifact_id = ar.id
+               JOIN projects p ON p.id = a.project_id
+               WHERE p.organisation = $1
+                 AND ar.files_purged_at IS NOT NULL
+                 AND EXISTS (SELECT 1 FROM artifact_files af WHERE af.artifact_staging_id = ar.artifact_id)"#,
+            plan.organisation,
+        )
+        .fetch_all(&self.db)
+        .await
+        .context("list purged artifacts with files")?;
+        for staging_id in staging_ids {
+            match self.delete_artifact_files(&staging_id).await {
+                Ok(files) => purged.files += files,
+                Err(e) => tracing::warn!(%staging_id, "failed to delete artifact files: {e:#}"),
+            }
+        }
+
+        if let Some(cutoff) = plan.log_cutoff {
+            purged.log_blocks = delete_in_batches(&self.db, || {
+                sqlx::query!(
+                    "DELETE FROM release_logs WHERE id IN (
+                         SELECT rl.id
+                         FROM release_logs rl
+                         JOIN release_intents ri ON ri.id = rl.release_intent_id
+                         JOIN projects p ON p.id = ri.project_id
+                         WHERE p.organisation = $1 AND rl.created < $2
+                         LIMIT $3
+                     )",
+                    plan.organisation,
+                    cutoff,
+                    DELETE_BATCH,
+                )
+            })
+            .await
+            .context("delete expired logs")?;
+            purged.health_observations = delete_in_batches(&self.db, || {
+                sqlx::query!(
+                    "DELETE FROM release_health_observations WHERE id IN (
+                         SELECT id FROM release_health_observations
+                         WHERE organisation = $1 AND updated_at < $2
+                         LIMIT $3
+                     )",
+                    plan.organisation,
+                    cutoff,
+                    DELETE_BATCH,
+                )
+            })
+            .await
+            .context("delete expired health observations")?;
+        }
+
+        if let Some(cutoff) = plan.notification_cutoff {
+            purged.notifications = delete_in_batches(&self.db, || {
+                sqlx::query!(
+                    "DELETE FROM notifications WHERE id IN (
+                         SELECT id FROM notifications
+                         WHERE organisation = $1 AND created_at < $2
+                         LIMIT $3
+                     )",
+                    plan.organisation,
+                    cutoff,
+                    DELETE_BATCH,
+                )
+            })
+            .await
+            .context("delete expired notifications")?;
+        }
+
+        Ok(purged)
+    }
+
+    /// Delete the files of an artifact upload from object storage, then
+    /// their rows. Returns the number of files.
+    async fn delete_artifact_files(&self, staging_id: &Uuid) -> anyhow::Result<i64> {
+        let files = sqlx::query!(
+            "SELECT env, destination, file_name, file_content
+             FROM artifact_files
+             WHERE artifact_staging_id = $1",
+            staging_id,
+        )
+        .fetch_all(&self.db)
+        .await
+        .context("list artifact files")?;
+
+        for f in &files {
+            let key = crate::object_store::keys::artifact_file(
+                &staging_id.to_string(),
+                &f.env,
+                &f.destination,
+                &f.file_name,
+            );
+            self.object_store.delete(&key).await?;
+        }
+
+        let blobs: Vec<Uuid> = files.iter().map(|f| f.file_content).collect();
+        let mut tx = self.db.begin().await?;
+        sqlx::query!(
+            "DELETE FROM artifact_files WHERE artifact_staging_id = $1",
+            staging_id,
+        )
+        .execute(&mut *tx)
+        .await
+        .context("delete artifact file rows")?;
+        sqlx::query!("DELETE FROM blob_storage WHERE id = ANY($1)", &blobs)
+            .execute(&mut *tx)
+            .await
+            .context("delete artifact blobs")?;
+        tx.commit().await?;
+
+        Ok(files.len() as i64)
+    }
+
+    /// Remove uploads that were started but never committed: artifact
+    /// staging entries without an artifact, and component versions still
+    /// staged. Only uploads older than `older_than` are touched.
+    pub async fn purge_abandoned_staging(
+        &self,
+        older_than: chrono::Duration,
+    ) -> anyhow::Result<StagingPurge> {
+        let cutoff = chrono::Utc::now() - older_than;
+        let mut purged = StagingPurge::default();
+
+        let abandoned = sqlx::query!(
+            "SELECT s.id, s.artifact_id
+             FROM artifact_staging s
+             WHERE s.created < $1
+               AND NOT EXISTS (SELECT 1 FROM artifacts ar WHERE ar.artifact_id = s.artifact_id)
+             LIMIT $2",
+            cutoff,
+            DELETE_BATCH,
+        )
+        .fetch_all(&self.db)
+        .await
+        .context("list abandoned artifact uploads")?;
+        for upload in abandoned {
+            if let Err(e) = self.delete_artifact_files(&upload.artifact_id).await {
+                tracing::warn!(staging_id = %upload.artifact_id, "failed to purge abandoned upload: {e:#}");
+                continue;
+            }
+            sqlx::query!("DELETE FROM artifact_staging WHERE id = $1", upload.id)
+                .execute(&self.db)
+                .await
+                .context("delete artifact staging entry")?;
+            purged.artifact_uploads += 1;
+        }
+
+        // A staged component version shares its object keys with the
+        // published version of the same name, so only versions that were
+        // never published are cleaned up.
+        let abandoned = sqlx::query!(
+            "SELECT cs.id, cs.organisation, cs.name, cs.version
+             FROM component_staging cs
+             WHERE cs.status = 'staged' AND cs.updated < $1
+               AND NOT EXISTS (
+                   SELECT 1 FROM components c
+                   WHERE c.organisation = cs.organisation
+                     AND c.name = cs.name
+                     AND c.version = cs.version
+               )
+             LIMIT $2",
+            cutoff,
+            DELETE_BATCH,
+        )
+        .fetch_all(&self.db)
+        .await
+        .context("list abandoned component uploads")?;
+        for upload in abandoned {
+            if let Err(e) = self
+                .delete_component_upload(&upload.id, &upload.organisation, &upload.name, &upload.version)
+                .await
+            {
+                tracing::warn!(upload_id = %upload.id, "failed to purge abandoned component upload: {e:#}");
+                continue;
+            }
+            purged.component_uploads += 1;
+        }
+
+        Ok(purged)
+    }
+
+    async fn delete_component_upload(
+        &self,
+        upload_id: &Uuid,
+        organisation: &str,
+        name: &str,
+        version: &str,
+    ) -> anyhow::Result<()> {
+        // Abandon first, so the upload can't be committed while its
+        // objects are deleted.
+        let abandoned = sqlx::query!(
+            "UPDATE component_staging SET status = 'abandoned', updated = now()
+             WHERE id = $1 AND status = 'staged'",
+            upload_id,
+        )
+        .execute(&self.db)
+        .await
+        .context("abandon component upload")?;
+        if abandoned.rows_affected() == 0 {
+            return Ok(());
+        }
+
+        let binaries = sqlx::query_scalar!(
+            "SELECT storage_path FROM component_artifacts WHERE component_id = $1",
+            upload_id,
+        )
+        .fetch_all(&self.db)
+        .await
+        .context("list component binaries")?;
+        for key in binaries.into_iter().flatten() {
+            self.object_store.delete(&key).await?;
+        }
+
+        let files = sqlx::query_scalar!(
+            "SELECT file_path FROM component_files WHERE component_id = $1",
+            upload_id,
+        )
+        .fetch_all(&self.db)
+        .await
+        .context("list component files")?;
+        for file_path in &files {
+            let key = crate::object_store::keys::component_file(organisation, name, version, file_path);
+            self.object_store.delete(&key).await?;
+        }
+
+        let mut tx = self.db.begin().await?;
+        sqlx::query!("DELETE FROM component_artifacts WHERE component_id = $1", upload_id)
+            .execute(&mut *tx)
+            .await
+            .context("delete component binary rows")?;
+        sqlx::query!("DELETE FROM component_files WHERE component_id = $1", upload_id)
+            .execute(&mut *tx)
+            .await
+            .context("delete component file rows")?;
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
+
+/// Run a `DELETE ... LIMIT` until it deletes less than a full batch.
+async fn delete_in_batches<'q, F>(db: &PgPool, query: F) -> anyhow::Result<i64>
+where
+    F: Fn() -> sqlx::query::Query<'q, sqlx::Postgres, sqlx::postgres::PgArguments>,
+{
+    let mut deleted = 0;
+    loop {
+        let res = query().execute(db).await?;
+        deleted += res.rows_affected() as i64;
+        if (res.rows_affected() as i64) < DELETE_BATCH {
+            return Ok(deleted);
+        }
+    }
+}
+
+/// Artifacts currently deployed to a destination, part of a release that
+/// hasn't finished, or released by an active schedule.
+async fn pinned_artifacts(
+    conn: &mut sqlx::PgConnection,
+    organisation: &str,
+) -> anyhow::Result<Vec<Uuid>> {
+    let pinned = sqlx::query_scalar!(
+        r#"SELECT artifact_id AS "artifact_id!" FROM (
+               SELECT DISTINCT ON (rs.project_id, rs.destination_id) rs.artifact_id
+               FROM release_states rs
+               JOIN projects p ON p.id = rs.project_id
+               WHERE p.organisation = $1
+                 AND rs.mode = 'deploy' AND rs.status = 'SUCCEEDED'
+               ORDER BY rs.project_id, rs.destination_id, rs.completed_at DESC NULLS LAST
+           ) deployed
+           UNION
+           SELECT rs.artifact_id
+           FROM release_states rs
+           JOIN projects p ON p.id = rs.project_id
+           WHERE p.organisation = $1
+             AND rs.status IN ('QUEUED', 'ASSIGNED', 'RUNNING')
+           UNION
+           SELECT ri.artifact
+           FROM release_intents ri
+           JOIN projects p ON p.id = ri.project_id
+           WHERE p.organisation = $1 AND ri.status = 'ACTIVE'
+           UNION
+           SELECT s.artifact_id
+           FROM release_schedules s
+           JOIN projects p ON p.id = s.project_id
+           WHERE p.organisation = $1 AND s.status = 'ACTIVE' AND s.artifact_id IS NOT NULL"#,
+        organisation,
+    )
+    .fetch_all(&mut *conn)
+    .await
+    .context("list pinned artifacts")?;
+
+    Ok(pinned)
+}
+
+pub trait RetentionRegistryState {
+    fn retention_registry(&self) -> RetentionRegistry;
+}
+
+impl RetentionRegistryState for State {
+    fn retention_registry(&self) -> RetentionRegistry {
+        RetentionRegistry {
+            db: self.db.clone(),
+            object_store: self.object_store.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(day: u32) -> chrono::DateTime<chrono::Utc> {
+        chrono::DateTime::parse_from_rfc3339(&format!("2026-10-{day:02}T12:00:00Z"))
+            .unwrap()
+            .with_timezone(&chrono::Utc)
+    }
+
+    fn artifact(project: Uuid, day: u32, releases: &[(&str, u32)]) -> ArtifactUsage {
+        ArtifactUsage {
+            id: Uuid::now_v7(),
+            project_id: project,
+            created: at(day),
+            releases: releases.iter().map(|(env, d)| (env.to_string(), at(*d))).collect(),
+            pinned: false,
+        }
+    }
+
+    #[test]
+    fn keeps_newest_per_project() {
+        let project = Uuid::now_v7();
+        let other = Uuid::now_v7();
+        let artifacts = vec![
+            artifact(project, 1, &[]),
+            artifact(project, 2, &[]),
+            artifact(project, 3, &[]),
+            artifact(other, 1, &[]),
+        ];
+        assert_eq!(select_expired(&artifacts, 2), vec![artifacts[0].id]);
+    }
+
+    #[test]
+    fn keeps_most_recently_released_per_environment() {
+        let project = Uuid::now_v7();
+        let artifacts = vec![
+            // Still the latest in prod, while newer builds only reached dev.
+            artifact(project, 1, &[("dev", 1), ("prod", 5)]),
+            artifact(project, 2, &[("dev", 2)]),
+            artifact(project, 3, &[("dev", 3)]),
+            artifact(project, 4, &[("dev", 4)]),
+        ];
+        assert_eq!(select_expired(&artifacts, 1), vec![artifacts[1].id, artifacts[2].id]);
+    }
+
+    #[test]
+    fn pinned_artifacts_are_kept() {
+        let project = Uuid::now_v7();
+        let mut artifacts = vec![artifact(project, 1, &[]), artifact(project, 2, &[])];
+        artifacts[0].pinned = true;
+        assert!(select_expired(&artifacts, 1).is_empty());
+    }
+
+    #[test]
+    fn policy_values_must_be_positive() {
+        let policy = RetentionPolicy {
+            keep_artifacts: Some(0),
+            ..Default::default()
+        };
+        assert!(policy.validate().is_err());
+        let policy = RetentionPolicy {
+            keep_artifacts: Some(10),
+            log_retention_days: Some(30),
+            ..Default::default()
+        };
+        assert!(policy.validate().is_ok());
+    }
+}
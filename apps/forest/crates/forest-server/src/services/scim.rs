@@ -0,0 +1,936 @@
+//! SCIM provisioning: maps an identity provider's Users and Groups onto
+//! organisation membership.
+//!
+//! - A SCIM user is a forest user the IdP manages in one organisation.
+//!   Creating one finds the forest account by email (or creates it) and
+//!   makes it a member; `active = false` removes the membership but keeps
+//!   the SCIM record so the IdP can reactivate it.
+//! - An existing account is only taken over when it is already a member,
+//!   or its email is verified and on a domain the organisation has proven
+//!   over DNS. Otherwise it gets a pending invite, and becomes a member
+//!   once its owner accepts.
+//! - Groups exist to carry roles. When the organisation has group → role
+//!   mappings, each active SCIM user is `admin` if any of their groups
+//!   maps to admin and `member` otherwise. Without mappings, roles set by
+//!   hand are left alone.
+//!
+//! Every change is recorded as an org event in the same transaction.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use sha2::Digest;
+use uuid::Uuid;
+
+use crate::{
+    State,
+    repositories::error::DbError,
+    repositories::organisations::{OrganisationRepository, OrganisationRepositoryState},
+    repositories::scim::{
+        ScimGroupMemberRow, ScimGroupRoleRow, ScimGroupRow, ScimInviteRow, ScimRepository,
+        ScimRepositoryState, ScimUserRow,
+    },
+    repositories::users::{UserRepository, UserRepositoryState},
+    services::domain_proofs,
+    services::event_bus::{EventBus, EventBusState, EventPayload},
+    services::organisations::validate_role,
+    services::registration_policy::{RegistrationPolicy, RegistrationPolicyState},
+};
+
+/// App permission that allows an app's tokens to call the SCIM API.
+pub const SCIM_PERMISSION: &str = "scim";
+
+pub struct ScimService {
+    repo: ScimRepository,
+    organisations: OrganisationRepository,
+    users: UserRepository,
+    registration_policy: RegistrationPolicy,
+    events: EventBus,
+}
+
+/// The organisation a SCIM change applies to, and who made it.
+#[derive(Debug, Clone)]
+pub struct ScimCaller {
+    pub organisation_id: Uuid,
+    pub organisation: String,
+    pub changed_by: ScimChangedBy,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum ScimChangedBy {
+    /// The IdP, through a token of this app.
+    App(Uuid),
+    /// A signed-in user: an org admin editing group → role mappings, or
+    /// the owner of an account accepting an invite.
+    User(Uuid),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ScimError {
+    #[error("resource not found")]
+    NotFound,
+    #[error("{0}")]
+    Conflict(String),
+    #[error("{0}")]
+    InvalidValue(String),
+    /// The change would break an invariant of the organisation, such as
+    /// leaving it without an owner.
+    #[error("{0}")]
+    Mutability(String),
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+impl From<DbError> for ScimError {
+    fn from(err: DbError) -> Self {
+        match err {
+            DbError::AlreadyExists(msg) => ScimError::Conflict(msg),
+            other => ScimError::Other(other.into()),
+        }
+    }
+}
+
+impl From<sqlx::Error> for ScimError {
+    fn from(err: sqlx::Error) -> Self {
+        ScimError::Other(err.into())
+    }
+}
+
+pub struct ScimUserInput {
+    pub user_name: String,
+    pub external_id: Option<String>,
+    pub display_name: Option<String>,
+    pub email: Option<String>,
+    pub active: bool,
+}
+
+pub struct ScimGroupInput {
+    pub display_name: String,
+    pub external_id: Option<String>,
+    pub members: Vec<Uuid>,
+}
+
+pub struct ScimGroup {
+    pub group: ScimGroupRow,
+    pub members: Vec<ScimGroupMemberRow>,
+}
+
+pub struct ScimPage<T> {
+    pub resources: Vec<T>,
+    pub total_results: i64,
+}
+
+type Tx = sqlx::Transaction<'static, sqlx::Postgres>;
+
+impl ScimService {
+    fn db(&self) -> &sqlx::PgPool {
+        self.repo.pool()
+    }
+
+    /// Resolve a bearer token to the organisation it provisions.
+    pub async fn authenticate(&self, raw_token: &str) -> anyhow::Result<Option<ScimCaller>> {
+        let token_hash = sha2::Sha256::digest(raw_token.as_bytes()).to_vec();
+        let Some(row) = self.repo.resolve_token(self.db(), &token_hash).await? else {
+            return Ok(None);
+        };
+        self.repo.touch_token(self.db(), &token_hash).await.ok();
+
+        Ok(Some(ScimCaller {
+            organisation_id: row.organisation_id,
+            organisation: row.organisation,
+            changed_by: ScimChangedBy::App(row.app_id),
+        }))
+    }
+
+    // -- Users ----------------------------------------------------------------
+
+    pub async fn create_user(
+        &self,
+        caller: &ScimCaller,
+        input: ScimUserInput,
+    ) -> Result<ScimUserRow, ScimError> {
+        let user_name = required(&input.user_name, "userName")?;
+        let email = input
+            .email
+            .as_deref()
+            .map(str::trim)
+            .filter(|e| !e.is_empty())
+            .or_else(|| user_name.contains('@').then_some(user_name))
+            .ok_or_else(|| {
+                ScimError::InvalidValue("an email address (emails or userName) is required".into())
+            })?
+            .to_lowercase();
+
+        let mut tx = self.db().begin().await?;
+
+        if self
+            .repo
+            .count_users(&mut *tx, caller.organisation_id, Some(user_name))
+            .await?
+            > 0
+        {
+            return Err(ScimError::Conflict(format!(
+                "userName {user_name} is already provisioned"
+            )));
+        }
+
+        let (user_id, pending) = match self.users.get_user_by_email(&mut *tx, &email).await? {
+            Some(user) => (user.id, !self.may_link(&mut tx, caller, user.id, &email).await?),
+            None => {
+                self.registration_policy
+                    .check_email(&email)
+                    .map_err(|e| ScimError::InvalidValue(e.to_string()))?;
+                // Same placeholder as SSO sign-up; forage asks the user to
+                // pick a username on first login. The email stays
+                // unverified until they prove they own it.
+                let user_id = Uuid::now_v7();
+                let placeholder = format!("user-{}", user_id.simple());
+                self.users.create_user(&mut *tx, user_id, &placeholder).await?;
+                self.users
+                    .add_user_email_with_verified(&mut *tx, user_id, &email, false)
+                    .await?;
+                (user_id, false)
+            }
+        };
+
+        if self
+            .repo
+            .get_user(&mut *tx, caller.organisation_id, user_id)
+            .await?
+            .is_some()
+        {
+            return Err(ScimError::Conflict(format!(
+                "{email} is already provisioned under another userName"
+            )));
+        }
+
+        self.repo
+            .insert_user(
+                &mut *tx,
+                caller.organisation_id,
+                user_id,
+                user_name,
+                input.external_id.as_deref(),
+                input.display_name.as_deref(),
+                input.active,
+                pending,
+            )
+            .await?;
+
+        record(
+            &mut tx,
+            caller,
+            "member",
+            if pending { "invited" } else { "provisioned" },
+            user_id,
+            [
+                ("user_name", user_name.to_string()),
+                ("active", input.active.to_string()),
+            ],
+        )
+        .await?;
+
+        if input.active && !pending {
+            self.activate_member(&mut tx, caller, user_id).await?;
+        }
+
+        let user = self.load_user(&mut tx, caller, user_id).await?;
+        tx.commit().await?;
+        self.events.notify(&caller.organisation).await;
+
+        Ok(user)
+    }
+
+    pub async fn get_user(
+        &self,
+        caller: &ScimCaller,
+        user_id: Uuid,
+    ) -> Result<ScimUserRow, ScimError> {
+        self.repo
+            .get_user(self.db(), caller.organisation_id, user_id)
+            .await?
+            .ok_or(ScimError::NotFound)
+    }
+
+    pub async fn list_users(
+        &self,
+        caller: &ScimCaller,
+        user_name: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<ScimPage<ScimUserRow>, ScimError> {
+        let resources = self
+            .repo
+            .list_users(self.db(), caller.organisation_id, user_name, limit, offset)
+            .await?;
+        let total_results = self
+            .repo
+            .count_users(self.db(), caller.organisation_id, user_name)
+            .await?;
+
+        Ok(ScimPage {
+            resources,
+            total_results,
+        })
+    }
+
+    /// Replace a user's SCIM attributes (PUT, and PATCH once applied).
+    /// The email is ignored: it only picks the forest account on create.
+    pub async fn replace_user(
+        &self,
+        caller: &ScimCaller,
+        user_id: Uuid,
+        input: ScimUserInput,
+    ) -> Result<ScimUserRow, ScimError> {
+        let user_name = required(&input.user_name, "userName")?;
+        let mut tx = self.db().begin().await?;
+
+        let existing = self
+            .repo
+            .get_user(&mut *tx, caller.organisation_id, user_id)
+            .await?
+            .ok_or(ScimError::NotFound)?;
+
+        self.repo
+            .update_user(
+                &mut *tx,
+                caller.organisation_id,
+                user_id,
+                user_name,
+                input.external_id.as_deref(),
+                input.display_name.as_deref(),
+                input.active,
+            )
+            .await?;
+
+        match (existing.active, input.active) {
+            // A pending invite holds no membership to change; accepting it
+            // goes by whatever `active` is then.
+            _ if existing.pending => {}
+            (true, false) => {
+                self.remove_member(&mut tx, caller, user_id).await?;
+                record(&mut tx, caller, "member", "deactivated", user_id, [
+                    ("user_name", user_name.to_string()),
+                ])
+                .await?;
+            }
+            (false, true) => {
+                record(&mut tx, caller, "member", "reactivated", user_id, [
+                    ("user_name", user_name.to_string()),
+                ])
+                .await?;
+                self.activate_member(&mut tx, caller, user_id).await?;
+            }
+            _ if existing.user_name != user_name
+                || existing.external_id != input.external_id
+                || existing.display_name != input.display_name =>
+            {
+                record(&mut tx, caller, "member", "updated", user_id, [
+                    ("user_name", user_name.to_string()),
+                ])
+                .await?;
+            }
+            _ => {}
+        }
+
+        let user = self.load_user(&mut tx, caller, user_id).await?;
+        tx.commit().await?;
+        self.events.notify(&caller.organisation).await;
+
+        Ok(user)
+    }
+
+    /// Remove a user from the organisation entirely: membership, groups and
+    /// the SCIM record. The forest account itself is kept.
+    pub async fn delete_user(&self, caller: &ScimCaller, user_id: Uuid) -> Result<(), ScimError> {
+        let mut tx = self.db().begin().await?;
+
+        let existing = self
+            .repo
+            .get_user(&mut *tx, caller.organisation_id, user_id)
+            .await?
+            .ok_or(ScimError::NotFound)?;
+
+        self.repo
+            .remove_user_from_groups(&mut *tx, caller.organisation_id, user_id)
+            .await?;
+        self.repo
+            .delete_user(&mut *tx, caller.organisation_id, user_id)
+            .await?;
+        self.remove_member(&mut tx, caller, user_id).await?;
+
+        record(&mut tx, caller, "member", "deprovisioned", user_id, [
+            ("user_name", existing.user_name),
+        ])
+        .await?;
+
+        tx.commit().await?;
+        self.events.notify(&caller.organisation).await;
+
+        Ok(())
+    }
+
+    // -- Groups ---------------------------------------------------------------
+
+    pub async fn create_group(
+        &self,
+        caller: &ScimCaller,
+        input: ScimGroupInput,
+    ) -> Result<ScimGroup, ScimError> {
+        let display_name = required(&input.display_name, "displayName")?;
+        let mut tx = self.db().begin().await?;
+
+        let group = self
+            .repo
+            .insert_group(
+                &mut *tx,
+                Uuid::now_v7(),
+                caller.organisation_id,
+                display_name,
+                input.external_id.as_deref(),
+            )
+            .await?;
+
+        let members: BTreeSet<Uuid> = input.members.into_iter().collect();
+        for &user_id in &members {
+            self.add_to_group(&mut tx, caller, group.id, user_id).await?;
+        }
+
+        record(&mut tx, caller, "scim_group", "created", group.id, [
+            ("display_name", display_name.to_string()),
+            ("members", members.len().to_string()),
+        ])
+        .await?;
+
+        for &user_id in &members {
+            self.sync_role(&mut tx, caller, user_id).await?;
+        }
+
+        let group = self.load_group(&mut tx, caller, group.id).await?;
+        tx.commit().await?;
+        self.events.notify(&caller.organisation).await;
+
+        Ok(group)
+    }
+
+    pub async fn get_group(&self, caller: &ScimCaller, id: Uuid) -> Result<ScimGroup, ScimError> {
+        let group = self
+            .repo
+            .get_group(self.db(), caller.organisation_id, id)
+            .await?
+            .ok_or(ScimError::NotFound)?;
+        let members = self.repo.list_group_members(self.db(), group.id).await?;
+
+        Ok(ScimGroup { group, members })
+    }
+
+    pub async fn list_groups(
+        &self,
+        caller: &ScimCaller,
+        display_name: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<ScimPage<ScimGroup>, ScimError> {
+        let groups = self
+            .repo
+            .list_groups(self.db(), caller.organisation_id, display_name, limit, offset)
+            .await?;
+        let total_results = self
+            .repo
+            .count_groups(self.db(), caller.organisation_id, display_name)
+            .await?;
+
+        let mut resources = Vec::with_capacity(groups.len());
+        for group in groups {
+            let members = self.repo.list_group_members(self.db(), group.id).await?;
+            resources.push(ScimGroup { group, members });
+        }
+
+        Ok(ScimPage {
+            resources,
+            total_results,
+        })
+    }
+
+    /// Replace a group's name and member list (PUT, and PATCH once
+    /// applied), then bring the roles of everyone affected up to date.
+    pub async fn replace_group(
+        &self,
+        caller: &ScimCaller,
+        id: Uuid,
+        input: ScimGroupInput,
+    ) -> Result<ScimGroup, ScimError> {
+        let display_name = required(&input.display_name, "displayName")?;
+        let mut tx = self.db().begin().await?;
+
+        let existing = self
+            .repo
+            .get_group(&mut *tx, caller.organisation_id, id)
+            .await?
+            .ok_or(ScimError::NotFound)?;
+        let before: BTreeSet<Uuid> = self
+            .repo
+            .list_group_members(&mut *tx, id)
+            .await?
+            .into_iter()
+            .map(|m| m.user_id)
+            .collect();
+        let after: BTreeSet<Uuid> = input.members.into_iter().collect();
+
+        self.repo
+            .update_group(
+                &mut *tx,
+                caller.organisation_id,
+                id,
+                display_name,
+                input.external_id.as_deref(),
+            )
+            .await?;
+
+        let added: Vec<Uuid> = after.difference(&before).copied().collect();
+        let removed: Vec<Uuid> = before.difference(&after).copied().collect();
+        for &user_id in &added {
+            self.add_to_group(&mut tx, caller, id, user_id).await?;
+        }
+        for &user_id in &removed {
+            self.repo.remove_group_member(&mut *tx, id, user_id).await?;
+        }
+
+        let renamed = !existing.display_name.eq_ignore_ascii_case(display_name);
+        if renamed || !added.is_empty() || !removed.is_empty() {
+            record(&mut tx, caller, "scim_group", "updated", id, [
+                ("display_name", display_name.to_string()),
+                ("members_added", added.len().to_string()),
+                ("members_removed", removed.len().to_string()),
+            ])
+            .await?;
+        }
+
+        // A rename can move the group in or out of a role mapping, so
+        // every member is affected; otherwise only the changed ones.
+        let affected: BTreeSet<Uuid> = if renamed {
+            before.union(&after).copied().collect()
+        } else {
+            added.iter().chain(&removed).copied().collect()
+        };
+        for user_id in affected {
+            self.sync_role(&mut tx, caller, user_id).await?;
+        }
+
+        let group = self.load_group(&mut tx, caller, id).await?;
+        tx.commit().await?;
+        self.events.notify(&caller.organisation).await;
+
+        Ok(group)
+    }
+
+    pub async fn delete_group(&self, caller: &ScimCaller, id: Uuid) -> Result<(), ScimError> {
+        let mut tx = self.db().begin().await?;
+
+        let existing = self
+            .repo
+            .get_group(&mut *tx, caller.organisation_id, id)
+            .await?
+            .ok_or(ScimError::NotFound)?;
+        let members = self.repo.list_group_members(&mut *tx, id).await?;
+
+        self.repo
+            .delete_group(&mut *tx, caller.organisation_id, id)
+            .await?;
+        record(&mut tx, caller, "scim_group", "deleted", id, [
+            ("display_name", existing.display_name),
+        ])
+        .await?;
+
+        for member in members {
+            self.sync_role(&mut tx, caller, member.user_id).await?;
+        }
+
+        tx.commit().await?;
+        self.events.notify(&caller.organisation).await;
+
+        Ok(())
+    }
+
+    // -- Invites --------------------------------------------------------------
+
+    pub async fn list_invites(&self, user_id: Uuid) -> anyhow::Result<Vec<ScimInviteRow>> {
+        self.repo.list_invites(self.db(), user_id).await
+    }
+
+    /// Accept an invite the organisation's IdP sent an existing account,
+    /// making the user a member if the IdP has them active. Returns false
+    /// if there was no pending invite.
+    pub async fn accept_invite(
+        &self,
+        organisation_id: Uuid,
+        user_id: Uuid,
+    ) -> anyhow::Result<bool> {
+        let caller = self.user_caller(organisation_id, user_id).await?;
+        let mut tx = self.db().begin().await?;
+        let Some(active) = self
+            .repo
+            .accept_invite(&mut *tx, organisation_id, user_id)
+            .await?
+        else {
+            return Ok(false);
+        };
+        record(&mut tx, &caller, "member", "invite_accepted", user_id, [
+            ("active", active.to_string()),
+        ])
+        .await?;
+        if active {
+            self.activate_member(&mut tx, &caller, user_id).await?;
+        }
+        tx.commit().await?;
+        self.events.notify(&caller.organisation).await;
+
+        Ok(true)
+    }
+
+    // -- Group → role mappings ------------------------------------------------
+
+    pub async fn list_group_roles(
+        &self,
+        organisation_id: Uuid,
+    ) -> anyhow::Result<Vec<ScimGroupRoleRow>> {
+        self.repo.list_group_roles(self.db(), organisation_id).await
+    }
+
+    /// Map an IdP group to an organisation role and re-derive the roles
+    /// of the organisation's SCIM users.
+    pub async fn set_group_role(
+        &self,
+        organisation_id: Uuid,
+        group_name: &str,
+        role: &str,
+        requester_id: Uuid,
+    ) -> anyhow::Result<()> {
+        let group_name = group_name.trim();
+        anyhow::ensure!(!group_name.is_empty(), "group name is required");
+        validate_role(role)?;
+
+        let caller = self.user_caller(organisation_id, requester_id).await?;
+        let mut tx = self.db().begin().await?;
+        self.repo
+            .set_group_role(&mut *tx, organisation_id, group_name, role)
+            .await?;
+        record(&mut tx, &caller, "scim_group_role", "set", organisation_id, [
+            ("group_name", group_name.to_string()),
+            ("role", role.to_string()),
+        ])
+        .await?;
+        self.resync_roles(&mut tx, &caller).await?;
+        tx.commit().await?;
+        self.events.notify(&caller.organisation).await;
+
+        Ok(())
+    }
+
+    /// Returns false if the group had no mapping.
+    pub async fn delete_group_role(
+        &self,
+        organisation_id: Uuid,
+        group_name: &str,
+        requester_id: Uuid,
+    ) -> anyhow::Result<bool> {
+        let group_name = group_name.trim();
+        let caller = self.user_caller(organisation_id, requester_id).await?;
+        let mut tx = self.db().begin().await?;
+        let deleted = self
+            .repo
+            .delete_group_role(&mut *tx, organisation_id, group_name)
+            .await?;
+        if deleted == 0 {
+            return Ok(false);
+        }
+        record(&mut tx, &caller, "scim_group_role", "deleted", organisation_id, [
+            ("group_name", group_name.to_string()),
+        ])
+        .await?;
+        self.resync_roles(&mut tx, &caller).await?;
+        tx.commit().await?;
+        self.events.notify(&caller.organisation).await;
+
+        Ok(true)
+    }
+
+    async fn user_caller(
+        &self,
+        organisation_id: Uuid,
+        requester_id: Uuid,
+    ) -> anyhow::Result<ScimCaller> {
+        let org = self
+            .organisations
+            .get_organisation(self.db(), organisation_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("organisation not found"))?;
+
+        Ok(ScimCaller {
+            organisation_id,
+            organisation: org.name,
+            changed_by: ScimChangedBy::User(requester_id),
+        })
+    }
+
+    /// Re-derive the role of every active SCIM user after the mappings
+    /// changed.
+    async fn resync_roles(&self, tx: &mut Tx, caller: &ScimCaller) -> Result<(), ScimError> {
+        for user_id in self
+            .repo
+            .list_active_user_ids(&mut **tx, caller.organisation_id)
+            .await?
+        {
+            self.sync_role(tx, caller, user_id).await?;
+        }
+        Ok(())
+    }
+
+    // -- Helpers --------------------------------------------------------------
+
+    /// Whether the IdP may take over an account forest already knows by
+    /// `email`: only when the account is already a member, or the email is
+    /// verified and on a domain the organisation has proven. Anything less
+    /// would let any organisation claim any account by its address.
+    async fn may_link(
+        &self,
+        tx: &mut Tx,
+        caller: &ScimCaller,
+        user_id: Uuid,
+        email: &str,
+    ) -> Result<bool, ScimError> {
+        if self
+            .organisations
+            .get_member(&mut **tx, caller.organisation_id, user_id)
+            .await?
+            .is_some()
+        {
+            return Ok(true);
+        }
+
+        let verified = self
+            .users
+            .get_user_emails(&mut **tx, user_id)
+            .await?
+            .iter()
+            .any(|e| e.verified && e.email.eq_ignore_ascii_case(email));
+        let Some((_, domain)) = email.rsplit_once('@').filter(|_| verified) else {
+            return Ok(false);
+        };
+
+        Ok(domain_proofs::is_proven(caller.organisation_id, domain).await?)
+    }
+
+    async fn load_user(
+        &self,
+        tx: &mut Tx,
+        caller: &ScimCaller,
+        user_id: Uuid,
+    ) -> Result<ScimUserRow, ScimError> {
+        self.repo
+            .get_user(&mut **tx, caller.organisation_id, user_id)
+            .await?
+            .ok_or(ScimError::NotFound)
+    }
+
+    async fn load_group(
+        &self,
+        tx: &mut Tx,
+        caller: &ScimCaller,
+        id: Uuid,
+    ) -> Result<ScimGroup, ScimError> {
+        let group = self
+            .repo
+            .get_group(&mut **tx, caller.organisation_id, id)
+            .await?
+            .ok_or(ScimError::NotFound)?;
+        let members = self.repo.list_group_members(&mut **tx, id).await?;
+
+        Ok(ScimGroup { group, members })
+    }
+
+    /// Groups may only contain users this organisation's IdP provisioned.
+    async fn add_to_group(
+        &self,
+        tx: &mut Tx,
+        caller: &ScimCaller,
+        group_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), ScimError> {
+        if self
+            .repo
+            .get_user(&mut **tx, caller.organisation_id, user_id)
+            .await?
+            .is_none()
+        {
+            return Err(ScimError::InvalidValue(format!(
+                "member {user_id} is not a provisioned user"
+            )));
+        }
+        self.repo.add_group_member(&mut **tx, group_id, user_id).await?;
+        Ok(())
+    }
+
+    /// Make an active SCIM user a member (if they aren't already) and
+    /// derive their role from their groups.
+    async fn activate_member(
+        &self,
+        tx: &mut Tx,
+        caller: &ScimCaller,
+        user_id: Uuid,
+    ) -> Result<(), ScimError> {
+        if self
+            .organisations
+            .get_member(&mut **tx, caller.organisation_id, user_id)
+            .await?
+            .is_none()
+        {
+            self.organisations
+                .add_member(&mut **tx, caller.organisation_id, user_id, "member")
+                .await?;
+        }
+        self.sync_role(tx, caller, user_id).await
+    }
+
+    /// Take a SCIM user's membership away, unless they are the
+    /// organisation's last owner.
+    async fn remove_member(
+        &self,
+        tx: &mut Tx,
+        caller: &ScimCaller,
+        user_id: Uuid,
+    ) -> Result<(), ScimError> {
+        if self
+            .organisations
+            .is_last_owner(&mut **tx, caller.organisation_id, user_id)
+            .await?
+        {
+            return Err(ScimError::Mutability(
+                "cannot deprovision the organisation's last owner; make someone else an owner first"
+                    .into(),
+            ));
+        }
+        self.organisations
+            .remove_member(&mut **tx, caller.organisation_id, user_id)
+            .await?;
+        Ok(())
+    }
+
+    /// Bring an active SCIM user's role in line with their mapped groups.
+    /// A no-op when the organisation maps no groups, for users who aren't
+    /// members (deactivated), for users the IdP doesn't manage, and for
+    /// owners: groups grant admin or member, never take ownership away.
+    async fn sync_role(
+        &self,
+        tx: &mut Tx,
+        caller: &ScimCaller,
+        user_id: Uuid,
+    ) -> Result<(), ScimError> {
+        if self
+            .repo
+            .list_group_roles(&mut **tx, caller.organisation_id)
+            .await?
+            .is_empty()
+        {
+            return Ok(());
+        }
+        let Some(scim_user) = self
+            .repo
+            .get_user(&mut **tx, caller.organisation_id, user_id)
+            .await?
+        else {
+            return Ok(());
+        };
+        let Some(member) = self
+            .organisations
+            .get_member(&mut **tx, caller.organisation_id, user_id)
+            .await?
+        else {
+            return Ok(());
+        };
+        if member.role == "owner" {
+            return Ok(());
+        }
+
+        let roles = self
+            .repo
+            .mapped_roles_for_user(&mut **tx, caller.organisation_id, user_id)
+            .await?;
+        let role = if roles.iter().any(|r| r == "admin") {
+            "admin"
+        } else {
+            "member"
+        };
+        if member.role == role {
+            return Ok(());
+        }
+
+        self.organisations
+            .update_member_role(&mut **tx, caller.organisation_id, user_id, role)
+            .await?;
+        record(tx, caller, "member", "role_changed", user_id, [
+            ("user_name", scim_user.user_name),
+            ("previous_role", member.role),
+            ("role", role.to_string()),
+        ])
+        .await?;
+
+        Ok(())
+    }
+}
+
+async fn record<const N: usize>(
+    tx: &mut Tx,
+    caller: &ScimCaller,
+    resource_type: &'static str,
+    action: &'static str,
+    resource_id: Uuid,
+    metadata: [(&str, String); N],
+) -> Result<(), ScimError> {
+    let mut metadata: BTreeMap<String, String> = metadata
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v))
+        .collect();
+    metadata.insert("source".into(), "scim".into());
+    match caller.changed_by {
+        ScimChangedBy::App(app_id) => metadata.insert("app_id".into(), app_id.to_string()),
+        ScimChangedBy::User(user_id) => metadata.insert("requested_by".into(), user_id.to_string()),
+    };
+
+    EventBus::record(tx, EventPayload {
+        organisation: caller.organisation.clone(),
+        project: String::new(),
+        resource_type,
+        action,
+        resource_id: resource_id.to_string(),
+        metadata,
+    })
+    .await?;
+
+    Ok(())
+}
+
+fn required<'a>(value: &'a str, attribute: &str) -> Result<&'a str, ScimError> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(ScimError::InvalidValue(format!("{attribute} is required")));
+    }
+    Ok(value)
+}
+
+// -- State trait --------------------------------------------------------------
+
+pub trait ScimServiceState {
+    fn scim_service(&self) -> ScimService;
+}
+
+impl ScimServiceState for State {
+    fn scim_service(&self) -> ScimService {
+        ScimService {
+            repo: self.scim_repository(),
+            organisations: self.organisation_repository(),
+            users: self.user_repository(),
+            registration_policy: self.registration_policy(),
+            events: self.event_bus(),
+        }
+    }
+}
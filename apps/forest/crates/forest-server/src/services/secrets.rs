@@ -0,0 +1,491 @@
+//! Encrypted secrets scoped to an organisation, optionally narrowed to a
+//! project and/or environment.
+//!
+//! Values are envelope encrypted: each secret gets its own data key, and the
+//! data key is sealed with the server's secrets key (`FOREST_SECRETS_KEY`).
+//! Both are bound to the secret's scope and name, so a ciphertext copied to
+//! another row fails to decrypt instead of leaking into another scope.
+//! Nothing outside this module sees plaintext until a release runs, when
+//! [`SecretRegistry::resolve`] hands the values in scope to the destination
+//! or runner.
+
+use std::collections::{BTreeMap, HashMap};
+
+use aes_gcm::{
+    AeadCore, Aes256Gcm, KeyInit, Nonce,
+    aead::{Aead, OsRng, Payload},
+};
+use anyhow::Context;
+use sha2::Digest;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::State;
+
+/// Destination metadata values of the form `secret://<name>` are replaced
+/// with the secret's value at release time.
+pub const SECRET_REFERENCE_PREFIX: &str = "secret://";
+
+/// Values shorter than this are not redacted from logs; masking every `1` or
+/// `ok` would make the logs useless while protecting nothing.
+const MIN_REDACTED_LEN: usize = 4;
+
+const REDACTED: &str = "***";
+
+#[derive(Clone)]
+struct SecretsKey {
+    id: String,
+    key: Vec<u8>,
+}
+
+#[derive(Clone)]
+pub struct SecretRegistry {
+    db: PgPool,
+    key: Option<SecretsKey>,
+}
+
+/// Where a secret applies. Empty `project`/`environment` means all of them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SecretScope {
+    pub organisation: String,
+    pub project: String,
+    pub environment: String,
+}
+
+impl SecretScope {
+    pub fn describe(&self) -> String {
+        match (self.project.is_empty(), self.environment.is_empty()) {
+            (true, true) => self.organisation.clone(),
+            (false, true) => format!("{}/{}", self.organisation, self.project),
+            (true, false) => format!("{} ({})", self.organisation, self.environment),
+            (false, false) => format!(
+                "{}/{} ({})",
+                self.organisation, self.project, self.environment
+            ),
+        }
+    }
+
+    /// Associated data binding a ciphertext to this scope and `name`.
+    /// None of the parts can contain a NUL, so the encoding is unambiguous.
+    fn associated_data(&self, name: &str) -> Vec<u8> {
+        [
+            "forest-secret-v1",
+            &self.organisation,
+            &self.project,
+            &self.environment,
+            name,
+        ]
+        .join("\0")
+        .into_bytes()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SecretRecord {
+    pub scope: SecretScope,
+    pub name: String,
+    pub version: i32,
+    pub key_id: String,
+    pub updated_by: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+pub fn validate_name(name: &str) -> anyhow::Result<()> {
+    let valid = !name.is_empty()
+        && name.len() <= 128
+        && name
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    if !valid {
+        anyhow::bail!(
+            "secret name '{name}' must start with a letter or underscore and contain only letters, digits, '_' and '-'"
+        );
+    }
+    Ok(())
+}
+
+fn seal(key: &[u8], plaintext: &[u8], aad: &[u8]) -> anyhow::Result<Vec<u8>> {
+    let cipher = Aes256Gcm::new_from_slice(key).context("key must be 32 bytes")?;
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, Payload { msg: plaintext, aad })
+        .map_err(|e| anyhow::anyhow!("encrypt secret: {e}"))?;
+
+    let mut output = nonce.to_vec();
+    output.extend_from_slice(&ciphertext);
+    Ok(output)
+}
+
+fn open(key: &[u8], sealed: &[u8], aad: &[u8]) -> anyhow::Result<Vec<u8>> {
+    let cipher = Aes256Gcm::new_from_slice(key).context("key must be 32 bytes")?;
+    // nonce is 12 bytes long for gcm
+    let (nonce, ciphertext) = sealed.split_at_checked(12).context("invalid ciphertext")?;
+    cipher
+        .decrypt(Nonce::from_slice(nonce), Payload { msg: ciphertext, aad })
+        .map_err(|e| anyhow::anyhow!("decrypt secret: {e}"))
+}
+
+impl SecretsKey {
+    fn new(key: Vec<u8>) -> Self {
+        let id = hex::encode(&sha2::Sha256::digest(&key)[..8]);
+        Self { id, key }
+    }
+
+    /// Returns `(encrypted_key, ciphertext)`, both bound to `aad`.
+    fn encrypt(&self, value: &str, aad: &[u8]) -> anyhow::Result<(Vec<u8>, Vec<u8>)> {
+        let data_key = Aes256Gcm::generate_key(&mut OsRng);
+        let ciphertext = seal(&data_key, value.as_bytes(), aad)?;
+        let encrypted_key = seal(&self.key, &data_key, aad)?;
+        Ok((encrypted_key, ciphertext))
+    }
+
+    fn decrypt(
+        &self,
+        key_id: &str,
+        encrypted_key: &[u8],
+        ciphertext: &[u8],
+        aad: &[u8],
+    ) -> anyhow::Result<String> {
+        if key_id != self.id {
+            anyhow::bail!("secret was encrypted with key {key_id}, but the server has key {}", self.id);
+        }
+        let data_key = open(&self.key, encrypted_key, aad).context("unwrap data key")?;
+        let value = open(&data_key, ciphertext, aad)?;
+        String::from_utf8(value).context("secret value is not utf-8")
+    }
+}
+
+impl SecretRegistry {
+    fn key(&self) -> anyhow::Result<&SecretsKey> {
+        self.key
+            .as_ref()
+            .context("secrets are not configured on this server (FOREST_SECRETS_KEY is unset)")
+    }
+
+    /// Create the secret, or rotate it to a new value if it exists.
+    pub async fn set(
+        &self,
+        scope: &SecretScope,
+        name: &str,
+        value: &str,
+        actor: &str,
+    ) -> anyhow::Result<SecretRecord> {
+        validate_name(name)?;
+        let key = self.key()?;
+        let (encrypted_key, ciphertext) = key.encrypt(value, &scope.associated_data(name))?;
+
+        let rec = sqlx::query!(
+            r#"INSERT INTO secrets
+                (id, organisation, project, environment, name, key_id,
+                 encrypted_key, ciphertext, created_by, updated_by)
+               VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $9)
+               ON CONFLICT (organisation, project, environment, name) DO UPDATE
+               SET version = secrets.version + 1,
+                   key_id = EXCLUDED.key_id,
+                   encrypted_key = EXCLUDED.encrypted_key,
+                   ciphertext = EXCLUDED.ciphertext,
+                   updated_by = EXCLUDED.updated_by,
+                   updated_at = now()
+               RETURNING version, key_id, updated_by, created_at, updated_at"#,
+            Uuid::now_v7(),
+            scope.organisation,
+            scope.project,
+            scope.environment,
+            name,
+            key.id,
+            encrypted_key,
+            ciphertext,
+            actor,
+        )
+        .fetch_one(&self.db)
+        .await
+        .context("store secret")?;
+
+        Ok(SecretRecord {
+            scope: scope.clone(),
+            name: name.to_string(),
+            version: rec.version,
+            key_id: rec.key_id,
+            updated_by: rec.updated_by,
+            created_at: rec.created_at,
+            updated_at: rec.updated_at,
+        })
+    }
+
+    /// Secrets in an organisation, optionally limited to an exact project
+    /// and/or environment scope. Never returns values.
+    pub async fn list(
+        &self,
+        organisation: &str,
+        project: Option<&str>,
+        environment: Option<&str>,
+    ) -> anyhow::Result<Vec<SecretRecord>> {
+        let rows = sqlx::query!(
+            r#"SELECT organisation, project, environment, name, version, key_id,
+                      updated_by, created_at, updated_at
+               FROM secrets
+               WHERE organisation = $1
+                 AND ($2::text IS NULL OR project = $2)
+                 AND ($3::text IS NULL OR environment = $3)
+               ORDER BY project, environment, name"#,
+            organisation,
+            project,
+            environment,
+        )
+        .fetch_all(&self.db)
+        .await
+        .context("list secrets")?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| SecretRecord {
+                scope: SecretScope {
+                    organisation: r.organisation,
+                    project: r.project,
+                    environment: r.environment,
+                },
+                name: r.name,
+                version: r.version,
+                key_id: r.key_id,
+                updated_by: r.updated_by,
+                created_at: r.created_at,
+                updated_at: r.updated_at,
+            })
+            .collect())
+    }
+
+    /// Returns false when there was no such secret.
+    pub async fn delete(&self, scope: &SecretScope, name: &str) -> anyhow::Result<bool> {
+        let res = sqlx::query!(
+            r#"DELETE FROM secrets
+               WHERE organisation = $1 AND project = $2 AND environment = $3 AND name = $4"#,
+            scope.organisation,
+            scope.project,
+            scope.environment,
+            name,
+        )
+        .execute(&self.db)
+        .await
+        .context("delete secret")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    /// Decrypt every secret that applies to a release of `project` into
+    /// `environment`. Where names collide the most specific scope wins:
+    /// project and environment, then project, then environment, then the
+    /// whole organisation.
+    pub async fn resolve(
+        &self,
+        organisation: &str,
+        project: &str,
+        environment: &str,
+    ) -> anyhow::Result<ReleaseSecrets> {
+        let rows = sqlx::query!(
+            r#"SELECT DISTINCT ON (name) project, environment, name, key_id, encrypted_key,
+                      ciphertext
+               FROM secrets
+               WHERE organisation = $1
+                 AND project IN ('', $2)
+                 AND environment IN ('', $3)
+               ORDER BY name, project DESC, environment DESC"#,
+            organisation,
+            project,
+            environment,
+        )
+        .fetch_all(&self.db)
+        .await
+        .context("load secrets")?;
+
+        if rows.is_empty() {
+            return Ok(ReleaseSecrets::default());
+        }
+
+        let key = self.key()?;
+        let mut values = BTreeMap::new();
+        for row in rows {
+            let scope = SecretScope {
+                organisation: organisation.to_string(),
+                project: row.project,
+                environment: row.environment,
+            };
+            let value = key
+                .decrypt(
+                    &row.key_id,
+                    &row.encrypted_key,
+                    &row.ciphertext,
+                    &scope.associated_data(&row.name),
+                )
+                .with_context(|| format!("decrypt secret '{}'", row.name))?;
+            values.insert(row.name, value);
+        }
+
+        Ok(ReleaseSecrets { values })
+    }
+}
+
+/// Plaintext secrets for a single release. Keep it for as short as possible.
+#[derive(Default, Clone)]
+pub struct ReleaseSecrets {
+    values: BTreeMap<String, String>,
+}
+
+impl ReleaseSecrets {
+    pub fn values(&self) -> &BTreeMap<String, String> {
+        &self.values
+    }
+
+    /// Replace `secret://<name>` metadata values with the secret's value.
+    /// A reference to a secret that isn't in scope fails the release rather
+    /// than handing the destination a literal `secret://…` string.
+    pub fn resolve_metadata(
+        &self,
+        metadata: &HashMap<String, String>,
+    ) -> anyhow::Result<HashMap<String, String>> {
+        metadata
+            .iter()
+            .map(|(key, value)| {
+                let Some(name) = value.strip_prefix(SECRET_REFERENCE_PREFIX) else {
+                    return Ok((key.clone(), value.clone()));
+                };
+                let secret = self.values.get(name).with_context(|| {
+                    format!("metadata '{key}' references secret '{name}', which is not set for this project and environment")
+                })?;
+                Ok((key.clone(), secret.clone()))
+            })
+            .collect()
+    }
+
+    /// Strings to mask in release logs. Logs arrive line by line, so each
+    /// line of a multi-line value is masked on its own as well.
+    pub fn redactions(&self) -> Vec<String> {
+        let mut redactions: Vec<String> = self
+            .values
+            .values()
+            .flat_map(|v| std::iter::once(v.as_str()).chain(v.lines()))
+            .map(str::trim)
+            .filter(|v| v.len() >= MIN_REDACTED_LEN)
+            .map(str::to_string)
+            .collect();
+        // Longest first, so a value containing another is masked whole.
+        redactions.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+        redactions.dedup();
+        redactions
+    }
+}
+
+/// Mask every occurrence of `redactions` in `line`.
+pub fn redact(line: &str, redactions: &[String]) -> String {
+    let mut line = line.to_string();
+    for secret in redactions {
+        if line.contains(secret.as_str()) {
+            line = line.replace(secret.as_str(), REDACTED);
+        }
+    }
+    line
+}
+
+pub trait SecretRegistryState {
+    fn secret_registry(&self) -> SecretRegistry;
+}
+
+impl SecretRegistryState for State {
+    fn secret_registry(&self) -> SecretRegistry {
+        SecretRegistry {
+            db: self.db.clone(),
+            key: self.config.secrets_key.clone().map(SecretsKey::new),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn secrets(values: &[(&str, &str)]) -> ReleaseSecrets {
+        ReleaseSecrets {
+            values: values
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        }
+    }
+
+    fn scope(project: &str, environment: &str) -> SecretScope {
+        SecretScope {
+            organisation: "acme".into(),
+            project: project.into(),
+            environment: environment.into(),
+        }
+    }
+
+    #[test]
+    fn envelope_round_trip() {
+        let key = SecretsKey::new(vec![7; 32]);
+        let aad = scope("", "").associated_data("TOKEN");
+        let value = "line one\nline two\n";
+        let (encrypted_key, ciphertext) = key.encrypt(value, &aad).unwrap();
+        assert_eq!(
+            key.decrypt(&key.id, &encrypted_key, &ciphertext, &aad).unwrap(),
+            value
+        );
+
+        let other = SecretsKey::new(vec![8; 32]);
+        assert!(other.decrypt(&key.id, &encrypted_key, &ciphertext, &aad).is_err());
+        assert!(other.decrypt(&other.id, &encrypted_key, &ciphertext, &aad).is_err());
+    }
+
+    #[test]
+    fn ciphertexts_are_bound_to_scope_and_name() {
+        let key = SecretsKey::new(vec![7; 32]);
+        let aad = scope("payments", "prod").associated_data("TOKEN");
+        let (encrypted_key, ciphertext) = key.encrypt("hunter22", &aad).unwrap();
+
+        for moved in [
+            scope("payments", "staging").associated_data("TOKEN"),
+            scope("checkout", "prod").associated_data("TOKEN"),
+            scope("payments", "prod").associated_data("OTHER"),
+            // Shifting a boundary must not produce the same encoding.
+            scope("payments\0prod", "").associated_data("TOKEN"),
+        ] {
+            assert!(key.decrypt(&key.id, &encrypted_key, &ciphertext, &moved).is_err());
+        }
+    }
+
+    #[test]
+    fn names_are_validated() {
+        assert!(validate_name("GIT_TOKEN").is_ok());
+        assert!(validate_name("db-password").is_ok());
+        assert!(validate_name("").is_err());
+        assert!(validate_name("1token").is_err());
+        assert!(validate_name("a b").is_err());
+    }
+
+    #[test]
+    fn metadata_references_are_resolved() {
+        let s = secrets(&[("git-token", "ghp_abc")]);
+        let metadata = HashMap::from([
+            ("git_token".to_string(), "secret://git-token".to_string()),
+            ("branch".to_string(), "main".to_string()),
+        ]);
+        let resolved = s.resolve_metadata(&metadata).unwrap();
+        assert_eq!(resolved["git_token"], "ghp_abc");
+        assert_eq!(resolved["branch"], "main");
+
+        let missing = HashMap::from([("x".to_string(), "secret://nope".to_string())]);
+        assert!(s.resolve_metadata(&missing).is_err());
+    }
+
+    #[test]
+    fn multi_line_values_are_redacted_per_line() {
+        let s = secrets(&[("key", "-----BEGIN KEY-----\nabcdefgh\n-----END KEY-----"), ("short", "ok")]);
+        let redactions = s.redactions();
+        assert_eq!(redact("token=abcdefgh", &redactions), "token=***");
+        assert_eq!(redact("status ok", &redactions), "status ok");
+    }
+}
@@ -3,11 +3,14 @@ use uuid::Uuid;
 use crate::{
     State,
     native_credentials::{NativeCredentials, NativeCredentialsState},
+    repositories::passkeys::{PasskeyRepository, PasskeyRepositoryState, PasskeyRow},
     repositories::users::{NativeMfaRow, UserRepository, UserRepositoryState},
+    webauthn::Passkey,
 };
 
 pub struct UserService {
     repo: UserRepository,
+    passkeys: PasskeyRepository,
     native_credentials: NativeCredentials,
 }
 
@@ -563,6 +566,19 @@ impl UserService {
         }))
     }
 
+    /// Look up an unexpired state without consuming it. Used when a
+    /// later step (e.g. finishing a passkey ceremony) does the consuming.
+    pub async fn peek_oauth_state(&self, state: &str) -> anyhow::Result<Option<OAuthStateInfo>> {
+        let row = self.repo.get_oauth_state_by_state(self.db(), state).await?;
+        Ok(row
+            .filter(|row| row.expires_at.is_none_or(|at| at >= chrono::Utc::now()))
+            .map(|row| OAuthStateInfo {
+                provider: row.provider,
+                redirect_uri: row.redirect_uri,
+                data: row.data,
+            }))
+    }
+
     // ── Personal access tokens ───────────────────────────────────────
 
     pub async fn create_personal_access_token(
@@ -693,6 +709,72 @@ impl UserService {
             .await
             .map_err(|e| anyhow::anyhow!(e))
     }
+
+    // ── Passkeys ─────────────────────────────────────────────────────
+
+    pub async fn add_passkey(
+        &self,
+        user_id: Uuid,
+        name: &str,
+        passkey: &Passkey,
+    ) -> anyhow::Result<PasskeyRow> {
+        let row = self
+            .passkeys
+            .create_passkey(
+                self.db(),
+                Uuid::now_v7(),
+                user_id,
+                name,
+                passkey.cred_id().as_ref(),
+                &serde_json::to_value(passkey)?,
+            )
+            .await?;
+        Ok(row)
+    }
+
+    pub async fn list_passkeys(&self, user_id: Uuid) -> anyhow::Result<Vec<PasskeyRow>> {
+        self.passkeys.list_passkeys(self.db(), user_id).await
+    }
+
+    pub async fn find_passkey(&self, credential_id: &[u8]) -> anyhow::Result<Option<PasskeyRow>> {
+        self.passkeys
+            .get_passkey_by_credential_id(self.db(), credential_id)
+            .await
+    }
+
+    /// Rename a passkey only if it belongs to `user_id`. Returns the
+    /// number of rows updated.
+    pub async fn rename_passkey(
+        &self,
+        passkey_id: Uuid,
+        user_id: Uuid,
+        name: &str,
+    ) -> anyhow::Result<u64> {
+        let n = self
+            .passkeys
+            .rename_passkey(self.db(), passkey_id, user_id, name)
+            .await?;
+        Ok(n)
+    }
+
+    /// Delete a passkey only if it belongs to `user_id`. Returns the
+    /// number of rows deleted.
+    pub async fn delete_passkey(&self, passkey_id: Uuid, user_id: Uuid) -> anyhow::Result<u64> {
+        let n = self
+            .passkeys
+            .delete_passkey(self.db(), passkey_id, user_id)
+            .await?;
+        Ok(n)
+    }
+
+    /// Record a successful assertion, storing the passkey as updated by it
+    /// (signature counter, backup state).
+    pub async fn touch_passkey(&self, passkey_id: Uuid, passkey: &Passkey) -> anyhow::Result<()> {
+        self.passkeys
+            .touch_passkey(self.db(), passkey_id, &serde_json::to_value(passkey)?)
+            .await?;
+        Ok(())
+    }
 }
 
 // ─── Return types ────────────────────────────────────────────────────
@@ -792,6 +874,7 @@ impl UserServiceState for State {
     fn user_service(&self) -> UserService {
         UserService {
             repo: self.user_repository(),
+            passkeys: self.passkey_repository(),
             native_credentials: self.native_credentials(),
         }
     }
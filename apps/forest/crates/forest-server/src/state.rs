@@ -95,6 +95,11 @@ pub struct Config {
     /// `failed_precondition` — i.e. the operator must opt in before
     /// the web-login flow becomes usable.
     pub web_app_url: Option<String>,
+
+    /// 32-byte key that seals the data keys of stored secrets. Set via
+    /// `FOREST_SECRETS_KEY` (base64). When `None`, secrets can be listed
+    /// and deleted but not set or used in releases.
+    pub secrets_key: Option<Vec<u8>>,
 }
 
 /// Pure validator for inter-field invariants on `Config`.
@@ -165,6 +170,7 @@ mod tests {
             registration_email_domain_regex: None,
             require_email_verification: false,
             web_app_url: None,
+            secrets_key: None,
         }
     }
 
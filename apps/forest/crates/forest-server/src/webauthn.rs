@@ -0,0 +1,113 @@
+//! WebAuthn (passkey) ceremonies, verified by webauthn-rs.
+//!
+//! Options and credentials travel as the JSON serialisation from the
+//! WebAuthn spec, with binary fields base64url-encoded, so forage only
+//! relays opaque strings. The state webauthn-rs needs to finish a
+//! ceremony stays on the server, in the ceremony's oauth state.
+//!
+//! Every passkey ceremony requires user verification (PIN, biometric),
+//! whether the passkey is a second factor or the whole login.
+
+use std::time::Duration;
+
+pub use webauthn_rs::prelude::{
+    DiscoverableAuthentication, DiscoverableKey, Passkey, PasskeyAuthentication,
+    PasskeyRegistration, PublicKeyCredential, RegisterPublicKeyCredential, Webauthn,
+    WebauthnError,
+};
+use webauthn_rs::prelude::{Url, WebauthnBuilder};
+
+/// How long a ceremony stays valid, in seconds. Also passed to the
+/// browser as the ceremony timeout.
+pub const CEREMONY_TIMEOUT_SECS: i64 = 300;
+
+/// The site passkeys are bound to. Derived from the forage URL: the
+/// browser scopes credentials to the host (`id`) and reports the page's
+/// origin in every ceremony.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelyingParty {
+    pub id: String,
+    pub name: String,
+    pub origin: String,
+}
+
+impl RelyingParty {
+    /// Build from `FOREST_WEB_APP_URL`, e.g. `https://forage.example.com`.
+    pub fn from_web_app_url(url: &str) -> Option<Self> {
+        let (scheme, rest) = url.trim().split_once("://")?;
+        if scheme != "https" && scheme != "http" {
+            return None;
+        }
+        let authority = rest.split(['/', '?', '#']).next()?;
+        let host = match authority.rsplit_once(':') {
+            Some((host, port)) if port.chars().all(|c| c.is_ascii_digit()) => host,
+            _ => authority,
+        };
+        if host.is_empty() {
+            return None;
+        }
+        Some(Self {
+            id: host.to_ascii_lowercase(),
+            name: "Forage".into(),
+            origin: format!("{scheme}://{}", authority.to_ascii_lowercase()),
+        })
+    }
+
+    /// The verifier for ceremonies on this site.
+    pub fn webauthn(&self) -> Result<Webauthn, WebauthnError> {
+        let origin = Url::parse(&self.origin).map_err(|_| WebauthnError::Configuration)?;
+        WebauthnBuilder::new(&self.id, &origin)?
+            .rp_name(&self.name)
+            .timeout(Duration::from_secs(CEREMONY_TIMEOUT_SECS as u64))
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine;
+    use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn rp() -> RelyingParty {
+        RelyingParty::from_web_app_url("https://forage.example.com").unwrap()
+    }
+
+    #[test]
+    fn relying_party_from_web_app_url() {
+        assert_eq!(
+            RelyingParty::from_web_app_url("https://Forage.example.com/"),
+            Some(RelyingParty {
+                id: "forage.example.com".into(),
+                name: "Forage".into(),
+                origin: "https://forage.example.com".into(),
+            })
+        );
+        let local = RelyingParty::from_web_app_url("http://localhost:3000").unwrap();
+        assert_eq!(local.id, "localhost");
+        assert_eq!(local.origin, "http://localhost:3000");
+        assert!(RelyingParty::from_web_app_url("forage.example.com").is_none());
+        assert!(RelyingParty::from_web_app_url("ftp://forage.example.com").is_none());
+    }
+
+    #[test]
+    fn options_carry_relying_party_and_user_handle() {
+        let webauthn = rp().webauthn().unwrap();
+        let user_id = Uuid::now_v7();
+
+        let (create, _) = webauthn
+            .start_passkey_registration(user_id, "alice", "alice", None)
+            .unwrap();
+        let create = serde_json::to_value(&create.public_key).unwrap();
+        assert_eq!(create["rp"]["id"], "forage.example.com");
+        assert_eq!(create["user"]["id"], URL_SAFE_NO_PAD.encode(user_id.as_bytes()));
+
+        let (get, _) = webauthn.start_discoverable_authentication().unwrap();
+        let get = serde_json::to_value(&get.public_key).unwrap();
+        assert_eq!(get["rpId"], "forage.example.com");
+        assert_eq!(get["allowCredentials"], serde_json::json!([]));
+        assert_eq!(get["userVerification"], "required");
+    }
+}
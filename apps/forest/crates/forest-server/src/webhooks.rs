@@ -9,11 +9,14 @@ use hmac::{Hmac, Mac};
 use sha2::Sha256;
 use sqlx::{PgPool, Row};
 
+use crate::services::secrets::{SECRET_REFERENCE_PREFIX, SecretRegistry};
+
 type HmacSha256 = Hmac<Sha256>;
 
 #[derive(Clone)]
 pub struct WebhookState {
     pub db: PgPool,
+    pub secrets: SecretRegistry,
 }
 
 pub fn webhook_routes(state: WebhookState) -> Router {
@@ -70,7 +73,7 @@ async fn handle_flux_notification(
     body: axum::body::Bytes,
 ) -> impl IntoResponse {
     // 1. Look up destination by (org, name) to get webhook_secret from metadata
-    let dest = match lookup_destination_with_secret(&state.db, &organisation, &destination_name).await {
+    let dest = match lookup_destination_with_secret(&state, &organisation, &destination_name).await {
         Ok(Some(dest)) => dest,
         Ok(None) => {
             tracing::warn!(
@@ -223,30 +226,42 @@ struct DestinationWithSecret {
 }
 
 async fn lookup_destination_with_secret(
-    db: &PgPool,
+    state: &WebhookState,
     organisation: &str,
     name: &str,
 ) -> anyhow::Result<Option<DestinationWithSecret>> {
     let rec = sqlx::query(
-        "SELECT id, metadata FROM destinations
+        "SELECT id, environment, metadata FROM destinations
          WHERE organisation = $1 AND name = $2
          LIMIT 1",
     )
     .bind(organisation)
     .bind(name)
-    .fetch_optional(db)
+    .fetch_optional(&state.db)
     .await?;
 
     let Some(rec) = rec else { return Ok(None) };
 
     let id: uuid::Uuid = rec.get("id");
+    let environment: String = rec.get("environment");
     let metadata_json: serde_json::Value = rec.get("metadata");
-    let metadata: std::collections::HashMap<String, String> =
+    let mut metadata: std::collections::HashMap<String, String> =
         serde_json::from_value(metadata_json).unwrap_or_default();
+    metadata.retain(|key, value| key == "webhook_secret" && !value.is_empty());
+
+    // Destinations aren't tied to a project, so a `secret://` reference
+    // resolves against the organisation and environment scopes only.
+    if metadata.values().any(|v| v.starts_with(SECRET_REFERENCE_PREFIX)) {
+        metadata = state
+            .secrets
+            .resolve(organisation, "", &environment)
+            .await?
+            .resolve_metadata(&metadata)?;
+    }
 
     Ok(Some(DestinationWithSecret {
         destination_id: id,
-        webhook_secret: metadata.get("webhook_secret").cloned().filter(|s| !s.is_empty()),
+        webhook_secret: metadata.get("webhook_secret").cloned(),
     }))
 }
 
@@ -48,6 +48,13 @@ const EXEMPT: &[(&str, &str)] = &[
     ("users.rs::setup_mfa", "self-scoped"),
     ("users.rs::verify_mfa", "completes login challenge, no actor yet"),
     ("users.rs::disable_mfa", "self-scoped"),
+    ("users.rs::begin_passkey_registration", "self-scoped via AppClaims.user_id"),
+    ("users.rs::finish_passkey_registration", "self-scoped — ceremony must belong to the caller"),
+    ("users.rs::list_passkeys", "self-scoped via AppClaims.user_id"),
+    ("users.rs::rename_passkey", "self-scoped — update is filtered by the caller's user_id"),
+    ("users.rs::delete_passkey", "self-scoped — delete is filtered by the caller's user_id"),
+    ("users.rs::begin_passkey_login", "anonymous, like login"),
+    ("users.rs::finish_passkey_login", "the passkey assertion is the credential, no actor yet"),
     ("users.rs::get_user_stats", "self-scoped via the actor"),
     ("users.rs::verify_email", "email-verification token authenticates the action"),
     ("users.rs::confirm_email_verification", "service-account internal call, scoped at the route"),
@@ -77,7 +84,7 @@ const EXEMPT: &[(&str, &str)] = &[
     // auth_layer, not the JWT/Actor pipeline. Their authz model is
     // "the token only unlocks the release_id it was minted for", which
     // is checked inside the handler against the release context.
-    ("runner.rs::register_runner", "release-scoped runner token, validated upstream"),
+    ("runner.rs::register_runner", "shared runners register anonymously; org/project-scoped registrations require org admin in the handler"),
     ("runner.rs::get_release_files", "release-scoped runner token"),
     ("runner.rs::get_spec_files", "release-scoped runner token"),
     ("runner.rs::get_release_annotation", "release-scoped runner token"),
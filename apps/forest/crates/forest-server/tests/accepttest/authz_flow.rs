@@ -97,6 +97,8 @@ async fn setup_org_with_destination(
                     description: String::new(),
                     fields: vec![],
                 }),
+                runner_labels: Default::default(),
+                queue_policy: Default::default(),
             },
         ))
         .await
@@ -149,6 +151,8 @@ async fn unauthenticated_cannot_create_destination() {
                 description: String::new(),
                 fields: vec![],
             }),
+            runner_labels: Default::default(),
+            queue_policy: Default::default(),
         }))
         .await;
 
@@ -262,6 +266,8 @@ async fn user_cannot_create_destination_in_other_org() {
                     description: String::new(),
                     fields: vec![],
                 }),
+                runner_labels: Default::default(),
+                queue_policy: Default::default(),
             },
         ))
         .await;
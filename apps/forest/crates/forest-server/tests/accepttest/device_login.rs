@@ -162,6 +162,7 @@ async fn approve_then_poll_returns_tokens() {
                 user_id: user_id.clone(),
                 approving_ip: "127.0.0.1".into(),
                 approving_user_agent: "test-browser".into(),
+                ..Default::default()
             },
         ))
         .await
@@ -209,6 +210,7 @@ async fn second_poll_after_success_returns_expired_not_approved() {
                 user_id,
                 approving_ip: "127.0.0.1".into(),
                 approving_user_agent: "ua".into(),
+                ..Default::default()
             },
         ))
         .await
@@ -305,6 +307,7 @@ async fn approve_without_service_account_is_rejected() {
             user_id: uuid::Uuid::now_v7().to_string(),
             approving_ip: String::new(),
             approving_user_agent: String::new(),
+            ..Default::default()
         })
         .await
         .expect_err("unauthed approve must be rejected");
@@ -369,6 +372,7 @@ async fn approve_accepts_lowercase_and_no_dash() {
                 user_id: user_id.clone(),
                 approving_ip: String::new(),
                 approving_user_agent: String::new(),
+                ..Default::default()
             },
         ))
         .await
@@ -456,6 +460,7 @@ async fn double_approve_with_different_user_is_rejected() {
                 user_id: user_a.clone(),
                 approving_ip: "127.0.0.1".into(),
                 approving_user_agent: "ua".into(),
+                ..Default::default()
             },
         ))
         .await
@@ -470,6 +475,7 @@ async fn double_approve_with_different_user_is_rejected() {
                 user_id: user_b,
                 approving_ip: "10.0.0.1".into(),
                 approving_user_agent: "attacker".into(),
+                ..Default::default()
             },
         ))
         .await
@@ -494,6 +500,7 @@ async fn approve_unknown_code_does_not_leak_distinct_error() {
                 user_id: user_id.clone(),
                 approving_ip: "127.0.0.1".into(),
                 approving_user_agent: "ua".into(),
+                ..Default::default()
             },
         ))
         .await
@@ -517,6 +524,7 @@ async fn approve_unknown_code_does_not_leak_distinct_error() {
                 user_id: user_id.clone(),
                 approving_ip: "127.0.0.1".into(),
                 approving_user_agent: "ua".into(),
+                ..Default::default()
             },
         ))
         .await
@@ -529,6 +537,7 @@ async fn approve_unknown_code_does_not_leak_distinct_error() {
                 user_id,
                 approving_ip: "127.0.0.1".into(),
                 approving_user_agent: "ua".into(),
+                ..Default::default()
             },
         ))
         .await
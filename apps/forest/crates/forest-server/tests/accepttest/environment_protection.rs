@@ -0,0 +1,392 @@
+//! Acceptance tests for environment protection rules: only admins may set
+//! them, invalid rules are refused, direct releases into an environment are
+//! held to its branch and prior-environment rules, and a release awaiting
+//! approval doesn't hold up other projects sharing its destination.
+
+use forest_grpc_interface::*;
+use tonic::metadata::MetadataValue;
+
+use crate::accepttest::fixtures::{Fixture, GivenReleaseFlow, testcase};
+use crate::accepttest::release_flow::ReleaseFlowData;
+
+fn authed_request<T>(token: &str, inner: T) -> tonic::Request<T> {
+    let mut req = tonic::Request::new(inner);
+    let val: MetadataValue<_> = format!("Bearer {token}").parse().expect("valid metadata");
+    req.metadata_mut().insert("authorization", val);
+    req
+}
+
+async fn environment_id(fixture: &Fixture, token: &str, organisation: &str, name: &str) -> String {
+    fixture
+        .environments()
+        .get_environment(authed_request(
+            token,
+            GetEnvironmentRequest {
+                identifier: Some(get_environment_request::Identifier::Lookup(
+                    EnvironmentLookup {
+                        organisation: organisation.into(),
+                        name: name.into(),
+                    },
+                )),
+            },
+        ))
+        .await
+        .expect("get environment")
+        .into_inner()
+        .environment
+        .expect("environment")
+        .id
+}
+
+async fn set_protection(
+    fixture: &Fixture,
+    token: &str,
+    id: &str,
+    protection: EnvironmentProtection,
+) -> Result<Environment, tonic::Status> {
+    fixture
+        .environments()
+        .update_environment(authed_request(
+            token,
+            UpdateEnvironmentRequest {
+                id: id.into(),
+                description: None,
+                sort_order: None,
+                protection: Some(protection),
+            },
+        ))
+        .await
+        .map(|r| r.into_inner().environment.expect("environment"))
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn protection_rules_are_validated_and_admin_only() -> anyhow::Result<()> {
+    let (given, _when, _then) = testcase::<ReleaseFlowData>().await?;
+    let suffix = uuid::Uuid::now_v7();
+    let org = format!("protect-org-{suffix}");
+    let given = given
+        .a_registered_user()
+        .await
+        .an_organisation(&org)
+        .await
+        .an_environment("staging")
+        .await
+        .an_environment("prod")
+        .await;
+    let fixture = given.fixture().clone();
+    let token = given.data().auth_token.clone();
+    let staging = environment_id(&fixture, &token, &org, "staging").await;
+    let prod = environment_id(&fixture, &token, &org, "prod").await;
+
+    let env = set_protection(
+        &fixture,
+        &token,
+        &prod,
+        EnvironmentProtection {
+            protected: true,
+            required_prior_environment: Some("staging".into()),
+            approver_groups: vec!["release-managers".into()],
+            allowed_branches: vec!["^main$".into()],
+        },
+    )
+    .await?;
+    let protection = env.protection.expect("protection");
+    assert!(protection.protected);
+    assert_eq!(protection.required_prior_environment.as_deref(), Some("staging"));
+    assert_eq!(protection.approver_groups, vec!["release-managers".to_string()]);
+
+    // Unknown prior environment.
+    let err = set_protection(
+        &fixture,
+        &token,
+        &staging,
+        EnvironmentProtection {
+            required_prior_environment: Some("qa".into()),
+            ..Default::default()
+        },
+    )
+    .await
+    .expect_err("unknown prior environment");
+    assert_eq!(err.code(), tonic::Code::InvalidArgument);
+
+    // staging → prod → staging would never release.
+    let err = set_protection(
+        &fixture,
+        &token,
+        &staging,
+        EnvironmentProtection {
+            required_prior_environment: Some("prod".into()),
+            ..Default::default()
+        },
+    )
+    .await
+    .expect_err("cycle");
+    assert_eq!(err.code(), tonic::Code::InvalidArgument);
+
+    let err = set_protection(
+        &fixture,
+        &token,
+        &staging,
+        EnvironmentProtection {
+            allowed_branches: vec!["(".into()],
+            ..Default::default()
+        },
+    )
+    .await
+    .expect_err("invalid regex");
+    assert_eq!(err.code(), tonic::Code::InvalidArgument);
+
+    // A plain member may edit the description but not the protection.
+    let member = fixture
+        .users()
+        .register(RegisterRequest {
+            username: format!("protect-member-{suffix}"),
+            email: format!("protect-member-{suffix}@example.com"),
+            password: "TestPassword123!".into(),
+        })
+        .await?
+        .into_inner();
+    let org_id = sqlx::query_scalar!("SELECT id FROM organisations WHERE name = $1", org)
+        .fetch_one(&fixture.db)
+        .await?;
+    fixture
+        .organisations()
+        .add_member(authed_request(
+            &token,
+            AddMemberRequest {
+                organisation_id: org_id.to_string(),
+                user_id: member.user.expect("user").user_id,
+                role: "member".into(),
+            },
+        ))
+        .await?;
+    let member_token = member.tokens.expect("tokens").access_token;
+
+    let err = set_protection(&fixture, &member_token, &prod, EnvironmentProtection::default())
+        .await
+        .expect_err("member cannot change protection");
+    assert_eq!(err.code(), tonic::Code::PermissionDenied);
+
+    fixture
+        .environments()
+        .update_environment(authed_request(
+            &member_token,
+            UpdateEnvironmentRequest {
+                id: prod.clone(),
+                description: Some("production".into()),
+                sort_order: None,
+                protection: None,
+            },
+        ))
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn direct_release_respects_branch_and_prior_environment() -> anyhow::Result<()> {
+    let (given, _when, _then) = testcase::<ReleaseFlowData>().await?;
+    let suffix = uuid::Uuid::now_v7();
+    let org = format!("protect-org-{suffix}");
+    let dest = format!("protect-dest-{suffix}");
+    let given = given
+        .a_registered_user()
+        .await
+        .an_organisation(&org)
+        .await
+        .an_environment("staging")
+        .await
+        .an_environment("prod")
+        .await
+        .a_destination(&dest, "prod")
+        .await
+        .an_uploaded_artifact()
+        .await
+        // Annotated from branch `main`.
+        .an_annotated_release()
+        .await;
+    let fixture = given.fixture().clone();
+    let (token, artifact_id) = {
+        let data = given.data();
+        (data.auth_token.clone(), data.artifact_id.clone())
+    };
+    let prod = environment_id(&fixture, &token, &org, "prod").await;
+
+    let release = |token: String| {
+        let fixture = fixture.clone();
+        let artifact_id = artifact_id.clone();
+        let dest = dest.clone();
+        async move {
+            fixture
+                .releases()
+                .release(authed_request(
+                    &token,
+                    ReleaseRequest {
+                        artifact_id,
+                        destinations: vec![dest],
+                        environments: vec![],
+                        force: false,
+                        use_pipeline: false,
+                        prepare_only: false,
+                    },
+                ))
+                .await
+        }
+    };
+
+    set_protection(
+        &fixture,
+        &token,
+        &prod,
+        EnvironmentProtection {
+            allowed_branches: vec!["^release/".into()],
+            ..Default::default()
+        },
+    )
+    .await?;
+    let err = release(token.clone()).await.expect_err("branch not allowed");
+    assert_eq!(err.code(), tonic::Code::FailedPrecondition);
+    assert!(err.message().contains("main"), "{}", err.message());
+
+    set_protection(
+        &fixture,
+        &token,
+        &prod,
+        EnvironmentProtection {
+            required_prior_environment: Some("staging".into()),
+            allowed_branches: vec!["^main$".into()],
+            ..Default::default()
+        },
+    )
+    .await?;
+    let err = release(token.clone()).await.expect_err("not released to staging");
+    assert_eq!(err.code(), tonic::Code::FailedPrecondition);
+    assert!(err.message().contains("staging"), "{}", err.message());
+
+    set_protection(&fixture, &token, &prod, EnvironmentProtection::default()).await?;
+    release(token).await?;
+
+    Ok(())
+}
+
+async fn release_intent(fixture: &Fixture, token: &str, artifact_id: &str, dest: &str) -> String {
+    fixture
+        .releases()
+        .release(authed_request(
+            token,
+            ReleaseRequest {
+                artifact_id: artifact_id.into(),
+                destinations: vec![dest.into()],
+                environments: vec![],
+                force: false,
+                use_pipeline: false,
+                prepare_only: false,
+            },
+        ))
+        .await
+        .expect("release")
+        .into_inner()
+        .intents
+        .first()
+        .expect("intent")
+        .release_intent_id
+        .clone()
+}
+
+/// Poll the release of `intent` until `done` accepts its (status, held).
+async fn wait_for_release(
+    fixture: &Fixture,
+    intent: &str,
+    done: impl Fn(&str, bool) -> bool,
+) -> anyhow::Result<(String, bool)> {
+    let intent: uuid::Uuid = intent.parse()?;
+    let state = tokio::time::timeout(std::time::Duration::from_secs(30), async {
+        loop {
+            let row = sqlx::query!(
+                "SELECT status, awaiting_approval FROM release_states WHERE release_intent_id = $1",
+                intent,
+            )
+            .fetch_optional(&fixture.db)
+            .await
+            .expect("load release state");
+            if let Some(row) = row
+                && done(&row.status, row.awaiting_approval)
+            {
+                return (row.status, row.awaiting_approval);
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+        }
+    })
+    .await?;
+    Ok(state)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn release_awaiting_approval_does_not_block_shared_destination() -> anyhow::Result<()> {
+    let (given, _when, _then) = testcase::<ReleaseFlowData>().await?;
+    let suffix = uuid::Uuid::now_v7();
+    let org = format!("protect-org-{suffix}");
+    let dest = format!("protect-dest-{suffix}");
+    let given = given
+        .a_registered_user()
+        .await
+        .an_organisation(&org)
+        .await
+        .an_environment("prod")
+        .await
+        .a_destination(&dest, "prod")
+        .await
+        .an_uploaded_artifact()
+        .await
+        .an_annotated_release_for("web")
+        .await;
+    let web_artifact = given.data().artifact_id.clone();
+    let given = given
+        .an_uploaded_artifact()
+        .await
+        .an_annotated_release_for("api")
+        .await;
+    let fixture = given.fixture().clone();
+    let (token, api_artifact) = {
+        let data = given.data();
+        (data.auth_token.clone(), data.artifact_id.clone())
+    };
+    let prod = environment_id(&fixture, &token, &org, "prod").await;
+    set_protection(
+        &fixture,
+        &token,
+        &prod,
+        EnvironmentProtection {
+            protected: true,
+            ..Default::default()
+        },
+    )
+    .await?;
+
+    // Nobody approves web's release: it is held, but steps out of the queue.
+    let web = release_intent(&fixture, &token, &web_artifact, &dest).await;
+    wait_for_release(&fixture, &web, |_, held| held).await?;
+
+    // api's release queues behind it, gets approved and runs anyway.
+    let api = release_intent(&fixture, &token, &api_artifact, &dest).await;
+    let api_intent: uuid::Uuid = api.parse()?;
+    sqlx::query!(
+        "INSERT INTO approval_decisions (release_intent_id, target_environment, user_id, username, decision)
+         VALUES ($1, 'prod', $2, 'approver', 'approved')",
+        api_intent,
+        uuid::Uuid::now_v7(),
+    )
+    .execute(&fixture.db)
+    .await?;
+    let (status, _) = wait_for_release(&fixture, &api, |status, _| {
+        matches!(status, "SUCCEEDED" | "FAILED" | "CANCELLED" | "TIMED_OUT")
+    })
+    .await?;
+    assert_eq!(status, "SUCCEEDED");
+
+    let (status, held) = wait_for_release(&fixture, &web, |_, _| true).await?;
+    assert_eq!(status, "QUEUED");
+    assert!(held);
+
+    Ok(())
+}
@@ -1,12 +1,17 @@
 use std::net::SocketAddr;
 use std::sync::{LazyLock, OnceLock};
 
+use forest_grpc_interface::app_service_client::AppServiceClient;
 use forest_grpc_interface::artifact_service_client::ArtifactServiceClient;
 use forest_grpc_interface::destination_service_client::DestinationServiceClient;
 use forest_grpc_interface::environment_service_client::EnvironmentServiceClient;
 use forest_grpc_interface::organisation_service_client::OrganisationServiceClient;
 use forest_grpc_interface::registry_service_client::RegistryServiceClient;
+use forest_grpc_interface::release_history_service_client::ReleaseHistoryServiceClient;
+use forest_grpc_interface::release_schedule_service_client::ReleaseScheduleServiceClient;
 use forest_grpc_interface::release_service_client::ReleaseServiceClient;
+use forest_grpc_interface::retention_service_client::RetentionServiceClient;
+use forest_grpc_interface::secret_service_client::SecretServiceClient;
 use forest_grpc_interface::users_service_client::UsersServiceClient;
 use tonic::transport::Channel;
 
@@ -14,6 +19,7 @@ use tonic::transport::Channel;
 pub struct Fixture {
     pub channel: Channel,
     pub db: sqlx::PgPool,
+    pub state: forest_server::State,
 }
 
 impl Fixture {
@@ -44,6 +50,26 @@ impl Fixture {
     pub fn registry(&self) -> RegistryServiceClient<Channel> {
         RegistryServiceClient::new(self.channel.clone())
     }
+
+    pub fn apps(&self) -> AppServiceClient<Channel> {
+        AppServiceClient::new(self.channel.clone())
+    }
+
+    pub fn secrets(&self) -> SecretServiceClient<Channel> {
+        SecretServiceClient::new(self.channel.clone())
+    }
+
+    pub fn release_history(&self) -> ReleaseHistoryServiceClient<Channel> {
+        ReleaseHistoryServiceClient::new(self.channel.clone())
+    }
+
+    pub fn retention(&self) -> RetentionServiceClient<Channel> {
+        RetentionServiceClient::new(self.channel.clone())
+    }
+
+    pub fn release_schedules(&self) -> ReleaseScheduleServiceClient<Channel> {
+        ReleaseScheduleServiceClient::new(self.channel.clone())
+    }
 }
 
 /// Dedicated runtime that outlives all tests, so spawned server/scheduler tasks
@@ -69,6 +95,7 @@ fn base_test_config() -> forest_server::Config {
         registration_email_domain_regex: None,
         require_email_verification: false,
         web_app_url: Some("http://forage.test.invalid".into()),
+        secrets_key: Some(b"test-secrets-encryption-key-32b!".to_vec()),
     }
 }
 
@@ -135,7 +162,7 @@ fn bring_up(config: forest_server::Config) -> Fixture {
             .await
             .expect("connect to grpc server");
 
-        Fixture { channel, db }
+        Fixture { channel, db, state }
     }))
 }
 
@@ -21,6 +21,7 @@ pub trait GivenReleaseFlow {
     async fn a_destination(self, name: &str, environment: &str) -> Self;
     async fn an_uploaded_artifact(self) -> Self;
     async fn an_annotated_release(self) -> Self;
+    async fn an_annotated_release_for(self, project: &str) -> Self;
 }
 
 impl GivenReleaseFlow for Given<ReleaseFlowData> {
@@ -120,6 +121,8 @@ impl GivenReleaseFlow for Given<ReleaseFlowData> {
                         description: String::new(),
                         fields: vec![],
                     }),
+                    runner_labels: Default::default(),
+                    queue_policy: Default::default(),
                 },
             ))
             .await
@@ -184,6 +187,10 @@ impl GivenReleaseFlow for Given<ReleaseFlowData> {
     }
 
     async fn an_annotated_release(self) -> Self {
+        self.an_annotated_release_for("test-project").await
+    }
+
+    async fn an_annotated_release_for(self, project: &str) -> Self {
         let mut release_client = self.fixture().releases();
         let (token, artifact_id, org) = {
             let data = self.data();
@@ -197,7 +204,7 @@ impl GivenReleaseFlow for Given<ReleaseFlowData> {
                     artifact_id,
                     project: Some(Project {
                         organisation: org,
-                        project: "test-project".into(),
+                        project: project.into(),
                         readme: String::new(),
                         description: String::new(),
                         metadata: Some(Default::default()),
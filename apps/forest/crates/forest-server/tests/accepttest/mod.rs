@@ -4,6 +4,15 @@ mod authz_flow;
 mod component_flow;
 mod device_login;
 mod email_verification;
+mod environment_protection;
 mod global_tools_flow;
+mod passkeys;
 mod registration_domain;
 mod release_flow;
+mod release_history;
+mod release_schedules;
+mod retention;
+mod scim_provisioning;
+mod secrets;
+mod sso_provisioning;
+mod terraform_state;
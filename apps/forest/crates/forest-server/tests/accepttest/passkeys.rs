@@ -0,0 +1,671 @@
+//! Acceptance tests for WebAuthn passkeys: registration, management,
+//! second-factor and passwordless login, and passkey-confirmed device
+//! approval. Ceremonies are driven by an in-process software
+//! authenticator that produces real ES256 signatures.
+//!
+//! These run against the same shared DB as the rest of the accept suite;
+//! identifiers are UUID-suffixed for isolation.
+
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use forest_grpc_interface::*;
+use ring::rand::SystemRandom;
+use ring::signature::{self, EcdsaKeyPair, KeyPair};
+use sha2::{Digest, Sha256};
+use tonic::metadata::MetadataValue;
+
+use crate::accepttest::fixtures::{
+    Fixture, RESTRICTED_FIXTURE_SERVICE_ACCOUNT_KEY, fixture, restricted_fixture,
+};
+
+/// Must match the fixture's `web_app_url`.
+const RP_ID: &str = "forage.test.invalid";
+const ORIGIN: &str = "http://forage.test.invalid";
+const PASSWORD: &str = "TestPassword123!";
+
+fn authed_request<T>(token: &str, inner: T) -> tonic::Request<T> {
+    let mut req = tonic::Request::new(inner);
+    let val: MetadataValue<_> = format!("Bearer {token}").parse().expect("valid metadata");
+    req.metadata_mut().insert("authorization", val);
+    req
+}
+
+fn b64(bytes: &[u8]) -> String {
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+// ── Software authenticator ────────────────────────────────────────────
+
+fn cbor_head(major: u8, len: usize) -> Vec<u8> {
+    match len {
+        0..=23 => vec![(major << 5) | len as u8],
+        24..=0xff => vec![(major << 5) | 24, len as u8],
+        _ => {
+            let mut v = vec![(major << 5) | 25];
+            v.extend((len as u16).to_be_bytes());
+            v
+        }
+    }
+}
+
+fn cbor_int(i: i64) -> Vec<u8> {
+    if i >= 0 {
+        cbor_head(0, i as usize)
+    } else {
+        cbor_head(1, (-1 - i) as usize)
+    }
+}
+
+fn cbor_bytes(b: &[u8]) -> Vec<u8> {
+    let mut v = cbor_head(2, b.len());
+    v.extend(b);
+    v
+}
+
+fn cbor_text(s: &str) -> Vec<u8> {
+    let mut v = cbor_head(3, s.len());
+    v.extend(s.as_bytes());
+    v
+}
+
+fn cbor_map(entries: Vec<(Vec<u8>, Vec<u8>)>) -> Vec<u8> {
+    let mut v = cbor_head(5, entries.len());
+    for (k, val) in entries {
+        v.extend(k);
+        v.extend(val);
+    }
+    v
+}
+
+/// A resident ES256 credential, held in memory.
+struct SoftAuthenticator {
+    key: EcdsaKeyPair,
+    credential_id: Vec<u8>,
+    user_handle: Vec<u8>,
+    counter: u32,
+    rng: SystemRandom,
+}
+
+impl SoftAuthenticator {
+    fn new() -> Self {
+        let rng = SystemRandom::new();
+        let alg = &signature::ECDSA_P256_SHA256_ASN1_SIGNING;
+        let pkcs8 = EcdsaKeyPair::generate_pkcs8(alg, &rng).unwrap();
+        let key = EcdsaKeyPair::from_pkcs8(alg, pkcs8.as_ref(), &rng).unwrap();
+        Self {
+            key,
+            credential_id: uuid::Uuid::now_v7().as_bytes().to_vec(),
+            user_handle: vec![],
+            counter: 0,
+            rng,
+        }
+    }
+
+    fn auth_data(&mut self, attested: bool) -> Vec<u8> {
+        self.counter += 1;
+        let mut data = Sha256::digest(RP_ID.as_bytes()).to_vec();
+        // UP | UV, plus AT when carrying the credential.
+        data.push(0x05 | if attested { 0x40 } else { 0 });
+        data.extend(self.counter.to_be_bytes());
+        if attested {
+            let point = self.key.public_key().as_ref();
+            data.extend([0u8; 16]);
+            data.extend((self.credential_id.len() as u16).to_be_bytes());
+            data.extend(&self.credential_id);
+            data.extend(cbor_map(vec![
+                (cbor_int(1), cbor_int(2)),
+                (cbor_int(3), cbor_int(-7)),
+                (cbor_int(-1), cbor_int(1)),
+                (cbor_int(-2), cbor_bytes(&point[1..33])),
+                (cbor_int(-3), cbor_bytes(&point[33..])),
+            ]));
+        }
+        data
+    }
+
+    fn client_data(kind: &str, options: &serde_json::Value) -> Vec<u8> {
+        serde_json::to_vec(&serde_json::json!({
+            "type": kind,
+            "challenge": options["challenge"],
+            "origin": ORIGIN,
+        }))
+        .unwrap()
+    }
+
+    /// Answer `navigator.credentials.create()`.
+    fn create(&mut self, options_json: &str) -> String {
+        let options: serde_json::Value = serde_json::from_str(options_json).unwrap();
+        assert_eq!(options["rp"]["id"], RP_ID);
+        self.user_handle = URL_SAFE_NO_PAD
+            .decode(options["user"]["id"].as_str().unwrap())
+            .unwrap();
+
+        let attestation = cbor_map(vec![
+            (cbor_text("fmt"), cbor_text("none")),
+            (cbor_text("attStmt"), cbor_map(vec![])),
+            (cbor_text("authData"), cbor_bytes(&self.auth_data(true))),
+        ]);
+        serde_json::json!({
+            "id": b64(&self.credential_id),
+            "rawId": b64(&self.credential_id),
+            "type": "public-key",
+            "response": {
+                "clientDataJSON": b64(&Self::client_data("webauthn.create", &options)),
+                "attestationObject": b64(&attestation),
+                "transports": ["internal"],
+            },
+        })
+        .to_string()
+    }
+
+    /// Answer `navigator.credentials.get()`.
+    fn get(&mut self, options_json: &str) -> String {
+        let options: serde_json::Value = serde_json::from_str(options_json).unwrap();
+        assert_eq!(options["rpId"], RP_ID);
+
+        let auth_data = self.auth_data(false);
+        let client_data = Self::client_data("webauthn.get", &options);
+        let mut signed = auth_data.clone();
+        signed.extend(Sha256::digest(&client_data));
+        let sig = self.key.sign(&self.rng, &signed).unwrap();
+        serde_json::json!({
+            "id": b64(&self.credential_id),
+            "rawId": b64(&self.credential_id),
+            "type": "public-key",
+            "response": {
+                "clientDataJSON": b64(&client_data),
+                "authenticatorData": b64(&auth_data),
+                "signature": b64(sig.as_ref()),
+                "userHandle": b64(&self.user_handle),
+            },
+        })
+        .to_string()
+    }
+}
+
+// ── Helpers ───────────────────────────────────────────────────────────
+
+struct TestUser {
+    user_id: String,
+    username: String,
+    access_token: String,
+}
+
+async fn registered_user(fixture: &Fixture) -> TestUser {
+    let username = format!("pk-user-{}", uuid::Uuid::now_v7());
+    let resp = fixture
+        .users()
+        .register(RegisterRequest {
+            username: username.clone(),
+            email: format!("{username}@understory.io"),
+            password: PASSWORD.into(),
+        })
+        .await
+        .expect("register")
+        .into_inner();
+
+    TestUser {
+        user_id: resp.user.expect("user").user_id,
+        username,
+        access_token: resp.tokens.expect("tokens").access_token,
+    }
+}
+
+async fn add_passkey(fixture: &Fixture, user: &TestUser, name: &str) -> (SoftAuthenticator, Passkey) {
+    let mut users = fixture.users();
+    let ceremony = users
+        .begin_passkey_registration(authed_request(
+            &user.access_token,
+            BeginPasskeyRegistrationRequest {},
+        ))
+        .await
+        .expect("begin registration")
+        .into_inner()
+        .ceremony
+        .expect("ceremony");
+
+    let mut authenticator = SoftAuthenticator::new();
+    let credential_json = authenticator.create(&ceremony.options_json);
+    let passkey = users
+        .finish_passkey_registration(authed_request(
+            &user.access_token,
+            FinishPasskeyRegistrationRequest {
+                ceremony_id: ceremony.ceremony_id,
+                name: name.into(),
+                credential_json,
+            },
+        ))
+        .await
+        .expect("finish registration")
+        .into_inner()
+        .passkey
+        .expect("passkey");
+
+    (authenticator, passkey)
+}
+
+async fn password_login(fixture: &Fixture, user: &TestUser) -> LoginResponse {
+    fixture
+        .users()
+        .login(LoginRequest {
+            identifier: Some(login_request::Identifier::Username(user.username.clone())),
+            password: PASSWORD.into(),
+        })
+        .await
+        .expect("login")
+        .into_inner()
+}
+
+// ── Registration and management ───────────────────────────────────────
+
+#[tokio::test(flavor = "multi_thread")]
+async fn register_list_rename_and_delete_passkeys() {
+    let fixture = fixture().await.unwrap();
+    let mut users = fixture.users();
+    let user = registered_user(&fixture).await;
+
+    let (_laptop, laptop) = add_passkey(&fixture, &user, "Laptop").await;
+    let (_phone, phone) = add_passkey(&fixture, &user, "Phone").await;
+
+    let listed = users
+        .list_passkeys(authed_request(&user.access_token, ListPasskeysRequest {}))
+        .await
+        .expect("list")
+        .into_inner()
+        .passkeys;
+    let names: Vec<_> = listed.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, vec!["Laptop", "Phone"]);
+    assert!(listed.iter().all(|p| p.last_used_at.is_none()));
+
+    users
+        .rename_passkey(authed_request(
+            &user.access_token,
+            RenamePasskeyRequest {
+                passkey_id: laptop.passkey_id.clone(),
+                name: "Work laptop".into(),
+            },
+        ))
+        .await
+        .expect("rename");
+    users
+        .delete_passkey(authed_request(
+            &user.access_token,
+            DeletePasskeyRequest {
+                passkey_id: phone.passkey_id.clone(),
+            },
+        ))
+        .await
+        .expect("delete");
+
+    let listed = users
+        .list_passkeys(authed_request(&user.access_token, ListPasskeysRequest {}))
+        .await
+        .expect("list")
+        .into_inner()
+        .passkeys;
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].passkey_id, laptop.passkey_id);
+    assert_eq!(listed[0].name, "Work laptop");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn passkeys_are_scoped_to_their_owner() {
+    let fixture = fixture().await.unwrap();
+    let mut users = fixture.users();
+    let owner = registered_user(&fixture).await;
+    let other = registered_user(&fixture).await;
+    let (_, passkey) = add_passkey(&fixture, &owner, "Laptop").await;
+
+    let err = users
+        .delete_passkey(authed_request(
+            &other.access_token,
+            DeletePasskeyRequest {
+                passkey_id: passkey.passkey_id.clone(),
+            },
+        ))
+        .await
+        .expect_err("other user must not delete");
+    assert_eq!(err.code(), tonic::Code::NotFound);
+
+    // A registration ceremony cannot be finished by someone else.
+    let ceremony = users
+        .begin_passkey_registration(authed_request(
+            &owner.access_token,
+            BeginPasskeyRegistrationRequest {},
+        ))
+        .await
+        .expect("begin")
+        .into_inner()
+        .ceremony
+        .expect("ceremony");
+    let credential_json = SoftAuthenticator::new().create(&ceremony.options_json);
+    let err = users
+        .finish_passkey_registration(authed_request(
+            &other.access_token,
+            FinishPasskeyRegistrationRequest {
+                ceremony_id: ceremony.ceremony_id,
+                name: "Stolen".into(),
+                credential_json,
+            },
+        ))
+        .await
+        .expect_err("ceremony belongs to owner");
+    assert_eq!(err.code(), tonic::Code::PermissionDenied);
+}
+
+// ── Login ─────────────────────────────────────────────────────────────
+
+#[tokio::test(flavor = "multi_thread")]
+async fn passkey_completes_password_login_as_second_factor() {
+    let fixture = fixture().await.unwrap();
+    let mut users = fixture.users();
+    let user = registered_user(&fixture).await;
+    let (mut authenticator, _) = add_passkey(&fixture, &user, "Laptop").await;
+
+    let login = password_login(&fixture, &user).await;
+    assert!(login.mfa_required);
+    assert!(login.tokens.is_none());
+    assert_eq!(login.mfa_types, vec![MfaType::Passkey as i32]);
+
+    let ceremony = users
+        .begin_passkey_login(BeginPasskeyLoginRequest {
+            mfa_session_token: login.mfa_session_token.clone(),
+        })
+        .await
+        .expect("begin login")
+        .into_inner()
+        .ceremony
+        .expect("ceremony");
+    let options: serde_json::Value = serde_json::from_str(&ceremony.options_json).unwrap();
+    assert_eq!(options["allowCredentials"].as_array().unwrap().len(), 1);
+
+    let resp = users
+        .finish_passkey_login(FinishPasskeyLoginRequest {
+            ceremony_id: ceremony.ceremony_id.clone(),
+            credential_json: authenticator.get(&ceremony.options_json),
+        })
+        .await
+        .expect("finish login")
+        .into_inner();
+    assert_eq!(resp.user.expect("user").user_id, user.user_id);
+    assert!(!resp.tokens.expect("tokens").access_token.is_empty());
+
+    // The MFA session and the ceremony are both single-use.
+    let err = users
+        .begin_passkey_login(BeginPasskeyLoginRequest {
+            mfa_session_token: login.mfa_session_token,
+        })
+        .await
+        .expect_err("mfa session consumed");
+    assert_eq!(err.code(), tonic::Code::Unauthenticated);
+    let err = users
+        .finish_passkey_login(FinishPasskeyLoginRequest {
+            ceremony_id: ceremony.ceremony_id,
+            credential_json: authenticator.get(&ceremony.options_json),
+        })
+        .await
+        .expect_err("ceremony consumed");
+    assert_eq!(err.code(), tonic::Code::Unauthenticated);
+
+    let listed = users
+        .list_passkeys(authed_request(&user.access_token, ListPasskeysRequest {}))
+        .await
+        .expect("list")
+        .into_inner()
+        .passkeys;
+    assert!(listed[0].last_used_at.is_some());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn failed_passkey_assertion_keeps_the_mfa_session() {
+    let fixture = fixture().await.unwrap();
+    let mut users = fixture.users();
+    let user = registered_user(&fixture).await;
+    let (mut authenticator, _) = add_passkey(&fixture, &user, "Laptop").await;
+    let login = password_login(&fixture, &user).await;
+
+    let first = users
+        .begin_passkey_login(BeginPasskeyLoginRequest {
+            mfa_session_token: login.mfa_session_token.clone(),
+        })
+        .await
+        .expect("begin login")
+        .into_inner()
+        .ceremony
+        .expect("ceremony");
+    let stale = authenticator.get(&first.options_json);
+
+    // Signed for another challenge, so the assertion fails.
+    let second = users
+        .begin_passkey_login(BeginPasskeyLoginRequest {
+            mfa_session_token: login.mfa_session_token.clone(),
+        })
+        .await
+        .expect("begin login")
+        .into_inner()
+        .ceremony
+        .expect("ceremony");
+    let err = users
+        .finish_passkey_login(FinishPasskeyLoginRequest {
+            ceremony_id: second.ceremony_id,
+            credential_json: stale,
+        })
+        .await
+        .expect_err("wrong challenge");
+    assert_eq!(err.code(), tonic::Code::Unauthenticated);
+
+    // The MFA session survives the failed prompt.
+    let retry = users
+        .begin_passkey_login(BeginPasskeyLoginRequest {
+            mfa_session_token: login.mfa_session_token,
+        })
+        .await
+        .expect("mfa session still valid")
+        .into_inner()
+        .ceremony
+        .expect("ceremony");
+    let resp = users
+        .finish_passkey_login(FinishPasskeyLoginRequest {
+            ceremony_id: retry.ceremony_id,
+            credential_json: authenticator.get(&retry.options_json),
+        })
+        .await
+        .expect("finish login")
+        .into_inner();
+    assert_eq!(resp.user.expect("user").user_id, user.user_id);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn passwordless_login_with_discoverable_passkey() {
+    let fixture = fixture().await.unwrap();
+    let mut users = fixture.users();
+    let user = registered_user(&fixture).await;
+    let (mut authenticator, _) = add_passkey(&fixture, &user, "Laptop").await;
+
+    let ceremony = users
+        .begin_passkey_login(BeginPasskeyLoginRequest::default())
+        .await
+        .expect("begin login")
+        .into_inner()
+        .ceremony
+        .expect("ceremony");
+    let options: serde_json::Value = serde_json::from_str(&ceremony.options_json).unwrap();
+    assert!(options["allowCredentials"].as_array().unwrap().is_empty());
+    assert_eq!(options["userVerification"], "required");
+
+    let resp = users
+        .finish_passkey_login(FinishPasskeyLoginRequest {
+            ceremony_id: ceremony.ceremony_id,
+            credential_json: authenticator.get(&ceremony.options_json),
+        })
+        .await
+        .expect("finish login")
+        .into_inner();
+    assert_eq!(resp.user.expect("user").user_id, user.user_id);
+    assert!(resp.tokens.is_some());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn passkey_login_rejects_replayed_assertion() {
+    let fixture = fixture().await.unwrap();
+    let mut users = fixture.users();
+    let user = registered_user(&fixture).await;
+    let (mut authenticator, _) = add_passkey(&fixture, &user, "Laptop").await;
+
+    let first = users
+        .begin_passkey_login(BeginPasskeyLoginRequest::default())
+        .await
+        .expect("begin")
+        .into_inner()
+        .ceremony
+        .expect("ceremony");
+    let stale = authenticator.get(&first.options_json);
+
+    // Signed for another challenge.
+    let second = users
+        .begin_passkey_login(BeginPasskeyLoginRequest::default())
+        .await
+        .expect("begin")
+        .into_inner()
+        .ceremony
+        .expect("ceremony");
+    let err = users
+        .finish_passkey_login(FinishPasskeyLoginRequest {
+            ceremony_id: second.ceremony_id,
+            credential_json: stale,
+        })
+        .await
+        .expect_err("wrong challenge");
+    assert_eq!(err.code(), tonic::Code::Unauthenticated);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn totp_verification_is_rejected_for_passkey_only_users() {
+    let fixture = fixture().await.unwrap();
+    let user = registered_user(&fixture).await;
+    add_passkey(&fixture, &user, "Laptop").await;
+
+    let login = password_login(&fixture, &user).await;
+    let err = fixture
+        .users()
+        .verify_login_mfa(VerifyLoginMfaRequest {
+            mfa_session_token: login.mfa_session_token,
+            code: "000000".into(),
+        })
+        .await
+        .expect_err("no TOTP configured");
+    assert_eq!(err.code(), tonic::Code::Unauthenticated);
+}
+
+// ── Device approval ───────────────────────────────────────────────────
+
+#[tokio::test(flavor = "multi_thread")]
+async fn device_login_approved_with_passkey() {
+    let fixture = fixture().await.unwrap();
+    let restricted = restricted_fixture().await.unwrap();
+    let mut users = restricted.users();
+    let user = registered_user(&fixture).await;
+    let (mut authenticator, _) = add_passkey(&fixture, &user, "Laptop").await;
+    let (mut other_authenticator, _) =
+        add_passkey(&fixture, &registered_user(&fixture).await, "Other").await;
+
+    let init = users
+        .initiate_device_login(InitiateDeviceLoginRequest {
+            client_name: "forest-cli".into(),
+            client_version: "0.3.2".into(),
+            scopes: vec![],
+        })
+        .await
+        .expect("initiate")
+        .into_inner();
+
+    let begin = || {
+        let mut users = restricted.users();
+        let user_id = user.user_id.clone();
+        async move {
+            users
+                .begin_passkey_verification(authed_request(
+                    RESTRICTED_FIXTURE_SERVICE_ACCOUNT_KEY,
+                    BeginPasskeyVerificationRequest { user_id },
+                ))
+                .await
+                .expect("begin verification")
+                .into_inner()
+                .ceremony
+                .expect("ceremony")
+        }
+    };
+    let approve = |ceremony_id: String, credential_json: String| ApproveDeviceLoginRequest {
+        user_code: init.user_code.clone(),
+        user_id: user.user_id.clone(),
+        approving_ip: "127.0.0.1".into(),
+        approving_user_agent: "test-browser".into(),
+        passkey_ceremony_id: ceremony_id,
+        passkey_credential_json: credential_json,
+    };
+
+    // Someone else's passkey cannot confirm this user's approval.
+    let ceremony = begin().await;
+    let err = users
+        .approve_device_login(authed_request(
+            RESTRICTED_FIXTURE_SERVICE_ACCOUNT_KEY,
+            approve(
+                ceremony.ceremony_id,
+                other_authenticator.get(&ceremony.options_json),
+            ),
+        ))
+        .await
+        .expect_err("foreign passkey");
+    assert_eq!(err.code(), tonic::Code::Unauthenticated);
+
+    let poll = users
+        .poll_device_login(PollDeviceLoginRequest {
+            device_code: init.device_code.clone(),
+        })
+        .await
+        .expect("poll")
+        .into_inner();
+    assert_eq!(poll.status, DeviceLoginStatus::Pending as i32);
+
+    let ceremony = begin().await;
+    users
+        .approve_device_login(authed_request(
+            RESTRICTED_FIXTURE_SERVICE_ACCOUNT_KEY,
+            approve(
+                ceremony.ceremony_id,
+                authenticator.get(&ceremony.options_json),
+            ),
+        ))
+        .await
+        .expect("approve");
+
+    // Respect the poll interval before asking again.
+    tokio::time::sleep(std::time::Duration::from_secs(init.interval_seconds as u64)).await;
+    let poll = users
+        .poll_device_login(PollDeviceLoginRequest {
+            device_code: init.device_code,
+        })
+        .await
+        .expect("poll")
+        .into_inner();
+    assert_eq!(poll.status, DeviceLoginStatus::Approved as i32);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn passkey_verification_requires_service_account() {
+    let fixture = fixture().await.unwrap();
+    let user = registered_user(&fixture).await;
+    add_passkey(&fixture, &user, "Laptop").await;
+
+    let err = fixture
+        .users()
+        .begin_passkey_verification(authed_request(
+            &user.access_token,
+            BeginPasskeyVerificationRequest {
+                user_id: user.user_id.clone(),
+            },
+        ))
+        .await
+        .expect_err("user token is not a service account");
+    assert_eq!(err.code(), tonic::Code::PermissionDenied);
+}
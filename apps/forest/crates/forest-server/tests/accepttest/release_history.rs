@@ -0,0 +1,140 @@
+//! Acceptance tests for release history: releases can be listed with
+//! filters and paged, and a single release shows its steps and events.
+
+use forest_grpc_interface::*;
+use tonic::metadata::MetadataValue;
+
+use crate::accepttest::fixtures::{GivenReleaseFlow, WhenReleaseFlow, testcase};
+use crate::accepttest::release_flow::ReleaseFlowData;
+
+fn authed_request<T>(token: &str, inner: T) -> tonic::Request<T> {
+    let mut req = tonic::Request::new(inner);
+    let val: MetadataValue<_> = format!("Bearer {token}").parse().expect("valid metadata");
+    req.metadata_mut().insert("authorization", val);
+    req
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn released_artifacts_are_listed_and_shown() -> anyhow::Result<()> {
+    let (given, when, then) = testcase::<ReleaseFlowData>().await?;
+    let suffix = uuid::Uuid::now_v7();
+    let org = format!("history-org-{suffix}");
+    let dest = format!("history-dest-{suffix}");
+    let env = format!("history-env-{suffix}");
+    given
+        .a_registered_user()
+        .await
+        .an_organisation(&org)
+        .await
+        .an_environment(&env)
+        .await
+        .a_destination(&dest, &env)
+        .await
+        .an_uploaded_artifact()
+        .await
+        .an_annotated_release()
+        .await;
+    when.release_is_triggered()
+        .await?
+        .release_reaches_terminal_state()
+        .await?;
+
+    let fixture = then.fixture().clone();
+    let (token, slug, intent_id, terminal_status) = {
+        let data = then.data();
+        (
+            data.auth_token.clone(),
+            data.slug.clone(),
+            data.release_intent_id.clone(),
+            data.terminal_status.clone(),
+        )
+    };
+    let mut history = fixture.release_history();
+
+    let list = |environment: Option<String>, page_token: &str| ListReleasesRequest {
+        organisation: org.clone(),
+        environment,
+        page_size: 1,
+        page_token: page_token.into(),
+        ..Default::default()
+    };
+
+    let listed = history
+        .list_releases(authed_request(&token, list(Some(env.clone()), "")))
+        .await?
+        .into_inner();
+    assert_eq!(listed.releases.len(), 1);
+    let release = &listed.releases[0];
+    assert_eq!(release.slug, slug);
+    assert_eq!(release.release_intent_id, intent_id);
+    assert_eq!(release.destinations.len(), 1);
+    assert_eq!(release.destinations[0].destination, dest);
+    assert!(listed.next_page_token.is_empty());
+    let project = release.project.clone().expect("project").project;
+
+    let other_env = history
+        .list_releases(authed_request(&token, list(Some("elsewhere".into()), "")))
+        .await?
+        .into_inner();
+    assert!(other_env.releases.is_empty());
+
+    let err = history
+        .list_releases(authed_request(&token, list(None, "not a token")))
+        .await
+        .expect_err("malformed page token");
+    assert_eq!(err.code(), tonic::Code::InvalidArgument);
+
+    let shown = history
+        .get_release(authed_request(
+            &token,
+            GetReleaseRequest {
+                slug: slug.clone(),
+                include_logs: false,
+                organisation: org.clone(),
+                project: project.clone(),
+            },
+        ))
+        .await?
+        .into_inner();
+    assert_eq!(shown.artifact.expect("artifact").slug, slug);
+    let deploy = shown
+        .attempts
+        .iter()
+        .find(|a| a.mode == "deploy")
+        .expect("deploy step");
+    assert_eq!(deploy.destination, dest);
+    assert_eq!(deploy.status, terminal_status);
+    assert!(!deploy.events.is_empty());
+    assert!(shown.policies.iter().any(|p| p.environment == env));
+
+    let err = history
+        .get_release(authed_request(
+            &token,
+            GetReleaseRequest {
+                slug: format!("missing-{suffix}"),
+                include_logs: false,
+                organisation: org.clone(),
+                project: project.clone(),
+            },
+        ))
+        .await
+        .expect_err("unknown slug");
+    assert_eq!(err.code(), tonic::Code::NotFound);
+
+    // A real slug under the wrong project looks exactly like a missing one.
+    let err = history
+        .get_release(authed_request(
+            &token,
+            GetReleaseRequest {
+                slug: slug.clone(),
+                include_logs: false,
+                organisation: org.clone(),
+                project: format!("other-{suffix}"),
+            },
+        ))
+        .await
+        .expect_err("slug in another project");
+    assert_eq!(err.code(), tonic::Code::NotFound);
+
+    Ok(())
+}
@@ -0,0 +1,297 @@
+//! Acceptance tests for scheduled releases: schedules are validated, fire
+//! through the normal release path when due, can be cancelled, and stop
+//! releasing once their creator can no longer act.
+
+use forest_grpc_interface::*;
+use forest_server::release_scheduler::ReleaseScheduler;
+use tonic::metadata::MetadataValue;
+
+use crate::accepttest::fixtures::{GivenReleaseFlow, testcase};
+use crate::accepttest::release_flow::ReleaseFlowData;
+
+fn authed_request<T>(token: &str, inner: T) -> tonic::Request<T> {
+    let mut req = tonic::Request::new(inner);
+    let val: MetadataValue<_> = format!("Bearer {token}").parse().expect("valid metadata");
+    req.metadata_mut().insert("authorization", val);
+    req
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn scheduled_releases_fire_when_due_and_can_be_cancelled() -> anyhow::Result<()> {
+    let (given, _when, _then) = testcase::<ReleaseFlowData>().await?;
+    let suffix = uuid::Uuid::now_v7();
+    let org = format!("schedule-org-{suffix}");
+    let env = format!("schedule-env-{suffix}");
+    let given = given
+        .a_registered_user()
+        .await
+        .an_organisation(&org)
+        .await
+        .an_environment(&env)
+        .await
+        .a_destination(&format!("schedule-dest-{suffix}"), &env)
+        .await
+        .an_uploaded_artifact()
+        .await
+        .an_annotated_release()
+        .await;
+    let fixture = given.fixture().clone();
+    let token = given.data().auth_token.clone();
+    let artifact_id = given.data().artifact_id.clone();
+    let project = Project {
+        organisation: org.clone(),
+        project: "test-project".into(),
+        ..Default::default()
+    };
+    let mut schedules = fixture.release_schedules();
+
+    let err = schedules
+        .create_release_schedule(authed_request(
+            &token,
+            CreateReleaseScheduleRequest {
+                project: Some(project.clone()),
+                cron: Some("0 25 * * *".into()),
+                environments: vec![env.clone()],
+                ..Default::default()
+            },
+        ))
+        .await
+        .expect_err("hour 25 is not valid");
+    assert_eq!(err.code(), tonic::Code::InvalidArgument);
+
+    let err = schedules
+        .create_release_schedule(authed_request(
+            &token,
+            CreateReleaseScheduleRequest {
+                project: Some(project.clone()),
+                run_at: Some("2001-01-01T00:00:00Z".into()),
+                environments: vec![env.clone()],
+                ..Default::default()
+            },
+        ))
+        .await
+        .expect_err("run_at must be in the future");
+    assert_eq!(err.code(), tonic::Code::InvalidArgument);
+
+    let nightly = schedules
+        .create_release_schedule(authed_request(
+            &token,
+            CreateReleaseScheduleRequest {
+                project: Some(project.clone()),
+                cron: Some("0 2 * * *".into()),
+                branch: Some("main".into()),
+                environments: vec![env.clone()],
+                ..Default::default()
+            },
+        ))
+        .await?
+        .into_inner()
+        .schedule
+        .expect("schedule");
+    assert_eq!(nightly.status(), ReleaseScheduleStatus::Active);
+    assert!(nightly.next_run_at.is_some());
+
+    let run_at = chrono::Utc::now() + chrono::Duration::seconds(1);
+    let once = schedules
+        .create_release_schedule(authed_request(
+            &token,
+            CreateReleaseScheduleRequest {
+                project: Some(project.clone()),
+                run_at: Some(run_at.to_rfc3339()),
+                artifact_id: Some(artifact_id.clone()),
+                environments: vec![env.clone()],
+                ..Default::default()
+            },
+        ))
+        .await?
+        .into_inner()
+        .schedule
+        .expect("schedule");
+
+    tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+    ReleaseScheduler::new(&fixture.state).fire_due().await?;
+
+    let listed = schedules
+        .list_release_schedules(authed_request(
+            &token,
+            ListReleaseSchedulesRequest {
+                project: Some(project.clone()),
+                include_inactive: true,
+            },
+        ))
+        .await?
+        .into_inner()
+        .schedules;
+    let fired = listed.iter().find(|s| s.id == once.id).expect("fired schedule");
+    assert_eq!(fired.status(), ReleaseScheduleStatus::Completed);
+    assert_eq!(fired.next_run_at, None);
+    let last_run = fired.last_run.as_ref().expect("last run");
+    assert_eq!(
+        last_run.outcome(),
+        ReleaseScheduleOutcome::Released,
+        "{}",
+        last_run.message
+    );
+    assert!(last_run.release_intent_id.is_some());
+
+    let cancelled = schedules
+        .cancel_release_schedule(authed_request(
+            &token,
+            CancelReleaseScheduleRequest {
+                project: Some(project.clone()),
+                id: nightly.id.clone(),
+            },
+        ))
+        .await?
+        .into_inner()
+        .schedule
+        .expect("schedule");
+    assert_eq!(cancelled.status(), ReleaseScheduleStatus::Cancelled);
+
+    let err = schedules
+        .cancel_release_schedule(authed_request(
+            &token,
+            CancelReleaseScheduleRequest {
+                project: Some(project.clone()),
+                id: nightly.id.clone(),
+            },
+        ))
+        .await
+        .expect_err("already cancelled");
+    assert_eq!(err.code(), tonic::Code::NotFound);
+
+    let active = schedules
+        .list_release_schedules(authed_request(
+            &token,
+            ListReleaseSchedulesRequest {
+                project: Some(project),
+                include_inactive: false,
+            },
+        ))
+        .await?
+        .into_inner()
+        .schedules;
+    assert!(active.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn schedules_stop_releasing_once_their_app_is_suspended() -> anyhow::Result<()> {
+    let (given, _when, _then) = testcase::<ReleaseFlowData>().await?;
+    let suffix = uuid::Uuid::now_v7();
+    let org = format!("schedule-app-org-{suffix}");
+    let env = format!("schedule-app-env-{suffix}");
+    let given = given
+        .a_registered_user()
+        .await
+        .an_organisation(&org)
+        .await
+        .an_environment(&env)
+        .await
+        .a_destination(&format!("schedule-app-dest-{suffix}"), &env)
+        .await
+        .an_uploaded_artifact()
+        .await
+        .an_annotated_release()
+        .await;
+    let fixture = given.fixture().clone();
+    let token = given.data().auth_token.clone();
+    let artifact_id = given.data().artifact_id.clone();
+    let project = Project {
+        organisation: org.clone(),
+        project: "test-project".into(),
+        ..Default::default()
+    };
+
+    let org_id = sqlx::query_scalar!("SELECT id FROM organisations WHERE name = $1", org)
+        .fetch_one(&fixture.db)
+        .await?;
+    let app = fixture
+        .apps()
+        .create_app(authed_request(
+            &token,
+            CreateAppRequest {
+                organisation_id: org_id.to_string(),
+                name: "nightly-releaser".into(),
+                description: String::new(),
+                permissions: vec![],
+            },
+        ))
+        .await?
+        .into_inner()
+        .app
+        .expect("app");
+    let app_token = fixture
+        .apps()
+        .create_app_token(authed_request(
+            &token,
+            CreateAppTokenRequest {
+                app_id: app.app_id.clone(),
+                name: "scheduler".into(),
+                expires_in_seconds: 0,
+            },
+        ))
+        .await?
+        .into_inner()
+        .raw_token;
+
+    let run_at = chrono::Utc::now() + chrono::Duration::seconds(1);
+    let schedule = fixture
+        .release_schedules()
+        .create_release_schedule(authed_request(
+            &app_token,
+            CreateReleaseScheduleRequest {
+                project: Some(project.clone()),
+                run_at: Some(run_at.to_rfc3339()),
+                artifact_id: Some(artifact_id),
+                environments: vec![env.clone()],
+                ..Default::default()
+            },
+        ))
+        .await?
+        .into_inner()
+        .schedule
+        .expect("schedule");
+
+    fixture
+        .apps()
+        .suspend_app(authed_request(
+            &token,
+            SuspendAppRequest {
+                app_id: app.app_id.clone(),
+                suspended: true,
+            },
+        ))
+        .await?;
+
+    tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+    ReleaseScheduler::new(&fixture.state).fire_due().await?;
+
+    let listed = fixture
+        .release_schedules()
+        .list_release_schedules(authed_request(
+            &token,
+            ListReleaseSchedulesRequest {
+                project: Some(project),
+                include_inactive: true,
+            },
+        ))
+        .await?
+        .into_inner()
+        .schedules;
+    let fired = listed
+        .iter()
+        .find(|s| s.id == schedule.id)
+        .expect("fired schedule");
+    let last_run = fired.last_run.as_ref().expect("last run");
+    assert_eq!(last_run.outcome(), ReleaseScheduleOutcome::Blocked);
+    assert!(
+        last_run.message.contains("suspended"),
+        "{}",
+        last_run.message
+    );
+    assert!(last_run.release_intent_id.is_none());
+
+    Ok(())
+}
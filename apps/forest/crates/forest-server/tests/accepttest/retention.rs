@@ -0,0 +1,190 @@
+//! Acceptance tests for retention: policies are validated and audited,
+//! previews match what is purged, and purged artifacts can't be released.
+
+use forest_grpc_interface::*;
+use forest_server::services::retention::RetentionRegistryState;
+use tonic::metadata::MetadataValue;
+
+use crate::accepttest::fixtures::{GivenReleaseFlow, testcase};
+use crate::accepttest::release_flow::ReleaseFlowData;
+
+fn authed_request<T>(token: &str, inner: T) -> tonic::Request<T> {
+    let mut req = tonic::Request::new(inner);
+    let val: MetadataValue<_> = format!("Bearer {token}").parse().expect("valid metadata");
+    req.metadata_mut().insert("authorization", val);
+    req
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn expired_artifacts_are_previewed_purged_and_unreleasable() -> anyhow::Result<()> {
+    let (given, _when, _then) = testcase::<ReleaseFlowData>().await?;
+    let suffix = uuid::Uuid::now_v7();
+    let org = format!("retention-org-{suffix}");
+    let env = format!("retention-env-{suffix}");
+    let given = given
+        .a_registered_user()
+        .await
+        .an_organisation(&org)
+        .await
+        .an_environment(&env)
+        .await
+        .a_destination(&format!("retention-dest-{suffix}"), &env)
+        .await
+        .an_uploaded_artifact()
+        .await
+        .an_annotated_release()
+        .await;
+    let (old_artifact, old_slug) = {
+        let data = given.data();
+        (data.artifact_id.clone(), data.slug.clone())
+    };
+    let given = given.an_uploaded_artifact().await.an_annotated_release().await;
+    let fixture = given.fixture().clone();
+    let token = given.data().auth_token.clone();
+    let mut retention = fixture.retention();
+
+    let err = retention
+        .set_retention_policy(authed_request(
+            &token,
+            SetRetentionPolicyRequest {
+                organisation: org.clone(),
+                keep_artifacts: Some(0),
+                ..Default::default()
+            },
+        ))
+        .await
+        .expect_err("keep_artifacts must be positive");
+    assert_eq!(err.code(), tonic::Code::InvalidArgument);
+
+    let policy = retention
+        .set_retention_policy(authed_request(
+            &token,
+            SetRetentionPolicyRequest {
+                organisation: org.clone(),
+                keep_artifacts: Some(1),
+                log_retention_days: Some(30),
+                notification_retention_days: None,
+            },
+        ))
+        .await?
+        .into_inner()
+        .policy
+        .expect("policy");
+    assert_eq!(policy.keep_artifacts, Some(1));
+    assert_eq!(policy.notification_retention_days, None);
+
+    let preview = retention
+        .preview_retention(authed_request(
+            &token,
+            PreviewRetentionRequest {
+                organisation: org.clone(),
+                policy: None,
+            },
+        ))
+        .await?
+        .into_inner();
+    assert_eq!(preview.artifacts.len(), 1);
+    assert_eq!(preview.artifacts[0].slug, old_slug);
+    assert_eq!(preview.artifact_files, 1);
+    assert_eq!(preview.log_blocks, 0);
+
+    let registry = fixture.state.retention_registry();
+    let stored = registry.get(&org).await?;
+    let plan = registry.plan(&stored, chrono::Utc::now()).await?;
+    let purged = registry.apply(&plan).await?;
+    assert_eq!(purged.artifacts, 1);
+    assert_eq!(purged.files, 1);
+
+    // Nothing left to purge.
+    let plan = registry.plan(&stored, chrono::Utc::now()).await?;
+    assert!(plan.is_empty());
+
+    let err = fixture
+        .releases()
+        .release(authed_request(
+            &token,
+            ReleaseRequest {
+                artifact_id: old_artifact,
+                destinations: vec![],
+                environments: vec![env.clone()],
+                force: false,
+                use_pipeline: false,
+                prepare_only: false,
+            },
+        ))
+        .await
+        .expect_err("purged artifact cannot be released");
+    assert_eq!(err.code(), tonic::Code::FailedPrecondition);
+
+    let actions = sqlx::query_scalar!(
+        "SELECT action FROM org_events WHERE organisation = $1 AND resource_type = 'retention' ORDER BY sequence",
+        org
+    )
+    .fetch_all(&fixture.db)
+    .await?;
+    assert_eq!(actions, vec!["updated"]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn artifacts_with_an_active_schedule_are_kept() -> anyhow::Result<()> {
+    let (given, _when, _then) = testcase::<ReleaseFlowData>().await?;
+    let suffix = uuid::Uuid::now_v7();
+    let org = format!("retention-sched-org-{suffix}");
+    let env = format!("retention-sched-env-{suffix}");
+    let given = given
+        .a_registered_user()
+        .await
+        .an_organisation(&org)
+        .await
+        .an_environment(&env)
+        .await
+        .a_destination(&format!("retention-sched-dest-{suffix}"), &env)
+        .await
+        .an_uploaded_artifact()
+        .await
+        .an_annotated_release()
+        .await;
+    let old_artifact = given.data().artifact_id.clone();
+    let given = given.an_uploaded_artifact().await.an_annotated_release().await;
+    let fixture = given.fixture().clone();
+    let token = given.data().auth_token.clone();
+
+    fixture
+        .release_schedules()
+        .create_release_schedule(authed_request(
+            &token,
+            CreateReleaseScheduleRequest {
+                project: Some(Project {
+                    organisation: org.clone(),
+                    project: "test-project".into(),
+                    ..Default::default()
+                }),
+                run_at: Some((chrono::Utc::now() + chrono::Duration::days(1)).to_rfc3339()),
+                artifact_id: Some(old_artifact.clone()),
+                environments: vec![env.clone()],
+                ..Default::default()
+            },
+        ))
+        .await?;
+
+    fixture
+        .retention()
+        .set_retention_policy(authed_request(
+            &token,
+            SetRetentionPolicyRequest {
+                organisation: org.clone(),
+                keep_artifacts: Some(1),
+                ..Default::default()
+            },
+        ))
+        .await?;
+
+    let registry = fixture.state.retention_registry();
+    let stored = registry.get(&org).await?;
+    let plan = registry.plan(&stored, chrono::Utc::now()).await?;
+    assert!(plan.is_empty(), "the scheduled artifact is pinned");
+
+    Ok(())
+}
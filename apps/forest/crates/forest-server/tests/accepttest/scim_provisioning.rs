@@ -0,0 +1,619 @@
+//! Acceptance tests for SCIM 2.0 provisioning: an IdP creates,
+//! deactivates and removes organisation members, and group membership
+//! drives org roles through the admin-managed group → role mappings.
+//!
+//! The SCIM router is driven in-process against the fixture's state; app
+//! tokens and role mappings go through gRPC like any other client.
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use forest_grpc_interface::*;
+use serde_json::{Value, json};
+use tonic::metadata::MetadataValue;
+use tower::ServiceExt;
+
+use crate::accepttest::fixtures::{Fixture, fixture};
+
+const PASSWORD: &str = "TestPassword123!";
+
+fn authed_request<T>(token: &str, inner: T) -> tonic::Request<T> {
+    let mut req = tonic::Request::new(inner);
+    let val: MetadataValue<_> = format!("Bearer {token}").parse().expect("valid metadata");
+    req.metadata_mut().insert("authorization", val);
+    req
+}
+
+struct TestUser {
+    user_id: String,
+    email: String,
+    access_token: String,
+}
+
+async fn registered_user(fixture: &Fixture) -> TestUser {
+    let username = format!("scim-user-{}", uuid::Uuid::now_v7());
+    let email = format!("{username}@understory.io");
+    let resp = fixture
+        .users()
+        .register(RegisterRequest {
+            username: username.clone(),
+            email: email.clone(),
+            password: PASSWORD.into(),
+        })
+        .await
+        .expect("register")
+        .into_inner();
+
+    TestUser {
+        user_id: resp.user.expect("user").user_id,
+        email,
+        access_token: resp.tokens.expect("tokens").access_token,
+    }
+}
+
+/// An admin with a fresh organisation, and a SCIM token for it.
+struct Scim {
+    admin: TestUser,
+    organisation: String,
+    organisation_id: String,
+    token: String,
+}
+
+async fn scim_setup(fixture: &Fixture) -> Scim {
+    let admin = registered_user(fixture).await;
+    let organisation = format!("scim-org-{}", uuid::Uuid::now_v7().simple());
+    let organisation_id = fixture
+        .organisations()
+        .create_organisation(authed_request(
+            &admin.access_token,
+            CreateOrganisationRequest {
+                name: organisation.clone(),
+            },
+        ))
+        .await
+        .expect("create org")
+        .into_inner()
+        .organisation_id;
+
+    let app = fixture
+        .apps()
+        .create_app(authed_request(
+            &admin.access_token,
+            CreateAppRequest {
+                organisation_id: organisation_id.clone(),
+                name: "okta".into(),
+                description: String::new(),
+                permissions: vec!["scim".into()],
+            },
+        ))
+        .await
+        .expect("create scim app")
+        .into_inner()
+        .app
+        .expect("app");
+    let token = fixture
+        .apps()
+        .create_app_token(authed_request(
+            &admin.access_token,
+            CreateAppTokenRequest {
+                app_id: app.app_id,
+                name: "provisioning".into(),
+                expires_in_seconds: 0,
+            },
+        ))
+        .await
+        .expect("create scim token")
+        .into_inner()
+        .raw_token;
+
+    Scim {
+        admin,
+        organisation,
+        organisation_id,
+        token,
+    }
+}
+
+async fn scim_call(
+    fixture: &Fixture,
+    token: &str,
+    method: &str,
+    path: &str,
+    body: Option<Value>,
+) -> (StatusCode, Value) {
+    let request = Request::builder()
+        .method(method)
+        .uri(path)
+        .header("authorization", format!("Bearer {token}"))
+        .header("content-type", "application/scim+json")
+        .body(body.map_or_else(Body::empty, |b| Body::from(b.to_string())))
+        .unwrap();
+
+    let response = forest_server::scim::scim_routes(fixture.state.clone())
+        .oneshot(request)
+        .await
+        .unwrap();
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let body = if bytes.is_empty() {
+        Value::Null
+    } else {
+        serde_json::from_slice(&bytes).unwrap()
+    };
+    (status, body)
+}
+
+async fn member_role(fixture: &Fixture, scim: &Scim, user_id: &str) -> Option<String> {
+    fixture
+        .organisations()
+        .list_members(authed_request(
+            &scim.admin.access_token,
+            ListMembersRequest {
+                organisation_id: scim.organisation_id.clone(),
+                page_size: 100,
+                page_token: String::new(),
+            },
+        ))
+        .await
+        .expect("list members")
+        .into_inner()
+        .members
+        .into_iter()
+        .find(|m| m.user_id == user_id)
+        .map(|m| m.role)
+}
+
+async fn member_actions(fixture: &Fixture, organisation: &str) -> Vec<String> {
+    sqlx::query_scalar::<_, String>(
+        "SELECT action FROM org_events WHERE organisation = $1 AND resource_type = 'member' ORDER BY sequence",
+    )
+    .bind(organisation)
+    .fetch_all(&fixture.db)
+    .await
+    .unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn scim_creates_deactivates_and_removes_members() {
+    let fixture = fixture().await.unwrap();
+    let scim = scim_setup(&fixture).await;
+    let email = format!("scim-{}@understory.io", uuid::Uuid::now_v7().simple());
+
+    let (status, user) = scim_call(
+        &fixture,
+        &scim.token,
+        "POST",
+        "/scim/v2/Users",
+        Some(json!({
+            "schemas": ["urn:ietf:params:scim:schemas:core:2.0:User"],
+            "userName": email,
+            "externalId": "okta-1",
+            "name": { "formatted": "Scim User" },
+            "emails": [{ "value": email, "primary": true }],
+            "active": true,
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{user}");
+    let user_id = user["id"].as_str().unwrap().to_string();
+    assert_eq!(user["displayName"], "Scim User");
+    assert_eq!(
+        member_role(&fixture, &scim, &user_id).await,
+        Some("member".into())
+    );
+
+    let (status, body) = scim_call(
+        &fixture,
+        &scim.token,
+        "POST",
+        "/scim/v2/Users",
+        Some(json!({ "userName": email })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(body["scimType"], "uniqueness");
+
+    let (status, list) = scim_call(
+        &fixture,
+        &scim.token,
+        "GET",
+        &format!(
+            "/scim/v2/Users?filter=userName%20eq%20%22{}%22",
+            email.to_uppercase()
+        ),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(list["totalResults"], 1);
+    assert_eq!(list["Resources"][0]["id"], user_id.as_str());
+
+    let (status, user) = scim_call(
+        &fixture,
+        &scim.token,
+        "PATCH",
+        &format!("/scim/v2/Users/{user_id}"),
+        Some(json!({
+            "schemas": ["urn:ietf:params:scim:api:messages:2.0:PatchOp"],
+            "Operations": [{ "op": "Replace", "path": "active", "value": "False" }],
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(user["active"], false);
+    assert_eq!(member_role(&fixture, &scim, &user_id).await, None);
+
+    let (status, _) = scim_call(
+        &fixture,
+        &scim.token,
+        "PATCH",
+        &format!("/scim/v2/Users/{user_id}"),
+        Some(json!({ "Operations": [{ "op": "replace", "value": { "active": true } }] })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        member_role(&fixture, &scim, &user_id).await,
+        Some("member".into())
+    );
+
+    let (status, _) = scim_call(
+        &fixture,
+        &scim.token,
+        "DELETE",
+        &format!("/scim/v2/Users/{user_id}"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    assert_eq!(member_role(&fixture, &scim, &user_id).await, None);
+
+    let (status, _) = scim_call(
+        &fixture,
+        &scim.token,
+        "GET",
+        &format!("/scim/v2/Users/{user_id}"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    assert_eq!(
+        member_actions(&fixture, &scim.organisation).await,
+        vec!["provisioned", "deactivated", "reactivated", "deprovisioned"]
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn scim_invites_existing_accounts_on_unproven_domains() {
+    let fixture = fixture().await.unwrap();
+    let scim = scim_setup(&fixture).await;
+    let existing = registered_user(&fixture).await;
+
+    // The organisation never proved it owns the email's domain, so the IdP
+    // can't take over the account.
+    let (status, user) = scim_call(
+        &fixture,
+        &scim.token,
+        "POST",
+        "/scim/v2/Users",
+        Some(json!({ "userName": existing.email, "active": true })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{user}");
+    assert_eq!(user["id"], existing.user_id.as_str());
+    assert_eq!(member_role(&fixture, &scim, &existing.user_id).await, None);
+
+    let invites = fixture
+        .organisations()
+        .list_my_invites(authed_request(
+            &existing.access_token,
+            ListMyInvitesRequest {},
+        ))
+        .await
+        .expect("list invites")
+        .into_inner()
+        .invites;
+    assert_eq!(invites.len(), 1);
+    assert_eq!(invites[0].organisation, scim.organisation);
+
+    // Nobody else can accept on the account's behalf.
+    let status = fixture
+        .organisations()
+        .accept_invite(authed_request(
+            &scim.admin.access_token,
+            AcceptInviteRequest {
+                organisation_id: scim.organisation_id.clone(),
+            },
+        ))
+        .await
+        .expect_err("admin has no invite");
+    assert_eq!(status.code(), tonic::Code::NotFound);
+
+    fixture
+        .organisations()
+        .accept_invite(authed_request(
+            &existing.access_token,
+            AcceptInviteRequest {
+                organisation_id: scim.organisation_id.clone(),
+            },
+        ))
+        .await
+        .expect("accept invite");
+    assert_eq!(
+        member_role(&fixture, &scim, &existing.user_id).await,
+        Some("member".into())
+    );
+
+    assert_eq!(
+        member_actions(&fixture, &scim.organisation).await,
+        vec!["invited", "invite_accepted"]
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn scim_group_membership_maps_to_org_roles() {
+    let fixture = fixture().await.unwrap();
+    let scim = scim_setup(&fixture).await;
+
+    fixture
+        .organisations()
+        .set_scim_group_role(authed_request(
+            &scim.admin.access_token,
+            SetScimGroupRoleRequest {
+                organisation_id: scim.organisation_id.clone(),
+                group_name: "Platform Admins".into(),
+                role: "admin".into(),
+            },
+        ))
+        .await
+        .expect("set group role");
+
+    let email = format!("scim-{}@understory.io", uuid::Uuid::now_v7().simple());
+    let (_, user) = scim_call(
+        &fixture,
+        &scim.token,
+        "POST",
+        "/scim/v2/Users",
+        Some(json!({ "userName": email })),
+    )
+    .await;
+    let user_id = user["id"].as_str().unwrap().to_string();
+    assert_eq!(
+        member_role(&fixture, &scim, &user_id).await,
+        Some("member".into())
+    );
+
+    let (status, group) = scim_call(
+        &fixture,
+        &scim.token,
+        "POST",
+        "/scim/v2/Groups",
+        Some(json!({
+            "schemas": ["urn:ietf:params:scim:schemas:core:2.0:Group"],
+            "displayName": "platform admins",
+            "members": [{ "value": user_id }],
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{group}");
+    let group_id = group["id"].as_str().unwrap().to_string();
+    assert_eq!(
+        member_role(&fixture, &scim, &user_id).await,
+        Some("admin".into())
+    );
+
+    let (status, _) = scim_call(
+        &fixture,
+        &scim.token,
+        "PATCH",
+        &format!("/scim/v2/Groups/{group_id}"),
+        Some(json!({
+            "Operations": [{ "op": "remove", "path": format!("members[value eq \"{user_id}\"]") }],
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        member_role(&fixture, &scim, &user_id).await,
+        Some("member".into())
+    );
+
+    // Groups may only reference users this organisation provisioned.
+    let outsider = registered_user(&fixture).await;
+    let (status, body) = scim_call(
+        &fixture,
+        &scim.token,
+        "PATCH",
+        &format!("/scim/v2/Groups/{group_id}"),
+        Some(json!({
+            "Operations": [{ "op": "add", "path": "members", "value": [{ "value": outsider.user_id }] }],
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["scimType"], "invalidValue");
+
+    let roles = fixture
+        .organisations()
+        .list_scim_group_roles(authed_request(
+            &scim.admin.access_token,
+            ListScimGroupRolesRequest {
+                organisation_id: scim.organisation_id.clone(),
+            },
+        ))
+        .await
+        .expect("list group roles")
+        .into_inner()
+        .group_roles;
+    assert_eq!(roles.len(), 1);
+    assert_eq!(roles[0].role, "admin");
+
+    assert!(
+        member_actions(&fixture, &scim.organisation)
+            .await
+            .contains(&"role_changed".to_string())
+    );
+}
+
+async fn set_member_role(fixture: &Fixture, scim: &Scim, user_id: &str, role: &str) {
+    // Owners aren't granted over gRPC; set the role directly.
+    sqlx::query(
+        "UPDATE organisation_members SET role = $3 WHERE organisation_id = $1 AND user_id = $2",
+    )
+    .bind(scim.organisation_id.parse::<uuid::Uuid>().unwrap())
+    .bind(user_id.parse::<uuid::Uuid>().unwrap())
+    .bind(role)
+    .execute(&fixture.db)
+    .await
+    .unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn scim_never_demotes_or_removes_the_last_owner() {
+    let fixture = fixture().await.unwrap();
+    let scim = scim_setup(&fixture).await;
+
+    fixture
+        .organisations()
+        .set_scim_group_role(authed_request(
+            &scim.admin.access_token,
+            SetScimGroupRoleRequest {
+                organisation_id: scim.organisation_id.clone(),
+                group_name: "engineers".into(),
+                role: "member".into(),
+            },
+        ))
+        .await
+        .expect("set group role");
+
+    let email = format!("scim-{}@understory.io", uuid::Uuid::now_v7().simple());
+    let (_, user) = scim_call(
+        &fixture,
+        &scim.token,
+        "POST",
+        "/scim/v2/Users",
+        Some(json!({ "userName": email })),
+    )
+    .await;
+    let user_id = user["id"].as_str().unwrap().to_string();
+    set_member_role(&fixture, &scim, &user_id, "owner").await;
+
+    // Group changes leave owners alone.
+    let (status, _) = scim_call(
+        &fixture,
+        &scim.token,
+        "POST",
+        "/scim/v2/Groups",
+        Some(json!({ "displayName": "engineers", "members": [{ "value": user_id }] })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(
+        member_role(&fixture, &scim, &user_id).await,
+        Some("owner".into())
+    );
+
+    // The last owner can be neither deactivated nor deleted.
+    let (status, body) = scim_call(
+        &fixture,
+        &scim.token,
+        "PATCH",
+        &format!("/scim/v2/Users/{user_id}"),
+        Some(json!({ "Operations": [{ "op": "replace", "path": "active", "value": false }] })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{body}");
+    assert_eq!(body["scimType"], "mutability");
+    let (status, body) = scim_call(
+        &fixture,
+        &scim.token,
+        "DELETE",
+        &format!("/scim/v2/Users/{user_id}"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{body}");
+    assert_eq!(
+        member_role(&fixture, &scim, &user_id).await,
+        Some("owner".into())
+    );
+
+    // With another owner in place, the IdP can deprovision them.
+    let (_, other) = scim_call(
+        &fixture,
+        &scim.token,
+        "POST",
+        "/scim/v2/Users",
+        Some(
+            json!({ "userName": format!("scim-{}@understory.io", uuid::Uuid::now_v7().simple()) }),
+        ),
+    )
+    .await;
+    set_member_role(&fixture, &scim, other["id"].as_str().unwrap(), "owner").await;
+    let (status, _) = scim_call(
+        &fixture,
+        &scim.token,
+        "DELETE",
+        &format!("/scim/v2/Users/{user_id}"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    assert_eq!(member_role(&fixture, &scim, &user_id).await, None);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn scim_requires_a_scim_app_token_and_admin_setup() {
+    let fixture = fixture().await.unwrap();
+    let scim = scim_setup(&fixture).await;
+
+    let (status, body) = scim_call(&fixture, "not-a-token", "GET", "/scim/v2/Users", None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    assert_eq!(body["status"], "401");
+
+    // Plain members can neither mint SCIM apps nor change role mappings.
+    let member = registered_user(&fixture).await;
+    fixture
+        .organisations()
+        .add_member(authed_request(
+            &scim.admin.access_token,
+            AddMemberRequest {
+                organisation_id: scim.organisation_id.clone(),
+                user_id: member.user_id.clone(),
+                role: "member".into(),
+            },
+        ))
+        .await
+        .expect("add member");
+
+    let err = fixture
+        .apps()
+        .create_app(authed_request(
+            &member.access_token,
+            CreateAppRequest {
+                organisation_id: scim.organisation_id.clone(),
+                name: "rogue".into(),
+                description: String::new(),
+                permissions: vec!["scim".into()],
+            },
+        ))
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), tonic::Code::PermissionDenied);
+
+    let err = fixture
+        .organisations()
+        .set_scim_group_role(authed_request(
+            &member.access_token,
+            SetScimGroupRoleRequest {
+                organisation_id: scim.organisation_id.clone(),
+                group_name: "Everyone".into(),
+                role: "admin".into(),
+            },
+        ))
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), tonic::Code::PermissionDenied);
+}
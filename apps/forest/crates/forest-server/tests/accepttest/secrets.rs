@@ -0,0 +1,221 @@
+//! Acceptance tests for encrypted secrets: only admins may write them, values
+//! are never returned or stored in plaintext, and releases see the most
+//! specific secret for their project and environment.
+
+use std::collections::HashMap;
+
+use forest_grpc_interface::*;
+use forest_server::services::secrets::SecretRegistryState;
+use tonic::metadata::MetadataValue;
+
+use crate::accepttest::fixtures::{GivenReleaseFlow, testcase};
+use crate::accepttest::release_flow::ReleaseFlowData;
+
+fn authed_request<T>(token: &str, inner: T) -> tonic::Request<T> {
+    let mut req = tonic::Request::new(inner);
+    let val: MetadataValue<_> = format!("Bearer {token}").parse().expect("valid metadata");
+    req.metadata_mut().insert("authorization", val);
+    req
+}
+
+fn scope(organisation: &str, project: &str, environment: &str) -> Option<SecretScope> {
+    Some(SecretScope {
+        organisation: organisation.into(),
+        project: project.into(),
+        environment: environment.into(),
+    })
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn secrets_are_encrypted_scoped_and_admin_only() -> anyhow::Result<()> {
+    let (given, _when, _then) = testcase::<ReleaseFlowData>().await?;
+    let suffix = uuid::Uuid::now_v7();
+    let org = format!("secrets-org-{suffix}");
+    let given = given
+        .a_registered_user()
+        .await
+        .an_organisation(&org)
+        .await
+        .an_environment("prod")
+        .await
+        .an_uploaded_artifact()
+        .await
+        .an_annotated_release()
+        .await;
+    let fixture = given.fixture().clone();
+    let token = given.data().auth_token.clone();
+    let mut secrets = fixture.secrets();
+
+    let set = |scope: Option<SecretScope>, value: &str| SetSecretRequest {
+        scope,
+        name: "DB_PASSWORD".into(),
+        value: value.into(),
+    };
+
+    let created = secrets
+        .set_secret(authed_request(&token, set(scope(&org, "", ""), "org-wide-value")))
+        .await?
+        .into_inner()
+        .secret
+        .expect("secret");
+    assert_eq!(created.version, 1);
+
+    let rotated = secrets
+        .set_secret(authed_request(&token, set(scope(&org, "", ""), "org-wide-rotated")))
+        .await?
+        .into_inner()
+        .secret
+        .expect("secret");
+    assert_eq!(rotated.version, 2);
+
+    secrets
+        .set_secret(authed_request(
+            &token,
+            set(scope(&org, "test-project", "prod"), "project-prod-value"),
+        ))
+        .await?;
+
+    let err = secrets
+        .set_secret(authed_request(&token, set(scope(&org, "", "missing"), "x")))
+        .await
+        .expect_err("unknown environment");
+    assert_eq!(err.code(), tonic::Code::NotFound);
+
+    let err = secrets
+        .set_secret(authed_request(
+            &token,
+            SetSecretRequest {
+                scope: scope(&org, "", ""),
+                name: "not a name".into(),
+                value: "x".into(),
+            },
+        ))
+        .await
+        .expect_err("invalid name");
+    assert_eq!(err.code(), tonic::Code::InvalidArgument);
+
+    // Nothing readable at rest.
+    let stored = sqlx::query_scalar!(
+        "SELECT ciphertext FROM secrets WHERE organisation = $1",
+        org
+    )
+    .fetch_all(&fixture.db)
+    .await?;
+    assert_eq!(stored.len(), 2);
+    for ciphertext in stored {
+        assert!(!String::from_utf8_lossy(&ciphertext).contains("value"));
+    }
+
+    // The most specific scope wins at release time.
+    let registry = fixture.state.secret_registry();
+    let metadata = HashMap::from([("password".to_string(), "secret://DB_PASSWORD".to_string())]);
+    let prod = registry.resolve(&org, "test-project", "prod").await?;
+    assert_eq!(prod.resolve_metadata(&metadata)?["password"], "project-prod-value");
+    let other = registry.resolve(&org, "other-project", "prod").await?;
+    assert_eq!(other.resolve_metadata(&metadata)?["password"], "org-wide-rotated");
+    let missing = HashMap::from([("token".to_string(), "secret://MISSING".to_string())]);
+    assert!(other.resolve_metadata(&missing).is_err());
+
+    // A ciphertext copied into another scope does not decrypt there.
+    sqlx::query!(
+        "UPDATE secrets AS s SET encrypted_key = o.encrypted_key, ciphertext = o.ciphertext
+         FROM secrets AS o
+         WHERE s.organisation = $1 AND s.project = 'test-project'
+           AND o.organisation = $1 AND o.project = '' AND o.environment = ''",
+        org
+    )
+    .execute(&fixture.db)
+    .await?;
+    assert!(registry.resolve(&org, "test-project", "prod").await.is_err());
+
+    // Plain members can list, but not write.
+    let member = fixture
+        .users()
+        .register(RegisterRequest {
+            username: format!("secrets-member-{suffix}"),
+            email: format!("secrets-member-{suffix}@example.com"),
+            password: "TestPassword123!".into(),
+        })
+        .await?
+        .into_inner();
+    let org_id = sqlx::query_scalar!("SELECT id FROM organisations WHERE name = $1", org)
+        .fetch_one(&fixture.db)
+        .await?;
+    fixture
+        .organisations()
+        .add_member(authed_request(
+            &token,
+            AddMemberRequest {
+                organisation_id: org_id.to_string(),
+                user_id: member.user.expect("user").user_id,
+                role: "member".into(),
+            },
+        ))
+        .await?;
+    let member_token = member.tokens.expect("tokens").access_token;
+
+    let err = secrets
+        .set_secret(authed_request(&member_token, set(scope(&org, "", ""), "x")))
+        .await
+        .expect_err("member cannot set secrets");
+    assert_eq!(err.code(), tonic::Code::PermissionDenied);
+
+    let listed = secrets
+        .list_secrets(authed_request(
+            &member_token,
+            ListSecretsRequest {
+                organisation: org.clone(),
+                project: Some(String::new()),
+                environment: None,
+            },
+        ))
+        .await?
+        .into_inner()
+        .secrets;
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].name, "DB_PASSWORD");
+    assert_eq!(listed[0].version, 2);
+
+    let err = secrets
+        .delete_secret(authed_request(
+            &token,
+            DeleteSecretRequest {
+                scope: scope(&org, "", "missing"),
+                name: "DB_PASSWORD".into(),
+            },
+        ))
+        .await
+        .expect_err("unknown environment");
+    assert_eq!(err.code(), tonic::Code::NotFound);
+
+    secrets
+        .delete_secret(authed_request(
+            &token,
+            DeleteSecretRequest {
+                scope: scope(&org, "test-project", "prod"),
+                name: "DB_PASSWORD".into(),
+            },
+        ))
+        .await?;
+    let err = secrets
+        .delete_secret(authed_request(
+            &token,
+            DeleteSecretRequest {
+                scope: scope(&org, "test-project", "prod"),
+                name: "DB_PASSWORD".into(),
+            },
+        ))
+        .await
+        .expect_err("already deleted");
+    assert_eq!(err.code(), tonic::Code::NotFound);
+
+    let actions = sqlx::query_scalar!(
+        "SELECT action FROM org_events WHERE organisation = $1 AND resource_type = 'secret' ORDER BY sequence",
+        org
+    )
+    .fetch_all(&fixture.db)
+    .await?;
+    assert_eq!(actions, vec!["created", "rotated", "created", "deleted"]);
+
+    Ok(())
+}
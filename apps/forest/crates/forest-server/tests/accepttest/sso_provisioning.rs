@@ -0,0 +1,335 @@
+//! Acceptance tests for the service-account path forage uses to sign in
+//! SSO users and provision them into their organisation just-in-time, and
+//! for organisations that enforce it.
+
+use forest_grpc_interface::*;
+use tonic::metadata::MetadataValue;
+
+use crate::accepttest::fixtures::{RESTRICTED_FIXTURE_SERVICE_ACCOUNT_KEY, restricted_fixture};
+
+fn authed_request<T>(token: &str, inner: T) -> tonic::Request<T> {
+    let mut req = tonic::Request::new(inner);
+    let val: MetadataValue<_> = format!("Bearer {token}").parse().expect("valid metadata");
+    req.metadata_mut().insert("authorization", val);
+    req
+}
+
+/// Sign a user in through the SSO provider, returning (user_id, access_token).
+async fn sso_login(fixture: &crate::accepttest::fixtures::Fixture, org: &str) -> (String, String) {
+    let id = uuid::Uuid::now_v7();
+    let resp = fixture
+        .users()
+        .o_auth_login(authed_request(
+            RESTRICTED_FIXTURE_SERVICE_ACCOUNT_KEY,
+            OAuthLoginRequest {
+                provider: OAuthProvider::OauthProviderSso as i32,
+                provider_user_id: format!("{org}:{id}"),
+                provider_email: format!("sso-{id}@understory.io"),
+                provider_display_name: "SSO User".into(),
+                provider_data_json: String::new(),
+            },
+        ))
+        .await
+        .expect("sso login")
+        .into_inner();
+
+    (
+        resp.user.expect("user").user_id,
+        resp.tokens.expect("tokens").access_token,
+    )
+}
+
+async fn member_role(
+    fixture: &crate::accepttest::fixtures::Fixture,
+    token: &str,
+    organisation_id: &str,
+    user_id: &str,
+) -> Option<String> {
+    fixture
+        .organisations()
+        .list_members(authed_request(
+            token,
+            ListMembersRequest {
+                organisation_id: organisation_id.into(),
+                page_size: 100,
+                page_token: String::new(),
+            },
+        ))
+        .await
+        .expect("list members")
+        .into_inner()
+        .members
+        .into_iter()
+        .find(|m| m.user_id == user_id)
+        .map(|m| m.role)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn service_account_provisions_sso_user_idempotently() {
+    let fixture = restricted_fixture().await.unwrap();
+    let org = format!("sso-org-{}", uuid::Uuid::now_v7().simple());
+
+    let (_, admin_token) = sso_login(&fixture, &org).await;
+    let organisation_id = fixture
+        .organisations()
+        .create_organisation(authed_request(
+            &admin_token,
+            CreateOrganisationRequest { name: org.clone() },
+        ))
+        .await
+        .expect("create org")
+        .into_inner()
+        .organisation_id;
+
+    let (user_id, user_token) = sso_login(&fixture, &org).await;
+
+    fixture
+        .organisations()
+        .add_member(authed_request(
+            RESTRICTED_FIXTURE_SERVICE_ACCOUNT_KEY,
+            AddMemberRequest {
+                organisation_id: organisation_id.clone(),
+                user_id: user_id.clone(),
+                role: "member".into(),
+            },
+        ))
+        .await
+        .expect("service account provisions member");
+    assert_eq!(
+        member_role(&fixture, &admin_token, &organisation_id, &user_id).await,
+        Some("member".into())
+    );
+
+    // A second login must not fail or change the assigned role.
+    fixture
+        .organisations()
+        .add_member(authed_request(
+            RESTRICTED_FIXTURE_SERVICE_ACCOUNT_KEY,
+            AddMemberRequest {
+                organisation_id: organisation_id.clone(),
+                user_id: user_id.clone(),
+                role: "admin".into(),
+            },
+        ))
+        .await
+        .expect("repeat provisioning is a no-op");
+    assert_eq!(
+        member_role(&fixture, &admin_token, &organisation_id, &user_id).await,
+        Some("member".into())
+    );
+
+    // Ordinary members still cannot add anyone.
+    let (other_id, _) = sso_login(&fixture, &org).await;
+    let err = fixture
+        .organisations()
+        .add_member(authed_request(
+            &user_token,
+            AddMemberRequest {
+                organisation_id,
+                user_id: other_id,
+                role: "member".into(),
+            },
+        ))
+        .await
+        .unwrap_err();
+    assert_ne!(err.code(), tonic::Code::Ok);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn sso_login_does_not_take_over_existing_account() {
+    let fixture = restricted_fixture().await.unwrap();
+    let email = format!("victim-{}@understory.io", uuid::Uuid::now_v7().simple());
+    let subject = format!("acme:{}", uuid::Uuid::now_v7());
+
+    let victim = fixture
+        .users()
+        .register(RegisterRequest {
+            username: format!("user-{}", uuid::Uuid::now_v7()),
+            email: email.clone(),
+            password: "TestPassword123!".into(),
+        })
+        .await
+        .expect("register user")
+        .into_inner();
+    let victim_id = victim.user.expect("user").user_id;
+    let victim_token = victim.tokens.expect("tokens").access_token;
+
+    // An IdP asserting the victim's address must not be let in.
+    let err = fixture
+        .users()
+        .o_auth_login(authed_request(
+            RESTRICTED_FIXTURE_SERVICE_ACCOUNT_KEY,
+            OAuthLoginRequest {
+                provider: OAuthProvider::OauthProviderSso as i32,
+                provider_user_id: "evil-org:victim".into(),
+                provider_email: email.clone(),
+                provider_display_name: "Victim".into(),
+                provider_data_json: String::new(),
+            },
+        ))
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), tonic::Code::AlreadyExists);
+
+    // Nor can a user link an SSO identity to themselves directly.
+    let err = fixture
+        .users()
+        .link_o_auth_provider(authed_request(
+            &victim_token,
+            LinkOAuthProviderRequest {
+                user_id: victim_id.clone(),
+                provider: OAuthProvider::OauthProviderSso as i32,
+                provider_user_id: subject.clone(),
+                provider_email: email.clone(),
+                provider_display_name: String::new(),
+                provider_data_json: String::new(),
+            },
+        ))
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), tonic::Code::PermissionDenied);
+
+    // Once forage links the identity on the user's behalf, it signs in.
+    fixture
+        .users()
+        .link_o_auth_provider(authed_request(
+            RESTRICTED_FIXTURE_SERVICE_ACCOUNT_KEY,
+            LinkOAuthProviderRequest {
+                user_id: victim_id.clone(),
+                provider: OAuthProvider::OauthProviderSso as i32,
+                provider_user_id: subject.clone(),
+                provider_email: email.clone(),
+                provider_display_name: String::new(),
+                provider_data_json: String::new(),
+            },
+        ))
+        .await
+        .expect("service account links sso identity");
+    let resp = fixture
+        .users()
+        .o_auth_login(authed_request(
+            RESTRICTED_FIXTURE_SERVICE_ACCOUNT_KEY,
+            OAuthLoginRequest {
+                provider: OAuthProvider::OauthProviderSso as i32,
+                provider_user_id: subject.clone(),
+                provider_email: email,
+                provider_display_name: "Victim".into(),
+                provider_data_json: String::new(),
+            },
+        ))
+        .await
+        .expect("linked sso login")
+        .into_inner();
+    assert_eq!(resp.user.expect("user").user_id, victim_id);
+}
+
+/// Sign in through the SSO provider as `subject`, returning the tokens.
+async fn sso_tokens(fixture: &crate::accepttest::fixtures::Fixture, subject: &str) -> AuthTokens {
+    fixture
+        .users()
+        .o_auth_login(authed_request(
+            RESTRICTED_FIXTURE_SERVICE_ACCOUNT_KEY,
+            OAuthLoginRequest {
+                provider: OAuthProvider::OauthProviderSso as i32,
+                provider_user_id: subject.into(),
+                provider_email: format!("sso-{}@understory.io", subject.replace(':', "-")),
+                provider_display_name: "SSO User".into(),
+                provider_data_json: String::new(),
+            },
+        ))
+        .await
+        .expect("sso login")
+        .into_inner()
+        .tokens
+        .expect("tokens")
+}
+
+async fn enforce_sso(
+    fixture: &crate::accepttest::fixtures::Fixture,
+    token: &str,
+    organisation_id: &str,
+) {
+    fixture
+        .organisations()
+        .set_sso_enforcement(authed_request(
+            token,
+            SetSsoEnforcementRequest {
+                organisation_id: organisation_id.into(),
+                enforced: true,
+            },
+        ))
+        .await
+        .expect("enforce sso");
+}
+
+async fn refresh(
+    fixture: &crate::accepttest::fixtures::Fixture,
+    refresh_token: String,
+) -> Result<tonic::Response<RefreshTokenResponse>, tonic::Status> {
+    fixture
+        .users()
+        .refresh_token(RefreshTokenRequest { refresh_token })
+        .await
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn enforcing_sso_revokes_member_sessions() {
+    let fixture = restricted_fixture().await.unwrap();
+    let org = format!("sso-org-{}", uuid::Uuid::now_v7().simple());
+    let admin_subject = format!("{org}:{}", uuid::Uuid::now_v7());
+
+    let admin = sso_tokens(&fixture, &admin_subject).await;
+    let organisation_id = fixture
+        .organisations()
+        .create_organisation(authed_request(
+            &admin.access_token,
+            CreateOrganisationRequest { name: org.clone() },
+        ))
+        .await
+        .expect("create org")
+        .into_inner()
+        .organisation_id;
+
+    let username = format!("user-{}", uuid::Uuid::now_v7());
+    let member = fixture
+        .users()
+        .register(RegisterRequest {
+            username: username.clone(),
+            email: format!("{username}@understory.io"),
+            password: "TestPassword123!".into(),
+        })
+        .await
+        .expect("register user")
+        .into_inner();
+    fixture
+        .organisations()
+        .add_member(authed_request(
+            RESTRICTED_FIXTURE_SERVICE_ACCOUNT_KEY,
+            AddMemberRequest {
+                organisation_id: organisation_id.clone(),
+                user_id: member.user.expect("user").user_id,
+                role: "member".into(),
+            },
+        ))
+        .await
+        .expect("add member");
+
+    enforce_sso(&fixture, &admin.access_token, &organisation_id).await;
+
+    // Sessions from before enforcement can't be renewed, whatever the login.
+    let err = refresh(&fixture, member.tokens.expect("tokens").refresh_token)
+        .await
+        .expect_err("password session is revoked");
+    assert_eq!(err.code(), tonic::Code::Unauthenticated);
+    let err = refresh(&fixture, admin.refresh_token)
+        .await
+        .expect_err("every member signs in again");
+    assert_eq!(err.code(), tonic::Code::Unauthenticated);
+
+    // Saving the setting again leaves new SSO sessions alone.
+    let admin = sso_tokens(&fixture, &admin_subject).await;
+    enforce_sso(&fixture, &admin.access_token, &organisation_id).await;
+    refresh(&fixture, admin.refresh_token)
+        .await
+        .expect("sso session still refreshes");
+}
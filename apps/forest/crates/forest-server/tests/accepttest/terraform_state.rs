@@ -0,0 +1,214 @@
+//! Acceptance tests for the terraform http backend: state is versioned in
+//! Postgres, locks record their holder, and admins can force-unlock and roll
+//! back through the destination service.
+
+use forest_grpc_interface::*;
+use forest_server::destinations::terraformv1::{
+    TerraformStateStore, TerraformStateStoreState, TerraformV1ServerState,
+};
+use tonic::metadata::MetadataValue;
+
+use crate::accepttest::fixtures::{GivenReleaseFlow, testcase};
+use crate::accepttest::release_flow::ReleaseFlowData;
+
+fn authed_request<T>(token: &str, inner: T) -> tonic::Request<T> {
+    let mut req = tonic::Request::new(inner);
+    let val: MetadataValue<_> = format!("Bearer {token}").parse().expect("valid metadata");
+    req.metadata_mut().insert("authorization", val);
+    req
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn terraform_state_is_versioned_locked_and_rolled_back() -> anyhow::Result<()> {
+    let (given, _when, _then) = testcase::<ReleaseFlowData>().await?;
+    let suffix = uuid::Uuid::now_v7();
+    let org = format!("tfstate-org-{suffix}");
+    let given = given
+        .a_registered_user()
+        .await
+        .an_organisation(&org)
+        .await
+        .an_environment("prod")
+        .await
+        .an_uploaded_artifact()
+        .await
+        .an_annotated_release()
+        .await;
+    let fixture = given.fixture().clone();
+    let token = given.data().auth_token.clone();
+
+    let project_id = sqlx::query_scalar!(
+        "SELECT id FROM projects WHERE organisation = $1 AND project = 'test-project'",
+        org
+    )
+    .fetch_one(&fixture.db)
+    .await?;
+    let store = fixture.state.terraform_state_store();
+    let state_id = TerraformStateStore::state_id_for("prod", &project_id.to_string());
+    let release_id = uuid::Uuid::now_v7();
+    let (_, password) = store
+        .release_credentials(state_id.clone(), &release_id)
+        .await?;
+    let (_, other_password) = store
+        .release_credentials(state_id.clone(), &uuid::Uuid::now_v7())
+        .await?;
+    let (_, secret) = store.urls(state_id.clone()).await?;
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+    let addr = listener.local_addr()?;
+    drop(listener);
+    let cancel = tokio_util::sync::CancellationToken::new();
+    let server = fixture.state.terraform_v1_server(addr);
+    {
+        let cancel = cancel.clone();
+        tokio::spawn(async move { server.serve(cancel).await });
+    }
+
+    let base = format!("http://{addr}/{state_id}/releases/{release_id}");
+    let http = reqwest::Client::new();
+    let lock = |id: &'static str, password: String| {
+        http.post(format!("{base}/lock"))
+            .basic_auth("forest-terraform-v1", Some(password))
+            .header("content-type", "application/json")
+            .body(
+                serde_json::json!({"ID": id, "Who": "ci@runner", "Operation": "OperationTypeApply"})
+                    .to_string(),
+            )
+            .send()
+    };
+
+    // Wait for the listener.
+    let mut status = None;
+    for _ in 0..50 {
+        if let Ok(resp) = lock("lock-1", "wrong".into()).await {
+            status = Some(resp.status());
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    }
+    assert_eq!(status, Some(reqwest::StatusCode::UNAUTHORIZED));
+
+    // A release's URL only takes that release's password, so the release
+    // recorded with the lock can't be claimed by anyone else.
+    for other in [secret, other_password] {
+        assert_eq!(
+            lock("lock-1", other).await?.status(),
+            reqwest::StatusCode::UNAUTHORIZED
+        );
+    }
+
+    assert!(
+        lock("lock-1", password.clone())
+            .await?
+            .status()
+            .is_success()
+    );
+    let held = lock("lock-2", password.clone()).await?;
+    assert_eq!(held.status(), reqwest::StatusCode::CONFLICT);
+    assert!(held.text().await?.contains(&release_id.to_string()));
+
+    for state in ["{\"serial\":1}", "{\"serial\":2}", "{\"serial\":2}"] {
+        let resp = http
+            .post(format!("{base}?ID=lock-1"))
+            .basic_auth("forest-terraform-v1", Some(password.clone()))
+            .body(state)
+            .send()
+            .await?;
+        assert!(resp.status().is_success());
+    }
+
+    let mut destinations = fixture.destinations();
+    let states = destinations
+        .list_terraform_states(authed_request(
+            &token,
+            ListTerraformStatesRequest {
+                organisation: org.clone(),
+                project: "test-project".into(),
+            },
+        ))
+        .await?
+        .into_inner()
+        .states;
+    assert_eq!(states.len(), 1);
+    assert_eq!(states[0].current_version, 2, "identical writes are not versioned");
+    let lock_info = states[0].lock.clone().expect("locked");
+    assert_eq!(lock_info.who, "ci@runner");
+    assert_eq!(lock_info.release_id, Some(release_id.to_string()));
+
+    let err = destinations
+        .rollback_terraform_state(authed_request(
+            &token,
+            RollbackTerraformStateRequest {
+                organisation: org.clone(),
+                project: "test-project".into(),
+                environment: "prod".into(),
+                version: 1,
+            },
+        ))
+        .await
+        .expect_err("locked state cannot be rolled back");
+    assert_eq!(err.code(), tonic::Code::FailedPrecondition);
+
+    let released = destinations
+        .force_unlock_terraform_state(authed_request(
+            &token,
+            ForceUnlockTerraformStateRequest {
+                organisation: org.clone(),
+                project: "test-project".into(),
+                environment: "prod".into(),
+            },
+        ))
+        .await?
+        .into_inner()
+        .released_lock
+        .expect("lock was held");
+    assert_eq!(released.lock_id, "lock-1");
+
+    let version = destinations
+        .rollback_terraform_state(authed_request(
+            &token,
+            RollbackTerraformStateRequest {
+                organisation: org.clone(),
+                project: "test-project".into(),
+                environment: "prod".into(),
+                version: 1,
+            },
+        ))
+        .await?
+        .into_inner()
+        .version
+        .expect("version");
+    assert_eq!(version.version, 3);
+    assert_eq!(version.rolled_back_from, Some(1));
+
+    let current = http
+        .get(&base)
+        .basic_auth("forest-terraform-v1", Some(password.clone()))
+        .send()
+        .await?
+        .text()
+        .await?;
+    assert_eq!(current, "{\"serial\":1}");
+
+    let versions = destinations
+        .list_terraform_state_versions(authed_request(
+            &token,
+            ListTerraformStateVersionsRequest {
+                organisation: org.clone(),
+                project: "test-project".into(),
+                environment: "prod".into(),
+            },
+        ))
+        .await?
+        .into_inner()
+        .versions;
+    assert_eq!(
+        versions.iter().map(|v| v.version).collect::<Vec<_>>(),
+        vec![3, 2, 1]
+    );
+    assert_eq!(versions[2].release_id, Some(release_id.to_string()));
+
+    cancel.cancel();
+
+    Ok(())
+}
@@ -40,6 +40,7 @@ mod organisation;
 mod project;
 mod release;
 mod run;
+mod secret;
 mod self_cmd;
 mod shell;
 mod template;
@@ -104,6 +105,8 @@ enum Commands {
     Organisation(OrganisationCommand),
     /// Manage and listen for notifications
     Notifications(NotificationsCommand),
+    /// Manage encrypted secrets referenced from destination metadata
+    Secret(secret::SecretCommand),
 
     // ── Component registry ──
     /// Browse and manage components (list, init)
@@ -253,15 +256,17 @@ fn maybe_print_context_banner(cli: &Command) {
 /// stay quiet.
 fn is_server_mutation(command: &Commands) -> bool {
     match command {
-        // Always mutates: pushes an artifact, drives a release.
-        Commands::Publish(_) | Commands::Release(_) => true,
+        // Always mutates: pushes an artifact.
+        Commands::Publish(_) => true,
         // Subcommand-mixed: drill in to skip read-only variants.
+        Commands::Release(cmd) => cmd.is_mutation(),
         Commands::Auth(cmd) => cmd.is_mutation(),
         Commands::Project(cmd) => cmd.is_mutation(),
         Commands::Destination(cmd) => cmd.is_mutation(),
         Commands::Environment(cmd) => cmd.is_mutation(),
         Commands::Organisation(cmd) => cmd.is_mutation(),
         Commands::Notifications(cmd) => cmd.is_mutation(),
+        Commands::Secret(cmd) => cmd.is_mutation(),
         // Local-only or read-only.
         Commands::Init(_)
         | Commands::Add(_)
@@ -357,6 +362,7 @@ impl CommandHandler {
             Commands::Environment(cmd) => cmd.execute(state).await,
             Commands::Organisation(cmd) => cmd.execute(state).await,
             Commands::Notifications(cmd) => cmd.execute(state).await,
+            Commands::Secret(cmd) => cmd.execute(state).await,
             Commands::Components(cmd) => cmd.execute(state).await,
             Commands::Docs(cmd) => cmd.execute(state).await,
             Commands::Admin(cmd) => cmd.execute(state).await,
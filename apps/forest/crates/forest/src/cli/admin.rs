@@ -1,10 +1,12 @@
 use deploy_components::DeployComponentCommand;
 use get_component::GetComponentCommand;
+use runners::RunnersCommand;
 
 use crate::state::State;
 
 mod deploy_components;
 mod get_component;
+mod runners;
 
 #[derive(clap::Parser)]
 #[command(subcommand_required = true, hide(true))]
@@ -17,6 +19,8 @@ pub struct AdminCommand {
 enum Commands {
     DeployComponent(DeployComponentCommand),
     GetComponent(GetComponentCommand),
+    /// List connected runners with their labels, load and heartbeat age
+    Runners(RunnersCommand),
 }
 
 impl Commands {
@@ -26,6 +30,7 @@ impl Commands {
             Commands::GetComponent(get_component_command) => {
                 get_component_command.execute(state).await
             }
+            Commands::Runners(cmd) => cmd.execute(state).await,
         }
     }
 }
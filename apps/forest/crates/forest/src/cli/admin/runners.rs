@@ -0,0 +1,88 @@
+use anyhow::Context;
+use serde::Serialize;
+use tabled::Tabled;
+
+use crate::{
+    cli::output::{self, OutputFormat},
+    grpc::GrpcClientState,
+    state::State,
+};
+
+#[derive(clap::Parser, Debug)]
+pub struct RunnersCommand {
+    /// Only show runners that can take work for this organisation.
+    /// Without it, all runners are listed (service accounts only).
+    #[arg(long, short = 'o')]
+    organisation: Option<String>,
+}
+
+#[derive(Tabled, Serialize)]
+struct RunnerRow {
+    #[tabled(rename = "Runner")]
+    runner_id: String,
+    #[tabled(rename = "Scope")]
+    scope: String,
+    #[tabled(rename = "Pool")]
+    pool: String,
+    #[tabled(rename = "Labels")]
+    labels: String,
+    #[tabled(rename = "Load")]
+    load: String,
+    #[tabled(rename = "Heartbeat")]
+    heartbeat: String,
+    #[tabled(rename = "Destinations")]
+    destinations: String,
+}
+
+impl RunnersCommand {
+    pub async fn execute(&self, state: &State) -> anyhow::Result<()> {
+        let runners = state
+            .grpc_client()
+            .list_runners(self.organisation.as_deref().unwrap_or_default())
+            .await
+            .context("failed to list runners")?;
+
+        let format = &state.config.format;
+        if runners.is_empty() {
+            match format {
+                OutputFormat::Json => println!("[]"),
+                _ => eprintln!("No runners connected"),
+            }
+            return Ok(());
+        }
+
+        let rows: Vec<RunnerRow> = runners
+            .into_iter()
+            .map(|r| {
+                let scope = match (r.organisation, r.project) {
+                    (Some(org), Some(project)) => format!("{org}/{project}"),
+                    (Some(org), None) => org,
+                    _ => "shared".into(),
+                };
+
+                let mut labels: Vec<String> =
+                    r.labels.iter().map(|(k, v)| format!("{k}={v}")).collect();
+                labels.sort();
+
+                RunnerRow {
+                    runner_id: r.runner_id,
+                    scope,
+                    pool: r.pool,
+                    labels: labels.join(","),
+                    load: format!("{}/{}", r.active_releases, r.max_concurrent),
+                    heartbeat: format!("{}s ago", r.heartbeat_age_seconds),
+                    destinations: r
+                        .capabilities
+                        .iter()
+                        .map(|c| format!("{}/{}@{}", c.organisation, c.name, c.version))
+                        .collect::<Vec<_>>()
+                        .join(","),
+                }
+            })
+            .collect();
+
+        print!("{}", output::render(format, &rows));
+
+        Ok(())
+    }
+}
@@ -12,6 +12,7 @@ pub(crate) mod init;
 mod list;
 pub(crate) mod publish;
 mod show;
+pub(crate) mod workspace;
 
 /// Browse and manage components in the registry.
 #[derive(clap::Parser)]
@@ -1,10 +1,12 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::{Path, PathBuf};
 
 use anyhow::Context;
+use futures::StreamExt;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 
+use crate::services::build_cache::{self, BuildCache, DependencyInputs, TargetInputs};
 use crate::state::State;
 
 /// Build the component binary for all configured platforms.
@@ -14,48 +16,60 @@ use crate::state::State;
 /// or Docker), stores it in the content-addressable cache, and caches
 /// the component descriptor for fast command discovery.
 ///
+/// Targets whose inputs (sources, lockfiles, local path dependencies,
+/// CUE specs, toolchain version) are unchanged since their last build are
+/// skipped; the rest are built concurrently, except Rust targets, which
+/// share one cargo target directory and build one at a time.
+///
+/// Run from a project directory instead, it builds every local path
+/// dependency in dependency order (see `workspace`).
+///
 /// Output: ~/.cache/forest/components/bin/{hash}
 /// Metadata: ~/.cache/forest/components/<org>/<name>/<version>/.forest/component/meta.json
-#[derive(clap::Parser)]
-pub struct BuildCommand {}
+#[derive(clap::Parser, Default)]
+pub struct BuildCommand {
+    /// Rebuild every target, ignoring the build cache
+    #[arg(long)]
+    force: bool,
+
+    /// How many targets to build at once (defaults to the number of CPUs;
+    /// Rust targets always build one at a time)
+    #[arg(long, short = 'j')]
+    jobs: Option<usize>,
+}
+
+/// What building a single component did, for the workspace summary.
+pub(crate) enum BuildOutcome {
+    Prebuilt,
+    Deno,
+    Targets { built: usize, up_to_date: usize },
+}
 
 impl BuildCommand {
     pub async fn execute(&self, state: &State) -> anyhow::Result<()> {
-        // Try forest.component.cue first (new SDK pattern), fall back to spec.cue (legacy)
-        let cue_files = if std::path::Path::new("forest.component.cue").exists() {
-            vec!["./forest.cue", "./forest.component.cue"]
-        } else {
-            vec!["./forest.cue", "./spec.cue"]
-        };
-        let output = crate::tools::cue::output(|| {
-            let mut cmd = tokio::process::Command::new("cue");
-            cmd.arg("export");
-            for f in &cue_files {
-                cmd.arg(f);
-            }
-            cmd.args(["--out", "json"]);
-            if let Ok(registry) = std::env::var("CUE_REGISTRY") {
-                cmd.env("CUE_REGISTRY", registry);
-            }
-            cmd
-        })
-        .await?;
-        let stdout = String::from_utf8(output.stdout)?;
-        let stderr = String::from_utf8(output.stderr)?;
-
-        if !output.status.success() {
-            if stderr.contains("no such file or directory") || stderr.contains("does not exist") {
-                anyhow::bail!(
-                    "no forest.cue or spec.cue found in current directory.\n\
-                     Are you in a component directory? Run `forest components init <name>` to create one."
-                );
-            }
-            anyhow::bail!("failed to evaluate CUE spec:\n{stderr}");
+        let (doc, cue_files) = evaluate_spec(Path::new(".")).await?;
+
+        if doc.component().is_none() && !doc.local_dependencies().is_empty() {
+            return super::workspace::build_workspace(state, &std::env::current_dir()?, self)
+                .await;
         }
 
-        let doc: Document = serde_json::from_str(stdout.trim())?;
+        self.build_component(state, &std::env::current_dir()?, &doc, &cue_files)
+            .await?;
+        Ok(())
+    }
 
-        let Some(component) = &doc.forest.as_ref().and_then(|f| f.component.as_ref()) else {
+    /// Build the component in `dir` from its evaluated spec. Sources,
+    /// codegen and outputs resolve against `dir`, never the process's
+    /// working directory.
+    pub(crate) async fn build_component(
+        &self,
+        state: &State,
+        dir: &Path,
+        doc: &Document,
+        cue_files: &[&str],
+    ) -> anyhow::Result<BuildOutcome> {
+        let Some(component) = doc.component() else {
             anyhow::bail!("cannot build when no forest.component section is set");
         };
 
@@ -76,33 +90,16 @@ impl BuildCommand {
                 "component '{}' uses upload.type=prebuilt — skipping build",
                 component.name,
             );
-            return Ok(());
+            return Ok(BuildOutcome::Prebuilt);
+        }
+
+        // Generated code is a build input, so refresh it before hashing.
+        if let Some(codegen) = &component.codegen {
+            regenerate_if_stale(state, dir, codegen).await?;
         }
 
-        // Deno/TypeScript components: auto-run codegen if stale, then generate meta.json
+        // Deno/TypeScript components: generate meta.json
         if matches!(upload.source_type, SourceType::Deno | SourceType::Typescript) {
-            // Auto-run codegen if forest.component.cue is newer than forestgen output
-            if let Some(codegen) = &component.codegen {
-                let spec_path = std::env::current_dir()?.join("forest.component.cue");
-                let gen_path = std::path::PathBuf::from(&codegen.output).join("forestgen.ts");
-                let needs_codegen = match (spec_path.metadata(), gen_path.metadata()) {
-                    (Ok(spec_meta), Ok(gen_meta)) => {
-                        spec_meta.modified().ok() > gen_meta.modified().ok()
-                    }
-                    (Ok(_), Err(_)) => true, // forestgen.ts doesn't exist
-                    _ => false,
-                };
-                if needs_codegen {
-                    tracing::info!(
-                        "forest.component.cue is newer than forestgen.ts — regenerating codegen"
-                    );
-                    let generate = super::generate::GenerateCommand {
-                        output: Some(std::path::PathBuf::from(&codegen.output)),
-                        language: None,
-                    };
-                    generate.execute(state).await?;
-                }
-            }
             let entrypoint = upload.source.join("main.ts");
             tracing::info!(
                 "deno component '{}' — generating meta.json",
@@ -111,7 +108,7 @@ impl BuildCommand {
 
             // Run _meta/describe to get the descriptor
             let descriptor = crate::services::component_deno::describe_deno_component(
-                &std::env::current_dir()?,
+                dir,
                 &entrypoint.to_string_lossy(),
             )
             .await
@@ -143,7 +140,7 @@ impl BuildCommand {
                 "meta.json generated for deno component at {}",
                 meta_dir.display()
             );
-            return Ok(());
+            return Ok(BuildOutcome::Deno);
         }
 
         let architectures = upload
@@ -163,30 +160,111 @@ impl BuildCommand {
             component.name,
         );
 
+        let source = dir.join(&upload.source);
+        let cache_path = BuildCache::path(&output_base_dir(dir));
+        let mut cache = BuildCache::load(&cache_path);
+        let sources = build_cache::hash_sources(&source)
+            .context("failed to hash component sources")?;
+        let specs = cue_files
+            .iter()
+            .map(|f| std::fs::read_to_string(dir.join(f)).with_context(|| format!("read {f}")))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let toolchain = toolchain_version(&upload.source_type, &source).await;
+        // Without them nothing can be trusted to be up to date.
+        let dependencies = match dependency_hash(&upload.source_type, &source).await {
+            Ok(hash) => Some(hash),
+            Err(e) => {
+                tracing::warn!("could not resolve build dependencies, rebuilding all targets: {e:#}");
+                None
+            }
+        };
+
+        let mut stale = Vec::new();
         for target in &targets {
-            tracing::info!("building {}/{} ...", target.os, target.arch);
+            let platform = format!("{}_{}", target.os, target.arch);
+            let input_hash = TargetInputs {
+                sources: &sources,
+                dependencies: dependencies.as_deref().unwrap_or_default(),
+                specs: &specs,
+                toolchain: &toolchain,
+                platform: &platform,
+                name: &component.name,
+                version: &component.version,
+            }
+            .hash();
+            let output = output_dir(dir, &target.os, &target.arch)?
+                .join(output_filename(&component.name, target));
 
-            match upload.source_type {
-                SourceType::Rust => {
-                    build_rust(state, component, &upload.source, target).await?;
-                }
-                SourceType::Golang => {
-                    build_golang(state, component, &upload.source, target).await?;
-                }
-                SourceType::Docker => {
-                    build_docker(state, component, &upload.source, target).await?;
-                }
-                SourceType::Deno | SourceType::Typescript | SourceType::Prebuilt => unreachable!(),
+            if !self.force
+                && dependencies.is_some()
+                && cache.is_fresh(&platform, &input_hash, &output)
+            {
+                tracing::info!("{}/{} is up to date", target.os, target.arch);
+                continue;
             }
+            stale.push((target, platform, input_hash, output));
+        }
+
+        // The shared builder must exist before parallel builds race to create it.
+        if matches!(upload.source_type, SourceType::Docker) && !stale.is_empty() {
+            ensure_buildx_builder().await?;
         }
 
-        generate_checksums(&component.name, &targets)?;
+        // Concurrent cargo builds would only queue on the target
+        // directory's lock, and cargo already builds crates in parallel.
+        let jobs = match upload.source_type {
+            SourceType::Rust => 1,
+            _ => self
+                .jobs
+                .unwrap_or_else(|| {
+                    std::thread::available_parallelism()
+                        .map(|n| n.get())
+                        .unwrap_or(1)
+                })
+                .max(1),
+        };
+
+        // Collected up front: a lazily mapped iterator held across the await
+        // trips the compiler's higher-ranked lifetime checks.
+        let builds: Vec<_> = stale
+            .iter()
+            .map(|(target, platform, input_hash, output)| async move {
+                let result = build_target(state, dir, component, upload, target).await;
+                (platform, input_hash, output, result)
+            })
+            .collect();
+        let results: Vec<_> = futures::stream::iter(builds)
+            .buffer_unordered(jobs)
+            .collect()
+            .await;
+
+        // Record what did build even if another target failed, so the
+        // next run only retries the failures.
+        let mut failures = Vec::new();
+        for (platform, input_hash, output, result) in results {
+            match result {
+                Ok(()) => cache.record(platform, input_hash, output)?,
+                Err(e) => failures.push(format!("{platform}: {e:#}")),
+            }
+        }
+        cache.save(&cache_path)?;
+
+        if !failures.is_empty() {
+            failures.sort();
+            anyhow::bail!(
+                "{} target(s) failed to build:\n  {}",
+                failures.len(),
+                failures.join("\n  ")
+            );
+        }
+
+        generate_checksums(dir, &component.name, &targets)?;
 
         // Store built binaries in content-addressable cache and write meta.json
         let mut platforms = serde_json::Map::new();
 
         for target in &targets {
-            let src = output_dir(&target.os, &target.arch)?
+            let src = output_dir(dir, &target.os, &target.arch)?
                 .join(output_filename(&component.name, target));
             let binary_content = std::fs::read(&src)
                 .with_context(|| format!("read built binary {}", src.display()))?;
@@ -262,10 +340,192 @@ impl BuildCommand {
         )?;
 
         tracing::info!("all targets built successfully");
-        Ok(())
+        Ok(BuildOutcome::Targets {
+            built: stale.len(),
+            up_to_date: targets.len() - stale.len(),
+        })
     }
 }
 
+/// Evaluate the CUE spec in `dir`: `forest.cue`, plus `forest.component.cue`
+/// (new SDK pattern) or `spec.cue` (legacy) when present. Returns the
+/// document and the files it was read from, relative to `dir`.
+pub(crate) async fn evaluate_spec(dir: &Path) -> anyhow::Result<(Document, Vec<&'static str>)> {
+    let mut cue_files = vec!["./forest.cue"];
+    if dir.join("forest.component.cue").exists() {
+        cue_files.push("./forest.component.cue");
+    } else if dir.join("spec.cue").exists() {
+        cue_files.push("./spec.cue");
+    }
+
+    let output = crate::tools::cue::output(|| {
+        let mut cmd = tokio::process::Command::new("cue");
+        cmd.arg("export");
+        for f in &cue_files {
+            cmd.arg(f);
+        }
+        cmd.args(["--out", "json"]);
+        cmd.current_dir(dir);
+        if let Ok(registry) = std::env::var("CUE_REGISTRY") {
+            cmd.env("CUE_REGISTRY", registry);
+        }
+        cmd
+    })
+    .await?;
+    let stdout = String::from_utf8(output.stdout)?;
+    let stderr = String::from_utf8(output.stderr)?;
+
+    if !output.status.success() {
+        if stderr.contains("no such file or directory") || stderr.contains("does not exist") {
+            anyhow::bail!(
+                "no forest.cue found in {}.\n\
+                 Are you in a component directory? Run `forest components init <name>` to create one.",
+                dir.display()
+            );
+        }
+        anyhow::bail!("failed to evaluate CUE spec:\n{stderr}");
+    }
+
+    Ok((serde_json::from_str(stdout.trim())?, cue_files))
+}
+
+async fn build_target(
+    state: &State,
+    dir: &Path,
+    component: &Component,
+    upload: &Upload,
+    target: &BuildTarget,
+) -> anyhow::Result<()> {
+    tracing::info!("building {}/{} ...", target.os, target.arch);
+
+    let source = dir.join(&upload.source);
+    let out_dir = output_dir(dir, &target.os, &target.arch)?;
+    match upload.source_type {
+        SourceType::Rust => build_rust(state, component, &source, &out_dir, target).await,
+        SourceType::Golang => build_golang(state, component, &source, &out_dir, target).await,
+        SourceType::Docker => build_docker(state, component, &source, &out_dir, target).await,
+        SourceType::Deno | SourceType::Typescript | SourceType::Prebuilt => unreachable!(),
+    }
+}
+
+/// Re-run `forest generate` when the CUE spec is newer than the generated
+/// code, or the generated code is missing.
+async fn regenerate_if_stale(state: &State, dir: &Path, codegen: &Codegen) -> anyhow::Result<()> {
+    let filename = match codegen.source_type {
+        SourceType::Rust => "forestgen.rs",
+        SourceType::Deno | SourceType::Typescript => "forestgen.ts",
+        // No generator for these.
+        SourceType::Golang | SourceType::Docker | SourceType::Prebuilt => return Ok(()),
+    };
+
+    let spec_path = dir.join("forest.component.cue");
+    let gen_path = dir.join(&codegen.output).join(filename);
+    let needs_codegen = match (spec_path.metadata(), gen_path.metadata()) {
+        (Ok(spec_meta), Ok(gen_meta)) => spec_meta.modified().ok() > gen_meta.modified().ok(),
+        (Ok(_), Err(_)) => true, // generated file doesn't exist
+        _ => false,
+    };
+    if !needs_codegen {
+        return Ok(());
+    }
+
+    tracing::info!("forest.component.cue is newer than {filename} — regenerating codegen");
+    let generate = super::generate::GenerateCommand {
+        output: Some(PathBuf::from(&codegen.output)),
+        language: None,
+    };
+    generate.generate(state, dir).await
+}
+
+/// Version of the compiler or builder, so upgrading it invalidates the
+/// build cache. Unknown versions still hash consistently; if the tool is
+/// missing, the build itself reports it.
+async fn toolchain_version(source_type: &SourceType, source: &Path) -> String {
+    let (program, args): (&str, &[&str]) = match source_type {
+        SourceType::Rust => ("rustc", &["+nightly", "-vV"]),
+        SourceType::Golang => ("go", &["version"]),
+        SourceType::Docker => ("docker", &["buildx", "version"]),
+        SourceType::Deno | SourceType::Typescript | SourceType::Prebuilt => return String::new(),
+    };
+
+    match tokio::process::Command::new(program)
+        .args(args)
+        .current_dir(source)
+        .output()
+        .await
+    {
+        Ok(output) if output.status.success() => {
+            String::from_utf8_lossy(&output.stdout).trim().to_string()
+        }
+        _ => "unknown".to_string(),
+    }
+}
+
+/// Hash of what the build reads from outside `source`; see
+/// [`DependencyInputs`]. Docker builds only see their context, which is
+/// `source` itself.
+async fn dependency_hash(source_type: &SourceType, source: &Path) -> anyhow::Result<String> {
+    let inputs = match source_type {
+        SourceType::Rust => {
+            let metadata = command_output(source, "cargo", &["metadata", "--format-version", "1"])
+                .await?;
+            let manifest = source
+                .join("Cargo.toml")
+                .canonicalize()
+                .context("find Cargo.toml")?;
+            DependencyInputs::from_cargo_metadata(&metadata, &manifest)?
+        }
+        SourceType::Golang => {
+            let list = command_output(
+                source,
+                "go",
+                &["list", "-deps", "-f", "{{if not .Standard}}{{.Dir}}{{end}}", "."],
+            )
+            .await?;
+            let env = command_output(source, "go", &["env", "GOMOD", "GOWORK", "GOMODCACHE"]).await?;
+            let mut env = env.lines().map(str::trim);
+            let (go_mod, go_work, mod_cache) = (
+                env.next().unwrap_or_default(),
+                env.next().unwrap_or_default(),
+                env.next().unwrap_or_default(),
+            );
+            anyhow::ensure!(!go_mod.is_empty(), "not in a go module");
+            DependencyInputs::from_go_list(
+                &list,
+                &source.canonicalize().context("resolve source")?,
+                Path::new(go_mod),
+                Some(Path::new(go_work)).filter(|w| !w.as_os_str().is_empty() && *w != Path::new("off")),
+                Path::new(mod_cache),
+            )
+        }
+        SourceType::Docker
+        | SourceType::Deno
+        | SourceType::Typescript
+        | SourceType::Prebuilt => DependencyInputs::default(),
+    };
+
+    inputs.hash()
+}
+
+/// Run `program` in `dir` and return its stdout, failing on a non-zero exit.
+async fn command_output(dir: &Path, program: &str, args: &[&str]) -> anyhow::Result<String> {
+    let output = tokio::process::Command::new(program)
+        .args(args)
+        .current_dir(dir)
+        .stderr(std::process::Stdio::piped())
+        .output()
+        .await
+        .with_context(|| format!("run {program}"))?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "{program} {} failed: {}",
+            args.first().unwrap_or(&""),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8(output.stdout)?)
+}
+
 #[derive(Debug)]
 struct BuildTarget {
     os: String,
@@ -361,13 +621,12 @@ fn resolve_targets(
     Ok(targets)
 }
 
-fn output_base_dir() -> anyhow::Result<PathBuf> {
-    let cur_dir = std::env::current_dir()?;
-    Ok(cur_dir.join(".forest/component/output"))
+fn output_base_dir(component_dir: &Path) -> PathBuf {
+    component_dir.join(".forest/component/output")
 }
 
-fn output_dir(os: &str, arch: &str) -> anyhow::Result<PathBuf> {
-    let dir = output_base_dir()?.join(format!("{os}/{arch}/"));
+fn output_dir(component_dir: &Path, os: &str, arch: &str) -> anyhow::Result<PathBuf> {
+    let dir = output_base_dir(component_dir).join(format!("{os}/{arch}/"));
     std::fs::create_dir_all(&dir).context("failed to create output dir")?;
     Ok(dir)
 }
@@ -382,8 +641,12 @@ fn output_filename(component_name: &str, target: &BuildTarget) -> String {
     }
 }
 
-fn generate_checksums(component_name: &str, targets: &[BuildTarget]) -> anyhow::Result<()> {
-    let base = output_base_dir()?;
+fn generate_checksums(
+    component_dir: &Path,
+    component_name: &str,
+    targets: &[BuildTarget],
+) -> anyhow::Result<()> {
+    let base = output_base_dir(component_dir);
     let mut entries = Vec::new();
 
     for target in targets {
@@ -413,6 +676,7 @@ async fn build_rust(
     _state: &State,
     component: &Component,
     source: &Path,
+    out_dir: &Path,
     target: &BuildTarget,
 ) -> anyhow::Result<()> {
     let triple = target
@@ -420,8 +684,6 @@ async fn build_rust(
         .as_ref()
         .context("rust target not resolved")?;
 
-    let out_dir = output_dir(&target.os, &target.arch)?;
-
     tracing::info!(
         "building rust project: {} (target: {triple})",
         source.display()
@@ -467,13 +729,12 @@ async fn build_golang(
     _state: &State,
     component: &Component,
     source: &Path,
+    out_dir: &Path,
     target: &BuildTarget,
 ) -> anyhow::Result<()> {
     let go_os = target.go_os.as_ref().context("go os not resolved")?;
     let go_arch = target.go_arch.as_ref().context("go arch not resolved")?;
 
-    let out_dir = output_dir(&target.os, &target.arch)?;
-
     let bin_name = if target.os == "windows" {
         format!("{}.exe", component.name)
     } else {
@@ -555,6 +816,7 @@ async fn build_docker(
     _state: &State,
     component: &Component,
     source: &Path,
+    out_dir: &Path,
     target: &BuildTarget,
 ) -> anyhow::Result<()> {
     let platform = target
@@ -562,9 +824,6 @@ async fn build_docker(
         .as_ref()
         .context("docker platform not resolved")?;
 
-    ensure_buildx_builder().await?;
-
-    let out_dir = output_dir(&target.os, &target.arch)?;
     let tar_name = format!("{}.tar", component.name);
     let output_path = out_dir.join(&tar_name);
 
@@ -623,6 +882,23 @@ async fn build_docker(
 pub struct Document {
     project: Option<ProjectMeta>,
     forest: Option<Forest>,
+    #[serde(default)]
+    dependencies: BTreeMap<String, serde_json::Value>,
+}
+
+impl Document {
+    pub fn component(&self) -> Option<&Component> {
+        self.forest.as_ref().and_then(|f| f.component.as_ref())
+    }
+
+    /// `path:` dependencies as `(org/name, path)`, paths as written
+    /// (relative to the spec's directory).
+    pub fn local_dependencies(&self) -> Vec<(&str, &str)> {
+        self.dependencies
+            .iter()
+            .filter_map(|(name, dep)| Some((name.as_str(), dep.get("path")?.as_str()?)))
+            .collect()
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
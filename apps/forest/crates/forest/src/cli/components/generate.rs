@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use anyhow::Context;
 use tokio::io::AsyncWriteExt;
@@ -30,16 +30,23 @@ pub struct GenerateCommand {
 }
 
 impl GenerateCommand {
-    pub async fn execute(&self, _state: &State) -> anyhow::Result<()> {
+    pub async fn execute(&self, state: &State) -> anyhow::Result<()> {
+        self.generate(state, &std::env::current_dir()?).await
+    }
+
+    /// Generate for the component in `dir`; a relative `--output` resolves
+    /// against it.
+    pub(crate) async fn generate(&self, _state: &State, dir: &Path) -> anyhow::Result<()> {
         let language = match &self.language {
             Some(lang) => lang.clone(),
-            None => detect_codegen_language().await.unwrap_or_else(|| "rust".to_string()),
+            None => detect_codegen_language(dir).await.unwrap_or_else(|| "rust".to_string()),
         };
 
         let output = match &self.output {
-            Some(o) => o.clone(),
-            None => detect_codegen_output()
+            Some(o) => dir.join(o),
+            None => detect_codegen_output(dir)
                 .await
+                .map(|o| dir.join(o))
                 .ok_or_else(|| anyhow::anyhow!(
                     "no --output specified and no codegen.output found in forest.cue"
                 ))?,
@@ -59,7 +66,7 @@ impl GenerateCommand {
         };
 
         // Generate own component types
-        let openapi_json = run_cue_def_openapi(&["./forest.component.cue"]).await?;
+        let openapi_json = run_cue_def_openapi_in_dir(&["./forest.component.cue"], dir).await?;
         let generated_code = codegen.generate(openapi_json.trim())?;
 
         tokio::fs::create_dir_all(&output).await?;
@@ -76,7 +83,7 @@ impl GenerateCommand {
 
         // Generate dependency clients
         if matches!(language.as_str(), "typescript" | "deno" | "ts") {
-            self.generate_dependency_clients(&output, &codegen).await?;
+            self.generate_dependency_clients(dir, &output, &codegen).await?;
         }
 
         Ok(())
@@ -85,10 +92,11 @@ impl GenerateCommand {
     /// Discover local component dependencies and generate typed clients for them.
     async fn generate_dependency_clients(
         &self,
-        output: &std::path::Path,
+        dir: &Path,
+        output: &Path,
         codegen: &forest_sdk_codegen::Codegen,
     ) -> anyhow::Result<()> {
-        let deps = discover_component_dependencies().await?;
+        let deps = discover_component_dependencies(dir).await?;
         if deps.is_empty() {
             return Ok(());
         }
@@ -129,15 +137,10 @@ impl GenerateCommand {
     }
 }
 
-/// Run `cue def --out openapi` on the given files in the current directory.
-async fn run_cue_def_openapi(cue_files: &[&str]) -> anyhow::Result<String> {
-    run_cue_def_openapi_in_dir(cue_files, &std::env::current_dir()?).await
-}
-
 /// Run `cue def --out openapi` on the given files in a specific directory.
 async fn run_cue_def_openapi_in_dir(
     cue_files: &[&str],
-    dir: &std::path::Path,
+    dir: &Path,
 ) -> anyhow::Result<String> {
     let output = crate::tools::cue::output(|| {
         let mut cmd = tokio::process::Command::new("cue");
@@ -164,7 +167,7 @@ async fn run_cue_def_openapi_in_dir(
 /// Parse forest.cue to find local component dependencies.
 /// Returns (component_id, local_path) pairs for dependencies that are local paths
 /// and have a forest.component.cue file (i.e., they are components, not just CUE modules).
-async fn discover_component_dependencies() -> anyhow::Result<Vec<(String, PathBuf)>> {
+async fn discover_component_dependencies(dir: &Path) -> anyhow::Result<Vec<(String, PathBuf)>> {
     // Read forest.cue to get dependencies
     let output = crate::tools::cue::output(|| {
         let mut cmd = tokio::process::Command::new("cue");
@@ -172,6 +175,7 @@ async fn discover_component_dependencies() -> anyhow::Result<Vec<(String, PathBu
             cmd.env("CUE_REGISTRY", registry);
         }
         cmd.args(["export", "--out", "json", "forest.cue"]);
+        cmd.current_dir(dir);
         cmd
     })
     .await?;
@@ -187,12 +191,11 @@ async fn discover_component_dependencies() -> anyhow::Result<Vec<(String, PathBu
     };
 
     let mut result = Vec::new();
-    let cwd = std::env::current_dir()?;
 
     for (name, spec) in deps {
         // Only local path dependencies
         if let Some(path) = spec.get("path").and_then(|p| p.as_str()) {
-            let dep_path = cwd.join(path);
+            let dep_path = dir.join(path);
             if dep_path.join("forest.component.cue").exists() {
                 result.push((name.clone(), dep_path));
             }
@@ -203,9 +206,10 @@ async fn discover_component_dependencies() -> anyhow::Result<Vec<(String, PathBu
 }
 
 /// Try to detect the codegen output directory from forest.cue's codegen.output field.
-async fn detect_codegen_output() -> Option<PathBuf> {
+async fn detect_codegen_output(dir: &Path) -> Option<PathBuf> {
     let output = tokio::process::Command::new("cue")
         .args(["export", "--out", "json", "forest.cue"])
+        .current_dir(dir)
         .output()
         .await
         .ok()?;
@@ -224,9 +228,10 @@ async fn detect_codegen_output() -> Option<PathBuf> {
 }
 
 /// Try to detect the codegen language from forest.cue's codegen.type field.
-async fn detect_codegen_language() -> Option<String> {
+async fn detect_codegen_language(dir: &Path) -> Option<String> {
     let output = tokio::process::Command::new("cue")
         .args(["export", "--out", "json", "forest.cue"])
+        .current_dir(dir)
         .output()
         .await
         .ok()?;
@@ -0,0 +1,151 @@
+//! Project-level `forest build`: builds every local path component.
+//!
+//! Members are the project's `path:` dependencies and, transitively,
+//! theirs. Each one is regenerated and built against its own directory,
+//! after the local components it depends on. A failed member
+//! skips its dependents but not the rest of the workspace.
+
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use anyhow::Context;
+use tabled::{Table, Tabled, settings::Style};
+
+use super::build::{BuildCommand, BuildOutcome, evaluate_spec};
+use crate::services::workspace::{self, Member};
+use crate::state::State;
+
+#[derive(Tabled)]
+struct SummaryRow {
+    #[tabled(rename = "COMPONENT")]
+    component: String,
+    #[tabled(rename = "PATH")]
+    path: String,
+    #[tabled(rename = "STATUS")]
+    status: String,
+    #[tabled(rename = "TIME")]
+    time: String,
+}
+
+pub(crate) async fn build_workspace(
+    state: &State,
+    project_dir: &Path,
+    build: &BuildCommand,
+) -> anyhow::Result<()> {
+    let members = discover(project_dir).await?;
+    let order = workspace::build_order(&members)?;
+    tracing::info!("building {} local component(s)", order.len());
+
+    let mut rows = Vec::new();
+    let mut failed: Vec<&Path> = Vec::new();
+    for member in order {
+        let path = member
+            .path
+            .strip_prefix(project_dir)
+            .unwrap_or(&member.path)
+            .display()
+            .to_string();
+
+        if let Some(dep) = member
+            .depends_on
+            .iter()
+            .find(|dep| failed.contains(&dep.as_path()))
+        {
+            let dep = members
+                .iter()
+                .find(|m| &m.path == dep)
+                .map_or("dependency", |m| m.name.as_str());
+            rows.push(SummaryRow {
+                component: member.name.clone(),
+                path,
+                status: format!("skipped ({dep} failed)"),
+                time: "-".into(),
+            });
+            failed.push(&member.path);
+            continue;
+        }
+
+        tracing::info!("building {} ({path})", member.name);
+        let started = Instant::now();
+        let result = build_member(state, build, &member.path).await;
+        let time = format!("{:.1}s", started.elapsed().as_secs_f64());
+
+        let status = match result {
+            Ok(BuildOutcome::Prebuilt) => "prebuilt".to_string(),
+            Ok(BuildOutcome::Deno) => "meta.json generated".to_string(),
+            Ok(BuildOutcome::Targets { built: 0, .. }) => "up to date".to_string(),
+            Ok(BuildOutcome::Targets { built, up_to_date }) => {
+                format!("built {built}, {up_to_date} up to date")
+            }
+            Err(e) => {
+                tracing::error!("{} failed to build: {e:#}", member.name);
+                failed.push(&member.path);
+                "failed".to_string()
+            }
+        };
+        rows.push(SummaryRow {
+            component: member.name.clone(),
+            path,
+            status,
+            time,
+        });
+    }
+
+    let mut table = Table::new(&rows);
+    table.with(Style::rounded());
+    eprintln!("{table}");
+
+    if !failed.is_empty() {
+        anyhow::bail!("{} of {} local component(s) did not build", failed.len(), rows.len());
+    }
+    Ok(())
+}
+
+/// Breadth-first walk of `path:` dependencies, starting at the project.
+async fn discover(project_dir: &Path) -> anyhow::Result<Vec<Member>> {
+    let (doc, _) = evaluate_spec(project_dir).await?;
+    let mut queue: VecDeque<(String, PathBuf)> = resolve(project_dir, doc.local_dependencies())?
+        .into_iter()
+        .collect();
+
+    let mut members: Vec<Member> = Vec::new();
+    while let Some((name, path)) = queue.pop_front() {
+        if members.iter().any(|m| m.path == path) {
+            continue;
+        }
+        let (doc, _) = evaluate_spec(&path)
+            .await
+            .with_context(|| format!("evaluate local component {name}"))?;
+        let deps = resolve(&path, doc.local_dependencies())?;
+        members.push(Member {
+            name,
+            path,
+            depends_on: deps.iter().map(|(_, path)| path.clone()).collect(),
+        });
+        queue.extend(deps);
+    }
+
+    Ok(members)
+}
+
+fn resolve(dir: &Path, deps: Vec<(&str, &str)>) -> anyhow::Result<Vec<(String, PathBuf)>> {
+    deps.into_iter()
+        .map(|(name, path)| {
+            let resolved = dir
+                .join(path)
+                .canonicalize()
+                .with_context(|| format!("local dependency {name} not found at {path}"))?;
+            Ok((name.to_string(), resolved))
+        })
+        .collect()
+}
+
+async fn build_member(
+    state: &State,
+    build: &BuildCommand,
+    dir: &Path,
+) -> anyhow::Result<BuildOutcome> {
+    let (doc, cue_files) = evaluate_spec(dir).await?;
+    build.build_component(state, dir, &doc, &cue_files).await
+}
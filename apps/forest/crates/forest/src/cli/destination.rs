@@ -1,7 +1,7 @@
 use crate::{
     cli::destination::{
-        create::CreateCommand, delete::DeleteCommand, list::ListCommand, types::TypesCommand,
-        update::UpdateCommand,
+        create::CreateCommand, delete::DeleteCommand, list::ListCommand,
+        terraform_state::TerraformStateCommand, types::TypesCommand, update::UpdateCommand,
     },
     state::State,
 };
@@ -9,6 +9,7 @@ use crate::{
 mod create;
 mod delete;
 mod list;
+mod terraform_state;
 mod types;
 mod update;
 
@@ -30,14 +31,17 @@ enum Commands {
     List(ListCommand),
     /// List available destination types (the blessed kinds: flux, terraform, forage, …)
     Types(TypesCommand),
+    /// Inspect and repair terraform state kept for terraform destinations
+    TerraformState(TerraformStateCommand),
 }
 
 impl DestinationCommand {
     pub fn is_mutation(&self) -> bool {
-        matches!(
-            self.commands,
-            Commands::Create(_) | Commands::Update(_) | Commands::Delete(_)
-        )
+        match &self.commands {
+            Commands::Create(_) | Commands::Update(_) | Commands::Delete(_) => true,
+            Commands::TerraformState(cmd) => cmd.is_mutation(),
+            _ => false,
+        }
     }
 
     pub async fn execute(&self, state: &State) -> anyhow::Result<()> {
@@ -47,6 +51,7 @@ impl DestinationCommand {
             Commands::Delete(cmd) => cmd.execute(state).await,
             Commands::List(cmd) => cmd.execute(state).await,
             Commands::Types(cmd) => cmd.execute(state).await,
+            Commands::TerraformState(cmd) => cmd.execute(state).await,
         }
     }
 }
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use anyhow::Context;
-use forest_models::DestinationType;
+use forest_models::{DestinationType, QueuePolicy};
 
 use crate::{grpc::GrpcClientState, state::State};
 
@@ -21,6 +21,16 @@ pub struct CreateCommand {
 
     #[arg(long = "metadata")]
     metadata: Vec<String>,
+
+    /// Only run releases on runners carrying this label (key=value, repeatable).
+    /// Use `pool=<name>` to pin the destination to a runner pool.
+    #[arg(long = "runner-label")]
+    runner_labels: Vec<String>,
+
+    /// What a new release does when releases are already queued for the
+    /// destination: `wait` behind them, or `supersede` (cancel) them.
+    #[arg(long, default_value_t = QueuePolicy::Wait)]
+    queue_policy: QueuePolicy,
 }
 
 impl CreateCommand {
@@ -47,6 +57,16 @@ impl CreateCommand {
             })
             .collect::<anyhow::Result<HashMap<_, _>>>()?;
 
+        let runner_labels = self
+            .runner_labels
+            .iter()
+            .map(|m| {
+                m.split_once("=")
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .ok_or(anyhow::anyhow!("runner label requires a 'key=value'"))
+            })
+            .collect::<anyhow::Result<HashMap<_, _>>>()?;
+
         state
             .grpc_client()
             .create_destination(
@@ -61,6 +81,8 @@ impl CreateCommand {
                     description: String::new(),
                     fields: vec![],
                 },
+                runner_labels,
+                self.queue_policy,
             )
             .await
             .context("create destination")?;
@@ -26,6 +26,13 @@ impl ListCommand {
 
         for destination in destinations {
             println!("{} @ {}", destination.environment, destination.name);
+            println!("queue policy: {}", destination.queue_policy);
+            if !destination.runner_labels.is_empty() {
+                println!("runner labels:");
+                for (key, val) in &destination.runner_labels {
+                    println!("  {key}: {val}")
+                }
+            }
             if destination.metadata.is_empty() {
                 continue;
             }
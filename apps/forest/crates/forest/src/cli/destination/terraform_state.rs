@@ -0,0 +1,170 @@
+use anyhow::Context;
+
+use crate::{grpc::GrpcClientState, state::State};
+
+#[derive(clap::Parser)]
+pub struct TerraformStateCommand {
+    #[command(subcommand)]
+    commands: Commands,
+}
+
+#[derive(clap::Subcommand)]
+enum Commands {
+    /// List a project's terraform states and who holds their locks
+    List(ListCommand),
+    /// Show the version history of a state
+    Versions(VersionsCommand),
+    /// Drop a lock left behind by a release (organisation admins only)
+    Unlock(UnlockCommand),
+    /// Make an earlier version current again (organisation admins only)
+    Rollback(RollbackCommand),
+}
+
+#[derive(clap::Args)]
+struct ProjectArgs {
+    #[arg(long, short = 'o', visible_alias = "org")]
+    organisation: String,
+
+    #[arg(long, short = 'p')]
+    project: String,
+}
+
+#[derive(clap::Parser)]
+struct ListCommand {
+    #[command(flatten)]
+    project: ProjectArgs,
+}
+
+#[derive(clap::Parser)]
+struct VersionsCommand {
+    #[command(flatten)]
+    project: ProjectArgs,
+
+    #[arg(long, short = 'e')]
+    environment: String,
+}
+
+#[derive(clap::Parser)]
+struct UnlockCommand {
+    #[command(flatten)]
+    project: ProjectArgs,
+
+    #[arg(long, short = 'e')]
+    environment: String,
+}
+
+#[derive(clap::Parser)]
+struct RollbackCommand {
+    #[command(flatten)]
+    project: ProjectArgs,
+
+    #[arg(long, short = 'e')]
+    environment: String,
+
+    /// Version to make current, see `versions`
+    #[arg(long)]
+    version: i64,
+}
+
+impl TerraformStateCommand {
+    pub fn is_mutation(&self) -> bool {
+        matches!(self.commands, Commands::Unlock(_) | Commands::Rollback(_))
+    }
+
+    pub async fn execute(&self, state: &State) -> anyhow::Result<()> {
+        let client = state.grpc_client();
+
+        match &self.commands {
+            Commands::List(cmd) => {
+                let states = client
+                    .list_terraform_states(&cmd.project.organisation, &cmd.project.project)
+                    .await
+                    .context("list terraform states")?;
+
+                if states.is_empty() {
+                    println!("No terraform state stored yet");
+                    return Ok(());
+                }
+
+                for s in states {
+                    println!(
+                        "{}: version {} (updated {})",
+                        s.environment, s.current_version, s.updated_at
+                    );
+                    if let Some(lock) = s.lock {
+                        println!(
+                            "  locked by {} ({}) since {}",
+                            lock.who, lock.operation, lock.locked_at
+                        );
+                        println!("  lock id: {}", lock.lock_id);
+                        if let Some(release_id) = lock.release_id {
+                            println!("  release: {release_id}");
+                        }
+                    }
+                }
+            }
+            Commands::Versions(cmd) => {
+                let versions = client
+                    .list_terraform_state_versions(
+                        &cmd.project.organisation,
+                        &cmd.project.project,
+                        &cmd.environment,
+                    )
+                    .await
+                    .context("list terraform state versions")?;
+
+                if versions.is_empty() {
+                    println!("No terraform state stored yet");
+                    return Ok(());
+                }
+
+                for v in versions {
+                    let source = match (v.rolled_back_from, v.release_id) {
+                        (Some(from), _) => format!("rolled back from {from}"),
+                        (None, Some(release_id)) => format!("release {release_id}"),
+                        (None, None) => "unknown release".to_string(),
+                    };
+                    println!(
+                        "{}\t{}\t{} bytes\t{source}",
+                        v.version, v.created_at, v.size_bytes
+                    );
+                }
+            }
+            Commands::Unlock(cmd) => {
+                let released = client
+                    .force_unlock_terraform_state(
+                        &cmd.project.organisation,
+                        &cmd.project.project,
+                        &cmd.environment,
+                    )
+                    .await
+                    .context("force unlock terraform state")?;
+
+                match released {
+                    Some(lock) => {
+                        eprintln!("Dropped lock {} held by {}", lock.lock_id, lock.who)
+                    }
+                    None => eprintln!("State was not locked"),
+                }
+            }
+            Commands::Rollback(cmd) => {
+                let version = client
+                    .rollback_terraform_state(
+                        &cmd.project.organisation,
+                        &cmd.project.project,
+                        &cmd.environment,
+                        cmd.version,
+                    )
+                    .await
+                    .context("rollback terraform state")?;
+
+                eprintln!(
+                    "Rolled back to version {} as version {}",
+                    cmd.version, version.version
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
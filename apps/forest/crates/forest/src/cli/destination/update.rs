@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use anyhow::Context;
+use forest_models::QueuePolicy;
 
 use crate::{grpc::GrpcClientState, state::State};
 
@@ -14,6 +15,18 @@ pub struct UpdateCommand {
 
     #[arg(long = "metadata")]
     metadata: Vec<String>,
+
+    /// Replace the runner labels releases require (key=value, repeatable).
+    #[arg(long = "runner-label", conflicts_with = "clear_runner_labels")]
+    runner_labels: Vec<String>,
+
+    /// Remove all runner label requirements.
+    #[arg(long)]
+    clear_runner_labels: bool,
+
+    /// Change how new releases treat queued ones: `wait` or `supersede`.
+    #[arg(long)]
+    queue_policy: Option<QueuePolicy>,
 }
 
 impl UpdateCommand {
@@ -28,9 +41,28 @@ impl UpdateCommand {
             })
             .collect::<anyhow::Result<HashMap<_, _>>>()?;
 
+        let runner_labels = self
+            .runner_labels
+            .iter()
+            .map(|m| {
+                m.split_once("=")
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .ok_or(anyhow::anyhow!("runner label requires a 'key=value'"))
+            })
+            .collect::<anyhow::Result<HashMap<_, _>>>()?;
+        // Leave labels untouched unless asked to change them.
+        let runner_labels = (self.clear_runner_labels || !runner_labels.is_empty())
+            .then_some(runner_labels);
+
         state
             .grpc_client()
-            .update_destination(&self.organisation, &self.name, metadata)
+            .update_destination(
+                &self.organisation,
+                &self.name,
+                metadata,
+                runner_labels,
+                self.queue_policy,
+            )
             .await
             .context("update destination")?;
 
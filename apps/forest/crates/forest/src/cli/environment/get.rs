@@ -37,6 +37,18 @@ impl GetCommand {
         }
         println!("  sort order:   {}", env.sort_order);
         println!("  created at:   {}", env.created_at);
+        if let Some(p) = &env.protection {
+            println!("  protected:    {}", if p.protected { "yes" } else { "no" });
+            if let Some(prior) = &p.required_prior_environment {
+                println!("  requires:     {prior}");
+            }
+            if !p.approver_groups.is_empty() {
+                println!("  approvers:    {}", p.approver_groups.join(", "));
+            }
+            if !p.allowed_branches.is_empty() {
+                println!("  branches:     {}", p.allowed_branches.join(", "));
+            }
+        }
 
         Ok(())
     }
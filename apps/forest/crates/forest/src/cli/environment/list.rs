@@ -29,7 +29,12 @@ impl ListCommand {
         eprintln!("environments\n");
 
         for env in envs {
-            println!("{}", env.name);
+            let protected = env.protection.as_ref().is_some_and(|p| p.protected);
+            if protected {
+                println!("{} (protected)", env.name);
+            } else {
+                println!("{}", env.name);
+            }
             if let Some(desc) = &env.description {
                 println!("  description: {desc}");
             }
@@ -1,36 +1,106 @@
 use anyhow::Context;
 
-use crate::{grpc::GrpcClientState, state::State};
+use crate::{cli::prompts, grpc::GrpcClientState, state::State};
 
 #[derive(clap::Parser)]
 pub struct UpdateCommand {
-    #[arg(long)]
+    #[arg(long, conflicts_with_all = ["organisation", "name"])]
     id: Option<String>,
 
+    /// Organisation of the environment, used with --name instead of --id
+    #[arg(long, short = 'o', visible_alias = "org")]
+    organisation: Option<String>,
+
+    /// Environment name, used instead of --id
+    #[arg(long)]
+    name: Option<String>,
+
     #[arg(long)]
     description: Option<String>,
 
     #[arg(long)]
     sort_order: Option<i32>,
+
+    /// Require an approval before releases into the environment run
+    #[arg(long, conflicts_with = "unprotected")]
+    protected: bool,
+
+    /// Stop requiring approvals for the environment (drops approver groups)
+    #[arg(long)]
+    unprotected: bool,
+
+    /// Environment an artifact must have been released to successfully first
+    #[arg(long, conflicts_with = "no_prior_environment")]
+    prior_environment: Option<String>,
+
+    /// Drop the required prior environment
+    #[arg(long)]
+    no_prior_environment: bool,
+
+    /// SCIM group that must approve releases (repeatable, replaces the list)
+    #[arg(long = "approver-group", conflicts_with = "clear_approver_groups")]
+    approver_groups: Vec<String>,
+
+    /// Remove all approver groups
+    #[arg(long)]
+    clear_approver_groups: bool,
+
+    /// Branch regex allowed to release (repeatable, replaces the list)
+    #[arg(long = "allowed-branch", conflicts_with = "clear_allowed_branches")]
+    allowed_branches: Vec<String>,
+
+    /// Allow releases from any branch
+    #[arg(long)]
+    clear_allowed_branches: bool,
 }
 
 impl UpdateCommand {
+    fn changes_protection(&self) -> bool {
+        self.protected
+            || self.unprotected
+            || self.prior_environment.is_some()
+            || self.no_prior_environment
+            || !self.approver_groups.is_empty()
+            || self.clear_approver_groups
+            || !self.allowed_branches.is_empty()
+            || self.clear_allowed_branches
+    }
+
     pub async fn execute(&self, state: &State) -> anyhow::Result<()> {
-        let id = match &self.id {
-            Some(id) => id.clone(),
-            None => inquire::Text::new("Environment ID:").prompt()?,
+        let client = state.grpc_client();
+
+        let id = match (&self.id, &self.name) {
+            (Some(id), _) => id.clone(),
+            (None, Some(name)) => {
+                let organisation = match &self.organisation {
+                    Some(o) => o.clone(),
+                    None => prompts::select_organisation(state).await?,
+                };
+                client
+                    .get_environment(&organisation, name)
+                    .await
+                    .context("get environment")?
+                    .id
+            }
+            (None, None) => inquire::Text::new("Environment ID:").prompt()?,
         };
 
+        // Only prompt for the basics when nothing else was asked for.
+        let interactive = !self.changes_protection();
+
         let description = match &self.description {
             Some(d) => Some(d.clone()),
-            None => inquire::Text::new("Description (optional, leave empty to skip):")
-                .prompt_skippable()?
-                .filter(|s| !s.is_empty()),
+            None if interactive => {
+                inquire::Text::new("Description (optional, leave empty to skip):")
+                    .prompt_skippable()?
+                    .filter(|s| !s.is_empty())
+            }
+            None => None,
         };
 
         let sort_order = match self.sort_order {
             Some(s) => Some(s),
-            None => {
+            None if interactive => {
                 let input = inquire::Text::new("Sort order (optional, leave empty to skip):")
                     .prompt_skippable()?
                     .filter(|s| !s.is_empty());
@@ -39,11 +109,49 @@ impl UpdateCommand {
                     None => None,
                 }
             }
+            None => None,
+        };
+
+        // The server replaces protection as a whole, so merge the flags into
+        // the environment's current rules.
+        let protection = if self.changes_protection() {
+            let current = client
+                .get_environment_by_id(&id)
+                .await
+                .context("get environment")?;
+            let mut protection = current.protection.unwrap_or_default();
+            if self.protected {
+                protection.protected = true;
+            }
+            if self.unprotected {
+                protection.protected = false;
+                protection.approver_groups.clear();
+            }
+            if let Some(prior) = &self.prior_environment {
+                protection.required_prior_environment = Some(prior.clone());
+            }
+            if self.no_prior_environment {
+                protection.required_prior_environment = None;
+            }
+            if !self.approver_groups.is_empty() {
+                protection.approver_groups = self.approver_groups.clone();
+            }
+            if self.clear_approver_groups {
+                protection.approver_groups.clear();
+            }
+            if !self.allowed_branches.is_empty() {
+                protection.allowed_branches = self.allowed_branches.clone();
+            }
+            if self.clear_allowed_branches {
+                protection.allowed_branches.clear();
+            }
+            Some(protection)
+        } else {
+            None
         };
 
-        let env = state
-            .grpc_client()
-            .update_environment(&id, description.as_deref(), sort_order)
+        let env = client
+            .update_environment(&id, description.as_deref(), sort_order, protection)
             .await
             .context("update environment")?;
 
@@ -88,6 +88,9 @@ fn format_type(t: NotificationType) -> &'static str {
         NotificationType::ReleaseStarted => "STARTED",
         NotificationType::ReleaseSucceeded => "SUCCEEDED",
         NotificationType::ReleaseFailed => "FAILED",
+        NotificationType::DriftDetected => "DRIFT_DETECTED",
+        NotificationType::DriftResolved => "DRIFT_RESOLVED",
+        NotificationType::ScheduleFailed => "SCHEDULE_FAILED",
         NotificationType::Unspecified => "UNKNOWN",
     }
 }
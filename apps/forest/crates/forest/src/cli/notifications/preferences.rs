@@ -58,6 +58,9 @@ enum NotifType {
     Started,
     Succeeded,
     Failed,
+    DriftDetected,
+    DriftResolved,
+    ScheduleFailed,
 }
 
 #[derive(clap::ValueEnum, Clone, Debug)]
@@ -90,6 +93,9 @@ impl SetPrefCommand {
             NotifType::Started => forest_grpc_interface::NotificationType::ReleaseStarted,
             NotifType::Succeeded => forest_grpc_interface::NotificationType::ReleaseSucceeded,
             NotifType::Failed => forest_grpc_interface::NotificationType::ReleaseFailed,
+            NotifType::DriftDetected => forest_grpc_interface::NotificationType::DriftDetected,
+            NotifType::DriftResolved => forest_grpc_interface::NotificationType::DriftResolved,
+            NotifType::ScheduleFailed => forest_grpc_interface::NotificationType::ScheduleFailed,
         };
 
         let channel = match self.channel {
@@ -124,6 +130,9 @@ fn format_notification_type(t: forest_grpc_interface::NotificationType) -> &'sta
         forest_grpc_interface::NotificationType::ReleaseStarted => "STARTED",
         forest_grpc_interface::NotificationType::ReleaseSucceeded => "SUCCEEDED",
         forest_grpc_interface::NotificationType::ReleaseFailed => "FAILED",
+        forest_grpc_interface::NotificationType::DriftDetected => "DRIFT_DETECTED",
+        forest_grpc_interface::NotificationType::DriftResolved => "DRIFT_RESOLVED",
+        forest_grpc_interface::NotificationType::ScheduleFailed => "SCHEDULE_FAILED",
         forest_grpc_interface::NotificationType::Unspecified => "UNKNOWN",
     }
 }
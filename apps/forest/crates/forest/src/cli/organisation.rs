@@ -1,6 +1,8 @@
 mod create;
 mod get;
 mod member;
+mod retention;
+mod scim;
 mod search;
 
 use crate::state::State;
@@ -22,6 +24,10 @@ enum Commands {
     Search(search::SearchCommand),
     /// Manage organisation members
     Member(member::MemberCommand),
+    /// Configure SCIM provisioning from an identity provider
+    Scim(scim::ScimCommand),
+    /// Configure how long artifacts, logs and notifications are kept
+    Retention(retention::RetentionCommand),
 }
 
 impl OrganisationCommand {
@@ -30,6 +36,8 @@ impl OrganisationCommand {
             Commands::Create(_) => true,
             Commands::Show(_) | Commands::Search(_) => false,
             Commands::Member(c) => c.is_mutation(),
+            Commands::Scim(c) => c.is_mutation(),
+            Commands::Retention(c) => c.is_mutation(),
         }
     }
 
@@ -42,6 +50,8 @@ impl OrganisationCommand {
             Commands::Show(cmd) => cmd.execute(state, &format).await,
             Commands::Search(cmd) => cmd.execute(state, &format).await,
             Commands::Member(cmd) => cmd.execute(state, &format).await,
+            Commands::Scim(cmd) => cmd.execute(state, &format).await,
+            Commands::Retention(cmd) => cmd.execute(state, &format).await,
         }
     }
 }
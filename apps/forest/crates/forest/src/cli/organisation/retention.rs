@@ -0,0 +1,358 @@
+use anyhow::Context;
+use forest_grpc_interface::RetentionPolicy;
+use serde::Serialize;
+use tabled::Tabled;
+
+use crate::{
+    cli::{
+        output::{self, OutputFormat},
+        prompts,
+    },
+    grpc::GrpcClientState,
+    state::State,
+};
+
+/// How long Forest keeps artifacts, release logs and notifications.
+///
+/// The server applies the policy hourly. Artifacts that are currently
+/// deployed or mid-release are always kept; purged artifacts keep their
+/// release history but can no longer be released.
+#[derive(clap::Parser)]
+pub struct RetentionCommand {
+    #[command(subcommand)]
+    commands: Commands,
+}
+
+#[derive(clap::Subcommand)]
+enum Commands {
+    /// Show the organisation's retention policy
+    Show(ShowCommand),
+    /// Change the retention policy (organisation admins only)
+    Set(SetCommand),
+    /// Show what the policy would delete right now, without deleting it
+    Preview(PreviewCommand),
+}
+
+impl RetentionCommand {
+    pub fn is_mutation(&self) -> bool {
+        matches!(self.commands, Commands::Set(_))
+    }
+
+    pub async fn execute(&self, state: &State, format: &OutputFormat) -> anyhow::Result<()> {
+        match &self.commands {
+            Commands::Show(cmd) => cmd.execute(state, format).await,
+            Commands::Set(cmd) => cmd.execute(state, format).await,
+            Commands::Preview(cmd) => cmd.execute(state, format).await,
+        }
+    }
+}
+
+/// A retention value: a positive number, or `all` to keep everything.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Keep(Option<i32>);
+
+impl std::str::FromStr for Keep {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("all") {
+            return Ok(Self(None));
+        }
+        match s.parse::<i32>() {
+            Ok(n) if n > 0 => Ok(Self(Some(n))),
+            _ => Err(format!("expected a positive number or 'all', got '{s}'")),
+        }
+    }
+}
+
+#[derive(clap::Args)]
+struct PolicyArgs {
+    /// Artifacts to keep per project, and per environment within it (or `all`)
+    #[arg(long)]
+    keep_artifacts: Option<Keep>,
+
+    /// Days to keep release logs and health observations (or `all`)
+    #[arg(long)]
+    log_retention_days: Option<Keep>,
+
+    /// Days to keep notifications (or `all`)
+    #[arg(long)]
+    notification_retention_days: Option<Keep>,
+}
+
+impl PolicyArgs {
+    fn is_empty(&self) -> bool {
+        self.keep_artifacts.is_none()
+            && self.log_retention_days.is_none()
+            && self.notification_retention_days.is_none()
+    }
+
+    /// `policy` with the given flags applied; omitted flags keep their
+    /// current value.
+    fn apply(&self, mut policy: RetentionPolicy) -> RetentionPolicy {
+        if let Some(Keep(v)) = self.keep_artifacts {
+            policy.keep_artifacts = v;
+        }
+        if let Some(Keep(v)) = self.log_retention_days {
+            policy.log_retention_days = v;
+        }
+        if let Some(Keep(v)) = self.notification_retention_days {
+            policy.notification_retention_days = v;
+        }
+        policy
+    }
+}
+
+async fn organisation(state: &State, organisation: &Option<String>) -> anyhow::Result<String> {
+    match organisation {
+        Some(org) => Ok(org.clone()),
+        None => prompts::select_organisation(state).await,
+    }
+}
+
+fn describe(value: Option<i32>, unit: &str) -> String {
+    match value {
+        Some(v) => format!("{v}{unit}"),
+        None => "keep all".to_string(),
+    }
+}
+
+fn print_policy(policy: &RetentionPolicy) {
+    println!("{}", policy.organisation);
+    println!("  artifacts:     {}", describe(policy.keep_artifacts, " per project/environment"));
+    println!("  logs:          {}", describe(policy.log_retention_days, " days"));
+    println!("  notifications: {}", describe(policy.notification_retention_days, " days"));
+    if !policy.updated_at.is_empty() {
+        println!("  updated:       {} by {}", policy.updated_at, policy.updated_by);
+    }
+}
+
+#[derive(Serialize)]
+struct PolicyJson {
+    organisation: String,
+    keep_artifacts: Option<i32>,
+    log_retention_days: Option<i32>,
+    notification_retention_days: Option<i32>,
+    updated_by: String,
+    updated_at: String,
+}
+
+impl From<&RetentionPolicy> for PolicyJson {
+    fn from(p: &RetentionPolicy) -> Self {
+        Self {
+            organisation: p.organisation.clone(),
+            keep_artifacts: p.keep_artifacts,
+            log_retention_days: p.log_retention_days,
+            notification_retention_days: p.notification_retention_days,
+            updated_by: p.updated_by.clone(),
+            updated_at: p.updated_at.clone(),
+        }
+    }
+}
+
+fn render_policy(policy: &RetentionPolicy, format: &OutputFormat) -> anyhow::Result<()> {
+    match format {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&PolicyJson::from(policy))?),
+        _ => print_policy(policy),
+    }
+    Ok(())
+}
+
+#[derive(clap::Parser)]
+struct ShowCommand {
+    #[arg(long, short = 'o', visible_alias = "org")]
+    organisation: Option<String>,
+}
+
+impl ShowCommand {
+    async fn execute(&self, state: &State, format: &OutputFormat) -> anyhow::Result<()> {
+        let organisation = organisation(state, &self.organisation).await?;
+        let policy = state
+            .grpc_client()
+            .get_retention_policy(&organisation)
+            .await
+            .context("get retention policy")?;
+
+        render_policy(&policy, format)
+    }
+}
+
+/// Examples:
+///   forest organisation retention set -o acme --keep-artifacts 20 --log-retention-days 30
+///   forest organisation retention set -o acme --notification-retention-days all
+#[derive(clap::Parser)]
+struct SetCommand {
+    #[arg(long, short = 'o', visible_alias = "org")]
+    organisation: Option<String>,
+
+    #[command(flatten)]
+    policy: PolicyArgs,
+}
+
+impl SetCommand {
+    async fn execute(&self, state: &State, format: &OutputFormat) -> anyhow::Result<()> {
+        if self.policy.is_empty() {
+            anyhow::bail!(
+                "nothing to change: pass --keep-artifacts, --log-retention-days or --notification-retention-days"
+            );
+        }
+
+        let organisation = organisation(state, &self.organisation).await?;
+        let client = state.grpc_client();
+        let current = client
+            .get_retention_policy(&organisation)
+            .await
+            .context("get retention policy")?;
+        let policy = client
+            .set_retention_policy(self.policy.apply(current))
+            .await
+            .context("set retention policy")?;
+
+        if !matches!(format, OutputFormat::Json) {
+            eprintln!("Updated retention policy; the server applies it within the hour.");
+            eprintln!("hint: use 'forest organisation retention preview' to see what it will delete\n");
+        }
+        render_policy(&policy, format)
+    }
+}
+
+/// Without flags, previews the stored policy. With flags, previews the
+/// stored policy with those changes, without saving them.
+#[derive(clap::Parser)]
+struct PreviewCommand {
+    #[arg(long, short = 'o', visible_alias = "org")]
+    organisation: Option<String>,
+
+    #[command(flatten)]
+    policy: PolicyArgs,
+}
+
+#[derive(Tabled, Serialize)]
+struct ExpiredRow {
+    #[tabled(rename = "Project")]
+    project: String,
+    #[tabled(rename = "Slug")]
+    slug: String,
+    #[tabled(rename = "Created")]
+    created_at: String,
+    #[tabled(rename = "Files")]
+    file_count: i64,
+    #[tabled(rename = "Size")]
+    size: String,
+}
+
+impl PreviewCommand {
+    async fn execute(&self, state: &State, format: &OutputFormat) -> anyhow::Result<()> {
+        let organisation = organisation(state, &self.organisation).await?;
+        let client = state.grpc_client();
+
+        let policy = if self.policy.is_empty() {
+            None
+        } else {
+            let current = client
+                .get_retention_policy(&organisation)
+                .await
+                .context("get retention policy")?;
+            Some(self.policy.apply(current))
+        };
+
+        let preview = client
+            .preview_retention(&organisation, policy)
+            .await
+            .context("preview retention")?;
+
+        let rows: Vec<ExpiredRow> = preview
+            .artifacts
+            .iter()
+            .map(|a| ExpiredRow {
+                project: a.project.clone(),
+                slug: a.slug.clone(),
+                created_at: a.created_at.clone(),
+                file_count: a.file_count,
+                size: human_bytes(a.size_bytes),
+            })
+            .collect();
+
+        if matches!(format, OutputFormat::Json) {
+            print!("{}", output::render(format, &rows));
+            return Ok(());
+        }
+
+        if let Some(policy) = &preview.policy {
+            print_policy(policy);
+            println!();
+        }
+        if !rows.is_empty() {
+            print!("{}", output::render(format, &rows));
+            println!();
+        }
+        println!(
+            "Would purge {} artifact(s) ({} files, {}), {} log block(s), {} health observation(s) and {} notification(s)",
+            preview.artifacts.len(),
+            preview.artifact_files,
+            human_bytes(preview.artifact_bytes),
+            preview.log_blocks,
+            preview.health_observations,
+            preview.notifications,
+        );
+
+        Ok(())
+    }
+}
+
+fn human_bytes(bytes: i64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut value = bytes as f64;
+    let mut unit = "B";
+    for u in UNITS {
+        if value < 1024.0 {
+            break;
+        }
+        value /= 1024.0;
+        unit = u;
+    }
+    format!("{value:.1} {unit}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keep_accepts_numbers_and_all() {
+        assert_eq!("30".parse::<Keep>().unwrap(), Keep(Some(30)));
+        assert_eq!("ALL".parse::<Keep>().unwrap(), Keep(None));
+        assert!("0".parse::<Keep>().is_err());
+        assert!("forever".parse::<Keep>().is_err());
+    }
+
+    #[test]
+    fn omitted_flags_keep_current_values() {
+        let args = PolicyArgs {
+            keep_artifacts: None,
+            log_retention_days: Some(Keep(Some(14))),
+            notification_retention_days: Some(Keep(None)),
+        };
+        let current = RetentionPolicy {
+            organisation: "acme".into(),
+            keep_artifacts: Some(20),
+            log_retention_days: Some(30),
+            notification_retention_days: Some(90),
+            ..Default::default()
+        };
+        let policy = args.apply(current);
+        assert_eq!(policy.keep_artifacts, Some(20));
+        assert_eq!(policy.log_retention_days, Some(14));
+        assert_eq!(policy.notification_retention_days, None);
+    }
+
+    #[test]
+    fn bytes_are_humanised() {
+        assert_eq!(human_bytes(512), "512 B");
+        assert_eq!(human_bytes(1536), "1.5 KiB");
+        assert_eq!(human_bytes(3 * 1024 * 1024 * 1024), "3.0 GiB");
+    }
+}
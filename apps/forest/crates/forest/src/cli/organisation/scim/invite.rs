@@ -0,0 +1,122 @@
+use anyhow::Context;
+use serde::Serialize;
+use tabled::Tabled;
+
+use crate::{
+    cli::{
+        organisation::member::resolve_org_id,
+        output::{self, OutputFormat},
+    },
+    grpc::GrpcClientState,
+    state::State,
+    user_state::UserStateLoaderState,
+};
+
+/// An organisation's IdP that provisions an account you already have sends
+/// an invite, unless your email is verified on a domain the organisation
+/// has proven. You join the organisation once you accept it.
+#[derive(clap::Parser)]
+pub struct InviteCommand {
+    #[command(subcommand)]
+    commands: Commands,
+}
+
+#[derive(clap::Subcommand)]
+enum Commands {
+    /// List organisations waiting for you to accept their invite
+    List(ListCommand),
+    /// Accept an organisation's invite and become a member
+    Accept(AcceptCommand),
+}
+
+impl InviteCommand {
+    pub fn is_mutation(&self) -> bool {
+        !matches!(self.commands, Commands::List(_))
+    }
+
+    pub async fn execute(&self, state: &State, format: &OutputFormat) -> anyhow::Result<()> {
+        let _user_state = state
+            .user_state()
+            .get_state()
+            .await?
+            .context("you must be logged in")?;
+
+        match &self.commands {
+            Commands::List(cmd) => cmd.execute(state, format).await,
+            Commands::Accept(cmd) => cmd.execute(state, format).await,
+        }
+    }
+}
+
+#[derive(clap::Parser)]
+pub struct ListCommand {}
+
+#[derive(Tabled, Serialize)]
+struct InviteRow {
+    #[tabled(rename = "Organisation")]
+    organisation: String,
+    #[tabled(rename = "User name")]
+    user_name: String,
+    #[tabled(rename = "Invited")]
+    created_at: String,
+}
+
+impl ListCommand {
+    async fn execute(&self, state: &State, format: &OutputFormat) -> anyhow::Result<()> {
+        let invites = state
+            .grpc_client()
+            .list_my_invites()
+            .await
+            .context("failed to list invites")?;
+
+        if invites.is_empty() {
+            match format {
+                OutputFormat::Json => print!("[]"),
+                _ => eprintln!("No pending invites"),
+            }
+            return Ok(());
+        }
+
+        let rows: Vec<InviteRow> = invites
+            .into_iter()
+            .map(|i| InviteRow {
+                organisation: i.organisation,
+                user_name: i.user_name,
+                created_at: i
+                    .created_at
+                    .and_then(|ts| chrono::DateTime::from_timestamp(ts.seconds, ts.nanos as u32))
+                    .map(|dt| dt.to_rfc3339())
+                    .unwrap_or_default(),
+            })
+            .collect();
+
+        print!("{}", output::render(format, &rows));
+
+        Ok(())
+    }
+}
+
+#[derive(clap::Parser)]
+pub struct AcceptCommand {
+    /// Organisation ID or name
+    #[arg(long)]
+    org: String,
+}
+
+impl AcceptCommand {
+    async fn execute(&self, state: &State, format: &OutputFormat) -> anyhow::Result<()> {
+        let org_id = resolve_org_id(state, &self.org).await?;
+
+        state
+            .grpc_client()
+            .accept_invite(&org_id)
+            .await
+            .context("failed to accept invite")?;
+
+        if !matches!(format, OutputFormat::Json) {
+            println!("Accepted the invite from '{}'", self.org);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+mod invite;
+mod role;
+
+use crate::{cli::output::OutputFormat, state::State};
+
+#[derive(clap::Parser)]
+pub struct ScimCommand {
+    #[command(subcommand)]
+    commands: Commands,
+}
+
+#[derive(clap::Subcommand)]
+enum Commands {
+    /// Map identity provider groups to organisation roles
+    Role(role::RoleCommand),
+    /// Accept invites from organisations that provisioned your account
+    Invite(invite::InviteCommand),
+}
+
+impl ScimCommand {
+    pub fn is_mutation(&self) -> bool {
+        match &self.commands {
+            Commands::Role(c) => c.is_mutation(),
+            Commands::Invite(c) => c.is_mutation(),
+        }
+    }
+
+    pub async fn execute(&self, state: &State, format: &OutputFormat) -> anyhow::Result<()> {
+        match &self.commands {
+            Commands::Role(cmd) => cmd.execute(state, format).await,
+            Commands::Invite(cmd) => cmd.execute(state, format).await,
+        }
+    }
+}
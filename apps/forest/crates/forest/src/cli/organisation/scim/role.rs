@@ -0,0 +1,177 @@
+use anyhow::Context;
+use serde::Serialize;
+use tabled::Tabled;
+
+use crate::{
+    cli::{
+        organisation::member::{prompt_org_select, resolve_org_id},
+        output::{self, OutputFormat},
+    },
+    grpc::GrpcClientState,
+    state::State,
+    user_state::UserStateLoaderState,
+};
+
+/// Members provisioned over SCIM take their role from their IdP groups:
+/// admin if any group maps to admin, member otherwise. Without mappings
+/// roles are managed by hand with `forest organisation member set-role`.
+#[derive(clap::Parser)]
+pub struct RoleCommand {
+    #[command(subcommand)]
+    commands: Commands,
+}
+
+#[derive(clap::Subcommand)]
+enum Commands {
+    /// Grant a role to members of an IdP group
+    Set(SetCommand),
+    /// List group → role mappings
+    List(ListCommand),
+    /// Remove a group's role mapping
+    Remove(RemoveCommand),
+}
+
+impl RoleCommand {
+    pub fn is_mutation(&self) -> bool {
+        !matches!(self.commands, Commands::List(_))
+    }
+
+    pub async fn execute(&self, state: &State, format: &OutputFormat) -> anyhow::Result<()> {
+        let _user_state = state
+            .user_state()
+            .get_state()
+            .await?
+            .context("you must be logged in")?;
+
+        match &self.commands {
+            Commands::Set(cmd) => cmd.execute(state, format).await,
+            Commands::List(cmd) => cmd.execute(state, format).await,
+            Commands::Remove(cmd) => cmd.execute(state, format).await,
+        }
+    }
+}
+
+#[derive(clap::Parser)]
+pub struct SetCommand {
+    /// Organisation ID or name
+    #[arg(long)]
+    org: Option<String>,
+
+    /// IdP group display name, as the identity provider sends it
+    #[arg(long)]
+    group: String,
+
+    /// Role: "admin" or "member"
+    #[arg(long)]
+    role: String,
+}
+
+impl SetCommand {
+    async fn execute(&self, state: &State, format: &OutputFormat) -> anyhow::Result<()> {
+        let org_id = match &self.org {
+            Some(o) => resolve_org_id(state, o).await?,
+            None => prompt_org_select(state, "admin").await?,
+        };
+
+        state
+            .grpc_client()
+            .set_scim_group_role(&org_id, &self.group, &self.role)
+            .await
+            .context("failed to set group role")?;
+
+        if !matches!(format, OutputFormat::Json) {
+            println!("Members of '{}' are now {}", self.group, self.role);
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(clap::Parser)]
+pub struct ListCommand {
+    /// Organisation ID or name
+    #[arg(long)]
+    org: Option<String>,
+}
+
+#[derive(Tabled, Serialize)]
+struct GroupRoleRow {
+    #[tabled(rename = "Group")]
+    group: String,
+    #[tabled(rename = "Role")]
+    role: String,
+    #[tabled(rename = "Created")]
+    created_at: String,
+}
+
+impl ListCommand {
+    async fn execute(&self, state: &State, format: &OutputFormat) -> anyhow::Result<()> {
+        let org_id = match &self.org {
+            Some(o) => resolve_org_id(state, o).await?,
+            None => prompt_org_select(state, "").await?,
+        };
+
+        let group_roles = state
+            .grpc_client()
+            .list_scim_group_roles(&org_id)
+            .await
+            .context("failed to list group roles")?;
+
+        if group_roles.is_empty() {
+            match format {
+                OutputFormat::Json => print!("[]"),
+                _ => eprintln!("No group role mappings; member roles are managed by hand"),
+            }
+            return Ok(());
+        }
+
+        let rows: Vec<GroupRoleRow> = group_roles
+            .into_iter()
+            .map(|r| GroupRoleRow {
+                group: r.group_name,
+                role: r.role,
+                created_at: r
+                    .created_at
+                    .and_then(|ts| chrono::DateTime::from_timestamp(ts.seconds, ts.nanos as u32))
+                    .map(|dt| dt.to_rfc3339())
+                    .unwrap_or_default(),
+            })
+            .collect();
+
+        print!("{}", output::render(format, &rows));
+
+        Ok(())
+    }
+}
+
+#[derive(clap::Parser)]
+pub struct RemoveCommand {
+    /// Organisation ID or name
+    #[arg(long)]
+    org: Option<String>,
+
+    /// IdP group display name
+    #[arg(long)]
+    group: String,
+}
+
+impl RemoveCommand {
+    async fn execute(&self, state: &State, format: &OutputFormat) -> anyhow::Result<()> {
+        let org_id = match &self.org {
+            Some(o) => resolve_org_id(state, o).await?,
+            None => prompt_org_select(state, "admin").await?,
+        };
+
+        state
+            .grpc_client()
+            .delete_scim_group_role(&org_id, &self.group)
+            .await
+            .context("failed to remove group role")?;
+
+        if !matches!(format, OutputFormat::Json) {
+            println!("Removed role mapping for '{}'", self.group);
+        }
+
+        Ok(())
+    }
+}
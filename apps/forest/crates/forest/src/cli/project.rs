@@ -2,7 +2,7 @@ use crate::{
     cli::project::{
         create::CreateCommand, init::InitCommand, list::ListCommand,
         pipeline::PipelineCommand, policy::PolicyCommand, publish::PublishCommand,
-        releases::ReleasesCommand, trigger::TriggerCommand,
+        releases::ReleasesCommand, schedule::ScheduleCommand, trigger::TriggerCommand,
     },
     state::State,
 };
@@ -14,6 +14,7 @@ mod pipeline;
 mod policy;
 mod publish;
 pub(crate) mod releases;
+mod schedule;
 mod trigger;
 
 #[derive(clap::Parser)]
@@ -40,6 +41,8 @@ enum Commands {
     Policy(PolicyCommand),
     /// Manage release pipelines for a project
     Pipeline(PipelineCommand),
+    /// Schedule releases for a set time or on a cron expression
+    Schedule(ScheduleCommand),
 }
 
 impl ProjectCommand {
@@ -50,6 +53,7 @@ impl ProjectCommand {
             Commands::Trigger(c) => c.is_mutation(),
             Commands::Policy(c) => c.is_mutation(),
             Commands::Pipeline(c) => c.is_mutation(),
+            Commands::Schedule(c) => c.is_mutation(),
         }
     }
 
@@ -63,6 +67,7 @@ impl ProjectCommand {
             Commands::Trigger(cmd) => cmd.execute(state).await,
             Commands::Policy(cmd) => cmd.execute(state).await,
             Commands::Pipeline(cmd) => cmd.execute(state).await,
+            Commands::Schedule(cmd) => cmd.execute(state).await,
         }
     }
 }
@@ -1,7 +1,9 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use anyhow::Context;
-use forest_grpc_interface::{pipeline_stage, DeployStageConfig, PipelineStage, PlanStageConfig, WaitStageConfig};
+use forest_grpc_interface::{
+    pipeline_stage, DeployBatch, DeployStageConfig, PipelineStage, PlanStageConfig, WaitStageConfig,
+};
 use serde::Deserialize;
 
 use crate::state::State;
@@ -50,11 +52,29 @@ impl PipelineCommand {
 #[derive(Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 enum JsonStageConfig {
-    Deploy { environment: String },
+    Deploy {
+        environment: String,
+        #[serde(default)]
+        batches: Vec<JsonDeployBatch>,
+    },
     Wait { duration_seconds: i64 },
     Plan { environment: String, #[serde(default)] auto_approve: bool },
 }
 
+/// One batch of a deploy stage, e.g. `{"destinations": ["prod-eu"], "wait_seconds": 600}`.
+#[derive(Deserialize)]
+struct JsonDeployBatch {
+    #[serde(default)]
+    destinations: Vec<String>,
+    #[serde(default)]
+    labels: BTreeMap<String, String>,
+    percentage: Option<u32>,
+    #[serde(default)]
+    wait_seconds: i64,
+    #[serde(default)]
+    health_check: bool,
+}
+
 #[derive(Deserialize)]
 struct JsonStageDefinition {
     #[serde(default)]
@@ -72,9 +92,22 @@ pub fn parse_stages_from_json(json: &str) -> anyhow::Result<Vec<PipelineStage>>
         .into_iter()
         .map(|(id, def)| {
             let config = match def.config {
-                JsonStageConfig::Deploy { environment } => {
-                    pipeline_stage::Config::Deploy(DeployStageConfig { environment })
-                }
+                JsonStageConfig::Deploy {
+                    environment,
+                    batches,
+                } => pipeline_stage::Config::Deploy(DeployStageConfig {
+                    environment,
+                    batches: batches
+                        .into_iter()
+                        .map(|b| DeployBatch {
+                            destinations: b.destinations,
+                            labels: b.labels.into_iter().collect(),
+                            percentage: b.percentage,
+                            wait_seconds: b.wait_seconds,
+                            health_check: b.health_check,
+                        })
+                        .collect(),
+                }),
                 JsonStageConfig::Wait { duration_seconds } => {
                     pipeline_stage::Config::Wait(WaitStageConfig { duration_seconds })
                 }
@@ -103,6 +136,9 @@ pub fn format_stages(stages: &[PipelineStage]) -> String {
     let mut parts = Vec::new();
     for s in stages {
         let type_str = match &s.config {
+            Some(pipeline_stage::Config::Deploy(c)) if !c.batches.is_empty() => {
+                format!("deploy({}, {} batches)", c.environment, c.batches.len())
+            }
             Some(pipeline_stage::Config::Deploy(c)) => {
                 format!("deploy({})", c.environment)
             }
@@ -126,3 +162,29 @@ pub fn format_stages(stages: &[PipelineStage]) -> String {
     }
     parts.join(", ")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_deploy_batches() {
+        let stages = parse_stages_from_json(
+            r#"{"deploy-prod": {"type": "deploy", "environment": "prod", "batches": [
+                {"destinations": ["prod-eu"], "wait_seconds": 600, "health_check": true},
+                {"percentage": 50}
+            ]}}"#,
+        )
+        .unwrap();
+
+        let Some(pipeline_stage::Config::Deploy(deploy)) = &stages[0].config else {
+            panic!("expected deploy stage");
+        };
+        assert_eq!(deploy.batches.len(), 2);
+        assert_eq!(deploy.batches[0].destinations, vec!["prod-eu"]);
+        assert_eq!(deploy.batches[0].wait_seconds, 600);
+        assert!(deploy.batches[0].health_check);
+        assert_eq!(deploy.batches[1].percentage, Some(50));
+        assert_eq!(format_stages(&stages), "deploy-prod: deploy(prod, 2 batches)");
+    }
+}
@@ -1,6 +1,6 @@
 use anyhow::Context;
 
-use forest_grpc_interface::PipelineRunStageStatus;
+use forest_grpc_interface::{PipelineRunStageStatus, ReleaseStepState};
 
 use crate::{cli::prompts, grpc::GrpcClientState, state::State};
 
@@ -102,6 +102,24 @@ impl ReleasesCommand {
                         println!("      error: {err}");
                     }
 
+                    for (i, batch) in stage.batches.iter().enumerate() {
+                        let status = PipelineRunStageStatus::try_from(batch.status)
+                            .unwrap_or(PipelineRunStageStatus::Unspecified);
+                        let mut line = format!(
+                            "      batch {}/{} [{status:?}]: {}",
+                            i + 1,
+                            stage.batches.len(),
+                            batch.destinations.join(", ")
+                        );
+                        if let Some(t) = &batch.wait_until {
+                            line.push_str(&format!("  bake until: {t}"));
+                        }
+                        if batch.health_check {
+                            line.push_str("  (health checked)");
+                        }
+                        println!("{line}");
+                    }
+
                     // Show release steps belonging to this stage
                     for step in &intent.steps {
                         if step.stage_id.as_deref() == Some(stage.stage_id.as_str()) {
@@ -114,9 +132,13 @@ impl ReleasesCommand {
                                 _ => "•",
                             };
                             println!(
-                                "      {step_icon} {}: {} [{}]",
-                                step.destination_name, step.environment, step.status
+                                "      {step_icon} {}: {} [{}]{}",
+                                step.destination_name,
+                                step.environment,
+                                step.status,
+                                drift_column(step)
                             );
+                            print_drift_detail(step, "          ");
                         }
                     }
                 }
@@ -141,8 +163,11 @@ impl ReleasesCommand {
                     };
 
                     println!(
-                        "  {icon} {}: {} [{}]",
-                        step.destination_name, step.environment, step.status
+                        "  {icon} {}: {} [{}]{}",
+                        step.destination_name,
+                        step.environment,
+                        step.status,
+                        drift_column(step)
                     );
 
                     let mut times = Vec::new();
@@ -165,6 +190,8 @@ impl ReleasesCommand {
                     if let Some(err) = &step.error_message {
                         println!("    error: {err}");
                     }
+
+                    print_drift_detail(step, "    ");
                 }
             }
 
@@ -174,3 +201,44 @@ impl ReleasesCommand {
         Ok(())
     }
 }
+
+/// The drift state of a deployed step, appended to its line.
+/// Empty until the drift detector has checked it.
+fn drift_column(step: &ReleaseStepState) -> String {
+    let Some(status) = &step.drift_status else {
+        return String::new();
+    };
+    match &step.drift_checked_at {
+        Some(t) => format!(" drift: {status} (checked {t})"),
+        None => format!(" drift: {status}"),
+    }
+}
+
+fn print_drift_detail(step: &ReleaseStepState, indent: &str) {
+    if step.drift_status.as_deref() == Some("IN_SYNC") {
+        return;
+    }
+    if let Some(detail) = &step.drift_detail {
+        for line in detail.lines() {
+            println!("{indent}{line}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drift_column_is_empty_until_checked() {
+        let mut step = ReleaseStepState::default();
+        assert_eq!(drift_column(&step), "");
+
+        step.drift_status = Some("DRIFTED".into());
+        step.drift_checked_at = Some("2026-05-31T10:00:00+00:00".into());
+        assert_eq!(
+            drift_column(&step),
+            " drift: DRIFTED (checked 2026-05-31T10:00:00+00:00)"
+        );
+    }
+}
@@ -0,0 +1,95 @@
+use forest_grpc_interface::{ReleaseSchedule, ReleaseScheduleOutcome, ReleaseScheduleStatus};
+
+use crate::state::State;
+
+mod cancel;
+mod create;
+mod list;
+
+#[derive(clap::Parser)]
+pub struct ScheduleCommand {
+    #[command(subcommand)]
+    commands: Commands,
+}
+
+#[derive(clap::Subcommand)]
+enum Commands {
+    /// Schedule a release for a set time, or on a cron expression
+    Create(create::CreateCommand),
+    /// List a project's scheduled releases
+    List(list::ListCommand),
+    /// Cancel a scheduled release
+    Cancel(cancel::CancelCommand),
+}
+
+impl ScheduleCommand {
+    pub fn is_mutation(&self) -> bool {
+        !matches!(self.commands, Commands::List(_))
+    }
+
+    pub async fn execute(&self, state: &State) -> anyhow::Result<()> {
+        match &self.commands {
+            Commands::Create(cmd) => cmd.execute(state).await,
+            Commands::List(cmd) => cmd.execute(state).await,
+            Commands::Cancel(cmd) => cmd.execute(state).await,
+        }
+    }
+}
+
+fn status_label(status: i32) -> &'static str {
+    match ReleaseScheduleStatus::try_from(status) {
+        Ok(ReleaseScheduleStatus::Active) => "active",
+        Ok(ReleaseScheduleStatus::Completed) => "completed",
+        Ok(ReleaseScheduleStatus::Cancelled) => "cancelled",
+        _ => "unknown",
+    }
+}
+
+fn outcome_label(outcome: i32) -> &'static str {
+    match ReleaseScheduleOutcome::try_from(outcome) {
+        Ok(ReleaseScheduleOutcome::Released) => "released",
+        Ok(ReleaseScheduleOutcome::Skipped) => "skipped",
+        Ok(ReleaseScheduleOutcome::Blocked) => "blocked",
+        Ok(ReleaseScheduleOutcome::Failed) => "failed",
+        _ => "unknown",
+    }
+}
+
+fn print_schedule(schedule: &ReleaseSchedule) {
+    println!("{} ({})", schedule.id, status_label(schedule.status));
+
+    if let Some(cron) = &schedule.cron {
+        println!("  cron:             {cron} (UTC)");
+    }
+    if let Some(run_at) = &schedule.run_at {
+        println!("  at:               {run_at}");
+    }
+    match (&schedule.artifact_id, &schedule.branch) {
+        (Some(artifact_id), _) => println!("  artifact:         {artifact_id}"),
+        (None, Some(branch)) => println!("  artifact:         latest on {branch}"),
+        (None, None) => println!("  artifact:         latest"),
+    }
+    if !schedule.environments.is_empty() {
+        println!("  environments:     {}", schedule.environments.join(", "));
+    }
+    if !schedule.destinations.is_empty() {
+        println!("  destinations:     {}", schedule.destinations.join(", "));
+    }
+    if schedule.force {
+        println!("  force:            true");
+    }
+    if schedule.use_pipeline {
+        println!("  use pipeline:     true");
+    }
+    if let Some(next_run_at) = &schedule.next_run_at {
+        println!("  next run:         {next_run_at}");
+    }
+    if let Some(last_run) = &schedule.last_run {
+        println!(
+            "  last run:         {} [{}] {}",
+            last_run.ran_at,
+            outcome_label(last_run.outcome),
+            last_run.message
+        );
+    }
+}
@@ -0,0 +1,39 @@
+use anyhow::Context;
+
+use crate::{cli::prompts, grpc::GrpcClientState, state::State};
+
+#[derive(clap::Parser)]
+pub struct CancelCommand {
+    #[arg(long, short = 'o')]
+    organisation: Option<String>,
+
+    #[arg(long, short = 'p')]
+    project: Option<String>,
+
+    /// Id of the schedule to cancel, as shown by `forest project schedule list`
+    id: String,
+}
+
+impl CancelCommand {
+    pub async fn execute(&self, state: &State) -> anyhow::Result<()> {
+        let organisation = match &self.organisation {
+            Some(o) => o.clone(),
+            None => prompts::select_organisation(state).await?,
+        };
+
+        let project = match &self.project {
+            Some(p) => p.clone(),
+            None => prompts::select_project(state, &organisation).await?,
+        };
+
+        let schedule = state
+            .grpc_client()
+            .cancel_release_schedule(&organisation, &project, &self.id)
+            .await
+            .context("cancel release schedule")?;
+
+        eprintln!("Cancelled scheduled release {}", schedule.id);
+
+        Ok(())
+    }
+}
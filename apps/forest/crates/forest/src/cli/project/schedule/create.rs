@@ -0,0 +1,102 @@
+use anyhow::Context;
+use forest_grpc_interface::{CreateReleaseScheduleRequest, Project};
+
+use crate::{cli::prompts, grpc::GrpcClientState, state::State};
+
+#[derive(clap::Parser)]
+pub struct CreateCommand {
+    #[arg(long, short = 'o')]
+    organisation: Option<String>,
+
+    #[arg(long, short = 'p')]
+    project: Option<String>,
+
+    /// Release once at this time (RFC 3339, e.g. 2026-10-20T06:00:00Z)
+    #[arg(long, conflicts_with = "cron", required_unless_present = "cron")]
+    at: Option<String>,
+
+    /// Release repeatedly on a five-field cron expression, evaluated in UTC
+    /// (e.g. "0 2 * * *" for 02:00 every night)
+    #[arg(long)]
+    cron: Option<String>,
+
+    /// Release this artifact on every run
+    #[arg(long, conflicts_with_all = ["slug", "branch"])]
+    artifact_id: Option<String>,
+
+    /// Release the artifact with this slug on every run
+    #[arg(long, conflicts_with = "branch")]
+    slug: Option<String>,
+
+    /// Release the latest artifact from this branch at each run. Without
+    /// an artifact or branch, the project's latest artifact is released.
+    #[arg(long)]
+    branch: Option<String>,
+
+    /// Target environments to release to (can be repeated)
+    #[arg(long = "env", short = 'e')]
+    environments: Vec<String>,
+
+    /// Target destinations to release to (can be repeated)
+    #[arg(long = "dest", short = 'd')]
+    destinations: Vec<String>,
+
+    /// Whether to force-release (cancel queued releases)
+    #[arg(long, default_value_t = false)]
+    force: bool,
+
+    /// Run the project's release pipeline instead of deploying directly
+    #[arg(long, default_value_t = false)]
+    use_pipeline: bool,
+}
+
+impl CreateCommand {
+    pub async fn execute(&self, state: &State) -> anyhow::Result<()> {
+        let organisation = match &self.organisation {
+            Some(o) => o.clone(),
+            None => prompts::select_organisation(state).await?,
+        };
+
+        let project = match &self.project {
+            Some(p) => p.clone(),
+            None => prompts::select_project(state, &organisation).await?,
+        };
+
+        let grpc = state.grpc_client();
+        let artifact_id = match (&self.artifact_id, &self.slug) {
+            (Some(artifact_id), _) => Some(artifact_id.clone()),
+            (_, Some(slug)) => Some(
+                grpc.get_release_annotation_by_slug(slug)
+                    .await
+                    .context("get release annotation by slug")?
+                    .artifact_id
+                    .to_string(),
+            ),
+            (None, None) => None,
+        };
+
+        let schedule = grpc
+            .create_release_schedule(CreateReleaseScheduleRequest {
+                project: Some(Project {
+                    organisation,
+                    project,
+                    ..Default::default()
+                }),
+                run_at: self.at.clone(),
+                cron: self.cron.clone(),
+                artifact_id,
+                branch: self.branch.clone(),
+                environments: self.environments.clone(),
+                destinations: self.destinations.clone(),
+                force: self.force,
+                use_pipeline: self.use_pipeline,
+            })
+            .await
+            .context("create release schedule")?;
+
+        eprintln!("Scheduled release {}", schedule.id);
+        super::print_schedule(&schedule);
+
+        Ok(())
+    }
+}
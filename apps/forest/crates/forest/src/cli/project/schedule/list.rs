@@ -0,0 +1,50 @@
+use anyhow::Context;
+
+use crate::{cli::prompts, grpc::GrpcClientState, state::State};
+
+#[derive(clap::Parser)]
+pub struct ListCommand {
+    #[arg(long, short = 'o')]
+    organisation: Option<String>,
+
+    #[arg(long, short = 'p')]
+    project: Option<String>,
+
+    /// Also list completed and cancelled schedules
+    #[arg(long, default_value_t = false)]
+    all: bool,
+}
+
+impl ListCommand {
+    pub async fn execute(&self, state: &State) -> anyhow::Result<()> {
+        let organisation = match &self.organisation {
+            Some(o) => o.clone(),
+            None => prompts::select_organisation(state).await?,
+        };
+
+        let project = match &self.project {
+            Some(p) => p.clone(),
+            None => prompts::select_project(state, &organisation).await?,
+        };
+
+        let schedules = state
+            .grpc_client()
+            .list_release_schedules(&organisation, &project, self.all)
+            .await
+            .context("list release schedules")?;
+
+        if schedules.is_empty() {
+            eprintln!("No scheduled releases found");
+            return Ok(());
+        }
+
+        eprintln!("scheduled releases\n");
+
+        for schedule in &schedules {
+            super::print_schedule(schedule);
+            println!();
+        }
+
+        Ok(())
+    }
+}
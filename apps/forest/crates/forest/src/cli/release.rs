@@ -1,15 +1,22 @@
 use crate::{
     cli::release::{
-        annotate::AnnotateCommand, commit::CommitCommand, create::CreateCommand,
-        prepare::PrepareCommand,
+        annotate::AnnotateCommand, cancel::CancelCommand, commit::CommitCommand,
+        create::CreateCommand, diff::DiffCommand, list::ListCommand, prepare::PrepareCommand,
+        queue::QueueCommand, show::ShowCommand, watch::WatchCommand,
     },
     state::State,
 };
 
 pub(crate) mod annotate;
+mod cancel;
 pub(crate) mod commit;
 mod create;
+pub(crate) mod diff;
+mod list;
 pub(crate) mod prepare;
+mod queue;
+mod show;
+pub(crate) mod watch;
 
 #[derive(clap::Parser)]
 #[clap(subcommand_required = false, args_conflicts_with_subcommands = true)]
@@ -32,9 +39,34 @@ pub enum Commands {
     Release(CommitCommand),
     /// Prepare, annotate, and release in one step (annotation-only, no auto-release from triggers).
     Create(CreateCommand),
+    /// Show what changed in the deployment manifests between releases
+    Diff(DiffCommand),
+    /// Stream a release's logs and follow its rollout health
+    Watch(WatchCommand),
+    /// Show releases queued or running per destination
+    Queue(QueueCommand),
+    /// Cancel a queued or running release
+    Cancel(CancelCommand),
+    /// List past releases, filtered by project, environment, branch, author, status or time
+    List(ListCommand),
+    /// Show a release's timeline, errors, health and policy evaluations
+    Show(ShowCommand),
 }
 
 impl ReleaseCommand {
+    pub fn is_mutation(&self) -> bool {
+        !matches!(
+            self.commands,
+            Some(
+                Commands::Diff(_)
+                    | Commands::Watch(_)
+                    | Commands::Queue(_)
+                    | Commands::List(_)
+                    | Commands::Show(_)
+            )
+        )
+    }
+
     pub async fn execute(&self, state: &State) -> anyhow::Result<()> {
         match &self.commands {
             Some(Commands::Prepare(cmd)) => {
@@ -44,6 +76,12 @@ impl ReleaseCommand {
             Some(Commands::Annotate(cmd)) => cmd.execute(state).await?,
             Some(Commands::Release(cmd)) => cmd.execute(state).await?,
             Some(Commands::Create(cmd)) => cmd.execute(state).await?,
+            Some(Commands::Diff(cmd)) => cmd.execute(state).await?,
+            Some(Commands::Watch(cmd)) => cmd.execute(state).await?,
+            Some(Commands::Queue(cmd)) => cmd.execute(state).await?,
+            Some(Commands::Cancel(cmd)) => cmd.execute(state).await?,
+            Some(Commands::List(cmd)) => cmd.execute(state).await?,
+            Some(Commands::Show(cmd)) => cmd.execute(state).await?,
             None => {
                 let cmd = self.release.as_ref().cloned().unwrap_or_default();
                 cmd.execute(state).await?
@@ -0,0 +1,30 @@
+use anyhow::Context;
+
+use crate::{grpc::GrpcClientState, state::State};
+
+/// Cancel a release. Queued releases leave their destination's queue;
+/// assigned or running ones are stopped on their runner. Pipeline stages
+/// that depend on the release are cancelled too.
+#[derive(clap::Parser)]
+pub struct CancelCommand {
+    /// The release to cancel (see `forest release queue`).
+    release_id: String,
+
+    /// Why the release is being cancelled; recorded on the release.
+    #[arg(long)]
+    reason: Option<String>,
+}
+
+impl CancelCommand {
+    pub async fn execute(&self, state: &State) -> anyhow::Result<()> {
+        let status = state
+            .grpc_client()
+            .cancel_release(&self.release_id, self.reason.as_deref())
+            .await
+            .context("cancel release")?;
+
+        eprintln!("release {} {}", self.release_id, status.to_lowercase());
+
+        Ok(())
+    }
+}
@@ -2,6 +2,7 @@ use std::fmt::Display;
 
 use anyhow::Context;
 
+use super::watch::{WatchArgs, follow_health, health_unavailable};
 use crate::{
     grpc::{GetProjectsQuery, GrpcClientState},
     models::{artifacts::ArtifactID, release_annotation::ReleaseAnnotation},
@@ -46,6 +47,14 @@ pub struct CommitCommand {
     /// Use the project's release pipeline instead of deploying directly
     #[arg(long)]
     pub(crate) pipeline: bool,
+
+    /// Follow the release until it is healthy and exit non-zero if it
+    /// fails (1), turns unhealthy (2) or times out waiting for health (3).
+    #[arg(long, conflicts_with_all = ["no_wait", "no_health"])]
+    pub(crate) watch: bool,
+
+    #[command(flatten)]
+    pub(crate) watch_args: WatchArgs,
 }
 
 impl CommitCommand {
@@ -190,10 +199,32 @@ impl CommitCommand {
         );
 
         if !self.no_wait {
-            eprintln!("Waiting for release to complete (streaming logs)...\n");
+            if !self.watch_args.compact() {
+                eprintln!("Waiting for release to complete (streaming logs)...\n");
+            }
 
+            // Show what a plan stage would change once it is waiting for
+            // approval, so the approver doesn't have to go look it up.
+            let mut diffed_stages = std::collections::HashSet::new();
+            let grpc_ref = &grpc;
+            let release_intent_id = release_result.release_intent_id;
             let result = grpc
-                .wait_release(release_result.release_intent_id)
+                .wait_release(release_intent_id, self.watch_args.compact(), move |stage| {
+                    let awaiting = stage.approval_status.as_deref() == Some("AWAITINGAPPROVAL")
+                        && diffed_stages.insert(stage.stage_id.clone());
+                    let stage_id = stage.stage_id.clone();
+                    async move {
+                        if !awaiting {
+                            return;
+                        }
+                        if let Err(e) = self
+                            .print_pending_diff(grpc_ref, release_intent_id, artifact_id, &stage_id)
+                            .await
+                        {
+                            tracing::warn!("failed to show release diff: {e:#}");
+                        }
+                    }
+                })
                 .await
                 .context("wait_release")?;
 
@@ -224,11 +255,19 @@ impl CommitCommand {
             if self.no_health {
                 return Ok(());
             }
-            self.watch_health(
+            let outcome = follow_health(
                 &grpc,
                 release_result.release_intent_id,
+                self.watch_args
+                    .health_timeout_or(if self.watch { 300 } else { 90 }),
+                self.watch_args.compact(),
             )
             .await;
+            // Without --watch, health is a courtesy: stay quiet if the
+            // service isn't there.
+            if self.watch {
+                outcome.unwrap_or_else(health_unavailable).exit_on_failure();
+            }
         } else {
             tracing::info!("release staged for {artifact_id}");
         }
@@ -238,85 +277,45 @@ impl CommitCommand {
 }
 
 impl CommitCommand {
-    /// Watch health updates for a release intent after deployment completes.
-    /// Streams health events for up to 60 seconds or until all destinations are healthy.
-    async fn watch_health(
+    /// Print the manifest diff for the environment a plan stage is gating.
+    async fn print_pending_diff(
         &self,
         grpc: &crate::grpc::GrpcClient,
         release_intent_id: uuid::Uuid,
-    ) {
-        let mut client = match grpc.health_client().await {
-            Ok(c) => c,
-            Err(_) => return, // Health service not available, skip silently
+        artifact_id: ArtifactID,
+        stage_id: &str,
+    ) -> anyhow::Result<()> {
+        let (organisation, project) = match (&self.organisation, &self.project) {
+            (Some(org), Some(project)) => (org.clone(), project.clone()),
+            _ => {
+                let Some(slug) = &self.slug else {
+                    return Ok(());
+                };
+                let Some(project) = grpc.get_release_annotation_by_slug(slug).await?.project
+                else {
+                    return Ok(());
+                };
+                (project.organisation, project.project)
+            }
         };
 
-        let timeout = tokio::time::sleep(std::time::Duration::from_secs(90));
-        tokio::pin!(timeout);
-
-        eprintln!("\nWatching health...");
-
-        let mut seen_statuses: std::collections::HashSet<String> = std::collections::HashSet::new();
-        let poll_interval = std::time::Duration::from_secs(5);
-        let mut waiting_printed = false;
-
-        loop {
-            tokio::select! {
-                _ = tokio::time::sleep(poll_interval) => {
-                    let resp = match client
-                        .get_release_health(forest_grpc_interface::GetReleaseHealthRequest {
-                            release_intent_id: release_intent_id.to_string(),
-                        })
-                        .await
-                    {
-                        Ok(r) => r.into_inner(),
-                        Err(_) => continue,
-                    };
-
-                    // Skip if no data yet or only PENDING/UNSPECIFIED
-                    let has_real_data = resp.destinations.iter().any(|d| {
-                        d.status != forest_grpc_interface::HealthStatus::Unspecified as i32
-                            && d.status != 0
-                    });
-
-                    if !has_real_data {
-                        if !waiting_printed {
-                            eprintln!("  waiting for health agent...");
-                            waiting_printed = true;
-                        }
-                        continue;
-                    }
-
-                    for dest in &resp.destinations {
-                        let status_str = match dest.status {
-                            x if x == forest_grpc_interface::HealthStatus::Healthy as i32 => "HEALTHY",
-                            x if x == forest_grpc_interface::HealthStatus::Progressing as i32 => "PROGRESSING",
-                            x if x == forest_grpc_interface::HealthStatus::Degraded as i32 => "DEGRADED",
-                            x if x == forest_grpc_interface::HealthStatus::Unhealthy as i32 => "UNHEALTHY",
-                            x if x == forest_grpc_interface::HealthStatus::Missing as i32 => "MISSING",
-                            _ => continue, // Skip PENDING/UNSPECIFIED
-                        };
-
-                        let key = format!("{}:{}", dest.destination, status_str);
-                        if seen_statuses.insert(key) {
-                            eprintln!(
-                                "  [{env}] {dest}  HEALTH: {status_str}",
-                                env = dest.environment,
-                                dest = dest.destination,
-                            );
-                        }
-                    }
+        let intents = grpc
+            .get_release_intent_states(&organisation, Some(&project), false)
+            .await?;
+        let Some(environment) = intents
+            .release_intents
+            .iter()
+            .filter(|i| i.release_intent_id == release_intent_id.to_string())
+            .flat_map(|i| &i.stages)
+            .find(|s| s.stage_id == stage_id)
+            .and_then(|s| s.environment.clone())
+        else {
+            return Ok(());
+        };
 
-                    if resp.aggregate_status == forest_grpc_interface::HealthStatus::Healthy as i32 {
-                        eprintln!("\nRelease healthy.");
-                        return;
-                    }
-                }
-                _ = &mut timeout => {
-                    eprintln!("\nHealth watch timed out.");
-                    return;
-                }
-            }
-        }
+        eprintln!("\n    changes awaiting approval in {environment}:\n");
+        super::diff::print_pending_diff(grpc, &organisation, &project, artifact_id, &environment)
+            .await
     }
 }
 
@@ -10,6 +10,7 @@ use super::{
     annotate::{self, git_output, AnnotateParams},
     commit::CommitCommand,
     prepare::PrepareCommand,
+    watch::WatchArgs,
 };
 
 /// Combined command: prepare → annotate (without auto-release) → release.
@@ -113,6 +114,10 @@ pub struct CreateCommand {
     #[arg(long = "set", value_name = "KEY=VALUE")]
     overrides: Vec<String>,
 
+    /// Skip building local path components before preparing.
+    #[arg(long)]
+    no_build: bool,
+
     /// Skip waiting for the release to complete.
     #[arg(long)]
     no_wait: bool,
@@ -128,6 +133,14 @@ pub struct CreateCommand {
     /// Use the project's release pipeline instead of deploying directly.
     #[arg(long)]
     pipeline: bool,
+
+    /// Follow the release until it is healthy; exits non-zero if it fails,
+    /// turns unhealthy or times out waiting for health.
+    #[arg(long, conflicts_with_all = ["no_wait", "no_health"])]
+    watch: bool,
+
+    #[command(flatten)]
+    watch_args: WatchArgs,
 }
 
 impl CreateCommand {
@@ -198,6 +211,7 @@ impl CreateCommand {
         tracing::info!("step 1/3: prepare");
         let prepare = PrepareCommand {
             overrides: self.overrides.clone(),
+            no_build: self.no_build,
         };
         prepare.execute(state).await.context("prepare")?;
 
@@ -251,6 +265,8 @@ impl CreateCommand {
             no_health: self.no_health,
             force: self.force,
             pipeline: self.pipeline,
+            watch: self.watch,
+            watch_args: self.watch_args.clone(),
             ..Default::default()
         };
         commit.execute(state).await.context("release")?;
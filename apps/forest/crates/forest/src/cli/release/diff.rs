@@ -0,0 +1,574 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use anyhow::Context;
+use serde::Serialize;
+use similar::TextDiff;
+use uuid::Uuid;
+
+use crate::{
+    cli::{output::OutputFormat, prompts},
+    grpc::{GrpcClient, GrpcClientState},
+    state::State,
+};
+
+/// Show what changed between two releases' deployment manifests.
+///
+/// - `forest release diff <from> <to>` compares two artifact slugs.
+/// - `forest release diff <slug>` compares what is currently deployed
+///   against the given slug (i.e. what releasing it would change).
+/// - `forest release diff` compares the current release in each
+///   environment against the one deployed before it.
+#[derive(clap::Parser)]
+pub struct DiffCommand {
+    /// Artifact slugs to compare (zero, one or two).
+    #[arg(num_args = 0..=2, value_name = "SLUG")]
+    slugs: Vec<String>,
+
+    #[arg(long, short = 'o')]
+    organisation: Option<String>,
+
+    #[arg(long, short = 'p')]
+    project: Option<String>,
+
+    /// Only compare files for this environment.
+    #[arg(long, short = 'e', alias = "env")]
+    environment: Option<String>,
+
+    /// Only compare files for this destination.
+    #[arg(long, short = 'd')]
+    destination: Option<String>,
+
+    /// Also compare the project spec (forest.cue) uploaded with each artifact.
+    #[arg(long)]
+    spec: bool,
+
+    /// Number of context lines around each change.
+    #[arg(long, short = 'U', default_value_t = 3)]
+    unified: usize,
+}
+
+impl DiffCommand {
+    pub async fn execute(&self, state: &State) -> anyhow::Result<()> {
+        let grpc = state.grpc_client();
+
+        let comparisons = match self.slugs.as_slice() {
+            [from, to] => {
+                let (from, to) = tokio::try_join!(
+                    grpc.get_release_annotation_by_slug(from),
+                    grpc.get_release_annotation_by_slug(to),
+                )
+                .context("resolve slugs")?;
+
+                vec![Comparison {
+                    environment: self.environment.clone(),
+                    from: Some(ArtifactRef::new(from.artifact_id, Some(from.slug))),
+                    to: ArtifactRef::new(to.artifact_id, Some(to.slug)),
+                }]
+            }
+            [slug] => {
+                let annotation = grpc
+                    .get_release_annotation_by_slug(slug)
+                    .await
+                    .context("resolve slug")?;
+                let project = annotation
+                    .project
+                    .clone()
+                    .context("artifact is not associated with a project")?;
+                let history =
+                    DeployHistory::load(&grpc, &project.organisation, &project.project).await?;
+
+                let to = ArtifactRef::new(annotation.artifact_id, Some(annotation.slug.clone()));
+                let environments = self.environments_or(history.environments());
+                if environments.is_empty() {
+                    // Nothing deployed yet: everything in the artifact is new.
+                    vec![Comparison {
+                        environment: None,
+                        from: None,
+                        to,
+                    }]
+                } else {
+                    environments
+                        .into_iter()
+                        .map(|env| Comparison {
+                            from: history.current(&env),
+                            environment: Some(env),
+                            to: to.clone(),
+                        })
+                        .collect()
+                }
+            }
+            _ => {
+                let organisation = match &self.organisation {
+                    Some(o) => o.clone(),
+                    None => prompts::select_organisation(state).await?,
+                };
+                let project = match &self.project {
+                    Some(p) => p.clone(),
+                    None => prompts::select_project(state, &organisation).await?,
+                };
+                let history = DeployHistory::load(&grpc, &organisation, &project).await?;
+
+                let environments = self.environments_or(history.environments());
+                if environments.is_empty() {
+                    anyhow::bail!("no successful releases found for {organisation}/{project}");
+                }
+
+                let mut comparisons = Vec::new();
+                for env in environments {
+                    let Some(current) = history.current(&env) else {
+                        eprintln!("{env}: nothing deployed yet, skipping");
+                        continue;
+                    };
+                    comparisons.push(Comparison {
+                        from: history.previous(&env),
+                        environment: Some(env),
+                        to: current,
+                    });
+                }
+                comparisons
+            }
+        };
+
+        let filter = FileFilter {
+            destination: self.destination.clone(),
+            include_spec: self.spec,
+        };
+
+        let mut reports = Vec::new();
+        for comparison in comparisons {
+            reports.push(diff_artifacts(&grpc, comparison, &filter, self.unified).await?);
+        }
+
+        match state.config.format {
+            OutputFormat::Json => {
+                println!("{}", serde_json::to_string_pretty(&reports)?);
+            }
+            _ => {
+                let color = use_color();
+                for report in &reports {
+                    print!("{}", report.render(color));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn environments_or(&self, known: Vec<String>) -> Vec<String> {
+        match &self.environment {
+            Some(env) => vec![env.clone()],
+            None => known,
+        }
+    }
+}
+
+/// Print the manifest diff between what is live in `environment` and
+/// `artifact_id`. Used while a plan stage waits for approval so the
+/// approver can see what the release is about to change.
+pub(crate) async fn print_pending_diff(
+    grpc: &GrpcClient,
+    organisation: &str,
+    project: &str,
+    artifact_id: Uuid,
+    environment: &str,
+) -> anyhow::Result<()> {
+    let history = DeployHistory::load(grpc, organisation, project).await?;
+
+    let comparison = Comparison {
+        environment: Some(environment.to_string()),
+        from: history.current(environment),
+        to: ArtifactRef::new(artifact_id, history.slugs.get(&artifact_id).cloned()),
+    };
+
+    let report = diff_artifacts(grpc, comparison, &FileFilter::default(), 3).await?;
+    print!("{}", report.render(use_color()));
+
+    Ok(())
+}
+
+fn use_color() -> bool {
+    use std::io::IsTerminal;
+    std::io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none()
+}
+
+#[derive(Clone, Serialize)]
+struct ArtifactRef {
+    artifact_id: Uuid,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    slug: Option<String>,
+}
+
+impl ArtifactRef {
+    fn new(artifact_id: Uuid, slug: Option<String>) -> Self {
+        Self { artifact_id, slug }
+    }
+
+    fn label(&self) -> String {
+        match &self.slug {
+            Some(slug) => slug.clone(),
+            None => self.artifact_id.to_string(),
+        }
+    }
+}
+
+struct Comparison {
+    environment: Option<String>,
+    /// `None` when nothing has been deployed before — every file is new.
+    from: Option<ArtifactRef>,
+    to: ArtifactRef,
+}
+
+#[derive(Default)]
+struct FileFilter {
+    destination: Option<String>,
+    include_spec: bool,
+}
+
+/// Successful deploys for a project, newest first, grouped by environment.
+struct DeployHistory {
+    by_environment: BTreeMap<String, Vec<Uuid>>,
+    slugs: HashMap<Uuid, String>,
+}
+
+impl DeployHistory {
+    async fn load(grpc: &GrpcClient, organisation: &str, project: &str) -> anyhow::Result<Self> {
+        let (intents, annotations) = tokio::try_join!(
+            grpc.get_release_intent_states(organisation, Some(project), true),
+            grpc.get_release_annotations_by_project(organisation, project),
+        )
+        .context("load release history")?;
+
+        // Intents come back ordered by creation time, newest first, so the
+        // first artifact seen per environment is the one currently live.
+        let mut by_environment: BTreeMap<String, Vec<Uuid>> = BTreeMap::new();
+        for intent in &intents.release_intents {
+            let Ok(artifact_id) = intent.artifact_id.parse::<Uuid>() else {
+                continue;
+            };
+            for step in intent.steps.iter().filter(|s| s.status == "SUCCEEDED") {
+                let artifacts = by_environment.entry(step.environment.clone()).or_default();
+                if !artifacts.contains(&artifact_id) {
+                    artifacts.push(artifact_id);
+                }
+            }
+        }
+
+        let slugs = annotations
+            .into_iter()
+            .map(|a| (a.artifact_id, a.slug))
+            .collect();
+
+        Ok(Self {
+            by_environment,
+            slugs,
+        })
+    }
+
+    fn environments(&self) -> Vec<String> {
+        self.by_environment.keys().cloned().collect()
+    }
+
+    fn nth(&self, environment: &str, n: usize) -> Option<ArtifactRef> {
+        let artifact_id = *self.by_environment.get(environment)?.get(n)?;
+        Some(ArtifactRef::new(
+            artifact_id,
+            self.slugs.get(&artifact_id).cloned(),
+        ))
+    }
+
+    fn current(&self, environment: &str) -> Option<ArtifactRef> {
+        self.nth(environment, 0)
+    }
+
+    fn previous(&self, environment: &str) -> Option<ArtifactRef> {
+        self.nth(environment, 1)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct FileKey {
+    environment: String,
+    destination: String,
+    file_name: String,
+}
+
+impl FileKey {
+    fn path(&self) -> String {
+        if self.destination.is_empty() {
+            self.file_name.clone()
+        } else {
+            format!("{}/{}", self.destination, self.file_name)
+        }
+    }
+}
+
+async fn load_files(
+    grpc: &GrpcClient,
+    artifact: &ArtifactRef,
+    environment: Option<&str>,
+    filter: &FileFilter,
+) -> anyhow::Result<BTreeMap<FileKey, String>> {
+    let files = grpc
+        .get_artifact_files(&artifact.artifact_id, Some("deployment"))
+        .await
+        .with_context(|| format!("get files for {}", artifact.label()))?;
+
+    let mut out: BTreeMap<FileKey, String> = files
+        .into_iter()
+        .filter(|f| environment.is_none_or(|env| f.env == env))
+        .filter(|f| {
+            filter
+                .destination
+                .as_deref()
+                .is_none_or(|dest| f.destination == dest)
+        })
+        .map(|f| {
+            (
+                FileKey {
+                    environment: f.env,
+                    destination: f.destination,
+                    file_name: f.file_name,
+                },
+                f.content,
+            )
+        })
+        .collect();
+
+    if filter.include_spec {
+        let spec = grpc
+            .get_artifact_spec(&artifact.artifact_id)
+            .await
+            .with_context(|| format!("get spec for {}", artifact.label()))?;
+        if !spec.is_empty() {
+            out.insert(
+                FileKey {
+                    environment: String::new(),
+                    destination: String::new(),
+                    file_name: "forest.cue".into(),
+                },
+                spec,
+            );
+        }
+    }
+
+    Ok(out)
+}
+
+async fn diff_artifacts(
+    grpc: &GrpcClient,
+    comparison: Comparison,
+    filter: &FileFilter,
+    context_lines: usize,
+) -> anyhow::Result<DiffReport> {
+    let env = comparison.environment.as_deref();
+
+    let (old, new) = match &comparison.from {
+        Some(from) => tokio::try_join!(
+            load_files(grpc, from, env, filter),
+            load_files(grpc, &comparison.to, env, filter),
+        )?,
+        None => (
+            BTreeMap::new(),
+            load_files(grpc, &comparison.to, env, filter).await?,
+        ),
+    };
+
+    let files = diff_file_sets(&old, &new, context_lines);
+
+    Ok(DiffReport {
+        environment: comparison.environment,
+        from: comparison.from,
+        to: comparison.to,
+        files,
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum FileChange {
+    Added,
+    Removed,
+    Modified,
+}
+
+#[derive(Debug, Serialize)]
+struct FileDiff {
+    environment: String,
+    destination: String,
+    file_name: String,
+    change: FileChange,
+    /// Unified diff hunks (without the `---`/`+++` header lines).
+    diff: String,
+}
+
+#[derive(Serialize)]
+struct DiffReport {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    environment: Option<String>,
+    from: Option<ArtifactRef>,
+    to: ArtifactRef,
+    files: Vec<FileDiff>,
+}
+
+fn diff_file_sets(
+    old: &BTreeMap<FileKey, String>,
+    new: &BTreeMap<FileKey, String>,
+    context_lines: usize,
+) -> Vec<FileDiff> {
+    let keys: BTreeSet<&FileKey> = old.keys().chain(new.keys()).collect();
+
+    keys.into_iter()
+        .filter_map(|key| {
+            let (change, before, after) = match (old.get(key), new.get(key)) {
+                (Some(a), Some(b)) if a == b => return None,
+                (Some(a), Some(b)) => (FileChange::Modified, a.as_str(), b.as_str()),
+                (None, Some(b)) => (FileChange::Added, "", b.as_str()),
+                (Some(a), None) => (FileChange::Removed, a.as_str(), ""),
+                (None, None) => return None,
+            };
+
+            let diff = TextDiff::from_lines(before, after)
+                .unified_diff()
+                .context_radius(context_lines)
+                .to_string();
+
+            Some(FileDiff {
+                environment: key.environment.clone(),
+                destination: key.destination.clone(),
+                file_name: key.file_name.clone(),
+                change,
+                diff,
+            })
+        })
+        .collect()
+}
+
+impl DiffReport {
+    fn render(&self, color: bool) -> String {
+        use std::fmt::Write;
+
+        let mut out = String::new();
+        let scope = self.environment.as_deref().unwrap_or("all environments");
+        let from = self
+            .from
+            .as_ref()
+            .map(|f| f.label())
+            .unwrap_or_else(|| "(nothing deployed)".into());
+        let _ = writeln!(out, "Comparing: {scope} ({from} → {})", self.to.label());
+
+        if self.files.is_empty() {
+            let _ = writeln!(out, "\nNo changes.\n");
+            return out;
+        }
+
+        for file in &self.files {
+            let key = FileKey {
+                environment: file.environment.clone(),
+                destination: file.destination.clone(),
+                file_name: file.file_name.clone(),
+            };
+            let path = key.path();
+            let (old_marker, new_marker) = match file.change {
+                FileChange::Added => (" (new)", ""),
+                FileChange::Removed => ("", " (removed)"),
+                FileChange::Modified => ("", ""),
+            };
+
+            out.push('\n');
+            if self.environment.is_none() && !file.environment.is_empty() {
+                let _ = writeln!(out, "[{}]", file.environment);
+            }
+            let _ = writeln!(out, "{}", paint(&format!("--- {path}{old_marker}"), "1", color));
+            let _ = writeln!(out, "{}", paint(&format!("+++ {path}{new_marker}"), "1", color));
+            for line in file.diff.lines() {
+                let styled = if line.starts_with("@@") {
+                    paint(line, "36", color)
+                } else if line.starts_with('+') {
+                    paint(line, "32", color)
+                } else if line.starts_with('-') {
+                    paint(line, "31", color)
+                } else {
+                    line.to_string()
+                };
+                let _ = writeln!(out, "{styled}");
+            }
+        }
+        out.push('\n');
+
+        out
+    }
+}
+
+fn paint(text: &str, code: &str, color: bool) -> String {
+    if color {
+        format!("\x1b[{code}m{text}\x1b[0m")
+    } else {
+        text.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(destination: &str, file_name: &str) -> FileKey {
+        FileKey {
+            environment: "prod".into(),
+            destination: destination.into(),
+            file_name: file_name.into(),
+        }
+    }
+
+    #[test]
+    fn diff_file_sets_classifies_changes() {
+        let old = BTreeMap::from([
+            (key("k8s", "deployment.yaml"), "replicas: 1\nimage: a\n".into()),
+            (key("k8s", "service.yaml"), "port: 80\n".into()),
+            (key("k8s", "old.yaml"), "gone\n".into()),
+        ]);
+        let new = BTreeMap::from([
+            (key("k8s", "deployment.yaml"), "replicas: 2\nimage: a\n".into()),
+            (key("k8s", "service.yaml"), "port: 80\n".into()),
+            (key("k8s", "new.yaml"), "hello\n".into()),
+        ]);
+
+        let files = diff_file_sets(&old, &new, 3);
+        let changes: Vec<_> = files
+            .iter()
+            .map(|f| (f.file_name.as_str(), f.change))
+            .collect();
+
+        assert_eq!(
+            changes,
+            vec![
+                ("deployment.yaml", FileChange::Modified),
+                ("new.yaml", FileChange::Added),
+                ("old.yaml", FileChange::Removed),
+            ]
+        );
+
+        let modified = &files[0].diff;
+        assert!(modified.contains("-replicas: 1"));
+        assert!(modified.contains("+replicas: 2"));
+    }
+
+    #[test]
+    fn render_marks_new_files() {
+        let report = DiffReport {
+            environment: Some("prod".into()),
+            from: None,
+            to: ArtifactRef::new(Uuid::nil(), Some("my-release".into())),
+            files: diff_file_sets(
+                &BTreeMap::new(),
+                &BTreeMap::from([(key("k8s", "a.yaml"), "x: 1\n".into())]),
+                3,
+            ),
+        };
+
+        let out = report.render(false);
+        assert!(out.contains("Comparing: prod ((nothing deployed) → my-release)"));
+        assert!(out.contains("--- k8s/a.yaml (new)"));
+        assert!(out.contains("+++ k8s/a.yaml"));
+        assert!(out.contains("+x: 1"));
+    }
+}
@@ -0,0 +1,224 @@
+use anyhow::Context;
+use chrono::{DateTime, NaiveDate, Utc};
+use forest_grpc_interface::{ListReleasesRequest, ReleaseHistoryEntry};
+use serde::Serialize;
+use tabled::Tabled;
+
+use crate::{
+    cli::{
+        output::{self, OutputFormat},
+        prompts,
+    },
+    grpc::GrpcClientState,
+    state::State,
+};
+
+/// List past releases, newest first.
+///
+/// Times accept a date (`2026-10-13`, midnight UTC), an RFC 3339
+/// timestamp, or a duration ago (`90m`, `36h`, `7d`). `--until` is
+/// exclusive, so a single day is `--since 2026-10-13 --until 2026-10-14`.
+///
+/// Examples:
+///   forest release list -p my-service -e prod --since 7d
+///   forest release list --author alice --status failed
+#[derive(clap::Parser)]
+pub struct ListCommand {
+    #[arg(long, short = 'o')]
+    organisation: Option<String>,
+
+    /// Only releases of this project. Defaults to every project.
+    #[arg(long, short = 'p')]
+    project: Option<String>,
+
+    /// Only releases deployed to this environment.
+    #[arg(long, short = 'e', alias = "env")]
+    environment: Option<String>,
+
+    /// Only releases built from this git branch.
+    #[arg(long)]
+    branch: Option<String>,
+
+    /// Only releases annotated by this username or email.
+    #[arg(long)]
+    author: Option<String>,
+
+    /// Only releases with a deploy step in this status (e.g. succeeded, failed).
+    #[arg(long)]
+    status: Option<String>,
+
+    /// Only releases created at or after this time.
+    #[arg(long)]
+    since: Option<String>,
+
+    /// Only releases created before this time.
+    #[arg(long)]
+    until: Option<String>,
+
+    /// Releases per page (max 100).
+    #[arg(long, default_value_t = 20)]
+    limit: i32,
+
+    /// Continue from a previous page.
+    #[arg(long)]
+    page_token: Option<String>,
+}
+
+#[derive(Tabled, Serialize)]
+struct ReleaseRow {
+    #[tabled(rename = "Slug")]
+    slug: String,
+    #[tabled(rename = "Project")]
+    project: String,
+    #[tabled(rename = "Title")]
+    title: String,
+    #[tabled(rename = "Branch")]
+    branch: String,
+    #[tabled(rename = "Author")]
+    author: String,
+    #[tabled(rename = "Destinations")]
+    destinations: String,
+    #[tabled(rename = "Created")]
+    created_at: String,
+}
+
+impl From<ReleaseHistoryEntry> for ReleaseRow {
+    fn from(r: ReleaseHistoryEntry) -> Self {
+        let source = r.source.unwrap_or_default();
+        Self {
+            slug: r.slug,
+            project: r.project.map(|p| p.project).unwrap_or_default(),
+            title: r.context.map(|c| c.title).unwrap_or_default(),
+            branch: r.r#ref.and_then(|r| r.branch).unwrap_or_default(),
+            author: source.user.or(source.email).unwrap_or_default(),
+            destinations: r
+                .destinations
+                .iter()
+                .map(|d| format!("{}/{}: {}", d.environment, d.destination, d.status))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            created_at: r.created_at,
+        }
+    }
+}
+
+impl ListCommand {
+    pub async fn execute(&self, state: &State) -> anyhow::Result<()> {
+        let organisation = match &self.organisation {
+            Some(org) => org.clone(),
+            None => prompts::select_organisation(state).await?,
+        };
+
+        let now = Utc::now();
+        let since = self
+            .since
+            .as_deref()
+            .map(|s| parse_time_bound(s, now).context("--since"))
+            .transpose()?;
+        let until = self
+            .until
+            .as_deref()
+            .map(|s| parse_time_bound(s, now).context("--until"))
+            .transpose()?;
+
+        let resp = state
+            .grpc_client()
+            .list_releases(ListReleasesRequest {
+                organisation,
+                project: self.project.clone(),
+                environment: self.environment.clone(),
+                branch: self.branch.clone(),
+                author: self.author.clone(),
+                status: self.status.clone(),
+                since,
+                until,
+                page_size: self.limit,
+                page_token: self.page_token.clone().unwrap_or_default(),
+            })
+            .await
+            .context("list releases")?;
+
+        let rows: Vec<ReleaseRow> = resp.releases.into_iter().map(Into::into).collect();
+
+        let format = &state.config.format;
+        if rows.is_empty() {
+            match format {
+                OutputFormat::Json => println!("[]"),
+                _ => eprintln!("No releases found"),
+            }
+            return Ok(());
+        }
+
+        print!("{}", output::render(format, &rows));
+
+        if !resp.next_page_token.is_empty() {
+            eprintln!("More releases: rerun with --page-token {}", resp.next_page_token);
+        }
+
+        Ok(())
+    }
+}
+
+/// Resolve a `--since`/`--until` value to RFC 3339.
+fn parse_time_bound(value: &str, now: DateTime<Utc>) -> anyhow::Result<String> {
+    if let Ok(t) = DateTime::parse_from_rfc3339(value) {
+        return Ok(t.with_timezone(&Utc).to_rfc3339());
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return Ok(date.and_time(chrono::NaiveTime::MIN).and_utc().to_rfc3339());
+    }
+
+    let (amount, unit) = value.split_at(value.len().saturating_sub(1));
+    let amount: i64 = amount
+        .parse()
+        .with_context(|| format!("expected a date, RFC 3339 time or duration like 7d, got '{value}'"))?;
+    let ago = match unit {
+        "m" => chrono::Duration::minutes(amount),
+        "h" => chrono::Duration::hours(amount),
+        "d" => chrono::Duration::days(amount),
+        "w" => chrono::Duration::weeks(amount),
+        _ => anyhow::bail!("unknown duration unit in '{value}', use m, h, d or w"),
+    };
+    Ok((now - ago).to_rfc3339())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn now() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-10-19T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn dates_are_midnight_utc() {
+        assert_eq!(
+            parse_time_bound("2026-10-13", now()).unwrap(),
+            "2026-10-13T00:00:00+00:00"
+        );
+    }
+
+    #[test]
+    fn timestamps_are_normalised_to_utc() {
+        assert_eq!(
+            parse_time_bound("2026-10-13T09:30:00+02:00", now()).unwrap(),
+            "2026-10-13T07:30:00+00:00"
+        );
+    }
+
+    #[test]
+    fn durations_count_back_from_now() {
+        assert_eq!(
+            parse_time_bound("36h", now()).unwrap(),
+            "2026-10-18T00:00:00+00:00"
+        );
+        assert_eq!(
+            parse_time_bound("1w", now()).unwrap(),
+            "2026-10-12T12:00:00+00:00"
+        );
+        assert!(parse_time_bound("3y", now()).is_err());
+        assert!(parse_time_bound("last tuesday", now()).is_err());
+    }
+}
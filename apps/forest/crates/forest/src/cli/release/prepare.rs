@@ -9,7 +9,7 @@ use crate::{
     component_cache::ComponentCacheState,
     contracts::{self, EnabledContracts},
     forest_context::ForestContextState,
-    models::{ComponentReference, ProjectValue},
+    models::{ComponentReference, DependencyType, ProjectValue},
     services::{
         component_binary, component_deno, components::ComponentsServiceState,
         project::ProjectParserState, templates::TemplatesServiceState,
@@ -24,6 +24,11 @@ pub struct PrepareCommand {
     /// Nested keys use dots: --set kjuulh/service.env_vars.LOG_LEVEL=debug
     #[arg(long = "set", value_name = "KEY=VALUE")]
     pub overrides: Vec<String>,
+
+    /// Use the local path components as they are, instead of building
+    /// them first.
+    #[arg(long)]
+    pub no_build: bool,
 }
 
 impl PrepareCommand {
@@ -49,6 +54,23 @@ impl PrepareCommand {
 
         tracing::info!("enabled contracts: {}", enabled_contracts);
 
+        // Local path components are built from source, so rebuild any that
+        // changed; otherwise prepare would run against stale binaries.
+        let has_local = project
+            .dependencies
+            .dependencies
+            .iter()
+            .any(|dep| matches!(dep.dependency_type, DependencyType::Local(_)));
+        if has_local && !self.no_build {
+            crate::cli::components::workspace::build_workspace(
+                state,
+                &project.path,
+                &crate::cli::components::build::BuildCommand::default(),
+            )
+            .await
+            .context("building local path components")?;
+        }
+
         // Auto-resolve missing dependencies (cargo-build-style). Versioned
         // deps that aren't already in the cache get downloaded here, so
         // `release prepare` works on a clean checkout without the user
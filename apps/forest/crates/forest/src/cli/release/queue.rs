@@ -0,0 +1,97 @@
+use anyhow::Context;
+use forest_grpc_interface::ReleaseQueuePolicy;
+use serde::Serialize;
+use tabled::Tabled;
+
+use crate::{
+    cli::{
+        output::{self, OutputFormat},
+        prompts,
+    },
+    grpc::GrpcClientState,
+    state::State,
+};
+
+/// Show releases waiting on or running against each destination.
+///
+/// Releases to a destination run one at a time, oldest first. Position 1
+/// is the release currently running (or next up); the rest wait behind it
+/// unless the destination's queue policy is `supersede`, in which case a
+/// newer release replaces anything still queued.
+#[derive(clap::Parser)]
+pub struct QueueCommand {
+    #[arg(long, short = 'o')]
+    organisation: Option<String>,
+
+    /// Only show releases for this project.
+    #[arg(long, short = 'p')]
+    project: Option<String>,
+}
+
+#[derive(Tabled, Serialize)]
+struct QueueRow {
+    #[tabled(rename = "Environment")]
+    environment: String,
+    #[tabled(rename = "Destination")]
+    destination: String,
+    #[tabled(rename = "Policy")]
+    policy: String,
+    #[tabled(rename = "#")]
+    position: i32,
+    #[tabled(rename = "Status")]
+    status: String,
+    #[tabled(rename = "Release")]
+    release_id: String,
+    #[tabled(rename = "Queued")]
+    queued_at: String,
+}
+
+impl QueueCommand {
+    pub async fn execute(&self, state: &State) -> anyhow::Result<()> {
+        let organisation = match &self.organisation {
+            Some(org) => org.clone(),
+            None => prompts::select_organisation(state).await?,
+        };
+
+        let resp = state
+            .grpc_client()
+            .get_destination_states(&organisation, self.project.as_deref())
+            .await
+            .context("get destination states")?;
+
+        let rows: Vec<QueueRow> = resp
+            .destinations
+            .into_iter()
+            .filter_map(|d| {
+                let position = d.queue_position?;
+                let policy = match d.queue_policy() {
+                    ReleaseQueuePolicy::Supersede => "supersede",
+                    _ => "wait",
+                };
+
+                Some(QueueRow {
+                    environment: d.environment,
+                    destination: d.destination_name,
+                    policy: policy.into(),
+                    position,
+                    status: d.status.unwrap_or_default(),
+                    release_id: d.release_id.unwrap_or_default(),
+                    queued_at: d.queued_at.unwrap_or_default(),
+                })
+            })
+            .collect();
+
+        let format = &state.config.format;
+        if rows.is_empty() {
+            match format {
+                OutputFormat::Json => println!("[]"),
+                _ => eprintln!("No releases queued or running"),
+            }
+            return Ok(());
+        }
+
+        print!("{}", output::render(format, &rows));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,176 @@
+use anyhow::Context;
+use forest_grpc_interface::{LogChannel, ReleaseAttempt};
+
+use crate::{cli::prompts, grpc::GrpcClientState, state::State};
+
+/// Show everything Forest knows about one release artifact.
+///
+/// Prints the artifact, every release of it with each step's timeline,
+/// errors and health, and the policies for each environment it was
+/// released to as they evaluate now.
+///
+/// Examples:
+///   forest release show my-service-a1b2c3 -p my-service
+///   forest release show my-service-a1b2c3 -p my-service --logs
+#[derive(clap::Parser)]
+pub struct ShowCommand {
+    /// Release slug, as printed by `forest release list`.
+    slug: String,
+
+    #[arg(long, short = 'o')]
+    organisation: Option<String>,
+
+    /// Project the release belongs to.
+    #[arg(long, short = 'p')]
+    project: Option<String>,
+
+    /// Include each step's log output.
+    #[arg(long)]
+    logs: bool,
+}
+
+impl ShowCommand {
+    pub async fn execute(&self, state: &State) -> anyhow::Result<()> {
+        let organisation = match &self.organisation {
+            Some(o) => o.clone(),
+            None => prompts::select_organisation(state).await?,
+        };
+        let project = match &self.project {
+            Some(p) => p.clone(),
+            None => prompts::select_project(state, &organisation).await?,
+        };
+
+        let resp = state
+            .grpc_client()
+            .get_release(&organisation, &project, &self.slug, self.logs)
+            .await
+            .context("get release")?;
+
+        let artifact = resp.artifact.context("release has no artifact")?;
+        let project = artifact.project.unwrap_or_default();
+        let context = artifact.context.unwrap_or_default();
+        let source = artifact.source.unwrap_or_default();
+        let reference = artifact.r#ref.unwrap_or_default();
+
+        println!("{} ({}/{})", artifact.slug, project.organisation, project.project);
+        println!("  title:   {}", context.title);
+        if let Some(description) = &context.description {
+            println!("  description: {description}");
+        }
+        if let Some(branch) = &reference.branch {
+            println!("  branch:  {branch}");
+        }
+        if !reference.commit_sha.is_empty() {
+            println!("  commit:  {}", reference.commit_sha);
+        }
+        if let Some(author) = source.user.as_ref().or(source.email.as_ref()) {
+            println!("  author:  {author}");
+        }
+        println!("  created: {}", artifact.created_at);
+        if let Some(url) = &source.run_url {
+            println!("  run:     {url}");
+        }
+
+        if resp.attempts.is_empty() {
+            println!();
+            eprintln!("Not released to any destination yet");
+        }
+
+        let mut intents: Vec<&str> = Vec::new();
+        for attempt in &resp.attempts {
+            if !intents.contains(&attempt.release_intent_id.as_str()) {
+                intents.push(&attempt.release_intent_id);
+            }
+        }
+
+        for intent in intents {
+            let steps: Vec<&ReleaseAttempt> = resp
+                .attempts
+                .iter()
+                .filter(|a| a.release_intent_id == intent)
+                .collect();
+            let id_short = intent.get(..8).unwrap_or(intent);
+
+            println!();
+            println!("Release {id_short} ({})", steps[0].queued_at);
+            for step in steps {
+                print_step(step);
+            }
+        }
+
+        for env in &resp.policies {
+            println!();
+            let verdict = if env.all_passed { "passing" } else { "blocked" };
+            println!("Policies for {} ({verdict})", env.environment);
+            for eval in &env.evaluations {
+                let status = if eval.passed { "PASS" } else { "FAIL" };
+                println!("  [{status}] {}", eval.policy_name);
+                if !eval.reason.is_empty() {
+                    println!("         {}", eval.reason);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn print_step(step: &ReleaseAttempt) {
+    let icon = match step.status.as_str() {
+        "SUCCEEDED" => "✓",
+        "RUNNING" => "▶",
+        "ASSIGNED" => "◉",
+        "QUEUED" => "◌",
+        "FAILED" | "TIMED_OUT" | "CANCELLED" => "✗",
+        _ => "•",
+    };
+    let stage = step
+        .stage_id
+        .as_deref()
+        .map(|s| format!(" (stage {s})"))
+        .unwrap_or_default();
+    println!(
+        "  {icon} {}: {} {} [{}]{stage}",
+        step.destination, step.environment, step.mode, step.status
+    );
+
+    let mut times = vec![format!("queued: {}", step.queued_at)];
+    if let Some(t) = &step.assigned_at {
+        times.push(format!("assigned: {t}"));
+    }
+    if let Some(t) = &step.started_at {
+        times.push(format!("started: {t}"));
+    }
+    if let Some(t) = &step.completed_at {
+        times.push(format!("completed: {t}"));
+    }
+    println!("    {}", times.join("  "));
+
+    if let Some(err) = &step.error_message {
+        println!("    error: {err}");
+    }
+    if let Some(health) = &step.health_status {
+        match &step.health_message {
+            Some(message) => println!("    health: {health} ({message})"),
+            None => println!("    health: {health}"),
+        }
+    }
+
+    for event in &step.events {
+        match &event.message {
+            Some(message) => println!("    {}  {}: {message}", event.created_at, event.event_type),
+            None => println!("    {}  {}", event.created_at, event.event_type),
+        }
+    }
+
+    if !step.logs.is_empty() {
+        println!("    logs:");
+        for line in &step.logs {
+            let channel = match LogChannel::try_from(line.channel) {
+                Ok(LogChannel::Stderr) => "err",
+                _ => "out",
+            };
+            println!("      {channel} | {}", line.line);
+        }
+    }
+}
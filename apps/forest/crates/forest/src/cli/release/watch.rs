@@ -0,0 +1,593 @@
+use std::{collections::HashMap, io::IsTerminal, time::Duration};
+
+use anyhow::Context;
+use forest_grpc_interface::{
+    GetReleaseHealthRequest, GetReleaseHealthResponse, HealthStatus, ReleaseHealthEvent,
+    WatchReleaseHealthRequest,
+};
+use futures::StreamExt;
+use uuid::Uuid;
+
+use crate::{
+    grpc::{GrpcClient, GrpcClientState, HealthClient},
+    state::State,
+};
+
+/// Follow a release: stream its step logs, then its rollout health until
+/// every destination is healthy, one turns unhealthy, or the health
+/// timeout expires.
+///
+/// Exit codes: 0 healthy, 1 release failed, 2 unhealthy, 3 timed out
+/// waiting for health, 130 interrupted.
+#[derive(clap::Parser)]
+pub struct WatchCommand {
+    /// Artifact slug whose latest release to watch.
+    #[arg(required_unless_present = "release_intent_id")]
+    slug: Option<String>,
+
+    /// Watch a specific release intent instead of resolving one from a slug.
+    #[arg(long, conflicts_with = "slug")]
+    release_intent_id: Option<String>,
+
+    #[arg(long, short = 'o')]
+    organisation: Option<String>,
+
+    #[arg(long, short = 'p')]
+    project: Option<String>,
+
+    /// Only consider releases to this environment when resolving the slug.
+    #[arg(long, short = 'e', alias = "env")]
+    environment: Option<String>,
+
+    /// Stop after the release finishes without following health.
+    #[arg(long)]
+    no_health: bool,
+
+    #[command(flatten)]
+    watch: WatchArgs,
+}
+
+/// Options shared by everything that follows a release to completion.
+#[derive(Clone, Debug, Default, clap::Args)]
+pub struct WatchArgs {
+    /// Seconds to wait for all destinations to report healthy
+    /// [default: 300 when watching, 90 otherwise].
+    #[arg(long)]
+    pub(crate) health_timeout: Option<u64>,
+
+    /// One line per event, no progress chatter. Step logs are still shown.
+    /// Defaults to on when stderr is not a terminal (e.g. in CI).
+    #[arg(long)]
+    pub(crate) compact: bool,
+}
+
+impl WatchArgs {
+    pub(crate) fn health_timeout_or(&self, default_secs: u64) -> Duration {
+        Duration::from_secs(self.health_timeout.unwrap_or(default_secs))
+    }
+
+    pub(crate) fn compact(&self) -> bool {
+        self.compact || !std::io::stderr().is_terminal()
+    }
+}
+
+/// How a watched release ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WatchOutcome {
+    Healthy,
+    /// One or more release steps failed before health was considered.
+    ReleaseFailed,
+    Unhealthy,
+    TimedOut,
+    /// The watch was interrupted; the release itself carries on.
+    Cancelled,
+}
+
+impl WatchOutcome {
+    pub fn exit_code(self) -> i32 {
+        match self {
+            WatchOutcome::Healthy => 0,
+            WatchOutcome::ReleaseFailed => 1,
+            WatchOutcome::Unhealthy => 2,
+            WatchOutcome::TimedOut => 3,
+            WatchOutcome::Cancelled => 130,
+        }
+    }
+
+    /// Exit the process with this outcome's code if it isn't a success.
+    pub fn exit_on_failure(self) {
+        if self != WatchOutcome::Healthy {
+            std::process::exit(self.exit_code());
+        }
+    }
+}
+
+impl WatchCommand {
+    pub async fn execute(&self, state: &State) -> anyhow::Result<()> {
+        let grpc = state.grpc_client();
+
+        let release_intent_id = match &self.release_intent_id {
+            Some(id) => id.parse::<Uuid>().context("release intent id")?,
+            None => self.resolve_intent(&grpc).await?,
+        };
+
+        let compact = self.watch.compact();
+        if !compact {
+            eprintln!("Watching release {release_intent_id} (streaming logs)...\n");
+        }
+
+        let result = grpc
+            .wait_release(release_intent_id, compact, |_| async {})
+            .await
+            .context("wait_release")?;
+
+        let failed: Vec<_> = result
+            .destinations
+            .iter()
+            .filter(|d| !d.status.is_success())
+            .collect();
+        for dest in &failed {
+            eprintln!("release failed: {} ({})", dest.destination, dest.status);
+        }
+        if !failed.is_empty() {
+            WatchOutcome::ReleaseFailed.exit_on_failure();
+        }
+        if !compact {
+            eprintln!("\nRelease completed.");
+        }
+
+        if self.no_health {
+            return Ok(());
+        }
+
+        follow_health(
+            &grpc,
+            release_intent_id,
+            self.watch.health_timeout_or(300),
+            compact,
+        )
+        .await
+        .unwrap_or_else(health_unavailable)
+        .exit_on_failure();
+
+        Ok(())
+    }
+
+    /// Find the newest release intent for the slug's artifact.
+    async fn resolve_intent(&self, grpc: &GrpcClient) -> anyhow::Result<Uuid> {
+        let slug = self.slug.as_deref().context("slug is required")?;
+        let annotation = grpc
+            .get_release_annotation_by_slug(slug)
+            .await
+            .context("get release annotation by slug")?;
+
+        let (organisation, project) = match (&self.organisation, &self.project) {
+            (Some(org), Some(project)) => (org.clone(), project.clone()),
+            _ => {
+                let project = annotation
+                    .project
+                    .context("artifact is not associated with a project: pass --organisation and --project")?;
+                (
+                    self.organisation.clone().unwrap_or(project.organisation),
+                    self.project.clone().unwrap_or(project.project),
+                )
+            }
+        };
+
+        let intents = grpc
+            .get_release_intent_states(&organisation, Some(&project), true)
+            .await
+            .context("get release intent states")?;
+
+        // Newest first, so the first match is the latest release.
+        let intent = intents
+            .release_intents
+            .iter()
+            .filter(|i| i.artifact_id == annotation.artifact_id.to_string())
+            .find(|i| {
+                self.environment.as_deref().is_none_or(|env| {
+                    i.steps.iter().any(|s| s.environment == env)
+                        || i.stages
+                            .iter()
+                            .any(|s| s.environment.as_deref() == Some(env))
+                })
+            })
+            .with_context(|| format!("no release found for {slug}"))?;
+
+        intent
+            .release_intent_id
+            .parse()
+            .context("release intent id")
+    }
+}
+
+/// Where [`follow_health`] reads rollout health from. The gRPC
+/// implementation is [`GrpcHealth`]; tests drive a fake.
+pub(crate) trait HealthSource {
+    /// Wait for the next change signal. `None` once the signal stream has
+    /// ended, after which the caller relies on polling alone.
+    async fn next_change(&mut self) -> Option<()>;
+
+    /// Read the full health state of the release.
+    async fn snapshot(&mut self) -> anyhow::Result<GetReleaseHealthResponse>;
+}
+
+/// Health from the `ReleaseHealthService`. Uses the `WatchReleaseHealth`
+/// stream as a change signal and re-reads the full state with
+/// `GetReleaseHealth`.
+pub(crate) struct GrpcHealth {
+    client: HealthClient,
+    events: Option<tonic::Streaming<ReleaseHealthEvent>>,
+    release_intent_id: Uuid,
+}
+
+impl GrpcHealth {
+    pub(crate) async fn connect(grpc: &GrpcClient, release_intent_id: Uuid) -> anyhow::Result<Self> {
+        let mut client = grpc.health_client().await?;
+        // Without the stream we still poll, so a failure here isn't fatal.
+        let events = client
+            .watch_release_health(WatchReleaseHealthRequest {
+                release_intent_id: release_intent_id.to_string(),
+            })
+            .await
+            .ok()
+            .map(|r| r.into_inner());
+
+        Ok(Self {
+            client,
+            events,
+            release_intent_id,
+        })
+    }
+}
+
+impl HealthSource for GrpcHealth {
+    async fn next_change(&mut self) -> Option<()> {
+        let event = self.events.as_mut()?.next().await;
+        if !matches!(event, Some(Ok(_))) {
+            self.events = None;
+            return None;
+        }
+        Some(())
+    }
+
+    async fn snapshot(&mut self) -> anyhow::Result<GetReleaseHealthResponse> {
+        Ok(self
+            .client
+            .get_release_health(GetReleaseHealthRequest {
+                release_intent_id: self.release_intent_id.to_string(),
+            })
+            .await?
+            .into_inner())
+    }
+}
+
+/// Follow rollout health for a release intent until all destinations are
+/// healthy, any destination is unhealthy, the timeout expires, or the user
+/// interrupts with Ctrl-C. Fails only when the health service can't be
+/// reached, so callers that merely peek at health can stay quiet about it.
+pub(crate) async fn follow_health(
+    grpc: &GrpcClient,
+    release_intent_id: Uuid,
+    health_timeout: Duration,
+    compact: bool,
+) -> anyhow::Result<WatchOutcome> {
+    let source = GrpcHealth::connect(grpc, release_intent_id).await?;
+
+    let interrupted = async {
+        let _ = tokio::signal::ctrl_c().await;
+    };
+
+    Ok(follow_health_from(source, health_timeout, POLL_INTERVAL, compact, interrupted).await)
+}
+
+/// Report an unreachable health service on a watched release; the health
+/// of the rollout is unknown, so it counts as a timeout.
+pub(crate) fn health_unavailable(e: anyhow::Error) -> WatchOutcome {
+    eprintln!("health service unavailable: {e:#}");
+    WatchOutcome::TimedOut
+}
+
+/// How often health is re-read when no change signal arrives.
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+async fn follow_health_from(
+    mut source: impl HealthSource,
+    health_timeout: Duration,
+    poll_interval: Duration,
+    compact: bool,
+    cancelled: impl Future<Output = ()>,
+) -> WatchOutcome {
+    let started = std::time::Instant::now();
+
+    if !compact {
+        eprintln!("\nWatching health...");
+    }
+
+    let timeout = tokio::time::sleep(health_timeout);
+    tokio::pin!(timeout);
+    tokio::pin!(cancelled);
+    let mut poll = tokio::time::interval(poll_interval);
+
+    let mut streaming = true;
+    let mut last_seen: HashMap<String, HealthStatus> = HashMap::new();
+    let mut waiting_printed = false;
+
+    loop {
+        tokio::select! {
+            _ = poll.tick() => {}
+            change = async {
+                if streaming {
+                    source.next_change().await
+                } else {
+                    std::future::pending().await
+                }
+            } => {
+                if change.is_none() {
+                    // Stream ended or errored; keep going on polling alone.
+                    streaming = false;
+                }
+            }
+            _ = &mut timeout => {
+                let secs = health_timeout.as_secs();
+                if last_seen.is_empty() {
+                    eprintln!("health: no observations after {secs}s — is a health agent reporting for this destination?");
+                } else {
+                    eprintln!("health: timed out after {secs}s waiting for all destinations to become healthy");
+                }
+                return WatchOutcome::TimedOut;
+            }
+            _ = &mut cancelled => {
+                eprintln!("health: watch cancelled; the release carries on");
+                return WatchOutcome::Cancelled;
+            }
+        }
+
+        let resp = match source.snapshot().await {
+            Ok(resp) => resp,
+            Err(_) => continue,
+        };
+
+        let mut reported = false;
+        for dest in &resp.destinations {
+            let status = HealthStatus::try_from(dest.status).unwrap_or(HealthStatus::Unspecified);
+            if status == HealthStatus::Unspecified {
+                continue;
+            }
+            reported = true;
+
+            if last_seen.insert(dest.destination.clone(), status) != Some(status) {
+                print_health(&dest.environment, &dest.destination, status, compact);
+            }
+        }
+
+        if !reported {
+            if !compact && !waiting_printed {
+                eprintln!("  waiting for health agent...");
+                waiting_printed = true;
+            }
+            continue;
+        }
+
+        let elapsed = started.elapsed().as_secs();
+        match HealthStatus::try_from(resp.aggregate_status).unwrap_or(HealthStatus::Unspecified) {
+            HealthStatus::Healthy => {
+                eprintln!("{}release healthy ({elapsed}s)", if compact { "" } else { "\n" });
+                return WatchOutcome::Healthy;
+            }
+            HealthStatus::Unhealthy => {
+                eprintln!("{}release unhealthy ({elapsed}s)", if compact { "" } else { "\n" });
+                return WatchOutcome::Unhealthy;
+            }
+            _ => {}
+        }
+    }
+}
+
+fn print_health(environment: &str, destination: &str, status: HealthStatus, compact: bool) {
+    let label = health_label(status);
+    if compact {
+        eprintln!("health: {environment}/{destination} {label}");
+        return;
+    }
+
+    let icon = match status {
+        HealthStatus::Healthy => "✓",
+        HealthStatus::Progressing => "▶",
+        HealthStatus::Degraded | HealthStatus::Missing => "◌",
+        HealthStatus::Unhealthy => "✗",
+        HealthStatus::Unspecified => "•",
+    };
+    eprintln!("  {icon} [{environment}] {destination}  HEALTH: {label}");
+}
+
+fn health_label(status: HealthStatus) -> &'static str {
+    status
+        .as_str_name()
+        .strip_prefix("HEALTH_STATUS_")
+        .unwrap_or("UNSPECIFIED")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exit_codes_are_distinct() {
+        let codes = [
+            WatchOutcome::Healthy,
+            WatchOutcome::ReleaseFailed,
+            WatchOutcome::Unhealthy,
+            WatchOutcome::TimedOut,
+            WatchOutcome::Cancelled,
+        ]
+        .map(WatchOutcome::exit_code);
+
+        assert_eq!(codes, [0, 1, 2, 3, 130]);
+    }
+
+    /// Replays health snapshots, repeating the last one, behind a change
+    /// stream that ends after `changes` signals.
+    struct FakeHealth {
+        changes: usize,
+        snapshots: std::collections::VecDeque<GetReleaseHealthResponse>,
+    }
+
+    impl FakeHealth {
+        fn new(changes: usize, snapshots: Vec<GetReleaseHealthResponse>) -> Self {
+            Self {
+                changes,
+                snapshots: snapshots.into(),
+            }
+        }
+    }
+
+    impl HealthSource for FakeHealth {
+        async fn next_change(&mut self) -> Option<()> {
+            if self.changes == 0 {
+                return None;
+            }
+            self.changes -= 1;
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            Some(())
+        }
+
+        async fn snapshot(&mut self) -> anyhow::Result<GetReleaseHealthResponse> {
+            if self.snapshots.len() > 1 {
+                return Ok(self.snapshots.pop_front().unwrap());
+            }
+            self.snapshots
+                .front()
+                .cloned()
+                .context("no health reported")
+        }
+    }
+
+    fn health(aggregate: HealthStatus, destinations: &[(&str, HealthStatus)]) -> GetReleaseHealthResponse {
+        GetReleaseHealthResponse {
+            destinations: destinations
+                .iter()
+                .map(|(name, status)| forest_grpc_interface::DestinationHealth {
+                    destination: name.to_string(),
+                    environment: "prod".into(),
+                    latest_observation: None,
+                    status: (*status).into(),
+                })
+                .collect(),
+            aggregate_status: aggregate.into(),
+        }
+    }
+
+    async fn follow(source: FakeHealth, timeout_ms: u64, cancelled: impl Future<Output = ()>) -> WatchOutcome {
+        follow_health_from(
+            source,
+            Duration::from_millis(timeout_ms),
+            Duration::from_millis(5),
+            true,
+            cancelled,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn follow_health_exits_zero_once_healthy() {
+        let source = FakeHealth::new(
+            3,
+            vec![
+                health(HealthStatus::Progressing, &[("eu", HealthStatus::Progressing)]),
+                health(HealthStatus::Healthy, &[("eu", HealthStatus::Healthy)]),
+            ],
+        );
+
+        let outcome = follow(source, 5_000, std::future::pending()).await;
+
+        assert_eq!(outcome, WatchOutcome::Healthy);
+        assert_eq!(outcome.exit_code(), 0);
+    }
+
+    #[tokio::test]
+    async fn follow_health_exits_two_when_unhealthy() {
+        let source = FakeHealth::new(
+            1,
+            vec![health(
+                HealthStatus::Unhealthy,
+                &[("eu", HealthStatus::Healthy), ("us", HealthStatus::Unhealthy)],
+            )],
+        );
+
+        let outcome = follow(source, 5_000, std::future::pending()).await;
+
+        assert_eq!(outcome, WatchOutcome::Unhealthy);
+        assert_eq!(outcome.exit_code(), 2);
+    }
+
+    #[tokio::test]
+    async fn follow_health_times_out_while_degraded() {
+        let source = FakeHealth::new(
+            5,
+            vec![health(HealthStatus::Degraded, &[("eu", HealthStatus::Degraded)])],
+        );
+
+        let outcome = follow(source, 50, std::future::pending()).await;
+
+        assert_eq!(outcome, WatchOutcome::TimedOut);
+        assert_eq!(outcome.exit_code(), 3);
+    }
+
+    #[tokio::test]
+    async fn follow_health_times_out_without_observations() {
+        let source = FakeHealth::new(0, vec![]);
+
+        let outcome = follow(source, 50, std::future::pending()).await;
+
+        assert_eq!(outcome, WatchOutcome::TimedOut);
+    }
+
+    #[tokio::test]
+    async fn follow_health_stops_when_cancelled() {
+        let source = FakeHealth::new(
+            0,
+            vec![health(HealthStatus::Progressing, &[("eu", HealthStatus::Progressing)])],
+        );
+
+        let outcome = follow(source, 5_000, tokio::time::sleep(Duration::from_millis(20))).await;
+
+        assert_eq!(outcome, WatchOutcome::Cancelled);
+        assert_eq!(outcome.exit_code(), 130);
+    }
+
+    #[tokio::test]
+    async fn follow_health_keeps_polling_after_the_stream_ends() {
+        // No change signals at all: only polling can see the rollout finish.
+        let source = FakeHealth::new(
+            0,
+            vec![
+                health(HealthStatus::Progressing, &[("eu", HealthStatus::Progressing)]),
+                health(HealthStatus::Progressing, &[("eu", HealthStatus::Progressing)]),
+                health(HealthStatus::Healthy, &[("eu", HealthStatus::Healthy)]),
+            ],
+        );
+
+        let outcome = follow(source, 5_000, std::future::pending()).await;
+
+        assert_eq!(outcome, WatchOutcome::Healthy);
+    }
+
+    #[test]
+    fn compact_log_lines_keep_the_step_output() {
+        assert_eq!(
+            crate::grpc::format_log_line("eu-prod", "applying manifests", true),
+            "log: eu-prod: applying manifests"
+        );
+        assert_eq!(
+            crate::grpc::format_log_line("eu-prod", "applying manifests", false),
+            "eu-prod: applying manifests"
+        );
+    }
+
+    #[test]
+    fn health_label_strips_prefix() {
+        assert_eq!(health_label(HealthStatus::Healthy), "HEALTHY");
+        assert_eq!(health_label(HealthStatus::Unhealthy), "UNHEALTHY");
+    }
+}
@@ -127,7 +127,7 @@ impl RunCommand {
 }
 
 /// Build the dynamic clap command with discovered project/component commands.
-fn build_dynamic_command(
+pub(crate) fn build_dynamic_command(
     project: &Project,
 ) -> (
     std::collections::BTreeMap<String, crate::models::CommandName>,
@@ -274,10 +274,10 @@ async fn build_enriched_subcommand(
     sub
 }
 
-struct InputField {
-    name: String,
+pub(crate) struct InputField {
+    pub(crate) name: String,
     required: bool,
-    description: Option<String>,
+    pub(crate) description: Option<String>,
     field_type: Option<String>,
 }
 
@@ -285,7 +285,7 @@ struct InputField {
 ///
 /// Runs `cue def --out openapi` on the component's `forest.component.cue`,
 /// then extracts the input properties for the given command name.
-async fn fetch_command_input_schema(
+pub(crate) async fn fetch_command_input_schema(
     cmd_name: &crate::models::CommandName,
     command_short: &str,
 ) -> Option<Vec<InputField>> {
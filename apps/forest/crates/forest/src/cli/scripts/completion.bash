@@ -0,0 +1,21 @@
+# forest shell bash — tab completion
+
+_forest_complete() {
+  local cur words cword
+  if declare -F _get_comp_words_by_ref >/dev/null; then
+    # Keep `component:command` and `--flag=value` as one word.
+    _get_comp_words_by_ref -n =: cur words cword
+  else
+    cur="${COMP_WORDS[COMP_CWORD]}"
+    words=("${COMP_WORDS[@]}")
+    cword=$COMP_CWORD
+  fi
+
+  local IFS=$'\n'
+  COMPREPLY=($(forest shell complete --shell bash --cword "$cword" -- "${words[@]}" 2>/dev/null))
+
+  if declare -F __ltrim_colon_completions >/dev/null; then
+    __ltrim_colon_completions "$cur"
+  fi
+}
+complete -o default -F _forest_complete forest
@@ -0,0 +1,12 @@
+# forest shell fish — tab completion
+
+function __forest_complete
+    set -l words (commandline -opc) (commandline -ct)
+    set -l candidates (forest shell complete --shell fish --cword (math (count $words) - 1) -- $words 2>/dev/null)
+    if test (count $candidates) -gt 0
+        printf '%s\n' $candidates
+    else
+        __fish_complete_path (commandline -ct)
+    end
+end
+complete -c forest -f -a '(__forest_complete)'
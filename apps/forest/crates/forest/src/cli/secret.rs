@@ -0,0 +1,182 @@
+use std::io::{IsTerminal, Read};
+use std::path::PathBuf;
+
+use anyhow::Context;
+use forest_grpc_interface::SecretScope;
+
+use crate::{grpc::GrpcClientState, state::State};
+
+#[derive(clap::Parser)]
+pub struct SecretCommand {
+    #[command(subcommand)]
+    commands: Commands,
+}
+
+#[derive(clap::Subcommand)]
+enum Commands {
+    /// Create or rotate a secret (organisation admins only)
+    Set(SetCommand),
+    /// List secrets; values are never shown
+    #[command(alias = "ls")]
+    List(ListCommand),
+    /// Delete a secret (organisation admins only)
+    #[command(alias = "rm")]
+    Delete(DeleteCommand),
+}
+
+#[derive(clap::Args)]
+struct ScopeArgs {
+    #[arg(long, short = 'o', visible_alias = "org")]
+    organisation: String,
+
+    /// Only use the secret for releases of this project
+    #[arg(long, short = 'p')]
+    project: Option<String>,
+
+    /// Only use the secret for releases to this environment
+    #[arg(long, short = 'e')]
+    environment: Option<String>,
+}
+
+impl From<&ScopeArgs> for SecretScope {
+    fn from(value: &ScopeArgs) -> Self {
+        Self {
+            organisation: value.organisation.clone(),
+            project: value.project.clone().unwrap_or_default(),
+            environment: value.environment.clone().unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(clap::Parser)]
+struct SetCommand {
+    /// Referenced from destination metadata as `secret://NAME`
+    name: String,
+
+    #[command(flatten)]
+    scope: ScopeArgs,
+
+    /// Read the value from this file, as is. Otherwise it is prompted for,
+    /// or read from stdin without its trailing newline.
+    #[arg(long)]
+    file: Option<PathBuf>,
+}
+
+#[derive(clap::Parser)]
+struct ListCommand {
+    #[arg(long, short = 'o', visible_alias = "org")]
+    organisation: String,
+
+    /// Only secrets scoped to exactly this project
+    #[arg(long, short = 'p')]
+    project: Option<String>,
+
+    /// Only secrets scoped to exactly this environment
+    #[arg(long, short = 'e')]
+    environment: Option<String>,
+}
+
+#[derive(clap::Parser)]
+struct DeleteCommand {
+    name: String,
+
+    #[command(flatten)]
+    scope: ScopeArgs,
+}
+
+impl SecretCommand {
+    pub fn is_mutation(&self) -> bool {
+        matches!(self.commands, Commands::Set(_) | Commands::Delete(_))
+    }
+
+    pub async fn execute(&self, state: &State) -> anyhow::Result<()> {
+        let client = state.grpc_client();
+
+        match &self.commands {
+            Commands::Set(cmd) => {
+                let value = cmd.read_value()?;
+                let secret = client
+                    .set_secret((&cmd.scope).into(), &cmd.name, &value)
+                    .await
+                    .context("set secret")?;
+
+                if secret.version == 1 {
+                    eprintln!("Created secret {}", secret.name);
+                } else {
+                    eprintln!("Rotated secret {} to version {}", secret.name, secret.version);
+                }
+            }
+            Commands::List(cmd) => {
+                let secrets = client
+                    .list_secrets(
+                        &cmd.organisation,
+                        cmd.project.as_deref(),
+                        cmd.environment.as_deref(),
+                    )
+                    .await
+                    .context("list secrets")?;
+
+                if secrets.is_empty() {
+                    eprintln!("No secrets found");
+                    return Ok(());
+                }
+
+                for secret in secrets {
+                    let scope = secret.scope.unwrap_or_default();
+                    let project = match scope.project.as_str() {
+                        "" => "*",
+                        project => project,
+                    };
+                    let environment = match scope.environment.as_str() {
+                        "" => "*",
+                        environment => environment,
+                    };
+                    println!("{}", secret.name);
+                    println!("  project:     {project}");
+                    println!("  environment: {environment}");
+                    println!("  version:     {}", secret.version);
+                    println!("  updated:     {} by {}", secret.updated_at, secret.updated_by);
+                }
+            }
+            Commands::Delete(cmd) => {
+                client
+                    .delete_secret((&cmd.scope).into(), &cmd.name)
+                    .await
+                    .context("delete secret")?;
+
+                eprintln!("Deleted secret {}", cmd.name);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl SetCommand {
+    /// The value is deliberately not accepted as an argument, so it never
+    /// ends up in shell history or the process list.
+    fn read_value(&self) -> anyhow::Result<String> {
+        if let Some(path) = &self.file {
+            return std::fs::read_to_string(path)
+                .with_context(|| format!("read secret from {}", path.display()));
+        }
+
+        if std::io::stdin().is_terminal() {
+            return inquire::Password::new(&format!("Value for {}:", self.name))
+                .without_confirmation()
+                .prompt()
+                .context("read secret value");
+        }
+
+        let mut value = String::new();
+        std::io::stdin()
+            .read_to_string(&mut value)
+            .context("read secret from stdin")?;
+        let trimmed = value
+            .strip_suffix('\n')
+            .map(|v| v.strip_suffix('\r').unwrap_or(v))
+            .unwrap_or(&value);
+        anyhow::ensure!(!trimmed.is_empty(), "secret value is empty");
+        Ok(trimmed.to_string())
+    }
+}
@@ -1,18 +1,30 @@
 //! `forest shell zsh|bash` — emits a single shell-integration block.
 //!
 //! Combines the global-tools PATH-prepend (formerly `forest eval`) with the
-//! shell helper functions (e.g. `forest-tmp`). Source it from your rc file:
+//! shell helper functions (e.g. `forest-tmp`) and tab completion. Source it
+//! from your rc file:
 //!
 //!     eval "$(forest shell zsh)"   # or `bash`
+//!
+//! Fish users, or anyone who only wants completion, can use
+//! `forest shell completions bash|zsh|fish` instead.
 
 use clap::{Parser, Subcommand};
 
 use crate::global::eval::{eval_bash, eval_zsh};
 use crate::state::State;
 
+mod complete;
+
+use complete::{CompleteArgs, CompletionShell};
+
 const ZSH_HELPERS: &str = include_str!("scripts/forest.zsh");
 const BASH_HELPERS: &str = include_str!("scripts/forest.bash");
 
+const ZSH_COMPLETION: &str = include_str!("scripts/completion.zsh");
+const BASH_COMPLETION: &str = include_str!("scripts/completion.bash");
+const FISH_COMPLETION: &str = include_str!("scripts/completion.fish");
+
 #[derive(Parser)]
 pub struct ShellCommand {
     #[command(subcommand)]
@@ -25,19 +37,35 @@ pub enum ShellCommands {
     Zsh,
     /// Emit bash integration (eval into ~/.bashrc).
     Bash,
+    /// Emit only the tab-completion script, e.g. `forest shell completions fish | source`.
+    Completions {
+        #[arg(value_enum)]
+        shell: CompletionShell,
+    },
+    /// Completion callback used by the scripts above.
+    #[command(hide = true)]
+    Complete(CompleteArgs),
 }
 
 impl ShellCommand {
-    pub async fn execute(&self, _state: &State) -> anyhow::Result<()> {
-        match self.subcommands {
+    pub async fn execute(&self, state: &State) -> anyhow::Result<()> {
+        match &self.subcommands {
             ShellCommands::Zsh => {
                 print!("{}", eval_zsh());
                 print!("{}", ZSH_HELPERS);
+                print!("{}", ZSH_COMPLETION);
             }
             ShellCommands::Bash => {
                 print!("{}", eval_bash());
                 print!("{}", BASH_HELPERS);
+                print!("{}", BASH_COMPLETION);
             }
+            ShellCommands::Completions { shell } => match shell {
+                CompletionShell::Bash => print!("{}", BASH_COMPLETION),
+                CompletionShell::Zsh => print!("{}", ZSH_COMPLETION),
+                CompletionShell::Fish => print!("{}", FISH_COMPLETION),
+            },
+            ShellCommands::Complete(args) => args.execute(state).await?,
         }
         Ok(())
     }
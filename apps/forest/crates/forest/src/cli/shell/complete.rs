@@ -0,0 +1,580 @@
+//! `forest shell complete` — the callback behind the completion scripts.
+//!
+//! The scripts hand over the words on the command line and the index of the
+//! one being completed. Static commands and flags come from walking the clap
+//! tree; `forest run` commands come from the project's component graph, and
+//! organisation, project, environment, destination and component names from
+//! the server, cached per context for a few minutes so tab stays fast.
+
+use std::{
+    collections::HashMap,
+    time::{Duration, SystemTime},
+};
+
+use clap::{Arg, Command, CommandFactory};
+
+use crate::{
+    cli::run::{build_dynamic_command, fetch_command_input_schema},
+    contexts::ContextStore,
+    global::paths::GlobalPaths,
+    grpc::{GetProjectsQuery, GrpcClientState},
+    services::project::ProjectParserState,
+    state::State,
+};
+
+/// How long server lookups are reused before asking again.
+const CACHE_TTL: Duration = Duration::from_secs(5 * 60);
+/// Completion must never hang the shell; stale or no names beat waiting.
+const LOOKUP_TIMEOUT: Duration = Duration::from_secs(2);
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompletionShell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+#[derive(clap::Args)]
+pub struct CompleteArgs {
+    #[arg(long, value_enum)]
+    shell: CompletionShell,
+
+    /// Index into `words` of the word being completed.
+    #[arg(long)]
+    cword: usize,
+
+    /// The command line, starting with `forest`.
+    #[arg(last = true, allow_hyphen_values = true)]
+    words: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Candidate {
+    value: String,
+    help: Option<String>,
+}
+
+impl Candidate {
+    fn new(value: impl Into<String>) -> Self {
+        Self {
+            value: value.into(),
+            help: None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Lookup {
+    Organisations,
+    Projects,
+    Environments,
+    Destinations,
+    Components,
+}
+
+impl Lookup {
+    /// Server-backed names for an argument, going by its flag or id.
+    fn for_arg(arg: &Arg) -> Option<Self> {
+        let name = arg.get_long().unwrap_or(arg.get_id().as_str());
+        match name {
+            "organisation" | "org" => Some(Self::Organisations),
+            "project" => Some(Self::Projects),
+            "component" => Some(Self::Components),
+            name if name.ends_with("environment") || name == "env" => Some(Self::Environments),
+            name if name.ends_with("destination") => Some(Self::Destinations),
+            _ => None,
+        }
+    }
+
+    fn cache_key(&self) -> &'static str {
+        match self {
+            Self::Organisations => "organisations",
+            Self::Projects => "projects",
+            Self::Environments => "environments",
+            Self::Destinations => "destinations",
+            Self::Components => "components",
+        }
+    }
+}
+
+/// What the word under the cursor should complete to.
+#[derive(Debug, PartialEq, Eq)]
+enum Completion {
+    Candidates(Vec<Candidate>),
+    Lookup {
+        lookup: Lookup,
+        organisation: Option<String>,
+    },
+    /// `forest run <command>`
+    RunCommands,
+    /// `forest run <command> --<input>`
+    RunInputs { command: String, given: Vec<String> },
+    /// Nothing forest knows about; let the shell complete paths.
+    Files,
+}
+
+impl CompleteArgs {
+    pub async fn execute(&self, state: &State) -> anyhow::Result<()> {
+        let current = self.words.get(self.cword).cloned().unwrap_or_default();
+        let completion = plan(&crate::cli::Command::command(), &self.words, self.cword);
+
+        let candidates = match completion {
+            Completion::Candidates(candidates) => candidates,
+            Completion::Lookup {
+                lookup,
+                organisation,
+            } => lookup_names(state, lookup, organisation, &current)
+                .await
+                .into_iter()
+                .map(Candidate::new)
+                .collect(),
+            Completion::RunCommands => run_commands(state, &current).await,
+            Completion::RunInputs { command, given } => {
+                run_inputs(state, &command, &given).await
+            }
+            Completion::Files => Vec::new(),
+        };
+
+        for candidate in candidates
+            .into_iter()
+            .filter(|c| c.value.starts_with(current.as_str()))
+        {
+            println!("{}", format_candidate(self.shell, &candidate));
+        }
+
+        Ok(())
+    }
+}
+
+fn format_candidate(shell: CompletionShell, candidate: &Candidate) -> String {
+    let help = candidate
+        .help
+        .as_deref()
+        .and_then(|h| h.lines().next())
+        .filter(|h| !h.is_empty());
+    match (shell, help) {
+        (CompletionShell::Bash, _) | (_, None) => candidate.value.clone(),
+        // `_describe` splits on the first unescaped colon.
+        (CompletionShell::Zsh, Some(help)) => {
+            format!("{}:{help}", candidate.value.replace(':', "\\:"))
+        }
+        (CompletionShell::Fish, Some(help)) => format!("{}\t{help}", candidate.value),
+    }
+}
+
+/// Walk the static command tree along `words[1..cword]` and decide what
+/// `words[cword]` completes to.
+fn plan(root: &Command, words: &[String], cword: usize) -> Completion {
+    let mut root = root.clone();
+    root.build();
+
+    let current = words.get(cword).map(String::as_str).unwrap_or_default();
+    let prior = words.get(1..cword).unwrap_or_default();
+
+    let mut cmd = &root;
+    let mut depth = 0;
+    let mut given: HashMap<String, String> = HashMap::new();
+    let mut pending: Option<&Arg> = None;
+    let mut positionals = 0;
+    let mut only_positionals = false;
+
+    for (i, word) in prior.iter().enumerate() {
+        if let Some(arg) = pending.take() {
+            given.insert(arg.get_id().to_string(), word.clone());
+            continue;
+        }
+        if !only_positionals && word == "--" {
+            only_positionals = true;
+            continue;
+        }
+        if !only_positionals && let Some(flag) = word.strip_prefix("--") {
+            let (name, value) = match flag.split_once('=') {
+                Some((name, value)) => (name, Some(value)),
+                None => (flag, None),
+            };
+            if let Some(arg) = cmd.get_arguments().find(|a| a.get_long() == Some(name))
+                && arg.get_action().takes_values()
+            {
+                match value {
+                    Some(value) => {
+                        given.insert(arg.get_id().to_string(), value.to_string());
+                    }
+                    None => pending = Some(arg),
+                }
+            }
+            continue;
+        }
+        if !only_positionals && word.len() > 1 && word.starts_with('-') {
+            // In a cluster like `-vo`, only the last short flag can take
+            // the next word as its value.
+            let last = word.chars().last().unwrap_or_default();
+            if let Some(arg) = cmd.get_arguments().find(|a| a.get_short() == Some(last))
+                && arg.get_action().takes_values()
+                && word.len() == 2
+            {
+                pending = Some(arg);
+            }
+            continue;
+        }
+        if positionals == 0
+            && let Some(sub) = cmd.find_subcommand(word)
+        {
+            cmd = sub;
+            depth += 1;
+            if depth == 1 && cmd.get_name() == "run" {
+                return plan_run(&prior[i + 1..], current);
+            }
+            continue;
+        }
+        positionals += 1;
+    }
+
+    if let Some(arg) = pending {
+        return complete_value(arg, &given);
+    }
+    if !only_positionals && current.starts_with('-') {
+        return Completion::Candidates(flags(cmd));
+    }
+    if positionals == 0 && cmd.has_subcommands() {
+        return Completion::Candidates(subcommands(cmd));
+    }
+    let mut args = cmd.get_positionals();
+    match args.nth(positionals) {
+        Some(arg) => complete_value(arg, &given),
+        None => Completion::Files,
+    }
+}
+
+/// `forest run` takes its commands from the project rather than clap.
+fn plan_run(rest: &[String], current: &str) -> Completion {
+    let Some(command) = rest.iter().find(|w| !w.starts_with('-')) else {
+        return if current.starts_with('-') {
+            Completion::Candidates(Vec::new())
+        } else {
+            Completion::RunCommands
+        };
+    };
+
+    let expects_value = rest
+        .last()
+        .is_some_and(|w| w.starts_with("--") && !w.contains('=') && w != command);
+    if expects_value && !current.starts_with('-') {
+        return Completion::Files;
+    }
+    if !current.is_empty() && !current.starts_with('-') {
+        return Completion::Files;
+    }
+
+    Completion::RunInputs {
+        command: command.clone(),
+        given: rest
+            .iter()
+            .filter_map(|w| w.strip_prefix("--"))
+            .map(|w| w.split('=').next().unwrap_or(w).to_string())
+            .collect(),
+    }
+}
+
+fn complete_value(arg: &Arg, given: &HashMap<String, String>) -> Completion {
+    let values = arg.get_possible_values();
+    if !values.is_empty() {
+        return Completion::Candidates(
+            values
+                .iter()
+                .filter(|v| !v.is_hide_set())
+                .map(|v| Candidate {
+                    value: v.get_name().to_string(),
+                    help: v.get_help().map(|h| h.to_string()),
+                })
+                .collect(),
+        );
+    }
+
+    match Lookup::for_arg(arg) {
+        Some(lookup) => Completion::Lookup {
+            lookup,
+            organisation: given.get("organisation").cloned(),
+        },
+        None => Completion::Files,
+    }
+}
+
+fn flags(cmd: &Command) -> Vec<Candidate> {
+    cmd.get_arguments()
+        .filter(|a| !a.is_hide_set())
+        .filter_map(|a| {
+            Some(Candidate {
+                value: format!("--{}", a.get_long()?),
+                help: a.get_help().map(|h| h.to_string()),
+            })
+        })
+        .collect()
+}
+
+fn subcommands(cmd: &Command) -> Vec<Candidate> {
+    cmd.get_subcommands()
+        .filter(|s| !s.is_hide_set())
+        .flat_map(|s| {
+            let help = s.get_about().map(|h| h.to_string());
+            std::iter::once(s.get_name())
+                .chain(s.get_visible_aliases())
+                .map(move |name| Candidate {
+                    value: name.to_string(),
+                    help: help.clone(),
+                })
+        })
+        .collect()
+}
+
+async fn run_commands(state: &State, current: &str) -> Vec<Candidate> {
+    let Ok(project) = state.project_parser().get_project().await else {
+        return Vec::new();
+    };
+    let (_, run_cmd) = build_dynamic_command(&project);
+
+    run_cmd
+        .get_subcommands()
+        // Qualified `component:command` names are hidden until asked for.
+        .filter(|s| !s.is_hide_set() || current.contains(':'))
+        .map(|s| Candidate {
+            value: s.get_name().to_string(),
+            help: s.get_about().map(|h| h.to_string()),
+        })
+        .collect()
+}
+
+async fn run_inputs(state: &State, command: &str, given: &[String]) -> Vec<Candidate> {
+    let Ok(project) = state.project_parser().get_project().await else {
+        return Vec::new();
+    };
+    let (cli_names, _) = build_dynamic_command(&project);
+    let Some(cmd_name) = cli_names.get(command) else {
+        return Vec::new();
+    };
+    let Some(fields) = fetch_command_input_schema(cmd_name, cmd_name.command_name()).await
+    else {
+        return Vec::new();
+    };
+
+    fields
+        .into_iter()
+        .filter(|f| !given.contains(&f.name))
+        .map(|f| Candidate {
+            value: format!("--{}", f.name),
+            help: f.description,
+        })
+        .collect()
+}
+
+async fn lookup_names(
+    state: &State,
+    lookup: Lookup,
+    organisation: Option<String>,
+    current: &str,
+) -> Vec<String> {
+    if lookup == Lookup::Organisations {
+        return cached(state, lookup.cache_key(), async {
+            let resp = state.grpc_client().list_my_organisations("").await?;
+            Ok(resp.organisations.into_iter().map(|o| o.name).collect())
+        })
+        .await;
+    }
+
+    // Components are typed as `<org>/<name>`, so the organisation may be
+    // right there in the word being completed.
+    let organisation = match (lookup, current.split_once('/')) {
+        (Lookup::Components, Some((org, _))) => Some(org.to_string()),
+        _ => organisation,
+    };
+    let organisation = match organisation {
+        Some(organisation) => organisation,
+        None => match state.project_parser().parse_project_file().await {
+            Ok(project) => match project.organisation {
+                Some(organisation) => organisation,
+                None => return Vec::new(),
+            },
+            Err(_) => return Vec::new(),
+        },
+    };
+
+    let key = format!("{}-{organisation}", lookup.cache_key());
+    let client = state.grpc_client();
+    cached(state, &key, async {
+        Ok(match lookup {
+            Lookup::Projects => client
+                .get_projects(GetProjectsQuery::Organisation(organisation.clone().into()))
+                .await?
+                .into_iter()
+                .map(|p| p.to_string())
+                .collect(),
+            Lookup::Environments => client
+                .list_environments(&organisation)
+                .await?
+                .into_iter()
+                .map(|e| e.name)
+                .collect(),
+            Lookup::Destinations => client
+                .get_destinations(&organisation)
+                .await?
+                .into_iter()
+                .map(|d| d.name)
+                .collect(),
+            Lookup::Components => client
+                .search_components("", &organisation)
+                .await?
+                .into_iter()
+                .map(|c| format!("{}/{}", c.organisation, c.name))
+                .collect(),
+            Lookup::Organisations => unreachable!("handled above"),
+        })
+    })
+    .await
+}
+
+/// Names from the completion cache, refreshed from the server once they are
+/// older than [`CACHE_TTL`]. When the server is slow or unreachable, stale
+/// names are better than none.
+async fn cached(
+    state: &State,
+    key: &str,
+    fetch: impl Future<Output = anyhow::Result<Vec<String>>>,
+) -> Vec<String> {
+    let Some(path) = cache_path(state, key) else {
+        return Vec::new();
+    };
+
+    let stale = std::fs::read_to_string(&path)
+        .ok()
+        .and_then(|content| serde_json::from_str::<Vec<String>>(&content).ok());
+    let fresh = std::fs::metadata(&path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+        .is_some_and(|age| age < CACHE_TTL);
+    if fresh && let Some(names) = stale {
+        return names;
+    }
+
+    match tokio::time::timeout(LOOKUP_TIMEOUT, fetch).await {
+        Ok(Ok(names)) => {
+            if let Some(parent) = path.parent() {
+                let _ = std::fs::create_dir_all(parent);
+            }
+            if let Ok(content) = serde_json::to_string(&names) {
+                let _ = std::fs::write(&path, content);
+            }
+            names
+        }
+        Ok(Err(e)) => {
+            tracing::debug!("completion lookup {key} failed: {e:#}");
+            stale.unwrap_or_default()
+        }
+        Err(_) => stale.unwrap_or_default(),
+    }
+}
+
+/// One cache directory per server, so switching contexts never offers
+/// another server's names.
+fn cache_path(state: &State, key: &str) -> Option<std::path::PathBuf> {
+    let scope = match &state.config.forest_server {
+        Some(server) => server.clone(),
+        None => ContextStore::from_env()
+            .ok()
+            .and_then(|store| store.try_resolve(state.config.context.as_deref()).ok())
+            .flatten()
+            .map(|entry| entry.name)
+            .unwrap_or_else(|| "default".into()),
+    };
+    let paths = GlobalPaths::from_env().ok()?;
+    Some(
+        paths
+            .cache_dir()
+            .join("completions")
+            .join(sanitize(&scope))
+            .join(format!("{}.json", sanitize(key))),
+    )
+}
+
+fn sanitize(name: &str) -> String {
+    name.chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' | '.' => c,
+            _ => '_',
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plan_for(line: &str) -> Completion {
+        let mut words: Vec<String> = line.split(' ').map(String::from).collect();
+        if words.len() == 1 {
+            words.push(String::new());
+        }
+        let cword = words.len() - 1;
+        plan(&crate::cli::Command::command(), &words, cword)
+    }
+
+    fn values(completion: Completion) -> Vec<String> {
+        match completion {
+            Completion::Candidates(candidates) => {
+                candidates.into_iter().map(|c| c.value).collect()
+            }
+            other => panic!("expected candidates, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn top_level_commands_are_offered() {
+        let values = values(plan_for("forest "));
+        assert!(values.contains(&"release".to_string()));
+        assert!(values.contains(&"environment".to_string()));
+        assert!(!values.contains(&"tmp".to_string()), "hidden commands stay hidden");
+    }
+
+    #[test]
+    fn flags_of_the_current_subcommand_are_offered() {
+        let values = values(plan_for("forest environment list --"));
+        assert!(values.contains(&"--organisation".to_string()));
+        assert!(values.contains(&"--format".to_string()), "global flags included");
+    }
+
+    #[test]
+    fn value_enums_complete_to_their_values() {
+        let values = values(plan_for("forest environment list --format "));
+        assert!(values.contains(&"json".to_string()));
+    }
+
+    #[test]
+    fn named_flags_look_up_server_names() {
+        assert_eq!(
+            plan_for("forest environment list --organisation "),
+            Completion::Lookup {
+                lookup: Lookup::Organisations,
+                organisation: None,
+            }
+        );
+        assert_eq!(
+            plan_for("forest destination create -o acme --environment "),
+            Completion::Lookup {
+                lookup: Lookup::Environments,
+                organisation: Some("acme".into()),
+            }
+        );
+    }
+
+    #[test]
+    fn run_completes_from_the_project() {
+        assert_eq!(plan_for("forest run "), Completion::RunCommands);
+        assert_eq!(
+            plan_for("forest run deploy --env prod --"),
+            Completion::RunInputs {
+                command: "deploy".into(),
+                given: vec!["env".into()],
+            }
+        );
+        assert_eq!(plan_for("forest run deploy --env "), Completion::Files);
+    }
+}
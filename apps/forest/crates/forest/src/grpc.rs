@@ -16,6 +16,10 @@ use forest_grpc_interface::{
     registry_service_client::RegistryServiceClient,
     release_pipeline_service_client::ReleasePipelineServiceClient,
     release_service_client::ReleaseServiceClient,
+    release_history_service_client::ReleaseHistoryServiceClient,
+    release_schedule_service_client::ReleaseScheduleServiceClient,
+    retention_service_client::RetentionServiceClient,
+    secret_service_client::SecretServiceClient,
     users_service_client::UsersServiceClient, *,
 };
 use forest_models::{Destination, DestinationType, OrganisationName, ProjectName};
@@ -528,6 +532,39 @@ impl GrpcClient {
         Ok(msg.artifact_id.try_into()?)
     }
 
+    pub async fn get_artifact_files(
+        &self,
+        artifact_id: &ArtifactID,
+        category: Option<&str>,
+    ) -> anyhow::Result<Vec<ArtifactFile>> {
+        let mut client = self.artifact_client().await?;
+
+        let resp = client
+            .get_artifact_files(GetArtifactFilesRequest {
+                artifact_id: artifact_id.to_string(),
+                category: category.map(|c| c.to_string()),
+            })
+            .await
+            .map_err(grpc_err)
+            .context("get artifact files (grpc)")?;
+
+        Ok(resp.into_inner().files)
+    }
+
+    pub async fn get_artifact_spec(&self, artifact_id: &ArtifactID) -> anyhow::Result<String> {
+        let mut client = self.artifact_client().await?;
+
+        let resp = client
+            .get_artifact_spec(GetArtifactSpecRequest {
+                artifact_id: artifact_id.to_string(),
+            })
+            .await
+            .map_err(grpc_err)
+            .context("get artifact spec (grpc)")?;
+
+        Ok(resp.into_inner().content)
+    }
+
     async fn channel(&self) -> anyhow::Result<Channel> {
         let channel = self
             .channel
@@ -564,9 +601,7 @@ impl GrpcClient {
         Ok(client.clone())
     }
 
-    pub async fn health_client(
-        &self,
-    ) -> anyhow::Result<forest_grpc_interface::release_health_service_client::ReleaseHealthServiceClient<AuthMiddleware<Channel>>> {
+    pub async fn health_client(&self) -> anyhow::Result<HealthClient> {
         let channel = self.auth_channel(self.channel().await?);
         Ok(forest_grpc_interface::release_health_service_client::ReleaseHealthServiceClient::new(channel))
     }
@@ -783,6 +818,79 @@ impl GrpcClient {
         Ok(resp.into_inner())
     }
 
+    pub async fn set_scim_group_role(
+        &self,
+        organisation_id: &str,
+        group_name: &str,
+        role: &str,
+    ) -> anyhow::Result<()> {
+        let mut client = self.organisation_client().await?;
+        client
+            .set_scim_group_role(SetScimGroupRoleRequest {
+                organisation_id: organisation_id.into(),
+                group_name: group_name.into(),
+                role: role.into(),
+            })
+            .await
+            .map_err(grpc_err)
+            .context("set scim group role")?;
+        Ok(())
+    }
+
+    pub async fn list_scim_group_roles(
+        &self,
+        organisation_id: &str,
+    ) -> anyhow::Result<Vec<ScimGroupRole>> {
+        let mut client = self.organisation_client().await?;
+        let resp = client
+            .list_scim_group_roles(ListScimGroupRolesRequest {
+                organisation_id: organisation_id.into(),
+            })
+            .await
+            .map_err(grpc_err)
+            .context("list scim group roles")?;
+        Ok(resp.into_inner().group_roles)
+    }
+
+    pub async fn delete_scim_group_role(
+        &self,
+        organisation_id: &str,
+        group_name: &str,
+    ) -> anyhow::Result<()> {
+        let mut client = self.organisation_client().await?;
+        client
+            .delete_scim_group_role(DeleteScimGroupRoleRequest {
+                organisation_id: organisation_id.into(),
+                group_name: group_name.into(),
+            })
+            .await
+            .map_err(grpc_err)
+            .context("delete scim group role")?;
+        Ok(())
+    }
+
+    pub async fn list_my_invites(&self) -> anyhow::Result<Vec<OrganisationInvite>> {
+        let mut client = self.organisation_client().await?;
+        let resp = client
+            .list_my_invites(ListMyInvitesRequest {})
+            .await
+            .map_err(grpc_err)
+            .context("list invites")?;
+        Ok(resp.into_inner().invites)
+    }
+
+    pub async fn accept_invite(&self, organisation_id: &str) -> anyhow::Result<()> {
+        let mut client = self.organisation_client().await?;
+        client
+            .accept_invite(AcceptInviteRequest {
+                organisation_id: organisation_id.into(),
+            })
+            .await
+            .map_err(grpc_err)
+            .context("accept invite")?;
+        Ok(())
+    }
+
     async fn notification_client(
         &self,
     ) -> anyhow::Result<NotificationServiceClient<AuthMiddleware<Channel>>> {
@@ -1281,7 +1389,17 @@ impl GrpcClient {
         })
     }
 
-    pub async fn wait_release(&self, release_intent_id: Uuid) -> anyhow::Result<WaitReleaseResult> {
+    /// Stream logs and status for a release intent until it finishes.
+    /// `on_stage` is invoked after each pipeline stage update is printed.
+    pub async fn wait_release<F>(
+        &self,
+        release_intent_id: Uuid,
+        compact: bool,
+        mut on_stage: impl FnMut(&forest_grpc_interface::PipelineStageUpdate) -> F,
+    ) -> anyhow::Result<WaitReleaseResult>
+    where
+        F: Future<Output = ()>,
+    {
         use futures::StreamExt;
 
         let mut client = self.release_client().await?;
@@ -1317,14 +1435,12 @@ impl GrpcClient {
                     final_statuses.insert(status.destination, release_status);
                 }
                 Some(forest_grpc_interface::wait_release_event::Event::LogLine(log)) => {
-                    // Print log lines to appropriate output stream
+                    // Step logs are kept in compact mode too; CI logs are
+                    // often the only place a failed step's output survives.
+                    let line = format_log_line(&log.destination, &log.line, compact);
                     match forest_grpc_interface::LogChannel::try_from(log.channel) {
-                        Ok(forest_grpc_interface::LogChannel::Stderr) => {
-                            eprintln!("{}: {}", log.destination, log.line);
-                        }
-                        _ => {
-                            println!("{}: {}", log.destination, log.line);
-                        }
+                        Ok(forest_grpc_interface::LogChannel::Stderr) => eprintln!("{line}"),
+                        _ => println!("{line}"),
                     }
                 }
                 Some(forest_grpc_interface::wait_release_event::Event::StageUpdate(stage)) => {
@@ -1335,6 +1451,15 @@ impl GrpcClient {
                         "received stage update"
                     );
 
+                    if compact {
+                        eprintln!("stage: {} {} {}", stage.stage_id, stage.stage_type, stage.status);
+                        if let Some(err) = &stage.error_message {
+                            eprintln!("stage: {} error: {err}", stage.stage_id);
+                        }
+                        on_stage(&stage).await;
+                        continue;
+                    }
+
                     let icon = match stage.status.as_str() {
                         "SUCCEEDED" => "✓",
                         "ACTIVE" => "▶",
@@ -1356,6 +1481,8 @@ impl GrpcClient {
                     if let Some(err) = &stage.error_message {
                         eprintln!("    error: {err}");
                     }
+
+                    on_stage(&stage).await;
                 }
                 None => {}
             }
@@ -1503,10 +1630,97 @@ impl GrpcClient {
                     r.metadata,
                     r.r#type.expect("to always be available").into(),
                 )
+                .with_runner_labels(r.runner_labels)
             })
             .collect())
     }
 
+    pub async fn list_terraform_states(
+        &self,
+        organisation: &str,
+        project: &str,
+    ) -> anyhow::Result<Vec<TerraformState>> {
+        let mut client = self.destination_client().await?;
+
+        let resp = client
+            .list_terraform_states(ListTerraformStatesRequest {
+                organisation: organisation.to_string(),
+                project: project.to_string(),
+            })
+            .await
+            .map_err(grpc_err)
+            .context("list terraform states (grpc)")?;
+
+        Ok(resp.into_inner().states)
+    }
+
+    pub async fn list_terraform_state_versions(
+        &self,
+        organisation: &str,
+        project: &str,
+        environment: &str,
+    ) -> anyhow::Result<Vec<TerraformStateVersion>> {
+        let mut client = self.destination_client().await?;
+
+        let resp = client
+            .list_terraform_state_versions(ListTerraformStateVersionsRequest {
+                organisation: organisation.to_string(),
+                project: project.to_string(),
+                environment: environment.to_string(),
+            })
+            .await
+            .map_err(grpc_err)
+            .context("list terraform state versions (grpc)")?;
+
+        Ok(resp.into_inner().versions)
+    }
+
+    pub async fn force_unlock_terraform_state(
+        &self,
+        organisation: &str,
+        project: &str,
+        environment: &str,
+    ) -> anyhow::Result<Option<TerraformStateLock>> {
+        let mut client = self.destination_client().await?;
+
+        let resp = client
+            .force_unlock_terraform_state(ForceUnlockTerraformStateRequest {
+                organisation: organisation.to_string(),
+                project: project.to_string(),
+                environment: environment.to_string(),
+            })
+            .await
+            .map_err(grpc_err)
+            .context("force unlock terraform state (grpc)")?;
+
+        Ok(resp.into_inner().released_lock)
+    }
+
+    pub async fn rollback_terraform_state(
+        &self,
+        organisation: &str,
+        project: &str,
+        environment: &str,
+        version: i64,
+    ) -> anyhow::Result<TerraformStateVersion> {
+        let mut client = self.destination_client().await?;
+
+        let resp = client
+            .rollback_terraform_state(RollbackTerraformStateRequest {
+                organisation: organisation.to_string(),
+                project: project.to_string(),
+                environment: environment.to_string(),
+                version,
+            })
+            .await
+            .map_err(grpc_err)
+            .context("rollback terraform state (grpc)")?;
+
+        resp.into_inner()
+            .version
+            .ok_or_else(|| anyhow::anyhow!("rollback returned no version"))
+    }
+
     pub async fn create_destination(
         &self,
         organisation: &str,
@@ -1514,6 +1728,8 @@ impl GrpcClient {
         environment: &str,
         metadata: HashMap<String, String>,
         destination_type: DestinationType,
+        runner_labels: HashMap<String, String>,
+        queue_policy: forest_models::QueuePolicy,
     ) -> anyhow::Result<()> {
         self.destination_client()
             .await?
@@ -1523,6 +1739,8 @@ impl GrpcClient {
                 environment: environment.to_string(),
                 metadata,
                 r#type: Some(destination_type.into()),
+                runner_labels,
+                queue_policy: ReleaseQueuePolicy::from(queue_policy).into(),
             })
             .await
             .map_err(grpc_err)
@@ -1536,6 +1754,8 @@ impl GrpcClient {
         organisation: &str,
         name: &str,
         metadata: HashMap<String, String>,
+        runner_labels: Option<HashMap<String, String>>,
+        queue_policy: Option<forest_models::QueuePolicy>,
     ) -> anyhow::Result<()> {
         self.destination_client()
             .await?
@@ -1543,6 +1763,8 @@ impl GrpcClient {
                 name: name.to_string(),
                 metadata,
                 organisation: organisation.to_string(),
+                runner_labels: runner_labels.map(|labels| RunnerLabels { labels }),
+                queue_policy: queue_policy.map(|p| ReleaseQueuePolicy::from(p).into()),
             })
             .await
             .map_err(grpc_err)
@@ -1551,6 +1773,21 @@ impl GrpcClient {
         Ok(())
     }
 
+    /// Runners connected to the server. With an organisation, only runners
+    /// that can take its work; without, all runners (service accounts only).
+    pub async fn list_runners(&self, organisation: &str) -> anyhow::Result<Vec<RunnerInfo>> {
+        let channel = self.auth_channel(self.channel().await?);
+        let resp = forest_grpc_interface::runner_service_client::RunnerServiceClient::new(channel)
+            .list_runners(ListRunnersRequest {
+                organisation: organisation.to_string(),
+            })
+            .await
+            .map_err(grpc_err)
+            .context("list runners (grpc)")?;
+
+        Ok(resp.into_inner().runners)
+    }
+
     pub async fn delete_destination(
         &self,
         organisation: &str,
@@ -1589,6 +1826,25 @@ impl GrpcClient {
         Ok(resp.into_inner())
     }
 
+    /// Cancel a queued or running release.
+    pub async fn cancel_release(
+        &self,
+        release_id: &str,
+        reason: Option<&str>,
+    ) -> anyhow::Result<String> {
+        let mut client = self.release_client().await?;
+        let resp = client
+            .cancel_release(CancelReleaseRequest {
+                release_id: release_id.to_string(),
+                reason: reason.map(|r| r.to_string()),
+            })
+            .await
+            .map_err(grpc_err)
+            .context("cancel release (grpc)")?;
+
+        Ok(resp.into_inner().status)
+    }
+
     pub async fn get_release_intent_states(
         &self,
         organisation: &str,
@@ -1677,11 +1933,27 @@ impl GrpcClient {
             .ok_or_else(|| anyhow::anyhow!("environment not found"))
     }
 
+    pub async fn get_environment_by_id(&self, id: &str) -> anyhow::Result<Environment> {
+        let mut client = self.environment_client().await?;
+        let resp = client
+            .get_environment(GetEnvironmentRequest {
+                identifier: Some(get_environment_request::Identifier::Id(id.to_string())),
+            })
+            .await
+            .map_err(grpc_err)
+            .context("get environment (grpc)")?;
+
+        resp.into_inner()
+            .environment
+            .ok_or_else(|| anyhow::anyhow!("environment not found"))
+    }
+
     pub async fn update_environment(
         &self,
         id: &str,
         description: Option<&str>,
         sort_order: Option<i32>,
+        protection: Option<EnvironmentProtection>,
     ) -> anyhow::Result<Environment> {
         let mut client = self.environment_client().await?;
         let resp = client
@@ -1689,6 +1961,7 @@ impl GrpcClient {
                 id: id.to_string(),
                 description: description.map(|s| s.to_string()),
                 sort_order,
+                protection,
             })
             .await
             .map_err(grpc_err)
@@ -2383,6 +2656,259 @@ impl GrpcClient {
 
         Ok(())
     }
+
+    // ── Secrets ───────────────────────────────────────────────────────
+
+    async fn secret_client(&self) -> anyhow::Result<SecretServiceClient<AuthMiddleware<Channel>>> {
+        // No OnceCell needed — secrets are managed by hand, one call per command
+        let channel = self.auth_channel(self.channel().await?);
+        Ok(SecretServiceClient::new(channel))
+    }
+
+    pub async fn set_secret(
+        &self,
+        scope: SecretScope,
+        name: &str,
+        value: &str,
+    ) -> anyhow::Result<Secret> {
+        let mut client = self.secret_client().await?;
+
+        let resp = client
+            .set_secret(SetSecretRequest {
+                scope: Some(scope),
+                name: name.to_string(),
+                value: value.to_string(),
+            })
+            .await
+            .map_err(grpc_err)
+            .context("set secret (grpc)")?;
+
+        resp.into_inner()
+            .secret
+            .ok_or_else(|| anyhow::anyhow!("set secret returned no secret"))
+    }
+
+    pub async fn list_secrets(
+        &self,
+        organisation: &str,
+        project: Option<&str>,
+        environment: Option<&str>,
+    ) -> anyhow::Result<Vec<Secret>> {
+        let mut client = self.secret_client().await?;
+
+        let resp = client
+            .list_secrets(ListSecretsRequest {
+                organisation: organisation.to_string(),
+                project: project.map(str::to_string),
+                environment: environment.map(str::to_string),
+            })
+            .await
+            .map_err(grpc_err)
+            .context("list secrets (grpc)")?;
+
+        Ok(resp.into_inner().secrets)
+    }
+
+    pub async fn delete_secret(&self, scope: SecretScope, name: &str) -> anyhow::Result<()> {
+        let mut client = self.secret_client().await?;
+
+        client
+            .delete_secret(DeleteSecretRequest {
+                scope: Some(scope),
+                name: name.to_string(),
+            })
+            .await
+            .map_err(grpc_err)
+            .context("delete secret (grpc)")?;
+
+        Ok(())
+    }
+
+    // ── Release history ───────────────────────────────────────────────
+
+    async fn release_history_client(
+        &self,
+    ) -> anyhow::Result<ReleaseHistoryServiceClient<AuthMiddleware<Channel>>> {
+        let channel = self.auth_channel(self.channel().await?);
+        Ok(ReleaseHistoryServiceClient::new(channel))
+    }
+
+    pub async fn list_releases(
+        &self,
+        request: ListReleasesRequest,
+    ) -> anyhow::Result<ListReleasesResponse> {
+        let mut client = self.release_history_client().await?;
+
+        let resp = client
+            .list_releases(request)
+            .await
+            .map_err(grpc_err)
+            .context("list releases (grpc)")?;
+
+        Ok(resp.into_inner())
+    }
+
+    pub async fn get_release(
+        &self,
+        organisation: &str,
+        project: &str,
+        slug: &str,
+        include_logs: bool,
+    ) -> anyhow::Result<GetReleaseResponse> {
+        let mut client = self.release_history_client().await?;
+
+        let resp = client
+            .get_release(GetReleaseRequest {
+                slug: slug.to_string(),
+                include_logs,
+                organisation: organisation.to_string(),
+                project: project.to_string(),
+            })
+            .await
+            .map_err(grpc_err)
+            .context("get release (grpc)")?;
+
+        Ok(resp.into_inner())
+    }
+
+    // ── Retention ─────────────────────────────────────────────────────
+
+    async fn retention_client(
+        &self,
+    ) -> anyhow::Result<RetentionServiceClient<AuthMiddleware<Channel>>> {
+        let channel = self.auth_channel(self.channel().await?);
+        Ok(RetentionServiceClient::new(channel))
+    }
+
+    pub async fn get_retention_policy(&self, organisation: &str) -> anyhow::Result<RetentionPolicy> {
+        let mut client = self.retention_client().await?;
+
+        let resp = client
+            .get_retention_policy(GetRetentionPolicyRequest {
+                organisation: organisation.to_string(),
+            })
+            .await
+            .map_err(grpc_err)
+            .context("get retention policy (grpc)")?;
+
+        resp.into_inner()
+            .policy
+            .ok_or_else(|| anyhow::anyhow!("get retention policy returned no policy"))
+    }
+
+    pub async fn set_retention_policy(&self, policy: RetentionPolicy) -> anyhow::Result<RetentionPolicy> {
+        let mut client = self.retention_client().await?;
+
+        let resp = client
+            .set_retention_policy(SetRetentionPolicyRequest {
+                organisation: policy.organisation,
+                keep_artifacts: policy.keep_artifacts,
+                log_retention_days: policy.log_retention_days,
+                notification_retention_days: policy.notification_retention_days,
+            })
+            .await
+            .map_err(grpc_err)
+            .context("set retention policy (grpc)")?;
+
+        resp.into_inner()
+            .policy
+            .ok_or_else(|| anyhow::anyhow!("set retention policy returned no policy"))
+    }
+
+    pub async fn preview_retention(
+        &self,
+        organisation: &str,
+        policy: Option<RetentionPolicy>,
+    ) -> anyhow::Result<PreviewRetentionResponse> {
+        let mut client = self.retention_client().await?;
+
+        let resp = client
+            .preview_retention(PreviewRetentionRequest {
+                organisation: organisation.to_string(),
+                policy,
+            })
+            .await
+            .map_err(grpc_err)
+            .context("preview retention (grpc)")?;
+
+        Ok(resp.into_inner())
+    }
+
+    // ── Release schedules ─────────────────────────────────────────────
+
+    async fn release_schedule_client(
+        &self,
+    ) -> anyhow::Result<ReleaseScheduleServiceClient<AuthMiddleware<Channel>>> {
+        let channel = self.auth_channel(self.channel().await?);
+        Ok(ReleaseScheduleServiceClient::new(channel))
+    }
+
+    pub async fn create_release_schedule(
+        &self,
+        request: CreateReleaseScheduleRequest,
+    ) -> anyhow::Result<ReleaseSchedule> {
+        let mut client = self.release_schedule_client().await?;
+
+        let resp = client
+            .create_release_schedule(request)
+            .await
+            .map_err(grpc_err)
+            .context("create release schedule (grpc)")?;
+
+        resp.into_inner()
+            .schedule
+            .ok_or_else(|| anyhow::anyhow!("create release schedule returned no schedule"))
+    }
+
+    pub async fn list_release_schedules(
+        &self,
+        organisation: &str,
+        project: &str,
+        include_inactive: bool,
+    ) -> anyhow::Result<Vec<ReleaseSchedule>> {
+        let mut client = self.release_schedule_client().await?;
+
+        let resp = client
+            .list_release_schedules(ListReleaseSchedulesRequest {
+                project: Some(Project {
+                    organisation: organisation.to_string(),
+                    project: project.to_string(),
+                    ..Default::default()
+                }),
+                include_inactive,
+            })
+            .await
+            .map_err(grpc_err)
+            .context("list release schedules (grpc)")?;
+
+        Ok(resp.into_inner().schedules)
+    }
+
+    pub async fn cancel_release_schedule(
+        &self,
+        organisation: &str,
+        project: &str,
+        id: &str,
+    ) -> anyhow::Result<ReleaseSchedule> {
+        let mut client = self.release_schedule_client().await?;
+
+        let resp = client
+            .cancel_release_schedule(CancelReleaseScheduleRequest {
+                project: Some(Project {
+                    organisation: organisation.to_string(),
+                    project: project.to_string(),
+                    ..Default::default()
+                }),
+                id: id.to_string(),
+            })
+            .await
+            .map_err(grpc_err)
+            .context("cancel release schedule (grpc)")?;
+
+        resp.into_inner()
+            .schedule
+            .ok_or_else(|| anyhow::anyhow!("cancel release schedule returned no schedule"))
+    }
 }
 
 pub enum GetProjectsQuery {
@@ -2399,6 +2925,19 @@ pub struct ReleaseIntentInfo {
     pub environment: String,
 }
 
+/// One step log line as printed while waiting on a release. Compact lines
+/// carry a `log:` prefix like the `stage:` and `health:` lines around them.
+pub(crate) fn format_log_line(destination: &str, line: &str, compact: bool) -> String {
+    if compact {
+        format!("log: {destination}: {line}")
+    } else {
+        format!("{destination}: {line}")
+    }
+}
+
+pub type HealthClient =
+    forest_grpc_interface::release_health_service_client::ReleaseHealthServiceClient<AuthMiddleware<Channel>>;
+
 pub struct WaitReleaseResult {
     pub destinations: Vec<WaitReleaseDestinationResult>,
 }
@@ -2531,6 +3070,7 @@ impl TryFrom<forest_grpc_interface::Artifact> for models::release_annotation::Re
             id: value.id.parse().context("id")?,
             artifact_id: value.artifact_id.parse().context("artifact id")?,
             slug: value.slug,
+            project: value.project.map(|p| p.into()),
             metadata: value.metadata,
             source: value.source.context("source not found")?.into(),
             context: value.context.context("context not found")?.into(),
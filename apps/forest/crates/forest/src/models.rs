@@ -37,12 +37,15 @@ pub mod release_annotation {
     use chrono::{DateTime, Utc};
     use uuid::Uuid;
 
-    use crate::models::{context::ArtifactContext, reference::Reference, source::Source};
+    use crate::models::{
+        context::ArtifactContext, project::Project, reference::Reference, source::Source,
+    };
 
     pub struct ReleaseAnnotation {
         pub id: Uuid,
         pub artifact_id: Uuid,
         pub slug: String,
+        pub project: Option<Project>,
         pub metadata: HashMap<String, String>,
         pub source: Source,
         pub context: ArtifactContext,
@@ -0,0 +1,487 @@
+//! Input hashing for `forest build`.
+//!
+//! Pure core, like `component_walk`. A target's input hash covers the
+//! component sources, what the build reads from outside them (lockfiles
+//! and local path dependencies), its CUE specs, the toolchain version and
+//! the target itself. When the hash and the built artifact both match what
+//! the last build recorded in `.forest/component/build-cache.json`, the
+//! target is skipped.
+
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, VecDeque},
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Directories that never affect a build: VCS metadata, build outputs and
+/// installed dependencies (their lockfiles are hashed instead).
+const IGNORED_DIRS: &[&str] = &[".git", "target", "node_modules", ".forest", ".idea", ".vscode"];
+
+/// Hash every file under `root`, in path order, skipping [`IGNORED_DIRS`].
+/// Renames, additions and deletions change the hash, not just edits.
+pub fn hash_sources(root: &Path) -> anyhow::Result<String> {
+    let mut files = Vec::new();
+    let walker = walkdir::WalkDir::new(root)
+        .follow_links(false)
+        .into_iter()
+        .filter_entry(|e| {
+            e.depth() == 0
+                || !(e.file_type().is_dir()
+                    && IGNORED_DIRS.contains(&e.file_name().to_string_lossy().as_ref()))
+        });
+    for entry in walker {
+        let entry = entry.with_context(|| format!("walk {}", root.display()))?;
+        if entry.file_type().is_file() {
+            files.push(entry.into_path());
+        }
+    }
+    files.sort();
+
+    let mut hasher = Sha256::new();
+    for path in files {
+        let rel = path.strip_prefix(root).unwrap_or(&path);
+        let content =
+            std::fs::read(&path).with_context(|| format!("read {}", path.display()))?;
+        hasher.update(rel.to_string_lossy().as_bytes());
+        hasher.update([0]);
+        hasher.update((content.len() as u64).to_le_bytes());
+        hasher.update(&content);
+    }
+    Ok(hex::encode(hasher.finalize()))
+}
+
+/// Files and directories outside the component's sources that its build
+/// reads: the workspace lockfile and manifest, and local path
+/// dependencies. Registry dependencies are pinned by the lockfile.
+#[derive(Debug, Default, PartialEq)]
+pub struct DependencyInputs {
+    pub files: BTreeSet<PathBuf>,
+    pub dirs: BTreeSet<PathBuf>,
+}
+
+#[derive(Deserialize)]
+struct CargoMetadata {
+    packages: Vec<CargoPackage>,
+    resolve: Option<CargoResolve>,
+    workspace_root: PathBuf,
+}
+
+#[derive(Deserialize)]
+struct CargoPackage {
+    id: String,
+    manifest_path: PathBuf,
+    source: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct CargoResolve {
+    nodes: Vec<CargoNode>,
+}
+
+#[derive(Deserialize)]
+struct CargoNode {
+    id: String,
+    deps: Vec<CargoDep>,
+}
+
+#[derive(Deserialize)]
+struct CargoDep {
+    pkg: String,
+}
+
+impl DependencyInputs {
+    /// From `cargo metadata --format-version 1` run in the component: the
+    /// local packages the one at `manifest_path` depends on, transitively,
+    /// plus the workspace's `Cargo.toml` and `Cargo.lock`.
+    pub fn from_cargo_metadata(metadata: &str, manifest_path: &Path) -> anyhow::Result<Self> {
+        let metadata: CargoMetadata =
+            serde_json::from_str(metadata).context("parse cargo metadata")?;
+        let packages: HashMap<&str, &CargoPackage> =
+            metadata.packages.iter().map(|p| (p.id.as_str(), p)).collect();
+        let root = metadata
+            .packages
+            .iter()
+            .find(|p| p.manifest_path == manifest_path)
+            .with_context(|| format!("no package at {} in cargo metadata", manifest_path.display()))?;
+        let resolve = metadata
+            .resolve
+            .context("cargo metadata has no dependency resolution")?;
+        let nodes: HashMap<&str, &CargoNode> =
+            resolve.nodes.iter().map(|n| (n.id.as_str(), n)).collect();
+
+        let mut inputs = Self::default();
+        inputs.files.insert(metadata.workspace_root.join("Cargo.toml"));
+        inputs.files.insert(metadata.workspace_root.join("Cargo.lock"));
+
+        let mut seen = BTreeSet::from([root.id.as_str()]);
+        let mut queue = VecDeque::from([root.id.as_str()]);
+        while let Some(id) = queue.pop_front() {
+            let Some(node) = nodes.get(id) else { continue };
+            for dep in &node.deps {
+                if !seen.insert(dep.pkg.as_str()) {
+                    continue;
+                }
+                queue.push_back(dep.pkg.as_str());
+                if let Some(package) = packages.get(dep.pkg.as_str())
+                    && package.source.is_none()
+                    && let Some(dir) = package.manifest_path.parent()
+                {
+                    inputs.dirs.insert(dir.to_path_buf());
+                }
+            }
+        }
+
+        Ok(inputs)
+    }
+
+    /// From `go list -deps -f '{{if not .Standard}}{{.Dir}}{{end}}'` run in
+    /// `source`: package directories outside the source and the module
+    /// cache (whose contents `go.sum` pins), plus the module's `go.mod` and
+    /// `go.sum` and, in a Go workspace, `go.work` and `go.work.sum`.
+    pub fn from_go_list(
+        list: &str,
+        source: &Path,
+        go_mod: &Path,
+        go_work: Option<&Path>,
+        mod_cache: &Path,
+    ) -> Self {
+        let mut inputs = Self::default();
+        inputs.files.insert(go_mod.to_path_buf());
+        inputs.files.insert(go_mod.with_file_name("go.sum"));
+        if let Some(go_work) = go_work {
+            inputs.files.insert(go_work.to_path_buf());
+            inputs.files.insert(go_work.with_file_name("go.work.sum"));
+        }
+
+        let mut dirs: Vec<PathBuf> = list
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty())
+            .map(PathBuf::from)
+            .filter(|d| !d.starts_with(source) && !d.starts_with(mod_cache))
+            .collect();
+        dirs.sort();
+        // Sources are hashed recursively, so nested packages are covered
+        // by their parent.
+        for dir in dirs {
+            if !inputs.dirs.iter().any(|d| dir.starts_with(d)) {
+                inputs.dirs.insert(dir);
+            }
+        }
+
+        inputs
+    }
+
+    /// Hash the files (a missing one hashes as absent) and the sources of
+    /// each directory, by path.
+    pub fn hash(&self) -> anyhow::Result<String> {
+        let mut hasher = Sha256::new();
+        for path in &self.files {
+            hasher.update(path.to_string_lossy().as_bytes());
+            hasher.update([0]);
+            match std::fs::read(path) {
+                Ok(content) => {
+                    hasher.update((content.len() as u64).to_le_bytes());
+                    hasher.update(&content);
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => hasher.update([0xff]),
+                Err(e) => return Err(e).with_context(|| format!("read {}", path.display())),
+            }
+        }
+        for dir in &self.dirs {
+            hasher.update(dir.to_string_lossy().as_bytes());
+            hasher.update([0]);
+            hasher.update(hash_sources(dir)?.as_bytes());
+        }
+        Ok(hex::encode(hasher.finalize()))
+    }
+}
+
+/// Everything a single target's build depends on.
+pub struct TargetInputs<'a> {
+    pub sources: &'a str,
+    /// [`DependencyInputs::hash`]
+    pub dependencies: &'a str,
+    /// Contents of `forest.cue` and the component spec.
+    pub specs: &'a [String],
+    pub toolchain: &'a str,
+    /// e.g. `linux_amd64`
+    pub platform: &'a str,
+    pub name: &'a str,
+    pub version: &'a str,
+}
+
+impl TargetInputs<'_> {
+    pub fn hash(&self) -> String {
+        let mut hasher = Sha256::new();
+        for part in [
+            self.sources,
+            self.dependencies,
+            self.toolchain,
+            self.platform,
+            self.name,
+            self.version,
+        ]
+        .into_iter()
+        .chain(self.specs.iter().map(String::as_str))
+        {
+            hasher.update((part.len() as u64).to_le_bytes());
+            hasher.update(part.as_bytes());
+        }
+        hex::encode(hasher.finalize())
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct BuildCache {
+    #[serde(default)]
+    targets: BTreeMap<String, CachedTarget>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedTarget {
+    input_hash: String,
+    output_sha256: String,
+}
+
+impl BuildCache {
+    pub fn path(output_base: &Path) -> PathBuf {
+        output_base
+            .parent()
+            .unwrap_or(output_base)
+            .join("build-cache.json")
+    }
+
+    /// A missing or unreadable cache is an empty one; it only costs a build.
+    pub fn load(path: &Path) -> Self {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("write {}", path.display()))
+    }
+
+    /// True when `platform` was last built from `input_hash` and its
+    /// artifact at `output` is still the one that build produced.
+    pub fn is_fresh(&self, platform: &str, input_hash: &str, output: &Path) -> bool {
+        let Some(cached) = self.targets.get(platform) else {
+            return false;
+        };
+        if cached.input_hash != input_hash {
+            return false;
+        }
+        match std::fs::read(output) {
+            Ok(content) => hex::encode(Sha256::digest(&content)) == cached.output_sha256,
+            Err(_) => false,
+        }
+    }
+
+    pub fn record(&mut self, platform: &str, input_hash: &str, output: &Path) -> anyhow::Result<()> {
+        let content =
+            std::fs::read(output).with_context(|| format!("read {}", output.display()))?;
+        self.targets.insert(
+            platform.to_string(),
+            CachedTarget {
+                input_hash: input_hash.to_string(),
+                output_sha256: hex::encode(Sha256::digest(&content)),
+            },
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn source_tree() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        fs::create_dir_all(dir.path().join("src")).unwrap();
+        fs::write(dir.path().join("src/main.rs"), "fn main() {}").unwrap();
+        fs::write(dir.path().join("Cargo.toml"), "[package]").unwrap();
+        dir
+    }
+
+    #[test]
+    fn source_hash_ignores_build_outputs() {
+        let dir = source_tree();
+        let before = hash_sources(dir.path()).unwrap();
+
+        fs::create_dir_all(dir.path().join("target/release")).unwrap();
+        fs::write(dir.path().join("target/release/app"), "binary").unwrap();
+        fs::create_dir_all(dir.path().join(".forest/component/output")).unwrap();
+        fs::write(dir.path().join(".forest/component/output/app"), "binary").unwrap();
+
+        assert_eq!(hash_sources(dir.path()).unwrap(), before);
+    }
+
+    #[test]
+    fn source_hash_changes_on_edit_and_rename() {
+        let dir = source_tree();
+        let before = hash_sources(dir.path()).unwrap();
+
+        fs::write(dir.path().join("src/main.rs"), "fn main() { }").unwrap();
+        let edited = hash_sources(dir.path()).unwrap();
+        assert_ne!(edited, before);
+
+        fs::rename(dir.path().join("src/main.rs"), dir.path().join("src/lib.rs")).unwrap();
+        assert_ne!(hash_sources(dir.path()).unwrap(), edited);
+    }
+
+    /// A component `app` in a workspace, depending on the local `shared`
+    /// (which depends on `util`) and on serde from crates.io.
+    fn cargo_metadata(root: &Path) -> String {
+        let r = root.display();
+        serde_json::json!({
+            "packages": [
+                { "id": "app", "manifest_path": format!("{r}/app/Cargo.toml"), "source": null },
+                { "id": "shared", "manifest_path": format!("{r}/shared/Cargo.toml"), "source": null },
+                { "id": "util", "manifest_path": format!("{r}/util/Cargo.toml"), "source": null },
+                { "id": "other", "manifest_path": format!("{r}/other/Cargo.toml"), "source": null },
+                {
+                    "id": "serde",
+                    "manifest_path": "/registry/serde-1.0.0/Cargo.toml",
+                    "source": "registry+https://github.com/rust-lang/crates.io-index",
+                },
+            ],
+            "resolve": {
+                "nodes": [
+                    { "id": "app", "deps": [{ "pkg": "shared" }, { "pkg": "serde" }] },
+                    { "id": "shared", "deps": [{ "pkg": "util" }] },
+                    { "id": "util", "deps": [] },
+                    { "id": "other", "deps": [] },
+                    { "id": "serde", "deps": [] },
+                ],
+            },
+            "workspace_root": r.to_string(),
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn cargo_inputs_are_the_lockfile_and_local_dependencies() {
+        let root = Path::new("/ws");
+        let inputs = DependencyInputs::from_cargo_metadata(
+            &cargo_metadata(root),
+            &root.join("app/Cargo.toml"),
+        )
+        .unwrap();
+
+        assert_eq!(
+            inputs.files,
+            BTreeSet::from([root.join("Cargo.lock"), root.join("Cargo.toml")])
+        );
+        // Not the unrelated workspace member, nor the registry crate.
+        assert_eq!(
+            inputs.dirs,
+            BTreeSet::from([root.join("shared"), root.join("util")])
+        );
+    }
+
+    #[test]
+    fn dependency_hash_changes_with_lockfile_and_path_dependency() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        fs::write(root.join("Cargo.lock"), "version = 4").unwrap();
+        fs::create_dir_all(root.join("shared/src")).unwrap();
+        fs::write(root.join("shared/src/lib.rs"), "pub fn a() {}").unwrap();
+        fs::create_dir_all(root.join("util")).unwrap();
+        let inputs = DependencyInputs::from_cargo_metadata(
+            &cargo_metadata(root),
+            &root.join("app/Cargo.toml"),
+        )
+        .unwrap();
+        let before = inputs.hash().unwrap();
+        assert_eq!(inputs.hash().unwrap(), before);
+
+        fs::write(root.join("shared/src/lib.rs"), "pub fn b() {}").unwrap();
+        let edited = inputs.hash().unwrap();
+        assert_ne!(edited, before);
+
+        fs::write(root.join("Cargo.lock"), "version = 4\n# bumped").unwrap();
+        assert_ne!(inputs.hash().unwrap(), edited);
+    }
+
+    #[test]
+    fn go_inputs_skip_the_module_cache_and_own_sources() {
+        let list = "\
+/src/app
+/src/app/internal/db
+/src/shared
+/src/shared/sub
+/home/me/go/pkg/mod/github.com/google/uuid@v1.6.0
+";
+        let inputs = DependencyInputs::from_go_list(
+            list,
+            Path::new("/src/app"),
+            Path::new("/src/app/go.mod"),
+            Some(Path::new("/src/go.work")),
+            Path::new("/home/me/go/pkg/mod"),
+        );
+
+        assert_eq!(inputs.dirs, BTreeSet::from([PathBuf::from("/src/shared")]));
+        assert_eq!(
+            inputs.files,
+            BTreeSet::from([
+                PathBuf::from("/src/app/go.mod"),
+                PathBuf::from("/src/app/go.sum"),
+                PathBuf::from("/src/go.work"),
+                PathBuf::from("/src/go.work.sum"),
+            ])
+        );
+    }
+
+    #[test]
+    fn target_hash_covers_toolchain_and_platform() {
+        let specs = vec!["spec".to_string()];
+        let inputs = |toolchain, platform| TargetInputs {
+            sources: "abc",
+            dependencies: "def",
+            specs: &specs,
+            toolchain,
+            platform,
+            name: "app",
+            version: "0.1.0",
+        };
+        let base = inputs("rustc 1.90", "linux_amd64").hash();
+        assert_eq!(base, inputs("rustc 1.90", "linux_amd64").hash());
+        assert_ne!(base, inputs("rustc 1.91", "linux_amd64").hash());
+        assert_ne!(base, inputs("rustc 1.90", "linux_arm64").hash());
+    }
+
+    #[test]
+    fn cache_is_fresh_only_for_the_recorded_artifact() {
+        let dir = TempDir::new().unwrap();
+        let output = dir.path().join("app");
+        fs::write(&output, "v1").unwrap();
+
+        let mut cache = BuildCache::default();
+        assert!(!cache.is_fresh("linux_amd64", "hash", &output));
+
+        cache.record("linux_amd64", "hash", &output).unwrap();
+        assert!(cache.is_fresh("linux_amd64", "hash", &output));
+        assert!(!cache.is_fresh("linux_amd64", "other", &output));
+
+        let path = BuildCache::path(&dir.path().join("output"));
+        cache.save(&path).unwrap();
+        let loaded = BuildCache::load(&path);
+        assert!(loaded.is_fresh("linux_amd64", "hash", &output));
+
+        fs::write(&output, "tampered").unwrap();
+        assert!(!loaded.is_fresh("linux_amd64", "hash", &output));
+    }
+}
@@ -299,7 +299,8 @@ impl ProjectParser {
         Ok(project_components)
     }
 
-    async fn parse_project_file(&self) -> Result<Project, anyhow::Error> {
+    /// Parse the project file alone, without resolving component commands.
+    pub async fn parse_project_file(&self) -> Result<Project, anyhow::Error> {
         let current_dir =
             std::env::current_dir().context("current project dir is required for a project")?;
         let (project_file_path, project_file_content) = self.find_project_file(current_dir).await?;
@@ -0,0 +1,122 @@
+//! Build order for a workspace of local path components.
+//!
+//! Pure core, like `component_walk`. The caller discovers the members
+//! (a project's `path:` dependencies and, transitively, theirs); this
+//! module only orders them so every component is built after the local
+//! components it depends on.
+
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Member {
+    /// `org/name`, as written in the dependency map.
+    pub name: String,
+    /// Canonical component directory.
+    pub path: PathBuf,
+    /// Canonical directories of the local components this one depends on.
+    pub depends_on: Vec<PathBuf>,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum WorkspaceError {
+    #[error("dependency cycle between local components: {}", .0.join(", "))]
+    Cycle(Vec<String>),
+}
+
+/// Order `members` so dependencies come first. Independent members keep
+/// their discovery order, so the result is stable across runs.
+/// Dependencies outside the workspace are ignored.
+pub fn build_order(members: &[Member]) -> Result<Vec<&Member>, WorkspaceError> {
+    let index_of = |path: &PathBuf| members.iter().position(|m| &m.path == path);
+    let mut remaining: Vec<usize> = members
+        .iter()
+        .map(|m| {
+            let mut deps: Vec<usize> = m.depends_on.iter().filter_map(index_of).collect();
+            deps.sort();
+            deps.dedup();
+            deps.len()
+        })
+        .collect();
+
+    let mut order = Vec::with_capacity(members.len());
+    let mut done = vec![false; members.len()];
+    while order.len() < members.len() {
+        let Some(next) = (0..members.len()).find(|&i| !done[i] && remaining[i] == 0) else {
+            let mut cycle: Vec<String> = (0..members.len())
+                .filter(|&i| !done[i])
+                .map(|i| members[i].name.clone())
+                .collect();
+            cycle.sort();
+            return Err(WorkspaceError::Cycle(cycle));
+        };
+
+        done[next] = true;
+        order.push(&members[next]);
+        for (i, member) in members.iter().enumerate() {
+            if !done[i] && member.depends_on.contains(&members[next].path) {
+                remaining[i] -= 1;
+            }
+        }
+    }
+
+    Ok(order)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn member(name: &str, depends_on: &[&str]) -> Member {
+        Member {
+            name: format!("forest/{name}"),
+            path: PathBuf::from(format!("/ws/{name}")),
+            depends_on: depends_on
+                .iter()
+                .map(|d| PathBuf::from(format!("/ws/{d}")))
+                .collect(),
+        }
+    }
+
+    fn names(order: Vec<&Member>) -> Vec<&str> {
+        order.iter().map(|m| m.name.as_str()).collect()
+    }
+
+    #[test]
+    fn dependencies_are_built_first() {
+        let members = vec![
+            member("service", &["deployment", "terraform"]),
+            member("terraform", &["deployment"]),
+            member("deployment", &[]),
+        ];
+        assert_eq!(
+            names(build_order(&members).unwrap()),
+            vec!["forest/deployment", "forest/terraform", "forest/service"]
+        );
+    }
+
+    #[test]
+    fn independent_members_keep_discovery_order() {
+        let members = vec![
+            member("b", &[]),
+            member("a", &[]),
+            member("c", &["outside-the-workspace"]),
+        ];
+        assert_eq!(
+            names(build_order(&members).unwrap()),
+            vec!["forest/b", "forest/a", "forest/c"]
+        );
+    }
+
+    #[test]
+    fn cycles_are_reported_by_name() {
+        let members = vec![
+            member("root", &[]),
+            member("a", &["b"]),
+            member("b", &["a"]),
+        ];
+        assert_eq!(
+            build_order(&members).unwrap_err(),
+            WorkspaceError::Cycle(vec!["forest/a".into(), "forest/b".into()])
+        );
+    }
+}